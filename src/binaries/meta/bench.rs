@@ -0,0 +1,283 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_grpc::RpcClientConf;
+use common_meta_client::ClientHandle;
+use common_meta_client::MetaGrpcClient;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::txn_op::Request;
+use common_meta_types::TxnGetRequest;
+use common_meta_types::TxnOp;
+use common_meta_types::TxnPutRequest;
+use common_meta_types::TxnRequest;
+use databend_meta::configs::Config;
+
+const DEFAULT_BENCH_PREFIX: &str = "__bench/";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum BenchOp {
+    Upsert,
+    Get,
+    Txn,
+}
+
+impl fmt::Display for BenchOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BenchOp::Upsert => write!(f, "upsert"),
+            BenchOp::Get => write!(f, "get"),
+            BenchOp::Txn => write!(f, "txn"),
+        }
+    }
+}
+
+/// Drives a kvapi workload against a running meta cluster with several concurrent clients.
+pub struct BenchCommand {
+    ops: Vec<BenchOp>,
+    clients: u64,
+    requests: u64,
+    prefix: String,
+    value: String,
+}
+
+impl BenchCommand {
+    pub fn from_config(config: &Config, workload: &str) -> Result<Self, String> {
+        let mut ops = vec![];
+        for op in workload.split(',').map(|s| s.trim()) {
+            let op = match op {
+                "upsert" => BenchOp::Upsert,
+                "get" => BenchOp::Get,
+                "txn" => BenchOp::Txn,
+                _ => {
+                    return Err(format!("Unknown bench workload: {}", op));
+                }
+            };
+            ops.push(op);
+        }
+        if config.bench_clients == 0 || config.bench_requests == 0 {
+            return Err("The number of clients and requests must be greater than 0".to_string());
+        }
+
+        let prefix = if config.prefix.is_empty() {
+            DEFAULT_BENCH_PREFIX.to_string()
+        } else {
+            config.prefix.clone()
+        };
+
+        Ok(Self {
+            ops,
+            clients: config.bench_clients,
+            requests: config.bench_requests,
+            prefix,
+            value: config.value.clone(),
+        })
+    }
+
+    pub async fn execute(&self, rpc_conf: RpcClientConf) -> anyhow::Result<String> {
+        let start = Instant::now();
+
+        let mut handles = Vec::with_capacity(self.clients as usize);
+        for client_id in 0..self.clients {
+            let client = MetaGrpcClient::try_new(&rpc_conf)?;
+            let worker = BenchWorker {
+                client,
+                client_id,
+                ops: self.ops.clone(),
+                requests: self.requests,
+                prefix: self.prefix.clone(),
+                value: self.value.clone(),
+            };
+            handles.push(tokio::spawn(worker.run()));
+        }
+
+        let mut stats = BenchStats::default();
+        for handle in handles {
+            stats.merge(handle.await?);
+        }
+
+        let report = BenchReport {
+            ops: self.ops.clone(),
+            clients: self.clients,
+            requests: self.requests,
+            elapsed: start.elapsed(),
+            stats,
+        };
+        Ok(report.to_string())
+    }
+}
+
+struct BenchWorker {
+    client: Arc<ClientHandle>,
+    client_id: u64,
+    ops: Vec<BenchOp>,
+    requests: u64,
+    prefix: String,
+    value: String,
+}
+
+impl BenchWorker {
+    async fn run(self) -> BenchStats {
+        let mut stats = BenchStats::default();
+        for i in 0..self.requests {
+            let op = self.ops[i as usize % self.ops.len()];
+            // Every round of the workload works on the same key,
+            // e.g., with `upsert,get` a get reads the key just written.
+            let key = format!(
+                "{}{}/{}",
+                self.prefix,
+                self.client_id,
+                i / self.ops.len() as u64
+            );
+
+            let start = Instant::now();
+            let ok = self.send(op, key).await;
+            stats.record(op, start.elapsed(), ok);
+        }
+        stats
+    }
+
+    async fn send(&self, op: BenchOp, key: String) -> bool {
+        let value = if self.value.is_empty() {
+            key.as_bytes().to_vec()
+        } else {
+            self.value.as_bytes().to_vec()
+        };
+
+        match op {
+            BenchOp::Upsert => self
+                .client
+                .upsert_kv(UpsertKVReq::update(&key, &value))
+                .await
+                .is_ok(),
+            BenchOp::Get => self.client.get_kv(&key).await.is_ok(),
+            BenchOp::Txn => {
+                let txn = TxnRequest {
+                    condition: vec![],
+                    if_then: vec![
+                        TxnOp {
+                            request: Some(Request::Get(TxnGetRequest { key: key.clone() })),
+                        },
+                        TxnOp {
+                            request: Some(Request::Put(TxnPutRequest {
+                                key,
+                                value,
+                                prev_value: true,
+                                expire_at: None,
+                                ttl_ms: None,
+                            })),
+                        },
+                    ],
+                    else_then: vec![],
+                };
+                self.client.transaction(txn).await.is_ok()
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct BenchStats {
+    /// Latencies in microseconds of the succeeded requests, grouped by operation.
+    latencies: BTreeMap<BenchOp, Vec<u64>>,
+    errors: u64,
+}
+
+impl BenchStats {
+    fn record(&mut self, op: BenchOp, latency: Duration, ok: bool) {
+        if ok {
+            self.latencies
+                .entry(op)
+                .or_default()
+                .push(latency.as_micros() as u64);
+        } else {
+            self.errors += 1;
+        }
+    }
+
+    fn merge(&mut self, other: BenchStats) {
+        for (op, latencies) in other.latencies {
+            self.latencies.entry(op).or_default().extend(latencies);
+        }
+        self.errors += other.errors;
+    }
+}
+
+struct BenchReport {
+    ops: Vec<BenchOp>,
+    clients: u64,
+    requests: u64,
+    elapsed: Duration,
+    stats: BenchStats,
+}
+
+impl fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let workload = self
+            .ops
+            .iter()
+            .map(|op| op.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let total = self.clients * self.requests;
+        let secs = self.elapsed.as_secs_f64();
+
+        writeln!(f, "workload: {}", workload)?;
+        writeln!(
+            f,
+            "clients: {}, requests per client: {}",
+            self.clients, self.requests
+        )?;
+        writeln!(
+            f,
+            "total: {} requests, {} errors, in {:.3} seconds",
+            total, self.stats.errors, secs
+        )?;
+        writeln!(f, "throughput: {:.2} requests/s", total as f64 / secs)?;
+
+        write!(f, "latency(ms):")?;
+        for (op, latencies) in &self.stats.latencies {
+            let mut latencies = latencies.clone();
+            latencies.sort_unstable();
+            let ms = |p: f64| percentile(&latencies, p) as f64 / 1000.0;
+            write!(
+                f,
+                "\n  {:<6} count={} p50={:.3} p90={:.3} p99={:.3} p99.9={:.3} max={:.3}",
+                op.to_string(),
+                latencies.len(),
+                ms(0.5),
+                ms(0.9),
+                ms(0.99),
+                ms(0.999),
+                ms(1.0),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the value at percentile `p` of the sorted `values`.
+fn percentile(values: &[u64], p: f64) -> u64 {
+    if values.is_empty() {
+        return 0;
+    }
+    let rank = (values.len() as f64 * p).ceil() as usize;
+    values[rank.clamp(1, values.len()) - 1]
+}
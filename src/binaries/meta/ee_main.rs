@@ -13,6 +13,7 @@
 // limitations under the License.
 
 #![allow(clippy::uninlined_format_args)]
+mod bench;
 mod entry;
 mod kvapi;
 
@@ -46,9 +46,11 @@ use log::warn;
 use tokio::time::sleep;
 use tokio::time::Instant;
 
+use crate::bench::BenchCommand;
 use crate::kvapi::KvApiCommand;
 
 const CMD_KVAPI_PREFIX: &str = "kvapi::";
+const CMD_BENCH_PREFIX: &str = "bench::";
 
 pub async fn entry(conf: Config) -> anyhow::Result<()> {
     if run_cmd(&conf).await {
@@ -252,6 +254,31 @@ async fn run_kvapi_command(conf: &Config, op: &str) {
     }
 }
 
+async fn run_bench_command(conf: &Config, workload: &str) {
+    match BenchCommand::from_config(conf, workload) {
+        Ok(bench_cmd) => {
+            let rpc_conf = RpcClientConf {
+                endpoints: vec![conf.grpc_api_address.clone()],
+                username: conf.username.clone(),
+                password: conf.password.clone(),
+                ..Default::default()
+            };
+
+            match bench_cmd.execute(rpc_conf).await {
+                Ok(res) => {
+                    println!("{}", res);
+                }
+                Err(e) => {
+                    eprintln!("{}", e);
+                }
+            }
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+        }
+    }
+}
+
 /// The meta service GRPC API address can be changed by administrator in the config file.
 ///
 /// Thus every time a meta server starts up, re-register the node info to broadcast its latest grpc address
@@ -366,6 +393,10 @@ async fn run_cmd(conf: &Config) -> bool {
                     return true;
                 }
             }
+            if let Some(workload) = cmd.strip_prefix(CMD_BENCH_PREFIX) {
+                run_bench_command(conf, workload).await;
+                return true;
+            }
             eprintln!("Invalid cmd: {}", conf.cmd);
             eprintln!("Available cmds:");
             eprintln!("  --cmd ver");
@@ -374,6 +405,8 @@ async fn run_cmd(conf: &Config) -> bool {
             eprintln!("    Print effective config");
             eprintln!("  --cmd kvapi::<cmd>");
             eprintln!("    Run kvapi command (upsert, get, mget, list)");
+            eprintln!("  --cmd bench::<workload>");
+            eprintln!("    Benchmark kvapi with a comma separated workload (upsert, get, txn)");
         }
     }
 
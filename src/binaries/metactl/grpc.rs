@@ -20,7 +20,9 @@ use common_meta_client::MetaGrpcClient;
 use common_meta_types::protobuf::Empty;
 use tokio_stream::StreamExt;
 
-pub async fn export_meta(addr: &str, save: String) -> anyhow::Result<()> {
+/// Export data from a running meta-service, or only a snapshot of its state machine if
+/// `snapshot` is true.
+pub async fn export_meta(addr: &str, save: String, snapshot: bool) -> anyhow::Result<()> {
     let client = MetaGrpcClient::try_create(
         vec![addr.to_string()],
         "root",
@@ -33,7 +35,13 @@ pub async fn export_meta(addr: &str, save: String) -> anyhow::Result<()> {
 
     let mut grpc_client = client.make_client().await?;
 
-    let exported = grpc_client.export(tonic::Request::new(Empty {})).await?;
+    let exported = if snapshot {
+        grpc_client
+            .export_snapshot(tonic::Request::new(Empty {}))
+            .await?
+    } else {
+        grpc_client.export(tonic::Request::new(Empty {})).await?
+    };
 
     let mut stream = exported.into_inner();
 
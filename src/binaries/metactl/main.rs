@@ -55,6 +55,13 @@ pub struct Config {
     #[clap(long)]
     pub export: bool,
 
+    /// When export, export only a snapshot of the state machine, without raft state and logs.
+    /// If exporting from a running meta-service, a snapshot including every applied log is
+    /// built first.
+    /// The snapshot has to be imported with `--initial-cluster` to restore a new cluster.
+    #[clap(long)]
+    pub snapshot: bool,
+
     #[clap(long, env = "METASRV_GRPC_API_ADDRESS", default_value = "")]
     pub grpc_api_address: String,
 
@@ -37,6 +37,9 @@ use common_meta_raft_store::ondisk::DATA_VERSION;
 use common_meta_raft_store::ondisk::TREE_HEADER;
 use common_meta_raft_store::sm_v002::SnapshotStoreV002;
 use common_meta_raft_store::state::RaftState;
+use common_meta_raft_store::state::RaftStateKey;
+use common_meta_raft_store::state::RaftStateValue;
+use common_meta_raft_store::state_machine::StateMachineMetaKey;
 use common_meta_sled_store::get_sled_db;
 use common_meta_sled_store::init_sled_db;
 use common_meta_sled_store::openraft::compat::Upgrade;
@@ -84,21 +87,105 @@ pub async fn import_data(config: &Config) -> anyhow::Result<()> {
     init_sled_db(raft_config.raft_dir.clone());
 
     clear(config)?;
-    let max_log_id = import_from_stdin_or_file(config).await?;
+    let imported = import_from_stdin_or_file(config).await?;
 
     if config.initial_cluster.is_empty() {
+        if imported.max_log_id.is_none() && imported.last_applied.is_some() {
+            return Err(anyhow!(
+                "imported a state machine without raft logs, e.g., an exported snapshot, \
+                 it requires --initial-cluster to restore a new cluster"
+            ));
+        }
         return Ok(());
     }
 
-    init_new_cluster(config, nodes, max_log_id, config.raft_config.id).await?;
+    init_new_cluster(config, nodes, imported.max_log_id, config.raft_config.id).await?;
     Ok(())
 }
 
-/// Import from lines of exported data and Return the max log id that is found.
+/// The log ids found in the imported data.
+///
+/// They are used to check that the imported state machine is consistent with the raft state
+/// and logs, e.g., when they are exported from different nodes or the exported data is broken.
+#[derive(Debug, Default)]
+struct ImportedLogIds {
+    /// The last log id applied to the imported state machine.
+    last_applied: Option<LogId>,
+
+    /// The committed log id in the imported raft state.
+    committed: Option<LogId>,
+
+    /// The first log id of the imported raft logs.
+    first_log_id: Option<LogId>,
+
+    /// The max log id of the imported raft logs.
+    max_log_id: Option<LogId>,
+}
+
+impl ImportedLogIds {
+    fn observe(&mut self, entry: &RaftStoreEntry) -> anyhow::Result<()> {
+        match entry {
+            RaftStoreEntry::Logs { value, .. } => {
+                let log_id = Some(value.log_id);
+                if self.first_log_id.is_none() || log_id < self.first_log_id {
+                    self.first_log_id = log_id;
+                }
+                self.max_log_id = std::cmp::max(self.max_log_id, log_id);
+            }
+            RaftStoreEntry::StateMachineMeta {
+                key: StateMachineMetaKey::LastApplied,
+                value,
+            } => {
+                let log_id: LogId = value.clone().try_into().map_err(|e| anyhow!("{}", e))?;
+                self.last_applied = Some(log_id);
+            }
+            RaftStoreEntry::RaftStateKV {
+                key: RaftStateKey::Committed,
+                value: RaftStateValue::Committed(committed),
+            } => {
+                self.committed = *committed;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Check the last applied log id of the state machine against the raft state and logs.
+    fn validate(&self) -> anyhow::Result<()> {
+        if let (Some(last_applied), Some(committed)) = (self.last_applied, self.committed) {
+            if last_applied > committed {
+                return Err(anyhow!(
+                    "inconsistent data: state machine applied log {} is greater than committed log {}",
+                    last_applied,
+                    committed
+                ));
+            }
+        }
+
+        // The logs after the last applied one must all be imported, otherwise they are lost.
+        if let Some(first_log_id) = self.first_log_id {
+            let next_index = self.last_applied.map_or(0, |x| x.index + 1);
+            if first_log_id.index > next_index {
+                return Err(anyhow!(
+                    "inconsistent data: logs since index {} are missing, state machine applied log: {:?}, first imported log: {}",
+                    next_index,
+                    self.last_applied,
+                    first_log_id
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Import from lines of exported data and return the log ids that are found.
+///
+/// The imported state machine is validated against the imported raft state and logs.
 fn import_lines<B: BufRead + 'static>(
     config: &Config,
     lines: Lines<B>,
-) -> anyhow::Result<Option<LogId>> {
+) -> anyhow::Result<ImportedLogIds> {
     #[allow(clippy::useless_conversion)]
     let mut it = lines.into_iter().peekable();
     let first = it
@@ -124,13 +211,19 @@ fn import_lines<B: BufRead + 'static>(
         ));
     }
 
-    let max_log_id = match version {
+    let imported = match version {
         DataVersion::V0 => import_v0_or_v001(config, it)?,
         DataVersion::V001 => import_v0_or_v001(config, it)?,
         DataVersion::V002 => import_v002(config, it)?,
     };
 
-    Ok(max_log_id)
+    eprintln!(
+        "Imported state machine last applied: {:?}, committed: {:?}, logs: [{:?}, {:?}]",
+        imported.last_applied, imported.committed, imported.first_log_id, imported.max_log_id,
+    );
+    imported.validate()?;
+
+    Ok(imported)
 }
 
 fn read_version(first_line: &str) -> anyhow::Result<DataVersion> {
@@ -156,14 +249,14 @@ fn read_version(first_line: &str) -> anyhow::Result<DataVersion> {
 
 /// Import serialized lines for `DataVersion::V0` and `DataVersion::V001`
 ///
-/// While importing, the max log id is also returned.
+/// While importing, the log ids found are also returned.
 fn import_v0_or_v001(
     _config: &Config,
     lines: impl IntoIterator<Item = Result<String, io::Error>>,
-) -> anyhow::Result<Option<LogId>> {
+) -> anyhow::Result<ImportedLogIds> {
     let db = get_sled_db();
     let mut n = 0;
-    let mut max_log_id: Option<LogId> = None;
+    let mut imported = ImportedLogIds::default();
     let mut trees = BTreeMap::new();
 
     for line in lines {
@@ -183,9 +276,7 @@ fn import_v0_or_v001(
         tree.insert(k, v)?;
         n += 1;
 
-        if let RaftStoreEntry::Logs { key: _, value } = kv_entry {
-            max_log_id = std::cmp::max(max_log_id, Some(value.log_id));
-        };
+        imported.observe(&kv_entry)?;
     }
 
     for tree in trees.values() {
@@ -193,24 +284,24 @@ fn import_v0_or_v001(
     }
 
     eprintln!("Imported {} records", n);
-    Ok(max_log_id)
+    Ok(imported)
 }
 
 /// Import serialized lines for `DataVersion::V002`
 ///
-/// While importing, the max log id is also returned.
+/// While importing, the log ids found are also returned.
 ///
 /// It write logs and related entries to sled trees, and state_machine entries to a snapshot.
 fn import_v002(
     config: &Config,
     lines: impl IntoIterator<Item = Result<String, io::Error>>,
-) -> anyhow::Result<Option<LogId>> {
+) -> anyhow::Result<ImportedLogIds> {
     let raft_config: RaftConfig = config.raft_config.clone().into();
 
     let db = get_sled_db();
 
     let mut n = 0;
-    let mut max_log_id: Option<LogId> = None;
+    let mut imported = ImportedLogIds::default();
     let mut trees = BTreeMap::new();
 
     let mut snapshot_store = SnapshotStoreV002::new(DataVersion::V002, raft_config);
@@ -221,6 +312,8 @@ fn import_v002(
         let (tree_name, kv_entry): (String, RaftStoreEntryCompat) = serde_json::from_str(&l)?;
         let kv_entry = kv_entry.upgrade();
 
+        imported.observe(&kv_entry)?;
+
         if tree_name.starts_with("state_machine/") {
             // Write to snapshot
             writer.write_entries::<io::Error>([kv_entry])?;
@@ -236,10 +329,6 @@ fn import_v002(
             let (k, v) = RaftStoreEntry::serialize(&kv_entry)?;
 
             tree.insert(k, v)?;
-
-            if let RaftStoreEntry::Logs { key: _, value } = kv_entry {
-                max_log_id = std::cmp::max(max_log_id, Some(value.log_id));
-            };
         }
 
         n += 1;
@@ -256,17 +345,17 @@ fn import_v002(
         snapshot_id.to_string(),
         snapshot_size
     );
-    Ok(max_log_id)
+    Ok(imported)
 }
 
 /// Read every line from stdin or restore file, deserialize it into tree_name, key and value.
 /// Insert them into sled db and flush.
 ///
 /// Finally upgrade the data in raft_dir to the latest version.
-async fn import_from_stdin_or_file(config: &Config) -> anyhow::Result<Option<LogId>> {
+async fn import_from_stdin_or_file(config: &Config) -> anyhow::Result<ImportedLogIds> {
     let restore = config.db.clone();
 
-    let max_log_id = if restore.is_empty() {
+    let imported = if restore.is_empty() {
         let lines = io::stdin().lines();
 
         import_lines(config, lines)?
@@ -280,7 +369,7 @@ async fn import_from_stdin_or_file(config: &Config) -> anyhow::Result<Option<Log
 
     upgrade(config).await?;
 
-    Ok(max_log_id)
+    Ok(imported)
 }
 
 /// Upgrade the data in raft_dir to the latest version.
@@ -465,7 +554,11 @@ async fn export_from_dir(config: &Config) -> anyhow::Result<()> {
     let raft_config: RaftConfig = config.raft_config.clone().into();
 
     let sto_inn = StoreInner::open_create(&raft_config, Some(()), None).await?;
-    let lines = sto_inn.export().await?;
+    let lines = if config.snapshot {
+        sto_inn.export_snapshot().await?
+    } else {
+        sto_inn.export().await?
+    };
 
     eprintln!("    From: {}", raft_config.raft_dir);
 
@@ -504,7 +597,12 @@ async fn export_from_running_node(config: &Config) -> Result<(), anyhow::Error>
 
     let grpc_api_addr = get_available_socket_addr(&config.grpc_api_address).await?;
 
-    export_meta(grpc_api_addr.to_string().as_str(), config.db.clone()).await?;
+    export_meta(
+        grpc_api_addr.to_string().as_str(),
+        config.db.clone(),
+        config.snapshot,
+    )
+    .await?;
     Ok(())
 }
 
@@ -29,12 +29,16 @@ use common_tracing::set_panic_hook;
 use databend_query::api::HttpService;
 use databend_query::api::RpcService;
 use databend_query::clusters::ClusterDiscovery;
+use databend_query::clusters::StandbyTables;
+use databend_query::interpreters::MaterializedViewRefresher;
 use databend_query::metrics::MetricService;
+use databend_query::pipes::PipeRunner;
 use databend_query::servers::FlightSQLServer;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
 use databend_query::servers::MySQLHandler;
 use databend_query::servers::MySQLTlsConfig;
+use databend_query::servers::PostgresHandler;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
 use databend_query::GlobalServices;
@@ -156,6 +160,28 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         );
     }
 
+    // PostgreSQL handler.
+    {
+        let hostname = conf.query.postgres_handler_host.clone();
+        let listening = format!("{}:{}", hostname, conf.query.postgres_handler_port);
+        let tcp_keepalive_timeout_secs = conf.query.mysql_handler_tcp_keepalive_timeout_secs;
+        let tls_config = MySQLTlsConfig::new(
+            conf.query.postgres_tls_server_cert.clone(),
+            conf.query.postgres_tls_server_key.clone(),
+        );
+
+        let mut handler = PostgresHandler::create(tcp_keepalive_timeout_secs, tls_config)?;
+        let listening = handler.start(listening.parse()?).await?;
+        shutdown_handle.add_service(handler);
+
+        info!(
+            "Listening for PostgreSQL compatibility protocol: {}, Usage: psql -h{} -p{} -Uroot",
+            listening,
+            listening.ip(),
+            listening.port(),
+        );
+    }
+
     // ClickHouse HTTP handler.
     {
         let hostname = conf.query.clickhouse_http_handler_host.clone();
@@ -238,6 +264,20 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         );
     }
 
+    // Follow standby tables.
+    {
+        StandbyTables::start(conf)?;
+        if !conf.query.standby_tables.is_empty() {
+            info!("Following standby tables: {:?}", conf.query.standby_tables);
+        }
+    }
+
+    // Run pipes of the tenant.
+    PipeRunner::start(conf)?;
+
+    // Refresh materialized views in the background.
+    MaterializedViewRefresher::start(conf)?;
+
     // Print information to users.
     println!("Databend Query");
     println!();
@@ -303,6 +343,15 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         "    connect via: mysql -u${{USER}} -p${{PASSWORD}} -h{} -P{}",
         conf.query.mysql_handler_host, conf.query.mysql_handler_port
     );
+    println!("PostgreSQL");
+    println!(
+        "    listened at {}:{}",
+        conf.query.postgres_handler_host, conf.query.postgres_handler_port
+    );
+    println!(
+        "    connect via: psql -U${{USER}} -h{} -p{}",
+        conf.query.postgres_handler_host, conf.query.postgres_handler_port
+    );
     println!("Clickhouse(http)");
     println!(
         "    listened at {}:{}",
@@ -27,6 +27,7 @@ use async_compression::codec::LzmaDecoder;
 use async_compression::codec::XzDecoder;
 use async_compression::codec::ZlibDecoder;
 use async_compression::codec::ZstdDecoder;
+use async_compression::futures::bufread;
 use async_compression::util::PartialBuffer;
 use bytes::Buf;
 use bytes::BytesMut;
@@ -34,6 +35,7 @@ use futures::io::BufReader;
 use futures::ready;
 use futures::AsyncBufRead;
 use futures::AsyncRead;
+use futures::AsyncReadExt;
 use log::trace;
 use pin_project::pin_project;
 use serde::Deserialize;
@@ -409,6 +411,54 @@ impl<R: AsyncRead> AsyncRead for DecompressReader<R> {
     }
 }
 
+/// Compress the whole `input` with the given algorithm.
+pub async fn compress(algo: CompressAlgorithm, input: &[u8]) -> Result<Vec<u8>> {
+    let mut output = Vec::with_capacity(input.len() / 2);
+    match algo {
+        CompressAlgorithm::Brotli => {
+            bufread::BrotliEncoder::new(input)
+                .read_to_end(&mut output)
+                .await?
+        }
+        CompressAlgorithm::Bz2 => {
+            bufread::BzEncoder::new(input)
+                .read_to_end(&mut output)
+                .await?
+        }
+        CompressAlgorithm::Deflate => {
+            bufread::DeflateEncoder::new(input)
+                .read_to_end(&mut output)
+                .await?
+        }
+        CompressAlgorithm::Gzip => {
+            bufread::GzipEncoder::new(input)
+                .read_to_end(&mut output)
+                .await?
+        }
+        CompressAlgorithm::Lzma => {
+            bufread::LzmaEncoder::new(input)
+                .read_to_end(&mut output)
+                .await?
+        }
+        CompressAlgorithm::Xz => {
+            bufread::XzEncoder::new(input)
+                .read_to_end(&mut output)
+                .await?
+        }
+        CompressAlgorithm::Zlib => {
+            bufread::ZlibEncoder::new(input)
+                .read_to_end(&mut output)
+                .await?
+        }
+        CompressAlgorithm::Zstd => {
+            bufread::ZstdEncoder::new(input)
+                .read_to_end(&mut output)
+                .await?
+        }
+    };
+    Ok(output)
+}
+
 #[cfg(test)]
 mod tests {
     use std::cmp::min;
@@ -691,4 +741,36 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_compress_roundtrip() -> Result<()> {
+        let _ = env_logger::try_init();
+
+        let content = fs::read(format!(
+            "{}/tests/data/ontime_200.csv",
+            env::current_dir()?.to_string_lossy()
+        ))?;
+
+        for algo in [
+            CompressAlgorithm::Brotli,
+            CompressAlgorithm::Bz2,
+            CompressAlgorithm::Deflate,
+            CompressAlgorithm::Gzip,
+            CompressAlgorithm::Lzma,
+            CompressAlgorithm::Xz,
+            CompressAlgorithm::Zlib,
+            CompressAlgorithm::Zstd,
+        ] {
+            let compressed_content = compress(algo, &content).await?;
+            assert!(compressed_content.len() < content.len());
+
+            let mut cr = DecompressReader::new(Cursor::new(compressed_content), algo);
+            let mut result = vec![];
+            cr.read_to_end(&mut result).await?;
+
+            assert_eq!(result, content, "algo: {:?}", algo);
+        }
+
+        Ok(())
+    }
 }
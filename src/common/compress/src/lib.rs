@@ -22,3 +22,4 @@ pub use compress::DecompressCodec;
 pub use compress::DecompressDecoder;
 pub use compress::DecompressReader;
 pub use compress::DecompressState;
+pub use compress::compress;
@@ -148,6 +148,15 @@ build_exceptions! {
     UnknownCatalog(1119),
     UnknownCatalogType(1120),
     UnmatchMaskPolicyReturnType(1121),
+    InvalidPartitionKey(1122),
+    // The table is not a partitioned table.
+    UnpartitionedTable(1123),
+    // The statement is rejected as the server is in read only mode.
+    ReadOnlyModePermissionDenied(1124),
+    // The query is estimated to scan more bytes than `max_estimated_scan_bytes`.
+    ScanBudgetExceeded(1125),
+    // Some segments hold the rows of the partition along with the rows of other partitions.
+    PartitionNotDroppable(1126),
 
     // Data Related Errors
 
@@ -266,10 +275,28 @@ build_exceptions! {
     IllegalFileFormat(2508),
     FileFormatAlreadyExists(2509),
 
+    // Storage profile error codes.
+    UnknownStorageProfile(2510),
+    StorageProfileAlreadyExists(2511),
+    IllegalStorageProfile(2512),
+
+    // Pipe error codes.
+    UnknownPipe(2513),
+    PipeAlreadyExists(2514),
+    IllegalPipe(2515),
+    PipeProgressConflict(2516),
+
+    // Sequence error codes.
+    UnknownSequence(2517),
+    SequenceAlreadyExists(2518),
+    IllegalSequence(2519),
+    SequenceExhausted(2520),
+
     // User defined function error codes.
     IllegalUDFFormat(2601),
     UnknownUDF(2602),
     UdfAlreadyExists(2603),
+    UDFRuntimeError(2604),
 
     // Database error codes.
     UnknownDatabaseEngine(2701),
@@ -299,6 +326,9 @@ build_exceptions! {
     DropIndexWithDropTime(2723),
     GetIndexWithDropTime(2724),
 
+    // Stream error codes.
+    IllegalStream(2730),
+
     // Variable error codes.
     UnknownVariable(2801),
     OnlySupportAsciiChars(2802),
@@ -315,6 +345,8 @@ build_exceptions! {
 build_exceptions! {
     StorageNotFound(3001),
     StoragePermissionDenied(3002),
+    StorageDataCorrupted(3003),
+    StorageEncryptionError(3004),
     StorageUnavailable(3901),
     StorageUnsupported(3902),
     StorageInsecure(3903),
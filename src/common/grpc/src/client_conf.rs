@@ -39,6 +39,8 @@ pub struct RpcClientConf {
     /// None disables auto-sync.
     pub auto_sync_interval: Option<Duration>,
     pub unhealth_endpoint_evict_time: Duration,
+    /// Whether to compress the requests and the responses exchanged with the server.
+    pub compression: bool,
 }
 
 impl RpcClientConf {
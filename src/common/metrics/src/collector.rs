@@ -0,0 +1,133 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Metrics which are not recorded by the prometheus recorder, but computed each time the
+//! metrics are dumped or rendered: the ratios derived from the recorded metrics, and the
+//! samples of the registered collectors.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::sync::Arc;
+
+use metrics_exporter_prometheus::PrometheusHandle;
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+
+use crate::dump::parse_metric_samples;
+use crate::MetricSample;
+use crate::MetricValue;
+
+type Collector = Arc<dyn Fn() -> Vec<MetricSample> + Send + Sync>;
+
+static COLLECTORS: Lazy<RwLock<Vec<Collector>>> = Lazy::new(|| RwLock::new(Vec::new()));
+
+/// Register a collector of metrics which are too hot to be recorded on each change, like
+/// the length of a queue, the collector is called each time the metrics are dumped.
+pub fn register_metric_collector(
+    collector: impl Fn() -> Vec<MetricSample> + Send + Sync + 'static,
+) {
+    COLLECTORS.write().push(Arc::new(collector));
+}
+
+pub fn collect_metric_samples() -> Vec<MetricSample> {
+    let collectors = COLLECTORS.read().clone();
+    collectors
+        .iter()
+        .flat_map(|collector| collector())
+        .collect()
+}
+
+/// Ratios derived from pairs of recorded gauges:
+///
+/// - `cache_{name}_hit_ratio`, from `cache_{name}_hit_count` and `cache_{name}_access_count`.
+/// - `{name}_pruning_ratio`, the ratio pruned, from `{name}_pruning_before` and
+///   `{name}_pruning_after`.
+///
+/// Ratios are derived when they are dumped rather than recorded, so that they stay
+/// consistent with their counts, also after the metrics are reset.
+pub fn derived_metric_samples(samples: &[MetricSample]) -> Vec<MetricSample> {
+    let gauges = samples
+        .iter()
+        .filter_map(|sample| match sample.value {
+            MetricValue::Gauge(v) if sample.labels.is_empty() => Some((sample.name.as_str(), v)),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut derived = Vec::new();
+    for (name, value) in gauges.iter() {
+        let ratio = if let Some(prefix) = name.strip_suffix("_access_count") {
+            gauges
+                .get(format!("{prefix}_hit_count").as_str())
+                .filter(|_| *value > 0.0)
+                .map(|hits| (format!("{prefix}_hit_ratio"), hits / value))
+        } else if let Some(prefix) = name.strip_suffix("_pruning_before") {
+            gauges
+                .get(format!("{prefix}_pruning_after").as_str())
+                .filter(|_| *value > 0.0)
+                .map(|after| (format!("{prefix}_pruning_ratio"), 1.0 - after / value))
+        } else {
+            None
+        };
+
+        if let Some((name, ratio)) = ratio {
+            derived.push(MetricSample {
+                name,
+                labels: HashMap::new(),
+                value: MetricValue::Gauge(ratio),
+            });
+        }
+    }
+    derived.sort_by(|a, b| a.name.cmp(&b.name));
+    derived
+}
+
+/// Render the metrics in the prometheus text format, with the metrics computed on demand.
+pub fn render_metrics(handle: &PrometheusHandle) -> String {
+    let mut text = handle.render();
+    let mut samples = match parse_metric_samples(&text) {
+        Ok(samples) => derived_metric_samples(&samples),
+        Err(_) => vec![],
+    };
+    samples.extend(collect_metric_samples());
+
+    let mut typed = HashSet::new();
+    for sample in samples {
+        let (kind, value) = match sample.value {
+            MetricValue::Counter(v) => ("counter", v),
+            MetricValue::Gauge(v) => ("gauge", v),
+            MetricValue::Untyped(v) => ("untyped", v),
+            // Histograms and summaries are recorded by the recorder only.
+            MetricValue::Histogram(_) | MetricValue::Summary(_) => continue,
+        };
+
+        let mut labels = sample
+            .labels
+            .iter()
+            .map(|(k, v)| format!("{}=\"{}\"", k, v.replace('\\', "\\\\").replace('"', "\\\"")))
+            .collect::<Vec<_>>();
+        labels.sort();
+        let labels = match labels.is_empty() {
+            true => String::new(),
+            false => format!("{{{}}}", labels.join(",")),
+        };
+
+        if typed.insert(sample.name.clone()) {
+            let _ = writeln!(text, "# TYPE {} {}", sample.name, kind);
+        }
+        let _ = writeln!(text, "{}{} {}", sample.name, labels, value);
+    }
+    text
+}
@@ -18,6 +18,9 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use metrics_exporter_prometheus::PrometheusHandle;
 
+use crate::collector::collect_metric_samples;
+use crate::collector::derived_metric_samples;
+
 #[derive(Debug)]
 pub struct MetricSample {
     pub name: String,
@@ -86,10 +89,21 @@ pub struct SummaryCount {
     pub count: f64,
 }
 
+/// Dump the recorded metrics, with the metrics computed on demand and the stats of the process.
 pub fn dump_metric_samples(handle: PrometheusHandle) -> Result<Vec<MetricSample>> {
-    let text = handle.render();
+    let mut samples = parse_metric_samples(&handle.render())?;
+    samples.extend(derived_metric_samples(&samples));
+    samples.extend(collect_metric_samples());
+
+    let proc_stats = dump_proc_stats().unwrap_or_default();
+    samples.extend(proc_stats);
+    Ok(samples)
+}
+
+/// Parse the metrics rendered in the prometheus text format.
+pub fn parse_metric_samples(text: &str) -> Result<Vec<MetricSample>> {
     let lines = text.lines().map(|s| Ok(s.to_owned()));
-    let mut samples = prometheus_parse::Scrape::parse(lines)
+    let samples = prometheus_parse::Scrape::parse(lines)
         .map_err(|err| ErrorCode::Internal(format!("Dump prometheus metrics failed: {:?}", err)))?
         .samples
         .into_iter()
@@ -102,9 +116,6 @@ pub fn dump_metric_samples(handle: PrometheusHandle) -> Result<Vec<MetricSample>
             }
         })
         .collect::<Vec<_>>();
-
-    let proc_stats = dump_proc_stats().unwrap_or_default();
-    samples.extend(proc_stats);
     Ok(samples)
 }
 
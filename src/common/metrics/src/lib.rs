@@ -14,11 +14,16 @@
 
 #![allow(clippy::uninlined_format_args)]
 
+mod collector;
 pub mod counter;
 mod dump;
 mod recorder;
 mod reset;
 
+pub use collector::collect_metric_samples;
+pub use collector::derived_metric_samples;
+pub use collector::register_metric_collector;
+pub use collector::render_metrics;
 pub use dump::dump_metric_samples;
 pub use dump::HistogramCount;
 pub use dump::MetricSample;
@@ -21,6 +21,8 @@ use metrics::decrement_gauge;
 use metrics::gauge;
 use metrics::histogram;
 use metrics::increment_gauge;
+use metrics_exporter_prometheus::BuildError;
+use metrics_exporter_prometheus::Matcher;
 use metrics_exporter_prometheus::PrometheusBuilder;
 use metrics_exporter_prometheus::PrometheusHandle;
 use once_cell::sync::Lazy;
@@ -32,6 +34,23 @@ static PROMETHEUS_HANDLE: Lazy<Arc<RwLock<Option<PrometheusHandle>>>> =
 pub const LABEL_KEY_TENANT: &str = "tenant";
 pub const LABEL_KEY_CLUSTER: &str = "cluster_name";
 
+/// Histograms of the bytes of each query are named with this suffix.
+const METRIC_SUFFIX_BYTES_PER_QUERY: &str = "_bytes_per_query";
+/// Histograms of the ratios of each query are named with this suffix.
+const METRIC_SUFFIX_RATIO_PER_QUERY: &str = "_ratio_per_query";
+
+const BYTES_BUCKETS: [f64; 8] = [
+    1024.0,
+    16.0 * 1024.0,
+    256.0 * 1024.0,
+    4.0 * 1024.0 * 1024.0,
+    64.0 * 1024.0 * 1024.0,
+    1024.0 * 1024.0 * 1024.0,
+    16.0 * 1024.0 * 1024.0 * 1024.0,
+    256.0 * 1024.0 * 1024.0 * 1024.0,
+];
+const RATIO_BUCKETS: [f64; 7] = [0.0, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0];
+
 #[inline]
 pub fn label_histogram_with_val(
     name: &'static str,
@@ -107,7 +126,17 @@ pub fn init_default_metrics_recorder() {
 
 /// Init prometheus recorder.
 fn init_prometheus_recorder() {
-    let recorder = PrometheusBuilder::new().build_recorder();
+    let recorder = match prometheus_builder() {
+        Ok(builder) => builder,
+        Err(err) => {
+            warn!(
+                "Set buckets of prometheus histograms failed, cause: {}",
+                err
+            );
+            PrometheusBuilder::new()
+        }
+    }
+    .build_recorder();
     let mut h = PROMETHEUS_HANDLE.as_ref().write();
     *h = Some(recorder.handle());
     unsafe {
@@ -119,6 +148,20 @@ fn init_prometheus_recorder() {
     };
 }
 
+/// The histograms of each query are exported as histograms with buckets, the others are
+/// exported as summaries.
+fn prometheus_builder() -> Result<PrometheusBuilder, BuildError> {
+    PrometheusBuilder::new()
+        .set_buckets_for_metric(
+            Matcher::Suffix(METRIC_SUFFIX_BYTES_PER_QUERY.to_string()),
+            &BYTES_BUCKETS,
+        )?
+        .set_buckets_for_metric(
+            Matcher::Suffix(METRIC_SUFFIX_RATIO_PER_QUERY.to_string()),
+            &RATIO_BUCKETS,
+        )
+}
+
 pub fn try_handle() -> Option<PrometheusHandle> {
     PROMETHEUS_HANDLE.as_ref().read().clone()
 }
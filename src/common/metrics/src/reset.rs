@@ -16,12 +16,13 @@ use common_exception::Result;
 use metrics::gauge;
 use metrics_exporter_prometheus::PrometheusHandle;
 
-use crate::dump_metric_samples;
+use crate::dump::parse_metric_samples;
 use crate::MetricValue;
 
 /// Reset gauge metrics to 0.
 pub fn reset_metrics(handle: PrometheusHandle) -> Result<()> {
-    let samples = dump_metric_samples(handle)?;
+    // Only the recorded metrics, the metrics computed on demand are not gauges of the recorder.
+    let samples = parse_metric_samples(&handle.render())?;
     for sample in samples {
         if let MetricValue::Gauge(_) = sample.value {
             gauge!(sample.name, 0_f64);
@@ -16,7 +16,10 @@ use std::collections::HashMap;
 
 use common_metrics::dump_metric_samples;
 use common_metrics::init_default_metrics_recorder;
+use common_metrics::register_metric_collector;
+use common_metrics::render_metrics;
 use common_metrics::try_handle;
+use common_metrics::MetricSample;
 use common_metrics::MetricValue;
 
 #[tokio::test(flavor = "multi_thread")]
@@ -51,3 +54,45 @@ async fn test_dump_metric_samples() -> common_exception::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_derived_and_collected_metric_samples() -> common_exception::Result<()> {
+    init_default_metrics_recorder();
+    metrics::gauge!("cache_test_derived_access_count", 4.0);
+    metrics::gauge!("cache_test_derived_hit_count", 3.0);
+    metrics::gauge!("test_derived_pruning_before", 10.0);
+    metrics::gauge!("test_derived_pruning_after", 2.0);
+    register_metric_collector(|| {
+        vec![MetricSample {
+            name: "test_collected_queue_length".to_string(),
+            labels: HashMap::new(),
+            value: MetricValue::Gauge(7.0),
+        }]
+    });
+
+    let handle = crate::try_handle().unwrap();
+    let samples = dump_metric_samples(handle.clone())
+        .unwrap()
+        .into_iter()
+        .map(|s| (s.name.clone(), s))
+        .collect::<HashMap<_, _>>();
+    assert_eq!(
+        MetricValue::Gauge(0.75),
+        samples.get("cache_test_derived_hit_ratio").unwrap().value
+    );
+    assert_eq!(
+        MetricValue::Gauge(0.8),
+        samples.get("test_derived_pruning_ratio").unwrap().value
+    );
+    assert_eq!(
+        MetricValue::Gauge(7.0),
+        samples.get("test_collected_queue_length").unwrap().value
+    );
+
+    let text = render_metrics(&handle);
+    assert!(text.contains("cache_test_derived_hit_ratio 0.75"));
+    assert!(text.contains("# TYPE test_collected_queue_length gauge"));
+    assert!(text.contains("test_collected_queue_length 7"));
+
+    Ok(())
+}
@@ -35,11 +35,18 @@ impl ColumnNodes {
         let mut column_nodes = Vec::with_capacity(schema.fields.len());
 
         let leaf_column_ids = table_schema.map(|table_schema| table_schema.to_leaf_column_ids());
-        for field in &schema.fields {
+        for (i, field) in schema.fields.iter().enumerate() {
             let mut column_node = Self::traverse_fields_dfs(field, false, &mut leaf_id);
             if let Some(ref leaf_column_ids) = leaf_column_ids {
                 column_node.build_leaf_column_ids(leaf_column_ids);
             }
+            if let Some(table_field) = table_schema.and_then(|s| s.fields().get(i)) {
+                column_node.prev_column_ids = table_field
+                    .prev_versions()
+                    .iter()
+                    .map(|v| v.column_id)
+                    .collect();
+            }
             column_nodes.push(column_node);
         }
 
@@ -159,6 +166,8 @@ pub struct ColumnNode {
     // Optional children column for nested types.
     pub children: Option<Vec<ColumnNode>>,
     pub leaf_column_ids: Vec<ColumnId>,
+    // The column ids of the previous versions, if the data type of the column has been widened.
+    pub prev_column_ids: Vec<ColumnId>,
 }
 
 impl ColumnNode {
@@ -174,6 +183,7 @@ impl ColumnNode {
             leaf_indices,
             children,
             leaf_column_ids: vec![],
+            prev_column_ids: vec![],
         }
     }
 
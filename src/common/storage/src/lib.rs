@@ -57,6 +57,10 @@ pub use parquet2::read_parquet_schema_async;
 pub mod parquet_rs;
 pub use parquet_rs::read_parquet_schema_async_rs;
 
+mod secrets;
+pub use secrets::SecretsCredentialLoader;
+pub use secrets::SecretsProvider;
+
 mod stage;
 pub use stage::init_stage_operator;
 pub use stage::StageFileInfo;
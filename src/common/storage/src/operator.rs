@@ -19,6 +19,8 @@ use std::io::Result;
 use std::time::Duration;
 
 use anyhow::anyhow;
+use base64::engine::general_purpose;
+use base64::Engine;
 use common_base::base::GlobalInstance;
 use common_base::runtime::GlobalIORuntime;
 use common_base::runtime::TrySpawn;
@@ -51,6 +53,8 @@ use opendal::Builder;
 use opendal::Operator;
 
 use crate::runtime_layer::RuntimeLayer;
+use crate::SecretsCredentialLoader;
+use crate::SecretsProvider;
 use crate::StorageConfig;
 
 /// init_operator will init an opendal operator based on storage config.
@@ -263,6 +267,15 @@ fn init_s3_operator(cfg: &StorageS3Config) -> Result<impl Builder> {
         builder.allow_anonymous();
     }
 
+    // Load (and refresh) credentials from the secrets provider.
+    if !cfg.secrets_provider.is_empty() {
+        let provider = cfg.secrets_provider.parse::<SecretsProvider>()?;
+        builder.customed_credential_load(Box::new(SecretsCredentialLoader::new(provider)));
+    }
+
+    // Server side encryption.
+    init_s3_server_side_encryption(&mut builder, cfg)?;
+
     let http_builder = {
         let mut builder = reqwest::ClientBuilder::new();
 
@@ -289,6 +302,64 @@ fn init_s3_operator(cfg: &StorageS3Config) -> Result<impl Builder> {
     Ok(builder)
 }
 
+/// Sets the server side encryption headers sent with the objects written, and
+/// with all the requests to them for keys provided by customers (SSE-C).
+fn init_s3_server_side_encryption(builder: &mut services::S3, cfg: &StorageS3Config) -> Result<()> {
+    let kms_key_id = &cfg.server_side_encryption_aws_kms_key_id;
+    match cfg.server_side_encryption.as_str() {
+        "" if kms_key_id.is_empty() => {}
+        "AES256" if kms_key_id.is_empty() => {
+            builder.server_side_encryption_with_s3_key();
+        }
+        "aws:kms" if kms_key_id.is_empty() => {
+            builder.server_side_encryption_with_aws_managed_kms_key();
+        }
+        "aws:kms" => {
+            builder.server_side_encryption_with_customer_managed_kms_key(kms_key_id);
+        }
+        "" | "AES256" => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                anyhow!(
+                    "server_side_encryption_aws_kms_key_id requires server_side_encryption `aws:kms`"
+                ),
+            ));
+        }
+        v => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                anyhow!("invalid server_side_encryption {v}, must be `AES256` or `aws:kms`"),
+            ));
+        }
+    }
+
+    if !cfg.server_side_encryption_customer_key.is_empty() {
+        if !cfg.server_side_encryption.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                anyhow!(
+                    "server_side_encryption_customer_key can't be used with server_side_encryption"
+                ),
+            ));
+        }
+        let key = general_purpose::STANDARD
+            .decode(&cfg.server_side_encryption_customer_key)
+            .ok()
+            .filter(|key| key.len() == 32)
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    anyhow!(
+                        "server_side_encryption_customer_key must be a base64 encoded 256-bit key"
+                    ),
+                )
+            })?;
+        builder.server_side_encryption_with_customer_key("AES256", &key);
+    }
+
+    Ok(())
+}
+
 /// init_obs_operator will init a opendal obs operator with input obs config.
 fn init_obs_operator(cfg: &StorageObsConfig) -> Result<impl Builder> {
     let mut builder = services::Obs::default();
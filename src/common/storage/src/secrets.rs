@@ -0,0 +1,221 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Secrets providers that load storage credentials from outside of the storage params.
+//!
+//! Unlike static credentials, the credentials loaded by a provider are reloaded before
+//! they expire, so long-running queries keep working after an STS token is rotated.
+
+use std::env;
+use std::io::Error;
+use std::io::ErrorKind;
+use std::io::Result;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+use common_base::base::tokio;
+use reqsign::AwsCredential;
+use reqsign::AwsCredentialLoad;
+use reqwest::Client;
+use serde::Deserialize;
+
+/// Credentials without an expiration are reloaded at this interval, so that
+/// rotated credentials in env or files are picked up.
+const DEFAULT_REFRESH_INTERVAL_SECS: i64 = 10 * 60;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SecretsProvider {
+    /// Load from `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY` and `AWS_SESSION_TOKEN`.
+    Env,
+    /// Load from a json file in the format of the aws `credential_process` output.
+    File(PathBuf),
+    /// Load from a path of the Vault AWS secrets engine.
+    ///
+    /// The address and token of Vault are read from `VAULT_ADDR` and `VAULT_TOKEN`.
+    Vault(String),
+}
+
+impl SecretsProvider {
+    /// Whether the provider reads secrets with the identity of the local node, such
+    /// providers must not be used by untrusted users.
+    ///
+    /// Vault is local too: it is accessed with the token of the node, which may be granted
+    /// paths that the user creating the stage is not supposed to read.
+    pub fn is_local(&self) -> bool {
+        matches!(
+            self,
+            SecretsProvider::Env | SecretsProvider::File(_) | SecretsProvider::Vault(_)
+        )
+    }
+
+    pub async fn load(&self, client: &Client) -> anyhow::Result<Option<AwsCredential>> {
+        match self {
+            SecretsProvider::Env => load_from_env(),
+            SecretsProvider::File(path) => load_from_file(path).await,
+            SecretsProvider::Vault(path) => load_from_vault(client, path).await,
+        }
+    }
+}
+
+impl FromStr for SecretsProvider {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if s == "env" {
+            return Ok(SecretsProvider::Env);
+        }
+        if let Some(path) = s.strip_prefix("file://") {
+            if !path.is_empty() {
+                return Ok(SecretsProvider::File(PathBuf::from(path)));
+            }
+        }
+        if let Some(path) = s.strip_prefix("vault://") {
+            let path = path.trim_matches('/');
+            if !path.is_empty() {
+                return Ok(SecretsProvider::Vault(path.to_string()));
+            }
+        }
+        Err(Error::new(
+            ErrorKind::InvalidInput,
+            anyhow!(
+                "invalid secrets provider {s:?}, expect `env`, `file://<path>` or `vault://<path>`"
+            ),
+        ))
+    }
+}
+
+/// SecretsCredentialLoader loads aws credentials from a [`SecretsProvider`].
+///
+/// The signer caches the loaded credential and calls the loader again before
+/// `expires_in`, which is how STS tokens get refreshed in the middle of a query.
+pub struct SecretsCredentialLoader {
+    provider: SecretsProvider,
+}
+
+impl SecretsCredentialLoader {
+    pub fn new(provider: SecretsProvider) -> Self {
+        Self { provider }
+    }
+}
+
+#[async_trait]
+impl AwsCredentialLoad for SecretsCredentialLoader {
+    async fn load_credential(&self, client: Client) -> anyhow::Result<Option<AwsCredential>> {
+        let cred = self.provider.load(&client).await?;
+        Ok(cred.map(|mut cred| {
+            if cred.expires_in.is_none() {
+                cred.expires_in =
+                    Some(Utc::now() + Duration::seconds(DEFAULT_REFRESH_INTERVAL_SECS));
+            }
+            cred
+        }))
+    }
+}
+
+fn load_from_env() -> anyhow::Result<Option<AwsCredential>> {
+    let (access_key_id, secret_access_key) = match (
+        env::var("AWS_ACCESS_KEY_ID"),
+        env::var("AWS_SECRET_ACCESS_KEY"),
+    ) {
+        (Ok(id), Ok(key)) => (id, key),
+        _ => return Ok(None),
+    };
+
+    Ok(Some(AwsCredential {
+        access_key_id,
+        secret_access_key,
+        session_token: env::var("AWS_SESSION_TOKEN").ok(),
+        expires_in: None,
+    }))
+}
+
+/// The output format of aws `credential_process`.
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct CredentialProcessOutput {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: Option<String>,
+    expiration: Option<DateTime<Utc>>,
+}
+
+async fn load_from_file(path: &PathBuf) -> anyhow::Result<Option<AwsCredential>> {
+    let content = tokio::fs::read(path).await.map_err(|err| {
+        anyhow!(
+            "read credentials file {} failed: {err:?}",
+            path.to_string_lossy()
+        )
+    })?;
+    let output: CredentialProcessOutput = serde_json::from_slice(&content).map_err(|err| {
+        anyhow!(
+            "parse credentials file {} failed: {err:?}",
+            path.to_string_lossy()
+        )
+    })?;
+
+    Ok(Some(AwsCredential {
+        access_key_id: output.access_key_id,
+        secret_access_key: output.secret_access_key,
+        session_token: output.session_token,
+        expires_in: output.expiration,
+    }))
+}
+
+#[derive(Deserialize)]
+struct VaultResponse {
+    lease_duration: i64,
+    data: VaultAwsCredential,
+}
+
+#[derive(Deserialize)]
+struct VaultAwsCredential {
+    access_key: String,
+    secret_key: String,
+    security_token: Option<String>,
+}
+
+async fn load_from_vault(client: &Client, path: &str) -> anyhow::Result<Option<AwsCredential>> {
+    let addr = env::var("VAULT_ADDR").map_err(|_| anyhow!("VAULT_ADDR is not set"))?;
+    let token = env::var("VAULT_TOKEN").map_err(|_| anyhow!("VAULT_TOKEN is not set"))?;
+
+    let url = format!("{}/v1/{}", addr.trim_end_matches('/'), path);
+    let resp = client
+        .get(&url)
+        .header("X-Vault-Token", token)
+        .send()
+        .await?;
+    if !resp.status().is_success() {
+        return Err(anyhow!(
+            "load credentials from vault path {path} failed: {}",
+            resp.status()
+        ));
+    }
+    let resp: VaultResponse = resp.json().await?;
+
+    Ok(Some(AwsCredential {
+        access_key_id: resp.data.access_key,
+        secret_access_key: resp.data.secret_key,
+        session_token: resp.data.security_token,
+        expires_in: if resp.lease_duration > 0 {
+            Some(Utc::now() + Duration::seconds(resp.lease_duration))
+        } else {
+            None
+        },
+    }))
+}
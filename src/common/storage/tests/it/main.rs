@@ -13,3 +13,5 @@
 // limitations under the License.
 
 mod column_node;
+mod operator;
+mod secrets;
@@ -0,0 +1,56 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_app::storage::StorageParams;
+use common_meta_app::storage::StorageS3Config;
+use common_storage::init_operator;
+
+#[test]
+fn test_init_s3_operator_with_invalid_server_side_encryption() {
+    let invalid_configs = [
+        // unknown encryption
+        StorageS3Config {
+            server_side_encryption: "aws:kms:dsse".to_string(),
+            ..Default::default()
+        },
+        // kms key without kms encryption
+        StorageS3Config {
+            server_side_encryption: "AES256".to_string(),
+            server_side_encryption_aws_kms_key_id: "key_id".to_string(),
+            ..Default::default()
+        },
+        // customer key with another encryption
+        StorageS3Config {
+            server_side_encryption: "aws:kms".to_string(),
+            server_side_encryption_customer_key: "MDEyMzQ1Njc4OWFiY2RlZjAxMjM0NTY3ODlhYmNkZWY="
+                .to_string(),
+            ..Default::default()
+        },
+        // customer key of 128 bits
+        StorageS3Config {
+            server_side_encryption_customer_key: "MDEyMzQ1Njc4OWFiY2RlZg==".to_string(),
+            ..Default::default()
+        },
+        // customer key not in base64
+        StorageS3Config {
+            server_side_encryption_customer_key: "not base64".to_string(),
+            ..Default::default()
+        },
+    ];
+
+    for cfg in invalid_configs {
+        let err = init_operator(&StorageParams::S3(cfg.clone())).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput, "{cfg:?}");
+    }
+}
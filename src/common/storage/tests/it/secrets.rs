@@ -0,0 +1,46 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::PathBuf;
+
+use common_storage::SecretsProvider;
+
+#[test]
+fn test_parse_secrets_provider() {
+    assert_eq!(
+        "env".parse::<SecretsProvider>().unwrap(),
+        SecretsProvider::Env
+    );
+    assert_eq!(
+        "file:///etc/databend/credentials.json"
+            .parse::<SecretsProvider>()
+            .unwrap(),
+        SecretsProvider::File(PathBuf::from("/etc/databend/credentials.json"))
+    );
+    assert_eq!(
+        "vault://aws/creds/reader/"
+            .parse::<SecretsProvider>()
+            .unwrap(),
+        SecretsProvider::Vault("aws/creds/reader".to_string())
+    );
+    assert!("file://".parse::<SecretsProvider>().is_err());
+    assert!("s3://bucket".parse::<SecretsProvider>().is_err());
+}
+
+#[test]
+fn test_secrets_provider_is_local() {
+    assert!(SecretsProvider::Env.is_local());
+    assert!(SecretsProvider::File(PathBuf::from("/tmp/credentials.json")).is_local());
+    assert!(!SecretsProvider::Vault("aws/creds/reader".to_string()).is_local());
+}
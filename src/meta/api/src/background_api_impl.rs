@@ -43,10 +43,10 @@ use common_meta_kvapi::kvapi::Key;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::ConditionResult::Eq;
 use common_meta_types::InvalidReply;
-use common_meta_types::KVMeta;
 use common_meta_types::MatchSeq;
 use common_meta_types::MatchSeq::Any;
 use common_meta_types::MetaError;
+use common_meta_types::MetaSpec;
 use common_meta_types::Operation;
 use common_meta_types::TxnRequest;
 use common_tracing::func_name;
@@ -236,9 +236,7 @@ impl<KV: kvapi::KVApi<Error = MetaError>> BackgroundApi for KV {
                 name_key.to_string_key().as_str(),
                 Any,
                 Operation::Update(serialize_struct(&meta)?),
-                Some(KVMeta {
-                    expire_at: Some(req.expire_at),
-                }),
+                Some(MetaSpec::new_expire(req.expire_at)),
             ))
             .await?;
         // confirm a successful update
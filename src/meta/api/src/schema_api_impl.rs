@@ -17,6 +17,7 @@ use std::fmt::Display;
 use std::sync::Arc;
 
 use chrono::DateTime;
+use chrono::TimeZone;
 use chrono::Utc;
 use common_meta_app::app_error::AppError;
 use common_meta_app::app_error::CatalogAlreadyExists;
@@ -114,6 +115,7 @@ use common_meta_app::schema::ListDroppedTableResp;
 use common_meta_app::schema::ListIndexesByIdReq;
 use common_meta_app::schema::ListIndexesReq;
 use common_meta_app::schema::ListTableLockRevReq;
+use common_meta_app::schema::ListTableMetaHistoryReq;
 use common_meta_app::schema::ListTableReq;
 use common_meta_app::schema::ListVirtualColumnsReq;
 use common_meta_app::schema::RenameDatabaseReply;
@@ -134,6 +136,7 @@ use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableInfoFilter;
 use common_meta_app::schema::TableLockKey;
 use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableMetaHistoryKey;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::TruncateTableReply;
 use common_meta_app::schema::TruncateTableReq;
@@ -141,8 +144,11 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateDatabaseCommentReply;
+use common_meta_app::schema::UpdateDatabaseCommentReq;
 use common_meta_app::schema::UpdateIndexReply;
 use common_meta_app::schema::UpdateIndexReq;
+use common_meta_app::schema::UpdateStreamMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReply;
@@ -747,6 +753,57 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         )))
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn update_database_comment(
+        &self,
+        req: UpdateDatabaseCommentReq,
+    ) -> Result<UpdateDatabaseCommentReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        let name_key = &req.name_ident;
+
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let (_, db_id, db_meta_seq, mut db_meta) =
+                get_db_or_err(self, name_key, "update_database_comment").await?;
+
+            let id_key = DatabaseId { db_id };
+            db_meta.comment = req.comment.clone();
+            db_meta.updated_on = Utc::now();
+
+            let txn_req = TxnRequest {
+                condition: vec![
+                    // db meta is not changed
+                    txn_cond_seq(&id_key, Eq, db_meta_seq),
+                ],
+                if_then: vec![
+                    txn_op_put(&id_key, serialize_struct(&db_meta)?), // (db_id) -> db_meta
+                ],
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                name = as_debug!(name_key),
+                id = as_debug!(&id_key),
+                succ = succ;
+                "update_database_comment"
+            );
+
+            if succ {
+                return Ok(UpdateDatabaseCommentReply {});
+            }
+        }
+
+        Err(KVAppError::AppError(AppError::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("update_database_comment", TXN_MAX_RETRY_TIMES),
+        )))
+    }
+
     #[logcall::logcall("debug")]
     #[minitrace::trace]
     async fn get_database(&self, req: GetDatabaseReq) -> Result<Arc<DatabaseInfo>, KVAppError> {
@@ -780,8 +837,8 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         // List tables by tenant, db_id, table_name.
         let dbid_tbname_idlist = DbIdListKey {
             tenant: req.tenant,
-            // Using a empty db to to list all
-            db_name: "".to_string(),
+            // Using a empty db to to list all, or a prefix to list the dbs starting with it
+            db_name: req.db_name_prefix.unwrap_or_default(),
         };
         let db_id_list_keys = list_keys(self, &dbid_tbname_idlist).await?;
 
@@ -865,8 +922,8 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
 
         let name_key = DatabaseNameIdent {
             tenant: req.tenant,
-            // Using a empty db to to list all
-            db_name: "".to_string(),
+            // Using a empty db to to list all, or a prefix to list the dbs starting with it
+            db_name: req.db_name_prefix.unwrap_or_default(),
         };
 
         // Pairs of db-name and db_id with seq
@@ -1563,6 +1620,7 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
             let table_id = fetch_id(self, IdGenerator::table_id()).await?;
 
             let tbid = TableId { table_id };
+            let tb_history_key = TableMetaHistoryKey::new(table_id, &Utc::now());
 
             // get table id name
             let table_id_to_name_key = TableIdToName { table_id };
@@ -1603,6 +1661,7 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                         txn_op_put(&dbid_tbname_idlist, serialize_struct(&tb_id_list)?), /* _fd_table_id_list/db_id/table_name -> tb_id_list */
                         txn_op_put(&tb_count_key, serialize_u64(tb_count + 1)?), /* _fd_table_count/tenant -> tb_count */
                         txn_op_put(&table_id_to_name_key, serialize_struct(&db_id_table_name)?), /* __fd_table_id_to_name/db_id/table_name -> DBIdTableName */
+                        txn_op_put(&tb_history_key, serialize_struct(&req.table_meta)?), /* __fd_table_meta_history/table_id/timestamp -> tb_meta */
                     ],
                     else_then: vec![],
                 };
@@ -2244,8 +2303,17 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         };
 
         let tb_infos = match db_meta.from_share {
-            None => list_tables_from_unshare_db(self, db_id, tenant_dbname).await?,
-            Some(share) => list_tables_from_share_db(self, share, tenant_dbname).await?,
+            None => {
+                let prefix = req.table_name_prefix.clone().unwrap_or_default();
+                list_tables_from_unshare_db(self, db_id, tenant_dbname, prefix).await?
+            }
+            Some(share) => {
+                let mut tb_infos = list_tables_from_share_db(self, share, tenant_dbname).await?;
+                if let Some(prefix) = &req.table_name_prefix {
+                    tb_infos.retain(|tb_info| tb_info.name.starts_with(prefix.as_str()));
+                }
+                tb_infos
+            }
         };
 
         Ok(tb_infos)
@@ -2784,6 +2852,30 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                     .push(build_upsert_table_deduplicated_label(deduplicated_label))
             }
 
+            // keep a version of the table meta whenever the schema changes
+            if table_meta.as_ref().map(|m| &m.schema) != Some(&req.new_table_meta.schema) {
+                let tb_history_key = TableMetaHistoryKey::new(req.table_id, &Utc::now());
+                txn_req.if_then.push(txn_op_put(
+                    &tb_history_key,
+                    serialize_struct(&req.new_table_meta)?,
+                ));
+            }
+
+            // advance the offsets of the consumed streams in the same txn
+            let stream_seqs = get_stream_seqs_or_err(self, &req.update_stream_meta).await?;
+            for (stream_req, stream_seq) in req.update_stream_meta.iter().zip(stream_seqs) {
+                let stream_id = TableId {
+                    table_id: stream_req.stream_id,
+                };
+                txn_req
+                    .condition
+                    .push(txn_cond_seq(&stream_id, Eq, stream_seq));
+                txn_req.if_then.push(txn_op_put(
+                    &stream_id,
+                    serialize_struct(&stream_req.new_stream_meta)?,
+                ));
+            }
+
             let (succ, responses) = send_txn(self, txn_req).await?;
 
             debug!(
@@ -2813,6 +2905,8 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
 
                     // check table version
                     if req_seq.match_seq(tb_meta_seq).is_ok() {
+                        // the txn may fail because of a consumed stream has been changed
+                        get_stream_seqs_or_err(self, &req.update_stream_meta).await?;
                         // if table version does match, but tx failed,
                         if fail_if_duplicated {
                             // report file duplication error
@@ -2962,6 +3056,7 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                     tenant: req.inner.tenant.clone(),
                     // need to get all db(include drop db)
                     filter: Some(DatabaseInfoFilter::IncludeDropped),
+                    db_name_prefix: None,
                 })
                 .await?;
 
@@ -3164,6 +3259,33 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         Ok(revisions)
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn list_table_meta_history(
+        &self,
+        req: ListTableMetaHistoryReq,
+    ) -> Result<Vec<(DateTime<Utc>, TableMeta)>, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        let prefix = format!("{}/{}/", TableMetaHistoryKey::PREFIX, req.table_id);
+        let reply = self.prefix_list_kv(&prefix).await?;
+
+        let mut history = Vec::with_capacity(reply.len());
+        for (k, v) in reply.into_iter() {
+            let history_key = TableMetaHistoryKey::from_str_key(&k).map_err(|e| {
+                let inv = InvalidReply::new("list_table_meta_history", &e);
+                let meta_net_err = MetaNetworkError::InvalidReply(inv);
+                MetaError::NetworkError(meta_net_err)
+            })?;
+
+            let updated_on = Utc.timestamp_nanos(history_key.timestamp as i64 * 1000);
+            let table_meta: TableMeta = deserialize_struct(&v.data)?;
+            history.push((updated_on, table_meta));
+        }
+        history.sort_by_key(|(updated_on, _)| *updated_on);
+        Ok(history)
+    }
+
     async fn create_table_lock_rev(
         &self,
         req: CreateTableLockRevReq,
@@ -3707,6 +3829,7 @@ async fn count_tables(
         .list_databases(ListDatabaseReq {
             tenant: key.tenant.clone(),
             filter: None,
+            db_name_prefix: None,
         })
         .await?;
     let mut count = 0;
@@ -3721,6 +3844,39 @@ async fn count_tables(
     Ok(count)
 }
 
+/// Returns the current seqs of the streams to update, or an error if any of them
+/// has been dropped or changed since it is read.
+async fn get_stream_seqs_or_err(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    reqs: &[UpdateStreamMetaReq],
+) -> Result<Vec<u64>, KVAppError> {
+    let mut seqs = Vec::with_capacity(reqs.len());
+    for req in reqs {
+        let stream_id = TableId {
+            table_id: req.stream_id,
+        };
+        let (stream_seq, stream_meta): (_, Option<TableMeta>) =
+            get_pb_value(kv_api, &stream_id).await?;
+        if stream_seq == 0 || stream_meta.is_none() {
+            return Err(KVAppError::AppError(AppError::UnknownTableId(
+                UnknownTableId::new(req.stream_id, "update_stream_meta"),
+            )));
+        }
+        if req.seq.match_seq(stream_seq).is_err() {
+            return Err(KVAppError::AppError(AppError::from(
+                TableVersionMismatched::new(
+                    req.stream_id,
+                    req.seq,
+                    stream_seq,
+                    "update_stream_meta",
+                ),
+            )));
+        }
+        seqs.push(stream_seq);
+    }
+    Ok(seqs)
+}
+
 async fn get_share_table_info_map(
     kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
     table_meta: &TableMeta,
@@ -3857,6 +4013,7 @@ fn build_upsert_table_deduplicated_label(deduplicated_label: String) -> TxnOp {
             value: 1_i8.to_le_bytes().to_vec(),
             prev_value: false,
             expire_at,
+            ttl_ms: None,
         })),
     }
 }
@@ -4204,6 +4361,28 @@ async fn gc_dropped_table_data(
     if_then.push(txn_op_del(&tbid));
 
     remove_table_copied_files(kv_api, table_id, condition, if_then).await?;
+    remove_table_meta_history(kv_api, table_id, if_then).await?;
+
+    Ok(())
+}
+
+/// Remove all the versions of the table meta of a dropped table.
+async fn remove_table_meta_history(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    table_id: u64,
+    if_then: &mut Vec<TxnOp>,
+) -> Result<(), KVAppError> {
+    let prefix = format!("{}/{}/", TableMetaHistoryKey::PREFIX, table_id);
+    let reply = kv_api.prefix_list_kv(&prefix).await?;
+
+    for (k, _) in reply.into_iter() {
+        let history_key = TableMetaHistoryKey::from_str_key(&k).map_err(|e| {
+            let inv = InvalidReply::new("remove_table_meta_history", &e);
+            let meta_net_err = MetaNetworkError::InvalidReply(inv);
+            MetaError::NetworkError(meta_net_err)
+        })?;
+        if_then.push(txn_op_del(&history_key));
+    }
 
     Ok(())
 }
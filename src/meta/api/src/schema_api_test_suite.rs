@@ -74,6 +74,7 @@ use common_meta_app::schema::ListDroppedTableReq;
 use common_meta_app::schema::ListIndexesByIdReq;
 use common_meta_app::schema::ListIndexesReq;
 use common_meta_app::schema::ListTableLockRevReq;
+use common_meta_app::schema::ListTableMetaHistoryReq;
 use common_meta_app::schema::ListTableReq;
 use common_meta_app::schema::ListVirtualColumnsReq;
 use common_meta_app::schema::RenameDatabaseReq;
@@ -95,6 +96,7 @@ use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateStreamMetaReq;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
@@ -306,6 +308,7 @@ impl SchemaApiTestSuite {
             .await?;
         suite.index_create_list_drop(&b.build().await).await?;
         suite.table_lock_revision(&b.build().await).await?;
+        suite.table_meta_history(&b.build().await).await?;
         suite
             .virtual_column_create_list_drop(&b.build().await)
             .await?;
@@ -921,6 +924,7 @@ impl SchemaApiTestSuite {
                 .list_databases(ListDatabaseReq {
                     tenant: tenant.to_string(),
                     filter: None,
+                    db_name_prefix: None,
                 })
                 .await?;
 
@@ -966,18 +970,33 @@ impl SchemaApiTestSuite {
                 .list_databases(ListDatabaseReq {
                     tenant: tenant1.to_string(),
                     filter: None,
+                    db_name_prefix: None,
                 })
                 .await?;
             let got = dbs.iter().map(|x| x.ident.db_id).collect::<Vec<_>>();
             assert_eq!(db_ids, got)
         }
 
+        info!("--- get_databases by tenant1 with a name prefix");
+        {
+            let dbs = mt
+                .list_databases(ListDatabaseReq {
+                    tenant: tenant1.to_string(),
+                    filter: None,
+                    db_name_prefix: Some("db2".to_string()),
+                })
+                .await?;
+            let got = dbs.iter().map(|x| x.ident.db_id).collect::<Vec<_>>();
+            assert_eq!(vec![db_ids[1]], got)
+        }
+
         info!("--- get_databases by tenant2");
         {
             let dbs = mt
                 .list_databases(ListDatabaseReq {
                     tenant: tenant2.to_string(),
                     filter: None,
+                    db_name_prefix: None,
                 })
                 .await?;
             let want: Vec<u64> = vec![db_id_3];
@@ -1141,6 +1160,7 @@ impl SchemaApiTestSuite {
                 .get_database_history(ListDatabaseReq {
                     tenant: tenant.to_string(),
                     filter: None,
+                    db_name_prefix: None,
                 })
                 .await?;
             calc_and_compare_drop_on_db_result(res, vec![DroponInfo {
@@ -1160,6 +1180,7 @@ impl SchemaApiTestSuite {
                 .get_database_history(ListDatabaseReq {
                     tenant: tenant.to_string(),
                     filter: None,
+                    db_name_prefix: None,
                 })
                 .await?;
             calc_and_compare_drop_on_db_result(res, vec![DroponInfo {
@@ -1178,6 +1199,7 @@ impl SchemaApiTestSuite {
                 .get_database_history(ListDatabaseReq {
                     tenant: tenant.to_string(),
                     filter: None,
+                    db_name_prefix: None,
                 })
                 .await?;
             calc_and_compare_drop_on_db_result(res, vec![DroponInfo {
@@ -1200,6 +1222,7 @@ impl SchemaApiTestSuite {
                 .get_database_history(ListDatabaseReq {
                     tenant: tenant.to_string(),
                     filter: None,
+                    db_name_prefix: None,
                 })
                 .await?;
             calc_and_compare_drop_on_db_result(res, vec![DroponInfo {
@@ -1225,6 +1248,7 @@ impl SchemaApiTestSuite {
                 .get_database_history(ListDatabaseReq {
                     tenant: tenant.to_string(),
                     filter: None,
+                    db_name_prefix: None,
                 })
                 .await?;
             calc_and_compare_drop_on_db_result(res, vec![DroponInfo {
@@ -1253,6 +1277,7 @@ impl SchemaApiTestSuite {
                 .get_database_history(ListDatabaseReq {
                     tenant: tenant.to_string(),
                     filter: None,
+                    db_name_prefix: None,
                 })
                 .await?;
             calc_and_compare_drop_on_db_result(res, vec![
@@ -1280,6 +1305,7 @@ impl SchemaApiTestSuite {
                 .get_database_history(ListDatabaseReq {
                     tenant: tenant.to_string(),
                     filter: None,
+                    db_name_prefix: None,
                 })
                 .await?;
             calc_and_compare_drop_on_db_result(res, vec![
@@ -1311,6 +1337,7 @@ impl SchemaApiTestSuite {
                 .get_database_history(ListDatabaseReq {
                     tenant: tenant.to_string(),
                     filter: None,
+                    db_name_prefix: None,
                 })
                 .await?;
             calc_and_compare_drop_on_db_result(res, vec![
@@ -2029,6 +2056,7 @@ impl SchemaApiTestSuite {
                     new_table_meta: new_table_meta.clone(),
                     copied_files: None,
                     deduplicated_label: None,
+                    update_stream_meta: vec![],
                 })
                 .await?;
 
@@ -2050,6 +2078,7 @@ impl SchemaApiTestSuite {
                         new_table_meta: new_table_meta.clone(),
                         copied_files: None,
                         deduplicated_label: None,
+                        update_stream_meta: vec![],
                     })
                     .await;
 
@@ -2091,6 +2120,7 @@ impl SchemaApiTestSuite {
                     new_table_meta: new_table_meta.clone(),
                     copied_files: Some(upsert_source_table),
                     deduplicated_label: None,
+                    update_stream_meta: vec![],
                 })
                 .await?;
 
@@ -2130,6 +2160,7 @@ impl SchemaApiTestSuite {
                     new_table_meta: new_table_meta.clone(),
                     copied_files: Some(upsert_source_table),
                     deduplicated_label: None,
+                    update_stream_meta: vec![],
                 })
                 .await?;
 
@@ -2170,12 +2201,91 @@ impl SchemaApiTestSuite {
                         new_table_meta: new_table_meta.clone(),
                         copied_files: Some(upsert_source_table),
                         deduplicated_label: None,
+                        update_stream_meta: vec![],
                     })
                     .await;
                 let err = result.unwrap_err();
                 let err = ErrorCode::from(err);
                 assert_eq!(ErrorCode::DuplicatedUpsertFiles("").code(), err.code());
             }
+
+            info!("--- update table meta, with stream meta");
+            {
+                let stream_name = "tb2_stream";
+                let req = CreateTableReq {
+                    if_not_exists: false,
+                    name_ident: TableNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                        table_name: stream_name.to_string(),
+                    },
+                    table_meta: table_meta(Utc::now()),
+                };
+                mt.create_table(req).await?;
+
+                let table = mt.get_table((tenant, "db1", "tb2").into()).await.unwrap();
+                let stream = mt
+                    .get_table((tenant, "db1", stream_name).into())
+                    .await
+                    .unwrap();
+
+                let mut new_table_meta = table.meta.clone();
+                new_table_meta.statistics.data_bytes = 2;
+                let mut new_stream_meta = stream.meta.clone();
+                new_stream_meta
+                    .options
+                    .insert("offset".to_string(), "1".to_string());
+
+                mt.update_table_meta(UpdateTableMetaReq {
+                    table_id: table.ident.table_id,
+                    seq: MatchSeq::Exact(table.ident.seq),
+                    new_table_meta: new_table_meta.clone(),
+                    copied_files: None,
+                    deduplicated_label: None,
+                    update_stream_meta: vec![UpdateStreamMetaReq {
+                        stream_id: stream.ident.table_id,
+                        seq: MatchSeq::Exact(stream.ident.seq),
+                        new_stream_meta: new_stream_meta.clone(),
+                    }],
+                })
+                .await?;
+
+                let table = mt.get_table((tenant, "db1", "tb2").into()).await.unwrap();
+                assert_eq!(table.meta, new_table_meta);
+                let got = mt
+                    .get_table((tenant, "db1", stream_name).into())
+                    .await
+                    .unwrap();
+                assert_eq!(got.meta, new_stream_meta);
+
+                info!("--- stream version mismatch, table meta is not updated");
+                let mut stale_table_meta = table.meta.clone();
+                stale_table_meta.statistics.data_bytes = 3;
+                let mut stale_stream_meta = stream.meta.clone();
+                stale_stream_meta
+                    .options
+                    .insert("offset".to_string(), "2".to_string());
+
+                let res = mt
+                    .update_table_meta(UpdateTableMetaReq {
+                        table_id: table.ident.table_id,
+                        seq: MatchSeq::Exact(table.ident.seq),
+                        new_table_meta: stale_table_meta,
+                        copied_files: None,
+                        deduplicated_label: None,
+                        update_stream_meta: vec![UpdateStreamMetaReq {
+                            stream_id: stream.ident.table_id,
+                            seq: MatchSeq::Exact(stream.ident.seq),
+                            new_stream_meta: stale_stream_meta,
+                        }],
+                    })
+                    .await;
+                let err = ErrorCode::from(res.unwrap_err());
+                assert_eq!(ErrorCode::TABLE_VERSION_MISMATCHED, err.code());
+
+                let table = mt.get_table((tenant, "db1", "tb2").into()).await.unwrap();
+                assert_eq!(table.meta, new_table_meta);
+            }
         }
         Ok(())
     }
@@ -2694,6 +2804,7 @@ impl SchemaApiTestSuite {
                 .get_database_history(ListDatabaseReq {
                     tenant: tenant.to_string(),
                     filter: None,
+                    db_name_prefix: None,
                 })
                 .await?;
 
@@ -2713,6 +2824,7 @@ impl SchemaApiTestSuite {
                 .get_database_history(ListDatabaseReq {
                     tenant: tenant.to_string(),
                     filter: None,
+                    db_name_prefix: None,
                 })
                 .await?;
 
@@ -2992,6 +3104,7 @@ impl SchemaApiTestSuite {
                 new_table_meta: table_meta.clone(),
                 copied_files: Some(req),
                 deduplicated_label: None,
+                update_stream_meta: vec![],
             };
 
             let _ = mt.update_table_meta(req).await?;
@@ -3145,6 +3258,7 @@ impl SchemaApiTestSuite {
                 new_table_meta: create_table_meta.clone(),
                 copied_files: Some(req),
                 deduplicated_label: None,
+                update_stream_meta: vec![],
             };
 
             let _ = mt.update_table_meta(req).await?;
@@ -4267,6 +4381,7 @@ impl SchemaApiTestSuite {
                 new_table_meta: table_meta(created_on),
                 copied_files: Some(req),
                 deduplicated_label: None,
+                update_stream_meta: vec![],
             };
 
             let _ = mt.update_table_meta(req).await?;
@@ -4304,6 +4419,7 @@ impl SchemaApiTestSuite {
                 new_table_meta: table_meta(created_on),
                 copied_files: Some(req),
                 deduplicated_label: None,
+                update_stream_meta: vec![],
             };
 
             let _ = mt.update_table_meta(req).await?;
@@ -4602,6 +4718,22 @@ impl SchemaApiTestSuite {
                 assert_eq!(tb_ids[0], res[0].ident.table_id);
                 assert_eq!(tb_ids[1], res[1].ident.table_id);
             }
+
+            info!("--- get_tables with a name prefix");
+            {
+                let req = ListTableReq::new(tenant, db_name).with_table_name_prefix("tb2");
+                let res = mt.list_tables(req).await?;
+                assert_eq!(1, res.len());
+                assert_eq!(tb_ids[1], res[0].ident.table_id);
+
+                let req = ListTableReq::new(tenant, db_name).with_table_name_prefix("tb");
+                let res = mt.list_tables(req).await?;
+                assert_eq!(tb_ids.len(), res.len());
+
+                let req = ListTableReq::new(tenant, db_name).with_table_name_prefix("tc");
+                let res = mt.list_tables(req).await?;
+                assert!(res.is_empty());
+            }
         }
 
         Ok(())
@@ -5097,6 +5229,82 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    async fn table_meta_history<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let mut util = Util::new(mt, "tenant1", "db1", "tb1", "eng1");
+        let table_id;
+        let table_meta;
+
+        info!("--- prepare db and table");
+        {
+            util.create_db().await?;
+            let (tid, tb_meta) = util.create_table().await?;
+            table_id = tid;
+            table_meta = tb_meta;
+        }
+
+        info!("--- create table records the first version");
+        {
+            let req = ListTableMetaHistoryReq { table_id };
+            let history = mt.list_table_meta_history(req).await?;
+            assert_eq!(history.len(), 1);
+            assert_eq!(history[0].1.schema, table_meta.schema);
+        }
+
+        info!("--- update table meta without schema change");
+        {
+            let table = mt
+                .get_table((util.tenant().as_str(), "db1", "tb1").into())
+                .await?;
+            let mut new_table_meta = table.meta.clone();
+            new_table_meta.comment = "new comment".to_string();
+            mt.update_table_meta(UpdateTableMetaReq {
+                table_id,
+                seq: MatchSeq::Exact(table.ident.seq),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+                update_stream_meta: vec![],
+            })
+            .await?;
+
+            let req = ListTableMetaHistoryReq { table_id };
+            let history = mt.list_table_meta_history(req).await?;
+            assert_eq!(history.len(), 1);
+        }
+
+        info!("--- update table meta with schema change");
+        let new_schema = Arc::new(TableSchema::new(vec![
+            TableField::new("number", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("name", TableDataType::String),
+        ]));
+        {
+            let table = mt
+                .get_table((util.tenant().as_str(), "db1", "tb1").into())
+                .await?;
+            let mut new_table_meta = table.meta.clone();
+            new_table_meta.schema = new_schema.clone();
+            mt.update_table_meta(UpdateTableMetaReq {
+                table_id,
+                seq: MatchSeq::Exact(table.ident.seq),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+                update_stream_meta: vec![],
+            })
+            .await?;
+
+            let req = ListTableMetaHistoryReq { table_id };
+            let history = mt.list_table_meta_history(req).await?;
+            assert_eq!(history.len(), 2);
+            assert!(history[0].0 <= history[1].0);
+            assert_eq!(history[0].1.schema, table_meta.schema);
+            assert_eq!(history[1].1.schema, new_schema);
+        }
+
+        Ok(())
+    }
+
     // pub async fn share_create_get_drop<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
     //     let tenant1 = "tenant1";
     //     let share_name1 = "share1";
@@ -5373,6 +5581,7 @@ impl SchemaApiTestSuite {
                 .list_databases(ListDatabaseReq {
                     tenant: tenant.to_string(),
                     filter: None,
+                    db_name_prefix: None,
                 })
                 .await;
             debug!("get database list: {:?}", res);
@@ -5626,6 +5835,7 @@ impl SchemaApiTestSuite {
                 new_table_meta: table_meta(created_on),
                 copied_files: Some(req),
                 deduplicated_label: None,
+                update_stream_meta: vec![],
             };
 
             let _ = mt.update_table_meta(req).await?;
@@ -5672,6 +5882,7 @@ impl SchemaApiTestSuite {
                 new_table_meta: table_meta(created_on),
                 copied_files: Some(req),
                 deduplicated_label: None,
+                update_stream_meta: vec![],
             };
 
             let result = mt.update_table_meta(req).await;
@@ -5715,6 +5926,7 @@ impl SchemaApiTestSuite {
                 new_table_meta: table_meta(created_on),
                 copied_files: Some(req),
                 deduplicated_label: None,
+                update_stream_meta: vec![],
             };
 
             mt.update_table_meta(req).await?;
@@ -5868,6 +6080,7 @@ where MT: SchemaApi
             new_table_meta: self.table_meta(),
             copied_files: Some(req),
             deduplicated_label: None,
+            update_stream_meta: vec![],
         };
 
         self.mt.update_table_meta(req).await?;
@@ -331,6 +331,7 @@ pub fn txn_op_put(key: &impl kvapi::Key, value: Vec<u8>) -> TxnOp {
             value,
             prev_value: true,
             expire_at: None,
+            ttl_ms: None,
         })),
     }
 }
@@ -343,6 +344,7 @@ pub fn txn_op_put_with_expire(key: &impl kvapi::Key, value: Vec<u8>, expire_at:
             value,
             prev_value: true,
             expire_at: Some(expire_at),
+            ttl_ms: None,
         })),
     }
 }
@@ -848,13 +850,14 @@ pub async fn list_tables_from_unshare_db(
     kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
     db_id: u64,
     tenant_dbname: &DatabaseNameIdent,
+    table_name_prefix: String,
 ) -> Result<Vec<Arc<TableInfo>>, KVAppError> {
     // List tables by tenant, db_id, table_name.
 
     let dbid_tbname = DBIdTableName {
         db_id,
-        // Use empty name to scan all tables
-        table_name: "".to_string(),
+        // Use empty name to scan all tables, or a prefix to scan the tables starting with it
+        table_name: table_name_prefix,
     };
 
     let (dbid_tbnames, ids) = list_u64_value(kv_api, &dbid_tbname).await?;
@@ -1092,7 +1095,8 @@ pub async fn remove_table_from_share(
                     unreachable!();
                 }
             }
-            let all_tables = list_tables_from_unshare_db(kv_api, shared_db_id, &db_name).await?;
+            let all_tables =
+                list_tables_from_unshare_db(kv_api, shared_db_id, &db_name, "".to_string()).await?;
             let table_infos = BTreeMap::from_iter(
                 all_tables
                     .iter()
@@ -1150,7 +1154,8 @@ pub async fn get_share_table_info(
                     unreachable!();
                 }
             }
-            let all_tables = list_tables_from_unshare_db(kv_api, shared_db_id, &db_name).await?;
+            let all_tables =
+                list_tables_from_unshare_db(kv_api, shared_db_id, &db_name, "".to_string()).await?;
             let table_infos = BTreeMap::from_iter(
                 all_tables
                     .iter()
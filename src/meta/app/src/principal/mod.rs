@@ -16,8 +16,10 @@
 
 mod file_format;
 mod network_policy;
+mod pipe;
 mod principal_identity;
 mod role_info;
+mod sequence;
 mod user_auth;
 mod user_defined_file_format;
 mod user_defined_function;
@@ -31,14 +33,23 @@ mod user_stage;
 
 pub use file_format::*;
 pub use network_policy::NetworkPolicy;
+pub use pipe::KafkaSource;
+pub use pipe::PipeBatch;
+pub use pipe::PipeInfo;
+pub use pipe::PipeProgress;
+pub use pipe::PipeSource;
+pub use pipe::StageSource;
 pub use principal_identity::PrincipalIdentity;
 pub use role_info::RoleInfo;
 pub use role_info::RoleInfoSerdeError;
+pub use sequence::SequenceInfo;
 pub use user_auth::AuthInfo;
 pub use user_auth::AuthType;
 pub use user_auth::PasswordHashMethod;
 pub use user_defined_file_format::UserDefinedFileFormat;
+pub use user_defined_function::UDFServer;
 pub use user_defined_function::UserDefinedFunction;
+pub use user_defined_function::WasmUDF;
 pub use user_grant::GrantEntry;
 pub use user_grant::GrantObject;
 pub use user_grant::UserGrantSet;
@@ -0,0 +1,140 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::principal::FileFormatParams;
+use crate::principal::UserIdentity;
+
+/// A pipe continuously loads the messages of a Kafka topic, or the files dropped
+/// into a stage, into a table.
+///
+/// The messages are parsed with the file format of the pipe, each message is
+/// a record of the format.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PipeInfo {
+    pub name: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub source: PipeSource,
+    pub file_format_params: FileFormatParams,
+    /// Max number of messages, or files of a stage, loaded by one commit.
+    pub batch_size: u64,
+    /// The messages are loaded with the privileges of the user created the pipe.
+    pub owner: UserIdentity,
+    pub comment: String,
+    pub created_on: DateTime<Utc>,
+}
+
+/// The source of a pipe.
+///
+/// It's untagged, so the pipes saved before the stage source was added are still
+/// read as Kafka pipes.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum PipeSource {
+    Kafka(KafkaSource),
+    Stage(StageSource),
+}
+
+impl Display for PipeSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PipeSource::Kafka(source) => {
+                write!(f, "kafka://{}/{}", source.brokers.join(","), source.topic)
+            }
+            PipeSource::Stage(source) => {
+                write!(f, "@{}{}", source.stage, source.path)?;
+                if let Some(pattern) = &source.pattern {
+                    write!(f, " PATTERN = '{}'", pattern)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct KafkaSource {
+    /// `host:port` of the bootstrap brokers.
+    pub brokers: Vec<String>,
+    pub topic: String,
+}
+
+/// The stage is listed periodically, and the files newer than the high-water mark of the
+/// pipe are loaded by `COPY INTO`, which skips the files already loaded into the table.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct StageSource {
+    pub stage: String,
+    /// The path in the stage, starts with `/`.
+    pub path: String,
+    pub pattern: Option<String>,
+    /// The options of `FILE_FORMAT` to copy the files with.
+    pub file_format_options: BTreeMap<String, String>,
+    /// Seconds between two listings of the stage.
+    pub refresh_interval: u64,
+}
+
+/// The progress of a pipe, it's updated after each batch is committed.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct PipeProgress {
+    /// The offset of the next message to load, by partition.
+    pub offsets: BTreeMap<i32, i64>,
+    /// The batch being loaded.
+    ///
+    /// It's saved before the batch is committed to the table, so the same batch is
+    /// loaded again after failures, and deduplicated by the label of the batch if it
+    /// has been committed already.
+    pub pending_batch: Option<PipeBatch>,
+    /// Number of batches committed.
+    pub batch_count: u64,
+    /// Number of messages committed, the rows for a stage pipe.
+    pub message_count: u64,
+    /// Number of files committed by a stage pipe.
+    #[serde(default)]
+    pub file_count: u64,
+    /// The high-water mark of a stage pipe: the newest last modified time of the files
+    /// committed, the older files are not loaded again.
+    #[serde(default)]
+    pub last_modified: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub updated_on: Option<DateTime<Utc>>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PipeBatch {
+    pub id: u64,
+    /// The offsets the batch starts from, by partition.
+    pub start_offsets: BTreeMap<i32, i64>,
+    /// The offsets after the last message of the batch, by partition.
+    pub end_offsets: BTreeMap<i32, i64>,
+}
+
+impl PipeBatch {
+    pub fn message_count(&self) -> u64 {
+        self.end_offsets
+            .iter()
+            .map(|(partition, end)| {
+                let start = self.start_offsets.get(partition).copied().unwrap_or(*end);
+                (end - start).max(0) as u64
+            })
+            .sum()
+    }
+}
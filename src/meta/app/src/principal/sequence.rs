@@ -0,0 +1,44 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use chrono::DateTime;
+use chrono::Utc;
+
+/// A sequence generates unique numbers, e.g. surrogate keys, by `nextval`.
+///
+/// The numbers are allocated by ranges from the meta service, so the query nodes
+/// don't request the meta service for every number, see `SequenceApi`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SequenceInfo {
+    pub name: String,
+    pub start: i64,
+    /// The difference between two consecutive numbers, it's not 0.
+    pub increment: i64,
+    /// The next number to allocate.
+    pub next_value: i64,
+    pub comment: String,
+    pub created_on: DateTime<Utc>,
+}
+
+impl SequenceInfo {
+    pub fn new(name: &str, start: i64, increment: i64, comment: String) -> Self {
+        Self {
+            name: name.to_string(),
+            start,
+            increment,
+            next_value: start,
+            comment,
+            created_on: Utc::now(),
+        }
+    }
+}
@@ -16,6 +16,7 @@ use std::convert::TryFrom;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -27,6 +28,40 @@ pub struct UserDefinedFunction {
 
     pub description: String,
     pub definition: String,
+    /// Whether the definition is a query, the function is then called in `FROM` clauses.
+    pub returns_table: bool,
+    /// The module implementing a `LANGUAGE WASM` function, its definition is then the stage
+    /// location the module is read from.
+    pub wasm: Option<WasmUDF>,
+    /// The external server evaluating the function, its definition is then the address of
+    /// the server.
+    pub server: Option<UDFServer>,
+}
+
+/// A function implemented by a WASM module, see `TransformUdf` for the calling convention.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct WasmUDF {
+    /// The exported function of the module called on each block.
+    pub handler: String,
+    pub arg_types: Vec<DataType>,
+    pub return_type: DataType,
+    /// The SHA-256 of the module when the function was created, hex encoded. The module
+    /// itself stays on its stage, it must not be changed as long as the function exists.
+    pub code_hash: String,
+}
+
+/// A function evaluated by an external UDF server over Arrow Flight, see `UDFFlightClient`
+/// for the protocol.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct UDFServer {
+    pub address: String,
+    /// The name of the function on the server.
+    pub handler: String,
+    pub language: String,
+    pub arg_types: Vec<DataType>,
+    pub return_type: DataType,
+    /// Whether the function returns NULL without calling the server if an argument is NULL.
+    pub returns_null_on_null_input: bool,
 }
 
 impl UserDefinedFunction {
@@ -36,6 +71,9 @@ impl UserDefinedFunction {
             parameters,
             definition: definition.to_string(),
             description: description.to_string(),
+            returns_table: false,
+            wasm: None,
+            server: None,
         }
     }
 }
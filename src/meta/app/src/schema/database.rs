@@ -220,6 +220,25 @@ impl Display for RenameDatabaseReq {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct RenameDatabaseReply {}
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateDatabaseCommentReq {
+    pub name_ident: DatabaseNameIdent,
+    pub comment: String,
+}
+
+impl Display for UpdateDatabaseCommentReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "update_database_comment:{}/{}={}",
+            self.name_ident.tenant, self.name_ident.db_name, self.comment
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateDatabaseCommentReply {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct DropDatabaseReq {
     pub if_exists: bool,
@@ -302,6 +321,8 @@ pub enum DatabaseInfoFilter {
 pub struct ListDatabaseReq {
     pub tenant: String,
     pub filter: Option<DatabaseInfoFilter>,
+    /// Only list the databases whose name starts with it, listed as a key range in meta.
+    pub db_name_prefix: Option<String>,
 }
 
 mod kvapi_key_impl {
@@ -40,6 +40,8 @@ pub use database::RenameDatabaseReply;
 pub use database::RenameDatabaseReq;
 pub use database::UndropDatabaseReply;
 pub use database::UndropDatabaseReq;
+pub use database::UpdateDatabaseCommentReply;
+pub use database::UpdateDatabaseCommentReq;
 pub use index::*;
 pub use ownership::Ownership;
 pub use table::CountTablesKey;
@@ -65,6 +67,7 @@ pub use table::GetTableReq;
 pub use table::ListDroppedTableReq;
 pub use table::ListDroppedTableResp;
 pub use table::ListTableLockRevReq;
+pub use table::ListTableMetaHistoryReq;
 pub use table::ListTableReq;
 pub use table::RenameTableReply;
 pub use table::RenameTableReq;
@@ -83,12 +86,14 @@ pub use table::TableInfo;
 pub use table::TableInfoFilter;
 pub use table::TableLockKey;
 pub use table::TableMeta;
+pub use table::TableMetaHistoryKey;
 pub use table::TableNameIdent;
 pub use table::TableStatistics;
 pub use table::TruncateTableReply;
 pub use table::TruncateTableReq;
 pub use table::UndropTableReply;
 pub use table::UndropTableReq;
+pub use table::UpdateStreamMetaReq;
 pub use table::UpdateTableMetaReply;
 pub use table::UpdateTableMetaReq;
 pub use table::UpsertTableCopiedFileReply;
@@ -125,4 +130,5 @@ const PREFIX_INDEX: &str = "__fd_index";
 const PREFIX_INDEX_ID_TO_NAME: &str = "__fd_index_id_to_name";
 const PREFIX_INDEX_BY_ID: &str = "__fd_index_by_id";
 const PREFIX_TABLE_LOCK: &str = "__fd_table_lock";
+const PREFIX_TABLE_META_HISTORY: &str = "__fd_table_meta_history";
 const PREFIX_VIRTUAL_COLUMN: &str = "__fd_virtual_column";
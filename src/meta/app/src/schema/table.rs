@@ -219,6 +219,10 @@ pub struct TableMeta {
     pub catalog: String,
     pub engine: String,
     pub engine_options: BTreeMap<String, String>,
+    /// Storage params of the table, `None` means the global storage is used.
+    ///
+    /// For tables with a `storage_profile` option, the params are resolved from the
+    /// profile when loading the table and are not persisted.
     pub storage_params: Option<StorageParams>,
     pub part_prefix: String,
     pub options: BTreeMap<String, String>,
@@ -595,6 +599,16 @@ pub struct UpdateTableMetaReq {
     pub new_table_meta: TableMeta,
     pub copied_files: Option<UpsertTableCopiedFileReq>,
     pub deduplicated_label: Option<String>,
+    /// Streams consumed by the statement, their offsets are advanced in the same
+    /// transaction that updates the table meta.
+    pub update_stream_meta: Vec<UpdateStreamMetaReq>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateStreamMetaReq {
+    pub stream_id: u64,
+    pub seq: MatchSeq,
+    pub new_stream_meta: TableMeta,
 }
 
 impl UpsertTableOptionReq {
@@ -687,6 +701,8 @@ impl GetTableReq {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct ListTableReq {
     pub inner: DatabaseNameIdent,
+    /// Only list the tables whose name starts with it, listed as a key range in meta.
+    pub table_name_prefix: Option<String>,
 }
 
 impl Deref for ListTableReq {
@@ -704,8 +720,14 @@ impl ListTableReq {
                 tenant: tenant.into(),
                 db_name: db_name.into(),
             },
+            table_name_prefix: None,
         }
     }
+
+    pub fn with_table_name_prefix(mut self, prefix: impl Into<String>) -> ListTableReq {
+        self.table_name_prefix = Some(prefix.into());
+        self
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -839,6 +861,28 @@ pub struct TableLockKey {
     pub revision: u64,
 }
 
+/// A version of the table meta, keyed by the time it took effect.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TableMetaHistoryKey {
+    pub table_id: u64,
+    /// Unix timestamp in microseconds.
+    pub timestamp: u64,
+}
+
+impl TableMetaHistoryKey {
+    pub fn new(table_id: u64, updated_on: &DateTime<Utc>) -> Self {
+        Self {
+            table_id,
+            timestamp: updated_on.timestamp_micros() as u64,
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ListTableMetaHistoryReq {
+    pub table_id: u64,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct ListTableLockRevReq {
     pub table_id: u64,
@@ -879,6 +923,7 @@ mod kvapi_key_impl {
     use crate::schema::TableIdListKey;
     use crate::schema::TableIdToName;
     use crate::schema::TableLockKey;
+    use crate::schema::TableMetaHistoryKey;
     use crate::schema::PREFIX_TABLE;
     use crate::schema::PREFIX_TABLE_BY_ID;
     use crate::schema::PREFIX_TABLE_COPIED_FILES;
@@ -887,6 +932,7 @@ mod kvapi_key_impl {
     use crate::schema::PREFIX_TABLE_ID_LIST;
     use crate::schema::PREFIX_TABLE_ID_TO_NAME;
     use crate::schema::PREFIX_TABLE_LOCK;
+    use crate::schema::PREFIX_TABLE_META_HISTORY;
 
     /// "__fd_table/<db_id>/<tb_name>"
     impl kvapi::Key for DBIdTableName {
@@ -1036,6 +1082,31 @@ mod kvapi_key_impl {
         }
     }
 
+    /// __fd_table_meta_history/table_id/timestamp -> TableMeta
+    impl kvapi::Key for TableMetaHistoryKey {
+        const PREFIX: &'static str = PREFIX_TABLE_META_HISTORY;
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_u64(self.table_id)
+                .push_u64(self.timestamp)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let table_id = p.next_u64()?;
+            let timestamp = p.next_u64()?;
+            p.done()?;
+
+            Ok(TableMetaHistoryKey {
+                table_id,
+                timestamp,
+            })
+        }
+    }
+
     /// __fd_table_lock/table_id/revision -> ""
     impl kvapi::Key for TableLockKey {
         const PREFIX: &'static str = PREFIX_TABLE_LOCK;
@@ -1065,6 +1136,7 @@ mod tests {
     use common_meta_kvapi::kvapi::Key;
 
     use crate::schema::TableCopiedFileNameIdent;
+    use crate::schema::TableMetaHistoryKey;
 
     #[test]
     fn test_table_copied_file_name_ident_conversion() -> Result<(), kvapi::KeyError> {
@@ -1112,4 +1184,18 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_table_meta_history_key_conversion() -> Result<(), kvapi::KeyError> {
+        let k = TableMetaHistoryKey {
+            table_id: 2,
+            timestamp: 1687772942038483,
+        };
+
+        let key = k.to_string_key();
+        assert_eq!(key, "__fd_table_meta_history/2/1687772942038483");
+        assert_eq!(TableMetaHistoryKey::from_str_key(&key)?, k);
+
+        Ok(())
+    }
 }
@@ -13,5 +13,7 @@
 // limitations under the License.
 
 mod storage_params;
+mod storage_profile;
 
 pub use storage_params::*;
+pub use storage_profile::StorageProfile;
@@ -324,6 +324,23 @@ pub struct StorageS3Config {
     pub external_id: String,
     /// Allow anonymous access to S3 if credential not loaded.
     pub allow_anonymous: bool,
+    /// The secrets provider to load (and refresh) credentials from instead of
+    /// the static `access_key_id` and `secret_access_key`.
+    ///
+    /// - `env`: load from `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY` and `AWS_SESSION_TOKEN`.
+    /// - `file://<path>`: load from a json file which is re-read before the credentials expire.
+    /// - `vault://<path>`: load from a Vault AWS secrets engine path, e.g. `vault://aws/creds/role`.
+    ///
+    /// Credentials with an expiration (like STS tokens) are refreshed automatically.
+    pub secrets_provider: String,
+    /// The server side encryption of written objects, `AES256` (SSE-S3) or `aws:kms` (SSE-KMS).
+    pub server_side_encryption: String,
+    /// The KMS key used by SSE-KMS, the AWS managed key is used if empty.
+    pub server_side_encryption_aws_kms_key_id: String,
+    /// The base64 encoded AES256 key to encrypt objects with (SSE-C).
+    ///
+    /// The same key is required to read the objects back.
+    pub server_side_encryption_customer_key: String,
 }
 
 impl Default for StorageS3Config {
@@ -342,6 +359,10 @@ impl Default for StorageS3Config {
             role_arn: "".to_string(),
             external_id: "".to_string(),
             allow_anonymous: false,
+            secrets_provider: "".to_string(),
+            server_side_encryption: "".to_string(),
+            server_side_encryption_aws_kms_key_id: "".to_string(),
+            server_side_encryption_customer_key: "".to_string(),
         }
     }
 }
@@ -365,6 +386,16 @@ impl Debug for StorageS3Config {
             .field("security_token", &mask_string(&self.security_token, 3))
             .field("master_key", &mask_string(&self.master_key, 3))
             .field("allow_anonymous", &self.allow_anonymous)
+            .field("secrets_provider", &self.secrets_provider)
+            .field("server_side_encryption", &self.server_side_encryption)
+            .field(
+                "server_side_encryption_aws_kms_key_id",
+                &self.server_side_encryption_aws_kms_key_id,
+            )
+            .field(
+                "server_side_encryption_customer_key",
+                &mask_string(&self.server_side_encryption_customer_key, 3),
+            )
             .finish()
     }
 }
@@ -31,6 +31,10 @@ pub struct TenantQuota {
 
     // The max number of users can be created in the tenant.
     pub max_users: u32,
+
+    // The max bytes of compressed table data in the tenant, no more table or database can be
+    // created once it is reached.
+    pub max_data_size: u64,
 }
 
 impl TryFrom<Vec<u8>> for TenantQuota {
@@ -15,6 +15,8 @@
 use std::convert::TryInto;
 use std::fmt::Debug;
 
+use common_meta_kvapi::kvapi::BatchUpsertKVReply;
+use common_meta_kvapi::kvapi::BatchUpsertKVReq;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::GetKVReq;
 use common_meta_kvapi::kvapi::ListKVReply;
@@ -25,6 +27,7 @@ use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::protobuf::meta_service_client::MetaServiceClient;
 use common_meta_types::protobuf::ClientInfo;
+use common_meta_types::protobuf::KeySpaceStat;
 use common_meta_types::protobuf::RaftRequest;
 use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::protobuf::WatchResponse;
@@ -38,6 +41,7 @@ use crate::grpc_client::AuthInterceptor;
 use crate::message::ExportReq;
 use crate::message::GetClientInfo;
 use crate::message::GetEndpoints;
+use crate::message::GetKeySpaceStats;
 use crate::message::MakeClient;
 
 /// Bind a request type to its corresponding response type.
@@ -48,12 +52,23 @@ pub trait RequestFor {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, derive_more::From)]
 pub enum MetaGrpcReq {
     UpsertKV(UpsertKVReq),
+    BatchUpsertKV(BatchUpsertKVReq),
 
     GetKV(GetKVReq),
     MGetKV(MGetKVReq),
     ListKV(ListKVReq),
 }
 
+impl MetaGrpcReq {
+    /// Whether the request only reads, which is safe to be sent more than once.
+    pub fn is_read(&self) -> bool {
+        matches!(
+            self,
+            MetaGrpcReq::GetKV(_) | MetaGrpcReq::MGetKV(_) | MetaGrpcReq::ListKV(_)
+        )
+    }
+}
+
 impl TryInto<MetaGrpcReq> for Request<RaftRequest> {
     type Error = tonic::Status;
 
@@ -96,6 +111,10 @@ impl RequestFor for UpsertKVReq {
     type Reply = UpsertKVReply;
 }
 
+impl RequestFor for BatchUpsertKVReq {
+    type Reply = BatchUpsertKVReply;
+}
+
 impl RequestFor for WatchRequest {
     type Reply = tonic::codec::Streaming<WatchResponse>;
 }
@@ -119,3 +138,7 @@ impl RequestFor for TxnRequest {
 impl RequestFor for GetClientInfo {
     type Reply = ClientInfo;
 }
+
+impl RequestFor for GetKeySpaceStats {
+    type Reply = Vec<KeySpaceStat>;
+}
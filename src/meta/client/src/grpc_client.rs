@@ -15,6 +15,7 @@
 use std::fmt::Debug;
 use std::fmt::Display;
 use std::fmt::Formatter;
+use std::future::Future;
 use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
@@ -27,6 +28,7 @@ use common_base::base::tokio::sync::mpsc::Sender;
 use common_base::base::tokio::sync::oneshot;
 use common_base::base::tokio::sync::oneshot::Receiver as OneRecv;
 use common_base::base::tokio::sync::oneshot::Sender as OneSend;
+use common_base::base::tokio::sync::Semaphore;
 use common_base::base::tokio::time::sleep;
 use common_base::containers::ItemManager;
 use common_base::containers::Pool;
@@ -46,6 +48,7 @@ use common_meta_types::protobuf::ClientInfo;
 use common_meta_types::protobuf::Empty;
 use common_meta_types::protobuf::ExportedChunk;
 use common_meta_types::protobuf::HandshakeRequest;
+use common_meta_types::protobuf::KeySpaceStat;
 use common_meta_types::protobuf::MemberListReply;
 use common_meta_types::protobuf::MemberListRequest;
 use common_meta_types::protobuf::RaftReply;
@@ -72,12 +75,14 @@ use log::debug;
 use log::error;
 use log::info;
 use log::warn;
+use minitrace::prelude::*;
 use parking_lot::Mutex;
 use prost::Message;
 use semver::Version;
 use serde::de::DeserializeOwned;
 use tonic::async_trait;
 use tonic::client::GrpcService;
+use tonic::codec::CompressionEncoding;
 use tonic::codegen::InterceptedService;
 use tonic::metadata::MetadataValue;
 use tonic::service::Interceptor;
@@ -104,6 +109,22 @@ const LABEL_ENDPOINT: &str = "endpoint";
 const LABEL_REQUEST: &str = "request";
 const LABEL_ERROR: &str = "error";
 
+/// Max times to send a read request, which is retried on other nodes if a node fails.
+const MAX_READ_ATTEMPTS: u32 = 5;
+
+/// Max times to send a write request.
+///
+/// A write is retried at most once, because it may have been applied when the RPC fails.
+const MAX_WRITE_ATTEMPTS: u32 = 2;
+
+const RETRY_BACKOFF_BASE: Duration = Duration::from_millis(100);
+const RETRY_BACKOFF_MAX: Duration = Duration::from_secs(2);
+
+type RealClient = MetaServiceClient<InterceptedService<Channel, AuthInterceptor>>;
+
+/// The max number of requests a meta-client worker sends to metasrv concurrently.
+const MAX_INFLIGHT_REQUESTS: usize = 64;
+
 #[derive(Debug)]
 struct MetaChannelManager {
     timeout: Option<Duration>,
@@ -179,6 +200,7 @@ impl ClientHandle {
             let req = message::ClientWorkerRequest {
                 resp_tx: tx,
                 req: req.into(),
+                span_context: SpanContext::current_local_parent(),
             };
 
             label_increment_gauge_with_val_and_labels(
@@ -236,6 +258,10 @@ impl ClientHandle {
         self.request(message::GetClientInfo {}).await
     }
 
+    pub async fn get_key_space_stats(&self) -> Result<Vec<KeySpaceStat>, MetaError> {
+        self.request(message::GetKeySpaceStats {}).await
+    }
+
     pub async fn make_client(
         &self,
     ) -> Result<MetaServiceClient<InterceptedService<Channel, AuthInterceptor>>, MetaClientError>
@@ -264,8 +290,12 @@ pub struct MetaGrpcClient {
     username: String,
     password: String,
     current_endpoint: Arc<Mutex<Option<String>>>,
+    /// The endpoint of the leader, it is connected first, to save a forwarding from a follower.
+    leader_endpoint: Mutex<Option<String>>,
     unhealthy_endpoints: Mutex<TtlHashMap<String, ()>>,
     auto_sync_interval: Option<Duration>,
+    /// Compress the requests sent to and the responses received from metasrv with gzip.
+    compression: bool,
 
     /// Dedicated runtime to support meta client background tasks.
     ///
@@ -273,7 +303,6 @@ pub struct MetaGrpcClient {
     /// If a background task is blocked, no meta-client will be able to proceed if meta-client is reused.
     ///
     /// Note that a thread_pool tokio runtime does not help: a scheduled tokio-task resides in `filo_slot` won't be stolen by other tokio-workers.
-    #[allow(dead_code)]
     rt: Arc<Runtime>,
 }
 
@@ -282,8 +311,10 @@ impl Debug for MetaGrpcClient {
         let mut de = f.debug_struct("MetaGrpcClient");
         de.field("endpoints", &self.endpoints);
         de.field("current_endpoints", &self.current_endpoint);
+        de.field("leader_endpoint", &self.leader_endpoint);
         de.field("unhealthy_endpoints", &self.unhealthy_endpoints);
         de.field("auto_sync_interval", &self.auto_sync_interval);
+        de.field("compression", &self.compression);
         de.finish()
     }
 }
@@ -299,31 +330,15 @@ impl MetaGrpcClient {
     ///
     /// The worker is a singleton and the returned handle is cheap to clone.
     /// When all handles are dropped the worker will quit, then the runtime will be destroyed.
-    pub fn try_new(conf: &RpcClientConf) -> Result<Arc<ClientHandle>, MetaClientError> {
-        Self::try_create(
-            conf.get_endpoints(),
-            &conf.username,
-            &conf.password,
-            conf.timeout,
-            conf.auto_sync_interval,
-            conf.unhealth_endpoint_evict_time,
-            conf.tls_conf.clone(),
-        )
-    }
-
     #[minitrace::trace]
-    pub fn try_create(
-        endpoints: Vec<String>,
-        username: &str,
-        password: &str,
-        timeout: Option<Duration>,
-        auto_sync_interval: Option<Duration>,
-        unhealth_endpoint_evict_time: Duration,
-        conf: Option<RpcClientTlsConfig>,
-    ) -> Result<Arc<ClientHandle>, MetaClientError> {
+    pub fn try_new(conf: &RpcClientConf) -> Result<Arc<ClientHandle>, MetaClientError> {
+        let endpoints = conf.get_endpoints();
         Self::endpoints_non_empty(&endpoints)?;
 
-        let mgr = MetaChannelManager { timeout, conf };
+        let mgr = MetaChannelManager {
+            timeout: conf.timeout,
+            conf: conf.tls_conf.clone(),
+        };
 
         let rt =
             Runtime::with_worker_threads(1, Some("meta-client-rt".to_string())).map_err(|e| {
@@ -347,10 +362,12 @@ impl MetaGrpcClient {
             conn_pool: Pool::new(mgr, Duration::from_millis(50)),
             endpoints: Mutex::new(endpoints),
             current_endpoint: Arc::new(Mutex::new(None)),
-            unhealthy_endpoints: Mutex::new(TtlHashMap::new(unhealth_endpoint_evict_time)),
-            auto_sync_interval,
-            username: username.to_string(),
-            password: password.to_string(),
+            leader_endpoint: Mutex::new(None),
+            unhealthy_endpoints: Mutex::new(TtlHashMap::new(conf.unhealth_endpoint_evict_time)),
+            auto_sync_interval: conf.auto_sync_interval,
+            compression: conf.compression,
+            username: conf.username.clone(),
+            password: conf.password.clone(),
             rt: rt.clone(),
         });
 
@@ -365,11 +382,39 @@ impl MetaGrpcClient {
         Ok(handle)
     }
 
+    #[minitrace::trace]
+    pub fn try_create(
+        endpoints: Vec<String>,
+        username: &str,
+        password: &str,
+        timeout: Option<Duration>,
+        auto_sync_interval: Option<Duration>,
+        unhealth_endpoint_evict_time: Duration,
+        conf: Option<RpcClientTlsConfig>,
+    ) -> Result<Arc<ClientHandle>, MetaClientError> {
+        Self::try_new(&RpcClientConf {
+            endpoints,
+            username: username.to_string(),
+            password: password.to_string(),
+            tls_conf: conf,
+            timeout,
+            auto_sync_interval,
+            unhealth_endpoint_evict_time,
+            compression: false,
+        })
+    }
+
     /// A worker runs a receiving-loop to accept user-request to metasrv and deals with request in the dedicated runtime.
+    ///
+    /// Requests are pipelined: every request is handled in its own task,
+    /// so that a slow RPC does not block the ones queued after it.
+    /// All of them share the same underlying HTTP/2 connection.
     #[minitrace::trace]
     async fn worker_loop(self: Arc<Self>, mut req_rx: Receiver<message::ClientWorkerRequest>) {
         info!("MetaGrpcClient::worker spawned");
 
+        let inflight = Arc::new(Semaphore::new(MAX_INFLIGHT_REQUESTS));
+
         loop {
             let t = req_rx.recv().await;
             let req = match t {
@@ -390,136 +435,165 @@ impl MetaGrpcClient {
                 continue;
             }
 
-            let resp_tx = req.resp_tx;
-            let req = req.req;
-            let req_name = req.name();
-            let req_str = format!("{:?}", req);
+            // Stop receiving when there are too many requests in flight,
+            // the callers are then blocked by the bounded request channel.
+            let permit = inflight
+                .clone()
+                .acquire_owned()
+                .await
+                .expect("inflight semaphore is never closed");
+
+            let span = match req.span_context {
+                Some(span_context) => Span::root(req.req.name(), span_context),
+                None => Span::noop(),
+            };
 
-            let start = Instant::now();
+            let worker = self.clone();
+            self.rt.spawn(UnlimitedFuture::create(async move {
+                worker.handle_request(req).in_span(span).await;
+                drop(permit);
+            }));
+        }
+    }
 
-            let resp = match req {
-                message::Request::Get(r) => {
-                    let resp = self
-                        .kv_api(r)
-                        .timed_ge(threshold(), info_spent("MetaGrpcClient::kv_api"))
-                        .await;
-                    message::Response::Get(resp)
-                }
-                message::Request::MGet(r) => {
-                    let resp = self
-                        .kv_api(r)
-                        .timed_ge(threshold(), info_spent("MetaGrpcClient::kv_api"))
-                        .await;
-                    message::Response::MGet(resp)
-                }
-                message::Request::PrefixList(r) => {
-                    let resp = self
-                        .kv_api(r)
-                        .timed_ge(threshold(), info_spent("MetaGrpcClient::kv_api"))
-                        .await;
-                    message::Response::PrefixList(resp)
-                }
-                message::Request::Upsert(r) => {
-                    let resp = self
-                        .kv_api(r)
-                        .timed_ge(threshold(), info_spent("MetaGrpcClient::kv_api"))
-                        .await;
-                    message::Response::Upsert(resp)
-                }
-                message::Request::Txn(r) => {
-                    let resp = self
-                        .transaction(r)
-                        .timed_ge(threshold(), info_spent("MetaGrpcClient::transaction"))
-                        .await;
-                    message::Response::Txn(resp)
-                }
-                message::Request::Watch(r) => {
-                    let resp = self.watch(r).await;
-                    message::Response::Watch(resp)
-                }
-                message::Request::Export(r) => {
-                    let resp = self.export(r).await;
-                    message::Response::Export(resp)
-                }
-                message::Request::MakeClient(_) => {
-                    let resp = self.make_client().await;
-                    message::Response::MakeClient(resp)
-                }
-                message::Request::GetEndpoints(_) => {
-                    let resp = self.get_cached_endpoints();
-                    message::Response::GetEndpoints(Ok(resp))
-                }
-                message::Request::GetClientInfo(_) => {
-                    let resp = self.get_client_info().await;
-                    message::Response::GetClientInfo(resp)
-                }
-            };
+    async fn handle_request(&self, req: message::ClientWorkerRequest) {
+        let resp_tx = req.resp_tx;
+        let req = req.req;
+        let req_name = req.name();
+        let req_str = format!("{:?}", req);
 
-            debug!(
-                resp = as_debug!(&resp);
-                "MetaGrpcClient send response to the handle"
-            );
+        let start = Instant::now();
 
-            let current_endpoint = {
-                let current_endpoint = self.current_endpoint.lock();
-                current_endpoint.clone()
-            };
+        let resp = match req {
+            message::Request::Get(r) => {
+                let resp = self
+                    .kv_api(r)
+                    .timed_ge(threshold(), info_spent("MetaGrpcClient::kv_api"))
+                    .await;
+                message::Response::Get(resp)
+            }
+            message::Request::MGet(r) => {
+                let resp = self
+                    .kv_api(r)
+                    .timed_ge(threshold(), info_spent("MetaGrpcClient::kv_api"))
+                    .await;
+                message::Response::MGet(resp)
+            }
+            message::Request::PrefixList(r) => {
+                let resp = self
+                    .kv_api(r)
+                    .timed_ge(threshold(), info_spent("MetaGrpcClient::kv_api"))
+                    .await;
+                message::Response::PrefixList(resp)
+            }
+            message::Request::Upsert(r) => {
+                let resp = self
+                    .kv_api(r)
+                    .timed_ge(threshold(), info_spent("MetaGrpcClient::kv_api"))
+                    .await;
+                message::Response::Upsert(resp)
+            }
+            message::Request::BatchUpsert(r) => {
+                let resp = self
+                    .kv_api(r)
+                    .timed_ge(threshold(), info_spent("MetaGrpcClient::kv_api"))
+                    .await;
+                message::Response::BatchUpsert(resp)
+            }
+            message::Request::Txn(r) => {
+                let resp = self
+                    .transaction(r)
+                    .timed_ge(threshold(), info_spent("MetaGrpcClient::transaction"))
+                    .await;
+                message::Response::Txn(resp)
+            }
+            message::Request::Watch(r) => {
+                let resp = self.watch(r).await;
+                message::Response::Watch(resp)
+            }
+            message::Request::Export(r) => {
+                let resp = self.export(r).await;
+                message::Response::Export(resp)
+            }
+            message::Request::MakeClient(_) => {
+                let resp = self.make_client().await;
+                message::Response::MakeClient(resp)
+            }
+            message::Request::GetEndpoints(_) => {
+                let resp = self.get_cached_endpoints();
+                message::Response::GetEndpoints(Ok(resp))
+            }
+            message::Request::GetClientInfo(_) => {
+                let resp = self.get_client_info().await;
+                message::Response::GetClientInfo(resp)
+            }
+            message::Request::GetKeySpaceStats(_) => {
+                let resp = self.get_key_space_stats().await;
+                message::Response::GetKeySpaceStats(resp)
+            }
+        };
+
+        debug!(
+            resp = as_debug!(&resp);
+            "MetaGrpcClient send response to the handle"
+        );
+
+        let current_endpoint = {
+            let current_endpoint = self.current_endpoint.lock();
+            current_endpoint.clone()
+        };
 
-            if let Some(current_endpoint) = current_endpoint {
-                let elapsed = start.elapsed().as_millis() as f64;
-                label_histogram_with_val(
-                    META_GRPC_CLIENT_REQUEST_DURATION_MS,
+        if let Some(current_endpoint) = current_endpoint {
+            let elapsed = start.elapsed().as_millis() as f64;
+            label_histogram_with_val(
+                META_GRPC_CLIENT_REQUEST_DURATION_MS,
+                &vec![
+                    (LABEL_ENDPOINT, current_endpoint.to_string()),
+                    (LABEL_REQUEST, req_name.to_string()),
+                ],
+                elapsed,
+            );
+            if elapsed > 1000_f64 {
+                warn!(
+                    "MetaGrpcClient slow request {} to {} takes {} ms: {}",
+                    req_name, current_endpoint, elapsed, req_str,
+                );
+            }
+
+            if let Some(err) = resp.err() {
+                label_counter_with_val_and_labels(
+                    META_GRPC_CLIENT_REQUEST_FAILED,
                     &vec![
                         (LABEL_ENDPOINT, current_endpoint.to_string()),
+                        (LABEL_ERROR, err.to_string()),
                         (LABEL_REQUEST, req_name.to_string()),
                     ],
-                    elapsed,
+                    1,
                 );
-                if elapsed > 1000_f64 {
-                    warn!(
-                        "MetaGrpcClient slow request {} to {} takes {} ms: {}",
-                        req_name, current_endpoint, elapsed, req_str,
-                    );
-                }
-
-                if let Some(err) = resp.err() {
-                    label_counter_with_val_and_labels(
-                        META_GRPC_CLIENT_REQUEST_FAILED,
-                        &vec![
-                            (LABEL_ENDPOINT, current_endpoint.to_string()),
-                            (LABEL_ERROR, err.to_string()),
-                            (LABEL_REQUEST, req_name.to_string()),
-                        ],
-                        1,
-                    );
-                    error!("MetaGrpcClient error: {:?}", err);
-                } else {
-                    label_counter_with_val_and_labels(
-                        META_GRPC_CLIENT_REQUEST_SUCCESS,
-                        &vec![
-                            (LABEL_ENDPOINT, current_endpoint.to_string()),
-                            (LABEL_REQUEST, req_name.to_string()),
-                        ],
-                        1,
-                    );
-                }
-            }
-
-            let send_res = resp_tx.send(resp);
-            if let Err(err) = send_res {
-                error!(
-                    err = as_debug!(&err);
-                    "MetaGrpcClient failed to send response to the handle. recv-end closed"
+                error!("MetaGrpcClient error: {:?}", err);
+            } else {
+                label_counter_with_val_and_labels(
+                    META_GRPC_CLIENT_REQUEST_SUCCESS,
+                    &vec![
+                        (LABEL_ENDPOINT, current_endpoint.to_string()),
+                        (LABEL_REQUEST, req_name.to_string()),
+                    ],
+                    1,
                 );
             }
         }
+
+        let send_res = resp_tx.send(resp);
+        if let Err(err) = send_res {
+            error!(
+                err = as_debug!(&err);
+                "MetaGrpcClient failed to send response to the handle. recv-end closed"
+            );
+        }
     }
 
     #[minitrace::trace]
-    pub async fn make_client(
-        &self,
-    ) -> Result<MetaServiceClient<InterceptedService<Channel, AuthInterceptor>>, MetaClientError>
-    {
+    pub async fn make_client(&self) -> Result<RealClient, MetaClientError> {
         let all_endpoints = self.get_cached_endpoints();
         debug!("meta-service all endpoints: {:?}", all_endpoints);
         debug_assert!(!all_endpoints.is_empty());
@@ -535,7 +609,7 @@ impl MetaGrpcClient {
 
         debug!("healthy endpoints: {:?}", &endpoints);
 
-        let endpoints = if endpoints.is_empty() {
+        let mut endpoints = if endpoints.is_empty() {
             warn!(
                 "meta-service has no healthy endpoints, force using all(healthy or not) endpoints: {:?}",
                 all_endpoints
@@ -546,6 +620,14 @@ impl MetaGrpcClient {
             endpoints
         };
 
+        // Connect to the leader first
+        let leader = self.leader_endpoint.lock().clone();
+        if let Some(leader) = leader {
+            if let Some(i) = endpoints.iter().position(|e| *e == leader) {
+                endpoints[..=i].rotate_right(1);
+            }
+        }
+
         for (addr, is_last) in endpoints
             .iter()
             .enumerate()
@@ -570,10 +652,15 @@ impl MetaGrpcClient {
 
                     match new_token {
                         Ok(token) => {
-                            let client =
+                            let mut client =
                                 MetaServiceClient::with_interceptor(c, AuthInterceptor { token })
                                     .max_decoding_message_size(GrpcConfig::MAX_DECODING_SIZE)
                                     .max_encoding_message_size(GrpcConfig::MAX_ENCODING_SIZE);
+                            if self.compression {
+                                client = client
+                                    .accept_compressed(CompressionEncoding::Gzip)
+                                    .send_compressed(CompressionEncoding::Gzip);
+                            }
 
                             return Ok(client);
                         }
@@ -690,33 +777,31 @@ impl MetaGrpcClient {
         Ok(())
     }
 
+    /// Fetch all endpoints and the leader of the meta-service cluster.
     #[minitrace::trace]
     pub async fn sync_endpoints(&self) -> Result<(), MetaError> {
-        let mut client = self.make_client().await?;
-        let result = client
-            .member_list(Request::new(MemberListRequest {
-                data: "".to_string(),
-            }))
-            .await;
-        let endpoints: Result<MemberListReply, Status> = match result {
-            Ok(r) => Ok(r.into_inner()),
-            Err(s) => {
-                if status_is_retryable(&s) {
-                    self.mark_as_unhealthy().await;
-                    let mut client = self.make_client().await?;
-                    let req = Request::new(MemberListRequest {
-                        data: "".to_string(),
-                    });
-                    Ok(client.member_list(req).await?.into_inner())
-                } else {
-                    Err(s)
-                }
-            }
+        let reply: MemberListReply = self
+            .call_with_retry(MAX_READ_ATTEMPTS, |mut client| async move {
+                let req = Request::new(MemberListRequest {
+                    data: "".to_string(),
+                });
+                client.member_list(req).await
+            })
+            .await?;
+
+        debug!(
+            "received meta endpoints: {:?}, leader: {:?}",
+            reply.data, reply.leader
+        );
+
+        let leader = if reply.data.contains(&reply.leader) && !reply.leader.is_empty() {
+            Some(reply.leader.clone())
+        } else {
+            None
         };
-        let result: Vec<String> = endpoints?.data;
-        debug!("received meta endpoints: {:?}", result);
 
-        self.set_endpoints(result).await?;
+        self.set_endpoints(reply.data).await?;
+        *self.leader_endpoint.lock() = leader;
         Ok(())
     }
 
@@ -725,6 +810,13 @@ impl MetaGrpcClient {
             "start auto sync endpoints: interval: {:?}",
             self.auto_sync_interval
         );
+
+        // Learn the full endpoint list and the leader at once, not to rely on only the
+        // configured endpoints before the first interval.
+        if let Err(e) = self.sync_endpoints().await {
+            warn!("initial sync endpoints failed: {:?}", e);
+        }
+
         if let Some(interval) = self.auto_sync_interval {
             loop {
                 select! {
@@ -888,6 +980,16 @@ impl MetaGrpcClient {
         Ok(res.into_inner())
     }
 
+    /// Get the key space usage of the state machine on the connected meta server.
+    #[minitrace::trace]
+    pub(crate) async fn get_key_space_stats(&self) -> Result<Vec<KeySpaceStat>, MetaError> {
+        debug!("MetaGrpcClient::get_key_space_stats");
+
+        let mut client = self.make_client().await?;
+        let res = client.key_space_stats(Empty {}).await?;
+        Ok(res.into_inner().stats)
+    }
+
     #[minitrace::trace]
     pub(crate) async fn kv_api<T, R>(&self, v: T) -> Result<R, MetaError>
     where
@@ -902,7 +1004,13 @@ impl MetaGrpcClient {
             "MetaGrpcClient::kv_api request"
         );
 
-        let req: Request<RaftRequest> = read_req.clone().try_into().map_err(|e| {
+        let max_attempts = if read_req.is_read() {
+            MAX_READ_ATTEMPTS
+        } else {
+            MAX_WRITE_ATTEMPTS
+        };
+
+        let req: Request<RaftRequest> = read_req.try_into().map_err(|e| {
             MetaNetworkError::InvalidArgument(InvalidArgument::new(e, "fail to encode request"))
         })?;
 
@@ -911,51 +1019,26 @@ impl MetaGrpcClient {
             "MetaGrpcClient::kv_api serialized request"
         );
 
-        let req = common_tracing::inject_span_to_tonic_request(req);
+        let raft_req = req.into_inner();
 
-        let mut client = self
-            .make_client()
-            .timed_ge(threshold(), info_spent("MetaGrpcClient::make_client-1"))
+        let raft_reply: RaftReply = self
+            .call_with_retry(max_attempts, |mut client| {
+                let req =
+                    common_tracing::inject_span_to_tonic_request(Request::new(raft_req.clone()));
+                async move {
+                    client
+                        .kv_api(req)
+                        .timed_ge(threshold(), info_spent("client::kv_api"))
+                        .await
+                }
+            })
             .await?;
 
-        let result = client
-            .kv_api(req)
-            .timed_ge(threshold(), info_spent("client::kv_api-1"))
-            .await;
-
         debug!(
-            reply = as_debug!(&result);
+            reply = as_debug!(&raft_reply);
             "MetaGrpcClient::kv_api reply"
         );
 
-        let rpc_res: Result<RaftReply, Status> = match result {
-            Ok(r) => Ok(r.into_inner()),
-            Err(s) => {
-                if status_is_retryable(&s) {
-                    self.mark_as_unhealthy().await;
-                    let mut client = self
-                        .make_client()
-                        .timed_ge(threshold(), info_spent("MetaGrpcClient::make_client-2"))
-                        .await?;
-                    let req: Request<RaftRequest> = read_req.try_into().map_err(|e| {
-                        MetaNetworkError::InvalidArgument(InvalidArgument::new(
-                            e,
-                            "fail to encode request",
-                        ))
-                    })?;
-                    let req = common_tracing::inject_span_to_tonic_request(req);
-                    Ok(client
-                        .kv_api(req)
-                        .timed_ge(threshold(), info_spent("client::kv_api-2"))
-                        .await?
-                        .into_inner())
-                } else {
-                    Err(s)
-                }
-            }
-        };
-        let raft_reply = rpc_res?;
-
         let resp: R = reply_to_api_result(raft_reply)?;
         Ok(resp)
     }
@@ -969,29 +1052,12 @@ impl MetaGrpcClient {
             "MetaGrpcClient::transaction request"
         );
 
-        let req: Request<TxnRequest> = Request::new(txn.clone());
-        let req = common_tracing::inject_span_to_tonic_request(req);
-
-        let mut client = self.make_client().await?;
-        let result = client.transaction(req).await;
-
-        let result: Result<TxnReply, Status> = match result {
-            Ok(r) => return Ok(r.into_inner()),
-            Err(s) => {
-                if status_is_retryable(&s) {
-                    self.mark_as_unhealthy().await;
-                    let mut client = self.make_client().await?;
-                    let req: Request<TxnRequest> = Request::new(txn);
-                    let req = common_tracing::inject_span_to_tonic_request(req);
-                    let ret = client.transaction(req).await?.into_inner();
-                    return Ok(ret);
-                } else {
-                    Err(s)
-                }
-            }
-        };
-
-        let reply = result?;
+        let reply: TxnReply = self
+            .call_with_retry(MAX_WRITE_ATTEMPTS, |mut client| {
+                let req = common_tracing::inject_span_to_tonic_request(Request::new(txn.clone()));
+                async move { client.transaction(req).await }
+            })
+            .await?;
 
         debug!(
             reply = as_display!(&reply);
@@ -1000,13 +1066,74 @@ impl MetaGrpcClient {
 
         Ok(reply)
     }
+
+    /// Send a request to a healthy node, built by `f` with a client connected to it.
+    ///
+    /// If the node fails, the request is retried on another node with exponential backoff,
+    /// until it has been sent `max_attempts` times.
+    async fn call_with_retry<Resp, F, Fut>(
+        &self,
+        max_attempts: u32,
+        f: F,
+    ) -> Result<Resp, MetaError>
+    where
+        F: Fn(RealClient) -> Fut,
+        Fut: Future<Output = Result<tonic::Response<Resp>, Status>>,
+    {
+        let mut attempt = 1;
+        loop {
+            let client = self
+                .make_client()
+                .timed_ge(threshold(), info_spent("MetaGrpcClient::make_client"))
+                .await;
+
+            let err = match client {
+                Ok(client) => match f(client).await {
+                    Ok(r) => return Ok(r.into_inner()),
+                    Err(s) if status_is_retryable(&s) => {
+                        self.mark_as_unhealthy().await;
+                        MetaError::from(s)
+                    }
+                    Err(s) => return Err(s.into()),
+                },
+                // No node can be connected, they may be restarting: wait and connect again.
+                Err(MetaClientError::NetworkError(e)) => MetaError::from(e),
+                Err(e) => return Err(e.into()),
+            };
+
+            if attempt >= max_attempts {
+                return Err(err);
+            }
+
+            let backoff = retry_backoff(attempt);
+            warn!(
+                "MetaGrpcClient request failed: {}; attempt: {}/{}, retry in {:?}",
+                err, attempt, max_attempts, backoff
+            );
+            sleep(backoff).await;
+            attempt += 1;
+        }
+    }
+
     async fn mark_as_unhealthy(&self) {
         let ca = self.current_endpoint.lock();
-        let mut ue = self.unhealthy_endpoints.lock();
-        ue.insert((*ca).as_ref().unwrap().clone(), ());
+        if let Some(addr) = (*ca).as_ref() {
+            let mut leader = self.leader_endpoint.lock();
+            if leader.as_ref() == Some(addr) {
+                *leader = None;
+            }
+
+            let mut ue = self.unhealthy_endpoints.lock();
+            ue.insert(addr.clone(), ());
+        }
     }
 }
 
+fn retry_backoff(attempt: u32) -> Duration {
+    let backoff = RETRY_BACKOFF_BASE.saturating_mul(1 << (attempt - 1).min(16));
+    backoff.min(RETRY_BACKOFF_MAX)
+}
+
 fn status_is_retryable(status: &Status) -> bool {
     matches!(
         status.code(),
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::BatchUpsertKVReply;
+use common_meta_kvapi::kvapi::BatchUpsertKVReq;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::GetKVReq;
 use common_meta_kvapi::kvapi::ListKVReply;
@@ -36,6 +38,14 @@ impl kvapi::KVApi for ClientHandle {
         Ok(reply)
     }
 
+    async fn batch_upsert_kv(
+        &self,
+        req: BatchUpsertKVReq,
+    ) -> Result<BatchUpsertKVReply, Self::Error> {
+        let reply = self.request(req).await?;
+        Ok(reply)
+    }
+
     async fn get_kv(&self, key: &str) -> Result<GetKVReply, Self::Error> {
         let reply = self
             .request(GetKVReq {
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use common_base::base::tokio::sync::oneshot::Sender;
+use common_meta_kvapi::kvapi::BatchUpsertKVReply;
+use common_meta_kvapi::kvapi::BatchUpsertKVReq;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::GetKVReq;
 use common_meta_kvapi::kvapi::ListKVReply;
@@ -24,12 +26,14 @@ use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::protobuf::meta_service_client::MetaServiceClient;
 use common_meta_types::protobuf::ClientInfo;
 use common_meta_types::protobuf::ExportedChunk;
+use common_meta_types::protobuf::KeySpaceStat;
 use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::protobuf::WatchResponse;
 use common_meta_types::MetaClientError;
 use common_meta_types::MetaError;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
+use minitrace::prelude::SpanContext;
 use tonic::codegen::InterceptedService;
 use tonic::transport::Channel;
 
@@ -43,6 +47,10 @@ pub struct ClientWorkerRequest {
 
     /// Request body
     pub(crate) req: Request,
+
+    /// The span of the caller, the worker handles the request in a child span of it, so that
+    /// the trace is propagated to the meta service.
+    pub(crate) span_context: Option<SpanContext>,
 }
 
 /// Meta-client handle-to-worker request body
@@ -60,6 +68,9 @@ pub enum Request {
     /// Update or insert KV
     Upsert(UpsertKVReq),
 
+    /// Update or insert several KVs atomically
+    BatchUpsert(BatchUpsertKVReq),
+
     /// Run a transaction on remote
     Txn(TxnRequest),
 
@@ -77,6 +88,9 @@ pub enum Request {
 
     /// Get info about the client
     GetClientInfo(GetClientInfo),
+
+    /// Get key space usage of the connected meta server
+    GetKeySpaceStats(GetKeySpaceStats),
 }
 
 impl Request {
@@ -86,12 +100,14 @@ impl Request {
             Request::MGet(_) => "MGet",
             Request::PrefixList(_) => "PrefixList",
             Request::Upsert(_) => "Upsert",
+            Request::BatchUpsert(_) => "BatchUpsert",
             Request::Txn(_) => "Txn",
             Request::Watch(_) => "Watch",
             Request::Export(_) => "Export",
             Request::MakeClient(_) => "MakeClient",
             Request::GetEndpoints(_) => "GetEndpoints",
             Request::GetClientInfo(_) => "GetClientInfo",
+            Request::GetKeySpaceStats(_) => "GetKeySpaceStats",
         }
     }
 }
@@ -103,6 +119,7 @@ pub enum Response {
     MGet(Result<MGetKVReply, MetaError>),
     PrefixList(Result<ListKVReply, MetaError>),
     Upsert(Result<UpsertKVReply, MetaError>),
+    BatchUpsert(Result<BatchUpsertKVReply, MetaError>),
     Txn(Result<TxnReply, MetaError>),
     Watch(Result<tonic::codec::Streaming<WatchResponse>, MetaError>),
     Export(Result<tonic::codec::Streaming<ExportedChunk>, MetaError>),
@@ -111,6 +128,7 @@ pub enum Response {
     ),
     GetEndpoints(Result<Vec<String>, MetaError>),
     GetClientInfo(Result<ClientInfo, MetaError>),
+    GetKeySpaceStats(Result<Vec<KeySpaceStat>, MetaError>),
 }
 
 impl Response {
@@ -120,12 +138,14 @@ impl Response {
             Response::MGet(res) => res.is_err(),
             Response::PrefixList(res) => res.is_err(),
             Response::Upsert(res) => res.is_err(),
+            Response::BatchUpsert(res) => res.is_err(),
             Response::Txn(res) => res.is_err(),
             Response::Watch(res) => res.is_err(),
             Response::Export(res) => res.is_err(),
             Response::MakeClient(res) => res.is_err(),
             Response::GetEndpoints(res) => res.is_err(),
             Response::GetClientInfo(res) => res.is_err(),
+            Response::GetKeySpaceStats(res) => res.is_err(),
         }
     }
 
@@ -147,6 +167,10 @@ impl Response {
                 .as_ref()
                 .err()
                 .map(|x| x as &(dyn std::error::Error + 'static)),
+            Response::BatchUpsert(res) => res
+                .as_ref()
+                .err()
+                .map(|x| x as &(dyn std::error::Error + 'static)),
             Response::Txn(res) => res
                 .as_ref()
                 .err()
@@ -171,6 +195,10 @@ impl Response {
                 .as_ref()
                 .err()
                 .map(|x| x as &(dyn std::error::Error + 'static)),
+            Response::GetKeySpaceStats(res) => res
+                .as_ref()
+                .err()
+                .map(|x| x as &(dyn std::error::Error + 'static)),
         };
         e
     }
@@ -193,3 +221,7 @@ pub struct GetEndpoints {}
 /// Get info about client
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 pub struct GetClientInfo {}
+
+/// Get the number of keys and bytes used by every key prefix
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct GetKeySpaceStats {}
@@ -25,6 +25,7 @@ use common_meta_types::protobuf::ClientInfo;
 use common_meta_types::protobuf::Empty;
 use common_meta_types::protobuf::ExportedChunk;
 use common_meta_types::protobuf::HandshakeResponse;
+use common_meta_types::protobuf::KeySpaceStatsReply;
 use common_meta_types::protobuf::MemberListReply;
 use common_meta_types::protobuf::MemberListRequest;
 use common_meta_types::protobuf::RaftReply;
@@ -78,6 +79,16 @@ impl MetaService for GrpcServiceForTestImpl {
         todo!()
     }
 
+    type ExportSnapshotStream =
+        Pin<Box<dyn Stream<Item = Result<ExportedChunk, tonic::Status>> + Send + Sync + 'static>>;
+
+    async fn export_snapshot(
+        &self,
+        _request: Request<common_meta_types::protobuf::Empty>,
+    ) -> Result<Response<Self::ExportSnapshotStream>, Status> {
+        todo!()
+    }
+
     type WatchStream =
         Pin<Box<dyn Stream<Item = Result<WatchResponse, tonic::Status>> + Send + Sync + 'static>>;
 
@@ -99,7 +110,7 @@ impl MetaService for GrpcServiceForTestImpl {
         &self,
         _request: Request<MemberListRequest>,
     ) -> Result<Response<MemberListReply>, Status> {
-        todo!()
+        Ok(Response::new(MemberListReply::default()))
     }
 
     async fn get_client_info(
@@ -108,6 +119,13 @@ impl MetaService for GrpcServiceForTestImpl {
     ) -> Result<Response<ClientInfo>, Status> {
         todo!()
     }
+
+    async fn key_space_stats(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<KeySpaceStatsReply>, Status> {
+        todo!()
+    }
 }
 
 pub fn start_grpc_server() -> String {
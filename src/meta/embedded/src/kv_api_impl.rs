@@ -14,6 +14,8 @@
 
 use async_trait::async_trait;
 use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::BatchUpsertKVReply;
+use common_meta_kvapi::kvapi::BatchUpsertKVReq;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::MGetKVReply;
@@ -34,6 +36,14 @@ impl kvapi::KVApi for MetaEmbedded {
         sm.upsert_kv(act).await
     }
 
+    async fn batch_upsert_kv(
+        &self,
+        req: BatchUpsertKVReq,
+    ) -> Result<BatchUpsertKVReply, Self::Error> {
+        let sm = self.inner.lock().await;
+        sm.batch_upsert_kv(req).await
+    }
+
     async fn get_kv(&self, key: &str) -> Result<GetKVReply, Self::Error> {
         let sm = self.inner.lock().await;
         sm.get_kv(key).await
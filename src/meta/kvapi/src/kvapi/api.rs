@@ -19,6 +19,8 @@ use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 
 use crate::kvapi;
+use crate::kvapi::BatchUpsertKVReply;
+use crate::kvapi::BatchUpsertKVReq;
 use crate::kvapi::GetKVReply;
 use crate::kvapi::ListKVReply;
 use crate::kvapi::MGetKVReply;
@@ -48,6 +50,15 @@ pub trait KVApi: Send + Sync {
     /// Update or insert a key-value record.
     async fn upsert_kv(&self, req: UpsertKVReq) -> Result<UpsertKVReply, Self::Error>;
 
+    /// Update or insert several key-value records atomically, in one write.
+    ///
+    /// Each upsert is applied as if by [`KVApi::upsert_kv`], the replies are in the order of
+    /// the upserts.
+    async fn batch_upsert_kv(
+        &self,
+        req: BatchUpsertKVReq,
+    ) -> Result<BatchUpsertKVReply, Self::Error>;
+
     /// Get a key-value record by key.
     async fn get_kv(&self, key: &str) -> Result<GetKVReply, Self::Error>;
 
@@ -69,6 +80,13 @@ impl<U: kvapi::KVApi, T: Deref<Target = U> + Send + Sync> kvapi::KVApi for T {
         self.deref().upsert_kv(act).await
     }
 
+    async fn batch_upsert_kv(
+        &self,
+        req: BatchUpsertKVReq,
+    ) -> Result<BatchUpsertKVReply, Self::Error> {
+        self.deref().batch_upsert_kv(req).await
+    }
+
     async fn get_kv(&self, key: &str) -> Result<GetKVReply, Self::Error> {
         self.deref().get_kv(key).await
     }
@@ -18,6 +18,12 @@ use common_meta_types::UpsertKV;
 
 pub type UpsertKVReq = UpsertKV;
 
+/// Several upserts that are applied atomically, in a single raft log.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BatchUpsertKVReq {
+    pub upserts: Vec<UpsertKVReq>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct GetKVReq {
     pub key: String,
@@ -34,6 +40,7 @@ pub struct ListKVReq {
 }
 
 pub type UpsertKVReply = Change<Vec<u8>>;
+pub type BatchUpsertKVReply = Vec<UpsertKVReply>;
 pub type GetKVReply = Option<SeqV<Vec<u8>>>;
 pub type MGetKVReply = Vec<Option<SeqV<Vec<u8>>>>;
 pub type ListKVReply = Vec<(String, SeqV<Vec<u8>>)>;
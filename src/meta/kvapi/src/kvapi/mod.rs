@@ -28,6 +28,8 @@ pub use key::Key;
 pub use key::KeyError;
 pub use key_builder::KeyBuilder;
 pub use key_parser::KeyParser;
+pub use message::BatchUpsertKVReply;
+pub use message::BatchUpsertKVReq;
 pub use message::GetKVReply;
 pub use message::GetKVReq;
 pub use message::ListKVReply;
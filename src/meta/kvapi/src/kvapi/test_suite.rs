@@ -22,6 +22,7 @@ use common_meta_types::txn_op_response;
 use common_meta_types::ConditionResult;
 use common_meta_types::KVMeta;
 use common_meta_types::MatchSeq;
+use common_meta_types::MetaSpec;
 use common_meta_types::Operation;
 use common_meta_types::SeqV;
 use common_meta_types::TxnCondition;
@@ -42,6 +43,7 @@ use log::debug;
 use log::info;
 
 use crate::kvapi;
+use crate::kvapi::BatchUpsertKVReq;
 use crate::kvapi::UpsertKVReq;
 
 pub struct TestSuite {}
@@ -55,6 +57,7 @@ impl kvapi::TestSuite {
     {
         self.kv_write_read(&builder.build().await).await?;
         self.kv_delete(&builder.build().await).await?;
+        self.kv_batch_upsert(&builder.build().await).await?;
         self.kv_update(&builder.build().await).await?;
         self.kv_timeout(&builder.build().await).await?;
         self.kv_meta(&builder.build().await).await?;
@@ -70,6 +73,8 @@ impl kvapi::TestSuite {
             .await?;
         self.kv_delete_by_prefix_transaction(&builder.build().await)
             .await?;
+        self.kv_transaction_condition_operators(&builder.build().await)
+            .await?;
 
         // Run cross node test on every 2 adjacent nodes
         let mut i = 0;
@@ -192,6 +197,60 @@ impl kvapi::TestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    pub async fn kv_batch_upsert<KV: kvapi::KVApi>(&self, kv: &KV) -> anyhow::Result<()> {
+        info!("--- kvapi::KVApiTestSuite::kv_batch_upsert() start");
+
+        kv.upsert_kv(UpsertKVReq::update("batch_c", b"c0")).await?;
+
+        let res = kv
+            .batch_upsert_kv(BatchUpsertKVReq {
+                upserts: vec![
+                    UpsertKVReq::update("batch_a", b"a1"),
+                    UpsertKVReq::update("batch_b", b"b1"),
+                    UpsertKVReq::delete("batch_c"),
+                    // seq mismatch, nothing changed
+                    UpsertKVReq::update("batch_d", b"d1").with(MatchSeq::GE(1)),
+                ],
+            })
+            .await?;
+
+        assert_eq!(4, res.len());
+        assert_eq!(
+            (None, Some(SeqV::with_meta(2, None, b"a1".to_vec()))),
+            (res[0].prev.clone(), res[0].result.clone())
+        );
+        assert_eq!(
+            (None, Some(SeqV::with_meta(3, None, b"b1".to_vec()))),
+            (res[1].prev.clone(), res[1].result.clone())
+        );
+        assert_eq!(
+            (Some(SeqV::with_meta(1, None, b"c0".to_vec())), None),
+            (res[2].prev.clone(), res[2].result.clone())
+        );
+        assert_eq!((None, None), (res[3].prev.clone(), res[3].result.clone()));
+
+        let got = kv
+            .mget_kv(&[
+                "batch_a".to_string(),
+                "batch_b".to_string(),
+                "batch_c".to_string(),
+                "batch_d".to_string(),
+            ])
+            .await?;
+        assert_eq!(
+            vec![
+                Some(SeqV::with_meta(2, None, b"a1".to_vec())),
+                Some(SeqV::with_meta(3, None, b"b1".to_vec())),
+                None,
+                None,
+            ],
+            got
+        );
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     pub async fn kv_update<KV: kvapi::KVApi>(&self, kv: &KV) -> anyhow::Result<()> {
         info!("--- kvapi::KVApiTestSuite::kv_update() start");
@@ -367,9 +426,7 @@ impl kvapi::TestSuite {
                 test_key,
                 MatchSeq::Exact(seq + 1),
                 Operation::AsIs,
-                Some(KVMeta {
-                    expire_at: Some(now + 20),
-                }),
+                Some(MetaSpec::new_expire(now + 20)),
             ))
             .await?;
         assert_eq!(Some(SeqV::with_meta(1, None, b"v1".to_vec())), r.prev);
@@ -382,9 +439,7 @@ impl kvapi::TestSuite {
                 test_key,
                 MatchSeq::Exact(seq),
                 Operation::AsIs,
-                Some(KVMeta {
-                    expire_at: Some(now + 20),
-                }),
+                Some(MetaSpec::new_expire(now + 20)),
             ))
             .await?;
         assert_eq!(Some(SeqV::with_meta(1, None, b"v1".to_vec())), r.prev);
@@ -505,6 +560,7 @@ impl kvapi::TestSuite {
                 value: b"new_v1".to_vec(),
                 prev_value: true,
                 expire_at: None,
+                ttl_ms: None,
             })),
         }];
 
@@ -666,6 +722,7 @@ impl kvapi::TestSuite {
                     value: b"new_v1".to_vec(),
                     prev_value: true,
                     expire_at: None,
+                    ttl_ms: None,
                 })),
             }];
 
@@ -718,6 +775,7 @@ impl kvapi::TestSuite {
                     value: b"new_v1".to_vec(),
                     prev_value: true,
                     expire_at: None,
+                    ttl_ms: None,
                 })),
             }];
 
@@ -773,6 +831,7 @@ impl kvapi::TestSuite {
                         value: val1_new.to_vec(),
                         prev_value: true,
                         expire_at: None,
+                        ttl_ms: None,
                     })),
                 },
                 // change k2
@@ -782,6 +841,7 @@ impl kvapi::TestSuite {
                         value: b"new_v2".to_vec(),
                         prev_value: true,
                         expire_at: None,
+                        ttl_ms: None,
                     })),
                 },
                 // get k1
@@ -883,6 +943,7 @@ impl kvapi::TestSuite {
                         value: val1_new.to_vec(),
                         prev_value: true,
                         expire_at: None,
+                        ttl_ms: None,
                     })),
                 },
                 // get k1
@@ -1029,6 +1090,84 @@ impl kvapi::TestSuite {
 
         Ok(())
     }
+
+    #[minitrace::trace]
+    pub async fn kv_transaction_condition_operators<KV: kvapi::KVApi>(
+        &self,
+        kv: &KV,
+    ) -> anyhow::Result<()> {
+        info!("--- {}", func_name!());
+        let key = || "txn_cond_K1".to_string();
+        let absent = || "txn_cond_absent".to_string();
+
+        kv.upsert_kv(UpsertKVReq::update(key(), b"snapshot/2"))
+            .await?;
+
+        let cases = vec![
+            (
+                "seq >",
+                TxnCondition::match_seq(key(), ConditionResult::Gt, 0),
+                true,
+            ),
+            (
+                "seq > fail",
+                TxnCondition::match_seq(key(), ConditionResult::Gt, 1),
+                false,
+            ),
+            (
+                "seq >=",
+                TxnCondition::match_seq(key(), ConditionResult::Ge, 1),
+                true,
+            ),
+            (
+                "seq <",
+                TxnCondition::match_seq(key(), ConditionResult::Lt, 2),
+                true,
+            ),
+            (
+                "seq < fail",
+                TxnCondition::match_seq(key(), ConditionResult::Lt, 1),
+                false,
+            ),
+            (
+                "absent seq",
+                TxnCondition::match_seq(absent(), ConditionResult::Eq, 0),
+                true,
+            ),
+            (
+                "prefix",
+                TxnCondition::match_value_prefix(key(), b"snapshot/".to_vec()),
+                true,
+            ),
+            (
+                "prefix fail",
+                TxnCondition::match_value_prefix(key(), b"snap/".to_vec()),
+                false,
+            ),
+            (
+                "absent prefix",
+                TxnCondition::match_value_prefix(absent(), vec![]),
+                false,
+            ),
+            ("exists", TxnCondition::exists(key()), true),
+            ("exists fail", TxnCondition::exists(absent()), false),
+            ("not exists", TxnCondition::not_exists(absent()), true),
+            ("not exists fail", TxnCondition::not_exists(key()), false),
+        ];
+
+        for (name, cond, want) in cases {
+            let txn = TxnRequest {
+                condition: vec![cond],
+                if_then: vec![],
+                else_then: vec![],
+            };
+
+            let resp = kv.transaction(txn).await?;
+            assert_eq!(want, resp.success, "case: {}", name);
+        }
+
+        Ok(())
+    }
 }
 
 /// Test that write and read should be forwarded to leader
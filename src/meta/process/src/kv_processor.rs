@@ -135,6 +135,24 @@ where F: Fn(&str, Vec<u8>) -> Result<Vec<u8>, anyhow::Error>
                 };
                 Ok(Some(x))
             }
+            Cmd::BatchUpsertKV(upserts) => {
+                let mut processed = vec![];
+                for ups in upserts {
+                    if let Some(ups) = self.proc_upsert_kv(ups)? {
+                        processed.push(ups);
+                    }
+                }
+
+                if processed.is_empty() {
+                    return Ok(None);
+                }
+
+                Ok(Some(LogEntry {
+                    txid: log_entry.txid,
+                    time_ms: log_entry.time_ms,
+                    cmd: Cmd::BatchUpsertKV(processed),
+                }))
+            }
             Cmd::Transaction(tx) => {
                 let mut condition = vec![];
                 for c in tx.condition {
@@ -218,6 +236,7 @@ where F: Fn(&str, Vec<u8>) -> Result<Vec<u8>, anyhow::Error>
             value,
             prev_value: p.prev_value,
             expire_at: p.expire_at,
+            ttl_ms: p.ttl_ms,
         };
 
         Ok(pr)
@@ -118,6 +118,10 @@ impl FromToProto for StorageS3Config {
             role_arn: p.role_arn,
             external_id: p.external_id,
             allow_anonymous: p.allow_anonymous,
+            secrets_provider: p.secrets_provider,
+            server_side_encryption: p.server_side_encryption,
+            server_side_encryption_aws_kms_key_id: p.server_side_encryption_aws_kms_key_id,
+            server_side_encryption_customer_key: p.server_side_encryption_customer_key,
         })
     }
 
@@ -138,6 +142,12 @@ impl FromToProto for StorageS3Config {
             role_arn: self.role_arn.clone(),
             external_id: self.external_id.clone(),
             allow_anonymous: self.allow_anonymous,
+            secrets_provider: self.secrets_provider.clone(),
+            server_side_encryption: self.server_side_encryption.clone(),
+            server_side_encryption_aws_kms_key_id: self
+                .server_side_encryption_aws_kms_key_id
+                .clone(),
+            server_side_encryption_customer_key: self.server_side_encryption_customer_key.clone(),
         })
     }
 }
@@ -76,6 +76,11 @@ impl FromToProto for ex::TableField {
             None => None,
         };
 
+        let mut prev_versions = Vec::with_capacity(p.prev_versions.len());
+        for v in p.prev_versions {
+            prev_versions.push(ex::ColumnVersion::from_pb(v)?);
+        }
+
         let v = ex::TableField::new_from_column_id(
             &p.name,
             ex::TableDataType::from_pb(p.data_type.ok_or_else(|| Incompatible {
@@ -84,7 +89,8 @@ impl FromToProto for ex::TableField {
             p.column_id,
         )
         .with_default_expr(p.default_expr)
-        .with_computed_expr(computed_expr);
+        .with_computed_expr(computed_expr)
+        .with_prev_versions(prev_versions);
         Ok(v)
     }
 
@@ -101,6 +107,39 @@ impl FromToProto for ex::TableField {
             data_type: Some(self.data_type().to_pb()?),
             column_id: self.column_id(),
             computed_expr,
+            prev_versions: self
+                .prev_versions()
+                .iter()
+                .map(|v| v.to_pb())
+                .collect::<Result<Vec<_>, _>>()?,
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for ex::ColumnVersion {
+    type PB = pb::ColumnVersion;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::ColumnVersion) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = ex::ColumnVersion {
+            column_id: p.column_id,
+            data_type: ex::TableDataType::from_pb(p.data_type.ok_or_else(|| Incompatible {
+                reason: "ColumnVersion.data_type can not be None".to_string(),
+            })?)?,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::ColumnVersion, Incompatible> {
+        let p = pb::ColumnVersion {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            column_id: self.column_id,
+            data_type: Some(self.data_type.to_pb()?),
         };
         Ok(p)
     }
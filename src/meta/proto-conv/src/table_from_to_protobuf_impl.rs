@@ -235,7 +235,10 @@ impl FromToProto for mt::TableMeta {
             schema: Some(self.schema.to_pb()?),
             engine: self.engine.clone(),
             engine_options: self.engine_options.clone(),
+            // The storage params of a table bound to a storage profile are resolved from
+            // the profile when loading the table, never persist the credentials here.
             storage_params: match self.storage_params.clone() {
+                Some(_) if self.options.contains_key("storage_profile") => None,
                 Some(sp) => Some(sp.to_pb()?),
                 None => None,
             },
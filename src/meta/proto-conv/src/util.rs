@@ -84,7 +84,12 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (52, "2023-08-15: Add: catalog.proto/HiveCatalogConfig add storage params", ),
     (53, "2023-08-17: Add: user.proto/CsvFileFormatParams add field `null_display`", ),
     (54, "2023-08-17: Add: index.proto/IndexMeta::sync_creation", ),
-    (55, "2023-07-31: Add: TableMeta and DatabaseMeta add Ownership", )
+    (55, "2023-07-31: Add: TableMeta and DatabaseMeta add Ownership", ),
+    (56, "2023-08-21: Add: config.proto/S3StorageConfig add secrets_provider", ),
+    (57, "2023-08-22: Add: config.proto/S3StorageConfig add server side encryption", ),
+    (58, "2023-08-23: Add: file_format.proto/AvroFileFormatParams", ),
+    (59, "2023-08-24: Add: file_format.proto/OrcFileFormatParams", ),
+    (60, "2023-08-28: Add: metadata.proto/DataField::prev_versions", )
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
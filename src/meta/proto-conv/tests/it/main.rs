@@ -60,3 +60,8 @@ mod v052_hive_catalog_config;
 mod v053_csv_format_params;
 mod v054_index_meta;
 mod v055_table_meta;
+mod v056_s3_secrets_provider;
+mod v057_s3_server_side_encryption;
+mod v058_avro_format_params;
+mod v059_orc_format_params;
+mod v060_data_field_prev_versions;
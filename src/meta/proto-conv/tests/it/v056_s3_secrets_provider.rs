@@ -0,0 +1,50 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_app::storage::StorageS3Config;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v56_s3_secrets_provider() -> anyhow::Result<()> {
+    let bytes = vec![
+        10, 9, 117, 115, 45, 101, 97, 115, 116, 45, 50, 18, 24, 104, 116, 116, 112, 115, 58, 47,
+        47, 115, 51, 46, 97, 109, 97, 122, 111, 110, 97, 119, 115, 46, 99, 111, 109, 42, 6, 98,
+        117, 99, 107, 101, 116, 50, 13, 47, 112, 97, 116, 104, 47, 116, 111, 47, 114, 111, 111,
+        116, 114, 24, 118, 97, 117, 108, 116, 58, 47, 47, 97, 119, 115, 47, 99, 114, 101, 100, 115,
+        47, 114, 101, 97, 100, 101, 114, 160, 6, 56, 168, 6, 24,
+    ];
+
+    let want = || StorageS3Config {
+        region: "us-east-2".to_string(),
+        endpoint_url: "https://s3.amazonaws.com".to_string(),
+        bucket: "bucket".to_string(),
+        root: "/path/to/root".to_string(),
+        secrets_provider: "vault://aws/creds/reader".to_string(),
+        ..Default::default()
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 56, want())
+}
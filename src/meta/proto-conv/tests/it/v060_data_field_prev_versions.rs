@@ -0,0 +1,49 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::NumberDataType;
+use common_expression::ColumnVersion;
+use common_expression::TableDataType;
+use common_expression::TableField;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v60_data_field_prev_versions() -> anyhow::Result<()> {
+    let table_field_v60 = vec![
+        10, 1, 97, 26, 17, 154, 2, 8, 66, 0, 160, 6, 60, 168, 6, 24, 160, 6, 60, 168, 6, 24, 32, 1,
+        50, 25, 18, 17, 154, 2, 8, 58, 0, 160, 6, 60, 168, 6, 24, 160, 6, 60, 168, 6, 24, 160, 6,
+        60, 168, 6, 24, 160, 6, 60, 168, 6, 24,
+    ];
+
+    let want = || {
+        TableField::new_from_column_id("a", TableDataType::Number(NumberDataType::Int64), 1)
+            .with_prev_versions(vec![ColumnVersion {
+                column_id: 0,
+                data_type: TableDataType::Number(NumberDataType::Int32),
+            }])
+    };
+    common::test_load_old(func_name!(), table_field_v60.as_slice(), 60, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
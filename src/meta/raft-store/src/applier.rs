@@ -25,8 +25,8 @@ use common_meta_types::Cmd;
 use common_meta_types::ConditionResult;
 use common_meta_types::Entry;
 use common_meta_types::EntryPayload;
-use common_meta_types::KVMeta;
 use common_meta_types::MatchSeq;
+use common_meta_types::MetaSpec;
 use common_meta_types::Node;
 use common_meta_types::SeqV;
 use common_meta_types::StoredMembership;
@@ -136,6 +136,8 @@ impl<'a> Applier<'a> {
 
             Cmd::UpsertKV(ref upsert_kv) => self.apply_upsert_kv(upsert_kv),
 
+            Cmd::BatchUpsertKV(ref upsert_kvs) => self.apply_batch_upsert_kv(upsert_kvs),
+
             Cmd::Transaction(txn) => self.apply_txn(txn),
         };
 
@@ -189,6 +191,22 @@ impl<'a> Applier<'a> {
         Change::new(prev, result).into()
     }
 
+    /// Execute a batch of upsert-kv operations, in the order they are given.
+    #[minitrace::trace]
+    fn apply_batch_upsert_kv(&mut self, upsert_kvs: &[UpsertKV]) -> AppliedState {
+        debug!("apply_batch_upsert_kv: {} upserts", upsert_kvs.len());
+
+        let changes = upsert_kvs
+            .iter()
+            .map(|upsert_kv| {
+                let (prev, result) = self.upsert_kv(upsert_kv);
+                Change::new(prev, result)
+            })
+            .collect();
+
+        AppliedState::KVs(changes)
+    }
+
     #[minitrace::trace]
     fn upsert_kv(&mut self, upsert_kv: &UpsertKV) -> (Option<SeqV>, Option<SeqV>) {
         debug!(upsert_kv = as_debug!(upsert_kv); "upsert_kv");
@@ -276,6 +294,10 @@ impl<'a> Applier<'a> {
                     false
                 }
             }
+            txn_condition::Target::Exists(right) => {
+                // seq is 0 if the record does not exist.
+                Self::eval_exists_condition(seqv.seq() > 0, cond.expected, *right)
+            }
         }
     }
 
@@ -299,6 +321,15 @@ impl<'a> Applier<'a> {
             Some(ConditionResult::Ne) => left != right,
             Some(ConditionResult::Ge) => left >= right,
             Some(ConditionResult::Le) => left <= right,
+            Some(ConditionResult::Prefix) => left.starts_with(right),
+            _ => false,
+        }
+    }
+
+    fn eval_exists_condition(left: bool, op: i32, right: bool) -> bool {
+        match FromPrimitive::from_i32(op) {
+            Some(ConditionResult::Eq) => left == right,
+            Some(ConditionResult::Ne) => left != right,
             _ => false,
         }
     }
@@ -337,9 +368,10 @@ impl<'a> Applier<'a> {
     }
 
     fn txn_execute_put(&mut self, put: &TxnPutRequest, resp: &mut TxnReply) {
-        let upsert = UpsertKV::update(&put.key, &put.value).with(KVMeta {
-            expire_at: put.expire_at,
-        });
+        let upsert = UpsertKV::update(&put.key, &put.value).with(MetaSpec::new(
+            put.expire_at,
+            put.ttl_ms.map(Duration::from_millis),
+        ));
 
         let (prev, _result) = self.upsert_kv(&upsert);
 
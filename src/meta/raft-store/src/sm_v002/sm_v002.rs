@@ -19,6 +19,8 @@ use std::io;
 use std::sync::Arc;
 
 use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::BatchUpsertKVReply;
+use common_meta_kvapi::kvapi::BatchUpsertKVReq;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::MGetKVReply;
@@ -27,6 +29,7 @@ use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_stoerr::MetaBytesError;
 use common_meta_types::AppliedState;
 use common_meta_types::Entry;
+use common_meta_types::KeySpaceStat;
 use common_meta_types::LogId;
 use common_meta_types::MatchSeqExt;
 use common_meta_types::Node;
@@ -72,6 +75,13 @@ impl<'a> kvapi::KVApi for SMV002KVApi<'a> {
         unreachable!("write operation SM2KVApi::upsert_kv is disabled")
     }
 
+    async fn batch_upsert_kv(
+        &self,
+        _req: BatchUpsertKVReq,
+    ) -> Result<BatchUpsertKVReply, Self::Error> {
+        unreachable!("write operation SM2KVApi::batch_upsert_kv is disabled")
+    }
+
     async fn get_kv(&self, key: &str) -> Result<GetKVReply, Self::Error> {
         let got = self.sm.get_kv(key);
 
@@ -289,6 +299,18 @@ impl SMV002 {
         res
     }
 
+    /// Count the keys and bytes used by every top level key prefix.
+    ///
+    /// Deleted records are not counted.
+    pub fn key_space_stats(&self) -> Vec<KeySpaceStat> {
+        let it = MapApi::<String>::range(&self.top, String::new()..);
+
+        let kvs =
+            it.filter_map(|(k, marked)| marked.unpack().map(|(v, _meta)| (k.as_str(), v.len())));
+
+        KeySpaceStat::aggregate(kvs)
+    }
+
     pub(crate) fn update_expire_cursor(&mut self, log_time_ms: u64) {
         if log_time_ms < self.expire_cursor.time_ms {
             warn!(
@@ -396,18 +418,25 @@ impl SMV002 {
             return (prev.clone(), prev);
         }
 
+        // The expiration by ttl is relative to the time of the log being applied, which is the
+        // expire cursor, so that it is the same on every node.
+        let kv_meta = upsert_kv
+            .value_meta
+            .as_ref()
+            .map(|m| m.to_kv_meta(self.expire_cursor.time_ms));
+
         let (prev, mut result) = match &upsert_kv.value {
-            Operation::Update(v) => self.top.set(
-                upsert_kv.key.clone(),
-                Some((v.clone(), upsert_kv.value_meta.clone())),
-            ),
-            Operation::Delete => self.top.set(upsert_kv.key.clone(), None),
-            Operation::AsIs => self
+            Operation::Update(v) => self
                 .top
-                .update_meta(upsert_kv.key.clone(), upsert_kv.value_meta.clone()),
+                .set(upsert_kv.key.clone(), Some((v.clone(), kv_meta.clone()))),
+            Operation::Delete => self.top.set(upsert_kv.key.clone(), None),
+            Operation::AsIs => self.top.update_meta(upsert_kv.key.clone(), kv_meta.clone()),
         };
 
-        let expire_ms = upsert_kv.get_expire_at_ms().unwrap_or(u64::MAX);
+        let expire_ms = kv_meta
+            .and_then(|m| m.expire_at)
+            .map(|t| t * 1000)
+            .unwrap_or(u64::MAX);
         if expire_ms < self.expire_cursor.time_ms {
             // The record has expired, delete it at once.
             //
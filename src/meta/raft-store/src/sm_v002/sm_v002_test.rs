@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Duration;
+
+use common_meta_types::KVMeta;
 use common_meta_types::SeqV;
 use common_meta_types::UpsertKV;
 use pretty_assertions::assert_eq;
@@ -244,6 +247,32 @@ fn test_inserting_expired_becomes_deleting() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_upsert_with_ttl() -> anyhow::Result<()> {
+    let mut sm = build_sm_with_expire();
+
+    // The ttl is relative to the time of the log being applied.
+    sm.update_expire_cursor(15_000);
+    sm.upsert_kv(UpsertKV::update("d", b"d0").with_ttl(Duration::from_millis(2_500)));
+
+    let got = sm.get_kv("d").unwrap();
+    assert_eq!(
+        got.meta,
+        Some(KVMeta {
+            expire_at: Some(18)
+        })
+    );
+
+    let got = sm.list_expire_index().collect::<Vec<_>>();
+    assert_eq!(got, vec![
+        (&ExpireKey::new(15_000, 4), &s("a")),
+        (&ExpireKey::new(18_000, 5), &s("d")),
+        (&ExpireKey::new(20_000, 3), &s("c")),
+    ]);
+
+    Ok(())
+}
+
 fn s(x: impl ToString) -> String {
     x.to_string()
 }
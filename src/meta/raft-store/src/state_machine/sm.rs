@@ -38,10 +38,10 @@ use common_meta_types::Cmd;
 use common_meta_types::ConditionResult;
 use common_meta_types::Entry;
 use common_meta_types::EntryPayload;
-use common_meta_types::KVMeta;
 use common_meta_types::LogId;
 use common_meta_types::MatchSeq;
 use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaSpec;
 use common_meta_types::Node;
 use common_meta_types::NodeId;
 use common_meta_types::Operation;
@@ -461,6 +461,25 @@ impl StateMachine {
         Ok(Change::new(prev, result).into())
     }
 
+    /// Apply a batch of upserts in one sled transaction, in the order they are given.
+    #[minitrace::trace]
+    fn apply_batch_update_kv_cmd(
+        &self,
+        upsert_kvs: &[UpsertKV],
+        txn_tree: &mut TransactionSledTree,
+        log_time_ms: u64,
+    ) -> Result<AppliedState, MetaStorageError> {
+        let mut changes = Vec::with_capacity(upsert_kvs.len());
+        for upsert_kv in upsert_kvs {
+            match self.apply_update_kv_cmd(upsert_kv, txn_tree, log_time_ms)? {
+                AppliedState::KV(change) => changes.push(change),
+                _ => unreachable!("expect AppliedState::KV"),
+            }
+        }
+
+        Ok(AppliedState::KVs(changes))
+    }
+
     fn return_value_condition_result(
         &self,
         expected: i32,
@@ -474,6 +493,15 @@ impl StateMachine {
             Some(ConditionResult::Ne) => value.data != *target_value,
             Some(ConditionResult::Ge) => value.data >= *target_value,
             Some(ConditionResult::Le) => value.data <= *target_value,
+            Some(ConditionResult::Prefix) => value.data.starts_with(target_value),
+            _ => false,
+        }
+    }
+
+    fn return_exists_condition_result(&self, expected: i32, target: bool, exists: bool) -> bool {
+        match FromPrimitive::from_i32(expected) {
+            Some(ConditionResult::Eq) => exists == target,
+            Some(ConditionResult::Ne) => exists != target,
             _ => false,
         }
     }
@@ -531,6 +559,13 @@ impl StateMachine {
                         return Ok(false);
                     }
                 }
+                txn_condition::Target::Exists(target) => {
+                    return Ok(self.return_exists_condition_result(
+                        cond.expected,
+                        *target,
+                        sv.is_some(),
+                    ));
+                }
             }
         };
 
@@ -584,9 +619,10 @@ impl StateMachine {
     ) -> Result<(), MetaStorageError> {
         let (expired, prev, result) = Self::txn_upsert_kv(
             txn_tree,
-            &UpsertKV::update(&put.key, &put.value).with(KVMeta {
-                expire_at: put.expire_at,
-            }),
+            &UpsertKV::update(&put.key, &put.value).with(MetaSpec::new(
+                put.expire_at,
+                put.ttl_ms.map(Duration::from_millis),
+            )),
             log_time_ms,
         )?;
 
@@ -799,6 +835,10 @@ impl StateMachine {
                 self.apply_update_kv_cmd(upsert_kv, txn_tree, log_time_ms)
             }
 
+            Cmd::BatchUpsertKV(ref upsert_kvs) => {
+                self.apply_batch_update_kv_cmd(upsert_kvs, txn_tree, log_time_ms)
+            }
+
             Cmd::Transaction(txn) => self.apply_txn_cmd(txn, txn_tree, kv_pairs, log_time_ms),
         };
 
@@ -968,17 +1008,21 @@ impl StateMachine {
             return Ok((expired, prev.clone(), prev));
         }
 
+        // The expiration by ttl is relative to the log time, which is the same on every node.
+        let kv_meta = upsert_kv
+            .value_meta
+            .as_ref()
+            .map(|m| m.to_kv_meta(log_time_ms));
+
         let mut new_seq_v = match &upsert_kv.value {
-            Operation::Update(v) => SeqV::with_meta(0, upsert_kv.value_meta.clone(), v.clone()),
+            Operation::Update(v) => SeqV::with_meta(0, kv_meta, v.clone()),
             Operation::Delete => {
                 kvs.remove(&upsert_kv.key)?;
                 return Ok((expired, prev, None));
             }
             Operation::AsIs => match prev {
                 None => return Ok((expired, prev, None)),
-                Some(ref prev_kv_value) => {
-                    prev_kv_value.clone().set_meta(upsert_kv.value_meta.clone())
-                }
+                Some(ref prev_kv_value) => prev_kv_value.clone().set_meta(kv_meta),
             },
         };
 
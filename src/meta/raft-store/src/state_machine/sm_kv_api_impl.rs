@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::BatchUpsertKVReply;
+use common_meta_kvapi::kvapi::BatchUpsertKVReq;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::MGetKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReply;
@@ -55,6 +57,27 @@ impl kvapi::KVApi for StateMachine {
         }
     }
 
+    async fn batch_upsert_kv(
+        &self,
+        req: BatchUpsertKVReq,
+    ) -> Result<BatchUpsertKVReply, Self::Error> {
+        let cmd = Cmd::BatchUpsertKV(req.upserts);
+
+        let res = self.sm_tree.txn(true, |mut txn_sled_tree| {
+            let r = self
+                .apply_cmd(&cmd, &mut txn_sled_tree, None, SeqV::<()>::now_ms())
+                .unwrap();
+            Ok(r)
+        })?;
+
+        match res {
+            AppliedState::KVs(x) => Ok(x),
+            _ => {
+                panic!("expect AppliedState::KVs");
+            }
+        }
+    }
+
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, Self::Error> {
         let cmd = Cmd::Transaction(txn);
 
@@ -27,6 +27,7 @@ use common_meta_types::EntryPayload;
 use common_meta_types::KVMeta;
 use common_meta_types::LogEntry;
 use common_meta_types::MatchSeq;
+use common_meta_types::MetaSpec;
 use common_meta_types::Node;
 use common_meta_types::Operation;
 use common_meta_types::SeqV;
@@ -215,7 +216,7 @@ async fn test_state_machine_apply_non_dup_generic_kv_upsert_get() -> anyhow::Res
                         key: c.key.clone(),
                         seq: c.seq,
                         value: Operation::Update(c.value.clone()),
-                        value_meta: c.value_meta.clone(),
+                        value_meta: c.value_meta.clone().map(MetaSpec::from),
                     }),
                     &mut t,
                     None,
@@ -277,9 +278,7 @@ async fn test_state_machine_apply_non_dup_generic_kv_value_meta() -> anyhow::Res
                     key: key.clone(),
                     seq: MatchSeq::GE(0),
                     value: Operation::AsIs,
-                    value_meta: Some(KVMeta {
-                        expire_at: Some(now + 10),
-                    }),
+                    value_meta: Some(MetaSpec::new_expire(now + 10)),
                 }),
                 &mut t,
                 None,
@@ -304,9 +303,7 @@ async fn test_state_machine_apply_non_dup_generic_kv_value_meta() -> anyhow::Res
                     key: key.clone(),
                     seq: MatchSeq::GE(0),
                     value: Operation::Update(b"value_meta_bar".to_vec()),
-                    value_meta: Some(KVMeta {
-                        expire_at: Some(now + 10),
-                    }),
+                    value_meta: Some(MetaSpec::new_expire(now + 10)),
                 }),
                 &mut t,
                 None,
@@ -323,9 +320,7 @@ async fn test_state_machine_apply_non_dup_generic_kv_value_meta() -> anyhow::Res
                     key: key.clone(),
                     seq: MatchSeq::GE(0),
                     value: Operation::AsIs,
-                    value_meta: Some(KVMeta {
-                        expire_at: Some(now + 20),
-                    }),
+                    value_meta: Some(MetaSpec::new_expire(now + 20)),
                 }),
                 &mut t,
                 None,
@@ -16,6 +16,7 @@ use std::pin::Pin;
 use std::sync::Arc;
 use std::task::Context;
 use std::task::Poll;
+use std::time::Duration;
 
 use common_arrow::arrow_format::flight::data::BasicAuth;
 use common_base::base::tokio::sync::mpsc;
@@ -30,6 +31,7 @@ use common_meta_types::protobuf::Empty;
 use common_meta_types::protobuf::ExportedChunk;
 use common_meta_types::protobuf::HandshakeRequest;
 use common_meta_types::protobuf::HandshakeResponse;
+use common_meta_types::protobuf::KeySpaceStatsReply;
 use common_meta_types::protobuf::MemberListReply;
 use common_meta_types::protobuf::MemberListRequest;
 use common_meta_types::protobuf::RaftReply;
@@ -177,6 +179,10 @@ impl MetaService for MetaServiceImpl {
                     let res = m.upsert_kv(a.clone()).await;
                     RaftReply::from(res)
                 }
+                MetaGrpcReq::BatchUpsertKV(a) => {
+                    let res = m.batch_upsert_kv(a.clone()).await;
+                    RaftReply::from(res)
+                }
                 MetaGrpcReq::GetKV(a) => {
                     let res = m.get_kv(&a.key).await;
                     RaftReply::from(res)
@@ -265,6 +271,51 @@ impl MetaService for MetaServiceImpl {
         Ok(Response::new(Box::pin(s)))
     }
 
+    type ExportSnapshotStream =
+        Pin<Box<dyn Stream<Item = Result<ExportedChunk, tonic::Status>> + Send + Sync + 'static>>;
+
+    // Export a snapshot of the state machine that includes every log applied so far.
+    async fn export_snapshot(
+        &self,
+        request: Request<common_meta_types::protobuf::Empty>,
+    ) -> Result<Response<Self::ExportSnapshotStream>, Status> {
+        self.check_token(request.metadata())?;
+
+        let _guard = RequestInFlight::guard();
+
+        let meta_node = &self.meta_node;
+
+        // Building snapshot must be done by raft, thus trigger it and wait for it to finish.
+        let last_applied = meta_node.raft.metrics().borrow().last_applied;
+        meta_node
+            .raft
+            .trigger()
+            .snapshot()
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+        meta_node
+            .raft
+            .wait(Some(Duration::from_secs(60)))
+            .metrics(
+                |m| m.snapshot >= last_applied,
+                "snapshot including last applied log is built",
+            )
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        let res = meta_node.sto.export_snapshot().await?;
+        info!(
+            "export snapshot including last applied log: {:?}, {} records",
+            last_applied,
+            res.len()
+        );
+
+        let stream = ExportStream { data: res };
+        let s = stream.map(|strings| Ok(ExportedChunk { data: strings }));
+
+        Ok(Response::new(Box::pin(s)))
+    }
+
     type WatchStream =
         Pin<Box<dyn Stream<Item = Result<WatchResponse, tonic::Status>> + Send + Sync + 'static>>;
 
@@ -302,7 +353,18 @@ impl MetaService for MetaServiceImpl {
         let meta_node = &self.meta_node;
         let members = meta_node.get_grpc_advertise_addrs().await;
 
-        let resp = MemberListReply { data: members };
+        let leader_id = meta_node.raft.metrics().borrow().current_leader;
+        let leader = match leader_id {
+            Some(id) => meta_node.get_node(&id).await,
+            None => None,
+        };
+
+        let resp = MemberListReply {
+            data: members,
+            leader: leader
+                .and_then(|n| n.grpc_api_advertise_address)
+                .unwrap_or_default(),
+        };
         network_metrics::incr_sent_bytes(resp.encoded_len() as u64);
 
         Ok(Response::new(resp))
@@ -323,6 +385,25 @@ impl MetaService for MetaServiceImpl {
         }
         Err(Status::unavailable("can not get client ip address"))
     }
+
+    async fn key_space_stats(
+        &self,
+        request: Request<Empty>,
+    ) -> Result<Response<KeySpaceStatsReply>, Status> {
+        self.check_token(request.metadata())?;
+
+        let _guard = RequestInFlight::guard();
+
+        let stats = {
+            let sm = self.meta_node.sto.state_machine.read().await;
+            sm.key_space_stats()
+        };
+
+        let resp = KeySpaceStatsReply { stats };
+        network_metrics::incr_sent_bytes(resp.encoded_len() as u64);
+
+        Ok(Response::new(resp))
+    }
 }
 
 pub struct ExportStream {
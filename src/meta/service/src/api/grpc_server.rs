@@ -28,6 +28,7 @@ use common_meta_types::MetaNetworkError;
 use futures::future::Either;
 use log::info;
 use minitrace::prelude::*;
+use tonic::codec::CompressionEncoding;
 use tonic::transport::Identity;
 use tonic::transport::Server;
 use tonic::transport::ServerTlsConfig;
@@ -94,9 +95,13 @@ impl GrpcServer {
         info!("gRPC addr: {}", addr);
 
         let grpc_impl = MetaServiceImpl::create(meta_node.clone());
+        // Compressed requests are always accepted, and a response is compressed only if the
+        // client claims it accepts compressed responses, thus it is compatible with old clients.
         let grpc_srv = MetaServiceServer::new(grpc_impl)
             .max_decoding_message_size(GrpcConfig::MAX_DECODING_SIZE)
-            .max_encoding_message_size(GrpcConfig::MAX_ENCODING_SIZE);
+            .max_encoding_message_size(GrpcConfig::MAX_ENCODING_SIZE)
+            .accept_compressed(CompressionEncoding::Gzip)
+            .send_compressed(CompressionEncoding::Gzip);
 
         let j = tokio::spawn(
             async move {
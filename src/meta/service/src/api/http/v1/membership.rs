@@ -0,0 +1,116 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Change the membership of the cluster online.
+//!
+//! To replace a node without downtime:
+//! - Add the new node as a learner with `add_learner`, it starts to replicate logs from the leader.
+//! - Promote it to a voter with `promote` once it has caught up, which is retried until then.
+//! - Remove the old node with `remove`.
+//!
+//! The requests are forwarded to the leader if this node is not the leader.
+
+use std::sync::Arc;
+
+use common_meta_types::Endpoint;
+use common_meta_types::MetaAPIError;
+use common_meta_types::NodeId;
+use poem::http::StatusCode;
+use poem::web::Data;
+use poem::web::IntoResponse;
+use poem::web::Json;
+use poem::web::Query;
+
+use crate::message::AddLearnerRequest;
+use crate::message::ForwardRequest;
+use crate::message::ForwardRequestBody;
+use crate::message::LeaveRequest;
+use crate::message::PromoteRequest;
+use crate::meta_service::MetaNode;
+
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct AddLearnerQuery {
+    node_id: NodeId,
+    raft_advertise_host: String,
+    raft_api_port: u32,
+    grpc_api_advertise_address: Option<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct NodeIdQuery {
+    node_id: NodeId,
+}
+
+/// Add a node as a learner, which replicates logs from the leader but does not vote.
+///
+/// The catch-up progress of the learner is in `replication` of `/v1/cluster/status` of the leader.
+#[poem::handler]
+pub async fn add_learner_handler(
+    meta_node: Data<&Arc<MetaNode>>,
+    Query(query): Query<AddLearnerQuery>,
+) -> poem::Result<impl IntoResponse> {
+    let req = AddLearnerRequest {
+        node_id: query.node_id,
+        endpoint: Endpoint::new(query.raft_advertise_host, query.raft_api_port),
+        grpc_api_advertise_address: query.grpc_api_advertise_address,
+    };
+    change_membership(&meta_node, req.into()).await?;
+    Ok(Json(()))
+}
+
+/// Promote a learner to a voter.
+///
+/// It responds with `503 Service Unavailable` if the learner has not yet caught up.
+#[poem::handler]
+pub async fn promote_handler(
+    meta_node: Data<&Arc<MetaNode>>,
+    Query(query): Query<NodeIdQuery>,
+) -> poem::Result<impl IntoResponse> {
+    let req = PromoteRequest {
+        node_id: query.node_id,
+    };
+    change_membership(&meta_node, req.into()).await?;
+    Ok(Json(()))
+}
+
+/// Remove a voter or a learner from the cluster.
+#[poem::handler]
+pub async fn remove_handler(
+    meta_node: Data<&Arc<MetaNode>>,
+    Query(query): Query<NodeIdQuery>,
+) -> poem::Result<impl IntoResponse> {
+    let req = LeaveRequest {
+        node_id: query.node_id,
+    };
+    change_membership(&meta_node, req.into()).await?;
+    Ok(Json(()))
+}
+
+async fn change_membership(meta_node: &MetaNode, body: ForwardRequestBody) -> poem::Result<()> {
+    meta_node
+        .handle_forwardable_request(ForwardRequest {
+            forward_to_leader: 1,
+            body,
+        })
+        .await
+        .map_err(|e: MetaAPIError| {
+            let status = if e.is_retryable() {
+                StatusCode::SERVICE_UNAVAILABLE
+            } else {
+                StatusCode::INTERNAL_SERVER_ERROR
+            };
+            poem::Error::from_string(format!("failed to change membership: {}", e), status)
+        })?;
+    Ok(())
+}
@@ -78,6 +78,18 @@ impl HttpService {
                 "/v1/cluster/status",
                 get(super::http::v1::cluster_state::status_handler),
             )
+            .at(
+                "/v1/cluster/add_learner",
+                get(super::http::v1::membership::add_learner_handler),
+            )
+            .at(
+                "/v1/cluster/promote",
+                get(super::http::v1::membership::promote_handler),
+            )
+            .at(
+                "/v1/cluster/remove",
+                get(super::http::v1::membership::remove_handler),
+            )
             .at(
                 "/v1/metrics",
                 get(super::http::v1::metrics::metrics_handler),
@@ -28,6 +28,8 @@ pub struct Config {
     pub value: String,
     pub expire_after: Option<u64>,
     pub prefix: String,
+    pub bench_clients: u64,
+    pub bench_requests: u64,
     pub username: String,
     pub password: String,
     pub config_file: String,
@@ -51,6 +53,8 @@ impl Default for Config {
             value: "".to_string(),
             expire_after: None,
             prefix: "".to_string(),
+            bench_clients: 10,
+            bench_requests: 10000,
             username: "".to_string(),
             password: "".to_string(),
             config_file: "".to_string(),
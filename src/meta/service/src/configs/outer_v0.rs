@@ -57,6 +57,11 @@ pub struct Config {
     /// -    `--cmd kvapi::mget   --key    foo bar`
     ///
     /// -    `--cmd kvapi::list   --prefix foo/`
+    ///
+    /// - `bench::<workload>`: benchmark the kvapi of a running cluster and print the latency and throughput.
+    ///   The workload is a comma separated list of `upsert`, `get` and `txn`, which are sent in turn:
+    ///
+    /// -    `--cmd bench::upsert,get --bench-clients 10 --bench-requests 10000 --prefix bench/`
     #[clap(long, default_value = "")]
     pub cmd: String,
 
@@ -76,6 +81,14 @@ pub struct Config {
     #[clap(long, default_value = "")]
     pub prefix: String,
 
+    /// The number of concurrent clients. Only used when running with `--cmd bench::*`
+    #[clap(long, default_value = "10")]
+    pub bench_clients: u64,
+
+    /// The number of requests sent by every client. Only used when running with `--cmd bench::*`
+    #[clap(long, default_value = "10000")]
+    pub bench_requests: u64,
+
     #[clap(long, default_value = "root")]
     pub username: String,
 
@@ -150,6 +163,8 @@ impl From<Config> for InnerConfig {
             value: outer.value,
             expire_after: outer.expire_after,
             prefix: outer.prefix,
+            bench_clients: outer.bench_clients,
+            bench_requests: outer.bench_requests,
             username: outer.username,
             password: outer.password,
             config_file: outer.config_file,
@@ -174,6 +189,8 @@ impl From<InnerConfig> for Config {
             value: inner.value,
             expire_after: inner.expire_after,
             prefix: inner.prefix,
+            bench_clients: inner.bench_clients,
+            bench_requests: inner.bench_requests,
             username: inner.username,
             password: inner.password,
             config_file: inner.config_file,
@@ -385,6 +402,8 @@ impl Into<Config> for ConfigViaEnv {
             value: "".to_string(),
             expire_after: None,
             prefix: "".to_string(),
+            bench_clients: 10,
+            bench_requests: 10000,
             username: "".to_string(),
             password: "".to_string(),
             config_file: self.metasrv_config_file,
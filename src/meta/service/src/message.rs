@@ -56,6 +56,20 @@ pub struct LeaveRequest {
     pub node_id: NodeId,
 }
 
+/// Add a node to the cluster as a learner, which receives logs but does not vote.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AddLearnerRequest {
+    pub node_id: NodeId,
+    pub endpoint: Endpoint,
+    pub grpc_api_advertise_address: Option<String>,
+}
+
+/// Promote a learner that has caught up with the leader to a voter.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct PromoteRequest {
+    pub node_id: NodeId,
+}
+
 #[derive(
     serde::Serialize,
     serde::Deserialize,
@@ -71,6 +85,8 @@ pub enum ForwardRequestBody {
 
     Join(JoinRequest),
     Leave(LeaveRequest),
+    AddLearner(AddLearnerRequest),
+    Promote(PromoteRequest),
 
     Write(LogEntry),
 
@@ -104,6 +120,8 @@ pub enum ForwardResponse {
 
     Join(()),
     Leave(()),
+    AddLearner(()),
+    Promote(()),
     AppliedState(AppliedState),
 
     GetKV(GetKVReply),
@@ -22,6 +22,7 @@ use common_meta_stoerr::MetaStorageError;
 use common_meta_types::AppliedState;
 use common_meta_types::ClientWriteError;
 use common_meta_types::Cmd;
+use common_meta_types::LearnerLagging;
 use common_meta_types::LogEntry;
 use common_meta_types::MembershipNode;
 use common_meta_types::MetaDataError;
@@ -38,17 +39,22 @@ use log::info;
 use maplit::btreemap;
 use maplit::btreeset;
 
+use crate::message::AddLearnerRequest;
 use crate::message::ForwardRequest;
 use crate::message::ForwardRequestBody;
 use crate::message::ForwardResponse;
 use crate::message::JoinRequest;
 use crate::message::LeaveRequest;
+use crate::message::PromoteRequest;
 use crate::meta_service::raftmeta::MetaRaft;
 use crate::meta_service::MetaNode;
 use crate::metrics::server_metrics;
 use crate::metrics::ProposalPending;
 use crate::store::RaftStore;
 
+/// A learner is promoted to a voter only if it lags behind the leader by at most this many logs.
+const PROMOTE_MAX_LAG: u64 = 1_000;
+
 /// The container of APIs of the leader in a meta service cluster.
 ///
 /// A leader does not imply it is actually the leader granted by the cluster.
@@ -84,6 +90,14 @@ impl<'a> MetaLeader<'a> {
                 self.leave(leave_req).await?;
                 Ok(ForwardResponse::Leave(()))
             }
+            ForwardRequestBody::AddLearner(add_learner_req) => {
+                self.add_learner(add_learner_req).await?;
+                Ok(ForwardResponse::AddLearner(()))
+            }
+            ForwardRequestBody::Promote(promote_req) => {
+                self.promote(promote_req).await?;
+                Ok(ForwardResponse::Promote(()))
+            }
             ForwardRequestBody::Write(entry) => {
                 let res = self.write(entry.clone()).await?;
                 Ok(ForwardResponse::AppliedState(res))
@@ -147,9 +161,100 @@ impl<'a> MetaLeader<'a> {
         Ok(())
     }
 
+    /// Add a new node to the cluster as a learner.
+    ///
+    /// - Adds the node to cluster persistently.
+    /// - Adds the node to membership as a learner and starts replication.
+    ///
+    /// The leader tracks how far the learner has caught up, as `replication` in the node status.
+    /// Once it has caught up, it can be promoted to a voter with [`Self::promote`].
+    ///
+    /// If the node is already in cluster membership, it still returns Ok.
+    #[minitrace::trace]
+    pub async fn add_learner(
+        &self,
+        req: AddLearnerRequest,
+    ) -> Result<(), RaftError<ClientWriteError>> {
+        let node_id = req.node_id;
+        let metrics = self.raft.metrics().borrow().clone();
+        let membership = metrics.membership_config.membership();
+
+        if membership
+            .voter_ids()
+            .chain(membership.learner_ids())
+            .any(|id| id == node_id)
+        {
+            return Ok(());
+        }
+
+        let ent = LogEntry {
+            txid: None,
+            time_ms: None,
+            cmd: Cmd::AddNode {
+                node_id,
+                node: Node::new(node_id, req.endpoint)
+                    .with_grpc_advertise_address(req.grpc_api_advertise_address),
+                overriding: false,
+            },
+        };
+        self.write(ent).await?;
+
+        self.raft
+            .change_membership(
+                ChangeMembers::AddNodes(btreemap! {node_id=>MembershipNode{}}),
+                false,
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Promote a learner to a voter.
+    ///
+    /// It is rejected with [`LearnerLagging`] if the learner has not yet caught up with the leader,
+    /// the caller should retry later.
+    ///
+    /// If the node is already a voter, it still returns Ok.
+    #[minitrace::trace]
+    pub async fn promote(&self, req: PromoteRequest) -> Result<(), MetaOperationError> {
+        let node_id = req.node_id;
+        let metrics = self.raft.metrics().borrow().clone();
+        let membership = metrics.membership_config.membership();
+
+        if membership.voter_ids().any(|id| id == node_id) {
+            return Ok(());
+        }
+
+        // A node that is not a learner is rejected by raft.
+        if membership.learner_ids().any(|id| id == node_id) {
+            let matched = metrics
+                .replication
+                .as_ref()
+                .and_then(|r| r.get(&node_id).cloned())
+                .flatten()
+                .map(|log_id| log_id.index);
+            let last_log_index = metrics.last_log_index.unwrap_or_default();
+
+            let caught_up = matched.map_or(false, |m| m + PROMOTE_MAX_LAG >= last_log_index);
+            if !caught_up {
+                let lagging = LearnerLagging {
+                    node_id,
+                    matched,
+                    last_log_index,
+                };
+                info!("can not promote: {}", lagging);
+                return Err(MetaDataError::from(lagging).into());
+            }
+        }
+
+        self.raft
+            .change_membership(ChangeMembers::AddVoterIds(btreeset! {node_id}), false)
+            .await?;
+        Ok(())
+    }
+
     /// A node leave the cluster.
     ///
-    /// - Remove the node from membership.
+    /// - Remove the node from membership, either it is a voter or a learner.
     /// - Stop replication.
     /// - Remove the node from cluster.
     ///
@@ -169,9 +274,18 @@ impl<'a> MetaLeader<'a> {
         }
 
         // 1. Remove it from membership if needed.
-        self.raft
-            .change_membership(ChangeMembers::RemoveVoters(btreeset! {node_id}), false)
-            .await?;
+        let is_learner = {
+            let metrics = self.raft.metrics().borrow().clone();
+            let membership = metrics.membership_config.membership();
+            membership.learner_ids().any(|id| id == node_id)
+        };
+
+        let changes = if is_learner {
+            ChangeMembers::RemoveNodes(btreeset! {node_id})
+        } else {
+            ChangeMembers::RemoveVoters(btreeset! {node_id})
+        };
+        self.raft.change_membership(changes, false).await?;
 
         // 2. Remove node info
         let ent = LogEntry {
@@ -14,6 +14,8 @@
 
 use async_trait::async_trait;
 use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::BatchUpsertKVReply;
+use common_meta_kvapi::kvapi::BatchUpsertKVReq;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::GetKVReq;
 use common_meta_kvapi::kvapi::ListKVReply;
@@ -59,6 +61,22 @@ impl kvapi::KVApi for MetaNode {
         }
     }
 
+    #[minitrace::trace]
+    async fn batch_upsert_kv(
+        &self,
+        req: BatchUpsertKVReq,
+    ) -> Result<BatchUpsertKVReply, Self::Error> {
+        let ent = LogEntry::new(Cmd::BatchUpsertKV(req.upserts));
+        let rst = self.write(ent).await?;
+
+        match rst {
+            AppliedState::KVs(x) => Ok(x),
+            _ => {
+                unreachable!("expect type {}", "AppliedState::KVs")
+            }
+        }
+    }
+
     #[minitrace::trace]
     async fn get_kv(&self, key: &str) -> Result<GetKVReply, Self::Error> {
         let res = self
@@ -15,10 +15,12 @@
 pub use meta_service_impl::RaftServiceImpl;
 pub use raftmeta::MetaNode;
 
+pub use crate::message::AddLearnerRequest;
 pub use crate::message::ForwardRequest;
 pub use crate::message::ForwardRequestBody;
 pub use crate::message::JoinRequest;
 pub use crate::message::LeaveRequest;
+pub use crate::message::PromoteRequest;
 
 mod errors;
 pub mod meta_leader;
@@ -391,20 +391,7 @@ impl StoreInner {
         let mut res = vec![];
 
         // Export data header first
-        {
-            let header_tree = SledTree::open(&self.db, TREE_HEADER, false)
-                .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
-
-            let header_kvs = header_tree
-                .export()
-                .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
-
-            for kv in header_kvs.iter() {
-                let line = vec_kv_to_json(TREE_HEADER, kv)
-                    .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
-                res.push(line);
-            }
-        }
+        self.export_header(&mut res)?;
 
         // Export RaftState
         //
@@ -501,40 +488,81 @@ impl StoreInner {
         }
 
         // Export snapshot of state machine
-        {
-            // NOTE:
-            // This name had been used by the sled tree based sm.
-            // Do not change it for keeping compatibility.
-            let tree_name = "state_machine/0";
+        if let Some(s) = current_snapshot.as_ref() {
+            self.export_snapshot_data(s, &mut res).await?;
+        }
+
+        Ok(res)
+    }
+
+    /// Export the data header and the current snapshot of the state machine.
+    ///
+    /// Unlike [`Self::export`], raft state and logs are not exported. The exported data is
+    /// enough to restore the state machine in a new cluster.
+    pub async fn export_snapshot(&self) -> Result<Vec<String>, io::Error> {
+        // Hold the snapshot lock to prevent snapshot from being replaced until exporting finished.
+        let current_snapshot = self.current_snapshot.read().await;
 
-            let snapshot = current_snapshot.clone();
-            if let Some(s) = snapshot {
-                let meta = s.meta;
+        let snapshot = current_snapshot
+            .as_ref()
+            .ok_or_else(|| io::Error::new(ErrorKind::NotFound, "no snapshot is built"))?;
 
-                let snapshot_store = SnapshotStoreV002::new(DATA_VERSION, self.config.clone());
+        let mut res = vec![];
+        self.export_header(&mut res)?;
+        self.export_snapshot_data(snapshot, &mut res).await?;
 
-                let f = snapshot_store
-                    .load_snapshot(&meta.snapshot_id)
-                    .await
-                    .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
-                let bf = BufReader::new(f);
-                let mut lines = AsyncBufReadExt::lines(bf);
+        Ok(res)
+    }
 
-                while let Some(l) = lines.next_line().await? {
-                    let ent: RaftStoreEntry = serde_json::from_str(&l)
-                        .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+    fn export_header(&self, res: &mut Vec<String>) -> Result<(), io::Error> {
+        let header_tree = SledTree::open(&self.db, TREE_HEADER, false)
+            .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
 
-                    let named_entry = (tree_name, ent);
+        let header_kvs = header_tree
+            .export()
+            .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
 
-                    let l = serde_json::to_string(&named_entry)
-                        .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+        for kv in header_kvs.iter() {
+            let line = vec_kv_to_json(TREE_HEADER, kv)
+                .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+            res.push(line);
+        }
 
-                    res.push(l);
-                }
-            }
+        Ok(())
+    }
+
+    async fn export_snapshot_data(
+        &self,
+        snapshot: &StoredSnapshot,
+        res: &mut Vec<String>,
+    ) -> Result<(), io::Error> {
+        // NOTE:
+        // This name had been used by the sled tree based sm.
+        // Do not change it for keeping compatibility.
+        let tree_name = "state_machine/0";
+
+        let snapshot_store = SnapshotStoreV002::new(DATA_VERSION, self.config.clone());
+
+        let f = snapshot_store
+            .load_snapshot(&snapshot.meta.snapshot_id)
+            .await
+            .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+        let bf = BufReader::new(f);
+        let mut lines = AsyncBufReadExt::lines(bf);
+
+        while let Some(l) = lines.next_line().await? {
+            let ent: RaftStoreEntry =
+                serde_json::from_str(&l).map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+
+            let named_entry = (tree_name, ent);
+
+            let l = serde_json::to_string(&named_entry)
+                .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+
+            res.push(l);
         }
 
-        Ok(res)
+        Ok(())
     }
 
     pub async fn get_node(&self, node_id: &NodeId) -> Option<Node> {
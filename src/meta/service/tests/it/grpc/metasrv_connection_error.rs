@@ -19,6 +19,7 @@ use std::fmt::Display;
 use std::sync::Arc;
 use std::time::Duration;
 
+use common_base::base::tokio;
 use common_base::base::Stoppable;
 use common_meta_client::ClientHandle;
 use common_meta_client::MetaGrpcClient;
@@ -144,6 +145,54 @@ async fn test_metasrv_one_client_leader_down() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Ensure grpc-client learns all nodes from the only configured one, and fails over to them.
+///
+/// - Start a cluster of 3.
+/// - Create a client to node 0 only, without auto sync.
+/// - Wait for the client to learn all nodes.
+/// - Shutdown node 0.
+/// - Test upsert kv, expect the client to auto choose the learned nodes.
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_metasrv_client_failover_to_synced_endpoints() -> anyhow::Result<()> {
+    info!("--- Start cluster 0,1,2");
+    let mut tcs = start_metasrv_cluster(&[0, 1, 2]).await?;
+
+    let a0 = tcs[0].config.grpc_api_address.clone();
+
+    let client = MetaGrpcClient::try_create(
+        vec![a0],
+        "root",
+        "xxx",
+        None,
+        None,
+        Duration::from_secs(10),
+        None,
+    )?;
+
+    info!("--- wait for the client to learn all nodes");
+    {
+        let mut endpoints = vec![];
+        for _ in 0..10 {
+            endpoints = client.get_cached_endpoints().await?;
+            if endpoints.len() == 3 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(500)).await;
+        }
+        assert_eq!(3, endpoints.len(), "all nodes are learned: {:?}", endpoints);
+    }
+
+    test_write_read(&client, "failover-node-0-running").await?;
+
+    let mut stopped = tcs.remove(0);
+    { stopped }.grpc_srv.take().unwrap().stop(None).await?;
+
+    test_write_read(&client, "failover-node-0-down").await?;
+
+    Ok(())
+}
+
 fn make_client(addresses: Vec<String>) -> Result<Arc<ClientHandle>, MetaClientError> {
     let client = MetaGrpcClient::try_create(
         addresses, // a1() will be shut down
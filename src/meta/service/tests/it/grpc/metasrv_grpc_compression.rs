@@ -0,0 +1,64 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_grpc::RpcClientConf;
+use common_meta_client::MetaGrpcClient;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use futures::future::try_join_all;
+use test_harness::test;
+
+use crate::testing::meta_service_test_harness;
+
+/// - Start a metasrv.
+/// - Send many concurrent requests with a compressing client.
+/// - Read them back.
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_compressed_pipelined_requests() -> anyhow::Result<()> {
+    let (_tc, addr) = crate::tests::start_metasrv().await?;
+
+    let client = MetaGrpcClient::try_new(&RpcClientConf {
+        endpoints: vec![addr],
+        username: "root".to_string(),
+        password: "xxx".to_string(),
+        timeout: Some(Duration::from_secs(10)),
+        unhealth_endpoint_evict_time: Duration::from_secs(10),
+        compression: true,
+        ..Default::default()
+    })?;
+
+    let keys = (0..200)
+        .map(|i| format!("compression-{}", i))
+        .collect::<Vec<_>>();
+
+    try_join_all(keys.iter().map(|k| {
+        let client = client.clone();
+        async move {
+            client
+                .upsert_kv(UpsertKVReq::update(k, &k.repeat(100).into_bytes()))
+                .await
+        }
+    }))
+    .await?;
+
+    let got = try_join_all(keys.iter().map(|k| client.get_kv(k))).await?;
+    for (k, v) in keys.iter().zip(got) {
+        assert_eq!(k.repeat(100).into_bytes(), v.unwrap().data);
+    }
+
+    Ok(())
+}
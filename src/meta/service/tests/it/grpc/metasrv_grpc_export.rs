@@ -122,3 +122,64 @@ async fn test_export() -> anyhow::Result<()> {
 fn b(s: impl ToString) -> Vec<u8> {
     s.to_string().into_bytes()
 }
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_export_snapshot() -> anyhow::Result<()> {
+    // - Start a metasrv server.
+    // - Write some data
+    // - Export a snapshot, which is built on demand and includes every applied log.
+
+    let (tc, _addr) = crate::tests::start_metasrv().await?;
+
+    let client = tc.grpc_client().await?;
+
+    info!("--- upsert kv");
+    {
+        for k in ["foo", "bar", "wow"] {
+            client.upsert_kv(UpsertKVReq::update(k, &b(k))).await?;
+        }
+    }
+
+    let mut grpc_client = client.make_client().await?;
+
+    let exported = grpc_client
+        .export_snapshot(tonic::Request::new(Empty {}))
+        .await?;
+
+    let mut stream = exported.into_inner();
+
+    let mut lines = vec![];
+    while let Some(chunk_res) = stream.next().await {
+        let chunk = chunk_res?;
+
+        lines.extend_from_slice(&chunk.data);
+    }
+
+    let want = vec![
+        r#"["state_machine/0",{"DataHeader":{"key":"header","value":{"version":"V002","upgrading":null}}}]"#,
+        r#"["state_machine/0",{"StateMachineMeta":{"key":"LastApplied","value":{"LogId":{"leader_id":{"term":1,"node_id":0},"index":6}}}}]"#,
+        r#"["state_machine/0",{"StateMachineMeta":{"key":"LastMembership","value":{"Membership":{"log_id":{"leader_id":{"term":1,"node_id":0},"index":3},"membership":{"configs":[[0]],"nodes":{"0":{}}}}}}}]"#,
+        r#"["state_machine/0",{"Sequences":{"key":"generic-kv","value":3}}]"#,
+        r#"["state_machine/0",{"Nodes":{"key":0,"value":{"name":"0","endpoint":{"addr":"localhost","port":29000},"grpc_api_advertise_address":"127.0.0.1:29000"}}}]"#,
+        r#"["state_machine/0",{"GenericKV":{"key":"bar","value":{"seq":2,"meta":null,"data":[98,97,114]}}}]"#,
+        r#"["state_machine/0",{"GenericKV":{"key":"foo","value":{"seq":1,"meta":null,"data":[102,111,111]}}}]"#,
+        r#"["state_machine/0",{"GenericKV":{"key":"wow","value":{"seq":3,"meta":null,"data":[119,111,119]}}}]"#,
+    ];
+
+    // The addresses are built from random number.
+    // Wash them.
+    let lines = lines
+        .iter()
+        .map(|x| {
+            Regex::new(r"29\d\d\d")
+                .unwrap()
+                .replace_all(x, "29000")
+                .to_string()
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(want, lines);
+
+    Ok(())
+}
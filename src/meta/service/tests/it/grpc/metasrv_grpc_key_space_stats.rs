@@ -0,0 +1,69 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::KeySpaceStat;
+use log::info;
+use pretty_assertions::assert_eq;
+use test_harness::test;
+
+use crate::testing::meta_service_test_harness;
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_key_space_stats() -> anyhow::Result<()> {
+    // - Start a metasrv server.
+    // - Write some data with different prefixes.
+    // - Check keys and bytes grouped by prefix.
+
+    let (tc, _addr) = crate::tests::start_metasrv().await?;
+
+    let client = tc.grpc_client().await?;
+
+    info!("--- upsert kv");
+    {
+        for k in ["a/1", "a/22", "b/x"] {
+            client.upsert_kv(UpsertKVReq::update(k, b"xx")).await?;
+        }
+        client.upsert_kv(UpsertKVReq::delete("a/1")).await?;
+    }
+
+    info!("--- get key space stats");
+    {
+        let stats = client.get_key_space_stats().await?;
+        let stats = stats
+            .into_iter()
+            .filter(|s| s.prefix == "a" || s.prefix == "b")
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                KeySpaceStat {
+                    prefix: "a".to_string(),
+                    keys: 1,
+                    bytes: 6,
+                },
+                KeySpaceStat {
+                    prefix: "b".to_string(),
+                    keys: 1,
+                    bytes: 5,
+                },
+            ],
+            stats
+        );
+    }
+
+    Ok(())
+}
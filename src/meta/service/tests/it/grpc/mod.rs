@@ -14,9 +14,11 @@
 
 pub mod metasrv_connection_error;
 pub mod metasrv_grpc_api;
+pub mod metasrv_grpc_compression;
 mod metasrv_grpc_export;
 pub mod metasrv_grpc_get_client_info;
 pub mod metasrv_grpc_handshake;
+pub mod metasrv_grpc_key_space_stats;
 pub mod metasrv_grpc_kv_api;
 pub mod metasrv_grpc_kv_api_restart_cluster;
 pub mod metasrv_grpc_schema_api;
@@ -27,10 +27,12 @@ use common_meta_types::LogEntry;
 use common_meta_types::NodeId;
 use common_meta_types::UpsertKV;
 use databend_meta::configs;
+use databend_meta::message::AddLearnerRequest;
 use databend_meta::message::ForwardRequest;
 use databend_meta::message::ForwardRequestBody;
 use databend_meta::message::JoinRequest;
 use databend_meta::message::LeaveRequest;
+use databend_meta::message::PromoteRequest;
 use databend_meta::meta_service::MetaNode;
 use log::info;
 use maplit::btreeset;
@@ -487,6 +489,119 @@ async fn test_meta_node_leave() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_meta_node_replace_by_learner() -> anyhow::Result<()> {
+    // - Bring up a cluster of 2 voters.
+    // - Add node-2 as a learner and wait for it to catch up.
+    // - Promoting a node that is not a learner is rejected.
+    // - Promote node-2 to a voter.
+    // - Remove node-1.
+
+    let (mut log_index, tcs) = start_meta_node_cluster(btreeset![0, 1], btreeset![]).await?;
+    let all = test_context_nodes(&tcs);
+
+    let leader = all[0].clone();
+
+    info!("--- bring up node-2");
+
+    let tc2 = MetaSrvTestContext::new(2);
+    let mn2 = MetaNode::open_create(&tc2.config.raft_config, None, Some(())).await?;
+
+    info!("--- add node-2 as a learner");
+    {
+        let req = ForwardRequest {
+            forward_to_leader: 0,
+            body: ForwardRequestBody::AddLearner(AddLearnerRequest {
+                node_id: 2,
+                endpoint: tc2.config.raft_config.raft_api_addr().await?,
+                grpc_api_advertise_address: tc2.config.grpc_api_advertise_address(),
+            }),
+        };
+
+        leader.handle_forwardable_request(req).await?;
+        // Add node, change membership
+        log_index += 2;
+
+        mn2.raft
+            .wait(timeout())
+            .log(Some(log_index), "node-2 caught up")
+            .await?;
+
+        let metrics = leader.raft.metrics().borrow().clone();
+        let membership = metrics.membership_config.membership();
+        assert_eq!(
+            vec![2],
+            membership.learner_ids().collect::<Vec<_>>(),
+            "node-2 is a learner"
+        );
+    }
+
+    info!("--- promote node-5 that is not a learner");
+    {
+        let req = ForwardRequest {
+            forward_to_leader: 0,
+            body: ForwardRequestBody::Promote(PromoteRequest { node_id: 5 }),
+        };
+
+        let res = leader.handle_forwardable_request(req).await;
+        assert!(res.is_err(), "node-5 is not a learner");
+    }
+
+    info!("--- promote node-2");
+    {
+        let req = ForwardRequest {
+            forward_to_leader: 0,
+            body: ForwardRequestBody::Promote(PromoteRequest { node_id: 2 }),
+        };
+
+        leader.handle_forwardable_request(req).await?;
+        // Joint and uniform membership
+        log_index += 2;
+
+        for mn in [&leader, &mn2] {
+            mn.raft
+                .wait(timeout())
+                .members(
+                    btreeset! {0,1,2},
+                    format!("node-2 is promoted: {}", mn.sto.id),
+                )
+                .await?;
+        }
+    }
+
+    info!("--- remove node-1");
+    {
+        let req = ForwardRequest {
+            forward_to_leader: 0,
+            body: ForwardRequestBody::Leave(LeaveRequest { node_id: 1 }),
+        };
+
+        leader.handle_forwardable_request(req).await?;
+        // Change membership, remove node
+        log_index += 3;
+
+        for mn in [&leader, &mn2] {
+            mn.raft
+                .wait(timeout())
+                .log(Some(log_index), format!("node-1 removed: {}", mn.sto.id))
+                .await?;
+            mn.raft
+                .wait(timeout())
+                .members(btreeset! {0,2}, format!("node-1 removed: {}", mn.sto.id))
+                .await?;
+        }
+
+        let nodes = leader.get_nodes().await;
+        assert_eq!(
+            vec!["0", "2"],
+            nodes.iter().map(|x| x.name.clone()).collect::<Vec<_>>()
+        );
+    }
+
+    Ok(())
+}
+
 #[test(harness = meta_service_test_harness)]
 #[minitrace::trace]
 async fn test_meta_node_leave_last_not_allowed() -> anyhow::Result<()> {
@@ -24,6 +24,8 @@ use common_meta_client::ClientHandle;
 use common_meta_client::MetaGrpcClient;
 use common_meta_embedded::MetaEmbedded;
 use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::BatchUpsertKVReply;
+use common_meta_kvapi::kvapi::BatchUpsertKVReq;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::MGetKVReply;
@@ -31,6 +33,7 @@ use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::protobuf::WatchResponse;
+use common_meta_types::KeySpaceStat;
 use common_meta_types::MetaError;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
@@ -75,6 +78,18 @@ impl MetaStore {
         }
     }
 
+    /// Returns the number of keys and bytes used by every top level key prefix.
+    pub async fn key_space_stats(&self) -> Result<Vec<KeySpaceStat>, MetaError> {
+        match self {
+            MetaStore::L(x) => {
+                let kvs = kvapi::KVApi::prefix_list_kv(x.as_ref(), "").await?;
+                let it = kvs.iter().map(|(k, v)| (k.as_str(), v.data.len()));
+                Ok(KeySpaceStat::aggregate(it))
+            }
+            MetaStore::R(grpc_client) => grpc_client.get_key_space_stats().await,
+        }
+    }
+
     pub async fn watch(&self, request: WatchRequest) -> Result<WatchStream, MetaError> {
         match self {
             MetaStore::L(_) => unreachable!(),
@@ -97,6 +112,16 @@ impl kvapi::KVApi for MetaStore {
         }
     }
 
+    async fn batch_upsert_kv(
+        &self,
+        req: BatchUpsertKVReq,
+    ) -> Result<BatchUpsertKVReply, MetaError> {
+        match self {
+            MetaStore::L(x) => x.batch_upsert_kv(req).await,
+            MetaStore::R(x) => x.batch_upsert_kv(req).await,
+        }
+    }
+
     async fn get_kv(&self, key: &str) -> Result<GetKVReply, MetaError> {
         match self {
             MetaStore::L(x) => x.get_kv(key).await,
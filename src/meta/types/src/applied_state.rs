@@ -40,6 +40,9 @@ pub enum AppliedState {
 
     KV(Change<Vec<u8>>),
 
+    /// The changes of a batch of upserts, in the order of the upserts.
+    KVs(Vec<Change<Vec<u8>>>),
+
     TxnReply(TxnReply),
 
     #[try_into(ignore)]
@@ -56,6 +59,16 @@ impl fmt::Display for AppliedState {
             AppliedState::KV(change) => {
                 write!(f, "KV: {}", change)
             }
+            AppliedState::KVs(changes) => {
+                write!(f, "KVs: [")?;
+                for (i, change) in changes.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", change)?;
+                }
+                write!(f, "]")
+            }
             AppliedState::TxnReply(txnreply) => {
                 write!(f, "Txn: {}", txnreply)
             }
@@ -75,6 +88,7 @@ impl AppliedState {
                 ref result,
             } => prev != result,
             AppliedState::KV(ref ch) => ch.is_changed(),
+            AppliedState::KVs(ref chs) => chs.iter().any(|ch| ch.is_changed()),
             AppliedState::None => false,
             AppliedState::TxnReply(txn) => txn.success,
         }
@@ -100,6 +114,7 @@ impl AppliedState {
         match self {
             AppliedState::Node { ref prev, .. } => prev.is_none(),
             AppliedState::KV(Change { ref prev, .. }) => prev.is_none(),
+            AppliedState::KVs(ref chs) => chs.iter().all(|ch| ch.prev.is_none()),
             AppliedState::None => true,
             AppliedState::TxnReply(_txn) => true,
         }
@@ -109,6 +124,7 @@ impl AppliedState {
         match self {
             AppliedState::Node { ref result, .. } => result.is_none(),
             AppliedState::KV(Change { ref result, .. }) => result.is_none(),
+            AppliedState::KVs(ref chs) => chs.iter().all(|ch| ch.result.is_none()),
             AppliedState::None => true,
             AppliedState::TxnReply(txn) => !txn.success,
         }
@@ -79,6 +79,19 @@ pub struct NodeInfo {
     pub version: u32,
     pub flight_address: String,
     pub binary_version: String,
+    /// The load of the node, refreshed by the heartbeat.
+    pub load: NodeLoad,
+}
+
+/// Load of a query node, used by autoscalers and proxies to route sessions.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Default)]
+#[serde(default)]
+pub struct NodeLoad {
+    pub running_queries: u64,
+    /// The running queries waiting for cpus, as the cpus are taken by the queries started before.
+    pub queued_queries: u64,
+    /// Threads required by the running queries in percentage of the cpus, may exceed 100.
+    pub cpu_saturation: u64,
 }
 
 impl NodeInfo {
@@ -94,6 +107,7 @@ impl NodeInfo {
             version: 0,
             flight_address,
             binary_version,
+            load: NodeLoad::default(),
         }
     }
 
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::fmt;
+use std::time::Duration;
 
 use serde::Deserialize;
 use serde::Serialize;
@@ -20,6 +21,7 @@ use serde::Serialize;
 use crate::with::With;
 use crate::KVMeta;
 use crate::MatchSeq;
+use crate::MetaSpec;
 use crate::Node;
 use crate::NodeId;
 use crate::Operation;
@@ -44,6 +46,9 @@ pub enum Cmd {
     /// Update or insert a general purpose kv store
     UpsertKV(UpsertKV),
 
+    /// Update or insert several kv atomically, each upsert is applied as a single `UpsertKV`.
+    BatchUpsertKV(Vec<UpsertKV>),
+
     /// Update one or more kv with a transaction.
     Transaction(TxnRequest),
 }
@@ -63,7 +68,7 @@ pub struct UpsertKV {
     pub value: Operation<Vec<u8>>,
 
     /// Meta data of a value.
-    pub value_meta: Option<KVMeta>,
+    pub value_meta: Option<MetaSpec>,
 }
 
 impl fmt::Display for Cmd {
@@ -86,6 +91,16 @@ impl fmt::Display for Cmd {
             Cmd::UpsertKV(upsert_kv) => {
                 write!(f, "upsert_kv:{}", upsert_kv)
             }
+            Cmd::BatchUpsertKV(upsert_kvs) => {
+                write!(f, "batch_upsert_kv:[")?;
+                for (i, upsert_kv) in upsert_kvs.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", upsert_kv)?;
+                }
+                write!(f, "]")
+            }
             Cmd::Transaction(txn) => {
                 write!(f, "txn:{}", txn)
             }
@@ -108,7 +123,7 @@ impl UpsertKV {
         key: &str,
         seq: MatchSeq,
         value: Operation<Vec<u8>>,
-        value_meta: Option<KVMeta>,
+        value_meta: Option<MetaSpec>,
     ) -> Self {
         Self {
             key: key.to_string(),
@@ -146,17 +161,12 @@ impl UpsertKV {
     }
 
     pub fn with_expire_sec(self, expire_at_sec: u64) -> Self {
-        self.with(KVMeta {
-            expire_at: Some(expire_at_sec),
-        })
+        self.with(MetaSpec::new_expire(expire_at_sec))
     }
 
-    pub fn get_expire_at_ms(&self) -> Option<u64> {
-        if let Some(meta) = &self.value_meta {
-            meta.expire_at.map(|x| x * 1000)
-        } else {
-            None
-        }
+    /// Set the record to expire after `ttl` since the upsert is applied.
+    pub fn with_ttl(self, ttl: Duration) -> Self {
+        self.with(MetaSpec::new_ttl(ttl))
     }
 }
 
@@ -167,9 +177,15 @@ impl With<MatchSeq> for UpsertKV {
     }
 }
 
-impl With<KVMeta> for UpsertKV {
-    fn with(mut self, meta: KVMeta) -> Self {
+impl With<MetaSpec> for UpsertKV {
+    fn with(mut self, meta: MetaSpec) -> Self {
         self.value_meta = Some(meta);
         self
     }
 }
+
+impl With<KVMeta> for UpsertKV {
+    fn with(self, meta: KVMeta) -> Self {
+        self.with(MetaSpec::from(meta))
+    }
+}
@@ -22,6 +22,7 @@ use crate::raft_types::ForwardToLeader;
 use crate::ClientWriteError;
 use crate::InvalidReply;
 use crate::MetaNetworkError;
+use crate::NodeId;
 use crate::RaftError;
 
 /// Errors raised when meta-service handling a request.
@@ -66,6 +67,10 @@ impl MetaAPIError {
                     ChangeMembershipError::EmptyMembership(_) => false,
                     ChangeMembershipError::LearnerNotFound(_) => false,
                 },
+                MetaDataError::LearnerLagging(_) => {
+                    // The learner is catching up, wait a while and retry
+                    true
+                }
                 MetaDataError::WriteError(_) => false,
                 MetaDataError::ReadError(_) => false,
             },
@@ -80,6 +85,7 @@ impl MetaAPIError {
             MetaAPIError::DataError(data_err) => match data_err {
                 MetaDataError::WriteError(_) => false,
                 MetaDataError::ChangeMembershipError(_) => true,
+                MetaDataError::LearnerLagging(_) => true,
                 MetaDataError::ReadError(_) => false,
             },
         }
@@ -117,6 +123,10 @@ pub enum MetaDataError {
     #[error(transparent)]
     ChangeMembershipError(#[from] ChangeMembershipError),
 
+    /// A learner is not yet allowed to become a voter.
+    #[error(transparent)]
+    LearnerLagging(#[from] LearnerLagging),
+
     /// Error occurred when reading.
     #[error(transparent)]
     ReadError(#[from] MetaDataReadError),
@@ -146,6 +156,17 @@ impl MetaDataReadError {
     }
 }
 
+/// A learner can not be promoted to a voter until it has caught up with the leader.
+///
+/// Otherwise the cluster may be unable to commit until the new voter catches up.
+#[derive(thiserror::Error, serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+#[error("learner {node_id} is lagging: matched log index: {matched:?}, leader last log index: {last_log_index}")]
+pub struct LearnerLagging {
+    pub node_id: NodeId,
+    pub matched: Option<u64>,
+    pub last_log_index: u64,
+}
+
 impl From<MetaDataReadError> for MetaOperationError {
     fn from(e: MetaDataReadError) -> Self {
         let de = MetaDataError::from(e);
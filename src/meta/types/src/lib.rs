@@ -29,6 +29,7 @@ mod grpc_config;
 mod log_entry;
 mod match_seq;
 mod message;
+mod meta_spec;
 mod operation;
 mod raft_snapshot_data;
 mod raft_txid;
@@ -55,9 +56,11 @@ pub use applied_state::AppliedState;
 pub use change::Change;
 pub use cluster::Node;
 pub use cluster::NodeInfo;
+pub use cluster::NodeLoad;
 pub use cmd::Cmd;
 pub use cmd::UpsertKV;
 pub use endpoint::Endpoint;
+pub use errors::meta_api_errors::LearnerLagging;
 pub use errors::meta_api_errors::MetaAPIError;
 pub use errors::meta_api_errors::MetaDataError;
 pub use errors::meta_api_errors::MetaDataReadError;
@@ -77,12 +80,14 @@ pub use grpc_config::GrpcConfig;
 pub use log_entry::LogEntry;
 pub use match_seq::MatchSeq;
 pub use match_seq::MatchSeqExt;
+pub use meta_spec::MetaSpec;
 pub use operation::MetaId;
 pub use operation::Operation;
 pub use protobuf::txn_condition;
 pub use protobuf::txn_condition::ConditionResult;
 pub use protobuf::txn_op;
 pub use protobuf::txn_op_response;
+pub use protobuf::KeySpaceStat;
 pub use protobuf::TxnCondition;
 pub use protobuf::TxnDeleteByPrefixRequest;
 pub use protobuf::TxnDeleteByPrefixResponse;
@@ -144,6 +149,32 @@ pub use crate::raft_types::VoteResponse;
 // pub use crate::raft_types::RaftChangeMembershipError;
 // pub use crate::raft_types::RaftWriteError;
 
+impl KeySpaceStat {
+    /// Build per-prefix statistics from an iterator of `(key, value_size)`.
+    ///
+    /// The prefix of a key is the segment before the first `/`.
+    /// The returned stats are sorted by prefix.
+    pub fn aggregate<'a>(kvs: impl IntoIterator<Item = (&'a str, usize)>) -> Vec<Self> {
+        let mut stats = std::collections::BTreeMap::<&str, (u64, u64)>::new();
+
+        for (key, value_size) in kvs {
+            let prefix = key.split('/').next().unwrap_or_default();
+            let ent = stats.entry(prefix).or_default();
+            ent.0 += 1;
+            ent.1 += (key.len() + value_size) as u64;
+        }
+
+        stats
+            .into_iter()
+            .map(|(prefix, (keys, bytes))| Self {
+                prefix: prefix.to_string(),
+                keys,
+                bytes,
+            })
+            .collect()
+    }
+}
+
 impl TxnCondition {
     /// Create a txn condition that checks if the `seq` matches.
     pub fn eq_seq(key: impl ToString, seq: u64) -> Self {
@@ -153,6 +184,46 @@ impl TxnCondition {
             target: Some(txn_condition::Target::Seq(seq)),
         }
     }
+
+    /// Create a txn condition that compares the `seq` with `op`.
+    ///
+    /// The `seq` of an absent record is 0.
+    pub fn match_seq(key: impl ToString, op: ConditionResult, seq: u64) -> Self {
+        Self {
+            key: key.to_string(),
+            expected: op as i32,
+            target: Some(txn_condition::Target::Seq(seq)),
+        }
+    }
+
+    /// Create a txn condition that checks if the value starts with `prefix`.
+    ///
+    /// It is false if the record does not exist.
+    pub fn match_value_prefix(key: impl ToString, prefix: Vec<u8>) -> Self {
+        Self {
+            key: key.to_string(),
+            expected: ConditionResult::Prefix as i32,
+            target: Some(txn_condition::Target::Value(prefix)),
+        }
+    }
+
+    /// Create a txn condition that checks if the record exists.
+    pub fn exists(key: impl ToString) -> Self {
+        Self {
+            key: key.to_string(),
+            expected: ConditionResult::Eq as i32,
+            target: Some(txn_condition::Target::Exists(true)),
+        }
+    }
+
+    /// Create a txn condition that checks if the record does not exist.
+    pub fn not_exists(key: impl ToString) -> Self {
+        Self {
+            key: key.to_string(),
+            expected: ConditionResult::Eq as i32,
+            target: Some(txn_condition::Target::Exists(false)),
+        }
+    }
 }
 
 impl TxnOp {
@@ -169,6 +240,20 @@ impl TxnOp {
                 value,
                 prev_value: true,
                 expire_at,
+                ttl_ms: None,
+            })),
+        }
+    }
+
+    /// Create a txn operation that puts a record which expires after `ttl` since it is applied.
+    pub fn put_with_ttl(key: impl ToString, value: Vec<u8>, ttl: std::time::Duration) -> TxnOp {
+        TxnOp {
+            request: Some(txn_op::Request::Put(TxnPutRequest {
+                key: key.to_string(),
+                value,
+                prev_value: true,
+                expire_at: None,
+                ttl_ms: Some(ttl.as_millis() as u64),
             })),
         }
     }
@@ -0,0 +1,125 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::KVMeta;
+
+/// The meta data of a record to write, which is converted into a [`KVMeta`] when the write is
+/// applied to the state machine.
+///
+/// A record expires either at an absolute time, or after a `ttl`, which is relative to the time
+/// of the raft log that applies the write. The log time is proposed by the leader, thus the
+/// expiration of a record with `ttl` is the same on every node. A record with `ttl` is a lease:
+/// it expires unless it is written again, with a new `ttl`, before it expires.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Eq, PartialEq)]
+pub struct MetaSpec {
+    /// Expiration time in second since 1970.
+    pub expire_at: Option<u64>,
+
+    /// Relative expiration time in millisecond, it takes precedence over `expire_at`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl_ms: Option<u64>,
+}
+
+impl MetaSpec {
+    pub fn new(expire_at: Option<u64>, ttl: Option<Duration>) -> Self {
+        Self {
+            expire_at,
+            ttl_ms: ttl.map(|ttl| ttl.as_millis() as u64),
+        }
+    }
+
+    /// Create a `MetaSpec` that expires at an absolute time in second since 1970.
+    pub fn new_expire(expire_at: u64) -> Self {
+        Self::new(Some(expire_at), None)
+    }
+
+    /// Create a `MetaSpec` that expires after `ttl` since the write is applied.
+    pub fn new_ttl(ttl: Duration) -> Self {
+        Self::new(None, Some(ttl))
+    }
+
+    /// Convert into the [`KVMeta`] to store, with the time of the log that applies the write.
+    ///
+    /// The expiration time of [`KVMeta`] is in second, it is rounded up so that a record never
+    /// expires before its `ttl`.
+    pub fn to_kv_meta(&self, log_time_ms: u64) -> KVMeta {
+        let expire_at = match self.ttl_ms {
+            Some(ttl_ms) => Some((log_time_ms + ttl_ms + 999) / 1000),
+            None => self.expire_at,
+        };
+        KVMeta { expire_at }
+    }
+}
+
+impl From<KVMeta> for MetaSpec {
+    fn from(meta: KVMeta) -> Self {
+        Self::new(meta.expire_at, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::KVMeta;
+    use crate::MetaSpec;
+
+    #[test]
+    fn test_meta_spec_to_kv_meta() {
+        let m = MetaSpec::new_expire(5);
+        assert_eq!(KVMeta { expire_at: Some(5) }, m.to_kv_meta(100_000));
+
+        let m = MetaSpec::new_ttl(Duration::from_millis(2_000));
+        assert_eq!(
+            KVMeta {
+                expire_at: Some(102)
+            },
+            m.to_kv_meta(100_000)
+        );
+        assert_eq!(
+            KVMeta {
+                expire_at: Some(103)
+            },
+            m.to_kv_meta(100_001)
+        );
+
+        // ttl takes precedence over expire_at
+        let m = MetaSpec::new(Some(5), Some(Duration::from_secs(1)));
+        assert_eq!(
+            KVMeta {
+                expire_at: Some(101)
+            },
+            m.to_kv_meta(100_000)
+        );
+
+        let m = MetaSpec::new(None, None);
+        assert_eq!(KVMeta { expire_at: None }, m.to_kv_meta(100_000));
+    }
+
+    #[test]
+    fn test_meta_spec_serde_compatible_with_kv_meta() -> anyhow::Result<()> {
+        let kv_meta = KVMeta { expire_at: Some(5) };
+        let m: MetaSpec = serde_json::from_str(&serde_json::to_string(&kv_meta)?)?;
+        assert_eq!(MetaSpec::new_expire(5), m);
+
+        let s = serde_json::to_string(&MetaSpec::new_expire(5))?;
+        assert_eq!(r#"{"expire_at":5}"#, s);
+        Ok(())
+    }
+}
@@ -106,6 +106,7 @@ impl Display for ConditionResult {
             ConditionResult::Lt => "<",
             ConditionResult::Le => "<=",
             ConditionResult::Ne => "!=",
+            ConditionResult::Prefix => "starts_with",
         };
         write!(f, "{}", x)
     }
@@ -152,6 +153,9 @@ impl Display for TxnPutRequest {
         if let Some(expire_at) = self.expire_at {
             write!(f, " expire at: {}", expire_at)?;
         }
+        if let Some(ttl_ms) = self.ttl_ms {
+            write!(f, " ttl: {} ms", ttl_ms)?;
+        }
         Ok(())
     }
 }
@@ -177,6 +181,9 @@ impl Display for Target {
             Target::Seq(seq) => {
                 write!(f, "seq({})", seq)
             }
+            Target::Exists(exists) => {
+                write!(f, "exists({})", exists)
+            }
         }
     }
 }
@@ -695,6 +695,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             ExplainKind::Memo(_) => "Memo",
             ExplainKind::JOIN => "JOIN",
             ExplainKind::AnalyzePlan => "Analyze",
+            ExplainKind::Estimate => "Estimate",
         });
         let format_ctx = AstFormatContext::with_children(name, 1);
         let node = FormatTreeNode::with_children(format_ctx, vec![child]);
@@ -1181,6 +1182,21 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_comment(&mut self, stmt: &'ast CommentStmt) {
+        let object_name = format!("Object {}", stmt.object);
+        let object_format_ctx = AstFormatContext::new(object_name);
+        let object_node = FormatTreeNode::new(object_format_ctx);
+
+        let comment_name = format!("Comment '{}'", stmt.comment);
+        let comment_format_ctx = AstFormatContext::new(comment_name);
+        let comment_node = FormatTreeNode::new(comment_format_ctx);
+
+        let name = "Comment".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![object_node, comment_node]);
+        self.children.push(node);
+    }
+
     fn visit_show_create_table(&mut self, stmt: &'ast ShowCreateTableStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
         let child = self.children.pop().unwrap();
@@ -1191,11 +1207,16 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
     }
 
     fn visit_describe_table(&mut self, stmt: &'ast DescribeTableStmt) {
+        let mut children = Vec::new();
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
-        let child = self.children.pop().unwrap();
+        children.push(self.children.pop().unwrap());
+        if let Some(travel_point) = &stmt.travel_point {
+            self.visit_time_travel_point(travel_point);
+            children.push(self.children.pop().unwrap());
+        }
         let name = "DescribeTable".to_string();
-        let format_ctx = AstFormatContext::with_children(name, 1);
-        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
         self.children.push(node);
     }
 
@@ -1258,6 +1279,17 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 FormatTreeNode::with_children(cluster_by_format_ctx, cluster_by_children);
             children.push(cluster_by_node);
         }
+        if let Some(partition_by) = &stmt.partition_by {
+            self.visit_expr(partition_by);
+            let partition_by_name = "PartitionBy".to_string();
+            let partition_by_format_ctx = AstFormatContext::with_children(partition_by_name, 1);
+            let partition_by_node =
+                FormatTreeNode::with_children(partition_by_format_ctx, vec![self
+                    .children
+                    .pop()
+                    .unwrap()]);
+            children.push(partition_by_node);
+        }
         if !stmt.table_options.is_empty() {
             let mut table_options_children = Vec::with_capacity(stmt.table_options.len());
             for (k, v) in stmt.table_options.iter() {
@@ -1287,12 +1319,17 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
 
     fn visit_create_table_source(&mut self, source: &'ast CreateTableSource) {
         match source {
-            CreateTableSource::Columns(columns) => {
-                let mut children = Vec::with_capacity(columns.len());
+            CreateTableSource::Columns(columns, constraints) => {
+                let mut children = Vec::with_capacity(columns.len() + constraints.len());
                 for column in columns.iter() {
                     self.visit_column_definition(column);
                     children.push(self.children.pop().unwrap());
                 }
+                for constraint in constraints.iter() {
+                    let name = format!("TableConstraint {}", constraint);
+                    let format_ctx = AstFormatContext::new(name);
+                    children.push(FormatTreeNode::new(format_ctx));
+                }
                 let name = "ColumnsDefinition".to_string();
                 let format_ctx = AstFormatContext::with_children(name, children.len());
                 let node = FormatTreeNode::with_children(format_ctx, children);
@@ -1310,6 +1347,24 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                let mut children = Vec::new();
+                self.visit_table_ref(catalog, database, table);
+                children.push(self.children.pop().unwrap());
+                if let Some(travel_point) = travel_point {
+                    self.visit_time_travel_point(travel_point);
+                    children.push(self.children.pop().unwrap());
+                }
+                let name = "CloneTable".to_string();
+                let format_ctx = AstFormatContext::with_children(name, children.len());
+                let node = FormatTreeNode::with_children(format_ctx, children);
+                self.children.push(node);
+            }
         }
     }
 
@@ -1435,6 +1490,12 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::DropPartition { partition } => {
+                self.visit_expr(partition);
+                let action_name = "Action DropPartition".to_string();
+                let action_format_ctx = AstFormatContext::with_children(action_name, 1);
+                FormatTreeNode::with_children(action_format_ctx, vec![self.children.pop().unwrap()])
+            }
             AlterTableAction::ReclusterTable {
                 selection, limit, ..
             } => {
@@ -1468,6 +1529,22 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::SetTags { tags } => {
+                let action_name = format!(
+                    "Action Set Tag: {}",
+                    tags.iter()
+                        .map(|(key, value)| format!("{key} to {value}"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::UnsetTags { tags } => {
+                let action_name = format!("Action Unset Tag: {}", tags.join(", "));
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterTable".to_string();
@@ -1602,6 +1679,60 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_materialized_view(&mut self, stmt: &'ast CreateMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let view_child = self.children.pop().unwrap();
+        self.visit_query(&stmt.query);
+        let query_child = self.children.pop().unwrap();
+
+        let name = "CreateMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![view_child, query_child]);
+        self.children.push(node);
+    }
+
+    fn visit_refresh_materialized_view(&mut self, stmt: &'ast RefreshMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let child = self.children.pop().unwrap();
+
+        let name = "RefreshMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_materialized_view(&mut self, stmt: &'ast DropMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let child = self.children.pop().unwrap();
+
+        let name = "DropMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_stream(&mut self, stmt: &'ast CreateStreamStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
+        let stream_child = self.children.pop().unwrap();
+        self.visit_table_ref(&None, &stmt.table_database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "CreateStream".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![stream_child, table_child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_stream(&mut self, stmt: &'ast DropStreamStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
+        let child = self.children.pop().unwrap();
+
+        let name = "DropStream".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_create_index(&mut self, stmt: &'ast CreateIndexStmt) {
         self.visit_index_ref(&stmt.index_name);
         let index_child = self.children.pop().unwrap();
@@ -1640,6 +1771,44 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_inverted_index(&mut self, stmt: &'ast CreateInvertedIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let mut columns_children = Vec::with_capacity(stmt.columns.len());
+        for column in stmt.columns.iter() {
+            self.visit_identifier(column);
+            columns_children.push(self.children.pop().unwrap());
+        }
+        let columns_name = "Columns".to_string();
+        let columns_ctx = AstFormatContext::with_children(columns_name, columns_children.len());
+        let columns_child = FormatTreeNode::with_children(columns_ctx, columns_children);
+        let mut children = vec![index_child, table_child, columns_child];
+        if let Some(tokenizer) = &stmt.tokenizer {
+            let tokenizer_ctx = AstFormatContext::new(format!("Tokenizer {tokenizer}"));
+            children.push(FormatTreeNode::new(tokenizer_ctx));
+        }
+
+        let name = "CreateInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_inverted_index(&mut self, stmt: &'ast DropInvertedIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "DropInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, table_child]);
+        self.children.push(node);
+    }
+
     fn visit_create_virtual_column(&mut self, stmt: &'ast CreateVirtualColumnStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
         let table_child = self.children.pop().unwrap();
@@ -2357,6 +2526,78 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_storage_profile(&mut self, stmt: &'ast CreateStorageProfileStmt) {
+        let ctx = AstFormatContext::new(format!("StorageProfileName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateStorageProfile".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_storage_profile(&mut self, stmt: &'ast DropStorageProfileStmt) {
+        let ctx = AstFormatContext::new(format!("StorageProfileName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropStorageProfile".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_desc_storage_profile(&mut self, stmt: &'ast DescStorageProfileStmt) {
+        let ctx = AstFormatContext::new(format!("StorageProfileName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DescStorageProfile".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_storage_profiles(&mut self) {
+        let ctx = AstFormatContext::new("ShowStorageProfiles".to_string());
+        let node = FormatTreeNode::new(ctx);
+        self.children.push(node);
+    }
+
+    fn visit_create_pipe(&mut self, stmt: &'ast CreatePipeStmt) {
+        let ctx = AstFormatContext::new(format!("PipeName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreatePipe".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_pipe(&mut self, stmt: &'ast DropPipeStmt) {
+        let ctx = AstFormatContext::new(format!("PipeName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropPipe".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_desc_pipe(&mut self, stmt: &'ast DescPipeStmt) {
+        let ctx = AstFormatContext::new(format!("PipeName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DescPipe".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_pipes(&mut self) {
+        let ctx = AstFormatContext::new("ShowPipes".to_string());
+        let node = FormatTreeNode::new(ctx);
+        self.children.push(node);
+    }
+
     fn visit_with(&mut self, with: &'ast With) {
         let mut children = Vec::with_capacity(with.ctes.len());
         for cte in with.ctes.iter() {
@@ -78,6 +78,13 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
         } else {
             RcDoc::nil()
         })
+        .append(if let Some(partition_by) = stmt.partition_by {
+            RcDoc::line()
+                .append(RcDoc::text("PARTITION BY "))
+                .append(parenthesized(pretty_expr(partition_by)))
+        } else {
+            RcDoc::nil()
+        })
         .append(if !stmt.table_options.is_empty() {
             RcDoc::line()
                 .append(interweave_comma(stmt.table_options.iter().map(|(k, v)| {
@@ -106,11 +113,16 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
 
 fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
     match source {
-        CreateTableSource::Columns(columns) => RcDoc::space().append(parenthesized(
+        CreateTableSource::Columns(columns, constraints) => RcDoc::space().append(parenthesized(
             interweave_comma(
                 columns
                     .into_iter()
-                    .map(|column| RcDoc::text(column.to_string())),
+                    .map(|column| RcDoc::text(column.to_string()))
+                    .chain(
+                        constraints
+                            .into_iter()
+                            .map(|constraint| RcDoc::text(constraint.to_string())),
+                    ),
             )
             .group(),
         )),
@@ -132,6 +144,34 @@ fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
                 RcDoc::nil()
             })
             .append(RcDoc::text(table.to_string())),
+        CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point,
+        } => RcDoc::space()
+            .append(RcDoc::text("CLONE"))
+            .append(RcDoc::space())
+            .append(if let Some(catalog) = catalog {
+                RcDoc::text(catalog.to_string()).append(RcDoc::text("."))
+            } else {
+                RcDoc::nil()
+            })
+            .append(if let Some(database) = database {
+                RcDoc::text(database.to_string()).append(RcDoc::text("."))
+            } else {
+                RcDoc::nil()
+            })
+            .append(RcDoc::text(table.to_string()))
+            .append(match travel_point {
+                Some(TimeTravelPoint::Snapshot(sid)) => {
+                    RcDoc::text(format!(" AT (SNAPSHOT => '{sid}')"))
+                }
+                Some(TimeTravelPoint::Timestamp(ts)) => {
+                    RcDoc::text(format!(" AT (TIMESTAMP => {ts})"))
+                }
+                None => RcDoc::nil(),
+            }),
     }
 }
 
@@ -188,6 +228,9 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
         AlterTableAction::DropTableClusterKey => {
             RcDoc::line().append(RcDoc::text("DROP CLUSTER KEY"))
         }
+        AlterTableAction::DropPartition { partition } => RcDoc::line()
+            .append(RcDoc::text("DROP PARTITION "))
+            .append(pretty_expr(partition)),
         AlterTableAction::ReclusterTable {
             is_final,
             selection,
@@ -225,11 +268,26 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
             }
             doc
         }
+        AlterTableAction::SetTags { tags } => RcDoc::line()
+            .append(RcDoc::text("SET TAG "))
+            .append(interweave_comma(
+                tags.into_iter()
+                    .map(|(key, value)| RcDoc::text(format!("{key} = '{value}'"))),
+            )),
+        AlterTableAction::UnsetTags { tags } => RcDoc::line()
+            .append(RcDoc::text("UNSET TAG "))
+            .append(interweave_comma(tags.into_iter().map(RcDoc::text))),
     }
 }
 
 pub(crate) fn pretty_create_view(stmt: CreateViewStmt) -> RcDoc<'static> {
-    RcDoc::text("CREATE VIEW")
+    RcDoc::text("CREATE")
+        .append(if stmt.temporary {
+            RcDoc::space().append(RcDoc::text("TEMPORARY"))
+        } else {
+            RcDoc::nil()
+        })
+        .append(RcDoc::space().append(RcDoc::text("VIEW")))
         .append(if stmt.if_not_exists {
             RcDoc::space().append(RcDoc::text("IF NOT EXISTS"))
         } else {
@@ -245,6 +245,16 @@ pub(crate) fn pretty_copy(copy_stmt: CopyStmt) -> RcDoc<'static> {
                 .append(RcDoc::text("DISABLE_VARIANT_CHECK = "))
                 .append(RcDoc::text(format!("{}", copy_stmt.disable_variant_check))),
         )
+        .append(if copy_stmt.return_file_status {
+            RcDoc::line().append(RcDoc::text("RETURN_FILE_STATUS = true"))
+        } else {
+            RcDoc::nil()
+        })
+        .append(if copy_stmt.manifest {
+            RcDoc::line().append(RcDoc::text("MANIFEST = true"))
+        } else {
+            RcDoc::nil()
+        })
 }
 
 fn pretty_copy_unit(copy_unit: CopyUnit) -> RcDoc<'static> {
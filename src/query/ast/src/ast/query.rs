@@ -314,6 +314,9 @@ pub enum JoinOperator {
     RightAnti,
     // CrossJoin can only work with `JoinCondition::None`
     CrossJoin,
+    // AsofJoin matches each row of the left side with the nearest row of the right side
+    Asof,
+    LeftAsof,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -508,6 +511,12 @@ impl Display for TableReference {
                     JoinOperator::CrossJoin => {
                         write!(f, " CROSS JOIN")?;
                     }
+                    JoinOperator::Asof => {
+                        write!(f, " ASOF JOIN")?;
+                    }
+                    JoinOperator::LeftAsof => {
+                        write!(f, " ASOF LEFT JOIN")?;
+                    }
                 }
                 write!(f, " {}", join.right)?;
                 match &join.condition {
@@ -0,0 +1,93 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::write_period_separated_list;
+use crate::ast::Identifier;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommentStmt {
+    pub if_exists: bool,
+    pub object: CommentObject,
+    pub comment: String,
+}
+
+impl Display for CommentStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "COMMENT ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "ON {} IS '{}'", self.object, self.comment)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommentObject {
+    Database {
+        catalog: Option<Identifier>,
+        database: Identifier,
+    },
+    Table {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+    },
+    Column {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+        column: Identifier,
+    },
+}
+
+impl Display for CommentObject {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommentObject::Database { catalog, database } => {
+                write!(f, "DATABASE ")?;
+                write_period_separated_list(f, catalog.iter().chain(Some(database)))
+            }
+            CommentObject::Table {
+                catalog,
+                database,
+                table,
+            } => {
+                write!(f, "TABLE ")?;
+                write_period_separated_list(
+                    f,
+                    catalog.iter().chain(database).chain(Some(table)),
+                )
+            }
+            CommentObject::Column {
+                catalog,
+                database,
+                table,
+                column,
+            } => {
+                write!(f, "COLUMN ")?;
+                write_period_separated_list(
+                    f,
+                    catalog
+                        .iter()
+                        .chain(database)
+                        .chain(Some(table))
+                        .chain(Some(column)),
+                )
+            }
+        }
+    }
+}
@@ -56,6 +56,8 @@ pub struct CopyStmt {
     pub force: bool,
     pub disable_variant_check: bool,
     pub on_error: String,
+    pub return_file_status: bool,
+    pub manifest: bool,
 }
 
 impl CopyStmt {
@@ -74,6 +76,8 @@ impl CopyStmt {
             CopyOption::Force(v) => self.force = v,
             CopyOption::DisableVariantCheck(v) => self.disable_variant_check = v,
             CopyOption::OnError(v) => self.on_error = v,
+            CopyOption::ReturnFileStatus(v) => self.return_file_status = v,
+            CopyOption::Manifest(v) => self.manifest = v,
         }
     }
 }
@@ -131,6 +135,14 @@ impl Display for CopyStmt {
         write!(f, " DISABLE_VARIANT_CHECK = {}", self.disable_variant_check)?;
         write!(f, " ON_ERROR = '{}'", self.on_error)?;
 
+        if self.return_file_status {
+            write!(f, " RETURN_FILE_STATUS = {}", self.return_file_status)?;
+        }
+
+        if self.manifest {
+            write!(f, " MANIFEST = {}", self.manifest)?;
+        }
+
         Ok(())
     }
 }
@@ -410,4 +422,6 @@ pub enum CopyOption {
     Force(bool),
     DisableVariantCheck(bool),
     OnError(String),
+    ReturnFileStatus(bool),
+    Manifest(bool),
 }
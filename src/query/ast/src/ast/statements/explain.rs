@@ -29,4 +29,7 @@ pub enum ExplainKind {
 
     // Explain analyze plan
     AnalyzePlan,
+
+    // The estimated rows, bytes and partitions of the scans
+    Estimate,
 }
@@ -15,6 +15,7 @@
 mod call;
 mod catalog;
 mod columns;
+mod comment;
 mod copy;
 mod data_mask;
 mod database;
@@ -24,12 +25,17 @@ mod index;
 mod insert;
 mod kill;
 mod network_policy;
+mod pipe;
 mod presign;
 mod replace;
+mod sequence;
+mod settings;
 mod share;
 mod show;
 mod stage;
 mod statement;
+mod storage_profile;
+mod stream;
 mod table;
 mod unset;
 mod update;
@@ -40,6 +46,7 @@ mod virtual_column;
 pub use call::*;
 pub use catalog::*;
 pub use columns::*;
+pub use comment::*;
 pub use copy::*;
 pub use data_mask::*;
 pub use database::*;
@@ -49,12 +56,17 @@ pub use index::*;
 pub use insert::*;
 pub use kill::*;
 pub use network_policy::*;
+pub use pipe::*;
 pub use presign::*;
 pub use replace::*;
+pub use sequence::*;
+pub use settings::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
 pub use statement::*;
+pub use storage_profile::*;
+pub use stream::*;
 pub use table::*;
 pub use unset::*;
 pub use update::*;
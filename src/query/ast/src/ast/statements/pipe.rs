@@ -0,0 +1,130 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::write_period_separated_list;
+use crate::ast::Identifier;
+use crate::ast::StageLocation;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreatePipeStmt {
+    pub if_not_exists: bool,
+    pub name: String,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    pub source: CreatePipeSource,
+    pub file_format: BTreeMap<String, String>,
+    pub comment: Option<String>,
+}
+
+impl Display for CreatePipeStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE PIPE ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{} INTO ", self.name)?;
+        write_period_separated_list(f, self.database.iter().chain(Some(&self.table)))?;
+        write!(f, " FROM {}", self.source)?;
+        if !self.file_format.is_empty() {
+            write!(f, " FILE_FORMAT = (")?;
+            for (k, v) in self.file_format.iter() {
+                write!(f, " {} = '{}'", k, v)?;
+            }
+            write!(f, " )")?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{}'", comment)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CreatePipeSource {
+    Kafka {
+        connection: BTreeMap<String, String>,
+    },
+    Stage {
+        location: StageLocation,
+        pattern: Option<String>,
+        options: BTreeMap<String, String>,
+    },
+}
+
+impl Display for CreatePipeSource {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            CreatePipeSource::Kafka { connection } => {
+                write!(f, "KAFKA CONNECTION = (")?;
+                for (k, v) in connection.iter() {
+                    write!(f, " {} = '{}'", k, v)?;
+                }
+                write!(f, " )")
+            }
+            CreatePipeSource::Stage {
+                location,
+                pattern,
+                options,
+            } => {
+                write!(f, "{}", location)?;
+                if let Some(pattern) = pattern {
+                    write!(f, " PATTERN = '{}'", pattern)?;
+                }
+                if !options.is_empty() {
+                    write!(f, " OPTIONS = (")?;
+                    for (k, v) in options.iter() {
+                        write!(f, " {} = '{}'", k, v)?;
+                    }
+                    write!(f, " )")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropPipeStmt {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+impl Display for DropPipeStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP PIPE ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DescPipeStmt {
+    pub name: String,
+}
+
+impl Display for DescPipeStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DESCRIBE PIPE {}", self.name)?;
+
+        Ok(())
+    }
+}
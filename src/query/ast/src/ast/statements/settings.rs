@@ -0,0 +1,47 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::write_comma_separated_list;
+use crate::ast::Expr;
+use crate::ast::Identifier;
+
+/// `SETTINGS (<name> = <value>, ...)` clause which overrides the settings
+/// for the single statement that follows it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettingsClause {
+    pub items: Vec<SettingItem>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettingItem {
+    pub name: Identifier,
+    pub value: Expr,
+}
+
+impl Display for SettingsClause {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "SETTINGS (")?;
+        write_comma_separated_list(f, &self.items)?;
+        write!(f, ")")
+    }
+}
+
+impl Display for SettingItem {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{} = {}", self.name, self.value)
+    }
+}
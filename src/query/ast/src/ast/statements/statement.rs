@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::fmt::Formatter;
 
@@ -25,6 +26,7 @@ use crate::ast::Expr;
 use crate::ast::Identifier;
 use crate::ast::Query;
 use crate::ast::TableReference;
+use crate::ast::TypeName;
 
 // SQL statement
 #[allow(clippy::large_enum_variant)]
@@ -38,6 +40,10 @@ pub enum Statement {
     ExplainAnalyze {
         query: Box<Statement>,
     },
+    StatementWithSettings {
+        settings: SettingsClause,
+        stmt: Box<Statement>,
+    },
 
     Copy(CopyStmt),
     Call(CallStmt),
@@ -123,15 +129,27 @@ pub enum Statement {
     // Columns
     ShowColumns(ShowColumnsStmt),
 
+    // Comments
+    Comment(CommentStmt),
+
     // Views
     CreateView(CreateViewStmt),
     AlterView(AlterViewStmt),
     DropView(DropViewStmt),
+    CreateMaterializedView(CreateMaterializedViewStmt),
+    RefreshMaterializedView(RefreshMaterializedViewStmt),
+    DropMaterializedView(DropMaterializedViewStmt),
+
+    // Streams
+    CreateStream(CreateStreamStmt),
+    DropStream(DropStreamStmt),
 
     // Indexes
     CreateIndex(CreateIndexStmt),
     DropIndex(DropIndexStmt),
     RefreshIndex(RefreshIndexStmt),
+    CreateInvertedIndex(CreateInvertedIndexStmt),
+    DropInvertedIndex(DropInvertedIndexStmt),
 
     // VirtualColumns
     CreateVirtualColumn(CreateVirtualColumnStmt),
@@ -165,8 +183,21 @@ pub enum Statement {
     // UDF
     CreateUDF {
         if_not_exists: bool,
+        temporary: bool,
         udf_name: Identifier,
         parameters: Vec<Identifier>,
+        /// The declared types of the parameters, empty for the lambda syntax.
+        arg_types: Vec<TypeName>,
+        /// The declared return type of a scalar function.
+        return_type: Option<TypeName>,
+        /// Whether the function is called in `FROM` clauses, the definition is then a subquery.
+        returns_table: bool,
+        /// The handler of a `LANGUAGE WASM` function, the definition is then the stage
+        /// location of its module.
+        wasm_handler: Option<String>,
+        /// The options of a function evaluated by an external UDF server, the definition is
+        /// then the address of the server.
+        server: Option<UDFServerOptions>,
         definition: Box<Expr>,
         description: Option<String>,
     },
@@ -238,6 +269,32 @@ pub enum Statement {
     DropNetworkPolicy(DropNetworkPolicyStmt),
     DescNetworkPolicy(DescNetworkPolicyStmt),
     ShowNetworkPolicies,
+
+    // storage profile
+    CreateStorageProfile(CreateStorageProfileStmt),
+    DropStorageProfile(DropStorageProfileStmt),
+    DescStorageProfile(DescStorageProfileStmt),
+    ShowStorageProfiles,
+
+    // pipe
+    CreatePipe(CreatePipeStmt),
+    DropPipe(DropPipeStmt),
+    DescPipe(DescPipeStmt),
+    ShowPipes,
+
+    // Sequences
+    CreateSequence(CreateSequenceStmt),
+    DropSequence(DropSequenceStmt),
+    ShowSequences,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UDFServerOptions {
+    pub language: String,
+    pub handler: String,
+    /// Whether the function returns NULL without calling the server if an argument is NULL,
+    /// i.e. `RETURNS NULL ON NULL INPUT`, instead of `CALLED ON NULL INPUT`.
+    pub returns_null_on_null_input: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -268,11 +325,50 @@ impl Statement {
                 }
                 format!("{}", Statement::CreateStage(stage_clone))
             }
+            Statement::CreateUser(user) => {
+                let mut user_clone = user.clone();
+                user_clone.auth_option = user_clone.auth_option.mask();
+                format!("{}", Statement::CreateUser(user_clone))
+            }
+            Statement::AlterUser(user) => {
+                let mut user_clone = user.clone();
+                user_clone.auth_option = user_clone.auth_option.map(|option| option.mask());
+                format!("{}", Statement::AlterUser(user_clone))
+            }
+            Statement::CreateTable(table) => {
+                let mut table_clone = table.clone();
+                if let Some(location) = &mut table_clone.uri_location {
+                    location.connection = location.connection.mask()
+                }
+                mask_connection_option(&mut table_clone.table_options);
+                format!("{}", Statement::CreateTable(table_clone))
+            }
+            Statement::AlterTable(table) => {
+                let mut table_clone = table.clone();
+                if let AlterTableAction::SetOptions { set_options } = &mut table_clone.action {
+                    mask_connection_option(set_options);
+                }
+                format!("{}", Statement::AlterTable(table_clone))
+            }
+            Statement::CreateStorageProfile(profile) => {
+                let mut profile_clone = profile.clone();
+                profile_clone.uri_location.connection =
+                    profile_clone.uri_location.connection.mask();
+                format!("{}", Statement::CreateStorageProfile(profile_clone))
+            }
             _ => format!("{}", self),
         }
     }
 }
 
+// The `connection` option of the tables of the remote databases, e.g. the MYSQL and POSTGRES
+// engines, is a url carrying the credentials.
+fn mask_connection_option(options: &mut BTreeMap<String, String>) {
+    if let Some(connection) = options.get_mut("connection") {
+        *connection = "********".to_string();
+    }
+}
+
 impl Display for Statement {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -289,12 +385,16 @@ impl Display for Statement {
                     ExplainKind::AnalyzePlan => write!(f, " ANALYZE")?,
                     ExplainKind::JOIN => write!(f, " JOIN")?,
                     ExplainKind::Memo(_) => write!(f, " MEMO")?,
+                    ExplainKind::Estimate => write!(f, " ESTIMATE")?,
                 }
                 write!(f, " {query}")?;
             }
             Statement::ExplainAnalyze { query } => {
                 write!(f, "EXPLAIN ANALYZE {query}")?;
             }
+            Statement::StatementWithSettings { settings, stmt } => {
+                write!(f, "{settings} {stmt}")?;
+            }
             Statement::Query(query) => write!(f, "{query}")?,
             Statement::Insert(insert) => write!(f, "{insert}")?,
             Statement::Replace(replace) => write!(f, "{replace}")?,
@@ -382,6 +482,7 @@ impl Display for Statement {
             Statement::UseDatabase { database } => write!(f, "USE {database}")?,
             Statement::ShowTables(stmt) => write!(f, "{stmt}")?,
             Statement::ShowColumns(stmt) => write!(f, "{stmt}")?,
+            Statement::Comment(stmt) => write!(f, "{stmt}")?,
             Statement::ShowCreateTable(stmt) => write!(f, "{stmt}")?,
             Statement::DescribeTable(stmt) => write!(f, "{stmt}")?,
             Statement::ShowTablesStatus(stmt) => write!(f, "{stmt}")?,
@@ -401,9 +502,16 @@ impl Display for Statement {
             Statement::CreateView(stmt) => write!(f, "{stmt}")?,
             Statement::AlterView(stmt) => write!(f, "{stmt}")?,
             Statement::DropView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::DropMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateStream(stmt) => write!(f, "{stmt}")?,
+            Statement::DropStream(stmt) => write!(f, "{stmt}")?,
             Statement::CreateIndex(stmt) => write!(f, "{stmt}")?,
             Statement::DropIndex(stmt) => write!(f, "{stmt}")?,
             Statement::RefreshIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateInvertedIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::DropInvertedIndex(stmt) => write!(f, "{stmt}")?,
             Statement::CreateVirtualColumn(stmt) => write!(f, "{stmt}")?,
             Statement::AlterVirtualColumn(stmt) => write!(f, "{stmt}")?,
             Statement::DropVirtualColumn(stmt) => write!(f, "{stmt}")?,
@@ -450,18 +558,73 @@ impl Display for Statement {
             Statement::Revoke(stmt) => write!(f, "{stmt}")?,
             Statement::CreateUDF {
                 if_not_exists,
+                temporary,
                 udf_name,
                 parameters,
+                arg_types,
+                return_type,
+                returns_table,
+                wasm_handler,
+                server,
                 definition,
                 description,
             } => {
-                write!(f, "CREATE FUNCTION")?;
+                write!(f, "CREATE")?;
+                if *temporary {
+                    write!(f, " TEMPORARY")?;
+                }
+                write!(f, " FUNCTION")?;
                 if *if_not_exists {
                     write!(f, " IF NOT EXISTS")?;
                 }
-                write!(f, " {udf_name} AS (")?;
-                write_comma_separated_list(f, parameters)?;
-                write!(f, ") -> {definition}")?;
+                if let Some(handler) = wasm_handler {
+                    write!(f, " {udf_name} (")?;
+                    write_comma_separated_list(f, arg_types)?;
+                    write!(f, ") RETURNS ")?;
+                    if let Some(return_type) = return_type {
+                        write!(f, "{return_type}")?;
+                    }
+                    write!(f, " LANGUAGE WASM HANDLER = '{handler}' AS {definition}")?;
+                } else if let Some(server) = server {
+                    write!(f, " {udf_name} (")?;
+                    write_comma_separated_list(f, arg_types)?;
+                    write!(f, ") RETURNS ")?;
+                    if let Some(return_type) = return_type {
+                        write!(f, "{return_type}")?;
+                    }
+                    if server.returns_null_on_null_input {
+                        write!(f, " RETURNS NULL ON NULL INPUT")?;
+                    } else {
+                        write!(f, " CALLED ON NULL INPUT")?;
+                    }
+                    write!(
+                        f,
+                        " LANGUAGE {} HANDLER = '{}' ADDRESS = {definition}",
+                        server.language, server.handler
+                    )?;
+                } else if arg_types.is_empty() && return_type.is_none() {
+                    write!(f, " {udf_name} AS (")?;
+                    write_comma_separated_list(f, parameters)?;
+                    write!(f, ") -> ")?;
+                    if *returns_table {
+                        write!(f, "TABLE ")?;
+                    }
+                    write!(f, "{definition}")?;
+                } else {
+                    write!(f, " {udf_name} (")?;
+                    for (i, (parameter, arg_type)) in parameters.iter().zip(arg_types).enumerate() {
+                        if i > 0 {
+                            write!(f, ", ")?;
+                        }
+                        write!(f, "{parameter} {arg_type}")?;
+                    }
+                    write!(f, ") RETURNS ")?;
+                    match return_type {
+                        Some(return_type) => write!(f, "{return_type}")?,
+                        None => write!(f, "TABLE")?,
+                    }
+                    write!(f, " AS {definition}")?;
+                }
                 if let Some(description) = description {
                     write!(f, " DESC = '{description}'")?;
                 }
@@ -556,6 +719,17 @@ impl Display for Statement {
             Statement::DropNetworkPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DescNetworkPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::ShowNetworkPolicies => write!(f, "SHOW NETWORK POLICIES")?,
+            Statement::CreateStorageProfile(stmt) => write!(f, "{stmt}")?,
+            Statement::DropStorageProfile(stmt) => write!(f, "{stmt}")?,
+            Statement::DescStorageProfile(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowStorageProfiles => write!(f, "SHOW STORAGE PROFILES")?,
+            Statement::CreatePipe(stmt) => write!(f, "{stmt}")?,
+            Statement::DropPipe(stmt) => write!(f, "{stmt}")?,
+            Statement::DescPipe(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowPipes => write!(f, "SHOW PIPES")?,
+            Statement::CreateSequence(stmt) => write!(f, "{stmt}")?,
+            Statement::DropSequence(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowSequences => write!(f, "SHOW SEQUENCES")?,
         }
         Ok(())
     }
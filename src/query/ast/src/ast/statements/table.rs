@@ -19,6 +19,7 @@ use std::format;
 
 use crate::ast::statements::show::ShowLimit;
 use crate::ast::write_comma_separated_list;
+use crate::ast::write_comma_separated_map;
 use crate::ast::write_period_separated_list;
 use crate::ast::write_space_separated_map;
 use crate::ast::Expr;
@@ -129,6 +130,7 @@ pub struct CreateTableStmt {
     pub engine: Option<Engine>,
     pub uri_location: Option<UriLocation>,
     pub cluster_by: Vec<Expr>,
+    pub partition_by: Option<Expr>,
     pub table_options: BTreeMap<String, String>,
     pub as_query: Option<Box<Query>>,
     pub transient: bool,
@@ -166,6 +168,10 @@ impl Display for CreateTableStmt {
             write!(f, ")")?
         }
 
+        if let Some(partition_by) = &self.partition_by {
+            write!(f, " PARTITION BY ({partition_by})")?;
+        }
+
         // Format table options
         write_space_separated_map(f, self.table_options.iter())?;
         if let Some(as_query) = &self.as_query {
@@ -204,20 +210,30 @@ impl Display for AttachTableStmt {
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq)]
 pub enum CreateTableSource {
-    Columns(Vec<ColumnDefinition>),
+    Columns(Vec<ColumnDefinition>, Vec<TableConstraint>),
     Like {
         catalog: Option<Identifier>,
         database: Option<Identifier>,
         table: Identifier,
     },
+    Clone {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
 }
 
 impl Display for CreateTableSource {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
-            CreateTableSource::Columns(columns) => {
+            CreateTableSource::Columns(columns, constraints) => {
                 write!(f, "(")?;
                 write_comma_separated_list(f, columns)?;
+                if !constraints.is_empty() {
+                    write!(f, ", ")?;
+                    write_comma_separated_list(f, constraints)?;
+                }
                 write!(f, ")")
             }
             CreateTableSource::Like {
@@ -228,15 +244,30 @@ impl Display for CreateTableSource {
                 write!(f, "LIKE ")?;
                 write_period_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                write!(f, "CLONE ")?;
+                write_period_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                match travel_point {
+                    Some(TimeTravelPoint::Snapshot(sid)) => write!(f, " AT (SNAPSHOT => '{sid}')"),
+                    Some(TimeTravelPoint::Timestamp(ts)) => write!(f, " AT (TIMESTAMP => {ts})"),
+                    None => Ok(()),
+                }
+            }
         }
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DescribeTableStmt {
     pub catalog: Option<Identifier>,
     pub database: Option<Identifier>,
     pub table: Identifier,
+    pub travel_point: Option<TimeTravelPoint>,
 }
 
 impl Display for DescribeTableStmt {
@@ -247,7 +278,12 @@ impl Display for DescribeTableStmt {
             self.catalog
                 .iter()
                 .chain(self.database.iter().chain(Some(&self.table))),
-        )
+        )?;
+        match &self.travel_point {
+            Some(TimeTravelPoint::Snapshot(sid)) => write!(f, " AT (SNAPSHOT => '{sid}')"),
+            Some(TimeTravelPoint::Timestamp(ts)) => write!(f, " AT (TIMESTAMP => {ts})"),
+            None => Ok(()),
+        }
     }
 }
 
@@ -342,6 +378,9 @@ pub enum AlterTableAction {
         cluster_by: Vec<Expr>,
     },
     DropTableClusterKey,
+    DropPartition {
+        partition: Expr,
+    },
     ReclusterTable {
         is_final: bool,
         selection: Option<Expr>,
@@ -353,6 +392,12 @@ pub enum AlterTableAction {
     SetOptions {
         set_options: BTreeMap<String, String>,
     },
+    SetTags {
+        tags: BTreeMap<String, String>,
+    },
+    UnsetTags {
+        tags: Vec<String>,
+    },
 }
 
 impl Display for AlterTableAction {
@@ -388,6 +433,9 @@ impl Display for AlterTableAction {
             AlterTableAction::DropTableClusterKey => {
                 write!(f, "DROP CLUSTER KEY")
             }
+            AlterTableAction::DropPartition { partition } => {
+                write!(f, "DROP PARTITION {partition}")
+            }
             AlterTableAction::ReclusterTable {
                 is_final,
                 selection,
@@ -409,6 +457,14 @@ impl Display for AlterTableAction {
                 write!(f, "REVERT TO {}", point)?;
                 Ok(())
             }
+            AlterTableAction::SetTags { tags } => {
+                write!(f, "SET TAG ")?;
+                write_comma_separated_map(f, tags)
+            }
+            AlterTableAction::UnsetTags { tags } => {
+                write!(f, "UNSET TAG ")?;
+                write_comma_separated_list(f, tags)
+            }
         }
     }
 }
@@ -613,6 +669,10 @@ pub enum Engine {
     Fuse,
     View,
     Random,
+    External,
+    Delta,
+    MySql,
+    Postgres,
 }
 
 impl Display for Engine {
@@ -623,6 +683,10 @@ impl Display for Engine {
             Engine::Fuse => write!(f, "FUSE"),
             Engine::View => write!(f, "VIEW"),
             Engine::Random => write!(f, "RANDOM"),
+            Engine::External => write!(f, "EXTERNAL"),
+            Engine::Delta => write!(f, "DELTA"),
+            Engine::MySql => write!(f, "MYSQL"),
+            Engine::Postgres => write!(f, "POSTGRES"),
         }
     }
 }
@@ -658,8 +722,13 @@ impl Display for VacuumTableOption {
 #[derive(Debug, Clone, PartialEq)]
 pub enum OptimizeTableAction {
     All,
-    Purge { before: Option<TimeTravelPoint> },
-    Compact { target: CompactTarget },
+    Purge {
+        before: Option<TimeTravelPoint>,
+    },
+    Compact {
+        target: CompactTarget,
+        partition: Option<Expr>,
+    },
 }
 
 impl Display for OptimizeTableAction {
@@ -673,7 +742,7 @@ impl Display for OptimizeTableAction {
                 }
                 Ok(())
             }
-            OptimizeTableAction::Compact { target } => {
+            OptimizeTableAction::Compact { target, partition } => {
                 match target {
                     CompactTarget::Block => {
                         write!(f, "COMPACT BLOCK")?;
@@ -682,6 +751,9 @@ impl Display for OptimizeTableAction {
                         write!(f, "COMPACT SEGMENT")?;
                     }
                 }
+                if let Some(partition) = partition {
+                    write!(f, " PARTITION {partition}")?;
+                }
                 Ok(())
             }
         }
@@ -693,6 +765,11 @@ pub enum ColumnExpr {
     Default(Box<Expr>),
     Virtual(Box<Expr>),
     Stored(Box<Expr>),
+    /// `IDENTITY(<start>, <increment>)`, filled from a sequence created with the table.
+    Identity {
+        start: i64,
+        increment: i64,
+    },
 }
 
 impl Display for ColumnExpr {
@@ -707,6 +784,9 @@ impl Display for ColumnExpr {
             ColumnExpr::Stored(expr) => {
                 write!(f, " AS ({expr}) STORED")?;
             }
+            ColumnExpr::Identity { start, increment } => {
+                write!(f, " IDENTITY({start}, {increment})")?;
+            }
         }
         Ok(())
     }
@@ -738,6 +818,54 @@ impl Display for ColumnDefinition {
     }
 }
 
+/// Informational constraints of a table, they are not enforced on write.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TableConstraint {
+    PrimaryKey {
+        columns: Vec<Identifier>,
+    },
+    Unique {
+        columns: Vec<Identifier>,
+    },
+    ForeignKey {
+        columns: Vec<Identifier>,
+        ref_database: Option<Identifier>,
+        ref_table: Identifier,
+        ref_columns: Vec<Identifier>,
+    },
+}
+
+impl Display for TableConstraint {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            TableConstraint::PrimaryKey { columns } => {
+                write!(f, "PRIMARY KEY (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")
+            }
+            TableConstraint::Unique { columns } => {
+                write!(f, "UNIQUE (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")
+            }
+            TableConstraint::ForeignKey {
+                columns,
+                ref_database,
+                ref_table,
+                ref_columns,
+            } => {
+                write!(f, "FOREIGN KEY (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ") REFERENCES ")?;
+                write_period_separated_list(f, ref_database.iter().chain(Some(ref_table)))?;
+                write!(f, " (")?;
+                write_comma_separated_list(f, ref_columns)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ModifyColumnAction {
     // (column name id, masking policy name)
@@ -20,12 +20,16 @@ use crate::ast::Identifier;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UnSetStmt {
+    pub is_session: bool,
     pub source: UnSetSource,
 }
 
 impl Display for UnSetStmt {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         write!(f, "UNSET ")?;
+        if self.is_session {
+            write!(f, "SESSION ")?;
+        }
         write!(f, "{}", self.source)
     }
 }
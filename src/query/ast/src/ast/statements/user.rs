@@ -57,6 +57,15 @@ pub struct AuthOption {
     pub password: Option<String>,
 }
 
+impl AuthOption {
+    pub fn mask(&self) -> Self {
+        Self {
+            auth_type: self.auth_type.clone(),
+            password: self.password.as_ref().map(|_| "********".to_string()),
+        }
+    }
+}
+
 impl Display for AuthOption {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         if let Some(auth_type) = &self.auth_type {
@@ -1176,6 +1176,21 @@ pub fn literal_u64(i: Input) -> IResult<u64> {
     )(i)
 }
 
+pub fn literal_i64(i: Input) -> IResult<i64> {
+    map_res(
+        rule! {
+            "-"? ~ #literal_u64
+        },
+        |(opt_minus, value)| {
+            let value = match opt_minus {
+                Some(_) => -i128::from(value),
+                None => i128::from(value),
+            };
+            i64::try_from(value).map_err(|_| ErrorKind::Other("integer out of range"))
+        },
+    )(i)
+}
+
 pub fn literal_number(i: Input) -> IResult<Literal> {
     let decimal_uint = map_res(
         rule! {
@@ -81,9 +81,9 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
     let with = map(with, SetOperationElement::With);
     let set_operator = map(
         rule! {
-            ( UNION | EXCEPT | INTERSECT ) ~ ALL?
+            ( UNION | EXCEPT | INTERSECT ) ~ ( ALL | DISTINCT )?
         },
-        |(op, all)| {
+        |(op, quantifier)| {
             let op = match op.kind {
                 UNION => SetOperator::Union,
                 INTERSECT => SetOperator::Intersect,
@@ -92,7 +92,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
             };
             SetOperationElement::SetOperation {
                 op,
-                all: all.is_some(),
+                all: quantifier.map_or(false, |q| q.kind == ALL),
             }
         },
     );
@@ -459,6 +459,8 @@ pub fn join_operator(i: Input) -> IResult<JoinOperator> {
         value(JoinOperator::RightOuter, rule! { RIGHT ~ OUTER? }),
         value(JoinOperator::FullOuter, rule! { FULL ~ OUTER? }),
         value(JoinOperator::CrossJoin, rule! { CROSS }),
+        value(JoinOperator::LeftAsof, rule! { ASOF ~ LEFT ~ OUTER? }),
+        value(JoinOperator::Asof, rule! { ASOF }),
     ))(i)
 }
 
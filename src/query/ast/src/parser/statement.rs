@@ -52,6 +52,17 @@ pub enum ShowGrantOption {
     ShareName(String),
 }
 
+/// The signature and the body of a SQL UDF.
+pub struct UDFDefinition {
+    pub parameters: Vec<Identifier>,
+    pub arg_types: Vec<TypeName>,
+    pub return_type: Option<TypeName>,
+    pub returns_table: bool,
+    pub wasm_handler: Option<String>,
+    pub server: Option<UDFServerOptions>,
+    pub definition: Expr,
+}
+
 #[derive(Clone)]
 pub enum CreateDatabaseOption {
     DatabaseEngine(DatabaseEngine),
@@ -61,7 +72,7 @@ pub enum CreateDatabaseOption {
 pub fn statement(i: Input) -> IResult<StatementMsg> {
     let explain = map_res(
         rule! {
-            EXPLAIN ~ ( AST | SYNTAX | PIPELINE | JOIN | GRAPH | FRAGMENTS | RAW | MEMO )? ~ #statement
+            EXPLAIN ~ ( AST | SYNTAX | PIPELINE | JOIN | GRAPH | FRAGMENTS | RAW | MEMO | ESTIMATE )? ~ #statement
         },
         |(_, opt_kind, statement)| {
             Ok(Statement::Explain {
@@ -82,6 +93,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                     Some(TokenKind::FRAGMENTS) => ExplainKind::Fragments,
                     Some(TokenKind::RAW) => ExplainKind::Raw,
                     Some(TokenKind::MEMO) => ExplainKind::Memo("".to_string()),
+                    Some(TokenKind::ESTIMATE) => ExplainKind::Estimate,
                     None => ExplainKind::Plan,
                     _ => unreachable!(),
                 },
@@ -218,10 +230,10 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
 
     let set_variable = map(
         rule! {
-            SET ~ (GLOBAL)? ~ #ident ~ "=" ~ #subexpr(0)
+            SET ~ ( SESSION | GLOBAL )? ~ #ident ~ "=" ~ #subexpr(0)
         },
-        |(_, opt_is_global, variable, _, value)| Statement::SetVariable {
-            is_global: opt_is_global.is_some(),
+        |(_, opt_scope, variable, _, value)| Statement::SetVariable {
+            is_global: opt_scope.map(|token| token.kind) == Some(TokenKind::GLOBAL),
             variable,
             value: Box::new(value),
         },
@@ -229,10 +241,11 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
 
     let unset_variable = map(
         rule! {
-            UNSET ~ #unset_source
+            UNSET ~ ( SESSION | GLOBAL )? ~ #unset_source
         },
-        |(_, unset_source)| {
+        |(_, opt_scope, unset_source)| {
             Statement::UnSetVariable(UnSetStmt {
+                is_session: opt_scope.map(|token| token.kind) == Some(TokenKind::SESSION),
                 source: unset_source,
             })
         },
@@ -427,6 +440,18 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let comment = map(
+        rule! {
+            COMMENT ~ ( IF ~ EXISTS )? ~ ON ~ #comment_object ~ IS ~ #literal_string
+        },
+        |(_, opt_if_exists, _, object, _, comment)| {
+            Statement::Comment(CommentStmt {
+                if_exists: opt_if_exists.is_some(),
+                object,
+                comment,
+            })
+        },
+    );
     let show_create_table = map(
         rule! {
             SHOW ~ CREATE ~ TABLE ~ #period_separated_idents_1_to_3
@@ -441,13 +466,14 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
     );
     let describe_table = map(
         rule! {
-            ( DESC | DESCRIBE ) ~ #period_separated_idents_1_to_3
+            ( DESC | DESCRIBE ) ~ #period_separated_idents_1_to_3 ~ (AT ~ #travel_point)?
         },
-        |(_, (catalog, database, table))| {
+        |(_, (catalog, database, table), travel_point)| {
             Statement::DescribeTable(DescribeTableStmt {
                 catalog,
                 database,
                 table,
+                travel_point: travel_point.map(|(_, p)| p),
             })
         },
     );
@@ -462,6 +488,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                 catalog,
                 database,
                 table,
+                travel_point: None,
             })
         },
     );
@@ -509,6 +536,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             ~ ( #engine )?
             ~ ( #uri_location )?
             ~ ( CLUSTER ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
+            ~ ( PARTITION ~ ^BY ~ ^"(" ~ ^#expr ~ ^")" )?
             ~ ( #table_option )?
             ~ ( AS ~ ^#query )?
         },
@@ -522,6 +550,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             engine,
             uri_location,
             opt_cluster_by,
+            opt_partition_by,
             opt_table_options,
             opt_as_query,
         )| {
@@ -536,6 +565,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                 cluster_by: opt_cluster_by
                     .map(|(_, _, _, exprs, _)| exprs)
                     .unwrap_or_default(),
+                partition_by: opt_partition_by.map(|(_, _, _, expr, _)| expr),
                 table_options: opt_table_options.unwrap_or_default(),
                 as_query: opt_as_query.map(|(_, query)| Box::new(query)),
                 transient: opt_transient.is_some(),
@@ -685,14 +715,24 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
     );
     let create_view = map(
         rule! {
-            CREATE ~ VIEW ~ ( IF ~ NOT ~ EXISTS )?
+            CREATE ~ TEMPORARY? ~ VIEW ~ ( IF ~ NOT ~ EXISTS )?
             ~ #period_separated_idents_1_to_3
             ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
             ~ AS ~ #query
         },
-        |(_, _, opt_if_not_exists, (catalog, database, view), opt_columns, _, query)| {
+        |(
+            _,
+            opt_temporary,
+            _,
+            opt_if_not_exists,
+            (catalog, database, view),
+            opt_columns,
+            _,
+            query,
+        )| {
             Statement::CreateView(CreateViewStmt {
                 if_not_exists: opt_if_not_exists.is_some(),
+                temporary: opt_temporary.is_some(),
                 catalog,
                 database,
                 view,
@@ -735,6 +775,89 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let create_materialized_view = map(
+        rule! {
+            CREATE ~ MATERIALIZED ~ VIEW ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #period_separated_idents_1_to_3
+            ~ AS ~ #query
+        },
+        |(_, _, _, opt_if_not_exists, (catalog, database, view), _, query)| {
+            Statement::CreateMaterializedView(CreateMaterializedViewStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                view,
+                query: Box::new(query),
+            })
+        },
+    );
+    let refresh_materialized_view = map(
+        rule! {
+            REFRESH ~ MATERIALIZED ~ VIEW ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, _, (catalog, database, view))| {
+            Statement::RefreshMaterializedView(RefreshMaterializedViewStmt {
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
+    let drop_materialized_view = map(
+        rule! {
+            DROP ~ MATERIALIZED ~ VIEW ~ ( IF ~ EXISTS )? ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, (catalog, database, view))| {
+            Statement::DropMaterializedView(DropMaterializedViewStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
+
+    let create_stream = map(
+        rule! {
+            CREATE ~ STREAM ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #period_separated_idents_1_to_3
+            ~ ON ~ TABLE ~ #period_separated_idents_1_to_2
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(
+            _,
+            _,
+            opt_if_not_exists,
+            (catalog, database, stream),
+            _,
+            _,
+            (table_database, table),
+            opt_comment,
+        )| {
+            Statement::CreateStream(CreateStreamStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                stream,
+                table_database,
+                table,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_stream = map(
+        rule! {
+            DROP ~ STREAM ~ ( IF ~ EXISTS )? ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, opt_if_exists, (catalog, database, stream))| {
+            Statement::DropStream(DropStreamStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                stream,
+            })
+        },
+    );
 
     let create_index = map(
         rule! {
@@ -777,6 +900,54 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let create_inverted_index = map(
+        rule! {
+            CREATE ~ INVERTED ~ INDEX ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident ~ ON ~ #period_separated_idents_1_to_3
+            ~ "(" ~ #comma_separated_list1(ident) ~ ")"
+            ~ ( TOKENIZER ~ "=" ~ #literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            index_name,
+            _,
+            (catalog, database, table),
+            _,
+            columns,
+            _,
+            opt_tokenizer,
+        )| {
+            Statement::CreateInvertedIndex(CreateInvertedIndexStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+                columns,
+                tokenizer: opt_tokenizer.map(|(_, _, tokenizer)| tokenizer),
+            })
+        },
+    );
+
+    let drop_inverted_index = map(
+        rule! {
+            DROP ~ INVERTED ~ INDEX ~ ( IF ~ EXISTS )?
+            ~ #ident ~ ON ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+            Statement::DropInvertedIndex(DropInvertedIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
     let create_virtual_column = map(
         rule! {
             CREATE ~ VIRTUAL ~ COLUMN ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" ~ FOR ~ #period_separated_idents_1_to_3
@@ -941,30 +1112,23 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
     );
     let create_udf = map(
         rule! {
-            CREATE ~ FUNCTION ~ ( IF ~ NOT ~ EXISTS )?
+            CREATE ~ TEMPORARY? ~ FUNCTION ~ ( IF ~ NOT ~ EXISTS )?
             ~ #ident
-            ~ AS ~ "(" ~ #comma_separated_list0(ident) ~ ")"
-            ~ "->" ~ #expr
+            ~ #udf_definition
             ~ ( DESC ~ ^"=" ~ ^#literal_string )?
         },
-        |(
-            _,
-            _,
-            opt_if_not_exists,
-            udf_name,
-            _,
-            _,
-            parameters,
-            _,
-            _,
-            definition,
-            opt_description,
-        )| {
+        |(_, opt_temporary, _, opt_if_not_exists, udf_name, udf, opt_description)| {
             Statement::CreateUDF {
                 if_not_exists: opt_if_not_exists.is_some(),
+                temporary: opt_temporary.is_some(),
                 udf_name,
-                parameters,
-                definition: Box::new(definition),
+                parameters: udf.parameters,
+                arg_types: udf.arg_types,
+                return_type: udf.return_type,
+                returns_table: udf.returns_table,
+                wasm_handler: udf.wasm_handler,
+                server: udf.server,
+                definition: Box::new(udf.definition),
                 description: opt_description.map(|(_, _, description)| description),
             }
         },
@@ -1100,6 +1264,8 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                 force: Default::default(),
                 disable_variant_check: Default::default(),
                 on_error: "abort".to_string(),
+                return_file_status: Default::default(),
+                manifest: Default::default(),
             };
             for opt in opts {
                 copy_stmt.apply_option(opt);
@@ -1425,6 +1591,157 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule! { SHOW ~ NETWORK ~ POLICIES },
     );
 
+    let create_storage_profile = map(
+        rule! {
+            CREATE ~ STORAGE ~ PROFILE ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
+             ~ #uri_location
+             ~ ( COMMENT ~ Eq ~ #literal_string)?
+        },
+        |(_, _, _, opt_if_not_exists, name, uri_location, opt_comment)| {
+            let stmt = CreateStorageProfileStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                uri_location,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            };
+            Statement::CreateStorageProfile(stmt)
+        },
+    );
+    let drop_storage_profile = map(
+        rule! {
+            DROP ~ STORAGE ~ PROFILE ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            let stmt = DropStorageProfileStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            };
+            Statement::DropStorageProfile(stmt)
+        },
+    );
+    let describe_storage_profile = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ STORAGE ~ PROFILE ~ #ident
+        },
+        |(_, _, _, name)| {
+            Statement::DescStorageProfile(DescStorageProfileStmt {
+                name: name.to_string(),
+            })
+        },
+    );
+    let show_storage_profiles = value(
+        Statement::ShowStorageProfiles,
+        rule! { SHOW ~ STORAGE ~ PROFILES },
+    );
+
+    let kafka_pipe_source = map(
+        rule! {
+            KAFKA ~ CONNECTION ~ "=" ~ #options
+        },
+        |(_, _, _, connection)| CreatePipeSource::Kafka { connection },
+    );
+    let stage_pipe_source = map(
+        rule! {
+            #stage_location
+             ~ ( PATTERN ~ "=" ~ #literal_string )?
+             ~ ( OPTIONS ~ "=" ~ #options )?
+        },
+        |(location, opt_pattern, opt_options)| CreatePipeSource::Stage {
+            location,
+            pattern: opt_pattern.map(|(_, _, pattern)| pattern),
+            options: opt_options
+                .map(|(_, _, options)| options)
+                .unwrap_or_default(),
+        },
+    );
+    let create_pipe = map(
+        rule! {
+            CREATE ~ PIPE ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
+             ~ INTO ~ #period_separated_idents_1_to_2
+             ~ FROM ~ ( #kafka_pipe_source | #stage_pipe_source )
+             ~ #file_format_clause
+             ~ ( COMMENT ~ Eq ~ #literal_string)?
+        },
+        |(
+            _,
+            _,
+            opt_if_not_exists,
+            name,
+            _,
+            (database, table),
+            _,
+            source,
+            file_format,
+            opt_comment,
+        )| {
+            let stmt = CreatePipeStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                database,
+                table,
+                source,
+                file_format,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            };
+            Statement::CreatePipe(stmt)
+        },
+    );
+    let drop_pipe = map(
+        rule! {
+            DROP ~ PIPE ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, name)| {
+            let stmt = DropPipeStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            };
+            Statement::DropPipe(stmt)
+        },
+    );
+    let describe_pipe = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ PIPE ~ #ident
+        },
+        |(_, _, name)| {
+            Statement::DescPipe(DescPipeStmt {
+                name: name.to_string(),
+            })
+        },
+    );
+    let show_pipes = value(Statement::ShowPipes, rule! { SHOW ~ PIPES });
+
+    let create_sequence = map(
+        rule! {
+            CREATE ~ SEQUENCE ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
+             ~ ( START ~ ( WITH | "=" )? ~ #literal_i64 )?
+             ~ ( INCREMENT ~ ( BY | "=" )? ~ #literal_i64 )?
+             ~ ( COMMENT ~ Eq ~ #literal_string )?
+        },
+        |(_, _, opt_if_not_exists, name, opt_start, opt_increment, opt_comment)| {
+            let stmt = CreateSequenceStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                start: opt_start.map(|(_, _, start)| start),
+                increment: opt_increment.map(|(_, _, increment)| increment),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            };
+            Statement::CreateSequence(stmt)
+        },
+    );
+    let drop_sequence = map(
+        rule! {
+            DROP ~ SEQUENCE ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, name)| {
+            let stmt = DropSequenceStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            };
+            Statement::DropSequence(stmt)
+        },
+    );
+    let show_sequences = value(Statement::ShowSequences, rule! { SHOW ~ SEQUENCES });
+
     let statement_body = alt((
         rule!(
             #map(query, |query| Statement::Query(Box::new(query)))
@@ -1462,14 +1779,14 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #replace : "`REPLACE INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
         ),
         rule!(
-            #set_variable : "`SET <variable> = <value>`"
-            | #unset_variable : "`UNSET <variable>`"
+            #set_variable : "`SET [SESSION | GLOBAL] <variable> = <value>`"
+            | #unset_variable : "`UNSET [SESSION | GLOBAL] <variable>`"
         ),
         rule!(
             #show_tables : "`SHOW [FULL] TABLES [FROM <database>] [<show_limit>]`"
             | #show_columns : "`SHOW [FULL] COLUMNS FROM <table> [FROM|IN <catalog>.<database>] [<show_limit>]`"
             | #show_create_table : "`SHOW CREATE TABLE [<database>.]<table>`"
-            | #describe_table : "`DESCRIBE [<database>.]<table>`"
+            | #describe_table : "`DESCRIBE [<database>.]<table> [AT (TIMESTAMP => <expr>)]`"
             | #show_fields : "`SHOW FIELDS FROM [<database>.]<table>`"
             | #show_tables_status : "`SHOW TABLES STATUS [FROM <database>] [<show_limit>]`"
             | #show_drop_tables_status : "`SHOW DROP TABLES [FROM <database>]`"
@@ -1486,16 +1803,24 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #analyze_table : "`ANALYZE TABLE [<database>.]<table>`"
             | #exists_table : "`EXISTS TABLE [<database>.]<table>`"
             | #show_table_functions : "`SHOW TABLE_FUNCTIONS [<show_limit>]`"
+            | #comment : "`COMMENT [IF EXISTS] ON (DATABASE | TABLE | COLUMN) <object> IS '<comment>'`"
         ),
         rule!(
-            #create_view : "`CREATE VIEW [IF NOT EXISTS] [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
+            #create_view : "`CREATE [TEMPORARY] VIEW [IF NOT EXISTS] [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
             | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
             | #alter_view : "`ALTER VIEW [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
+            | #create_stream : "`CREATE STREAM [IF NOT EXISTS] [<database>.]<stream> ON TABLE [<database>.]<table> [COMMENT = '<comment>']`"
+            | #drop_stream : "`DROP STREAM [IF EXISTS] [<database>.]<stream>`"
+            | #create_materialized_view : "`CREATE MATERIALIZED VIEW [IF NOT EXISTS] [<database>.]<view> AS SELECT ...`"
+            | #refresh_materialized_view : "`REFRESH MATERIALIZED VIEW [<database>.]<view>`"
+            | #drop_materialized_view : "`DROP MATERIALIZED VIEW [IF EXISTS] [<database>.]<view>`"
         ),
         rule!(
             #create_index: "`CREATE AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP AGGREGATING INDEX [IF EXISTS] <index>`"
             | #refresh_index: "`REFRESH AGGREGATING INDEX <index> [LIMIT <limit>]`"
+            | #create_inverted_index: "`CREATE INVERTED INDEX [IF NOT EXISTS] <index> ON [<database>.]<table> (<column>, ...) [TOKENIZER = '<tokenizer>']`"
+            | #drop_inverted_index: "`DROP INVERTED INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
         ),
         rule!(
             #create_virtual_column: "`CREATE VIRTUAL COLUMN (expr, ...) FOR [<database>.]<table>`"
@@ -1511,7 +1836,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #show_roles : "`SHOW ROLES`"
             | #create_role : "`CREATE ROLE [IF NOT EXISTS] <role_name>`"
             | #drop_role : "`DROP ROLE [IF EXISTS] <role_name>`"
-            | #create_udf : "`CREATE FUNCTION [IF NOT EXISTS] <udf_name> (<parameter>, ...) -> <definition expr> [DESC = <description>]`"
+            | #create_udf : "`CREATE [TEMPORARY] FUNCTION [IF NOT EXISTS] <udf_name> { AS (<parameter>, ...) -> [TABLE] <definition> | (<parameter> <type>, ...) RETURNS { <type> | TABLE } AS <definition> | (<type>, ...) RETURNS <type> LANGUAGE WASM HANDLER = '<handler>' AS '<stage location>' | (<type>, ...) RETURNS <type> [CALLED ON NULL INPUT | RETURNS NULL ON NULL INPUT] LANGUAGE <language> HANDLER = '<handler>' ADDRESS = '<address>' } [DESC = <description>]`"
             | #drop_udf : "`DROP FUNCTION [IF EXISTS] <udf_name>`"
             | #alter_udf : "`ALTER FUNCTION <udf_name> (<parameter>, ...) -> <definition_expr> [DESC = <description>]`"
         ),
@@ -1577,14 +1902,40 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         | #create_catalog: "`CREATE CATALOG [IF NOT EXISTS] <catalog> TYPE=<catalog_type> CONNECTION=<catalog_options>`"
         | #drop_catalog: "`DROP CATALOG [IF EXISTS] <catalog>`"
         ),
+        // storage profile
+        rule!(
+            #create_storage_profile: "`CREATE STORAGE PROFILE [IF NOT EXISTS] name '<protocol>://<location>' CONNECTION = (<connection_options>) [COMMENT = '<string_literal>']`"
+            | #drop_storage_profile: "`DROP STORAGE PROFILE [IF EXISTS] name`"
+            | #describe_storage_profile: "`DESC STORAGE PROFILE name`"
+            | #show_storage_profiles: "`SHOW STORAGE PROFILES`"
+        ),
+        // pipe
+        rule!(
+            #create_pipe: "`CREATE PIPE [IF NOT EXISTS] name INTO [<database>.]<table> FROM { KAFKA CONNECTION = (<kafka_options>) | @<stage>[/<path>] [PATTERN = '<regex>'] [OPTIONS = (<stage_options>)] } FILE_FORMAT = (<format_options>) [COMMENT = '<string_literal>']`"
+            | #drop_pipe: "`DROP PIPE [IF EXISTS] name`"
+            | #describe_pipe: "`DESC PIPE name`"
+            | #show_pipes: "`SHOW PIPES`"
+        ),
+        // sequence
+        rule!(
+            #create_sequence: "`CREATE SEQUENCE [IF NOT EXISTS] name [START [WITH] <integer>] [INCREMENT [BY] <integer>] [COMMENT = '<string_literal>']`"
+            | #drop_sequence: "`DROP SEQUENCE [IF EXISTS] name`"
+            | #show_sequences: "`SHOW SEQUENCES`"
+        ),
     ));
 
     map(
         rule! {
-            #statement_body ~ (FORMAT ~ #ident)? ~ ";"? ~ &EOI
+            #settings_clause? ~ #statement_body ~ (FORMAT ~ #ident)? ~ ";"? ~ &EOI
         },
-        |(stmt, opt_format, _, _)| StatementMsg {
-            stmt,
+        |(opt_settings, stmt, opt_format, _, _)| StatementMsg {
+            stmt: match opt_settings {
+                Some(settings) => Statement::StatementWithSettings {
+                    settings,
+                    stmt: Box::new(stmt),
+                },
+                None => stmt,
+            },
             format: opt_format.map(|(_, format)| format.name),
         },
     )(i)
@@ -1663,6 +2014,24 @@ pub fn set_var_hints(i: Input) -> IResult<HintItem> {
     )(i)
 }
 
+pub fn settings_clause(i: Input) -> IResult<SettingsClause> {
+    map(
+        rule! {
+            SETTINGS ~ "(" ~ #comma_separated_list1(setting_item) ~ ")"
+        },
+        |(_, _, items, _)| SettingsClause { items },
+    )(i)
+}
+
+pub fn setting_item(i: Input) -> IResult<SettingItem> {
+    map(
+        rule! {
+            #ident ~ "=" ~ #subexpr(0)
+        },
+        |(name, _, value)| SettingItem { name, value },
+    )(i)
+}
+
 pub fn hint(i: Input) -> IResult<Hint> {
     let hint = map(
         rule! {
@@ -1699,6 +2068,7 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         DefaultExpr(Box<Expr>),
         VirtualExpr(Box<Expr>),
         StoredExpr(Box<Expr>),
+        Identity { start: i64, increment: i64 },
     }
 
     let nullable = alt((
@@ -1724,6 +2094,18 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             },
             |(_, _, _, stored_expr, _, _)| ColumnConstraint::StoredExpr(Box::new(stored_expr)),
         ),
+        map(
+            rule! {
+                ( IDENTITY | AUTOINCREMENT )
+                ~ ( "(" ~ ^#literal_i64 ~ ^"," ~ ^#literal_i64 ~ ^")" )?
+            },
+            |(_, opt_args)| {
+                let (start, increment) = opt_args
+                    .map(|(_, start, _, increment, _)| (start, increment))
+                    .unwrap_or((1, 1));
+                ColumnConstraint::Identity { start, increment }
+            },
+        ),
     ));
 
     let comment = map(
@@ -1739,7 +2121,7 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             ~ #type_name
             ~ ( #nullable | #expr )*
             ~ ( #comment )?
-            : "`<column name> <type> [DEFAULT <expr>] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [COMMENT '<comment>']`"
+            : "`<column name> <type> [DEFAULT <expr>] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [IDENTITY [(<start>, <increment>)]] [COMMENT '<comment>']`"
         },
         |(name, data_type, constraints, comment)| {
             let mut def = ColumnDefinition {
@@ -1764,6 +2146,9 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
                     ColumnConstraint::StoredExpr(stored_expr) => {
                         def.expr = Some(ColumnExpr::Stored(stored_expr))
                     }
+                    ColumnConstraint::Identity { start, increment } => {
+                        def.expr = Some(ColumnExpr::Identity { start, increment })
+                    }
                 }
             }
             def
@@ -1959,12 +2344,68 @@ pub fn grant_option(i: Input) -> IResult<PrincipalIdentity> {
     )(i)
 }
 
+pub fn table_constraint(i: Input) -> IResult<TableConstraint> {
+    let primary_key = map(
+        rule! {
+            PRIMARY ~ KEY ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, columns, _)| TableConstraint::PrimaryKey { columns },
+    );
+    let unique = map(
+        rule! {
+            UNIQUE ~ "(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, columns, _)| TableConstraint::Unique { columns },
+    );
+    let foreign_key = map(
+        rule! {
+            FOREIGN ~ KEY ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+            ~ ^REFERENCES ~ ^#period_separated_idents_1_to_2
+            ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, columns, _, _, (ref_database, ref_table), _, ref_columns, _)| {
+            TableConstraint::ForeignKey {
+                columns,
+                ref_database,
+                ref_table,
+                ref_columns,
+            }
+        },
+    );
+
+    rule!(
+        #primary_key
+        | #unique
+        | #foreign_key
+        : "`PRIMARY KEY (<column>, ...)`, `UNIQUE (<column>, ...)` or `FOREIGN KEY (<column>, ...) REFERENCES <table> (<column>, ...)`"
+    )(i)
+}
+
 pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
+    enum TableElement {
+        Column(ColumnDefinition),
+        Constraint(TableConstraint),
+    }
+
+    let element = alt((
+        map(table_constraint, TableElement::Constraint),
+        map(column_def, TableElement::Column),
+    ));
     let columns = map(
         rule! {
-            "(" ~ ^#comma_separated_list1(column_def) ~ ^")"
+            "(" ~ ^#comma_separated_list1(element) ~ ^")"
+        },
+        |(_, elements, _)| {
+            let mut columns = Vec::new();
+            let mut constraints = Vec::new();
+            for element in elements {
+                match element {
+                    TableElement::Column(column) => columns.push(column),
+                    TableElement::Constraint(constraint) => constraints.push(constraint),
+                }
+            }
+            CreateTableSource::Columns(columns, constraints)
         },
-        |(_, columns, _)| CreateTableSource::Columns(columns),
     );
     let like = map(
         rule! {
@@ -1977,9 +2418,187 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
         },
     );
 
+    let clone = map(
+        rule! {
+            CLONE ~ #period_separated_idents_1_to_3 ~ (AT ~ #travel_point)?
+        },
+        |(_, (catalog, database, table), travel_point)| CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point: travel_point.map(|(_, p)| p),
+        },
+    );
+
     rule!(
         #columns
         | #like
+        | #clone
+    )(i)
+}
+
+pub fn udf_definition(i: Input) -> IResult<UDFDefinition> {
+    let lambda_table = map(
+        rule! {
+            AS ~ "(" ~ #comma_separated_list0(ident) ~ ")"
+            ~ "->" ~ TABLE ~ #udf_table_body
+        },
+        |(_, _, parameters, _, _, _, definition)| UDFDefinition {
+            parameters,
+            arg_types: vec![],
+            return_type: None,
+            returns_table: true,
+            wasm_handler: None,
+            server: None,
+            definition,
+        },
+    );
+    let lambda = map(
+        rule! {
+            AS ~ "(" ~ #comma_separated_list0(ident) ~ ")"
+            ~ "->" ~ #expr
+        },
+        |(_, _, parameters, _, _, definition)| UDFDefinition {
+            parameters,
+            arg_types: vec![],
+            return_type: None,
+            returns_table: false,
+            wasm_handler: None,
+            server: None,
+            definition,
+        },
+    );
+    let typed_table = map(
+        rule! {
+            "(" ~ #comma_separated_list0(udf_parameter) ~ ")"
+            ~ RETURNS ~ TABLE ~ AS ~ #udf_table_body
+        },
+        |(_, parameters, _, _, _, _, definition)| {
+            let (parameters, arg_types) = parameters.into_iter().unzip();
+            UDFDefinition {
+                parameters,
+                arg_types,
+                return_type: None,
+                returns_table: true,
+                wasm_handler: None,
+                server: None,
+                definition,
+            }
+        },
+    );
+    let typed = map(
+        rule! {
+            "(" ~ #comma_separated_list0(udf_parameter) ~ ")"
+            ~ RETURNS ~ #type_name ~ AS ~ #expr
+        },
+        |(_, parameters, _, _, return_type, _, definition)| {
+            let (parameters, arg_types) = parameters.into_iter().unzip();
+            UDFDefinition {
+                parameters,
+                arg_types,
+                return_type: Some(return_type),
+                returns_table: false,
+                wasm_handler: None,
+                server: None,
+                definition,
+            }
+        },
+    );
+    let wasm = map(
+        rule! {
+            "(" ~ #comma_separated_list0(type_name) ~ ")"
+            ~ RETURNS ~ #type_name
+            ~ LANGUAGE ~ WASM ~ HANDLER ~ ^"=" ~ ^#literal_string
+            ~ AS ~ ^#consumed(literal_string)
+        },
+        |(_, arg_types, _, _, return_type, _, _, _, _, handler, _, (span, location))| {
+            UDFDefinition {
+                parameters: vec![],
+                arg_types,
+                return_type: Some(return_type),
+                returns_table: false,
+                wasm_handler: Some(handler),
+                server: None,
+                definition: Expr::Literal {
+                    span: transform_span(span.0),
+                    lit: Literal::String(location),
+                },
+            }
+        },
+    );
+
+    let server = map(
+        rule! {
+            "(" ~ #comma_separated_list0(type_name) ~ ")"
+            ~ RETURNS ~ #type_name
+            ~ #udf_null_handling?
+            ~ LANGUAGE ~ #ident ~ HANDLER ~ ^"=" ~ ^#literal_string
+            ~ ADDRESS ~ ^"=" ~ ^#consumed(literal_string)
+        },
+        |(
+            _,
+            arg_types,
+            _,
+            _,
+            return_type,
+            returns_null_on_null_input,
+            _,
+            language,
+            _,
+            _,
+            handler,
+            _,
+            _,
+            (span, address),
+        )| UDFDefinition {
+            parameters: vec![],
+            arg_types,
+            return_type: Some(return_type),
+            returns_table: false,
+            wasm_handler: None,
+            server: Some(UDFServerOptions {
+                language: language.name,
+                handler,
+                returns_null_on_null_input: returns_null_on_null_input.unwrap_or(false),
+            }),
+            definition: Expr::Literal {
+                span: transform_span(span.0),
+                lit: Literal::String(address),
+            },
+        },
+    );
+
+    rule!(
+        #lambda_table
+        | #lambda
+        | #wasm
+        | #server
+        | #typed_table
+        | #typed
+    )(i)
+}
+
+fn udf_null_handling(i: Input) -> IResult<bool> {
+    alt((
+        value(false, rule! { CALLED ~ ON ~ NULL ~ INPUT }),
+        value(true, rule! { RETURNS ~ NULL ~ ON ~ NULL ~ INPUT }),
+    ))(i)
+}
+
+fn udf_parameter(i: Input) -> IResult<(Identifier, TypeName)> {
+    rule! { #ident ~ #type_name }
+    (i)
+}
+
+/// The query of a table UDF, kept as a subquery expression.
+fn udf_table_body(i: Input) -> IResult<Expr> {
+    map(
+        consumed(rule! { "(" ~ #query ~ ")" }),
+        |(span, (_, query, _))| Expr::Subquery {
+            span: transform_span(span.0),
+            modifier: None,
+            subquery: Box::new(query),
+        },
     )(i)
 }
 
@@ -2109,6 +2728,13 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _)| AlterTableAction::DropTableClusterKey,
     );
 
+    let drop_partition = map(
+        rule! {
+            DROP ~ PARTITION ~ ^#expr
+        },
+        |(_, _, partition)| AlterTableAction::DropPartition { partition },
+    );
+
     let recluster_table = map(
         rule! {
             RECLUSTER ~ FINAL? ~ ( WHERE ~ ^#expr )? ~ ( LIMIT ~ #literal_u64 )?
@@ -2134,6 +2760,24 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _, set_options, _)| AlterTableAction::SetOptions { set_options },
     );
 
+    let set_table_tags = map(
+        rule! {
+            SET ~ TAG ~ #comma_separated_list1(table_tag)
+        },
+        |(_, _, tags)| AlterTableAction::SetTags {
+            tags: BTreeMap::from_iter(tags),
+        },
+    );
+
+    let unset_table_tags = map(
+        rule! {
+            UNSET ~ TAG ~ #comma_separated_list1(ident)
+        },
+        |(_, _, tags)| AlterTableAction::UnsetTags {
+            tags: tags.iter().map(|tag| tag.name.to_lowercase()).collect(),
+        },
+    );
+
     rule!(
         #rename_table
         | #rename_column
@@ -2142,9 +2786,66 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         | #modify_column
         | #alter_table_cluster_key
         | #drop_table_cluster_key
+        | #drop_partition
         | #recluster_table
         | #revert_table
         | #set_table_options
+        | #set_table_tags
+        | #unset_table_tags
+    )(i)
+}
+
+pub fn comment_object(i: Input) -> IResult<CommentObject> {
+    let database = map(
+        rule! {
+            DATABASE ~ #period_separated_idents_1_to_2
+        },
+        |(_, (catalog, database))| CommentObject::Database { catalog, database },
+    );
+    let table = map(
+        rule! {
+            TABLE ~ #period_separated_idents_1_to_3
+        },
+        |(_, (catalog, database, table))| CommentObject::Table {
+            catalog,
+            database,
+            table,
+        },
+    );
+    let column = map(
+        rule! {
+            COLUMN ~ #ident ~ "." ~ #period_separated_idents_1_to_3
+        },
+        |(_, first, _, (ident0, ident1, column))| {
+            let mut idents = vec![first];
+            idents.extend(ident0);
+            idents.extend(ident1);
+            // Safe unwrap: `idents` contains at least one identifier.
+            let table = idents.pop().unwrap();
+            let database = idents.pop();
+            let catalog = idents.pop();
+            CommentObject::Column {
+                catalog,
+                database,
+                table,
+                column,
+            }
+        },
+    );
+
+    rule!(
+        #database
+        | #table
+        | #column
+    )(i)
+}
+
+pub fn table_tag(i: Input) -> IResult<(String, String)> {
+    map(
+        rule! {
+            #ident ~ "=" ~ #literal_string
+        },
+        |(key, _, value)| (key.name.to_lowercase(), value),
     )(i)
 }
 
@@ -2166,11 +2867,13 @@ pub fn optimize_table_action(i: Input) -> IResult<OptimizeTableAction> {
                 before: opt_travel_point.map(|(_, p)| p),
             },
         ),
-        map(rule! { COMPACT ~ (SEGMENT)?}, |(_, opt_segment)| {
-            OptimizeTableAction::Compact {
+        map(
+            rule! { COMPACT ~ (SEGMENT)? ~ (PARTITION ~ ^#expr)?},
+            |(_, opt_segment, opt_partition)| OptimizeTableAction::Compact {
                 target: opt_segment.map_or(CompactTarget::Block, |_| CompactTarget::Segment),
-            }
-        }),
+                partition: opt_partition.map(|(_, p)| p),
+            },
+        ),
     ))(i)
 }
 
@@ -2319,6 +3022,10 @@ pub fn engine(i: Input) -> IResult<Engine> {
         value(Engine::Fuse, rule! { FUSE }),
         value(Engine::View, rule! { VIEW }),
         value(Engine::Random, rule! { RANDOM }),
+        value(Engine::External, rule! { EXTERNAL }),
+        value(Engine::Delta, rule! { DELTA }),
+        value(Engine::MySql, rule! { MYSQL }),
+        value(Engine::Postgres, rule! { POSTGRES }),
     ));
 
     map(
@@ -2479,6 +3186,14 @@ pub fn copy_option(i: Input) -> IResult<CopyOption> {
             rule! {DISABLE_VARIANT_CHECK ~ "=" ~ #literal_bool},
             |(_, _, disable_variant_check)| CopyOption::DisableVariantCheck(disable_variant_check),
         ),
+        map(
+            rule! {RETURN_FILE_STATUS ~ "=" ~ #literal_bool},
+            |(_, _, return_file_status)| CopyOption::ReturnFileStatus(return_file_status),
+        ),
+        map(
+            rule! { MANIFEST ~ "=" ~ #literal_bool },
+            |(_, _, manifest)| CopyOption::Manifest(manifest),
+        ),
     ))(i)
 }
 
@@ -293,6 +293,8 @@ pub enum TokenKind {
     ALLOWED_IP_LIST,
     #[token("ADD", ignore(ascii_case))]
     ADD,
+    #[token("ADDRESS", ignore(ascii_case))]
+    ADDRESS,
     #[token("AFTER", ignore(ascii_case))]
     AFTER,
     #[token("AGGREGATING", ignore(ascii_case))]
@@ -303,6 +305,8 @@ pub enum TokenKind {
     ARGS,
     #[token("AUTO", ignore(ascii_case))]
     AUTO,
+    #[token("AUTOINCREMENT", ignore(ascii_case))]
+    AUTOINCREMENT,
     #[token("SOME", ignore(ascii_case))]
     SOME,
     #[token("ALTER", ignore(ascii_case))]
@@ -323,6 +327,8 @@ pub enum TokenKind {
     AT,
     #[token("ASC", ignore(ascii_case))]
     ASC,
+    #[token("ASOF", ignore(ascii_case))]
+    ASOF,
     #[token("ANTI", ignore(ascii_case))]
     ANTI,
     #[token("BEFORE", ignore(ascii_case))]
@@ -351,6 +357,8 @@ pub enum TokenKind {
     BZ2,
     #[token("CALL", ignore(ascii_case))]
     CALL,
+    #[token("CALLED", ignore(ascii_case))]
+    CALLED,
     #[token("CASE", ignore(ascii_case))]
     CASE,
     #[token("CAST", ignore(ascii_case))]
@@ -361,6 +369,8 @@ pub enum TokenKind {
     CATALOGS,
     #[token("CENTURY", ignore(ascii_case))]
     CENTURY,
+    #[token("CLONE", ignore(ascii_case))]
+    CLONE,
     #[token("CLUSTER", ignore(ascii_case))]
     CLUSTER,
     #[token("COMMENT", ignore(ascii_case))]
@@ -433,6 +443,8 @@ pub enum TokenKind {
     DEFLATE,
     #[token("DELETE", ignore(ascii_case))]
     DELETE,
+    #[token("DELTA", ignore(ascii_case))]
+    DELTA,
     #[token("DESC", ignore(ascii_case))]
     DESC,
     #[token("DESCRIBE", ignore(ascii_case))]
@@ -477,12 +489,16 @@ pub enum TokenKind {
     EPOCH,
     #[token("ESCAPE", ignore(ascii_case))]
     ESCAPE,
+    #[token("ESTIMATE", ignore(ascii_case))]
+    ESTIMATE,
     #[token("EXISTS", ignore(ascii_case))]
     EXISTS,
     #[token("EXPLAIN", ignore(ascii_case))]
     EXPLAIN,
     #[token("EXPIRE", ignore(ascii_case))]
     EXPIRE,
+    #[token("EXTERNAL", ignore(ascii_case))]
+    EXTERNAL,
     #[token("EXTRACT", ignore(ascii_case))]
     EXTRACT,
     #[token("FALSE", ignore(ascii_case))]
@@ -515,6 +531,8 @@ pub enum TokenKind {
     FOR,
     #[token("FORCE", ignore(ascii_case))]
     FORCE,
+    #[token("FOREIGN", ignore(ascii_case))]
+    FOREIGN,
     #[token("FORMAT", ignore(ascii_case))]
     FORMAT,
     #[token("FOLLOWING", ignore(ascii_case))]
@@ -549,6 +567,8 @@ pub enum TokenKind {
     GROUP,
     #[token("GZIP", ignore(ascii_case))]
     GZIP,
+    #[token("HANDLER", ignore(ascii_case))]
+    HANDLER,
     #[token("HAVING", ignore(ascii_case))]
     HAVING,
     #[token("HISTORY", ignore(ascii_case))]
@@ -565,14 +585,20 @@ pub enum TokenKind {
     INTERSECT,
     #[token("IDENTIFIED", ignore(ascii_case))]
     IDENTIFIED,
+    #[token("IDENTITY", ignore(ascii_case))]
+    IDENTITY,
     #[token("IF", ignore(ascii_case))]
     IF,
     #[token("IN", ignore(ascii_case))]
     IN,
     #[token("INDEX", ignore(ascii_case))]
     INDEX,
+    #[token("INCREMENT", ignore(ascii_case))]
+    INCREMENT,
     #[token("INNER", ignore(ascii_case))]
     INNER,
+    #[token("INPUT", ignore(ascii_case))]
+    INPUT,
     #[token("INSERT", ignore(ascii_case))]
     INSERT,
     #[token("INT", ignore(ascii_case))]
@@ -591,6 +617,8 @@ pub enum TokenKind {
     INTERVAL,
     #[token("INTO", ignore(ascii_case))]
     INTO,
+    #[token("INVERTED", ignore(ascii_case))]
+    INVERTED,
     #[token("IS", ignore(ascii_case))]
     IS,
     #[token("ISODOW", ignore(ascii_case))]
@@ -605,6 +633,8 @@ pub enum TokenKind {
     JULIAN,
     #[token("JWT", ignore(ascii_case))]
     JWT,
+    #[token("KAFKA", ignore(ascii_case))]
+    KAFKA,
     #[token("KEY", ignore(ascii_case))]
     KEY,
     #[token("KILL", ignore(ascii_case))]
@@ -616,6 +646,8 @@ pub enum TokenKind {
     /// L2DISTANCE op, from https://github.com/pgvector/pgvector
     #[token("<->")]
     L2DISTANCE,
+    #[token("LANGUAGE", ignore(ascii_case))]
+    LANGUAGE,
     #[token("LEADING", ignore(ascii_case))]
     LEADING,
     #[token("LEFT", ignore(ascii_case))]
@@ -632,6 +664,8 @@ pub enum TokenKind {
     MASKING,
     #[token("MAP", ignore(ascii_case))]
     MAP,
+    #[token("MANIFEST", ignore(ascii_case))]
+    MANIFEST,
     #[token("MAX_FILE_SIZE", ignore(ascii_case))]
     MAX_FILE_SIZE,
     #[token("MASTER_KEY", ignore(ascii_case))]
@@ -656,6 +690,8 @@ pub enum TokenKind {
     MODIFY,
     #[token("MATERIALIZED", ignore(ascii_case))]
     MATERIALIZED,
+    #[token("MYSQL", ignore(ascii_case))]
+    MYSQL,
     #[token("NON_DISPLAY", ignore(ascii_case))]
     NON_DISPLAY,
     #[token("NATURAL", ignore(ascii_case))]
@@ -706,8 +742,12 @@ pub enum TokenKind {
     PARQUET,
     #[token("PATTERN", ignore(ascii_case))]
     PATTERN,
+    #[token("PIPE", ignore(ascii_case))]
+    PIPE,
     #[token("PIPELINE", ignore(ascii_case))]
     PIPELINE,
+    #[token("PIPES", ignore(ascii_case))]
+    PIPES,
     #[token("PLAINTEXT_PASSWORD", ignore(ascii_case))]
     PLAINTEXT_PASSWORD,
     #[token("POLICIES", ignore(ascii_case))]
@@ -716,8 +756,14 @@ pub enum TokenKind {
     POLICY,
     #[token("POSITION", ignore(ascii_case))]
     POSITION,
+    #[token("POSTGRES", ignore(ascii_case))]
+    POSTGRES,
     #[token("PROCESSLIST", ignore(ascii_case))]
     PROCESSLIST,
+    #[token("PROFILE", ignore(ascii_case))]
+    PROFILE,
+    #[token("PROFILES", ignore(ascii_case))]
+    PROFILES,
     #[token("PURGE", ignore(ascii_case))]
     PURGE,
     #[token("QUARTER", ignore(ascii_case))]
@@ -736,6 +782,8 @@ pub enum TokenKind {
     RECORD_DELIMITER,
     #[token("REFERENCE_USAGE", ignore(ascii_case))]
     REFERENCE_USAGE,
+    #[token("REFERENCES", ignore(ascii_case))]
+    REFERENCES,
     #[token("REFRESH", ignore(ascii_case))]
     REFRESH,
     #[token("REGEXP", ignore(ascii_case))]
@@ -760,6 +808,8 @@ pub enum TokenKind {
     PRECISION,
     #[token("PRESIGN", ignore(ascii_case))]
     PRESIGN,
+    #[token("PRIMARY", ignore(ascii_case))]
+    PRIMARY,
     #[token("PRIVILEGES", ignore(ascii_case))]
     PRIVILEGES,
     #[token("REMOVE", ignore(ascii_case))]
@@ -774,6 +824,8 @@ pub enum TokenKind {
     RETURN,
     #[token("RETURNS", ignore(ascii_case))]
     RETURNS,
+    #[token("RETURN_FILE_STATUS", ignore(ascii_case))]
+    RETURN_FILE_STATUS,
     #[token("RUN", ignore(ascii_case))]
     RUN,
     #[token("GRANTS", ignore(ascii_case))]
@@ -798,6 +850,12 @@ pub enum TokenKind {
     UNPIVOT,
     #[token("SEGMENT", ignore(ascii_case))]
     SEGMENT,
+    #[token("SEQUENCE", ignore(ascii_case))]
+    SEQUENCE,
+    #[token("SEQUENCES", ignore(ascii_case))]
+    SEQUENCES,
+    #[token("SESSION", ignore(ascii_case))]
+    SESSION,
     #[token("SET", ignore(ascii_case))]
     SET,
     #[token("UNSET", ignore(ascii_case))]
@@ -832,6 +890,8 @@ pub enum TokenKind {
     SPLIT_SIZE,
     #[token("STAGE", ignore(ascii_case))]
     STAGE,
+    #[token("START", ignore(ascii_case))]
+    START,
     #[token("SYNTAX", ignore(ascii_case))]
     SYNTAX,
     #[token("USAGE", ignore(ascii_case))]
@@ -848,8 +908,12 @@ pub enum TokenKind {
     SUPER,
     #[token("STATUS", ignore(ascii_case))]
     STATUS,
+    #[token("STORAGE", ignore(ascii_case))]
+    STORAGE,
     #[token("STORED", ignore(ascii_case))]
     STORED,
+    #[token("STREAM", ignore(ascii_case))]
+    STREAM,
     #[token("STRING", ignore(ascii_case))]
     STRING,
     #[token("SUBSTRING", ignore(ascii_case))]
@@ -866,6 +930,10 @@ pub enum TokenKind {
     TABLE,
     #[token("TABLES", ignore(ascii_case))]
     TABLES,
+    #[token("TAG", ignore(ascii_case))]
+    TAG,
+    #[token("TEMPORARY", ignore(ascii_case))]
+    TEMPORARY,
     #[token("TEXT", ignore(ascii_case))]
     TEXT,
     #[token("TENANTSETTING", ignore(ascii_case))]
@@ -890,6 +958,8 @@ pub enum TokenKind {
     TO,
     #[token("TOKEN", ignore(ascii_case))]
     TOKEN,
+    #[token("TOKENIZER", ignore(ascii_case))]
+    TOKENIZER,
     #[token("TRAILING", ignore(ascii_case))]
     TRAILING,
     #[token("TRANSIENT", ignore(ascii_case))]
@@ -922,6 +992,8 @@ pub enum TokenKind {
     UINT8,
     #[token("UNDROP", ignore(ascii_case))]
     UNDROP,
+    #[token("UNIQUE", ignore(ascii_case))]
+    UNIQUE,
     #[token("UNSIGNED", ignore(ascii_case))]
     UNSIGNED,
     #[token("URL", ignore(ascii_case))]
@@ -950,6 +1022,8 @@ pub enum TokenKind {
     VIEW,
     #[token("VIRTUAL", ignore(ascii_case))]
     VIRTUAL,
+    #[token("WASM", ignore(ascii_case))]
+    WASM,
     #[token("WEEK", ignore(ascii_case))]
     WEEK,
     #[token("WHEN", ignore(ascii_case))]
@@ -1294,6 +1368,7 @@ impl TokenKind {
             | TokenKind::WHEN => true,
             | TokenKind::ARRAY
             | TokenKind::AS
+            | TokenKind::ASOF
             | TokenKind::BETWEEN
             | TokenKind::CREATE
             | TokenKind::ATTACH
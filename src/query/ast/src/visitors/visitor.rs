@@ -442,6 +442,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_show_columns(&mut self, _stmt: &'ast ShowColumnsStmt) {}
 
+    fn visit_comment(&mut self, _stmt: &'ast CommentStmt) {}
+
     fn visit_show_create_table(&mut self, _stmt: &'ast ShowCreateTableStmt) {}
 
     fn visit_describe_table(&mut self, _stmt: &'ast DescribeTableStmt) {}
@@ -482,10 +484,22 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &'ast DropViewStmt) {}
 
+    fn visit_create_materialized_view(&mut self, _stmt: &'ast CreateMaterializedViewStmt) {}
+
+    fn visit_refresh_materialized_view(&mut self, _stmt: &'ast RefreshMaterializedViewStmt) {}
+
+    fn visit_drop_materialized_view(&mut self, _stmt: &'ast DropMaterializedViewStmt) {}
+
+    fn visit_create_stream(&mut self, _stmt: &'ast CreateStreamStmt) {}
+
+    fn visit_drop_stream(&mut self, _stmt: &'ast DropStreamStmt) {}
+
     fn visit_create_index(&mut self, _stmt: &'ast CreateIndexStmt) {}
 
     fn visit_drop_index(&mut self, _stmt: &'ast DropIndexStmt) {}
     fn visit_refresh_index(&mut self, _stmt: &'ast RefreshIndexStmt) {}
+    fn visit_create_inverted_index(&mut self, _stmt: &'ast CreateInvertedIndexStmt) {}
+    fn visit_drop_inverted_index(&mut self, _stmt: &'ast DropInvertedIndexStmt) {}
 
     fn visit_create_virtual_column(&mut self, _stmt: &'ast CreateVirtualColumnStmt) {}
 
@@ -602,6 +616,22 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_show_network_policies(&mut self) {}
 
+    fn visit_create_storage_profile(&mut self, _stmt: &'ast CreateStorageProfileStmt) {}
+
+    fn visit_drop_storage_profile(&mut self, _stmt: &'ast DropStorageProfileStmt) {}
+
+    fn visit_desc_storage_profile(&mut self, _stmt: &'ast DescStorageProfileStmt) {}
+
+    fn visit_show_storage_profiles(&mut self) {}
+
+    fn visit_create_pipe(&mut self, _stmt: &'ast CreatePipeStmt) {}
+
+    fn visit_drop_pipe(&mut self, _stmt: &'ast DropPipeStmt) {}
+
+    fn visit_desc_pipe(&mut self, _stmt: &'ast DescPipeStmt) {}
+
+    fn visit_show_pipes(&mut self) {}
+
     fn visit_with(&mut self, with: &'ast With) {
         let With { ctes, .. } = with;
         for cte in ctes.iter() {
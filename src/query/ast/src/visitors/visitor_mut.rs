@@ -457,6 +457,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_show_columns(&mut self, _stmt: &mut ShowColumnsStmt) {}
 
+    fn visit_comment(&mut self, _stmt: &mut CommentStmt) {}
+
     fn visit_show_create_table(&mut self, _stmt: &mut ShowCreateTableStmt) {}
 
     fn visit_describe_table(&mut self, _stmt: &mut DescribeTableStmt) {}
@@ -497,10 +499,22 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &mut DropViewStmt) {}
 
+    fn visit_create_materialized_view(&mut self, _stmt: &mut CreateMaterializedViewStmt) {}
+
+    fn visit_refresh_materialized_view(&mut self, _stmt: &mut RefreshMaterializedViewStmt) {}
+
+    fn visit_drop_materialized_view(&mut self, _stmt: &mut DropMaterializedViewStmt) {}
+
+    fn visit_create_stream(&mut self, _stmt: &mut CreateStreamStmt) {}
+
+    fn visit_drop_stream(&mut self, _stmt: &mut DropStreamStmt) {}
+
     fn visit_create_index(&mut self, _stmt: &mut CreateIndexStmt) {}
 
     fn visit_drop_index(&mut self, _stmt: &mut DropIndexStmt) {}
     fn visit_refresh_index(&mut self, _stmt: &mut RefreshIndexStmt) {}
+    fn visit_create_inverted_index(&mut self, _stmt: &mut CreateInvertedIndexStmt) {}
+    fn visit_drop_inverted_index(&mut self, _stmt: &mut DropInvertedIndexStmt) {}
 
     fn visit_create_virtual_column(&mut self, _stmt: &mut CreateVirtualColumnStmt) {}
 
@@ -617,6 +631,22 @@ pub trait VisitorMut: Sized {
 
     fn visit_show_network_policies(&mut self) {}
 
+    fn visit_create_storage_profile(&mut self, _stmt: &mut CreateStorageProfileStmt) {}
+
+    fn visit_drop_storage_profile(&mut self, _stmt: &mut DropStorageProfileStmt) {}
+
+    fn visit_desc_storage_profile(&mut self, _stmt: &mut DescStorageProfileStmt) {}
+
+    fn visit_show_storage_profiles(&mut self) {}
+
+    fn visit_create_pipe(&mut self, _stmt: &mut CreatePipeStmt) {}
+
+    fn visit_drop_pipe(&mut self, _stmt: &mut DropPipeStmt) {}
+
+    fn visit_desc_pipe(&mut self, _stmt: &mut DescPipeStmt) {}
+
+    fn visit_show_pipes(&mut self) {}
+
     fn visit_with(&mut self, with: &mut With) {
         let With { ctes, .. } = with;
         for cte in ctes.iter_mut() {
@@ -82,8 +82,10 @@ fn test_statement() {
         r#"show create table a.b format TabSeparatedWithNamesAndTypes;"#,
         r#"explain pipeline select a from b;"#,
         r#"explain pipeline select a from t1 ignore_result;"#,
+        r#"explain estimate select a from b;"#,
         r#"describe a;"#,
         r#"describe a format TabSeparatedWithNamesAndTypes;"#,
+        r#"describe a at (timestamp => '2023-06-26 09:49:02.038483'::TIMESTAMP);"#,
         r#"create table a (c decimal(38, 0))"#,
         r#"create table a (c decimal(38))"#,
         r#"create table if not exists a.b (c integer not null default 1, b varchar);"#,
@@ -93,6 +95,7 @@ fn test_statement() {
         r#"create table if not exists a.b (a int, b int, c int generated always as (a + b) virtual );"#,
         r#"create table a.b like c.d;"#,
         r#"create table t like t2 engine = memory;"#,
+        r#"create table t clone t2 at (snapshot => 'abc');"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900');"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/'
              connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900')
@@ -111,10 +114,20 @@ fn test_statement() {
         r#"drop database if exists t;"#,
         r#"create table c(a DateTime null, b DateTime(3));"#,
         r#"create view v as select number % 3 as a from numbers(1000);"#,
+        r#"create temporary view v as select number % 3 as a from numbers(1000);"#,
+        r#"create temporary function f as (a) -> a + 1;"#,
+        r#"create function f(a int) returns int as a + 1;"#,
+        r#"create function wasm_add(int, int) returns int language wasm handler = 'add' as '@udf/add.wasm';"#,
+        r#"create function string_reverse(string) returns string returns null on null input language python handler = 'string_reverse' address = 'http://0.0.0.0:8815';"#,
         r#"alter view v as select number % 3 as a from numbers(1000);"#,
         r#"drop view v;"#,
         r#"create view v1(c1) as select number % 3 as a from numbers(1000);"#,
         r#"alter view v1(c2) as select number % 3 as a from numbers(1000);"#,
+        r#"create stream if not exists s on table db.t comment = 'c';"#,
+        r#"drop stream if exists s;"#,
+        r#"create materialized view if not exists db.mv as select * from t4;"#,
+        r#"refresh materialized view mv;"#,
+        r#"drop materialized view if exists db.mv;"#,
         r#"rename table d.t to e.s;"#,
         r#"truncate table test;"#,
         r#"truncate table test_db.test;"#,
@@ -124,6 +137,9 @@ fn test_statement() {
         r#"CREATE TABLE t(c1 int not null, c2 bigint not null, c3 varchar not null);"#,
         r#"CREATE TABLE t(c1 varbinary);"#,
         r#"CREATE TABLE t(c1 int default 1);"#,
+        r#"CREATE TABLE t(id bigint identity, n bigint autoincrement(10, -2));"#,
+        r#"CREATE TABLE t(a int, b int, PRIMARY KEY (a), FOREIGN KEY (b) REFERENCES t2 (c));"#,
+        r#"CREATE TABLE t(a int, d date) PARTITION BY (to_yyyymm(d));"#,
         r#"create table abc as (select * from xyz limit 10)"#,
         r#"ALTER USER u1 IDENTIFIED BY '123456';"#,
         r#"ALTER USER u1 WITH DEFAULT_ROLE = role1;"#,
@@ -147,6 +163,7 @@ fn test_statement() {
         r#"select * from a anti join b on a.a = b.a;"#,
         r#"select * from a right semi join b on a.a = b.a;"#,
         r#"select * from a right anti join b on a.a = b.a;"#,
+        r#"select * from a asof left join b on a.a = b.a;"#,
         r#"select * from a full outer join b on a.a = b.a;"#,
         r#"select * from a inner join b on a.a = b.a;"#,
         r#"select * from a left outer join b using(a);"#,
@@ -182,10 +199,12 @@ fn test_statement() {
         r#"drop role if exists 'test'"#,
         r#"OPTIMIZE TABLE t COMPACT SEGMENT LIMIT 10;"#,
         r#"OPTIMIZE TABLE t COMPACT LIMIT 10;"#,
+        r#"OPTIMIZE TABLE t COMPACT SEGMENT PARTITION 202301 LIMIT 10;"#,
         r#"OPTIMIZE TABLE t PURGE BEFORE (SNAPSHOT => '9828b23f74664ff3806f44bbc1925ea5') LIMIT 10;"#,
         r#"OPTIMIZE TABLE t PURGE BEFORE (TIMESTAMP => '2023-06-26 09:49:02.038483'::TIMESTAMP) LIMIT 10;"#,
         r#"ALTER TABLE t CLUSTER BY(c1);"#,
         r#"ALTER TABLE t DROP CLUSTER KEY;"#,
+        r#"ALTER TABLE t DROP PARTITION 202301;"#,
         r#"ALTER TABLE t RECLUSTER FINAL WHERE c1 > 0 LIMIT 10;"#,
         r#"ALTER TABLE t ADD COLUMN c int null;"#,
         r#"ALTER TABLE t ADD COLUMN a float default 1.1 COMMENT 'hello' FIRST;"#,
@@ -327,6 +346,13 @@ fn test_statement() {
                     skip_header = 1
                 )
                 size_limit=10;"#,
+        r#"COPY INTO @my_stage
+                FROM mytable
+                FILE_FORMAT = (
+                    type = CSV
+                )
+                max_file_size=1024
+                manifest=true;"#,
         r#"COPY INTO mytable
                 FROM 's3://mybucket/data.csv'
                 CREDENTIALS = (
@@ -377,6 +403,12 @@ fn test_statement() {
                 )
                 size_limit=10
                 disable_variant_check=true;"#,
+        r#"COPY INTO mytable
+                FROM @my_stage
+                FILE_FORMAT = (
+                    type = CSV
+                )
+                return_file_status=true;"#,
         // We used to support COPY FROM a quoted at string
         // r#"COPY INTO mytable
         //         FROM '@external_stage/path/to/file.csv'
@@ -424,6 +456,9 @@ fn test_statement() {
         r#"SET max_threads = 10*2;"#,
         r#"UNSET max_threads;"#,
         r#"UNSET (max_threads, sql_dialect);"#,
+        r#"SET SESSION max_threads = 10;"#,
+        r#"UNSET SESSION max_threads;"#,
+        r#"SETTINGS (max_threads = 1, timezone = 'UTC') SELECT 1;"#,
         r#"SELECT t.c1 FROM @stage1/dir/file
         ( file_format => 'PARQUET', FILES => ('file1', 'file2')) t;"#,
         r#"select table0.c1, table1.c2 from
@@ -449,6 +484,15 @@ fn test_statement() {
         r#"REFRESH VIRTUAL COLUMN FOR t"#,
         r#"CREATE NETWORK POLICY mypolicy ALLOWED_IP_LIST=('192.168.10.0/24') BLOCKED_IP_LIST=('192.168.10.99') COMMENT='test'"#,
         r#"ALTER NETWORK POLICY mypolicy SET ALLOWED_IP_LIST=('192.168.10.0/24','192.168.255.1') BLOCKED_IP_LIST=('192.168.1.99') COMMENT='test'"#,
+        r#"DROP STORAGE PROFILE IF EXISTS my_profile"#,
+        r#"SHOW STORAGE PROFILES"#,
+        r#"CREATE PIPE IF NOT EXISTS my_pipe INTO db.t FROM @my_stage/logs/ PATTERN = '.*[.]csv' OPTIONS = (refresh_interval = '30') FILE_FORMAT = (type = CSV) COMMENT = 'load logs'"#,
+        r#"DROP PIPE IF EXISTS my_pipe"#,
+        r#"SHOW PIPES"#,
+        r#"CREATE SEQUENCE IF NOT EXISTS seq START WITH 10 INCREMENT BY -2 COMMENT = 'ids'"#,
+        r#"CREATE SEQUENCE seq"#,
+        r#"DROP SEQUENCE IF EXISTS seq"#,
+        r#"SHOW SEQUENCES"#,
         "--各环节转各环节转各环节转各环节转各\n  select 34343",
         "-- 96477300355	31379974136	3.074486292973661\nselect 34343",
         "-- xxxxx\n  select 34343;",
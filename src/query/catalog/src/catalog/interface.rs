@@ -16,6 +16,8 @@ use std::any::Any;
 use std::fmt::Debug;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::CatalogInfo;
@@ -65,6 +67,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateDatabaseCommentReply;
+use common_meta_app::schema::UpdateDatabaseCommentReq;
 use common_meta_app::schema::UpdateIndexReply;
 use common_meta_app::schema::UpdateIndexReq;
 use common_meta_app::schema::UpdateTableMetaReply;
@@ -110,6 +114,17 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
     // Get all the databases.
     async fn list_databases(&self, tenant: &str) -> Result<Vec<Arc<dyn Database>>>;
 
+    // Get the databases whose name starts with the prefix.
+    async fn list_databases_with_prefix(
+        &self,
+        tenant: &str,
+        prefix: &str,
+    ) -> Result<Vec<Arc<dyn Database>>> {
+        let mut dbs = self.list_databases(tenant).await?;
+        dbs.retain(|db| db.name().starts_with(prefix));
+        Ok(dbs)
+    }
+
     // Operation with database.
     async fn create_database(&self, req: CreateDatabaseReq) -> Result<CreateDatabaseReply>;
 
@@ -170,6 +185,11 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
 
     async fn rename_database(&self, req: RenameDatabaseReq) -> Result<RenameDatabaseReply>;
 
+    async fn update_database_comment(
+        &self,
+        req: UpdateDatabaseCommentReq,
+    ) -> Result<UpdateDatabaseCommentReply>;
+
     /// Table.
 
     // Build a `Arc<dyn Table>` from `TableInfo`.
@@ -187,6 +207,18 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
     ) -> Result<Arc<dyn Table>>;
 
     async fn list_tables(&self, tenant: &str, db_name: &str) -> Result<Vec<Arc<dyn Table>>>;
+
+    // Get the tables of the db whose name starts with the prefix.
+    async fn list_tables_with_prefix(
+        &self,
+        tenant: &str,
+        db_name: &str,
+        prefix: &str,
+    ) -> Result<Vec<Arc<dyn Table>>> {
+        let mut tables = self.list_tables(tenant, db_name).await?;
+        tables.retain(|table| table.name().starts_with(prefix));
+        Ok(tables)
+    }
     async fn list_tables_history(&self, tenant: &str, db_name: &str)
     -> Result<Vec<Arc<dyn Table>>>;
 
@@ -261,6 +293,16 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
 
     async fn list_table_lock_revs(&self, table_id: u64) -> Result<Vec<u64>>;
 
+    /// List the versions of the table meta, ordered by the time they took effect.
+    async fn list_table_meta_history(
+        &self,
+        _table_id: u64,
+    ) -> Result<Vec<(DateTime<Utc>, TableMeta)>> {
+        Err(ErrorCode::Unimplemented(
+            "'list_table_meta_history' not implemented",
+        ))
+    }
+
     async fn create_table_lock_rev(
         &self,
         expire_secs: u64,
@@ -99,6 +99,14 @@ pub trait Database: DynClone + Sync + Send {
         )))
     }
 
+    // List the tables whose name starts with the prefix.
+    #[async_backtrace::framed]
+    async fn list_tables_with_prefix(&self, prefix: &str) -> Result<Vec<Arc<dyn Table>>> {
+        let mut tables = self.list_tables().await?;
+        tables.retain(|table| table.name().starts_with(prefix));
+        Ok(tables)
+    }
+
     #[async_backtrace::framed]
     async fn list_tables_history(&self) -> Result<Vec<Arc<dyn Table>>> {
         Err(ErrorCode::Unimplemented(format!(
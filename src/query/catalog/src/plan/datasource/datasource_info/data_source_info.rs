@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_expression::TableSchema;
 use common_meta_app::schema::TableInfo;
 
+use crate::plan::OrcTableInfo;
 use crate::plan::Parquet2TableInfo;
 use crate::plan::ParquetTableInfo;
 use crate::plan::ResultScanTableInfo;
@@ -31,6 +32,8 @@ pub enum DataSourceInfo {
     // stage source with parquet format used for select.
     ParquetSource(ParquetTableInfo),
     Parquet2Source(Parquet2TableInfo),
+    // stage source with orc format used for select.
+    OrcSource(OrcTableInfo),
     // Table Function Result_Scan
     ResultScanSource(ResultScanTableInfo),
 }
@@ -42,6 +45,7 @@ impl DataSourceInfo {
             DataSourceInfo::StageSource(table_info) => table_info.schema(),
             DataSourceInfo::ParquetSource(table_info) => table_info.schema(),
             DataSourceInfo::Parquet2Source(table_info) => table_info.schema(),
+            DataSourceInfo::OrcSource(table_info) => table_info.schema(),
             DataSourceInfo::ResultScanSource(table_info) => table_info.schema(),
         }
     }
@@ -52,6 +56,7 @@ impl DataSourceInfo {
             DataSourceInfo::StageSource(table_info) => table_info.desc(),
             DataSourceInfo::ParquetSource(table_info) => table_info.desc(),
             DataSourceInfo::Parquet2Source(table_info) => table_info.desc(),
+            DataSourceInfo::OrcSource(table_info) => table_info.desc(),
             DataSourceInfo::ResultScanSource(table_info) => table_info.desc(),
         }
     }
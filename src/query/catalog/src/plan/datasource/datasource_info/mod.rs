@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod data_source_info;
+mod orc;
 mod parquet;
 mod parquet2;
 mod parquet_read_options;
@@ -20,6 +21,7 @@ mod result_scan;
 mod stage;
 
 pub use data_source_info::DataSourceInfo;
+pub use orc::OrcTableInfo;
 pub use parquet::ParquetTableInfo;
 pub use parquet::ParquetTableInfo as ParquetTableInfoV2;
 pub use parquet2::Parquet2TableInfo;
@@ -31,6 +31,8 @@ pub struct StageTableInfo {
     pub stage_info: StageInfo,
     pub files_to_copy: Option<Vec<StageFileInfo>>,
     pub is_select: bool,
+    /// Only for unloading: write a manifest of the unloaded files.
+    pub manifest: bool,
 }
 
 impl StageTableInfo {
@@ -31,7 +31,10 @@ use common_io::prelude::FormatSettings;
 use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::RoleInfo;
+use common_meta_app::principal::UserDefinedFunction;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateStreamMetaReq;
 use common_pipeline_core::InputError;
 use common_settings::ChangeValue;
 use common_settings::Settings;
@@ -118,8 +121,6 @@ pub trait TableContext: Send + Sync {
     fn get_partition(&self) -> Option<PartInfoPtr>;
     fn get_partitions(&self, num: usize) -> Vec<PartInfoPtr>;
     fn set_partitions(&self, partitions: Partitions) -> Result<()>;
-    fn add_partitions_sha(&self, sha: String);
-    fn get_partitions_shas(&self) -> Vec<String>;
     fn get_cacheable(&self) -> bool;
     fn set_cacheable(&self, cacheable: bool);
     fn get_can_scan_from_agg_index(&self) -> bool;
@@ -155,11 +156,16 @@ pub trait TableContext: Send + Sync {
     fn get_query_id_history(&self) -> HashSet<String>;
     fn get_result_cache_key(&self, query_id: &str) -> Option<String>;
     fn set_query_id_result_cache(&self, query_id: String, result_cache_key: String);
+    // Temporary views and UDFs only live in the current session.
+    fn get_temporary_view(&self, name: &str) -> Option<TableInfo>;
+    fn get_temporary_udf(&self, name: &str) -> Option<UserDefinedFunction>;
     fn get_on_error_map(&self) -> Option<Arc<DashMap<String, HashMap<u16, InputError>>>>;
     fn set_on_error_map(&self, map: Arc<DashMap<String, HashMap<u16, InputError>>>);
     fn get_on_error_mode(&self) -> Option<OnErrorMode>;
     fn set_on_error_mode(&self, mode: OnErrorMode);
     fn get_maximum_error_per_file(&self) -> Option<HashMap<String, ErrorCode>>;
+    // DashMap<file_path, number of rows loaded from the file>
+    fn get_rows_loaded_per_file(&self) -> Arc<DashMap<String, u64>>;
 
     fn apply_changed_settings(&self, changes: HashMap<String, ChangeValue>) -> Result<()>;
     fn get_changed_settings(&self) -> HashMap<String, ChangeValue>;
@@ -197,4 +203,9 @@ pub trait TableContext: Send + Sync {
     fn add_segment_location(&self, segment_loc: Location) -> Result<()>;
 
     fn get_segment_locations(&self) -> Result<Vec<Location>>;
+
+    /// Records a stream read by the query, its offset is advanced when the query commits.
+    fn add_stream_update(&self, update: UpdateStreamMetaReq);
+
+    fn get_stream_updates(&self) -> Vec<UpdateStreamMetaReq>;
 }
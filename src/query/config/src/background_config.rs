@@ -63,6 +63,12 @@ pub struct BackgroundCompactionConfig {
     #[clap(long)]
     pub block_limit: Option<u64>,
 
+    // Re-analyze a table once the rows inserted or deleted since its last ANALYZE
+    // exceed this percentage of its row count.
+    // 0 disables the automatic re-analyze.
+    #[clap(long, default_value = "20")]
+    pub analyze_changed_rows_percent: u64,
+
     #[clap(flatten)]
     pub scheduled_config: BackgroundScheduledConfig,
 }
@@ -114,6 +120,7 @@ pub struct InnerBackgroundCompactionConfig {
     pub target_tables: Option<Vec<String>>,
     pub segment_limit: Option<u64>,
     pub block_limit: Option<u64>,
+    pub analyze_changed_rows_percent: u64,
     pub params: BackgroundJobParams,
 }
 
@@ -150,6 +157,7 @@ impl TryInto<InnerBackgroundCompactionConfig> for BackgroundCompactionConfig {
         Ok(InnerBackgroundCompactionConfig {
             segment_limit: self.segment_limit,
             block_limit: self.block_limit,
+            analyze_changed_rows_percent: self.analyze_changed_rows_percent,
             enable: self.enable_compaction,
             target_tables: self.target_tables,
             params: {
@@ -196,6 +204,7 @@ impl From<InnerBackgroundCompactionConfig> for BackgroundCompactionConfig {
             target_tables: inner.target_tables,
             segment_limit: inner.segment_limit,
             block_limit: inner.block_limit,
+            analyze_changed_rows_percent: inner.analyze_changed_rows_percent,
             scheduled_config: Default::default(), // it would be set later
         };
         match inner.params.job_type {
@@ -238,6 +247,7 @@ impl Default for BackgroundCompactionConfig {
             target_tables: None,
             segment_limit: None,
             block_limit: None,
+            analyze_changed_rows_percent: 20,
             scheduled_config: Default::default(),
         }
     }
@@ -249,6 +259,10 @@ impl Debug for BackgroundCompactionConfig {
             .field("mode", &self.compact_mode)
             .field("segment_limit", &self.segment_limit)
             .field("block_limit", &self.block_limit)
+            .field(
+                "analyze_changed_rows_percent",
+                &self.analyze_changed_rows_percent,
+            )
             .field("fixed_config", &self.scheduled_config)
             .finish()
     }
@@ -281,6 +295,7 @@ impl Default for InnerBackgroundConfig {
                 target_tables: None,
                 segment_limit: None,
                 block_limit: None,
+                analyze_changed_rows_percent: 20,
                 params: Default::default(),
             },
         }
@@ -300,6 +315,10 @@ impl Debug for InnerBackgroundCompactionConfig {
         f.debug_struct("InnerBackgroundCompactionConfig")
             .field("segment_limit", &self.segment_limit)
             .field("block_limit", &self.block_limit)
+            .field(
+                "analyze_changed_rows_percent",
+                &self.analyze_changed_rows_percent,
+            )
             .field("params", &self.params)
             .finish()
     }
@@ -674,6 +674,31 @@ pub struct S3StorageConfig {
     #[clap(long = "storage-s3-allow-anonymous", default_value_t)]
     #[serde(rename = "allow_anonymous")]
     pub s3_allow_anonymous: bool,
+
+    #[clap(long = "storage-s3-secrets-provider", default_value_t)]
+    #[serde(rename = "secrets_provider")]
+    pub s3_secrets_provider: String,
+
+    /// Server side encryption of written objects, `AES256` or `aws:kms`
+    #[clap(long = "storage-s3-server-side-encryption", default_value_t)]
+    #[serde(rename = "server_side_encryption")]
+    pub s3_server_side_encryption: String,
+
+    /// KMS key id for `aws:kms` server side encryption
+    #[clap(
+        long = "storage-s3-server-side-encryption-aws-kms-key-id",
+        default_value_t
+    )]
+    #[serde(rename = "server_side_encryption_aws_kms_key_id")]
+    pub s3_server_side_encryption_aws_kms_key_id: String,
+
+    /// Base64 encoded AES256 key for server side encryption with customer provided keys
+    #[clap(
+        long = "storage-s3-server-side-encryption-customer-key",
+        default_value_t
+    )]
+    #[serde(rename = "server_side_encryption_customer_key")]
+    pub s3_server_side_encryption_customer_key: String,
 }
 
 impl Default for S3StorageConfig {
@@ -699,6 +724,16 @@ impl Debug for S3StorageConfig {
             )
             .field("master_key", &mask_string(&self.master_key, 3))
             .field("allow_anonymous", &self.s3_allow_anonymous)
+            .field("secrets_provider", &self.s3_secrets_provider)
+            .field("server_side_encryption", &self.s3_server_side_encryption)
+            .field(
+                "server_side_encryption_aws_kms_key_id",
+                &self.s3_server_side_encryption_aws_kms_key_id,
+            )
+            .field(
+                "server_side_encryption_customer_key",
+                &mask_string(&self.s3_server_side_encryption_customer_key, 3),
+            )
             .finish()
     }
 }
@@ -718,6 +753,10 @@ impl From<InnerStorageS3Config> for S3StorageConfig {
             s3_role_arn: inner.role_arn,
             s3_external_id: inner.external_id,
             s3_allow_anonymous: inner.allow_anonymous,
+            s3_secrets_provider: inner.secrets_provider,
+            s3_server_side_encryption: inner.server_side_encryption,
+            s3_server_side_encryption_aws_kms_key_id: inner.server_side_encryption_aws_kms_key_id,
+            s3_server_side_encryption_customer_key: inner.server_side_encryption_customer_key,
         }
     }
 }
@@ -740,6 +779,10 @@ impl TryInto<InnerStorageS3Config> for S3StorageConfig {
             role_arn: self.s3_role_arn,
             external_id: self.s3_external_id,
             allow_anonymous: self.s3_allow_anonymous,
+            secrets_provider: self.s3_secrets_provider,
+            server_side_encryption: self.s3_server_side_encryption,
+            server_side_encryption_aws_kms_key_id: self.s3_server_side_encryption_aws_kms_key_id,
+            server_side_encryption_customer_key: self.s3_server_side_encryption_customer_key,
         })
     }
 }
@@ -1277,6 +1320,20 @@ pub struct QueryConfig {
     #[clap(long, default_value_t)]
     pub mysql_tls_server_key: String,
 
+    #[clap(long, default_value = "127.0.0.1")]
+    pub postgres_handler_host: String,
+
+    #[clap(long, default_value = "5433")]
+    pub postgres_handler_port: u16,
+
+    /// The PostgreSQL handler receives the passwords in clear text, it only listens on a
+    /// loopback address unless the certificate and the key are given.
+    #[clap(long, default_value_t)]
+    pub postgres_tls_server_cert: String,
+
+    #[clap(long, default_value_t)]
+    pub postgres_tls_server_key: String,
+
     #[clap(long, default_value = "256")]
     pub max_active_sessions: u64,
 
@@ -1310,6 +1367,21 @@ pub struct QueryConfig {
     #[clap(long, default_value = "60")]
     pub http_handler_result_timeout_secs: u64,
 
+    /// Max bytes of the results of the http queries of a session spooled to the storage,
+    /// when the client fetches the pages slower than the queries produce them.
+    /// 0 disables spooling.
+    #[clap(long, default_value = "0")]
+    pub http_handler_result_spool_max_bytes: u64,
+
+    /// Spool the results of http queries to this local directory instead of the storage.
+    #[clap(long, default_value = "")]
+    pub http_handler_result_spool_local_path: String,
+
+    /// Max bytes of the results of the http queries of a session kept in memory, the
+    /// following results are spooled. 0 means no limit. Only works if spooling is enabled.
+    #[clap(long, default_value = "0")]
+    pub http_handler_result_memory_max_bytes: u64,
+
     #[clap(long, default_value = "127.0.0.1")]
     pub flight_sql_handler_host: String,
 
@@ -1393,6 +1465,11 @@ pub struct QueryConfig {
     #[clap(long)]
     pub management_mode: bool,
 
+    /// If in read only mode, the statements that write data or change the metadata are rejected,
+    /// only queries (including the time travel ones) are allowed.
+    #[clap(long)]
+    pub read_only: bool,
+
     /// Deprecated: jwt_key_file is deprecated, use jwt_key_files to add a list of available jwks url
     #[clap(long, default_value_t)]
     pub jwt_key_file: String,
@@ -1426,6 +1503,57 @@ pub struct QueryConfig {
     #[clap(long)]
     pub internal_merge_on_read_mutation: bool,
 
+    /// Tables (`database.table`) followed by this query cluster as a hot standby,
+    /// caches are pre-warmed as soon as their snapshots change.
+    #[clap(skip)]
+    pub standby_tables: Vec<String>,
+
+    /// Persist the finished queries of the node into the table `system_history.query_log`.
+    #[clap(long)]
+    pub enable_query_history: bool,
+
+    /// Refresh the materialized views of the tenant every so many seconds, 0 to only
+    /// refresh them manually.
+    #[clap(long, default_value = "0")]
+    pub materialized_view_refresh_interval_secs: u64,
+
+    /// Allow the functions implemented by external UDF servers, whose addresses must be
+    /// listed in `udf_server_allow_list`.
+    #[clap(long)]
+    pub enable_udf_server: bool,
+
+    /// The addresses of the UDF servers the functions can use, e.g. `http://127.0.0.1:8815`.
+    #[clap(skip)]
+    pub udf_server_allow_list: Vec<String>,
+
+    /// The secret from which the key encrypting storage profiles in meta is derived,
+    /// it must be the same on all the query nodes of a tenant. Storage profiles are
+    /// disabled if it's empty, and a node of a tenant with storage profiles refuses to start.
+    // This will not show in system.configs, put it to mask.rs.
+    #[clap(long, default_value = "")]
+    pub storage_profile_secret: String,
+
+    /// The address of the KMS generating and wrapping the data keys of the encrypted tables,
+    /// which serves the API of the transit secrets engine of HashiCorp Vault.
+    #[clap(long, default_value = "")]
+    pub data_encryption_kms_endpoint: String,
+
+    /// The name of the KMS key wrapping the data keys of the encrypted tables.
+    #[clap(long, default_value = "")]
+    pub data_encryption_kms_key_name: String,
+
+    /// The token to access the KMS.
+    // This will not show in system.configs, put it to mask.rs.
+    #[clap(long, default_value = "")]
+    pub data_encryption_kms_token: String,
+
+    /// For deployments without a KMS, e.g. tests: 32 random bytes in base64 wrapping the data
+    /// keys of the encrypted tables, instead of the KMS. It must be the same on all the query
+    /// nodes and must not be changed once encrypted tables are created.
+    // This will not show in system.configs, put it to mask.rs.
+    #[clap(long, default_value = "")]
+    pub data_encryption_kms_local_key: String,
+
     // ----- the following options/args are all deprecated               ----
     // ----- and turned into Option<T>, to help user migrate the configs ----
     /// OBSOLETED: Table disk cache size (mb).
@@ -1526,6 +1654,10 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             mysql_handler_tcp_keepalive_timeout_secs: self.mysql_handler_tcp_keepalive_timeout_secs,
             mysql_tls_server_cert: self.mysql_tls_server_cert,
             mysql_tls_server_key: self.mysql_tls_server_key,
+            postgres_handler_host: self.postgres_handler_host,
+            postgres_handler_port: self.postgres_handler_port,
+            postgres_tls_server_cert: self.postgres_tls_server_cert,
+            postgres_tls_server_key: self.postgres_tls_server_key,
             max_active_sessions: self.max_active_sessions,
             max_server_memory_usage: self.max_server_memory_usage,
             max_memory_limit_enabled: self.max_memory_limit_enabled,
@@ -1534,6 +1666,9 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             http_handler_host: self.http_handler_host,
             http_handler_port: self.http_handler_port,
             http_handler_result_timeout_secs: self.http_handler_result_timeout_secs,
+            http_handler_result_spool_max_bytes: self.http_handler_result_spool_max_bytes,
+            http_handler_result_spool_local_path: self.http_handler_result_spool_local_path,
+            http_handler_result_memory_max_bytes: self.http_handler_result_memory_max_bytes,
             flight_api_address: self.flight_api_address,
             flight_sql_handler_host: self.flight_sql_handler_host,
             flight_sql_handler_port: self.flight_sql_handler_port,
@@ -1557,6 +1692,7 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             max_query_log_size: self.max_query_log_size,
             databend_enterprise_license: self.databend_enterprise_license,
             management_mode: self.management_mode,
+            read_only: self.read_only,
             parquet_fast_read_bytes: self.parquet_fast_read_bytes,
             max_storage_io_requests: self.max_storage_io_requests,
             jwt_key_file: self.jwt_key_file,
@@ -1571,6 +1707,16 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             tenant_quota: self.quota,
             internal_enable_sandbox_tenant: self.internal_enable_sandbox_tenant,
             internal_merge_on_read_mutation: self.internal_merge_on_read_mutation,
+            standby_tables: self.standby_tables,
+            enable_query_history: self.enable_query_history,
+            materialized_view_refresh_interval_secs: self.materialized_view_refresh_interval_secs,
+            enable_udf_server: self.enable_udf_server,
+            udf_server_allow_list: self.udf_server_allow_list,
+            storage_profile_secret: self.storage_profile_secret,
+            data_encryption_kms_endpoint: self.data_encryption_kms_endpoint,
+            data_encryption_kms_key_name: self.data_encryption_kms_key_name,
+            data_encryption_kms_token: self.data_encryption_kms_token,
+            data_encryption_kms_local_key: self.data_encryption_kms_local_key,
             disable_system_table_load: self.disable_system_table_load,
             openai_api_chat_base_url: self.openai_api_chat_base_url,
             openai_api_embedding_base_url: self.openai_api_embedding_base_url,
@@ -1595,6 +1741,10 @@ impl From<InnerQueryConfig> for QueryConfig {
                 .mysql_handler_tcp_keepalive_timeout_secs,
             mysql_tls_server_cert: inner.mysql_tls_server_cert,
             mysql_tls_server_key: inner.mysql_tls_server_key,
+            postgres_handler_host: inner.postgres_handler_host,
+            postgres_handler_port: inner.postgres_handler_port,
+            postgres_tls_server_cert: inner.postgres_tls_server_cert,
+            postgres_tls_server_key: inner.postgres_tls_server_key,
             max_active_sessions: inner.max_active_sessions,
             max_server_memory_usage: inner.max_server_memory_usage,
             max_memory_limit_enabled: inner.max_memory_limit_enabled,
@@ -1608,6 +1758,9 @@ impl From<InnerQueryConfig> for QueryConfig {
             http_handler_host: inner.http_handler_host,
             http_handler_port: inner.http_handler_port,
             http_handler_result_timeout_secs: inner.http_handler_result_timeout_secs,
+            http_handler_result_spool_max_bytes: inner.http_handler_result_spool_max_bytes,
+            http_handler_result_spool_local_path: inner.http_handler_result_spool_local_path,
+            http_handler_result_memory_max_bytes: inner.http_handler_result_memory_max_bytes,
             flight_api_address: inner.flight_api_address,
             flight_sql_handler_host: inner.flight_sql_handler_host,
             flight_sql_handler_port: inner.flight_sql_handler_port,
@@ -1631,6 +1784,7 @@ impl From<InnerQueryConfig> for QueryConfig {
             max_query_log_size: inner.max_query_log_size,
             databend_enterprise_license: inner.databend_enterprise_license,
             management_mode: inner.management_mode,
+            read_only: inner.read_only,
             parquet_fast_read_bytes: inner.parquet_fast_read_bytes,
             max_storage_io_requests: inner.max_storage_io_requests,
             jwt_key_file: inner.jwt_key_file,
@@ -1643,6 +1797,16 @@ impl From<InnerQueryConfig> for QueryConfig {
             quota: inner.tenant_quota,
             internal_enable_sandbox_tenant: inner.internal_enable_sandbox_tenant,
             internal_merge_on_read_mutation: false,
+            standby_tables: inner.standby_tables,
+            enable_query_history: inner.enable_query_history,
+            materialized_view_refresh_interval_secs: inner.materialized_view_refresh_interval_secs,
+            enable_udf_server: inner.enable_udf_server,
+            udf_server_allow_list: inner.udf_server_allow_list,
+            storage_profile_secret: inner.storage_profile_secret,
+            data_encryption_kms_endpoint: inner.data_encryption_kms_endpoint,
+            data_encryption_kms_key_name: inner.data_encryption_kms_key_name,
+            data_encryption_kms_token: inner.data_encryption_kms_token,
+            data_encryption_kms_local_key: inner.data_encryption_kms_local_key,
             // obsoleted config entries
             table_disk_cache_mb_size: None,
             table_meta_cache_enabled: None,
@@ -1972,6 +2136,11 @@ pub struct MetaConfig {
     #[clap(long = "unhealth-endpoint-evict-time", default_value = "120")]
     pub unhealth_endpoint_evict_time: u64,
 
+    /// Compress the requests and the responses exchanged with meta service with gzip.
+    /// Requires a meta service that supports compressed requests.
+    #[clap(long = "meta-rpc-compression")]
+    pub rpc_compression: bool,
+
     /// Certificate for client to identify meta rpc serve
     #[clap(long = "meta-rpc-tls-meta-server-root-ca-cert", default_value_t)]
     pub rpc_tls_meta_server_root_ca_cert: String,
@@ -2023,6 +2192,7 @@ impl TryInto<InnerMetaConfig> for MetaConfig {
             client_timeout_in_second: self.client_timeout_in_second,
             auto_sync_interval: self.auto_sync_interval,
             unhealth_endpoint_evict_time: self.unhealth_endpoint_evict_time,
+            rpc_compression: self.rpc_compression,
             rpc_tls_meta_server_root_ca_cert: self.rpc_tls_meta_server_root_ca_cert,
             rpc_tls_meta_service_domain_name: self.rpc_tls_meta_service_domain_name,
         })
@@ -2039,6 +2209,7 @@ impl From<InnerMetaConfig> for MetaConfig {
             client_timeout_in_second: inner.client_timeout_in_second,
             auto_sync_interval: inner.auto_sync_interval,
             unhealth_endpoint_evict_time: inner.unhealth_endpoint_evict_time,
+            rpc_compression: inner.rpc_compression,
             rpc_tls_meta_server_root_ca_cert: inner.rpc_tls_meta_server_root_ca_cert,
             rpc_tls_meta_service_domain_name: inner.rpc_tls_meta_service_domain_name,
 
@@ -2064,6 +2235,7 @@ impl Debug for MetaConfig {
                 "unhealth_endpoint_evict_time",
                 &self.unhealth_endpoint_evict_time,
             )
+            .field("rpc_compression", &self.rpc_compression)
             .field(
                 "rpc_tls_meta_server_root_ca_cert",
                 &self.rpc_tls_meta_server_root_ca_cert,
@@ -2283,6 +2455,22 @@ pub struct CacheConfig {
     #[clap(long = "cache-table-data-deserialized-data-bytes", default_value = "0")]
     pub table_data_deserialized_data_bytes: u64,
 
+    /// Max size of in memory table data block cache. By default it is 0 (disabled)
+    ///
+    /// The cached items are the decoded blocks of small tables, keyed by the table snapshot,
+    /// so that the tables which are scanned repeatedly (like the dimension tables of joins)
+    /// need not be read and deserialized again until they are changed.
+    #[clap(long = "cache-table-data-block-bytes", default_value = "0")]
+    pub table_data_block_bytes: u64,
+
+    /// Max size of in memory hash join build cache. By default it is 0 (disabled)
+    ///
+    /// The cached items are the hash tables built from small tables on the build side of joins,
+    /// keyed by the table snapshot and the join keys, so that the queries joining the same
+    /// small table need not build its hash table again until the table is changed.
+    #[clap(long = "cache-hash-join-build-bytes", default_value = "0")]
+    pub hash_join_build_bytes: u64,
+
     // ----- the following options/args are all deprecated               ----
     /// Max number of cached table segment
     #[clap(long = "cache-table-meta-segment-count")]
@@ -2407,6 +2595,8 @@ mod cache_config_converters {
                     .table_data_cache_population_queue_size,
                 disk_cache_config: value.disk_cache_config.try_into()?,
                 table_data_deserialized_data_bytes: value.table_data_deserialized_data_bytes,
+                table_data_block_bytes: value.table_data_block_bytes,
+                hash_join_build_bytes: value.hash_join_build_bytes,
             })
         }
     }
@@ -2428,6 +2618,8 @@ mod cache_config_converters {
                     .table_data_cache_population_queue_size,
                 disk_cache_config: value.disk_cache_config.into(),
                 table_data_deserialized_data_bytes: value.table_data_deserialized_data_bytes,
+                table_data_block_bytes: value.table_data_block_bytes,
+                hash_join_build_bytes: value.hash_join_build_bytes,
                 table_meta_segment_count: None,
             }
         }
@@ -151,6 +151,10 @@ pub struct QueryConfig {
     pub mysql_handler_tcp_keepalive_timeout_secs: u64,
     pub mysql_tls_server_cert: String,
     pub mysql_tls_server_key: String,
+    pub postgres_handler_host: String,
+    pub postgres_handler_port: u16,
+    pub postgres_tls_server_cert: String,
+    pub postgres_tls_server_key: String,
     pub max_active_sessions: u64,
     pub max_server_memory_usage: u64,
     pub max_memory_limit_enabled: bool,
@@ -159,6 +163,9 @@ pub struct QueryConfig {
     pub http_handler_host: String,
     pub http_handler_port: u16,
     pub http_handler_result_timeout_secs: u64,
+    pub http_handler_result_spool_max_bytes: u64,
+    pub http_handler_result_spool_local_path: String,
+    pub http_handler_result_memory_max_bytes: u64,
     pub flight_api_address: String,
     pub flight_sql_handler_host: String,
     pub flight_sql_handler_port: u16,
@@ -187,6 +194,8 @@ pub struct QueryConfig {
     pub databend_enterprise_license: Option<String>,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
     pub management_mode: bool,
+    /// If in read only mode, the statements that write data or change the metadata are rejected.
+    pub read_only: bool,
 
     pub parquet_fast_read_bytes: Option<u64>,
     pub max_storage_io_requests: Option<u64>,
@@ -201,6 +210,24 @@ pub struct QueryConfig {
     pub tenant_quota: Option<TenantQuota>,
     pub internal_enable_sandbox_tenant: bool,
     pub internal_merge_on_read_mutation: bool,
+    /// Tables (`database.table`) followed as a hot standby.
+    pub standby_tables: Vec<String>,
+    /// Persist the finished queries into `system_history.query_log`.
+    pub enable_query_history: bool,
+    /// Refresh the materialized views every so many seconds, 0 to disable.
+    pub materialized_view_refresh_interval_secs: u64,
+    /// Allow the functions implemented by external UDF servers.
+    pub enable_udf_server: bool,
+    /// The addresses of the UDF servers the functions can use.
+    pub udf_server_allow_list: Vec<String>,
+    /// The secret from which the key encrypting storage profiles in meta is derived.
+    pub storage_profile_secret: String,
+    /// The KMS generating and wrapping the data keys of the encrypted tables.
+    pub data_encryption_kms_endpoint: String,
+    pub data_encryption_kms_key_name: String,
+    pub data_encryption_kms_token: String,
+    /// The key wrapping the data keys of the encrypted tables without a KMS.
+    pub data_encryption_kms_local_key: String,
     /// Disable some system load(For example system.configs) for cloud security.
     pub disable_system_table_load: bool,
 
@@ -224,6 +251,10 @@ impl Default for QueryConfig {
             mysql_handler_tcp_keepalive_timeout_secs: 120,
             mysql_tls_server_cert: "".to_string(),
             mysql_tls_server_key: "".to_string(),
+            postgres_handler_host: "127.0.0.1".to_string(),
+            postgres_handler_port: 5433,
+            postgres_tls_server_cert: "".to_string(),
+            postgres_tls_server_key: "".to_string(),
             max_active_sessions: 256,
             max_server_memory_usage: 0,
             max_memory_limit_enabled: false,
@@ -232,6 +263,9 @@ impl Default for QueryConfig {
             http_handler_host: "127.0.0.1".to_string(),
             http_handler_port: 8000,
             http_handler_result_timeout_secs: 60,
+            http_handler_result_spool_max_bytes: 0,
+            http_handler_result_spool_local_path: "".to_string(),
+            http_handler_result_memory_max_bytes: 0,
             flight_api_address: "127.0.0.1:9090".to_string(),
             flight_sql_handler_host: "127.0.0.1".to_string(),
             flight_sql_handler_port: 8900,
@@ -254,6 +288,7 @@ impl Default for QueryConfig {
             max_query_log_size: 10_000,
             databend_enterprise_license: None,
             management_mode: false,
+            read_only: false,
             parquet_fast_read_bytes: None,
             max_storage_io_requests: None,
             jwt_key_file: "".to_string(),
@@ -266,6 +301,16 @@ impl Default for QueryConfig {
             tenant_quota: None,
             internal_enable_sandbox_tenant: false,
             internal_merge_on_read_mutation: false,
+            standby_tables: Vec::new(),
+            enable_query_history: false,
+            materialized_view_refresh_interval_secs: 0,
+            enable_udf_server: false,
+            udf_server_allow_list: Vec::new(),
+            storage_profile_secret: "".to_string(),
+            data_encryption_kms_endpoint: "".to_string(),
+            data_encryption_kms_key_name: "".to_string(),
+            data_encryption_kms_token: "".to_string(),
+            data_encryption_kms_local_key: "".to_string(),
             disable_system_table_load: false,
             flight_sql_tls_server_key: "".to_string(),
             openai_api_chat_base_url: "https://api.openai.com/v1/".to_string(),
@@ -293,6 +338,10 @@ impl QueryConfig {
             .clone()
             .map(|s| mask_string(&s, 3));
         sanitized.openai_api_key = mask_string(&self.openai_api_key, 3);
+        sanitized.storage_profile_secret = mask_string(&self.storage_profile_secret, 3);
+        sanitized.data_encryption_kms_token = mask_string(&self.data_encryption_kms_token, 3);
+        sanitized.data_encryption_kms_local_key =
+            mask_string(&self.data_encryption_kms_local_key, 3);
         sanitized
     }
 }
@@ -313,6 +362,9 @@ pub struct MetaConfig {
     /// 0 disables auto-sync. By default auto-sync is disabled.
     pub auto_sync_interval: u64,
     pub unhealth_endpoint_evict_time: u64,
+    /// Compress the requests and the responses exchanged with meta service with gzip.
+    /// Requires a meta service that supports compressed requests.
+    pub rpc_compression: bool,
     /// Certificate for client to identify meta rpc serve
     pub rpc_tls_meta_server_root_ca_cert: String,
     pub rpc_tls_meta_service_domain_name: String,
@@ -328,6 +380,7 @@ impl Default for MetaConfig {
             client_timeout_in_second: 10,
             auto_sync_interval: 0,
             unhealth_endpoint_evict_time: 120,
+            rpc_compression: false,
             rpc_tls_meta_server_root_ca_cert: "".to_string(),
             rpc_tls_meta_service_domain_name: "localhost".to_string(),
         }
@@ -387,6 +440,7 @@ impl MetaConfig {
                 None
             },
             unhealth_endpoint_evict_time: Duration::from_secs(self.unhealth_endpoint_evict_time),
+            compression: self.rpc_compression,
         }
     }
 }
@@ -404,6 +458,7 @@ impl Debug for MetaConfig {
                 "unhealth_endpoint_evict_time",
                 &self.unhealth_endpoint_evict_time,
             )
+            .field("rpc_compression", &self.rpc_compression)
             .field(
                 "rpc_tls_meta_server_root_ca_cert",
                 &self.rpc_tls_meta_server_root_ca_cert,
@@ -537,6 +592,16 @@ pub struct CacheConfig {
     /// Only if query nodes have plenty of un-utilized memory, the working set can be fitted into,
     /// and the access pattern will benefit from caching, consider enabled this cache.
     pub table_data_deserialized_data_bytes: u64,
+
+    /// Max size of in memory table data block cache. By default it is 0 (disabled)
+    ///
+    /// The cache items are the decoded blocks of small tables, keyed by the table snapshot.
+    pub table_data_block_bytes: u64,
+
+    /// Max size of in memory hash join build cache. By default it is 0 (disabled)
+    ///
+    /// The cache items are the hash tables built from small tables on the build side of joins.
+    pub hash_join_build_bytes: u64,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -595,6 +660,8 @@ impl Default for CacheConfig {
             table_data_cache_population_queue_size: 65536,
             disk_cache_config: Default::default(),
             table_data_deserialized_data_bytes: 0,
+            table_data_block_bytes: 0,
+            hash_join_build_bytes: 0,
         }
     }
 }
@@ -16,7 +16,12 @@ use crate::Config;
 
 // Mask the config value to ******
 impl Config {
-    pub const fn mask_option_keys() -> &'static [&'static str; 1] {
-        &["openai_api_key"]
+    pub const fn mask_option_keys() -> &'static [&'static str; 4] {
+        &[
+            "openai_api_key",
+            "storage_profile_secret",
+            "data_encryption_kms_token",
+            "data_encryption_kms_local_key",
+        ]
     }
 }
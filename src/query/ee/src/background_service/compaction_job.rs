@@ -217,6 +217,15 @@ impl CompactionJob {
                         );
                     }
                 }
+                if let Err(e) = self
+                    .analyze_table(self.session.clone(), db_name.clone(), tb_name.clone())
+                    .await
+                {
+                    error!(
+                        "analyze table failed, db: {}, table: {}, err: {}",
+                        db_name, tb_name, e
+                    );
+                }
             }
         }
         info!(
@@ -630,6 +639,47 @@ impl CompactionJob {
         Ok((need_segment_compact, need_block_compact, table_statistics))
     }
 
+    // re-analyze the table if too many rows are changed since its last analyze,
+    // so that the statistics used by the optimizer are kept up to date.
+    async fn analyze_table(
+        &self,
+        session: Arc<Session>,
+        database: String,
+        table: String,
+    ) -> Result<()> {
+        let percent = self.conf.background.compaction.analyze_changed_rows_percent;
+        if percent == 0 {
+            return Ok(());
+        }
+        let sql = Self::get_analyze_advice_sql(database.clone(), table.clone(), percent);
+        debug!(
+            job = "compaction",
+            background = true,
+            sql = sql.as_str();
+            "check analyze"
+        );
+        let ctx = session.create_query_context().await?;
+        let res = SuggestedBackgroundTasksSource::do_execute_sql(ctx, sql).await?;
+        let need_analyze = res.is_some_and(|res| {
+            res.num_rows() > 0
+                && res
+                    .column(0)
+                    .as_any()
+                    .downcast_ref::<BooleanArray>()
+                    .unwrap()
+                    .value(0)
+        });
+        if !need_analyze {
+            return Ok(());
+        }
+
+        info!(job = "compaction", background = true, database = database.clone(), table = table.clone(); "start analyze");
+        let sql = Self::get_analyze_sql(database, table);
+        let ctx = session.create_query_context().await?;
+        SuggestedBackgroundTasksSource::do_execute_sql(ctx, sql).await?;
+        Ok(())
+    }
+
     async fn do_segment_compaction(
         &self,
         session: Arc<Session>,
@@ -688,6 +738,21 @@ impl CompactionJob {
             blk_count, avg_seg, blk_count, avg_blk, database, table
         )
     }
+    pub fn get_analyze_advice_sql(database: String, table: String, percent: u64) -> String {
+        format!(
+            "
+        select
+        IF(rows_changed_since_analyze > 0 AND rows_changed_since_analyze * 100 >= row_count * {}, TRUE, FALSE) AS analyze_advice
+        from fuse_snapshot('{}', '{}') order by timestamp DESC LIMIT 1;
+        ",
+            percent, database, table
+        )
+    }
+
+    pub fn get_analyze_sql(database: String, table: String) -> String {
+        format!("ANALYZE TABLE {}.{};", database, table)
+    }
+
     pub fn get_segment_compaction_sql(
         database: String,
         table: String,
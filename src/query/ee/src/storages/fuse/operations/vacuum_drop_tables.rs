@@ -22,6 +22,7 @@ use futures_util::TryStreamExt;
 use log::info;
 use opendal::EntryMode;
 use opendal::Metakey;
+use storages_common_table_meta::table::OPT_KEY_STORAGE_PROFILE;
 
 #[async_backtrace::framed]
 async fn do_vacuum_drop_table(
@@ -38,8 +39,15 @@ async fn do_vacuum_drop_table(
         return Ok(None);
     }
     let table_info = table.get_table_info();
-    // storage_params is_some means it is an external table, ignore
-    if table_info.meta.storage_params.is_some() {
+    // storage_params is_some means it is an external table, ignore.
+    // Tables bound to a storage profile are managed by databend, they are vacuumed
+    // with the operator built from the profile.
+    if table_info.meta.storage_params.is_some()
+        && !table_info
+            .meta
+            .options
+            .contains_key(OPT_KEY_STORAGE_PROFILE)
+    {
         info!("ignore external table {}", table.get_table_info().name);
         return Ok(None);
     }
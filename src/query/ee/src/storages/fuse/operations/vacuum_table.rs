@@ -41,6 +41,7 @@ pub struct SnapshotReferencedFiles {
     pub segments: HashSet<String>,
     pub blocks: HashSet<String>,
     pub blocks_index: HashSet<String>,
+    pub deletion_vectors: HashSet<String>,
 }
 
 impl SnapshotReferencedFiles {
@@ -55,6 +56,9 @@ impl SnapshotReferencedFiles {
         for file in &self.blocks_index {
             files.push(file.clone());
         }
+        for file in &self.deletion_vectors {
+            files.push(file.clone());
+        }
         files
     }
 }
@@ -129,10 +133,22 @@ pub async fn get_snapshot_referenced_files(
     segments_vec.into_iter().for_each(|(location, _)| {
         segments.insert(location);
     });
+    let mut blocks = locations_referenced.block_location;
+    let mut blocks_index = locations_referenced.bloom_location;
+    let mut deletion_vectors = locations_referenced.deletion_vector_location;
+
+    // 3. The files shared with the clones of the table are referenced by them.
+    let shared_locations = fuse_table.locations_shared_with_clones(ctx).await?;
+    segments.extend(shared_locations.iter().cloned());
+    blocks.extend(shared_locations.iter().cloned());
+    blocks_index.extend(shared_locations.iter().cloned());
+    deletion_vectors.extend(shared_locations);
+
     Ok(Some(SnapshotReferencedFiles {
         segments,
-        blocks: locations_referenced.block_location,
-        blocks_index: locations_referenced.bloom_location,
+        blocks,
+        blocks_index,
+        deletion_vectors,
     }))
 }
 
@@ -143,7 +159,12 @@ async fn get_orphan_files_to_be_purged(
     referenced_files: HashSet<String>,
     retention_time: DateTime<Utc>,
 ) -> Result<Vec<String>> {
-    let files_to_be_purged = match referenced_files.iter().next().cloned() {
+    // the referenced files of a cloned table may be located in the tables it is cloned from.
+    let files_to_be_purged = match referenced_files
+        .iter()
+        .find(|location| fuse_table.is_owned_location(location))
+        .cloned()
+    {
         Some(location) => {
             let prefix = SnapshotsIO::get_s3_prefix_from_file(&location);
             if let Some(prefix) = prefix {
@@ -266,6 +287,36 @@ pub async fn do_gc_orphan_files(
     );
     ctx.set_status_info(&status);
 
+    // 5. Purge orphan deletion vector files.
+    // 5.1 Get orphan deletion vector files to be purged
+    let deletion_vector_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        referenced_files.deletion_vectors,
+        retention_time,
+    )
+    .await?;
+    let status = format!(
+        "gc orphan: read deletion_vector_locations_to_be_purged:{}, cost:{} sec",
+        deletion_vector_locations_to_be_purged.len(),
+        start.elapsed().as_secs()
+    );
+    ctx.set_status_info(&status);
+
+    // 5.2 Delete all the orphan deletion vector files to be purged
+    let purged_file_num = deletion_vector_locations_to_be_purged.len();
+    fuse_table
+        .try_purge_location_files(
+            ctx.clone(),
+            HashSet::from_iter(deletion_vector_locations_to_be_purged.into_iter()),
+        )
+        .await?;
+    let status = format!(
+        "gc orphan: purged deletion vector files:{}, cost:{} sec",
+        purged_file_num,
+        start.elapsed().as_secs()
+    );
+    ctx.set_status_info(&status);
+
     Ok(())
 }
 
@@ -334,6 +385,25 @@ pub async fn do_dry_run_orphan_files(
     ctx.set_status_info(&status);
 
     purge_files.extend(index_locations_to_be_purged);
+    if purge_files.len() >= dry_run_limit {
+        return Ok(());
+    }
+
+    // 5. Get purge orphan deletion vector files.
+    let deletion_vector_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        referenced_files.deletion_vectors,
+        retention_time,
+    )
+    .await?;
+    let status = format!(
+        "dry_run orphan: read deletion_vector_locations_to_be_purged:{}, cost:{} sec",
+        deletion_vector_locations_to_be_purged.len(),
+        start.elapsed().as_secs()
+    );
+    ctx.set_status_info(&status);
+
+    purge_files.extend(deletion_vector_locations_to_be_purged);
 
     Ok(())
 }
@@ -66,6 +66,18 @@ async fn test_get_block_compaction_sql() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_get_analyze_sql() -> Result<()> {
+    let sql = CompactionJob::get_analyze_advice_sql("db1".to_string(), "tbl1".to_string(), 20);
+    assert_eq!(
+        sql.trim(),
+        "select\n        IF(rows_changed_since_analyze > 0 AND rows_changed_since_analyze * 100 >= row_count * 20, TRUE, FALSE) AS analyze_advice\n        from fuse_snapshot('db1', 'tbl1') order by timestamp DESC LIMIT 1;"
+    );
+    let sql = CompactionJob::get_analyze_sql("db1".to_string(), "tbl1".to_string());
+    assert_eq!(sql.trim(), "ANALYZE TABLE db1.tbl1;");
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_parse_target_tables() -> Result<()> {
     let tables = CompactionJob::parse_all_target_tables(Some(&vec![
@@ -53,6 +53,7 @@ impl TryFrom<&ArrowField> for TableField {
             default_expr: None,
             column_id: 0,
             computed_expr: None,
+            prev_versions: vec![],
         })
     }
 }
@@ -18,6 +18,7 @@ use std::ops::BitAnd;
 use std::ops::BitOr;
 use std::ops::Not;
 use std::sync::Arc;
+use std::sync::Mutex;
 
 use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::bitmap::MutableBitmap;
@@ -26,6 +27,8 @@ use common_exception::Result;
 use common_exception::Span;
 use enum_as_inner::EnumAsInner;
 use itertools::Itertools;
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -92,7 +95,7 @@ pub enum FunctionEval {
     },
 }
 
-#[derive(Clone, Default)]
+#[derive(Clone)]
 pub struct FunctionContext {
     pub tz: TzLUT,
 
@@ -102,6 +105,45 @@ pub struct FunctionContext {
     pub openai_api_version: String,
     pub openai_api_embedding_model: String,
     pub openai_api_completion_model: String,
+
+    /// The maximum size in bytes of a string generated by functions like `repeat` and `space`.
+    pub max_string_result_bytes: u64,
+
+    /// The generators of the random functions with a constant seed, shared by the query.
+    pub seeded_rngs: Arc<SeededRngs>,
+}
+
+impl Default for FunctionContext {
+    fn default() -> Self {
+        FunctionContext {
+            tz: TzLUT::default(),
+            openai_api_chat_base_url: String::new(),
+            openai_api_embedding_base_url: String::new(),
+            openai_api_key: String::new(),
+            openai_api_version: String::new(),
+            openai_api_embedding_model: String::new(),
+            openai_api_completion_model: String::new(),
+            max_string_result_bytes: 256 * 1024 * 1024,
+            seeded_rngs: Arc::new(SeededRngs::default()),
+        }
+    }
+}
+
+/// A generator for each constant seed, so that the blocks of a query continue the
+/// sequence of the seed instead of generating the same values again.
+#[derive(Default)]
+pub struct SeededRngs {
+    rngs: Mutex<HashMap<u64, SmallRng>>,
+}
+
+impl SeededRngs {
+    pub fn with_rng<T>(&self, seed: u64, f: impl FnOnce(&mut SmallRng) -> T) -> T {
+        let mut rngs = self.rngs.lock().unwrap();
+        let rng = rngs
+            .entry(seed)
+            .or_insert_with(|| SmallRng::seed_from_u64(seed));
+        f(rng)
+    }
 }
 
 #[derive(Clone)]
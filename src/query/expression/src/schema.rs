@@ -48,11 +48,15 @@ pub const ROW_ID_COLUMN_ID: u32 = u32::MAX;
 pub const BLOCK_NAME_COLUMN_ID: u32 = u32::MAX - 1;
 pub const SEGMENT_NAME_COLUMN_ID: u32 = u32::MAX - 2;
 pub const SNAPSHOT_NAME_COLUMN_ID: u32 = u32::MAX - 3;
+// Column id under which the partition value of blocks and segments is recorded in the statistics.
+// Leaves some room for more internal columns.
+pub const PARTITION_KEY_COLUMN_ID: u32 = u32::MAX - 8;
 
 pub const ROW_ID_COL_NAME: &str = "_row_id";
 pub const SNAPSHOT_NAME_COL_NAME: &str = "_snapshot_name";
 pub const SEGMENT_NAME_COL_NAME: &str = "_segment_name";
 pub const BLOCK_NAME_COL_NAME: &str = "_block_name";
+pub const PARTITION_KEY_COL_NAME: &str = "_partition_key";
 
 #[inline]
 pub fn is_internal_column_id(column_id: ColumnId) -> bool {
@@ -110,6 +114,19 @@ pub struct TableField {
     #[serde(default = "uninit_column_id")]
     pub column_id: ColumnId,
     pub computed_expr: Option<ComputedExpr>,
+    /// The previous versions of the column, the latest first.
+    #[serde(default)]
+    pub prev_versions: Vec<ColumnVersion>,
+}
+
+/// A previous version of a column whose data type has been widened in place.
+///
+/// Blocks written before the widening store the column with the previous column id and
+/// data type, they are read with the previous type and cast to the current one.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ColumnVersion {
+    pub column_id: ColumnId,
+    pub data_type: TableDataType,
 }
 
 /// DataType with more information that is only available for table field, e.g, the
@@ -377,6 +394,9 @@ impl TableSchema {
             if field.contain_column_id(column_id) {
                 return false;
             }
+            if field.prev_versions.iter().any(|v| v.column_id == column_id) {
+                return false;
+            }
         }
 
         true
@@ -408,6 +428,33 @@ impl TableSchema {
         Ok(())
     }
 
+    /// Widen the data type of the column at `i` without rewriting the existing data.
+    ///
+    /// The column is assigned a new column id, the previous column id and data type are kept
+    /// in `prev_versions`, blocks written before are cast to the new type when being read.
+    pub fn widen_column_type(&mut self, i: FieldIndex, data_type: TableDataType) -> Result<()> {
+        let field = &self.fields[i];
+        if !field.data_type.can_widen_to(&data_type) {
+            return Err(ErrorCode::UnmatchColumnDataType(format!(
+                "column {} of type {} can not be widened to {}",
+                field.name, field.data_type, data_type
+            )));
+        }
+
+        let mut prev_versions = Vec::with_capacity(field.prev_versions.len() + 1);
+        prev_versions.push(ColumnVersion {
+            column_id: field.column_id,
+            data_type: field.data_type.clone(),
+        });
+        prev_versions.extend(field.prev_versions.iter().cloned());
+
+        let mut new_field = field.clone();
+        new_field.data_type = data_type;
+        new_field.prev_versions = prev_versions;
+        self.fields[i] = new_field.build_column_id(&mut self.next_column_id);
+        Ok(())
+    }
+
     // Every internal column has constant column id, no need to generate column id of internal columns.
     pub fn add_internal_field(
         &mut self,
@@ -958,6 +1005,7 @@ impl TableField {
             data_type,
             column_id: 0,
             computed_expr: None,
+            prev_versions: vec![],
         }
     }
 
@@ -968,6 +1016,7 @@ impl TableField {
             data_type,
             column_id,
             computed_expr: None,
+            prev_versions: vec![],
         }
     }
 
@@ -1014,6 +1063,7 @@ impl TableField {
             data_type: self.data_type.clone(),
             column_id,
             computed_expr: self.computed_expr.clone(),
+            prev_versions: self.prev_versions.clone(),
         }
     }
 
@@ -1057,6 +1107,12 @@ impl TableField {
         self
     }
 
+    #[must_use]
+    pub fn with_prev_versions(mut self, prev_versions: Vec<ColumnVersion>) -> Self {
+        self.prev_versions = prev_versions;
+        self
+    }
+
     pub fn name(&self) -> &String {
         &self.name
     }
@@ -1073,6 +1129,10 @@ impl TableField {
         self.computed_expr.as_ref()
     }
 
+    pub fn prev_versions(&self) -> &[ColumnVersion] {
+        &self.prev_versions
+    }
+
     #[inline]
     pub fn is_nullable(&self) -> bool {
         self.data_type.is_nullable()
@@ -1128,6 +1188,20 @@ impl TableDataType {
         !self.is_nullable_or_null()
     }
 
+    /// Returns true if every value of `self` can be cast to `dest` without loss,
+    /// so that a column of `self` can be widened to `dest` without rewriting its data.
+    pub fn can_widen_to(&self, dest: &TableDataType) -> bool {
+        match (self, dest) {
+            (TableDataType::Nullable(src), TableDataType::Nullable(dest)) => src.can_widen_to(dest),
+            (TableDataType::Nullable(_), _) => false,
+            (src, TableDataType::Nullable(dest)) => src == dest.as_ref() || src.can_widen_to(dest),
+            (TableDataType::Number(src), TableDataType::Number(dest)) => {
+                src != dest && src.can_lossless_cast_to(*dest)
+            }
+            _ => false,
+        }
+    }
+
     pub fn remove_nullable(&self) -> Self {
         match self {
             TableDataType::Nullable(ty) => (**ty).clone(),
@@ -1297,6 +1371,7 @@ impl From<&ArrowField> for TableField {
             default_expr: None,
             column_id: 0,
             computed_expr: None,
+            prev_versions: vec![],
         }
     }
 }
@@ -0,0 +1,381 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::check_number;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_io::prelude::deserialize_from_slice;
+use common_io::prelude::serialize_into_buf;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::AggregateFunctionRef;
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_params;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::BUILTIN_FUNCTIONS;
+
+/// The number of counters kept for each of the `k` values, more counters make the counts of
+/// the top values more accurate.
+const COUNTERS_PER_VALUE: usize = 3;
+
+/// The largest `k` accepted, as each state keeps `k * COUNTERS_PER_VALUE` counters.
+const MAX_K: u64 = 10000;
+
+#[derive(Serialize, Deserialize, Clone)]
+struct Counter {
+    value: Scalar,
+    count: u64,
+    /// The upper bound of the overestimation of `count`.
+    error: u64,
+}
+
+/// The SpaceSaving sketch: a value without a counter takes the counter of the least frequent
+/// value when all the counters are used, inheriting its count as the error.
+///
+/// The counters are kept as a binary min-heap on the count, so the least frequent value is
+/// always the first counter, and `positions` maps each value to its index in the heap.
+#[derive(Serialize, Deserialize)]
+struct ApproxTopKState {
+    capacity: usize,
+    counters: Vec<Counter>,
+    #[serde(skip)]
+    positions: HashMap<Scalar, usize>,
+}
+
+impl ApproxTopKState {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            counters: Vec::new(),
+            positions: HashMap::new(),
+        }
+    }
+
+    fn add(&mut self, value: ScalarRef) {
+        let value = value.to_owned();
+        if let Some(pos) = self.positions.get(&value) {
+            let pos = *pos;
+            self.counters[pos].count += 1;
+            self.sift_down(pos);
+        } else if self.counters.len() < self.capacity {
+            // A new counter has the smallest possible count, it's moved up to the root.
+            self.positions.insert(value.clone(), self.counters.len());
+            self.counters.push(Counter {
+                value,
+                count: 1,
+                error: 0,
+            });
+            self.sift_up(self.counters.len() - 1);
+        } else {
+            let counter = &mut self.counters[0];
+            self.positions.remove(&counter.value);
+            self.positions.insert(value.clone(), 0);
+            counter.value = value;
+            counter.error = counter.count;
+            counter.count += 1;
+            self.sift_down(0);
+        }
+    }
+
+    fn sift_up(&mut self, mut pos: usize) {
+        while pos > 0 {
+            let parent = (pos - 1) / 2;
+            if self.counters[parent].count <= self.counters[pos].count {
+                break;
+            }
+            self.swap(parent, pos);
+            pos = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut pos: usize) {
+        loop {
+            let mut smallest = pos;
+            for child in [2 * pos + 1, 2 * pos + 2] {
+                if child < self.counters.len()
+                    && self.counters[child].count < self.counters[smallest].count
+                {
+                    smallest = child;
+                }
+            }
+            if smallest == pos {
+                break;
+            }
+            self.swap(pos, smallest);
+            pos = smallest;
+        }
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.counters.swap(a, b);
+        self.positions.insert(self.counters[a].value.clone(), a);
+        self.positions.insert(self.counters[b].value.clone(), b);
+    }
+
+    /// The count a value without a counter may have at most.
+    fn min_count(&self) -> u64 {
+        if self.counters.len() < self.capacity {
+            0
+        } else {
+            self.counters[0].count
+        }
+    }
+
+    fn merge(&mut self, rhs: &Self) {
+        if rhs.counters.is_empty() {
+            return;
+        }
+
+        let lhs_min = self.min_count();
+        let rhs_min = rhs.min_count();
+        let mut rhs_merged = vec![false; rhs.counters.len()];
+        for counter in self.counters.iter_mut() {
+            match rhs.positions.get(&counter.value) {
+                Some(pos) => {
+                    counter.count += rhs.counters[*pos].count;
+                    counter.error += rhs.counters[*pos].error;
+                    rhs_merged[*pos] = true;
+                }
+                None => {
+                    counter.count += rhs_min;
+                    counter.error += rhs_min;
+                }
+            }
+        }
+        for (counter, merged) in rhs.counters.iter().zip(rhs_merged) {
+            if !merged {
+                self.counters.push(Counter {
+                    value: counter.value.clone(),
+                    count: counter.count + lhs_min,
+                    error: counter.error + lhs_min,
+                });
+            }
+        }
+
+        // Keeps the most frequent values, in ascending order of count, which is a valid heap.
+        self.counters.sort_by(Self::cmp_desc);
+        self.counters.truncate(self.capacity);
+        self.counters.reverse();
+        self.rebuild_positions();
+    }
+
+    /// Orders the counters by count descending, ties are broken by value to be deterministic.
+    fn cmp_desc(a: &Counter, b: &Counter) -> Ordering {
+        b.count.cmp(&a.count).then_with(|| a.value.cmp(&b.value))
+    }
+
+    fn rebuild_positions(&mut self) {
+        self.positions = self
+            .counters
+            .iter()
+            .enumerate()
+            .map(|(pos, counter)| (counter.value.clone(), pos))
+            .collect();
+    }
+
+    fn merge_result(&self, builder: &mut ColumnBuilder, k: usize) -> Result<()> {
+        let mut counters = self.counters.iter().collect::<Vec<_>>();
+        counters.sort_by(|a, b| Self::cmp_desc(a, b));
+
+        let builder = match builder {
+            ColumnBuilder::Array(box b) => b,
+            _ => unreachable!(),
+        };
+        for counter in counters.into_iter().take(k) {
+            builder.put_item(ScalarRef::Tuple(vec![
+                counter.value.as_ref(),
+                ScalarRef::Number(NumberScalar::UInt64(counter.count)),
+            ]));
+        }
+        builder.commit_row();
+        Ok(())
+    }
+}
+
+/// Returns the `k` most frequent values with their approximate counts, the sketches of the
+/// partial aggregations are merged with the Parallel SpaceSaving algorithm.
+#[derive(Clone)]
+pub struct AggregateApproxTopKFunction {
+    display_name: String,
+    return_type: DataType,
+    k: usize,
+}
+
+impl AggregateFunction for AggregateApproxTopKFunction {
+    fn name(&self) -> &str {
+        "AggregateApproxTopKFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(self.return_type.clone())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| ApproxTopKState::new(self.k * COUNTERS_PER_VALUE));
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<ApproxTopKState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<ApproxTopKState>();
+        match validity {
+            Some(bitmap) => {
+                for (value, is_valid) in columns[0].iter().zip(bitmap.iter()) {
+                    if is_valid {
+                        state.add(value);
+                    }
+                }
+            }
+            None => {
+                for value in columns[0].iter() {
+                    state.add(value);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (value, place) in columns[0].iter().zip(places.iter()) {
+            let state = place.next(offset).get::<ApproxTopKState>();
+            state.add(value);
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        if let Some(value) = columns[0].index(row) {
+            let state = place.get::<ApproxTopKState>();
+            state.add(value);
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<ApproxTopKState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<ApproxTopKState>();
+        *state = deserialize_from_slice(reader)?;
+        state.rebuild_positions();
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<ApproxTopKState>();
+        let state = place.get::<ApproxTopKState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<ApproxTopKState>();
+        state.merge_result(builder, self.k)
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<ApproxTopKState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl fmt::Display for AggregateApproxTopKFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub fn try_create_aggregate_approx_top_k_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_params(display_name, params.len(), 1)?;
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let k: u64 = check_number(
+        None,
+        &FunctionContext::default(),
+        &Expr::<usize>::Cast {
+            span: None,
+            is_try: false,
+            expr: Box::new(Expr::Constant {
+                span: None,
+                scalar: params[0].clone(),
+                data_type: params[0].as_ref().infer_data_type(),
+            }),
+            dest_type: DataType::Number(NumberDataType::UInt64),
+        },
+        &BUILTIN_FUNCTIONS,
+    )?;
+    if k == 0 || k > MAX_K {
+        return Err(ErrorCode::BadArguments(format!(
+            "The k of {} must be between 1 and {}",
+            display_name, MAX_K
+        )));
+    }
+
+    let return_type = DataType::Array(Box::new(DataType::Tuple(vec![
+        arguments[0].clone(),
+        DataType::Number(NumberDataType::UInt64),
+    ])));
+    Ok(Arc::new(AggregateApproxTopKFunction {
+        display_name: display_name.to_string(),
+        return_type,
+        k: k as usize,
+    }))
+}
+
+pub fn aggregate_approx_top_k_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_approx_top_k_function))
+}
@@ -77,8 +77,8 @@ impl QuantileTDigestState {
             unmerged_total_weight: 0f64,
             unmerged_weights: vec![],
             unmerged_means: vec![],
-            min: 0f64,
-            max: 0f64,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
         }
     }
 
@@ -90,6 +90,8 @@ impl QuantileTDigestState {
         self.unmerged_weights.push(1f64);
         self.unmerged_means.push(other);
         self.unmerged_total_weight += 1f64;
+        self.min = f64::min(self.min, other);
+        self.max = f64::max(self.max, other);
     }
 
     fn merge(&mut self, rhs: &mut Self) -> Result<()> {
@@ -101,7 +103,9 @@ impl QuantileTDigestState {
 
         self.unmerged_weights.extend_from_slice(&rhs.weights);
         self.unmerged_means.extend_from_slice(&rhs.means);
-        self.unmerged_total_weight = rhs.weights.iter().sum();
+        self.unmerged_total_weight += rhs.total_weight;
+        self.min = f64::min(self.min, rhs.min);
+        self.max = f64::max(self.max, rhs.max);
         self.compress();
 
         Ok(())
@@ -262,11 +266,6 @@ impl QuantileTDigestState {
             }
         }
 
-        if self.total_weight > 0f64 {
-            self.min = f64::min(self.min, means[0]);
-            self.max = f64::max(self.max, means[means.len() - 1]);
-        }
-
         self.weights = weights;
         self.means = means;
     }
@@ -293,7 +292,7 @@ impl<T> AggregateFunction for AggregateQuantileTDigestFunction<T>
 where T: Number + AsPrimitive<f64>
 {
     fn name(&self) -> &str {
-        "AggregateQuantileDiscFunction"
+        "AggregateQuantileTDigestFunction"
     }
     fn return_type(&self) -> Result<DataType> {
         Ok(self.return_type.clone())
@@ -394,7 +393,166 @@ where T: Number + AsPrimitive<f64>
         params: Vec<Scalar>,
         arguments: Vec<DataType>,
     ) -> Result<Arc<dyn AggregateFunction>> {
-        let levels = if params.len() == 1 {
+        let levels = get_levels(&params)?;
+        let func = AggregateQuantileTDigestFunction::<T> {
+            display_name: display_name.to_string(),
+            return_type,
+            levels,
+            _arguments: arguments,
+            _t: PhantomData,
+        };
+        Ok(Arc::new(func))
+    }
+}
+
+/// Merges the states serialized by `quantile_tdigest_state`, so the digests of partial
+/// aggregations can be stored and combined later.
+#[derive(Clone)]
+pub struct AggregateQuantileTDigestMergeFunction {
+    display_name: String,
+    return_type: DataType,
+    levels: Vec<f64>,
+}
+
+impl Display for AggregateQuantileTDigestMergeFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl AggregateQuantileTDigestMergeFunction {
+    fn merge_serialized(state: &mut QuantileTDigestState, mut data: &[u8]) -> Result<()> {
+        let mut rhs: QuantileTDigestState = deserialize_from_slice(&mut data).map_err(|e| {
+            ErrorCode::BadBytes(format!(
+                "Invalid state of quantile_tdigest: {}",
+                e.message()
+            ))
+        })?;
+        state.merge(&mut rhs)
+    }
+}
+
+impl AggregateFunction for AggregateQuantileTDigestMergeFunction {
+    fn name(&self) -> &str {
+        "AggregateQuantileTDigestMergeFunction"
+    }
+    fn return_type(&self) -> Result<DataType> {
+        Ok(self.return_type.clone())
+    }
+    fn init_state(&self, place: StateAddr) {
+        place.write(QuantileTDigestState::new)
+    }
+    fn state_layout(&self) -> Layout {
+        Layout::new::<QuantileTDigestState>()
+    }
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column = StringType::try_downcast_column(&columns[0]).unwrap();
+        let state = place.get::<QuantileTDigestState>();
+        match validity {
+            Some(bitmap) => {
+                for (data, is_valid) in column.iter().zip(bitmap.iter()) {
+                    if is_valid {
+                        Self::merge_serialized(state, data)?;
+                    }
+                }
+            }
+            None => {
+                for data in column.iter() {
+                    Self::merge_serialized(state, data)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let column = StringType::try_downcast_column(&columns[0]).unwrap();
+        if let Some(data) = StringType::index_column(&column, row) {
+            let state = place.get::<QuantileTDigestState>();
+            Self::merge_serialized(state, data)?;
+        }
+        Ok(())
+    }
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column = StringType::try_downcast_column(&columns[0]).unwrap();
+        for (data, place) in column.iter().zip(places.iter()) {
+            let state = place.next(offset).get::<QuantileTDigestState>();
+            Self::merge_serialized(state, data)?;
+        }
+        Ok(())
+    }
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<QuantileTDigestState>();
+        serialize_into_buf(writer, state)
+    }
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<QuantileTDigestState>();
+        *state = deserialize_from_slice(reader)?;
+
+        Ok(())
+    }
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<QuantileTDigestState>();
+        let state = place.get::<QuantileTDigestState>();
+        state.merge(rhs)
+    }
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<QuantileTDigestState>();
+        state.merge_result(builder, self.levels.clone())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<QuantileTDigestState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+fn get_levels(params: &[Scalar]) -> Result<Vec<f64>> {
+    if params.len() == 1 {
+        let level: F64 = check_number(
+            None,
+            &FunctionContext::default(),
+            &Expr::<usize>::Cast {
+                span: None,
+                is_try: false,
+                expr: Box::new(Expr::Constant {
+                    span: None,
+                    scalar: params[0].clone(),
+                    data_type: params[0].as_ref().infer_data_type(),
+                }),
+                dest_type: DataType::Number(NumberDataType::Float64),
+            },
+            &BUILTIN_FUNCTIONS,
+        )?;
+        let level = level.0;
+        if !(0.0..=1.0).contains(&level) {
+            return Err(ErrorCode::BadDataValueType(format!(
+                "level range between [0, 1], got: {:?}",
+                level
+            )));
+        }
+        Ok(vec![level])
+    } else if params.is_empty() {
+        Ok(vec![0.5f64])
+    } else {
+        let mut levels = Vec::with_capacity(params.len());
+        for param in params.iter() {
             let level: F64 = check_number(
                 None,
                 &FunctionContext::default(),
@@ -403,8 +561,8 @@ where T: Number + AsPrimitive<f64>
                     is_try: false,
                     expr: Box::new(Expr::Constant {
                         span: None,
-                        scalar: params[0].clone(),
-                        data_type: params[0].as_ref().infer_data_type(),
+                        scalar: param.clone(),
+                        data_type: param.as_ref().infer_data_type(),
                     }),
                     dest_type: DataType::Number(NumberDataType::Float64),
                 },
@@ -413,50 +571,13 @@ where T: Number + AsPrimitive<f64>
             let level = level.0;
             if !(0.0..=1.0).contains(&level) {
                 return Err(ErrorCode::BadDataValueType(format!(
-                    "level range between [0, 1], got: {:?}",
+                    "level range between [0, 1], got: {:?} in levels",
                     level
                 )));
             }
-            vec![level]
-        } else if params.is_empty() {
-            vec![0.5f64]
-        } else {
-            let mut levels = Vec::with_capacity(params.len());
-            for param in params {
-                let level: F64 = check_number(
-                    None,
-                    &FunctionContext::default(),
-                    &Expr::<usize>::Cast {
-                        span: None,
-                        is_try: false,
-                        expr: Box::new(Expr::Constant {
-                            span: None,
-                            scalar: param.clone(),
-                            data_type: param.as_ref().infer_data_type(),
-                        }),
-                        dest_type: DataType::Number(NumberDataType::Float64),
-                    },
-                    &BUILTIN_FUNCTIONS,
-                )?;
-                let level = level.0;
-                if !(0.0..=1.0).contains(&level) {
-                    return Err(ErrorCode::BadDataValueType(format!(
-                        "level range between [0, 1], got: {:?} in levels",
-                        level
-                    )));
-                }
-                levels.push(level);
-            }
-            levels
-        };
-        let func = AggregateQuantileTDigestFunction::<T> {
-            display_name: display_name.to_string(),
-            return_type,
-            levels,
-            _arguments: arguments,
-            _t: PhantomData,
-        };
-        Ok(Arc::new(func))
+            levels.push(level);
+        }
+        Ok(levels)
     }
 }
 
@@ -504,3 +625,35 @@ pub fn aggregate_median_tdigest_function_desc() -> AggregateFunctionDescription
         try_create_aggregate_quantile_tdigest_function::<MEDIAN>,
     ))
 }
+
+pub fn try_create_aggregate_quantile_tdigest_merge_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+    if arguments[0] != DataType::String {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}', expect the state of quantile_tdigest",
+            display_name, arguments[0]
+        )));
+    }
+
+    let return_type = if params.len() > 1 {
+        DataType::Array(Box::new(DataType::Number(NumberDataType::Float64)))
+    } else {
+        DataType::Number(NumberDataType::Float64)
+    };
+    let func = AggregateQuantileTDigestMergeFunction {
+        display_name: display_name.to_string(),
+        return_type,
+        levels: get_levels(&params)?,
+    };
+    Ok(Arc::new(func))
+}
+
+pub fn aggregate_quantile_tdigest_merge_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_quantile_tdigest_merge_function,
+    ))
+}
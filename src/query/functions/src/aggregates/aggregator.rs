@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::aggregate_approx_count_distinct::aggregate_approx_count_distinct_function_desc;
+use super::aggregate_approx_top_k::aggregate_approx_top_k_function_desc;
 use super::aggregate_arg_min_max::aggregate_arg_max_function_desc;
 use super::aggregate_arg_min_max::aggregate_arg_min_function_desc;
 use super::aggregate_avg::aggregate_avg_function_desc;
@@ -46,6 +47,7 @@ use crate::aggregates::aggregate_quantile_cont::aggregate_quantile_cont_function
 use crate::aggregates::aggregate_quantile_disc::aggregate_quantile_disc_function_desc;
 use crate::aggregates::aggregate_quantile_tdigest::aggregate_median_tdigest_function_desc;
 use crate::aggregates::aggregate_quantile_tdigest::aggregate_quantile_tdigest_function_desc;
+use crate::aggregates::aggregate_quantile_tdigest::aggregate_quantile_tdigest_merge_function_desc;
 use crate::aggregates::aggregate_retention::aggregate_retention_function_desc;
 use crate::aggregates::aggregate_skewness::aggregate_skewness_function_desc;
 use crate::aggregates::aggregate_string_agg::aggregate_string_agg_function_desc;
@@ -80,6 +82,14 @@ impl Aggregators {
             "quantile_tdigest",
             aggregate_quantile_tdigest_function_desc(),
         );
+        factory.register(
+            "quantile_tdigest_merge",
+            aggregate_quantile_tdigest_merge_function_desc(),
+        );
+        factory.register(
+            "approx_percentile",
+            aggregate_quantile_tdigest_function_desc(),
+        );
         factory.register("median", aggregate_median_function_desc());
         factory.register("median_tdigest", aggregate_median_tdigest_function_desc());
         factory.register("window_funnel", aggregate_window_funnel_function_desc());
@@ -87,6 +97,7 @@ impl Aggregators {
             "approx_count_distinct",
             aggregate_approx_count_distinct_function_desc(),
         );
+        factory.register("approx_top_k", aggregate_approx_top_k_function_desc());
         factory.register("retention", aggregate_retention_function_desc());
         factory.register("array_agg", aggregate_array_agg_function_desc());
         factory.register("list", aggregate_array_agg_function_desc());
@@ -20,6 +20,7 @@ mod aggregate_function_state;
 
 mod adaptors;
 mod aggregate_approx_count_distinct;
+mod aggregate_approx_top_k;
 mod aggregate_arg_min_max;
 mod aggregate_array_agg;
 mod aggregate_array_moving;
@@ -47,6 +48,7 @@ mod aggregator;
 mod aggregator_common;
 
 pub use adaptors::*;
+pub use aggregate_approx_top_k::AggregateApproxTopKFunction;
 pub use aggregate_arg_min_max::AggregateArgMinMaxFunction;
 pub use aggregate_array_agg::AggregateArrayAggFunction;
 pub use aggregate_array_moving::AggregateArrayMovingAvgFunction;
@@ -32,6 +32,7 @@ pub fn is_builtin_function(name: &str) -> bool {
         || AggregateFunctionFactory::instance().contains(name)
         || GENERAL_WINDOW_FUNCTIONS.contains(&name)
         || GENERAL_LAMBDA_FUNCTIONS.contains(&name)
+        || ASYNC_FUNCTIONS.contains(&name)
 }
 
 #[ctor]
@@ -55,6 +56,9 @@ pub const GENERAL_WINDOW_FUNCTIONS: [&str; 13] = [
 
 pub const GENERAL_LAMBDA_FUNCTIONS: [&str; 3] = ["array_transform", "array_apply", "array_filter"];
 
+/// Functions evaluated by asynchronous processors, as they call remote services.
+pub const ASYNC_FUNCTIONS: [&str; 1] = ["nextval"];
+
 fn builtin_functions() -> FunctionRegistry {
     let mut registry = FunctionRegistry::empty();
 
@@ -0,0 +1,297 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use common_exception::ErrorCode;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::ArrayType;
+use common_expression::types::BooleanType;
+use common_expression::types::StringType;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::vectorize_with_builder_3_arg;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Tokenizer used by full-text search functions and the inverted index.
+///
+/// The same tokenizer must be used to build the index and to evaluate `match()`,
+/// otherwise the pruner could skip blocks that actually contain the terms.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Tokenizer {
+    /// Split on any non-alphanumeric character and lowercase the terms.
+    #[default]
+    Standard,
+    /// Split on whitespace, terms are kept as is.
+    Whitespace,
+    /// Split on the Unicode word boundaries and lowercase the terms.
+    Unicode,
+    /// Like `Standard`, but runs of CJK characters, which are usually not separated
+    /// by spaces, are split into overlapping pairs of characters.
+    CjkBigram,
+}
+
+impl Tokenizer {
+    pub fn tokenize(&self, text: &str) -> Vec<String> {
+        match self {
+            Tokenizer::Standard => standard_terms(text).collect(),
+            Tokenizer::Whitespace => text.split_whitespace().map(|s| s.to_string()).collect(),
+            Tokenizer::Unicode => text.unicode_words().map(|s| s.to_lowercase()).collect(),
+            Tokenizer::CjkBigram => {
+                let mut terms = vec![];
+                for term in standard_terms(text) {
+                    cjk_bigrams(&term, &mut terms);
+                }
+                terms
+            }
+        }
+    }
+}
+
+impl FromStr for Tokenizer {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "standard" => Ok(Tokenizer::Standard),
+            "whitespace" => Ok(Tokenizer::Whitespace),
+            "unicode" => Ok(Tokenizer::Unicode),
+            "cjk_bigram" => Ok(Tokenizer::CjkBigram),
+            other => Err(ErrorCode::BadArguments(format!(
+                "Unknown tokenizer '{}', expecting 'standard', 'whitespace', 'unicode' or 'cjk_bigram'",
+                other
+            ))),
+        }
+    }
+}
+
+impl Display for Tokenizer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tokenizer::Standard => write!(f, "standard"),
+            Tokenizer::Whitespace => write!(f, "whitespace"),
+            Tokenizer::Unicode => write!(f, "unicode"),
+            Tokenizer::CjkBigram => write!(f, "cjk_bigram"),
+        }
+    }
+}
+
+fn standard_terms(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_lowercase())
+}
+
+/// Pushes the runs of non-CJK characters of `term`, and the bigrams of its runs of CJK
+/// characters, or the character itself if it's alone, to `terms`.
+fn cjk_bigrams(term: &str, terms: &mut Vec<String>) {
+    let chars = term.chars().collect::<Vec<_>>();
+    let mut start = 0;
+    while start < chars.len() {
+        let cjk = is_cjk(chars[start]);
+        let end = chars[start..]
+            .iter()
+            .position(|c| is_cjk(*c) != cjk)
+            .map_or(chars.len(), |len| start + len);
+        let run = &chars[start..end];
+        if cjk && run.len() > 1 {
+            terms.extend(
+                run.windows(2)
+                    .map(|bigram| bigram.iter().collect::<String>()),
+            );
+        } else {
+            terms.push(run.iter().collect());
+        }
+        start = end;
+    }
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{1100}'..='\u{11FF}' // Hangul Jamo
+        | '\u{3040}'..='\u{30FF}' // Hiragana and Katakana
+        | '\u{3130}'..='\u{318F}' // Hangul Compatibility Jamo
+        | '\u{3400}'..='\u{4DBF}' // CJK Unified Ideographs Extension A
+        | '\u{4E00}'..='\u{9FFF}' // CJK Unified Ideographs
+        | '\u{AC00}'..='\u{D7AF}' // Hangul Syllables
+        | '\u{F900}'..='\u{FAFF}' // CJK Compatibility Ideographs
+        | '\u{20000}'..='\u{2FA1F}' // CJK Unified Ideographs Extension B to F
+    )
+}
+
+/// The overlapping sequences of `n` characters of `text`, none if it is shorter.
+pub fn ngrams(text: &str, n: usize) -> Vec<&str> {
+    let starts = text
+        .char_indices()
+        .map(|(pos, _)| pos)
+        .chain([text.len()])
+        .collect::<Vec<_>>();
+    if n == 0 || n >= starts.len() {
+        return vec![];
+    }
+    starts
+        .windows(n + 1)
+        .map(|window| &text[window[0]..window[n]])
+        .collect()
+}
+
+/// Returns true if every term of `query` is one of the terms of `text`.
+pub fn match_terms(tokenizer: Tokenizer, text: &[u8], query: &[u8]) -> bool {
+    let terms = tokenizer.tokenize(&String::from_utf8_lossy(query));
+    if terms.is_empty() {
+        return true;
+    }
+    let text_terms = tokenizer
+        .tokenize(&String::from_utf8_lossy(text))
+        .into_iter()
+        .collect::<HashSet<_>>();
+    terms.iter().all(|term| text_terms.contains(term))
+}
+
+/// Parses the query string of `query()` into groups of terms, a text matches the query if
+/// it contains all the terms of any group. The terms are combined with `AND` by default and
+/// the groups are separated by the keyword `OR`, e.g. `timeout OR connection refused`.
+pub fn parse_query(tokenizer: Tokenizer, query: &str) -> Vec<Vec<String>> {
+    let mut groups = vec![];
+    let mut group = vec![];
+    for word in query.split_whitespace() {
+        match word {
+            "OR" => groups.push(tokenizer.tokenize(&std::mem::take(&mut group).join(" "))),
+            "AND" => {}
+            _ => group.push(word),
+        }
+    }
+    groups.push(tokenizer.tokenize(&group.join(" ")));
+    groups
+}
+
+/// Returns true if `text` contains all the terms of any group of `query`.
+pub fn query_terms(tokenizer: Tokenizer, text: &[u8], query: &[u8]) -> bool {
+    let groups = parse_query(tokenizer, &String::from_utf8_lossy(query));
+    let text_terms = tokenizer
+        .tokenize(&String::from_utf8_lossy(text))
+        .into_iter()
+        .collect::<HashSet<_>>();
+    groups
+        .iter()
+        .any(|terms| terms.iter().all(|term| text_terms.contains(term)))
+}
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "match",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<StringType, StringType, BooleanType>(
+            |text, query, output, _| {
+                output.push(match_terms(Tokenizer::Standard, text, query));
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, StringType, BooleanType, _, _>(
+        "match",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<StringType, StringType, StringType, BooleanType>(
+            |text, query, tokenizer, output, ctx| {
+                match Tokenizer::from_str(&String::from_utf8_lossy(tokenizer)) {
+                    Ok(tokenizer) => output.push(match_terms(tokenizer, text, query)),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e.message());
+                        output.push(false);
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "query",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<StringType, StringType, BooleanType>(
+            |text, query, output, _| {
+                output.push(query_terms(Tokenizer::Standard, text, query));
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, StringType, BooleanType, _, _>(
+        "query",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<StringType, StringType, StringType, BooleanType>(
+            |text, query, tokenizer, output, ctx| {
+                match Tokenizer::from_str(&String::from_utf8_lossy(tokenizer)) {
+                    Ok(tokenizer) => output.push(query_terms(tokenizer, text, query)),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e.message());
+                        output.push(false);
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, ArrayType<StringType>, _, _>(
+        "tokenize",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<StringType, ArrayType<StringType>>(|text, output, _| {
+            for term in Tokenizer::Standard.tokenize(&String::from_utf8_lossy(text)) {
+                output.put_item(term.as_bytes());
+            }
+            output.commit_row();
+        }),
+    );
+
+    registry
+        .register_passthrough_nullable_2_arg::<StringType, StringType, ArrayType<StringType>, _, _>(
+            "tokenize",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<StringType, StringType, ArrayType<StringType>>(
+                |text, tokenizer, output, ctx| {
+                    match Tokenizer::from_str(&String::from_utf8_lossy(tokenizer)) {
+                        Ok(tokenizer) => {
+                            for term in tokenizer.tokenize(&String::from_utf8_lossy(text)) {
+                                output.put_item(term.as_bytes());
+                            }
+                        }
+                        Err(e) => ctx.set_error(output.len(), e.message()),
+                    }
+                    output.commit_row();
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<StringType, UInt64Type, ArrayType<StringType>, _, _>(
+            "ngrams",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<StringType, UInt64Type, ArrayType<StringType>>(
+                |text, n, output, ctx| {
+                    if n == 0 {
+                        ctx.set_error(output.len(), "The length of n-grams must be positive");
+                    } else {
+                        let n = usize::try_from(n).unwrap_or(usize::MAX);
+                        for ngram in ngrams(&String::from_utf8_lossy(text), n) {
+                            output.put_item(ngram.as_bytes());
+                        }
+                    }
+                    output.commit_row();
+                },
+            ),
+        );
+}
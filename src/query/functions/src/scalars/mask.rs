@@ -0,0 +1,248 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deterministic masking functions, which are used to anonymize the sensitive
+//! data in masking policies or when exporting production data for testing.
+
+use common_expression::types::number::Float64Type;
+use common_expression::types::number::Int64Type;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::number::F64;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::DateType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::utils::date_helper::DateRounder;
+use common_expression::utils::date_helper::ToLastMonday;
+use common_expression::utils::date_helper::ToStartOfMonth;
+use common_expression::utils::date_helper::ToStartOfQuarter;
+use common_expression::utils::date_helper::ToStartOfYear;
+use common_expression::utils::date_helper::TzLUT;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::vectorize_with_builder_3_arg;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use sha2::Digest;
+use sha2::Sha256;
+
+const MASK_CHAR: char = '*';
+
+/// The number of trailing alphanumeric characters kept by `partial_mask` for
+/// values which are not emails, e.g. phone or card numbers.
+const DEFAULT_KEEP_SUFFIX: usize = 4;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
+        "hash_mask",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<StringType, StringType>(|val, output, _| {
+            output.put_str(&hash_hex(b"", val));
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, StringType, _, _>(
+        "hash_mask",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<StringType, StringType, StringType>(
+            |val, salt, output, _| {
+                output.put_str(&hash_hex(salt, val));
+                output.commit_row();
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
+        "partial_mask",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, StringType>(|val, output, ctx| {
+            match std::str::from_utf8(val) {
+                Ok(val) => match val.rfind('@') {
+                    Some(pos) if pos > 0 => {
+                        mask_alphanumeric(&val[..pos], 1, 0, output);
+                        output.put_str(&val[pos..]);
+                    }
+                    _ => mask_alphanumeric(val, 0, DEFAULT_KEEP_SUFFIX, output),
+                },
+                Err(err) => ctx.set_error(output.len(), err.to_string()),
+            }
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<
+        StringType,
+        UInt64Type,
+        UInt64Type,
+        StringType,
+        _,
+        _,
+    >(
+        "partial_mask",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<StringType, UInt64Type, UInt64Type, StringType>(
+            |val, keep_prefix, keep_suffix, output, ctx| {
+                match std::str::from_utf8(val) {
+                    Ok(val) => mask_alphanumeric(
+                        val,
+                        keep_prefix as usize,
+                        keep_suffix as usize,
+                        output,
+                    ),
+                    Err(err) => ctx.set_error(output.len(), err.to_string()),
+                }
+                output.commit_row();
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<Int64Type, Int64Type, Int64Type, _, _>(
+        "generalize",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<Int64Type, Int64Type, Int64Type>(
+            |val, step, output, ctx| {
+                if step <= 0 {
+                    ctx.set_error(
+                        output.len(),
+                        format!("generalize step must be positive, but got {}", step),
+                    );
+                    output.push(0);
+                } else {
+                    output.push(val.div_euclid(step) * step);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<Float64Type, Float64Type, Float64Type, _, _>(
+        "generalize",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<Float64Type, Float64Type, Float64Type>(
+            |val, step, output, ctx| {
+                if !(step.is_finite() && step > F64::from(0.0)) {
+                    ctx.set_error(
+                        output.len(),
+                        format!("generalize step must be positive, but got {}", step),
+                    );
+                    output.push(F64::from(0.0));
+                } else {
+                    output.push((val / step).floor() * step);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<DateType, StringType, DateType, _, _>(
+        "generalize",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, StringType, DateType>(|val, unit, output, ctx| {
+            match DateUnit::parse(unit) {
+                Ok(unit) => output.push(unit.eval_date(val, ctx.func_ctx.tz)),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(0);
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<TimestampType, StringType, DateType, _, _>(
+        "generalize",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<TimestampType, StringType, DateType>(
+            |val, unit, output, ctx| match DateUnit::parse(unit) {
+                Ok(unit) => output.push(unit.eval_timestamp(val, ctx.func_ctx.tz)),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+}
+
+fn hash_hex(salt: &[u8], val: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(salt);
+    hasher.update(val);
+    format!("{:x}", hasher.finalize())
+}
+
+/// Replaces the alphanumeric characters with `*`, except the first `keep_prefix` and
+/// the last `keep_suffix` ones. Other characters such as separators are kept as is,
+/// so the masked value preserves the format of the original one.
+fn mask_alphanumeric(
+    val: &str,
+    keep_prefix: usize,
+    keep_suffix: usize,
+    output: &mut StringColumnBuilder,
+) {
+    let total = val.chars().filter(|c| c.is_alphanumeric()).count();
+    let mut idx = 0;
+    for c in val.chars() {
+        if !c.is_alphanumeric() {
+            output.put_char(c);
+            continue;
+        }
+        if idx < keep_prefix || idx + keep_suffix >= total {
+            output.put_char(c);
+        } else {
+            output.put_char(MASK_CHAR);
+        }
+        idx += 1;
+    }
+}
+
+/// The unit to which `generalize` truncates the dates and timestamps.
+enum DateUnit {
+    Year,
+    Quarter,
+    Month,
+    Week,
+}
+
+impl DateUnit {
+    fn parse(unit: &[u8]) -> Result<Self, String> {
+        let unit = String::from_utf8_lossy(unit).to_lowercase();
+        match unit.as_str() {
+            "year" => Ok(DateUnit::Year),
+            "quarter" => Ok(DateUnit::Quarter),
+            "month" => Ok(DateUnit::Month),
+            "week" => Ok(DateUnit::Week),
+            _ => Err(format!(
+                "generalize unit must be one of [year, quarter, month, week], but got '{}'",
+                unit
+            )),
+        }
+    }
+
+    fn eval_date(&self, date: i32, tz: TzLUT) -> i32 {
+        match self {
+            DateUnit::Year => DateRounder::eval_date::<ToStartOfYear>(date, tz),
+            DateUnit::Quarter => DateRounder::eval_date::<ToStartOfQuarter>(date, tz),
+            DateUnit::Month => DateRounder::eval_date::<ToStartOfMonth>(date, tz),
+            DateUnit::Week => DateRounder::eval_date::<ToLastMonday>(date, tz),
+        }
+    }
+
+    fn eval_timestamp(&self, us: i64, tz: TzLUT) -> i32 {
+        match self {
+            DateUnit::Year => DateRounder::eval_timestamp::<ToStartOfYear>(us, tz),
+            DateUnit::Quarter => DateRounder::eval_timestamp::<ToStartOfQuarter>(us, tz),
+            DateUnit::Month => DateRounder::eval_timestamp::<ToStartOfMonth>(us, tz),
+            DateUnit::Week => DateRounder::eval_timestamp::<ToLastMonday>(us, tz),
+        }
+    }
+}
@@ -23,14 +23,17 @@ mod comparison;
 mod control;
 mod datetime;
 mod decimal;
+mod full_text;
 mod geo;
 mod geo_h3;
 mod hash;
 mod map;
+mod mask;
 mod math;
 mod other;
 mod string;
 mod string_multi_args;
+mod string_similarity;
 mod tuple;
 mod variant;
 mod vector;
@@ -39,6 +42,11 @@ pub use comparison::check_pattern_type;
 pub use comparison::is_like_pattern_escape;
 pub use comparison::PatternType;
 pub use comparison::ALL_COMP_FUNC_NAMES;
+pub use full_text::match_terms;
+pub use full_text::parse_query;
+pub use full_text::query_terms;
+pub use full_text::Tokenizer;
+pub use string_multi_args::format;
 
 pub fn register(registry: &mut FunctionRegistry) {
     variant::register(registry);
@@ -52,6 +60,7 @@ pub fn register(registry: &mut FunctionRegistry) {
     map::register(registry);
     string::register(registry);
     string_multi_args::register(registry);
+    string_similarity::register(registry);
     tuple::register(registry);
     geo::register(registry);
     geo_h3::register(registry);
@@ -60,4 +69,6 @@ pub fn register(registry: &mut FunctionRegistry) {
     decimal::register(registry);
     vector::register(registry);
     bitmap::register(registry);
+    full_text::register(registry);
+    mask::register(registry);
 }
@@ -21,14 +21,17 @@ use common_base::base::convert_byte_size;
 use common_base::base::convert_number_size;
 use common_base::base::uuid::Uuid;
 use common_expression::error_to_null;
+use common_expression::passthrough_nullable_1_arg;
 use common_expression::types::boolean::BooleanDomain;
 use common_expression::types::nullable::NullableColumn;
+use common_expression::types::nullable::NullableDomain;
 use common_expression::types::number::Float64Type;
 use common_expression::types::number::Int64Type;
 use common_expression::types::number::UInt32Type;
 use common_expression::types::number::UInt8Type;
 use common_expression::types::number::F64;
 use common_expression::types::string::StringColumn;
+use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::ArgType;
 use common_expression::types::DataType;
 use common_expression::types::DateType;
@@ -43,11 +46,13 @@ use common_expression::types::SimpleDomain;
 use common_expression::types::StringType;
 use common_expression::types::TimestampType;
 use common_expression::types::ValueType;
+use common_expression::utils::arrow::constant_bitmap;
 use common_expression::vectorize_with_builder_1_arg;
 use common_expression::Column;
 use common_expression::Domain;
 use common_expression::EvalContext;
 use common_expression::Function;
+use common_expression::FunctionContext;
 use common_expression::FunctionDomain;
 use common_expression::FunctionEval;
 use common_expression::FunctionProperty;
@@ -58,6 +63,8 @@ use common_expression::ScalarRef;
 use common_expression::Value;
 use common_expression::ValueRef;
 use ordered_float::OrderedFloat;
+use rand::distributions::Alphanumeric;
+use rand::rngs::SmallRng;
 use rand::Rng;
 use rand::SeedableRng;
 
@@ -78,6 +85,18 @@ pub fn register(registry: &mut FunctionRegistry) {
         "rand".to_string(),
         FunctionProperty::default().non_deterministic(),
     );
+    registry.properties.insert(
+        "gen_random_uuid".to_string(),
+        FunctionProperty::default().non_deterministic(),
+    );
+    registry.properties.insert(
+        "randn".to_string(),
+        FunctionProperty::default().non_deterministic(),
+    );
+    registry.properties.insert(
+        "random_string".to_string(),
+        FunctionProperty::default().non_deterministic(),
+    );
 
     registry.register_passthrough_nullable_1_arg::<Float64Type, StringType, _, _>(
         "humanize_size",
@@ -139,7 +158,7 @@ pub fn register(registry: &mut FunctionRegistry) {
             })
         },
         |ctx| {
-            let mut rng = rand::rngs::SmallRng::from_entropy();
+            let mut rng = SmallRng::from_entropy();
             let rand_nums = (0..ctx.num_rows)
                 .map(|_| rng.gen::<F64>())
                 .collect::<Vec<_>>();
@@ -147,7 +166,9 @@ pub fn register(registry: &mut FunctionRegistry) {
         },
     );
 
-    registry.register_1_arg::<NumberType<u64>, NumberType<F64>, _, _>(
+    // A constant seed generates a repeatable sequence of values, one per row.
+    register_random_1_arg::<NumberType<u64>, NumberType<F64>, _, _>(
+        registry,
         "rand",
         |_, _| {
             FunctionDomain::Domain(SimpleDomain {
@@ -155,9 +176,63 @@ pub fn register(registry: &mut FunctionRegistry) {
                 max: OrderedFloat(1.0),
             })
         },
-        |val, _| {
-            let mut rng = rand::rngs::SmallRng::seed_from_u64(val);
-            rng.gen::<F64>()
+        |seed, ctx| gen_seeded(seed, ctx, |rng| rng.gen::<F64>()),
+    );
+
+    registry.register_0_arg_core::<NumberType<F64>, _, _>(
+        "randn",
+        |_| FunctionDomain::Full,
+        |ctx| {
+            let mut rng = SmallRng::from_entropy();
+            let rand_nums = (0..ctx.num_rows)
+                .map(|_| gen_standard_normal(&mut rng))
+                .collect::<Vec<_>>();
+            Value::Column(rand_nums.into())
+        },
+    );
+
+    register_random_1_arg::<NumberType<u64>, NumberType<F64>, _, _>(
+        registry,
+        "randn",
+        |_, _| FunctionDomain::Full,
+        |seed, ctx| gen_seeded(seed, ctx, gen_standard_normal),
+    );
+
+    register_random_1_arg::<NumberType<u64>, StringType, _, _>(
+        registry,
+        "random_string",
+        |ctx, len| {
+            if len.max > ctx.max_string_result_bytes {
+                FunctionDomain::MayThrow
+            } else {
+                FunctionDomain::Full
+            }
+        },
+        |len, ctx| {
+            let max_bytes = ctx.func_ctx.max_string_result_bytes;
+            let mut rng = SmallRng::from_entropy();
+            let mut builder = StringColumnBuilder::with_capacity(ctx.num_rows, 0);
+            for row in 0..ctx.num_rows {
+                let len = match &len {
+                    ValueRef::Scalar(len) => *len,
+                    ValueRef::Column(col) => col[row],
+                };
+                if len > max_bytes {
+                    ctx.set_error(
+                        row,
+                        format!(
+                            "Too long random string: ({}), maximum is: {}",
+                            len, max_bytes
+                        ),
+                    );
+                } else {
+                    builder
+                        .data
+                        .extend((&mut rng).sample_iter(Alphanumeric).take(len as usize));
+                }
+                builder.commit_row();
+            }
+            Value::Column(builder.build())
         },
     );
 
@@ -235,6 +310,85 @@ pub fn register(registry: &mut FunctionRegistry) {
     );
 }
 
+/// Registers a random function of one argument, which generates a value per row even if
+/// the argument is a constant, so the values are not folded into one broadcast scalar.
+fn register_random_1_arg<I1: ArgType, O: ArgType, F, G>(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    calc_domain: F,
+    func: G,
+) where
+    F: Fn(&FunctionContext, &I1::Domain) -> FunctionDomain<O>
+        + 'static
+        + Clone
+        + Copy
+        + Send
+        + Sync,
+    G: for<'a> Fn(ValueRef<'a, I1>, &mut EvalContext) -> Value<O>
+        + 'static
+        + Clone
+        + Copy
+        + Send
+        + Sync,
+{
+    registry.register_1_arg_core::<I1, O, _, _>(name, calc_domain, func);
+
+    registry.register_1_arg_core::<NullableType<I1>, NullableType<O>, _, _>(
+        name,
+        move |ctx, arg| match &arg.value {
+            Some(value) => match calc_domain(ctx, value).normalize() {
+                Some(domain) => FunctionDomain::Domain(NullableDomain {
+                    has_null: arg.has_null,
+                    value: Some(Box::new(domain)),
+                }),
+                None => FunctionDomain::MayThrow,
+            },
+            None => FunctionDomain::Domain(NullableDomain {
+                has_null: true,
+                value: None,
+            }),
+        },
+        move |arg, ctx| match arg {
+            ValueRef::Scalar(None) => Value::Scalar(None),
+            ValueRef::Scalar(Some(arg)) => match func(ValueRef::Scalar(arg), ctx) {
+                Value::Scalar(value) => Value::Scalar(Some(value)),
+                Value::Column(column) => Value::Column(NullableColumn {
+                    column,
+                    validity: constant_bitmap(true, ctx.num_rows).into(),
+                }),
+            },
+            ValueRef::Column(arg) => passthrough_nullable_1_arg(func)(ValueRef::Column(arg), ctx),
+        },
+    );
+}
+
+/// Generates a value per row, from a generator seeded by `seed`. A constant seed seeds
+/// one generator for all the rows of the query, otherwise each row has its own generator.
+fn gen_seeded(
+    seed: ValueRef<NumberType<u64>>,
+    ctx: &EvalContext,
+    gen: impl Fn(&mut SmallRng) -> F64,
+) -> Value<NumberType<F64>> {
+    let rand_nums = match seed {
+        ValueRef::Scalar(seed) => ctx.func_ctx.seeded_rngs.with_rng(seed, |rng| {
+            (0..ctx.num_rows).map(|_| gen(rng)).collect::<Vec<_>>()
+        }),
+        ValueRef::Column(col) => col
+            .iter()
+            .map(|seed| gen(&mut SmallRng::seed_from_u64(*seed)))
+            .collect::<Vec<_>>(),
+    };
+    Value::Column(rand_nums.into())
+}
+
+/// Generates a value of the standard normal distribution with the Box-Muller transform.
+fn gen_standard_normal(rng: &mut SmallRng) -> F64 {
+    // `1 - u` is in (0, 1], so its logarithm is finite.
+    let u1 = 1.0 - rng.gen::<f64>();
+    let u2 = rng.gen::<f64>();
+    F64::from((-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos())
+}
+
 fn register_inet_aton(registry: &mut FunctionRegistry) {
     registry.register_passthrough_nullable_1_arg::<StringType, UInt32Type, _, _>(
         "inet_aton",
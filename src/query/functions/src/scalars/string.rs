@@ -231,6 +231,33 @@ pub fn register(registry: &mut FunctionRegistry) {
         }),
     );
 
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, StringType, StringType, _, _>(
+        "translate",
+        |_, _, _, _| FunctionDomain::Full,
+        vectorize_with_builder_3_arg::<StringType, StringType, StringType, StringType>(
+            |str, from, to, output, _| {
+            // Characters of `from` without a counterpart in `to` are deleted.
+            let from = from.chars().collect::<Vec<_>>();
+            let to = to.chars().collect::<Vec<_>>();
+            for (start, end, ch) in str.char_indices() {
+                if ch == '\u{FFFD}' {
+                    // If char is invalid, just copy it.
+                    output.put_slice(&str[start..end]);
+                    continue;
+                }
+                match from.iter().position(|c| *c == ch) {
+                    Some(idx) => {
+                        if let Some(c) = to.get(idx) {
+                            output.put_char(*c);
+                        }
+                    }
+                    None => output.put_slice(&str[start..end]),
+                }
+            }
+            output.commit_row();
+        }),
+    );
+
     registry.register_2_arg::<StringType, StringType, NumberType<i8>, _, _>(
         "strcmp",
         |_, _, _| FunctionDomain::Full,
@@ -528,22 +555,28 @@ pub fn register(registry: &mut FunctionRegistry) {
         }),
     );
 
-    const MAX_REPEAT_TIMES: u64 = 1000000;
     registry.register_passthrough_nullable_2_arg::<StringType, NumberType<u64>, StringType, _, _>(
         "repeat",
         |_, _, _| FunctionDomain::MayThrow,
         vectorize_with_builder_2_arg::<StringType, NumberType<u64>, StringType>(
             |a, times, output, ctx| {
-                if times > MAX_REPEAT_TIMES {
-                    ctx.set_error(
+                let max_bytes = ctx.func_ctx.max_string_result_bytes;
+                match (a.len() as u64).checked_mul(times) {
+                    Some(bytes) if bytes <= max_bytes => {
+                        if !a.is_empty() {
+                            output.data.reserve(bytes as usize);
+                            (0..times).for_each(|_| output.put_slice(a));
+                        }
+                    }
+                    _ => ctx.set_error(
                         output.len(),
                         format!(
-                            "Too many times to repeat: ({}), maximum is: {}",
-                            times, MAX_REPEAT_TIMES
+                            "Too many bytes to repeat: ({} * {}), maximum is: {}",
+                            a.len(),
+                            times,
+                            max_bytes
                         ),
-                    );
-                } else {
-                    (0..times).for_each(|_| output.put_slice(a));
+                    ),
                 }
                 output.commit_row();
             },
@@ -603,29 +636,51 @@ pub fn register(registry: &mut FunctionRegistry) {
     const SPACE: u8 = 0x20;
     registry.register_passthrough_nullable_1_arg::<NumberType<u64>, StringType, _, _>(
         "space",
-        |_, domain| {
+        |ctx, domain| {
+            if domain.max > ctx.max_string_result_bytes {
+                return FunctionDomain::MayThrow;
+            }
             FunctionDomain::Domain(StringDomain {
                 min: vec![SPACE; domain.min as usize],
                 max: Some(vec![SPACE; domain.max as usize]),
             })
         },
-        |times, _| match times {
-            ValueRef::Scalar(times) => Value::Scalar(vec![SPACE; times as usize]),
-            ValueRef::Column(col) => {
-                let mut total_space: u64 = 0;
-                let mut offsets: Vec<u64> = Vec::with_capacity(col.len() + 1);
-                offsets.push(0);
-                for times in col.iter() {
-                    total_space += times;
-                    offsets.push(total_space);
+        |times, ctx| {
+            let max_bytes = ctx.func_ctx.max_string_result_bytes;
+            match times {
+                ValueRef::Scalar(times) => {
+                    if times > max_bytes {
+                        ctx.set_error(
+                            0,
+                            format!("Too many spaces: ({}), maximum is: {}", times, max_bytes),
+                        );
+                        return Value::Scalar(vec![]);
+                    }
+                    Value::Scalar(vec![SPACE; times as usize])
                 }
-                let col = StringColumnBuilder {
-                    data: vec![SPACE; total_space as usize],
-                    offsets,
-                    need_estimated: false,
+                ValueRef::Column(col) => {
+                    let mut total_space: u64 = 0;
+                    let mut offsets: Vec<u64> = Vec::with_capacity(col.len() + 1);
+                    offsets.push(0);
+                    for (row, times) in col.iter().enumerate() {
+                        if *times > max_bytes {
+                            ctx.set_error(
+                                row,
+                                format!("Too many spaces: ({}), maximum is: {}", times, max_bytes),
+                            );
+                        } else {
+                            total_space += times;
+                        }
+                        offsets.push(total_space);
+                    }
+                    let col = StringColumnBuilder {
+                        data: vec![SPACE; total_space as usize],
+                        offsets,
+                        need_estimated: false,
+                    }
+                    .build();
+                    Value::Column(col)
                 }
-                .build();
-                Value::Column(col)
             }
         },
     );
@@ -633,30 +688,21 @@ pub fn register(registry: &mut FunctionRegistry) {
     registry.register_passthrough_nullable_2_arg::<StringType, NumberType<u64>, StringType, _, _>(
         "left",
         |_, _, _| FunctionDomain::Full,
-        vectorize_with_builder_2_arg::<StringType, NumberType<u64>, StringType>(
-            |s, n, output, _| {
-                let n = n as usize;
-                if n < s.len() {
-                    output.put_slice(&s[0..n]);
-                } else {
-                    output.put_slice(s);
-                }
-                output.commit_row();
-            },
-        ),
+        vectorize_string_slice(left),
     );
 
     registry.register_passthrough_nullable_2_arg::<StringType, NumberType<u64>, StringType, _, _>(
         "right",
         |_, _, _| FunctionDomain::Full,
-        vectorize_with_builder_2_arg::<StringType, NumberType<u64>, StringType>(
-            |s, n, output, _| {
-                let n = n as usize;
-                if n < s.len() {
-                    output.put_slice(&s[s.len() - n..]);
-                } else {
-                    output.put_slice(s);
-                }
+        vectorize_string_slice(right),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, NumberType<i64>, StringType, _, _>(
+        "substring_index",
+        |_, _, _, _| FunctionDomain::Full,
+        vectorize_with_builder_3_arg::<StringType, StringType, NumberType<i64>, StringType>(
+            |s, delim, count, output, _| {
+                output.put_slice(substring_index(s, delim, count));
                 output.commit_row();
             },
         ),
@@ -773,6 +819,58 @@ pub(crate) mod soundex {
     }
 }
 
+/// The first `n` characters of `s`, or its first `n` bytes if it's known to be ASCII.
+#[inline]
+fn left(s: &[u8], n: usize, ascii: bool) -> &[u8] {
+    // A string has at most as many characters as bytes.
+    if ascii || n >= s.len() {
+        return &s[..n.min(s.len())];
+    }
+    let end = s
+        .char_indices()
+        .nth(n)
+        .map_or(s.len(), |(start, _, _)| start);
+    &s[..end]
+}
+
+/// The last `n` characters of `s`, or its last `n` bytes if it's known to be ASCII.
+#[inline]
+fn right(s: &[u8], n: usize, ascii: bool) -> &[u8] {
+    if ascii || n >= s.len() {
+        return &s[s.len() - n.min(s.len())..];
+    }
+    if n == 0 {
+        return &s[s.len()..];
+    }
+    let start = s
+        .char_indices()
+        .rev()
+        .nth(n - 1)
+        .map_or(0, |(start, _, _)| start);
+    &s[start..]
+}
+
+/// The part of `s` before the `count`-th occurrence of `delim` if `count` is positive, or
+/// after the `-count`-th occurrence from the end if it's negative.
+#[inline]
+fn substring_index<'a>(s: &'a [u8], delim: &[u8], count: i64) -> &'a [u8] {
+    if count == 0 || delim.is_empty() {
+        return &s[..0];
+    }
+    let nth = (count.unsigned_abs() - 1) as usize;
+    if count > 0 {
+        match s.find_iter(delim).nth(nth) {
+            Some(pos) => &s[..pos],
+            None => s,
+        }
+    } else {
+        match s.rfind_iter(delim).nth(nth) {
+            Some(pos) => &s[pos + delim.len()..],
+            None => s,
+        }
+    }
+}
+
 #[inline]
 fn substr(str: &[u8], pos: i64, len: u64) -> &[u8] {
     if pos > 0 && pos <= str.len() as i64 {
@@ -823,7 +921,8 @@ pub fn vectorize_string_to_string(
 ) -> impl Fn(ValueRef<StringType>, &mut EvalContext) -> Value<StringType> + Copy {
     move |arg1, ctx| match arg1 {
         ValueRef::Scalar(val) => {
-            let mut builder = StringColumnBuilder::with_capacity(1, 0);
+            let data_capacity = estimate_bytes(&StringColumnBuilder::repeat(val, 1).build());
+            let mut builder = StringColumnBuilder::with_capacity(1, data_capacity);
             func(val, &mut builder, ctx);
             Value::Scalar(builder.build_scalar())
         }
@@ -839,6 +938,46 @@ pub fn vectorize_string_to_string(
     }
 }
 
+/// (String, UInt64) to String scalar function returning a slice of the string. `func` is told
+/// whether the strings are all ASCII, so that it can count bytes instead of characters.
+fn vectorize_string_slice(
+    func: impl Fn(&[u8], usize, bool) -> &[u8] + Copy,
+) -> impl Fn(ValueRef<StringType>, ValueRef<UInt64Type>, &mut EvalContext) -> Value<StringType> + Copy
+{
+    move |arg1, arg2, _| match (arg1, arg2) {
+        (ValueRef::Scalar(s), ValueRef::Scalar(n)) => {
+            Value::Scalar(func(s, n as usize, s.is_ascii()).to_vec())
+        }
+        (ValueRef::Scalar(s), ValueRef::Column(ns)) => {
+            let ascii = s.is_ascii();
+            let mut builder = StringColumnBuilder::with_capacity(ns.len(), s.len() * ns.len());
+            for n in ns.iter() {
+                builder.put_slice(func(s, *n as usize, ascii));
+                builder.commit_row();
+            }
+            Value::Column(builder.build())
+        }
+        (ValueRef::Column(col), ValueRef::Scalar(n)) => {
+            let ascii = col.data().is_ascii();
+            let mut builder = StringColumnBuilder::with_capacity(col.len(), col.data().len());
+            for s in col.iter() {
+                builder.put_slice(func(s, n as usize, ascii));
+                builder.commit_row();
+            }
+            Value::Column(builder.build())
+        }
+        (ValueRef::Column(col), ValueRef::Column(ns)) => {
+            let ascii = col.data().is_ascii();
+            let mut builder = StringColumnBuilder::with_capacity(col.len(), col.data().len());
+            for (s, n) in col.iter().zip(ns.iter()) {
+                builder.put_slice(func(s, *n as usize, ascii));
+                builder.commit_row();
+            }
+            Value::Column(builder.build())
+        }
+    }
+}
+
 /// (String, String) to String scalar function with estimated output column capacity.
 fn vectorize_string_to_string_2_arg(
     estimate_bytes: impl Fn(&StringColumn, &StringColumn) -> usize + Copy,
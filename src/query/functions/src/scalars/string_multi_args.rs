@@ -247,6 +247,14 @@ pub fn register(registry: &mut FunctionRegistry) {
         }))
     });
 
+    registry.register_function_factory("format", |_, args_type| {
+        format_function("format", format::parse_format, args_type)
+    });
+
+    registry.register_function_factory("printf", |_, args_type| {
+        format_function("printf", format::parse_printf, args_type)
+    });
+
     registry.register_function_factory("char", |_, args_type| {
         if args_type.is_empty() {
             return None;
@@ -495,6 +503,103 @@ fn concat_fn(args: &[ValueRef<AnyType>], _: &mut EvalContext) -> Value<AnyType>
     }
 }
 
+type ParseTemplate = fn(&[u8]) -> Result<Vec<format::Piece>, String>;
+
+fn format_function(
+    name: &'static str,
+    parse: ParseTemplate,
+    args_type: &[DataType],
+) -> Option<Arc<Function>> {
+    if args_type.is_empty() {
+        return None;
+    }
+    let has_null = args_type.iter().any(|t| t.is_nullable_or_null());
+
+    let f = Function {
+        signature: FunctionSignature {
+            name: name.to_string(),
+            args_type: vec![DataType::String; args_type.len()],
+            return_type: DataType::String,
+        },
+        eval: FunctionEval::Scalar {
+            calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+            eval: Box::new(move |args, ctx| format_fn(name, parse, args, ctx)),
+        },
+    };
+
+    if has_null {
+        Some(Arc::new(f.wrap_nullable()))
+    } else {
+        Some(Arc::new(f))
+    }
+}
+
+fn format_fn(
+    name: &str,
+    parse: ParseTemplate,
+    args: &[ValueRef<AnyType>],
+    ctx: &mut EvalContext,
+) -> Value<AnyType> {
+    let len = args.iter().find_map(|arg| match arg {
+        ValueRef::Column(col) => Some(col.len()),
+        _ => None,
+    });
+    let args = args
+        .iter()
+        .map(|arg| arg.try_downcast::<StringType>().unwrap())
+        .collect::<Vec<_>>();
+    let num_args = args.len() - 1;
+    let parse_and_check = |template: &[u8]| {
+        let pieces = parse(template).map_err(|e| format!("{}: {}", name, e))?;
+        format::check_args(name, &pieces, num_args)?;
+        Ok::<_, String>(pieces)
+    };
+
+    // The template is parsed once if it is a constant.
+    let cached_pieces = match &args[0] {
+        ValueRef::Scalar(template) => Some(parse_and_check(template)),
+        _ => None,
+    };
+
+    let size = len.unwrap_or(1);
+    let mut builder = StringColumnBuilder::with_capacity(size, 0);
+    let mut values = Vec::with_capacity(num_args);
+    let mut buf = vec![];
+    for idx in 0..size {
+        let local_pieces;
+        let pieces = match &cached_pieces {
+            Some(pieces) => pieces,
+            None => {
+                local_pieces = parse_and_check(unsafe { args[0].index_unchecked(idx) });
+                &local_pieces
+            }
+        };
+        values.clear();
+        values.extend(
+            args.iter()
+                .skip(1)
+                .map(|arg| unsafe { arg.index_unchecked(idx) }),
+        );
+        buf.clear();
+        let result = match pieces {
+            Ok(pieces) => {
+                format::render(pieces, &values, &mut buf).map_err(|e| format!("{}: {}", name, e))
+            }
+            Err(e) => Err(e.clone()),
+        };
+        match result {
+            Ok(()) => builder.put_slice(&buf),
+            Err(e) => ctx.set_error(idx, e),
+        }
+        builder.commit_row();
+    }
+
+    match len {
+        Some(_) => Value::Column(Column::String(builder.build())),
+        _ => Value::Scalar(Scalar::String(builder.build_scalar())),
+    }
+}
+
 fn char_fn(args: &[ValueRef<AnyType>], _: &mut EvalContext) -> Value<AnyType> {
     let args = args
         .iter()
@@ -1145,3 +1250,301 @@ pub mod regexp {
         m
     }
 }
+
+/// Templates of `format`, with Python-style `{}` or `{<index>}` placeholders, and of `printf`,
+/// with C-style `%[flags][width][.precision]<conversion>` placeholders.
+pub mod format {
+    /// The maximum width or precision of a `printf` placeholder.
+    const MAX_WIDTH: usize = 1 << 20;
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Piece {
+        Literal(Vec<u8>),
+        /// The argument at an index, formatted by a `printf` conversion if any.
+        Arg(usize, Option<Conversion>),
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    pub struct Conversion {
+        pub left_align: bool,
+        pub zero_pad: bool,
+        pub plus_sign: bool,
+        pub space_sign: bool,
+        pub width: usize,
+        pub precision: Option<usize>,
+        pub specifier: u8,
+    }
+
+    pub fn parse_format(template: &[u8]) -> Result<Vec<Piece>, String> {
+        let mut pieces = vec![];
+        let mut literal = vec![];
+        let mut next_index = 0;
+        let (mut auto_index, mut manual_index) = (false, false);
+        let mut i = 0;
+        while i < template.len() {
+            match template[i] {
+                b'{' if template.get(i + 1) == Some(&b'{') => {
+                    literal.push(b'{');
+                    i += 2;
+                }
+                b'}' if template.get(i + 1) == Some(&b'}') => {
+                    literal.push(b'}');
+                    i += 2;
+                }
+                b'{' => {
+                    let end = template[i..]
+                        .iter()
+                        .position(|c| *c == b'}')
+                        .map(|pos| i + pos)
+                        .ok_or_else(|| "unmatched '{' in the template".to_string())?;
+                    let field = &template[i + 1..end];
+                    let index = if field.is_empty() {
+                        auto_index = true;
+                        next_index += 1;
+                        next_index - 1
+                    } else {
+                        manual_index = true;
+                        std::str::from_utf8(field)
+                            .ok()
+                            .and_then(|field| field.parse::<usize>().ok())
+                            .ok_or_else(|| {
+                                format!(
+                                    "invalid placeholder '{{{}}}' in the template, only '{{}}' and '{{<index>}}' are supported",
+                                    String::from_utf8_lossy(field)
+                                )
+                            })?
+                    };
+                    if auto_index && manual_index {
+                        return Err(
+                            "'{}' and '{<index>}' can't be mixed in the template".to_string()
+                        );
+                    }
+                    if !literal.is_empty() {
+                        pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+                    }
+                    pieces.push(Piece::Arg(index, None));
+                    i = end + 1;
+                }
+                b'}' => {
+                    return Err("single '}' in the template, use '}}' to insert a '}'".to_string());
+                }
+                c => {
+                    literal.push(c);
+                    i += 1;
+                }
+            }
+        }
+        if !literal.is_empty() {
+            pieces.push(Piece::Literal(literal));
+        }
+        Ok(pieces)
+    }
+
+    pub fn parse_printf(template: &[u8]) -> Result<Vec<Piece>, String> {
+        let mut pieces = vec![];
+        let mut literal = vec![];
+        let mut index = 0;
+        let mut i = 0;
+        while i < template.len() {
+            if template[i] != b'%' {
+                literal.push(template[i]);
+                i += 1;
+                continue;
+            }
+            i += 1;
+            if template.get(i) == Some(&b'%') {
+                literal.push(b'%');
+                i += 1;
+                continue;
+            }
+
+            let mut conversion = Conversion::default();
+            while let Some(c) = template.get(i) {
+                match c {
+                    b'-' => conversion.left_align = true,
+                    b'0' => conversion.zero_pad = true,
+                    b'+' => conversion.plus_sign = true,
+                    b' ' => conversion.space_sign = true,
+                    _ => break,
+                }
+                i += 1;
+            }
+            conversion.width = parse_number(template, &mut i)?.unwrap_or(0);
+            if template.get(i) == Some(&b'.') {
+                i += 1;
+                conversion.precision = Some(parse_number(template, &mut i)?.unwrap_or(0));
+            }
+            conversion.specifier = match template.get(i) {
+                Some(c @ (b's' | b'd' | b'i' | b'f' | b'F' | b'e' | b'E' | b'x' | b'X' | b'o')) => {
+                    *c
+                }
+                Some(c) => {
+                    return Err(format!(
+                        "unsupported conversion '%{}' in the template",
+                        *c as char
+                    ));
+                }
+                None => return Err("incomplete placeholder at the end of the template".to_string()),
+            };
+            i += 1;
+
+            if !literal.is_empty() {
+                pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+            }
+            pieces.push(Piece::Arg(index, Some(conversion)));
+            index += 1;
+        }
+        if !literal.is_empty() {
+            pieces.push(Piece::Literal(literal));
+        }
+        Ok(pieces)
+    }
+
+    fn parse_number(template: &[u8], i: &mut usize) -> Result<Option<usize>, String> {
+        let start = *i;
+        while template.get(*i).is_some_and(|c| c.is_ascii_digit()) {
+            *i += 1;
+        }
+        if start == *i {
+            return Ok(None);
+        }
+        match std::str::from_utf8(&template[start..*i])
+            .unwrap()
+            .parse::<usize>()
+        {
+            Ok(n) if n <= MAX_WIDTH => Ok(Some(n)),
+            _ => Err(format!(
+                "the width or precision of a placeholder can't exceed {}",
+                MAX_WIDTH
+            )),
+        }
+    }
+
+    /// The number of arguments taken by a template.
+    pub fn num_args(pieces: &[Piece]) -> usize {
+        pieces
+            .iter()
+            .filter_map(|piece| match piece {
+                Piece::Arg(index, _) => Some(index + 1),
+                Piece::Literal(_) => None,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn check_args(fn_name: &str, pieces: &[Piece], num_given: usize) -> Result<(), String> {
+        let num_args = num_args(pieces);
+        if num_args != num_given {
+            return Err(format!(
+                "{}: the template takes {} arguments, but got {}",
+                fn_name, num_args, num_given
+            ));
+        }
+        Ok(())
+    }
+
+    /// Renders the template into `buf`, `args` must hold the arguments it takes.
+    pub fn render(pieces: &[Piece], args: &[&[u8]], buf: &mut Vec<u8>) -> Result<(), String> {
+        for piece in pieces {
+            match piece {
+                Piece::Literal(literal) => buf.extend_from_slice(literal),
+                Piece::Arg(index, None) => buf.extend_from_slice(args[*index]),
+                Piece::Arg(index, Some(conversion)) => conversion.render(args[*index], buf)?,
+            }
+        }
+        Ok(())
+    }
+
+    impl Conversion {
+        fn render(&self, arg: &[u8], buf: &mut Vec<u8>) -> Result<(), String> {
+            let arg = String::from_utf8_lossy(arg);
+            let (negative, mut body) = match self.specifier {
+                b's' => match self.precision {
+                    Some(precision) => (false, arg.chars().take(precision).collect()),
+                    None => (false, arg.to_string()),
+                },
+                b'd' | b'i' => {
+                    let v = self.parse_int(&arg)?;
+                    (v < 0, v.unsigned_abs().to_string())
+                }
+                b'x' => (false, format!("{:x}", self.parse_int(&arg)? as u64)),
+                b'X' => (false, format!("{:X}", self.parse_int(&arg)? as u64)),
+                b'o' => (false, format!("{:o}", self.parse_int(&arg)? as u64)),
+                b'f' | b'F' => {
+                    let v = self.parse_float(&arg)?;
+                    let precision = self.precision.unwrap_or(6);
+                    (v.is_sign_negative(), format!("{:.*}", precision, v.abs()))
+                }
+                b'e' | b'E' => {
+                    let v = self.parse_float(&arg)?;
+                    let precision = self.precision.unwrap_or(6);
+                    // Rust renders `1.5e2`, C renders `1.5e+02`.
+                    let s = format!("{:.*e}", precision, v.abs());
+                    let s = match s.split_once('e') {
+                        Some((mantissa, exp)) => {
+                            let exp = exp.parse::<i32>().unwrap_or(0);
+                            let sign = if exp < 0 { '-' } else { '+' };
+                            format!("{}e{}{:02}", mantissa, sign, exp.abs())
+                        }
+                        None => s,
+                    };
+                    let s = match self.specifier {
+                        b'E' => s.to_uppercase(),
+                        _ => s,
+                    };
+                    (v.is_sign_negative(), s)
+                }
+                _ => unreachable!(),
+            };
+            let numeric = self.specifier != b's';
+            if let (b'd' | b'i' | b'x' | b'X' | b'o', Some(precision)) =
+                (self.specifier, self.precision)
+            {
+                let digits = body.len();
+                if digits < precision {
+                    body.insert_str(0, &"0".repeat(precision - digits));
+                }
+            }
+
+            let sign = match (negative, numeric) {
+                (true, _) => "-",
+                (false, true) if self.plus_sign => "+",
+                (false, true) if self.space_sign => " ",
+                _ => "",
+            };
+            let pad = self.width.saturating_sub(sign.len() + body.chars().count());
+            if self.left_align {
+                buf.extend_from_slice(sign.as_bytes());
+                buf.extend_from_slice(body.as_bytes());
+                buf.resize(buf.len() + pad, b' ');
+            } else if self.zero_pad && numeric {
+                buf.extend_from_slice(sign.as_bytes());
+                buf.resize(buf.len() + pad, b'0');
+                buf.extend_from_slice(body.as_bytes());
+            } else {
+                buf.resize(buf.len() + pad, b' ');
+                buf.extend_from_slice(sign.as_bytes());
+                buf.extend_from_slice(body.as_bytes());
+            }
+            Ok(())
+        }
+
+        fn parse_int(&self, arg: &str) -> Result<i64, String> {
+            let s = arg.trim();
+            s.parse::<i64>()
+                .or_else(|_| s.parse::<f64>().map(|v| v.trunc() as i64))
+                .map_err(|_| self.invalid_arg(arg))
+        }
+
+        fn parse_float(&self, arg: &str) -> Result<f64, String> {
+            arg.trim().parse::<f64>().map_err(|_| self.invalid_arg(arg))
+        }
+
+        fn invalid_arg(&self, arg: &str) -> String {
+            format!(
+                "'%{}' expects a number, but got '{}'",
+                self.specifier as char, arg
+            )
+        }
+    }
+}
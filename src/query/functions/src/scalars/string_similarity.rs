@@ -0,0 +1,179 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fuzzy string matching functions, which are used to find near duplicate values,
+//! e.g. misspelled names. The strings are compared character by character.
+
+use bstr::ByteSlice;
+use common_expression::types::number::Float64Type;
+use common_expression::types::number::SimpleDomain;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::number::F64;
+use common_expression::types::StringType;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+
+/// The weight of the common prefix in the Jaro-Winkler similarity.
+const JARO_WINKLER_PREFIX_WEIGHT: f64 = 0.1;
+/// The maximum length of the common prefix taken into account by the Jaro-Winkler similarity.
+const JARO_WINKLER_MAX_PREFIX: usize = 4;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_2_arg::<StringType, StringType, UInt64Type, _, _>(
+        "levenshtein",
+        |_, _, _| FunctionDomain::Full,
+        |a, b, _| levenshtein(&chars(a), &chars(b), usize::MAX) as u64,
+    );
+
+    // The distance is computed up to `max`, which is returned for more dissimilar strings.
+    registry.register_3_arg::<StringType, StringType, UInt64Type, UInt64Type, _, _>(
+        "levenshtein",
+        |_, _, _, max| {
+            FunctionDomain::Domain(SimpleDomain {
+                min: 0,
+                max: max.max,
+            })
+        },
+        |a, b, max, _| {
+            let max = usize::try_from(max).unwrap_or(usize::MAX);
+            levenshtein(&chars(a), &chars(b), max) as u64
+        },
+    );
+
+    registry.register_2_arg::<StringType, StringType, Float64Type, _, _>(
+        "levenshtein_ratio",
+        |_, _, _| unit_domain(),
+        |a, b, _| {
+            let (a, b) = (chars(a), chars(b));
+            let len = a.len().max(b.len());
+            if len == 0 {
+                return F64::from(1.0);
+            }
+            let distance = levenshtein(&a, &b, usize::MAX);
+            F64::from(1.0 - distance as f64 / len as f64)
+        },
+    );
+
+    registry.register_2_arg::<StringType, StringType, Float64Type, _, _>(
+        "jaro_winkler",
+        |_, _, _| unit_domain(),
+        |a, b, _| F64::from(jaro_winkler(&chars(a), &chars(b))),
+    );
+}
+
+fn unit_domain() -> FunctionDomain<Float64Type> {
+    FunctionDomain::Domain(SimpleDomain {
+        min: F64::from(0.0),
+        max: F64::from(1.0),
+    })
+}
+
+fn chars(s: &[u8]) -> Vec<char> {
+    s.chars().collect()
+}
+
+/// The Levenshtein distance between `a` and `b`, or `max` if it's greater than `max`.
+///
+/// The distance is computed row by row, and computing it stops as soon as all the
+/// distances of a row are greater than `max`, as the following rows can only be greater.
+fn levenshtein(a: &[char], b: &[char], max: usize) -> usize {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let (a, b) = (&a[prefix..], &b[prefix..]);
+    let suffix = a
+        .iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (a, b) = (&a[..a.len() - suffix], &b[..b.len() - suffix]);
+    let (a, b) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+
+    // The distance is at least the difference of the lengths.
+    if b.len() - a.len() > max {
+        return max;
+    }
+    if a.is_empty() {
+        return b.len();
+    }
+
+    // `row[i]` is the distance between `a[..i]` and the processed prefix of `b`.
+    let mut row = (0..=a.len()).collect::<Vec<_>>();
+    for (j, cb) in b.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = j + 1;
+        let mut row_min = row[0];
+        for (i, ca) in a.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[i + 1];
+            row[i + 1] = substitution.min(row[i] + 1).min(diagonal + 1);
+            row_min = row_min.min(row[i + 1]);
+        }
+        if row_min > max {
+            return max;
+        }
+    }
+    row[a.len()].min(max)
+}
+
+/// The Jaro similarity of `a` and `b`, increased for strings with a common prefix.
+fn jaro_winkler(a: &[char], b: &[char]) -> f64 {
+    let similarity = jaro(a, b);
+    let prefix = a
+        .iter()
+        .zip(b)
+        .take(JARO_WINKLER_MAX_PREFIX)
+        .take_while(|(x, y)| x == y)
+        .count();
+    similarity + prefix as f64 * JARO_WINKLER_PREFIX_WEIGHT * (1.0 - similarity)
+}
+
+fn jaro(a: &[char], b: &[char]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    // Characters match if they are equal and not farther apart than `window`.
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut b_matched = vec![false; b.len()];
+    let mut a_matches = Vec::with_capacity(a.len());
+    for (i, ca) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        if let Some(j) = (start..end).find(|&j| !b_matched[j] && b[j] == *ca) {
+            b_matched[j] = true;
+            a_matches.push(*ca);
+        }
+    }
+    if a_matches.is_empty() {
+        return 0.0;
+    }
+
+    let b_matches = b
+        .iter()
+        .zip(b_matched)
+        .filter_map(|(cb, matched)| matched.then_some(cb));
+    let transpositions = a_matches
+        .iter()
+        .zip(b_matches)
+        .filter(|(x, y)| x != y)
+        .count() as f64
+        / 2.0;
+
+    let matches = a_matches.len() as f64;
+    (matches / a.len() as f64 + matches / b.len() as f64 + (matches - transpositions) / matches)
+        / 3.0
+}
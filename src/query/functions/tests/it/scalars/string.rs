@@ -50,6 +50,7 @@ fn test_string() {
     test_unhex(file);
     test_pad(file);
     test_replace(file);
+    test_translate(file);
     test_strcmp(file);
     test_locate(file);
     test_char(file);
@@ -60,7 +61,13 @@ fn test_string() {
     test_space(file);
     test_left(file);
     test_right(file);
+    test_substring_index(file);
     test_substr(file);
+    test_format(file);
+    test_levenshtein(file);
+    test_jaro_winkler(file);
+    test_tokenize(file);
+    test_ngrams(file);
 }
 
 fn test_upper(file: &mut impl Write) {
@@ -474,6 +481,20 @@ fn test_replace(file: &mut impl Write) {
     run_ast(file, "replace(a, b, c)", &table);
 }
 
+fn test_translate(file: &mut impl Write) {
+    run_ast(file, "translate('abcdef', 'abc', 'xyz')", &[]);
+    run_ast(file, "translate('abcdef', 'abc', 'x')", &[]);
+    run_ast(file, "translate('abcabc', 'aa', 'xy')", &[]);
+    run_ast(file, "translate('Dobrý den', 'ý ', 'y_')", &[]);
+
+    let table = [
+        ("a", StringType::from_data(&["abc", "hello", "12345", ""])),
+        ("b", StringType::from_data(&["ab", "l", "135", "x"])),
+        ("c", StringType::from_data(&["xy", "L", "", "y"])),
+    ];
+    run_ast(file, "translate(a, b, c)", &table);
+}
+
 fn test_strcmp(file: &mut impl Write) {
     run_ast(file, "strcmp('text', 'text2')", &[]);
     run_ast(file, "strcmp('text2', 'text')", &[]);
@@ -546,7 +567,8 @@ fn test_ord(file: &mut impl Write) {
 fn test_repeat(file: &mut impl Write) {
     run_ast(file, "repeat('3', NULL)", &[]);
     run_ast(file, "repeat('3', 5)", &[]);
-    run_ast(file, "repeat('3', 1000001)", &[]);
+    run_ast(file, "repeat('3', 1000000000)", &[]);
+    run_ast(file, "repeat('', 1000000000)", &[]);
     let table = [("a", StringType::from_data(&["a", "b", "c"]))];
     run_ast(file, "repeat(a, 3)", &table);
 }
@@ -597,6 +619,7 @@ fn test_insert(file: &mut impl Write) {
 fn test_space(file: &mut impl Write) {
     run_ast(file, "space(0)", &[]);
     run_ast(file, "space(5)", &[]);
+    run_ast(file, "space(1000000000)", &[]);
     run_ast(file, "space(a)", &[(
         "a",
         UInt8Type::from_data(vec![0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9]),
@@ -610,6 +633,11 @@ fn test_left(file: &mut impl Write) {
         "a",
         UInt8Type::from_data(vec![0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10]),
     )]);
+    run_ast(file, "left('Dobrý den', 5)", &[]);
+    run_ast(file, "left(a, 2)", &[(
+        "a",
+        StringType::from_data(&["Abc", "Dobrý den", "ß😀山"]),
+    )]);
 }
 
 fn test_right(file: &mut impl Write) {
@@ -619,6 +647,23 @@ fn test_right(file: &mut impl Write) {
         "a",
         UInt8Type::from_data(vec![0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10]),
     )]);
+    run_ast(file, "right('Dobrý den', 5)", &[]);
+    run_ast(file, "right(a, 2)", &[(
+        "a",
+        StringType::from_data(&["Abc", "Dobrý den", "ß😀山"]),
+    )]);
+}
+
+fn test_substring_index(file: &mut impl Write) {
+    run_ast(file, "substring_index('www.databend.com', '.', 2)", &[]);
+    run_ast(file, "substring_index('www.databend.com', '.', -2)", &[]);
+    run_ast(file, "substring_index('www.databend.com', '.', 5)", &[]);
+    run_ast(file, "substring_index('www.databend.com', '.', 0)", &[]);
+    run_ast(file, "substring_index('www.databend.com', '', 1)", &[]);
+    run_ast(file, "substring_index(a, ',', 2)", &[(
+        "a",
+        StringType::from_data(&["a,b,c", "a", "", ",x"]),
+    )]);
 }
 
 fn test_substr(file: &mut impl Write) {
@@ -645,3 +690,57 @@ fn test_substr(file: &mut impl Write) {
         ),
     ]);
 }
+
+fn test_format(file: &mut impl Write) {
+    run_ast(file, "format('{} bought {} items', 'Alice', '3')", &[]);
+    run_ast(file, "format('{1}-{0}-{{}}', 'a', 'b')", &[]);
+    run_ast(file, "format(NULL, 'a')", &[]);
+    run_ast(file, "format('{} {}', 'a')", &[]);
+    run_ast(
+        file,
+        "printf('%5d|%-5s|%05.1f|%x|%+.2e|%%', '42', 'ab', '3.14159', '255', '12345')",
+        &[],
+    );
+    run_ast(file, "printf('%d', 'abc')", &[]);
+}
+
+fn test_levenshtein(file: &mut impl Write) {
+    run_ast(file, "levenshtein('kitten', 'sitting')", &[]);
+    run_ast(file, "levenshtein('kitten', 'sitting', 2)", &[]);
+    run_ast(file, "levenshtein('Dobrý', 'Dobry')", &[]);
+    run_ast(file, "levenshtein_ratio('kitten', 'sitting')", &[]);
+    run_ast(file, "levenshtein_ratio('', '')", &[]);
+
+    let table = [
+        (
+            "a",
+            StringType::from_data(&["kitten", "saturday", "数据库", ""]),
+        ),
+        (
+            "b",
+            StringType::from_data(&["sitting", "sunday", "数据", ""]),
+        ),
+    ];
+    run_ast(file, "levenshtein(a, b)", &table);
+}
+
+fn test_jaro_winkler(file: &mut impl Write) {
+    run_ast(file, "jaro_winkler('MARTHA', 'MARHTA')", &[]);
+    run_ast(file, "jaro_winkler('DIXON', 'DICKSONX')", &[]);
+    run_ast(file, "jaro_winkler('abc', '')", &[]);
+}
+
+fn test_tokenize(file: &mut impl Write) {
+    run_ast(file, "tokenize('The quick, brown FOX!')", &[]);
+    run_ast(file, "tokenize('The quick, brown FOX!', 'whitespace')", &[]);
+    run_ast(file, "tokenize('3.14 km/h', 'unicode')", &[]);
+    run_ast(file, "tokenize('数据库系统 databend', 'cjk_bigram')", &[]);
+    run_ast(file, "tokenize('abc', 'unknown')", &[]);
+}
+
+fn test_ngrams(file: &mut impl Write) {
+    run_ast(file, "ngrams('Databend', 3)", &[]);
+    run_ast(file, "ngrams('数据库', 2)", &[]);
+    run_ast(file, "ngrams('ab', 3)", &[]);
+    run_ast(file, "ngrams('ab', 0)", &[]);
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use common_exception::Result;
 use common_meta_types::MatchSeq;
 use common_meta_types::NodeInfo;
@@ -24,10 +26,13 @@ pub trait ClusterApi: Sync + Send {
     // Get the tenant's cluster all nodes.
     async fn get_nodes(&self) -> Result<Vec<NodeInfo>>;
 
+    // Get the nodes of all the tenant's clusters, keyed by the cluster id.
+    async fn get_tenant_clusters(&self) -> Result<BTreeMap<String, Vec<NodeInfo>>>;
+
     // Drop the tenant's cluster one node by node.id.
     async fn drop_node(&self, node_id: String, seq: MatchSeq) -> Result<()>;
 
-    // Keep the tenant's cluster node alive.
+    // Keep the tenant's cluster node alive, and refresh the node info(e.g. the load).
     async fn heartbeat(&self, node: &NodeInfo, seq: MatchSeq) -> Result<u64>;
 
     async fn get_local_addr(&self) -> Result<Option<String>>;
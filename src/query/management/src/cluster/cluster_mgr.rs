@@ -12,9 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::ops::Add;
+use std::collections::BTreeMap;
 use std::time::Duration;
-use std::time::UNIX_EPOCH;
 
 use common_base::base::escape_for_key;
 use common_base::base::unescape_for_key;
@@ -24,8 +23,8 @@ use common_meta_kvapi::kvapi::KVApi;
 use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_store::MetaStore;
-use common_meta_types::KVMeta;
 use common_meta_types::MatchSeq;
+use common_meta_types::MetaSpec;
 use common_meta_types::NodeInfo;
 use common_meta_types::Operation;
 use common_meta_types::SeqV;
@@ -37,6 +36,7 @@ pub static CLUSTER_API_KEY_PREFIX: &str = "__fd_clusters";
 pub struct ClusterMgr {
     metastore: MetaStore,
     lift_time: Duration,
+    tenant_prefix: String,
     cluster_prefix: String,
 }
 
@@ -53,28 +53,23 @@ impl ClusterMgr {
             ));
         }
 
+        let tenant_prefix = format!("{}/{}", CLUSTER_API_KEY_PREFIX, escape_for_key(tenant)?);
         Ok(ClusterMgr {
             metastore,
             lift_time,
             cluster_prefix: format!(
-                "{}/{}/{}/databend_query",
-                CLUSTER_API_KEY_PREFIX,
-                escape_for_key(tenant)?,
+                "{}/{}/databend_query",
+                tenant_prefix,
                 escape_for_key(cluster_id)?
             ),
+            tenant_prefix,
         })
     }
 
-    fn new_lift_time(&self) -> KVMeta {
-        let now = std::time::SystemTime::now();
-        let expire_at = now
-            .add(self.lift_time)
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards");
-
-        KVMeta {
-            expire_at: Some(expire_at.as_secs()),
-        }
+    /// The node expires after the lift time since it is applied by the meta service, unless
+    /// it is renewed by heartbeat.
+    fn new_lift_time(&self) -> MetaSpec {
+        MetaSpec::new_ttl(self.lift_time)
     }
 }
 
@@ -116,6 +111,30 @@ impl ClusterApi for ClusterMgr {
         Ok(nodes_info)
     }
 
+    #[async_backtrace::framed]
+    async fn get_tenant_clusters(&self) -> Result<BTreeMap<String, Vec<NodeInfo>>> {
+        let prefix = format!("{}/", self.tenant_prefix);
+        let values = self.metastore.prefix_list_kv(&prefix).await?;
+
+        let mut clusters = BTreeMap::<String, Vec<NodeInfo>>::new();
+        for (node_key, value) in values {
+            // The key is `<tenant_prefix>/<cluster_id>/databend_query/<node_id>`.
+            let parts = node_key[prefix.len()..].split('/').collect::<Vec<_>>();
+            if parts.len() != 3 || parts[1] != "databend_query" {
+                continue;
+            }
+
+            let mut node_info = serde_json::from_slice::<NodeInfo>(&value.data)?;
+            node_info.id = unescape_for_key(parts[2])?;
+            clusters
+                .entry(unescape_for_key(parts[0])?)
+                .or_default()
+                .push(node_info);
+        }
+
+        Ok(clusters)
+    }
+
     #[async_backtrace::framed]
     async fn drop_node(&self, node_id: String, seq: MatchSeq) -> Result<()> {
         let node_key = format!("{}/{}", self.cluster_prefix, escape_for_key(&node_id)?);
@@ -139,11 +158,12 @@ impl ClusterApi for ClusterMgr {
     #[async_backtrace::framed]
     async fn heartbeat(&self, node: &NodeInfo, seq: MatchSeq) -> Result<u64> {
         let meta = Some(self.new_lift_time());
+        let value = Operation::Update(serde_json::to_vec(node)?);
         let node_key = format!("{}/{}", self.cluster_prefix, escape_for_key(&node.id)?);
 
-        let upsert_meta =
-            self.metastore
-                .upsert_kv(UpsertKVReq::new(&node_key, seq, Operation::AsIs, meta));
+        let upsert_meta = self
+            .metastore
+            .upsert_kv(UpsertKVReq::new(&node_key, seq, value, meta));
 
         match upsert_meta.await? {
             UpsertKVReply {
@@ -17,11 +17,14 @@
 mod cluster;
 mod file_format;
 mod network_policy;
+mod pipe;
 mod quota;
 mod role;
+mod sequence;
 mod serde;
 mod setting;
 mod stage;
+mod storage_profile;
 mod udf;
 mod user;
 
@@ -31,16 +34,22 @@ pub use file_format::FileFormatApi;
 pub use file_format::FileFormatMgr;
 pub use network_policy::NetworkPolicyApi;
 pub use network_policy::NetworkPolicyMgr;
+pub use pipe::PipeApi;
+pub use pipe::PipeMgr;
 pub use quota::QuotaApi;
 pub use quota::QuotaMgr;
 pub use role::RoleApi;
 pub use role::RoleMgr;
+pub use sequence::SequenceApi;
+pub use sequence::SequenceMgr;
 pub use serde::deserialize_struct;
 pub use serde::serialize_struct;
 pub use setting::SettingApi;
 pub use setting::SettingMgr;
 pub use stage::StageApi;
 pub use stage::StageMgr;
+pub use storage_profile::StorageProfileApi;
+pub use storage_profile::StorageProfileMgr;
 pub use udf::UdfApi;
 pub use udf::UdfMgr;
 pub use user::UserApi;
@@ -0,0 +1,49 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_exception::Result;
+use common_meta_app::principal::PipeInfo;
+use common_meta_app::principal::PipeProgress;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+#[async_trait::async_trait]
+pub trait PipeApi: Sync + Send {
+    async fn add_pipe(&self, pipe: PipeInfo) -> Result<u64>;
+
+    /// Drop the pipe along with its progress.
+    async fn drop_pipe(&self, name: &str, seq: MatchSeq) -> Result<()>;
+
+    async fn get_pipe(&self, name: &str, seq: MatchSeq) -> Result<SeqV<PipeInfo>>;
+
+    async fn get_pipes(&self) -> Result<Vec<PipeInfo>>;
+
+    /// Get the progress of the pipe, the seq is 0 if the pipe has no progress yet.
+    async fn get_pipe_progress(&self, name: &str) -> Result<SeqV<PipeProgress>>;
+
+    /// Update the progress of the pipe if the seq matches, returns the new seq.
+    async fn update_pipe_progress(
+        &self,
+        name: &str,
+        progress: &PipeProgress,
+        seq: MatchSeq,
+    ) -> Result<u64>;
+
+    /// Acquire or renew the lease to run the pipe for `ttl`.
+    ///
+    /// Returns false if the lease is held by another node.
+    async fn acquire_pipe_lease(&self, name: &str, node_id: &str, ttl: Duration) -> Result<bool>;
+}
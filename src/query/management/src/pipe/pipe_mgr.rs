@@ -0,0 +1,195 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::PipeInfo;
+use common_meta_app::principal::PipeProgress;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaError;
+use common_meta_types::MetaSpec;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+use crate::pipe::pipe_api::PipeApi;
+
+static PIPE_API_KEY_PREFIX: &str = "__fd_pipes";
+static PIPE_PROGRESS_API_KEY_PREFIX: &str = "__fd_pipe_progress";
+static PIPE_LEASE_API_KEY_PREFIX: &str = "__fd_pipe_lease";
+
+pub struct PipeMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    pipe_prefix: String,
+    progress_prefix: String,
+    lease_prefix: String,
+}
+
+impl PipeMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty (while create pipe)",
+            ));
+        }
+
+        Ok(PipeMgr {
+            kv_api,
+            pipe_prefix: format!("{}/{}", PIPE_API_KEY_PREFIX, tenant),
+            progress_prefix: format!("{}/{}", PIPE_PROGRESS_API_KEY_PREFIX, tenant),
+            lease_prefix: format!("{}/{}", PIPE_LEASE_API_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn make_key(prefix: &str, name: &str) -> Result<String> {
+        Ok(format!("{}/{}", prefix, escape_for_key(name)?))
+    }
+
+    fn unknown_pipe(name: &str) -> ErrorCode {
+        ErrorCode::UnknownPipe(format!("Unknown pipe {}", name))
+    }
+}
+
+#[async_trait::async_trait]
+impl PipeApi for PipeMgr {
+    #[async_backtrace::framed]
+    async fn add_pipe(&self, pipe: PipeInfo) -> Result<u64> {
+        let match_seq = MatchSeq::Exact(0);
+        let key = Self::make_key(&self.pipe_prefix, &pipe.name)?;
+        let value = Operation::Update(serde_json::to_vec(&pipe)?);
+
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = kv_api.upsert_kv(UpsertKVReq::new(&key, match_seq, value, None));
+
+        let res = upsert_kv.await?.added_or_else(|v| {
+            ErrorCode::PipeAlreadyExists(format!("Pipe already exists, seq [{}]", v.seq))
+        })?;
+
+        Ok(res.seq)
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_pipe(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = Self::make_key(&self.pipe_prefix, name)?;
+        let kv_api = self.kv_api.clone();
+        let res = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_none() || res.result.is_some() {
+            return Err(Self::unknown_pipe(name));
+        }
+
+        // The progress is not used once the pipe is dropped.
+        for prefix in [&self.progress_prefix, &self.lease_prefix] {
+            let key = Self::make_key(prefix, name)?;
+            kv_api
+                .upsert_kv(UpsertKVReq::new(
+                    &key,
+                    MatchSeq::GE(0),
+                    Operation::Delete,
+                    None,
+                ))
+                .await?;
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn get_pipe(&self, name: &str, seq: MatchSeq) -> Result<SeqV<PipeInfo>> {
+        let key = Self::make_key(&self.pipe_prefix, name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value = res.ok_or_else(|| Self::unknown_pipe(name))?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(SeqV::new(
+                seq_value.seq,
+                serde_json::from_slice(&seq_value.data)?,
+            )),
+            Err(_) => Err(Self::unknown_pipe(name)),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn get_pipes(&self) -> Result<Vec<PipeInfo>> {
+        let values = self.kv_api.prefix_list_kv(&self.pipe_prefix).await?;
+
+        let mut pipes = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            pipes.push(serde_json::from_slice(&value.data)?);
+        }
+        Ok(pipes)
+    }
+
+    #[async_backtrace::framed]
+    async fn get_pipe_progress(&self, name: &str) -> Result<SeqV<PipeProgress>> {
+        let key = Self::make_key(&self.progress_prefix, name)?;
+        match self.kv_api.get_kv(&key).await? {
+            Some(seq_value) => Ok(SeqV::new(
+                seq_value.seq,
+                serde_json::from_slice(&seq_value.data)?,
+            )),
+            None => Ok(SeqV::new(0, PipeProgress::default())),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn update_pipe_progress(
+        &self,
+        name: &str,
+        progress: &PipeProgress,
+        seq: MatchSeq,
+    ) -> Result<u64> {
+        let key = Self::make_key(&self.progress_prefix, name)?;
+        let value = Operation::Update(serde_json::to_vec(progress)?);
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, value, None))
+            .await?;
+
+        match res.result {
+            Some(SeqV { seq: s, .. }) if res.is_changed() => Ok(s),
+            _ => Err(ErrorCode::PipeProgressConflict(format!(
+                "Progress of pipe {} has been updated by others",
+                name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn acquire_pipe_lease(&self, name: &str, node_id: &str, ttl: Duration) -> Result<bool> {
+        let key = Self::make_key(&self.lease_prefix, name)?;
+        let seq = match self.kv_api.get_kv(&key).await? {
+            None => MatchSeq::Exact(0),
+            Some(seq_value) if seq_value.data == node_id.as_bytes() => {
+                MatchSeq::Exact(seq_value.seq)
+            }
+            Some(_) => return Ok(false),
+        };
+
+        // The lease expires after ttl since it is applied by the meta service, unless renewed.
+        let meta = Some(MetaSpec::new_ttl(ttl));
+        let value = Operation::Update(node_id.as_bytes().to_vec());
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, value, meta))
+            .await?;
+        Ok(res.result.is_some() && res.is_changed())
+    }
+}
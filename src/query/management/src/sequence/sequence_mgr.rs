@@ -0,0 +1,159 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::SequenceInfo;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+use crate::sequence::sequence_api::SequenceApi;
+
+static SEQUENCE_API_KEY_PREFIX: &str = "__fd_sequences";
+
+pub struct SequenceMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    sequence_prefix: String,
+}
+
+impl SequenceMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty (while create sequence)",
+            ));
+        }
+
+        Ok(SequenceMgr {
+            kv_api,
+            sequence_prefix: format!("{}/{}", SEQUENCE_API_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn make_key(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.sequence_prefix,
+            escape_for_key(name)?
+        ))
+    }
+
+    fn unknown_sequence(name: &str) -> ErrorCode {
+        ErrorCode::UnknownSequence(format!("Unknown sequence {}", name))
+    }
+}
+
+#[async_trait::async_trait]
+impl SequenceApi for SequenceMgr {
+    #[async_backtrace::framed]
+    async fn add_sequence(&self, sequence: SequenceInfo) -> Result<u64> {
+        let match_seq = MatchSeq::Exact(0);
+        let key = self.make_key(&sequence.name)?;
+        let value = Operation::Update(serde_json::to_vec(&sequence)?);
+
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = kv_api.upsert_kv(UpsertKVReq::new(&key, match_seq, value, None));
+
+        let res = upsert_kv.await?.added_or_else(|v| {
+            ErrorCode::SequenceAlreadyExists(format!("Sequence already exists, seq [{}]", v.seq))
+        })?;
+
+        Ok(res.seq)
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_sequence(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = self.make_key(name)?;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_none() || res.result.is_some() {
+            return Err(Self::unknown_sequence(name));
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn get_sequence(&self, name: &str, seq: MatchSeq) -> Result<SeqV<SequenceInfo>> {
+        let key = self.make_key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value = res.ok_or_else(|| Self::unknown_sequence(name))?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(SeqV::new(
+                seq_value.seq,
+                serde_json::from_slice(&seq_value.data)?,
+            )),
+            Err(_) => Err(Self::unknown_sequence(name)),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn get_sequences(&self) -> Result<Vec<SequenceInfo>> {
+        let values = self.kv_api.prefix_list_kv(&self.sequence_prefix).await?;
+
+        let mut sequences = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            sequences.push(serde_json::from_slice(&value.data)?);
+        }
+        Ok(sequences)
+    }
+
+    #[async_backtrace::framed]
+    async fn allocate_sequence_values(&self, name: &str, count: u64) -> Result<SequenceInfo> {
+        let key = self.make_key(name)?;
+        // The sequence is updated if it's not updated by others since it's read, a conflict
+        // means others have allocated numbers, so it's retried until it succeeds.
+        loop {
+            let SeqV {
+                seq,
+                data: sequence,
+                ..
+            } = self.get_sequence(name, MatchSeq::GE(0)).await?;
+            let next_value = i64::try_from(count)
+                .ok()
+                .and_then(|count| count.checked_mul(sequence.increment))
+                .and_then(|delta| sequence.next_value.checked_add(delta))
+                .ok_or_else(|| {
+                    ErrorCode::SequenceExhausted(format!(
+                        "Sequence {} can't allocate {} more numbers from {}",
+                        name, count, sequence.next_value
+                    ))
+                })?;
+
+            let mut allocated = sequence.clone();
+            allocated.next_value = next_value;
+            let value = Operation::Update(serde_json::to_vec(&allocated)?);
+            let res = self
+                .kv_api
+                .upsert_kv(UpsertKVReq::new(&key, MatchSeq::Exact(seq), value, None))
+                .await?;
+            if res.result.is_some() && res.is_changed() {
+                return Ok(sequence);
+            }
+            if res.prev.is_none() {
+                return Err(Self::unknown_sequence(name));
+            }
+        }
+    }
+}
@@ -0,0 +1,28 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_exception::Result;
+use common_meta_app::storage::StorageProfile;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+#[async_trait::async_trait]
+pub trait StorageProfileApi: Sync + Send {
+    async fn add_storage_profile(&self, storage_profile: StorageProfile) -> Result<u64>;
+
+    async fn drop_storage_profile(&self, name: &str, seq: MatchSeq) -> Result<()>;
+
+    async fn get_storage_profile(&self, name: &str, seq: MatchSeq) -> Result<SeqV<StorageProfile>>;
+
+    async fn get_storage_profiles(&self) -> Result<Vec<StorageProfile>>;
+}
@@ -0,0 +1,214 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::storage::StorageProfile;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+use ring::aead::Aad;
+use ring::aead::LessSafeKey;
+use ring::aead::Nonce;
+use ring::aead::UnboundKey;
+use ring::aead::AES_256_GCM;
+use ring::aead::NONCE_LEN;
+use ring::rand::SecureRandom;
+use ring::rand::SystemRandom;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::storage_profile::storage_profile_api::StorageProfileApi;
+
+static STORAGE_PROFILE_API_KEY_PREFIX: &str = "__fd_storage_profiles";
+
+/// Storage profiles carry credentials, they are stored in meta encrypted with AES-256-GCM,
+/// the key is derived from the `storage_profile_secret` of the query config.
+///
+/// The stored value is `nonce | ciphertext | tag`.
+pub struct StorageProfileMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    storage_profile_prefix: String,
+    key: LessSafeKey,
+    rng: SystemRandom,
+}
+
+impl StorageProfileMgr {
+    pub fn create(
+        kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+        tenant: &str,
+        secret: &str,
+    ) -> Result<Self, ErrorCode> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty (while create storage profile)",
+            ));
+        }
+
+        if secret.is_empty() {
+            return Err(ErrorCode::IllegalStorageProfile(
+                "Storage profiles are disabled, storage_profile_secret is not set",
+            ));
+        }
+
+        let digest = Sha256::digest(secret.as_bytes());
+        let key = UnboundKey::new(&AES_256_GCM, digest.as_slice())
+            .map_err(|_| ErrorCode::IllegalStorageProfile("Invalid storage profile secret"))?;
+
+        Ok(StorageProfileMgr {
+            kv_api,
+            storage_profile_prefix: format!("{}/{}", STORAGE_PROFILE_API_KEY_PREFIX, tenant),
+            key: LessSafeKey::new(key),
+            rng: SystemRandom::new(),
+        })
+    }
+
+    /// Whether the tenant has any storage profile, they are not decrypted, so the secret is
+    /// not needed.
+    #[async_backtrace::framed]
+    pub async fn exists_any(
+        kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+        tenant: &str,
+    ) -> Result<bool> {
+        let prefix = format!("{}/{}", STORAGE_PROFILE_API_KEY_PREFIX, tenant);
+        Ok(!kv_api.prefix_list_kv(&prefix).await?.is_empty())
+    }
+
+    fn make_storage_profile_key(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.storage_profile_prefix,
+            escape_for_key(name)?
+        ))
+    }
+
+    fn encrypt(&self, storage_profile: &StorageProfile) -> Result<Vec<u8>> {
+        let mut nonce = [0u8; NONCE_LEN];
+        self.rng.fill(&mut nonce).map_err(|_| {
+            ErrorCode::IllegalStorageProfile("Failed to generate nonce for storage profile")
+        })?;
+
+        let mut in_out = serde_json::to_vec(storage_profile)?;
+        self.key
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::empty(),
+                &mut in_out,
+            )
+            .map_err(|_| ErrorCode::IllegalStorageProfile("Failed to encrypt storage profile"))?;
+
+        let mut data = Vec::with_capacity(NONCE_LEN + in_out.len());
+        data.extend_from_slice(&nonce);
+        data.extend_from_slice(&in_out);
+        Ok(data)
+    }
+
+    fn decrypt(&self, data: &[u8]) -> Result<StorageProfile> {
+        if data.len() < NONCE_LEN {
+            return Err(ErrorCode::IllegalStorageProfile(
+                "Storage profile data is corrupted",
+            ));
+        }
+
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce)
+            .map_err(|_| ErrorCode::IllegalStorageProfile("Storage profile data is corrupted"))?;
+        let mut in_out = ciphertext.to_vec();
+        let plaintext = self
+            .key
+            .open_in_place(nonce, Aad::empty(), &mut in_out)
+            .map_err(|_| {
+                ErrorCode::IllegalStorageProfile(
+                    "Failed to decrypt storage profile, storage_profile_secret may have been changed",
+                )
+            })?;
+        Ok(serde_json::from_slice(plaintext)?)
+    }
+}
+
+#[async_trait::async_trait]
+impl StorageProfileApi for StorageProfileMgr {
+    #[async_backtrace::framed]
+    async fn add_storage_profile(&self, storage_profile: StorageProfile) -> Result<u64> {
+        let match_seq = MatchSeq::Exact(0);
+        let key = self.make_storage_profile_key(storage_profile.name.as_str())?;
+        let value = Operation::Update(self.encrypt(&storage_profile)?);
+
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = kv_api.upsert_kv(UpsertKVReq::new(&key, match_seq, value, None));
+
+        let res = upsert_kv.await?.added_or_else(|v| {
+            ErrorCode::StorageProfileAlreadyExists(format!(
+                "StorageProfile already exists, seq [{}]",
+                v.seq
+            ))
+        })?;
+
+        Ok(res.seq)
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_storage_profile(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = self.make_storage_profile_key(name)?;
+        let kv_api = self.kv_api.clone();
+        let res = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownStorageProfile(format!(
+                "Unknown StorageProfile {}",
+                name
+            )))
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn get_storage_profile(&self, name: &str, seq: MatchSeq) -> Result<SeqV<StorageProfile>> {
+        let key = self.make_storage_profile_key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value = res.ok_or_else(|| {
+            ErrorCode::UnknownStorageProfile(format!("Unknown StorageProfile {}", name))
+        })?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(SeqV::new(seq_value.seq, self.decrypt(&seq_value.data)?)),
+            Err(_) => Err(ErrorCode::UnknownStorageProfile(format!(
+                "Unknown StorageProfile {}",
+                name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn get_storage_profiles(&self) -> Result<Vec<StorageProfile>> {
+        let values = self
+            .kv_api
+            .prefix_list_kv(&self.storage_profile_prefix)
+            .await?;
+
+        let mut storage_profiles = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            storage_profiles.push(self.decrypt(&value.data)?);
+        }
+        Ok(storage_profiles)
+    }
+}
@@ -24,6 +24,7 @@ use common_meta_kvapi::kvapi::KVApi;
 use common_meta_store::MetaStore;
 use common_meta_types::MatchSeq;
 use common_meta_types::NodeInfo;
+use common_meta_types::NodeLoad;
 use common_meta_types::SeqV;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -139,6 +140,53 @@ async fn test_successfully_heartbeat_node() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_heartbeat_refresh_node_load() -> Result<()> {
+    let (_, cluster_api) = new_cluster_api().await?;
+
+    let mut node_info = create_test_node_info();
+    cluster_api.add_node(node_info.clone()).await?;
+
+    node_info.load = NodeLoad {
+        running_queries: 2,
+        queued_queries: 1,
+        cpu_saturation: 150,
+    };
+    cluster_api.heartbeat(&node_info, MatchSeq::GE(1)).await?;
+
+    let nodes = cluster_api.get_nodes().await?;
+    assert_eq!(nodes, vec![node_info]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_get_tenant_clusters() -> Result<()> {
+    let (kv_api, cluster_api) = new_cluster_api().await?;
+    let other_cluster_api = ClusterMgr::create(
+        kv_api.clone(),
+        "test-tenant-id",
+        "other-cluster-id",
+        Duration::from_secs(60),
+    )?;
+    let other_tenant_api = ClusterMgr::create(
+        kv_api,
+        "other-tenant-id",
+        "test-cluster-id",
+        Duration::from_secs(60),
+    )?;
+
+    let node_info = create_test_node_info();
+    cluster_api.add_node(node_info.clone()).await?;
+    other_cluster_api.add_node(node_info.clone()).await?;
+    other_tenant_api.add_node(node_info.clone()).await?;
+
+    let clusters = cluster_api.get_tenant_clusters().await?;
+    assert_eq!(clusters.len(), 2);
+    assert_eq!(clusters["test-cluster-id"], vec![node_info.clone()]);
+    assert_eq!(clusters["other-cluster-id"], vec![node_info]);
+    Ok(())
+}
+
 fn current_seconds_time() -> u64 {
     let now = std::time::SystemTime::now();
     now.duration_since(UNIX_EPOCH)
@@ -153,6 +201,7 @@ fn create_test_node_info() -> NodeInfo {
         version: 0,
         flight_address: String::from("ip:port"),
         binary_version: "binary_version".to_string(),
+        load: Default::default(),
     }
 }
 
@@ -15,7 +15,10 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod cluster;
+mod pipe;
+mod sequence;
 mod setting;
 mod stage;
+mod storage_profile;
 mod udf;
 mod user;
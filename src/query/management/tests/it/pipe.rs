@@ -0,0 +1,154 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::*;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::KafkaSource;
+use common_meta_app::principal::NdJsonFileFormatParams;
+use common_meta_app::principal::PipeBatch;
+use common_meta_app::principal::PipeInfo;
+use common_meta_app::principal::PipeProgress;
+use common_meta_app::principal::PipeSource;
+use common_meta_app::principal::UserIdentity;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::MatchSeq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_get_drop_pipe() -> Result<()> {
+    let mgr = new_pipe_api().await?;
+
+    let pipe = create_test_pipe();
+    mgr.add_pipe(pipe.clone()).await?;
+
+    // Add again.
+    {
+        let res = mgr.add_pipe(pipe.clone()).await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::PipeAlreadyExists("").code()
+        );
+    }
+
+    // Get.
+    {
+        let got = mgr.get_pipe("my_pipe", MatchSeq::GE(0)).await?;
+        assert_eq!(got.data, pipe);
+
+        let got = mgr.get_pipes().await?;
+        assert_eq!(got, vec![pipe.clone()]);
+    }
+
+    // Drop.
+    {
+        mgr.update_pipe_progress("my_pipe", &PipeProgress::default(), MatchSeq::Exact(0))
+            .await?;
+        mgr.drop_pipe("my_pipe", MatchSeq::GE(1)).await?;
+        let res = mgr.get_pipe("my_pipe", MatchSeq::GE(0)).await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::UnknownPipe("").code());
+
+        // The progress is dropped along with the pipe.
+        let progress = mgr.get_pipe_progress("my_pipe").await?;
+        assert_eq!(progress.seq, 0);
+
+        let res = mgr.drop_pipe("my_pipe", MatchSeq::GE(1)).await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::UnknownPipe("").code());
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_update_pipe_progress() -> Result<()> {
+    let mgr = new_pipe_api().await?;
+    mgr.add_pipe(create_test_pipe()).await?;
+
+    let progress = mgr.get_pipe_progress("my_pipe").await?;
+    assert_eq!(progress.seq, 0);
+    assert_eq!(progress.data, PipeProgress::default());
+
+    let mut new_progress = progress.data.clone();
+    new_progress.pending_batch = Some(PipeBatch {
+        id: 1,
+        start_offsets: [(0, 0), (1, 5)].into(),
+        end_offsets: [(0, 10), (1, 7)].into(),
+    });
+    assert_eq!(
+        new_progress.pending_batch.as_ref().unwrap().message_count(),
+        12
+    );
+    let seq = mgr
+        .update_pipe_progress("my_pipe", &new_progress, MatchSeq::Exact(progress.seq))
+        .await?;
+
+    let got = mgr.get_pipe_progress("my_pipe").await?;
+    assert_eq!(got.seq, seq);
+    assert_eq!(got.data, new_progress);
+
+    // Update with a stale seq.
+    {
+        let res = mgr
+            .update_pipe_progress("my_pipe", &PipeProgress::default(), MatchSeq::Exact(0))
+            .await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::PipeProgressConflict("").code()
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_acquire_pipe_lease() -> Result<()> {
+    let mgr = new_pipe_api().await?;
+    let ttl = Duration::from_secs(60);
+
+    assert!(mgr.acquire_pipe_lease("my_pipe", "node1", ttl).await?);
+    // Renew by the holder.
+    assert!(mgr.acquire_pipe_lease("my_pipe", "node1", ttl).await?);
+    // Held by another node.
+    assert!(!mgr.acquire_pipe_lease("my_pipe", "node2", ttl).await?);
+
+    Ok(())
+}
+
+fn create_test_pipe() -> PipeInfo {
+    PipeInfo {
+        name: "my_pipe".to_string(),
+        catalog: "default".to_string(),
+        database: "default".to_string(),
+        table: "t".to_string(),
+        source: PipeSource::Kafka(KafkaSource {
+            brokers: vec!["127.0.0.1:9092".to_string()],
+            topic: "my_topic".to_string(),
+        }),
+        file_format_params: FileFormatParams::NdJson(NdJsonFileFormatParams::default()),
+        batch_size: 1000,
+        owner: UserIdentity::new("root", "%"),
+        comment: "".to_string(),
+        created_on: Utc::now(),
+    }
+}
+
+async fn new_pipe_api() -> Result<PipeMgr> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    PipeMgr::create(test_api, "admin")
+}
@@ -0,0 +1,105 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::*;
+use common_meta_app::principal::SequenceInfo;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::MatchSeq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_get_drop_sequence() -> Result<()> {
+    let mgr = new_sequence_api().await?;
+
+    let sequence = SequenceInfo::new("my_seq", 1, 1, "".to_string());
+    mgr.add_sequence(sequence.clone()).await?;
+
+    // Add again.
+    {
+        let res = mgr.add_sequence(sequence.clone()).await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::SequenceAlreadyExists("").code()
+        );
+    }
+
+    // Get.
+    {
+        let got = mgr.get_sequence("my_seq", MatchSeq::GE(0)).await?;
+        assert_eq!(got.data, sequence);
+
+        let got = mgr.get_sequences().await?;
+        assert_eq!(got, vec![sequence.clone()]);
+    }
+
+    // Drop.
+    {
+        mgr.drop_sequence("my_seq", MatchSeq::GE(1)).await?;
+        let res = mgr.get_sequence("my_seq", MatchSeq::GE(0)).await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::UnknownSequence("").code()
+        );
+
+        let res = mgr.drop_sequence("my_seq", MatchSeq::GE(1)).await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::UnknownSequence("").code()
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_allocate_sequence_values() -> Result<()> {
+    let mgr = new_sequence_api().await?;
+    mgr.add_sequence(SequenceInfo::new("my_seq", 10, -2, "".to_string()))
+        .await?;
+
+    let got = mgr.allocate_sequence_values("my_seq", 3).await?;
+    assert_eq!(got.next_value, 10);
+    let got = mgr.allocate_sequence_values("my_seq", 5).await?;
+    assert_eq!(got.next_value, 4);
+    let got = mgr.get_sequence("my_seq", MatchSeq::GE(0)).await?;
+    assert_eq!(got.data.next_value, -6);
+
+    // Overflow.
+    {
+        let res = mgr.allocate_sequence_values("my_seq", u64::MAX).await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::SequenceExhausted("").code()
+        );
+    }
+
+    // Unknown.
+    {
+        let res = mgr.allocate_sequence_values("unknown", 1).await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::UnknownSequence("").code()
+        );
+    }
+
+    Ok(())
+}
+
+async fn new_sequence_api() -> Result<SequenceMgr> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    SequenceMgr::create(test_api, "admin")
+}
@@ -0,0 +1,130 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::*;
+use common_meta_app::storage::StorageParams;
+use common_meta_app::storage::StorageProfile;
+use common_meta_app::storage::StorageS3Config;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_types::MatchSeq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_get_drop_storage_profile() -> Result<()> {
+    let (kv_api, mgr) = new_storage_profile_api("secret").await?;
+
+    let profile = create_test_storage_profile();
+    mgr.add_storage_profile(profile.clone()).await?;
+
+    // The credentials must not be stored in plain text.
+    {
+        let value = kv_api
+            .get_kv("__fd_storage_profiles/admin/my_profile")
+            .await?
+            .unwrap();
+        let data = String::from_utf8_lossy(&value.data);
+        assert!(!data.contains("my_secret_access_key"));
+        assert!(!data.contains("my_bucket"));
+    }
+
+    // Add again.
+    {
+        let res = mgr.add_storage_profile(profile.clone()).await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::StorageProfileAlreadyExists("").code()
+        );
+    }
+
+    // Get.
+    {
+        let got = mgr
+            .get_storage_profile("my_profile", MatchSeq::GE(0))
+            .await?;
+        assert_eq!(got.data, profile);
+
+        let got = mgr.get_storage_profiles().await?;
+        assert_eq!(got, vec![profile.clone()]);
+    }
+
+    // Get with another secret.
+    {
+        let other = StorageProfileMgr::create(kv_api.clone(), "admin", "other")?;
+        let res = other
+            .get_storage_profile("my_profile", MatchSeq::GE(0))
+            .await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::IllegalStorageProfile("").code()
+        );
+    }
+
+    // Without a secret.
+    {
+        let res = StorageProfileMgr::create(kv_api.clone(), "admin", "");
+        assert_eq!(
+            res.err().unwrap().code(),
+            ErrorCode::IllegalStorageProfile("").code()
+        );
+        assert!(StorageProfileMgr::exists_any(kv_api.clone(), "admin").await?);
+        assert!(!StorageProfileMgr::exists_any(kv_api.clone(), "other").await?);
+    }
+
+    // Drop.
+    {
+        mgr.drop_storage_profile("my_profile", MatchSeq::GE(1))
+            .await?;
+        let res = mgr.get_storage_profile("my_profile", MatchSeq::GE(0)).await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::UnknownStorageProfile("").code()
+        );
+
+        let res = mgr
+            .drop_storage_profile("my_profile", MatchSeq::GE(1))
+            .await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::UnknownStorageProfile("").code()
+        );
+    }
+
+    Ok(())
+}
+
+fn create_test_storage_profile() -> StorageProfile {
+    StorageProfile {
+        name: "my_profile".to_string(),
+        storage_params: StorageParams::S3(StorageS3Config {
+            bucket: "my_bucket".to_string(),
+            access_key_id: "my_access_key_id".to_string(),
+            secret_access_key: "my_secret_access_key".to_string(),
+            ..Default::default()
+        }),
+        comment: "".to_string(),
+        create_on: Utc::now(),
+    }
+}
+
+async fn new_storage_profile_api(secret: &str) -> Result<(Arc<MetaEmbedded>, StorageProfileMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = StorageProfileMgr::create(test_api.clone(), "admin", secret)?;
+    Ok((test_api, mgr))
+}
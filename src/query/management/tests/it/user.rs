@@ -23,6 +23,8 @@ use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::PasswordHashMethod;
 use common_meta_app::principal::UserIdentity;
 use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::BatchUpsertKVReply;
+use common_meta_kvapi::kvapi::BatchUpsertKVReq;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::MGetKVReply;
@@ -49,6 +51,11 @@ mock! {
             act: UpsertKVReq,
         ) -> Result<UpsertKVReply, MetaError>;
 
+        async fn batch_upsert_kv(
+            &self,
+            req: BatchUpsertKVReq,
+        ) -> Result<BatchUpsertKVReply, MetaError>;
+
         async fn get_kv(&self, key: &str) -> Result<GetKVReply,MetaError>;
 
         async fn mget_kv(
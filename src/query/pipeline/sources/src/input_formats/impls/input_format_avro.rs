@@ -0,0 +1,265 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use apache_avro::schema::Name;
+use apache_avro::schema::ResolvedSchema;
+use apache_avro::types::Value;
+use apache_avro::Reader;
+use apache_avro::Schema;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_formats::FieldDecoder;
+use common_formats::FieldJsonAstDecoder;
+use common_formats::FileFormatOptionsExt;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::StageFileFormatType;
+use common_pipeline_core::InputError;
+
+use crate::input_formats::impls::input_format_xml::AligningStateWholeFile;
+use crate::input_formats::impls::InputFormatNDJson;
+use crate::input_formats::BlockBuilder;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormatTextBase;
+use crate::input_formats::RowBatch;
+use crate::input_formats::SplitInfo;
+
+/// Reads Avro object container files, each record of the file is a row.
+///
+/// The fields of the records are mapped to the columns by name, the same way as NDJSON.
+pub struct InputFormatAvro {}
+
+impl InputFormatAvro {
+    pub fn create() -> Self {
+        Self {}
+    }
+}
+
+impl InputFormatTextBase for InputFormatAvro {
+    type AligningState = AligningStateWholeFile;
+
+    fn format_type() -> StageFileFormatType {
+        StageFileFormatType::Avro
+    }
+
+    fn create_field_decoder(
+        _params: &FileFormatParams,
+        options: &FileFormatOptionsExt,
+    ) -> Arc<dyn FieldDecoder> {
+        Arc::new(FieldJsonAstDecoder::create(options))
+    }
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::AligningState> {
+        AligningStateWholeFile::try_create(ctx, split_info)
+    }
+
+    fn deserialize(
+        builder: &mut BlockBuilder<Self>,
+        batch: RowBatch,
+    ) -> Result<HashMap<u16, InputError>> {
+        let field_decoder = builder
+            .field_decoder
+            .as_any()
+            .downcast_ref::<FieldJsonAstDecoder>()
+            .expect("must success");
+        let columns = &mut builder.mutable_columns;
+        let path = &batch.split_info.file.path;
+
+        let mut error_map: HashMap<u16, InputError> = HashMap::new();
+        if batch.data.is_empty() {
+            return Ok(error_map);
+        }
+
+        let reader = Reader::new(Cursor::new(&batch.data))
+            .map_err(|e| avro_error(&e.to_string(), path, None))?;
+        let schema = reader.writer_schema().clone();
+        let resolved = ResolvedSchema::try_from(&schema)
+            .map_err(|e| avro_error(&e.to_string(), path, None))?;
+        let converter = AvroJsonConverter {
+            names: resolved.get_names(),
+        };
+
+        for (i, value) in reader.enumerate() {
+            let value = value.map_err(|e| avro_error(&e.to_string(), path, Some(i)))?;
+            let res = converter.convert(value, Some(&schema)).and_then(|json| {
+                InputFormatNDJson::read_value(field_decoder, json, columns, &builder.ctx.schema)
+            });
+            if let Err(e) = res {
+                builder
+                    .ctx
+                    .on_error(e, Some((columns, builder.num_rows)), Some(&mut error_map))
+                    .map_err(|e| avro_error(&e.message(), path, Some(i)))?;
+            } else {
+                builder.num_rows += 1;
+            }
+        }
+        Ok(error_map)
+    }
+}
+
+/// Converts the Avro values into JSON values that `FieldJsonAstDecoder` accepts.
+///
+/// The logical types are converted to the representations of the corresponding
+/// expression types: decimals to strings with the scale of the schema, dates to days
+/// and timestamps to microseconds.
+struct AvroJsonConverter<'a> {
+    names: &'a HashMap<Name, &'a Schema>,
+}
+
+impl<'a> AvroJsonConverter<'a> {
+    fn convert(&self, value: Value, schema: Option<&Schema>) -> Result<serde_json::Value> {
+        let schema = match schema {
+            Some(Schema::Ref { name }) => match self.names.get(name) {
+                Some(schema) => Some(*schema),
+                None => {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "unresolved avro schema reference {}",
+                        name
+                    )));
+                }
+            },
+            schema => schema,
+        };
+
+        let json = match value {
+            Value::Null => serde_json::Value::Null,
+            Value::Boolean(v) => serde_json::Value::Bool(v),
+            Value::Int(v) | Value::Date(v) | Value::TimeMillis(v) => v.into(),
+            Value::Long(v) | Value::TimeMicros(v) => v.into(),
+            Value::Float(v) => float_to_json(v as f64),
+            Value::Double(v) => float_to_json(v),
+            Value::Bytes(v) | Value::Fixed(_, v) => {
+                serde_json::Value::String(String::from_utf8_lossy(&v).into_owned())
+            }
+            Value::String(v) | Value::Enum(_, v) => serde_json::Value::String(v),
+            Value::Uuid(v) => serde_json::Value::String(v.to_string()),
+            Value::TimestampMillis(v) | Value::LocalTimestampMillis(v) => v
+                .checked_mul(1000)
+                .ok_or_else(|| ErrorCode::BadBytes("avro timestamp-millis out of range"))?
+                .into(),
+            Value::TimestampMicros(v) | Value::LocalTimestampMicros(v) => v.into(),
+            Value::Duration(v) => serde_json::json!({
+                "months": u32::from(v.months()),
+                "days": u32::from(v.days()),
+                "millis": u32::from(v.millis()),
+            }),
+            Value::Decimal(v) => {
+                let scale = match schema {
+                    Some(Schema::Decimal(decimal)) => decimal.scale,
+                    _ => 0,
+                };
+                let bytes = Vec::<u8>::try_from(&v)
+                    .map_err(|e| ErrorCode::BadBytes(format!("invalid avro decimal: {}", e)))?;
+                serde_json::Value::String(decimal_to_string(&bytes, scale)?)
+            }
+            Value::Union(i, v) => {
+                let variant = match schema {
+                    Some(Schema::Union(union)) => union.variants().get(i as usize),
+                    _ => None,
+                };
+                self.convert(*v, variant)?
+            }
+            Value::Array(values) => {
+                let items = match schema {
+                    Some(Schema::Array(items)) => Some(items.as_ref()),
+                    _ => None,
+                };
+                serde_json::Value::Array(
+                    values
+                        .into_iter()
+                        .map(|v| self.convert(v, items))
+                        .collect::<Result<_>>()?,
+                )
+            }
+            Value::Map(values) => {
+                let items = match schema {
+                    Some(Schema::Map(items)) => Some(items.as_ref()),
+                    _ => None,
+                };
+                serde_json::Value::Object(
+                    values
+                        .into_iter()
+                        .map(|(k, v)| Ok((k, self.convert(v, items)?)))
+                        .collect::<Result<_>>()?,
+                )
+            }
+            Value::Record(fields) => {
+                let record = match schema {
+                    Some(Schema::Record(record)) => Some(record),
+                    _ => None,
+                };
+                serde_json::Value::Object(
+                    fields
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, (k, v))| {
+                            let field_schema =
+                                record.and_then(|r| r.fields.get(i)).map(|f| &f.schema);
+                            Ok((k, self.convert(v, field_schema)?))
+                        })
+                        .collect::<Result<_>>()?,
+                )
+            }
+        };
+        Ok(json)
+    }
+}
+
+fn float_to_json(v: f64) -> serde_json::Value {
+    serde_json::Number::from_f64(v)
+        .map(serde_json::Value::Number)
+        .unwrap_or(serde_json::Value::Null)
+}
+
+// The unscaled value of avro decimal is a two's-complement big-endian integer.
+fn decimal_to_string(bytes: &[u8], scale: usize) -> Result<String> {
+    if bytes.len() > 16 {
+        return Err(ErrorCode::BadBytes(format!(
+            "avro decimal of {} bytes is too large",
+            bytes.len()
+        )));
+    }
+
+    let negative = bytes.first().map_or(false, |b| b & 0x80 != 0);
+    let mut buf = if negative { [0xff; 16] } else { [0; 16] };
+    buf[16 - bytes.len()..].copy_from_slice(bytes);
+    let n = i128::from_be_bytes(buf);
+
+    let mut digits = n.unsigned_abs().to_string();
+    if scale > 0 {
+        if digits.len() <= scale {
+            digits = format!("{}{}", "0".repeat(scale + 1 - digits.len()), digits);
+        }
+        digits.insert(digits.len() - scale, '.');
+    }
+    if negative {
+        digits.insert(0, '-');
+    }
+    Ok(digits)
+}
+
+fn avro_error(msg: &str, path: &str, record: Option<usize>) -> ErrorCode {
+    let msg = match record {
+        Some(i) => format!("fail to parse Avro {} at record {}: {}", path, i, msg),
+        None => format!("fail to parse Avro {}: {}", path, msg),
+    };
+
+    ErrorCode::BadBytes(msg)
+}
@@ -0,0 +1,116 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use bstr::ByteSlice;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_formats::FieldDecoder;
+use common_formats::FieldJsonAstDecoder;
+use common_formats::FileFormatOptionsExt;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::StageFileFormatType;
+use common_pipeline_core::InputError;
+
+use crate::input_formats::impls::input_format_xml::AligningStateWholeFile;
+use crate::input_formats::impls::InputFormatNDJson;
+use crate::input_formats::BlockBuilder;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormatTextBase;
+use crate::input_formats::RowBatch;
+use crate::input_formats::SplitInfo;
+
+/// Reads a file which contains a top-level JSON array, each element of the array is a row.
+///
+/// A file with a single JSON object is read as one row.
+pub struct InputFormatJson {}
+
+impl InputFormatJson {
+    pub fn create() -> Self {
+        Self {}
+    }
+}
+
+impl InputFormatTextBase for InputFormatJson {
+    type AligningState = AligningStateWholeFile;
+
+    fn format_type() -> StageFileFormatType {
+        StageFileFormatType::Json
+    }
+
+    fn create_field_decoder(
+        _params: &FileFormatParams,
+        options: &FileFormatOptionsExt,
+    ) -> Arc<dyn FieldDecoder> {
+        Arc::new(FieldJsonAstDecoder::create(options))
+    }
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::AligningState> {
+        AligningStateWholeFile::try_create(ctx, split_info)
+    }
+
+    fn deserialize(
+        builder: &mut BlockBuilder<Self>,
+        batch: RowBatch,
+    ) -> Result<HashMap<u16, InputError>> {
+        let field_decoder = builder
+            .field_decoder
+            .as_any()
+            .downcast_ref::<FieldJsonAstDecoder>()
+            .expect("must success");
+        let columns = &mut builder.mutable_columns;
+        let path = &batch.split_info.file.path;
+
+        let mut error_map: HashMap<u16, InputError> = HashMap::new();
+        let buf = batch.data.trim();
+        if buf.is_empty() {
+            return Ok(error_map);
+        }
+
+        let json: serde_json::Value =
+            serde_json::from_slice(buf).map_err(|e| json_error(&e.to_string(), path, None))?;
+        let values = match json {
+            serde_json::Value::Array(values) => values,
+            value => vec![value],
+        };
+
+        for (i, value) in values.into_iter().enumerate() {
+            if let Err(e) =
+                InputFormatNDJson::read_value(field_decoder, value, columns, &builder.ctx.schema)
+            {
+                builder
+                    .ctx
+                    .on_error(e, Some((columns, builder.num_rows)), Some(&mut error_map))
+                    .map_err(|e| json_error(&e.message(), path, Some(i)))?;
+            } else {
+                builder.num_rows += 1;
+            }
+        }
+        Ok(error_map)
+    }
+}
+
+fn json_error(msg: &str, path: &str, element: Option<usize>) -> ErrorCode {
+    let msg = match element {
+        Some(i) => format!("fail to parse JSON {} at element {}: {}", path, i, msg),
+        None => format!("fail to parse JSON {}: {}", path, msg),
+    };
+
+    ErrorCode::BadBytes(msg)
+}
@@ -47,7 +47,17 @@ impl InputFormatNDJson {
         columns: &mut [ColumnBuilder],
         schema: &TableSchemaRef,
     ) -> Result<()> {
-        let mut json: serde_json::Value = serde_json::from_reader(buf)?;
+        let json: serde_json::Value = serde_json::from_reader(buf)?;
+        Self::read_value(field_decoder, json, columns, schema)
+    }
+
+    /// Reads one row from a JSON value, the fields of the object are mapped to the columns by name.
+    pub(crate) fn read_value(
+        field_decoder: &FieldJsonAstDecoder,
+        mut json: serde_json::Value,
+        columns: &mut [ColumnBuilder],
+        schema: &TableSchemaRef,
+    ) -> Result<()> {
         // todo: this is temporary
         if field_decoder.is_select {
             field_decoder
@@ -12,13 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod input_format_avro;
 mod input_format_csv;
+mod input_format_json;
 mod input_format_ndjson;
 mod input_format_parquet;
 mod input_format_tsv;
 mod input_format_xml;
 
+pub use input_format_avro::InputFormatAvro;
 pub use input_format_csv::InputFormatCSV;
+pub use input_format_json::InputFormatJson;
 pub use input_format_ndjson::InputFormatNDJson;
 pub use input_format_parquet::InputFormatParquet;
 pub use input_format_tsv::InputFormatTSV;
@@ -41,7 +41,9 @@ use common_settings::Settings;
 use dashmap::DashMap;
 use opendal::Operator;
 
+use crate::input_formats::impls::InputFormatAvro;
 use crate::input_formats::impls::InputFormatCSV;
+use crate::input_formats::impls::InputFormatJson;
 use crate::input_formats::impls::InputFormatNDJson;
 use crate::input_formats::impls::InputFormatParquet;
 use crate::input_formats::impls::InputFormatTSV;
@@ -126,6 +128,7 @@ pub struct InputContext {
     pub on_error_mode: OnErrorMode,
     pub on_error_count: AtomicU64,
     pub on_error_map: Option<Arc<DashMap<String, HashMap<u16, InputError>>>>,
+    pub rows_loaded_map: Option<Arc<DashMap<String, u64>>>,
     pub projection: Option<Vec<usize>>,
 }
 
@@ -146,12 +149,13 @@ impl InputContext {
             FileFormatParams::Tsv(_) => Ok(Arc::new(InputFormatTSV::create())),
             FileFormatParams::Csv(_) => Ok(Arc::new(InputFormatCSV::create())),
             FileFormatParams::NdJson(_) => Ok(Arc::new(InputFormatNDJson::create())),
+            FileFormatParams::Json(_) => Ok(Arc::new(InputFormatJson::create())),
             FileFormatParams::Parquet(_) => Ok(Arc::new(InputFormatParquet {})),
             FileFormatParams::Xml(_) => Ok(Arc::new(InputFormatXML::create())),
-            format => Err(ErrorCode::Internal(format!(
-                "Unsupported file format: {:?}",
-                format
-            ))),
+            FileFormatParams::Avro(_) => Ok(Arc::new(InputFormatAvro::create())),
+            FileFormatParams::Orc(_) => Err(ErrorCode::Unimplemented(
+                "ORC files are read by the query pipeline, not the input format",
+            )),
         }
     }
 
@@ -165,6 +169,7 @@ impl InputContext {
         scan_progress: Arc<Progress>,
         block_compact_thresholds: BlockThresholds,
         on_error_map: Arc<DashMap<String, HashMap<u16, InputError>>>,
+        rows_loaded_map: Arc<DashMap<String, u64>>,
         is_select: bool,
         projection: Option<Vec<usize>>,
     ) -> Result<Self> {
@@ -194,6 +199,7 @@ impl InputContext {
             on_error_mode,
             on_error_count: AtomicU64::new(0),
             on_error_map: Some(on_error_map),
+            rows_loaded_map: Some(rows_loaded_map),
             projection,
         })
     }
@@ -248,6 +254,7 @@ impl InputContext {
             on_error_mode: OnErrorMode::AbortNum(1),
             on_error_count: AtomicU64::new(0),
             on_error_map: None,
+            rows_loaded_map: None,
             projection: None,
         })
     }
@@ -288,6 +295,7 @@ impl InputContext {
             on_error_mode,
             on_error_count: AtomicU64::new(0),
             on_error_map: None,
+            rows_loaded_map: None,
             projection: None,
         })
     }
@@ -646,6 +646,12 @@ impl<T: InputFormatTextBase> BlockBuilder<T> {
                 .or_insert(error_map);
         }
     }
+
+    fn add_rows_loaded(&self, file_name: String, rows: usize) {
+        if let Some(ref rows_loaded_map) = self.ctx.rows_loaded_map {
+            *rows_loaded_map.entry(file_name).or_insert(0) += rows as u64;
+        }
+    }
 }
 
 impl<T: InputFormatTextBase> BlockBuilderTrait for BlockBuilder<T> {
@@ -654,7 +660,9 @@ impl<T: InputFormatTextBase> BlockBuilderTrait for BlockBuilder<T> {
     fn deserialize(&mut self, batch: Option<RowBatch>) -> Result<Vec<DataBlock>> {
         if let Some(b) = batch {
             let file_name = b.split_info.file.path.clone();
+            let num_rows = self.num_rows;
             let r = T::deserialize(self, b)?;
+            self.add_rows_loaded(file_name.clone(), self.num_rows - num_rows);
             self.merge_map(r, file_name);
             let mem = self.memory_size();
             debug!(
@@ -0,0 +1,91 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_base::base::GlobalInstance;
+use dashmap::DashMap;
+
+/// The aggregated reads of a table column.
+#[derive(Clone, Debug)]
+pub struct ColumnUsage {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub column: String,
+    /// The number of table scans which read the column.
+    pub read_count: u64,
+    pub last_read_on: DateTime<Utc>,
+}
+
+/// Manager of the column usage statistics.
+///
+/// It tracks which columns the table scans actually read, after the projection
+/// is pushed down, so that the unused columns and tables can be found.
+/// This is a singleton in every databend-query process.
+#[derive(Default)]
+pub struct ColumnUsageManager {
+    /// The key is (catalog, database, table, column).
+    usages: DashMap<(String, String, String, String), ColumnUsage>,
+}
+
+impl ColumnUsageManager {
+    pub fn init() {
+        GlobalInstance::set(Arc::new(Self::default()));
+    }
+
+    pub fn instance() -> Arc<Self> {
+        GlobalInstance::get()
+    }
+
+    /// Records a scan of the table which reads the given columns.
+    pub fn record_read<'a>(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        columns: impl IntoIterator<Item = &'a str>,
+    ) {
+        let now = Utc::now();
+        for column in columns {
+            let key = (
+                catalog.to_string(),
+                database.to_string(),
+                table.to_string(),
+                column.to_string(),
+            );
+            self.usages
+                .entry(key)
+                .and_modify(|usage| {
+                    usage.read_count += 1;
+                    usage.last_read_on = now;
+                })
+                .or_insert_with(|| ColumnUsage {
+                    catalog: catalog.to_string(),
+                    database: database.to_string(),
+                    table: table.to_string(),
+                    column: column.to_string(),
+                    read_count: 1,
+                    last_read_on: now,
+                });
+        }
+    }
+
+    /// Lists the usages of all the columns read since the process started.
+    pub fn list_all(&self) -> Vec<ColumnUsage> {
+        self.usages.iter().map(|v| v.value().clone()).collect()
+    }
+}
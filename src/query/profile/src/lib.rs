@@ -12,10 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod column_usage;
 mod mgr;
 mod proc;
 mod prof;
 
+pub use column_usage::ColumnUsage;
+pub use column_usage::ColumnUsageManager;
 pub use mgr::QueryProfileManager;
 pub use proc::*;
 pub use prof::*;
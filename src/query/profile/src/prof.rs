@@ -63,6 +63,7 @@ pub enum OperatorType {
     ProjectSet,
     EvalScalar,
     Lambda,
+    Udf,
     Limit,
     TableScan,
     CteScan,
@@ -87,6 +88,7 @@ impl Display for OperatorType {
             OperatorType::ProjectSet => write!(f, "ProjectSet"),
             OperatorType::EvalScalar => write!(f, "EvalScalar"),
             OperatorType::Lambda => write!(f, "Lambda"),
+            OperatorType::Udf => write!(f, "Udf"),
             OperatorType::Limit => write!(f, "Limit"),
             OperatorType::TableScan => write!(f, "TableScan"),
             OperatorType::Sort => write!(f, "Sort"),
@@ -139,6 +141,7 @@ pub enum OperatorAttribute {
     EvalScalar(EvalScalarAttribute),
     ProjectSet(ProjectSetAttribute),
     Lambda(LambdaAttribute),
+    Udf(UdfAttribute),
     Limit(LimitAttribute),
     TableScan(TableScanAttribute),
     Sort(SortAttribute),
@@ -182,6 +185,11 @@ pub struct LambdaAttribute {
     pub scalars: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct UdfAttribute {
+    pub scalars: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct FilterAttribute {
     pub predicate: String,
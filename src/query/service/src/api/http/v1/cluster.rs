@@ -19,7 +19,10 @@ use common_meta_types::NodeInfo;
 use poem::http::StatusCode;
 use poem::web::IntoResponse;
 use poem::web::Json;
+use serde::Deserialize;
+use serde::Serialize;
 
+use crate::clusters::ClusterDiscovery;
 use crate::clusters::ClusterHelper;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
@@ -50,3 +53,65 @@ async fn list_nodes(sessions: &Arc<SessionManager>) -> Result<Vec<Arc<NodeInfo>>
     let watch_cluster_context = watch_cluster_session.create_query_context().await?;
     Ok(watch_cluster_context.get_cluster().get_nodes())
 }
+
+#[derive(Serialize, Deserialize, Eq, PartialEq, Debug)]
+pub struct ClusterLoad {
+    pub cluster_id: String,
+    pub nodes: u64,
+    pub cpu_nums: u64,
+    pub running_queries: u64,
+    // the running queries waiting for cpus
+    pub queued_queries: u64,
+    // the threads required by the running queries in percentage of the cpus of the cluster
+    pub cpu_saturation: u64,
+}
+
+impl ClusterLoad {
+    fn create(cluster_id: String, nodes: &[NodeInfo]) -> ClusterLoad {
+        let cpu_nums = nodes.iter().map(|node| node.cpu_nums).sum::<u64>();
+        let threads = nodes
+            .iter()
+            .map(|node| node.load.cpu_saturation * node.cpu_nums)
+            .sum::<u64>();
+        ClusterLoad {
+            cluster_id,
+            nodes: nodes.len() as u64,
+            cpu_nums,
+            running_queries: nodes.iter().map(|node| node.load.running_queries).sum(),
+            queued_queries: nodes.iter().map(|node| node.load.queued_queries).sum(),
+            cpu_saturation: threads / cpu_nums.max(1),
+        }
+    }
+}
+
+// GET /v1/cluster/load
+// list the load of all clusters(warehouses) of the tenant, for autoscalers and proxies
+// request: None
+// return: the load of the clusters, the least loaded one first, so the proxies can route the
+// new sessions to the first one
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn cluster_load_handler() -> poem::Result<impl IntoResponse> {
+    let clusters = ClusterDiscovery::instance()
+        .get_tenant_clusters()
+        .await
+        .map_err(|cause| {
+            poem::Error::from_string(
+                format!("Failed to fetch clusters load. cause: {cause}"),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            )
+        })?;
+
+    let mut loads = clusters
+        .into_iter()
+        .map(|(cluster_id, nodes)| ClusterLoad::create(cluster_id, &nodes))
+        .collect::<Vec<_>>();
+    loads.sort_by_key(|load| {
+        (
+            load.queued_queries,
+            load.cpu_saturation,
+            load.running_queries,
+        )
+    });
+    Ok(Json(loads))
+}
@@ -17,6 +17,8 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_catalog::catalog::Catalog;
 use common_catalog::catalog::StorageDescription;
 use common_catalog::database::Database;
@@ -72,6 +74,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateDatabaseCommentReply;
+use common_meta_app::schema::UpdateDatabaseCommentReq;
 use common_meta_app::schema::UpdateIndexReply;
 use common_meta_app::schema::UpdateIndexReq;
 use common_meta_app::schema::UpdateTableMetaReply;
@@ -185,6 +189,30 @@ impl Catalog for DatabaseCatalog {
         Ok(dbs)
     }
 
+    #[async_backtrace::framed]
+    async fn list_databases_with_prefix(
+        &self,
+        tenant: &str,
+        prefix: &str,
+    ) -> Result<Vec<Arc<dyn Database>>> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while list databases)",
+            ));
+        }
+
+        let mut dbs = self
+            .immutable_catalog
+            .list_databases_with_prefix(tenant, prefix)
+            .await?;
+        let mut other = self
+            .mutable_catalog
+            .list_databases_with_prefix(tenant, prefix)
+            .await?;
+        dbs.append(&mut other);
+        Ok(dbs)
+    }
+
     #[async_backtrace::framed]
     async fn create_database(&self, req: CreateDatabaseReq) -> Result<CreateDatabaseReply> {
         if req.name_ident.tenant.is_empty() {
@@ -252,6 +280,29 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.rename_database(req).await
     }
 
+    #[async_backtrace::framed]
+    async fn update_database_comment(
+        &self,
+        req: UpdateDatabaseCommentReq,
+    ) -> Result<UpdateDatabaseCommentReply> {
+        if req.name_ident.tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while update database comment)",
+            ));
+        }
+        info!("Update database comment from req:{:?}", req);
+
+        if self
+            .immutable_catalog
+            .exists_database(&req.name_ident.tenant, &req.name_ident.db_name)
+            .await?
+        {
+            return self.immutable_catalog.update_database_comment(req).await;
+        }
+
+        self.mutable_catalog.update_database_comment(req).await
+    }
+
     fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
         let res = self.immutable_catalog.get_table_by_info(table_info);
         match res {
@@ -329,6 +380,37 @@ impl Catalog for DatabaseCatalog {
         }
     }
 
+    #[async_backtrace::framed]
+    async fn list_tables_with_prefix(
+        &self,
+        tenant: &str,
+        db_name: &str,
+        prefix: &str,
+    ) -> Result<Vec<Arc<dyn Table>>> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while list tables)",
+            ));
+        }
+
+        let r = self
+            .immutable_catalog
+            .list_tables_with_prefix(tenant, db_name, prefix)
+            .await;
+        match r {
+            Ok(x) => Ok(x),
+            Err(e) => {
+                if e.code() == ErrorCode::UNKNOWN_DATABASE {
+                    self.mutable_catalog
+                        .list_tables_with_prefix(tenant, db_name, prefix)
+                        .await
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
     #[async_backtrace::framed]
     async fn list_tables_history(
         &self,
@@ -613,6 +695,14 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.list_table_lock_revs(table_id).await
     }
 
+    #[async_backtrace::framed]
+    async fn list_table_meta_history(
+        &self,
+        table_id: u64,
+    ) -> Result<Vec<(DateTime<Utc>, TableMeta)>> {
+        self.mutable_catalog.list_table_meta_history(table_id).await
+    }
+
     #[async_backtrace::framed]
     async fn create_table_lock_rev(
         &self,
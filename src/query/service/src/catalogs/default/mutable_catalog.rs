@@ -17,6 +17,7 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
 
+use chrono::DateTime;
 use chrono::Utc;
 use common_catalog::catalog::Catalog;
 use common_config::InnerConfig;
@@ -64,6 +65,7 @@ use common_meta_app::schema::ListDroppedTableReq;
 use common_meta_app::schema::ListIndexesByIdReq;
 use common_meta_app::schema::ListIndexesReq;
 use common_meta_app::schema::ListTableLockRevReq;
+use common_meta_app::schema::ListTableMetaHistoryReq;
 use common_meta_app::schema::ListVirtualColumnsReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
@@ -80,6 +82,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateDatabaseCommentReply;
+use common_meta_app::schema::UpdateDatabaseCommentReq;
 use common_meta_app::schema::UpdateIndexReply;
 use common_meta_app::schema::UpdateIndexReq;
 use common_meta_app::schema::UpdateTableMetaReply;
@@ -97,6 +101,7 @@ use super::catalog_context::CatalogContext;
 use crate::databases::Database;
 use crate::databases::DatabaseContext;
 use crate::databases::DatabaseFactory;
+use crate::databases::resolve_storage_profile;
 use crate::storages::StorageDescription;
 use crate::storages::StorageFactory;
 use crate::storages::Table;
@@ -215,6 +220,30 @@ impl Catalog for MutableCatalog {
             .list_databases(ListDatabaseReq {
                 tenant: tenant.to_string(),
                 filter: None,
+                db_name_prefix: None,
+            })
+            .await?;
+
+        dbs.iter().try_fold(vec![], |mut acc, item| {
+            let db = self.build_db_instance(item)?;
+            acc.push(db);
+            Ok(acc)
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn list_databases_with_prefix(
+        &self,
+        tenant: &str,
+        prefix: &str,
+    ) -> Result<Vec<Arc<dyn Database>>> {
+        let dbs = self
+            .ctx
+            .meta
+            .list_databases(ListDatabaseReq {
+                tenant: tenant.to_string(),
+                filter: None,
+                db_name_prefix: Some(prefix.to_string()),
             })
             .await?;
 
@@ -337,6 +366,15 @@ impl Catalog for MutableCatalog {
         Ok(res)
     }
 
+    #[async_backtrace::framed]
+    async fn update_database_comment(
+        &self,
+        req: UpdateDatabaseCommentReq,
+    ) -> Result<UpdateDatabaseCommentReply> {
+        let res = self.ctx.meta.update_database_comment(req).await?;
+        Ok(res)
+    }
+
     fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
         let storage = self.ctx.storage_factory.clone();
         storage.get_table(table_info)
@@ -368,6 +406,17 @@ impl Catalog for MutableCatalog {
         db.list_tables().await
     }
 
+    #[async_backtrace::framed]
+    async fn list_tables_with_prefix(
+        &self,
+        tenant: &str,
+        db_name: &str,
+        prefix: &str,
+    ) -> Result<Vec<Arc<dyn Table>>> {
+        let db = self.get_database(tenant, db_name).await?;
+        db.list_tables_with_prefix(prefix).await
+    }
+
     #[async_backtrace::framed]
     async fn list_tables_history(
         &self,
@@ -396,6 +445,7 @@ impl Catalog for MutableCatalog {
 
         let mut tables = vec![];
         for table_info in drop_table_infos {
+            let table_info = resolve_storage_profile(&self.tenant, table_info).await?;
             tables.push(storage.get_table(table_info.as_ref())?);
         }
         Ok((tables, drop_ids))
@@ -520,6 +570,16 @@ impl Catalog for MutableCatalog {
         Ok(res)
     }
 
+    #[async_backtrace::framed]
+    async fn list_table_meta_history(
+        &self,
+        table_id: u64,
+    ) -> Result<Vec<(DateTime<Utc>, TableMeta)>> {
+        let req = ListTableMetaHistoryReq { table_id };
+        let res = self.ctx.meta.list_table_meta_history(req).await?;
+        Ok(res)
+    }
+
     #[async_backtrace::framed]
     async fn create_table_lock_rev(
         &self,
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::net::SocketAddr;
 use std::ops::RangeInclusive;
 use std::str::FromStr;
@@ -56,6 +57,7 @@ use rand::thread_rng;
 use rand::Rng;
 
 use crate::api::FlightClient;
+use crate::sessions::SessionManager;
 
 pub struct ClusterDiscovery {
     local_id: String,
@@ -187,6 +189,19 @@ impl ClusterDiscovery {
         GlobalInstance::get()
     }
 
+    pub fn local_id(&self) -> String {
+        self.local_id.clone()
+    }
+
+    /// Get the nodes of all the clusters of the tenant, with the load reported by their heartbeats.
+    #[async_backtrace::framed]
+    pub async fn get_tenant_clusters(&self) -> Result<BTreeMap<String, Vec<NodeInfo>>> {
+        self.api_provider
+            .get_tenant_clusters()
+            .await
+            .map_err(|cause| cause.add_message_back("(while cluster api get_tenant_clusters)."))
+    }
+
     fn create_provider(
         cfg: &InnerConfig,
         metastore: MetaStore,
@@ -440,7 +455,7 @@ impl ClusterHeartbeat {
         }
     }
 
-    fn heartbeat_loop(&self, node: NodeInfo) -> impl Future<Output = ()> + 'static {
+    fn heartbeat_loop(&self, mut node: NodeInfo) -> impl Future<Output = ()> + 'static {
         let shutdown = self.shutdown.clone();
         let shutdown_notify = self.shutdown_notify.clone();
         let cluster_api = self.cluster_api.clone();
@@ -465,6 +480,8 @@ impl ClusterHeartbeat {
                     }
                     Either::Right((_, new_shutdown_notified)) => {
                         shutdown_notified = new_shutdown_notified;
+                        node.load =
+                            SessionManager::instance().get_current_node_load(node.cpu_nums);
                         let heartbeat = cluster_api.heartbeat(&node, MatchSeq::GE(1));
                         if let Err(failure) = heartbeat.await {
                             label_counter_with_val_and_labels(
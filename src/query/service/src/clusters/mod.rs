@@ -14,7 +14,10 @@
 
 mod cluster;
 mod metrics;
+mod standby;
 
 pub use cluster::Cluster;
 pub use cluster::ClusterDiscovery;
 pub use cluster::ClusterHelper;
+pub use standby::StandbyTable;
+pub use standby::StandbyTables;
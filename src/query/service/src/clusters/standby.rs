@@ -0,0 +1,224 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_base::base::tokio::time::sleep;
+use common_catalog::catalog::CatalogManager;
+use common_catalog::table::Table;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableId;
+use common_meta_kvapi::kvapi::Key;
+use common_meta_types::protobuf::watch_request::FilterType;
+use common_meta_types::protobuf::WatchRequest;
+use common_storages_fuse::io::SegmentsIO;
+use common_storages_fuse::FuseTable;
+use common_users::UserApiProvider;
+use futures::future::try_join_all;
+use futures::Stream;
+use futures::StreamExt;
+use log::info;
+use log::warn;
+
+/// Follows the snapshot pointer of tables written by another cluster.
+///
+/// Every table listed in `query.standby_tables` is watched on the meta service,
+/// once its snapshot changes, the new snapshot and segments are loaded into the
+/// table meta caches, so that queries on this cluster don't pay for them.
+/// A table is followed until it's dropped.
+pub struct StandbyTables;
+
+impl StandbyTables {
+    const RETRY_INTERVAL: Duration = Duration::from_secs(5);
+
+    pub fn start(conf: &InnerConfig) -> Result<()> {
+        if conf.query.standby_tables.is_empty() {
+            return Ok(());
+        }
+
+        if conf.meta.is_embedded_meta()? {
+            return Err(ErrorCode::InvalidConfig(
+                "standby_tables requires a remote meta service",
+            ));
+        }
+
+        let tenant = conf.query.tenant_id.clone();
+        for name in &conf.query.standby_tables {
+            let (database, table) = match name.split_once('.') {
+                Some((database, table)) if !database.is_empty() && !table.is_empty() => {
+                    (database.to_string(), table.to_string())
+                }
+                _ => {
+                    return Err(ErrorCode::InvalidConfig(format!(
+                        "Invalid standby table '{}', expect 'database.table'",
+                        name
+                    )));
+                }
+            };
+
+            let tenant = tenant.clone();
+            tokio::spawn(async_backtrace::location!().frame(async move {
+                loop {
+                    match Self::follow(&tenant, &database, &table).await {
+                        Err(cause) if Self::is_dropped(&cause) => {
+                            warn!(
+                                "Stop following standby table {}.{}: {:?}",
+                                database, table, cause
+                            );
+                            return;
+                        }
+                        Err(cause) => {
+                            warn!(
+                                "Follow standby table {}.{} failure: {:?}",
+                                database, table, cause
+                            );
+                        }
+                        Ok(_) => {}
+                    }
+                    sleep(Self::RETRY_INTERVAL).await;
+                }
+            }));
+        }
+        Ok(())
+    }
+
+    /// Returns true if following a table failed because the table has been dropped, it's not
+    /// retried then.
+    pub fn is_dropped(cause: &ErrorCode) -> bool {
+        cause.code() == ErrorCode::UNKNOWN_TABLE || cause.code() == ErrorCode::UNKNOWN_DATABASE
+    }
+
+    /// Watch the table meta until the table is dropped or replaced.
+    #[async_backtrace::framed]
+    async fn follow(tenant: &str, database: &str, table_name: &str) -> Result<()> {
+        let mut standby = StandbyTable::create(tenant, database, table_name).await?;
+        info!(
+            "Start following standby table {}.{}, id {}",
+            database,
+            table_name,
+            standby.table_id()
+        );
+
+        let req = WatchRequest {
+            key: TableId {
+                table_id: standby.table_id(),
+            }
+            .to_string_key(),
+            key_end: None,
+            filter_type: FilterType::All.into(),
+        };
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let changes = meta_api.watch(req).await?.filter_map(|resp| async move {
+            match resp {
+                Ok(resp) => resp.event.map(|_| Ok(())),
+                Err(cause) => Some(Err(cause.into())),
+            }
+        });
+        standby.follow(changes).await
+    }
+}
+
+/// A table followed by this cluster, with the location of its snapshot loaded into the caches.
+pub struct StandbyTable {
+    tenant: String,
+    database: String,
+    table_name: String,
+    table_id: u64,
+    snapshot_location: Option<String>,
+}
+
+impl StandbyTable {
+    /// Loads the current snapshot of the table into the caches.
+    #[async_backtrace::framed]
+    pub async fn create(tenant: &str, database: &str, table_name: &str) -> Result<Self> {
+        let catalog = CatalogManager::instance().get_default_catalog()?;
+        let table = catalog.get_table(tenant, database, table_name).await?;
+        let table_id = table.get_id();
+        let snapshot_location = Self::warm_up(table).await?;
+        Ok(StandbyTable {
+            tenant: tenant.to_string(),
+            database: database.to_string(),
+            table_name: table_name.to_string(),
+            table_id,
+            snapshot_location,
+        })
+    }
+
+    pub fn table_id(&self) -> u64 {
+        self.table_id
+    }
+
+    pub fn snapshot_location(&self) -> Option<&str> {
+        self.snapshot_location.as_deref()
+    }
+
+    /// Catches up on every change of the table meta, until the table is replaced by another
+    /// table of the same name. Fails with `UnknownTable` once the table is dropped.
+    #[async_backtrace::framed]
+    pub async fn follow(&mut self, changes: impl Stream<Item = Result<()>>) -> Result<()> {
+        let mut changes = Box::pin(changes);
+        while let Some(change) = changes.next().await {
+            change?;
+            if !self.catch_up().await? {
+                return Ok(());
+            }
+        }
+        Ok(())
+    }
+
+    /// Loads the latest snapshot of the table into the caches if it's changed, returns false
+    /// if the table has been replaced by another table of the same name.
+    #[async_backtrace::framed]
+    pub async fn catch_up(&mut self) -> Result<bool> {
+        let catalog = CatalogManager::instance().get_default_catalog()?;
+        let table = catalog
+            .get_table(&self.tenant, &self.database, &self.table_name)
+            .await?;
+        if table.get_id() != self.table_id {
+            return Ok(false);
+        }
+
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        if fuse_table.snapshot_loc().await? != self.snapshot_location {
+            self.snapshot_location = Self::warm_up(table).await?;
+        }
+        Ok(true)
+    }
+
+    /// Load the current snapshot of the table and its segments into caches.
+    #[async_backtrace::framed]
+    async fn warm_up(table: Arc<dyn Table>) -> Result<Option<String>> {
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let location = fuse_table.snapshot_loc().await?;
+        if let Some(snapshot) = fuse_table.read_table_snapshot().await? {
+            let operator = fuse_table.get_operator();
+            let schema = fuse_table.schema();
+            // Segments already cached are not read again.
+            try_join_all(snapshot.segments.iter().map(|location| {
+                SegmentsIO::read_compact_segment(
+                    operator.clone(),
+                    location.clone(),
+                    schema.clone(),
+                    true,
+                )
+            }))
+            .await?;
+        }
+        Ok(location)
+    }
+}
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_catalog::table::Table;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_api::SchemaApi;
 use common_meta_app::schema::CreateTableReply;
@@ -39,6 +40,9 @@ use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableOptionReply;
 use common_meta_app::schema::UpsertTableOptionReq;
+use common_users::UserApiProvider;
+use log::warn;
+use storages_common_table_meta::table::OPT_KEY_STORAGE_PROFILE;
 
 use crate::databases::Database;
 use crate::databases::DatabaseContext;
@@ -57,12 +61,35 @@ impl DefaultDatabase {
         Ok(Box::new(Self { ctx, db_info }))
     }
 
-    fn load_tables(&self, table_infos: Vec<Arc<TableInfo>>) -> Result<Vec<Arc<dyn Table>>> {
-        table_infos.iter().try_fold(vec![], |mut acc, item| {
-            let tbl = self.get_table_by_info(item.as_ref())?;
-            acc.push(tbl);
-            Ok(acc)
-        })
+    async fn load_tables(&self, table_infos: Vec<Arc<TableInfo>>) -> Result<Vec<Arc<dyn Table>>> {
+        let mut tables = Vec::with_capacity(table_infos.len());
+        for table_info in table_infos {
+            let table_info = resolve_storage_profile(self.get_tenant(), table_info).await?;
+            tables.push(self.get_table_by_info(table_info.as_ref())?);
+        }
+        Ok(tables)
+    }
+}
+
+/// Fill in the storage params of a table which is bound to a storage profile.
+///
+/// The params of such tables are not persisted in the table meta, they are
+/// looked up from the (encrypted) storage profile every time the table is loaded.
+#[async_backtrace::framed]
+pub(crate) async fn resolve_storage_profile(
+    tenant: &str,
+    table_info: Arc<TableInfo>,
+) -> Result<Arc<TableInfo>> {
+    match table_info.meta.options.get(OPT_KEY_STORAGE_PROFILE) {
+        Some(name) if table_info.meta.storage_params.is_none() => {
+            let profile = UserApiProvider::instance()
+                .get_storage_profile(tenant, name)
+                .await?;
+            let mut table_info = table_info.as_ref().clone();
+            table_info.meta.storage_params = Some(profile.storage_params);
+            Ok(Arc::new(table_info))
+        }
+        _ => Ok(table_info),
     }
 }
 
@@ -93,6 +120,7 @@ impl Database for DefaultDatabase {
                 table_name,
             ))
             .await?;
+        let table_info = resolve_storage_profile(self.get_tenant(), table_info).await?;
         self.get_table_by_info(table_info.as_ref())
     }
 
@@ -104,7 +132,16 @@ impl Database for DefaultDatabase {
             .list_tables(ListTableReq::new(self.get_tenant(), self.get_db_name()))
             .await?;
 
-        self.load_tables(table_infos)
+        self.load_tables(table_infos).await
+    }
+
+    #[async_backtrace::framed]
+    async fn list_tables_with_prefix(&self, prefix: &str) -> Result<Vec<Arc<dyn Table>>> {
+        let req =
+            ListTableReq::new(self.get_tenant(), self.get_db_name()).with_table_name_prefix(prefix);
+        let table_infos = self.ctx.meta.list_tables(req).await?;
+
+        self.load_tables(table_infos).await
     }
 
     #[async_backtrace::framed]
@@ -112,7 +149,7 @@ impl Database for DefaultDatabase {
         // `get_table_history` will not fetch the tables that created before the
         // "metasrv time travel functions" is added.
         // thus, only the table-infos of dropped tables are used.
-        let mut dropped = self
+        let dropped = self
             .ctx
             .meta
             .get_table_history(ListTableReq::new(self.get_tenant(), self.get_db_name()))
@@ -121,15 +158,28 @@ impl Database for DefaultDatabase {
             .filter(|i| i.meta.drop_on.is_some())
             .collect::<Vec<_>>();
 
-        let mut table_infos = self
+        let table_infos = self
             .ctx
             .meta
             .list_tables(ListTableReq::new(self.get_tenant(), self.get_db_name()))
             .await?;
 
-        table_infos.append(&mut dropped);
-
-        self.load_tables(table_infos)
+        let mut tables = self.load_tables(table_infos).await?;
+        for table_info in dropped {
+            // The storage profile of a dropped table may be dropped as well, the data of
+            // the table can't be reached any more.
+            match resolve_storage_profile(self.get_tenant(), table_info.clone()).await {
+                Ok(table_info) => tables.push(self.get_table_by_info(table_info.as_ref())?),
+                Err(e) if e.code() == ErrorCode::UNKNOWN_STORAGE_PROFILE => {
+                    warn!(
+                        "skip the dropped table {}, its storage profile is dropped: {}",
+                        table_info.desc, e
+                    );
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(tables)
     }
 
     #[async_backtrace::framed]
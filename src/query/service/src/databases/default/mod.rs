@@ -14,4 +14,5 @@
 
 mod default_database;
 
+pub(crate) use default_database::resolve_storage_profile;
 pub use default_database::DefaultDatabase;
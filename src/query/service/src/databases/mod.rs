@@ -22,6 +22,7 @@ mod system;
 
 pub use database::Database;
 pub use database_context::DatabaseContext;
+pub(crate) use default::resolve_storage_profile;
 pub use database_factory::DatabaseFactory;
 pub use information_schema::InformationSchemaDatabase;
 pub use system::SystemDatabase;
@@ -28,6 +28,7 @@ use common_storages_system::CachesTable;
 use common_storages_system::CatalogsTable;
 use common_storages_system::ClusteringHistoryTable;
 use common_storages_system::ClustersTable;
+use common_storages_system::ColumnUsageTable;
 use common_storages_system::ColumnsTable;
 use common_storages_system::ConfigsTable;
 use common_storages_system::ContributorsTable;
@@ -38,8 +39,10 @@ use common_storages_system::FunctionsTable;
 use common_storages_system::IndexesTable;
 use common_storages_system::MallocStatsTable;
 use common_storages_system::MallocStatsTotalsTable;
+use common_storages_system::MetasrvStatusTable;
 use common_storages_system::MetricsTable;
 use common_storages_system::OneTable;
+use common_storages_system::PartitionsTable;
 use common_storages_system::ProcessesTable;
 use common_storages_system::QueryCacheTable;
 use common_storages_system::QueryLogTable;
@@ -48,6 +51,7 @@ use common_storages_system::RolesTable;
 use common_storages_system::SettingsTable;
 use common_storages_system::StagesTable;
 use common_storages_system::TableFunctionsTable;
+use common_storages_system::TablesHistoryTable;
 use common_storages_system::TablesTableWithHistory;
 use common_storages_system::TablesTableWithoutHistory;
 use common_storages_system::TracingTable;
@@ -82,6 +86,7 @@ impl SystemDatabase {
             SettingsTable::create(sys_db_meta.next_table_id()),
             TablesTableWithoutHistory::create(sys_db_meta.next_table_id()),
             TablesTableWithHistory::create(sys_db_meta.next_table_id()),
+            TablesHistoryTable::create(sys_db_meta.next_table_id()),
             ClustersTable::create(sys_db_meta.next_table_id()),
             DatabasesTable::create(sys_db_meta.next_table_id()),
             Arc::new(TracingTable::create(sys_db_meta.next_table_id())),
@@ -91,6 +96,7 @@ impl SystemDatabase {
             MallocStatsTable::create(sys_db_meta.next_table_id()),
             MallocStatsTotalsTable::create(sys_db_meta.next_table_id()),
             ColumnsTable::create(sys_db_meta.next_table_id()),
+            ColumnUsageTable::create(sys_db_meta.next_table_id()),
             UsersTable::create(sys_db_meta.next_table_id()),
             Arc::new(QueryLogTable::create(
                 sys_db_meta.next_table_id(),
@@ -113,6 +119,8 @@ impl SystemDatabase {
             BackgroundTaskTable::create(sys_db_meta.next_table_id()),
             BackgroundJobTable::create(sys_db_meta.next_table_id()),
             BacktraceTable::create(sys_db_meta.next_table_id()),
+            PartitionsTable::create(sys_db_meta.next_table_id()),
+            MetasrvStatusTable::create(sys_db_meta.next_table_id()),
         ];
 
         let disable_tables = Self::disable_system_tables();
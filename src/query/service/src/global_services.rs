@@ -23,10 +23,12 @@ use common_config::GlobalConfig;
 use common_config::InnerConfig;
 use common_exception::Result;
 use common_meta_app::schema::CatalogType;
+use common_profile::ColumnUsageManager;
 use common_profile::QueryProfileManager;
 use common_sharing::ShareEndpointManager;
 use common_storage::DataOperator;
 use common_storage::ShareTableConfig;
+use common_storages_fuse::io::KeyManager;
 use common_storages_hive::HiveCreator;
 use common_storages_iceberg::IcebergCreator;
 use common_tracing::GlobalLogger;
@@ -38,7 +40,9 @@ use crate::api::DataExchangeManager;
 use crate::auth::AuthMgr;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::ClusterDiscovery;
+use crate::pipelines::processors::HashJoinBuildCache;
 use crate::servers::http::v1::HttpQueryManager;
+use crate::sessions::QueryHistory;
 use crate::sessions::SessionManager;
 
 pub struct GlobalServices;
@@ -73,6 +77,13 @@ impl GlobalServices {
         )?;
 
         CacheManager::init(&config.cache, &config.query.tenant_id)?;
+        KeyManager::init(
+            &config.query.data_encryption_kms_endpoint,
+            &config.query.data_encryption_kms_key_name,
+            &config.query.data_encryption_kms_token,
+            &config.query.data_encryption_kms_local_key,
+        )?;
+        HashJoinBuildCache::init(&config.cache)?;
 
         // TODO(xuanwo):
         //
@@ -100,6 +111,7 @@ impl GlobalServices {
         UserApiProvider::init(
             config.meta.to_meta_grpc_client_conf(),
             config.query.idm,
+            config.query.storage_profile_secret.as_str(),
             config.query.tenant_id.as_str(),
             config.query.tenant_quota,
         )
@@ -107,6 +119,8 @@ impl GlobalServices {
         RoleCacheManager::init()?;
         ShareEndpointManager::init()?;
         QueryProfileManager::init();
+        ColumnUsageManager::init();
+        QueryHistory::init(&config)?;
 
         Ok(())
     }
@@ -94,6 +94,15 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::CreateNetworkPolicy(_)
                 | Plan::AlterNetworkPolicy(_)
                 | Plan::DropNetworkPolicy(_)
+                // Storage profile.
+                | Plan::CreateStorageProfile(_)
+                | Plan::DropStorageProfile(_)
+                // Pipe.
+                | Plan::CreatePipe(_)
+                | Plan::DropPipe(_)
+                // Sequence.
+                | Plan::CreateSequence(_)
+                | Plan::DropSequence(_)
 
                 // UDF
                 | Plan::CreateUDF(_)
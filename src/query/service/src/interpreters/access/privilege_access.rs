@@ -14,18 +14,24 @@
 
 use std::sync::Arc;
 
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::plan::DataSourceInfo;
+use common_catalog::table_args::TableArgs;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::UserGrantSet;
 use common_meta_app::principal::UserPrivilegeType;
+use common_sql::plans::CommentTarget;
 use common_sql::plans::CopyPlan;
 use common_sql::plans::RewriteKind;
+use common_storages_fuse::table_functions::string_value;
 use common_users::RoleCacheManager;
 
 use crate::interpreters::access::AccessChecker;
 use crate::sessions::QueryContext;
+use crate::sessions::QueryHistory;
 use crate::sql::plans::Plan;
 
 pub struct PrivilegeAccess {
@@ -47,6 +53,8 @@ impl AccessChecker for PrivilegeAccess {
         let (identity, grant_set) = (user.identity().to_string(), user.grants);
         let tenant = self.ctx.get_tenant();
 
+        check_query_history_database(plan)?;
+
         match plan {
             Plan::Query {
                 metadata,
@@ -89,16 +97,28 @@ impl AccessChecker for PrivilegeAccess {
                     if table.is_source_of_view() {
                         continue;
                     }
-                    session
-                        .validate_privilege(
-                            &GrantObject::Table(
+                    let target = match table.table().table_args() {
+                        Some(table_args) => table_function_target(table.name(), &table_args)?,
+                        None if matches!(
+                            table.table().get_data_source_info(),
+                            DataSourceInfo::StageSource(_)
+                        ) =>
+                        {
+                            Some(stage_read_target())
+                        }
+                        None => None,
+                    };
+                    let (object, privilege) = target.unwrap_or_else(|| {
+                        (
+                            GrantObject::Table(
                                 table.catalog().to_string(),
                                 table.database().to_string(),
                                 table.name().to_string(),
                             ),
-                            vec![UserPrivilegeType::Select],
+                            UserPrivilegeType::Select,
                         )
-                        .await?
+                    });
+                    session.validate_privilege(&object, vec![privilege]).await?
                 }
             }
             Plan::ExplainAnalyze { plan } | Plan::Explain { plan, .. } => self.check(plan).await?,
@@ -112,6 +132,24 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?
             }
+            // The module of a WASM function is read from a stage, creating one requires the
+            // privilege of stage reads even if it is temporary.
+            Plan::CreateUDF(plan) if plan.udf.wasm.is_some() => {
+                let (object, privilege) = stage_read_target();
+                session.validate_privilege(&object, vec![privilege]).await?;
+                if !plan.temporary {
+                    session
+                        .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Create])
+                        .await?;
+                }
+            }
+            // Temporary objects only live in the current session.
+            // A server function makes the query nodes connect to the server, creating one
+            // requires the privilege even if it is temporary.
+            Plan::CreateUDF(plan) if plan.temporary && plan.udf.server.is_none() => {}
+            Plan::DropUDF(plan) if plan.temporary => {}
+            Plan::CreateView(plan) if plan.temporary => {}
+            Plan::DropView(plan) if plan.temporary => {}
             Plan::CreateUDF(_) | Plan::CreateDatabase(_) | Plan::CreateIndex(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Create])
@@ -141,6 +179,32 @@ impl AccessChecker for PrivilegeAccess {
                 };
             }
 
+            // Inverted Index.
+            Plan::CreateInvertedIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Create],
+                    )
+                    .await?;
+            }
+            Plan::DropInvertedIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Drop],
+                    )
+                    .await?;
+            }
+
             // Virtual Column.
             Plan::CreateVirtualColumn(plan) => {
                 session
@@ -276,6 +340,35 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::SetTableTags(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::Comment(plan) => {
+                let object = match &plan.target {
+                    CommentTarget::Database => {
+                        GrantObject::Database(plan.catalog.clone(), plan.database.clone())
+                    }
+                    CommentTarget::Table { table } | CommentTarget::Column { table, .. } => {
+                        GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            table.clone(),
+                        )
+                    }
+                };
+                session
+                    .validate_privilege(&object, vec![UserPrivilegeType::Alter])
+                    .await?;
+            }
             Plan::AddTableColumn(plan) => {
                 session
                     .validate_privilege(
@@ -348,6 +441,18 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::DropTablePartition(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Drop],
+                    )
+                    .await?;
+            }
             Plan::ReclusterTable(plan) => {
                 session
                     .validate_privilege(
@@ -489,6 +594,72 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::CreateMaterializedView(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Create],
+                    )
+                    .await?;
+                for (table_database, table_name) in &plan.source_tables {
+                    session
+                        .validate_privilege(
+                            &GrantObject::Table(
+                                plan.catalog.clone(),
+                                table_database.clone(),
+                                table_name.clone(),
+                            ),
+                            vec![UserPrivilegeType::Select],
+                        )
+                        .await?;
+                }
+            }
+            Plan::RefreshMaterializedView(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.view_name.clone(),
+                        ),
+                        vec![UserPrivilegeType::Insert],
+                    )
+                    .await?;
+            }
+            Plan::DropMaterializedView(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Drop],
+                    )
+                    .await?;
+            }
+            Plan::CreateStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Create],
+                    )
+                    .await?;
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.table_database.clone(),
+                            plan.table_name.clone(),
+                        ),
+                        vec![UserPrivilegeType::Select],
+                    )
+                    .await?;
+            }
+            Plan::DropStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Drop],
+                    )
+                    .await?;
+            }
             Plan::CreateUser(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::CreateUser])
@@ -577,7 +748,18 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::AlterNetworkPolicy(_)
             | Plan::DropNetworkPolicy(_)
             | Plan::DescNetworkPolicy(_)
-            | Plan::ShowNetworkPolicies(_) => {
+            | Plan::ShowNetworkPolicies(_)
+            | Plan::CreateStorageProfile(_)
+            | Plan::DropStorageProfile(_)
+            | Plan::DescStorageProfile(_)
+            | Plan::ShowStorageProfiles(_)
+            | Plan::CreatePipe(_)
+            | Plan::DropPipe(_)
+            | Plan::DescPipe(_)
+            | Plan::ShowPipes(_)
+            | Plan::CreateSequence(_)
+            | Plan::DropSequence(_)
+            | Plan::ShowSequences(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Super])
                     .await?;
@@ -634,3 +816,71 @@ async fn has_priv(
             }
         }))
 }
+
+// The database of the query history can't be created, dropped or renamed by the users.
+fn check_query_history_database(plan: &Plan) -> Result<()> {
+    let databases = match plan {
+        Plan::CreateDatabase(plan) => vec![(&plan.catalog, &plan.database)],
+        Plan::DropDatabase(plan) => vec![(&plan.catalog, &plan.database)],
+        Plan::UndropDatabase(plan) => vec![(&plan.catalog, &plan.database)],
+        Plan::RenameDatabase(plan) => plan
+            .entities
+            .iter()
+            .flat_map(|e| [(&e.catalog, &e.database), (&e.catalog, &e.new_database)])
+            .collect(),
+        _ => return Ok(()),
+    };
+    for (catalog, database) in databases {
+        QueryHistory::check_privilege(
+            &GrantObject::Database(catalog.clone(), database.clone()),
+            &[UserPrivilegeType::Drop],
+        )?;
+    }
+    Ok(())
+}
+
+// The files of a stage are read by queries through a stage table, bound as an unnamed table
+// of `default.system`, the privilege of stage reads is checked on it.
+fn stage_read_target() -> (GrantObject, UserPrivilegeType) {
+    let object = GrantObject::Table(
+        CATALOG_DEFAULT.to_string(),
+        "system".to_string(),
+        String::new(),
+    );
+    (object, UserPrivilegeType::Select)
+}
+
+// The fuse table functions take `<database>, <table>` as their first arguments and
+// expose (or modify) the given table, the privilege is checked on that table.
+fn table_function_target(
+    table_func_name: &str,
+    table_args: &TableArgs,
+) -> Result<Option<(GrantObject, UserPrivilegeType)>> {
+    let privilege = match table_func_name {
+        "fuse_snapshot"
+        | "fuse_segment"
+        | "fuse_block"
+        | "fuse_column"
+        | "fuse_statistic"
+        | "fuse_check_stats"
+        | "clustering_information" => UserPrivilegeType::Select,
+        // Vacuum purges the history of the table.
+        "fuse_vacuum" => UserPrivilegeType::Delete,
+        "fuse_rotate_encryption_key" => UserPrivilegeType::Alter,
+        _ => return Ok(None),
+    };
+
+    let args = table_args.expect_all_positioned(table_func_name, None)?;
+    if args.len() < 2 {
+        return Err(ErrorCode::BadArguments(format!(
+            "{} expects <database>, <table_name> as the first arguments, but got {:?}",
+            table_func_name, args
+        )));
+    }
+    let database = string_value(&args[0])?;
+    let table = string_value(&args[1])?;
+    Ok(Some((
+        GrantObject::Table(CATALOG_DEFAULT.to_string(), database, table),
+        privilege,
+    )))
+}
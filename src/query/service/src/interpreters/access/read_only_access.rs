@@ -0,0 +1,126 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_config::GlobalConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::optimizer::SExpr;
+use common_sql::plans::RelOperator;
+use common_sql::plans::ScalarExpr;
+use common_sql::plans::UDFType;
+
+use crate::interpreters::access::AccessChecker;
+use crate::sql::plans::Plan;
+use crate::sql::plans::PresignAction;
+
+pub struct ReadOnlyModeAccess {}
+
+impl ReadOnlyModeAccess {
+    pub fn create() -> Box<dyn AccessChecker> {
+        Box::new(ReadOnlyModeAccess {})
+    }
+
+    // Whether the plan only reads data and metadata.
+    fn is_read_only(plan: &Plan) -> bool {
+        match plan {
+            // Query, including the time travel ones, unless it writes through a table
+            // function or a sequence.
+            Plan::Query {
+                s_expr, metadata, ..
+            } => {
+                let metadata = metadata.read();
+                let has_side_effects = metadata.tables().iter().any(|table| {
+                    table.table().table_args().is_some()
+                        && matches!(table.name(), "fuse_vacuum" | "fuse_rotate_encryption_key")
+                });
+                !has_side_effects && !Self::has_sequence(s_expr)
+            }
+
+            // Explain.
+            Plan::Explain { .. } | Plan::ExplainAst { .. } | Plan::ExplainSyntax { .. } => true,
+            // The analyzed plan is executed.
+            Plan::ExplainAnalyze { plan } => Self::is_read_only(plan),
+
+            // Show and describe.
+            Plan::ShowCreateCatalog(_)
+            | Plan::ShowCreateDatabase(_)
+            | Plan::ShowCreateTable(_)
+            | Plan::DescribeTable(_)
+            | Plan::ExistsTable(_)
+            | Plan::ShowRoles(_)
+            | Plan::ShowGrants(_)
+            | Plan::ShowFileFormats(_)
+            | Plan::ShowShareEndpoint(_)
+            | Plan::DescShare(_)
+            | Plan::ShowShares(_)
+            | Plan::ShowObjectGrantPrivileges(_)
+            | Plan::ShowGrantTenantsOfShare(_)
+            | Plan::DescDatamaskPolicy(_)
+            | Plan::DescNetworkPolicy(_)
+            | Plan::ShowNetworkPolicies(_)
+            | Plan::DescStorageProfile(_)
+            | Plan::ShowStorageProfiles(_)
+            | Plan::DescPipe(_)
+            | Plan::ShowPipes(_)
+            | Plan::ShowSequences(_) => true,
+
+            // Session.
+            Plan::UseDatabase(_)
+            | Plan::SetRole(_)
+            | Plan::SetVariable(_)
+            | Plan::UnSetVariable(_)
+            | Plan::Kill(_) => true,
+
+            // Presign.
+            Plan::Presign(plan) => matches!(plan.action, PresignAction::Download),
+
+            _ => false,
+        }
+    }
+
+    // Whether the query takes values from a sequence, with `nextval`.
+    fn has_sequence(s_expr: &SExpr) -> bool {
+        if let RelOperator::Udf(udf) = s_expr.plan() {
+            let has_sequence = udf.items.iter().any(|item| {
+                matches!(
+                    &item.scalar,
+                    ScalarExpr::UDFCall(call) if matches!(call.udf_type, UDFType::Sequence(_))
+                )
+            });
+            if has_sequence {
+                return true;
+            }
+        }
+        s_expr
+            .children()
+            .iter()
+            .any(|child| Self::has_sequence(child))
+    }
+}
+
+#[async_trait::async_trait]
+impl AccessChecker for ReadOnlyModeAccess {
+    // Reject the statements that write data or change the metadata if in read only mode.
+    #[async_backtrace::framed]
+    async fn check(&self, plan: &Plan) -> Result<()> {
+        if GlobalConfig::instance().query.read_only && !Self::is_read_only(plan) {
+            return Err(ErrorCode::ReadOnlyModePermissionDenied(format!(
+                "Access denied for operation:{:?} in read-only mode",
+                plan.format_indent()
+            )));
+        }
+
+        Ok(())
+    }
+}
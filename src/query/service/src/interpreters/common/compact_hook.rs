@@ -12,16 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
+use std::time::Duration;
 use std::time::Instant;
 
 use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::Thread;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_pipeline_core::Pipeline;
 use common_sql::plans::OptimizeTableAction;
 use common_sql::plans::OptimizeTablePlan;
 use log::info;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
 
 use crate::interpreters::common::metrics_inc_compact_hook_compact_time_ms;
 use crate::interpreters::common::metrics_inc_compact_hook_main_operation_time_ms;
@@ -30,6 +36,7 @@ use crate::interpreters::OptimizeTableInterpreter;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
 use crate::sessions::QueryContext;
+use crate::sessions::QueryContextShared;
 
 pub struct CompactTargetTableDescription {
     pub catalog: String,
@@ -42,8 +49,9 @@ pub struct CompactHookTraceCtx {
     pub operation_name: String,
 }
 
-// only if target table have cluster keys defined, and auto-reclustering is enabled,
+// if target table have cluster keys defined, and auto-reclustering is enabled,
 // we will hook the compact action with a on-finished callback.
+// otherwise, if auto-compaction is enabled, the compact action will be run in the background.
 //
 // errors (if any) are ignored
 pub async fn hook_compact(
@@ -77,13 +85,11 @@ async fn do_hook_compact(
         .await?;
 
     let has_cluster_key = !table.cluster_keys(ctx.clone()).is_empty();
+    let settings = ctx.get_settings();
 
     // only if target table have cluster keys defined, and auto-reclustering is enabled,
     // we will hook the compact action with a on-finished callback
-    if !pipeline.is_empty()
-        && has_cluster_key
-        && ctx.get_settings().get_enable_recluster_after_write()?
-    {
+    if !pipeline.is_empty() && has_cluster_key && settings.get_enable_recluster_after_write()? {
         pipeline.set_on_finished(move |err| {
 
             let op_name = &trace_ctx.operation_name;
@@ -103,12 +109,170 @@ async fn do_hook_compact(
             }
             metrics_inc_compact_hook_compact_time_ms(&trace_ctx.operation_name, compact_start_at.elapsed().as_millis() as u64);
 
+            Ok(())
+        });
+    } else {
+        hook_background_compact_on_finished(
+            ctx,
+            pipeline,
+            compact_target,
+            trace_ctx,
+            table.get_id(),
+        )?;
+    }
+    Ok(())
+}
+
+// if auto-compaction is enabled, the compact action will be run in the background after the
+// write finished, unlike `hook_compact`, target table will not be re-clustered synchronously.
+//
+// errors (if any) are ignored
+pub async fn hook_background_compact(
+    ctx: Arc<QueryContext>,
+    pipeline: &mut Pipeline,
+    compact_target: CompactTargetTableDescription,
+    trace_ctx: CompactHookTraceCtx,
+) {
+    let op_name = trace_ctx.operation_name.clone();
+    if let Err(e) = do_hook_background_compact(ctx, pipeline, compact_target, trace_ctx).await {
+        info!("compact hook ({}) with error (ignored): {}", op_name, e);
+    }
+}
+
+async fn do_hook_background_compact(
+    ctx: Arc<QueryContext>,
+    pipeline: &mut Pipeline,
+    compact_target: CompactTargetTableDescription,
+    trace_ctx: CompactHookTraceCtx,
+) -> Result<()> {
+    if pipeline.is_empty() || !ctx.get_settings().get_enable_compact_after_write()? {
+        return Ok(());
+    }
+
+    let table = ctx
+        .get_table(
+            &compact_target.catalog,
+            &compact_target.database,
+            &compact_target.table,
+        )
+        .await?;
+    hook_background_compact_on_finished(ctx, pipeline, compact_target, trace_ctx, table.get_id())
+}
+
+fn hook_background_compact_on_finished(
+    ctx: Arc<QueryContext>,
+    pipeline: &mut Pipeline,
+    compact_target: CompactTargetTableDescription,
+    trace_ctx: CompactHookTraceCtx,
+    table_id: u64,
+) -> Result<()> {
+    let settings = ctx.get_settings();
+    if settings.get_enable_compact_after_write()? {
+        let min_interval =
+            Duration::from_secs(settings.get_compact_after_write_min_interval_secs()?);
+        pipeline.set_on_finished(move |err| {
+            let op_name = &trace_ctx.operation_name;
+            metrics_inc_compact_hook_main_operation_time_ms(
+                op_name,
+                trace_ctx.start.elapsed().as_millis() as u64,
+            );
+
+            if err.is_none() {
+                spawn_background_compact(
+                    ctx,
+                    compact_target,
+                    table_id,
+                    min_interval,
+                    op_name.clone(),
+                );
+            }
             Ok(())
         });
     }
     Ok(())
 }
 
+/// Throttles the background compactions, a table is compacted by at most one task at a time,
+/// and no more often than the minimum interval.
+#[derive(Default)]
+struct BackgroundCompactThrottle {
+    running: HashSet<u64>,
+    last_started: HashMap<u64, Instant>,
+}
+
+impl BackgroundCompactThrottle {
+    fn try_acquire(&mut self, table_id: u64, min_interval: Duration) -> bool {
+        if self.running.contains(&table_id) {
+            return false;
+        }
+        // forget the tables compacted long enough ago, they are not throttled anymore.
+        self.last_started
+            .retain(|_, last_started| last_started.elapsed() < min_interval);
+        if self.last_started.contains_key(&table_id) {
+            return false;
+        }
+        self.running.insert(table_id);
+        self.last_started.insert(table_id, Instant::now());
+        true
+    }
+
+    fn release(&mut self, table_id: u64) {
+        self.running.remove(&table_id);
+    }
+}
+
+static BACKGROUND_COMPACT_THROTTLE: Lazy<Mutex<BackgroundCompactThrottle>> =
+    Lazy::new(|| Mutex::new(BackgroundCompactThrottle::default()));
+
+fn spawn_background_compact(
+    ctx: Arc<QueryContext>,
+    compact_target: CompactTargetTableDescription,
+    table_id: u64,
+    min_interval: Duration,
+    op_name: String,
+) {
+    if !BACKGROUND_COMPACT_THROTTLE
+        .lock()
+        .try_acquire(table_id, min_interval)
+    {
+        info!(
+            "execute {op_name} finished successfully. background table compaction of table {} is throttled.",
+            table_id
+        );
+        return;
+    }
+
+    info!("execute {op_name} finished successfully. running background table compaction job.");
+    // the query is finished, the compaction runs in a context of its own, with its own id,
+    // progress and executor. It's not attached to the session, which may run other queries.
+    let session = ctx.get_current_session();
+    let cluster = ctx.get_cluster();
+    // the compact pipeline is executed synchronously, use a dedicated thread to not block the io runtime.
+    Thread::named_spawn(Some(String::from("BackgroundCompact")), move || {
+        let compact_start_at = Instant::now();
+        let compact = async move {
+            let shared = QueryContextShared::try_create(session, cluster)?;
+            compact_table(QueryContext::create_from_shared(shared), compact_target).await
+        };
+        match GlobalIORuntime::instance().block_on(compact) {
+            Ok(_) => {
+                info!("background table compaction job of {op_name} finished.");
+            }
+            Err(e) => {
+                info!(
+                    "background table compaction job of {op_name} failed. {:?}",
+                    e
+                );
+            }
+        }
+        metrics_inc_compact_hook_compact_time_ms(
+            &op_name,
+            compact_start_at.elapsed().as_millis() as u64,
+        );
+        BACKGROUND_COMPACT_THROTTLE.lock().release(table_id);
+    });
+}
+
 async fn compact_table(
     ctx: Arc<QueryContext>,
     compact_target: CompactTargetTableDescription,
@@ -126,6 +290,7 @@ async fn compact_table(
             table: compact_target.table,
             action: OptimizeTableAction::CompactBlocks,
             limit: None,
+            partition: None,
         })?;
 
     let mut build_res = optimize_interpreter.execute2().await?;
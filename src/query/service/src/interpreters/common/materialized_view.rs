@@ -0,0 +1,347 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_ast::ast::Identifier;
+use common_ast::ast::InsertSource;
+use common_ast::ast::InsertStmt;
+use common_ast::ast::Query;
+use common_ast::ast::Statement;
+use common_ast::ast::TableAlias;
+use common_ast::ast::TableReference;
+use common_ast::parser::parse_sql;
+use common_ast::parser::quote::quote_ident;
+use common_ast::parser::tokenize_sql;
+use common_ast::walk_table_reference_mut;
+use common_ast::Dialect;
+use common_ast::VisitorMut;
+use common_catalog::catalog::CatalogManager;
+use common_catalog::table::NavigationPoint;
+use common_catalog::table::Table;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableSchema;
+use common_meta_app::schema::UpdateStreamMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::InsertInputSource;
+use common_sql::plans::Plan;
+use common_sql::Binder;
+use common_sql::Metadata;
+use common_sql::NameResolutionContext;
+use common_storages_fuse::FuseTable;
+use common_storages_stream::stream_table::snapshot_id_from_location;
+use common_storages_stream::stream_table::StreamTable;
+use common_storages_stream::stream_table::CHANGE_ACTION_COL_NAME;
+use common_storages_stream::stream_table::OPT_KEY_OFFSET;
+use parking_lot::RwLock;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_AGGREGATES;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_STREAMS;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+
+use crate::interpreters::InterpreterFactory;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// The name of the stream tracking the changes of the source table `table_id` of the
+/// materialized view `view_name`.
+pub fn materialized_view_stream_name(view_name: &str, table_id: u64) -> String {
+    format!("_mv_{view_name}_{table_id}")
+}
+
+/// Builds the pipeline bringing the materialized view `view_name` up to date with its source
+/// tables, and moving the offsets of its streams to the snapshots it is refreshed from.
+///
+/// Nothing is done if none of the source tables has changed since the last refresh. If the
+/// view is incremental and rows were only inserted into its source table, the query of the
+/// view over the inserted rows is appended to the view. If the view aggregates the rows of
+/// its source table, and rows were only inserted into it, the view is overwritten with the
+/// merge of its rows and the query over the inserted rows. Otherwise, or if `full` is set,
+/// the view is overwritten with the result of its query.
+#[async_backtrace::framed]
+pub async fn build_refresh_materialized_view(
+    ctx: Arc<QueryContext>,
+    catalog_name: &str,
+    database: &str,
+    view_name: &str,
+    full: bool,
+) -> Result<PipelineBuildResult> {
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_catalog(catalog_name).await?;
+    let view = catalog
+        .get_table(tenant.as_str(), database, view_name)
+        .await?;
+    let options = view.options();
+    let query = options
+        .get(OPT_KEY_MATERIALIZED_VIEW_QUERY)
+        .ok_or_else(|| {
+            ErrorCode::TableEngineNotSupported(format!(
+                "`{}`.`{}` is not a materialized view",
+                database, view_name
+            ))
+        })?;
+    let incremental = options
+        .get(OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL)
+        .is_some_and(|v| v == "true");
+    let aggregates = options
+        .get(OPT_KEY_MATERIALIZED_VIEW_AGGREGATES)
+        .map(|v| v.split(',').map(|f| f.to_string()).collect::<Vec<_>>())
+        .filter(|aggregates| aggregates.len() == view.schema().num_fields());
+
+    let mut sources: Vec<(StreamTable, Arc<dyn Table>)> = vec![];
+    let stream_names = options
+        .get(OPT_KEY_MATERIALIZED_VIEW_STREAMS)
+        .cloned()
+        .unwrap_or_default();
+    for stream_name in stream_names.split(',').filter(|name| !name.is_empty()) {
+        let stream = catalog
+            .get_table(tenant.as_str(), database, stream_name)
+            .await?;
+        let stream = StreamTable::try_new(stream.get_table_info().clone())?;
+        let source = catalog
+            .get_table(tenant.as_str(), &stream.table_database, &stream.table_name)
+            .await?;
+        if source.get_id() != stream.table_id {
+            return Err(ErrorCode::IllegalStream(format!(
+                "The source table `{}`.`{}` of materialized view `{}`.`{}` has been dropped",
+                stream.table_database, stream.table_name, database, view_name
+            )));
+        }
+        sources.push((stream, source));
+    }
+
+    if !full
+        && sources
+            .iter()
+            .all(|(stream, source)| stream.offset == snapshot_id(source.as_ref()))
+    {
+        return Ok(PipelineBuildResult::create());
+    }
+
+    let tokens = tokenize_sql(query)?;
+    let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+    let Statement::Query(mut query) = stmt else {
+        return Err(ErrorCode::Internal(format!(
+            "Invalid query of materialized view `{}`.`{}`",
+            database, view_name
+        )));
+    };
+
+    let read_changes = match sources.as_slice() {
+        [(stream, source)] if (incremental || aggregates.is_some()) && !full => {
+            is_append_only(source.clone(), &stream.offset).await?
+        }
+        _ => false,
+    };
+    let append = read_changes && aggregates.is_none();
+    if read_changes {
+        // Reading the stream moves its offset, see `Binder::resolve_stream_query`.
+        let changes = format!(
+            "SELECT * EXCLUDE \"{CHANGE_ACTION_COL_NAME}\" FROM {}.{} \
+             WHERE \"{CHANGE_ACTION_COL_NAME}\" = 'INSERT'",
+            quote_ident(database, '"', true),
+            quote_ident(sources[0].0.name(), '"', true)
+        );
+        let tokens = tokenize_sql(&changes)?;
+        let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+        if let Statement::Query(changes) = stmt {
+            let mut replacer = SourceTableReplacer { changes: *changes };
+            replacer.visit_query(&mut query);
+        }
+    }
+    if let (true, Some(aggregates)) = (read_changes, &aggregates) {
+        query = Box::new(merge_query(
+            &view.schema(),
+            aggregates,
+            database,
+            view_name,
+            &query,
+        )?);
+    }
+
+    let stmt = Statement::Insert(InsertStmt {
+        hints: None,
+        catalog: Some(quoted_identifier(catalog_name)),
+        database: Some(quoted_identifier(database)),
+        table: quoted_identifier(view_name),
+        columns: vec![],
+        source: InsertSource::Select { query },
+        overwrite: !append,
+    });
+    let settings = ctx.get_settings();
+    let metadata = Arc::new(RwLock::new(Metadata::default()));
+    let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+    let binder = Binder::new(
+        ctx.clone(),
+        CatalogManager::instance(),
+        name_resolution_ctx,
+        metadata,
+    );
+    let plan = binder.bind(&stmt).await?;
+
+    if !read_changes {
+        // The streams are not read by a full refresh, their offsets are moved to the
+        // snapshots of the source tables read by the query instead.
+        let read_tables: Vec<Arc<dyn Table>> = match &plan {
+            Plan::Insert(insert) => match &insert.source {
+                InsertInputSource::SelectPlan(box Plan::Query { metadata, .. }) => metadata
+                    .read()
+                    .tables()
+                    .iter()
+                    .map(|table| table.table())
+                    .collect(),
+                _ => vec![],
+            },
+            _ => vec![],
+        };
+        for (stream, source) in &sources {
+            let read = read_tables
+                .iter()
+                .find(|table| table.get_id() == source.get_id())
+                .unwrap_or(source);
+            let stream_info = stream.get_table_info();
+            let mut new_stream_meta = stream_info.meta.clone();
+            new_stream_meta
+                .options
+                .insert(OPT_KEY_OFFSET.to_string(), snapshot_id(read.as_ref()));
+            ctx.add_stream_update(UpdateStreamMetaReq {
+                stream_id: stream_info.ident.table_id,
+                seq: MatchSeq::Exact(stream_info.ident.seq),
+                new_stream_meta,
+            });
+        }
+    }
+
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    interpreter.execute2().await
+}
+
+fn snapshot_id(table: &dyn Table) -> String {
+    table
+        .options()
+        .get(OPT_KEY_SNAPSHOT_LOCATION)
+        .map(|location| snapshot_id_from_location(location))
+        .unwrap_or_default()
+}
+
+/// The query merging the rows of the view with the result of its query over the inserted rows
+/// `changes`, by aggregating each column with its merge function, and grouping by the columns
+/// without one.
+fn merge_query(
+    schema: &TableSchema,
+    aggregates: &[String],
+    database: &str,
+    view_name: &str,
+    changes: &Query,
+) -> Result<Query> {
+    let mut select_list = Vec::with_capacity(aggregates.len());
+    let mut group_keys = vec![];
+    for (field, function) in schema.fields().iter().zip(aggregates) {
+        let column = quote_ident(field.name(), '"', true);
+        if function.is_empty() {
+            select_list.push(column.clone());
+            group_keys.push(column);
+        } else {
+            select_list.push(format!("{function}({column}) AS {column}"));
+        }
+    }
+    let group_by = if group_keys.is_empty() {
+        String::new()
+    } else {
+        format!(" GROUP BY {}", group_keys.join(", "))
+    };
+    let sql = format!(
+        "SELECT {} FROM (SELECT * FROM {}.{} UNION ALL {}) AS _mv_merge{}",
+        select_list.join(", "),
+        quote_ident(database, '"', true),
+        quote_ident(view_name, '"', true),
+        changes,
+        group_by
+    );
+    let tokens = tokenize_sql(&sql)?;
+    let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+    match stmt {
+        Statement::Query(query) => Ok(*query),
+        _ => Err(ErrorCode::Internal(format!(
+            "Invalid merge query of materialized view `{}`.`{}`",
+            database, view_name
+        ))),
+    }
+}
+
+fn quoted_identifier(name: &str) -> Identifier {
+    Identifier::from_name_with_quoted(name, Some('"'))
+}
+
+/// Whether every segment of the snapshot `offset` of `source` is still in its current
+/// snapshot, i.e. rows have only been inserted into it since.
+async fn is_append_only(source: Arc<dyn Table>, offset: &str) -> Result<bool> {
+    if offset.is_empty() {
+        return Ok(true);
+    }
+    // The snapshot may have been purged, the view is then refreshed in full.
+    let Ok(base) = source
+        .navigate_to(&NavigationPoint::SnapshotID(offset.to_string()))
+        .await
+    else {
+        return Ok(false);
+    };
+    let base = FuseTable::try_from_table(base.as_ref())?
+        .read_table_snapshot()
+        .await?;
+    let current = FuseTable::try_from_table(source.as_ref())?
+        .read_table_snapshot()
+        .await?;
+    Ok(match (base, current) {
+        (None, _) => true,
+        (Some(_), None) => false,
+        (Some(base), Some(current)) => {
+            let segments = current.segments.iter().collect::<HashSet<_>>();
+            base.segments
+                .iter()
+                .all(|segment| segments.contains(segment))
+        }
+    })
+}
+
+/// Replaces the source table of the query of an incremental materialized view with the rows
+/// inserted into it, keeping the name the query refers to it with.
+struct SourceTableReplacer {
+    changes: Query,
+}
+
+impl VisitorMut for SourceTableReplacer {
+    fn visit_table_reference(&mut self, table_ref: &mut TableReference) {
+        if let TableReference::Table {
+            span, table, alias, ..
+        } = table_ref
+        {
+            let alias = alias.clone().unwrap_or_else(|| TableAlias {
+                name: table.clone(),
+                columns: vec![],
+            });
+            *table_ref = TableReference::Subquery {
+                span: *span,
+                subquery: Box::new(self.changes.clone()),
+                alias: Some(alias),
+            };
+            return;
+        }
+        walk_table_reference_mut(self, table_ref);
+    }
+}
@@ -0,0 +1,163 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_base::base::tokio::time::sleep;
+use common_base::base::GlobalUniqName;
+use common_catalog::catalog::CatalogManager;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::table::Table;
+use common_config::InnerConfig;
+use common_exception::Result;
+use common_meta_app::principal::UserIdentity;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::MetaSpec;
+use common_meta_types::Operation;
+use common_sql::plans::Plan;
+use common_sql::plans::RefreshMaterializedViewPlan;
+use common_users::UserApiProvider;
+use futures::StreamExt;
+use log::info;
+use log::warn;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_OWNER;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+/// Refreshes the materialized views of the tenant in the background.
+///
+/// Every `query.materialized_view_refresh_interval_secs`, each materialized view is refreshed
+/// by the node holding its lease in the meta service, with the privileges of the user who
+/// created it. A view aggregating or filtering the rows of one table only reads the rows
+/// inserted into the table since the last refresh, from the stream of the view.
+pub struct MaterializedViewRefresher;
+
+impl MaterializedViewRefresher {
+    const LEASE_PREFIX: &'static str = "__fd_materialized_view_leases";
+
+    pub fn start(conf: &InnerConfig) -> Result<()> {
+        let interval = conf.query.materialized_view_refresh_interval_secs;
+        if interval == 0 {
+            return Ok(());
+        }
+
+        let interval = Duration::from_secs(interval);
+        let tenant = conf.query.tenant_id.clone();
+        let node_id = GlobalUniqName::unique();
+        tokio::spawn(async_backtrace::location!().frame(async move {
+            loop {
+                sleep(interval).await;
+                if let Err(cause) = Self::refresh_all(&tenant, &node_id, interval).await {
+                    warn!(
+                        "Refresh materialized views of tenant {} failure: {:?}",
+                        tenant, cause
+                    );
+                }
+            }
+        }));
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn refresh_all(tenant: &str, node_id: &str, interval: Duration) -> Result<()> {
+        let catalog = CatalogManager::instance().get_default_catalog()?;
+        for database in catalog.list_databases(tenant).await? {
+            for view in database.list_tables().await? {
+                if !view.options().contains_key(OPT_KEY_MATERIALIZED_VIEW_QUERY) {
+                    continue;
+                }
+                // The lease outlives the interval, so that a view is not refreshed by
+                // another node while it's being refreshed.
+                if !Self::acquire_lease(tenant, view.get_id(), node_id, interval * 2).await? {
+                    continue;
+                }
+                if let Err(cause) = Self::refresh(tenant, database.name(), view.as_ref()).await {
+                    warn!(
+                        "Refresh materialized view {}.{} failure: {:?}",
+                        database.name(),
+                        view.name(),
+                        cause
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Refresh the view as the user who created it.
+    #[async_backtrace::framed]
+    async fn refresh(tenant: &str, database: &str, view: &dyn Table) -> Result<()> {
+        let owner = match view.options().get(OPT_KEY_MATERIALIZED_VIEW_OWNER) {
+            Some(owner) => serde_json::from_str::<UserIdentity>(owner)?,
+            None => {
+                info!(
+                    "Materialized view {}.{} has no owner, it's only refreshed manually",
+                    database,
+                    view.name()
+                );
+                return Ok(());
+            }
+        };
+        let session = SessionManager::instance()
+            .create_session(SessionType::MaterializedView)
+            .await?;
+        let user = UserApiProvider::instance().get_user(tenant, owner).await?;
+        session.set_authed_user(user, None).await?;
+        let ctx = session.create_query_context().await?;
+
+        let plan = Plan::RefreshMaterializedView(Box::new(RefreshMaterializedViewPlan {
+            tenant: tenant.to_string(),
+            catalog: CATALOG_DEFAULT.to_string(),
+            database: database.to_string(),
+            view_name: view.name().to_string(),
+        }));
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let mut stream = interpreter.execute(ctx).await?;
+        while let Some(block) = stream.next().await {
+            block?;
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn acquire_lease(
+        tenant: &str,
+        view_id: u64,
+        node_id: &str,
+        ttl: Duration,
+    ) -> Result<bool> {
+        let key = format!("{}/{}/{}", Self::LEASE_PREFIX, tenant, view_id);
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let seq = match meta_api.get_kv(&key).await? {
+            None => MatchSeq::Exact(0),
+            Some(seq_value) if seq_value.data == node_id.as_bytes() => {
+                MatchSeq::Exact(seq_value.seq)
+            }
+            Some(_) => return Ok(false),
+        };
+
+        let meta = Some(MetaSpec::new_ttl(ttl));
+        let value = Operation::Update(node_id.as_bytes().to_vec());
+        let res = meta_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, value, meta))
+            .await?;
+        Ok(res.result.is_some() && res.is_changed())
+    }
+}
@@ -14,16 +14,29 @@
 
 mod compact_hook;
 mod grant;
+mod materialized_view;
+mod materialized_view_refresher;
 mod metrics;
+mod quota;
 mod refresh_aggregating_index;
+mod result_cache;
+mod scan_budget;
 mod table;
 mod util;
 
 pub use compact_hook::*;
 pub use grant::validate_grant_object_exists;
+pub use materialized_view::build_refresh_materialized_view;
+pub use materialized_view::materialized_view_stream_name;
+pub use materialized_view_refresher::MaterializedViewRefresher;
+pub use quota::check_data_size_quota;
 pub use refresh_aggregating_index::hook_refresh_agg_index;
 pub use refresh_aggregating_index::RefreshAggIndexDesc;
+pub use result_cache::gen_query_result_cache_key;
+pub use scan_budget::check_scan_budget;
+pub use scan_budget::collect_table_scans;
 pub use table::check_referenced_computed_columns;
+pub use table::check_referenced_partition_key;
 pub use util::check_deduplicate_label;
 
 pub use self::metrics::*;
@@ -0,0 +1,51 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::convert_byte_size;
+use common_catalog::catalog::Catalog;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::tenant::TenantQuota;
+
+/// Reject a DDL if the tenant has reached the quota `max_data_size`, the compressed bytes
+/// of the data of all its tables.
+///
+/// Listing every table is not cheap, the data size is computed only if the quota is set.
+pub async fn check_data_size_quota(
+    catalog: &Arc<dyn Catalog>,
+    tenant: &str,
+    quota: &TenantQuota,
+) -> Result<()> {
+    if quota.max_data_size == 0 {
+        return Ok(());
+    }
+
+    let mut data_size = 0;
+    for database in catalog.list_databases(tenant).await? {
+        for table in catalog.list_tables(tenant, database.name()).await? {
+            data_size += table.get_table_info().meta.statistics.compressed_data_bytes;
+        }
+    }
+
+    if data_size >= quota.max_data_size {
+        return Err(ErrorCode::TenantQuotaExceeded(format!(
+            "Max data size quota exceeded: {}, data size of tenant: {}",
+            convert_byte_size(quota.max_data_size as f64),
+            convert_byte_size(data_size as f64)
+        )));
+    }
+    Ok(())
+}
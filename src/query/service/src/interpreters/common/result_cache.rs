@@ -0,0 +1,82 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_catalog::table::Table;
+use common_exception::Result;
+use common_sql::optimizer::SExpr;
+use common_sql::BindContext;
+use common_sql::MetadataRef;
+use common_storages_result_cache::gen_result_cache_key_of;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Generate the key of the query result cache, and the snapshots of the tables in the query.
+///
+/// The key is generated from the optimized plan instead of the SQL text, so the queries
+/// that are planned the same share the cached result. The cached result is valid only for
+/// the snapshots it is computed from, so it's invalidated once a table has a new snapshot,
+/// without reading the partitions of the tables.
+///
+/// The plan only refers to the tables by index, so the arguments of the table functions,
+/// e.g. the query id of RESULT_SCAN, are a part of the key as well.
+///
+/// Returns None if any of the tables can not be cached.
+pub fn gen_query_result_cache_key(
+    ctx: &QueryContext,
+    s_expr: &SExpr,
+    bind_context: &BindContext,
+    metadata: &MetadataRef,
+) -> Result<Option<(String, Vec<String>)>> {
+    let mut tables = vec![];
+    let mut table_snapshots = vec![];
+    for entry in metadata.read().tables() {
+        let table = entry.table();
+        if !table.result_can_be_cached() {
+            return Ok(None);
+        }
+
+        let table_info = table.get_table_info();
+        let table_id = format!(
+            "{}.{}.{}",
+            entry.catalog(),
+            table_info.meta.engine,
+            table_info.ident.table_id
+        );
+        let table_args = table
+            .table_args()
+            .map(|args| format!("{:?}", args))
+            .unwrap_or_default();
+        let snapshot = table
+            .options()
+            .get(OPT_KEY_SNAPSHOT_LOCATION)
+            .cloned()
+            .unwrap_or_default();
+        table_snapshots.push(format!("{table_id}:{snapshot}"));
+        tables.push((table_id, table_args));
+    }
+
+    // The output columns are not a part of the plan, and the timezone affects the
+    // evaluation of the functions on dates and timestamps.
+    let output_columns = bind_context
+        .columns
+        .iter()
+        .map(|column| (column.index, column.column_name.as_str()))
+        .collect::<Vec<_>>();
+    let timezone = ctx.get_settings().get_timezone()?;
+
+    let key = gen_result_cache_key_of(&(s_expr, output_columns, tables, timezone));
+    Ok(Some((key, table_snapshots)))
+}
@@ -0,0 +1,96 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::convert_byte_size;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::executor::PhysicalPlan;
+use common_sql::executor::TableScan;
+use common_sql::MetadataRef;
+use common_sql::DUMMY_TABLE_INDEX;
+use itertools::Itertools;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+// Number of the largest scans listed in the error.
+const MAX_SCANS_IN_ERROR: usize = 3;
+
+/// Collect the table scans of the plan, the dummy scans of `SELECT` without `FROM` are skipped.
+pub fn collect_table_scans(plan: &PhysicalPlan) -> Vec<&TableScan> {
+    let mut scans = vec![];
+    PhysicalPlan::traverse(
+        plan,
+        &mut |_| true,
+        &mut |plan| {
+            if let PhysicalPlan::TableScan(scan) = plan {
+                if scan.table_index != DUMMY_TABLE_INDEX {
+                    scans.push(scan);
+                }
+            }
+        },
+        &mut |_| {},
+    );
+    scans
+}
+
+/// Reject the query before execution if the bytes it's estimated to scan exceed the
+/// setting `max_estimated_scan_bytes`, the largest scans are listed in the error.
+pub fn check_scan_budget(
+    ctx: &QueryContext,
+    plan: &PhysicalPlan,
+    metadata: &MetadataRef,
+) -> Result<()> {
+    let budget = ctx.get_settings().get_max_estimated_scan_bytes()?;
+    if budget == 0 {
+        return Ok(());
+    }
+
+    let scans = collect_table_scans(plan);
+    let total_bytes = scans
+        .iter()
+        .map(|scan| scan.source.statistics.read_bytes as u64)
+        .sum::<u64>();
+    if total_bytes <= budget {
+        return Ok(());
+    }
+
+    let metadata = metadata.read();
+    let largest_scans = scans
+        .iter()
+        .sorted_by_key(|scan| std::cmp::Reverse(scan.source.statistics.read_bytes))
+        .take(MAX_SCANS_IN_ERROR)
+        .map(|scan| {
+            let table = metadata.table(scan.table_index);
+            let statistics = &scan.source.statistics;
+            format!(
+                "{}.{}.{} ({}, {}/{} partitions)",
+                table.catalog(),
+                table.database(),
+                table.name(),
+                convert_byte_size(statistics.read_bytes as f64),
+                statistics.partitions_scanned,
+                statistics.partitions_total
+            )
+        })
+        .join(", ");
+
+    Err(ErrorCode::ScanBudgetExceeded(format!(
+        "The query is estimated to scan {}, which exceeds max_estimated_scan_bytes ({}). \
+        The largest scans: {}. Add filters to prune the partitions, or increase the setting",
+        convert_byte_size(total_bytes as f64),
+        convert_byte_size(budget as f64),
+        largest_scans
+    )))
+}
@@ -14,12 +14,15 @@
 
 use std::sync::Arc;
 
+use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::ComputedExpr;
 use common_expression::DataSchemaRef;
 use common_sql::parse_computed_expr;
+use common_sql::parse_exprs;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 
 pub fn check_referenced_computed_columns(
     ctx: Arc<dyn TableContext>,
@@ -55,3 +58,27 @@ pub fn check_referenced_computed_columns(
     }
     Ok(())
 }
+
+/// The columns of the partition key can't be dropped or renamed, the partition values of the
+/// segments are computed from them.
+pub fn check_referenced_partition_key(
+    ctx: Arc<dyn TableContext>,
+    table: Arc<dyn Table>,
+    column: &str,
+) -> Result<()> {
+    let partition_key = match table.options().get(OPT_KEY_PARTITION_BY) {
+        Some(partition_key) => partition_key.clone(),
+        None => return Ok(()),
+    };
+    let index = table.schema().index_of(column)?;
+    let referenced = parse_exprs(ctx, table, &partition_key)?
+        .iter()
+        .any(|expr| expr.column_refs().contains_key(&index));
+    if referenced {
+        return Err(ErrorCode::InvalidPartitionKey(format!(
+            "column `{}` is referenced by the partition key `{}`",
+            column, partition_key
+        )));
+    }
+    Ok(())
+}
@@ -42,6 +42,7 @@ pub trait Interpreter: Sync + Send {
     fn name(&self) -> &str;
 
     /// The core of the databend processor which will execute the logical plan and get the DataBlock
+    #[minitrace::trace(name = "interpreter_execute")]
     #[async_backtrace::framed]
     async fn execute(&self, ctx: Arc<QueryContext>) -> Result<SendableDataBlockStream> {
         ctx.set_status_info("building pipeline");
@@ -0,0 +1,171 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::UpdateDatabaseCommentReq;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::CommentPlan;
+use common_sql::plans::CommentTarget;
+use common_storages_share::save_share_table_info;
+use common_storages_view::view_table::VIEW_ENGINE;
+use storages_common_table_meta::table::OPT_KEY_COMMENT;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CommentInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CommentPlan,
+}
+
+impl CommentInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CommentPlan) -> Result<Self> {
+        Ok(CommentInterpreter { ctx, plan })
+    }
+
+    async fn get_table(&self, table: &str) -> Result<Option<Arc<dyn Table>>> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        match catalog
+            .get_table(&self.plan.tenant, &self.plan.database, table)
+            .await
+        {
+            Ok(table) => {
+                let table_info = table.get_table_info();
+                if table_info.engine() == VIEW_ENGINE
+                    || table_info.db_type != DatabaseType::NormalDB
+                {
+                    return Err(ErrorCode::TableEngineNotSupported(format!(
+                        "{}.{} doesn't support comment",
+                        &self.plan.database, table
+                    )));
+                }
+                Ok(Some(table))
+            }
+            Err(e) if self.plan.if_exists && e.code() == ErrorCode::UNKNOWN_TABLE => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn comment_database(&self) -> Result<()> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        if self.plan.if_exists
+            && !catalog
+                .exists_database(&self.plan.tenant, &self.plan.database)
+                .await?
+        {
+            return Ok(());
+        }
+
+        let req = UpdateDatabaseCommentReq {
+            name_ident: DatabaseNameIdent {
+                tenant: self.plan.tenant.clone(),
+                db_name: self.plan.database.clone(),
+            },
+            comment: self.plan.comment.clone(),
+        };
+        catalog.update_database_comment(req).await?;
+        Ok(())
+    }
+
+    async fn comment_table(&self, table: &str) -> Result<()> {
+        let table = match self.get_table(table).await? {
+            Some(table) => table,
+            None => return Ok(()),
+        };
+
+        // An empty comment removes the comment.
+        let comment = if self.plan.comment.is_empty() {
+            None
+        } else {
+            Some(self.plan.comment.clone())
+        };
+        let req = UpsertTableOptionReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+            options: HashMap::from([(OPT_KEY_COMMENT.to_string(), comment)]),
+        };
+
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        catalog
+            .upsert_table_option(&self.plan.tenant, &self.plan.database, req)
+            .await?;
+        Ok(())
+    }
+
+    async fn comment_column(&self, table: &str, column: &str) -> Result<()> {
+        let table = match self.get_table(table).await? {
+            Some(table) => table,
+            None => return Ok(()),
+        };
+
+        let table_info = table.get_table_info();
+        let mut new_table_meta = table_info.meta.clone();
+        let index = new_table_meta.schema.index_of(column)?;
+        // compatibility: creating table in the old planner will not have `fields_comments`
+        let n_fields = new_table_meta.schema.num_fields();
+        if new_table_meta.field_comments.len() != n_fields {
+            new_table_meta.field_comments = vec!["".to_string(); n_fields];
+        }
+        new_table_meta.field_comments[index] = self.plan.comment.clone();
+
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+            deduplicated_label: None,
+            update_stream_meta: vec![],
+        };
+
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let res = catalog.update_table_meta(table_info, req).await?;
+        if let Some(share_table_info) = res.share_table_info {
+            save_share_table_info(
+                &self.plan.tenant,
+                self.ctx.get_data_operator()?.operator(),
+                share_table_info,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CommentInterpreter {
+    fn name(&self) -> &str {
+        "CommentInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        match &self.plan.target {
+            CommentTarget::Database => self.comment_database().await?,
+            CommentTarget::Table { table } => self.comment_table(table).await?,
+            CommentTarget::Column { table, column } => self.comment_column(table, column).await?,
+        }
+        Ok(PipelineBuildResult::create())
+    }
+}
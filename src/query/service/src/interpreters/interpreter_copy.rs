@@ -19,10 +19,15 @@ use common_catalog::plan::StageTableInfo;
 use common_catalog::table::AppendMode;
 use common_exception::Result;
 use common_expression::infer_table_schema;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
 use common_expression::BlockThresholds;
+use common_expression::DataBlock;
 use common_expression::DataField;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
+use common_expression::FromData;
+use common_expression::FromOptData;
 use common_meta_app::principal::StageInfo;
 use common_pipeline_core::Pipeline;
 use common_sql::executor::table_read_plan::ToReadDataSourcePlan;
@@ -46,6 +51,8 @@ use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreter;
 use crate::pipelines::builders::build_append2table_with_commit_pipeline;
 use crate::pipelines::builders::build_commit_data_pipeline;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
 use crate::pipelines::PipelineBuildResult;
 use crate::schedulers::build_query_pipeline_without_render_result_set;
 use crate::sessions::QueryContext;
@@ -109,6 +116,7 @@ impl CopyInterpreter {
         &self,
         stage: &StageInfo,
         path: &str,
+        manifest: bool,
         query: &Plan,
     ) -> Result<PipelineBuildResult> {
         let (select_interpreter, data_schema) = self.build_query(query).await?;
@@ -125,6 +133,7 @@ impl CopyInterpreter {
             },
             files_to_copy: None,
             is_select: false,
+            manifest,
         };
         let to_table = StageTable::try_create(stage_table_info)?;
         build_append2table_with_commit_pipeline(
@@ -245,6 +254,58 @@ impl CopyInterpreter {
 
         Ok(())
     }
+
+    /// Run the copy pipeline to the end and report the loading status of each file:
+    /// the rows loaded, the errors skipped by ON_ERROR and the most frequent error.
+    fn execute_with_file_status(
+        &self,
+        mut build_res: PipelineBuildResult,
+        files: &[StageFileInfo],
+    ) -> Result<PipelineBuildResult> {
+        let settings = self.ctx.get_settings();
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+
+        let executor_settings = ExecutorSettings::try_create(&settings, self.ctx.get_id())?;
+        let executor = PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
+        self.ctx.set_executor(executor.get_inner())?;
+        executor.execute()?;
+
+        let rows_loaded_map = self.ctx.get_rows_loaded_per_file();
+        let on_error_map = self.ctx.get_on_error_map();
+
+        let mut paths = Vec::with_capacity(files.len());
+        let mut rows_loaded = Vec::with_capacity(files.len());
+        let mut errors_seen = Vec::with_capacity(files.len());
+        let mut errors = Vec::with_capacity(files.len());
+        for file in files {
+            paths.push(file.path.as_bytes().to_vec());
+            rows_loaded.push(rows_loaded_map.get(&file.path).map_or(0, |v| *v));
+            match on_error_map.as_ref().and_then(|m| m.get(&file.path)) {
+                Some(file_errors) => {
+                    errors_seen.push(file_errors.values().map(|e| e.num as u64).sum::<u64>());
+                    errors.push(
+                        file_errors
+                            .values()
+                            .max_by_key(|e| e.num)
+                            .map(|e| e.err.message().into_bytes()),
+                    );
+                }
+                None => {
+                    errors_seen.push(0);
+                    errors.push(None);
+                }
+            }
+        }
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(paths),
+            UInt64Type::from_data(rows_loaded),
+            UInt64Type::from_data(errors_seen),
+            StringType::from_opt_data(errors),
+        ])])
+    }
 }
 
 #[async_trait::async_trait]
@@ -294,12 +355,20 @@ impl Interpreter for CopyInterpreter {
                     trace_ctx,
                 )
                 .await;
+
+                if plan.return_file_status {
+                    return self.execute_with_file_status(build_res, &files);
+                }
                 Ok(build_res)
             }
             CopyPlan::IntoStage {
-                stage, from, path, ..
+                stage,
+                from,
+                path,
+                manifest,
+                ..
             } => {
-                self.build_local_copy_into_stage_pipeline(stage, path, from)
+                self.build_local_copy_into_stage_pipeline(stage, path, *manifest, from)
                     .await
             }
             CopyPlan::NoFileToCopy => Ok(PipelineBuildResult::create()),
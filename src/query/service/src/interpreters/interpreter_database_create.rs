@@ -24,6 +24,7 @@ use common_sql::plans::CreateDatabasePlan;
 use common_users::UserApiProvider;
 use log::debug;
 
+use crate::interpreters::common::check_data_size_quota;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -110,6 +111,7 @@ impl Interpreter for CreateDatabaseInterpreter {
                 quota.max_databases
             )));
         };
+        check_data_size_quota(&catalog, &tenant, &quota).await?;
         // if create from other tenant, check from share endpoint
         if let Some(ref share_name) = self.plan.meta.from_share {
             self.check_create_database_from_share(&tenant, share_name)
@@ -20,6 +20,7 @@ use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
 use common_expression::DataBlock;
 use common_expression::FromData;
 use common_profile::QueryProfileManager;
@@ -27,11 +28,12 @@ use common_profile::SharedProcessorProfiles;
 use common_sql::executor::ProfileHelper;
 use common_sql::optimizer::ColumnSet;
 use common_sql::MetadataRef;
-use common_storages_result_cache::gen_result_cache_key;
 use common_storages_result_cache::ResultCacheReader;
 use common_users::UserApiProvider;
 
 use super::InterpreterFactory;
+use crate::interpreters::common::collect_table_scans;
+use crate::interpreters::common::gen_query_result_cache_key;
 use crate::interpreters::Interpreter;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
@@ -45,6 +47,7 @@ use crate::sql::executor::PhysicalPlan;
 use crate::sql::executor::PhysicalPlanBuilder;
 use crate::sql::optimizer::SExpr;
 use crate::sql::plans::Plan;
+use crate::sql::BindContext;
 
 pub struct ExplainInterpreter {
     ctx: Arc<QueryContext>,
@@ -71,16 +74,14 @@ impl Interpreter for ExplainInterpreter {
                     formatted_ast,
                     ..
                 } => {
-                    let ctx = self.ctx.clone();
                     // If `formatted_ast` is Some, it means we may use query result cache.
-                    // If we use result cache for this query,
-                    // we should not use `dry_run` mode to build the physical plan.
-                    // It's because we need to get the same partitions as the original selecting plan.
-                    let mut builder =
-                        PhysicalPlanBuilder::new(metadata.clone(), ctx, formatted_ast.is_none());
-                    let plan = builder.build(s_expr, bind_context.column_set()).await?;
-                    self.explain_physical_plan(&plan, metadata, formatted_ast)
-                        .await?
+                    self.explain_physical_plan(
+                        s_expr,
+                        metadata,
+                        bind_context,
+                        formatted_ast.is_some(),
+                    )
+                    .await?
                 }
                 _ => self.explain_plan(&self.plan)?,
             },
@@ -123,6 +124,21 @@ impl Interpreter for ExplainInterpreter {
                 ))?,
             },
 
+            ExplainKind::Estimate => match &self.plan {
+                Plan::Query {
+                    s_expr,
+                    metadata,
+                    bind_context,
+                    ..
+                } => {
+                    self.explain_estimate(s_expr, metadata, bind_context)
+                        .await?
+                }
+                _ => Err(ErrorCode::Unimplemented(
+                    "Unsupported EXPLAIN ESTIMATE statement",
+                ))?,
+            },
+
             ExplainKind::Pipeline => {
                 let interpter = InterpreterFactory::get(self.ctx.clone(), &self.plan).await?;
                 let pipeline = interpter.execute2().await?;
@@ -181,17 +197,26 @@ impl ExplainInterpreter {
 
     pub async fn explain_physical_plan(
         &self,
-        plan: &PhysicalPlan,
+        s_expr: &SExpr,
         metadata: &MetadataRef,
-        formatted_ast: &Option<String>,
+        bind_context: &BindContext,
+        use_result_cache: bool,
     ) -> Result<Vec<DataBlock>> {
-        if self.ctx.get_settings().get_enable_query_result_cache()? && self.ctx.get_cacheable() {
-            let key = gen_result_cache_key(formatted_ast.as_ref().unwrap());
+        let cache_key = if use_result_cache
+            && self.ctx.get_settings().get_enable_query_result_cache()?
+            && self.ctx.get_cacheable()
+        {
+            gen_query_result_cache_key(&self.ctx, s_expr, bind_context, metadata)?
+        } else {
+            None
+        };
+        if let Some((key, table_snapshots)) = cache_key {
             let kv_store = UserApiProvider::instance().get_meta_store_client();
             let cache_reader = ResultCacheReader::create(
                 self.ctx.clone(),
                 &key,
-                kv_store.clone(),
+                table_snapshots,
+                kv_store,
                 self.ctx
                     .get_settings()
                     .get_query_result_cache_allow_inconsistent()?,
@@ -214,6 +239,8 @@ impl ExplainInterpreter {
             }
         }
 
+        let mut builder = PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone(), true);
+        let plan = builder.build(s_expr, bind_context.column_set()).await?;
         let result = plan
             .format(metadata.clone(), SharedProcessorProfiles::default())?
             .format_pretty()?;
@@ -222,6 +249,57 @@ impl ExplainInterpreter {
         Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
     }
 
+    /// The estimated rows, and the rows, bytes and partitions to read of each table scan,
+    /// so the queries can be checked before they run.
+    pub async fn explain_estimate(
+        &self,
+        s_expr: &SExpr,
+        metadata: &MetadataRef,
+        bind_context: &BindContext,
+    ) -> Result<Vec<DataBlock>> {
+        let mut builder = PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone(), true);
+        let plan = builder.build(s_expr, bind_context.column_set()).await?;
+
+        let scans = collect_table_scans(&plan);
+        let metadata = metadata.read();
+        let mut tables = Vec::with_capacity(scans.len());
+        let mut estimated_rows = Vec::with_capacity(scans.len());
+        let mut read_rows = Vec::with_capacity(scans.len());
+        let mut read_bytes = Vec::with_capacity(scans.len());
+        let mut partitions_total = Vec::with_capacity(scans.len());
+        let mut partitions_scanned = Vec::with_capacity(scans.len());
+        for scan in scans {
+            let table = metadata.table(scan.table_index);
+            let statistics = &scan.source.statistics;
+            tables.push(format!(
+                "{}.{}.{}",
+                table.catalog(),
+                table.database(),
+                table.name()
+            ));
+            estimated_rows.push(
+                scan.stat_info
+                    .as_ref()
+                    .map_or(statistics.read_rows as u64, |info| {
+                        info.estimated_rows.round() as u64
+                    }),
+            );
+            read_rows.push(statistics.read_rows as u64);
+            read_bytes.push(statistics.read_bytes as u64);
+            partitions_total.push(statistics.partitions_total as u64);
+            partitions_scanned.push(statistics.partitions_scanned as u64);
+        }
+
+        Ok(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(tables),
+            UInt64Type::from_data(estimated_rows),
+            UInt64Type::from_data(read_rows),
+            UInt64Type::from_data(read_bytes),
+            UInt64Type::from_data(partitions_total),
+            UInt64Type::from_data(partitions_scanned),
+        ])])
+    }
+
     pub fn explain_join_order(
         &self,
         plan: &PhysicalPlan,
@@ -17,13 +17,19 @@ use std::sync::Arc;
 use common_ast::ast::ExplainKind;
 use common_exception::Result;
 use log::error;
+use sha2::Digest;
+use sha2::Sha256;
 
 use super::interpreter_catalog_create::CreateCatalogInterpreter;
 use super::interpreter_catalog_show_create::ShowCreateCatalogInterpreter;
+use super::interpreter_comment::CommentInterpreter;
 use super::interpreter_index_create::CreateIndexInterpreter;
 use super::interpreter_index_drop::DropIndexInterpreter;
+use super::interpreter_inverted_index_create::CreateInvertedIndexInterpreter;
+use super::interpreter_inverted_index_drop::DropInvertedIndexInterpreter;
 use super::interpreter_share_desc::DescShareInterpreter;
 use super::interpreter_table_set_options::SetOptionsInterpreter;
+use super::interpreter_table_set_tags::SetTableTagsInterpreter;
 use super::interpreter_user_stage_drop::DropUserStageInterpreter;
 use super::*;
 use crate::interpreters::access::Accessor;
@@ -44,6 +50,7 @@ use crate::interpreters::DropUserInterpreter;
 use crate::interpreters::SetRoleInterpreter;
 use crate::interpreters::UpdateInterpreter;
 use crate::sessions::QueryContext;
+use crate::sessions::QueryHistory;
 use crate::sql::plans::Plan;
 
 /// InterpreterFactory is the entry of Interpreter.
@@ -54,6 +61,13 @@ pub struct InterpreterFactory;
 impl InterpreterFactory {
     #[async_backtrace::framed]
     pub async fn get(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
+        // The queries with the same plan share the plan hash in the query history.
+        if QueryHistory::instance().is_enabled() {
+            if let Ok(plan) = plan.format_indent() {
+                ctx.attach_plan_hash(format!("{:x}", Sha256::digest(plan.as_bytes())));
+            }
+        }
+
         // Check the access permission.
         let access_checker = Accessor::create(ctx.clone());
         access_checker.check(plan).await.map_err(|e| {
@@ -164,6 +178,14 @@ impl InterpreterFactory {
                 ctx,
                 *set_options.clone(),
             )?)),
+            Plan::SetTableTags(set_tags) => Ok(Arc::new(SetTableTagsInterpreter::try_create(
+                ctx,
+                *set_tags.clone(),
+            )?)),
+            Plan::Comment(comment) => Ok(Arc::new(CommentInterpreter::try_create(
+                ctx,
+                *comment.clone(),
+            )?)),
             Plan::RenameTableColumn(rename_table_column) => Ok(Arc::new(
                 RenameTableColumnInterpreter::try_create(ctx, *rename_table_column.clone())?,
             )),
@@ -182,6 +204,9 @@ impl InterpreterFactory {
             Plan::DropTableClusterKey(drop_table_cluster_key) => Ok(Arc::new(
                 DropTableClusterKeyInterpreter::try_create(ctx, *drop_table_cluster_key.clone())?,
             )),
+            Plan::DropTablePartition(drop_table_partition) => Ok(Arc::new(
+                DropTablePartitionInterpreter::try_create(ctx, *drop_table_partition.clone())?,
+            )),
             Plan::ReclusterTable(recluster_table) => Ok(Arc::new(
                 ReclusterTableInterpreter::try_create(ctx, *recluster_table.clone())?,
             )),
@@ -221,6 +246,27 @@ impl InterpreterFactory {
                 *drop_view.clone(),
             )?)),
 
+            // Materialized views
+            Plan::CreateMaterializedView(create_view) => Ok(Arc::new(
+                CreateMaterializedViewInterpreter::try_create(ctx, *create_view.clone())?,
+            )),
+            Plan::RefreshMaterializedView(refresh_view) => Ok(Arc::new(
+                RefreshMaterializedViewInterpreter::try_create(ctx, *refresh_view.clone())?,
+            )),
+            Plan::DropMaterializedView(drop_view) => Ok(Arc::new(
+                DropMaterializedViewInterpreter::try_create(ctx, *drop_view.clone())?,
+            )),
+
+            // Streams
+            Plan::CreateStream(create_stream) => Ok(Arc::new(CreateStreamInterpreter::try_create(
+                ctx,
+                *create_stream.clone(),
+            )?)),
+            Plan::DropStream(drop_stream) => Ok(Arc::new(DropStreamInterpreter::try_create(
+                ctx,
+                *drop_stream.clone(),
+            )?)),
+
             // Indexes
             Plan::CreateIndex(index) => Ok(Arc::new(CreateIndexInterpreter::try_create(
                 ctx,
@@ -235,6 +281,12 @@ impl InterpreterFactory {
                 ctx,
                 *index.clone(),
             )?)),
+            Plan::CreateInvertedIndex(index) => Ok(Arc::new(
+                CreateInvertedIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
+            Plan::DropInvertedIndex(index) => Ok(Arc::new(
+                DropInvertedIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
             // Virtual columns
             Plan::CreateVirtualColumn(create_virtual_column) => Ok(Arc::new(
                 CreateVirtualColumnInterpreter::try_create(ctx, *create_virtual_column.clone())?,
@@ -438,6 +490,39 @@ impl InterpreterFactory {
             Plan::ShowNetworkPolicies(_) => {
                 Ok(Arc::new(ShowNetworkPoliciesInterpreter::try_create(ctx)?))
             }
+
+            Plan::CreateStorageProfile(p) => Ok(Arc::new(
+                CreateStorageProfileInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropStorageProfile(p) => Ok(Arc::new(DropStorageProfileInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DescStorageProfile(p) => Ok(Arc::new(DescStorageProfileInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::ShowStorageProfiles(_) => {
+                Ok(Arc::new(ShowStorageProfilesInterpreter::try_create(ctx)?))
+            }
+
+            Plan::CreatePipe(p) => Ok(Arc::new(CreatePipeInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropPipe(p) => Ok(Arc::new(DropPipeInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DescPipe(p) => Ok(Arc::new(DescPipeInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::ShowPipes(_) => Ok(Arc::new(ShowPipesInterpreter::try_create(ctx)?)),
+
+            Plan::CreateSequence(p) => Ok(Arc::new(CreateSequenceInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropSequence(p) => Ok(Arc::new(DropSequenceInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::ShowSequences(_) => Ok(Arc::new(ShowSequencesInterpreter::try_create(ctx)?)),
         }
     }
 }
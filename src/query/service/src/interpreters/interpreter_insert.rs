@@ -14,6 +14,7 @@
 
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Instant;
 
 use common_catalog::table::AppendMode;
 use common_catalog::table::Table;
@@ -32,7 +33,10 @@ use common_sql::plans::Plan;
 use common_sql::NameResolutionContext;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::hook_background_compact;
 use crate::interpreters::common::hook_refresh_agg_index;
+use crate::interpreters::common::CompactHookTraceCtx;
+use crate::interpreters::common::CompactTargetTableDescription;
 use crate::interpreters::common::RefreshAggIndexDesc;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
@@ -71,6 +75,22 @@ impl InsertInterpreter {
         let cast_needed = select_schema != DataSchema::from(output_schema.as_ref()).into();
         Ok(cast_needed)
     }
+
+    #[async_backtrace::framed]
+    async fn hook_compact(&self, start: Instant, pipeline: &mut Pipeline) {
+        let compact_target = CompactTargetTableDescription {
+            catalog: self.plan.catalog.clone(),
+            database: self.plan.database.clone(),
+            table: self.plan.table.clone(),
+        };
+
+        let trace_ctx = CompactHookTraceCtx {
+            start,
+            operation_name: "insert_into".to_owned(),
+        };
+
+        hook_background_compact(self.ctx.clone(), pipeline, compact_target, trace_ctx).await;
+    }
 }
 
 #[async_trait::async_trait]
@@ -81,6 +101,7 @@ impl Interpreter for InsertInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let start = Instant::now();
         if check_deduplicate_label(self.ctx.clone()).await? {
             return Ok(PipelineBuildResult::create());
         }
@@ -246,6 +267,8 @@ impl Interpreter for InsertInterpreter {
                 )
                 .await?;
 
+                self.hook_compact(start, &mut build_res.main_pipeline).await;
+
                 return Ok(build_res);
             }
         };
@@ -274,6 +297,8 @@ impl Interpreter for InsertInterpreter {
         )
         .await?;
 
+        self.hook_compact(start, &mut build_res.main_pipeline).await;
+
         Ok(build_res)
     }
 }
@@ -0,0 +1,103 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::CreateInvertedIndexPlan;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_NAME;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_TOKENIZER;
+
+use super::interpreter_table_create::is_valid_inverted_index_options;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateInvertedIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateInvertedIndexPlan,
+}
+
+impl CreateInvertedIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateInvertedIndexPlan) -> Result<Self> {
+        Ok(CreateInvertedIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateInvertedIndexInterpreter {
+    fn name(&self) -> &str {
+        "CreateInvertedIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = self
+            .ctx
+            .get_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
+            .await?;
+
+        // A table has at most one inverted index.
+        let table_options = &table.get_table_info().meta.options;
+        if let Some(name) = table_options.get(OPT_KEY_INVERTED_INDEX_NAME) {
+            if self.plan.if_not_exists {
+                return Ok(PipelineBuildResult::create());
+            }
+            return Err(ErrorCode::IndexAlreadyExists(format!(
+                "Inverted index `{}` already exists on table `{}`.`{}`",
+                name, self.plan.database, self.plan.table
+            )));
+        }
+
+        let mut new_options = BTreeMap::new();
+        new_options.insert(
+            OPT_KEY_INVERTED_INDEX_NAME.to_string(),
+            self.plan.index_name.clone(),
+        );
+        new_options.insert(
+            OPT_KEY_INVERTED_INDEX_COLUMNS.to_string(),
+            self.plan.columns.join(","),
+        );
+        new_options.insert(
+            OPT_KEY_INVERTED_INDEX_TOKENIZER.to_string(),
+            self.plan.tokenizer.to_string(),
+        );
+        // check inverted_index_columns and inverted_index_tokenizer.
+        is_valid_inverted_index_options(&new_options, table.schema())?;
+
+        let options: HashMap<String, Option<String>> = new_options
+            .into_iter()
+            .map(|(k, v)| (k, Some(v)))
+            .collect();
+        let req = UpsertTableOptionReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+            options,
+        };
+
+        catalog
+            .upsert_table_option(tenant.as_str(), &self.plan.database, req)
+            .await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::DropInvertedIndexPlan;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_NAME;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_TOKENIZER;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropInvertedIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropInvertedIndexPlan,
+}
+
+impl DropInvertedIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropInvertedIndexPlan) -> Result<Self> {
+        Ok(DropInvertedIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropInvertedIndexInterpreter {
+    fn name(&self) -> &str {
+        "DropInvertedIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = self
+            .ctx
+            .get_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
+            .await?;
+
+        let table_options = &table.get_table_info().meta.options;
+        if table_options.get(OPT_KEY_INVERTED_INDEX_NAME) != Some(&self.plan.index_name) {
+            if self.plan.if_exists {
+                return Ok(PipelineBuildResult::create());
+            }
+            return Err(ErrorCode::UnknownIndex(format!(
+                "Unknown inverted index `{}` on table `{}`.`{}`",
+                self.plan.index_name, self.plan.database, self.plan.table
+            )));
+        }
+
+        // Existing term filters are left in the bloom index files,
+        // they are no longer consulted once the options are removed.
+        let options = HashMap::from([
+            (OPT_KEY_INVERTED_INDEX_NAME.to_string(), None),
+            (OPT_KEY_INVERTED_INDEX_COLUMNS.to_string(), None),
+            (OPT_KEY_INVERTED_INDEX_TOKENIZER.to_string(), None),
+        ]);
+        let req = UpsertTableOptionReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+            options,
+        };
+
+        catalog
+            .upsert_table_option(tenant.as_str(), &self.plan.database, req)
+            .await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
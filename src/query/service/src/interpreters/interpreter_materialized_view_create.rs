@@ -0,0 +1,158 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_sql::plans::CreateMaterializedViewPlan;
+use common_sql::plans::CreateStreamPlan;
+use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_AGGREGATES;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_OWNER;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_STREAMS;
+
+use crate::interpreters::common::build_refresh_materialized_view;
+use crate::interpreters::common::materialized_view_stream_name;
+use crate::interpreters::CreateStreamInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateMaterializedViewPlan,
+}
+
+impl CreateMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateMaterializedViewPlan) -> Result<Self> {
+        Ok(CreateMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "CreateMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        if catalog
+            .exists_table(tenant.as_str(), &self.plan.database, &self.plan.view_name)
+            .await?
+        {
+            return if self.plan.if_not_exists {
+                Ok(PipelineBuildResult::create())
+            } else {
+                Err(ErrorCode::TableAlreadyExists(format!(
+                    "Materialized view `{}`.`{}` already exists",
+                    self.plan.database, self.plan.view_name
+                )))
+            };
+        }
+
+        // Each source table is tracked by a stream, which tells the changes of the table since
+        // the last refresh of the view.
+        let mut stream_names = Vec::with_capacity(self.plan.source_tables.len());
+        for (table_database, table_name) in &self.plan.source_tables {
+            let table = catalog
+                .get_table(tenant.as_str(), table_database, table_name)
+                .await?;
+            let stream_name = materialized_view_stream_name(&self.plan.view_name, table.get_id());
+            let plan = CreateStreamPlan {
+                if_not_exists: true,
+                tenant: self.plan.tenant.clone(),
+                catalog: self.plan.catalog.clone(),
+                database: self.plan.database.clone(),
+                stream_name: stream_name.clone(),
+                table_database: table_database.clone(),
+                table_name: table_name.clone(),
+                comment: Some(format!(
+                    "changes of `{}`.`{}` for materialized view `{}`",
+                    table_database, table_name, self.plan.view_name
+                )),
+            };
+            CreateStreamInterpreter::try_create(self.ctx.clone(), plan)?
+                .execute2()
+                .await?;
+            stream_names.push(stream_name);
+        }
+
+        let db = catalog
+            .get_database(tenant.as_str(), &self.plan.database)
+            .await?;
+        let mut options = BTreeMap::new();
+        options.insert(
+            OPT_KEY_DATABASE_ID.to_string(),
+            db.get_db_info().ident.db_id.to_string(),
+        );
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_QUERY.to_string(),
+            self.plan.subquery.clone(),
+        );
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_STREAMS.to_string(),
+            stream_names.join(","),
+        );
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL.to_string(),
+            self.plan.incremental.to_string(),
+        );
+        if let Some(aggregates) = &self.plan.aggregates {
+            options.insert(
+                OPT_KEY_MATERIALIZED_VIEW_AGGREGATES.to_string(),
+                aggregates.join(","),
+            );
+        }
+        let owner = self.ctx.get_current_user()?.identity();
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_OWNER.to_string(),
+            serde_json::to_string(&owner)?,
+        );
+        let req = CreateTableReq {
+            if_not_exists: self.plan.if_not_exists,
+            name_ident: TableNameIdent {
+                tenant: self.plan.tenant.clone(),
+                db_name: self.plan.database.clone(),
+                table_name: self.plan.view_name.clone(),
+            },
+            table_meta: TableMeta {
+                schema: self.plan.schema.clone(),
+                engine: "FUSE".to_string(),
+                options,
+                ..Default::default()
+            },
+        };
+        catalog.create_table(req).await?;
+
+        build_refresh_materialized_view(
+            self.ctx.clone(),
+            &self.plan.catalog,
+            &self.plan.database,
+            &self.plan.view_name,
+            true,
+        )
+        .await
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::DropTableByIdReq;
+use common_sql::plans::DropMaterializedViewPlan;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_STREAMS;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropMaterializedViewPlan,
+}
+
+impl DropMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropMaterializedViewPlan) -> Result<Self> {
+        Ok(DropMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "DropMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let view_name = self.plan.view_name.as_str();
+        let tbl = self
+            .ctx
+            .get_table(catalog_name, db_name, view_name)
+            .await
+            .ok();
+
+        if tbl.is_none() && !self.plan.if_exists {
+            return Err(ErrorCode::UnknownTable(format!(
+                "Unknown materialized view `{}`.`{}` in catalog '{}'",
+                db_name, view_name, catalog_name
+            )));
+        }
+
+        if let Some(table) = &tbl {
+            let Some(stream_names) = table.options().get(OPT_KEY_MATERIALIZED_VIEW_STREAMS) else {
+                return Err(ErrorCode::TableEngineNotSupported(format!(
+                    "{}.{} is not a materialized view, please use `DROP TABLE {}.{}`",
+                    db_name, view_name, db_name, view_name
+                )));
+            };
+
+            let catalog = self.ctx.get_catalog(catalog_name).await?;
+            let tenant = self.ctx.get_tenant();
+            for stream_name in stream_names.split(',').filter(|name| !name.is_empty()) {
+                if let Ok(stream) = catalog
+                    .get_table(tenant.as_str(), db_name, stream_name)
+                    .await
+                {
+                    catalog
+                        .drop_table_by_id(DropTableByIdReq {
+                            if_exists: true,
+                            tb_id: stream.get_id(),
+                        })
+                        .await?;
+                }
+            }
+            catalog
+                .drop_table_by_id(DropTableByIdReq {
+                    if_exists: self.plan.if_exists,
+                    tb_id: table.get_id(),
+                })
+                .await?;
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
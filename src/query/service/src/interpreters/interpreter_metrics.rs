@@ -44,6 +44,12 @@ const QUERY_SCAN_PARTITIONS: &str = "query_scan_partitions";
 const QUERY_TOTAL_PARTITIONS: &str = "query_total_partitions";
 const QUERY_RESULT_ROWS: &str = "query_result_rows";
 const QUERY_RESULT_BYTES: &str = "query_result_bytes";
+const QUERY_SPILL_BYTES: &str = "query_spill_bytes";
+
+// Aggregated per query into histograms, the buckets are set by the suffix of the name.
+const QUERY_SCAN_BYTES_PER_QUERY: &str = "query_scan_bytes_per_query";
+const QUERY_SPILL_BYTES_PER_QUERY: &str = "query_spill_bytes_per_query";
+const QUERY_PARTITIONS_PRUNED_RATIO_PER_QUERY: &str = "query_partitions_pruned_ratio_per_query";
 
 const LABEL_HANDLER: &str = "handler";
 const LABEL_KIND: &str = "kind";
@@ -89,6 +95,8 @@ impl InterpreterMetrics {
         let result_rows = ctx.get_result_progress_value().rows as u64;
         let result_bytes = ctx.get_result_progress_value().bytes as u64;
 
+        let spill_bytes = ctx.get_spill_metrics().get_write_bytes() as u64;
+
         label_histogram_with_val(QUERY_DURATION_MS, labels, query_duration_ms);
 
         label_counter_with_val_and_labels(QUERY_WRITE_ROWS, labels, written_rows);
@@ -117,6 +125,20 @@ impl InterpreterMetrics {
         label_counter_with_val_and_labels(QUERY_TOTAL_PARTITIONS, labels, total_partitions);
         label_counter_with_val_and_labels(QUERY_RESULT_ROWS, labels, result_rows);
         label_counter_with_val_and_labels(QUERY_RESULT_BYTES, labels, result_bytes);
+        label_counter_with_val_and_labels(QUERY_SPILL_BYTES, labels, spill_bytes);
+
+        label_histogram_with_val(QUERY_SCAN_BYTES_PER_QUERY, labels, scan_bytes as f64);
+        if spill_bytes > 0 {
+            label_histogram_with_val(QUERY_SPILL_BYTES_PER_QUERY, labels, spill_bytes as f64);
+        }
+        if total_partitions > 0 {
+            let pruned_ratio = 1.0 - scan_partitions as f64 / total_partitions as f64;
+            label_histogram_with_val(
+                QUERY_PARTITIONS_PRUNED_RATIO_PER_QUERY,
+                labels,
+                pruned_ratio,
+            );
+        }
     }
 
     pub fn record_query_start(ctx: &QueryContext) {
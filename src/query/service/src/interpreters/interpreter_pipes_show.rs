@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::NumberType;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_meta_app::principal::PipeInfo;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct ShowPipesInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl ShowPipesInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(ShowPipesInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowPipesInterpreter {
+    fn name(&self) -> &str {
+        "ShowPipesInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+        let mut pipes = user_mgr.get_pipes(&tenant).await?;
+        pipes.sort_by(|a, b| a.name.cmp(&b.name));
+
+        PipelineBuildResult::from_blocks(vec![pipes_to_block(pipes)])
+    }
+}
+
+pub(crate) fn pipes_to_block(pipes: Vec<PipeInfo>) -> DataBlock {
+    let mut names = Vec::with_capacity(pipes.len());
+    let mut tables = Vec::with_capacity(pipes.len());
+    let mut sources = Vec::with_capacity(pipes.len());
+    let mut file_formats = Vec::with_capacity(pipes.len());
+    let mut batch_sizes = Vec::with_capacity(pipes.len());
+    let mut owners = Vec::with_capacity(pipes.len());
+    let mut comments = Vec::with_capacity(pipes.len());
+    for pipe in pipes {
+        names.push(pipe.name.as_bytes().to_vec());
+        tables.push(format!("{}.{}", pipe.database, pipe.table).into_bytes());
+        sources.push(pipe.source.to_string().into_bytes());
+        file_formats.push(pipe.file_format_params.get_type().to_string().into_bytes());
+        batch_sizes.push(pipe.batch_size);
+        owners.push(pipe.owner.to_string().into_bytes());
+        comments.push(pipe.comment.as_bytes().to_vec());
+    }
+
+    DataBlock::new_from_columns(vec![
+        StringType::from_data(names),
+        StringType::from_data(tables),
+        StringType::from_data(sources),
+        StringType::from_data(file_formats),
+        NumberType::<u64>::from_data(batch_sizes),
+        StringType::from_data(owners),
+        StringType::from_data(comments),
+    ])
+}
@@ -29,6 +29,7 @@ use log::info;
 use serde_json;
 
 use crate::sessions::QueryContext;
+use crate::sessions::QueryHistory;
 use crate::sessions::TableContext;
 
 pub struct InterpreterQueryLog;
@@ -57,9 +58,10 @@ fn error_fields(log_type: LogType, err: Option<ErrorCode>) -> (LogType, i32, Str
 }
 
 impl InterpreterQueryLog {
-    fn write_log(event: QueryLogElement) -> Result<()> {
+    fn write_log(ctx: &QueryContext, event: QueryLogElement) -> Result<()> {
         let event_str = serde_json::to_string(&event)?;
         info!(target: "query", "{}", event_str);
+        QueryHistory::instance().append(&event, ctx.get_plan_hash());
         QueryLogQueue::instance()?.append_data(event)
     }
 
@@ -126,7 +128,7 @@ impl InterpreterQueryLog {
         let (log_type, exception_code, exception_text, stack_trace) =
             error_fields(LogType::Start, err);
 
-        Self::write_log(QueryLogElement {
+        Self::write_log(ctx, QueryLogElement {
             log_type,
             handler_type,
             tenant_id,
@@ -241,7 +243,7 @@ impl InterpreterQueryLog {
         let (log_type, exception_code, exception_text, stack_trace) =
             error_fields(LogType::Finish, err);
 
-        Self::write_log(QueryLogElement {
+        Self::write_log(ctx, QueryLogElement {
             log_type,
             handler_type,
             tenant_id,
@@ -31,12 +31,13 @@ use common_sql::executor::PhysicalPlan;
 use common_sql::parse_result_scan_args;
 use common_sql::ColumnBinding;
 use common_sql::MetadataRef;
-use common_storages_result_cache::gen_result_cache_key;
 use common_storages_result_cache::ResultCacheReader;
 use common_storages_result_cache::WriteResultCacheSink;
 use common_users::UserApiProvider;
 use log::error;
 
+use crate::interpreters::common::check_scan_budget;
+use crate::interpreters::common::gen_query_result_cache_key;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::schedulers::build_query_pipeline;
@@ -88,9 +89,11 @@ impl SelectInterpreter {
     pub async fn build_physical_plan(&self) -> Result<PhysicalPlan> {
         let mut builder = PhysicalPlanBuilder::new(self.metadata.clone(), self.ctx.clone(), false);
         self.ctx.set_status_info("building physical plan");
-        builder
+        let physical_plan = builder
             .build(&self.s_expr, self.bind_context.column_set())
-            .await
+            .await?;
+        check_scan_budget(&self.ctx, &physical_plan, &self.metadata)?;
+        Ok(physical_plan)
     }
 
     #[async_backtrace::framed]
@@ -118,6 +121,7 @@ impl SelectInterpreter {
     fn add_result_cache(
         &self,
         key: &str,
+        table_snapshots: Vec<String>,
         schema: TableSchemaRef,
         pipeline: &mut Pipeline,
         kv_store: Arc<MetaStore>,
@@ -171,6 +175,7 @@ impl SelectInterpreter {
             WriteResultCacheSink::try_create(
                 self.ctx.clone(),
                 key,
+                table_snapshots,
                 schema,
                 sink_inputs.clone(),
                 kv_store,
@@ -215,10 +220,11 @@ impl Interpreter for SelectInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         self.ctx.set_status_info("preparing plan");
 
-        // 0. Need to build physical plan first to get the partitions.
-        let physical_plan = self.build_physical_plan().await?;
-        if self.ctx.get_settings().get_enable_query_result_cache()? && self.ctx.get_cacheable() {
-            let key = gen_result_cache_key(self.formatted_ast.as_ref().unwrap());
+        // `formatted_ast` is only set for the queries bound with query result cache enabled.
+        if self.formatted_ast.is_some()
+            && self.ctx.get_settings().get_enable_query_result_cache()?
+            && self.ctx.get_cacheable()
+        {
             // 1. Try to get result from cache.
             let kv_store = UserApiProvider::instance().get_meta_store_client();
 
@@ -239,41 +245,64 @@ impl Interpreter for SelectInterpreter {
                     self.ctx
                         .set_query_id_result_cache(self.ctx.get_id(), meta_key);
                 }
+                let physical_plan = self.build_physical_plan().await?;
                 return self.build_pipeline(physical_plan).await;
             }
 
-            let cache_reader = ResultCacheReader::create(
-                self.ctx.clone(),
-                &key,
-                kv_store.clone(),
-                self.ctx
-                    .get_settings()
-                    .get_query_result_cache_allow_inconsistent()?,
-            );
-
-            // 2. Check the cache.
-            match cache_reader.try_read_cached_result().await {
-                Ok(Some(blocks)) => {
-                    // 2.0 update query_id -> result_cache_meta_key in session.
+            // The key is checked before building the physical plan, so the cached result
+            // is returned without reading the partitions of the tables.
+            if let Some((key, table_snapshots)) = gen_query_result_cache_key(
+                &self.ctx,
+                &self.s_expr,
+                &self.bind_context,
+                &self.metadata,
+            )? {
+                let cache_reader = ResultCacheReader::create(
+                    self.ctx.clone(),
+                    &key,
+                    table_snapshots.clone(),
+                    kv_store.clone(),
                     self.ctx
-                        .set_query_id_result_cache(self.ctx.get_id(), cache_reader.get_meta_key());
-                    // 2.1 If found, return the result directly.
-                    return PipelineBuildResult::from_blocks(blocks);
-                }
-                Ok(None) => {
-                    let mut build_res = self.build_pipeline(physical_plan).await?;
-                    // 2.2 If not found result in cache, add pipelines to write the result to cache.
-                    let schema = infer_table_schema(&self.bind_context.output_schema())?;
-                    self.add_result_cache(&key, schema, &mut build_res.main_pipeline, kv_store)?;
-                    return Ok(build_res);
-                }
-                Err(e) => {
-                    // 2.3 If an error occurs, turn back to the normal pipeline.
-                    error!("Failed to read query result cache. {}", e);
+                        .get_settings()
+                        .get_query_result_cache_allow_inconsistent()?,
+                );
+
+                // 2. Check the cache.
+                match cache_reader.try_read_cached_result().await {
+                    Ok(Some(blocks)) => {
+                        // 2.0 update query_id -> result_cache_meta_key in session.
+                        self.ctx.set_query_id_result_cache(
+                            self.ctx.get_id(),
+                            cache_reader.get_meta_key(),
+                        );
+                        // 2.1 If found, return the result directly.
+                        return PipelineBuildResult::from_blocks(blocks);
+                    }
+                    Ok(None) => {
+                        let physical_plan = self.build_physical_plan().await?;
+                        let mut build_res = self.build_pipeline(physical_plan).await?;
+                        // 2.2 If not found result in cache, add pipelines to write the result to cache.
+                        if self.ctx.get_cacheable() {
+                            let schema = infer_table_schema(&self.bind_context.output_schema())?;
+                            self.add_result_cache(
+                                &key,
+                                table_snapshots,
+                                schema,
+                                &mut build_res.main_pipeline,
+                                kv_store,
+                            )?;
+                        }
+                        return Ok(build_res);
+                    }
+                    Err(e) => {
+                        // 2.3 If an error occurs, turn back to the normal pipeline.
+                        error!("Failed to read query result cache. {}", e);
+                    }
                 }
             }
         }
         // Not use query cache.
+        let physical_plan = self.build_physical_plan().await?;
         self.build_pipeline(physical_plan).await
     }
 }
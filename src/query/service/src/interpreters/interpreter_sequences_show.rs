@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::NumberType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct ShowSequencesInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl ShowSequencesInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(ShowSequencesInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowSequencesInterpreter {
+    fn name(&self) -> &str {
+        "ShowSequencesInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+        let mut sequences = user_mgr.get_sequences(&tenant).await?;
+        sequences.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut names = Vec::with_capacity(sequences.len());
+        let mut starts = Vec::with_capacity(sequences.len());
+        let mut increments = Vec::with_capacity(sequences.len());
+        let mut next_values = Vec::with_capacity(sequences.len());
+        let mut created_ons = Vec::with_capacity(sequences.len());
+        let mut comments = Vec::with_capacity(sequences.len());
+        for sequence in sequences {
+            names.push(sequence.name.into_bytes());
+            starts.push(sequence.start);
+            increments.push(sequence.increment);
+            // The numbers cached by the query nodes are allocated already.
+            next_values.push(sequence.next_value);
+            created_ons.push(sequence.created_on.timestamp_micros());
+            comments.push(sequence.comment.into_bytes());
+        }
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            NumberType::<i64>::from_data(starts),
+            NumberType::<i64>::from_data(increments),
+            NumberType::<i64>::from_data(next_values),
+            TimestampType::from_data(created_ons),
+            StringType::from_data(comments),
+        ])])
+    }
+}
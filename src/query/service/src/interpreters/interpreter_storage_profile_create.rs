@@ -0,0 +1,67 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_exception::Result;
+use common_meta_app::storage::StorageProfile;
+use common_sql::plans::CreateStorageProfilePlan;
+use common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CreateStorageProfileInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateStorageProfilePlan,
+}
+
+impl CreateStorageProfileInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateStorageProfilePlan) -> Result<Self> {
+        Ok(CreateStorageProfileInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateStorageProfileInterpreter {
+    fn name(&self) -> &str {
+        "CreateStorageProfileInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "create_storage_profile_execute");
+
+        let plan = self.plan.clone();
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+
+        let storage_profile = StorageProfile {
+            name: plan.name,
+            storage_params: plan.storage_params,
+            comment: plan.comment,
+            create_on: Utc::now(),
+        };
+        user_mgr
+            .add_storage_profile(&tenant, storage_profile, plan.if_not_exists)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
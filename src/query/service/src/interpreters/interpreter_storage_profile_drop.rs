@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::plans::DropStorageProfilePlan;
+use common_users::UserApiProvider;
+use log::debug;
+use storages_common_table_meta::table::OPT_KEY_STORAGE_PROFILE;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DropStorageProfileInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropStorageProfilePlan,
+}
+
+impl DropStorageProfileInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropStorageProfilePlan) -> Result<Self> {
+        Ok(DropStorageProfileInterpreter { ctx, plan })
+    }
+
+    /// The tables bound to a storage profile can't be loaded without it, the dropped ones
+    /// are skipped by the drop history once the profile is dropped.
+    #[async_backtrace::framed]
+    async fn check_not_in_use(&self, tenant: &str) -> Result<()> {
+        let catalog = self.ctx.get_catalog(CATALOG_DEFAULT).await?;
+        for database in catalog.list_databases(tenant).await? {
+            for table in catalog.list_tables(tenant, database.name()).await? {
+                if table.options().get(OPT_KEY_STORAGE_PROFILE) == Some(&self.plan.name) {
+                    return Err(ErrorCode::IllegalStorageProfile(format!(
+                        "Storage profile {} is still used by table {}.{}",
+                        self.plan.name,
+                        database.name(),
+                        table.name()
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropStorageProfileInterpreter {
+    fn name(&self) -> &str {
+        "DropStorageProfileInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "drop_storage_profile_execute");
+
+        let plan = self.plan.clone();
+        let tenant = self.ctx.get_tenant();
+
+        let user_mgr = UserApiProvider::instance();
+        if user_mgr
+            .exists_storage_profile(&tenant, plan.name.as_str())
+            .await?
+        {
+            self.check_not_in_use(&tenant).await?;
+        }
+        user_mgr
+            .drop_storage_profile(&tenant, plan.name.as_str(), plan.if_exists)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
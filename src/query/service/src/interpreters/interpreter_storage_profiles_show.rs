@@ -0,0 +1,65 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct ShowStorageProfilesInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl ShowStorageProfilesInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(ShowStorageProfilesInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowStorageProfilesInterpreter {
+    fn name(&self) -> &str {
+        "ShowStorageProfilesInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+        let storage_profiles = user_mgr.get_storage_profiles(&tenant).await?;
+
+        let mut names = Vec::with_capacity(storage_profiles.len());
+        let mut storage_params = Vec::with_capacity(storage_profiles.len());
+        let mut comments = Vec::with_capacity(storage_profiles.len());
+        for storage_profile in storage_profiles {
+            names.push(storage_profile.name.as_bytes().to_vec());
+            storage_params.push(storage_profile.storage_params.to_string().into_bytes());
+            comments.push(storage_profile.comment.as_bytes().to_vec());
+        }
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(storage_params),
+            StringType::from_data(comments),
+        ])])
+    }
+}
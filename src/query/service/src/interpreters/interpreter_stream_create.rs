@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_sql::plans::CreateStreamPlan;
+use common_storages_fuse::FuseTable;
+use common_storages_stream::stream_table::snapshot_id_from_location;
+use common_storages_stream::stream_table::OPT_KEY_OFFSET;
+use common_storages_stream::stream_table::OPT_KEY_TABLE_DATABASE;
+use common_storages_stream::stream_table::OPT_KEY_TABLE_ID;
+use common_storages_stream::stream_table::OPT_KEY_TABLE_NAME;
+use common_storages_stream::stream_table::STREAM_ENGINE;
+use storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateStreamInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateStreamPlan,
+}
+
+impl CreateStreamInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateStreamPlan) -> Result<Self> {
+        Ok(CreateStreamInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateStreamInterpreter {
+    fn name(&self) -> &str {
+        "CreateStreamInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(
+                tenant.as_str(),
+                &self.plan.table_database,
+                &self.plan.table_name,
+            )
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref()).map_err(|_| {
+            ErrorCode::IllegalStream(format!(
+                "Stream can only be created on FUSE tables, but `{}`.`{}` is of engine {}",
+                self.plan.table_database,
+                self.plan.table_name,
+                table.engine()
+            ))
+        })?;
+        let table_info = table.get_table_info();
+
+        // Deletes stop writing deletion vectors on the table from now on,
+        // which would hide the deleted rows from the stream.
+        if !fuse_table.change_tracking_enabled() {
+            let req = UpsertTableOptionReq::new(&table_info.ident, OPT_KEY_CHANGE_TRACKING, "true");
+            catalog
+                .upsert_table_option(tenant.as_str(), &self.plan.table_database, req)
+                .await?;
+        }
+
+        let offset = table_info
+            .options()
+            .get(OPT_KEY_SNAPSHOT_LOCATION)
+            .map(|location| snapshot_id_from_location(location))
+            .unwrap_or_default();
+        let mut options = BTreeMap::new();
+        options.insert(
+            OPT_KEY_TABLE_DATABASE.to_string(),
+            self.plan.table_database.clone(),
+        );
+        options.insert(OPT_KEY_TABLE_NAME.to_string(), self.plan.table_name.clone());
+        options.insert(
+            OPT_KEY_TABLE_ID.to_string(),
+            table_info.ident.table_id.to_string(),
+        );
+        options.insert(OPT_KEY_OFFSET.to_string(), offset);
+
+        let req = CreateTableReq {
+            if_not_exists: self.plan.if_not_exists,
+            name_ident: TableNameIdent {
+                tenant: self.plan.tenant.clone(),
+                db_name: self.plan.database.clone(),
+                table_name: self.plan.stream_name.clone(),
+            },
+            table_meta: TableMeta {
+                engine: STREAM_ENGINE.to_string(),
+                options,
+                comment: self.plan.comment.clone().unwrap_or_default(),
+                ..Default::default()
+            },
+        };
+        catalog.create_table(req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
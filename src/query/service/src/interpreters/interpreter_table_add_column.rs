@@ -111,6 +111,7 @@ impl Interpreter for AddTableColumnInterpreter {
                 new_table_meta,
                 copied_files: None,
                 deduplicated_label: None,
+                update_stream_meta: vec![],
             };
 
             let res = catalog.update_table_meta(table_info, req).await?;
@@ -16,6 +16,7 @@ use std::collections::BTreeMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use common_ast::ast::Engine;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -25,6 +26,7 @@ use common_expression::BLOCK_NAME_COL_NAME;
 use common_expression::ROW_ID_COL_NAME;
 use common_expression::SEGMENT_NAME_COL_NAME;
 use common_expression::SNAPSHOT_NAME_COL_NAME;
+use common_functions::scalars::Tokenizer;
 use common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use common_license::license::Feature::ComputedColumn;
 use common_license::license_manager::get_license_manager;
@@ -34,33 +36,67 @@ use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::TableStatistics;
 use common_meta_types::MatchSeq;
 use common_sql::field_default_value;
+use common_sql::plans::CloneTableSource;
 use common_sql::plans::CreateTablePlan;
 use common_sql::plans::PREDICATE_COLUMN_NAME;
 use common_sql::BloomIndexColumns;
 use common_storage::DataOperator;
+use common_storages_federated::FEDERATED_OPT_KEY_CONNECTION;
+use common_storages_federated::FEDERATED_OPT_KEY_REMOTE_TABLE;
 use common_storages_fuse::io::MetaReaders;
+use common_storages_fuse::io::TableEncryption;
+use common_storages_fuse::io::ENCRYPTION_ALGORITHM;
+use common_storages_fuse::operations::cloned_from_option;
+use common_storages_fuse::operations::get_cloned_from;
+use common_storages_fuse::operations::register_clone;
+use common_storages_fuse::FuseTable;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use common_storages_fuse::FUSE_OPT_KEY_COMPACT_VARIANT;
+use common_storages_fuse::FUSE_OPT_KEY_COMPRESSION_LEVEL;
+use common_storages_fuse::FUSE_OPT_KEY_ENABLE_DICTIONARY;
+use common_storages_fuse::FUSE_OPT_KEY_PAGE_SIZE;
+use common_storages_fuse::FUSE_OPT_KEY_PARQUET_BLOOM_FILTER_COLUMNS;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
 use common_storages_fuse::FUSE_TBL_LAST_SNAPSHOT_HINT;
+use common_storages_parquet::DeltaTable;
+use common_storages_parquet::ExternalTable;
+use common_storages_parquet::EXTERNAL_OPT_KEY_FILE_LIST_CACHE_TTL;
+use common_storages_parquet::EXTERNAL_OPT_KEY_PARTITION_COLUMNS;
+use common_storages_parquet::EXTERNAL_OPT_KEY_PATTERN;
 use common_users::UserApiProvider;
 use log::error;
 use once_cell::sync::Lazy;
+use storages_common_blocks::parquet_bloom_filter_supported_type;
 use storages_common_cache::LoadParams;
 use storages_common_index::BloomIndex;
+use storages_common_index::InvertedIndex;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::Versioned;
+use storages_common_table_meta::table::ClusterType;
+use storages_common_table_meta::table::TableConstraints;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_CLONED_FROM;
+use storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use storages_common_table_meta::table::OPT_KEY_COMMENT;
+use storages_common_table_meta::table::OPT_KEY_CONSTRAINTS;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_ENCRYPTION;
+use storages_common_table_meta::table::OPT_KEY_ENCRYPTION_KEYS;
 use storages_common_table_meta::table::OPT_KEY_ENGINE;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_TOKENIZER;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
+use storages_common_table_meta::table::OPT_KEY_STORAGE_PROFILE;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use storages_common_table_meta::table::OPT_KEY_TAGS;
 
+use crate::interpreters::common::check_data_size_quota;
 use crate::interpreters::InsertInterpreter;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -125,6 +161,7 @@ impl Interpreter for CreateTableInterpreter {
                 )));
             }
         }
+        check_data_size_quota(&catalog, &self.plan.tenant, &quota).await?;
 
         let engine_desc: Option<StorageDescription> = catalog
             .get_table_engines()
@@ -143,9 +180,12 @@ impl Interpreter for CreateTableInterpreter {
             }
         }
 
-        match &self.plan.as_select {
-            Some(select_plan_node) => self.create_table_as_select(select_plan_node.clone()).await,
-            None => self.create_table().await,
+        match (&self.plan.as_select, &self.plan.clone_from) {
+            (Some(select_plan_node), _) => {
+                self.create_table_as_select(select_plan_node.clone()).await
+            }
+            (None, Some(source)) => self.create_table_clone(source).await,
+            (None, None) => self.create_table().await,
         }
     }
 }
@@ -157,10 +197,13 @@ impl CreateTableInterpreter {
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
 
         // TODO: maybe the table creation and insertion should be a transaction, but it may require create_table support 2pc.
-        let reply = catalog.create_table(self.build_request(None)?).await?;
+        let reply = catalog
+            .create_table(self.build_request(None).await?)
+            .await?;
         if !reply.new_table {
             return Ok(PipelineBuildResult::create());
         }
+        self.create_identity_sequences().await?;
         let table = catalog
             .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
             .await?;
@@ -189,6 +232,63 @@ impl CreateTableInterpreter {
             .await
     }
 
+    /// `CREATE TABLE ... CLONE`, the new table shares the files of the source table.
+    #[async_backtrace::framed]
+    async fn create_table_clone(&self, source: &CloneTableSource) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let source_catalog = self.ctx.get_catalog(&source.catalog).await?;
+        let mut source_table = source_catalog
+            .get_table(tenant.as_str(), &source.database, &source.table)
+            .await?;
+        if let Some(point) = &source.navigation {
+            source_table = source_table.navigate_to(point).await?;
+        }
+
+        let mut req = self.build_request(None).await?;
+        // keep the column ids, they are referenced by the statistics of the shared blocks.
+        req.table_meta.schema = self.plan.schema.clone();
+        req.table_meta.options.insert(
+            OPT_KEY_CLONED_FROM.to_owned(),
+            cloned_from_option(source_table.get_table_info()),
+        );
+        // the shared blocks of an encrypted source table are only readable with its data keys.
+        if let Some(keys) = source_table.options().get(OPT_KEY_ENCRYPTION_KEYS) {
+            req.table_meta.options.insert(
+                OPT_KEY_ENCRYPTION.to_owned(),
+                ENCRYPTION_ALGORITHM.to_owned(),
+            );
+            req.table_meta
+                .options
+                .insert(OPT_KEY_ENCRYPTION_KEYS.to_owned(), keys.clone());
+        }
+
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let reply = catalog.create_table(req).await?;
+        if !reply.new_table {
+            return Ok(PipelineBuildResult::create());
+        }
+        let table = catalog
+            .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+            .await?;
+        // registered before the snapshot is shared, the purge of the source tables keeps the
+        // files referenced by the clone from then on.
+        register_clone(
+            self.ctx.as_ref(),
+            &source.catalog,
+            &get_cloned_from(table.get_table_info()),
+            table.get_id(),
+        )
+        .await?;
+        FuseTable::try_from_table(table.as_ref())?
+            .do_clone(
+                self.ctx.as_ref(),
+                FuseTable::try_from_table(source_table.as_ref())?,
+            )
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+
     #[async_backtrace::framed]
     async fn create_table(&self) -> Result<PipelineBuildResult> {
         let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
@@ -216,21 +316,53 @@ impl CreateTableInterpreter {
                 });
             }
         }
-        let req = if let Some(storage_prefix) = self.plan.options.get(OPT_KEY_STORAGE_PREFIX) {
+        let mut req = if let Some(storage_prefix) = self.plan.options.get(OPT_KEY_STORAGE_PREFIX) {
             self.build_attach_request(storage_prefix).await
         } else {
-            self.build_request(stat)
+            self.build_request(stat).await
         }?;
-        catalog.create_table(req).await?;
+        if self.plan.engine == Engine::External {
+            // Safe to unwrap, EXTERNAL table must have a location.
+            let storage_params = self.plan.storage_params.as_ref().unwrap();
+            req.table_meta.schema = ExternalTable::infer_schema(
+                storage_params,
+                &self.plan.options,
+                self.plan.schema.clone(),
+            )
+            .await?;
+        }
+        if self.plan.engine == Engine::Delta {
+            // Safe to unwrap, DELTA table must have a location.
+            let storage_params = self.plan.storage_params.as_ref().unwrap();
+            req.table_meta.schema =
+                DeltaTable::infer_schema(storage_params, self.plan.schema.clone()).await?;
+        }
+        let reply = catalog.create_table(req).await?;
+        if reply.new_table {
+            self.create_identity_sequences().await?;
+        }
 
         Ok(PipelineBuildResult::create())
     }
 
+    /// Creates the sequences filling the identity columns of the new table.
+    #[async_backtrace::framed]
+    async fn create_identity_sequences(&self) -> Result<()> {
+        let user_api = UserApiProvider::instance();
+        for sequence in self.plan.identity_sequences.iter() {
+            user_api
+                .add_sequence(&self.plan.tenant, sequence.clone(), false)
+                .await?;
+        }
+        Ok(())
+    }
+
     /// Build CreateTableReq from CreateTablePlanV2.
     ///
     /// - Rebuild `DataSchema` with default exprs.
     /// - Update cluster key of table meta.
-    fn build_request(&self, statistics: Option<TableStatistics>) -> Result<CreateTableReq> {
+    #[async_backtrace::framed]
+    async fn build_request(&self, statistics: Option<TableStatistics>) -> Result<CreateTableReq> {
         let fields = self.plan.schema.fields().clone();
         for field in fields.iter() {
             if field.default_expr().is_some() {
@@ -259,8 +391,20 @@ impl CreateTableInterpreter {
 
         is_valid_block_per_segment(&table_meta.options)?;
         is_valid_row_per_block(&table_meta.options)?;
+        // check inverted_index_columns and inverted_index_tokenizer.
+        is_valid_inverted_index_options(&table_meta.options, schema.clone())?;
+        // check constraints.
+        is_valid_table_constraints(&table_meta.options, schema.clone())?;
         // check bloom_index_columns.
-        is_valid_bloom_index_columns(&table_meta.options, schema)?;
+        is_valid_bloom_index_columns(&table_meta.options, schema.clone())?;
+        // check parquet writer options.
+        is_valid_parquet_write_options(&table_meta.options, schema)?;
+        // check tags.
+        is_valid_table_tags(&table_meta.options)?;
+        // check cluster_type.
+        is_valid_cluster_type(&table_meta.options)?;
+        // check encryption.
+        is_valid_encryption(&table_meta.options, self.plan.engine)?;
 
         for table_option in table_meta.options.iter() {
             let key = table_option.0.to_lowercase();
@@ -272,6 +416,13 @@ impl CreateTableInterpreter {
             }
         }
 
+        if table_meta.options.contains_key(OPT_KEY_ENCRYPTION) {
+            table_meta.options.insert(
+                OPT_KEY_ENCRYPTION_KEYS.to_owned(),
+                TableEncryption::create().await?.to_option(),
+            );
+        }
+
         if let Some(cluster_key) = &self.plan.cluster_key {
             table_meta = table_meta.push_cluster_key(cluster_key.clone());
         }
@@ -354,12 +505,32 @@ pub static CREATE_TABLE_OPTIONS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     r.insert(FUSE_OPT_KEY_ROW_PER_BLOCK);
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
+    r.insert(FUSE_OPT_KEY_COMPRESSION_LEVEL);
+    r.insert(FUSE_OPT_KEY_ENABLE_DICTIONARY);
+    r.insert(FUSE_OPT_KEY_PAGE_SIZE);
+    r.insert(FUSE_OPT_KEY_PARQUET_BLOOM_FILTER_COLUMNS);
+    r.insert(FUSE_OPT_KEY_COMPACT_VARIANT);
+
+    r.insert(EXTERNAL_OPT_KEY_PARTITION_COLUMNS);
+    r.insert(EXTERNAL_OPT_KEY_PATTERN);
+    r.insert(EXTERNAL_OPT_KEY_FILE_LIST_CACHE_TTL);
+
+    r.insert(FEDERATED_OPT_KEY_CONNECTION);
+    r.insert(FEDERATED_OPT_KEY_REMOTE_TABLE);
 
     r.insert(OPT_KEY_BLOOM_INDEX_COLUMNS);
+    r.insert(OPT_KEY_CLUSTER_TYPE);
+    r.insert(OPT_KEY_CONSTRAINTS);
+    r.insert(OPT_KEY_INVERTED_INDEX_COLUMNS);
+    r.insert(OPT_KEY_INVERTED_INDEX_TOKENIZER);
+    r.insert(OPT_KEY_PARTITION_BY);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
     r.insert(OPT_KEY_STORAGE_FORMAT);
+    r.insert(OPT_KEY_STORAGE_PROFILE);
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_COMMENT);
+    r.insert(OPT_KEY_TAGS);
+    r.insert(OPT_KEY_ENCRYPTION);
 
     r.insert(OPT_KEY_ENGINE);
 
@@ -433,3 +604,126 @@ pub fn is_valid_bloom_index_columns(
     }
     Ok(())
 }
+
+pub fn is_valid_inverted_index_options(
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_INVERTED_INDEX_COLUMNS) {
+        BloomIndexColumns::verify_definition(value, schema, InvertedIndex::supported_type)?;
+    }
+    if let Some(value) = options.get(OPT_KEY_INVERTED_INDEX_TOKENIZER) {
+        value.parse::<Tokenizer>()?;
+    }
+    Ok(())
+}
+
+pub fn is_valid_parquet_write_options(
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> Result<()> {
+    if let Some(value) = options.get(FUSE_OPT_KEY_COMPRESSION_LEVEL) {
+        // the level only works with zstd compression.
+        let level = value.parse::<i32>()?;
+        if !(1..=22).contains(&level) {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "invalid {} option, must be between 1 and 22",
+                FUSE_OPT_KEY_COMPRESSION_LEVEL
+            )));
+        }
+    }
+    for key in [FUSE_OPT_KEY_ENABLE_DICTIONARY, FUSE_OPT_KEY_COMPACT_VARIANT] {
+        if let Some(value) = options.get(key) {
+            value.parse::<bool>().map_err(|_| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "invalid {} option, must be true or false",
+                    key
+                ))
+            })?;
+        }
+    }
+    if let Some(value) = options.get(FUSE_OPT_KEY_PAGE_SIZE) {
+        if value.parse::<usize>()? == 0 {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "invalid {} option, can't be 0",
+                FUSE_OPT_KEY_PAGE_SIZE
+            )));
+        }
+    }
+    if let Some(value) = options.get(FUSE_OPT_KEY_PARQUET_BLOOM_FILTER_COLUMNS) {
+        BloomIndexColumns::verify_definition(value, schema, parquet_bloom_filter_supported_type)?;
+    }
+    Ok(())
+}
+
+pub fn is_valid_cluster_type(options: &BTreeMap<String, String>) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_CLUSTER_TYPE) {
+        value.parse::<ClusterType>()?;
+    }
+    Ok(())
+}
+
+pub fn is_valid_encryption(options: &BTreeMap<String, String>, engine: Engine) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_ENCRYPTION) {
+        if !value.eq_ignore_ascii_case(ENCRYPTION_ALGORITHM) {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "invalid {} option '{}', only {} is supported",
+                OPT_KEY_ENCRYPTION, value, ENCRYPTION_ALGORITHM
+            )));
+        }
+        if engine != Engine::Fuse {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "table option {} only supports the FUSE engine",
+                OPT_KEY_ENCRYPTION
+            )));
+        }
+        if options
+            .get(OPT_KEY_STORAGE_FORMAT)
+            .is_some_and(|format| format.eq_ignore_ascii_case("native"))
+        {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "table option {} is not supported by the native storage format",
+                OPT_KEY_ENCRYPTION
+            )));
+        }
+    }
+    Ok(())
+}
+
+pub fn is_valid_table_constraints(
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_CONSTRAINTS) {
+        let constraints = TableConstraints::parse(value)?;
+        let columns = constraints
+            .unique_keys()
+            .chain(constraints.foreign_keys.iter().map(|fk| &fk.columns));
+        for column in columns.flatten() {
+            if schema.index_of(column).is_err() {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "invalid {} option, column '{}' does not exist",
+                    OPT_KEY_CONSTRAINTS, column
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn is_valid_table_tags(options: &BTreeMap<String, String>) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_TAGS) {
+        parse_table_tags(value)?;
+    }
+    Ok(())
+}
+
+/// Tags are stored in the table options as a json object, e.g. `{"owner":"finance"}`.
+pub fn parse_table_tags(value: &str) -> Result<BTreeMap<String, String>> {
+    serde_json::from_str(value).map_err(|e| {
+        ErrorCode::TableOptionInvalid(format!(
+            "invalid {} option '{}', expecting a json object of strings: {}",
+            OPT_KEY_TAGS, value, e
+        ))
+    })
+}
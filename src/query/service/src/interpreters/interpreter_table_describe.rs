@@ -14,6 +14,8 @@
 
 use std::sync::Arc;
 
+use common_catalog::table::NavigationPoint;
+use common_catalog::table::Table;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::infer_table_schema;
@@ -22,6 +24,7 @@ use common_expression::ComputedExpr;
 use common_expression::DataBlock;
 use common_expression::FromData;
 use common_expression::Scalar;
+use common_expression::TableSchemaRef;
 use common_sql::plans::DescribeTablePlan;
 use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
@@ -41,6 +44,40 @@ impl DescribeTableInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, plan: DescribeTablePlan) -> Result<Self> {
         Ok(DescribeTableInterpreter { ctx, plan })
     }
+
+    /// The schema of the table at the navigation point.
+    ///
+    /// A time point is resolved against the versions of the table meta kept in meta,
+    /// so that the schema is available even if no snapshot was written at that time.
+    #[async_backtrace::framed]
+    async fn schema_at(
+        &self,
+        table: &dyn Table,
+        navigation: &NavigationPoint,
+    ) -> Result<TableSchemaRef> {
+        match navigation {
+            NavigationPoint::SnapshotID(_) => Ok(table.navigate_to(navigation).await?.schema()),
+            NavigationPoint::TimePoint(time_point) => {
+                let table_info = table.get_table_info();
+                let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+                let history = catalog
+                    .list_table_meta_history(table_info.ident.table_id)
+                    .await?;
+
+                history
+                    .into_iter()
+                    .rev()
+                    .find(|(updated_on, _)| updated_on <= time_point)
+                    .map(|(_, table_meta)| table_meta.schema)
+                    .ok_or_else(|| {
+                        ErrorCode::TableHistoricalDataNotFound(format!(
+                            "No schema of table {} found at {}",
+                            table_info.desc, time_point
+                        ))
+                    })
+            }
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -57,7 +94,9 @@ impl Interpreter for DescribeTableInterpreter {
         let table = self.ctx.get_table(catalog, database, table).await?;
         let tbl_info = table.get_table_info();
 
-        let schema = if tbl_info.engine() == VIEW_ENGINE {
+        let schema = if let Some(navigation) = &self.plan.navigation {
+            self.schema_at(table.as_ref(), navigation).await
+        } else if tbl_info.engine() == VIEW_ENGINE {
             if let Some(query) = tbl_info.options().get(QUERY) {
                 let mut planner = Planner::new(self.ctx.clone());
                 let (plan, _) = planner.plan_sql(query).await?;
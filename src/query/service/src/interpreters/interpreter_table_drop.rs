@@ -20,7 +20,9 @@ use common_exception::Result;
 use common_meta_app::schema::DropTableByIdReq;
 use common_sql::plans::DropTablePlan;
 use common_storages_share::save_share_spec;
+use common_storages_stream::stream_table::STREAM_ENGINE;
 use common_storages_view::view_table::VIEW_ENGINE;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -68,6 +70,18 @@ impl Interpreter for DropTableInterpreter {
                     &self.plan.database, &self.plan.table, &self.plan.database, &self.plan.table
                 )));
             }
+            if tbl.get_table_info().engine() == STREAM_ENGINE {
+                return Err(ErrorCode::TableEngineNotSupported(format!(
+                    "{}.{} engine is STREAM that doesn't support drop, use `DROP STREAM {}.{}` instead",
+                    &self.plan.database, &self.plan.table, &self.plan.database, &self.plan.table
+                )));
+            }
+            if tbl.options().contains_key(OPT_KEY_MATERIALIZED_VIEW_QUERY) {
+                return Err(ErrorCode::TableEngineNotSupported(format!(
+                    "{}.{} is a materialized view that doesn't support drop, use `DROP MATERIALIZED VIEW {}.{}` instead",
+                    &self.plan.database, &self.plan.table, &self.plan.database, &self.plan.table
+                )));
+            }
             let catalog = self.ctx.get_catalog(catalog_name).await?;
 
             let resp = catalog
@@ -25,8 +25,12 @@ use common_sql::BloomIndexColumns;
 use common_storages_share::save_share_table_info;
 use common_storages_view::view_table::VIEW_ENGINE;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_NAME;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_TOKENIZER;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::check_referenced_partition_key;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -75,6 +79,8 @@ impl Interpreter for DropTableColumnInterpreter {
             )));
         }
 
+        check_referenced_partition_key(self.ctx.clone(), table.clone(), &self.plan.column)?;
+
         let mut schema: DataSchema = table_info.schema().into();
         let field = schema.field_with_name(self.plan.column.as_str())?;
         if field.computed_expr().is_none() {
@@ -93,16 +99,24 @@ impl Interpreter for DropTableColumnInterpreter {
 
         // update table options
         let opts = &mut new_table_meta.options;
-        if let Some(value) = opts.get_mut(OPT_KEY_BLOOM_INDEX_COLUMNS) {
-            let bloom_index_cols = value.parse::<BloomIndexColumns>()?;
-            if let BloomIndexColumns::Specify(mut cols) = bloom_index_cols {
-                if let Some(pos) = cols.iter().position(|x| *x == self.plan.column) {
-                    // remove from the bloom index columns.
-                    cols.remove(pos);
-                    *value = cols.join(",");
+        for key in [OPT_KEY_BLOOM_INDEX_COLUMNS, OPT_KEY_INVERTED_INDEX_COLUMNS] {
+            if let Some(value) = opts.get_mut(key) {
+                let index_cols = value.parse::<BloomIndexColumns>()?;
+                if let BloomIndexColumns::Specify(mut cols) = index_cols {
+                    if let Some(pos) = cols.iter().position(|x| *x == self.plan.column) {
+                        // remove from the index columns.
+                        cols.remove(pos);
+                        *value = cols.join(",");
+                    }
                 }
             }
         }
+        // the inverted index is dropped together with its last column.
+        if matches!(opts.get(OPT_KEY_INVERTED_INDEX_COLUMNS), Some(v) if v.is_empty()) {
+            opts.remove(OPT_KEY_INVERTED_INDEX_NAME);
+            opts.remove(OPT_KEY_INVERTED_INDEX_COLUMNS);
+            opts.remove(OPT_KEY_INVERTED_INDEX_TOKENIZER);
+        }
 
         let table_id = table_info.ident.table_id;
         let table_version = table_info.ident.seq;
@@ -113,6 +127,7 @@ impl Interpreter for DropTableColumnInterpreter {
             new_table_meta,
             copied_files: None,
             deduplicated_label: None,
+            update_stream_meta: vec![],
         };
 
         let res = catalog.update_table_meta(table_info, req).await?;
@@ -50,7 +50,9 @@ use common_storages_view::view_table::VIEW_ENGINE;
 use common_users::UserApiProvider;
 use data_mask_feature::get_datamask_handler;
 use storages_common_index::BloomIndex;
+use storages_common_index::InvertedIndex;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_COLUMNS;
 use table_lock::TableLockHandlerWrapper;
 
 use super::common::check_referenced_computed_columns;
@@ -234,6 +236,12 @@ impl ModifyTableColumnInterpreter {
                 bloom_index_cols = cols;
             }
         }
+        let mut inverted_index_cols = vec![];
+        if let Some(v) = table_info.options().get(OPT_KEY_INVERTED_INDEX_COLUMNS) {
+            if let BloomIndexColumns::Specify(cols) = v.parse::<BloomIndexColumns>()? {
+                inverted_index_cols = cols;
+            }
+        }
 
         for (column, type_name, _default_expr_opt) in column_name_types {
             let column = column.to_string();
@@ -260,6 +268,15 @@ impl ModifyTableColumnInterpreter {
                             new_type
                         )));
                     }
+                    // The inverted index is only built for string columns.
+                    if inverted_index_cols.iter().any(|v| v.as_str() == column)
+                        && !InvertedIndex::supported_type(&new_type)
+                    {
+                        return Err(ErrorCode::TableOptionInvalid(format!(
+                            "Unsupported data type '{}' for inverted index",
+                            new_type
+                        )));
+                    }
                     new_schema.fields[i].data_type = new_type;
                 }
             } else {
@@ -274,6 +291,37 @@ impl ModifyTableColumnInterpreter {
             return Ok(PipelineBuildResult::create());
         }
 
+        // If the data types of all the modified columns are widened, such as from Int32 to
+        // Int64, the existing blocks are kept and cast to the new types when being read.
+        // It is only supported by the parquet storage format.
+        if !fuse_table.is_native() {
+            if let Some(widened_schema) = widen_schema(&schema, &new_schema)? {
+                let mut new_table_meta = table_info.meta.clone();
+                new_table_meta.schema = widened_schema.into();
+
+                let req = UpdateTableMetaReq {
+                    table_id: table_info.ident.table_id,
+                    seq: MatchSeq::Exact(table_info.ident.seq),
+                    new_table_meta,
+                    copied_files: None,
+                    deduplicated_label: None,
+                    update_stream_meta: vec![],
+                };
+                let res = catalog.update_table_meta(table_info, req).await;
+                heartbeat.shutdown().await?;
+
+                if let Some(share_table_info) = res?.share_table_info {
+                    save_share_table_info(
+                        &self.ctx.get_tenant(),
+                        self.ctx.get_data_operator()?.operator(),
+                        share_table_info,
+                    )
+                    .await?;
+                }
+                return Ok(PipelineBuildResult::create());
+            }
+        }
+
         // 1. construct sql for selecting data from old table
         let mut sql = "select".to_string();
         schema
@@ -411,6 +459,7 @@ impl ModifyTableColumnInterpreter {
             new_table_meta,
             copied_files: None,
             deduplicated_label: None,
+            update_stream_meta: vec![],
         };
 
         let res = catalog.update_table_meta(table_info, req).await?;
@@ -428,6 +477,22 @@ impl ModifyTableColumnInterpreter {
     }
 }
 
+/// Returns the schema in which the modified columns are widened in place, if the data types
+/// of all the modified columns are widened and nothing else but the default values changes.
+fn widen_schema(schema: &TableSchema, new_schema: &TableSchema) -> Result<Option<TableSchema>> {
+    let mut widened_schema = schema.clone();
+    for (i, (field, new_field)) in schema.fields().iter().zip(new_schema.fields()).enumerate() {
+        if field.data_type() != new_field.data_type() {
+            if !field.data_type().can_widen_to(new_field.data_type()) {
+                return Ok(None);
+            }
+            widened_schema.widen_column_type(i, new_field.data_type().clone())?;
+        }
+        widened_schema.fields[i].default_expr = new_field.default_expr().cloned();
+    }
+    Ok(Some(widened_schema))
+}
+
 #[async_trait::async_trait]
 impl Interpreter for ModifyTableColumnInterpreter {
     fn name(&self) -> &str {
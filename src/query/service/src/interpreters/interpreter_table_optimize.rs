@@ -24,6 +24,7 @@ use common_pipeline_core::Pipeline;
 use common_sql::plans::OptimizeTableAction;
 use common_sql::plans::OptimizeTablePlan;
 use common_storages_factory::NavigationPoint;
+use common_storages_fuse::FuseTable;
 
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterClusteringHistory;
@@ -80,8 +81,10 @@ impl OptimizeTableInterpreter {
             .ctx
             .get_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
             .await?;
+        // Compacting a partition never re-clusters the other partitions.
         let need_recluster = !table.cluster_keys(self.ctx.clone()).is_empty()
-            && matches!(target, CompactTarget::Blocks);
+            && matches!(target, CompactTarget::Blocks)
+            && self.plan.partition.is_none();
 
         // check if the table is locked.
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
@@ -96,14 +99,30 @@ impl OptimizeTableInterpreter {
         }
 
         let mut compact_pipeline = Pipeline::create();
-        table
-            .compact(
-                self.ctx.clone(),
-                target,
-                self.plan.limit,
-                &mut compact_pipeline,
-            )
-            .await?;
+        match &self.plan.partition {
+            Some(partition) => {
+                let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+                fuse_table
+                    .compact_partition(
+                        self.ctx.clone(),
+                        target,
+                        partition.clone(),
+                        self.plan.limit,
+                        &mut compact_pipeline,
+                    )
+                    .await?;
+            }
+            None => {
+                table
+                    .compact(
+                        self.ctx.clone(),
+                        target,
+                        self.plan.limit,
+                        &mut compact_pipeline,
+                    )
+                    .await?;
+            }
+        }
 
         let mut build_res = PipelineBuildResult::create();
         let settings = self.ctx.get_settings();
@@ -25,8 +25,10 @@ use common_sql::BloomIndexColumns;
 use common_storages_share::save_share_table_info;
 use common_storages_view::view_table::VIEW_ENGINE;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::check_referenced_partition_key;
 use crate::interpreters::interpreter_table_create::is_valid_column;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -83,6 +85,7 @@ impl Interpreter for RenameTableColumnInterpreter {
             let mut new_table_meta = table.get_table_info().meta.clone();
 
             is_valid_column(&self.plan.new_column)?;
+            check_referenced_partition_key(self.ctx.clone(), table.clone(), &self.plan.old_column)?;
 
             let mut schema: DataSchema = table_info.schema().into();
             let field = schema.field_with_name(self.plan.old_column.as_str())?;
@@ -101,13 +104,15 @@ impl Interpreter for RenameTableColumnInterpreter {
 
             // update table options
             let opts = &mut new_table_meta.options;
-            if let Some(value) = opts.get_mut(OPT_KEY_BLOOM_INDEX_COLUMNS) {
-                let bloom_index_cols = value.parse::<BloomIndexColumns>()?;
-                if let BloomIndexColumns::Specify(mut cols) = bloom_index_cols {
-                    if let Some(pos) = cols.iter().position(|x| *x == self.plan.old_column) {
-                        // replace the bloom index columns with new column name.
-                        cols[pos] = self.plan.new_column.clone();
-                        *value = cols.join(",");
+            for key in [OPT_KEY_BLOOM_INDEX_COLUMNS, OPT_KEY_INVERTED_INDEX_COLUMNS] {
+                if let Some(value) = opts.get_mut(key) {
+                    let index_cols = value.parse::<BloomIndexColumns>()?;
+                    if let BloomIndexColumns::Specify(mut cols) = index_cols {
+                        if let Some(pos) = cols.iter().position(|x| *x == self.plan.old_column) {
+                            // replace the index columns with new column name.
+                            cols[pos] = self.plan.new_column.clone();
+                            *value = cols.join(",");
+                        }
                     }
                 }
             }
@@ -121,6 +126,7 @@ impl Interpreter for RenameTableColumnInterpreter {
                 new_table_meta,
                 copied_files: None,
                 deduplicated_label: None,
+                update_stream_meta: vec![],
             };
 
             let res = catalog.update_table_meta(table_info, req).await?;
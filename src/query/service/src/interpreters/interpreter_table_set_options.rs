@@ -23,12 +23,20 @@ use common_sql::plans::SetOptionsPlan;
 use common_storages_fuse::TableContext;
 use log::error;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_ENCRYPTION;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+use storages_common_table_meta::table::OPT_KEY_STORAGE_PROFILE;
 
 use super::interpreter_table_create::is_valid_block_per_segment;
 use super::interpreter_table_create::is_valid_bloom_index_columns;
+use super::interpreter_table_create::is_valid_cluster_type;
 use super::interpreter_table_create::is_valid_create_opt;
+use super::interpreter_table_create::is_valid_inverted_index_options;
+use super::interpreter_table_create::is_valid_parquet_write_options;
 use super::interpreter_table_create::is_valid_row_per_block;
+use super::interpreter_table_create::is_valid_table_constraints;
+use super::interpreter_table_create::is_valid_table_tags;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -58,6 +66,10 @@ impl Interpreter for SetOptionsInterpreter {
         is_valid_block_per_segment(&self.plan.set_options)?;
         // check row_per_block
         is_valid_row_per_block(&self.plan.set_options)?;
+        // check tags
+        is_valid_table_tags(&self.plan.set_options)?;
+        // check cluster_type
+        is_valid_cluster_type(&self.plan.set_options)?;
         // check storage_format
         let error_str = "invalid opt for fuse table in alter table statement";
         if self.plan.set_options.get(OPT_KEY_STORAGE_FORMAT).is_some() {
@@ -67,6 +79,13 @@ impl Interpreter for SetOptionsInterpreter {
                 OPT_KEY_STORAGE_FORMAT
             )));
         }
+        if self.plan.set_options.get(OPT_KEY_PARTITION_BY).is_some() {
+            error!("{}", &error_str);
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "can't change {} for alter table statement",
+                OPT_KEY_PARTITION_BY
+            )));
+        }
         if self.plan.set_options.get(OPT_KEY_DATABASE_ID).is_some() {
             error!("{}", &error_str);
             return Err(ErrorCode::TableOptionInvalid(format!(
@@ -74,6 +93,20 @@ impl Interpreter for SetOptionsInterpreter {
                 OPT_KEY_DATABASE_ID
             )));
         }
+        if self.plan.set_options.get(OPT_KEY_ENCRYPTION).is_some() {
+            error!("{}", &error_str);
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "can't change {} for alter table statement",
+                OPT_KEY_ENCRYPTION
+            )));
+        }
+        if self.plan.set_options.get(OPT_KEY_STORAGE_PROFILE).is_some() {
+            error!("{}", &error_str);
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "can't change {} for alter table statement",
+                OPT_KEY_STORAGE_PROFILE
+            )));
+        }
         for table_option in self.plan.set_options.iter() {
             let key = table_option.0.to_lowercase();
             if !is_valid_create_opt(&key) {
@@ -105,6 +138,12 @@ impl Interpreter for SetOptionsInterpreter {
 
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&self.plan.set_options, table.schema())?;
+        // check inverted_index_columns and inverted_index_tokenizer.
+        is_valid_inverted_index_options(&self.plan.set_options, table.schema())?;
+        // check parquet writer options.
+        is_valid_parquet_write_options(&self.plan.set_options, table.schema())?;
+        // check constraints.
+        is_valid_table_constraints(&self.plan.set_options, table.schema())?;
 
         let req = UpsertTableOptionReq {
             table_id: table.get_id(),
@@ -0,0 +1,83 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::SetTableTagsPlan;
+use storages_common_table_meta::table::OPT_KEY_TAGS;
+
+use super::interpreter_table_create::parse_table_tags;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct SetTableTagsInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: SetTableTagsPlan,
+}
+
+impl SetTableTagsInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: SetTableTagsPlan) -> Result<Self> {
+        Ok(SetTableTagsInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for SetTableTagsInterpreter {
+    fn name(&self) -> &str {
+        "SetTableTagsInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = self
+            .ctx
+            .get_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
+            .await?;
+
+        let mut tags = match table.options().get(OPT_KEY_TAGS) {
+            Some(value) => parse_table_tags(value)?,
+            None => Default::default(),
+        };
+        for (key, value) in &self.plan.tags {
+            match value {
+                Some(value) => tags.insert(key.clone(), value.clone()),
+                None => tags.remove(key),
+            };
+        }
+
+        let value = if tags.is_empty() {
+            None
+        } else {
+            Some(serde_json::to_string(&tags)?)
+        };
+        let req = UpsertTableOptionReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+            options: HashMap::from([(OPT_KEY_TAGS.to_string(), value)]),
+        };
+
+        catalog
+            .upsert_table_option(tenant.as_str(), &self.plan.database, req)
+            .await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -22,11 +22,14 @@ use common_expression::ComputedExpr;
 use common_expression::DataBlock;
 use common_expression::Scalar;
 use common_expression::Value;
+use common_sql::identity_sequence_name;
 use common_sql::plans::ShowCreateTablePlan;
 use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
+use common_users::UserApiProvider;
 use log::debug;
 use storages_common_table_meta::table::is_internal_opt_key;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -102,11 +105,21 @@ impl Interpreter for ShowCreateTableInterpreter {
         {
             let mut columns = vec![];
             for (idx, field) in schema.fields().iter().enumerate() {
-                let default_expr = match field.default_expr() {
-                    Some(expr) => {
+                let identity = match field.default_expr().and_then(|e| identity_sequence_name(e)) {
+                    Some(sequence) => UserApiProvider::instance()
+                        .get_sequence(tenant.as_str(), &sequence)
+                        .await
+                        .ok(),
+                    None => None,
+                };
+                let default_expr = match (identity, field.default_expr()) {
+                    (Some(sequence), _) => {
+                        format!(" IDENTITY({}, {})", sequence.start, sequence.increment)
+                    }
+                    (None, Some(expr)) => {
                         format!(" DEFAULT {expr}")
                     }
-                    None => "".to_string(),
+                    (None, None) => "".to_string(),
                 };
                 let computed_expr = match field.computed_expr() {
                     Some(ComputedExpr::Virtual(expr)) => {
@@ -155,6 +168,9 @@ impl Interpreter for ShowCreateTableInterpreter {
         if let Some((_, cluster_keys_str)) = table_info.meta.cluster_key() {
             table_create_sql.push_str(format!(" CLUSTER BY {}", cluster_keys_str).as_str());
         }
+        if let Some(partition_key) = table_info.options().get(OPT_KEY_PARTITION_BY) {
+            table_create_sql.push_str(format!(" PARTITION BY ({})", partition_key).as_str());
+        }
 
         let settings = self.ctx.get_settings();
         let hide_options_in_show_create_table = settings
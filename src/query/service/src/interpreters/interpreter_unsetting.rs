@@ -52,6 +52,14 @@ impl Interpreter for UnSettingInterpreter {
             let (ok, value) = match var.to_lowercase().as_str() {
                 // To be compatible with some drivers
                 "sql_mode" | "autocommit" => (false, String::from("")),
+                setting if plan.is_session => {
+                    // fall back to the global value if there is one, keep it in the meta service
+                    let value = settings.try_unset_session_setting(setting).await?;
+                    keys.push(var);
+                    values.push(value.to_string());
+                    is_globals.push(false);
+                    continue;
+                }
                 setting => {
                     self.ctx
                         .get_shard_settings()
@@ -14,12 +14,17 @@
 
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_sql::binder::read_wasm_module;
 use common_sql::plans::CreateUDFPlan;
 use common_users::UserApiProvider;
 use log::debug;
 
 use crate::interpreters::Interpreter;
+use crate::pipelines::processors::transforms::validate_wasm_module;
+use crate::pipelines::processors::transforms::UDFFlightClient;
+use crate::pipelines::processors::transforms::UdfServerSettings;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
@@ -47,7 +52,42 @@ impl Interpreter for CreateUserUDFInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         debug!("ctx.id" = self.ctx.get_id().as_str(); "create_user_udf_execute");
 
-        let plan = self.plan.clone();
+        let mut plan = self.plan.clone();
+        if let Some(wasm) = plan.udf.wasm.as_mut() {
+            // The module stays on its stage and is read again when the function is called,
+            // `@~` would then be the stage of the calling user.
+            if !plan.temporary && plan.udf.definition.starts_with("@~") {
+                return Err(ErrorCode::IllegalUDFFormat(
+                    "Only temporary WASM functions can read their module from a user stage",
+                ));
+            }
+            let ctx: Arc<dyn TableContext> = self.ctx.clone();
+            let (code, code_hash) = read_wasm_module(&ctx, &plan.udf.definition).await?;
+            validate_wasm_module(&code, &wasm.handler)?;
+            wasm.code_hash = code_hash;
+        }
+        if let Some(server) = &plan.udf.server {
+            let settings = UdfServerSettings::try_create(&self.ctx.get_settings())?;
+            let mut client = UDFFlightClient::connect(&server.address, &settings).await?;
+            client
+                .check_schema(&server.handler, &server.arg_types, &server.return_type)
+                .await?;
+        }
+
+        if plan.temporary {
+            if self.ctx.get_temporary_udf(&plan.udf.name).is_some() {
+                if plan.if_not_exists {
+                    return Ok(PipelineBuildResult::create());
+                }
+                return Err(ErrorCode::UdfAlreadyExists(format!(
+                    "Temporary UDF '{}' already exists",
+                    plan.udf.name
+                )));
+            }
+            self.ctx.add_temporary_udf(plan.udf);
+            return Ok(PipelineBuildResult::create());
+        }
+
         let tenant = self.ctx.get_tenant();
         let udf = plan.udf;
         let _ = UserApiProvider::instance()
@@ -48,6 +48,11 @@ impl Interpreter for DropUserUDFInterpreter {
         debug!("ctx.id" = self.ctx.get_id().as_str(); "drop_user_udf_execute");
 
         let plan = self.plan.clone();
+        if plan.temporary {
+            self.ctx.remove_temporary_udf(plan.name.as_str());
+            return Ok(PipelineBuildResult::create());
+        }
+
         let tenant = self.ctx.get_tenant();
         UserApiProvider::instance()
             .drop_udf(&tenant, plan.name.as_str(), plan.if_exists)
@@ -13,8 +13,10 @@
 // limitations under the License.
 
 use std::cmp::min;
+use std::collections::HashSet;
 use std::sync::Arc;
 
+use common_catalog::table::Table;
 use common_exception::Result;
 use common_expression::types::StringType;
 use common_expression::DataBlock;
@@ -22,12 +24,15 @@ use common_expression::FromData;
 use common_license::license::Feature::Vacuum;
 use common_license::license_manager::get_license_manager;
 use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::DroppedId;
 use common_meta_app::schema::GcDroppedTableReq;
 use common_meta_app::schema::ListDroppedTableReq;
 use common_meta_app::schema::TableInfoFilter;
 use common_sql::plans::VacuumDropTablePlan;
+use common_storages_fuse::operations::list_clones;
 use log::as_debug;
 use log::info;
+use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use vacuum_handler::get_vacuum_handler;
 
 use crate::interpreters::Interpreter;
@@ -87,6 +92,7 @@ impl Interpreter for VacuumDropTablesInterpreter {
                 filter,
             })
             .await?;
+        let (tables, drop_ids) = self.exclude_shared_tables(tables, drop_ids).await?;
         let handler = get_vacuum_handler();
         let files_opt = handler
             .do_vacuum_drop_tables(
@@ -128,3 +134,56 @@ impl Interpreter for VacuumDropTablesInterpreter {
         }
     }
 }
+
+impl VacuumDropTablesInterpreter {
+    /// Excludes the dropped tables whose files are shared with their clones, unless the
+    /// clones are vacuumed as well.
+    #[async_backtrace::framed]
+    async fn exclude_shared_tables(
+        &self,
+        tables: Vec<Arc<dyn Table>>,
+        drop_ids: Vec<DroppedId>,
+    ) -> Result<(Vec<Arc<dyn Table>>, Vec<DroppedId>)> {
+        let vacuumed: HashSet<u64> = tables.iter().map(|table| table.get_id()).collect();
+        let mut shared = HashSet::new();
+        for table in &tables {
+            let clones = list_clones(self.ctx.as_ref(), table.get_table_info()).await?;
+            if clones
+                .iter()
+                .any(|clone| !vacuumed.contains(&clone.get_id()))
+            {
+                shared.insert(table.get_id());
+            }
+        }
+        if shared.is_empty() {
+            return Ok((tables, drop_ids));
+        }
+
+        let (kept, tables): (Vec<_>, Vec<_>) = tables
+            .into_iter()
+            .partition(|table| shared.contains(&table.get_id()));
+        info!(
+            "vacuum drop table: skip tables {:?}, their files are shared with clones",
+            kept.iter().map(|table| table.get_id()).collect::<Vec<_>>()
+        );
+        // the database is kept as long as any of its tables is.
+        let kept_databases: HashSet<u64> = kept
+            .iter()
+            .filter_map(|table| {
+                table
+                    .get_table_info()
+                    .options()
+                    .get(OPT_KEY_DATABASE_ID)
+                    .and_then(|id| id.parse().ok())
+            })
+            .collect();
+        let drop_ids = drop_ids
+            .into_iter()
+            .filter(|drop_id| match drop_id {
+                DroppedId::Db(db_id, _) => !kept_databases.contains(db_id),
+                DroppedId::Table(_, table_id, _) => !shared.contains(table_id),
+            })
+            .collect();
+        Ok((tables, drop_ids))
+    }
+}
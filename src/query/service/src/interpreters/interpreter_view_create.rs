@@ -18,6 +18,7 @@ use std::sync::Arc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableNameIdent;
 use common_sql::plans::CreateViewPlan;
@@ -50,6 +51,10 @@ impl Interpreter for CreateViewInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
+        if self.plan.temporary {
+            return self.create_temporary_view().await;
+        }
+
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
         let tenant = self.ctx.get_tenant();
         let table_function = catalog.list_table_functions();
@@ -92,24 +97,7 @@ impl Interpreter for CreateViewInterpreter {
             }
         }
 
-        let subquery = if self.plan.column_names.is_empty() {
-            self.plan.subquery.clone()
-        } else {
-            if plan.schema().fields().len() != self.plan.column_names.len() {
-                return Err(ErrorCode::BadDataArrayLength(format!(
-                    "column name length mismatch, expect {}, got {}",
-                    plan.schema().fields().len(),
-                    self.plan.column_names.len(),
-                )));
-            }
-            format!(
-                "select * from ({}) {}({})",
-                self.plan.subquery,
-                self.plan.view_name,
-                self.plan.column_names.join(", ")
-            )
-        };
-        options.insert(QUERY.to_string(), subquery);
+        options.insert(QUERY.to_string(), self.view_query(&plan)?);
 
         let plan = CreateTableReq {
             if_not_exists: self.plan.if_not_exists,
@@ -129,3 +117,57 @@ impl Interpreter for CreateViewInterpreter {
         Ok(PipelineBuildResult::create())
     }
 }
+
+impl CreateViewInterpreter {
+    // The query of the view, with the columns renamed if the column names are given.
+    fn view_query(&self, plan: &Plan) -> Result<String> {
+        if self.plan.column_names.is_empty() {
+            return Ok(self.plan.subquery.clone());
+        }
+        if plan.schema().fields().len() != self.plan.column_names.len() {
+            return Err(ErrorCode::BadDataArrayLength(format!(
+                "column name length mismatch, expect {}, got {}",
+                plan.schema().fields().len(),
+                self.plan.column_names.len(),
+            )));
+        }
+        Ok(format!(
+            "select * from ({}) {}({})",
+            self.plan.subquery,
+            self.plan.view_name,
+            self.plan.column_names.join(", ")
+        ))
+    }
+
+    // A temporary view is kept in the session, and is never written to the meta service.
+    #[async_backtrace::framed]
+    async fn create_temporary_view(&self) -> Result<PipelineBuildResult> {
+        if self.ctx.get_temporary_view(&self.plan.view_name).is_some() {
+            if self.plan.if_not_exists {
+                return Ok(PipelineBuildResult::create());
+            }
+            return Err(ErrorCode::ViewAlreadyExists(format!(
+                "Temporary view {} already exists",
+                self.plan.view_name
+            )));
+        }
+
+        let mut planner = Planner::new(self.ctx.clone());
+        let (plan, _) = planner.plan_sql(&self.plan.subquery.clone()).await?;
+        let mut options = BTreeMap::new();
+        options.insert(QUERY.to_string(), self.view_query(&plan)?);
+
+        self.ctx.add_temporary_view(TableInfo {
+            desc: format!("'{}'", self.plan.view_name),
+            name: self.plan.view_name.clone(),
+            meta: TableMeta {
+                engine: VIEW_ENGINE.to_string(),
+                options,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+        Ok(PipelineBuildResult::create())
+    }
+}
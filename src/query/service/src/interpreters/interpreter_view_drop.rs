@@ -44,6 +44,11 @@ impl Interpreter for DropViewInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
+        if self.plan.temporary {
+            self.ctx.remove_temporary_view(&self.plan.view_name);
+            return Ok(PipelineBuildResult::create());
+        }
+
         let catalog_name = self.plan.catalog.clone();
         let db_name = self.plan.database.clone();
         let view_name = self.plan.view_name.clone();
@@ -43,6 +43,7 @@ pub mod databases;
 pub mod interpreters;
 pub mod metrics;
 pub mod pipelines;
+pub mod pipes;
 pub mod schedulers;
 pub mod servers;
 pub mod sessions;
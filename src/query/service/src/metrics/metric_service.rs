@@ -33,7 +33,7 @@ pub struct MetricService {
 #[poem::handler]
 #[async_backtrace::framed]
 pub async fn metric_handler(prom_extension: Data<&PrometheusHandle>) -> impl IntoResponse {
-    prom_extension.0.render()
+    common_metrics::render_metrics(prom_extension.0)
 }
 
 impl MetricService {
@@ -14,14 +14,26 @@
 
 use std::sync::Arc;
 
+use common_base::base::tokio::runtime::Handle;
+use common_base::base::tokio::task::block_in_place;
 use common_catalog::table::AppendMode;
 use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataField;
+use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
+use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::Pipeline;
+use common_pipeline_transforms::processors::transforms::AsyncTransformer;
+use common_sql::identity_sequence_name;
+use common_users::UserApiProvider;
 
 use crate::pipelines::processors::transforms::TransformAddComputedColumns;
+use crate::pipelines::processors::transforms::TransformSequence;
 use crate::pipelines::processors::TransformResortAddOn;
 use crate::sessions::QueryContext;
 
@@ -36,6 +48,46 @@ pub fn build_fill_missing_columns_pipeline(
     let default_schema: DataSchemaRef = Arc::new(table_default_schema.into());
     let computed_schema: DataSchemaRef = Arc::new(table_computed_schema.into());
 
+    // Fill missing identity columns from their sequences, they are cast to the column types
+    // when the columns are resorted.
+    let identity_fields = default_schema
+        .fields()
+        .iter()
+        .filter(|f| !source_schema.has_field(f.name()))
+        .filter_map(|f| Some((f, identity_sequence_name(f.default_expr()?)?)))
+        .collect::<Vec<_>>();
+    let source_schema = if identity_fields.is_empty() {
+        source_schema
+    } else {
+        let tenant = ctx.get_tenant();
+        let cache_size = ctx.get_settings().get_sequence_cache_size()?;
+        let user_api = UserApiProvider::instance();
+        let sequences = block_in_place(|| {
+            Handle::current().block_on(async {
+                let mut sequences = Vec::with_capacity(identity_fields.len());
+                for (_, name) in identity_fields.iter() {
+                    sequences.push(user_api.get_sequence(&tenant, name).await?);
+                }
+                Result::Ok(sequences)
+            })
+        })?;
+        pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(AsyncTransformer::create(
+                input,
+                output,
+                TransformSequence::new(tenant.clone(), sequences.clone(), cache_size),
+            )))
+        })?;
+
+        let mut fields = source_schema.fields().clone();
+        fields.extend(
+            identity_fields
+                .iter()
+                .map(|(f, _)| DataField::new(f.name(), DataType::Number(NumberDataType::Int64))),
+        );
+        Arc::new(DataSchema::new(fields))
+    };
+
     // Fill missing default columns and resort the columns.
     if source_schema != default_schema {
         pipeline.add_transform(|transform_input_port, transform_output_port| {
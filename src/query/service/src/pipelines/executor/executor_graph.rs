@@ -22,6 +22,8 @@ use common_base::runtime::TrySpawn;
 use common_exception::Result;
 use log::debug;
 use log::trace;
+use minitrace::future::FutureExt;
+use minitrace::Span;
 use petgraph::dot::Config;
 use petgraph::dot::Dot;
 use petgraph::prelude::EdgeIndex;
@@ -331,7 +333,9 @@ impl ScheduleQueue {
     ) {
         unsafe {
             workers_condvar.inc_active_async_worker();
-            let process_future = proc.async_process();
+            let span = Span::enter_with_local_parent("ProcessorAsyncTask")
+                .with_property(|| ("processor", proc.name()));
+            let process_future = proc.async_process().in_span(span);
             executor
                 .async_runtime
                 .spawn(TrackedFuture::create(ProcessorAsyncTask::create(
@@ -12,13 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicI64;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::sync::Once;
 
 use common_base::base::tokio::sync::Notify;
 use common_exception::Result;
+use common_metrics::register_metric_collector;
+use common_metrics::MetricSample;
+use common_metrics::MetricValue;
 use parking_lot::Mutex;
 use petgraph::prelude::NodeIndex;
 
@@ -28,6 +34,33 @@ use crate::pipelines::executor::executor_worker_context::ExecutorTask;
 use crate::pipelines::executor::executor_worker_context::ExecutorWorkerContext;
 use crate::pipelines::processors::processor::ProcessorPtr;
 
+/// The sync tasks queued in the executors of this node, waiting for a worker.
+static QUEUED_SYNC_TASKS: AtomicI64 = AtomicI64::new(0);
+/// The completed async tasks queued in the executors of this node, waiting for a worker.
+static QUEUED_ASYNC_COMPLETED_TASKS: AtomicI64 = AtomicI64::new(0);
+
+/// The queues are changed on each schedule of a processor, their lengths are reported
+/// only when the metrics are dumped.
+fn register_queue_metrics() {
+    static REGISTER: Once = Once::new();
+    REGISTER.call_once(|| {
+        register_metric_collector(|| {
+            let gauge = |name: &str, value: &AtomicI64| MetricSample {
+                name: name.to_string(),
+                labels: HashMap::new(),
+                value: MetricValue::Gauge(value.load(Ordering::Relaxed) as f64),
+            };
+            vec![
+                gauge("pipeline_executor_queued_sync_tasks", &QUEUED_SYNC_TASKS),
+                gauge(
+                    "pipeline_executor_queued_async_completed_tasks",
+                    &QUEUED_ASYNC_COMPLETED_TASKS,
+                ),
+            ]
+        })
+    });
+}
+
 pub struct ExecutorTasksQueue {
     finished: Arc<AtomicBool>,
     finished_notify: Arc<Notify>,
@@ -36,6 +69,7 @@ pub struct ExecutorTasksQueue {
 
 impl ExecutorTasksQueue {
     pub fn create(workers_size: usize) -> Arc<ExecutorTasksQueue> {
+        register_queue_metrics();
         Arc::new(ExecutorTasksQueue {
             finished: Arc::new(AtomicBool::new(false)),
             finished_notify: Arc::new(Notify::new()),
@@ -165,6 +199,7 @@ impl ExecutorTasksQueue {
         let mut worker_id = task.worker_id;
         workers_tasks.tasks_size += 1;
         workers_tasks.workers_completed_async_tasks[worker_id].push_back(task);
+        QUEUED_ASYNC_COMPLETED_TASKS.fetch_add(1, Ordering::Relaxed);
 
         condvar.dec_active_async_worker();
 
@@ -239,10 +274,12 @@ impl ExecutorTasks {
     #[inline]
     fn pop_worker_task(&mut self, worker_id: usize) -> ExecutorTask {
         if let Some(task) = self.workers_completed_async_tasks[worker_id].pop_front() {
+            QUEUED_ASYNC_COMPLETED_TASKS.fetch_sub(1, Ordering::Relaxed);
             return ExecutorTask::AsyncCompleted(task);
         }
 
         if let Some(processor) = self.workers_sync_tasks[worker_id].pop_front() {
+            QUEUED_SYNC_TASKS.fetch_sub(1, Ordering::Relaxed);
             return ExecutorTask::Sync(processor);
         }
 
@@ -301,8 +338,32 @@ impl ExecutorTasks {
 
         match task {
             ExecutorTask::None => unreachable!(),
-            ExecutorTask::Sync(processor) => sync_queue.push_back(processor),
-            ExecutorTask::AsyncCompleted(task) => completed_queue.push_back(task),
+            ExecutorTask::Sync(processor) => {
+                sync_queue.push_back(processor);
+                QUEUED_SYNC_TASKS.fetch_add(1, Ordering::Relaxed);
+            }
+            ExecutorTask::AsyncCompleted(task) => {
+                completed_queue.push_back(task);
+                QUEUED_ASYNC_COMPLETED_TASKS.fetch_add(1, Ordering::Relaxed);
+            }
         }
     }
 }
+
+impl Drop for ExecutorTasks {
+    fn drop(&mut self) {
+        // The tasks left in the queues of a finished or aborted executor.
+        let sync_tasks = self
+            .workers_sync_tasks
+            .iter()
+            .map(|q| q.len())
+            .sum::<usize>();
+        let completed_tasks = self
+            .workers_completed_async_tasks
+            .iter()
+            .map(|q| q.len())
+            .sum::<usize>();
+        QUEUED_SYNC_TASKS.fetch_sub(sync_tasks as i64, Ordering::Relaxed);
+        QUEUED_ASYNC_COMPLETED_TASKS.fetch_sub(completed_tasks as i64, Ordering::Relaxed);
+    }
+}
@@ -30,6 +30,7 @@ use futures_util::future::Either;
 use log::info;
 use log::warn;
 use log::LevelFilter;
+use minitrace::prelude::*;
 use parking_lot::Mutex;
 use petgraph::matrix_graph::Zero;
 
@@ -57,6 +58,9 @@ pub struct PipelineExecutor {
     settings: ExecutorSettings,
     finished_notify: Arc<Notify>,
     finished_error: Mutex<Option<ErrorCode>>,
+    // The executor runs in other threads, the spans of the pipeline are the children of the
+    // span in which the executor was created.
+    span_context: Option<SpanContext>,
 }
 
 impl PipelineExecutor {
@@ -177,6 +181,7 @@ impl PipelineExecutor {
             settings,
             finished_error: Mutex::new(None),
             finished_notify: Arc::new(Notify::new()),
+            span_context: SpanContext::current_local_parent(),
         }))
     }
 
@@ -209,6 +214,12 @@ impl PipelineExecutor {
     }
 
     pub fn execute(self: &Arc<Self>) -> Result<()> {
+        let span = match self.span_context {
+            Some(span_context) => Span::root("PipelineExecutor::execute", span_context),
+            None => Span::noop(),
+        };
+        let _guard = span.set_local_parent();
+
         self.init()?;
 
         self.start_executor_daemon()?;
@@ -326,7 +337,9 @@ impl PipelineExecutor {
                 }
             }
 
+            let span = Span::enter_with_local_parent("PipelineExecutor::execute_single_thread");
             thread_join_handles.push(Thread::named_spawn(name, move || unsafe {
+                let _guard = span.set_local_parent();
                 let this_clone = this.clone();
                 let try_result = catch_unwind(move || -> Result<()> {
                     match this_clone.execute_single_thread(thread_num) {
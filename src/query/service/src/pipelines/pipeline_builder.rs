@@ -66,6 +66,7 @@ use common_pipeline_transforms::processors::profile_wrapper::ProfileStub;
 use common_pipeline_transforms::processors::profile_wrapper::TransformProfileWrapper;
 use common_pipeline_transforms::processors::transforms::build_full_sort_pipeline;
 use common_pipeline_transforms::processors::transforms::create_dummy_item;
+use common_pipeline_transforms::processors::transforms::AsyncTransformer;
 use common_pipeline_transforms::processors::transforms::Transformer;
 use common_profile::SharedProcessorProfiles;
 use common_sql::evaluator::BlockOperator;
@@ -74,6 +75,7 @@ use common_sql::executor::AggregateExpand;
 use common_sql::executor::AggregateFinal;
 use common_sql::executor::AggregateFunctionDesc;
 use common_sql::executor::AggregatePartial;
+use common_sql::executor::AggregateStreaming;
 use common_sql::executor::AsyncSourcerPlan;
 use common_sql::executor::ConstantTableScan;
 use common_sql::executor::CopyIntoTable;
@@ -101,15 +103,16 @@ use common_sql::executor::RuntimeFilterSource;
 use common_sql::executor::SelectCtx;
 use common_sql::executor::Sort;
 use common_sql::executor::TableScan;
+use common_sql::executor::Udf;
 use common_sql::executor::UnionAll;
 use common_sql::executor::Window;
+use common_sql::plans::UDFType;
 use common_sql::BindContext;
 use common_sql::ColumnBinding;
 use common_sql::IndexType;
 use common_sql::Metadata;
 use common_sql::MetadataRef;
 use common_sql::NameResolutionContext;
-use common_storage::DataOperator;
 use common_storages_factory::Table;
 use common_storages_fuse::operations::build_row_fetcher_pipeline;
 use common_storages_fuse::operations::common::TransformSerializeSegment;
@@ -131,6 +134,8 @@ use crate::api::ExchangeInjector;
 use crate::pipelines::builders::build_append_data_pipeline;
 use crate::pipelines::builders::build_fill_missing_columns_pipeline;
 use crate::pipelines::processors::transforms::build_partition_bucket;
+use crate::pipelines::processors::transforms::hash_join::HashJoinBuildCache;
+use crate::pipelines::processors::transforms::hash_join::HashJoinBuildCacheItem;
 use crate::pipelines::processors::transforms::hash_join::HashJoinBuildState;
 use crate::pipelines::processors::transforms::hash_join::HashJoinProbeState;
 use crate::pipelines::processors::transforms::hash_join::TransformHashJoinBuild;
@@ -151,7 +156,12 @@ use crate::pipelines::processors::transforms::TransformGroupBySpillWriter;
 use crate::pipelines::processors::transforms::TransformMergeBlock;
 use crate::pipelines::processors::transforms::TransformPartialAggregate;
 use crate::pipelines::processors::transforms::TransformPartialGroupBy;
+use crate::pipelines::processors::transforms::TransformSequence;
+use crate::pipelines::processors::transforms::TransformStreamingAggregate;
+use crate::pipelines::processors::transforms::TransformUdf;
+use crate::pipelines::processors::transforms::TransformUdfServer;
 use crate::pipelines::processors::transforms::TransformWindow;
+use crate::pipelines::processors::transforms::UdfServerSettings;
 use crate::pipelines::processors::AggregatorParams;
 use crate::pipelines::processors::HashJoinState;
 use crate::pipelines::processors::SinkRuntimeFilterSource;
@@ -231,6 +241,9 @@ impl PipelineBuilder {
             PhysicalPlan::AggregateExpand(aggregate) => self.build_aggregate_expand(aggregate),
             PhysicalPlan::AggregatePartial(aggregate) => self.build_aggregate_partial(aggregate),
             PhysicalPlan::AggregateFinal(aggregate) => self.build_aggregate_final(aggregate),
+            PhysicalPlan::AggregateStreaming(aggregate) => {
+                self.build_aggregate_streaming(aggregate)
+            }
             PhysicalPlan::Window(window) => self.build_window(window),
             PhysicalPlan::Sort(sort) => self.build_sort(sort),
             PhysicalPlan::Limit(limit) => self.build_limit(limit),
@@ -244,6 +257,7 @@ impl PipelineBuilder {
             }
             PhysicalPlan::ProjectSet(project_set) => self.build_project_set(project_set),
             PhysicalPlan::Lambda(lambda) => self.build_lambda(lambda),
+            PhysicalPlan::Udf(udf) => self.build_udf(udf),
             PhysicalPlan::Exchange(_) => Err(ErrorCode::Internal(
                 "Invalid physical plan with PhysicalPlan::Exchange",
             )),
@@ -651,7 +665,7 @@ impl PipelineBuilder {
     }
 
     fn build_range_join(&mut self, range_join: &RangeJoin) -> Result<()> {
-        let state = Arc::new(RangeJoinState::new(self.ctx.clone(), range_join));
+        let state = Arc::new(RangeJoinState::new(self.ctx.clone(), range_join)?);
         self.expand_right_side_pipeline(range_join, state.clone())?;
         self.build_left_side(range_join, state)?;
         if self.enable_profiling {
@@ -726,18 +740,32 @@ impl PipelineBuilder {
     }
 
     fn build_join(&mut self, join: &HashJoin) -> Result<()> {
-        let state = self.build_join_state(join)?;
-        self.expand_build_side_pipeline(&join.build, join, state.clone())?;
+        let build_cache_key = HashJoinBuildCache::cache_key(&self.ctx, join)?;
+        let cached_build = build_cache_key
+            .as_ref()
+            .and_then(|key| HashJoinBuildCache::instance().get(key));
+
+        // The build side needn't be read if the hash table is cached.
+        let build_cached = cached_build.is_some();
+        let state = self.build_join_state(join, cached_build)?;
+        if !build_cached {
+            self.expand_build_side_pipeline(&join.build, join, state.clone(), build_cache_key)?;
+        }
         self.build_join_probe(join, state)
     }
 
-    fn build_join_state(&mut self, join: &HashJoin) -> Result<Arc<HashJoinState>> {
+    fn build_join_state(
+        &mut self,
+        join: &HashJoin,
+        cached_build: Option<Arc<HashJoinBuildCacheItem>>,
+    ) -> Result<Arc<HashJoinState>> {
         HashJoinState::try_create(
             self.ctx.clone(),
             join.build.output_schema()?,
             &join.build_projections,
             HashJoinDesc::create(join)?,
             &join.probe_to_build,
+            cached_build,
         )
     }
 
@@ -746,6 +774,7 @@ impl PipelineBuilder {
         build: &PhysicalPlan,
         hash_join_plan: &HashJoin,
         join_state: Arc<HashJoinState>,
+        build_cache_key: Option<String>,
     ) -> Result<()> {
         let build_side_context = QueryContext::create_from(self.ctx.clone());
         let mut build_side_builder = PipelineBuilder::create(
@@ -762,6 +791,7 @@ impl PipelineBuilder {
             &hash_join_plan.build_keys,
             &hash_join_plan.build_projections,
             join_state,
+            build_cache_key,
         )?;
         let create_sink_processor = |input| {
             let transform = TransformHashJoinBuild::try_create(input, build_state.clone())?;
@@ -1110,6 +1140,93 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    fn build_udf(&mut self, udf: &Udf) -> Result<()> {
+        self.build_pipeline(&udf.input)?;
+
+        // WASM functions come first and sequences last, see `PhysicalPlanBuilder`.
+        let (wasm_funcs, other_funcs): (Vec<_>, Vec<_>) = udf
+            .udf_funcs
+            .iter()
+            .cloned()
+            .partition(|func| matches!(func.udf_type, UDFType::Wasm { .. }));
+        let (sequence_funcs, server_funcs): (Vec<_>, Vec<_>) = other_funcs
+            .into_iter()
+            .partition(|func| matches!(func.udf_type, UDFType::Sequence(_)));
+
+        let input_schema = udf.input.output_schema()?;
+        if !wasm_funcs.is_empty() {
+            self.main_pipeline.add_transform(|input, output| {
+                let transform = TransformUdf::try_create(&input_schema, &wasm_funcs)?;
+
+                if self.enable_profiling {
+                    Ok(ProcessorPtr::create(TransformProfileWrapper::create(
+                        transform,
+                        input,
+                        output,
+                        udf.plan_id,
+                        self.proc_profs.clone(),
+                    )))
+                } else {
+                    Ok(ProcessorPtr::create(Transformer::create(
+                        input, output, transform,
+                    )))
+                }
+            })?;
+        }
+
+        if !server_funcs.is_empty() {
+            let settings = UdfServerSettings::try_create(&self.ctx.get_settings())?;
+            self.main_pipeline.add_transform(|input, output| {
+                let transform = AsyncTransformer::create(
+                    input,
+                    output,
+                    TransformUdfServer::new(server_funcs.clone(), settings),
+                );
+
+                if self.enable_profiling {
+                    Ok(ProcessorPtr::create(ProcessorProfileWrapper::create(
+                        transform,
+                        udf.plan_id,
+                        self.proc_profs.clone(),
+                    )))
+                } else {
+                    Ok(ProcessorPtr::create(transform))
+                }
+            })?;
+        }
+
+        if !sequence_funcs.is_empty() {
+            let sequences = sequence_funcs
+                .iter()
+                .filter_map(|func| match &func.udf_type {
+                    UDFType::Sequence(sequence) => Some(sequence.clone()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            let tenant = self.ctx.get_tenant();
+            let cache_size = self.ctx.get_settings().get_sequence_cache_size()?;
+            self.main_pipeline.add_transform(|input, output| {
+                let transform = AsyncTransformer::create(
+                    input,
+                    output,
+                    TransformSequence::new(tenant.clone(), sequences.clone(), cache_size),
+                );
+
+                if self.enable_profiling {
+                    Ok(ProcessorPtr::create(ProcessorProfileWrapper::create(
+                        transform,
+                        udf.plan_id,
+                        self.proc_profs.clone(),
+                    )))
+                } else {
+                    Ok(ProcessorPtr::create(transform))
+                }
+            })?;
+        }
+
+        Ok(())
+    }
+
     fn build_aggregate_expand(&mut self, expand: &AggregateExpand) -> Result<()> {
         self.build_pipeline(&expand.input)?;
         let input_schema = expand.input.output_schema()?;
@@ -1232,7 +1349,7 @@ impl PipelineBuilder {
 
         // If cluster mode, spill write will be completed in exchange serialize, because we need scatter the block data first
         if self.ctx.get_cluster().is_empty() {
-            let operator = DataOperator::instance().operator();
+            let operator = self.ctx.get_spill_operator();
             let location_prefix = format!("_aggregate_spill/{}", self.ctx.get_tenant());
             self.main_pipeline.add_transform(|input, output| {
                 let transform = match params.aggregate_functions.is_empty() {
@@ -1270,14 +1387,23 @@ impl PipelineBuilder {
         }
 
         let tenant = self.ctx.get_tenant();
+        let operator = self.ctx.get_spill_operator();
         self.exchange_injector = match params.aggregate_functions.is_empty() {
             true => with_mappedhash_method!(|T| match method.clone() {
-                HashMethodKind::T(method) =>
-                    AggregateInjector::<_, ()>::create(tenant.clone(), method, params.clone()),
+                HashMethodKind::T(method) => AggregateInjector::<_, ()>::create(
+                    tenant.clone(),
+                    operator.clone(),
+                    method,
+                    params.clone()
+                ),
             }),
             false => with_mappedhash_method!(|T| match method.clone() {
-                HashMethodKind::T(method) =>
-                    AggregateInjector::<_, usize>::create(tenant.clone(), method, params.clone()),
+                HashMethodKind::T(method) => AggregateInjector::<_, usize>::create(
+                    tenant.clone(),
+                    operator.clone(),
+                    method,
+                    params.clone()
+                ),
             }),
         };
 
@@ -1334,6 +1460,7 @@ impl PipelineBuilder {
         let method = DataBlock::choose_hash_method(&sample_block, group_cols, efficiently_memory)?;
 
         let tenant = self.ctx.get_tenant();
+        let operator = self.ctx.get_spill_operator();
         let old_inject = self.exchange_injector.clone();
 
         match params.aggregate_functions.is_empty() {
@@ -1341,8 +1468,12 @@ impl PipelineBuilder {
                 HashMethodKind::T(v) => {
                     let input: &PhysicalPlan = &aggregate.input;
                     if matches!(input, PhysicalPlan::ExchangeSource(_)) {
-                        self.exchange_injector =
-                            AggregateInjector::<_, ()>::create(tenant, v.clone(), params.clone());
+                        self.exchange_injector = AggregateInjector::<_, ()>::create(
+                            tenant,
+                            operator,
+                            v.clone(),
+                            params.clone(),
+                        );
                     }
 
                     self.build_pipeline(&aggregate.input)?;
@@ -1363,6 +1494,7 @@ impl PipelineBuilder {
                     if matches!(input, PhysicalPlan::ExchangeSource(_)) {
                         self.exchange_injector = AggregateInjector::<_, usize>::create(
                             tenant,
+                            operator,
                             v.clone(),
                             params.clone(),
                         );
@@ -1382,6 +1514,35 @@ impl PipelineBuilder {
         }
     }
 
+    fn build_aggregate_streaming(&mut self, aggregate: &AggregateStreaming) -> Result<()> {
+        self.build_pipeline(&aggregate.input)?;
+
+        let params = Self::build_aggregator_params(
+            aggregate.input.output_schema()?,
+            &aggregate.group_by,
+            &aggregate.agg_funcs,
+            None,
+        )?;
+        let max_block_size = self.ctx.get_settings().get_max_block_size()? as usize;
+
+        // The input is sorted, keep the order of the rows with a single stream.
+        self.main_pipeline.try_resize(1)?;
+        self.main_pipeline.add_transform(|input, output| {
+            let transform =
+                TransformStreamingAggregate::try_create(input, output, &params, max_block_size)?;
+
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProcessorProfileWrapper::create(
+                    transform,
+                    aggregate.plan_id,
+                    self.proc_profs.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })
+    }
+
     pub fn build_aggregator_params(
         input_schema: DataSchemaRef,
         group_by: &[IndexType],
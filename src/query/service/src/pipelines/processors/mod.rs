@@ -15,6 +15,7 @@
 pub use common_pipeline_core::processors::*;
 pub(crate) mod transforms;
 
+pub use transforms::hash_join::HashJoinBuildCache;
 pub use transforms::hash_join::TransformHashJoinBuild;
 pub use transforms::hash_join::TransformHashJoinProbe;
 pub use transforms::AggregatorParams;
@@ -26,7 +26,7 @@ use common_hashtable::HashtableEntryRefLike;
 use common_hashtable::HashtableLike;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::Pipeline;
-use common_storage::DataOperator;
+use opendal::Operator;
 use strength_reduce::StrengthReducedU64;
 
 use crate::api::DataExchange;
@@ -187,6 +187,7 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> FlightScatter
 pub struct AggregateInjector<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> {
     method: Method,
     tenant: String,
+    operator: Operator,
     aggregator_params: Arc<AggregatorParams>,
     _phantom: PhantomData<V>,
 }
@@ -194,12 +195,14 @@ pub struct AggregateInjector<Method: HashMethodBounds, V: Copy + Send + Sync + '
 impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> AggregateInjector<Method, V> {
     pub fn create(
         tenant: String,
+        operator: Operator,
         method: Method,
         params: Arc<AggregatorParams>,
     ) -> Arc<dyn ExchangeInjector> {
         Arc::new(AggregateInjector::<Method, V> {
             method,
             tenant,
+            operator,
             aggregator_params: params,
             _phantom: Default::default(),
         })
@@ -241,7 +244,7 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
         let method = &self.method;
         let params = self.aggregator_params.clone();
 
-        let operator = DataOperator::instance().operator();
+        let operator = self.operator.clone();
         let location_prefix = format!("_aggregate_spill/{}", self.tenant);
 
         pipeline.add_transform(|input, output| {
@@ -286,7 +289,7 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
     ) -> Result<()> {
         let method = &self.method;
         let params = self.aggregator_params.clone();
-        let operator = DataOperator::instance().operator();
+        let operator = self.operator.clone();
         let location_prefix = format!("_aggregate_spill/{}", self.tenant);
 
         let schema = shuffle_params.schema.clone();
@@ -0,0 +1,232 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bumpalo::Bump;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_functions::aggregates::AggregateFunctionRef;
+use common_functions::aggregates::StateAddr;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::transform_accumulating::AccumulatingTransform;
+use common_pipeline_transforms::processors::transforms::transform_accumulating::AccumulatingTransformer;
+
+use crate::pipelines::processors::AggregatorParams;
+
+/// Aggregate the input sorted by the group keys.
+///
+/// The rows of a group are adjacent in the input, so only the states of the current group
+/// are kept, and a group is finished once a row of the next group arrives.
+pub struct TransformStreamingAggregate {
+    #[allow(dead_code)]
+    arena: Bump,
+    places: Vec<StateAddr>,
+    funcs: Vec<AggregateFunctionRef>,
+    arg_indices: Vec<Vec<usize>>,
+    group_columns: Vec<usize>,
+    group_data_types: Vec<DataType>,
+
+    /// The values of the group keys of the current group.
+    current_group: Option<Vec<Scalar>>,
+    agg_builders: Vec<ColumnBuilder>,
+    group_builders: Vec<ColumnBuilder>,
+    max_block_size: usize,
+}
+
+impl TransformStreamingAggregate {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        params: &Arc<AggregatorParams>,
+        max_block_size: usize,
+    ) -> Result<Box<dyn Processor>> {
+        let arena = Bump::new();
+        let mut places = Vec::with_capacity(params.offsets_aggregate_states.len());
+        if let Some(layout) = params.layout {
+            let place: StateAddr = arena.alloc_layout(layout).into();
+            for (idx, func) in params.aggregate_functions.iter().enumerate() {
+                let arg_place = place.next(params.offsets_aggregate_states[idx]);
+                func.init_state(arg_place);
+                places.push(arg_place);
+            }
+        }
+
+        let mut transform = TransformStreamingAggregate {
+            arena,
+            places,
+            funcs: params.aggregate_functions.clone(),
+            arg_indices: params.aggregate_functions_arguments.clone(),
+            group_columns: params.group_columns.clone(),
+            group_data_types: params.group_data_types.clone(),
+            current_group: None,
+            agg_builders: vec![],
+            group_builders: vec![],
+            max_block_size,
+        };
+        transform.reset_builders()?;
+
+        Ok(AccumulatingTransformer::create(input, output, transform))
+    }
+
+    fn reset_builders(&mut self) -> Result<()> {
+        self.agg_builders = self
+            .funcs
+            .iter()
+            .map(|func| {
+                Ok(ColumnBuilder::with_capacity(
+                    &func.return_type()?,
+                    self.max_block_size,
+                ))
+            })
+            .collect::<Result<_>>()?;
+        self.group_builders = self
+            .group_data_types
+            .iter()
+            .map(|data_type| ColumnBuilder::with_capacity(data_type, self.max_block_size))
+            .collect();
+        Ok(())
+    }
+
+    /// Output the result of the current group, and reset the states for the next group.
+    fn finish_group(&mut self, group: Vec<Scalar>) -> Result<()> {
+        for (idx, func) in self.funcs.iter().enumerate() {
+            let place = self.places[idx];
+            func.merge_result(place, &mut self.agg_builders[idx])?;
+            if func.need_manual_drop_state() {
+                unsafe { func.drop_state(place) }
+            }
+            func.init_state(place);
+        }
+        for (builder, value) in self.group_builders.iter_mut().zip(group.iter()) {
+            builder.push(value.as_ref());
+        }
+        Ok(())
+    }
+
+    fn num_groups(&self) -> usize {
+        self.group_builders
+            .first()
+            .map_or(0, |builder| builder.len())
+    }
+
+    fn build_block(&mut self) -> Result<DataBlock> {
+        let agg_builders = std::mem::take(&mut self.agg_builders);
+        let group_builders = std::mem::take(&mut self.group_builders);
+        self.reset_builders()?;
+
+        let columns = agg_builders
+            .into_iter()
+            .chain(group_builders)
+            .map(|builder| builder.build())
+            .collect();
+        Ok(DataBlock::new_from_columns(columns))
+    }
+}
+
+impl AccumulatingTransform for TransformStreamingAggregate {
+    const NAME: &'static str = "TransformStreamingAggregate";
+
+    fn transform(&mut self, block: DataBlock) -> Result<Vec<DataBlock>> {
+        if block.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let block = block.convert_to_full();
+        let num_rows = block.num_rows();
+        let group_columns = self
+            .group_columns
+            .iter()
+            .map(|index| block.get_by_offset(*index).value.as_column().unwrap())
+            .collect::<Vec<&Column>>();
+        let same_group = |a: usize, b: usize| {
+            group_columns
+                .iter()
+                .all(|column| column.index(a) == column.index(b))
+        };
+
+        let mut blocks = vec![];
+        let mut start = 0;
+        while start < num_rows {
+            let mut end = start + 1;
+            while end < num_rows && same_group(start, end) {
+                end += 1;
+            }
+
+            let is_current_group = self.current_group.as_ref().is_some_and(|group| {
+                group
+                    .iter()
+                    .zip(group_columns.iter())
+                    .all(|(value, column)| column.index(start) == Some(value.as_ref()))
+            });
+            if !is_current_group {
+                if let Some(group) = self.current_group.take() {
+                    self.finish_group(group)?;
+                    if self.num_groups() >= self.max_block_size {
+                        blocks.push(self.build_block()?);
+                    }
+                }
+                self.current_group = Some(
+                    group_columns
+                        .iter()
+                        .map(|column| column.index(start).unwrap().to_owned())
+                        .collect(),
+                );
+            }
+
+            // Accumulate the rows of the same group at once.
+            for (idx, func) in self.funcs.iter().enumerate() {
+                let arg_columns = self.arg_indices[idx]
+                    .iter()
+                    .map(|index| {
+                        let column = block.get_by_offset(*index).value.as_column().unwrap();
+                        column.slice(start..end)
+                    })
+                    .collect::<Vec<_>>();
+                func.accumulate(self.places[idx], &arg_columns, None, end - start)?;
+            }
+            start = end;
+        }
+
+        Ok(blocks)
+    }
+
+    fn on_finish(&mut self, generate_data: bool) -> Result<Vec<DataBlock>> {
+        let mut generate_data_block = vec![];
+
+        if generate_data {
+            if let Some(group) = self.current_group.take() {
+                self.finish_group(group)?;
+            }
+            if self.num_groups() > 0 {
+                generate_data_block.push(self.build_block()?);
+            }
+        }
+
+        // destroy states
+        for (place, func) in self.places.iter().zip(self.funcs.iter()) {
+            if func.need_manual_drop_state() {
+                unsafe { func.drop_state(*place) }
+            }
+        }
+
+        Ok(generate_data_block)
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::type_check::check_function;
 use common_expression::Expr;
@@ -31,12 +32,20 @@ pub struct MarkJoinDesc {
     pub(crate) has_null: RwLock<bool>,
 }
 
+pub struct AsofJoinDesc {
+    /// The build side argument of the inequality condition.
+    pub(crate) build_expr: Expr,
+    /// Whether the nearest build row has the largest value, e.g. `probe.ts >= build.ts`.
+    pub(crate) nearest_is_max: bool,
+}
+
 pub struct HashJoinDesc {
     pub(crate) build_keys: Vec<Expr>,
     pub(crate) probe_keys: Vec<Expr>,
     pub(crate) join_type: JoinType,
     pub(crate) other_predicate: Option<Expr>,
     pub(crate) marker_join_desc: MarkJoinDesc,
+    pub(crate) asof_join_desc: Option<AsofJoinDesc>,
     /// Whether the Join are derived from correlated subquery.
     pub(crate) from_correlated_subquery: bool,
 }
@@ -56,6 +65,12 @@ impl HashJoinDesc {
             .map(|k| k.as_expr(&BUILTIN_FUNCTIONS))
             .collect();
 
+        let asof_join_desc = if join.join_type.is_asof_join() {
+            Some(Self::asof_join_desc(other_predicate.as_ref())?)
+        } else {
+            None
+        };
+
         Ok(HashJoinDesc {
             join_type: join.join_type.clone(),
             build_keys,
@@ -65,10 +80,17 @@ impl HashJoinDesc {
                 has_null: RwLock::new(false),
                 // marker_index: join.marker_index,
             },
+            asof_join_desc,
             from_correlated_subquery: join.from_correlated_subquery,
         })
     }
 
+    /// Joins without equi conditions are executed by nested loop join instead of probing
+    /// the hash table, the outer and semi joins still probe a hash table of empty keys.
+    pub(crate) fn is_nested_loop_join(&self) -> bool {
+        self.build_keys.is_empty() && matches!(self.join_type, JoinType::Inner | JoinType::Cross)
+    }
+
     fn join_predicate(non_equi_conditions: &[RemoteExpr]) -> Result<Option<Expr>> {
         non_equi_conditions
             .iter()
@@ -77,4 +99,27 @@ impl HashJoinDesc {
                 check_function(None, "and_filters", &[], &[lhs, rhs], &BUILTIN_FUNCTIONS)
             })
     }
+
+    // The binder ensures that the non-equi condition of asof join is a comparison
+    // with the probe side argument first.
+    fn asof_join_desc(other_predicate: Option<&Expr>) -> Result<AsofJoinDesc> {
+        if let Some(Expr::FunctionCall { function, args, .. }) = other_predicate {
+            let nearest_is_max = match function.signature.name.as_str() {
+                "gt" | "gte" => Some(true),
+                "lt" | "lte" => Some(false),
+                _ => None,
+            };
+            if let (Some(nearest_is_max), [_, build_expr]) = (nearest_is_max, args.as_slice()) {
+                return Ok(AsofJoinDesc {
+                    build_expr: build_expr.clone(),
+                    nearest_is_max,
+                });
+            }
+        }
+
+        Err(ErrorCode::Internal(format!(
+            "Invalid non-equi condition of asof join: {:?}",
+            other_predicate
+        )))
+    }
 }
@@ -0,0 +1,249 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cell::SyncUnsafeCell;
+use std::sync::Arc;
+
+use common_base::base::GlobalInstance;
+use common_cache::DefaultHashBuilder;
+use common_cache::Meter;
+use common_catalog::table_context::TableContext;
+use common_config::CacheConfig;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::RemoteExpr;
+use common_sql::executor::HashJoin;
+use common_sql::executor::PhysicalPlan;
+use common_sql::plans::JoinType;
+use storages_common_cache::CacheAccessor;
+use storages_common_cache::InMemoryCacheBuilder;
+use storages_common_cache::InMemoryItemCacheHolder;
+use storages_common_cache::Named;
+use storages_common_cache::NamedCache;
+
+use crate::pipelines::processors::transforms::hash_join::hash_join_state::HashJoinHashTable;
+use crate::sessions::QueryContext;
+
+/// The hash table built from the build side of a hash join, which can be shared by queries.
+///
+/// Once cached, the hash table and the chunks are only read by the probe processors.
+pub struct HashJoinBuildCacheItem {
+    pub(crate) hash_table: Arc<SyncUnsafeCell<HashJoinHashTable>>,
+    pub(crate) chunks: Arc<SyncUnsafeCell<Vec<DataBlock>>>,
+    pub(crate) build_num_rows: usize,
+    pub(crate) is_build_projected: bool,
+    /// The entries of `hash_table` point into these spaces.
+    _raw_entry_spaces: Vec<Vec<u8>>,
+    memory_size: usize,
+}
+
+impl HashJoinBuildCacheItem {
+    pub(crate) fn create(
+        hash_table: Arc<SyncUnsafeCell<HashJoinHashTable>>,
+        chunks: Arc<SyncUnsafeCell<Vec<DataBlock>>>,
+        build_num_rows: usize,
+        is_build_projected: bool,
+        raw_entry_spaces: Vec<Vec<u8>>,
+    ) -> Self {
+        let chunks_size = unsafe { &*chunks.get() }
+            .iter()
+            .map(|chunk| chunk.memory_size())
+            .sum::<usize>();
+        let entries_size = raw_entry_spaces
+            .iter()
+            .map(|space| space.capacity())
+            .sum::<usize>();
+        // See `HashJoinHashTable::with_build_row_num`.
+        let pointers_size = std::cmp::max((build_num_rows * 2).next_power_of_two(), 1 << 10)
+            * std::mem::size_of::<u64>();
+
+        HashJoinBuildCacheItem {
+            hash_table,
+            chunks,
+            build_num_rows,
+            is_build_projected,
+            _raw_entry_spaces: raw_entry_spaces,
+            memory_size: chunks_size + entries_size + pointers_size,
+        }
+    }
+}
+
+pub struct HashJoinBuildCacheMeter;
+
+impl Meter<String, Arc<HashJoinBuildCacheItem>> for HashJoinBuildCacheMeter {
+    type Measure = usize;
+
+    fn measure<Q: ?Sized>(&self, _: &Q, value: &Arc<HashJoinBuildCacheItem>) -> Self::Measure {
+        value.memory_size
+    }
+}
+
+type BuildCache = NamedCache<
+    InMemoryItemCacheHolder<HashJoinBuildCacheItem, DefaultHashBuilder, HashJoinBuildCacheMeter>,
+>;
+
+/// Caches the hash tables built from small tables across queries, keyed by the table snapshot.
+pub struct HashJoinBuildCache {
+    cache: Option<BuildCache>,
+}
+
+impl HashJoinBuildCache {
+    pub fn init(config: &CacheConfig) -> Result<()> {
+        let cache = match config.hash_join_build_bytes {
+            0 => None,
+            capacity => Some(
+                InMemoryCacheBuilder::new_in_memory_cache(capacity, HashJoinBuildCacheMeter)
+                    .name_with("hash_join_build"),
+            ),
+        };
+
+        GlobalInstance::set(Arc::new(HashJoinBuildCache { cache }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<HashJoinBuildCache> {
+        GlobalInstance::get()
+    }
+
+    pub fn get(&self, key: &str) -> Option<Arc<HashJoinBuildCacheItem>> {
+        self.cache.as_ref().and_then(|cache| cache.get(key))
+    }
+
+    pub fn put(&self, key: String, item: Arc<HashJoinBuildCacheItem>) {
+        if let Some(cache) = &self.cache {
+            cache.put(key, item);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.as_ref().map_or(0, |cache| cache.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the key of the hash table built for the join, or `None` if it can not be cached.
+    ///
+    /// Only the hash tables built from a full scan of a small table are cached, the key is
+    /// composed of the snapshot location, the scanned columns and the join keys, so that the
+    /// cached hash table is invalidated as soon as the table is changed.
+    pub fn cache_key(ctx: &QueryContext, join: &HashJoin) -> Result<Option<String>> {
+        if Self::instance().cache.is_none() {
+            return Ok(None);
+        }
+
+        // The probe of the other join types changes the build side states.
+        if !matches!(
+            join.join_type,
+            JoinType::Inner | JoinType::Left | JoinType::LeftSemi | JoinType::LeftAnti
+        ) || join.contain_runtime_filter
+            || !ctx.get_cluster().is_empty()
+        {
+            return Ok(None);
+        }
+
+        let PhysicalPlan::TableScan(scan) = join.build.as_ref() else {
+            return Ok(None);
+        };
+        let source = &scan.source;
+
+        let max_table_bytes = ctx
+            .get_settings()
+            .get_hash_join_build_cache_max_table_bytes()? as usize;
+        if max_table_bytes == 0 || source.statistics.read_bytes > max_table_bytes {
+            return Ok(None);
+        }
+
+        let Some(snapshot_loc) = source.statistics.snapshot.as_ref() else {
+            return Ok(None);
+        };
+
+        if source.query_internal_columns
+            || source.data_mask_policy.is_some()
+            || source.parts.len() != source.statistics.partitions_total
+        {
+            return Ok(None);
+        }
+
+        if let Some(push_downs) = &source.push_downs {
+            if push_downs.filter.is_some()
+                || push_downs.prewhere.is_some()
+                || push_downs.limit.is_some()
+                || push_downs.virtual_columns.is_some()
+                || push_downs.agg_index.is_some()
+            {
+                return Ok(None);
+            }
+        }
+
+        let columns = scan.name_mapping.keys().collect::<Vec<_>>();
+        let build_keys = join
+            .build_keys
+            .iter()
+            .map(normalize_expr)
+            .collect::<Vec<_>>();
+        let mut build_projections = join.build_projections.iter().collect::<Vec<_>>();
+        build_projections.sort();
+
+        Ok(Some(format!(
+            "{}-{:?}-{:?}-{:?}-{}",
+            snapshot_loc, columns, build_keys, build_projections, join.join_type
+        )))
+    }
+}
+
+// Remove the parts of the expression which vary between queries, like spans and
+// the display names carrying the column indexes of the metadata.
+fn normalize_expr(expr: &RemoteExpr) -> RemoteExpr {
+    match expr {
+        RemoteExpr::Constant {
+            scalar, data_type, ..
+        } => RemoteExpr::Constant {
+            span: None,
+            scalar: scalar.clone(),
+            data_type: data_type.clone(),
+        },
+        RemoteExpr::ColumnRef { id, data_type, .. } => RemoteExpr::ColumnRef {
+            span: None,
+            id: *id,
+            data_type: data_type.clone(),
+            display_name: String::new(),
+        },
+        RemoteExpr::Cast {
+            is_try,
+            expr,
+            dest_type,
+            ..
+        } => RemoteExpr::Cast {
+            span: None,
+            is_try: *is_try,
+            expr: Box::new(normalize_expr(expr)),
+            dest_type: dest_type.clone(),
+        },
+        RemoteExpr::FunctionCall {
+            id,
+            generics,
+            args,
+            return_type,
+            ..
+        } => RemoteExpr::FunctionCall {
+            span: None,
+            id: id.clone(),
+            generics: generics.clone(),
+            args: args.iter().map(normalize_expr).collect(),
+            return_type: return_type.clone(),
+        },
+    }
+}
@@ -49,10 +49,12 @@ use parking_lot::RwLock;
 
 use crate::pipelines::processors::transforms::hash_join::common::set_validity;
 use crate::pipelines::processors::transforms::hash_join::desc::MARKER_KIND_FALSE;
+use crate::pipelines::processors::transforms::hash_join::hash_join_build_cache::HashJoinBuildCacheItem;
 use crate::pipelines::processors::transforms::hash_join::hash_join_state::FixedKeyHashJoinHashTable;
 use crate::pipelines::processors::transforms::hash_join::hash_join_state::HashJoinHashTable;
 use crate::pipelines::processors::transforms::hash_join::hash_join_state::SerializerHashJoinHashTable;
 use crate::pipelines::processors::transforms::hash_join::hash_join_state::SingleStringHashJoinHashTable;
+use crate::pipelines::processors::transforms::hash_join::HashJoinBuildCache;
 use crate::pipelines::processors::HashJoinState;
 use crate::sessions::QueryContext;
 
@@ -88,6 +90,8 @@ pub struct HashJoinBuildState {
     pub(crate) build_worker_num: Arc<AtomicU32>,
     /// Tasks for building hash table.
     pub(crate) build_hash_table_tasks: Arc<RwLock<VecDeque<(usize, usize)>>>,
+    /// The key of the hash table in the hash join build cache, if it can be cached.
+    pub(crate) build_cache_key: Option<String>,
 }
 
 impl HashJoinBuildState {
@@ -96,6 +100,7 @@ impl HashJoinBuildState {
         build_keys: &[RemoteExpr],
         build_projections: &ColumnSet,
         hash_join_state: Arc<HashJoinState>,
+        build_cache_key: Option<String>,
     ) -> Result<Arc<HashJoinBuildState>> {
         let hash_key_types = build_keys
             .iter()
@@ -115,6 +120,7 @@ impl HashJoinBuildState {
             build_projections: Arc::new(build_projections.clone()),
             build_worker_num: Arc::new(Default::default()),
             build_hash_table_tasks: Arc::new(Default::default()),
+            build_cache_key,
         }))
     }
 
@@ -141,7 +147,7 @@ impl HashJoinBuildState {
         let mut data_block = data_block;
         if matches!(
             self.hash_join_state.hash_join_desc.join_type,
-            JoinType::Left | JoinType::LeftSingle | JoinType::Full
+            JoinType::Left | JoinType::LeftSingle | JoinType::Full | JoinType::LeftAsof
         ) {
             let mut validity = MutableBitmap::new();
             validity.extend_constant(data_block.num_rows(), true);
@@ -214,7 +220,8 @@ impl HashJoinBuildState {
             // Get the number of rows of the build side.
             let build_num_rows = unsafe { *self.hash_join_state.build_num_rows.get() };
 
-            if self.hash_join_state.hash_join_desc.join_type == JoinType::Cross {
+            if self.hash_join_state.hash_join_desc.is_nested_loop_join() {
+                self.project_build_chunks();
                 let mut row_space_build_done = self.row_space_build_done.lock();
                 *row_space_build_done = true;
                 self.row_space_build_done_notify.notify_waiters();
@@ -324,6 +331,19 @@ impl HashJoinBuildState {
         Ok(())
     }
 
+    // The hash table isn't built for nested loop join, project the build chunks here.
+    fn project_build_chunks(&self) {
+        let chunks = unsafe { &mut *self.hash_join_state.chunks.get() };
+        for chunk in chunks.iter_mut() {
+            *chunk = chunk.clone().project(&self.build_projections);
+        }
+        if self.build_projections.is_empty() {
+            self.hash_join_state
+                .is_build_projected
+                .store(false, Ordering::SeqCst);
+        }
+    }
+
     /// Divide the finalize phase into multiple tasks.
     pub fn generate_finalize_task(&self) -> Result<()> {
         let chunks_len = unsafe { &*self.hash_join_state.chunks.get() }.len();
@@ -579,6 +599,10 @@ impl HashJoinBuildState {
         let mut count = self.hash_join_state.hash_table_builders.lock();
         *count -= 1;
         if *count == 0 {
+            if let Some(build_cache_key) = &self.build_cache_key {
+                self.put_build_cache(build_cache_key.clone());
+            }
+
             let mut build_done = self.hash_join_state.build_done.lock();
             *build_done = true;
             self.hash_join_state.build_done_notify.notify_waiters();
@@ -586,6 +610,22 @@ impl HashJoinBuildState {
         Ok(())
     }
 
+    // Share the built hash table with the following queries.
+    fn put_build_cache(&self, key: String) {
+        let raw_entry_spaces = std::mem::take(&mut *self.raw_entry_spaces.lock());
+        let item = Arc::new(HashJoinBuildCacheItem::create(
+            self.hash_join_state.hash_table.clone(),
+            self.hash_join_state.chunks.clone(),
+            unsafe { *self.hash_join_state.build_num_rows.get() },
+            self.hash_join_state
+                .is_build_projected
+                .load(Ordering::Relaxed),
+            raw_entry_spaces,
+        ));
+        *self.hash_join_state.cached_build.lock() = Some(item.clone());
+        HashJoinBuildCache::instance().put(key, item);
+    }
+
     #[async_backtrace::framed]
     pub(crate) async fn wait_row_space_build_finish(&self) -> Result<()> {
         let notified = {
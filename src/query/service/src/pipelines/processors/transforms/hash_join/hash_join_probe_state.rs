@@ -102,6 +102,9 @@ impl HashJoinProbeState {
 
     /// Probe the hash table and retrieve matched rows as DataBlocks.
     pub fn probe(&self, input: DataBlock, probe_state: &mut ProbeState) -> Result<Vec<DataBlock>> {
+        if self.hash_join_state.hash_join_desc.is_nested_loop_join() {
+            return self.nested_loop_join(input, probe_state);
+        }
         match self.hash_join_state.hash_join_desc.join_type {
             JoinType::Inner
             | JoinType::LeftSemi
@@ -114,8 +117,10 @@ impl HashJoinProbeState {
             | JoinType::LeftSingle
             | JoinType::RightSingle
             | JoinType::Right
-            | JoinType::Full => self.probe_join(input, probe_state),
-            JoinType::Cross => self.cross_join(input, probe_state),
+            | JoinType::Full
+            | JoinType::Asof
+            | JoinType::LeftAsof => self.probe_join(input, probe_state),
+            JoinType::Cross => self.nested_loop_join(input, probe_state),
         }
     }
 
@@ -201,7 +206,11 @@ impl HashJoinProbeState {
         if self.hash_join_state.fast_return()?
             && matches!(
                 self.hash_join_state.hash_join_desc.join_type,
-                JoinType::Left | JoinType::LeftSingle | JoinType::Full | JoinType::LeftAnti
+                JoinType::Left
+                    | JoinType::LeftSingle
+                    | JoinType::Full
+                    | JoinType::LeftAnti
+                    | JoinType::LeftAsof
             )
         {
             return self.left_fast_return(input, is_probe_projected);
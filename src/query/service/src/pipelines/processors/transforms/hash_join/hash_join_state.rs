@@ -33,6 +33,7 @@ use ethnum::U256;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
 
+use crate::pipelines::processors::transforms::hash_join::hash_join_build_cache::HashJoinBuildCacheItem;
 use crate::pipelines::processors::transforms::hash_join::row::RowSpace;
 use crate::pipelines::processors::transforms::hash_join::util::build_schema_wrap_nullable;
 use crate::pipelines::processors::HashJoinDesc;
@@ -97,6 +98,9 @@ pub struct HashJoinState {
     pub(crate) outer_scan_map: Arc<SyncUnsafeCell<Vec<Vec<bool>>>>,
     /// LeftMarkScan map, initialized at `HashJoinBuildState`, used in `HashJoinProbeState`
     pub(crate) mark_scan_map: Arc<SyncUnsafeCell<Vec<Vec<u8>>>>,
+    /// The hash table shared with the hash join build cache, it keeps the hash table
+    /// alive until the query is finished.
+    pub(crate) cached_build: Mutex<Option<Arc<HashJoinBuildCacheItem>>>,
 }
 
 impl HashJoinState {
@@ -106,31 +110,48 @@ impl HashJoinState {
         build_projections: &ColumnSet,
         hash_join_desc: HashJoinDesc,
         probe_to_build: &[(usize, (bool, bool))],
+        cached_build: Option<Arc<HashJoinBuildCacheItem>>,
     ) -> Result<Arc<HashJoinState>> {
         if matches!(
             hash_join_desc.join_type,
-            JoinType::Left | JoinType::LeftSingle
+            JoinType::Left | JoinType::LeftSingle | JoinType::LeftAsof
         ) {
             build_schema = build_schema_wrap_nullable(&build_schema);
         };
         if hash_join_desc.join_type == JoinType::Full {
             build_schema = build_schema_wrap_nullable(&build_schema);
         }
+        // The hash table is already built if it is from the hash join build cache.
+        let (hash_table, chunks, build_num_rows, is_build_projected) = match &cached_build {
+            Some(item) => (
+                item.hash_table.clone(),
+                item.chunks.clone(),
+                item.build_num_rows,
+                item.is_build_projected,
+            ),
+            None => (
+                Arc::new(SyncUnsafeCell::new(HashJoinHashTable::Null)),
+                Arc::new(SyncUnsafeCell::new(Vec::new())),
+                0,
+                true,
+            ),
+        };
         Ok(Arc::new(HashJoinState {
-            hash_table: Arc::new(SyncUnsafeCell::new(HashJoinHashTable::Null)),
+            hash_table,
             hash_table_builders: Mutex::new(0),
-            build_done: Mutex::new(false),
+            build_done: Mutex::new(cached_build.is_some()),
             build_done_notify: Arc::new(Default::default()),
             hash_join_desc,
             interrupt: Arc::new(AtomicBool::new(false)),
             fast_return: Arc::new(Default::default()),
             row_space: RowSpace::new(ctx, build_schema, build_projections)?,
-            chunks: Arc::new(SyncUnsafeCell::new(Vec::new())),
-            build_num_rows: Arc::new(SyncUnsafeCell::new(0)),
+            chunks,
+            build_num_rows: Arc::new(SyncUnsafeCell::new(build_num_rows)),
             probe_to_build: Arc::new(probe_to_build.to_vec()),
-            is_build_projected: Arc::new(AtomicBool::new(true)),
+            is_build_projected: Arc::new(AtomicBool::new(is_build_projected)),
             outer_scan_map: Arc::new(SyncUnsafeCell::new(Vec::new())),
             mark_scan_map: Arc::new(SyncUnsafeCell::new(Vec::new())),
+            cached_build: Mutex::new(cached_build),
         }))
     }
 
@@ -14,6 +14,7 @@
 
 mod common;
 mod desc;
+mod hash_join_build_cache;
 mod hash_join_build_state;
 mod hash_join_probe_state;
 mod hash_join_state;
@@ -26,6 +27,8 @@ mod transform_hash_join_probe;
 mod util;
 
 pub use desc::HashJoinDesc;
+pub use hash_join_build_cache::HashJoinBuildCache;
+pub use hash_join_build_cache::HashJoinBuildCacheItem;
 pub use hash_join_build_state::HashJoinBuildState;
 pub use hash_join_probe_state::HashJoinProbeState;
 pub use hash_join_state::FixedKeyHashJoinHashTable;
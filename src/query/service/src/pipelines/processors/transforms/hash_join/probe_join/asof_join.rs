@@ -0,0 +1,258 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::iter::TrustedLen;
+use std::sync::atomic::Ordering;
+
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_hashtable::HashJoinHashtableLike;
+use common_hashtable::RowPtr;
+
+use crate::pipelines::processors::transforms::hash_join::HashJoinProbeState;
+use crate::pipelines::processors::transforms::hash_join::ProbeState;
+use crate::sql::plans::JoinType;
+
+impl HashJoinProbeState {
+    /// Asof join matches each probe row with at most one build row: among the rows with the
+    /// same keys which satisfy the inequality condition, the one with the nearest value.
+    pub(crate) fn probe_asof_join<'a, H: HashJoinHashtableLike, IT>(
+        &self,
+        hash_table: &H,
+        probe_state: &mut ProbeState,
+        keys_iter: IT,
+        input: &DataBlock,
+        is_probe_projected: bool,
+    ) -> Result<Vec<DataBlock>>
+    where
+        IT: Iterator<Item = &'a H::Key> + TrustedLen,
+        H::Key: 'a,
+    {
+        let input_num_rows = input.num_rows();
+        let max_block_size = probe_state.max_block_size;
+        let valids = &probe_state.valids;
+        let probe_indexes = &mut probe_state.probe_indexes;
+        let local_build_indexes = &mut probe_state.build_indexes;
+        let local_build_indexes_ptr = local_build_indexes.as_mut_ptr();
+
+        let mut matched_num = 0;
+        let mut probe_indexes_occupied = 0;
+        // The nearest build row of each probe row, with the value of the build side argument.
+        let mut nearest: Vec<Option<(Scalar, RowPtr)>> = vec![None; input_num_rows];
+
+        // Start to probe hash table.
+        for (i, key) in keys_iter.enumerate() {
+            let (mut probe_matched, mut incomplete_ptr) = self.probe_key(
+                hash_table,
+                key,
+                valids,
+                i,
+                local_build_indexes_ptr,
+                matched_num,
+                max_block_size,
+            );
+            if probe_matched > 0 {
+                matched_num += probe_matched;
+                probe_indexes[probe_indexes_occupied] = (i as u32, probe_matched as u32);
+                probe_indexes_occupied += 1;
+            }
+            if matched_num >= max_block_size || i == input_num_rows - 1 {
+                loop {
+                    if self.hash_join_state.interrupt.load(Ordering::Relaxed) {
+                        return Err(ErrorCode::AbortedQuery(
+                            "Aborted query, because the server is shutting down or the query was killed.",
+                        ));
+                    }
+
+                    if matched_num > 0 {
+                        self.update_asof_nearest(
+                            input,
+                            &probe_indexes[0..probe_indexes_occupied],
+                            &local_build_indexes[0..matched_num],
+                            is_probe_projected,
+                            &mut nearest,
+                        )?;
+                    }
+
+                    matched_num = 0;
+                    probe_indexes_occupied = 0;
+
+                    if incomplete_ptr == 0 {
+                        break;
+                    }
+                    (probe_matched, incomplete_ptr) = hash_table.next_incomplete_ptr(
+                        key,
+                        incomplete_ptr,
+                        local_build_indexes_ptr,
+                        matched_num,
+                        max_block_size,
+                    );
+
+                    if probe_matched > 0 {
+                        matched_num += probe_matched;
+                        probe_indexes[probe_indexes_occupied] = (i as u32, probe_matched as u32);
+                        probe_indexes_occupied += 1;
+                    }
+
+                    if matched_num < max_block_size && i != input_num_rows - 1 {
+                        break;
+                    }
+                }
+            }
+        }
+
+        let mut matched_probe_indexes = vec![];
+        let mut matched_build_indexes = vec![];
+        let mut unmatched_probe_indexes = vec![];
+        for (i, row) in nearest.into_iter().enumerate() {
+            match row {
+                Some((_, row_ptr)) => {
+                    matched_probe_indexes.push((i as u32, 1));
+                    matched_build_indexes.push(row_ptr);
+                }
+                None => unmatched_probe_indexes.push((i as u32, 1)),
+            }
+        }
+
+        let data_blocks = unsafe { &*self.hash_join_state.chunks.get() };
+        let build_num_rows = unsafe { *self.hash_join_state.build_num_rows.get() };
+        let is_build_projected = self
+            .hash_join_state
+            .is_build_projected
+            .load(Ordering::Relaxed);
+
+        let mut result_blocks = vec![];
+        for (probe_indexes, build_indexes) in matched_probe_indexes
+            .chunks(max_block_size)
+            .zip(matched_build_indexes.chunks(max_block_size))
+        {
+            let num_rows = build_indexes.len();
+            let probe_block = if is_probe_projected {
+                Some(DataBlock::take_compacted_indices(
+                    input,
+                    probe_indexes,
+                    num_rows,
+                )?)
+            } else {
+                None
+            };
+            let build_block = if is_build_projected {
+                Some(self.hash_join_state.row_space.gather(
+                    build_indexes,
+                    data_blocks,
+                    &build_num_rows,
+                )?)
+            } else {
+                None
+            };
+            result_blocks.push(self.merge_eq_block(probe_block, build_block, num_rows));
+        }
+
+        if self.hash_join_state.hash_join_desc.join_type == JoinType::LeftAsof {
+            for probe_indexes in unmatched_probe_indexes.chunks(max_block_size) {
+                result_blocks.push(self.create_left_join_null_block(
+                    input,
+                    probe_indexes,
+                    probe_indexes.len(),
+                    is_probe_projected,
+                    is_build_projected,
+                )?);
+            }
+        }
+        Ok(result_blocks)
+    }
+
+    // Evaluate the inequality condition on the matched rows, and keep the nearest build row
+    // of each probe row among the ones satisfying it.
+    fn update_asof_nearest(
+        &self,
+        input: &DataBlock,
+        probe_indexes: &[(u32, u32)],
+        build_indexes: &[RowPtr],
+        is_probe_projected: bool,
+        nearest: &mut [Option<(Scalar, RowPtr)>],
+    ) -> Result<()> {
+        let matched_num = build_indexes.len();
+        let data_blocks = unsafe { &*self.hash_join_state.chunks.get() };
+        let build_num_rows = unsafe { *self.hash_join_state.build_num_rows.get() };
+        let is_build_projected = self
+            .hash_join_state
+            .is_build_projected
+            .load(Ordering::Relaxed);
+
+        let probe_block = if is_probe_projected {
+            Some(DataBlock::take_compacted_indices(
+                input,
+                probe_indexes,
+                matched_num,
+            )?)
+        } else {
+            None
+        };
+        let build_block = if is_build_projected {
+            Some(self.hash_join_state.row_space.gather(
+                build_indexes,
+                data_blocks,
+                &build_num_rows,
+            )?)
+        } else {
+            None
+        };
+        let merged_block = self.merge_eq_block(probe_block, build_block, matched_num);
+
+        let hash_join_desc = &self.hash_join_state.hash_join_desc;
+        let (bm, _, all_false) = self.get_other_filters(
+            &merged_block,
+            hash_join_desc.other_predicate.as_ref().unwrap(),
+        )?;
+        if all_false {
+            return Ok(());
+        }
+
+        // Safe to unwrap.
+        let asof_join_desc = hash_join_desc.asof_join_desc.as_ref().unwrap();
+        let func_ctx = self.ctx.get_function_context()?;
+        let evaluator = Evaluator::new(&merged_block, &func_ctx, &BUILTIN_FUNCTIONS);
+        let values = evaluator
+            .run(&asof_join_desc.build_expr)?
+            .convert_to_full_column(asof_join_desc.build_expr.data_type(), matched_num);
+
+        let mut idx = 0;
+        for (probe_index, count) in probe_indexes {
+            let nearest_row = &mut nearest[*probe_index as usize];
+            for _ in 0..*count {
+                if bm.as_ref().map_or(true, |bm| bm.get_bit(idx)) {
+                    let value = unsafe { values.index_unchecked(idx) };
+                    let is_nearer = match nearest_row {
+                        Some((nearest_value, _)) if asof_join_desc.nearest_is_max => {
+                            value > nearest_value.as_ref()
+                        }
+                        Some((nearest_value, _)) => value < nearest_value.as_ref(),
+                        None => true,
+                    };
+                    if is_nearer {
+                        *nearest_row = Some((value.to_owned(), build_indexes[idx]));
+                    }
+                }
+                idx += 1;
+            }
+        }
+        Ok(())
+    }
+}
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::Ordering;
+
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::BlockEntry;
 use common_expression::DataBlock;
@@ -21,33 +24,92 @@ use crate::pipelines::processors::transforms::hash_join::HashJoinProbeState;
 use crate::pipelines::processors::transforms::hash_join::ProbeState;
 
 impl HashJoinProbeState {
-    pub(crate) fn cross_join(
+    /// Join the probe block with each build block kept in memory.
+    ///
+    /// The cross products are generated in blocks of about `max_block_size` rows,
+    /// and the non-equi conditions are evaluated over the whole blocks.
+    pub(crate) fn nested_loop_join(
         &self,
         input: DataBlock,
-        _probe_state: &mut ProbeState,
+        probe_state: &mut ProbeState,
     ) -> Result<Vec<DataBlock>> {
         let build_blocks = unsafe { &*self.hash_join_state.chunks.get() };
-        let build_num_rows = build_blocks
-            .iter()
-            .fold(0, |acc, block| acc + block.num_rows());
         let input_num_rows = input.num_rows();
-        if build_num_rows == 0 || input_num_rows == 0 {
+        if input_num_rows == 0 || build_blocks.iter().all(|block| block.is_empty()) {
             return Ok(vec![]);
         }
         let probe_block = input.project(&self.probe_projections);
-        let build_block = DataBlock::concat(build_blocks)?;
-        let mut result_blocks = Vec::with_capacity(input_num_rows);
-        for i in 0..input_num_rows {
-            result_blocks.push(self.merge_with_constant_block(
-                &build_block,
-                &probe_block,
-                i,
-                build_num_rows,
-            )?);
+        let other_predicate = self.hash_join_state.hash_join_desc.other_predicate.as_ref();
+
+        let mut result_blocks = vec![];
+        for build_block in build_blocks.iter() {
+            let build_num_rows = build_block.num_rows();
+            if build_num_rows == 0 {
+                continue;
+            }
+            // The number of probe rows joined with the build block at a time.
+            let probe_rows = std::cmp::max(1, probe_state.max_block_size / build_num_rows);
+            let mut start = 0;
+            while start < input_num_rows {
+                if self.hash_join_state.interrupt.load(Ordering::Relaxed) {
+                    return Err(ErrorCode::AbortedQuery(
+                        "Aborted query, because the server is shutting down or the query was killed.",
+                    ));
+                }
+
+                let end = std::cmp::min(start + probe_rows, input_num_rows);
+                let merged_block = if end - start == 1 {
+                    self.merge_with_constant_block(
+                        build_block,
+                        &probe_block,
+                        start,
+                        build_num_rows,
+                    )?
+                } else {
+                    self.merge_with_cross_block(build_block, &probe_block, start, end)?
+                };
+                start = end;
+
+                match other_predicate {
+                    Some(predicate) => {
+                        let (bm, all_true, all_false) =
+                            self.get_other_filters(&merged_block, predicate)?;
+                        if all_true {
+                            result_blocks.push(merged_block);
+                        } else if !all_false {
+                            // Safe to unwrap.
+                            result_blocks.push(merged_block.filter_with_bitmap(&bm.unwrap())?);
+                        }
+                    }
+                    None => result_blocks.push(merged_block),
+                }
+            }
         }
         Ok(result_blocks)
     }
 
+    // Merge build block and the cross product of the probe rows in [start, end).
+    fn merge_with_cross_block(
+        &self,
+        build_block: &DataBlock,
+        probe_block: &DataBlock,
+        start: usize,
+        end: usize,
+    ) -> Result<DataBlock> {
+        let build_num_rows = build_block.num_rows();
+        let num_rows = (end - start) * build_num_rows;
+        let indices = (start..end)
+            .map(|i| (i as u32, build_num_rows as u32))
+            .collect::<Vec<_>>();
+        let mut merged_block = probe_block.take_compacted_indices(&indices, num_rows)?;
+        let build_blocks = vec![build_block.clone(); end - start];
+        let replicated_build_block = DataBlock::concat(&build_blocks)?;
+        for col in replicated_build_block.columns() {
+            merged_block.add_column(col.clone());
+        }
+        Ok(merged_block)
+    }
+
     // Merge build block and probe block (1 row block)
     pub(crate) fn merge_with_constant_block(
         &self,
@@ -559,7 +559,7 @@ impl HashJoinProbeState {
         Ok(result_blocks)
     }
 
-    fn create_left_join_null_block(
+    pub(crate) fn create_left_join_null_block(
         &self,
         input: &DataBlock,
         indexes: &[(u32, u32)],
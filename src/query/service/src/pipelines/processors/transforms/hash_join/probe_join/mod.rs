@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod asof_join;
 mod cross_join;
 mod inner_join;
 mod left_join;
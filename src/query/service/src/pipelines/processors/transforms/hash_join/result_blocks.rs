@@ -155,6 +155,13 @@ impl HashJoinProbeState {
                     )
                 }
             }
+            JoinType::Asof | JoinType::LeftAsof => self.probe_asof_join::<_, _>(
+                hash_table,
+                probe_state,
+                keys_iter,
+                input,
+                is_probe_projected,
+            ),
             JoinType::Right | JoinType::RightSingle => self.probe_right_join::<_, _>(
                 hash_table,
                 probe_state,
@@ -253,13 +253,15 @@ impl Processor for TransformHashJoinProbe {
                         | JoinType::RightSingle
                         | JoinType::RightAnti
                         | JoinType::RightSemi
-                        | JoinType::LeftSemi => {
+                        | JoinType::LeftSemi
+                        | JoinType::Asof => {
                             self.step = HashJoinProbeStep::FastReturn;
                         }
                         JoinType::Left
                         | JoinType::Full
                         | JoinType::LeftSingle
-                        | JoinType::LeftAnti => {
+                        | JoinType::LeftAnti
+                        | JoinType::LeftAsof => {
                             self.step = HashJoinProbeStep::Running;
                         }
                         _ => {
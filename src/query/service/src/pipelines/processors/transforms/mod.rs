@@ -30,6 +30,9 @@ mod transform_merge_block;
 mod transform_resort_addon;
 mod transform_runtime_cast_schema;
 mod transform_runtime_filter;
+mod transform_sequence;
+mod transform_udf;
+mod transform_udf_server;
 
 pub use aggregator::build_partition_bucket;
 pub use aggregator::AggregateInjector;
@@ -50,6 +53,7 @@ pub use aggregator::TransformGroupBySpillReader;
 pub use aggregator::TransformGroupBySpillWriter;
 pub use aggregator::TransformPartialAggregate;
 pub use aggregator::TransformPartialGroupBy;
+pub use aggregator::TransformStreamingAggregate;
 use common_pipeline_transforms::processors::transforms::transform;
 use common_pipeline_transforms::processors::transforms::transform_block_compact;
 use common_pipeline_transforms::processors::transforms::transform_compact;
@@ -77,7 +81,14 @@ pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_runtime_cast_schema::TransformRuntimeCastSchema;
 pub use transform_runtime_filter::SinkRuntimeFilterSource;
 pub use transform_runtime_filter::TransformRuntimeFilter;
+pub use transform_sequence::invalidate_sequence_cache;
+pub use transform_sequence::TransformSequence;
 pub use transform_sort_partial::TransformSortPartial;
+pub use transform_udf::validate_wasm_module;
+pub use transform_udf::TransformUdf;
+pub use transform_udf_server::TransformUdfServer;
+pub use transform_udf_server::UDFFlightClient;
+pub use transform_udf_server::UdfServerSettings;
 pub use window::FrameBound;
 pub use window::TransformWindow;
 pub use window::WindowFunctionInfo;
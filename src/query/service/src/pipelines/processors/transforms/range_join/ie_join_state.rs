@@ -35,7 +35,6 @@ use common_functions::BUILTIN_FUNCTIONS;
 use common_pipeline_transforms::processors::transforms::sort_merge;
 use common_sql::executor::RangeJoin;
 
-use crate::pipelines::processors::transforms::range_join::ie_join_util::filter_block;
 use crate::pipelines::processors::transforms::range_join::ie_join_util::order_match;
 use crate::pipelines::processors::transforms::range_join::ie_join_util::probe_l1;
 use crate::pipelines::processors::transforms::range_join::RangeJoinState;
@@ -342,12 +341,12 @@ impl RangeJoinState {
                     if let ScalarRef::Number(NumberScalar::Int64(right)) =
                         unsafe { l1_index_column.index_unchecked(j) }
                     {
-                        right_buffer.push((-right - 1) as usize - right_offset);
+                        right_buffer.push(((-right - 1) as usize - right_offset) as u32);
                     }
                     if let ScalarRef::Number(NumberScalar::Int64(left)) =
                         unsafe { l1_index_column.index_unchecked(*p as usize) }
                     {
-                        left_buffer.push((left - 1) as usize - left_offset);
+                        left_buffer.push(((left - 1) as usize - left_offset) as u32);
                     }
                 }
                 j += 1;
@@ -356,30 +355,6 @@ impl RangeJoinState {
         if left_buffer.is_empty() {
             return Ok(DataBlock::empty());
         }
-        let left_table = self.left_table.read();
-        let right_table = self.right_table.read();
-        let mut indices = Vec::with_capacity(left_buffer.len());
-        for res in left_buffer.iter() {
-            indices.push((0u32, *res as u32, 1usize));
-        }
-        let mut left_result_block =
-            DataBlock::take_blocks(&left_table[left_idx..left_idx + 1], &indices, indices.len());
-        indices.clear();
-        for res in right_buffer.iter() {
-            indices.push((0u32, *res as u32, 1usize));
-        }
-        let right_result_block = DataBlock::take_blocks(
-            &right_table[right_idx..right_idx + 1],
-            &indices,
-            indices.len(),
-        );
-        // Merge left_result_block and right_result_block
-        for col in right_result_block.columns() {
-            left_result_block.add_column(col.clone());
-        }
-        for filter in self.other_conditions.iter() {
-            left_result_block = filter_block(left_result_block, filter)?;
-        }
-        Ok(left_result_block)
+        self.take_and_filter(left_idx, right_idx, left_buffer, right_buffer)
     }
 }
@@ -15,7 +15,9 @@
 use std::cmp::min;
 use std::cmp::Ordering;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_exception::Result;
+use common_expression::filter_helper::FilterHelpers;
 use common_expression::types::BooleanType;
 use common_expression::types::DataType;
 use common_expression::Column;
@@ -26,19 +28,20 @@ use common_expression::RemoteExpr;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_sql::executor::cast_expr_to_non_null_boolean;
 
-pub(crate) fn filter_block(block: DataBlock, filter: &RemoteExpr) -> Result<DataBlock> {
+// Evaluate the filter on the block, return the bitmap of rows satisfying it.
+pub(crate) fn filter_predicate(block: &DataBlock, filter: &RemoteExpr) -> Result<Bitmap> {
     let filter = filter.as_expr(&BUILTIN_FUNCTIONS);
     let other_predicate = cast_expr_to_non_null_boolean(filter)?;
     assert_eq!(other_predicate.data_type(), &DataType::Boolean);
 
     let func_ctx = FunctionContext::default();
 
-    let evaluator = Evaluator::new(&block, &func_ctx, &BUILTIN_FUNCTIONS);
+    let evaluator = Evaluator::new(block, &func_ctx, &BUILTIN_FUNCTIONS);
     let predicate = evaluator
         .run(&other_predicate)?
         .try_downcast::<BooleanType>()
         .unwrap();
-    block.filter_boolean_value(&predicate)
+    Ok(FilterHelpers::filter_to_bitmap(predicate, block.num_rows()).into())
 }
 
 pub(crate) fn order_match(op: &str, order: Ordering) -> bool {
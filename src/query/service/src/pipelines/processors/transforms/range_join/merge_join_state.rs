@@ -21,7 +21,6 @@ use common_expression::ScalarRef;
 use common_expression::SortColumnDescription;
 use common_functions::BUILTIN_FUNCTIONS;
 
-use crate::pipelines::processors::transforms::range_join::ie_join_util::filter_block;
 use crate::pipelines::processors::transforms::RangeJoinState;
 
 impl RangeJoinState {
@@ -74,9 +73,8 @@ impl RangeJoinState {
         let row_offset = self.row_offset.read();
         let (left_offset, right_offset) = row_offset[task_id];
 
-        let mut result_blocks = Vec::with_capacity(left_len);
-        let left_table = self.left_table.read();
-        let right_table = self.right_table.read();
+        let mut left_buffer = Vec::new();
+        let mut right_buffer = Vec::new();
 
         while i < left_len {
             if j == right_len {
@@ -88,56 +86,47 @@ impl RangeJoinState {
             }
             let left_scalar = unsafe { left_join_key_col.index_unchecked(i) };
             let right_scalar = unsafe { right_join_key_col.index_unchecked(j) };
+            // Null doesn't match any value, nulls are sorted first.
+            if left_scalar == ScalarRef::Null {
+                i += 1;
+                continue;
+            }
+            if right_scalar == ScalarRef::Null {
+                j += 1;
+                continue;
+            }
             if compare_scalar(
                 &left_scalar,
                 &right_scalar,
                 self.conditions[0].operator.as_str(),
             ) {
-                let mut left_result_block = DataBlock::empty();
-                let mut right_buffer = Vec::with_capacity(right_len - j);
                 if let ScalarRef::Number(NumberScalar::Int64(left)) =
                     unsafe { left_idx_col.index_unchecked(i) }
                 {
-                    left_result_block = left_table[left_idx].take_compacted_indices(
-                        &[(
-                            ((left - 1) as usize - left_offset) as u32,
-                            (right_len - j) as u32,
-                        )],
-                        right_len - j,
-                    )?;
-                }
-                for k in j..right_len {
-                    if let ScalarRef::Number(NumberScalar::Int64(right)) =
-                        unsafe { right_idx_col.index_unchecked(k) }
-                    {
-                        right_buffer.push((-right - 1) as usize - right_offset);
+                    let left = ((left - 1) as usize - left_offset) as u32;
+                    for k in j..right_len {
+                        if let ScalarRef::Number(NumberScalar::Int64(right)) =
+                            unsafe { right_idx_col.index_unchecked(k) }
+                        {
+                            left_buffer.push(left);
+                            right_buffer.push(((-right - 1) as usize - right_offset) as u32);
+                        }
                     }
                 }
-                if !left_result_block.is_empty() {
-                    let mut indices = Vec::with_capacity(right_buffer.len());
-                    for res in right_buffer.iter() {
-                        indices.push((0u32, *res as u32, 1usize));
-                    }
-                    let right_result_block = DataBlock::take_blocks(
-                        &right_table[right_idx..right_idx + 1],
-                        &indices,
-                        indices.len(),
-                    );
-                    // Merge left_result_block and right_result_block
-                    for col in right_result_block.columns() {
-                        left_result_block.add_column(col.clone());
-                    }
-                    for filter in self.other_conditions.iter() {
-                        left_result_block = filter_block(left_result_block, filter)?;
-                    }
-                    result_blocks.push(left_result_block);
-                }
                 i += 1;
             } else {
                 j += 1;
             }
         }
-        Ok(result_blocks)
+        if left_buffer.is_empty() {
+            return Ok(vec![]);
+        }
+        Ok(vec![self.take_and_filter(
+            left_idx,
+            right_idx,
+            left_buffer,
+            right_buffer,
+        )?])
     }
 
     // Used by merge join
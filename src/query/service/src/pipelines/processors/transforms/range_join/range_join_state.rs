@@ -13,28 +13,36 @@
 // limitations under the License.
 
 use std::sync::atomic;
+use std::sync::atomic::AtomicBool;
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 
+use common_arrow::arrow::bitmap::MutableBitmap;
 use common_base::base::tokio::sync::Notify;
 use common_exception::Result;
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
 use common_expression::types::NumberScalar;
+use common_expression::BlockEntry;
 use common_expression::ColumnBuilder;
 use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
 use common_expression::Evaluator;
 use common_expression::FunctionContext;
 use common_expression::RemoteExpr;
+use common_expression::Scalar;
 use common_expression::ScalarRef;
+use common_expression::Value;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_sql::executor::RangeJoin;
 use common_sql::executor::RangeJoinCondition;
 use common_sql::executor::RangeJoinType;
+use common_sql::plans::JoinType;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
 
 use crate::pipelines::processors::transforms::range_join::ie_join_state::IEJoinState;
+use crate::pipelines::processors::transforms::range_join::ie_join_util::filter_predicate;
 use crate::sessions::QueryContext;
 
 pub struct RangeJoinState {
@@ -46,8 +54,14 @@ pub struct RangeJoinState {
     // For iejoin, it's L1: sort by the first join key
     pub(crate) left_sorted_blocks: RwLock<Vec<DataBlock>>,
     pub(crate) conditions: Vec<RangeJoinCondition>,
-    // pub(crate) join_type: JoinType,
+    pub(crate) join_type: JoinType,
     pub(crate) other_conditions: Vec<RemoteExpr>,
+    // Schemas of left/right table, used to generate null columns for outer join
+    pub(crate) left_schema: DataSchemaRef,
+    pub(crate) right_schema: DataSchemaRef,
+    // Matched rows of each left/right table block, only used by outer join
+    pub(crate) left_matched: Mutex<Vec<MutableBitmap>>,
+    pub(crate) right_matched: Mutex<Vec<MutableBitmap>>,
     // Pipeline event related
     pub(crate) partition_finished: Mutex<bool>,
     pub(crate) finished_notify: Arc<Notify>,
@@ -58,27 +72,35 @@ pub struct RangeJoinState {
     // Row index offset for left/right
     pub(crate) row_offset: RwLock<Vec<(usize, usize)>>,
     pub(crate) finished_tasks: AtomicU64,
+    // Number of tasks whose results are generated
+    pub(crate) completed_tasks: AtomicU64,
+    // If the unmatched rows of outer join are generated
+    pub(crate) unmatched_generated: AtomicBool,
     // IEJoin state
     pub(crate) ie_join_state: Option<IEJoinState>,
 }
 
 impl RangeJoinState {
-    pub fn new(ctx: Arc<QueryContext>, range_join: &RangeJoin) -> Self {
+    pub fn new(ctx: Arc<QueryContext>, range_join: &RangeJoin) -> Result<Self> {
         let ie_join_state = if matches!(range_join.range_join_type, RangeJoinType::IEJoin) {
             Some(IEJoinState::new(range_join))
         } else {
             None
         };
 
-        Self {
+        Ok(Self {
             ctx,
             left_table: RwLock::new(vec![]),
             right_table: RwLock::new(vec![]),
             right_sorted_blocks: Default::default(),
             left_sorted_blocks: Default::default(),
             conditions: range_join.conditions.clone(),
-            // join_type: range_join.join_type.clone(),
+            join_type: range_join.join_type.clone(),
             other_conditions: range_join.other_conditions.clone(),
+            left_schema: range_join.left_schema()?,
+            right_schema: range_join.right_schema()?,
+            left_matched: Mutex::new(vec![]),
+            right_matched: Mutex::new(vec![]),
             partition_finished: Mutex::new(false),
             finished_notify: Arc::new(Notify::new()),
             left_sinker_count: RwLock::new(0),
@@ -86,18 +108,36 @@ impl RangeJoinState {
             tasks: RwLock::new(vec![]),
             row_offset: RwLock::new(vec![]),
             finished_tasks: AtomicU64::new(0),
+            completed_tasks: AtomicU64::new(0),
+            unmatched_generated: AtomicBool::new(false),
             ie_join_state,
-        }
+        })
+    }
+
+    // The rows of left table are preserved by right/full join
+    fn preserve_left(&self) -> bool {
+        matches!(self.join_type, JoinType::Right | JoinType::Full)
     }
 
-    pub(crate) fn sink_right(&self, block: DataBlock) -> Result<()> {
+    // The rows of right table are preserved by left/full join
+    fn preserve_right(&self) -> bool {
+        matches!(self.join_type, JoinType::Left | JoinType::Full)
+    }
+
+    pub(crate) fn sink_right(&self, mut block: DataBlock) -> Result<()> {
+        if self.preserve_left() {
+            block = wrap_nullable_block(block);
+        }
         // Sink block to right table
         let mut right_table = self.right_table.write();
         right_table.push(block);
         Ok(())
     }
 
-    pub(crate) fn sink_left(&self, block: DataBlock) -> Result<()> {
+    pub(crate) fn sink_left(&self, mut block: DataBlock) -> Result<()> {
+        if self.preserve_right() {
+            block = wrap_nullable_block(block);
+        }
         // Sink block to left table
         let mut left_table = self.left_table.write();
         left_table.push(block);
@@ -236,6 +276,19 @@ impl RangeJoinState {
             right_sorted_blocks.push(keys_block);
             current_rows += right_block.num_rows();
         }
+        if self.preserve_left() {
+            *self.left_matched.lock() = left_table
+                .iter()
+                .map(|block| MutableBitmap::from_len_zeroed(block.num_rows()))
+                .collect();
+        }
+        if self.preserve_right() {
+            *self.right_matched.lock() = right_table
+                .iter()
+                .map(|block| MutableBitmap::from_len_zeroed(block.num_rows()))
+                .collect();
+        }
+
         // Add tasks
         let mut row_offset = self.row_offset.write();
         let mut left_offset = 0;
@@ -252,4 +305,156 @@ impl RangeJoinState {
         }
         Ok(())
     }
+
+    /// Take the rows of the `left_idx` block of left table and the `right_idx` block of right table
+    /// by the row indices, merge them and filter the result by other conditions.
+    /// The matched rows are recorded for outer join.
+    pub(crate) fn take_and_filter(
+        &self,
+        left_idx: usize,
+        right_idx: usize,
+        mut left_indices: Vec<u32>,
+        mut right_indices: Vec<u32>,
+    ) -> Result<DataBlock> {
+        let left_table = self.left_table.read();
+        let right_table = self.right_table.read();
+        let mut result_block = take_rows(&left_table[left_idx], &left_indices);
+        let right_result_block = take_rows(&right_table[right_idx], &right_indices);
+        // Merge left_result_block and right_result_block
+        for col in right_result_block.columns() {
+            result_block.add_column(col.clone());
+        }
+
+        for filter in self.other_conditions.iter() {
+            let predicate = filter_predicate(&result_block, filter)?;
+            if predicate.unset_bits() > 0 {
+                let mut iter = predicate.iter();
+                left_indices.retain(|_| iter.next().unwrap());
+                let mut iter = predicate.iter();
+                right_indices.retain(|_| iter.next().unwrap());
+                result_block = result_block.filter_with_bitmap(&predicate)?;
+            }
+        }
+
+        if self.preserve_left() {
+            let matched = &mut self.left_matched.lock()[left_idx];
+            for idx in left_indices {
+                matched.set(idx as usize, true);
+            }
+        }
+        if self.preserve_right() {
+            let matched = &mut self.right_matched.lock()[right_idx];
+            for idx in right_indices {
+                matched.set(idx as usize, true);
+            }
+        }
+        Ok(result_block)
+    }
+
+    /// Called after the results of a task are generated.
+    ///
+    /// For outer join, the unmatched rows of the preserved tables are returned
+    /// once all tasks are completed.
+    pub(crate) fn complete_task(&self) -> Result<Vec<DataBlock>> {
+        self.completed_tasks.fetch_add(1, atomic::Ordering::SeqCst);
+        self.unmatched_blocks()
+    }
+
+    /// Generate the rows of the preserved tables which don't match any row,
+    /// with nulls for the columns of the other table.
+    ///
+    /// Only the first caller after all tasks are completed gets the rows.
+    pub(crate) fn unmatched_blocks(&self) -> Result<Vec<DataBlock>> {
+        if !self.preserve_left() && !self.preserve_right() {
+            return Ok(vec![]);
+        }
+        let completed = self.completed_tasks.load(atomic::Ordering::SeqCst);
+        if completed < self.tasks.read().len() as u64
+            || self
+                .unmatched_generated
+                .swap(true, atomic::Ordering::SeqCst)
+        {
+            return Ok(vec![]);
+        }
+
+        let mut result_blocks = vec![];
+        if self.preserve_left() {
+            let left_table = self.left_table.read();
+            let left_matched = self.left_matched.lock();
+            for (block, matched) in left_table.iter().zip(left_matched.iter()) {
+                let indices = unmatched_indices(matched);
+                if indices.is_empty() {
+                    continue;
+                }
+                let mut result_block = take_rows(block, &indices);
+                for field in self.right_schema.fields() {
+                    result_block.add_column(null_entry(field.data_type()));
+                }
+                result_blocks.push(result_block);
+            }
+        }
+        if self.preserve_right() {
+            let right_table = self.right_table.read();
+            let right_matched = self.right_matched.lock();
+            for (block, matched) in right_table.iter().zip(right_matched.iter()) {
+                let indices = unmatched_indices(matched);
+                if indices.is_empty() {
+                    continue;
+                }
+                let taken = take_rows(block, &indices);
+                let mut result_block = DataBlock::new(
+                    self.left_schema
+                        .fields()
+                        .iter()
+                        .map(|field| null_entry(field.data_type()))
+                        .collect(),
+                    indices.len(),
+                );
+                for col in taken.columns() {
+                    result_block.add_column(col.clone());
+                }
+                result_blocks.push(result_block);
+            }
+        }
+        Ok(result_blocks)
+    }
+}
+
+fn take_rows(block: &DataBlock, indices: &[u32]) -> DataBlock {
+    let indices = indices
+        .iter()
+        .map(|idx| (0u32, *idx, 1usize))
+        .collect::<Vec<_>>();
+    DataBlock::take_blocks(std::slice::from_ref(block), &indices, indices.len())
+}
+
+fn unmatched_indices(matched: &MutableBitmap) -> Vec<u32> {
+    (0..matched.len())
+        .filter(|idx| !matched.get(*idx))
+        .map(|idx| idx as u32)
+        .collect()
+}
+
+fn null_entry(data_type: &DataType) -> BlockEntry {
+    BlockEntry::new(data_type.clone(), Value::Scalar(Scalar::Null))
+}
+
+// Wrap nullable for the columns of the table whose rows may be not matched by outer join.
+fn wrap_nullable_block(block: DataBlock) -> DataBlock {
+    let num_rows = block.num_rows();
+    let columns = block
+        .columns()
+        .iter()
+        .map(|entry| {
+            if entry.data_type.is_nullable_or_null() {
+                entry.clone()
+            } else {
+                BlockEntry::new(
+                    entry.data_type.wrap_nullable(),
+                    entry.value.clone().wrap_nullable(None),
+                )
+            }
+        })
+        .collect();
+    DataBlock::new(columns, num_rows)
 }
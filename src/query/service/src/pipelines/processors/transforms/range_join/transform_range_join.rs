@@ -143,7 +143,13 @@ impl Processor for TransformRangeJoinLeft {
                             self.output_data_blocks.push_back(block);
                         }
                     }
+                    // For outer join, the last completed task generates the unmatched rows
+                    let unmatched_blocks = self.state.complete_task()?;
+                    self.output_data_blocks.extend(unmatched_blocks);
                 } else {
+                    // There may be no task if left or right table is empty
+                    let unmatched_blocks = self.state.unmatched_blocks()?;
+                    self.output_data_blocks.extend(unmatched_blocks);
                     self.execute_finished = true;
                 }
             }
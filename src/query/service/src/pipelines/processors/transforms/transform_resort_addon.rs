@@ -74,11 +74,22 @@ where Self: Transform
             } else {
                 let field = input_schema.field_with_name(f.name()).unwrap();
                 let id = input_schema.index_of(f.name()).unwrap();
-                Expr::ColumnRef {
+                let expr = Expr::ColumnRef {
                     span: None,
                     id,
                     data_type: field.data_type().clone(),
                     display_name: field.name().clone(),
+                };
+                // The values of identity columns are generated as Int64.
+                if field.data_type() != f.data_type() {
+                    Expr::Cast {
+                        span: None,
+                        is_try: false,
+                        expr: Box::new(expr),
+                        dest_type: f.data_type().clone(),
+                    }
+                } else {
+                    expr
                 }
             };
             exprs.push(expr);
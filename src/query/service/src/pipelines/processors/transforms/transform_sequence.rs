@@ -0,0 +1,157 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Evaluation of `nextval`.
+//!
+//! The numbers of a sequence are allocated from the meta service in ranges of at least
+//! `sequence_cache_size` numbers, the numbers of a range not used by a query are cached by the
+//! node for the next ones. So the numbers are unique, but there may be gaps between them, and
+//! they are not ordered across the nodes of a cluster.
+
+use std::collections::HashMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::Int64Type;
+use common_expression::types::NumberDataType;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::Value;
+use common_meta_app::principal::SequenceInfo;
+use common_pipeline_transforms::processors::transforms::AsyncTransform;
+use common_users::UserApiProvider;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+
+/// The numbers allocated to the node and not used yet.
+struct CachedRange {
+    next: i64,
+    remaining: u64,
+    increment: i64,
+}
+
+impl CachedRange {
+    fn take(&mut self, count: u64, values: &mut Vec<i64>) {
+        let count = count.min(self.remaining);
+        for _ in 0..count {
+            values.push(self.next);
+            // The range is allocated, so only the number after the last one may overflow.
+            self.next = self.next.wrapping_add(self.increment);
+        }
+        self.remaining -= count;
+    }
+}
+
+/// The cached ranges, keyed by tenant, name and creation time of the sequence, so a
+/// sequence created again never uses the ranges of the dropped one.
+static CACHE: Lazy<Mutex<HashMap<(String, String, i64), CachedRange>>> =
+    Lazy::new(Default::default);
+
+fn cache_key(tenant: &str, sequence: &SequenceInfo) -> (String, String, i64) {
+    (
+        tenant.to_string(),
+        sequence.name.clone(),
+        sequence.created_on.timestamp_micros(),
+    )
+}
+
+/// Drops the cached ranges of the sequence `name`.
+pub fn invalidate_sequence_cache(tenant: &str, name: &str) {
+    CACHE.lock().retain(|(cached_tenant, cached_name, _), _| {
+        cached_tenant != tenant || cached_name != name
+    });
+}
+
+/// Appends a column of new numbers to the blocks of its input for each of the sequences, for
+/// `nextval` and the identity columns.
+pub struct TransformSequence {
+    tenant: String,
+    sequences: Vec<SequenceInfo>,
+    cache_size: u64,
+}
+
+impl TransformSequence {
+    pub fn new(tenant: String, sequences: Vec<SequenceInfo>, cache_size: u64) -> Self {
+        Self {
+            tenant,
+            sequences,
+            cache_size,
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn next_values(&self, sequence: &SequenceInfo, count: u64) -> Result<Vec<i64>> {
+        let key = cache_key(&self.tenant, sequence);
+        let mut values = Vec::with_capacity(count as usize);
+        if let Some(range) = CACHE.lock().get_mut(&key) {
+            range.take(count, &mut values);
+        }
+        let missing = count - values.len() as u64;
+        if missing == 0 {
+            return Ok(values);
+        }
+
+        let user_api = UserApiProvider::instance();
+        let mut num_allocated = missing.max(self.cache_size);
+        let allocated = match user_api
+            .allocate_sequence_values(&self.tenant, &sequence.name, num_allocated)
+            .await
+        {
+            // The sequence may not have enough numbers left to be cached.
+            Err(e) if e.code() == ErrorCode::SEQUENCE_EXHAUSTED && num_allocated > missing => {
+                num_allocated = missing;
+                user_api
+                    .allocate_sequence_values(&self.tenant, &sequence.name, missing)
+                    .await?
+            }
+            res => res?,
+        };
+        if allocated.created_on != sequence.created_on {
+            return Err(ErrorCode::UnknownSequence(format!(
+                "Sequence {} was dropped while the query is running",
+                sequence.name
+            )));
+        }
+
+        let mut range = CachedRange {
+            next: allocated.next_value,
+            remaining: num_allocated,
+            increment: allocated.increment,
+        };
+        range.take(missing, &mut values);
+        if range.remaining > 0 {
+            CACHE.lock().insert(key, range);
+        }
+        Ok(values)
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformSequence {
+    const NAME: &'static str = "SequenceTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, mut block: DataBlock) -> Result<DataBlock> {
+        for sequence in self.sequences.iter() {
+            let values = self.next_values(sequence, block.num_rows() as u64).await?;
+            block.add_column(BlockEntry::new(
+                DataType::Number(NumberDataType::Int64),
+                Value::Column(Int64Type::from_data(values)),
+            ));
+        }
+        Ok(block)
+    }
+}
@@ -0,0 +1,286 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Evaluation of `LANGUAGE WASM` user-defined functions.
+//!
+//! A module implementing such a function must not import anything, and must export:
+//!
+//! - `memory`: its linear memory.
+//! - `alloc(len: i32) -> i32`: allocates `len` bytes, returning their offset in `memory`.
+//! - `dealloc(ptr: i32, len: i32)`: frees the bytes allocated by `alloc`.
+//! - the handler `(ptr: i32, len: i32) -> i64`.
+//!
+//! For each block, the host allocates and writes an Arrow IPC stream holding one record
+//! batch with the arguments of the function, named `0`, `1`, ..., and calls the handler
+//! on it. The handler returns `(ptr << 32) | len`, the location of an Arrow IPC stream
+//! allocated with `alloc`, holding one record batch with a single column: the results, one
+//! per row, of the declared return type. The host frees both buffers.
+//!
+//! Each block is evaluated by a fresh instance of the module, whose memory and fuel, i.e.
+//! executed instructions, are limited.
+
+use std::collections::HashMap;
+
+use arrow_ipc::reader::StreamReader;
+use arrow_ipc::writer::StreamWriter;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockEntry;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::Value;
+use common_sql::executor::UdfFunctionDesc;
+use common_sql::plans::UDFType;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use wasmtime::Config;
+use wasmtime::Engine;
+use wasmtime::ExternType;
+use wasmtime::Instance;
+use wasmtime::Module;
+use wasmtime::Store;
+use wasmtime::StoreLimits;
+use wasmtime::StoreLimitsBuilder;
+use wasmtime::ValType;
+
+use crate::pipelines::processors::transforms::transform::Transform;
+
+/// The maximum size of the linear memory of an instance.
+const MAX_MEMORY_SIZE: usize = 256 * 1024 * 1024;
+/// The maximum number of instructions executed to evaluate a block.
+const MAX_FUEL: u64 = 10_000_000_000;
+/// The maximum number of compiled modules kept in memory.
+const MAX_CACHED_MODULES: usize = 64;
+
+static ENGINE: Lazy<Engine> = Lazy::new(|| {
+    let mut config = Config::new();
+    config.consume_fuel(true);
+    Engine::new(&config).expect("the WASM engine config is valid")
+});
+
+static MODULES: Lazy<Mutex<HashMap<String, Module>>> = Lazy::new(Default::default);
+
+fn runtime_error(func_name: &str, e: impl std::fmt::Display) -> ErrorCode {
+    ErrorCode::UDFRuntimeError(format!("WASM function `{}` failed: {}", func_name, e))
+}
+
+/// Compiles the module `code` and checks it exports what the function `handler` needs.
+pub fn validate_wasm_module(code: &[u8], handler: &str) -> Result<()> {
+    let module = Module::new(&ENGINE, code)
+        .map_err(|e| ErrorCode::IllegalUDFFormat(format!("Invalid WASM module: {}", e)))?;
+    check_exports(&module, handler)
+}
+
+fn check_exports(module: &Module, handler: &str) -> Result<()> {
+    if module.imports().next().is_some() {
+        return Err(ErrorCode::IllegalUDFFormat(
+            "WASM modules of functions can't import anything",
+        ));
+    }
+    if !matches!(module.get_export("memory"), Some(ExternType::Memory(_))) {
+        return Err(ErrorCode::IllegalUDFFormat(
+            "WASM modules of functions must export `memory`",
+        ));
+    }
+
+    let (i32, i64) = (ValType::I32, ValType::I64);
+    for (name, params, results) in [
+        ("alloc", vec![i32], vec![i32]),
+        ("dealloc", vec![i32, i32], vec![]),
+        (handler, vec![i32, i32], vec![i64]),
+    ] {
+        let valid = match module.get_export(name) {
+            Some(ExternType::Func(ty)) => {
+                ty.params().eq(params.iter().copied()) && ty.results().eq(results.iter().copied())
+            }
+            _ => false,
+        };
+        if !valid {
+            return Err(ErrorCode::IllegalUDFFormat(format!(
+                "WASM modules of functions must export `{}` with params {:?} and results {:?}",
+                name, params, results
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Gets the compiled module of `func`, compiling it at its first use.
+fn get_module(func: &UdfFunctionDesc) -> Result<Module> {
+    let UDFType::Wasm { code_hash, code } = &func.udf_type else {
+        return Err(ErrorCode::Internal("Expected WASM function"));
+    };
+    if let Some(module) = MODULES.lock().get(code_hash) {
+        return Ok(module.clone());
+    }
+
+    let code = hex::decode(code.as_str()).map_err(|e| runtime_error(&func.func_name, e))?;
+    let module = Module::new(&ENGINE, code).map_err(|e| runtime_error(&func.func_name, e))?;
+    check_exports(&module, &func.handler)?;
+
+    let mut modules = MODULES.lock();
+    if modules.len() >= MAX_CACHED_MODULES {
+        modules.clear();
+    }
+    modules.insert(code_hash.clone(), module.clone());
+    Ok(module)
+}
+
+struct WasmFunction {
+    desc: UdfFunctionDesc,
+    module: Module,
+    arg_schema: DataSchema,
+}
+
+impl WasmFunction {
+    fn try_create(desc: &UdfFunctionDesc, input_types: Vec<DataField>) -> Result<Self> {
+        let fields = input_types
+            .into_iter()
+            .enumerate()
+            .map(|(i, field)| DataField::new(&i.to_string(), field.data_type().clone()))
+            .collect();
+        Ok(Self {
+            desc: desc.clone(),
+            module: get_module(desc)?,
+            arg_schema: DataSchema::new(fields),
+        })
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<BlockEntry> {
+        let num_rows = block.num_rows();
+        let data_type = (*self.desc.data_type).clone();
+        if num_rows == 0 {
+            let column = ColumnBuilder::with_capacity(&data_type, 0).build();
+            return Ok(BlockEntry::new(data_type, Value::Column(column)));
+        }
+
+        let args = self
+            .desc
+            .arg_indices
+            .iter()
+            .map(|i| block.get_by_offset(*i).clone())
+            .collect::<Vec<_>>();
+        let batch = DataBlock::new(args, num_rows).to_record_batch(&self.arg_schema)?;
+        let mut input = vec![];
+        {
+            let mut writer = StreamWriter::try_new(&mut input, &batch.schema())?;
+            writer.write(&batch)?;
+            writer.finish()?;
+        }
+
+        let output = self
+            .call(&input)
+            .map_err(|e| runtime_error(&self.desc.func_name, e))?;
+
+        let mut reader = StreamReader::try_new(output.as_slice(), None)?;
+        let batch = match reader.next() {
+            Some(batch) => batch?,
+            None => {
+                return Err(runtime_error(&self.desc.func_name, "no result returned"));
+            }
+        };
+        let (result, schema) = DataBlock::from_record_batch(&batch)?;
+        if result.num_columns() != 1 || result.num_rows() != num_rows {
+            return Err(runtime_error(
+                &self.desc.func_name,
+                format!(
+                    "expected 1 column of {} rows, but got {} columns of {} rows",
+                    num_rows,
+                    result.num_columns(),
+                    result.num_rows()
+                ),
+            ));
+        }
+        let result_type = schema.field(0).data_type();
+        if result_type != &data_type {
+            return Err(runtime_error(
+                &self.desc.func_name,
+                format!("expected results of {}, but got {}", data_type, result_type),
+            ));
+        }
+        Ok(result.get_by_offset(0).clone())
+    }
+
+    /// Calls the handler of a new instance of the module on `input`.
+    fn call(&self, input: &[u8]) -> wasmtime::Result<Vec<u8>> {
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(MAX_MEMORY_SIZE)
+            .instances(1)
+            .build();
+        let mut store: Store<StoreLimits> = Store::new(&ENGINE, limits);
+        store.limiter(|limits| limits);
+        store.add_fuel(MAX_FUEL)?;
+
+        let instance = Instance::new(&mut store, &self.module, &[])?;
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .ok_or_else(|| wasmtime::Error::msg("`memory` is not exported"))?;
+        let alloc = instance.get_typed_func::<i32, i32>(&mut store, "alloc")?;
+        let dealloc = instance.get_typed_func::<(i32, i32), ()>(&mut store, "dealloc")?;
+        let handler = instance.get_typed_func::<(i32, i32), i64>(&mut store, &self.desc.handler)?;
+
+        let input_len = i32::try_from(input.len())?;
+        let input_ptr = alloc.call(&mut store, input_len)?;
+        memory.write(&mut store, input_ptr as u32 as usize, input)?;
+        let ret = handler.call(&mut store, (input_ptr, input_len))?;
+        let (output_ptr, output_len) = ((ret >> 32) as u32, ret as u32);
+        if output_ptr as usize + output_len as usize > memory.data_size(&store) {
+            return Err(wasmtime::Error::msg(
+                "the result is out of the bounds of `memory`",
+            ));
+        }
+
+        let mut output = vec![0; output_len as usize];
+        memory.read(&store, output_ptr as usize, &mut output)?;
+        dealloc.call(&mut store, (input_ptr, input_len))?;
+        dealloc.call(&mut store, (output_ptr as i32, output_len as i32))?;
+        Ok(output)
+    }
+}
+
+/// Evaluates `LANGUAGE WASM` functions on the blocks of its input, appending their results.
+pub struct TransformUdf {
+    funcs: Vec<WasmFunction>,
+}
+
+impl TransformUdf {
+    pub fn try_create(input_schema: &DataSchema, funcs: &[UdfFunctionDesc]) -> Result<Self> {
+        let funcs = funcs
+            .iter()
+            .map(|func| {
+                let arg_fields = func
+                    .arg_indices
+                    .iter()
+                    .map(|i| input_schema.field(*i).clone())
+                    .collect();
+                WasmFunction::try_create(func, arg_fields)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { funcs })
+    }
+}
+
+impl Transform for TransformUdf {
+    const NAME: &'static str = "UdfTransform";
+
+    fn transform(&mut self, mut block: DataBlock) -> Result<DataBlock> {
+        for func in self.funcs.iter() {
+            let entry = func.eval(&block)?;
+            block.add_column(entry);
+        }
+        Ok(block)
+    }
+}
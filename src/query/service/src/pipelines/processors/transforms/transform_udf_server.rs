@@ -0,0 +1,439 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Evaluation of functions implemented by external UDF servers.
+//!
+//! A UDF server is an Arrow Flight server. `GetFlightInfo` on the path `[handler]` describes
+//! the function `handler`: the fields of its schema are the arguments of the function,
+//! followed by its result. Rows are evaluated in batches by `DoExchange` with the same
+//! descriptor: each record batch sent holds the arguments, named `0`, `1`, ..., and the
+//! record batches returned hold a single column with the results, one per row.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use arrow_array::RecordBatch;
+use arrow_flight::encode::FlightDataEncoderBuilder;
+use arrow_flight::FlightClient;
+use arrow_flight::FlightDescriptor;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_base::base::tokio::time::timeout;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::ScalarRef;
+use common_expression::Value;
+use common_pipeline_transforms::processors::transforms::AsyncTransform;
+use common_settings::Settings;
+use common_sql::binder::check_udf_server_address;
+use common_sql::executor::UdfFunctionDesc;
+use common_sql::plans::UDFType;
+use futures::stream;
+use futures::TryStreamExt;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use tonic::transport::Channel;
+use tonic::transport::ClientTlsConfig;
+use tonic::transport::Endpoint;
+
+/// Connections to UDF servers, keyed by address, shared by the queries calling them.
+static CHANNELS: Lazy<Mutex<HashMap<String, Channel>>> = Lazy::new(Default::default);
+
+fn server_error(address: &str, e: impl std::fmt::Display) -> ErrorCode {
+    ErrorCode::UDFRuntimeError(format!("UDF server `{}` failed: {}", address, e))
+}
+
+/// The settings of the requests to UDF servers.
+#[derive(Clone, Copy, Debug)]
+pub struct UdfServerSettings {
+    pub connect_timeout: Duration,
+    pub request_timeout: Duration,
+    pub max_retries: u64,
+    pub batch_rows: usize,
+}
+
+impl UdfServerSettings {
+    pub fn try_create(settings: &Settings) -> Result<Self> {
+        Ok(Self {
+            connect_timeout: Duration::from_secs(settings.get_udf_server_connect_timeout_secs()?),
+            request_timeout: Duration::from_secs(settings.get_udf_server_request_timeout_secs()?),
+            max_retries: settings.get_udf_server_request_max_retries()?,
+            batch_rows: settings.get_udf_server_request_batch_rows()? as usize,
+        })
+    }
+}
+
+/// A client of the UDF server at `address`.
+pub struct UDFFlightClient {
+    address: String,
+    inner: FlightClient,
+    request_timeout: Duration,
+}
+
+impl UDFFlightClient {
+    /// Connects to the UDF server at `address`, reusing the connection to it if any.
+    #[async_backtrace::framed]
+    pub async fn connect(address: &str, settings: &UdfServerSettings) -> Result<Self> {
+        // The function may have been created before the address was removed from the list.
+        check_udf_server_address(address)?;
+        let cached = CHANNELS.lock().get(address).cloned();
+        let channel = match cached {
+            Some(channel) => channel,
+            None => {
+                let channel = Self::endpoint(address, settings.connect_timeout)?
+                    .connect()
+                    .await
+                    .map_err(|e| server_error(address, e))?;
+                CHANNELS.lock().insert(address.to_string(), channel.clone());
+                channel
+            }
+        };
+        Ok(Self {
+            address: address.to_string(),
+            inner: FlightClient::new(channel),
+            request_timeout: settings.request_timeout,
+        })
+    }
+
+    fn endpoint(address: &str, connect_timeout: Duration) -> Result<Endpoint> {
+        // gRPC over plain HTTP/2.
+        let uri = match address.strip_prefix("grpc://") {
+            Some(authority) => format!("http://{}", authority),
+            None => address.to_string(),
+        };
+        let endpoint = Endpoint::from_shared(uri)
+            .map_err(|e| server_error(address, e))?
+            .connect_timeout(connect_timeout)
+            .tcp_nodelay(true);
+        if address.starts_with("https://") {
+            return endpoint
+                .tls_config(ClientTlsConfig::new())
+                .map_err(|e| server_error(address, e));
+        }
+        Ok(endpoint)
+    }
+
+    /// Drops the connection to the server, the next client connects to it again.
+    fn disconnect(&self) {
+        CHANNELS.lock().remove(&self.address);
+    }
+
+    fn descriptor(handler: &str) -> FlightDescriptor {
+        FlightDescriptor::new_path(vec![handler.to_string()])
+    }
+
+    /// Checks the server implements `handler` with the given signature, the nullability of
+    /// the types is not checked.
+    #[async_backtrace::framed]
+    pub async fn check_schema(
+        &mut self,
+        handler: &str,
+        arg_types: &[DataType],
+        return_type: &DataType,
+    ) -> Result<()> {
+        let request = self.inner.get_flight_info(Self::descriptor(handler));
+        let info = timeout(self.request_timeout, request)
+            .await
+            .map_err(|e| server_error(&self.address, e))?
+            .map_err(|e| server_error(&self.address, e))?;
+        let schema = info
+            .try_decode_schema()
+            .map_err(|e| server_error(&self.address, e))?;
+        let schema = DataSchema::try_from(&schema)?;
+
+        let actual = schema
+            .fields()
+            .iter()
+            .map(|field| field.data_type().remove_nullable())
+            .collect::<Vec<_>>();
+        let expected = arg_types
+            .iter()
+            .chain([return_type])
+            .map(|ty| ty.remove_nullable())
+            .collect::<Vec<_>>();
+        if actual != expected {
+            let display = |types: &[DataType]| match types.split_last() {
+                Some((ret, args)) => format!(
+                    "({}) RETURNS {}",
+                    args.iter()
+                        .map(|ty| ty.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    ret
+                ),
+                None => "()".to_string(),
+            };
+            return Err(ErrorCode::IllegalUDFFormat(format!(
+                "The function `{}` of UDF server `{}` has the signature {}, but {} is expected",
+                handler,
+                self.address,
+                display(&actual),
+                display(&expected)
+            )));
+        }
+        Ok(())
+    }
+
+    /// Calls `handler` on the rows of `batch`, returning the record batches of the results.
+    #[async_backtrace::framed]
+    pub async fn call(&mut self, handler: &str, batch: RecordBatch) -> Result<Vec<RecordBatch>> {
+        let mut data = FlightDataEncoderBuilder::new()
+            .build(stream::iter([Ok(batch)]))
+            .try_collect::<Vec<_>>()
+            .await
+            .map_err(|e| server_error(&self.address, e))?;
+        if let Some(first) = data.first_mut() {
+            first.flight_descriptor = Some(Self::descriptor(handler));
+        }
+
+        let inner = &mut self.inner;
+        let request = async move {
+            let response = inner
+                .do_exchange(stream::iter(data.into_iter().map(Ok)))
+                .await?;
+            response.try_collect::<Vec<_>>().await
+        };
+        let result = timeout(self.request_timeout, request)
+            .await
+            .map_err(|e| server_error(&self.address, e))
+            .and_then(|r| r.map_err(|e| server_error(&self.address, e)));
+        if result.is_err() {
+            self.disconnect();
+        }
+        result
+    }
+}
+
+/// Evaluates functions implemented by UDF servers on the blocks of its input, appending their
+/// results.
+///
+/// Rows are sent in batches of at most `batch_rows` rows, a failed request is retried with a
+/// new connection up to `max_retries` times. The rows where an argument of a `RETURNS NULL ON
+/// NULL INPUT` function is NULL are not sent, their results are NULL.
+pub struct TransformUdfServer {
+    funcs: Vec<UdfFunctionDesc>,
+    settings: UdfServerSettings,
+    clients: HashMap<String, UDFFlightClient>,
+}
+
+impl TransformUdfServer {
+    pub fn new(funcs: Vec<UdfFunctionDesc>, settings: UdfServerSettings) -> Self {
+        Self {
+            funcs,
+            settings,
+            clients: HashMap::new(),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn call(
+        &mut self,
+        address: &str,
+        handler: &str,
+        batch: RecordBatch,
+    ) -> Result<Vec<RecordBatch>> {
+        let mut retries = 0;
+        loop {
+            if !self.clients.contains_key(address) {
+                let client = UDFFlightClient::connect(address, &self.settings).await?;
+                self.clients.insert(address.to_string(), client);
+            }
+            let client = self.clients.get_mut(address).unwrap();
+            match client.call(handler, batch.clone()).await {
+                Ok(batches) => return Ok(batches),
+                Err(e) if retries >= self.settings.max_retries => return Err(e),
+                Err(_) => {
+                    // The client has dropped the shared connection, a new one is opened.
+                    self.clients.remove(address);
+                    retries += 1;
+                }
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn eval(&mut self, func: &UdfFunctionDesc, block: &DataBlock) -> Result<BlockEntry> {
+        let UDFType::Server {
+            address,
+            returns_null_on_null_input,
+        } = &func.udf_type
+        else {
+            return Err(ErrorCode::Internal("Expected UDF server function"));
+        };
+        let num_rows = block.num_rows();
+        let data_type = (*func.data_type).clone();
+
+        let mut args = func
+            .arg_indices
+            .iter()
+            .map(|i| {
+                let entry = block.get_by_offset(*i);
+                entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows)
+            })
+            .collect::<Vec<_>>();
+
+        // The rows where no argument is NULL, the only ones sent to the server.
+        let mut validity: Option<Bitmap> = None;
+        if *returns_null_on_null_input && data_type.is_nullable() {
+            for arg in args.iter() {
+                let arg_validity = match arg {
+                    Column::Nullable(column) => column.validity.clone(),
+                    Column::Null { len } => Bitmap::new_zeroed(*len),
+                    _ => continue,
+                };
+                validity = Some(match validity {
+                    Some(validity) => &validity & &arg_validity,
+                    None => arg_validity,
+                });
+            }
+            if let Some(validity) = &validity {
+                args = args
+                    .iter()
+                    .map(|arg| arg.filter(validity).remove_nullable())
+                    .collect();
+            }
+        }
+        let result_type = match validity {
+            Some(_) => data_type.remove_nullable(),
+            None => data_type.clone(),
+        };
+
+        let num_sent_rows = validity
+            .as_ref()
+            .map(|validity| num_rows - validity.unset_bits())
+            .unwrap_or(num_rows);
+        let arg_schema = DataSchema::new(
+            args.iter()
+                .enumerate()
+                .map(|(i, arg)| DataField::new(&i.to_string(), arg.data_type()))
+                .collect(),
+        );
+        let args = DataBlock::new_from_columns(args);
+
+        let mut results = Vec::with_capacity(num_sent_rows / self.settings.batch_rows + 1);
+        let mut start = 0;
+        while start < num_sent_rows {
+            let end = num_sent_rows.min(start + self.settings.batch_rows);
+            let batch = args
+                .slice(start..end)
+                .to_record_batch(&arg_schema)
+                .map_err(|e| server_error(address, e))?;
+            for batch in self.call(address, &func.handler, batch).await? {
+                let (result, _) = DataBlock::from_record_batch(&batch)?;
+                if result.num_columns() != 1 {
+                    return Err(server_error(
+                        address,
+                        format!(
+                            "function `{}` returned {} columns, but 1 is expected",
+                            func.func_name,
+                            result.num_columns()
+                        ),
+                    ));
+                }
+                let column = result.get_by_offset(0).value.clone().into_column().unwrap();
+                results.push(Self::cast_result(address, func, column, &result_type)?);
+            }
+            start = end;
+        }
+
+        let result = if results.is_empty() {
+            ColumnBuilder::with_capacity(&result_type, 0).build()
+        } else {
+            Column::concat(&results)
+        };
+        if result.len() != num_sent_rows {
+            return Err(server_error(
+                address,
+                format!(
+                    "function `{}` returned {} rows, but {} is expected",
+                    func.func_name,
+                    result.len(),
+                    num_sent_rows
+                ),
+            ));
+        }
+
+        let column = match validity {
+            // Scatters the results back to the rows they were computed for.
+            Some(validity) => {
+                let mut builder = ColumnBuilder::with_capacity(&data_type, num_rows);
+                let mut results = (0..result.len()).map(|i| result.index(i).unwrap());
+                for valid in validity.iter() {
+                    match valid {
+                        true => builder.push(results.next().unwrap()),
+                        false => builder.push(ScalarRef::Null),
+                    }
+                }
+                builder.build()
+            }
+            None => result,
+        };
+        Ok(BlockEntry::new(data_type, Value::Column(column)))
+    }
+
+    /// Checks the results are of `result_type`, nullable results without NULL values are
+    /// accepted for a non-nullable type.
+    fn cast_result(
+        address: &str,
+        func: &UdfFunctionDesc,
+        column: Column,
+        result_type: &DataType,
+    ) -> Result<Column> {
+        let column_type = column.data_type();
+        if &column_type == result_type {
+            return Ok(column);
+        }
+        if column_type.remove_nullable() == result_type.remove_nullable() {
+            match column {
+                Column::Nullable(column) if column.validity.unset_bits() == 0 => {
+                    return Ok(column.column);
+                }
+                column if result_type.is_nullable() => {
+                    return Ok(column.wrap_nullable(None));
+                }
+                _ => {}
+            }
+        }
+        Err(server_error(
+            address,
+            format!(
+                "function `{}` returned results of {}, but {} is expected",
+                func.func_name, column_type, result_type
+            ),
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformUdfServer {
+    const NAME: &'static str = "UdfServerTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, mut block: DataBlock) -> Result<DataBlock> {
+        let funcs = self.funcs.clone();
+        for func in funcs.iter() {
+            let entry = self.eval(func, &block).await?;
+            block.add_column(entry);
+        }
+        Ok(block)
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::KafkaSource;
+use kafka::client::FetchOffset;
+use kafka::client::FetchPartition;
+use kafka::client::KafkaClient;
+
+/// Messages fetched from a topic, with the offsets after the last message of each partition.
+pub struct FetchedMessages {
+    pub messages: Vec<Vec<u8>>,
+    pub end_offsets: BTreeMap<i32, i64>,
+}
+
+/// A blocking consumer of a Kafka topic, it must be used in `spawn_blocking`.
+///
+/// The consumer doesn't commit offsets to Kafka, the offsets of pipes are kept in the
+/// meta service, so they are committed together with the progress of the pipe.
+pub struct KafkaConsumer {
+    client: KafkaClient,
+    topic: String,
+}
+
+impl KafkaConsumer {
+    const MAX_BYTES_PER_PARTITION: i32 = 1024 * 1024;
+
+    pub fn connect(source: &KafkaSource) -> Result<Self> {
+        let mut client = KafkaClient::new(source.brokers.clone());
+        client.set_client_id("databend-pipe".to_string());
+        client.set_fetch_max_bytes_per_partition(Self::MAX_BYTES_PER_PARTITION);
+        client
+            .load_metadata(&[&source.topic])
+            .map_err(|e| kafka_error(&source.topic, e))?;
+        if !client.topics().contains(&source.topic) {
+            return Err(ErrorCode::IllegalPipe(format!(
+                "kafka topic '{}' doesn't exist",
+                source.topic
+            )));
+        }
+        Ok(KafkaConsumer {
+            client,
+            topic: source.topic.clone(),
+        })
+    }
+
+    /// Start the partitions that have not been consumed from the earliest offsets.
+    pub fn init_offsets(&mut self, offsets: &mut BTreeMap<i32, i64>) -> Result<()> {
+        let partitions = match self.client.topics().partitions(&self.topic) {
+            Some(partitions) => partitions.iter().map(|p| p.id()).collect::<Vec<_>>(),
+            None => return Ok(()),
+        };
+        if partitions.iter().all(|id| offsets.contains_key(id)) {
+            return Ok(());
+        }
+
+        let earliest = self
+            .client
+            .fetch_topic_offsets(&self.topic, FetchOffset::Earliest)
+            .map_err(|e| kafka_error(&self.topic, e))?;
+        for partition_offset in earliest {
+            offsets
+                .entry(partition_offset.partition)
+                .or_insert(partition_offset.offset);
+        }
+        Ok(())
+    }
+
+    /// Fetch at most `limit` messages starting from `start_offsets`.
+    ///
+    /// If `end_offsets` is given, the messages before the end offsets are fetched,
+    /// that's used to load a pending batch again.
+    pub fn fetch(
+        &mut self,
+        start_offsets: &BTreeMap<i32, i64>,
+        end_offsets: Option<&BTreeMap<i32, i64>>,
+        limit: u64,
+    ) -> Result<FetchedMessages> {
+        let mut offsets = start_offsets.clone();
+        let mut messages = Vec::new();
+        loop {
+            let requests = offsets
+                .iter()
+                .filter(|(partition, offset)| match end_offsets {
+                    Some(end_offsets) => end_offsets
+                        .get(partition)
+                        .map(|end| *offset < end)
+                        .unwrap_or(false),
+                    None => true,
+                })
+                .map(|(partition, offset)| FetchPartition::new(&self.topic, *partition, *offset))
+                .collect::<Vec<_>>();
+            if requests.is_empty() || messages.len() as u64 >= limit {
+                break;
+            }
+
+            let responses = self
+                .client
+                .fetch_messages(requests)
+                .map_err(|e| kafka_error(&self.topic, e))?;
+            let mut fetched = false;
+            for response in responses.iter() {
+                for topic in response.topics() {
+                    for partition in topic.partitions() {
+                        let data = match partition.data() {
+                            Ok(data) => data,
+                            Err(e) => return Err(kafka_error(&self.topic, e)),
+                        };
+                        let id = partition.partition();
+                        let offset = offsets.entry(id).or_insert(0);
+                        let end = end_offsets.and_then(|end_offsets| end_offsets.get(&id));
+                        for message in data.messages() {
+                            // Compressed message sets may start before the requested offset.
+                            if message.offset < *offset {
+                                continue;
+                            }
+                            if end.map(|end| message.offset >= *end).unwrap_or(false)
+                                || messages.len() as u64 >= limit
+                            {
+                                break;
+                            }
+                            messages.push(message.value.to_vec());
+                            *offset = message.offset + 1;
+                            fetched = true;
+                        }
+                    }
+                }
+            }
+
+            if !fetched {
+                // No new messages, or the pending batch is not available anymore.
+                if let Some(end_offsets) = end_offsets {
+                    if offsets.iter().any(|(id, offset)| {
+                        end_offsets.get(id).map(|end| offset < end).unwrap_or(false)
+                    }) {
+                        return Err(ErrorCode::IllegalPipe(format!(
+                            "messages of kafka topic '{}' are not available anymore, expect offsets {:?}",
+                            self.topic, end_offsets
+                        )));
+                    }
+                }
+                break;
+            }
+        }
+
+        Ok(FetchedMessages {
+            messages,
+            end_offsets: offsets,
+        })
+    }
+}
+
+fn kafka_error(topic: &str, e: impl Display) -> ErrorCode {
+    ErrorCode::CannotConnectNode(format!("read kafka topic '{}' failure: {}", topic, e))
+}
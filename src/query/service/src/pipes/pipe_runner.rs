@@ -0,0 +1,422 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_base::base::tokio::task::JoinHandle;
+use common_base::base::tokio::time::sleep;
+use common_base::base::GlobalUniqName;
+use common_catalog::table::Table;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::KafkaSource;
+use common_meta_app::principal::OnErrorMode;
+use common_meta_app::principal::PipeBatch;
+use common_meta_app::principal::PipeInfo;
+use common_meta_app::principal::PipeSource;
+use common_meta_app::principal::StageSource;
+use common_pipeline_sources::input_formats::InputContext;
+use common_pipeline_sources::input_formats::StreamingReadBatch;
+use common_sql::plans::Insert;
+use common_sql::plans::InsertInputSource;
+use common_sql::plans::Plan;
+use common_users::UserApiProvider;
+use futures::StreamExt;
+use log::info;
+use log::warn;
+
+use crate::interpreters::InterpreterFactory;
+use crate::pipes::kafka::FetchedMessages;
+use crate::pipes::kafka::KafkaConsumer;
+use crate::pipes::stage::copy_new_files;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+/// Runs the pipes of the tenant on this node.
+///
+/// Each pipe is run by the node holding its lease in the meta service. A batch of
+/// messages is loaded as below, so that every message is committed to the table
+/// exactly once:
+///
+/// 1. The batch, i.e. the offsets range of each partition, is saved as the pending
+///    batch in the progress of the pipe.
+/// 2. The messages are inserted into the table with a deduplicate label of the batch,
+///    the label is committed together with the table.
+/// 3. The offsets are advanced to the end of the batch and the pending batch is cleared.
+///
+/// If the node fails between 2 and 3, the pending batch is loaded again by the next
+/// runner, and the insert is skipped as the label exists. The progress is updated with
+/// the seq read before, so a runner that lost its lease can't overwrite the progress.
+///
+/// The messages that can't be parsed are skipped, the error is kept as the last error
+/// of the pipe.
+///
+/// A stage pipe lists the stage every refresh interval, and copies the files newer than
+/// its high-water mark into the table, at most `batch_size` files each time.
+pub struct PipeRunner;
+
+impl PipeRunner {
+    const SCHEDULE_INTERVAL: Duration = Duration::from_secs(5);
+    const POLL_INTERVAL: Duration = Duration::from_secs(1);
+    const RETRY_INTERVAL: Duration = Duration::from_secs(10);
+    const LEASE_TTL: Duration = Duration::from_secs(60);
+
+    pub fn start(conf: &InnerConfig) -> Result<()> {
+        let tenant = conf.query.tenant_id.clone();
+        let node_id = GlobalUniqName::unique();
+        tokio::spawn(async_backtrace::location!().frame(async move {
+            let mut runners = HashMap::new();
+            loop {
+                if let Err(cause) = Self::schedule(&tenant, &node_id, &mut runners).await {
+                    warn!("Schedule pipes of tenant {} failure: {:?}", tenant, cause);
+                }
+                sleep(Self::SCHEDULE_INTERVAL).await;
+            }
+        }));
+        Ok(())
+    }
+
+    /// Start a runner for each pipe not running on this node, the runner exits
+    /// if the lease of the pipe is held by another node.
+    #[async_backtrace::framed]
+    async fn schedule(
+        tenant: &str,
+        node_id: &str,
+        runners: &mut HashMap<String, JoinHandle<()>>,
+    ) -> Result<()> {
+        runners.retain(|_, runner| !runner.is_finished());
+
+        let pipes = UserApiProvider::instance().get_pipes(tenant).await?;
+        for pipe in pipes {
+            if runners.contains_key(&pipe.name) {
+                continue;
+            }
+            let name = pipe.name.clone();
+            let tenant = tenant.to_string();
+            let node_id = node_id.to_string();
+            let runner = tokio::spawn(async_backtrace::location!().frame(async move {
+                Self::run(&tenant, &node_id, pipe).await;
+            }));
+            runners.insert(name, runner);
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn run(tenant: &str, node_id: &str, pipe: PipeInfo) {
+        let user_mgr = UserApiProvider::instance();
+        let mut started = false;
+        loop {
+            match user_mgr
+                .acquire_pipe_lease(tenant, &pipe.name, node_id, Self::LEASE_TTL)
+                .await
+            {
+                Ok(true) => {}
+                Ok(false) => return,
+                Err(cause) => {
+                    warn!("Acquire lease of pipe {} failure: {:?}", pipe.name, cause);
+                    return;
+                }
+            }
+
+            // Stop if the pipe is dropped, or dropped and created again.
+            match user_mgr.get_pipe(tenant, &pipe.name).await {
+                Ok(current) if current.created_on == pipe.created_on => {}
+                Ok(_) => return,
+                Err(cause) if cause.code() == ErrorCode::UNKNOWN_PIPE => return,
+                Err(cause) => {
+                    warn!("Get pipe {} failure: {:?}", pipe.name, cause);
+                    sleep(Self::RETRY_INTERVAL).await;
+                    continue;
+                }
+            }
+            if !started {
+                info!("Start running pipe {} of tenant {}", pipe.name, tenant);
+                started = true;
+            }
+
+            match Self::run_batch(tenant, &pipe).await {
+                Ok(0) => sleep(Self::POLL_INTERVAL).await,
+                Ok(_) => {}
+                Err(cause) => {
+                    warn!("Run pipe {} failure: {:?}", pipe.name, cause);
+                    Self::record_error(tenant, &pipe.name, &cause).await;
+                    sleep(Self::RETRY_INTERVAL).await;
+                }
+            }
+        }
+    }
+
+    /// Returns the number of messages, or files of a stage, loaded.
+    #[async_backtrace::framed]
+    async fn run_batch(tenant: &str, pipe: &PipeInfo) -> Result<u64> {
+        match &pipe.source {
+            PipeSource::Kafka(source) => Self::run_kafka_batch(tenant, pipe, source).await,
+            PipeSource::Stage(source) => Self::run_stage_batch(tenant, pipe, source).await,
+        }
+    }
+
+    /// Load the pending batch, or a new batch if there is no pending batch.
+    #[async_backtrace::framed]
+    async fn run_kafka_batch(tenant: &str, pipe: &PipeInfo, source: &KafkaSource) -> Result<u64> {
+        let user_mgr = UserApiProvider::instance();
+        let progress = user_mgr.get_pipe_progress(tenant, &pipe.name).await?;
+        let mut seq = progress.seq;
+        let mut progress = progress.data;
+
+        let batch_size = pipe.batch_size;
+        let pending_batch = progress.pending_batch.clone();
+        let mut offsets = progress.offsets.clone();
+        let consumer_source = source.clone();
+        let (offsets, fetched) = tokio::task::spawn_blocking(move || {
+            let mut consumer = KafkaConsumer::connect(&consumer_source)?;
+            let fetched = match &pending_batch {
+                Some(batch) => {
+                    consumer.fetch(&batch.start_offsets, Some(&batch.end_offsets), u64::MAX)?
+                }
+                None => {
+                    consumer.init_offsets(&mut offsets)?;
+                    consumer.fetch(&offsets, None, batch_size)?
+                }
+            };
+            Ok::<(_, FetchedMessages), ErrorCode>((offsets, fetched))
+        })
+        .await
+        .map_err(|e| ErrorCode::TokioError(format!("fetch messages of pipe failure: {}", e)))??;
+
+        let batch = match progress.pending_batch.clone() {
+            Some(batch) => batch,
+            None => {
+                if fetched.messages.is_empty() {
+                    return Ok(0);
+                }
+                let batch = PipeBatch {
+                    id: progress.batch_count,
+                    start_offsets: offsets.clone(),
+                    end_offsets: fetched.end_offsets,
+                };
+                progress.offsets = offsets;
+                progress.pending_batch = Some(batch.clone());
+                seq = user_mgr
+                    .update_pipe_progress(tenant, &pipe.name, &progress, seq)
+                    .await?;
+                batch
+            }
+        };
+
+        // A message failing to load fails the batch every time it's loaded again, so the
+        // batch is loaded again skipping the invalid messages instead of stalling the pipe,
+        // and the error is kept in the progress.
+        let messages = &fetched.messages;
+        let loaded = Self::load(
+            tenant,
+            pipe,
+            source,
+            &batch,
+            messages,
+            OnErrorMode::AbortNum(1),
+        );
+        let last_error = match loaded.await {
+            Ok(()) => None,
+            Err(cause) => {
+                warn!(
+                    "Load batch {} of pipe {} failure, skip the invalid messages: {:?}",
+                    batch.id, pipe.name, cause
+                );
+                Self::load(
+                    tenant,
+                    pipe,
+                    source,
+                    &batch,
+                    messages,
+                    OnErrorMode::Continue,
+                )
+                .await?;
+                Some(format!(
+                    "invalid messages of batch {} skipped: {}",
+                    batch.id,
+                    cause.message()
+                ))
+            }
+        };
+
+        let message_count = batch.message_count();
+        progress.offsets.extend(batch.end_offsets);
+        progress.pending_batch = None;
+        progress.batch_count += 1;
+        progress.message_count += message_count;
+        progress.last_error = last_error;
+        progress.updated_on = Some(Utc::now());
+        user_mgr
+            .update_pipe_progress(tenant, &pipe.name, &progress, seq)
+            .await?;
+        Ok(message_count)
+    }
+
+    /// Copy the new files of the stage if the refresh interval has elapsed since the
+    /// last run.
+    #[async_backtrace::framed]
+    async fn run_stage_batch(tenant: &str, pipe: &PipeInfo, source: &StageSource) -> Result<u64> {
+        let user_mgr = UserApiProvider::instance();
+        let progress = user_mgr.get_pipe_progress(tenant, &pipe.name).await?;
+        let seq = progress.seq;
+        let mut progress = progress.data;
+
+        if let Some(updated_on) = progress.updated_on {
+            let elapsed = (Utc::now() - updated_on).num_seconds();
+            if elapsed >= 0 && (elapsed as u64) < source.refresh_interval {
+                return Ok(0);
+            }
+        }
+
+        let ctx = Self::create_context(tenant, pipe).await?;
+        let copied = copy_new_files(ctx, pipe, source, progress.last_modified).await?;
+
+        // The progress is updated even if there is no new file, so that the stage is
+        // listed again after the refresh interval.
+        if copied.file_count > 0 {
+            progress.batch_count += 1;
+            progress.file_count += copied.file_count;
+            progress.message_count += copied.row_count;
+            progress.last_modified = progress.last_modified.max(copied.last_modified);
+        }
+        progress.last_error = None;
+        progress.updated_on = Some(Utc::now());
+        user_mgr
+            .update_pipe_progress(tenant, &pipe.name, &progress, seq)
+            .await?;
+        Ok(copied.file_count)
+    }
+
+    /// Create a query context with the privileges of the owner of the pipe.
+    #[async_backtrace::framed]
+    async fn create_context(tenant: &str, pipe: &PipeInfo) -> Result<Arc<QueryContext>> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Pipe)
+            .await?;
+        let user = UserApiProvider::instance()
+            .get_user(tenant, pipe.owner.clone())
+            .await?;
+        session.set_authed_user(user, None).await?;
+        session.create_query_context().await
+    }
+
+    /// Insert the messages into the table as the owner of the pipe.
+    #[async_backtrace::framed]
+    async fn load(
+        tenant: &str,
+        pipe: &PipeInfo,
+        source: &KafkaSource,
+        batch: &PipeBatch,
+        messages: &[Vec<u8>],
+        on_error_mode: OnErrorMode,
+    ) -> Result<()> {
+        let ctx = Self::create_context(tenant, pipe).await?;
+
+        // The label is bound to the pipe, so a pipe created again with the same name
+        // doesn't collide with the batches of the dropped one.
+        let label = format!(
+            "__fd_pipe_batches/{}/{}/{}/{}",
+            tenant,
+            pipe.name,
+            pipe.created_on.timestamp_micros(),
+            batch.id
+        );
+        ctx.get_settings().set_deduplicate_label(label)?;
+
+        let table = ctx
+            .get_table(&pipe.catalog, &pipe.database, &pipe.table)
+            .await?;
+        let schema = table.schema();
+
+        // Each message is a record, they are loaded as one file.
+        let mut data = Vec::with_capacity(messages.iter().map(|m| m.len() + 1).sum());
+        for message in messages {
+            data.extend_from_slice(message);
+            if !message.ends_with(b"\n") {
+                data.push(b'\n');
+            }
+        }
+        let (tx, rx) = tokio::sync::mpsc::channel(1);
+        tx.send(Ok(StreamingReadBatch {
+            data,
+            path: format!("kafka://{}", source.topic),
+            is_start: true,
+            compression: None,
+        }))
+        .await
+        .map_err(|e| ErrorCode::Internal(format!("send messages of pipe failure: {}", e)))?;
+        drop(tx);
+
+        let input_context = Arc::new(
+            InputContext::try_create_from_insert_file_format(
+                rx,
+                ctx.get_settings(),
+                pipe.file_format_params.clone(),
+                schema.clone(),
+                ctx.get_scan_progress(),
+                false,
+                table.get_block_thresholds(),
+                on_error_mode.clone(),
+            )
+            .await?,
+        );
+        let plan = Plan::Insert(Box::new(Insert {
+            catalog: pipe.catalog.clone(),
+            database: pipe.database.clone(),
+            table: pipe.table.clone(),
+            table_id: table.get_id(),
+            schema,
+            overwrite: false,
+            source: InsertInputSource::StreamingWithFileFormat {
+                format: pipe.file_format_params.clone(),
+                on_error_mode,
+                start: 0,
+                input_context_option: Some(input_context),
+            },
+        }));
+
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let mut stream = interpreter.execute(ctx).await?;
+        while let Some(block) = stream.next().await {
+            block?;
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn record_error(tenant: &str, name: &str, cause: &ErrorCode) {
+        let user_mgr = UserApiProvider::instance();
+        let res = async {
+            let progress = user_mgr.get_pipe_progress(tenant, name).await?;
+            let mut data = progress.data;
+            data.last_error = Some(cause.message());
+            data.updated_on = Some(Utc::now());
+            user_mgr
+                .update_pipe_progress(tenant, name, &data, progress.seq)
+                .await
+        }
+        .await;
+        if let Err(e) = res {
+            warn!("Record error of pipe {} failure: {:?}", name, e);
+        }
+    }
+}
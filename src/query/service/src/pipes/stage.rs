@@ -0,0 +1,201 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+use common_ast::ast::CopyStmt;
+use common_ast::ast::CopyUnit;
+use common_ast::ast::Identifier;
+use common_ast::ast::StageLocation;
+use common_ast::ast::Statement;
+use common_catalog::catalog::CatalogManager;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::UInt64Type;
+use common_expression::types::ValueType;
+use common_meta_app::principal::PipeInfo;
+use common_meta_app::principal::StageSource;
+use common_sql::binder::parse_stage_location_v2;
+use common_sql::optimizer::optimize;
+use common_sql::optimizer::OptimizerConfig;
+use common_sql::optimizer::OptimizerContext;
+use common_sql::Binder;
+use common_sql::Metadata;
+use common_sql::NameResolutionContext;
+use common_storage::StageFileInfo;
+use common_storage::StageFilesInfo;
+use common_storages_stage::StageTable;
+use futures::StreamExt;
+use parking_lot::RwLock;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// The files modified within the lag before the high-water mark are listed again, a file
+/// may become visible after the newer ones, e.g. the upload of a large file.
+const HIGH_WATER_MARK_LAG_SECS: i64 = 600;
+
+/// Files and rows loaded by one run of a stage pipe.
+#[derive(Default)]
+pub struct CopiedFiles {
+    pub file_count: u64,
+    pub row_count: u64,
+    /// The newest last modified time of the files loaded.
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+/// Copy the files of the stage not loaded yet into the table of the pipe, the oldest first.
+///
+/// The files older than the high-water mark `last_modified` (minus a lag) are never loaded
+/// again, so the files are not loaded twice after the copied files info of the table expires.
+/// The newer ones already loaded are skipped with the copied files info, so a file is loaded
+/// only once even if the runner fails after the copy is committed.
+#[async_backtrace::framed]
+pub async fn copy_new_files(
+    ctx: Arc<QueryContext>,
+    pipe: &PipeInfo,
+    source: &StageSource,
+    last_modified: Option<DateTime<Utc>>,
+) -> Result<CopiedFiles> {
+    let files = list_new_files(&ctx, pipe, source, last_modified).await?;
+    if files.is_empty() {
+        return Ok(CopiedFiles::default());
+    }
+    let last_modified = files.iter().map(|file| file.last_modified).max();
+    let files = files.into_iter().map(|file| file.path).collect();
+
+    let ident = |name: &str| Identifier {
+        name: name.to_string(),
+        quote: Some('`'),
+        span: None,
+    };
+    let stmt = Statement::Copy(CopyStmt {
+        hints: None,
+        src: CopyUnit::StageLocation(StageLocation {
+            name: source.stage.clone(),
+            path: source.path.clone(),
+        }),
+        dst: CopyUnit::Table {
+            catalog: Some(ident(&pipe.catalog)),
+            database: Some(ident(&pipe.database)),
+            table: ident(&pipe.table),
+            columns: None,
+        },
+        files: Some(files),
+        pattern: None,
+        file_format: source.file_format_options.clone(),
+        validation_mode: "".to_string(),
+        size_limit: 0,
+        max_files: pipe.batch_size as usize,
+        max_file_size: 0,
+        split_size: 0,
+        single: false,
+        purge: false,
+        force: false,
+        disable_variant_check: false,
+        on_error: "abort".to_string(),
+        return_file_status: true,
+        manifest: false,
+    });
+
+    let settings = ctx.get_settings();
+    let metadata = Arc::new(RwLock::new(Metadata::default()));
+    let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+    let binder = Binder::new(
+        ctx.clone(),
+        CatalogManager::instance(),
+        name_resolution_ctx,
+        metadata,
+    );
+    let plan = binder.bind(&stmt).await?;
+    let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
+        enable_distributed_optimization: false,
+    }));
+    let plan = optimize(ctx.clone(), opt_ctx, plan)?;
+
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let mut stream = interpreter.execute(ctx).await?;
+    // Each row is the status of a file: `File`, `Rows_loaded`, `Errors_seen`, `Error`.
+    let mut copied = CopiedFiles {
+        last_modified,
+        ..Default::default()
+    };
+    while let Some(block) = stream.next().await {
+        let block = block?;
+        let num_rows = block.num_rows();
+        copied.file_count += num_rows as u64;
+        let rows_loaded = block
+            .get_by_offset(1)
+            .value
+            .convert_to_full_column(&DataType::Number(NumberDataType::UInt64), num_rows);
+        if let Some(rows_loaded) = UInt64Type::try_downcast_column(&rows_loaded) {
+            copied.row_count += rows_loaded.iter().sum::<u64>();
+        }
+    }
+    Ok(copied)
+}
+
+/// List at most `batch_size` files of the stage newer than the high-water mark and not
+/// loaded yet, the paths are relative to the path of the source.
+#[async_backtrace::framed]
+async fn list_new_files(
+    ctx: &Arc<QueryContext>,
+    pipe: &PipeInfo,
+    source: &StageSource,
+    last_modified: Option<DateTime<Utc>>,
+) -> Result<Vec<StageFileInfo>> {
+    let table_ctx: Arc<dyn TableContext> = ctx.clone();
+    let (stage_info, path) =
+        parse_stage_location_v2(&table_ctx, &source.stage, &source.path).await?;
+    let operator = StageTable::get_op(&stage_info)?;
+    let files_info = StageFilesInfo {
+        path: path.clone(),
+        files: None,
+        pattern: source.pattern.clone(),
+    };
+
+    let since = last_modified.map(|t| t - Duration::seconds(HIGH_WATER_MARK_LAG_SECS));
+    let mut files = files_info
+        .list(&operator, false, None)
+        .await?
+        .into_iter()
+        .filter(|file| since.map_or(true, |since| file.last_modified > since))
+        .collect::<Vec<_>>();
+    files.sort_by(|a, b| {
+        a.last_modified
+            .cmp(&b.last_modified)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+
+    let mut files = ctx
+        .filter_out_copied_files(
+            &pipe.catalog,
+            &pipe.database,
+            &pipe.table,
+            &files,
+            Some(pipe.batch_size as usize),
+        )
+        .await?;
+    for file in files.iter_mut() {
+        if let Some(relative) = file.path.strip_prefix(&path) {
+            file.path = relative.trim_start_matches('/').to_string();
+        }
+    }
+    Ok(files)
+}
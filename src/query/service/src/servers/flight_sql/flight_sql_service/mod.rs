@@ -30,6 +30,7 @@ use common_sql::PlanExtras;
 use dashmap::DashMap;
 use futures::Stream;
 use parking_lot::Mutex;
+use query::StatementResult;
 use sql_info::SqlInfoProvider;
 use tonic::Status;
 use uuid::Uuid;
@@ -52,6 +53,8 @@ type DoGetStream = Pin<Box<dyn Stream<Item = Result<FlightData, Status>> + Send
 pub struct FlightSqlServiceImpl {
     pub sessions: Mutex<ExpiringMap<String, Arc<Session>>>,
     statements: Arc<DashMap<Uuid, (Plan, PlanExtras)>>,
+    /// Results of the statements being fetched, by the handle of the statement.
+    results: Arc<DashMap<Uuid, Arc<StatementResult>>>,
 }
 
 /// in current official JDBC driver, Statement is based on PreparedStatement too, so we impl it first.
@@ -60,6 +63,7 @@ impl FlightSqlServiceImpl {
         FlightSqlServiceImpl {
             sessions: Mutex::new(Default::default()),
             statements: Arc::new(Default::default()),
+            results: Arc::new(Default::default()),
         }
     }
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
@@ -26,6 +27,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
 use common_sql::plans::Plan;
 use common_sql::PlanExtras;
 use common_sql::Planner;
@@ -35,6 +37,7 @@ use futures::StreamExt;
 use serde::Deserialize;
 use serde::Serialize;
 use tonic::Status;
+use uuid::Uuid;
 
 use super::status;
 use super::DoGetStream;
@@ -238,6 +241,90 @@ impl FlightSqlServiceImpl {
     }
 }
 
+/// The result of a statement, which is fetched by multiple streams in parallel.
+///
+/// The blocks of the result are sent to a channel shared by the streams, each
+/// block is fetched by one of the streams.
+pub(super) struct StatementResult {
+    schema: DataSchemaRef,
+    receiver: async_channel::Receiver<Result<DataBlock>>,
+    /// Number of the streams not fetched yet, the result is removed once all
+    /// the streams are fetched.
+    remaining_streams: AtomicUsize,
+}
+
+impl FlightSqlServiceImpl {
+    /// Start executing the statement, the result is fetched by `num_streams` streams
+    /// with the returned handle.
+    #[async_backtrace::framed]
+    pub(super) async fn start_statement(
+        &self,
+        session: Arc<Session>,
+        plan: &Plan,
+        plan_extras: &PlanExtras,
+        num_streams: usize,
+    ) -> Result<Uuid> {
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+
+        context.attach_query_str(plan.to_string(), plan_extras.statement.to_mask_sql());
+        let interpreter = InterpreterFactory::get(context.clone(), plan).await?;
+        let mut data_stream = interpreter.execute(context.clone()).await?;
+
+        let (sender, receiver) = async_channel::bounded(num_streams);
+        tokio::spawn(async move {
+            while let Some(block) = data_stream.next().await {
+                let is_err = block.is_err();
+                // The send fails if all the streams are dropped.
+                if sender.send(block).await.is_err() || is_err {
+                    break;
+                }
+            }
+        });
+
+        let handle = Uuid::new_v4();
+        self.results.insert(
+            handle,
+            Arc::new(StatementResult {
+                schema: plan.schema(),
+                receiver,
+                remaining_streams: AtomicUsize::new(num_streams),
+            }),
+        );
+        Ok(handle)
+    }
+
+    /// One of the streams of the result, it ends when all the blocks of the
+    /// result are fetched by the streams.
+    pub(super) fn fetch_statement(&self, handle: &Uuid) -> Result<DoGetStream> {
+        let result = self
+            .results
+            .get(handle)
+            .map(|result| result.value().clone())
+            .ok_or_else(|| {
+                ErrorCode::BadArguments(format!(
+                    "Result of statement {handle} not found, it may have been fetched"
+                ))
+            })?;
+        if result.remaining_streams.fetch_sub(1, Ordering::SeqCst) <= 1 {
+            self.results.remove(handle);
+        }
+
+        let schema = result.schema.clone();
+        let header = Self::schema_to_flight_data((*schema).clone());
+        let batches = result.receiver.clone().map(move |block| {
+            block
+                .and_then(|block| Self::block_to_flight_data(block, &schema))
+                .map_err(|e| status!("Could not convert batches", e))
+        });
+        Ok(Box::pin(
+            futures::stream::once(async { Ok(header) }).chain(batches),
+        ))
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 struct ProgressValue {
     pub total_rows: usize,
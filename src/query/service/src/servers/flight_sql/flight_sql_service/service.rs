@@ -64,6 +64,7 @@ use arrow_flight::PutResult;
 use arrow_flight::SchemaAsIpc;
 use arrow_flight::Ticket;
 use arrow_ipc::writer::IpcWriteOptions;
+use common_ast::ast::Statement;
 use common_base::base::uuid::Uuid;
 use common_exception::Result;
 use common_expression::DataSchema;
@@ -207,9 +208,62 @@ impl FlightSqlService for FlightSqlServiceImpl {
         query: CommandStatementQuery,
         request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        info!("get_flight_info_sql_info(query={})", query.query);
-        let _session = self.get_session(&request)?;
-        Ok(simple_flight_info(query))
+        info!("get_flight_info_statement(query={})", query.query);
+        let session = self.get_session(&request)?;
+        let (plan, plan_extras) = self
+            .plan_sql(&session, &query.query)
+            .await
+            .map_err(|e| status!("Error getting result schema", e))?;
+
+        // The result is fetched by multiple streams in parallel, unless it's sorted.
+        let ordered =
+            matches!(&plan_extras.statement, Statement::Query(query) if !query.order_by.is_empty());
+        let num_streams = if ordered || !plan.has_result_set() {
+            1
+        } else {
+            let max_threads = session
+                .get_settings()
+                .get_max_threads()
+                .map_err(|e| status!("Could not get max_threads", e))?;
+            max_threads.max(1) as usize
+        };
+
+        let schema = plan.schema().as_ref().into();
+        let handle = self
+            .start_statement(session, &plan, &plan_extras, num_streams)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        info!("get_flight_info_statement with handle={handle}, streams={num_streams}");
+
+        // All the endpoints share the same ticket, the location is left empty so the
+        // streams are fetched from this server.
+        let ticket = TicketStatementQuery {
+            statement_handle: handle.as_bytes().to_vec().into(),
+        };
+        let ticket = Ticket {
+            ticket: ticket.as_any().encode_to_vec().into(),
+        };
+        let endpoints = (0..num_streams)
+            .map(|_| FlightEndpoint {
+                ticket: Some(ticket.clone()),
+                location: vec![],
+            })
+            .collect();
+
+        let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
+            .try_into()
+            .map_err(|e| status!("Unable to serialize schema", e))?;
+        let IpcMessage(schema_bytes) = message;
+
+        let info = FlightInfo {
+            schema: schema_bytes,
+            flight_descriptor: Some(request.into_inner()),
+            endpoint: endpoints,
+            total_records: -1,
+            total_bytes: -1,
+            ordered,
+        };
+        Ok(Response::new(info))
     }
 
     #[async_backtrace::framed]
@@ -370,10 +424,18 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_statement(
         &self,
         ticket: TicketStatementQuery,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        info!("do_get_statement({ticket:?}");
-        Err(Status::unimplemented("do_get_statement not implemented"))
+        let _session = self.get_session(&request)?;
+        let handle = Uuid::from_slice(ticket.statement_handle.as_ref())
+            .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
+
+        info!("do_get_statement with handle={handle}");
+
+        let stream = self
+            .fetch_statement(&handle)
+            .map_err(|e| status!("fail to fetch", e))?;
+        Ok(Response::new(stream))
     }
 
     #[async_backtrace::framed]
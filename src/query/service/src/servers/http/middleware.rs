@@ -48,6 +48,7 @@ use crate::sessions::SessionType;
 
 const DEDUPLICATE_LABEL: &str = "X-DATABEND-DEDUPLICATE-LABEL";
 const USER_AGENT: &str = "User-Agent";
+const TRACE_PARENT: &str = "traceparent";
 
 pub struct HTTPSessionMiddleware {
     pub kind: HttpHandlerKind,
@@ -191,10 +192,17 @@ impl<E> HTTPSessionEndpoint<E> {
             .get(USER_AGENT)
             .map(|id| id.to_str().unwrap().to_string());
 
+        let trace_parent = req
+            .headers()
+            .get(TRACE_PARENT)
+            .and_then(|id| id.to_str().ok())
+            .map(|id| id.to_string());
+
         Ok(HttpQueryContext::new(
             session,
             deduplicate_label,
             user_agent,
+            trace_parent,
         ))
     }
 }
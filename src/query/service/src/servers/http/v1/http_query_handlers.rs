@@ -106,6 +106,8 @@ impl QueryResponseField {
 pub struct QueryResponse {
     pub id: String,
     pub session_id: Option<String>,
+    // pass it in the later queries to use the session kept by the server.
+    pub session_token: Option<String>,
     pub session: Option<HttpSessionConf>,
     pub schema: Vec<QueryResponseField>,
     pub data: Vec<Vec<JsonValue>>,
@@ -168,6 +170,7 @@ impl QueryResponse {
             state: state.state,
             schema: QueryResponseField::from_schema(schema),
             session_id: Some(session_id),
+            session_token: r.session_token,
             session: r.session,
             stats,
             affect: state.affect,
@@ -192,6 +195,7 @@ impl QueryResponse {
             data: vec![],
             schema: vec![],
             session_id: None,
+            session_token: None,
             session: None,
             next_uri: None,
             stats_uri: None,
@@ -218,6 +222,7 @@ async fn query_final_handler(
                     StatusCode::BAD_REQUEST,
                 ));
             }
+            query.detach().await;
             Ok(QueryResponse::from_internal(query_id, response, true))
         }
         None => Err(query_id_not_found(query_id)),
@@ -235,6 +240,7 @@ async fn query_cancel_handler(
     match http_query_manager.get_query(&query_id).await {
         Some(query) => {
             query.kill().await;
+            query.detach().await;
             http_query_manager.remove_query(&query_id).await;
             StatusCode::OK
         }
@@ -40,7 +40,7 @@ use ExecuteState::*;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterQueryLog;
-use crate::servers::http::v1::query::sized_spsc::SizedChannelSender;
+use crate::servers::http::v1::query::result_spool::ResultSender;
 use crate::sessions::QueryAffect;
 use crate::sessions::QueryContext;
 use crate::sessions::Session;
@@ -226,7 +226,7 @@ impl ExecuteState {
         extras: PlanExtras,
         session: Arc<Session>,
         ctx: Arc<QueryContext>,
-        block_sender: SizedChannelSender<DataBlock>,
+        block_sender: ResultSender,
     ) -> Result<()> {
         ctx.attach_query_str(plan.to_string(), extras.statement.to_mask_sql());
         let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
@@ -268,7 +268,7 @@ async fn execute(
     interpreter: Arc<dyn Interpreter>,
     schema: DataSchemaRef,
     ctx: Arc<QueryContext>,
-    block_sender: SizedChannelSender<DataBlock>,
+    block_sender: ResultSender,
     executor: Arc<RwLock<Executor>>,
 ) -> Result<()> {
     let data_stream_res = interpreter.execute(ctx.clone()).await;
@@ -278,14 +278,14 @@ async fn execute(
             DataType::String,
             common_expression::Value::Scalar(Scalar::String(err.to_string().into_bytes())),
         );
-        block_sender.send(DataBlock::new(vec![data], 1), 1).await;
+        block_sender.send(DataBlock::new(vec![data], 1)).await;
         return Err(err);
     }
     let mut data_stream = data_stream_res.unwrap();
     match data_stream.next().await {
         None => {
             let block = DataBlock::empty_with_schema(schema);
-            block_sender.send(block, 0).await;
+            block_sender.send(block).await;
             Executor::stop(&executor, Ok(()), false).await;
             block_sender.close();
         }
@@ -295,17 +295,16 @@ async fn execute(
                 DataType::String,
                 common_expression::Value::Scalar(Scalar::String(err.to_string().into_bytes())),
             );
-            block_sender.send(DataBlock::new(vec![data], 1), 1).await;
+            block_sender.send(DataBlock::new(vec![data], 1)).await;
             Executor::stop(&executor, Err(err), false).await;
             block_sender.close();
         }
         Some(Ok(block)) => {
-            let size = block.num_rows();
-            block_sender.send(block, size).await;
+            block_sender.send(block).await;
             while let Some(block_r) = data_stream.next().await {
                 match block_r {
                     Ok(block) => {
-                        block_sender.send(block.clone(), block.num_rows()).await;
+                        block_sender.send(block).await;
                     }
                     Err(err) => {
                         // duplicate codes, but there is an async call
@@ -315,7 +314,7 @@ async fn execute(
                                 err.to_string().into_bytes(),
                             )),
                         );
-                        block_sender.send(DataBlock::new(vec![data], 1), 1).await;
+                        block_sender.send(DataBlock::new(vec![data], 1)).await;
                         block_sender.close();
                         return Err(err);
                     }
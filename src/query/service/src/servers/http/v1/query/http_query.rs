@@ -28,6 +28,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use log::info;
 use log::warn;
+use minitrace::prelude::*;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -39,6 +40,8 @@ use crate::servers::http::v1::query::execute_state::Progresses;
 use crate::servers::http::v1::query::expirable::Expirable;
 use crate::servers::http::v1::query::expirable::ExpiringState;
 use crate::servers::http::v1::query::http_query_manager::HttpQueryConfig;
+use crate::servers::http::v1::query::result_spool::ResultSender;
+use crate::servers::http::v1::query::result_spool::ResultSpool;
 use crate::servers::http::v1::query::sized_spsc::sized_spsc;
 use crate::servers::http::v1::query::ExecuteState;
 use crate::servers::http::v1::query::ExecuteStateKind;
@@ -59,6 +62,8 @@ fn default_as_true() -> bool {
 #[derive(Deserialize)]
 pub struct HttpQueryRequest {
     pub session_id: Option<String>,
+    /// Token of a session kept by the server, returned by the query which created it.
+    pub session_token: Option<String>,
     pub session: Option<HttpSessionConf>,
     pub sql: String,
     #[serde(default)]
@@ -72,6 +77,10 @@ impl Debug for HttpQueryRequest {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("HttpQueryRequest")
             .field("session_id", &self.session_id)
+            .field(
+                "session_token",
+                &self.session_token.as_ref().map(|_| "******"),
+            )
             .field("session", &self.session)
             .field("sql", &short_sql(self.sql.clone()))
             .field("pagination", &self.pagination)
@@ -182,6 +191,7 @@ pub struct ResponseState {
 pub struct HttpQueryResponseInternal {
     pub data: Option<ResponseData>,
     pub session_id: String,
+    pub session_token: Option<String>,
     pub session: Option<HttpSessionConf>,
     pub state: ResponseState,
 }
@@ -201,6 +211,7 @@ pub enum ExpireResult {
 pub struct HttpQuery {
     pub(crate) id: String,
     pub(crate) session_id: String,
+    session_token: Option<String>,
     request: HttpQueryRequest,
     state: Arc<RwLock<Executor>>,
     page_manager: Arc<TokioMutex<PageManager>>,
@@ -217,10 +228,27 @@ impl HttpQuery {
     ) -> Result<Arc<HttpQuery>> {
         let http_query_manager = HttpQueryManager::instance();
 
-        let session = if let Some(id) = &request.session_id {
-            let session = http_query_manager.get_session(id).await.ok_or_else(|| {
-                ErrorCode::UnknownSession(format!("unknown session-id {}, maybe expired", id))
-            })?;
+        let kept_session = match (&request.session_token, &request.session_id) {
+            (Some(token), _) => {
+                let session = http_query_manager.get_session_by_token(token).await?;
+                let user = ctx.get_session(SessionType::HTTPQuery).get_current_user()?;
+                if session.get_current_user()?.identity() != user.identity() {
+                    return Err(ErrorCode::UnknownSession(
+                        "session token is issued to another user",
+                    ));
+                }
+                Some(session)
+            }
+            (None, Some(id)) => {
+                Some(http_query_manager.get_session(id).await.ok_or_else(|| {
+                    ErrorCode::UnknownSession(format!("unknown session-id {}, maybe expired", id))
+                })?)
+            }
+            (None, None) => None,
+        };
+        let mut session_token = request.session_token.clone();
+
+        let session = if let Some(session) = kept_session {
             let mut n = 1;
             while let ExpiringState::InUse(query_id) = session.expire_state() {
                 if let Some(last_query) = &http_query_manager.get_query(&query_id).await {
@@ -230,6 +258,7 @@ impl HttpQuery {
                         ));
                     } else {
                         http_query_manager.remove_query(&query_id).await;
+                        last_query.detach().await;
                     }
                 }
                 // wait for Arc<QueryContextShared> to drop and detach itself from session
@@ -265,16 +294,24 @@ impl HttpQuery {
                 }
             }
             if let Some(secs) = session_conf.keep_server_session_secs {
-                if secs > 0 && request.session_id.is_none() {
-                    http_query_manager
-                        .add_session(session.clone(), Duration::from_secs(secs))
-                        .await;
+                if secs > 0 && request.session_id.is_none() && request.session_token.is_none() {
+                    session_token = Some(
+                        http_query_manager
+                            .add_session(session.clone(), Duration::from_secs(secs))
+                            .await,
+                    );
                 }
             }
         };
 
         let deduplicate_label = &ctx.deduplicate_label;
         let user_agent = &ctx.user_agent;
+        let span_context = ctx
+            .trace_parent
+            .as_ref()
+            .and_then(|trace_parent| SpanContext::decode_w3c_traceparent(trace_parent))
+            .unwrap_or_else(SpanContext::random);
+        let root = Span::root("HttpQuery::run", span_context);
         let ctx = session.create_query_context().await?;
 
         if let Some(label) = deduplicate_label {
@@ -299,6 +336,10 @@ impl HttpQuery {
         };
 
         let (block_sender, block_receiver) = sized_spsc(request.pagination.max_rows_in_buffer);
+        let spool = http_query_manager
+            .get_result_store(&ctx.get_tenant(), &session_id)
+            .map(|store| ResultSpool::create(store, &id));
+        let block_sender = ResultSender::create(block_sender, spool.clone());
         let start_time = Instant::now();
         let state = Arc::new(RwLock::new(Executor {
             query_id: id.clone(),
@@ -312,13 +353,14 @@ impl HttpQuery {
         let query_id = id.clone();
         let query_id_clone = id.clone();
 
-        let (plan, plan_extras) = ExecuteState::plan_sql(&sql, ctx.clone()).await?;
+        let (plan, plan_extras) = ExecuteState::plan_sql(&sql, ctx.clone())
+            .in_span(Span::enter_with_parent("HttpQuery::plan_sql", &root))
+            .await?;
         let schema = plan.schema();
 
         let http_query_runtime_instance = GlobalQueryRuntime::instance();
-        http_query_runtime_instance
-            .runtime()
-            .try_spawn(async move {
+        http_query_runtime_instance.runtime().try_spawn(
+            async move {
                 let state = state_clone.clone();
                 if let Err(e) = ExecuteState::try_start_query(
                     state,
@@ -345,19 +387,23 @@ impl HttpQuery {
                         .await;
                     block_sender_closer.close();
                 }
-            })?;
+            }
+            .in_span(root),
+        )?;
 
         let format_settings = ctx.get_format_settings()?;
         let data = Arc::new(TokioMutex::new(PageManager::new(
             query_id_clone,
             request.pagination.max_rows_per_page,
             block_receiver,
+            spool,
             schema,
             format_settings,
         )));
         let query = HttpQuery {
             id,
             session_id,
+            session_token,
             request,
             state,
             page_manager: data,
@@ -384,6 +430,7 @@ impl HttpQuery {
             state,
             session: session_conf,
             session_id: self.session_id.clone(),
+            session_token: self.session_token.clone(),
         })
     }
 
@@ -392,6 +439,7 @@ impl HttpQuery {
         HttpQueryResponseInternal {
             data: None,
             session_id: self.session_id.clone(),
+            session_token: self.session_token.clone(),
             state: self.get_state().await,
             session: None,
         }
@@ -26,6 +26,8 @@ pub struct HttpQueryContext {
     session: Arc<Session>,
     pub deduplicate_label: Option<String>,
     pub user_agent: Option<String>,
+    /// The W3C trace context of the client, the query is traced as a part of its trace.
+    pub trace_parent: Option<String>,
 }
 
 impl HttpQueryContext {
@@ -33,11 +35,13 @@ impl HttpQueryContext {
         session: Arc<Session>,
         deduplicate_label: Option<String>,
         user_agent: Option<String>,
+        trace_parent: Option<String>,
     ) -> Self {
         HttpQueryContext {
             session,
             deduplicate_label,
             user_agent,
+            trace_parent,
         }
     }
 
@@ -14,6 +14,7 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::sync::Weak;
 use std::time::Duration;
 
 use common_base::base::tokio::sync::RwLock;
@@ -22,40 +23,85 @@ use common_base::base::GlobalInstance;
 use common_base::runtime::GlobalIORuntime;
 use common_base::runtime::TrySpawn;
 use common_config::InnerConfig;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::storage::StorageFsConfig;
+use common_meta_app::storage::StorageParams;
+use common_storage::init_operator;
+use common_storage::DataOperator;
 use log::warn;
+use opendal::Operator;
 use parking_lot::Mutex;
+use uuid::Uuid;
 
 use super::expiring_map::ExpiringMap;
 use super::HttpQueryContext;
+use crate::clusters::ClusterDiscovery;
 use crate::servers::http::v1::query::http_query::ExpireResult;
 use crate::servers::http::v1::query::http_query::HttpQuery;
+use crate::servers::http::v1::query::result_spool::SessionResultStore;
 use crate::servers::http::v1::query::HttpQueryRequest;
 use crate::sessions::Session;
 
+const RESULT_SPOOL_PREFIX: &str = "_result_spool/";
+
 // TODO(youngsofun): may need refactor later for 2 reasons:
 // 1. some can be both configured and overwritten by http query request
 // 2. maybe QueryConfig can contain it directly
 #[derive(Copy, Clone)]
 pub(crate) struct HttpQueryConfig {
     pub(crate) result_timeout_secs: u64,
+    pub(crate) result_spool_max_bytes: u64,
+    pub(crate) result_memory_max_bytes: u64,
 }
 
 pub struct HttpQueryManager {
     pub(crate) queries: Arc<RwLock<HashMap<String, Arc<HttpQuery>>>>,
     pub(crate) sessions: Mutex<ExpiringMap<String, Arc<Session>>>,
+    // token -> id of the kept session.
+    pub(crate) session_tokens: Mutex<HashMap<String, String>>,
     pub(crate) config: HttpQueryConfig,
+    // None if spooling the results is disabled.
+    result_spool_operator: Option<Operator>,
+    // session id -> the results buffered by the queries of the session.
+    result_stores: Mutex<HashMap<String, Weak<SessionResultStore>>>,
+    // the tokens are prefixed with the node id, so the requests of a session can be
+    // routed to the node keeping it.
+    node_id: String,
 }
 
 impl HttpQueryManager {
     #[async_backtrace::framed]
     pub async fn init(cfg: &InnerConfig) -> Result<()> {
+        let result_spool_operator = match cfg.query.http_handler_result_spool_max_bytes {
+            0 => None,
+            _ => match cfg.query.http_handler_result_spool_local_path.as_str() {
+                "" => Some(DataOperator::instance().operator()),
+                path => {
+                    let operator = init_operator(&StorageParams::Fs(StorageFsConfig {
+                        root: path.to_string(),
+                    }))?;
+                    // the results spooled to the local disk before restarting are useless.
+                    if let Err(e) = operator.remove_all(RESULT_SPOOL_PREFIX).await {
+                        warn!("fail to remove the results spooled in {}: {}", path, e);
+                    }
+                    Some(operator)
+                }
+            },
+        };
+
         GlobalInstance::set(Arc::new(HttpQueryManager {
             queries: Arc::new(RwLock::new(HashMap::new())),
             sessions: Mutex::new(ExpiringMap::default()),
+            session_tokens: Mutex::new(HashMap::new()),
             config: HttpQueryConfig {
                 result_timeout_secs: cfg.query.http_handler_result_timeout_secs,
+                result_spool_max_bytes: cfg.query.http_handler_result_spool_max_bytes,
+                result_memory_max_bytes: cfg.query.http_handler_result_memory_max_bytes,
             },
+            result_spool_operator,
+            result_stores: Mutex::new(HashMap::new()),
+            node_id: ClusterDiscovery::instance().local_id(),
         }));
 
         Ok(())
@@ -135,12 +181,84 @@ impl HttpQueryManager {
     }
 
     #[async_backtrace::framed]
-    pub(crate) async fn add_session(self: &Arc<Self>, session: Arc<Session>, timeout: Duration) {
+    pub(crate) async fn get_session_by_token(
+        self: &Arc<Self>,
+        token: &str,
+    ) -> Result<Arc<Session>> {
+        if let Some((node_id, _)) = token.split_once(':') {
+            if node_id != self.node_id {
+                return Err(ErrorCode::UnknownSession(format!(
+                    "session token is issued by node {}, not this node {}",
+                    node_id, self.node_id
+                )));
+            }
+        }
+        let mut tokens = self.session_tokens.lock();
+        let session = tokens
+            .get(token)
+            .and_then(|session_id| self.sessions.lock().get(session_id));
+        match session {
+            Some(session) => Ok(session),
+            None => {
+                tokens.remove(token);
+                Err(ErrorCode::UnknownSession(
+                    "unknown session token, maybe expired",
+                ))
+            }
+        }
+    }
+
+    /// Keep the session on the server, returns the token to use it in later queries.
+    #[async_backtrace::framed]
+    pub(crate) async fn add_session(
+        self: &Arc<Self>,
+        session: Arc<Session>,
+        timeout: Duration,
+    ) -> String {
+        let token = format!("{}:{}", self.node_id, Uuid::new_v4().simple());
+        {
+            let mut tokens = self.session_tokens.lock();
+            tokens.insert(token.clone(), session.get_id());
+        }
         let mut sessions = self.sessions.lock();
         sessions.insert(session.get_id(), session, Some(timeout));
+        token
+    }
+
+    /// Returns the result store shared by the queries of the session, None if spooling
+    /// the results is disabled.
+    pub(crate) fn get_result_store(
+        self: &Arc<Self>,
+        tenant: &str,
+        session_id: &str,
+    ) -> Option<Arc<SessionResultStore>> {
+        let operator = self.result_spool_operator.clone()?;
+        let mut stores = self.result_stores.lock();
+        if let Some(store) = stores.get(session_id).and_then(|store| store.upgrade()) {
+            return Some(store);
+        }
+
+        // the stores are dropped with the last query of the session.
+        stores.retain(|_, store| store.strong_count() > 0);
+        let store = Arc::new(SessionResultStore::create(
+            operator,
+            format!("{}{}/{}", RESULT_SPOOL_PREFIX, tenant, session_id),
+            self.config.result_memory_max_bytes as usize,
+            self.config.result_spool_max_bytes as usize,
+        ));
+        stores.insert(session_id.to_string(), Arc::downgrade(&store));
+        Some(store)
     }
 
     pub(crate) fn kill_session(self: &Arc<Self>, session_id: &str) {
+        {
+            let mut tokens = self.session_tokens.lock();
+            tokens.retain(|_, id| id != session_id);
+        }
+        {
+            let mut stores = self.result_stores.lock();
+            stores.remove(session_id);
+        }
         let mut sessions = self.sessions.lock();
         sessions.remove(session_id);
     }
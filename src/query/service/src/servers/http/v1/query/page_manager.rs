@@ -26,6 +26,8 @@ use log::info;
 use serde_json::Value as JsonValue;
 
 use crate::servers::http::v1::json_block::block_to_json_value;
+use crate::servers::http::v1::query::result_spool::ResultBlock;
+use crate::servers::http::v1::query::result_spool::ResultSpool;
 use crate::servers::http::v1::query::sized_spsc::SizedChannelReceiver;
 use crate::servers::http::v1::JsonBlock;
 
@@ -56,7 +58,8 @@ pub struct PageManager {
     schema: DataSchemaRef,
     last_page: Option<Page>,
     row_buffer: VecDeque<Vec<JsonValue>>,
-    block_receiver: SizedChannelReceiver<DataBlock>,
+    block_receiver: SizedChannelReceiver<ResultBlock>,
+    spool: Option<ResultSpool>,
     format_settings: FormatSettings,
 }
 
@@ -64,7 +67,8 @@ impl PageManager {
     pub fn new(
         query_id: String,
         max_rows_per_page: usize,
-        block_receiver: SizedChannelReceiver<DataBlock>,
+        block_receiver: SizedChannelReceiver<ResultBlock>,
+        spool: Option<ResultSpool>,
         schema: DataSchemaRef,
         format_settings: FormatSettings,
     ) -> PageManager {
@@ -78,6 +82,7 @@ impl PageManager {
             row_buffer: Default::default(),
             schema,
             block_receiver,
+            spool,
             max_rows_per_page,
             format_settings,
         }
@@ -122,6 +127,30 @@ impl PageManager {
         }
     }
 
+    #[async_backtrace::framed]
+    async fn load_block(&self, block: ResultBlock) -> Result<DataBlock> {
+        match (block, &self.spool) {
+            (ResultBlock::Memory { block, .. }, _) => Ok(block),
+            (
+                ResultBlock::Spooled {
+                    location,
+                    num_rows,
+                    size,
+                },
+                Some(spool),
+            ) => {
+                debug!(
+                    "http query {} read spooled block {}",
+                    &self.query_id, &location
+                );
+                spool.read(&location, num_rows, size).await
+            }
+            (ResultBlock::Spooled { .. }, None) => {
+                Err(ErrorCode::Internal("spooled block without spool"))
+            }
+        }
+    }
+
     fn append_block(
         &mut self,
         rows: &mut Vec<Vec<JsonValue>>,
@@ -156,7 +185,10 @@ impl PageManager {
             }
             match tp {
                 Wait::Async => match self.block_receiver.try_recv() {
-                    Some(block) => self.append_block(&mut res, block, remain)?,
+                    Some(block) => {
+                        let block = self.load_block(block).await?;
+                        self.append_block(&mut res, block, remain)?
+                    }
                     None => break,
                 },
                 Wait::Deadline(t) => {
@@ -164,6 +196,7 @@ impl PageManager {
                     let d = *t - now;
                     match tokio::time::timeout(d, self.block_receiver.recv()).await {
                         Ok(Some(block)) => {
+                            let block = self.load_block(block).await?;
                             debug!(
                                 "http query {} got new block with {} rows",
                                 &self.query_id,
@@ -200,5 +233,8 @@ impl PageManager {
     #[async_backtrace::framed]
     pub async fn detach(&self) {
         self.block_receiver.close();
+        if let Some(spool) = &self.spool {
+            spool.cleanup().await;
+        }
     }
 }
@@ -0,0 +1,312 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Spool the result blocks of http queries to the storage.
+//!
+//! When the buffer of a query is full, the following blocks are written to the storage
+//! instead of blocking the query, so the query can run to the end while the client fetches
+//! the pages at its own pace. The spooled blocks are read back (and removed) by the page
+//! manager in order.
+//!
+//! The queries of a session share one [`SessionResultStore`], which bounds both the bytes
+//! of the blocks kept in memory and the bytes spooled, so a client paging through several
+//! large results does not pin unbounded memory on the server.
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::arrow::deserialize_column;
+use common_expression::arrow::serialize_column;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::Value;
+use log::info;
+use log::warn;
+use opendal::Operator;
+
+use crate::servers::http::v1::query::sized_spsc::SizedChannelSender;
+use crate::servers::http::v1::query::sized_spsc::SizedChannelSenderCloser;
+
+pub enum ResultBlock {
+    Memory {
+        block: DataBlock,
+        // released when the block is taken by the page manager or dropped with the buffer.
+        reservation: Option<MemoryReservation>,
+    },
+    Spooled {
+        location: String,
+        num_rows: usize,
+        size: usize,
+    },
+}
+
+/// The results buffered by the http queries of a session.
+///
+/// It is kept as long as any query of the session is alive, the spooled blocks left
+/// (e.g. the queries are killed) are removed when it is dropped.
+pub struct SessionResultStore {
+    operator: Operator,
+    location_prefix: String,
+    // 0 means no limit.
+    max_memory_bytes: usize,
+    max_spool_bytes: usize,
+    // bytes of the blocks in the buffers.
+    memory_bytes: AtomicUsize,
+    // bytes of the blocks spooled but not read yet.
+    spooled_bytes: AtomicUsize,
+    has_spooled: AtomicBool,
+}
+
+impl SessionResultStore {
+    pub fn create(
+        operator: Operator,
+        location_prefix: String,
+        max_memory_bytes: usize,
+        max_spool_bytes: usize,
+    ) -> Self {
+        SessionResultStore {
+            operator,
+            location_prefix,
+            max_memory_bytes,
+            max_spool_bytes,
+            memory_bytes: AtomicUsize::new(0),
+            spooled_bytes: AtomicUsize::new(0),
+            has_spooled: AtomicBool::new(false),
+        }
+    }
+
+    fn memory_bytes(&self) -> usize {
+        self.memory_bytes.load(Ordering::Acquire)
+    }
+
+    /// Returns None if the block can not be kept in memory without exceeding the limit.
+    fn try_reserve_memory(self: &Arc<Self>, size: usize) -> Option<MemoryReservation> {
+        let reservation = self.reserve_memory(size);
+        if self.max_memory_bytes != 0 && self.memory_bytes() > self.max_memory_bytes {
+            return None;
+        }
+        Some(reservation)
+    }
+
+    fn reserve_memory(self: &Arc<Self>, size: usize) -> MemoryReservation {
+        self.memory_bytes.fetch_add(size, Ordering::AcqRel);
+        MemoryReservation {
+            store: self.clone(),
+            size,
+        }
+    }
+}
+
+impl Drop for SessionResultStore {
+    fn drop(&mut self) {
+        if !self.has_spooled.load(Ordering::Acquire) {
+            return;
+        }
+        let operator = self.operator.clone();
+        let location_prefix = format!("{}/", self.location_prefix);
+        GlobalIORuntime::instance().spawn(async move {
+            if let Err(e) = operator.remove_all(&location_prefix).await {
+                warn!(
+                    "fail to remove spooled results in {}: {}",
+                    &location_prefix, e
+                );
+            }
+        });
+    }
+}
+
+pub struct MemoryReservation {
+    store: Arc<SessionResultStore>,
+    size: usize,
+}
+
+impl Drop for MemoryReservation {
+    fn drop(&mut self) {
+        self.store
+            .memory_bytes
+            .fetch_sub(self.size, Ordering::AcqRel);
+    }
+}
+
+#[derive(Clone)]
+pub struct ResultSpool {
+    store: Arc<SessionResultStore>,
+    location_prefix: String,
+    next_id: Arc<AtomicUsize>,
+}
+
+impl ResultSpool {
+    pub fn create(store: Arc<SessionResultStore>, query_id: &str) -> Self {
+        let location_prefix = format!("{}/{}", store.location_prefix, query_id);
+        ResultSpool {
+            store,
+            location_prefix,
+            next_id: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Returns None if the block exceeds the max bytes of the spool.
+    #[async_backtrace::framed]
+    pub async fn write(&self, block: &DataBlock) -> Result<Option<ResultBlock>> {
+        let data = serialize_block(block);
+        let size = data.len();
+        let store = &self.store;
+        if store.spooled_bytes.fetch_add(size, Ordering::AcqRel) + size > store.max_spool_bytes {
+            store.spooled_bytes.fetch_sub(size, Ordering::AcqRel);
+            return Ok(None);
+        }
+
+        let location = format!(
+            "{}/{}",
+            self.location_prefix,
+            self.next_id.fetch_add(1, Ordering::Relaxed)
+        );
+        store.has_spooled.store(true, Ordering::Release);
+        if let Err(e) = store.operator.write(&location, data).await {
+            store.spooled_bytes.fetch_sub(size, Ordering::AcqRel);
+            return Err(e.into());
+        }
+
+        Ok(Some(ResultBlock::Spooled {
+            location,
+            num_rows: block.num_rows(),
+            size,
+        }))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn read(&self, location: &str, num_rows: usize, size: usize) -> Result<DataBlock> {
+        let operator = &self.store.operator;
+        let data = operator.read(location).await?;
+        operator.delete(location).await?;
+        self.store.spooled_bytes.fetch_sub(size, Ordering::AcqRel);
+        deserialize_block(&data, num_rows)
+    }
+
+    /// Remove the blocks not read by the client.
+    #[async_backtrace::framed]
+    pub async fn cleanup(&self) {
+        if self.next_id.load(Ordering::Relaxed) == 0 {
+            return;
+        }
+        info!("remove spooled results in {}", &self.location_prefix);
+        let location_prefix = format!("{}/", self.location_prefix);
+        if let Err(e) = self.store.operator.remove_all(&location_prefix).await {
+            warn!(
+                "fail to remove spooled results in {}: {}",
+                &self.location_prefix, e
+            );
+        }
+    }
+}
+
+/// Send the result blocks to the page manager, spooling them when the buffer is full.
+pub struct ResultSender {
+    sender: SizedChannelSender<ResultBlock>,
+    spool: Option<ResultSpool>,
+}
+
+impl ResultSender {
+    pub fn create(sender: SizedChannelSender<ResultBlock>, spool: Option<ResultSpool>) -> Self {
+        ResultSender { sender, spool }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn send(&self, block: DataBlock) -> bool {
+        let num_rows = block.num_rows();
+        let spool = match &self.spool {
+            None => {
+                let block = ResultBlock::Memory {
+                    block,
+                    reservation: None,
+                };
+                return self.sender.send(block, num_rows).await;
+            }
+            Some(spool) => spool,
+        };
+
+        // spool the block directly if the memory of the session is used up.
+        let block = match spool.store.try_reserve_memory(block.memory_size()) {
+            None => block,
+            Some(reservation) => {
+                let block = ResultBlock::Memory {
+                    block,
+                    reservation: Some(reservation),
+                };
+                match self.sender.try_send(block, num_rows) {
+                    Ok(sent) => return sent,
+                    Err(ResultBlock::Memory { block, .. }) => block,
+                    Err(_) => unreachable!(),
+                }
+            }
+        };
+
+        let block = match spool.write(&block).await {
+            // spooled blocks do not take the space of the buffer.
+            Ok(Some(spooled)) => return self.sender.send(spooled, 0).await,
+            Ok(None) => block,
+            Err(e) => {
+                warn!("fail to spool result block: {}", e);
+                block
+            }
+        };
+        // the spool is full, wait for the client to fetch the buffered blocks.
+        let reservation = spool.store.reserve_memory(block.memory_size());
+        let block = ResultBlock::Memory {
+            block,
+            reservation: Some(reservation),
+        };
+        self.sender.send(block, num_rows).await
+    }
+
+    pub fn close(&self) {
+        self.sender.close()
+    }
+
+    pub fn closer(&self) -> SizedChannelSenderCloser<ResultBlock> {
+        self.sender.closer()
+    }
+}
+
+fn serialize_block(block: &DataBlock) -> Vec<u8> {
+    let mut data = Vec::new();
+    for entry in block.convert_to_full().columns() {
+        let column = serialize_column(entry.value.as_column().unwrap());
+        data.extend_from_slice(&(column.len() as u64).to_le_bytes());
+        data.extend_from_slice(&column);
+    }
+    data
+}
+
+fn deserialize_block(data: &[u8], num_rows: usize) -> Result<DataBlock> {
+    let invalid = || ErrorCode::Internal("invalid spooled result block");
+    let mut columns = vec![];
+    let mut offset = 0;
+    while offset < data.len() {
+        let len_bytes = data.get(offset..offset + 8).ok_or_else(invalid)?;
+        let len = u64::from_le_bytes(len_bytes.try_into().unwrap()) as usize;
+        offset += 8;
+        let column_bytes = data.get(offset..offset + len).ok_or_else(invalid)?;
+        let column = deserialize_column(column_bytes).ok_or_else(invalid)?;
+        offset += len;
+        columns.push(BlockEntry::new(column.data_type(), Value::Column(column)));
+    }
+    Ok(DataBlock::new(columns, num_rows))
+}
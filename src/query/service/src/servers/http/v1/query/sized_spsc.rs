@@ -114,6 +114,18 @@ impl<T> SizedChannel<T> {
         guard.try_recv()
     }
 
+    /// Returns the value back if the channel is full.
+    pub fn try_send_nowait(&self, value: T, size: usize) -> Result<bool, T> {
+        match self.try_send(value, size) {
+            Ok(Some(v)) => Err(v),
+            Ok(None) => {
+                self.notify_on_sent.notify_one();
+                Ok(true)
+            }
+            Err(_) => Ok(false),
+        }
+    }
+
     #[async_backtrace::framed]
     pub async fn send(&self, value: T, size: usize) -> bool {
         let mut to_send = value;
@@ -203,6 +215,12 @@ impl<T> SizedChannelSender<T> {
         self.chan.send(value, size).await
     }
 
+    /// Send without waiting, returns the value back if the channel is full,
+    /// or false if the channel is closed.
+    pub fn try_send(&self, value: T, size: usize) -> Result<bool, T> {
+        self.chan.try_send_nowait(value, size)
+    }
+
     pub fn close(&self) {
         self.chan.stop_send()
     }
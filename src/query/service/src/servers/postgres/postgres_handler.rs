@@ -0,0 +1,219 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::future::Future;
+use std::net::Shutdown;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_base::base::tokio::net::TcpStream;
+use common_base::base::tokio::task::JoinHandle;
+use common_base::runtime::Runtime;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::future::AbortHandle;
+use futures::future::AbortRegistration;
+use futures::future::Abortable;
+use futures::StreamExt;
+use log::error;
+use log::info;
+use log::warn;
+use pgwire::tokio::process_socket;
+use socket2::SockRef;
+use socket2::TcpKeepalive;
+use tokio_rustls::TlsAcceptor;
+use tokio_stream::wrappers::TcpListenerStream;
+
+use crate::servers::postgres::postgres_session::PostgresConnection;
+use crate::servers::server::ListeningStream;
+use crate::servers::server::Server;
+use crate::servers::MySQLTlsConfig;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+/// Serve the PostgreSQL wire protocol, so that psql and pg drivers can connect.
+///
+/// The passwords are sent by the clients in clear text, as the users only keep the hashes of
+/// the passwords, which the MD5 and SCRAM authentications can't be checked against. So the
+/// connections must be encrypted with TLS, unless the handler only listens on a loopback
+/// address.
+pub struct PostgresHandler {
+    abort_handle: AbortHandle,
+    abort_registration: Option<AbortRegistration>,
+    join_handle: Option<JoinHandle<()>>,
+    keepalive: TcpKeepalive,
+    tls: Option<Arc<TlsAcceptor>>,
+}
+
+impl PostgresHandler {
+    pub fn create(
+        tcp_keepalive_timeout_secs: u64,
+        tls_config: MySQLTlsConfig,
+    ) -> Result<Box<dyn Server>> {
+        let (abort_handle, registration) = AbortHandle::new_pair();
+        let keepalive = TcpKeepalive::new()
+            .with_time(std::time::Duration::from_secs(tcp_keepalive_timeout_secs));
+        let tls = tls_config
+            .setup()?
+            .map(|config| Arc::new(TlsAcceptor::from(Arc::new(config))));
+
+        Ok(Box::new(PostgresHandler {
+            abort_handle,
+            abort_registration: Some(registration),
+            join_handle: None,
+            keepalive,
+            tls,
+        }))
+    }
+
+    #[async_backtrace::framed]
+    async fn listener_tcp(listening: SocketAddr) -> Result<(TcpListenerStream, SocketAddr)> {
+        let listener = tokio::net::TcpListener::bind(listening)
+            .await
+            .map_err(|e| {
+                ErrorCode::TokioError(format!("{{{}:{}}} {}", listening.ip(), listening.port(), e))
+            })?;
+        let listener_addr = listener.local_addr()?;
+        Ok((TcpListenerStream::new(listener), listener_addr))
+    }
+
+    fn listen_loop(&self, stream: ListeningStream, rt: Arc<Runtime>) -> impl Future<Output = ()> {
+        let keepalive = self.keepalive.clone();
+        let tls = self.tls.clone();
+
+        stream.for_each(move |accept_socket| {
+            let keepalive = keepalive.clone();
+            let tls = tls.clone();
+            let executor = rt.clone();
+            let sessions = SessionManager::instance();
+            async move {
+                match accept_socket {
+                    Err(error) => error!("Broken session connection: {}", error),
+                    Ok(socket) => {
+                        PostgresHandler::accept_socket(sessions, executor, socket, keepalive, tls)
+                    }
+                };
+            }
+        })
+    }
+
+    fn accept_socket(
+        sessions: Arc<SessionManager>,
+        executor: Arc<Runtime>,
+        socket: TcpStream,
+        keepalive: TcpKeepalive,
+        tls: Option<Arc<TlsAcceptor>>,
+    ) {
+        executor.spawn(async move {
+            match sessions.create_session(SessionType::Postgres).await {
+                Err(error) => {
+                    // The client gets an unexpected EOF, as it's not authenticated yet.
+                    warn!("create session failed, {:?}", error);
+                }
+                Ok(session) => {
+                    info!("PostgreSQL connection coming: {:?}", socket.peer_addr());
+
+                    if let Err(e) = SockRef::from(&socket).set_tcp_keepalive(&keepalive) {
+                        warn!("failed to set socket option keepalive {}", e);
+                    }
+
+                    let socket = match Self::attach_session(&session, socket) {
+                        Ok(socket) => socket,
+                        Err(error) => {
+                            error!("Cannot attach PostgreSQL session: {:?}", error);
+                            return;
+                        }
+                    };
+
+                    let connection = Arc::new(PostgresConnection::create(session, tls.is_some()));
+                    if let Err(error) = process_socket(
+                        socket,
+                        tls,
+                        connection.clone(),
+                        connection.clone(),
+                        connection,
+                    )
+                    .await
+                    {
+                        error!("Unexpected error occurred during query: {:?}", error);
+                    }
+                }
+            }
+        });
+    }
+
+    /// The session is killed by shutting down the socket.
+    fn attach_session(session: &Arc<Session>, socket: TcpStream) -> Result<TcpStream> {
+        let host = socket.peer_addr().ok();
+        let socket = socket.into_std()?;
+        let socket_ref = socket.try_clone()?;
+        session.attach(host, move || {
+            if let Err(error) = socket_ref.shutdown(Shutdown::Both) {
+                error!("Cannot shutdown PostgreSQL session io {}", error);
+            }
+        });
+        Ok(TcpStream::from_std(socket)?)
+    }
+}
+
+#[async_trait::async_trait]
+impl Server for PostgresHandler {
+    #[async_backtrace::framed]
+    async fn shutdown(&mut self, graceful: bool) {
+        if !graceful {
+            return;
+        }
+
+        self.abort_handle.abort();
+
+        if let Some(join_handle) = self.join_handle.take() {
+            if let Err(error) = join_handle.await {
+                error!(
+                    "Unexpected error during shutdown PostgresHandler. cause {}",
+                    error
+                );
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn start(&mut self, listening: SocketAddr) -> Result<SocketAddr> {
+        match self.abort_registration.take() {
+            None => Err(ErrorCode::Internal("PostgresHandler already running.")),
+            Some(registration) => {
+                if self.tls.is_none() && !listening.ip().is_loopback() {
+                    return Err(ErrorCode::TLSConfigurationFailure(format!(
+                        "The PostgreSQL handler can't listen on {} without TLS, the passwords \
+                         are sent in clear text, set postgres_tls_server_cert and \
+                         postgres_tls_server_key",
+                        listening
+                    )));
+                }
+                let rt = Arc::new(Runtime::with_worker_threads(
+                    1,
+                    Some("postgres-handler".to_string()),
+                )?);
+                let (stream, listener) = Self::listener_tcp(listening).await?;
+                let stream = Abortable::new(stream, registration);
+                self.join_handle = Some(tokio::spawn(
+                    async_backtrace::location!().frame(self.listen_loop(stream, rt)),
+                ));
+                Ok(listener)
+            }
+        }
+    }
+}
@@ -0,0 +1,487 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::UserIdentity;
+use common_sql::Planner;
+use common_users::UserApiProvider;
+use futures::channel::mpsc;
+use futures::Sink;
+use futures::SinkExt;
+use futures_util::StreamExt;
+use log::info;
+use log::warn;
+use pgwire::api::auth::finish_authentication;
+use pgwire::api::auth::save_startup_parameters_to_metadata;
+use pgwire::api::auth::DefaultServerParameterProvider;
+use pgwire::api::auth::StartupHandler;
+use pgwire::api::portal::Format;
+use pgwire::api::portal::Portal;
+use pgwire::api::query::ExtendedQueryHandler;
+use pgwire::api::query::SimpleQueryHandler;
+use pgwire::api::query::StatementOrPortal;
+use pgwire::api::results::DescribeResponse;
+use pgwire::api::results::FieldInfo;
+use pgwire::api::results::QueryResponse;
+use pgwire::api::results::Response;
+use pgwire::api::results::Tag;
+use pgwire::api::stmt::NoopQueryParser;
+use pgwire::api::store::MemPortalStore;
+use pgwire::api::ClientInfo;
+use pgwire::api::PgWireConnectionState;
+use pgwire::api::Type;
+use pgwire::api::METADATA_DATABASE;
+use pgwire::api::METADATA_USER;
+use pgwire::error::ErrorInfo;
+use pgwire::error::PgWireError;
+use pgwire::error::PgWireResult;
+use pgwire::messages::data::DataRow;
+use pgwire::messages::startup::Authentication;
+use pgwire::messages::PgWireBackendMessage;
+use pgwire::messages::PgWireFrontendMessage;
+
+use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterQueryLog;
+use crate::servers::postgres::postgres_types::bind_params;
+use crate::servers::postgres::postgres_types::count_params;
+use crate::servers::postgres::postgres_types::encode_rows;
+use crate::servers::postgres::postgres_types::param_to_literal;
+use crate::servers::postgres::postgres_types::to_field_infos;
+use crate::sessions::Session;
+use crate::sessions::TableContext;
+
+/// Session variables set by pg drivers on connecting, which are not settings of databend.
+const IGNORED_SET_VARIABLES: [&str; 6] = [
+    "application_name",
+    "client_encoding",
+    "datestyle",
+    "extra_float_digits",
+    "intervalstyle",
+    "search_path",
+];
+
+enum QueryOutput {
+    Rows {
+        fields: Arc<Vec<FieldInfo>>,
+        rows: mpsc::Receiver<Result<DataRow>>,
+    },
+    Execution(Tag),
+}
+
+/// Handles the startup, simple query and extended query messages of a connection.
+///
+/// The statements of the extended query protocol are kept as the query text, and
+/// the parameters are bound by replacing the placeholders with literals.
+pub struct PostgresConnection {
+    session: Arc<Session>,
+    portal_store: Arc<MemPortalStore<String>>,
+    query_parser: Arc<NoopQueryParser>,
+    // The password is sent in clear text, it's refused on a connection without TLS.
+    require_tls: bool,
+}
+
+impl PostgresConnection {
+    /// Max number of the encoded rows buffered before they are sent to the client.
+    const ROWS_BUFFER_SIZE: usize = 1024;
+
+    pub fn create(session: Arc<Session>, require_tls: bool) -> PostgresConnection {
+        PostgresConnection {
+            session,
+            portal_store: Arc::new(MemPortalStore::new()),
+            query_parser: Arc::new(NoopQueryParser::new()),
+            require_tls,
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn authenticate(
+        &self,
+        user: &str,
+        password: &str,
+        client_ip: Option<&str>,
+    ) -> Result<bool> {
+        let tenant = self.session.get_current_tenant();
+        let identity = UserIdentity::new(user, "%");
+        let user_info = UserApiProvider::instance()
+            .get_user_with_client_ip(&tenant, identity, client_ip)
+            .await?;
+
+        let authed = match &user_info.auth_info {
+            AuthInfo::None => true,
+            AuthInfo::Password {
+                hash_value: h,
+                hash_method: t,
+            } => !password.is_empty() && *h == t.hash(password.as_bytes()),
+            _ => false,
+        };
+        if authed {
+            self.session.set_authed_user(user_info, None).await?;
+        }
+        Ok(authed)
+    }
+
+    #[async_backtrace::framed]
+    async fn use_database(&self, user: &str, database: &str) -> Result<()> {
+        let query = format!("USE `{}`", database.replace('`', "``"));
+        match self.execute(&query, &Format::UnifiedText).await {
+            Ok(_) => Ok(()),
+            // libpq uses the name of the user if the database is not given.
+            Err(_) if database == user => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn execute(&self, query: &str, format: &Format) -> Result<QueryOutput> {
+        if let Some(tag) = Self::ignored_set_statement(query) {
+            return Ok(QueryOutput::Execution(tag));
+        }
+
+        info!("Normal query: {}", query);
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context.clone());
+        let (plan, extras) = planner.plan_sql(query).await?;
+        context.attach_query_str(plan.to_string(), extras.statement.to_mask_sql());
+
+        let interpreter = match InterpreterFactory::get(context.clone(), &plan).await {
+            Ok(interpreter) => interpreter,
+            Err(e) => {
+                InterpreterQueryLog::fail_to_start(context, e.clone());
+                return Err(e);
+            }
+        };
+
+        let fields = Arc::new(to_field_infos(&plan.schema(), format));
+        let has_result_set = plan.has_result_set();
+        let timezone = context.get_format_settings()?.timezone;
+        // The rows are streamed to the client as the blocks are produced, an error of the
+        // execution is sent after the rows before it.
+        let (mut tx, mut rows) = mpsc::channel(Self::ROWS_BUFFER_SIZE);
+        let handle = context.try_spawn({
+            let ctx = context.clone();
+            let fields = fields.clone();
+            async move {
+                let res = async {
+                    let mut data_stream = interpreter.execute(ctx).await?;
+                    while let Some(block) = data_stream.next().await {
+                        for row in encode_rows(&fields, block?, timezone)? {
+                            if tx.send(Ok(row)).await.is_err() {
+                                // The client is gone, no more rows are needed.
+                                return Ok(());
+                            }
+                        }
+                    }
+                    Ok::<_, ErrorCode>(())
+                }
+                .await;
+                if let Err(e) = res {
+                    let _ = tx.send(Err(e)).await;
+                }
+            }
+        })?;
+
+        if has_result_set {
+            return Ok(QueryOutput::Rows { fields, rows });
+        }
+
+        // The statement is finished before the tag is returned.
+        while let Some(row) = rows.next().await {
+            row?;
+        }
+        handle.await.map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot join handle from context's runtime",
+        )?;
+        Ok(QueryOutput::Execution(Tag::new_for_execution(
+            &Self::command_tag(query),
+            None,
+        )))
+    }
+
+    /// The schema of the result of the query, the parameters are planned as NULL.
+    #[async_backtrace::framed]
+    async fn describe(&self, query: &str, format: &Format) -> Result<Vec<FieldInfo>> {
+        if Self::ignored_set_statement(query).is_some() {
+            return Ok(vec![]);
+        }
+
+        let literals = vec!["NULL".to_string(); count_params(query)];
+        let query = bind_params(query, &literals)?;
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context);
+        let (plan, _) = planner.plan_sql(&query).await?;
+        match plan.has_result_set() {
+            true => Ok(to_field_infos(&plan.schema(), format)),
+            false => Ok(vec![]),
+        }
+    }
+
+    fn ignored_set_statement(query: &str) -> Option<Tag> {
+        let words = query
+            .trim()
+            .trim_end_matches(';')
+            .split_whitespace()
+            .take(2)
+            .map(|word| word.to_lowercase())
+            .collect::<Vec<_>>();
+        match words.as_slice() {
+            [set, name]
+                if set == "set"
+                    && IGNORED_SET_VARIABLES
+                        .contains(&name.split('=').next().unwrap_or_default()) =>
+            {
+                Some(Tag::new_for_execution("SET", None))
+            }
+            _ => None,
+        }
+    }
+
+    /// The command tag is the first keyword of the query, like `INSERT`.
+    fn command_tag(query: &str) -> String {
+        query
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .trim_end_matches(';')
+            .to_uppercase()
+    }
+
+    fn to_response<'a>(output: QueryOutput) -> Response<'a> {
+        match output {
+            QueryOutput::Rows { fields, rows } => Response::Query(QueryResponse::new(
+                fields,
+                rows.map(|row| row.map_err(to_pg_error)),
+            )),
+            QueryOutput::Execution(tag) => Response::Execution(tag),
+        }
+    }
+}
+
+fn to_pg_error(error: ErrorCode) -> PgWireError {
+    PgWireError::UserError(Box::new(ErrorInfo::new(
+        "ERROR".to_string(),
+        "XX000".to_string(),
+        error.to_string(),
+    )))
+}
+
+#[async_trait::async_trait]
+impl StartupHandler for PostgresConnection {
+    #[async_backtrace::framed]
+    async fn on_startup<C>(
+        &self,
+        client: &mut C,
+        message: PgWireFrontendMessage,
+    ) -> PgWireResult<()>
+    where
+        C: ClientInfo + Sink<PgWireBackendMessage> + Unpin + Send,
+        C::Error: Debug,
+        PgWireError: From<<C as Sink<PgWireBackendMessage>>::Error>,
+    {
+        match message {
+            PgWireFrontendMessage::Startup(ref startup) => {
+                if self.require_tls && !client.is_secure() {
+                    let error_info = ErrorInfo::new(
+                        "FATAL".to_string(),
+                        "28000".to_string(),
+                        "SSL connection is required".to_string(),
+                    );
+                    client
+                        .feed(PgWireBackendMessage::ErrorResponse(error_info.into()))
+                        .await?;
+                    client.close().await?;
+                    return Ok(());
+                }
+                save_startup_parameters_to_metadata(client, startup);
+                client.set_state(PgWireConnectionState::AuthenticationInProgress);
+                client
+                    .send(PgWireBackendMessage::Authentication(
+                        Authentication::CleartextPassword,
+                    ))
+                    .await?;
+            }
+            PgWireFrontendMessage::PasswordMessageFamily(password) => {
+                let password = password.into_password()?;
+                let metadata = client.metadata();
+                let user = metadata.get(METADATA_USER).cloned().unwrap_or_default();
+                let database = metadata.get(METADATA_DATABASE).cloned();
+                let client_ip = client.socket_addr().ip().to_string();
+
+                let authed = match self
+                    .authenticate(&user, &password.password, Some(&client_ip))
+                    .await
+                {
+                    Ok(authed) => authed,
+                    Err(e) => {
+                        warn!("PostgreSQL authenticate failed: {:?}", e);
+                        false
+                    }
+                };
+                if !authed {
+                    let error_info = ErrorInfo::new(
+                        "FATAL".to_string(),
+                        "28P01".to_string(),
+                        format!("password authentication failed for user \"{}\"", user),
+                    );
+                    client
+                        .feed(PgWireBackendMessage::ErrorResponse(error_info.into()))
+                        .await?;
+                    client.close().await?;
+                    return Ok(());
+                }
+
+                if let Some(database) = database {
+                    if let Err(e) = self.use_database(&user, &database).await {
+                        let error_info =
+                            ErrorInfo::new("FATAL".to_string(), "3D000".to_string(), e.message());
+                        client
+                            .feed(PgWireBackendMessage::ErrorResponse(error_info.into()))
+                            .await?;
+                        client.close().await?;
+                        return Ok(());
+                    }
+                }
+
+                finish_authentication(client, &DefaultServerParameterProvider::default()).await;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl SimpleQueryHandler for PostgresConnection {
+    #[async_backtrace::framed]
+    async fn do_query<'a, C>(
+        &self,
+        _client: &C,
+        query: &'a str,
+    ) -> PgWireResult<Vec<Response<'a>>>
+    where
+        C: ClientInfo + Unpin + Send + Sync,
+    {
+        if query.trim().trim_end_matches(';').trim().is_empty() {
+            return Ok(vec![Response::EmptyQuery]);
+        }
+        let output = self
+            .execute(query, &Format::UnifiedText)
+            .await
+            .map_err(to_pg_error)?;
+        Ok(vec![Self::to_response(output)])
+    }
+}
+
+#[async_trait::async_trait]
+impl ExtendedQueryHandler for PostgresConnection {
+    type Statement = String;
+    type PortalStore = MemPortalStore<Self::Statement>;
+    type QueryParser = NoopQueryParser;
+
+    fn portal_store(&self) -> Arc<Self::PortalStore> {
+        self.portal_store.clone()
+    }
+
+    fn query_parser(&self) -> Arc<Self::QueryParser> {
+        self.query_parser.clone()
+    }
+
+    #[async_backtrace::framed]
+    async fn do_query<'a, 'b: 'a, C>(
+        &'b self,
+        _client: &mut C,
+        portal: &'a Portal<Self::Statement>,
+        _max_rows: usize,
+    ) -> PgWireResult<Response<'a>>
+    where
+        C: ClientInfo + Unpin + Send + Sync,
+    {
+        let statement = &portal.statement;
+        let literals = portal
+            .parameters
+            .iter()
+            .enumerate()
+            .map(|(idx, value)| {
+                let pg_type = statement
+                    .parameter_types
+                    .get(idx)
+                    .cloned()
+                    .unwrap_or(Type::UNKNOWN);
+                param_to_literal(
+                    value.as_deref(),
+                    &pg_type,
+                    portal.parameter_format.is_binary(idx),
+                )
+            })
+            .collect::<Result<Vec<_>>>()
+            .map_err(to_pg_error)?;
+        let query = bind_params(&statement.statement, &literals).map_err(to_pg_error)?;
+
+        if query.trim().trim_end_matches(';').trim().is_empty() {
+            return Ok(Response::EmptyQuery);
+        }
+        // Portals are not suspended, all the rows are returned at once.
+        let output = self
+            .execute(&query, &portal.result_column_format)
+            .await
+            .map_err(to_pg_error)?;
+        Ok(Self::to_response(output))
+    }
+
+    #[async_backtrace::framed]
+    async fn do_describe<C>(
+        &self,
+        _client: &mut C,
+        target: StatementOrPortal<'_, Self::Statement>,
+    ) -> PgWireResult<DescribeResponse>
+    where
+        C: ClientInfo + Unpin + Send + Sync,
+    {
+        match target {
+            StatementOrPortal::Statement(statement) => {
+                // The types of the parameters not given by the client are unknown,
+                // they are bound as text.
+                let param_types = (0..count_params(&statement.statement))
+                    .map(|idx| {
+                        statement
+                            .parameter_types
+                            .get(idx)
+                            .cloned()
+                            .unwrap_or(Type::TEXT)
+                    })
+                    .collect();
+                // The formats of the result are not known until the statement is bound.
+                let fields = self
+                    .describe(&statement.statement, &Format::UnifiedText)
+                    .await
+                    .map_err(to_pg_error)?;
+                Ok(DescribeResponse::new(Some(param_types), fields))
+            }
+            StatementOrPortal::Portal(portal) => {
+                let fields = self
+                    .describe(&portal.statement.statement, &portal.result_column_format)
+                    .await
+                    .map_err(to_pg_error)?;
+                Ok(DescribeResponse::new(None, fields))
+            }
+        }
+    }
+}
@@ -0,0 +1,331 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::sync::Arc;
+
+use chrono_tz::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::date_helper::DateConverter;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::ScalarRef;
+use common_formats::field_encoder::FieldEncoderRowBased;
+use common_formats::field_encoder::FieldEncoderValues;
+use pgwire::api::portal::Format;
+use pgwire::api::results::DataRowEncoder;
+use pgwire::api::results::FieldFormat;
+use pgwire::api::results::FieldInfo;
+use pgwire::api::Type;
+use pgwire::error::PgWireResult;
+use pgwire::messages::data::DataRow;
+
+/// Map the data type to the pg type reported to clients.
+pub fn to_pg_type(data_type: &DataType) -> Type {
+    match data_type.remove_nullable() {
+        DataType::Boolean => Type::BOOL,
+        DataType::Number(number) => match number {
+            NumberDataType::Int8 | NumberDataType::UInt8 | NumberDataType::Int16 => Type::INT2,
+            NumberDataType::UInt16 | NumberDataType::Int32 => Type::INT4,
+            NumberDataType::UInt32 | NumberDataType::Int64 => Type::INT8,
+            // UInt64 may overflow INT8.
+            NumberDataType::UInt64 => Type::NUMERIC,
+            NumberDataType::Float32 => Type::FLOAT4,
+            NumberDataType::Float64 => Type::FLOAT8,
+        },
+        DataType::Decimal(_) => Type::NUMERIC,
+        DataType::String => Type::VARCHAR,
+        DataType::Date => Type::DATE,
+        DataType::Timestamp => Type::TIMESTAMP,
+        DataType::Variant => Type::JSON,
+        _ => Type::TEXT,
+    }
+}
+
+/// The result columns are sent in the formats asked by the client, except the
+/// columns of NUMERIC, JSON and TEXT, which are always sent in the text format.
+pub fn to_field_infos(schema: &DataSchemaRef, format: &Format) -> Vec<FieldInfo> {
+    schema
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(idx, field)| {
+            let pg_type = to_pg_type(field.data_type());
+            let format = match pg_type {
+                Type::NUMERIC | Type::JSON | Type::TEXT => FieldFormat::Text,
+                _ => format.format_for(idx),
+            };
+            FieldInfo::new(field.name().clone(), None, None, pg_type, format)
+        })
+        .collect()
+}
+
+/// Encode the rows of the block in the formats of the fields.
+pub fn encode_rows(
+    fields: &Arc<Vec<FieldInfo>>,
+    block: DataBlock,
+    timezone: Tz,
+) -> Result<Vec<DataRow>> {
+    let num_rows = block.num_rows();
+    let encoder = FieldEncoderValues::create_for_postgres_handler(timezone);
+    let mut buf = Vec::<u8>::new();
+
+    let columns = block
+        .convert_to_full()
+        .columns()
+        .iter()
+        .map(|column| column.value.clone().into_column().unwrap())
+        .collect::<Vec<_>>();
+
+    let mut rows = Vec::with_capacity(num_rows);
+    for row_index in 0..num_rows {
+        let mut row_encoder = DataRowEncoder::new(fields.clone());
+        for (column, field) in columns.iter().zip(fields.iter()) {
+            let value = unsafe { column.index_unchecked(row_index) };
+            let res = match (value, field.format()) {
+                (ScalarRef::Null, _) => row_encoder.encode_field(&None::<i8>),
+                (value, FieldFormat::Binary) => {
+                    encode_binary(&mut row_encoder, value, field.datatype(), timezone)?
+                }
+                (ScalarRef::Bitmap(_), _) => row_encoder.encode_field(&"<bitmap binary>"),
+                (_, FieldFormat::Text) => {
+                    buf.clear();
+                    encoder.write_field(column, row_index, &mut buf, true);
+                    row_encoder.encode_field(&String::from_utf8_lossy(&buf).as_ref())
+                }
+            };
+            res.map_err(|e| ErrorCode::Internal(format!("fail to encode pg row: {}", e)))?;
+        }
+        rows.push(
+            row_encoder
+                .finish()
+                .map_err(|e| ErrorCode::Internal(format!("fail to encode pg row: {}", e)))?,
+        );
+    }
+    Ok(rows)
+}
+
+fn encode_binary(
+    row_encoder: &mut DataRowEncoder,
+    value: ScalarRef,
+    pg_type: &Type,
+    timezone: Tz,
+) -> Result<PgWireResult<()>> {
+    let res = match (value, pg_type) {
+        (ScalarRef::Boolean(v), _) => row_encoder.encode_field(&v),
+        (ScalarRef::Number(number), &Type::INT2) => {
+            row_encoder.encode_field(&(number_to_i64(number)? as i16))
+        }
+        (ScalarRef::Number(number), &Type::INT4) => {
+            row_encoder.encode_field(&(number_to_i64(number)? as i32))
+        }
+        (ScalarRef::Number(number), &Type::INT8) => {
+            row_encoder.encode_field(&number_to_i64(number)?)
+        }
+        (ScalarRef::Number(NumberScalar::Float32(v)), _) => row_encoder.encode_field(&v.0),
+        (ScalarRef::Number(NumberScalar::Float64(v)), _) => row_encoder.encode_field(&v.0),
+        (ScalarRef::String(v), &Type::VARCHAR) => {
+            row_encoder.encode_field(&String::from_utf8_lossy(v).as_ref())
+        }
+        (ScalarRef::Date(v), _) => row_encoder.encode_field(&v.to_date(Tz::UTC)),
+        (ScalarRef::Timestamp(v), _) => {
+            row_encoder.encode_field(&v.to_timestamp(timezone).naive_local())
+        }
+        _ => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "binary format of pg type {} is not supported",
+                pg_type.name()
+            )));
+        }
+    };
+    Ok(res)
+}
+
+fn number_to_i64(number: NumberScalar) -> Result<i64> {
+    match number {
+        NumberScalar::Int8(v) => Ok(v as i64),
+        NumberScalar::Int16(v) => Ok(v as i64),
+        NumberScalar::Int32(v) => Ok(v as i64),
+        NumberScalar::Int64(v) => Ok(v),
+        NumberScalar::UInt8(v) => Ok(v as i64),
+        NumberScalar::UInt16(v) => Ok(v as i64),
+        NumberScalar::UInt32(v) => Ok(v as i64),
+        NumberScalar::UInt64(v) => i64::try_from(v)
+            .map_err(|_| ErrorCode::Overflow(format!("{} overflows pg type int8", v))),
+        NumberScalar::Float32(v) => Ok(v.0 as i64),
+        NumberScalar::Float64(v) => Ok(v.0 as i64),
+    }
+}
+
+/// Decode a parameter of the extended query protocol into a SQL literal.
+///
+/// Binary parameters are decoded by the type of the parameter, text parameters
+/// are quoted as strings unless they are typed as numbers. A number is validated
+/// and wrapped in parentheses, so it can't be merged with the tokens before it,
+/// e.g. `a-$1` bound with `-5` is `a-(-5)` instead of the comment `a--5`.
+pub fn param_to_literal(value: Option<&[u8]>, pg_type: &Type, binary: bool) -> Result<String> {
+    let value = match value {
+        None => return Ok("NULL".to_string()),
+        Some(value) => value,
+    };
+
+    if binary {
+        let invalid = || {
+            ErrorCode::BadBytes(format!(
+                "invalid binary parameter of type {}",
+                pg_type.name()
+            ))
+        };
+        let literal = match *pg_type {
+            Type::BOOL => match value {
+                [0] => "FALSE".to_string(),
+                [_] => "TRUE".to_string(),
+                _ => return Err(invalid()),
+            },
+            Type::INT2 => {
+                number_literal(i16::from_be_bytes(value.try_into().map_err(|_| invalid())?))
+            }
+            Type::INT4 => {
+                number_literal(i32::from_be_bytes(value.try_into().map_err(|_| invalid())?))
+            }
+            Type::INT8 => {
+                number_literal(i64::from_be_bytes(value.try_into().map_err(|_| invalid())?))
+            }
+            Type::FLOAT4 => {
+                float_literal(f32::from_be_bytes(value.try_into().map_err(|_| invalid())?) as f64)?
+            }
+            Type::FLOAT8 => {
+                float_literal(f64::from_be_bytes(value.try_into().map_err(|_| invalid())?))?
+            }
+            _ => quote_literal(&String::from_utf8_lossy(value)),
+        };
+        return Ok(literal);
+    }
+
+    let text = String::from_utf8_lossy(value);
+    match *pg_type {
+        Type::INT2 | Type::INT4 | Type::INT8 | Type::FLOAT4 | Type::FLOAT8 | Type::NUMERIC => {
+            // Only signs, digits, the point and the exponent are left in a finite number.
+            let text = text.trim();
+            match text.parse::<f64>() {
+                Ok(v) if v.is_finite() => Ok(number_literal(text)),
+                _ => Err(ErrorCode::BadBytes(format!(
+                    "invalid numeric parameter: {}",
+                    text
+                ))),
+            }
+        }
+        Type::BOOL => match text.trim().to_ascii_lowercase().as_str() {
+            "t" | "true" | "1" | "y" | "yes" | "on" => Ok("TRUE".to_string()),
+            "f" | "false" | "0" | "n" | "no" | "off" => Ok("FALSE".to_string()),
+            _ => Err(ErrorCode::BadBytes(format!(
+                "invalid boolean parameter: {}",
+                text
+            ))),
+        },
+        _ => Ok(quote_literal(&text)),
+    }
+}
+
+fn number_literal(number: impl Display) -> String {
+    format!("({})", number)
+}
+
+fn float_literal(v: f64) -> Result<String> {
+    if v.is_finite() {
+        Ok(number_literal(v))
+    } else {
+        Err(ErrorCode::BadBytes(format!(
+            "invalid numeric parameter: {}",
+            v
+        )))
+    }
+}
+
+fn quote_literal(s: &str) -> String {
+    format!("'{}'", s.replace('\\', "\\\\").replace('\'', "''"))
+}
+
+/// Replace the placeholders `$1`, `$2`, ... in the query with the literals.
+pub fn bind_params(query: &str, literals: &[String]) -> Result<String> {
+    replace_params(query, |index| {
+        index
+            .checked_sub(1)
+            .and_then(|i| literals.get(i))
+            .cloned()
+            .ok_or_else(|| {
+                ErrorCode::BadArguments(format!(
+                    "no value bound to parameter ${}, {} parameters are bound",
+                    index,
+                    literals.len()
+                ))
+            })
+    })
+}
+
+/// Number of the parameters of the query, it's the max index of the placeholders.
+pub fn count_params(query: &str) -> usize {
+    let mut count = 0;
+    let _ = replace_params(query, |index| {
+        count = count.max(index);
+        Ok(String::new())
+    });
+    count
+}
+
+/// Placeholders in quoted strings and identifiers are kept as is.
+fn replace_params<F>(query: &str, mut replace: F) -> Result<String>
+where F: FnMut(usize) -> Result<String> {
+    let mut result = String::with_capacity(query.len());
+    let mut chars = query.chars().peekable();
+    let mut quote: Option<char> = None;
+
+    while let Some(c) = chars.next() {
+        match quote {
+            Some(q) => {
+                result.push(c);
+                if c == '\\' && q == '\'' {
+                    if let Some(escaped) = chars.next() {
+                        result.push(escaped);
+                    }
+                } else if c == q {
+                    quote = None;
+                }
+            }
+            None if c == '\'' || c == '"' || c == '`' => {
+                quote = Some(c);
+                result.push(c);
+            }
+            None if c == '$' && matches!(chars.peek(), Some(d) if d.is_ascii_digit()) => {
+                let mut index = 0usize;
+                while let Some(d) = chars.peek().and_then(|d| d.to_digit(10)) {
+                    index = index
+                        .checked_mul(10)
+                        .and_then(|index| index.checked_add(d as usize))
+                        .ok_or_else(|| {
+                            ErrorCode::BadArguments("the index of a parameter is too large")
+                        })?;
+                    chars.next();
+                }
+                result.push_str(&replace(index)?);
+            }
+            None => result.push(c),
+        }
+    }
+    Ok(result)
+}
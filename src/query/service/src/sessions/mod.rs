@@ -15,6 +15,7 @@
 mod query_affect;
 pub mod query_ctx;
 mod query_ctx_shared;
+mod query_history;
 mod session;
 mod session_ctx;
 mod session_info;
@@ -28,6 +29,7 @@ pub use query_affect::QueryAffect;
 pub use query_ctx::QueryContext;
 pub use query_ctx_shared::short_sql;
 pub use query_ctx_shared::QueryContextShared;
+pub use query_history::QueryHistory;
 pub use session::Session;
 pub use session_ctx::SessionContext;
 pub use session_info::ProcessInfo;
@@ -51,10 +51,12 @@ use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::StageFileFormatType;
+use common_meta_app::principal::UserDefinedFunction;
 use common_meta_app::principal::UserInfo;
 use common_meta_app::schema::CatalogInfo;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateStreamMetaReq;
 use common_pipeline_core::InputError;
 use common_settings::ChangeValue;
 use common_settings::Settings;
@@ -63,7 +65,9 @@ use common_storage::common_metrics::copy::metrics_inc_filter_out_copied_files_re
 use common_storage::DataOperator;
 use common_storage::StageFileInfo;
 use common_storage::StorageMetrics;
+use common_storage::StorageMetricsLayer;
 use common_storages_fuse::TableContext;
+use common_storages_orc::OrcTable;
 use common_storages_parquet::Parquet2Table;
 use common_storages_parquet::ParquetRSTable;
 use common_storages_result_cache::ResultScan;
@@ -73,6 +77,7 @@ use dashmap::mapref::multiple::RefMulti;
 use dashmap::DashMap;
 use log::debug;
 use log::info;
+use opendal::Operator;
 use parking_lot::RwLock;
 use storages_common_table_meta::meta::Location;
 
@@ -103,6 +108,8 @@ pub struct QueryContext {
     fragment_id: Arc<AtomicUsize>,
     // Used by synchronized generate aggregating indexes when new data written.
     inserted_segment_locs: Arc<RwLock<Vec<Location>>>,
+    // Streams consumed by the query, their offsets are advanced on commit.
+    stream_updates: Arc<RwLock<Vec<UpdateStreamMetaReq>>>,
 }
 
 impl QueryContext {
@@ -124,6 +131,7 @@ impl QueryContext {
             query_settings,
             fragment_id: Arc::new(AtomicUsize::new(0)),
             inserted_segment_locs: Arc::new(RwLock::new(Vec::new())),
+            stream_updates: Arc::new(RwLock::new(Vec::new())),
         })
     }
 
@@ -231,6 +239,17 @@ impl QueryContext {
         self.shared.get_data_metrics()
     }
 
+    pub fn get_spill_metrics(&self) -> Arc<StorageMetrics> {
+        self.shared.get_spill_metrics()
+    }
+
+    /// The operator to spill the data of this query, which records the spilled bytes.
+    pub fn get_spill_operator(&self) -> Operator {
+        DataOperator::instance()
+            .operator()
+            .layer(StorageMetricsLayer::new(self.get_spill_metrics()))
+    }
+
     pub fn set_affect(self: &Arc<Self>, affect: QueryAffect) {
         self.shared.set_affect(affect)
     }
@@ -260,9 +279,33 @@ impl QueryContext {
         self.shared.created_time
     }
 
+    pub fn attach_plan_hash(&self, hash: String) {
+        self.shared.attach_plan_hash(hash)
+    }
+
+    pub fn get_plan_hash(&self) -> String {
+        self.shared.get_plan_hash()
+    }
+
     pub fn evict_table_from_cache(&self, catalog: &str, database: &str, table: &str) -> Result<()> {
         self.shared.evict_table_from_cache(catalog, database, table)
     }
+
+    pub fn add_temporary_view(&self, view: TableInfo) {
+        self.shared.session.session_ctx.add_temporary_view(view)
+    }
+
+    pub fn remove_temporary_view(&self, name: &str) -> Option<TableInfo> {
+        self.shared.session.session_ctx.remove_temporary_view(name)
+    }
+
+    pub fn add_temporary_udf(&self, udf: UserDefinedFunction) {
+        self.shared.session.session_ctx.add_temporary_udf(udf)
+    }
+
+    pub fn remove_temporary_udf(&self, name: &str) -> Option<UserDefinedFunction> {
+        self.shared.session.session_ctx.remove_temporary_udf(name)
+    }
 }
 
 #[async_trait::async_trait]
@@ -288,6 +331,7 @@ impl TableContext for QueryContext {
             ),
             DataSourceInfo::Parquet2Source(table_info) => Parquet2Table::from_info(table_info),
             DataSourceInfo::ParquetSource(table_info) => ParquetRSTable::from_info(table_info),
+            DataSourceInfo::OrcSource(table_info) => OrcTable::from_info(table_info),
             DataSourceInfo::ResultScanSource(table_info) => ResultScan::from_info(table_info),
         }
     }
@@ -370,18 +414,6 @@ impl TableContext for QueryContext {
         Ok(())
     }
 
-    fn add_partitions_sha(&self, s: String) {
-        let mut shas = self.shared.partitions_shas.write();
-        shas.push(s);
-    }
-
-    fn get_partitions_shas(&self) -> Vec<String> {
-        let mut sha = self.shared.partitions_shas.read().clone();
-        // Sort to make sure the SHAs are stable for the same query.
-        sha.sort();
-        sha
-    }
-
     fn get_cacheable(&self) -> bool {
         self.shared.cacheable.load(Ordering::Acquire)
     }
@@ -484,7 +516,8 @@ impl TableContext for QueryContext {
     }
 
     fn get_function_context(&self) -> Result<FunctionContext> {
-        let tz = self.get_settings().get_timezone()?;
+        let settings = self.get_settings();
+        let tz = settings.get_timezone()?;
         let tz = TzFactory::instance().get_by_name(&tz)?;
 
         let query_config = &GlobalConfig::instance().query;
@@ -498,6 +531,9 @@ impl TableContext for QueryContext {
             openai_api_embedding_base_url: query_config.openai_api_embedding_base_url.clone(),
             openai_api_embedding_model: query_config.openai_api_embedding_model.clone(),
             openai_api_completion_model: query_config.openai_api_completion_model.clone(),
+
+            max_string_result_bytes: settings.get_max_string_result_bytes()?,
+            seeded_rngs: self.shared.seeded_rngs.clone(),
         })
     }
 
@@ -555,6 +591,14 @@ impl TableContext for QueryContext {
             .update_query_ids_results(query_id, Some(result_cache_key))
     }
 
+    fn get_temporary_view(&self, name: &str) -> Option<TableInfo> {
+        self.shared.session.session_ctx.get_temporary_view(name)
+    }
+
+    fn get_temporary_udf(&self, name: &str) -> Option<UserDefinedFunction> {
+        self.shared.session.session_ctx.get_temporary_udf(name)
+    }
+
     fn get_on_error_map(&self) -> Option<Arc<DashMap<String, HashMap<u16, InputError>>>> {
         self.shared.get_on_error_map()
     }
@@ -588,6 +632,10 @@ impl TableContext for QueryContext {
         None
     }
 
+    fn get_rows_loaded_per_file(&self) -> Arc<DashMap<String, u64>> {
+        self.shared.get_rows_loaded_per_file()
+    }
+
     fn apply_changed_settings(&self, changes: HashMap<String, ChangeValue>) -> Result<()> {
         self.shared.apply_changed_settings(changes)
     }
@@ -718,6 +766,21 @@ impl TableContext for QueryContext {
     fn get_segment_locations(&self) -> Result<Vec<Location>> {
         Ok(self.inserted_segment_locs.read().to_vec())
     }
+
+    fn add_stream_update(&self, update: UpdateStreamMetaReq) {
+        let mut stream_updates = self.stream_updates.write();
+        // a stream may be referenced more than once in a query
+        if !stream_updates
+            .iter()
+            .any(|v| v.stream_id == update.stream_id)
+        {
+            stream_updates.push(update);
+        }
+    }
+
+    fn get_stream_updates(&self) -> Vec<UpdateStreamMetaReq> {
+        self.stream_updates.read().clone()
+    }
 }
 
 impl TrySpawn for QueryContext {
@@ -27,6 +27,7 @@ use common_catalog::table_context::MaterializedCtesBlocks;
 use common_catalog::table_context::StageAttachment;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::SeededRngs;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
@@ -65,7 +66,10 @@ pub struct QueryContextShared {
     pub(in crate::sessions) cluster_cache: Arc<Cluster>,
     pub(in crate::sessions) running_query: Arc<RwLock<Option<String>>>,
     pub(in crate::sessions) running_query_kind: Arc<RwLock<Option<String>>>,
+    pub(in crate::sessions) running_plan_hash: Arc<RwLock<Option<String>>>,
+    pub(in crate::sessions) spill_metrics: Arc<StorageMetrics>,
     pub(in crate::sessions) aborting: Arc<AtomicBool>,
+    pub(in crate::sessions) seeded_rngs: Arc<SeededRngs>,
     pub(in crate::sessions) tables_refs: Arc<Mutex<HashMap<DatabaseAndTable, Arc<dyn Table>>>>,
     pub(in crate::sessions) affect: Arc<Mutex<Option<QueryAffect>>>,
     pub(in crate::sessions) catalog_manager: Arc<CatalogManager>,
@@ -79,8 +83,8 @@ pub struct QueryContextShared {
     pub(in crate::sessions) on_error_map:
         Arc<RwLock<Option<Arc<DashMap<String, HashMap<u16, InputError>>>>>>,
     pub(in crate::sessions) on_error_mode: Arc<RwLock<Option<OnErrorMode>>>,
-    /// partitions_sha for each table in the query. Not empty only when enabling query result cache.
-    pub(in crate::sessions) partitions_shas: Arc<RwLock<Vec<String>>>,
+    // DashMap<file_path, number of rows loaded from the file>
+    pub(in crate::sessions) rows_loaded_map: Arc<DashMap<String, u64>>,
     pub(in crate::sessions) cacheable: Arc<AtomicBool>,
     pub(in crate::sessions) can_scan_from_agg_index: Arc<AtomicBool>,
     // Status info.
@@ -111,7 +115,10 @@ impl QueryContextShared {
             runtime: Arc::new(RwLock::new(None)),
             running_query: Arc::new(RwLock::new(None)),
             running_query_kind: Arc::new(RwLock::new(None)),
+            running_plan_hash: Arc::new(RwLock::new(None)),
+            spill_metrics: Arc::new(StorageMetrics::default()),
             aborting: Arc::new(AtomicBool::new(false)),
+            seeded_rngs: Arc::new(SeededRngs::default()),
             tables_refs: Arc::new(Mutex::new(HashMap::new())),
             affect: Arc::new(Mutex::new(None)),
             executor: Arc::new(RwLock::new(Weak::new())),
@@ -119,7 +126,7 @@ impl QueryContextShared {
             created_time: SystemTime::now(),
             on_error_map: Arc::new(RwLock::new(None)),
             on_error_mode: Arc::new(RwLock::new(None)),
-            partitions_shas: Arc::new(RwLock::new(vec![])),
+            rows_loaded_map: Arc::new(DashMap::new()),
             cacheable: Arc::new(AtomicBool::new(true)),
             can_scan_from_agg_index: Arc::new(AtomicBool::new(true)),
             status: Arc::new(RwLock::new("null".to_string())),
@@ -143,6 +150,10 @@ impl QueryContextShared {
         *guard = Some(map);
     }
 
+    pub fn get_rows_loaded_per_file(&self) -> Arc<DashMap<String, u64>> {
+        self.rows_loaded_map.clone()
+    }
+
     pub fn get_on_error_map(&self) -> Option<Arc<DashMap<String, HashMap<u16, InputError>>>> {
         self.on_error_map.read().as_ref().cloned()
     }
@@ -223,6 +234,10 @@ impl QueryContextShared {
         StorageMetrics::merge(&metrics)
     }
 
+    pub fn get_spill_metrics(&self) -> Arc<StorageMetrics> {
+        self.spill_metrics.clone()
+    }
+
     pub fn get_tenant(&self) -> String {
         self.session.get_current_tenant()
     }
@@ -337,6 +352,16 @@ impl QueryContextShared {
             .unwrap_or_else(|| "Unknown".to_string())
     }
 
+    pub fn attach_plan_hash(&self, hash: String) {
+        let mut running_plan_hash = self.running_plan_hash.write();
+        *running_plan_hash = Some(hash);
+    }
+
+    pub fn get_plan_hash(&self) -> String {
+        let running_plan_hash = self.running_plan_hash.read();
+        running_plan_hash.as_ref().cloned().unwrap_or_default()
+    }
+
     pub fn get_connection_id(&self) -> String {
         self.session.get_id()
     }
@@ -0,0 +1,371 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::tokio;
+use common_base::base::tokio::sync::mpsc::error::TrySendError;
+use common_base::base::tokio::sync::mpsc::Receiver;
+use common_base::base::tokio::sync::mpsc::Sender;
+use common_base::base::tokio::time::sleep;
+use common_base::base::GlobalInstance;
+use common_catalog::catalog::CatalogManager;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::table::AppendMode;
+use common_catalog::table::Table;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::Int32Type;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::UserPrivilegeType;
+use common_meta_app::schema::CreateDatabaseReq;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::DatabaseMeta;
+use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_storages_system::LogType;
+use common_storages_system::QueryLogElement;
+use log::warn;
+use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+
+use crate::interpreters::hook_background_compact;
+use crate::interpreters::CompactHookTraceCtx;
+use crate::interpreters::CompactTargetTableDescription;
+use crate::pipelines::builders::build_append2table_with_commit_pipeline;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+/// A finished query, as recorded in `system_history.query_log`.
+struct QueryHistoryRecord {
+    query_id: String,
+    node_id: String,
+    sql_user: String,
+    query_kind: String,
+    query_text: String,
+    plan_hash: String,
+    current_database: String,
+    start_time: i64,
+    end_time: i64,
+    duration_ms: u64,
+    scan_rows: u64,
+    scan_bytes: u64,
+    written_rows: u64,
+    result_rows: u64,
+    status: &'static str,
+    error_code: i32,
+    error_message: String,
+}
+
+/// Persists the finished queries of this node into the fuse table `system_history.query_log`.
+///
+/// Queries are not slowed down by the history: the records are sent to a background task,
+/// which appends them to the table every flush interval, and compacts the small blocks of
+/// the flushes in the background. Records are dropped if the task falls behind, or if the
+/// table can't be written.
+///
+/// The history is owned by the query nodes, users can read it but not change it.
+pub struct QueryHistory {
+    sender: Option<Sender<QueryHistoryRecord>>,
+}
+
+impl QueryHistory {
+    pub const DATABASE: &'static str = "system_history";
+    pub const TABLE: &'static str = "query_log";
+
+    const FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+    const MAX_PENDING_RECORDS: usize = 10000;
+
+    pub fn init(conf: &InnerConfig) -> Result<()> {
+        let sender = if conf.query.enable_query_history {
+            let (sender, receiver) = tokio::sync::mpsc::channel(Self::MAX_PENDING_RECORDS);
+            let tenant = conf.query.tenant_id.clone();
+            tokio::spawn(async_backtrace::location!().frame(Self::run(tenant, receiver)));
+            Some(sender)
+        } else {
+            None
+        };
+
+        GlobalInstance::set(Arc::new(QueryHistory { sender }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<QueryHistory> {
+        GlobalInstance::get()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.sender.is_some()
+    }
+
+    /// Reject the privileges other than SELECT on the database of the history and its tables.
+    pub fn check_privilege(object: &GrantObject, privileges: &[UserPrivilegeType]) -> Result<()> {
+        let database = match object {
+            GrantObject::Database(_, database) | GrantObject::Table(_, database, _) => database,
+            _ => return Ok(()),
+        };
+        if database == Self::DATABASE && privileges.iter().any(|p| *p != UserPrivilegeType::Select)
+        {
+            return Err(ErrorCode::PermissionDenied(format!(
+                "Permission denied, {} is maintained by the query nodes and is read only",
+                Self::DATABASE
+            )));
+        }
+        Ok(())
+    }
+
+    /// Record the query of a finish log, start logs are ignored.
+    pub fn append(&self, element: &QueryLogElement, plan_hash: String) {
+        let sender = match &self.sender {
+            Some(sender) => sender,
+            None => return,
+        };
+
+        let status = match element.log_type {
+            LogType::Start => return,
+            LogType::Finish => "Succeeded",
+            LogType::Error => "Failed",
+            LogType::Aborted => "Aborted",
+        };
+        let record = QueryHistoryRecord {
+            query_id: element.query_id.clone(),
+            node_id: element.node_id.clone(),
+            sql_user: element.sql_user.clone(),
+            query_kind: element.query_kind.clone(),
+            query_text: element.query_text.clone(),
+            plan_hash,
+            current_database: element.current_database.clone(),
+            start_time: element.query_start_time,
+            end_time: element.event_time,
+            duration_ms: element.query_duration_ms as u64,
+            scan_rows: element.scan_rows,
+            scan_bytes: element.scan_bytes,
+            written_rows: element.written_rows,
+            result_rows: element.result_rows,
+            status,
+            error_code: element.exception_code,
+            error_message: element.exception_text.clone(),
+        };
+        if let Err(TrySendError::Full(record)) = sender.try_send(record) {
+            warn!(
+                "Query history is falling behind, drop the record of query {}",
+                record.query_id
+            );
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn run(tenant: String, mut receiver: Receiver<QueryHistoryRecord>) {
+        let mut records = Vec::new();
+        loop {
+            // Wait for the first record, then flush it with the records of the interval.
+            match receiver.recv().await {
+                Some(record) => records.push(record),
+                None => return,
+            }
+            sleep(Self::FLUSH_INTERVAL).await;
+            while let Ok(record) = receiver.try_recv() {
+                records.push(record);
+            }
+
+            if let Err(cause) = Self::flush(&tenant, &records).await {
+                warn!(
+                    "Write {} records of query history failure: {:?}",
+                    records.len(),
+                    cause
+                );
+            }
+            records.clear();
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn flush(tenant: &str, records: &[QueryHistoryRecord]) -> Result<()> {
+        let table = Self::get_or_create_table(tenant).await?;
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+        let ctx = session.create_query_context().await?;
+
+        let source_schema: DataSchemaRef = Arc::new(table.schema().into());
+        let mut build_res = PipelineBuildResult::from_blocks(vec![Self::to_block(records)])?;
+        build_append2table_with_commit_pipeline(
+            ctx.clone(),
+            &mut build_res.main_pipeline,
+            table,
+            source_schema,
+            None,
+            false,
+            AppendMode::Normal,
+        )?;
+
+        // Every flush appends a small block, compact them in the background, at most once
+        // per `compact_after_write_min_interval_secs`.
+        let settings = ctx.get_settings();
+        settings.set_setting("enable_compact_after_write".to_string(), "1".to_string())?;
+        hook_background_compact(
+            ctx.clone(),
+            &mut build_res.main_pipeline,
+            CompactTargetTableDescription {
+                catalog: CATALOG_DEFAULT.to_string(),
+                database: Self::DATABASE.to_string(),
+                table: Self::TABLE.to_string(),
+            },
+            CompactHookTraceCtx {
+                start: Instant::now(),
+                operation_name: "query_history".to_string(),
+            },
+        )
+        .await;
+
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        let settings = ExecutorSettings::try_create(&settings, ctx.get_id())?;
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+        let executor = PipelineCompleteExecutor::from_pipelines(pipelines, settings)?;
+        ctx.set_executor(executor.get_inner())?;
+
+        tokio::task::spawn_blocking(move || executor.execute())
+            .await
+            .map_err(|e| ErrorCode::TokioError(format!("write query history failure: {}", e)))?
+    }
+
+    /// Create the database and the table of the history if they don't exist, the table is
+    /// created again if it has been dropped.
+    #[async_backtrace::framed]
+    async fn get_or_create_table(tenant: &str) -> Result<Arc<dyn Table>> {
+        let catalog = CatalogManager::instance().get_default_catalog()?;
+        match catalog.get_table(tenant, Self::DATABASE, Self::TABLE).await {
+            Ok(table) => return Ok(table),
+            Err(cause)
+                if cause.code() == ErrorCode::UNKNOWN_DATABASE
+                    || cause.code() == ErrorCode::UNKNOWN_TABLE => {}
+            Err(cause) => return Err(cause),
+        }
+
+        catalog
+            .create_database(CreateDatabaseReq {
+                if_not_exists: true,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: Self::DATABASE.to_string(),
+                },
+                meta: DatabaseMeta {
+                    engine: "".to_string(),
+                    ..Default::default()
+                },
+            })
+            .await?;
+        let database = catalog.get_database(tenant, Self::DATABASE).await?;
+
+        let options = BTreeMap::from([
+            (
+                OPT_KEY_DATABASE_ID.to_owned(),
+                database.get_db_info().ident.db_id.to_string(),
+            ),
+            (OPT_KEY_STORAGE_FORMAT.to_owned(), "parquet".to_owned()),
+        ]);
+        catalog
+            .create_table(CreateTableReq {
+                if_not_exists: true,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: Self::DATABASE.to_string(),
+                    table_name: Self::TABLE.to_string(),
+                },
+                table_meta: TableMeta {
+                    schema: Self::schema(),
+                    engine: "FUSE".to_string(),
+                    options,
+                    ..Default::default()
+                },
+            })
+            .await?;
+        catalog.get_table(tenant, Self::DATABASE, Self::TABLE).await
+    }
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("node_id", TableDataType::String),
+            TableField::new("sql_user", TableDataType::String),
+            TableField::new("query_kind", TableDataType::String),
+            TableField::new("query_text", TableDataType::String),
+            TableField::new("plan_hash", TableDataType::String),
+            TableField::new("current_database", TableDataType::String),
+            TableField::new("start_time", TableDataType::Timestamp),
+            TableField::new("end_time", TableDataType::Timestamp),
+            TableField::new("duration_ms", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("scan_rows", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("scan_bytes", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "written_rows",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("result_rows", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("status", TableDataType::String),
+            TableField::new("error_code", TableDataType::Number(NumberDataType::Int32)),
+            TableField::new("error_message", TableDataType::String),
+        ])
+    }
+
+    fn to_block(records: &[QueryHistoryRecord]) -> DataBlock {
+        let strings = |f: fn(&QueryHistoryRecord) -> &str| {
+            StringType::from_data(records.iter().map(f).collect::<Vec<_>>())
+        };
+        let numbers = |f: fn(&QueryHistoryRecord) -> u64| {
+            UInt64Type::from_data(records.iter().map(f).collect::<Vec<_>>())
+        };
+
+        DataBlock::new_from_columns(vec![
+            strings(|r| r.query_id.as_str()),
+            strings(|r| r.node_id.as_str()),
+            strings(|r| r.sql_user.as_str()),
+            strings(|r| r.query_kind.as_str()),
+            strings(|r| r.query_text.as_str()),
+            strings(|r| r.plan_hash.as_str()),
+            strings(|r| r.current_database.as_str()),
+            TimestampType::from_data(records.iter().map(|r| r.start_time).collect::<Vec<_>>()),
+            TimestampType::from_data(records.iter().map(|r| r.end_time).collect::<Vec<_>>()),
+            numbers(|r| r.duration_ms),
+            numbers(|r| r.scan_rows),
+            numbers(|r| r.scan_bytes),
+            numbers(|r| r.written_rows),
+            numbers(|r| r.result_rows),
+            strings(|r| r.status),
+            Int32Type::from_data(records.iter().map(|r| r.error_code).collect::<Vec<_>>()),
+            strings(|r| r.error_message.as_str()),
+        ])
+    }
+}
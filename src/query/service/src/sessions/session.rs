@@ -35,6 +35,7 @@ use crate::clusters::ClusterDiscovery;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::QueryContext;
 use crate::sessions::QueryContextShared;
+use crate::sessions::QueryHistory;
 use crate::sessions::SessionContext;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionStatus;
@@ -308,6 +309,8 @@ impl Session {
             return Ok(());
         }
 
+        QueryHistory::check_privilege(object, &privilege)?;
+
         // 1. check user's privilege set
         let current_user = self.get_current_user()?;
         let user_verified = current_user
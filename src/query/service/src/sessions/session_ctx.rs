@@ -23,7 +23,9 @@ use std::sync::Weak;
 use common_config::GlobalConfig;
 use common_exception::Result;
 use common_meta_app::principal::RoleInfo;
+use common_meta_app::principal::UserDefinedFunction;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::schema::TableInfo;
 use common_settings::ChangeValue;
 use common_settings::Settings;
 use parking_lot::RwLock;
@@ -57,6 +59,10 @@ pub struct SessionContext {
     // We store `query_id -> query_result_cache_key` to session context, so that we can fetch
     // query result through previous query_id easily.
     query_ids_results: RwLock<Vec<(String, Option<String>)>>,
+    // Temporary views and UDFs are only visible to this session, they are never
+    // persisted to the meta service.
+    temporary_views: RwLock<HashMap<String, TableInfo>>,
+    temporary_udfs: RwLock<HashMap<String, UserDefinedFunction>>,
     typ: SessionType,
 }
 
@@ -75,6 +81,8 @@ impl SessionContext {
             io_shutdown_tx: Default::default(),
             query_context_shared: Default::default(),
             query_ids_results: Default::default(),
+            temporary_views: Default::default(),
+            temporary_udfs: Default::default(),
             typ,
         }))
     }
@@ -280,4 +288,28 @@ impl SessionContext {
         let lock = self.query_ids_results.read();
         HashSet::from_iter(lock.iter().map(|result| result.clone().0))
     }
+
+    pub fn get_temporary_view(&self, name: &str) -> Option<TableInfo> {
+        self.temporary_views.read().get(name).cloned()
+    }
+
+    pub fn add_temporary_view(&self, view: TableInfo) {
+        self.temporary_views.write().insert(view.name.clone(), view);
+    }
+
+    pub fn remove_temporary_view(&self, name: &str) -> Option<TableInfo> {
+        self.temporary_views.write().remove(name)
+    }
+
+    pub fn get_temporary_udf(&self, name: &str) -> Option<UserDefinedFunction> {
+        self.temporary_udfs.read().get(name).cloned()
+    }
+
+    pub fn add_temporary_udf(&self, udf: UserDefinedFunction) {
+        self.temporary_udfs.write().insert(udf.name.clone(), udf);
+    }
+
+    pub fn remove_temporary_udf(&self, name: &str) -> Option<UserDefinedFunction> {
+        self.temporary_udfs.write().remove(name)
+    }
 }
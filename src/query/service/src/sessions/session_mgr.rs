@@ -29,6 +29,7 @@ use common_config::GlobalConfig;
 use common_config::InnerConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::NodeLoad;
 use common_metrics::label_counter;
 use common_metrics::label_gauge;
 use common_settings::Settings;
@@ -316,4 +317,35 @@ impl SessionManager {
         status_t.active_sessions_count = active_sessions_count;
         status_t
     }
+
+    /// Get the load of the node, it's reported to the meta by the cluster heartbeat.
+    pub fn get_current_node_load(&self, cpu_nums: u64) -> NodeLoad {
+        let mut queries = self
+            .processes_info()
+            .into_iter()
+            .filter(|info| info.state == ProcessInfoState::Query)
+            .map(|info| {
+                let threads = info.settings.get_max_threads().unwrap_or(1);
+                (info.created_time, threads)
+            })
+            .collect::<Vec<_>>();
+        // The queries created earlier take the cpus first.
+        queries.sort_by_key(|(created_time, _)| *created_time);
+
+        let cpu_nums = cpu_nums.max(1);
+        let mut threads = 0;
+        let mut queued_queries = 0;
+        for (_, query_threads) in &queries {
+            if threads >= cpu_nums {
+                queued_queries += 1;
+            }
+            threads += query_threads;
+        }
+
+        NodeLoad {
+            running_queries: queries.len() as u64,
+            queued_queries,
+            cpu_saturation: threads * 100 / cpu_nums,
+        }
+    }
 }
@@ -27,6 +27,9 @@ pub enum SessionType {
     Dummy,
     Fuzz,
     Local,
+    MaterializedView,
+    Pipe,
+    Postgres,
 }
 
 impl SessionType {
@@ -52,6 +55,9 @@ impl fmt::Display for SessionType {
             SessionType::HTTPAPI(usage) => format!("HTTPAPI({})", usage),
             SessionType::Fuzz => "Fuzz".to_string(),
             SessionType::Local => "Local".to_string(),
+            SessionType::MaterializedView => "MaterializedView".to_string(),
+            SessionType::Pipe => "Pipe".to_string(),
+            SessionType::Postgres => "Postgres".to_string(),
         };
         write!(f, "{}", name)
     }
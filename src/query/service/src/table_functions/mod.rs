@@ -29,6 +29,7 @@ pub use numbers::NumbersTable;
 pub use openai::GPT2SQLTable;
 pub use others::ExecuteBackgroundJobTable;
 pub use others::LicenseInfoTable;
+pub use others::PipeStatusTable;
 pub use others::SuggestedBackgroundTasksSource;
 pub use others::SuggestedBackgroundTasksTable;
 pub use others::TenantQuotaTable;
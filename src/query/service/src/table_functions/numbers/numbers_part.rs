@@ -64,25 +64,24 @@ impl NumbersPartInfo {
     }
 }
 
-pub fn generate_numbers_parts(start: u64, workers: u64, total: u64) -> Partitions {
+/// Split the numbers in `[start, end)` into the parts of the workers.
+pub fn generate_numbers_parts(start: u64, workers: u64, end: u64) -> Partitions {
+    let total = end.saturating_sub(start);
     let part_size = total / workers;
     let part_remain = total % workers;
 
     let mut partitions = Vec::with_capacity(workers as usize);
     if part_size == 0 {
-        partitions.push(NumbersPartInfo::create(start, total, total));
+        partitions.push(NumbersPartInfo::create(start, start + total, end));
     } else {
         for part in 0..workers {
-            let mut part_begin = part * part_size;
-            if part == 0 && start > 0 {
-                part_begin = start;
-            }
-            let mut part_end = (part + 1) * part_size;
+            let part_begin = start + part * part_size;
+            let mut part_end = start + (part + 1) * part_size;
             if part == (workers - 1) && part_remain > 0 {
                 part_end += part_remain;
             }
 
-            partitions.push(NumbersPartInfo::create(part_begin, part_end, total));
+            partitions.push(NumbersPartInfo::create(part_begin, part_end, end));
         }
     }
 
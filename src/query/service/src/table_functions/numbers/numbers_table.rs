@@ -141,6 +141,14 @@ impl Table for NumbersTable {
     ) -> Result<(PartStatistics, Partitions)> {
         let max_block_size = ctx.get_settings().get_max_block_size()?;
         let mut limit = None;
+        // Only the numbers in the range of the filter are generated.
+        let (start, end) = match push_downs
+            .as_ref()
+            .and_then(|extras| extras.filter.as_ref())
+        {
+            Some(filter) => number_range(&filter.as_expr(&BUILTIN_FUNCTIONS), self.total),
+            None => (0, self.total),
+        };
 
         if let Some(extras) = &push_downs {
             if extras.limit.is_some() && extras.filter.is_none() && extras.order_by.is_empty() {
@@ -153,10 +161,11 @@ impl Table for NumbersTable {
                 limit = extras.limit;
             }
         }
-        let total = match limit {
-            Some(limit) => std::cmp::min(self.total, limit as u64),
-            None => self.total,
+        let end = match limit {
+            Some(limit) => std::cmp::min(end, start.saturating_add(limit as u64)),
+            None => end,
         };
+        let total = end.saturating_sub(start);
 
         let fake_partitions = (total / max_block_size) + 1;
         let statistics = PartStatistics::new_exact(
@@ -174,7 +183,7 @@ impl Table for NumbersTable {
             false => worker_num * cluster.nodes.len() as u64,
         };
 
-        let parts = generate_numbers_parts(0, worker_num, total);
+        let parts = generate_numbers_parts(start, worker_num, end);
         Ok((statistics, parts))
     }
 
@@ -227,6 +236,91 @@ impl Table for NumbersTable {
     }
 }
 
+/// Get the range `[start, end)` of the numbers matching the conjunctions of the filter
+/// like `number < 10`, the numbers out of the range are never matched.
+fn number_range(filter: &Expr<String>, total: u64) -> (u64, u64) {
+    let mut start = 0;
+    let mut end = total;
+    visit_number_bounds(filter, &mut |op, value| {
+        // The bounds are in i128, so that they never overflow.
+        let (lower, upper) = match op {
+            "eq" => (value, value + 1),
+            "lt" => (0, value),
+            "lte" => (0, value + 1),
+            "gt" => (value + 1, total as i128),
+            "gte" => (value, total as i128),
+            _ => return,
+        };
+        start = start.max(lower.clamp(0, total as i128) as u64);
+        end = end.min(upper.clamp(0, total as i128) as u64);
+    });
+    (start, end.max(start))
+}
+
+fn visit_number_bounds(expr: &Expr<String>, visitor: &mut impl FnMut(&str, i128)) {
+    if let Expr::FunctionCall { function, args, .. } = expr {
+        let name = function.signature.name.as_str();
+        match (name, args.as_slice()) {
+            ("and" | "and_filters", _) => {
+                for arg in args {
+                    visit_number_bounds(arg, visitor);
+                }
+            }
+            (_, [column, Expr::Constant { scalar, .. }]) if is_number_column(column) => {
+                if let Some(value) = integer_value(scalar) {
+                    visitor(name, value);
+                }
+            }
+            (_, [Expr::Constant { scalar, .. }, column]) if is_number_column(column) => {
+                let name = match name {
+                    "lt" => "gt",
+                    "lte" => "gte",
+                    "gt" => "lt",
+                    "gte" => "lte",
+                    name => name,
+                };
+                if let Some(value) = integer_value(scalar) {
+                    visitor(name, value);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The column `number`, which may be cast to `Int64` to compare with a signed constant.
+fn is_number_column(expr: &Expr<String>) -> bool {
+    match expr {
+        Expr::ColumnRef { id, .. } => id == "number",
+        Expr::Cast {
+            is_try: false,
+            expr,
+            dest_type,
+            ..
+        } => {
+            matches!(
+                dest_type.remove_nullable(),
+                DataType::Number(NumberDataType::UInt64 | NumberDataType::Int64)
+            ) && is_number_column(expr)
+        }
+        _ => false,
+    }
+}
+
+fn integer_value(scalar: &Scalar) -> Option<i128> {
+    match scalar {
+        Scalar::Number(NumberScalar::UInt8(v)) => Some(*v as i128),
+        Scalar::Number(NumberScalar::UInt16(v)) => Some(*v as i128),
+        Scalar::Number(NumberScalar::UInt32(v)) => Some(*v as i128),
+        Scalar::Number(NumberScalar::UInt64(v)) => Some(*v as i128),
+        Scalar::Number(NumberScalar::Int8(v)) => Some(*v as i128),
+        Scalar::Number(NumberScalar::Int16(v)) => Some(*v as i128),
+        Scalar::Number(NumberScalar::Int32(v)) => Some(*v as i128),
+        Scalar::Number(NumberScalar::Int64(v)) => Some(*v as i128),
+        _ => None,
+    }
+}
+
 struct NumbersSource {
     begin: u64,
     end: u64,
@@ -14,12 +14,14 @@
 
 mod execute_background_job;
 mod license_info;
+mod pipe_status;
 mod suggested_background_compaction_tasks;
 mod suggested_background_tasks;
 mod tenant_quota;
 
 pub use execute_background_job::ExecuteBackgroundJobTable;
 pub use license_info::LicenseInfoTable;
+pub use pipe_status::PipeStatusTable;
 pub use suggested_background_tasks::SuggestedBackgroundTasksSource;
 pub use suggested_background_tasks::SuggestedBackgroundTasksTable;
 pub use tenant_quota::TenantQuotaTable;
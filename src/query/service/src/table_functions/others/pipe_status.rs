@@ -0,0 +1,281 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use chrono::NaiveDateTime;
+use chrono::TimeZone;
+use chrono::Utc;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table_args::TableArgs;
+use common_catalog::table_context::TableContext;
+use common_catalog::table_function::TableFunction;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::Int32Type;
+use common_expression::types::Int64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::FromOptData;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::principal::PipeProgress;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::Pipeline;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use common_storages_factory::Table;
+use common_users::UserApiProvider;
+
+/// `pipe_status('<pipe_name>')` shows the progress of a pipe, one row per partition
+/// of the topic.
+pub struct PipeStatusTable {
+    table_info: TableInfo,
+    pipe_name: String,
+}
+
+impl PipeStatusTable {
+    pub fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("pipe_name", TableDataType::String),
+            TableField::new(
+                "partition",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::Int32))),
+            ),
+            TableField::new(
+                "offset",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::Int64))),
+            ),
+            TableField::new(
+                "pending_end_offset",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::Int64))),
+            ),
+            TableField::new("batch_count", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "message_count",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("file_count", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "last_error",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "updated_on",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+        ])
+    }
+
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args = table_args.expect_all_positioned(table_func_name, Some(1))?;
+        let pipe_name = String::from_utf8(
+            args[0]
+                .clone()
+                .into_string()
+                .map_err(|_| ErrorCode::BadArguments("Expected string argument"))?,
+        )?;
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: String::from("pipe_status"),
+            meta: TableMeta {
+                schema: Self::schema(),
+                engine: String::from(table_func_name),
+                // Assuming that created_on is unnecessary for function table,
+                // we could make created_on fixed to pass test_shuffle_action_try_into.
+                created_on: Utc
+                    .from_utc_datetime(&NaiveDateTime::from_timestamp_opt(0, 0).unwrap()),
+                updated_on: Utc
+                    .from_utc_datetime(&NaiveDateTime::from_timestamp_opt(0, 0).unwrap()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(PipeStatusTable {
+            table_info,
+            pipe_name,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for PipeStatusTable {
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        // dummy statistics
+        Ok((PartStatistics::new_exact(1, 1, 1, 1), Partitions::default()))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        Some(TableArgs::new_positioned(vec![Scalar::String(
+            self.pipe_name.as_bytes().to_vec(),
+        )]))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        pipeline.add_source(
+            |output| PipeStatusSource::create(ctx.clone(), output, self.pipe_name.clone()),
+            1,
+        )?;
+
+        Ok(())
+    }
+}
+
+struct PipeStatusSource {
+    ctx: Arc<dyn TableContext>,
+    pipe_name: String,
+    done: bool,
+}
+
+impl PipeStatusSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        pipe_name: String,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, PipeStatusSource {
+            ctx,
+            pipe_name,
+            done: false,
+        })
+    }
+
+    fn to_block(&self, progress: &PipeProgress) -> DataBlock {
+        let pending_end_offsets = progress
+            .pending_batch
+            .as_ref()
+            .map(|batch| batch.end_offsets.clone())
+            .unwrap_or_default();
+        // Partitions of the pending batch may have not been committed yet.
+        let mut partitions = progress
+            .offsets
+            .keys()
+            .chain(pending_end_offsets.keys())
+            .map(|partition| Some(*partition))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        // Show the counts and the last error even if no partitions are consumed.
+        if partitions.is_empty() {
+            partitions.push(None);
+        }
+
+        let num_rows = partitions.len();
+        let offsets = partitions
+            .iter()
+            .map(|partition| partition.and_then(|p| progress.offsets.get(&p).copied()))
+            .collect::<Vec<_>>();
+        let pending_end_offsets = partitions
+            .iter()
+            .map(|partition| partition.and_then(|p| pending_end_offsets.get(&p).copied()))
+            .collect::<Vec<_>>();
+        let last_error = progress
+            .last_error
+            .as_ref()
+            .map(|error| error.as_bytes().to_vec());
+        let updated_on = progress.updated_on.map(|t| t.timestamp_micros());
+
+        DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![self.pipe_name.as_bytes().to_vec(); num_rows]),
+            Int32Type::from_opt_data(partitions),
+            Int64Type::from_opt_data(offsets),
+            Int64Type::from_opt_data(pending_end_offsets),
+            UInt64Type::from_data(vec![progress.batch_count; num_rows]),
+            UInt64Type::from_data(vec![progress.message_count; num_rows]),
+            UInt64Type::from_data(vec![progress.file_count; num_rows]),
+            StringType::from_opt_data(vec![last_error; num_rows]),
+            TimestampType::from_opt_data(vec![updated_on; num_rows]),
+        ])
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for PipeStatusSource {
+    const NAME: &'static str = "pipe_status";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        self.done = true;
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+        // Make sure the pipe exists, the progress is empty before the first batch.
+        user_mgr.get_pipe(&tenant, &self.pipe_name).await?;
+        let progress = user_mgr.get_pipe_progress(&tenant, &self.pipe_name).await?;
+
+        Ok(Some(self.to_block(&progress.data)))
+    }
+}
+
+impl TableFunction for PipeStatusTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
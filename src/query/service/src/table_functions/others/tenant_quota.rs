@@ -30,6 +30,7 @@ pub use common_exception::Result;
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
 use common_expression::types::UInt32Type;
+use common_expression::types::UInt64Type;
 use common_expression::types::ValueType;
 use common_expression::BlockEntry;
 use common_expression::DataBlock;
@@ -74,6 +75,11 @@ impl TenantQuotaTable {
                 "max_files_per_stage",
                 TableDataType::Number(NumberDataType::UInt32),
             ),
+            TableField::new("max_users", TableDataType::Number(NumberDataType::UInt32)),
+            TableField::new(
+                "max_data_size",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
         ])
     }
 
@@ -197,6 +203,14 @@ impl TenantQuotaSource {
                     DataType::Number(NumberDataType::UInt32),
                     Value::Scalar(UInt32Type::upcast_scalar(quota.max_files_per_stage)),
                 ),
+                BlockEntry::new(
+                    DataType::Number(NumberDataType::UInt32),
+                    Value::Scalar(UInt32Type::upcast_scalar(quota.max_users)),
+                ),
+                BlockEntry::new(
+                    DataType::Number(NumberDataType::UInt64),
+                    Value::Scalar(UInt64Type::upcast_scalar(quota.max_data_size)),
+                ),
             ],
             1,
         ))
@@ -209,6 +223,8 @@ impl TenantQuotaSource {
 /// max_tables_per_database: u32
 /// max_stages: u32
 /// max_files_per_stage: u32
+/// max_users: u32
+/// max_data_size: u64, in bytes
 #[async_trait::async_trait]
 impl AsyncSource for TenantQuotaSource {
     const NAME: &'static str = "tenant_quota";
@@ -252,6 +268,12 @@ impl AsyncSource for TenantQuotaSource {
         if let Some(max_files_per_stage) = args.get(4) {
             quota.max_files_per_stage = max_files_per_stage.parse::<u32>()?
         };
+        if let Some(max_users) = args.get(5) {
+            quota.max_users = max_users.parse::<u32>()?;
+        };
+        if let Some(max_data_size) = args.get(6) {
+            quota.max_data_size = max_data_size.parse::<u64>()?;
+        };
 
         quota_api
             .set_quota(&quota, MatchSeq::Exact(res.seq))
@@ -25,15 +25,19 @@ use parking_lot::RwLock;
 
 use super::ExecuteBackgroundJobTable;
 use super::LicenseInfoTable;
+use super::PipeStatusTable;
 use super::SuggestedBackgroundTasksTable;
 use super::TenantQuotaTable;
 use crate::catalogs::SYS_TBL_FUC_ID_END;
 use crate::catalogs::SYS_TBL_FUNC_ID_BEGIN;
 use crate::storages::fuse::table_functions::ClusteringInformationTable;
 use crate::storages::fuse::table_functions::FuseBlockTable;
+use crate::storages::fuse::table_functions::FuseCheckStatsTable;
+use crate::storages::fuse::table_functions::FuseRotateEncryptionKeyTable;
 use crate::storages::fuse::table_functions::FuseSegmentTable;
 use crate::storages::fuse::table_functions::FuseSnapshotTable;
 use crate::storages::fuse::table_functions::FuseStatisticTable;
+use crate::storages::fuse::table_functions::FuseVacuumTable;
 use crate::table_functions::async_crash_me::AsyncCrashMeTable;
 use crate::table_functions::infer_schema::InferSchemaTable;
 use crate::table_functions::list_stage::ListStageTable;
@@ -128,6 +132,18 @@ impl TableFunctionFactory {
             "fuse_statistic".to_string(),
             (next_id(), Arc::new(FuseStatisticTable::create)),
         );
+        creators.insert(
+            "fuse_vacuum".to_string(),
+            (next_id(), Arc::new(FuseVacuumTable::create)),
+        );
+        creators.insert(
+            "fuse_rotate_encryption_key".to_string(),
+            (next_id(), Arc::new(FuseRotateEncryptionKeyTable::create)),
+        );
+        creators.insert(
+            "fuse_check_stats".to_string(),
+            (next_id(), Arc::new(FuseCheckStatsTable::create)),
+        );
 
         creators.insert(
             "clustering_information".to_string(),
@@ -189,6 +205,11 @@ impl TableFunctionFactory {
             (next_id(), Arc::new(TenantQuotaTable::create)),
         );
 
+        creators.insert(
+            "pipe_status".to_string(),
+            (next_id(), Arc::new(PipeStatusTable::create)),
+        );
+
         TableFunctionFactory {
             creators: RwLock::new(creators),
         }
@@ -41,6 +41,12 @@ impl ConfigBuilder {
         ConfigBuilder { conf }
     }
 
+    pub fn with_read_only(&self) -> ConfigBuilder {
+        let mut conf = self.conf.clone();
+        conf.query.read_only = true;
+        ConfigBuilder { conf }
+    }
+
     pub fn api_tls_server_key(mut self, value: impl Into<String>) -> ConfigBuilder {
         self.conf.query.api_tls_server_key = value.into();
         self
@@ -88,6 +94,24 @@ impl ConfigBuilder {
         self
     }
 
+    pub fn http_handler_result_spool_max_bytes(mut self, value: impl Into<u64>) -> ConfigBuilder {
+        self.conf.query.http_handler_result_spool_max_bytes = value.into();
+        self
+    }
+
+    pub fn http_handler_result_spool_local_path(
+        mut self,
+        value: impl Into<String>,
+    ) -> ConfigBuilder {
+        self.conf.query.http_handler_result_spool_local_path = value.into();
+        self
+    }
+
+    pub fn http_handler_result_memory_max_bytes(mut self, value: impl Into<u64>) -> ConfigBuilder {
+        self.conf.query.http_handler_result_memory_max_bytes = value.into();
+        self
+    }
+
     pub fn http_handler_tls_server_key(mut self, value: impl Into<String>) -> ConfigBuilder {
         self.conf.query.http_handler_tls_server_key = value.into();
         self
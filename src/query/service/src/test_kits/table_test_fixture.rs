@@ -188,6 +188,8 @@ impl TestFixture {
             .into(),
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
+            clone_from: None,
+            identity_sequences: vec![],
             cluster_key: Some("(id)".to_string()),
         }
     }
@@ -211,6 +213,8 @@ impl TestFixture {
             .into(),
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
+            clone_from: None,
+            identity_sequences: vec![],
             cluster_key: None,
         }
     }
@@ -245,6 +249,8 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            clone_from: None,
+            identity_sequences: vec![],
             cluster_key: None,
         }
     }
@@ -288,6 +294,8 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            clone_from: None,
+            identity_sequences: vec![],
             cluster_key: None,
         }
     }
@@ -32,7 +32,9 @@ async fn test_cluster() -> Result<()> {
     let _guard =
         TestGlobalServices::setup(databend_query::test_kits::ConfigBuilder::create().build())
             .await?;
-    let cluster_router = Route::new().at("/v1/cluster/list", get(cluster_list_handler));
+    let cluster_router = Route::new()
+        .at("/v1/cluster/list", get(cluster_list_handler))
+        .at("/v1/cluster/load", get(cluster_load_handler));
 
     // List Node
     {
@@ -53,5 +55,26 @@ async fn test_cluster() -> Result<()> {
         assert_eq!(nodes.len(), 1);
     }
 
+    // Load of the clusters
+    {
+        let response = cluster_router
+            .call(
+                Request::builder()
+                    .uri(Uri::from_static("/v1/cluster/load"))
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .method(Method::GET)
+                    .finish(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response.into_body().into_vec().await.unwrap();
+        let loads = serde_json::from_str::<Vec<ClusterLoad>>(&String::from_utf8_lossy(&body))?;
+        assert_eq!(loads.len(), 1);
+        assert_eq!(loads[0].nodes, 1);
+        assert_eq!(loads[0].running_queries, 0);
+    }
+
     Ok(())
 }
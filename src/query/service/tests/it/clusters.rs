@@ -13,11 +13,19 @@
 // limitations under the License.
 
 use common_base::base::tokio;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use databend_query::clusters::ClusterDiscovery;
 use databend_query::clusters::ClusterHelper;
+use databend_query::clusters::StandbyTable;
+use databend_query::clusters::StandbyTables;
+use databend_query::storages::fuse::FuseTable;
+use databend_query::test_kits::table_test_fixture::append_sample_data;
+use databend_query::test_kits::table_test_fixture::execute_command;
+use databend_query::test_kits::table_test_fixture::TestFixture;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestGlobalServices;
+use futures::stream;
 use pretty_assertions::assert_eq;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -65,6 +73,96 @@ async fn test_remove_invalid_nodes() -> Result<()> {
     Ok(())
 }
 
+async fn latest_snapshot_location(fixture: &TestFixture) -> Result<Option<String>> {
+    let table = fixture.latest_default_table().await?;
+    FuseTable::try_from_table(table.as_ref())?
+        .snapshot_loc()
+        .await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_standby_table_catch_up() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let tenant = fixture.default_tenant();
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    fixture.create_default_table().await?;
+
+    let mut standby = StandbyTable::create(&tenant, &db, &tbl).await?;
+    assert_eq!(standby.snapshot_location(), None);
+
+    // the snapshot committed by the writing cluster is loaded on catching up.
+    append_sample_data(1, &fixture).await?;
+    assert!(standby.catch_up().await?);
+    let location = latest_snapshot_location(&fixture).await?;
+    assert!(location.is_some());
+    assert_eq!(standby.snapshot_location(), location.as_deref());
+
+    // nothing changed
+    assert!(standby.catch_up().await?);
+    assert_eq!(standby.snapshot_location(), location.as_deref());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_standby_table_follow() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let tenant = fixture.default_tenant();
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    fixture.create_default_table().await?;
+
+    let mut standby = StandbyTable::create(&tenant, &db, &tbl).await?;
+    let table_id = standby.table_id();
+    append_sample_data(1, &fixture).await?;
+    append_sample_data(1, &fixture).await?;
+    standby.follow(stream::iter(vec![Ok(()), Ok(())])).await?;
+    assert_eq!(
+        standby.snapshot_location(),
+        latest_snapshot_location(&fixture).await?.as_deref()
+    );
+
+    // the table is replaced by another table of the same name, which is followed afresh,
+    // the changes after the replacement are not consumed.
+    let qry = format!("drop table {}.{}", db, tbl);
+    execute_command(fixture.ctx(), qry.as_str()).await?;
+    fixture.create_default_table().await?;
+    let changes = vec![Ok(()), Err(ErrorCode::Internal("not consumed"))];
+    standby.follow(stream::iter(changes)).await?;
+
+    let standby = StandbyTable::create(&tenant, &db, &tbl).await?;
+    assert_ne!(standby.table_id(), table_id);
+    assert_eq!(standby.snapshot_location(), None);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_standby_table_drop() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let tenant = fixture.default_tenant();
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    fixture.create_default_table().await?;
+    append_sample_data(1, &fixture).await?;
+
+    let mut standby = StandbyTable::create(&tenant, &db, &tbl).await?;
+    let qry = format!("drop table {}.{}", db, tbl);
+    execute_command(fixture.ctx(), qry.as_str()).await?;
+
+    // following a dropped table fails, and is not retried.
+    let err = standby
+        .follow(stream::iter(vec![Ok(())]))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::UNKNOWN_TABLE);
+    assert!(StandbyTables::is_dropped(&err));
+    assert!(StandbyTable::create(&tenant, &db, &tbl).await.is_err());
+
+    // other failures are retried.
+    assert!(!StandbyTables::is_dropped(&ErrorCode::Internal("")));
+    Ok(())
+}
+
 // TODO:(Winter) need kvapi::KVApi for cluster multiple nodes test
 // #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 // async fn test_multiple_cluster_discovery() -> Result<()> {
@@ -721,6 +721,9 @@ clickhouse_http_handler_port = 8124
 http_handler_host = "127.0.0.1"
 http_handler_port = 8000
 http_handler_result_timeout_secs = 60
+http_handler_result_spool_max_bytes = 0
+http_handler_result_spool_local_path = ""
+http_handler_result_memory_max_bytes = 0
 flight_api_address = "127.0.0.1:9090"
 admin_api_address = "127.0.0.1:8080"
 metric_api_address = "127.0.0.1:7070"
@@ -738,6 +741,7 @@ table_engine_memory_enabled = true
 wait_timeout_mills = 5000
 max_query_log_size = 10000
 management_mode = false
+read_only = false
 jwt_key_file = ""
 users = []
 share_endpoint_address = ""
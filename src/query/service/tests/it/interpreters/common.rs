@@ -0,0 +1,49 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::DataSchemaRef;
+use common_expression::SendableDataBlockStream;
+use common_sql::planner::plans::Plan;
+use common_sql::Planner;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::interpreters::InterpreterPtr;
+use databend_query::sessions::QueryContext;
+
+pub async fn plan_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<Plan> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    Ok(plan)
+}
+
+pub async fn get_interpreter(
+    ctx: Arc<QueryContext>,
+    sql: &str,
+) -> Result<(InterpreterPtr, DataSchemaRef)> {
+    let plan = plan_sql(ctx.clone(), sql).await?;
+    let it = InterpreterFactory::get(ctx, &plan).await?;
+    Ok((it, plan.schema()))
+}
+
+pub async fn execute_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<SendableDataBlockStream> {
+    let (interpreter, _) = get_interpreter(ctx.clone(), sql).await?;
+    interpreter.execute(ctx).await
+}
+
+pub async fn execute_plan(ctx: Arc<QueryContext>, plan: &Plan) -> Result<SendableDataBlockStream> {
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan).await?;
+    interpreter.execute(ctx).await
+}
@@ -0,0 +1,59 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::test_kits::create_query_context_with_config;
+use databend_query::test_kits::ConfigBuilder;
+use futures::TryStreamExt;
+
+use crate::interpreters::common::execute_sql;
+use crate::interpreters::common::plan_sql;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_read_only_mode() -> Result<()> {
+    let config = ConfigBuilder::create().with_read_only().build();
+    let (_guard, ctx) = create_query_context_with_config(config, None).await?;
+
+    // Reads are allowed.
+    for sql in [
+        "select * from numbers(3)",
+        "show tables",
+        "explain select * from numbers(3)",
+        "set max_threads = 1",
+    ] {
+        let stream = execute_sql(ctx.clone(), sql).await?;
+        stream.try_collect::<Vec<_>>().await?;
+    }
+
+    // Writes are rejected.
+    for sql in [
+        "create table t(a int)",
+        "drop database default",
+        "insert into system.one values(1)",
+        "select * from fuse_vacuum('default', 't')",
+    ] {
+        let plan = plan_sql(ctx.clone(), sql).await?;
+        let res = InterpreterFactory::get(ctx.clone(), &plan).await;
+        assert_eq!(
+            res.err().map(|e| e.code()),
+            Some(ErrorCode::READ_ONLY_MODE_PERMISSION_DENIED),
+            "{sql}"
+        );
+    }
+
+    Ok(())
+}
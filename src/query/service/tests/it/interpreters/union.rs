@@ -12,47 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::sync::Arc;
-
 use common_base::base::tokio;
 use common_exception::Result;
 use common_expression::type_check::common_super_type;
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
-use common_expression::DataSchemaRef;
-use common_expression::SendableDataBlockStream;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_sql::planner::plans::Plan;
-use common_sql::Planner;
-use databend_query::interpreters::InterpreterFactory;
-use databend_query::interpreters::InterpreterPtr;
-use databend_query::sessions::QueryContext;
 use databend_query::test_kits::TestFixture;
 
-async fn plan_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<Plan> {
-    let mut planner = Planner::new(ctx.clone());
-    let (plan, _) = planner.plan_sql(sql).await?;
-    Ok(plan)
-}
-
-async fn get_interpreter(
-    ctx: Arc<QueryContext>,
-    sql: &str,
-) -> Result<(InterpreterPtr, DataSchemaRef)> {
-    let plan = plan_sql(ctx.clone(), sql).await?;
-    let it = InterpreterFactory::get(ctx, &plan).await?;
-    Ok((it, plan.schema()))
-}
-
-async fn execute_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<SendableDataBlockStream> {
-    let (interpreter, _) = get_interpreter(ctx.clone(), sql).await?;
-    interpreter.execute(ctx).await
-}
-
-async fn execute_plan(ctx: Arc<QueryContext>, plan: &Plan) -> Result<SendableDataBlockStream> {
-    let interpreter = InterpreterFactory::get(ctx.clone(), plan).await?;
-    interpreter.execute(ctx).await
-}
+use crate::interpreters::common::execute_plan;
+use crate::interpreters::common::execute_sql;
+use crate::interpreters::common::get_interpreter;
+use crate::interpreters::common::plan_sql;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_simple_union_output_type() -> Result<()> {
@@ -133,6 +133,21 @@ async fn test_query() -> Result<()> {
             };
             writeln!(file, "{}", res).unwrap();
         }
+
+        // The result of a statement is split across the streams of all the endpoints.
+        let flight_info = client
+            .execute("select number from numbers(100000)".to_string(), None)
+            .await
+            .unwrap();
+        assert!(!flight_info.endpoint.is_empty());
+        let mut num_rows = 0;
+        for endpoint in flight_info.endpoint {
+            let flight_data = client.do_get(endpoint.ticket.unwrap()).await.unwrap();
+            let flight_data: Vec<FlightData> = flight_data.try_collect().await.unwrap();
+            let batches = flight_data_to_batches(&flight_data).unwrap();
+            num_rows += batches.iter().map(|batch| batch.num_rows()).sum::<usize>();
+        }
+        assert_eq!(num_rows, 100000);
     };
     tokio::pin!(serve_future);
 
@@ -126,7 +126,7 @@ async fn test_simple_sql() -> Result<()> {
     assert_eq!(result.state, ExecuteStateKind::Succeeded, "{:?}", result);
     assert_eq!(result.next_uri, Some(final_uri.clone()), "{:?}", result);
     assert_eq!(result.data.len(), 10, "{:?}", result);
-    assert_eq!(result.schema.len(), 17, "{:?}", result);
+    assert_eq!(result.schema.len(), 19, "{:?}", result);
 
     // get state
     let uri = make_state_uri(query_id);
@@ -433,6 +433,140 @@ async fn test_http_session() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_http_session_token() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let ep = create_endpoint().await?;
+    let json =
+        serde_json::json!({"sql":  "use system", "session": {"keep_server_session_secs": 10}});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert!(result.error.is_none(), "{:?}", result);
+    let session_token = result.session_token.clone().unwrap();
+
+    let json = serde_json::json!({"sql": "select database()", "session_token": session_token});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert_eq!(result.data[0][0], "system", "{:?}", result);
+    assert_eq!(result.session_token, Some(session_token.clone()));
+
+    // queries not keeping the session get no token.
+    let (_, result) = post_sql_to_endpoint(&ep, "select 1", 1).await?;
+    assert!(result.session_token.is_none(), "{:?}", result);
+
+    let json =
+        serde_json::json!({"sql": "select 1", "session_token": format!("{}x", session_token)});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    let error = result.error.unwrap();
+    assert_eq!(
+        error.code,
+        ErrorCode::UnknownSession("").code(),
+        "{:?}",
+        error
+    );
+
+    let json = serde_json::json!({"sql": "select 1", "session_token": "other_node:abc"});
+    let (_, result) = post_json_to_endpoint(&ep, &json).await?;
+    let error = result.error.unwrap();
+    assert!(error.message.contains("other_node"), "{:?}", error);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_result_spool() -> Result<()> {
+    let config = ConfigBuilder::create()
+        .http_handler_result_spool_max_bytes(1024u64 * 1024 * 1024)
+        .build();
+    let _guard = TestGlobalServices::setup(config).await?;
+
+    let rows = 200000;
+    let ep = create_endpoint().await?;
+    let sql = format!("select * from numbers({})", rows);
+    let json = serde_json::json!({"sql": sql, "pagination": {"wait_time_secs": 1, "max_rows_in_buffer": 10000, "max_rows_per_page": 50000}});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+
+    // the query runs to the end without the client fetching the pages.
+    let mut state = result.state;
+    for _ in 0..50 {
+        if state != ExecuteStateKind::Running {
+            break;
+        }
+        sleep(std::time::Duration::from_millis(100)).await;
+        let (_, result) = get_uri_checked(&ep, result.stats_uri.as_ref().unwrap()).await?;
+        state = result.state;
+    }
+    assert_eq!(state, ExecuteStateKind::Succeeded);
+
+    let mut num_rows = result.data.len();
+    let mut next_uri = result.next_uri.clone().unwrap();
+    while next_uri.contains("/page/") {
+        let (status, result) = get_uri_checked(&ep, &next_uri).await?;
+        assert_eq!(status, StatusCode::OK, "{:?}", result);
+        assert!(result.error.is_none(), "{:?}", result);
+        num_rows += result.data.len();
+        next_uri = result.next_uri.clone().unwrap();
+    }
+    assert_eq!(num_rows, rows);
+    check_final(&ep, &next_uri).await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_result_spool_local_with_memory_limit() -> Result<()> {
+    let spool_dir = tempfile::TempDir::new().unwrap();
+    let config = ConfigBuilder::create()
+        .http_handler_result_spool_max_bytes(1024u64 * 1024 * 1024)
+        .http_handler_result_spool_local_path(spool_dir.path().to_str().unwrap())
+        .http_handler_result_memory_max_bytes(64u64 * 1024)
+        .build();
+    let _guard = TestGlobalServices::setup(config).await?;
+
+    let rows = 200000;
+    let ep = create_endpoint().await?;
+    let sql = format!("select * from numbers({})", rows);
+    // the buffer can hold all the rows, but not the memory limit of the session.
+    let json = serde_json::json!({"sql": sql, "pagination": {"wait_time_secs": 1, "max_rows_in_buffer": rows, "max_rows_per_page": 50000}});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+
+    let mut state = result.state;
+    for _ in 0..50 {
+        if state != ExecuteStateKind::Running {
+            break;
+        }
+        sleep(std::time::Duration::from_millis(100)).await;
+        let (_, result) = get_uri_checked(&ep, result.stats_uri.as_ref().unwrap()).await?;
+        state = result.state;
+    }
+    assert_eq!(state, ExecuteStateKind::Succeeded);
+
+    let spooled_files = walkdir::WalkDir::new(spool_dir.path())
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .count();
+    assert!(spooled_files > 0);
+
+    let mut num_rows = result.data.len();
+    let mut next_uri = result.next_uri.clone().unwrap();
+    while next_uri.contains("/page/") {
+        let (status, result) = get_uri_checked(&ep, &next_uri).await?;
+        assert_eq!(status, StatusCode::OK, "{:?}", result);
+        assert!(result.error.is_none(), "{:?}", result);
+        num_rows += result.data.len();
+        next_uri = result.next_uri.clone().unwrap();
+    }
+    assert_eq!(num_rows, rows);
+    check_final(&ep, &next_uri).await?;
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_result_timeout() -> Result<()> {
     let config = ConfigBuilder::create()
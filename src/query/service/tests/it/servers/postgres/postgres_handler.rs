@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::SocketAddr;
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::servers::MySQLTlsConfig;
+use databend_query::servers::PostgresHandler;
+use databend_query::test_kits::ConfigBuilder;
+use databend_query::test_kits::TestGlobalServices;
+use tokio_postgres::Client;
+use tokio_postgres::NoTls;
+use tokio_postgres::types::Type;
+use tokio_postgres::SimpleQueryMessage;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_simple_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = PostgresHandler::create(120, MySQLTlsConfig::default())?;
+    let listening = handler.start("127.0.0.1:0".parse::<SocketAddr>()?).await?;
+    let client = create_connection(listening.port()).await?;
+
+    let messages = client
+        .simple_query("SELECT number, number % 2 = 0 FROM numbers(3) ORDER BY number")
+        .await
+        .map_err(to_error_code)?;
+    let rows = messages
+        .iter()
+        .filter_map(|message| match message {
+            SimpleQueryMessage::Row(row) => Some((
+                row.get(0).unwrap().to_string(),
+                row.get(1).unwrap().to_string(),
+            )),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(rows, vec![
+        ("0".to_string(), "true".to_string()),
+        ("1".to_string(), "false".to_string()),
+        ("2".to_string(), "true".to_string()),
+    ]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_extended_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = PostgresHandler::create(120, MySQLTlsConfig::default())?;
+    let listening = handler.start("127.0.0.1:0".parse::<SocketAddr>()?).await?;
+    let client = create_connection(listening.port()).await?;
+
+    // The parameter is bound as text, quotes in it are escaped.
+    let rows = client
+        .query("SELECT concat('hello ', $1) AS greeting", &[&"o'neil"])
+        .await
+        .map_err(to_error_code)?;
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].columns()[0].name(), "greeting");
+    let greeting: String = rows[0].get(0);
+    assert_eq!(greeting, "hello o'neil");
+
+    // Placeholders in string literals are not parameters.
+    let rows = client
+        .query("SELECT '$1', $1", &[&"a"])
+        .await
+        .map_err(to_error_code)?;
+    let (literal, param): (String, String) = (rows[0].get(0), rows[0].get(1));
+    assert_eq!((literal.as_str(), param.as_str()), ("$1", "a"));
+
+    // A negative number is not merged with the minus before it into a comment.
+    let statement = client
+        .prepare_typed("SELECT 1-$1", &[Type::INT8])
+        .await
+        .map_err(to_error_code)?;
+    let rows = client
+        .query(&statement, &[&-5i64])
+        .await
+        .map_err(to_error_code)?;
+    let value: i64 = rows[0].get(0);
+    assert_eq!(value, 6);
+
+    // A number that is not finite is rejected.
+    let statement = client
+        .prepare_typed("SELECT $1", &[Type::FLOAT8])
+        .await
+        .map_err(to_error_code)?;
+    assert!(client.query(&statement, &[&f64::NAN]).await.is_err());
+
+    Ok(())
+}
+
+async fn create_connection(port: u16) -> Result<Client> {
+    let config = format!(
+        "host=127.0.0.1 port={} user=root password=root dbname=default",
+        port
+    );
+    let (client, connection) = tokio_postgres::connect(&config, NoTls)
+        .await
+        .map_err(to_error_code)?;
+    // The errors of the connection are returned by the queries of the client.
+    tokio::spawn(connection);
+    Ok(client)
+}
+
+fn to_error_code(e: tokio_postgres::Error) -> ErrorCode {
+    ErrorCode::UnknownException(format!("postgres error: {}", e))
+}
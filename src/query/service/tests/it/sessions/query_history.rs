@@ -0,0 +1,77 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::block_debug::pretty_format_blocks;
+use common_expression::DataBlock;
+use databend_query::sessions::SessionType;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::create_query_context_with_session;
+use databend_query::test_kits::table_test_fixture::execute_command;
+use databend_query::test_kits::table_test_fixture::execute_query;
+use databend_query::test_kits::TestGlobalServices;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_query_history() -> Result<()> {
+    let mut conf = databend_query::test_kits::ConfigBuilder::create().config();
+    conf.query.enable_query_history = true;
+    let guard = TestGlobalServices::setup(conf).await?;
+
+    let (guard, ctx) = create_query_context_with_session(SessionType::Dummy, Some(guard)).await?;
+    ctx.attach_query_str("Query".to_string(), "select 1".to_string());
+    execute_command(ctx, "select 1").await?;
+
+    let (_guard, ctx) = create_query_context_with_session(SessionType::Dummy, Some(guard)).await?;
+    let query = "select sql_user, query_kind, status, length(plan_hash) from \
+                 system_history.query_log where query_text = 'select 1'";
+    let expected = vec![
+        "+----------+----------+-------------+----------+",
+        "| Column 0 | Column 1 | Column 2    | Column 3 |",
+        "+----------+----------+-------------+----------+",
+        "| 'root'   | 'Query'  | 'Succeeded' | 64       |",
+        "+----------+----------+-------------+----------+",
+    ];
+
+    // The history is written in background, wait for the first flush.
+    for _ in 0..30 {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        let blocks = match execute_query(ctx.clone(), query).await {
+            Ok(stream) => stream.try_collect::<Vec<DataBlock>>().await?,
+            Err(_) => continue,
+        };
+        if blocks.iter().map(|b| b.num_rows()).sum::<usize>() > 0 {
+            assert_eq!(pretty_format_blocks(&blocks)?, expected.join("\n"));
+
+            // The history can't be changed by the users.
+            for query in [
+                "truncate table system_history.query_log",
+                "drop table system_history.query_log",
+                "drop database system_history",
+            ] {
+                let res = execute_command(ctx.clone(), query).await;
+                assert_eq!(
+                    res.unwrap_err().code(),
+                    ErrorCode::PermissionDenied("").code()
+                );
+            }
+            return Ok(());
+        }
+    }
+    panic!("query history is not written");
+}
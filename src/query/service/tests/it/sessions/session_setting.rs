@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_base::base::tokio;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use databend_query::sessions::SessionManager;
 use databend_query::sessions::SessionType;
@@ -69,3 +70,36 @@ async fn test_session_setting_override() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_session_setting_range() -> Result<()> {
+    let _guard =
+        TestGlobalServices::setup(databend_query::test_kits::ConfigBuilder::create().build())
+            .await?;
+    let session = SessionManager::instance()
+        .create_session(SessionType::Dummy)
+        .await?;
+
+    // Settings.
+    {
+        let settings = session.get_settings();
+        settings.set_setting("enable_cbo".to_string(), "0".to_string())?;
+        assert!(!settings.get_enable_cbo()?);
+
+        let err = settings
+            .set_setting("enable_cbo".to_string(), "2".to_string())
+            .unwrap_err();
+        assert_eq!(err.code(), ErrorCode::WRONG_VALUE_FOR_VARIABLE);
+        assert!(!settings.get_enable_cbo()?);
+
+        let err = settings
+            .set_setting("max_threads".to_string(), "1025".to_string())
+            .unwrap_err();
+        assert_eq!(err.code(), ErrorCode::WRONG_VALUE_FOR_VARIABLE);
+
+        let err = settings.set_max_threads(1025).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::WRONG_VALUE_FOR_VARIABLE);
+    }
+
+    Ok(())
+}
@@ -80,6 +80,8 @@ fn create_table_plan(fixture: &TestFixture, format: &str) -> CreateTablePlan {
         .into(),
         field_comments: vec![],
         as_select: None,
+        clone_from: None,
+        identity_sequences: vec![],
         cluster_key: None,
     }
 }
@@ -51,6 +51,7 @@ fn test_unresolvable_delete_conflict() {
         removed_segment_indexes: vec![1],
         removed_statistics: Statistics::default(),
         merged_statistics: Statistics::default(),
+        changed_rows: 0,
     });
 
     let mut generator = MutationGenerator::new(Arc::new(base_snapshot));
@@ -139,6 +140,7 @@ fn test_resolvable_delete_conflict() {
         removed_segment_indexes: vec![1],
         removed_statistics,
         merged_statistics,
+        changed_rows: 3,
     });
 
     let mut generator = MutationGenerator::new(Arc::new(base_snapshot));
@@ -152,6 +154,7 @@ fn test_resolvable_delete_conflict() {
     let snapshot = result.unwrap();
     let expected = vec![("8".to_string(), 1), ("4".to_string(), 1)];
     assert_eq!(snapshot.segments, expected);
+    assert_eq!(snapshot.rows_changed_since_analyze, 3);
 
     let actual = snapshot.summary;
     let expected = Statistics {
@@ -242,6 +245,7 @@ fn test_resolvable_replace_conflict() {
         removed_segment_indexes: vec![1],
         removed_statistics,
         merged_statistics,
+        changed_rows: 0,
     });
 
     let mut generator = MutationGenerator::new(Arc::new(base_snapshot));
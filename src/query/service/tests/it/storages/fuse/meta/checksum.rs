@@ -0,0 +1,112 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_storages_fuse::io::gen_columns_checksum;
+use common_storages_fuse::io::verify_column_checksum;
+use common_storages_fuse::io::ChecksumVerification;
+use storages_common_table_meta::meta::ColumnMeta;
+use storages_common_table_meta::meta::CompactSegmentInfo;
+use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::SingleColumnMeta;
+use storages_common_table_meta::meta::Statistics;
+use storages_common_table_meta::meta::SEGMENT_CHECKSUM_FLAG;
+
+#[test]
+fn test_segment_checksum() -> Result<()> {
+    let segment = SegmentInfo::new(vec![], Statistics::default());
+    let bytes = segment.to_bytes()?;
+
+    // the checksum flag is set in the encoding byte, following the format version
+    assert_ne!(bytes[8] & SEGMENT_CHECKSUM_FLAG, 0);
+    assert_eq!(
+        SegmentInfo::from_slice_with_checksum(&bytes, true)?,
+        segment
+    );
+    assert!(CompactSegmentInfo::from_slice_with_checksum(&bytes, true).is_ok());
+
+    // segments written before the checksum was introduced have neither the flag nor a trailer
+    let mut without_checksum = bytes[..bytes.len() - 4].to_vec();
+    without_checksum[8] &= !SEGMENT_CHECKSUM_FLAG;
+    assert_eq!(
+        SegmentInfo::from_slice_with_checksum(&without_checksum, true)?,
+        segment
+    );
+    assert!(CompactSegmentInfo::from_slice_with_checksum(&without_checksum, true).is_ok());
+
+    let mut corrupted = bytes.clone();
+    *corrupted.last_mut().unwrap() ^= 0xff;
+    let e = SegmentInfo::from_slice_with_checksum(&corrupted, true).unwrap_err();
+    assert_eq!(e.code(), ErrorCode::StorageDataCorrupted("").code());
+    let e = CompactSegmentInfo::from_slice_with_checksum(&corrupted, true).unwrap_err();
+    assert_eq!(e.code(), ErrorCode::StorageDataCorrupted("").code());
+
+    // the checksum is only verified if asked to
+    assert_eq!(SegmentInfo::from_slice(&corrupted)?, segment);
+    assert!(CompactSegmentInfo::from_slice(&corrupted).is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn test_column_checksum() -> Result<()> {
+    let block_data = (0..64u8).collect::<Vec<_>>();
+    let col_metas = HashMap::from([
+        (
+            0,
+            ColumnMeta::Parquet(SingleColumnMeta {
+                offset: 0,
+                len: 16,
+                num_values: 1,
+            }),
+        ),
+        (
+            1,
+            ColumnMeta::Parquet(SingleColumnMeta {
+                offset: 16,
+                len: 48,
+                num_values: 1,
+            }),
+        ),
+    ]);
+
+    let checksums = gen_columns_checksum(&block_data, &col_metas);
+    assert_eq!(checksums.len(), 2);
+    verify_column_checksum("block", 0, &block_data[0..16], checksums[&0])?;
+    verify_column_checksum("block", 1, &block_data[16..64], checksums[&1])?;
+
+    let e = verify_column_checksum("block", 1, &block_data[0..16], checksums[&1]).unwrap_err();
+    assert_eq!(e.code(), ErrorCode::StorageDataCorrupted("").code());
+
+    Ok(())
+}
+
+#[test]
+fn test_checksum_verification_mode() -> Result<()> {
+    assert_eq!(
+        ChecksumVerification::try_from("off")?,
+        ChecksumVerification::Off
+    );
+    assert_eq!(
+        ChecksumVerification::try_from("Always")?,
+        ChecksumVerification::Always
+    );
+    assert!(!ChecksumVerification::Off.should_verify());
+    assert!(ChecksumVerification::Always.should_verify());
+    assert!(ChecksumVerification::try_from("never").is_err());
+
+    Ok(())
+}
@@ -39,6 +39,7 @@ use common_io::prelude::FormatSettings;
 use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::RoleInfo;
+use common_meta_app::principal::UserDefinedFunction;
 use common_meta_app::principal::UserInfo;
 use common_meta_app::schema::CatalogInfo;
 use common_meta_app::schema::CountTablesReply;
@@ -83,8 +84,11 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateDatabaseCommentReply;
+use common_meta_app::schema::UpdateDatabaseCommentReq;
 use common_meta_app::schema::UpdateIndexReply;
 use common_meta_app::schema::UpdateIndexReq;
+use common_meta_app::schema::UpdateStreamMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReply;
@@ -396,14 +400,6 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
-    fn add_partitions_sha(&self, _sha: String) {
-        todo!()
-    }
-
-    fn get_partitions_shas(&self) -> Vec<String> {
-        todo!()
-    }
-
     fn get_cacheable(&self) -> bool {
         todo!()
     }
@@ -528,6 +524,14 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_temporary_view(&self, _name: &str) -> Option<TableInfo> {
+        todo!()
+    }
+
+    fn get_temporary_udf(&self, _name: &str) -> Option<UserDefinedFunction> {
+        todo!()
+    }
+
     fn get_on_error_map(&self) -> Option<Arc<DashMap<String, HashMap<u16, InputError>>>> {
         todo!()
     }
@@ -544,6 +548,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_rows_loaded_per_file(&self) -> Arc<DashMap<String, u64>> {
+        todo!()
+    }
+
     fn apply_changed_settings(&self, _changes: HashMap<String, ChangeValue>) -> Result<()> {
         todo!()
     }
@@ -606,6 +614,14 @@ impl TableContext for CtxDelegation {
     fn get_segment_locations(&self) -> Result<Vec<Location>> {
         todo!()
     }
+
+    fn add_stream_update(&self, _update: UpdateStreamMetaReq) {
+        todo!()
+    }
+
+    fn get_stream_updates(&self) -> Vec<UpdateStreamMetaReq> {
+        self.ctx.get_stream_updates()
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -648,6 +664,13 @@ impl Catalog for FakedCatalog {
         todo!()
     }
 
+    async fn update_database_comment(
+        &self,
+        _req: UpdateDatabaseCommentReq,
+    ) -> Result<UpdateDatabaseCommentReply> {
+        todo!()
+    }
+
     fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
         self.cat.get_table_by_info(table_info)
     }
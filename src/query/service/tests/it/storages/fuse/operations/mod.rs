@@ -24,6 +24,7 @@ mod navigate;
 mod optimize;
 mod purge_drop;
 mod purge_truncate;
+mod read_cache;
 mod read_plan;
 mod replace_into;
 mod table_analyze;
@@ -221,6 +221,7 @@ async fn test_safety() -> Result<()> {
             base_snapshot: Arc::new(snapshot),
             block_per_seg: 10,
             limit: Some(limit),
+            partition: None,
         };
 
         eprintln!("running target select");
@@ -250,6 +250,7 @@ async fn build_mutator(
         base_snapshot,
         block_per_seg,
         limit,
+        partition: None,
     };
 
     let mut segment_mutator = SegmentCompactMutator::try_create(
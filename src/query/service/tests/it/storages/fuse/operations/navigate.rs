@@ -86,6 +86,7 @@ async fn test_fuse_navigate() -> Result<()> {
             loc.clone(),
             version,
             fuse_table.meta_location_generator().clone(),
+            None,
         )
         .try_collect()
         .await?;
@@ -93,6 +94,20 @@ async fn test_fuse_navigate() -> Result<()> {
     // 3. there should be two snapshots
     assert_eq!(2, snapshots.len());
 
+    // 3.1 the history is read lazily, only the first snapshot is read with limit 1
+    let reader = MetaReaders::table_snapshot_reader(fuse_table.get_operator());
+    let limited: Vec<_> = reader
+        .snapshot_history(
+            loc.clone(),
+            version,
+            fuse_table.meta_location_generator().clone(),
+            Some(1),
+        )
+        .try_collect()
+        .await?;
+    assert_eq!(1, limited.len());
+    assert_eq!(snapshots[0].0.snapshot_id, limited[0].0.snapshot_id);
+
     // 4. navigate to the first snapshot
     // history is order by timestamp DESC
     let (latest, _ver) = &snapshots[0];
@@ -118,7 +133,14 @@ async fn test_fuse_navigate() -> Result<()> {
     let res = fuse_table.navigate_to_time_point(loc, instant).await;
     match res {
         Ok(_) => panic!("historical data should not exist"),
-        Err(e) => assert_eq!(e.code(), ErrorCode::TABLE_HISTORICAL_DATA_NOT_FOUND),
+        Err(e) => {
+            assert_eq!(e.code(), ErrorCode::TABLE_HISTORICAL_DATA_NOT_FOUND);
+            // the earliest data available is the first insertion
+            assert!(e.message().ends_with(&format!(
+                "the earliest data available is at {}",
+                first_insertion.timestamp.unwrap()
+            )));
+        }
     };
     Ok(())
 }
@@ -192,6 +214,7 @@ async fn test_navigate_for_purge() -> Result<()> {
             loc.clone(),
             version,
             fuse_table.meta_location_generator().clone(),
+            None,
         )
         .try_collect()
         .await?;
@@ -0,0 +1,145 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_expression::block_debug::pretty_format_blocks;
+use common_expression::DataBlock;
+use common_meta_app::storage::StorageFsConfig;
+use common_meta_app::storage::StorageParams;
+use databend_query::pipelines::processors::HashJoinBuildCache;
+use databend_query::test_kits::create_query_context_with_config;
+use databend_query::test_kits::table_test_fixture::execute_command;
+use databend_query::test_kits::table_test_fixture::execute_query;
+use databend_query::test_kits::table_test_fixture::TestFixture;
+use databend_query::test_kits::ConfigBuilder;
+use futures::TryStreamExt;
+use storages_common_cache::CacheAccessor;
+use storages_common_cache_manager::CacheManager;
+use tempfile::TempDir;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_table_data_block_cache() -> Result<()> {
+    let tmp_dir = TempDir::new().unwrap();
+    let mut conf = ConfigBuilder::create().config();
+    conf.storage.params = StorageParams::Fs(StorageFsConfig {
+        root: tmp_dir.path().to_str().unwrap().to_string(),
+    });
+    conf.cache.table_data_block_bytes = 64 * 1024 * 1024;
+
+    let (guard, ctx) = create_query_context_with_config(conf, None).await?;
+    let fixture = TestFixture::new_with_ctx(guard, ctx).await;
+    fixture.create_default_table().await?;
+
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let cache = CacheManager::instance()
+        .get_table_data_block_cache()
+        .unwrap();
+
+    execute_command(
+        fixture.ctx(),
+        &format!("insert into {db}.{tbl} values(1, (2, 3)), (4, (5, 6))"),
+    )
+    .await?;
+
+    let read_rows = |query: String| {
+        let ctx = fixture.ctx();
+        async move {
+            let blocks: Vec<DataBlock> = execute_query(ctx, &query).await?.try_collect().await?;
+            Result::Ok(blocks.iter().map(|b| b.num_rows()).sum::<usize>())
+        }
+    };
+
+    // scans with filter are not cached
+    let rows = read_rows(format!("select * from {db}.{tbl} where id > 1")).await?;
+    assert_eq!(rows, 1);
+    assert_eq!(cache.len(), 0);
+
+    // the full scan populates the cache, and the next one is served from it
+    for _ in 0..2 {
+        let rows = read_rows(format!("select * from {db}.{tbl}")).await?;
+        assert_eq!(rows, 2);
+        assert_eq!(cache.len(), 1);
+    }
+
+    // a different projection is cached separately
+    let rows = read_rows(format!("select id from {db}.{tbl}")).await?;
+    assert_eq!(rows, 2);
+    assert_eq!(cache.len(), 2);
+
+    // changes of the table are visible, since the cache is keyed by snapshot
+    execute_command(
+        fixture.ctx(),
+        &format!("insert into {db}.{tbl} values(7, (8, 9))"),
+    )
+    .await?;
+    let rows = read_rows(format!("select * from {db}.{tbl}")).await?;
+    assert_eq!(rows, 3);
+    assert_eq!(cache.len(), 3);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_hash_join_build_cache() -> Result<()> {
+    let tmp_dir = TempDir::new().unwrap();
+    let mut conf = ConfigBuilder::create().config();
+    conf.storage.params = StorageParams::Fs(StorageFsConfig {
+        root: tmp_dir.path().to_str().unwrap().to_string(),
+    });
+    conf.cache.hash_join_build_bytes = 64 * 1024 * 1024;
+
+    let (guard, ctx) = create_query_context_with_config(conf, None).await?;
+    let fixture = TestFixture::new_with_ctx(guard, ctx).await;
+    fixture.create_default_table().await?;
+
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let cache = HashJoinBuildCache::instance();
+
+    execute_command(
+        fixture.ctx(),
+        &format!("insert into {db}.{tbl} values(1, (2, 3)), (4, (5, 6))"),
+    )
+    .await?;
+
+    let join = |query: String| {
+        let ctx = fixture.ctx();
+        async move {
+            let blocks: Vec<DataBlock> = execute_query(ctx, &query).await?.try_collect().await?;
+            Result::Ok(pretty_format_blocks(&blocks)?)
+        }
+    };
+    let query = format!(
+        "select n.number, t.t from numbers(10) n join {db}.{tbl} t on n.number = t.id order by n.number"
+    );
+
+    // the hash table of the table is built once, and then taken from the cache
+    let expected = join(query.clone()).await?;
+    assert_eq!(cache.len(), 1);
+    assert_eq!(join(query.clone()).await?, expected);
+    assert_eq!(cache.len(), 1);
+
+    // changes of the table are visible, since the cache is keyed by snapshot
+    execute_command(
+        fixture.ctx(),
+        &format!("insert into {db}.{tbl} values(7, (8, 9))"),
+    )
+    .await?;
+    assert_ne!(join(query).await?, expected);
+    assert_eq!(cache.len(), 2);
+
+    Ok(())
+}
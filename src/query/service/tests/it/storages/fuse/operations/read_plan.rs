@@ -67,6 +67,7 @@ fn test_to_partitions() -> Result<()> {
         leaf_indices: vec![field_index],
         leaf_column_ids: vec![field_index as ColumnId],
         children: None,
+        prev_column_ids: vec![],
     };
 
     // generates fake data.
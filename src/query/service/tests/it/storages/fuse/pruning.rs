@@ -42,6 +42,7 @@ use databend_query::sessions::TableContext;
 use databend_query::storages::fuse::io::MetaReaders;
 use databend_query::storages::fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use databend_query::storages::fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
+use databend_query::test_kits::table_test_fixture::execute_command;
 use databend_query::test_kits::table_test_fixture::TestFixture;
 use opendal::Operator;
 use storages_common_cache::LoadParams;
@@ -102,6 +103,8 @@ async fn test_block_pruner() -> Result<()> {
         .into(),
         field_comments: vec![],
         as_select: None,
+        clone_from: None,
+        identity_sequences: vec![],
         cluster_key: None,
     };
 
@@ -257,3 +260,78 @@ async fn test_block_pruner() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_partition_pruner() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+
+    let qry = format!(
+        "create table {}.t_partition(a int, d date) partition by (to_yyyymm(d))",
+        db
+    );
+    execute_command(ctx.clone(), &qry).await?;
+    // each insertion writes a block for each of the partitions it touches.
+    for values in [
+        "(1, '2023-01-01'), (2, '2023-02-01'), (3, '2023-01-15')",
+        "(4, '2023-02-10'), (5, '2023-03-01')",
+    ] {
+        let qry = format!("insert into {}.t_partition values {}", db, values);
+        execute_command(ctx.clone(), &qry).await?;
+    }
+
+    let catalog = ctx.get_catalog("default").await?;
+    let table = catalog
+        .get_table(fixture.default_tenant().as_str(), &db, "t_partition")
+        .await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+    let partition_key = fuse_table.partition_key(ctx.clone())?;
+    assert!(partition_key.is_some());
+
+    let filters = vec![
+        (None, 4, 5),
+        (Some("to_yyyymm(d) = 202302"), 2, 2),
+        (Some("to_yyyymm(d) = 202303"), 1, 1),
+        (Some("to_yyyymm(d) > 202301"), 3, 3),
+        (Some("to_yyyymm(d) = 202304"), 0, 0),
+    ];
+
+    for (filter, expected_blocks, expected_rows) in filters {
+        let push_down = match filter {
+            Some(filter) => Some(PushDownInfo {
+                filter: Some(parse_to_remote_string_expr(
+                    ctx.clone(),
+                    table.clone(),
+                    filter,
+                )?),
+                ..Default::default()
+            }),
+            None => None,
+        };
+        let ctx: Arc<dyn TableContext> = ctx.clone();
+        let segment_locs = create_segment_location_vector(snapshot.segments.clone(), None);
+        let blocks = FusePruner::create_with_pages(
+            &ctx,
+            fuse_table.get_operator(),
+            table.get_table_info().schema(),
+            &push_down,
+            None,
+            vec![],
+            fuse_table.bloom_index_cols(),
+            partition_key.clone(),
+        )?
+        .read_pruning(segment_locs)
+        .await?;
+
+        let rows = blocks
+            .iter()
+            .map(|(_, b)| b.row_count as usize)
+            .sum::<usize>();
+        assert_eq!(expected_rows, rows, "filter: {:?}", filter);
+        assert_eq!(expected_blocks, blocks.len(), "filter: {:?}", filter);
+    }
+
+    Ok(())
+}
@@ -110,6 +110,21 @@ fn test_util_generate_parts() -> Result<()> {
         assert_eq!(numbers_part.part_end, 2);
         assert_eq!(numbers_part.total, 2);
     }
+    {
+        // start from the lower bound of the filter
+        let ps = generate_numbers_parts(5, 2, 12);
+
+        assert_eq!(2, ps.len());
+        let numbers_part = NumbersPartInfo::from_part(&ps.partitions[0])?;
+        assert_eq!(numbers_part.part_start, 5);
+        assert_eq!(numbers_part.part_end, 8);
+        assert_eq!(numbers_part.total, 12);
+
+        let numbers_part = NumbersPartInfo::from_part(&ps.partitions[1])?;
+        assert_eq!(numbers_part.part_start, 8);
+        assert_eq!(numbers_part.part_end, 12);
+        assert_eq!(numbers_part.total, 12);
+    }
 
     Ok(())
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::ops::RangeInclusive;
 use std::sync::Arc;
 
 use common_config::GlobalConfig;
@@ -28,9 +29,25 @@ pub struct DefaultSettingValue {
     pub(crate) value: UserSettingValue,
     pub(crate) desc: &'static str,
     pub(crate) possible_values: Option<Vec<&'static str>>,
+    pub(crate) range: Option<RangeInclusive<u64>>,
     pub(crate) display_in_show_settings: bool,
 }
 
+impl DefaultSettingValue {
+    pub(crate) fn check_range(&self, key: &str, val: u64) -> Result<()> {
+        match &self.range {
+            Some(range) if !range.contains(&val) => Err(ErrorCode::WrongValueForVariable(format!(
+                "Invalid setting value: {} for variable {:?}, value must be in range [{}, {}]",
+                val,
+                key,
+                range.start(),
+                range.end()
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct DefaultSettings {
     pub(crate) settings: HashMap<String, DefaultSettingValue>,
@@ -48,18 +65,21 @@ impl DefaultSettings {
                     value: UserSettingValue::UInt64(65536),
                     desc: "Sets the maximum byte size of a single data block that can be read.",
                     possible_values: None,
+                    range: Some(1..=u64::MAX),
                     display_in_show_settings: true,
                 }),
                 ("max_threads", DefaultSettingValue {
                     value: UserSettingValue::UInt64(num_cpus),
                     desc: "Sets the maximum number of threads to execute a request.",
                     possible_values: None,
+                    range: Some(0..=1024),
                     display_in_show_settings: true,
                 }),
                 ("max_memory_usage", DefaultSettingValue {
                     value: UserSettingValue::UInt64(max_memory_usage),
                     desc: "Sets the maximum memory usage in bytes for processing a single query.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("retention_period", DefaultSettingValue {
@@ -67,12 +87,14 @@ impl DefaultSettings {
                     value: UserSettingValue::UInt64(12),
                     desc: "Sets the retention period in hours.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("max_storage_io_requests", DefaultSettingValue {
                     value: UserSettingValue::UInt64(default_max_storage_io_requests),
                     desc: "Sets the maximum number of concurrent I/O requests.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("storage_io_min_bytes_for_seek", DefaultSettingValue {
@@ -80,156 +102,189 @@ impl DefaultSettings {
                     desc: "Sets the minimum byte size of data that must be read from storage in a single I/O operation \
                 when seeking a new location in the data file.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("storage_io_max_page_bytes_for_read", DefaultSettingValue {
                     value: UserSettingValue::UInt64(512 * 1024),
                     desc: "Sets the maximum byte size of data pages that can be read from storage in a single I/O operation.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("flight_client_timeout", DefaultSettingValue {
                     value: UserSettingValue::UInt64(60),
                     desc: "Sets the maximum time in seconds that a flight client request can be processed.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("storage_read_buffer_size", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1024 * 1024),
                     desc: "Sets the byte size of the buffer used for reading data into memory.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("input_read_buffer_size", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1024 * 1024),
                     desc: "Sets the memory size in bytes allocated to the buffer used by the buffered reader to read data from storage.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("timezone", DefaultSettingValue {
                     value: UserSettingValue::String("UTC".to_owned()),
                     desc: "Sets the timezone.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("group_by_two_level_threshold", DefaultSettingValue {
                     value: UserSettingValue::UInt64(20000),
                     desc: "Sets the number of keys in a GROUP BY operation that will trigger a two-level aggregation.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("max_inlist_to_or", DefaultSettingValue {
                     value: UserSettingValue::UInt64(3),
                     desc: "Sets the maximum number of values that can be included in an IN expression to be converted to an OR operator.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("unquoted_ident_case_sensitive", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Determines whether Databend treats unquoted identifiers as case-sensitive.",
                     possible_values: None,
+                    range: Some(0..=1),
                     display_in_show_settings: true,
                 }),
                 ("quoted_ident_case_sensitive", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Determines whether Databend treats quoted identifiers as case-sensitive.",
                     possible_values: None,
+                    range: Some(0..=1),
                     display_in_show_settings: true,
                 }),
                 ("sql_dialect", DefaultSettingValue {
                     value: UserSettingValue::String("PostgreSQL".to_owned()),
                     desc: "Sets the SQL dialect. Available values include \"PostgreSQL\", \"MySQL\", and \"Hive\".",
                     possible_values: Some(vec!["PostgreSQL", "MySQL", "Hive"]),
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("enable_dphyp", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables dphyp join order algorithm.",
                     possible_values: None,
+                    range: Some(0..=1),
                     display_in_show_settings: true,
                 }),
                 ("enable_cbo", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables cost-based optimization.",
                     possible_values: None,
+                    range: Some(0..=1),
                     display_in_show_settings: true,
                 }),
                 ("enable_runtime_filter", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enables runtime filter optimization for JOIN.",
                     possible_values: None,
+                    range: Some(0..=1),
                     display_in_show_settings: true,
                 }),
                 ("max_execute_time_in_seconds", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Sets the maximum query execution time in seconds. Setting it to 0 means no limit.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("collation", DefaultSettingValue {
                     value: UserSettingValue::String("binary".to_owned()),
                     desc: "Sets the character collation. Available values include \"binary\" and \"utf8\".",
                     possible_values: Some(vec!["binary", "utf8"]),
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("max_result_rows", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Sets the maximum number of rows that can be returned in a query result when no specific row count is specified. Setting it to 0 means no limit.",
                     possible_values: None,
+                    range: None,
+                    display_in_show_settings: true,
+                }),
+                ("max_estimated_scan_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum bytes a query is estimated to scan, the queries exceeding it are rejected before execution. Setting it to 0 means no limit.",
+                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("prefer_broadcast_join", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables broadcast join.",
                     possible_values: None,
+                    range: Some(0..=1),
                     display_in_show_settings: true,
                 }),
                 ("storage_fetch_part_num", DefaultSettingValue {
                     value: UserSettingValue::UInt64(2),
                     desc: "Sets the number of partitions that are fetched in parallel from storage during query execution.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("load_file_metadata_expire_hours", DefaultSettingValue {
                     value: UserSettingValue::UInt64(24 * 7),
                     desc: "Sets the hours that the metadata of files you load data from with COPY INTO will expire in.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("hide_options_in_show_create_table", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Hides table-relevant information, such as SNAPSHOT_LOCATION and STORAGE_FORMAT, at the end of the result of SHOW TABLE CREATE.",
                     possible_values: None,
+                    range: Some(0..=1),
                     display_in_show_settings: true,
                 }),
                 ("sandbox_tenant", DefaultSettingValue {
                     value: UserSettingValue::String("".to_string()),
                     desc: "Injects a custom 'sandbox_tenant' into this session. This is only for testing purposes and will take effect only when 'internal_enable_sandbox_tenant' is turned on.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("parquet_uncompressed_buffer_size", DefaultSettingValue {
                     value: UserSettingValue::UInt64(2 * 1024 * 1024),
                     desc: "Sets the byte size of the buffer used for reading Parquet files.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("enable_bushy_join", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enables generating a bushy join plan with the optimizer.",
                     possible_values: None,
+                    range: Some(0..=1),
                     display_in_show_settings: true,
                 }),
                 ("enable_query_result_cache", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enables caching query results to improve performance for identical queries.",
                     possible_values: None,
+                    range: Some(0..=1),
                     display_in_show_settings: true,
                 }),
                 ("query_result_cache_max_bytes", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1048576), // 1MB
                     desc: "Sets the maximum byte size of cache for a single query result.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("query_result_cache_ttl_secs", DefaultSettingValue {
@@ -237,60 +292,70 @@ impl DefaultSettings {
                     desc: "Sets the time-to-live (TTL) in seconds for cached query results. \
                 Once the TTL for a cached result has expired, the result is considered stale and will not be used for new queries.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("query_result_cache_allow_inconsistent", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Determines whether Databend will return cached query results that are inconsistent with the underlying data.",
                     possible_values: None,
+                    range: Some(0..=1),
                     display_in_show_settings: true,
                 }),
                 ("enable_hive_parquet_predict_pushdown", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enable hive parquet predict pushdown  by setting this variable to 1, default value: 1",
                     possible_values: None,
+                    range: Some(0..=1),
                     display_in_show_settings: true,
                 }),
                 ("hive_parquet_chunk_size", DefaultSettingValue {
                     value: UserSettingValue::UInt64(16384),
                     desc: "the max number of rows each read from parquet to databend processor",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("spilling_bytes_threshold_per_proc", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Sets the maximum amount of memory in bytes that an aggregator can use before spilling data to storage during query execution.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("spilling_memory_ratio", DefaultSettingValue {
                     value: UserSettingValue::UInt64(100),
                     desc: "Sets the maximum memory ratio in bytes that an aggregator can use before spilling data to storage during query execution.",
                     possible_values: None,
+                    range: Some(0..=100),
                     display_in_show_settings: true,
                 }),
                 ("group_by_shuffle_mode", DefaultSettingValue {
                     value: UserSettingValue::String(String::from("before_merge")),
                     desc: "Group by shuffle mode, 'before_partial' is more balanced, but more data needs to exchange.",
                     possible_values: Some(vec!["before_partial", "before_merge"]),
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("efficiently_memory_group_by", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Memory is used efficiently, but this may cause performance degradation.",
                     possible_values: None,
+                    range: Some(0..=1),
                     display_in_show_settings: true,
                 }),
                 ("lazy_read_threshold", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1000),
                     desc: "Sets the maximum LIMIT in a query to enable lazy read optimization. Setting it to 0 disables the optimization.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("parquet_fast_read_bytes", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Parquet file with smaller size will be read as a whole file, instead of column by column.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
 
@@ -299,6 +364,7 @@ impl DefaultSettings {
                     value: UserSettingValue::String("".to_owned()),
                     desc: "License key for use enterprise features",
                     possible_values: None,
+                    range: None,
                     // license key should not be reported
                     display_in_show_settings: false,
                 }),
@@ -306,36 +372,42 @@ impl DefaultSettings {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables table lock if necessary (enabled by default).",
                     possible_values: None,
+                    range: Some(0..=1),
                     display_in_show_settings: true,
                 }),
                 ("table_lock_expire_secs", DefaultSettingValue {
                     value: UserSettingValue::UInt64(5),
                     desc: "Sets the seconds that the table lock will expire in.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("deduplicate_label", DefaultSettingValue {
                     value: UserSettingValue::String("".to_owned()),
                     desc: "Sql duplicate label for deduplication.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: false,
                 }),
                 ("enable_distributed_copy_into", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enable distributed execution of copy into.",
                     possible_values: None,
+                    range: Some(0..=1),
                     display_in_show_settings: true,
                 }),
                 ("enable_distributed_replace_into", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enable distributed execution of replace into.",
                     possible_values: None,
+                    range: Some(0..=1),
                     display_in_show_settings: true,
                 }),
                 ("enable_aggregating_index_scan", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enable scanning aggregating index data while querying.",
                     possible_values: None,
+                    range: Some(0..=1),
                     display_in_show_settings: true,
                 }),
 
@@ -343,48 +415,154 @@ impl DefaultSettings {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables re-clustering after write(copy/replace-into).",
                     possible_values: None,
+                    range: Some(0..=1),
+                    display_in_show_settings: true,
+                }),
+                ("enable_compact_after_write", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables compacting small blocks and segments in the background after write(insert/copy/replace-into).",
+                    possible_values: None,
+                    range: Some(0..=1),
+                    display_in_show_settings: true,
+                }),
+                ("compact_after_write_min_interval_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(60),
+                    desc: "Sets the minimum seconds between two background compactions of the same table.",
+                    possible_values: None,
+                    range: None,
+                    display_in_show_settings: true,
+                }),
+                ("insert_commit_coalesce_window_ms", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the milliseconds an INSERT may defer its commit to be coalesced with other INSERTs into the same table, 0 disables it.",
+                    possible_values: None,
+                    range: None,
+                    display_in_show_settings: true,
+                }),
+                ("insert_commit_coalesce_max_rows", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1000000),
+                    desc: "Sets the maximum rows of coalesced INSERTs waiting for commit, reaching it commits them immediately.",
+                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("use_parquet2", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Use parquet2 instead of parquet_rs when infer_schema().",
                     possible_values: None,
+                    range: Some(0..=1),
                     display_in_show_settings: true,
                 }),
                 ("enable_replace_into_partitioning", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables partitioning for replace-into statement (if table has cluster keys).",
                     possible_values: None,
+                    range: Some(0..=1),
                     display_in_show_settings: true,
                 }),
                 ("enable_replace_into_bloom_pruning", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables bloom pruning for replace-into statement.",
                     possible_values: None,
+                    range: Some(0..=1),
                     display_in_show_settings: true,
                 }),
                 ("replace_into_bloom_pruning_max_column_number", DefaultSettingValue {
                     value: UserSettingValue::UInt64(4),
                     desc: "Max number of columns used by bloom pruning for replace-into statement.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("replace_into_shuffle_strategy", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "0 for Block level shuffle, 1 for segment level shuffle",
                     possible_values: None,
+                    range: Some(0..=1),
                     display_in_show_settings: true,
                 }),
                 ("recluster_timeout_secs", DefaultSettingValue {
                     value: UserSettingValue::UInt64(12 * 60 * 60),
                     desc: "Sets the seconds that recluster final will be timeout.",
                     possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("enable_refresh_aggregating_index_after_write", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Refresh aggregating index after new data written",
                     possible_values: None,
+                    range: Some(0..=1),
+                    display_in_show_settings: true,
+                }),
+                ("deletion_vector_max_percent", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum percentage of rows deleted from a block for which the deletion is recorded in a deletion vector instead of rewriting the block, 0 disables deletion vectors.",
+                    possible_values: None,
+                    range: Some(0..=100),
+                    display_in_show_settings: true,
+                }),
+                ("data_checksum_verification", DefaultSettingValue {
+                    value: UserSettingValue::String("off".to_owned()),
+                    desc: "Sets how the checksums of block data and segments are verified on read. Available values include \"off\", \"sample\" and \"always\".",
+                    possible_values: Some(vec!["off", "sample", "always"]),
+                    range: None,
+                    display_in_show_settings: true,
+                }),
+                ("table_data_block_cache_max_table_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(64 * 1024 * 1024),
+                    desc: "Sets the maximum byte size of a table whose decoded blocks can be kept in the table data block cache, 0 disables caching the blocks of the table.",
+                    possible_values: None,
+                    range: None,
+                    display_in_show_settings: true,
+                }),
+                ("hash_join_build_cache_max_table_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(64 * 1024 * 1024),
+                    desc: "Sets the maximum byte size of a table on the build side of a hash join whose hash table can be kept in the hash join build cache, 0 disables caching the hash table.",
+                    possible_values: None,
+                    range: None,
+                    display_in_show_settings: true,
+                }),
+                ("udf_server_connect_timeout_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(10),
+                    desc: "Sets the timeout in seconds for connecting to a UDF server.",
+                    possible_values: None,
+                    range: Some(1..=u64::MAX),
+                    display_in_show_settings: true,
+                }),
+                ("udf_server_request_timeout_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(180),
+                    desc: "Sets the timeout in seconds for a request to a UDF server.",
+                    possible_values: None,
+                    range: Some(1..=u64::MAX),
+                    display_in_show_settings: true,
+                }),
+                ("udf_server_request_max_retries", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(3),
+                    desc: "Sets the maximum number of times a failed request to a UDF server is retried.",
+                    possible_values: None,
+                    range: None,
+                    display_in_show_settings: true,
+                }),
+                ("udf_server_request_batch_rows", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(65536),
+                    desc: "Sets the maximum number of rows sent to a UDF server in a request.",
+                    possible_values: None,
+                    range: Some(1..=u64::MAX),
+                    display_in_show_settings: true,
+                }),
+                ("max_string_result_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(256 * 1024 * 1024),
+                    desc: "Sets the maximum size in bytes of a string generated by functions like repeat and space.",
+                    possible_values: None,
+                    range: None,
+                    display_in_show_settings: true,
+                }),
+                ("sequence_cache_size", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1000),
+                    desc: "Sets the minimum number of sequence numbers allocated from the meta service at once and cached by nextval.",
+                    possible_values: None,
+                    range: Some(1..=u64::MAX),
                     display_in_show_settings: true,
                 }),
             ]);
@@ -480,6 +658,7 @@ impl DefaultSettings {
                         };
 
                         let u64_val = val.parse::<u64>()?;
+                        setting_value.check_range(&k, u64_val)?;
                         Ok((k, Some(UserSettingValue::UInt64(u64_val))))
                     }
                     UserSettingValue::String(_) => Ok((k, Some(UserSettingValue::String(v)))),
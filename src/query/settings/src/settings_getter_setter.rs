@@ -53,6 +53,7 @@ impl Settings {
                     )));
                 }
 
+                default_val.check_range(key, val)?;
                 self.changes.insert(key.to_string(), ChangeValue {
                     level: ScopeLevel::Session,
                     value: UserSettingValue::UInt64(val),
@@ -216,6 +217,10 @@ impl Settings {
         self.try_get_u64("max_result_rows")
     }
 
+    pub fn get_max_estimated_scan_bytes(&self) -> Result<u64> {
+        self.try_get_u64("max_estimated_scan_bytes")
+    }
+
     pub fn get_enable_dphyp(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_dphyp")? != 0)
     }
@@ -455,6 +460,22 @@ impl Settings {
         self.try_set_u64("recluster_timeout_secs", val)
     }
 
+    pub fn get_enable_compact_after_write(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_compact_after_write")? != 0)
+    }
+
+    pub fn get_compact_after_write_min_interval_secs(&self) -> Result<u64> {
+        self.try_get_u64("compact_after_write_min_interval_secs")
+    }
+
+    pub fn get_insert_commit_coalesce_window_ms(&self) -> Result<u64> {
+        self.try_get_u64("insert_commit_coalesce_window_ms")
+    }
+
+    pub fn get_insert_commit_coalesce_max_rows(&self) -> Result<u64> {
+        self.try_get_u64("insert_commit_coalesce_max_rows")
+    }
+
     pub fn get_enable_refresh_aggregating_index_after_write(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_refresh_aggregating_index_after_write")? != 0)
     }
@@ -465,4 +486,44 @@ impl Settings {
             u64::from(val),
         )
     }
+
+    pub fn get_deletion_vector_max_percent(&self) -> Result<u64> {
+        self.try_get_u64("deletion_vector_max_percent")
+    }
+
+    pub fn get_data_checksum_verification(&self) -> Result<String> {
+        self.try_get_string("data_checksum_verification")
+    }
+
+    pub fn get_table_data_block_cache_max_table_bytes(&self) -> Result<u64> {
+        self.try_get_u64("table_data_block_cache_max_table_bytes")
+    }
+
+    pub fn get_hash_join_build_cache_max_table_bytes(&self) -> Result<u64> {
+        self.try_get_u64("hash_join_build_cache_max_table_bytes")
+    }
+
+    pub fn get_udf_server_connect_timeout_secs(&self) -> Result<u64> {
+        self.try_get_u64("udf_server_connect_timeout_secs")
+    }
+
+    pub fn get_udf_server_request_timeout_secs(&self) -> Result<u64> {
+        self.try_get_u64("udf_server_request_timeout_secs")
+    }
+
+    pub fn get_udf_server_request_max_retries(&self) -> Result<u64> {
+        self.try_get_u64("udf_server_request_max_retries")
+    }
+
+    pub fn get_udf_server_request_batch_rows(&self) -> Result<u64> {
+        self.try_get_u64("udf_server_request_batch_rows")
+    }
+
+    pub fn get_max_string_result_bytes(&self) -> Result<u64> {
+        self.try_get_u64("max_string_result_bytes")
+    }
+
+    pub fn get_sequence_cache_size(&self) -> Result<u64> {
+        self.try_get_u64("sequence_cache_size")
+    }
 }
@@ -56,6 +56,44 @@ impl Settings {
             .await
     }
 
+    /// Drops the session level value of the setting, falling back to the global value
+    /// persisted in the meta service if there is one, otherwise to the default value.
+    /// Returns the value that takes effect after unsetting.
+    #[async_backtrace::framed]
+    pub async fn try_unset_session_setting(&self, key: &str) -> Result<UserSettingValue> {
+        let default_settings = DefaultSettings::instance()?;
+        let default_value = match default_settings.settings.get(key) {
+            None => {
+                return Err(ErrorCode::UnknownVariable(format!(
+                    "Unknown variable: {:?}",
+                    key
+                )));
+            }
+            Some(default_value) => default_value.value.clone(),
+        };
+
+        self.changes.remove(key);
+
+        let api = UserApiProvider::instance();
+        let global_settings = Settings::load_settings(api, self.tenant.clone()).await?;
+        for global_setting in global_settings {
+            if global_setting.name != key {
+                continue;
+            }
+
+            let val = global_setting.value.as_string()?;
+            if let (_, Some(value)) = DefaultSettings::convert_value(key.to_string(), val)? {
+                self.changes.insert(key.to_string(), ChangeValue {
+                    level: ScopeLevel::Global,
+                    value: value.clone(),
+                });
+                return Ok(value);
+            }
+        }
+
+        Ok(default_value)
+    }
+
     #[async_backtrace::framed]
     pub async fn set_global_setting(&self, k: String, v: String) -> Result<()> {
         if let (key, Some(value)) = DefaultSettings::convert_value(k.clone(), v)? {
@@ -108,10 +146,18 @@ impl Settings {
                         }
 
                         match &default_setting_value.value {
-                            UserSettingValue::UInt64(_) => ChangeValue {
-                                level: ScopeLevel::Global,
-                                value: UserSettingValue::UInt64(val.parse::<u64>()?),
-                            },
+                            UserSettingValue::UInt64(_) => {
+                                let u64_val = val.parse::<u64>()?;
+                                if default_setting_value.check_range(&name, u64_val).is_err() {
+                                    warn!("Ignore out of range global setting {} = {}", name, val);
+                                    continue;
+                                }
+
+                                ChangeValue {
+                                    level: ScopeLevel::Global,
+                                    value: UserSettingValue::UInt64(u64_val),
+                                }
+                            }
                             UserSettingValue::String(_) => ChangeValue {
                                 level: ScopeLevel::Global,
                                 value: UserSettingValue::String(val.clone()),
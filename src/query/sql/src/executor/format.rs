@@ -24,6 +24,7 @@ use super::AggregateExpand;
 use super::AggregateFinal;
 use super::AggregateFunctionDesc;
 use super::AggregatePartial;
+use super::AggregateStreaming;
 use super::CopyIntoTable;
 use super::DeletePartial;
 use super::EvalScalar;
@@ -39,6 +40,7 @@ use super::ProjectSet;
 use super::RowFetch;
 use super::Sort;
 use super::TableScan;
+use super::Udf;
 use super::UnionAll;
 use super::WindowFunction;
 use crate::executor::explain::PlanStatsInfo;
@@ -183,6 +185,9 @@ fn to_format_tree(
             aggregate_partial_to_format_tree(plan, metadata, profs)
         }
         PhysicalPlan::AggregateFinal(plan) => aggregate_final_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::AggregateStreaming(plan) => {
+            aggregate_streaming_to_format_tree(plan, metadata, profs)
+        }
         PhysicalPlan::Window(plan) => window_to_format_tree(plan, metadata, profs),
         PhysicalPlan::Sort(plan) => sort_to_format_tree(plan, metadata, profs),
         PhysicalPlan::Limit(plan) => limit_to_format_tree(plan, metadata, profs),
@@ -203,6 +208,7 @@ fn to_format_tree(
         }
         PhysicalPlan::ProjectSet(plan) => project_set_to_format_tree(plan, metadata, profs),
         PhysicalPlan::Lambda(plan) => lambda_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::Udf(plan) => udf_to_format_tree(plan, metadata, profs),
         PhysicalPlan::RuntimeFilterSource(plan) => {
             runtime_filter_source_to_format_tree(plan, metadata, profs)
         }
@@ -632,6 +638,52 @@ fn aggregate_final_to_format_tree(
     ))
 }
 
+fn aggregate_streaming_to_format_tree(
+    plan: &AggregateStreaming,
+    metadata: &Metadata,
+    prof_span_set: &SharedProcessorProfiles,
+) -> Result<FormatTreeNode<String>> {
+    let group_by = plan
+        .group_by
+        .iter()
+        .map(|&index| {
+            let name = metadata.column(index).name();
+            Ok(name)
+        })
+        .collect::<Result<Vec<_>>>()?
+        .join(", ");
+
+    let agg_funcs = plan
+        .agg_funcs
+        .iter()
+        .map(|agg| pretty_display_agg_desc(agg, metadata))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("group by: [{group_by}]")),
+        FormatTreeNode::new(format!("aggregate functions: [{agg_funcs}]")),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+
+    Ok(FormatTreeNode::with_children(
+        "AggregateStreaming".to_string(),
+        children,
+    ))
+}
+
 fn window_to_format_tree(
     plan: &Window,
     metadata: &Metadata,
@@ -1151,6 +1203,37 @@ fn lambda_to_format_tree(
     ))
 }
 
+fn udf_to_format_tree(
+    plan: &Udf,
+    metadata: &Metadata,
+    prof_span_set: &SharedProcessorProfiles,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!(
+        "output columns: [{}]",
+        format_output_columns(plan.output_schema()?, metadata, true)
+    ))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    children.extend(vec![FormatTreeNode::new(format!(
+        "udf functions: {}",
+        plan.udf_funcs
+            .iter()
+            .map(|func| format!("{}({})", func.func_name, func.arg_exprs.join(", ")))
+            .collect::<Vec<_>>()
+            .join(", ")
+    ))]);
+
+    children.extend(vec![to_format_tree(&plan.input, metadata, prof_span_set)?]);
+
+    Ok(FormatTreeNode::with_children("Udf".to_string(), children))
+}
+
 fn runtime_filter_source_to_format_tree(
     plan: &RuntimeFilterSource,
     metadata: &Metadata,
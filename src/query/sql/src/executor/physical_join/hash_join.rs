@@ -92,7 +92,7 @@ impl PhysicalPlanBuilder {
         }
 
         let build_schema = match join.join_type {
-            JoinType::Left | JoinType::LeftSingle | JoinType::Full => {
+            JoinType::Left | JoinType::LeftSingle | JoinType::Full | JoinType::LeftAsof => {
                 let build_schema = build_side.output_schema()?;
                 // Wrap nullable type for columns in build side.
                 let build_schema = DataSchemaRefExt::create(
@@ -270,7 +270,9 @@ impl PhysicalPlanBuilder {
             | JoinType::LeftSingle
             | JoinType::Right
             | JoinType::RightSingle
-            | JoinType::Full => {
+            | JoinType::Full
+            | JoinType::Asof
+            | JoinType::LeftAsof => {
                 probe_fields.extend(build_fields);
                 probe_fields
             }
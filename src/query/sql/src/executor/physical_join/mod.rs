@@ -52,7 +52,12 @@ pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
         )
     }
 
-    if !range_conditions.is_empty() && matches!(join.join_type, JoinType::Inner | JoinType::Cross) {
+    if !range_conditions.is_empty()
+        && matches!(
+            join.join_type,
+            JoinType::Inner | JoinType::Cross | JoinType::Left | JoinType::Right | JoinType::Full
+        )
+    {
         return Ok(PhysicalJoinType::RangeJoin(
             range_conditions,
             other_conditions,
@@ -85,6 +90,7 @@ fn check_condition(
                 JoinPredicate::Left(_) => left = true,
                 JoinPredicate::Right(_) => right = true,
                 JoinPredicate::Both { .. } | JoinPredicate::Other(_) => {
+                    other_conditions.push(expr.clone());
                     return;
                 }
             }
@@ -16,7 +16,6 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::type_check::common_super_type;
 use common_expression::DataSchemaRef;
-use common_expression::DataSchemaRefExt;
 use common_expression::RemoteExpr;
 use common_functions::BUILTIN_FUNCTIONS;
 
@@ -31,6 +30,7 @@ use crate::optimizer::ColumnSet;
 use crate::optimizer::RelExpr;
 use crate::optimizer::RelationalProperty;
 use crate::optimizer::SExpr;
+use crate::plans::Join;
 use crate::plans::JoinType;
 use crate::ScalarExpr;
 use crate::TypeCheck;
@@ -38,6 +38,7 @@ use crate::TypeCheck;
 impl PhysicalPlanBuilder {
     pub async fn build_range_join(
         &mut self,
+        join: &Join,
         s_expr: &SExpr,
         left_required: ColumnSet,
         right_required: ColumnSet,
@@ -59,47 +60,49 @@ impl PhysicalPlanBuilder {
             RangeJoinType::Merge
         };
 
+        let join_type = match &join.join_type {
+            JoinType::Cross => JoinType::Inner,
+            join_type => join_type.clone(),
+        };
+
         // Construct IEJoin
         let left_side = self.build(s_expr.child(1)?, left_required).await?;
         let right_side = self.build(s_expr.child(0)?, right_required).await?;
 
-        let left_schema = left_side.output_schema()?;
-        let right_schema = right_side.output_schema()?;
-
-        let merged_schema = DataSchemaRefExt::create(
-            left_side
-                .output_schema()?
-                .fields()
-                .iter()
-                .chain(right_side.output_schema()?.fields())
-                .cloned()
-                .collect::<Vec<_>>(),
-        );
-
-        Ok(PhysicalPlan::RangeJoin(RangeJoin {
+        let mut range_join = RangeJoin {
             plan_id: self.next_plan_id(),
             left: Box::new(left_side),
             right: Box::new(right_side),
-            conditions: range_conditions
-                .iter()
-                .map(|scalar| {
-                    resolve_range_condition(
-                        scalar,
-                        &left_schema,
-                        &right_schema,
-                        &left_prop,
-                        &right_prop,
-                    )
-                })
-                .collect::<Result<_>>()?,
-            other_conditions: other_conditions
-                .iter()
-                .map(|scalar| resolve_scalar(scalar, &merged_schema))
-                .collect::<Result<_>>()?,
-            join_type: JoinType::Inner,
+            conditions: vec![],
+            other_conditions: vec![],
+            join_type,
             range_join_type,
             stat_info: Some(self.build_plan_stat_info(s_expr)?),
-        }))
+        };
+
+        // The columns of the side whose rows are not preserved by outer join are nullable.
+        let left_schema = range_join.left_schema()?;
+        let right_schema = range_join.right_schema()?;
+        let merged_schema = range_join.output_schema()?;
+
+        range_join.conditions = range_conditions
+            .iter()
+            .map(|scalar| {
+                resolve_range_condition(
+                    scalar,
+                    &left_schema,
+                    &right_schema,
+                    &left_prop,
+                    &right_prop,
+                )
+            })
+            .collect::<Result<_>>()?;
+        range_join.other_conditions = other_conditions
+            .iter()
+            .map(|scalar| resolve_scalar(scalar, &merged_schema))
+            .collect::<Result<_>>()?;
+
+        Ok(PhysicalPlan::RangeJoin(range_join))
     }
 }
 
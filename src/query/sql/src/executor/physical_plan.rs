@@ -53,6 +53,7 @@ use crate::optimizer::ColumnSet;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::JoinType;
 use crate::plans::RuntimeFilterId;
+use crate::plans::UDFType;
 use crate::plans::ValidationMode;
 use crate::plans::WindowFuncFrame;
 use crate::ColumnBinding;
@@ -400,6 +401,41 @@ impl AggregateFinal {
     }
 }
 
+/// Aggregate the input which is sorted by the group keys, the rows of a group are
+/// adjacent, so only the states of the current group need to be kept.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct AggregateStreaming {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub input: Box<PhysicalPlan>,
+    pub group_by: Vec<IndexType>,
+    pub agg_funcs: Vec<AggregateFunctionDesc>,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl AggregateStreaming {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = Vec::with_capacity(self.agg_funcs.len() + self.group_by.len());
+        for agg in self.agg_funcs.iter() {
+            let data_type = agg.sig.return_type()?;
+            fields.push(DataField::new(&agg.output_column.to_string(), data_type));
+        }
+        for id in self.group_by.iter() {
+            let data_type = input_schema
+                .field_with_name(&id.to_string())?
+                .data_type()
+                .clone();
+            fields.push(DataField::new(&id.to_string(), data_type));
+        }
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub enum WindowFunction {
     Aggregate(AggregateFunctionDesc),
@@ -508,6 +544,43 @@ impl Lambda {
     }
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct UdfFunctionDesc {
+    pub func_name: String,
+    pub output_column: IndexType,
+    pub arg_indices: Vec<IndexType>,
+    pub arg_exprs: Vec<String>,
+    pub handler: String,
+    pub udf_type: UDFType,
+    pub data_type: Box<DataType>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Udf {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub input: Box<PhysicalPlan>,
+    pub udf_funcs: Vec<UdfFunctionDesc>,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl Udf {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = input_schema.fields().clone();
+        for udf_func in self.udf_funcs.iter() {
+            let name = udf_func.output_column.to_string();
+            let data_type = udf_func.data_type.clone();
+            fields.push(DataField::new(&name, *data_type));
+        }
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct Sort {
     /// A unique id of operator in a `PhysicalPlan` tree.
@@ -650,7 +723,8 @@ pub struct RangeJoin {
     pub conditions: Vec<RangeJoinCondition>,
     /// The other conditions
     pub other_conditions: Vec<RemoteExpr>,
-    /// Now only support inner join, will support left/right join later
+    /// Inner, left, right or full join.
+    /// Note that `left` is the right child of the join, so a left join preserves the rows of `right`.
     pub join_type: JoinType,
     pub range_join_type: RangeJoinType,
 
@@ -660,10 +734,38 @@ pub struct RangeJoin {
 
 impl RangeJoin {
     pub fn output_schema(&self) -> Result<DataSchemaRef> {
-        let mut fields = self.left.output_schema()?.fields().clone();
-        fields.extend(self.right.output_schema()?.fields().clone());
+        let mut fields = self.left_schema()?.fields().clone();
+        fields.extend(self.right_schema()?.fields().clone());
         Ok(DataSchemaRefExt::create(fields))
     }
+
+    /// Schema of `left`, the columns are nullable if the rows of `right` are preserved.
+    pub fn left_schema(&self) -> Result<DataSchemaRef> {
+        let schema = self.left.output_schema()?;
+        match self.join_type {
+            JoinType::Left | JoinType::Full => Ok(wrap_nullable_schema(&schema)),
+            _ => Ok(schema),
+        }
+    }
+
+    /// Schema of `right`, the columns are nullable if the rows of `left` are preserved.
+    pub fn right_schema(&self) -> Result<DataSchemaRef> {
+        let schema = self.right.output_schema()?;
+        match self.join_type {
+            JoinType::Right | JoinType::Full => Ok(wrap_nullable_schema(&schema)),
+            _ => Ok(schema),
+        }
+    }
+}
+
+fn wrap_nullable_schema(schema: &DataSchemaRef) -> DataSchemaRef {
+    DataSchemaRefExt::create(
+        schema
+            .fields()
+            .iter()
+            .map(|field| DataField::new(field.name(), field.data_type().wrap_nullable()))
+            .collect::<Vec<_>>(),
+    )
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -971,8 +1073,10 @@ pub enum PhysicalPlan {
     AggregateExpand(AggregateExpand),
     AggregatePartial(AggregatePartial),
     AggregateFinal(AggregateFinal),
+    AggregateStreaming(AggregateStreaming),
     Window(Window),
     Lambda(Lambda),
+    Udf(Udf),
     Sort(Sort),
     Limit(Limit),
     RowFetch(RowFetch),
@@ -1022,8 +1126,10 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateExpand(v) => v.plan_id,
             PhysicalPlan::AggregatePartial(v) => v.plan_id,
             PhysicalPlan::AggregateFinal(v) => v.plan_id,
+            PhysicalPlan::AggregateStreaming(v) => v.plan_id,
             PhysicalPlan::Window(v) => v.plan_id,
             PhysicalPlan::Lambda(v) => v.plan_id,
+            PhysicalPlan::Udf(v) => v.plan_id,
             PhysicalPlan::Sort(v) => v.plan_id,
             PhysicalPlan::Limit(v) => v.plan_id,
             PhysicalPlan::RowFetch(v) => v.plan_id,
@@ -1058,8 +1164,10 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateExpand(plan) => plan.output_schema(),
             PhysicalPlan::AggregatePartial(plan) => plan.output_schema(),
             PhysicalPlan::AggregateFinal(plan) => plan.output_schema(),
+            PhysicalPlan::AggregateStreaming(plan) => plan.output_schema(),
             PhysicalPlan::Window(plan) => plan.output_schema(),
             PhysicalPlan::Lambda(plan) => plan.output_schema(),
+            PhysicalPlan::Udf(plan) => plan.output_schema(),
             PhysicalPlan::Sort(plan) => plan.output_schema(),
             PhysicalPlan::Limit(plan) => plan.output_schema(),
             PhysicalPlan::RowFetch(plan) => plan.output_schema(),
@@ -1093,8 +1201,10 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateExpand(_) => "AggregateExpand".to_string(),
             PhysicalPlan::AggregatePartial(_) => "AggregatePartial".to_string(),
             PhysicalPlan::AggregateFinal(_) => "AggregateFinal".to_string(),
+            PhysicalPlan::AggregateStreaming(_) => "AggregateStreaming".to_string(),
             PhysicalPlan::Window(_) => "Window".to_string(),
             PhysicalPlan::Lambda(_) => "Lambda".to_string(),
+            PhysicalPlan::Udf(_) => "Udf".to_string(),
             PhysicalPlan::Sort(_) => "Sort".to_string(),
             PhysicalPlan::Limit(_) => "Limit".to_string(),
             PhysicalPlan::RowFetch(_) => "RowFetch".to_string(),
@@ -1130,8 +1240,12 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateExpand(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::AggregatePartial(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::AggregateFinal(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::AggregateStreaming(plan) => {
+                Box::new(std::iter::once(plan.input.as_ref()))
+            }
             PhysicalPlan::Window(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Lambda(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::Udf(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Sort(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Limit(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::RowFetch(plan) => Box::new(std::iter::once(plan.input.as_ref())),
@@ -1176,6 +1290,7 @@ impl PhysicalPlan {
             PhysicalPlan::EvalScalar(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::Window(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::Lambda(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::Udf(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::Sort(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::Limit(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::Exchange(plan) => plan.input.try_find_single_data_source(),
@@ -1192,6 +1307,7 @@ impl PhysicalPlan {
             | PhysicalPlan::AggregateExpand(_)
             | PhysicalPlan::AggregateFinal(_)
             | PhysicalPlan::AggregatePartial(_)
+            | PhysicalPlan::AggregateStreaming(_)
             | PhysicalPlan::DeletePartial(_)
             | PhysicalPlan::MutationAggregate(_)
             | PhysicalPlan::CopyIntoTable(_)
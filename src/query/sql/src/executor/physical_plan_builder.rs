@@ -28,6 +28,7 @@ use common_catalog::plan::VirtualColumnInfo;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::is_internal_column_id;
 use common_expression::type_check;
 use common_expression::type_check::check_function;
 use common_expression::type_check::common_super_type;
@@ -43,6 +44,7 @@ use common_expression::RemoteExpr;
 use common_expression::TableSchema;
 use common_expression::ROW_ID_COL_NAME;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_profile::ColumnUsageManager;
 use itertools::Itertools;
 
 use super::cast_expr_to_non_null_boolean;
@@ -51,6 +53,7 @@ use super::AggregateFinal;
 use super::AggregateFunctionDesc;
 use super::AggregateFunctionSignature;
 use super::AggregatePartial;
+use super::AggregateStreaming;
 use super::EvalScalar;
 use super::Exchange as PhysicalExchange;
 use super::Filter;
@@ -61,6 +64,7 @@ use super::ProjectSet;
 use super::RowFetch;
 use super::Sort;
 use super::TableScan;
+use super::Udf;
 use super::WindowFunction;
 use crate::binder::wrap_cast;
 use crate::binder::ColumnBindingBuilder;
@@ -80,6 +84,7 @@ use crate::executor::PhysicalJoinType;
 use crate::executor::PhysicalPlan;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::SortDesc;
+use crate::executor::UdfFunctionDesc;
 use crate::executor::UnionAll;
 use crate::executor::Window;
 use crate::optimizer::ColumnSet;
@@ -97,6 +102,7 @@ use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::Scan;
+use crate::plans::UDFType;
 use crate::plans::Window as LogicalWindow;
 use crate::plans::WindowFuncFrameBound;
 use crate::plans::WindowFuncType;
@@ -225,8 +231,15 @@ impl PhysicalPlanBuilder {
                         .await
                     }
                     PhysicalJoinType::RangeJoin(range, other) => {
-                        self.build_range_join(s_expr, left_required, right_required, range, other)
-                            .await
+                        self.build_range_join(
+                            join,
+                            s_expr,
+                            left_required,
+                            right_required,
+                            range,
+                            other,
+                        )
+                        .await
                     }
                 }
             }
@@ -530,6 +543,25 @@ impl PhysicalPlanBuilder {
                         }).collect::<Result<_>>()?;
 
                         match input {
+                            // If the input is sorted by the group keys, the rows of a group are adjacent,
+                            // aggregate the input directly instead of building a hash table.
+                            PhysicalPlan::AggregatePartial(partial)
+                                if !group_items.is_empty()
+                                    && !matches!(
+                                        *partial.input,
+                                        PhysicalPlan::AggregateExpand(_)
+                                    )
+                                    && is_sorted_by(&partial.input, &group_items) =>
+                            {
+                                PhysicalPlan::AggregateStreaming(AggregateStreaming {
+                                    plan_id: self.next_plan_id(),
+                                    input: partial.input,
+                                    group_by: group_items,
+                                    agg_funcs,
+                                    stat_info: Some(stat_info),
+                                })
+                            }
+
                             PhysicalPlan::AggregatePartial(ref partial) => {
                                 let before_group_by_schema = partial.input.output_schema()?;
                                 let limit = agg.limit;
@@ -1121,6 +1153,81 @@ impl PhysicalPlanBuilder {
                 }))
             }
 
+            RelOperator::Udf(udf) => {
+                // 1. Prune unused Columns.
+                let mut used = vec![];
+                for item in udf.items.iter() {
+                    if required.contains(&item.index) {
+                        used.push(item.clone());
+                        item.scalar.used_columns().iter().for_each(|c| {
+                            required.insert(*c);
+                        })
+                    }
+                }
+
+                // 2. Build physical plan.
+                if used.is_empty() {
+                    return self.build(s_expr.child(0)?, required).await;
+                }
+                let input = self.build(s_expr.child(0)?, required).await?;
+                let input_schema = input.output_schema()?;
+                let mut udf_funcs = used
+                    .iter()
+                    .map(|item| {
+                        let ScalarExpr::UDFCall(func) = &item.scalar else {
+                            return Err(ErrorCode::Internal(
+                                "Expected WASM, UDF server or async function".to_string(),
+                            ));
+                        };
+                        let arg_indices = func
+                            .arguments
+                            .iter()
+                            .map(|arg| match arg {
+                                ScalarExpr::BoundColumnRef(col) => {
+                                    input_schema.index_of(&col.column.index.to_string())
+                                }
+                                _ => Err(ErrorCode::Internal(
+                                    "UDF argument must be a BoundColumnRef".to_string(),
+                                )),
+                            })
+                            .collect::<Result<Vec<_>>>()?;
+                        let arg_exprs = func
+                            .arguments
+                            .iter()
+                            .map(|arg| {
+                                let expr = arg.as_expr()?;
+                                let remote_expr = expr.as_remote_expr();
+                                Ok(remote_expr.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+                            })
+                            .collect::<Result<Vec<_>>>()?;
+
+                        Ok(UdfFunctionDesc {
+                            func_name: func.func_name.clone(),
+                            output_column: item.index,
+                            arg_indices,
+                            arg_exprs,
+                            handler: func.handler.clone(),
+                            udf_type: func.udf_type.clone(),
+                            data_type: func.return_type.clone(),
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                // WASM functions are evaluated before UDF server functions, then `nextval`,
+                // and the results are appended in that order.
+                udf_funcs.sort_by_key(|func| match func.udf_type {
+                    UDFType::Wasm { .. } => 0,
+                    UDFType::Server { .. } => 1,
+                    UDFType::Sequence(_) => 2,
+                });
+
+                Ok(PhysicalPlan::Udf(Udf {
+                    plan_id: self.next_plan_id(),
+                    input: Box::new(input),
+                    udf_funcs,
+                    stat_info: Some(stat_info),
+                }))
+            }
+
             RelOperator::ConstantTableScan(scan) => {
                 // 1. Prune unused Columns.
                 let used: ColumnSet = required.intersection(&scan.columns).cloned().collect();
@@ -1751,6 +1858,24 @@ impl PhysicalPlanBuilder {
         let push_downs =
             self.push_downs(&scan, &table_schema, has_inner_column, has_virtual_column)?;
 
+        if !self.dry_run {
+            // Record the columns the scan actually reads, after the projection is pushed down.
+            let read_schema = match &push_downs.projection {
+                Some(projection) => projection.project_schema(&table_schema),
+                None => table_schema.as_ref().clone(),
+            };
+            ColumnUsageManager::instance().record_read(
+                table_entry.catalog(),
+                table_entry.database(),
+                table_entry.name(),
+                read_schema
+                    .fields()
+                    .iter()
+                    .filter(|f| !is_internal_column_id(f.column_id()))
+                    .map(|f| f.name().as_str()),
+            );
+        }
+
         let mut source = table
             .read_plan_with_catalog(
                 self.ctx.clone(),
@@ -2063,6 +2188,26 @@ impl PhysicalPlanBuilder {
     }
 }
 
+/// Check if the output of the plan is sorted by the columns `keys` in any order, so that
+/// the rows with the same values of `keys` are adjacent.
+fn is_sorted_by(plan: &PhysicalPlan, keys: &[IndexType]) -> bool {
+    match plan {
+        // The sort pipeline merges the sorted streams into a single stream.
+        PhysicalPlan::Sort(sort) => {
+            sort.order_by.len() >= keys.len()
+                && keys.iter().all(|key| {
+                    sort.order_by[..keys.len()]
+                        .iter()
+                        .any(|desc| desc.order_by == *key)
+                })
+        }
+        PhysicalPlan::EvalScalar(plan) => is_sorted_by(&plan.input, keys),
+        PhysicalPlan::Filter(plan) => is_sorted_by(&plan.input, keys),
+        PhysicalPlan::Limit(plan) => is_sorted_by(&plan.input, keys),
+        _ => false,
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct RangeJoinCondition {
     pub left_expr: RemoteExpr,
@@ -30,6 +30,7 @@ use super::ReplaceInto;
 use super::RowFetch;
 use crate::executor::AggregateFinal;
 use crate::executor::AggregatePartial;
+use crate::executor::AggregateStreaming;
 use crate::executor::ConstantTableScan;
 use crate::executor::CteScan;
 use crate::executor::EvalScalar;
@@ -47,6 +48,7 @@ use crate::executor::RangeJoin;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::Sort;
 use crate::executor::TableScan;
+use crate::executor::Udf;
 use crate::executor::UnionAll;
 use crate::executor::Window;
 use crate::plans::JoinType;
@@ -74,6 +76,7 @@ impl<'a> Display for PhysicalPlanIndentFormatDisplay<'a> {
             PhysicalPlan::AggregateExpand(aggregate) => write!(f, "{}", aggregate)?,
             PhysicalPlan::AggregatePartial(aggregate) => write!(f, "{}", aggregate)?,
             PhysicalPlan::AggregateFinal(aggregate) => write!(f, "{}", aggregate)?,
+            PhysicalPlan::AggregateStreaming(aggregate) => write!(f, "{}", aggregate)?,
             PhysicalPlan::Window(window) => write!(f, "{}", window)?,
             PhysicalPlan::Sort(sort) => write!(f, "{}", sort)?,
             PhysicalPlan::Limit(limit) => write!(f, "{}", limit)?,
@@ -88,6 +91,7 @@ impl<'a> Display for PhysicalPlanIndentFormatDisplay<'a> {
             PhysicalPlan::MutationAggregate(mutation) => write!(f, "{}", mutation)?,
             PhysicalPlan::ProjectSet(unnest) => write!(f, "{}", unnest)?,
             PhysicalPlan::Lambda(lambda) => write!(f, "{}", lambda)?,
+            PhysicalPlan::Udf(udf) => write!(f, "{}", udf)?,
             PhysicalPlan::RuntimeFilterSource(plan) => write!(f, "{}", plan)?,
             PhysicalPlan::RangeJoin(plan) => write!(f, "{}", plan)?,
             PhysicalPlan::CopyIntoTable(copy_into_table) => write!(f, "{}", copy_into_table)?,
@@ -253,6 +257,39 @@ impl Display for AggregateFinal {
     }
 }
 
+impl Display for AggregateStreaming {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let group_items = self
+            .group_by
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let agg_funcs = self
+            .agg_funcs
+            .iter()
+            .map(|item| {
+                format!(
+                    "{}({})",
+                    item.sig.name,
+                    item.args
+                        .iter()
+                        .map(|index| index.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )
+            })
+            .join(", ");
+
+        write!(
+            f,
+            "Aggregate(Streaming): group items: [{}], aggregate functions: [{}]",
+            group_items, agg_funcs
+        )
+    }
+}
+
 impl Display for AggregatePartial {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let group_items = self
@@ -469,3 +506,14 @@ impl Display for Lambda {
         write!(f, "Lambda functions: {}", scalars.join(", "))
     }
 }
+
+impl Display for Udf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let scalars = self
+            .udf_funcs
+            .iter()
+            .map(|func| format!("{}({})", func.func_name, func.arg_exprs.join(", ")))
+            .collect::<Vec<String>>();
+        write!(f, "Udf functions: {}", scalars.join(", "))
+    }
+}
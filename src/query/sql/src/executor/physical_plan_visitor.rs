@@ -17,6 +17,7 @@ use common_exception::Result;
 use super::AggregateExpand;
 use super::AggregateFinal;
 use super::AggregatePartial;
+use super::AggregateStreaming;
 use super::AsyncSourcerPlan;
 use super::CopyIntoTable;
 use super::CopyIntoTableSource;
@@ -40,6 +41,7 @@ use super::ReplaceInto;
 use super::RowFetch;
 use super::Sort;
 use super::TableScan;
+use super::Udf;
 use crate::executor::ConstantTableScan;
 use crate::executor::CteScan;
 use crate::executor::MaterializedCte;
@@ -59,6 +61,7 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::AggregateExpand(plan) => self.replace_aggregate_expand(plan),
             PhysicalPlan::AggregatePartial(plan) => self.replace_aggregate_partial(plan),
             PhysicalPlan::AggregateFinal(plan) => self.replace_aggregate_final(plan),
+            PhysicalPlan::AggregateStreaming(plan) => self.replace_aggregate_streaming(plan),
             PhysicalPlan::Window(plan) => self.replace_window(plan),
             PhysicalPlan::Sort(plan) => self.replace_sort(plan),
             PhysicalPlan::Limit(plan) => self.replace_limit(plan),
@@ -71,6 +74,7 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::DistributedInsertSelect(plan) => self.replace_insert_select(plan),
             PhysicalPlan::ProjectSet(plan) => self.replace_project_set(plan),
             PhysicalPlan::Lambda(plan) => self.replace_lambda(plan),
+            PhysicalPlan::Udf(plan) => self.replace_udf(plan),
             PhysicalPlan::RuntimeFilterSource(plan) => self.replace_runtime_filter_source(plan),
             PhysicalPlan::DeletePartial(plan) => self.replace_delete_partial(plan),
             PhysicalPlan::MutationAggregate(plan) => self.replace_delete_final(plan),
@@ -171,6 +175,18 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_aggregate_streaming(&mut self, plan: &AggregateStreaming) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::AggregateStreaming(AggregateStreaming {
+            plan_id: plan.plan_id,
+            input: Box::new(input),
+            group_by: plan.group_by.clone(),
+            agg_funcs: plan.agg_funcs.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
     fn replace_window(&mut self, plan: &Window) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -415,6 +431,16 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_udf(&mut self, plan: &Udf) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::Udf(Udf {
+            plan_id: plan.plan_id,
+            input: Box::new(input),
+            udf_funcs: plan.udf_funcs.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
     fn replace_runtime_filter_source(
         &mut self,
         plan: &RuntimeFilterSource,
@@ -463,6 +489,9 @@ impl PhysicalPlan {
                 PhysicalPlan::AggregateFinal(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::AggregateStreaming(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::Window(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
@@ -499,6 +528,9 @@ impl PhysicalPlan {
                 PhysicalPlan::Lambda(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit)
                 }
+                PhysicalPlan::Udf(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit)
+                }
                 PhysicalPlan::CopyIntoTable(plan) => match &plan.source {
                     CopyIntoTableSource::Query(input) => {
                         Self::traverse(&input.plan, pre_visit, visit, post_visit);
@@ -31,6 +31,7 @@ use common_profile::ProjectSetAttribute;
 use common_profile::QueryProfile;
 use common_profile::SortAttribute;
 use common_profile::TableScanAttribute;
+use common_profile::UdfAttribute;
 use common_profile::WindowAttribute;
 use itertools::Itertools;
 
@@ -194,6 +195,24 @@ fn flatten_plan_node_profile(
             };
             plan_node_profs.push(prof);
         }
+        PhysicalPlan::Udf(udf) => {
+            flatten_plan_node_profile(metadata, &udf.input, profs, plan_node_profs)?;
+            let proc_prof = profs.get(&udf.plan_id).copied().unwrap_or_default();
+            let prof = OperatorProfile {
+                id: udf.plan_id,
+                operator_type: OperatorType::Udf,
+                execution_info: proc_prof.into(),
+                children: vec![udf.input.get_id()],
+                attribute: OperatorAttribute::Udf(UdfAttribute {
+                    scalars: udf
+                        .udf_funcs
+                        .iter()
+                        .map(|func| format!("{}({})", func.func_name, func.arg_exprs.join(", ")))
+                        .join(", "),
+                }),
+            };
+            plan_node_profs.push(prof);
+        }
         PhysicalPlan::AggregateExpand(expand) => {
             flatten_plan_node_profile(metadata, &expand.input, profs, plan_node_profs)?;
             let proc_prof = profs.get(&expand.plan_id).copied().unwrap_or_default();
@@ -267,6 +286,32 @@ fn flatten_plan_node_profile(
             };
             plan_node_profs.push(prof);
         }
+        PhysicalPlan::AggregateStreaming(agg_streaming) => {
+            flatten_plan_node_profile(metadata, &agg_streaming.input, profs, plan_node_profs)?;
+            let proc_prof = profs
+                .get(&agg_streaming.plan_id)
+                .copied()
+                .unwrap_or_default();
+            let prof = OperatorProfile {
+                id: agg_streaming.plan_id,
+                operator_type: OperatorType::Aggregate,
+                execution_info: proc_prof.into(),
+                children: vec![agg_streaming.input.get_id()],
+                attribute: OperatorAttribute::Aggregate(AggregateAttribute {
+                    group_keys: agg_streaming
+                        .group_by
+                        .iter()
+                        .map(|column| metadata.column(*column).name())
+                        .join(", "),
+                    functions: agg_streaming
+                        .agg_funcs
+                        .iter()
+                        .map(|desc| pretty_display_agg_desc(desc, metadata))
+                        .join(", "),
+                }),
+            };
+            plan_node_profs.push(prof);
+        }
         PhysicalPlan::Window(window) => {
             flatten_plan_node_profile(metadata, &window.input, profs, plan_node_profs)?;
             let proc_prof = profs.get(&window.plan_id).copied().unwrap_or_default();
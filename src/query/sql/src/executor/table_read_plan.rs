@@ -108,12 +108,6 @@ impl ToReadDataSourcePlan for dyn Table {
             bytes: statistics.read_bytes,
         });
 
-        // We need the partition sha256 to specify the result cache.
-        if ctx.get_settings().get_enable_query_result_cache()? {
-            let sha = parts.compute_sha256()?;
-            ctx.add_partitions_sha(sha);
-        }
-
         let source_info = self.get_data_source_info();
 
         let schema = &source_info.schema();
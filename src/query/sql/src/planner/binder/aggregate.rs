@@ -49,6 +49,7 @@ use crate::plans::LambdaFunc;
 use crate::plans::NthValueFunction;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
+use crate::plans::UDFCall;
 use crate::plans::WindowFunc;
 use crate::plans::WindowFuncType;
 use crate::plans::WindowOrderBy;
@@ -230,6 +231,19 @@ impl<'a> AggregateRewriter<'a> {
                 }
                 .into())
             }
+
+            ScalarExpr::UDFCall(udf) => {
+                let new_args = udf
+                    .arguments
+                    .iter()
+                    .map(|arg| self.visit(arg))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(UDFCall {
+                    arguments: new_args,
+                    ..udf.clone()
+                }
+                .into())
+            }
         }
     }
 
@@ -37,6 +37,7 @@ use super::AggregateInfo;
 use super::INTERNAL_COLUMN_FACTORY;
 use crate::binder::column_binding::ColumnBinding;
 use crate::binder::lambda::LambdaInfo;
+use crate::binder::udf::UdfInfo;
 use crate::binder::window::WindowInfo;
 use crate::binder::ColumnBindingBuilder;
 use crate::normalize_identifier;
@@ -118,6 +119,8 @@ pub struct BindContext {
 
     pub lambda_info: LambdaInfo,
 
+    pub udf_info: UdfInfo,
+
     /// If the `BindContext` is created from a CTE, record the cte name
     pub cte_name: Option<String>,
 
@@ -133,6 +136,10 @@ pub struct BindContext {
     /// It's used to check if the view has a loop dependency.
     pub view_info: Option<(String, String)>,
 
+    /// If true, the view being bound is a temporary view. Tables referenced
+    /// by it still need privilege checks, as temporary views are not granted.
+    pub in_temporary_view: bool,
+
     /// Set-returning functions in current context.
     /// The key is the `Expr::to_string` of the function.
     pub srfs: DashMap<String, ScalarExpr>,
@@ -169,10 +176,12 @@ impl BindContext {
             aggregate_info: AggregateInfo::default(),
             windows: WindowInfo::default(),
             lambda_info: LambdaInfo::default(),
+            udf_info: UdfInfo::default(),
             cte_name: None,
             cte_map_ref: Box::default(),
             in_grouping: false,
             view_info: None,
+            in_temporary_view: false,
             srfs: DashMap::new(),
             expr_context: ExprContext::default(),
             planning_agg_index: false,
@@ -188,10 +197,12 @@ impl BindContext {
             aggregate_info: Default::default(),
             windows: Default::default(),
             lambda_info: LambdaInfo::default(),
+            udf_info: UdfInfo::default(),
             cte_name: parent.cte_name,
             cte_map_ref: parent.cte_map_ref.clone(),
             in_grouping: false,
             view_info: None,
+            in_temporary_view: false,
             srfs: DashMap::new(),
             expr_context: ExprContext::default(),
             planning_agg_index: false,
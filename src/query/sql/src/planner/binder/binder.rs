@@ -19,12 +19,15 @@ use std::sync::Arc;
 use chrono_tz::Tz;
 use common_ast::ast::format_statement;
 use common_ast::ast::ExplainKind;
+use common_ast::ast::Expr as AExpr;
 use common_ast::ast::Hint;
 use common_ast::ast::Identifier;
+use common_ast::ast::Literal;
 use common_ast::ast::Statement;
 use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::Dialect;
+use common_ast::VisitorMut;
 use common_catalog::catalog::CatalogManager;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
@@ -34,15 +37,20 @@ use common_expression::ConstantFolder;
 use common_expression::Expr;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::principal::StageFileFormatType;
+use common_meta_app::principal::UDFServer;
 use common_meta_app::principal::UserDefinedFunction;
+use common_meta_app::principal::WasmUDF;
 use indexmap::IndexMap;
 use log::warn;
 
+use crate::binder::check_udf_server_address;
 use crate::binder::wrap_cast;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::CteInfo;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
+use crate::planner::semantic::resolve_type_name;
+use crate::planner::semantic::UDFParameterRewriter;
 use crate::planner::udf_validator::UDFValidator;
 use crate::plans::AlterUDFPlan;
 use crate::plans::CreateFileFormatPlan;
@@ -224,6 +232,11 @@ impl<'a> Binder {
                 Plan::ExplainAnalyze { plan: Box::new(plan) }
             }
 
+            Statement::StatementWithSettings { settings, stmt } => {
+                self.bind_statement_settings(bind_context, settings).await?;
+                self.bind_statement(bind_context, stmt).await?
+            }
+
             Statement::ShowFunctions { limit } => {
                 self.bind_show_functions(bind_context, limit).await?
             }
@@ -283,6 +296,7 @@ impl<'a> Binder {
             }
             // Columns
             Statement::ShowColumns(stmt) => self.bind_show_columns(bind_context, stmt).await?,
+            Statement::Comment(stmt) => self.bind_comment(stmt).await?,
             // Tables
             Statement::ShowTables(stmt) => self.bind_show_tables(bind_context, stmt).await?,
             Statement::ShowCreateTable(stmt) => self.bind_show_create_table(stmt).await?,
@@ -310,11 +324,26 @@ impl<'a> Binder {
             Statement::CreateView(stmt) => self.bind_create_view(stmt).await?,
             Statement::AlterView(stmt) => self.bind_alter_view(stmt).await?,
             Statement::DropView(stmt) => self.bind_drop_view(stmt).await?,
+            Statement::CreateMaterializedView(stmt) => {
+                self.bind_create_materialized_view(stmt).await?
+            }
+            Statement::RefreshMaterializedView(stmt) => {
+                self.bind_refresh_materialized_view(stmt).await?
+            }
+            Statement::DropMaterializedView(stmt) => {
+                self.bind_drop_materialized_view(stmt).await?
+            }
+
+            // Streams
+            Statement::CreateStream(stmt) => self.bind_create_stream(stmt).await?,
+            Statement::DropStream(stmt) => self.bind_drop_stream(stmt).await?,
 
             // Indexes
             Statement::CreateIndex(stmt) => self.bind_create_index(bind_context, stmt).await?,
             Statement::DropIndex(stmt) => self.bind_drop_index(stmt).await?,
             Statement::RefreshIndex(stmt) => self.bind_refresh_index(bind_context, stmt).await?,
+            Statement::CreateInvertedIndex(stmt) => self.bind_create_inverted_index(stmt).await?,
+            Statement::DropInvertedIndex(stmt) => self.bind_drop_inverted_index(stmt).await?,
 
             // Virtual Columns
             Statement::CreateVirtualColumn(stmt) => self.bind_create_virtual_column(stmt).await?,
@@ -434,26 +463,167 @@ impl<'a> Binder {
             // UDFs
             Statement::CreateUDF {
                 if_not_exists,
+                temporary,
                 udf_name,
                 parameters,
+                arg_types,
+                return_type,
+                returns_table,
+                wasm_handler,
+                server,
                 definition,
                 description,
             } => {
+                if let Some(handler) = wasm_handler {
+                    let AExpr::Literal {
+                        lit: Literal::String(location),
+                        ..
+                    } = definition.as_ref()
+                    else {
+                        return Err(ErrorCode::SyntaxException(
+                            "The definition of a WASM function must be the location of its module",
+                        ));
+                    };
+                    if !location.starts_with('@') {
+                        return Err(ErrorCode::SyntaxException(format!(
+                            "The module of a WASM function must be in a stage, like '@stage/{}'",
+                            location
+                        )));
+                    }
+                    let arg_types = arg_types
+                        .iter()
+                        .map(|arg_type| Ok(DataType::from(&resolve_type_name(arg_type)?)))
+                        .collect::<Result<Vec<_>>>()?;
+                    let return_type = match return_type {
+                        Some(return_type) => DataType::from(&resolve_type_name(return_type)?),
+                        None => {
+                            return Err(ErrorCode::SyntaxException(
+                                "A WASM function must declare its return type",
+                            ));
+                        }
+                    };
+                    // The module is read from the stage, checked and hashed by the interpreter.
+                    let udf = UserDefinedFunction {
+                        name: udf_name.to_string(),
+                        parameters: vec![],
+                        definition: location.clone(),
+                        description: description.clone().unwrap_or_default(),
+                        returns_table: false,
+                        wasm: Some(WasmUDF {
+                            handler: handler.clone(),
+                            arg_types,
+                            return_type,
+                            code_hash: String::new(),
+                        }),
+                        server: None,
+                    };
+                    return Ok(Plan::CreateUDF(Box::new(CreateUDFPlan {
+                        if_not_exists: *if_not_exists,
+                        temporary: *temporary,
+                        udf,
+                    })));
+                }
+
+                if let Some(server) = server {
+                    let AExpr::Literal {
+                        lit: Literal::String(address),
+                        ..
+                    } = definition.as_ref()
+                    else {
+                        return Err(ErrorCode::SyntaxException(
+                            "The address of a UDF server must be a string",
+                        ));
+                    };
+                    let schemes = ["http://", "https://", "grpc://"];
+                    if !schemes.iter().any(|scheme| address.starts_with(scheme)) {
+                        return Err(ErrorCode::SyntaxException(format!(
+                            "The address '{}' of a UDF server must start with one of {:?}",
+                            address, schemes
+                        )));
+                    }
+                    check_udf_server_address(address)?;
+                    let arg_types = arg_types
+                        .iter()
+                        .map(|arg_type| Ok(DataType::from(&resolve_type_name(arg_type)?)))
+                        .collect::<Result<Vec<_>>>()?;
+                    let return_type = match return_type {
+                        Some(return_type) => DataType::from(&resolve_type_name(return_type)?),
+                        None => {
+                            return Err(ErrorCode::SyntaxException(
+                                "A function of a UDF server must declare its return type",
+                            ));
+                        }
+                    };
+                    // The signature of the function is checked against the server by the
+                    // interpreter.
+                    let udf = UserDefinedFunction {
+                        name: udf_name.to_string(),
+                        parameters: vec![],
+                        definition: address.clone(),
+                        description: description.clone().unwrap_or_default(),
+                        returns_table: false,
+                        wasm: None,
+                        server: Some(UDFServer {
+                            address: address.clone(),
+                            handler: server.handler.clone(),
+                            language: server.language.clone(),
+                            arg_types,
+                            return_type,
+                            returns_null_on_null_input: server.returns_null_on_null_input,
+                        }),
+                    };
+                    return Ok(Plan::CreateUDF(Box::new(CreateUDFPlan {
+                        if_not_exists: *if_not_exists,
+                        temporary: *temporary,
+                        udf,
+                    })));
+                }
+
                 let mut validator = UDFValidator {
                     name: udf_name.to_string(),
                     parameters: parameters.iter().map(|v| v.to_string()).collect(),
                     ..Default::default()
                 };
-                validator.verify_definition_expr(definition)?;
+                // The declared types are kept as casts in the definition, which is inlined
+                // into the calls of the function.
+                let mut casts = UDFParameterRewriter::with_casts(parameters, arg_types);
+                let mut definition = definition.clone();
+                let definition = if *returns_table {
+                    let AExpr::Subquery { subquery, .. } = definition.as_mut() else {
+                        return Err(ErrorCode::SyntaxException(
+                            "The definition of a table function must be a query",
+                        ));
+                    };
+                    validator.verify_definition_query(subquery)?;
+                    casts.visit_query(subquery);
+                    subquery.to_string()
+                } else {
+                    validator.verify_definition_expr(&definition)?;
+                    casts.visit_expr(&mut definition);
+                    match return_type {
+                        Some(return_type) => AExpr::Cast {
+                            span: definition.span(),
+                            expr: definition,
+                            target_type: return_type.clone(),
+                            pg_style: false,
+                        }
+                        .to_string(),
+                        None => definition.to_string(),
+                    }
+                };
                 let udf = UserDefinedFunction {
                     name: validator.name,
                     parameters: validator.parameters,
-                    definition: definition.to_string(),
+                    definition,
                     description: description.clone().unwrap_or_default(),
+                    returns_table: *returns_table,
+                    wasm: None,
+                    server: None,
                 };
 
                 Plan::CreateUDF(Box::new(CreateUDFPlan {
                     if_not_exists: *if_not_exists,
+                    temporary: *temporary,
                     udf,
                 }))
             }
@@ -474,6 +644,9 @@ impl<'a> Binder {
                     parameters: validator.parameters,
                     definition: definition.to_string(),
                     description: description.clone().unwrap_or_default(),
+                    returns_table: false,
+                    wasm: None,
+                    server: None,
                 };
 
                 Plan::AlterUDF(Box::new(AlterUDFPlan {
@@ -485,6 +658,11 @@ impl<'a> Binder {
                 udf_name,
             } => Plan::DropUDF(Box::new(DropUDFPlan {
                 if_exists: *if_exists,
+                // A temporary UDF shadows the persistent one of the same name.
+                temporary: self
+                    .ctx
+                    .get_temporary_udf(&udf_name.to_string())
+                    .is_some(),
                 name: udf_name.to_string(),
             })),
             Statement::Call(stmt) => self.bind_call(bind_context, stmt).await?,
@@ -578,6 +756,23 @@ impl<'a> Binder {
             Statement::ShowNetworkPolicies => {
                 self.bind_show_network_policies().await?
             }
+            Statement::CreateStorageProfile(stmt) => {
+                self.bind_create_storage_profile(stmt).await?
+            }
+            Statement::DropStorageProfile(stmt) => {
+                self.bind_drop_storage_profile(stmt).await?
+            }
+            Statement::DescStorageProfile(stmt) => {
+                self.bind_desc_storage_profile(stmt).await?
+            }
+            Statement::ShowStorageProfiles => self.bind_show_storage_profiles().await?,
+            Statement::CreatePipe(stmt) => self.bind_create_pipe(stmt).await?,
+            Statement::DropPipe(stmt) => self.bind_drop_pipe(stmt).await?,
+            Statement::DescPipe(stmt) => self.bind_desc_pipe(stmt).await?,
+            Statement::ShowPipes => self.bind_show_pipes().await?,
+            Statement::CreateSequence(stmt) => self.bind_create_sequence(stmt).await?,
+            Statement::DropSequence(stmt) => self.bind_drop_sequence(stmt).await?,
+            Statement::ShowSequences => self.bind_show_sequences().await?,
         };
         Ok(plan)
     }
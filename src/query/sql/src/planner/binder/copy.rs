@@ -121,12 +121,14 @@ impl<'a> Binder {
                     table_name,
                     validation_mode,
                     force: stmt.force,
+                    return_file_status: stmt.return_file_status,
                     stage_table_info: StageTableInfo {
                         schema: stage_schema,
                         files_info,
                         stage_info,
                         files_to_copy: None,
                         is_select: false,
+                        manifest: false,
                     },
                     values_consts: vec![],
                     required_source_schema: required_values_schema.clone(),
@@ -201,12 +203,14 @@ impl<'a> Binder {
                     table_name,
                     validation_mode,
                     force: stmt.force,
+                    return_file_status: stmt.return_file_status,
                     stage_table_info: StageTableInfo {
                         schema: stage_schema,
                         files_info,
                         stage_info,
                         files_to_copy: None,
                         is_select: false,
+                        manifest: false,
                     },
                     values_consts: vec![],
                     required_source_schema: required_values_schema.clone(),
@@ -357,12 +361,14 @@ impl<'a> Binder {
                     required_values_schema: required_values_schema.clone(),
                     values_consts: vec![],
                     force: stmt.force,
+                    return_file_status: stmt.return_file_status,
                     stage_table_info: StageTableInfo {
                         schema: infer_table_schema(&required_values_schema)?,
                         files_info,
                         stage_info,
                         files_to_copy: None,
                         is_select: false,
+                        manifest: false,
                     },
                     write_mode: CopyIntoTableMode::Copy,
                     query: None,
@@ -391,7 +397,7 @@ impl<'a> Binder {
     ) -> Result<Plan> {
         if matches!(
             plan.stage_table_info.stage_info.file_format_params,
-            FileFormatParams::Parquet(_)
+            FileFormatParams::Parquet(_) | FileFormatParams::Orc(_)
         ) {
             let select_list = plan
                 .required_source_schema
@@ -481,12 +487,14 @@ impl<'a> Binder {
             required_values_schema,
             values_consts: const_columns,
             force: true,
+            return_file_status: false,
             stage_table_info: StageTableInfo {
                 schema: stage_schema,
                 files_info,
                 stage_info,
                 files_to_copy: None,
                 is_select: false,
+                manifest: false,
             },
             write_mode,
             query: None,
@@ -541,6 +549,7 @@ impl<'a> Binder {
             stage: Box::new(stage_info),
             path,
             validation_mode,
+            manifest: stmt.manifest,
             from: Box::new(query),
         })))
     }
@@ -592,6 +601,7 @@ impl<'a> Binder {
             stage: Box::new(stage_info),
             path,
             validation_mode,
+            manifest: stmt.manifest,
             from: Box::new(query),
         })))
     }
@@ -622,6 +632,7 @@ impl<'a> Binder {
             stage: Box::new(stage_info),
             path,
             validation_mode,
+            manifest: stmt.manifest,
             from: Box::new(query),
         })))
     }
@@ -658,6 +669,7 @@ impl<'a> Binder {
             stage: Box::new(stage_info),
             path,
             validation_mode,
+            manifest: stmt.manifest,
             from: Box::new(query),
         })))
     }
@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::CommentObject;
+use common_ast::ast::CommentStmt;
+use common_exception::Result;
+
+use crate::normalize_identifier;
+use crate::plans::CommentPlan;
+use crate::plans::CommentTarget;
+use crate::plans::Plan;
+use crate::Binder;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_comment(
+        &mut self,
+        stmt: &CommentStmt,
+    ) -> Result<Plan> {
+        let CommentStmt {
+            if_exists,
+            object,
+            comment,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, target) = match object {
+            CommentObject::Database { catalog, database } => {
+                let catalog = catalog
+                    .as_ref()
+                    .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+                    .unwrap_or_else(|| self.ctx.get_current_catalog());
+                let database = self.normalize_object_identifier(database);
+                (catalog, database, CommentTarget::Database)
+            }
+            CommentObject::Table {
+                catalog,
+                database,
+                table,
+            } => {
+                let (catalog, database, table) =
+                    self.normalize_object_identifier_triple(catalog, database, table);
+                (catalog, database, CommentTarget::Table { table })
+            }
+            CommentObject::Column {
+                catalog,
+                database,
+                table,
+                column,
+            } => {
+                let (catalog, database, table) =
+                    self.normalize_object_identifier_triple(catalog, database, table);
+                let column = self.normalize_object_identifier(column);
+                (catalog, database, CommentTarget::Column { table, column })
+            }
+        };
+
+        Ok(Plan::Comment(Box::new(CommentPlan {
+            if_exists: *if_exists,
+            tenant,
+            catalog,
+            database,
+            target,
+            comment: comment.clone(),
+        })))
+    }
+}
@@ -15,7 +15,9 @@
 use std::sync::Arc;
 
 use common_ast::ast::CreateIndexStmt;
+use common_ast::ast::CreateInvertedIndexStmt;
 use common_ast::ast::DropIndexStmt;
+use common_ast::ast::DropInvertedIndexStmt;
 use common_ast::ast::GroupBy;
 use common_ast::ast::Identifier;
 use common_ast::ast::Query;
@@ -31,6 +33,7 @@ use common_ast::Dialect;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::aggregates::AggregateFunctionFactory;
+use common_functions::scalars::Tokenizer;
 use common_meta_app::schema::GetIndexReq;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::IndexNameIdent;
@@ -40,8 +43,11 @@ use crate::binder::Binder;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerConfig;
 use crate::optimizer::OptimizerContext;
+use crate::normalize_identifier;
 use crate::plans::CreateIndexPlan;
+use crate::plans::CreateInvertedIndexPlan;
 use crate::plans::DropIndexPlan;
+use crate::plans::DropInvertedIndexPlan;
 use crate::plans::Plan;
 use crate::plans::RefreshIndexPlan;
 use crate::AggregatingIndexChecker;
@@ -123,6 +129,80 @@ impl Binder {
         Ok(Plan::DropIndex(Box::new(plan)))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_inverted_index(
+        &mut self,
+        stmt: &CreateInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let CreateInvertedIndexStmt {
+            if_not_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+            columns,
+            tokenizer,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        let table_info = self.ctx.get_table(&catalog, &database, &table).await?;
+        if table_info.engine() != "FUSE" {
+            return Err(ErrorCode::UnsupportedIndex(
+                "Inverted index only support FUSE engine",
+            ));
+        }
+
+        let index_name = self.normalize_object_identifier(index_name);
+        let columns = columns
+            .iter()
+            .map(|column| normalize_identifier(column, &self.name_resolution_ctx).name)
+            .collect();
+        let tokenizer = match tokenizer {
+            Some(tokenizer) => tokenizer.parse::<Tokenizer>()?,
+            None => Tokenizer::default(),
+        };
+
+        let plan = CreateInvertedIndexPlan {
+            if_not_exists: *if_not_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+            columns,
+            tokenizer,
+        };
+        Ok(Plan::CreateInvertedIndex(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_inverted_index(
+        &mut self,
+        stmt: &DropInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let DropInvertedIndexStmt {
+            if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = self.normalize_object_identifier(index_name);
+
+        let plan = DropInvertedIndexPlan {
+            if_exists: *if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        };
+        Ok(Plan::DropInvertedIndex(Box::new(plan)))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_refresh_index(
         &mut self,
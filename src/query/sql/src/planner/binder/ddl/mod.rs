@@ -15,13 +15,18 @@
 mod account;
 mod catalog;
 mod column;
+mod comment;
 mod data_mask;
 mod database;
 mod index;
 mod network_policy;
+mod pipe;
 mod role;
+mod sequence;
 mod share;
 mod stage;
+mod storage_profile;
+mod stream;
 mod table;
 mod view;
 mod virtual_column;
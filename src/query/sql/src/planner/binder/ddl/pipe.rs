@@ -0,0 +1,255 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+
+use common_ast::ast::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::FileFormatOptionsAst;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::KafkaSource;
+use common_meta_app::principal::PipeSource;
+use common_meta_app::principal::StageFileCompression;
+use common_meta_app::principal::StageSource;
+use regex::Regex;
+
+use super::super::copy::parse_stage_location_v2;
+use crate::binder::Binder;
+use crate::plans::CreatePipePlan;
+use crate::plans::DescPipePlan;
+use crate::plans::DropPipePlan;
+use crate::plans::Plan;
+use crate::plans::ShowPipesPlan;
+
+const PIPE_OPT_KEY_BROKERS: &str = "brokers";
+const PIPE_OPT_KEY_TOPIC: &str = "topic";
+const PIPE_OPT_KEY_BATCH_SIZE: &str = "batch_size";
+const DEFAULT_PIPE_BATCH_SIZE: u64 = 10000;
+
+const PIPE_OPT_KEY_REFRESH_INTERVAL: &str = "refresh_interval";
+/// A stage pipe loads at most this many files by one commit by default.
+const DEFAULT_STAGE_PIPE_BATCH_SIZE: u64 = 1000;
+const DEFAULT_STAGE_PIPE_REFRESH_INTERVAL: u64 = 60;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_pipe(
+        &mut self,
+        stmt: &CreatePipeStmt,
+    ) -> Result<Plan> {
+        let CreatePipeStmt {
+            if_not_exists,
+            name,
+            database,
+            table,
+            source,
+            file_format,
+            comment,
+        } = stmt;
+
+        let catalog = self.ctx.get_current_catalog();
+        let database = database
+            .as_ref()
+            .map(|ident| self.normalize_object_identifier(ident))
+            .unwrap_or_else(|| self.ctx.get_current_database());
+        let table = self.normalize_object_identifier(table);
+        // Make sure the table exists.
+        self.ctx.get_table(&catalog, &database, &table).await?;
+
+        let file_format_params: FileFormatParams = FileFormatOptionsAst {
+            options: file_format.clone(),
+        }
+        .try_into()?;
+
+        let (source, batch_size) = match source {
+            CreatePipeSource::Kafka { connection } => {
+                // Each message is a record of the format.
+                if !matches!(
+                    file_format_params,
+                    FileFormatParams::Csv(_)
+                        | FileFormatParams::Tsv(_)
+                        | FileFormatParams::NdJson(_)
+                ) {
+                    return Err(ErrorCode::IllegalPipe(format!(
+                        "kafka pipe only supports file format CSV, TSV and NDJSON, but got {}",
+                        file_format_params.get_type().to_string()
+                    )));
+                }
+                if !matches!(
+                    file_format_params.compression(),
+                    StageFileCompression::None | StageFileCompression::Auto
+                ) {
+                    return Err(ErrorCode::IllegalPipe(
+                        "kafka pipe doesn't support compressed messages",
+                    ));
+                }
+                let (source, batch_size) = parse_kafka_options(connection)?;
+                (PipeSource::Kafka(source), batch_size)
+            }
+            CreatePipeSource::Stage {
+                location,
+                pattern,
+                options,
+            } => {
+                // Make sure the stage exists.
+                parse_stage_location_v2(&self.ctx, &location.name, &location.path).await?;
+                if let Some(pattern) = pattern {
+                    if let Err(err) = Regex::new(pattern) {
+                        return Err(ErrorCode::IllegalPipe(format!(
+                            "invalid pattern '{pattern}': {err}"
+                        )));
+                    }
+                }
+                let (refresh_interval, batch_size) = parse_stage_options(options)?;
+                let source = StageSource {
+                    stage: location.name.clone(),
+                    path: location.path.clone(),
+                    pattern: pattern.clone(),
+                    file_format_options: file_format.clone(),
+                    refresh_interval,
+                };
+                (PipeSource::Stage(source), batch_size)
+            }
+        };
+
+        let plan = CreatePipePlan {
+            if_not_exists: *if_not_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.to_string(),
+            catalog,
+            database,
+            table,
+            source,
+            file_format_params,
+            batch_size,
+            owner: self.ctx.get_current_user()?.identity(),
+            comment: comment.clone().unwrap_or_default(),
+        };
+        Ok(Plan::CreatePipe(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_pipe(
+        &mut self,
+        stmt: &DropPipeStmt,
+    ) -> Result<Plan> {
+        let DropPipeStmt { if_exists, name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = DropPipePlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+        };
+        Ok(Plan::DropPipe(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_pipe(
+        &mut self,
+        stmt: &DescPipeStmt,
+    ) -> Result<Plan> {
+        let DescPipeStmt { name } = stmt;
+
+        let plan = DescPipePlan {
+            name: name.to_string(),
+        };
+        Ok(Plan::DescPipe(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_pipes(&mut self) -> Result<Plan> {
+        let plan = ShowPipesPlan {};
+        Ok(Plan::ShowPipes(Box::new(plan)))
+    }
+}
+
+fn parse_kafka_options(options: &BTreeMap<String, String>) -> Result<(KafkaSource, u64)> {
+    let mut brokers = None;
+    let mut topic = None;
+    let mut batch_size = DEFAULT_PIPE_BATCH_SIZE;
+    for (key, value) in options.iter() {
+        match key.as_str() {
+            PIPE_OPT_KEY_BROKERS => {
+                brokers = Some(
+                    value
+                        .split(',')
+                        .map(|broker| broker.trim().to_string())
+                        .filter(|broker| !broker.is_empty())
+                        .collect::<Vec<_>>(),
+                );
+            }
+            PIPE_OPT_KEY_TOPIC => topic = Some(value.clone()),
+            PIPE_OPT_KEY_BATCH_SIZE => {
+                batch_size = match value.parse::<u64>() {
+                    Ok(batch_size) if batch_size > 0 => batch_size,
+                    _ => {
+                        return Err(ErrorCode::IllegalPipe(format!(
+                            "invalid {PIPE_OPT_KEY_BATCH_SIZE} '{value}', expect a positive integer"
+                        )));
+                    }
+                };
+            }
+            _ => {
+                return Err(ErrorCode::IllegalPipe(format!(
+                    "unknown kafka option '{key}', expect {PIPE_OPT_KEY_BROKERS}, {PIPE_OPT_KEY_TOPIC} or {PIPE_OPT_KEY_BATCH_SIZE}"
+                )));
+            }
+        }
+    }
+
+    let brokers = match brokers {
+        Some(brokers) if !brokers.is_empty() => brokers,
+        _ => {
+            return Err(ErrorCode::IllegalPipe(format!(
+                "kafka option {PIPE_OPT_KEY_BROKERS} is required"
+            )));
+        }
+    };
+    let topic = match topic {
+        Some(topic) if !topic.is_empty() => topic,
+        _ => {
+            return Err(ErrorCode::IllegalPipe(format!(
+                "kafka option {PIPE_OPT_KEY_TOPIC} is required"
+            )));
+        }
+    };
+    Ok((KafkaSource { brokers, topic }, batch_size))
+}
+
+/// Returns the refresh interval in seconds and the batch size of a stage pipe.
+fn parse_stage_options(options: &BTreeMap<String, String>) -> Result<(u64, u64)> {
+    let mut refresh_interval = DEFAULT_STAGE_PIPE_REFRESH_INTERVAL;
+    let mut batch_size = DEFAULT_STAGE_PIPE_BATCH_SIZE;
+    for (key, value) in options.iter() {
+        let target = match key.as_str() {
+            PIPE_OPT_KEY_REFRESH_INTERVAL => &mut refresh_interval,
+            PIPE_OPT_KEY_BATCH_SIZE => &mut batch_size,
+            _ => {
+                return Err(ErrorCode::IllegalPipe(format!(
+                    "unknown stage pipe option '{key}', expect {PIPE_OPT_KEY_REFRESH_INTERVAL} or {PIPE_OPT_KEY_BATCH_SIZE}"
+                )));
+            }
+        };
+        *target = match value.parse::<u64>() {
+            Ok(v) if v > 0 => v,
+            _ => {
+                return Err(ErrorCode::IllegalPipe(format!(
+                    "invalid {key} '{value}', expect a positive integer"
+                )));
+            }
+        };
+    }
+    Ok((refresh_interval, batch_size))
+}
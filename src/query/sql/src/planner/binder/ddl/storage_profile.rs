@@ -0,0 +1,97 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_ast::ast::*;
+use common_config::GlobalConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_storage::DataOperator;
+
+use crate::binder::location::parse_uri_location;
+use crate::binder::Binder;
+use crate::plans::CreateStorageProfilePlan;
+use crate::plans::DescStorageProfilePlan;
+use crate::plans::DropStorageProfilePlan;
+use crate::plans::Plan;
+use crate::plans::ShowStorageProfilesPlan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_storage_profile(
+        &mut self,
+        stmt: &CreateStorageProfileStmt,
+    ) -> Result<Plan> {
+        let CreateStorageProfileStmt {
+            if_not_exists,
+            name,
+            uri_location,
+            comment,
+        } = stmt;
+
+        let mut uri_location = uri_location.clone();
+        let (storage_params, _) = parse_uri_location(&mut uri_location)?;
+        if !storage_params.is_secure() && !GlobalConfig::instance().storage.allow_insecure {
+            return Err(ErrorCode::StorageInsecure(
+                "create storage profile with insecure storage is not allowed",
+            ));
+        }
+
+        // create a temporary op to check if params is correct
+        DataOperator::try_create(&storage_params).await?;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = CreateStorageProfilePlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            name: name.to_string(),
+            storage_params,
+            comment: comment.clone().unwrap_or_default(),
+        };
+        Ok(Plan::CreateStorageProfile(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_storage_profile(
+        &mut self,
+        stmt: &DropStorageProfileStmt,
+    ) -> Result<Plan> {
+        let DropStorageProfileStmt { if_exists, name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = DropStorageProfilePlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+        };
+        Ok(Plan::DropStorageProfile(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_storage_profile(
+        &mut self,
+        stmt: &DescStorageProfileStmt,
+    ) -> Result<Plan> {
+        let DescStorageProfileStmt { name } = stmt;
+
+        let plan = DescStorageProfilePlan {
+            name: name.to_string(),
+        };
+        Ok(Plan::DescStorageProfile(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_storage_profiles(&mut self) -> Result<Plan> {
+        let plan = ShowStorageProfilesPlan {};
+        Ok(Plan::ShowStorageProfiles(Box::new(plan)))
+    }
+}
@@ -42,6 +42,7 @@ use common_ast::ast::ShowLimit;
 use common_ast::ast::ShowTablesStatusStmt;
 use common_ast::ast::ShowTablesStmt;
 use common_ast::ast::Statement;
+use common_ast::ast::TableConstraint;
 use common_ast::ast::TableReference;
 use common_ast::ast::TruncateTableStmt;
 use common_ast::ast::UndropTableStmt;
@@ -59,46 +60,62 @@ use common_expression::infer_schema_type;
 use common_expression::infer_table_schema;
 use common_expression::types::DataType;
 use common_expression::ComputedExpr;
+use common_expression::ConstantFolder;
 use common_expression::DataField;
 use common_expression::DataSchemaRefExt;
+use common_expression::Scalar;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_expression::TableSchemaRefExt;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::SequenceInfo;
 use common_meta_app::storage::StorageParams;
 use common_storage::DataOperator;
+use common_storages_federated::FEDERATED_OPT_KEY_CONNECTION;
 use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
+use common_users::UserApiProvider;
 use log::debug;
 use log::error;
 use storages_common_table_meta::table::is_reserved_opt_key;
+use storages_common_table_meta::table::ForeignKey;
+use storages_common_table_meta::table::TableConstraints;
+use storages_common_table_meta::table::OPT_KEY_CONSTRAINTS;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
+use storages_common_table_meta::table::OPT_KEY_STORAGE_PROFILE;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 
 use crate::binder::location::parse_uri_location;
 use crate::binder::scalar::ScalarBinder;
+use crate::binder::wrap_cast;
 use crate::binder::Binder;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::Visibility;
+use crate::identity_sequence_name;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerConfig;
 use crate::optimizer::OptimizerContext;
 use crate::parse_computed_expr_to_string;
 use crate::parse_default_expr_to_string;
+use crate::parse_exprs;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
+use crate::planner::semantic::TypeChecker;
 use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::CloneTableSource;
 use crate::plans::CreateTablePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
+use crate::plans::DropTablePartitionPlan;
 use crate::plans::DropTablePlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::ModifyTableColumnPlan;
@@ -111,6 +128,7 @@ use crate::plans::RenameTablePlan;
 use crate::plans::RevertTablePlan;
 use crate::plans::RewriteKind;
 use crate::plans::SetOptionsPlan;
+use crate::plans::SetTableTagsPlan;
 use crate::plans::ShowCreateTablePlan;
 use crate::plans::TruncateTablePlan;
 use crate::plans::UndropTablePlan;
@@ -235,10 +253,18 @@ impl Binder {
             catalog,
             database,
             table,
+            travel_point,
         } = stmt;
 
         let (catalog, database, table) =
             self.normalize_object_identifier_triple(catalog, database, table);
+        let navigation = match travel_point {
+            Some(point) => Some(
+                self.resolve_data_travel_point(&mut BindContext::new(), point)
+                    .await?,
+            ),
+            None => None,
+        };
         let schema = DataSchemaRefExt::create(vec![
             DataField::new("Field", DataType::String),
             DataField::new("Type", DataType::String),
@@ -251,6 +277,7 @@ impl Binder {
             catalog,
             database,
             table,
+            navigation,
             schema,
         })))
     }
@@ -382,6 +409,7 @@ impl Binder {
             source,
             table_options,
             cluster_by,
+            partition_by,
             as_query,
             transient,
             engine,
@@ -401,6 +429,13 @@ impl Binder {
                 table_option.1.to_string(),
             )?;
         }
+        if matches!(engine, Engine::MySql | Engine::Postgres)
+            && !options.contains_key(FEDERATED_OPT_KEY_CONNECTION)
+        {
+            return Err(ErrorCode::BadArguments(format!(
+                "{engine} engine requires the table option {FEDERATED_OPT_KEY_CONNECTION}"
+            )));
+        }
 
         let (storage_params, part_prefix) = match uri_location {
             Some(uri) => {
@@ -425,14 +460,131 @@ impl Binder {
 
                 (Some(sp), fp)
             }
+            None if engine == Engine::External => {
+                return Err(ErrorCode::BadArguments(
+                    "EXTERNAL engine requires the location of the parquet files",
+                ));
+            }
+            None if engine == Engine::Delta => {
+                return Err(ErrorCode::BadArguments(
+                    "DELTA engine requires the location of the delta table",
+                ));
+            }
             None => (None, "".to_string()),
         };
 
+        // Tables bound to a storage profile use the storage params of the profile.
+        let storage_params = match options.get(OPT_KEY_STORAGE_PROFILE) {
+            Some(_) if storage_params.is_some() => {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "table option {OPT_KEY_STORAGE_PROFILE} can't be used together with an external location"
+                )));
+            }
+            Some(_) if engine != Engine::Fuse => {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "table option {OPT_KEY_STORAGE_PROFILE} only supports the FUSE engine"
+                )));
+            }
+            Some(name) => {
+                let profile = UserApiProvider::instance()
+                    .get_storage_profile(&self.ctx.get_tenant(), name)
+                    .await?;
+                Some(profile.storage_params)
+            }
+            None => storage_params,
+        };
+
         // If table is TRANSIENT, set a flag in table option
         if *transient {
             options.insert("TRANSIENT".to_owned(), "T".to_owned());
         }
 
+        // `CREATE TABLE ... CLONE` shares the snapshot of the source table (at the time
+        // travel point if specified), no data is copied.
+        let mut clone_from = None;
+        let mut cloned_schema = None;
+        let (source, as_query) = match source {
+            Some(CreateTableSource::Clone {
+                catalog: source_catalog,
+                database: source_database,
+                table: source_table,
+                travel_point,
+            }) => {
+                if as_query.is_some() {
+                    return Err(ErrorCode::BadArguments(
+                        "CREATE TABLE ... CLONE can't be used together with AS SELECT",
+                    ));
+                }
+                if engine != Engine::Fuse {
+                    return Err(ErrorCode::BadArguments(
+                        "CREATE TABLE ... CLONE only supports the FUSE engine",
+                    ));
+                }
+
+                let (catalog_name, database_name, table_name) = self
+                    .normalize_object_identifier_triple(
+                        source_catalog,
+                        source_database,
+                        source_table,
+                    );
+                let source_table = self
+                    .ctx
+                    .get_table(&catalog_name, &database_name, &table_name)
+                    .await?;
+                if source_table.engine() != "FUSE" {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "CREATE TABLE ... CLONE can't clone table {}.{} of engine {}",
+                        database_name,
+                        table_name,
+                        source_table.engine()
+                    )));
+                }
+
+                // Keep the storage format of the source table, the files are shared.
+                for key in [OPT_KEY_STORAGE_FORMAT, OPT_KEY_TABLE_COMPRESSION] {
+                    if let Some(value) = source_table.options().get(key) {
+                        options.insert(key.to_owned(), value.clone());
+                    }
+                }
+
+                let navigation = match travel_point {
+                    Some(point) => {
+                        let point = self
+                            .resolve_data_travel_point(&mut BindContext::new(), point)
+                            .await?;
+                        self.check_time_travel_point(&point)?;
+                        Some(point)
+                    }
+                    None => None,
+                };
+                // The schema (column ids included) must be the one of the shared snapshot.
+                let source_table = match &navigation {
+                    Some(point) => source_table.navigate_to(point).await?,
+                    None => source_table,
+                };
+                cloned_schema =
+                    Some((source_table.schema(), source_table.field_comments().clone()));
+                clone_from = Some(CloneTableSource {
+                    catalog: catalog_name,
+                    database: database_name,
+                    table: table_name,
+                    navigation,
+                });
+                (None, None)
+            }
+            _ if as_query.is_some()
+                && matches!(
+                    engine,
+                    Engine::External | Engine::Delta | Engine::MySql | Engine::Postgres
+                ) =>
+            {
+                return Err(ErrorCode::BadArguments(format!(
+                    "CREATE TABLE ... AS SELECT doesn't support the {engine} engine"
+                )));
+            }
+            _ => (source.clone(), as_query.clone()),
+        };
+
         // Build table schema
         let (schema, field_comments) = match (&source, &as_query) {
             (Some(source), None) => {
@@ -479,11 +631,25 @@ impl Binder {
                 Self::validate_create_table_schema(&source_schema)?;
                 (source_schema, source_comments)
             }
-            _ => Err(ErrorCode::BadArguments(
+            // The schema of an EXTERNAL or DELTA table is inferred from the files when creating
+            (None, None) if matches!(engine, Engine::External | Engine::Delta) => {
+                (TableSchemaRefExt::create(vec![]), vec![])
+            }
+            // `CREATE TABLE ... CLONE` takes the schema of the source table
+            (None, None) => cloned_schema.ok_or_else(|| ErrorCode::BadArguments(
                 "Incorrect CREATE query: required list of column descriptions or AS section or SELECT..",
             ))?,
         };
 
+        if let Some(CreateTableSource::Columns(_, constraints)) = &source {
+            if !constraints.is_empty() {
+                let constraints = self
+                    .analyze_table_constraints(&catalog, &database, &table, &schema, constraints)
+                    .await?;
+                options.insert(OPT_KEY_CONSTRAINTS.to_owned(), constraints.to_json()?);
+            }
+        }
+
         // for fuse engine, we will insert database_id, so if we check it in execute phase,
         // we can't distinct user key and our internal key.
         if options.contains_key(&OPT_KEY_DATABASE_ID.to_lowercase()) {
@@ -563,6 +729,26 @@ impl Binder {
             }
         };
 
+        if let Some(partition_by) = partition_by {
+            if engine != Engine::Fuse {
+                return Err(ErrorCode::InvalidPartitionKey(format!(
+                    "Partition by is not supported by table engine {}",
+                    engine
+                )));
+            }
+            let partition_key = self
+                .analyze_partition_key(partition_by, schema.clone())
+                .await?;
+            options.insert(OPT_KEY_PARTITION_BY.to_owned(), partition_key);
+        }
+
+        let identity_sequences = match &source {
+            Some(CreateTableSource::Columns(columns, _)) => {
+                self.analyze_identity_sequences(&database, &table, columns, &schema)?
+            }
+            _ => vec![],
+        };
+
         let plan = CreateTablePlan {
             if_not_exists: *if_not_exists,
             tenant: self.ctx.get_tenant(),
@@ -587,6 +773,8 @@ impl Binder {
             } else {
                 None
             },
+            clone_from,
+            identity_sequences,
         };
         Ok(Plan::CreateTable(Box::new(plan)))
     }
@@ -645,6 +833,8 @@ impl Binder {
             engine: Engine::Fuse,
             cluster_key: None,
             as_select: None,
+            clone_from: None,
+            identity_sequences: vec![],
             schema: Arc::new(TableSchema::default()),
             field_comments: vec![],
             storage_params: Some(sp),
@@ -833,6 +1023,18 @@ impl Binder {
                     table,
                 },
             ))),
+            AlterTableAction::DropPartition { partition } => {
+                let partition = self
+                    .resolve_partition_value(bind_context, &catalog, &database, &table, partition)
+                    .await?;
+                Ok(Plan::DropTablePartition(Box::new(DropTablePartitionPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    partition,
+                })))
+            }
             AlterTableAction::ReclusterTable {
                 is_final,
                 selection,
@@ -872,6 +1074,7 @@ impl Binder {
             }
             AlterTableAction::RevertTo { point } => {
                 let point = self.resolve_data_travel_point(bind_context, point).await?;
+                self.check_time_travel_point(&point)?;
                 Ok(Plan::RevertTable(Box::new(RevertTablePlan {
                     tenant,
                     catalog,
@@ -888,6 +1091,25 @@ impl Binder {
                     table,
                 })))
             }
+            AlterTableAction::SetTags { tags } => {
+                Ok(Plan::SetTableTags(Box::new(SetTableTagsPlan {
+                    catalog,
+                    database,
+                    table,
+                    tags: tags
+                        .iter()
+                        .map(|(k, v)| (k.clone(), Some(v.clone())))
+                        .collect(),
+                })))
+            }
+            AlterTableAction::UnsetTags { tags } => {
+                Ok(Plan::SetTableTags(Box::new(SetTableTagsPlan {
+                    catalog,
+                    database,
+                    table,
+                    tags: tags.iter().map(|k| (k.clone(), None)).collect(),
+                })))
+            }
         }
     }
 
@@ -980,11 +1202,21 @@ impl Binder {
                 };
                 OptimizeTableAction::Purge(p)
             }
-            AstOptimizeTableAction::Compact { target } => match target {
+            AstOptimizeTableAction::Compact { target, .. } => match target {
                 CompactTarget::Block => OptimizeTableAction::CompactBlocks,
                 CompactTarget::Segment => OptimizeTableAction::CompactSegments,
             },
         };
+        let partition = match ast_action {
+            AstOptimizeTableAction::Compact {
+                partition: Some(partition),
+                ..
+            } => Some(
+                self.resolve_partition_value(bind_context, &catalog, &database, &table, partition)
+                    .await?,
+            ),
+            _ => None,
+        };
 
         Ok(Plan::OptimizeTable(Box::new(OptimizeTablePlan {
             catalog,
@@ -992,9 +1224,58 @@ impl Binder {
             table,
             action,
             limit: limit.map(|v| v as usize),
+            partition,
         })))
     }
 
+    /// Resolve the value of a partition of the table, e.g. the `202301` of
+    /// `ALTER TABLE t DROP PARTITION 202301`, as a constant of the partition key type.
+    #[async_backtrace::framed]
+    async fn resolve_partition_value(
+        &self,
+        bind_context: &mut BindContext,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        partition: &Expr,
+    ) -> Result<Scalar> {
+        let table_meta = self.ctx.get_table(catalog, database, table).await?;
+        let partition_key = match table_meta.options().get(OPT_KEY_PARTITION_BY) {
+            Some(partition_key) => partition_key.clone(),
+            None => {
+                return Err(ErrorCode::UnpartitionedTable(format!(
+                    "Table '{}'.'{}' is not partitioned",
+                    database, table
+                )));
+            }
+        };
+        let partition_key = parse_exprs(self.ctx.clone(), table_meta, &partition_key)?;
+        let data_type = partition_key[0].data_type().clone();
+
+        let mut type_checker = TypeChecker::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            false,
+            false,
+        );
+        let (scalar, _) = *type_checker.resolve(partition).await?;
+        let scalar = wrap_cast(&scalar, &data_type);
+        let expr = scalar.as_expr()?;
+
+        let (new_expr, _) =
+            ConstantFolder::fold(&expr, &self.ctx.get_function_context()?, &BUILTIN_FUNCTIONS);
+        match new_expr {
+            common_expression::Expr::Constant { scalar, .. } => Ok(scalar),
+            _ => Err(ErrorCode::SemanticError(format!(
+                "Partition `{:#}` must be a constant value",
+                partition
+            ))),
+        }
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_vacuum_table(
         &mut self,
@@ -1190,6 +1471,11 @@ impl Binder {
                         "can't add a stored computed column".to_string(),
                     ));
                 }
+                ColumnExpr::Identity { .. } => {
+                    return Err(ErrorCode::SemanticError(
+                        "can't add an identity column".to_string(),
+                    ));
+                }
             }
         }
         let comment = column.comment.clone().unwrap_or_default();
@@ -1221,6 +1507,30 @@ impl Binder {
                         )?;
                         field = field.with_default_expr(Some(expr));
                     }
+                    ColumnExpr::Identity { increment, .. } => {
+                        if !DataType::from(&schema_data_type)
+                            .remove_nullable()
+                            .is_integer()
+                        {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "identity column {} must be of an integer type, but got {}",
+                                name, schema_data_type
+                            )));
+                        }
+                        if *increment == 0 {
+                            return Err(ErrorCode::IllegalSequence(format!(
+                                "The increment of identity column {} can't be 0",
+                                name
+                            )));
+                        }
+                        // The sequence is created with the table, see `analyze_identity_sequences`.
+                        let sequence = format!(
+                            "_identity_{}_{}",
+                            self.ctx.get_id().replace('-', ""),
+                            fields.len()
+                        );
+                        field = field.with_default_expr(Some(format!("nextval('{sequence}')")));
+                    }
                     _ => has_computed = true,
                 }
             }
@@ -1276,19 +1586,52 @@ impl Binder {
         Ok((schema, fields_comments))
     }
 
+    /// The sequences filling the identity columns of a new table.
+    fn analyze_identity_sequences(
+        &self,
+        database: &str,
+        table: &str,
+        columns: &[ColumnDefinition],
+        schema: &TableSchemaRef,
+    ) -> Result<Vec<SequenceInfo>> {
+        let mut sequences = vec![];
+        for column in columns.iter() {
+            if let Some(ColumnExpr::Identity { start, increment }) = &column.expr {
+                let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
+                let field = schema.field_with_name(&name)?;
+                let Some(sequence) = field.default_expr().and_then(|e| identity_sequence_name(e))
+                else {
+                    return Err(ErrorCode::Internal(format!(
+                        "Identity column {} has no sequence",
+                        name
+                    )));
+                };
+                let comment = format!("Identity column {} of table {}.{}", name, database, table);
+                sequences.push(SequenceInfo::new(&sequence, *start, *increment, comment));
+            }
+        }
+        Ok(sequences)
+    }
+
     #[async_backtrace::framed]
     async fn analyze_create_table_schema(
         &self,
         source: &CreateTableSource,
     ) -> Result<(TableSchemaRef, Vec<String>)> {
         match source {
-            CreateTableSource::Columns(columns) => {
+            CreateTableSource::Columns(columns, _) => {
                 self.analyze_create_table_schema_by_columns(columns).await
             }
             CreateTableSource::Like {
                 catalog,
                 database,
                 table,
+            }
+            | CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                ..
             } => {
                 let (catalog, database, table) =
                     self.normalize_object_identifier_triple(catalog, database, table);
@@ -1311,8 +1654,90 @@ impl Binder {
         }
     }
 
+    /// Resolve the informational constraints of the table to be created.
+    ///
+    /// The constraints are not enforced, but the columns they refer to must exist.
+    #[async_backtrace::framed]
+    async fn analyze_table_constraints(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        schema: &TableSchemaRef,
+        constraints: &[TableConstraint],
+    ) -> Result<TableConstraints> {
+        let resolve_columns = |schema: &TableSchemaRef, columns: &[Identifier]| {
+            columns
+                .iter()
+                .map(|column| {
+                    let name = normalize_identifier(column, &self.name_resolution_ctx).name;
+                    schema.index_of(&name)?;
+                    Ok(name)
+                })
+                .collect::<Result<Vec<_>>>()
+        };
+
+        let mut result = TableConstraints::default();
+        for constraint in constraints {
+            match constraint {
+                TableConstraint::PrimaryKey { columns } => {
+                    if result.primary_key.is_some() {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "Multiple primary keys defined for table {}",
+                            table
+                        )));
+                    }
+                    result.primary_key = Some(resolve_columns(schema, columns)?);
+                }
+                TableConstraint::Unique { columns } => {
+                    result.unique_keys.push(resolve_columns(schema, columns)?);
+                }
+                TableConstraint::ForeignKey {
+                    columns,
+                    ref_database,
+                    ref_table,
+                    ref_columns,
+                } => {
+                    if columns.len() != ref_columns.len() {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "Foreign key of table {} has {} columns, but references {} columns",
+                            table,
+                            columns.len(),
+                            ref_columns.len()
+                        )));
+                    }
+                    let ref_database = ref_database
+                        .as_ref()
+                        .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+                        .unwrap_or_else(|| database.to_string());
+                    let ref_table = normalize_identifier(ref_table, &self.name_resolution_ctx).name;
+                    let columns = resolve_columns(schema, columns)?;
+                    let ref_columns = if ref_database == database && ref_table == table {
+                        resolve_columns(schema, ref_columns)?
+                    } else {
+                        let ref_schema = self
+                            .ctx
+                            .get_table(catalog, &ref_database, &ref_table)
+                            .await?
+                            .schema();
+                        resolve_columns(&ref_schema, ref_columns)?
+                    };
+                    result.foreign_keys.push(ForeignKey {
+                        columns,
+                        ref_database,
+                        ref_table,
+                        ref_columns,
+                    });
+                }
+            }
+        }
+        Ok(result)
+    }
+
     /// Validate the schema of the table to be created.
-    fn validate_create_table_schema(schema: &TableSchemaRef) -> Result<()> {
+    pub(in crate::planner::binder) fn validate_create_table_schema(
+        schema: &TableSchemaRef,
+    ) -> Result<()> {
         // Check if there are duplicated column names
         let mut name_set = HashSet::new();
         for field in schema.fields() {
@@ -1416,6 +1841,84 @@ impl Binder {
         Ok(cluster_keys)
     }
 
+    #[async_backtrace::framed]
+    async fn analyze_partition_key(
+        &mut self,
+        partition_by: &Expr,
+        schema: TableSchemaRef,
+    ) -> Result<String> {
+        // Build a temporary BindContext to resolve the expr
+        let mut bind_context = BindContext::new();
+        for (index, field) in schema.fields().iter().enumerate() {
+            let column = ColumnBindingBuilder::new(
+                field.name().clone(),
+                index,
+                Box::new(DataType::from(field.data_type())),
+                Visibility::Visible,
+            )
+            .build();
+
+            bind_context.columns.push(column);
+        }
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        // partition key cannot be a udf expression.
+        scalar_binder.forbid_udf();
+
+        let (partition_key, _) = scalar_binder.bind(partition_by).await?;
+        let used_columns = partition_key.used_columns();
+        if used_columns.is_empty() || !partition_key.valid_for_clustering() {
+            return Err(ErrorCode::InvalidPartitionKey(format!(
+                "Partition by expression `{:#}` is invalid",
+                partition_by
+            )));
+        }
+        // The partition value is evaluated on the stored columns of each block.
+        for index in used_columns.iter() {
+            if matches!(
+                schema.field(*index).computed_expr(),
+                Some(ComputedExpr::Virtual(_))
+            ) {
+                return Err(ErrorCode::InvalidPartitionKey(format!(
+                    "Partition by expression `{:#}` can't reference virtual computed column",
+                    partition_by
+                )));
+            }
+        }
+
+        let expr = partition_key.as_expr()?;
+        if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+            return Err(ErrorCode::InvalidPartitionKey(format!(
+                "Partition by expression `{:#}` is not deterministic",
+                partition_by
+            )));
+        }
+
+        let data_type = expr.data_type();
+        if !Self::valid_cluster_key_type(data_type) {
+            return Err(ErrorCode::InvalidPartitionKey(format!(
+                "Unsupported data type '{}' for partition by expression `{:#}`",
+                data_type, partition_by
+            )));
+        }
+
+        let mut partition_by = partition_by.clone();
+        walk_expr_mut(
+            &mut IdentifierNormalizer {
+                ctx: &self.name_resolution_ctx,
+            },
+            &mut partition_by,
+        );
+        Ok(format!("{:#}", &partition_by))
+    }
+
     fn valid_cluster_key_type(data_type: &DataType) -> bool {
         let inner_type = data_type.remove_nullable();
         matches!(
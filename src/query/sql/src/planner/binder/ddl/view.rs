@@ -13,16 +13,34 @@
 // limitations under the License.
 
 use common_ast::ast::AlterViewStmt;
+use common_ast::ast::CreateMaterializedViewStmt;
 use common_ast::ast::CreateViewStmt;
+use common_ast::ast::DropMaterializedViewStmt;
 use common_ast::ast::DropViewStmt;
+use common_ast::ast::Expr;
+use common_ast::ast::GroupBy;
+use common_ast::ast::Query;
+use common_ast::ast::RefreshMaterializedViewStmt;
+use common_ast::ast::SelectTarget;
+use common_ast::ast::SetExpr;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::infer_schema_type;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
 
 use crate::binder::Binder;
+use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
 use crate::plans::AlterViewPlan;
+use crate::plans::CreateMaterializedViewPlan;
 use crate::plans::CreateViewPlan;
+use crate::plans::DropMaterializedViewPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::Plan;
+use crate::plans::RefreshMaterializedViewPlan;
+use crate::plans::RelOperator;
+use crate::BindContext;
 
 impl Binder {
     #[async_backtrace::framed]
@@ -32,6 +50,7 @@ impl Binder {
     ) -> Result<Plan> {
         let CreateViewStmt {
             if_not_exists,
+            temporary,
             catalog,
             database,
             view,
@@ -39,6 +58,14 @@ impl Binder {
             query,
         } = stmt;
 
+        if *temporary && (catalog.is_some() || database.is_some()) {
+            return Err(ErrorCode::SemanticError(format!(
+                "Temporary view `{}` can't be qualified by database",
+                view
+            ))
+            .set_span(view.span));
+        }
+
         let tenant = self.ctx.get_tenant();
         let (catalog, database, view_name) =
             self.normalize_object_identifier_triple(catalog, database, view);
@@ -50,6 +77,7 @@ impl Binder {
 
         let plan = CreateViewPlan {
             if_not_exists: *if_not_exists,
+            temporary: *temporary,
             tenant,
             catalog,
             database,
@@ -105,11 +133,20 @@ impl Binder {
             view,
         } = stmt;
 
+        // An unqualified name refers to the temporary view first.
+        let temporary = catalog.is_none()
+            && database.is_none()
+            && self
+                .ctx
+                .get_temporary_view(&normalize_identifier(view, &self.name_resolution_ctx).name)
+                .is_some();
+
         let tenant = self.ctx.get_tenant();
         let (catalog, database, view_name) =
             self.normalize_object_identifier_triple(catalog, database, view);
         let plan = DropViewPlan {
             if_exists: *if_exists,
+            temporary,
             tenant,
             catalog,
             database,
@@ -117,4 +154,225 @@ impl Binder {
         };
         Ok(Plan::DropView(Box::new(plan)))
     }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_materialized_view(
+        &mut self,
+        stmt: &CreateMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let CreateMaterializedViewStmt {
+            if_not_exists,
+            catalog,
+            database,
+            view,
+            query,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+
+        let mut bind_context = BindContext::new();
+        let (s_expr, bind_context) = self.bind_query(&mut bind_context, query).await?;
+        let fields = bind_context
+            .columns
+            .iter()
+            .map(|column_binding| {
+                Ok(TableField::new(
+                    &column_binding.column_name,
+                    infer_schema_type(&column_binding.data_type)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let schema = TableSchemaRefExt::create(fields);
+        Self::validate_create_table_schema(&schema)?;
+
+        // The view is refreshed with the changes of its source tables tracked by streams,
+        // which are only supported on FUSE tables of the catalog of the view.
+        let metadata = self.metadata.read();
+        let mut source_tables: Vec<(String, String)> = vec![];
+        for table in metadata.tables() {
+            if table.catalog() != catalog || table.table().engine() != "FUSE" {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Materialized view can only read FUSE tables of catalog '{}', but `{}`.`{}` is of engine {}",
+                    catalog,
+                    table.database(),
+                    table.name(),
+                    table.table().engine()
+                )));
+            }
+            let source = (table.database().to_string(), table.name().to_string());
+            if !source_tables.contains(&source) {
+                source_tables.push(source);
+            }
+        }
+        if source_tables.is_empty() {
+            return Err(ErrorCode::SemanticError(
+                "Materialized view must read at least one table",
+            ));
+        }
+        let incremental = metadata.tables().len() == 1 && is_filter_project(&s_expr);
+        let aggregates = if metadata.tables().len() == 1 && is_aggregate_of_filter_project(&s_expr)
+        {
+            merge_functions(query)
+        } else {
+            None
+        };
+
+        let plan = CreateMaterializedViewPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            catalog,
+            database,
+            view_name,
+            schema,
+            subquery: format!("{}", query),
+            source_tables,
+            incremental,
+            aggregates,
+        };
+        Ok(Plan::CreateMaterializedView(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_materialized_view(
+        &mut self,
+        stmt: &RefreshMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let RefreshMaterializedViewStmt {
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let plan = RefreshMaterializedViewPlan {
+            tenant,
+            catalog,
+            database,
+            view_name,
+        };
+        Ok(Plan::RefreshMaterializedView(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_materialized_view(
+        &mut self,
+        stmt: &DropMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let DropMaterializedViewStmt {
+            if_exists,
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let plan = DropMaterializedViewPlan {
+            if_exists: *if_exists,
+            tenant,
+            catalog,
+            database,
+            view_name,
+        };
+        Ok(Plan::DropMaterializedView(Box::new(plan)))
+    }
+}
+
+/// Whether the query only filters and projects the rows it scans, so that the result over
+/// the union of two sets of rows is the union of the results over each of them.
+fn is_filter_project(s_expr: &SExpr) -> bool {
+    match s_expr.plan() {
+        RelOperator::Scan(_) => true,
+        RelOperator::Filter(_) | RelOperator::EvalScalar(_) => s_expr
+            .children()
+            .iter()
+            .all(|child| is_filter_project(child)),
+        _ => false,
+    }
+}
+
+/// Whether the query aggregates the rows of one table, without filtering the groups.
+fn is_aggregate_of_filter_project(s_expr: &SExpr) -> bool {
+    match s_expr.plan() {
+        RelOperator::EvalScalar(_) => s_expr
+            .children()
+            .iter()
+            .all(|child| is_aggregate_of_filter_project(child)),
+        RelOperator::Aggregate(aggregate) => {
+            aggregate.grouping_sets.is_empty()
+                && s_expr
+                    .children()
+                    .iter()
+                    .all(|child| is_filter_project(child))
+        }
+        _ => false,
+    }
+}
+
+/// The functions merging each column of the results of an aggregation over two sets of rows
+/// into its result over the union of them, empty for the group keys.
+///
+/// Returns `None` if a column can't be merged, e.g. an average or an expression of aggregates,
+/// or if a group key is not in the result.
+fn merge_functions(query: &Query) -> Option<Vec<String>> {
+    if query.with.is_some()
+        || !query.order_by.is_empty()
+        || !query.limit.is_empty()
+        || query.offset.is_some()
+    {
+        return None;
+    }
+    let SetExpr::Select(select) = &query.body else {
+        return None;
+    };
+    if select.distinct || select.having.is_some() {
+        return None;
+    }
+    let group_keys = match &select.group_by {
+        None => vec![],
+        Some(GroupBy::Normal(exprs)) => exprs.iter().map(|expr| expr.to_string()).collect(),
+        Some(_) => return None,
+    };
+
+    let mut selected_keys = vec![];
+    let mut functions = Vec::with_capacity(select.select_list.len());
+    for target in &select.select_list {
+        let SelectTarget::AliasedExpr { expr, .. } = target else {
+            return None;
+        };
+        let function = match expr.as_ref() {
+            Expr::CountAll { window: None, .. } => Some("sum"),
+            Expr::FunctionCall {
+                distinct: false,
+                name,
+                window: None,
+                ..
+            } => match name.name.to_lowercase().as_str() {
+                "count" | "sum" => Some("sum"),
+                "min" => Some("min"),
+                "max" => Some("max"),
+                _ => None,
+            },
+            _ => None,
+        };
+        let function = match function {
+            Some(function) => function,
+            None if group_keys.contains(&expr.to_string()) => {
+                selected_keys.push(expr.to_string());
+                ""
+            }
+            None => return None,
+        };
+        functions.push(function.to_string());
+    }
+    if group_keys.iter().all(|key| selected_keys.contains(key)) {
+        Some(functions)
+    } else {
+        None
+    }
 }
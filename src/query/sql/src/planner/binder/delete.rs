@@ -203,7 +203,8 @@ impl Binder {
             | ScalarExpr::ConstantExpr(_)
             | ScalarExpr::WindowFunction(_)
             | ScalarExpr::AggregateFunction(_)
-            | ScalarExpr::LambdaFunction(_) => {}
+            | ScalarExpr::LambdaFunction(_)
+            | ScalarExpr::UDFCall(_) => {}
         }
         Ok(())
     }
@@ -82,6 +82,13 @@ impl Binder {
                     "cross join should not contain join conditions".to_string(),
                 ));
             }
+            JoinOperator::Asof | JoinOperator::LeftAsof
+                if !matches!(join.condition, JoinCondition::On(_)) =>
+            {
+                return Err(ErrorCode::SemanticError(
+                    "asof join should contain ON conditions".to_string(),
+                ));
+            }
             _ => (),
         };
 
@@ -111,6 +118,15 @@ impl Binder {
             )
             .await?;
 
+        if matches!(join.op, JoinOperator::Asof | JoinOperator::LeftAsof) {
+            check_asof_conditions(
+                &left_context,
+                &right_context,
+                &left_join_conditions,
+                &mut non_equi_conditions,
+            )?;
+        }
+
         let join_conditions = JoinConditions {
             left_conditions: left_join_conditions,
             right_conditions: right_join_conditions,
@@ -170,6 +186,15 @@ impl Binder {
                     right_child,
                 )
             }
+            JoinOperator::Asof => {
+                self.bind_join_with_type(JoinType::Asof, join_conditions, left_child, right_child)
+            }
+            JoinOperator::LeftAsof => self.bind_join_with_type(
+                JoinType::LeftAsof,
+                join_conditions,
+                left_child,
+                right_child,
+            ),
         }?;
         Ok((s_expr, bind_context))
     }
@@ -295,7 +320,7 @@ fn wrap_nullable_for_column(
     bind_context: &mut BindContext,
 ) {
     match join_type {
-        JoinOperator::LeftOuter => {
+        JoinOperator::LeftOuter | JoinOperator::LeftAsof => {
             for column in left_context.all_column_bindings() {
                 bind_context.add_column_binding(column.clone());
             }
@@ -340,6 +365,63 @@ fn wrap_nullable_for_column(
     }
 }
 
+// Asof join needs equi conditions and a single comparison between the both sides,
+// the comparison is rewritten to put the expression of the left side first.
+fn check_asof_conditions(
+    left_context: &BindContext,
+    right_context: &BindContext,
+    left_conditions: &[ScalarExpr],
+    non_equi_conditions: &mut [ScalarExpr],
+) -> Result<()> {
+    if left_conditions.is_empty() || non_equi_conditions.len() != 1 {
+        return Err(ErrorCode::SemanticError(
+            "asof join should contain equi conditions and exactly one non-equi condition, e.g. `t1.a = t2.a AND t1.ts >= t2.ts`",
+        ));
+    }
+
+    let columns = |context: &BindContext| {
+        context
+            .all_column_bindings()
+            .iter()
+            .map(|column| column.index)
+            .collect::<ColumnSet>()
+    };
+    let (left_columns, right_columns) = (columns(left_context), columns(right_context));
+    let is_side = |scalar: &ScalarExpr, side: &ColumnSet| {
+        let used_columns = scalar.used_columns();
+        !used_columns.is_empty() && used_columns.is_subset(side)
+    };
+
+    if let ScalarExpr::FunctionCall(func) = &mut non_equi_conditions[0] {
+        if func.arguments.len() == 2
+            && matches!(func.func_name.as_str(), "gt" | "lt" | "gte" | "lte")
+        {
+            if is_side(&func.arguments[0], &left_columns)
+                && is_side(&func.arguments[1], &right_columns)
+            {
+                return Ok(());
+            }
+            if is_side(&func.arguments[0], &right_columns)
+                && is_side(&func.arguments[1], &left_columns)
+            {
+                func.arguments.swap(0, 1);
+                func.func_name = match func.func_name.as_str() {
+                    "gt" => "lt",
+                    "lt" => "gt",
+                    "gte" => "lte",
+                    _ => "gte",
+                }
+                .to_string();
+                return Ok(());
+            }
+        }
+    }
+
+    Err(ErrorCode::SemanticError(
+        "the non-equi condition of asof join should compare the left side with the right side by one of `>`, `>=`, `<`, `<=`",
+    ))
+}
+
 pub fn check_duplicate_join_tables(
     left_context: &BindContext,
     right_context: &BindContext,
@@ -690,7 +772,7 @@ impl<'a> JoinConditionResolver<'a> {
         let predicate_used_columns = predicate.used_columns();
         let (left_columns, right_columns) = self.left_right_columns()?;
         match self.join_op {
-            JoinOperator::LeftOuter => {
+            JoinOperator::LeftOuter | JoinOperator::LeftAsof => {
                 if predicate_used_columns.is_subset(&right_columns) {
                     other_join_conditions.push(predicate);
                     return Ok(true);
@@ -702,7 +784,7 @@ impl<'a> JoinConditionResolver<'a> {
                     return Ok(true);
                 }
             }
-            JoinOperator::Inner => {
+            JoinOperator::Inner | JoinOperator::Asof => {
                 if predicate_used_columns.is_subset(&left_columns)
                     || predicate_used_columns.is_subset(&right_columns)
                 {
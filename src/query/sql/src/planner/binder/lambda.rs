@@ -218,6 +218,9 @@ impl<'a> LambdaRewriter<'a> {
 
                 Ok(replaced_lambda.into())
             }
+
+            // WASM and UDF server functions have been rewritten by `UdfRewriter`.
+            ScalarExpr::UDFCall(_) => Ok(scalar.clone()),
         }
     }
 }
@@ -32,6 +32,7 @@ use common_meta_app::storage::StorageWebhdfsConfig;
 use common_meta_app::storage::STORAGE_GCS_DEFAULT_ENDPOINT;
 use common_meta_app::storage::STORAGE_IPFS_DEFAULT_ENDPOINT;
 use common_meta_app::storage::STORAGE_S3_DEFAULT_ENDPOINT;
+use common_storage::SecretsProvider;
 use opendal::Scheme;
 use percent_encoding::percent_decode_str;
 
@@ -171,6 +172,38 @@ fn parse_s3_params(l: &mut UriLocation, root: String) -> Result<StorageParams> {
         )
     })?;
 
+    let secrets_provider = l
+        .connection
+        .get("secrets_provider")
+        .cloned()
+        .unwrap_or_default();
+    if !secrets_provider.is_empty() {
+        let provider = secrets_provider.parse::<SecretsProvider>()?;
+        // The providers read the secrets with the identity of the query node itself.
+        if provider.is_local() && !GlobalConfig::instance().storage.allow_insecure {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                anyhow!("secrets provider {secrets_provider} is not allowed"),
+            ));
+        }
+    }
+
+    let server_side_encryption = l
+        .connection
+        .get("server_side_encryption")
+        .cloned()
+        .unwrap_or_default();
+    let server_side_encryption_aws_kms_key_id = l
+        .connection
+        .get("server_side_encryption_aws_kms_key_id")
+        .cloned()
+        .unwrap_or_default();
+    let server_side_encryption_customer_key = l
+        .connection
+        .get("server_side_encryption_customer_key")
+        .cloned()
+        .unwrap_or_default();
+
     let sp = StorageParams::S3(StorageS3Config {
         endpoint_url: secure_omission(endpoint),
         region,
@@ -187,6 +220,10 @@ fn parse_s3_params(l: &mut UriLocation, root: String) -> Result<StorageParams> {
         role_arn,
         external_id,
         allow_anonymous,
+        secrets_provider,
+        server_side_encryption,
+        server_side_encryption_aws_kms_key_id,
+        server_side_encryption_customer_key,
     });
 
     l.connection.check()?;
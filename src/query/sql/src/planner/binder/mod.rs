@@ -46,6 +46,7 @@ mod sort;
 mod stage;
 mod table;
 mod table_args;
+mod udf;
 mod update;
 mod values;
 mod window;
@@ -57,10 +58,13 @@ pub use builders::*;
 pub use column_binding::ColumnBinding;
 pub use column_binding::ColumnBindingBuilder;
 pub use copy::parse_stage_location;
+pub use copy::parse_stage_location_v2;
 pub use internal_column_factory::INTERNAL_COLUMN_FACTORY;
 pub use location::parse_uri_location;
 pub use scalar::ScalarBinder;
 pub use scalar_common::*;
 pub use scalar_visitor::*;
 pub use table::parse_result_scan_args;
+pub use udf::check_udf_server_address;
+pub use udf::read_wasm_module;
 pub use window::WindowOrderByInfo;
@@ -206,6 +206,10 @@ pub fn prune_by_children(scalar: &ScalarExpr, columns: &HashSet<ScalarExpr>) ->
             .args
             .iter()
             .all(|arg| prune_by_children(arg, columns)),
+        ScalarExpr::UDFCall(scalar) => scalar
+            .arguments
+            .iter()
+            .all(|arg| prune_by_children(arg, columns)),
         ScalarExpr::FunctionCall(scalar) => scalar
             .arguments
             .iter()
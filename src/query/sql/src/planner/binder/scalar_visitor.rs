@@ -58,6 +58,11 @@ pub trait ScalarVisitor: Sized {
                                         stack.push(RecursionProcessing::Call(arg));
                                     }
                                 }
+                                ScalarExpr::UDFCall(func) => {
+                                    for arg in &func.arguments {
+                                        stack.push(RecursionProcessing::Call(arg));
+                                    }
+                                }
                                 ScalarExpr::WindowFunction(WindowFunc {
                                     func,
                                     partition_by,
@@ -65,6 +65,11 @@ use crate::plans::JoinType;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::UnionAll;
+use crate::plans::Window;
+use crate::plans::WindowFuncFrame;
+use crate::plans::WindowFuncFrameBound;
+use crate::plans::WindowFuncFrameUnits;
+use crate::plans::WindowFuncType;
 use crate::ColumnBinding;
 use crate::ColumnEntry;
 use crate::IndexType;
@@ -165,6 +170,9 @@ impl Binder {
             .normalize_select_list(&mut from_context, &stmt.select_list)
             .await?;
 
+        // analyze WASM and UDF server functions, they are evaluated before lambda functions
+        self.analyze_udf(&mut from_context, &mut select_list)?;
+
         // analyze lambda
         self.analyze_lambda(&mut from_context, &mut select_list)?;
 
@@ -236,6 +244,10 @@ impl Binder {
             )?;
         }
 
+        if !from_context.udf_info.udf_functions.is_empty() {
+            s_expr = self.bind_udf(&mut from_context, s_expr).await?;
+        }
+
         if !from_context.lambda_info.lambda_functions.is_empty() {
             s_expr = self.bind_lambda(&mut from_context, s_expr).await?;
         }
@@ -437,13 +449,15 @@ impl Binder {
             self.bind_set_expr(bind_context, right, &[], 0).await?;
 
         if left_bind_context.columns.len() != right_bind_context.columns.len() {
-            return Err(ErrorCode::SemanticError(
-                "SetOperation must have the same number of columns",
-            ));
+            return Err(ErrorCode::SemanticError(format!(
+                "SetOperation must have the same number of columns, but the left side has {} columns and the right side has {} columns",
+                left_bind_context.columns.len(),
+                right_bind_context.columns.len()
+            )));
         }
 
-        match (op, all) {
-            (SetOperator::Intersect, false) => {
+        match op {
+            SetOperator::Intersect => {
                 // Transfer Intersect to Semi join
                 self.bind_intersect(
                     left.span(),
@@ -452,9 +466,10 @@ impl Binder {
                     right_bind_context,
                     left_expr,
                     right_expr,
+                    *all,
                 )
             }
-            (SetOperator::Except, false) => {
+            SetOperator::Except => {
                 // Transfer Except to Anti join
                 self.bind_except(
                     left.span(),
@@ -463,29 +478,18 @@ impl Binder {
                     right_bind_context,
                     left_expr,
                     right_expr,
+                    *all,
                 )
             }
-            (SetOperator::Union, true) => self.bind_union(
+            SetOperator::Union => self.bind_union(
                 left.span(),
                 right.span(),
                 left_bind_context,
                 right_bind_context,
                 left_expr,
                 right_expr,
-                false,
+                !*all,
             ),
-            (SetOperator::Union, false) => self.bind_union(
-                left.span(),
-                right.span(),
-                left_bind_context,
-                right_bind_context,
-                left_expr,
-                right_expr,
-                true,
-            ),
-            _ => Err(ErrorCode::Unimplemented(
-                "Unsupported query type, currently, databend only support intersect distinct and except distinct",
-            )),
         }
     }
 
@@ -500,33 +504,8 @@ impl Binder {
         right_expr: SExpr,
         distinct: bool,
     ) -> Result<(SExpr, BindContext)> {
-        let mut coercion_types = Vec::with_capacity(left_context.columns.len());
-        for (left_col, right_col) in left_context
-            .columns
-            .iter()
-            .zip(right_context.columns.iter())
-        {
-            if left_col.data_type != right_col.data_type {
-                if let Some(data_type) = common_super_type(
-                    *left_col.data_type.clone(),
-                    *right_col.data_type.clone(),
-                    &BUILTIN_FUNCTIONS.default_cast_rules,
-                ) {
-                    coercion_types.push(data_type);
-                } else {
-                    return Err(ErrorCode::SemanticError(format!(
-                        "SetOperation's types cannot be matched, left column {:?}, type: {:?}, right column {:?}, type: {:?}",
-                        left_col.column_name,
-                        left_col.data_type,
-                        right_col.column_name,
-                        right_col.data_type
-                    )));
-                }
-            } else {
-                coercion_types.push(*left_col.data_type.clone());
-            }
-        }
-        let (new_bind_context, pairs, left_expr, right_expr) = self.coercion_union_type(
+        let coercion_types = self.coercion_set_operation_types(&left_context, &right_context)?;
+        let (new_bind_context, right_context, left_expr, right_expr) = self.coercion_union_type(
             left_span,
             right_span,
             left_context,
@@ -535,6 +514,12 @@ impl Binder {
             right_expr,
             coercion_types,
         )?;
+        let pairs = new_bind_context
+            .columns
+            .iter()
+            .zip(right_context.columns.iter())
+            .map(|(left_col, right_col)| (left_col.index, right_col.index))
+            .collect();
 
         let union_plan = UnionAll { pairs };
         let mut new_expr = SExpr::create_binary(
@@ -556,6 +541,7 @@ impl Binder {
         Ok((new_expr, new_bind_context))
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn bind_intersect(
         &mut self,
         left_span: Span,
@@ -564,6 +550,7 @@ impl Binder {
         right_context: BindContext,
         left_expr: SExpr,
         right_expr: SExpr,
+        all: bool,
     ) -> Result<(SExpr, BindContext)> {
         self.bind_intersect_or_except(
             left_span,
@@ -573,9 +560,11 @@ impl Binder {
             left_expr,
             right_expr,
             JoinType::LeftSemi,
+            all,
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn bind_except(
         &mut self,
         left_span: Span,
@@ -584,6 +573,7 @@ impl Binder {
         right_context: BindContext,
         left_expr: SExpr,
         right_expr: SExpr,
+        all: bool,
     ) -> Result<(SExpr, BindContext)> {
         self.bind_intersect_or_except(
             left_span,
@@ -593,9 +583,16 @@ impl Binder {
             left_expr,
             right_expr,
             JoinType::LeftAnti,
+            all,
         )
     }
 
+    /// Bind `INTERSECT` and `EXCEPT` to semi join and anti join.
+    ///
+    /// The `ALL` variants keep the duplicated rows, so the rows with the same values
+    /// on each side are numbered by `row_number()`, and the numbers are joined as well.
+    /// Then `INTERSECT ALL` keeps `min(m, n)` copies of a row that appears `m` times
+    /// on the left side and `n` times on the right side, and `EXCEPT ALL` keeps `m - n` copies.
     #[allow(clippy::too_many_arguments)]
     pub fn bind_intersect_or_except(
         &mut self,
@@ -606,16 +603,21 @@ impl Binder {
         left_expr: SExpr,
         right_expr: SExpr,
         join_type: JoinType,
+        all: bool,
     ) -> Result<(SExpr, BindContext)> {
-        let left_expr = self.bind_distinct(
+        let coercion_types = self.coercion_set_operation_types(&left_context, &right_context)?;
+        let (left_context, right_context, left_expr, right_expr) = self.coercion_union_type(
             left_span,
-            &left_context,
-            left_context.all_column_bindings(),
-            &mut HashMap::new(),
+            right_span,
+            left_context,
+            right_context,
             left_expr,
+            right_expr,
+            coercion_types,
         )?;
-        let mut left_conditions = Vec::with_capacity(left_context.columns.len());
-        let mut right_conditions = Vec::with_capacity(right_context.columns.len());
+
+        let mut left_conditions = Vec::with_capacity(left_context.columns.len() + 1);
+        let mut right_conditions = Vec::with_capacity(right_context.columns.len() + 1);
         assert_eq!(left_context.columns.len(), right_context.columns.len());
         for (left_column, right_column) in left_context
             .columns
@@ -637,6 +639,38 @@ impl Binder {
                 .into(),
             );
         }
+
+        let (left_expr, right_expr) = if all {
+            let (left_expr, left_row_number) =
+                self.bind_row_number_of_duplicates(left_span, &left_context, left_expr);
+            let (right_expr, right_row_number) =
+                self.bind_row_number_of_duplicates(right_span, &right_context, right_expr);
+            left_conditions.push(
+                BoundColumnRef {
+                    span: left_span,
+                    column: left_row_number,
+                }
+                .into(),
+            );
+            right_conditions.push(
+                BoundColumnRef {
+                    span: right_span,
+                    column: right_row_number,
+                }
+                .into(),
+            );
+            (left_expr, right_expr)
+        } else {
+            let left_expr = self.bind_distinct(
+                left_span,
+                &left_context,
+                left_context.all_column_bindings(),
+                &mut HashMap::new(),
+                left_expr,
+            )?;
+            (left_expr, right_expr)
+        };
+
         let join_conditions = JoinConditions {
             left_conditions,
             right_conditions,
@@ -647,6 +681,96 @@ impl Binder {
         Ok((s_expr, left_context))
     }
 
+    /// Number the rows with the same values by `row_number() over (partition by <all columns>)`.
+    fn bind_row_number_of_duplicates(
+        &self,
+        span: Span,
+        bind_context: &BindContext,
+        child: SExpr,
+    ) -> (SExpr, ColumnBinding) {
+        let function = WindowFuncType::RowNumber;
+        let data_type = function.return_type();
+        let index = self
+            .metadata
+            .write()
+            .add_derived_column("_row_number".to_string(), data_type.clone());
+        let row_number = ColumnBindingBuilder::new(
+            "_row_number".to_string(),
+            index,
+            Box::new(data_type),
+            Visibility::InVisible,
+        )
+        .build();
+
+        let partition_by = bind_context
+            .columns
+            .iter()
+            .map(|column| ScalarItem {
+                scalar: BoundColumnRef {
+                    span,
+                    column: column.clone(),
+                }
+                .into(),
+                index: column.index,
+            })
+            .collect();
+        let window_plan = Window {
+            span,
+            index,
+            function,
+            arguments: vec![],
+            partition_by,
+            order_by: vec![],
+            frame: WindowFuncFrame {
+                units: WindowFuncFrameUnits::Range,
+                start_bound: WindowFuncFrameBound::Preceding(None),
+                end_bound: WindowFuncFrameBound::Following(None),
+            },
+        };
+        let s_expr = SExpr::create_unary(Arc::new(window_plan.into()), Arc::new(child));
+        (s_expr, row_number)
+    }
+
+    /// Find the common super types of the columns of both sides of a set operation.
+    fn coercion_set_operation_types(
+        &self,
+        left_context: &BindContext,
+        right_context: &BindContext,
+    ) -> Result<Vec<DataType>> {
+        let mut coercion_types = Vec::with_capacity(left_context.columns.len());
+        for (idx, (left_col, right_col)) in left_context
+            .columns
+            .iter()
+            .zip(right_context.columns.iter())
+            .enumerate()
+        {
+            if left_col.data_type != right_col.data_type {
+                if let Some(data_type) = common_super_type(
+                    *left_col.data_type.clone(),
+                    *right_col.data_type.clone(),
+                    &BUILTIN_FUNCTIONS.default_cast_rules,
+                ) {
+                    coercion_types.push(data_type);
+                } else {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "SetOperation's types cannot be matched, column #{} of the left side {:?} is {}, but column #{} of the right side {:?} is {}",
+                        idx + 1,
+                        left_col.column_name,
+                        left_col.data_type,
+                        idx + 1,
+                        right_col.column_name,
+                        right_col.data_type
+                    )));
+                }
+            } else {
+                coercion_types.push(*left_col.data_type.clone());
+            }
+        }
+        Ok(coercion_types)
+    }
+
+    /// Cast the columns of both sides to `coercion_types`, returns the new bind contexts
+    /// and expressions of the left side and the right side.
     #[allow(clippy::type_complexity)]
     #[allow(clippy::too_many_arguments)]
     fn coercion_union_type(
@@ -658,18 +782,18 @@ impl Binder {
         mut left_expr: SExpr,
         mut right_expr: SExpr,
         coercion_types: Vec<DataType>,
-    ) -> Result<(BindContext, Vec<(IndexType, IndexType)>, SExpr, SExpr)> {
+    ) -> Result<(BindContext, BindContext, SExpr, SExpr)> {
         let mut left_scalar_items = Vec::with_capacity(left_bind_context.columns.len());
         let mut right_scalar_items = Vec::with_capacity(right_bind_context.columns.len());
         let mut new_bind_context = BindContext::new();
-        let mut pairs = Vec::with_capacity(left_bind_context.columns.len());
+        let mut new_right_bind_context = BindContext::new();
         for (idx, (left_col, right_col)) in left_bind_context
             .columns
             .iter()
             .zip(right_bind_context.columns.iter())
             .enumerate()
         {
-            let left_index = if *left_col.data_type != coercion_types[idx] {
+            if *left_col.data_type != coercion_types[idx] {
                 let new_column_index = self
                     .metadata
                     .write()
@@ -698,16 +822,21 @@ impl Binder {
                     index: new_column_index,
                 });
                 new_bind_context.add_column_binding(column_binding);
-                new_column_index
             } else {
                 new_bind_context.add_column_binding(left_col.clone());
-                left_col.index
-            };
-            let right_index = if *right_col.data_type != coercion_types[idx] {
+            }
+            if *right_col.data_type != coercion_types[idx] {
                 let new_column_index = self
                     .metadata
                     .write()
                     .add_derived_column(right_col.column_name.clone(), coercion_types[idx].clone());
+                let column_binding = ColumnBindingBuilder::new(
+                    right_col.column_name.clone(),
+                    new_column_index,
+                    Box::new(coercion_types[idx].clone()),
+                    Visibility::Visible,
+                )
+                .build();
                 let right_coercion_expr = CastExpr {
                     span: right_span,
                     is_try: false,
@@ -724,11 +853,10 @@ impl Binder {
                     scalar: right_coercion_expr.into(),
                     index: new_column_index,
                 });
-                new_column_index
+                new_right_bind_context.add_column_binding(column_binding);
             } else {
-                right_col.index
-            };
-            pairs.push((left_index, right_index));
+                new_right_bind_context.add_column_binding(right_col.clone());
+            }
         }
         if !left_scalar_items.is_empty() {
             left_expr = SExpr::create_unary(
@@ -752,7 +880,12 @@ impl Binder {
                 Arc::new(right_expr),
             );
         }
-        Ok((new_bind_context, pairs, left_expr, right_expr))
+        Ok((
+            new_bind_context,
+            new_right_bind_context,
+            left_expr,
+            right_expr,
+        ))
     }
 
     #[allow(clippy::too_many_arguments)]
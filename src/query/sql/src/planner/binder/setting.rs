@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use chrono_tz::Tz;
 use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
+use common_ast::ast::SettingsClause;
 use common_ast::ast::UnSetSource;
 use common_ast::ast::UnSetStmt;
 use common_exception::ErrorCode;
@@ -71,6 +73,51 @@ impl Binder {
         }
     }
 
+    /// Applies the `SETTINGS (...)` clause to the settings of the current query only,
+    /// the session settings are left untouched.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_statement_settings(
+        &mut self,
+        bind_context: &mut BindContext,
+        settings: &SettingsClause,
+    ) -> Result<()> {
+        let mut type_checker = TypeChecker::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            false,
+            false,
+        );
+
+        let query_settings = self.ctx.get_settings();
+        for item in &settings.items {
+            let variable = item.name.name.clone();
+            let (scalar, _) = *type_checker.resolve(&item.value).await?;
+            let scalar = wrap_cast(&scalar, &DataType::String);
+            let expr = scalar.as_expr()?;
+
+            let (new_expr, _) =
+                ConstantFolder::fold(&expr, &self.ctx.get_function_context()?, &BUILTIN_FUNCTIONS);
+            match new_expr {
+                common_expression::Expr::Constant { scalar, .. } => {
+                    let value = String::from_utf8(scalar.into_string().unwrap())?;
+                    if variable.to_lowercase().as_str() == "timezone" {
+                        let tz = value.trim_matches(|c| c == '\'' || c == '\"');
+                        tz.parse::<Tz>().map_err(|_| {
+                            ErrorCode::InvalidTimezone(format!("Invalid Timezone: {:?}", value))
+                        })?;
+                    }
+                    query_settings.set_setting(variable, value)?;
+                }
+                _ => return Err(ErrorCode::SemanticError("value must be constant value")),
+            }
+        }
+
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_unset_variable(
         &mut self,
@@ -81,14 +128,20 @@ impl Binder {
             UnSetSource::Var { variable } => {
                 let variable = variable.name;
                 let vars = vec![variable];
-                Ok(Plan::UnSetVariable(Box::new(UnSettingPlan { vars })))
+                Ok(Plan::UnSetVariable(Box::new(UnSettingPlan {
+                    is_session: stmt.is_session,
+                    vars,
+                })))
             }
             UnSetSource::Vars { variables } => {
                 let mut vars: Vec<String> = vec![];
                 for var in variables {
                     vars.push(var.name.clone());
                 }
-                Ok(Plan::UnSetVariable(Box::new(UnSettingPlan { vars })))
+                Ok(Plan::UnSetVariable(Box::new(UnSettingPlan {
+                    is_session: stmt.is_session,
+                    vars,
+                })))
             }
         }
     }
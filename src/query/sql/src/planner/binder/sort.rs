@@ -40,6 +40,7 @@ use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::Sort;
 use crate::plans::SortItem;
+use crate::plans::UDFCall;
 use crate::BindContext;
 use crate::IndexType;
 use crate::WindowChecker;
@@ -364,6 +365,19 @@ impl Binder {
                         return_type: lambda_func.return_type.clone(),
                     }))
                 }
+                ScalarExpr::UDFCall(udf) => {
+                    let arguments = udf
+                        .arguments
+                        .iter()
+                        .map(|arg| {
+                            self.rewrite_scalar_with_replacement(bind_context, arg, replacement_fn)
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    Ok(ScalarExpr::UDFCall(UDFCall {
+                        arguments,
+                        ..udf.clone()
+                    }))
+                }
                 window @ ScalarExpr::WindowFunction(_) => {
                     let mut rewriter = WindowRewriter::new(bind_context, self.metadata.clone());
                     rewriter.visit(window)
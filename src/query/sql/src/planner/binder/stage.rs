@@ -34,16 +34,20 @@ use common_expression::Expr;
 use common_expression::Scalar;
 use common_expression::Value;
 use common_pipeline_transforms::processors::transforms::Transform;
+use common_users::UserApiProvider;
 use indexmap::IndexMap;
 
 use crate::binder::wrap_cast;
 use crate::binder::wrap_cast_scalar;
 use crate::evaluator::BlockOperator;
 use crate::evaluator::CompoundBlockOperator;
+use crate::identity_sequence_name;
+use crate::plans::ConstantExpr;
 use crate::BindContext;
 use crate::MetadataRef;
 use crate::NameResolutionContext;
 use crate::ScalarBinder;
+use crate::ScalarExpr;
 
 impl BindContext {
     pub async fn exprs_to_scalar(
@@ -78,7 +82,8 @@ impl BindContext {
             if let AExpr::ColumnRef { column, .. } = expr {
                 if column.name().eq_ignore_ascii_case("default") {
                     let field = schema.field(i);
-                    fill_default_value(&mut scalar_binder, &mut map_exprs, field, schema).await?;
+                    fill_default_value(&ctx, &mut scalar_binder, &mut map_exprs, field, schema)
+                        .await?;
                     continue;
                 }
             }
@@ -121,12 +126,26 @@ impl BindContext {
 }
 
 async fn fill_default_value(
+    ctx: &Arc<dyn TableContext>,
     binder: &mut ScalarBinder<'_>,
     map_exprs: &mut Vec<Expr>,
     field: &DataField,
     schema: &DataSchema,
 ) -> Result<()> {
-    if let Some(default_expr) = field.default_expr() {
+    if let Some(sequence) = field.default_expr().and_then(|e| identity_sequence_name(e)) {
+        // The default of an identity column is the next number of its sequence.
+        let allocated = UserApiProvider::instance()
+            .allocate_sequence_values(&ctx.get_tenant(), &sequence, 1)
+            .await?;
+        let scalar = ScalarExpr::ConstantExpr(ConstantExpr {
+            span: None,
+            value: Scalar::Number(NumberScalar::Int64(allocated.next_value)),
+        });
+        let expr = wrap_cast(&scalar, field.data_type())
+            .as_expr()?
+            .project_column_ref(|col| schema.index_of(&col.index.to_string()).unwrap());
+        map_exprs.push(expr);
+    } else if let Some(default_expr) = field.default_expr() {
         let tokens = tokenize_sql(default_expr)?;
         let ast = parse_expr(&tokens, Dialect::PostgreSQL)?;
         let (mut scalar, _) = binder.bind(&ast).await?;
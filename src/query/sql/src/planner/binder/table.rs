@@ -19,10 +19,13 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use async_recursion::async_recursion;
+use chrono::Duration;
 use chrono::TimeZone;
 use chrono::Utc;
+use common_ast::ast::Expr;
 use common_ast::ast::Indirection;
 use common_ast::ast::Join;
+use common_ast::ast::Query;
 use common_ast::ast::SelectStmt;
 use common_ast::ast::SelectTarget;
 use common_ast::ast::Statement;
@@ -32,6 +35,7 @@ use common_ast::ast::TimeTravelPoint;
 use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::Dialect;
+use common_ast::VisitorMut;
 use common_catalog::catalog_kind::CATALOG_DEFAULT;
 use common_catalog::plan::ParquetReadOptions;
 use common_catalog::plan::StageTableInfo;
@@ -61,20 +65,29 @@ use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::ListIndexesReq;
+use common_meta_app::schema::UpdateStreamMetaReq;
+use common_meta_types::MatchSeq;
 use common_meta_types::MetaId;
 use common_storage::DataOperator;
 use common_storage::StageFileInfo;
 use common_storage::StageFilesInfo;
+use common_storages_orc::OrcTable;
 use common_storages_parquet::Parquet2Table;
 use common_storages_parquet::ParquetRSTable;
 use common_storages_result_cache::ResultCacheMetaManager;
 use common_storages_result_cache::ResultCacheReader;
 use common_storages_result_cache::ResultScan;
 use common_storages_stage::StageTable;
+use common_storages_stream::stream_table::snapshot_id_from_location;
+use common_storages_stream::stream_table::StreamTable;
+use common_storages_stream::stream_table::OPT_KEY_OFFSET;
+use common_storages_stream::stream_table::STREAM_ENGINE;
+use common_storages_view::view_table::ViewTable;
 use common_storages_view::view_table::QUERY;
 use common_users::UserApiProvider;
 use dashmap::DashMap;
 use parking_lot::RwLock;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 
 use crate::binder::copy::parse_file_location;
 use crate::binder::scalar::ScalarBinder;
@@ -88,6 +101,7 @@ use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::TypeChecker;
+use crate::planner::semantic::UDFParameterRewriter;
 use crate::plans::CteScan;
 use crate::plans::Scan;
 use crate::plans::Statistics;
@@ -174,6 +188,14 @@ impl Binder {
                 pivot: _,
                 unpivot: _,
             } => {
+                // An unqualified name refers to the temporary view of the session first.
+                let temporary_view = if catalog.is_none() && database.is_none() {
+                    self.ctx.get_temporary_view(
+                        &normalize_identifier(table, &self.name_resolution_ctx).name,
+                    )
+                } else {
+                    None
+                };
                 let (catalog, database, table_name) =
                     self.normalize_object_identifier_triple(catalog, database, table);
                 let table_alias_name = if let Some(table_alias) = alias {
@@ -206,21 +228,30 @@ impl Binder {
                 let tenant = self.ctx.get_tenant();
 
                 let navigation_point = match travel_point {
-                    Some(tp) => Some(self.resolve_data_travel_point(bind_context, tp).await?),
+                    Some(tp) => {
+                        let point = self.resolve_data_travel_point(bind_context, tp).await?;
+                        self.check_time_travel_point(&point)?;
+                        Some(point)
+                    }
                     None => None,
                 };
 
                 // Resolve table with catalog
-                let table_meta = match self
-                    .resolve_data_source(
-                        tenant.as_str(),
-                        catalog.as_str(),
-                        database.as_str(),
-                        table_name.as_str(),
-                        &navigation_point,
-                    )
-                    .await
-                {
+                let is_temporary_view = temporary_view.is_some();
+                let table_meta = match temporary_view {
+                    Some(view_info) => Ok(ViewTable::create(view_info)),
+                    None => {
+                        self.resolve_data_source(
+                            tenant.as_str(),
+                            catalog.as_str(),
+                            database.as_str(),
+                            table_name.as_str(),
+                            &navigation_point,
+                        )
+                        .await
+                    }
+                };
+                let table_meta = match table_meta {
                     Ok(table) => table,
                     Err(_) => {
                         let mut parent = bind_context.parent.as_mut();
@@ -301,12 +332,18 @@ impl Binder {
                 }
 
                 match table_meta.engine() {
-                    "VIEW" => {
+                    "VIEW" | STREAM_ENGINE => {
                         Self::check_view_dep(bind_context, &database, &table_name)?;
-                        let query = table_meta
-                            .options()
-                            .get(QUERY)
-                            .ok_or_else(|| ErrorCode::Internal("Invalid VIEW object"))?;
+                        let query = if table_meta.engine() == STREAM_ENGINE {
+                            self.resolve_stream_query(&tenant, &catalog, table_meta.as_ref())
+                                .await?
+                        } else {
+                            table_meta
+                                .options()
+                                .get(QUERY)
+                                .cloned()
+                                .ok_or_else(|| ErrorCode::Internal("Invalid VIEW object"))?
+                        };
                         let tokens = tokenize_sql(query.as_str())?;
                         let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
                         // For view, we need use a new context to bind it.
@@ -314,13 +351,16 @@ impl Binder {
                             BindContext::with_parent(Box::new(bind_context.clone()));
                         new_bind_context.view_info =
                             Some((database.clone(), table_name.to_string()));
+                        new_bind_context.in_temporary_view = is_temporary_view;
                         if let Statement::Query(query) = &stmt {
+                            // A temporary view can't be granted, so its own entry is
+                            // skipped by privilege checks while its tables are not.
                             self.metadata.write().add_table(
                                 catalog,
                                 database.clone(),
                                 table_meta,
                                 table_alias_name,
-                                false,
+                                is_temporary_view,
                                 false,
                             );
                             let (s_expr, mut new_bind_context) =
@@ -355,7 +395,7 @@ impl Binder {
                             database.clone(),
                             table_meta,
                             table_alias_name,
-                            bind_context.view_info.is_some(),
+                            bind_context.view_info.is_some() && !bind_context.in_temporary_view,
                             bind_context.planning_agg_index,
                         );
 
@@ -492,6 +532,20 @@ impl Binder {
                     };
                     self.bind_select_stmt(&mut bind_context, &stmt, &[], 0)
                         .await
+                } else if let Some(query) = self
+                    .resolve_table_udf(*span, &func_name.name, params)
+                    .await?
+                {
+                    // A SQL table UDF is bound as a subquery, named after the function.
+                    let table_ref = TableReference::Subquery {
+                        span: *span,
+                        subquery: Box::new(query),
+                        alias: Some(alias.clone().unwrap_or_else(|| TableAlias {
+                            name: name.clone(),
+                            columns: vec![],
+                        })),
+                    };
+                    self.bind_single_table(bind_context, &table_ref).await
                 } else {
                     // Other table functions always reside is default catalog
                     let table_meta: Arc<dyn TableFunction> =
@@ -604,7 +658,12 @@ impl Binder {
                     .await?
                 }
             }
-            FileFormatParams::NdJson(..) => {
+            FileFormatParams::Orc(..) => {
+                OrcTable::create(stage_info.clone(), files_info, files_to_copy).await?
+            }
+            FileFormatParams::NdJson(..)
+            | FileFormatParams::Json(..)
+            | FileFormatParams::Avro(..) => {
                 let schema = Arc::new(TableSchema::new(vec![TableField::new(
                     "_$1", // TODO: this name should be in visible
                     TableDataType::Variant,
@@ -615,6 +674,7 @@ impl Binder {
                     files_info,
                     files_to_copy: None,
                     is_select: true,
+                    manifest: false,
                 };
                 StageTable::try_create(info)?
             }
@@ -638,6 +698,7 @@ impl Binder {
                     files_info,
                     files_to_copy: None,
                     is_select: true,
+                    manifest: false,
                 };
                 StageTable::try_create(info)?
             }
@@ -789,10 +850,12 @@ impl Binder {
             aggregate_info: Default::default(),
             windows: Default::default(),
             lambda_info: Default::default(),
+            udf_info: Default::default(),
             cte_name: Some(table_name.to_string()),
             cte_map_ref: Box::default(),
             in_grouping: false,
             view_info: None,
+            in_temporary_view: false,
             srfs: Default::default(),
             expr_context: ExprContext::default(),
             planning_agg_index: false,
@@ -962,6 +1025,114 @@ impl Binder {
         ))
     }
 
+    /// Builds the query reading the changes of a stream, and registers the update moving the
+    /// offset of the stream to the snapshot of the source table being read, so that the stream
+    /// is consumed by the statement that commits the changes to another table.
+    #[async_backtrace::framed]
+    async fn resolve_stream_query(
+        &self,
+        tenant: &str,
+        catalog_name: &str,
+        stream: &dyn Table,
+    ) -> Result<String> {
+        let stream_info = stream.get_table_info();
+        let stream_table = StreamTable::try_new(stream_info.clone())?;
+        let source = self
+            .resolve_data_source(
+                tenant,
+                catalog_name,
+                &stream_table.table_database,
+                &stream_table.table_name,
+                &None,
+            )
+            .await?;
+        if source.get_id() != stream_table.table_id {
+            return Err(ErrorCode::IllegalStream(format!(
+                "The source table `{}`.`{}` of stream `{}` has been dropped",
+                stream_table.table_database,
+                stream_table.table_name,
+                stream.name()
+            )));
+        }
+
+        let offset = source
+            .options()
+            .get(OPT_KEY_SNAPSHOT_LOCATION)
+            .map(|location| snapshot_id_from_location(location))
+            .unwrap_or_default();
+        let mut new_stream_meta = stream_info.meta.clone();
+        new_stream_meta
+            .options
+            .insert(OPT_KEY_OFFSET.to_string(), offset.clone());
+        self.ctx.add_stream_update(UpdateStreamMetaReq {
+            stream_id: stream_info.ident.table_id,
+            seq: MatchSeq::Exact(stream_info.ident.seq),
+            new_stream_meta,
+        });
+
+        // the changes are read up to the new offset, even if the source table is changed
+        // before the query reads it.
+        Ok(stream_table.query(&offset))
+    }
+
+    /// Resolves the SQL table UDF `func_name` to its query, with the parameters replaced by
+    /// `args`. Builtin table functions shadow the UDFs of the same name.
+    #[async_backtrace::framed]
+    async fn resolve_table_udf(
+        &self,
+        span: Span,
+        func_name: &str,
+        args: &[Expr],
+    ) -> Result<Option<Query>> {
+        if self
+            .catalogs
+            .get_default_catalog()?
+            .exists_table_function(func_name)
+        {
+            return Ok(None);
+        }
+
+        // The temporary UDF of the session shadows the persistent one.
+        let udf = match self.ctx.get_temporary_udf(func_name) {
+            Some(udf) => udf,
+            None => match UserApiProvider::instance()
+                .get_udf(self.ctx.get_tenant().as_str(), func_name)
+                .await
+            {
+                Ok(udf) => udf,
+                Err(_) => return Ok(None),
+            },
+        };
+        if !udf.returns_table {
+            return Ok(None);
+        }
+        if udf.parameters.len() != args.len() {
+            return Err(ErrorCode::SyntaxException(format!(
+                "Require {} parameters, but got: {}",
+                udf.parameters.len(),
+                args.len()
+            ))
+            .set_span(span));
+        }
+
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let tokens = tokenize_sql(&udf.definition)?;
+        let (stmt, _) = parse_sql(&tokens, sql_dialect)?;
+        let Statement::Query(mut query) = stmt else {
+            return Err(ErrorCode::Internal(format!(
+                "Invalid definition of table function `{}`",
+                func_name
+            )));
+        };
+        let args = udf
+            .parameters
+            .into_iter()
+            .zip(args.iter().cloned())
+            .collect();
+        UDFParameterRewriter::new(args).visit_query(&mut query);
+        Ok(Some(*query))
+    }
+
     #[async_backtrace::framed]
     pub(crate) async fn resolve_data_source(
         &self,
@@ -1026,6 +1197,22 @@ impl Binder {
         }
     }
 
+    /// Time travel to a point before the retention period is not allowed, as the data
+    /// of that point may have been purged.
+    pub(crate) fn check_time_travel_point(&self, point: &NavigationPoint) -> Result<()> {
+        if let NavigationPoint::TimePoint(time_point) = point {
+            let retention_period = self.ctx.get_settings().get_retention_period()?;
+            let earliest = Utc::now() - Duration::hours(retention_period as i64);
+            if *time_point < earliest {
+                return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                    "Time travel point {} is out of the retention period of {} hours, the earliest point allowed is {}",
+                    time_point, retention_period, earliest
+                )));
+            }
+        }
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     pub(crate) async fn resolve_table_indexes(
         &self,
@@ -0,0 +1,372 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_config::GlobalConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_storage::init_stage_operator;
+use sha2::Digest;
+use sha2::Sha256;
+
+use super::select::SelectList;
+use crate::binder::parse_stage_location;
+use crate::binder::ColumnBindingBuilder;
+use crate::optimizer::SExpr;
+use crate::plans::AggregateFunction;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::EvalScalar;
+use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::plans::UDFCall;
+use crate::plans::Udf;
+use crate::plans::WindowFunc;
+use crate::plans::WindowOrderBy;
+use crate::BindContext;
+use crate::Binder;
+use crate::MetadataRef;
+use crate::Visibility;
+
+/// The maximum size of the module of a `LANGUAGE WASM` function.
+const MAX_WASM_MODULE_SIZE: u64 = 8 * 1024 * 1024;
+
+#[derive(Default, Clone, PartialEq, Eq, Debug)]
+pub struct UdfInfo {
+    /// Arguments of WASM and UDF server functions
+    pub udf_arguments: Vec<ScalarItem>,
+    /// WASM and UDF server functions
+    pub udf_functions: Vec<ScalarItem>,
+    /// Mapping: (function display name) -> (derived column ref)
+    /// This is used to generate column in projection.
+    pub udf_functions_map: HashMap<String, BoundColumnRef>,
+}
+
+/// Rewrites the calls of WASM and UDF server functions in select list into the derived columns
+/// evaluated by the `Udf` plan, the arguments of the calls are evaluated by an
+/// `EvalScalar` below it.
+pub(super) struct UdfRewriter<'a> {
+    pub bind_context: &'a mut BindContext,
+    pub metadata: MetadataRef,
+    // Aggregate, window, lambda and external functions are evaluated at or above
+    // the `Udf` plan, so they can't be used in the arguments of external functions.
+    in_udf: bool,
+}
+
+impl<'a> UdfRewriter<'a> {
+    pub fn new(bind_context: &'a mut BindContext, metadata: MetadataRef) -> Self {
+        Self {
+            bind_context,
+            metadata,
+            in_udf: false,
+        }
+    }
+
+    fn check_not_in_udf(&self, scalar: &ScalarExpr) -> Result<()> {
+        if self.in_udf {
+            return Err(ErrorCode::SemanticError(
+                "WASM and UDF server functions can't take aggregate, window, lambda, WASM or UDF server functions as arguments"
+                    .to_string(),
+            )
+            .set_span(scalar.span()));
+        }
+        Ok(())
+    }
+
+    pub fn visit(&mut self, scalar: &ScalarExpr) -> Result<ScalarExpr> {
+        match scalar {
+            ScalarExpr::BoundColumnRef(_) => Ok(scalar.clone()),
+            ScalarExpr::ConstantExpr(_) => Ok(scalar.clone()),
+            ScalarExpr::FunctionCall(func) => {
+                let new_args = func
+                    .arguments
+                    .iter()
+                    .map(|arg| self.visit(arg))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(FunctionCall {
+                    span: func.span,
+                    func_name: func.func_name.clone(),
+                    params: func.params.clone(),
+                    arguments: new_args,
+                }
+                .into())
+            }
+            ScalarExpr::CastExpr(cast) => Ok(CastExpr {
+                span: cast.span,
+                is_try: cast.is_try,
+                argument: Box::new(self.visit(&cast.argument)?),
+                target_type: cast.target_type.clone(),
+            }
+            .into()),
+
+            ScalarExpr::SubqueryExpr(_) => Ok(scalar.clone()),
+
+            ScalarExpr::AggregateFunction(agg_func) => {
+                self.check_not_in_udf(scalar)?;
+                let new_args = agg_func
+                    .args
+                    .iter()
+                    .map(|arg| self.visit(arg))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(AggregateFunction {
+                    func_name: agg_func.func_name.clone(),
+                    distinct: agg_func.distinct,
+                    params: agg_func.params.clone(),
+                    args: new_args,
+                    return_type: agg_func.return_type.clone(),
+                    display_name: agg_func.display_name.clone(),
+                }
+                .into())
+            }
+
+            ScalarExpr::WindowFunction(window) => {
+                self.check_not_in_udf(scalar)?;
+                let new_partition_by = window
+                    .partition_by
+                    .iter()
+                    .map(|partition_by| self.visit(partition_by))
+                    .collect::<Result<Vec<_>>>()?;
+
+                let mut new_order_by = Vec::with_capacity(window.order_by.len());
+                for order_by in window.order_by.iter() {
+                    new_order_by.push(WindowOrderBy {
+                        expr: self.visit(&order_by.expr)?,
+                        asc: order_by.asc,
+                        nulls_first: order_by.nulls_first,
+                    });
+                }
+
+                Ok(WindowFunc {
+                    span: window.span,
+                    display_name: window.display_name.clone(),
+                    partition_by: new_partition_by,
+                    func: window.func.clone(),
+                    order_by: new_order_by,
+                    frame: window.frame.clone(),
+                }
+                .into())
+            }
+
+            ScalarExpr::LambdaFunction(lambda_func) => {
+                self.check_not_in_udf(scalar)?;
+                let new_args = lambda_func
+                    .args
+                    .iter()
+                    .map(|arg| self.visit(arg))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(LambdaFunc {
+                    span: lambda_func.span,
+                    func_name: lambda_func.func_name.clone(),
+                    display_name: lambda_func.display_name.clone(),
+                    args: new_args,
+                    params: lambda_func.params.clone(),
+                    lambda_expr: lambda_func.lambda_expr.clone(),
+                    return_type: lambda_func.return_type.clone(),
+                }
+                .into())
+            }
+
+            ScalarExpr::UDFCall(udf) => {
+                self.check_not_in_udf(scalar)?;
+                if let Some(column_ref) = self
+                    .bind_context
+                    .udf_info
+                    .udf_functions_map
+                    .get(&udf.display_name)
+                {
+                    return Ok(column_ref.clone().into());
+                }
+
+                let in_udf = self.in_udf;
+                self.in_udf = true;
+                let new_args = udf
+                    .arguments
+                    .iter()
+                    .map(|arg| self.visit(arg))
+                    .collect::<Result<Vec<_>>>();
+                self.in_udf = in_udf;
+
+                let mut replaced_args = Vec::with_capacity(udf.arguments.len());
+                for (i, new_arg) in new_args?.into_iter().enumerate() {
+                    let replaced_arg = if let ScalarExpr::BoundColumnRef(ref column_ref) = new_arg {
+                        column_ref.clone()
+                    } else {
+                        let name = format!("{}_arg_{}", &udf.display_name, i);
+                        let index = self
+                            .metadata
+                            .write()
+                            .add_derived_column(name.clone(), new_arg.data_type()?);
+
+                        // Generate a ColumnBinding for each argument of the function
+                        let column = ColumnBindingBuilder::new(
+                            name,
+                            index,
+                            Box::new(new_arg.data_type()?),
+                            Visibility::Visible,
+                        )
+                        .build();
+
+                        BoundColumnRef {
+                            span: new_arg.span(),
+                            column,
+                        }
+                    };
+
+                    self.bind_context.udf_info.udf_arguments.push(ScalarItem {
+                        index: replaced_arg.column.index,
+                        scalar: new_arg,
+                    });
+                    replaced_args.push(replaced_arg.into());
+                }
+
+                let index = self
+                    .metadata
+                    .write()
+                    .add_derived_column(udf.display_name.clone(), *udf.return_type.clone());
+
+                let column = ColumnBindingBuilder::new(
+                    udf.display_name.clone(),
+                    index,
+                    udf.return_type.clone(),
+                    Visibility::Visible,
+                )
+                .build();
+
+                let replaced_column = BoundColumnRef {
+                    span: scalar.span(),
+                    column,
+                };
+
+                let replaced_udf = UDFCall {
+                    span: udf.span,
+                    func_name: udf.func_name.clone(),
+                    display_name: udf.display_name.clone(),
+                    arguments: replaced_args,
+                    return_type: udf.return_type.clone(),
+                    handler: udf.handler.clone(),
+                    udf_type: udf.udf_type.clone(),
+                };
+
+                self.bind_context
+                    .udf_info
+                    .udf_functions_map
+                    .insert(udf.display_name.clone(), replaced_column.clone());
+                self.bind_context.udf_info.udf_functions.push(ScalarItem {
+                    index,
+                    scalar: replaced_udf.into(),
+                });
+
+                Ok(replaced_column.into())
+            }
+        }
+    }
+}
+
+impl Binder {
+    /// Analyze WASM and UDF server functions in select clause, this will rewrite them.
+    /// See [`UdfRewriter`] for more details.
+    pub(crate) fn analyze_udf(
+        &mut self,
+        bind_context: &mut BindContext,
+        select_list: &mut SelectList,
+    ) -> Result<()> {
+        for item in select_list.items.iter_mut() {
+            let mut rewriter = UdfRewriter::new(bind_context, self.metadata.clone());
+            let new_scalar = rewriter.visit(&item.scalar)?;
+            item.scalar = new_scalar;
+        }
+
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn bind_udf(
+        &mut self,
+        bind_context: &mut BindContext,
+        child: SExpr,
+    ) -> Result<SExpr> {
+        let udf_info = &bind_context.udf_info;
+        if udf_info.udf_functions.is_empty() {
+            return Ok(child);
+        }
+
+        let mut new_expr = child;
+        if !udf_info.udf_arguments.is_empty() {
+            let mut scalar_items = udf_info.udf_arguments.clone();
+            scalar_items.sort_by_key(|item| item.index);
+            let eval_scalar = EvalScalar {
+                items: scalar_items,
+            };
+            new_expr = SExpr::create_unary(Arc::new(eval_scalar.into()), Arc::new(new_expr));
+        }
+
+        let udf_plan = Udf {
+            items: udf_info.udf_functions.clone(),
+        };
+        new_expr = SExpr::create_unary(Arc::new(udf_plan.into()), Arc::new(new_expr));
+
+        Ok(new_expr)
+    }
+}
+
+/// Checks the node allows the UDF server at `address`, both when a function is created and
+/// when it is called: the servers are contacted by the query nodes, so only the addresses
+/// listed in `udf_server_allow_list` can be used, and only if `enable_udf_server` is set.
+pub fn check_udf_server_address(address: &str) -> Result<()> {
+    let config = &GlobalConfig::instance().query;
+    if !config.enable_udf_server {
+        return Err(ErrorCode::PermissionDenied(
+            "UDF servers are not enabled, set `enable_udf_server` in the config of the query nodes",
+        ));
+    }
+    let address = address.trim_end_matches('/');
+    if !config
+        .udf_server_allow_list
+        .iter()
+        .any(|allowed| allowed.trim_end_matches('/') == address)
+    {
+        return Err(ErrorCode::PermissionDenied(format!(
+            "The address '{}' of the UDF server is not in `udf_server_allow_list`",
+            address
+        )));
+    }
+    Ok(())
+}
+
+/// Reads the module of a `LANGUAGE WASM` function from the stage `location`, returning it with
+/// its SHA-256, hex encoded. It's read when the function is created and each time a call is
+/// bound, meta only keeps the location and the hash.
+#[async_backtrace::framed]
+pub async fn read_wasm_module(
+    ctx: &Arc<dyn TableContext>,
+    location: &str,
+) -> Result<(Vec<u8>, String)> {
+    let (stage, path) = parse_stage_location(ctx, location).await?;
+    let operator = init_stage_operator(&stage)?;
+    let size = operator.stat(&path).await?.content_length();
+    if size > MAX_WASM_MODULE_SIZE {
+        return Err(ErrorCode::IllegalUDFFormat(format!(
+            "The WASM module '{}' is too large, {} bytes at most are allowed",
+            location, MAX_WASM_MODULE_SIZE
+        )));
+    }
+    let code = operator.read(&path).await?;
+    let code_hash = hex::encode(Sha256::digest(&code));
+    Ok((code, code_hash))
+}
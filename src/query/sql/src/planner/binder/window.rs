@@ -33,6 +33,7 @@ use crate::plans::LambdaFunc;
 use crate::plans::NthValueFunction;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
+use crate::plans::UDFCall;
 use crate::plans::Window;
 use crate::plans::WindowFunc;
 use crate::plans::WindowFuncFrame;
@@ -324,6 +325,19 @@ impl<'a> WindowRewriter<'a> {
                 }
                 .into())
             }
+
+            ScalarExpr::UDFCall(udf) => {
+                let new_args = udf
+                    .arguments
+                    .iter()
+                    .map(|arg| self.visit(arg))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(UDFCall {
+                    arguments: new_args,
+                    ..udf.clone()
+                }
+                .into())
+            }
         }
     }
 
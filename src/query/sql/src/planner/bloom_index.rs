@@ -30,7 +30,7 @@ use common_settings::Settings;
 use crate::normalize_identifier;
 use crate::planner::semantic::NameResolutionContext;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub enum BloomIndexColumns {
     /// Default, all columns that support bloom index.
     All,
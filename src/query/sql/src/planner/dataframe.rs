@@ -428,6 +428,7 @@ impl Dataframe {
             dataframe.bind_context,
             self.s_expr,
             dataframe.s_expr,
+            false,
         )?;
         self.s_expr = s_expr;
         self.bind_context = bind_context;
@@ -442,6 +443,7 @@ impl Dataframe {
             dataframe.bind_context,
             self.s_expr,
             dataframe.s_expr,
+            false,
         )?;
         self.s_expr = s_expr;
         self.bind_context = bind_context;
@@ -15,7 +15,9 @@
 use std::sync::Arc;
 
 use common_ast::ast::Expr as AExpr;
+use common_ast::ast::Literal;
 use common_ast::parser::parse_comma_separated_exprs;
+use common_ast::parser::parse_expr;
 use common_ast::parser::tokenize_sql;
 use common_ast::walk_expr_mut;
 use common_ast::Dialect;
@@ -399,11 +401,33 @@ impl Table for DummyTable {
     }
 }
 
+/// The name of the sequence filling an identity column, whose default expression is
+/// `nextval('<sequence>')`.
+pub fn identity_sequence_name(default_expr: &str) -> Option<String> {
+    let tokens = tokenize_sql(default_expr).ok()?;
+    match parse_expr(&tokens, Dialect::PostgreSQL).ok()? {
+        AExpr::FunctionCall { name, args, .. } if name.name.eq_ignore_ascii_case("nextval") => {
+            match args.as_slice() {
+                [AExpr::Literal {
+                    lit: Literal::String(sequence),
+                    ..
+                }] => Some(sequence.clone()),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 pub fn field_default_value(ctx: Arc<dyn TableContext>, field: &TableField) -> Result<Scalar> {
     let data_type = field.data_type();
     let data_type = DataType::from(data_type);
 
     match field.default_expr() {
+        // The values of an identity column are generated when the rows are inserted.
+        Some(default_expr) if identity_sequence_name(default_expr).is_some() => {
+            Ok(Scalar::default_value(&data_type))
+        }
         Some(default_expr) => {
             let table: Arc<dyn Table> = Arc::new(DummyTable::default());
             let mut expr = parse_exprs(ctx.clone(), table.clone(), default_expr)?;
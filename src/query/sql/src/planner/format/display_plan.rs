@@ -72,6 +72,8 @@ impl Plan {
             Plan::DescribeTable(describe_table) => Ok(format!("{:?}", describe_table)),
             Plan::RenameTable(rename_table) => Ok(format!("{:?}", rename_table)),
             Plan::SetOptions(set_options) => Ok(format!("{:?}", set_options)),
+            Plan::SetTableTags(set_tags) => Ok(format!("{:?}", set_tags)),
+            Plan::Comment(comment) => Ok(format!("{:?}", comment)),
             Plan::RenameTableColumn(rename_table_column) => {
                 Ok(format!("{:?}", rename_table_column))
             }
@@ -86,6 +88,9 @@ impl Plan {
             Plan::DropTableClusterKey(drop_table_cluster_key) => {
                 Ok(format!("{:?}", drop_table_cluster_key))
             }
+            Plan::DropTablePartition(drop_table_partition) => {
+                Ok(format!("{:?}", drop_table_partition))
+            }
             Plan::ReclusterTable(recluster_table) => Ok(format!("{:?}", recluster_table)),
             Plan::TruncateTable(truncate_table) => Ok(format!("{:?}", truncate_table)),
             Plan::OptimizeTable(optimize_table) => Ok(format!("{:?}", optimize_table)),
@@ -98,11 +103,20 @@ impl Plan {
             Plan::CreateView(create_view) => Ok(format!("{:?}", create_view)),
             Plan::AlterView(alter_view) => Ok(format!("{:?}", alter_view)),
             Plan::DropView(drop_view) => Ok(format!("{:?}", drop_view)),
+            Plan::CreateMaterializedView(create) => Ok(format!("{:?}", create)),
+            Plan::RefreshMaterializedView(refresh) => Ok(format!("{:?}", refresh)),
+            Plan::DropMaterializedView(drop) => Ok(format!("{:?}", drop)),
+
+            // Streams
+            Plan::CreateStream(create_stream) => Ok(format!("{:?}", create_stream)),
+            Plan::DropStream(drop_stream) => Ok(format!("{:?}", drop_stream)),
 
             // Indexes
             Plan::CreateIndex(index) => Ok(format!("{:?}", index)),
             Plan::DropIndex(index) => Ok(format!("{:?}", index)),
             Plan::RefreshIndex(index) => Ok(format!("{index:?}")),
+            Plan::CreateInvertedIndex(index) => Ok(format!("{index:?}")),
+            Plan::DropInvertedIndex(index) => Ok(format!("{index:?}")),
 
             // Virtual Columns
             Plan::CreateVirtualColumn(create_virtual_column) => {
@@ -182,6 +196,23 @@ impl Plan {
             Plan::DropNetworkPolicy(p) => Ok(format!("{:?}", p)),
             Plan::DescNetworkPolicy(p) => Ok(format!("{:?}", p)),
             Plan::ShowNetworkPolicies(p) => Ok(format!("{:?}", p)),
+
+            // storage profile
+            Plan::CreateStorageProfile(p) => Ok(format!("{:?}", p)),
+            Plan::DropStorageProfile(p) => Ok(format!("{:?}", p)),
+            Plan::DescStorageProfile(p) => Ok(format!("{:?}", p)),
+            Plan::ShowStorageProfiles(p) => Ok(format!("{:?}", p)),
+
+            // pipe
+            Plan::CreatePipe(p) => Ok(format!("{:?}", p)),
+            Plan::DropPipe(p) => Ok(format!("{:?}", p)),
+            Plan::DescPipe(p) => Ok(format!("{:?}", p)),
+            Plan::ShowPipes(p) => Ok(format!("{:?}", p)),
+
+            // sequence
+            Plan::CreateSequence(p) => Ok(format!("{:?}", p)),
+            Plan::DropSequence(p) => Ok(format!("{:?}", p)),
+            Plan::ShowSequences(p) => Ok(format!("{:?}", p)),
         }
     }
 }
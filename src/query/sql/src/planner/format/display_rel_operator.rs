@@ -79,6 +79,7 @@ impl Display for FormatContext {
                 RelOperator::CteScan(_) => write!(f, "CteScan"),
                 RelOperator::MaterializedCte(_) => write!(f, "MaterializedCte"),
                 RelOperator::Lambda(_) => write!(f, "Lambda"),
+                RelOperator::Udf(_) => write!(f, "Udf"),
                 RelOperator::ConstantTableScan(_) => write!(f, "ConstantTableScan"),
             },
             Self::Text(text) => write!(f, "{}", text),
@@ -105,6 +106,7 @@ pub fn format_scalar(scalar: &ScalarExpr) -> String {
         ScalarExpr::WindowFunction(win) => win.display_name.clone(),
         ScalarExpr::AggregateFunction(agg) => agg.display_name.clone(),
         ScalarExpr::LambdaFunction(lambda) => lambda.display_name.clone(),
+        ScalarExpr::UDFCall(udf) => udf.display_name.clone(),
         ScalarExpr::FunctionCall(func) => {
             format!(
                 "{}({})",
@@ -161,6 +161,46 @@ impl Metadata {
             .collect()
     }
 
+    /// Return true if the column is a base table column with a masking policy.
+    ///
+    /// The storage reads the raw data of such columns and applies the masking policy
+    /// afterwards, so the predicates on them must not be pushed down to the storage.
+    pub fn is_masked_column(&self, index: IndexType) -> bool {
+        match self.columns.get(index) {
+            Some(ColumnEntry::BaseTableColumn(BaseTableColumn {
+                table_index,
+                column_name,
+                path_indices,
+                ..
+            })) => {
+                let table = self.table(*table_index).table();
+                match &table.get_table_info().meta.column_mask_policy {
+                    Some(policies) => {
+                        // Inner columns of struct are named as `<column>:<field>`.
+                        let name = match path_indices {
+                            Some(_) => column_name.split(':').next().unwrap_or(column_name),
+                            None => column_name,
+                        };
+                        policies.contains_key(name)
+                    }
+                    None => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// Return true if any column of the table has a masking policy.
+    pub fn has_masked_columns(&self, table_index: IndexType) -> bool {
+        let table = self.table(table_index).table();
+        table
+            .get_table_info()
+            .meta
+            .column_mask_policy
+            .as_ref()
+            .is_some_and(|policies| !policies.is_empty())
+    }
+
     pub fn virtual_columns_by_table_index(&self, index: IndexType) -> Vec<ColumnEntry> {
         self.columns
             .iter()
@@ -26,7 +26,10 @@ pub fn get_explore_rule_set(optimized: bool) -> RuleSet {
 /// The join order has been optimized by dphyp, therefore we will not change the join order
 /// and only attempt to exchange the order of build and probe.
 fn rule_set_dphyp() -> RuleSet {
-    RuleSet::create_with_ids(vec![RuleID::EagerAggregation])
+    RuleSet::create_with_ids(vec![
+        RuleID::EagerAggregation,
+        RuleID::PushDownAggregateUnion,
+    ])
 }
 
 /// Get rule set of join order RS-L1, which will only generate left-deep trees.
@@ -36,5 +39,6 @@ fn rule_set_rs_l1() -> RuleSet {
         RuleID::CommuteJoinBaseTable,
         RuleID::LeftExchangeJoin,
         RuleID::EagerAggregation,
+        RuleID::PushDownAggregateUnion,
     ])
 }
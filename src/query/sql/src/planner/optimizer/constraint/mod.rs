@@ -0,0 +1,321 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_exception::Result;
+use storages_common_table_meta::table::TableConstraints;
+use storages_common_table_meta::table::OPT_KEY_CONSTRAINTS;
+
+use crate::optimizer::SExpr;
+use crate::plans::Aggregate;
+use crate::plans::BoundColumnRef;
+use crate::plans::Join;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::plans::Scan;
+use crate::ColumnEntry;
+use crate::ColumnSet;
+use crate::IndexType;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+
+/// Rewrite the query with the informational constraints of the tables.
+///
+/// The constraints are declared with `PRIMARY KEY`, `UNIQUE` and `FOREIGN KEY` in
+/// `CREATE TABLE` and are not enforced, so the rewrites trust the user:
+/// - A LEFT JOIN is replaced by its left side, if the right side is a table joined on a
+///   unique key and none of its columns are used above the join.
+/// - An INNER JOIN on a foreign key is replaced by the referencing side, if none of the
+///   columns of the referenced table are used above the join.
+/// - A DISTINCT over a single table is removed, if the distinct columns contain a unique key.
+pub struct ConstraintOptimizer {
+    metadata: MetadataRef,
+}
+
+impl ConstraintOptimizer {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self { metadata }
+    }
+
+    /// `required` is the set of columns used by the ancestors of `s_expr`.
+    pub fn optimize(&self, s_expr: &SExpr, mut required: ColumnSet) -> Result<SExpr> {
+        match s_expr.plan() {
+            RelOperator::Join(join) => {
+                if let Some(result) = self.try_eliminate_join(s_expr, join, &required)? {
+                    return self.optimize(&result, required);
+                }
+                required.extend(join.used_columns()?);
+                let left = self.optimize(s_expr.child(0)?, required.clone())?;
+                let right = self.optimize(s_expr.child(1)?, required)?;
+                Ok(s_expr.replace_children([Arc::new(left), Arc::new(right)]))
+            }
+            RelOperator::Aggregate(agg) => {
+                if self.is_redundant_distinct(s_expr, agg)? {
+                    return self.optimize(s_expr.child(0)?, required);
+                }
+                for item in agg.group_items.iter().chain(agg.aggregate_functions.iter()) {
+                    required.extend(item.scalar.used_columns());
+                }
+                self.optimize_child(s_expr, required)
+            }
+            RelOperator::EvalScalar(eval) => {
+                for item in eval.items.iter() {
+                    required.extend(item.scalar.used_columns());
+                }
+                self.optimize_child(s_expr, required)
+            }
+            RelOperator::Filter(filter) => {
+                for predicate in filter.predicates.iter() {
+                    required.extend(predicate.used_columns());
+                }
+                self.optimize_child(s_expr, required)
+            }
+            RelOperator::Sort(sort) => {
+                required.extend(sort.items.iter().map(|item| item.index));
+                self.optimize_child(s_expr, required)
+            }
+            RelOperator::Limit(_) => self.optimize_child(s_expr, required),
+            // Stop at other operators, we don't know which columns they use.
+            _ => Ok(s_expr.clone()),
+        }
+    }
+
+    fn optimize_child(&self, s_expr: &SExpr, required: ColumnSet) -> Result<SExpr> {
+        let child = self.optimize(s_expr.child(0)?, required)?;
+        Ok(s_expr.replace_children([Arc::new(child)]))
+    }
+
+    fn try_eliminate_join(
+        &self,
+        s_expr: &SExpr,
+        join: &Join,
+        required: &ColumnSet,
+    ) -> Result<Option<SExpr>> {
+        if join.left_conditions.is_empty()
+            || !join.non_equi_conditions.is_empty()
+            || join.marker_index.is_some()
+            || join.from_correlated_subquery
+        {
+            return Ok(None);
+        }
+
+        match join.join_type {
+            JoinType::Left => {
+                // Each row of the left side matches at most one row of the right side,
+                // the filters on the right side can only reduce the matches.
+                let scan = match find_scan(s_expr.child(1)?, false) {
+                    Some(scan) => scan,
+                    None => return Ok(None),
+                };
+                if !scan.columns.is_disjoint(required) {
+                    return Ok(None);
+                }
+                let keys = match self.base_columns(scan.table_index, &join.right_conditions) {
+                    Some(keys) => keys,
+                    None => return Ok(None),
+                };
+                if self.has_unique_key(scan.table_index, &keys) {
+                    return Ok(Some(s_expr.child(0)?.clone()));
+                }
+                Ok(None)
+            }
+            JoinType::Inner => {
+                let sides = [
+                    (0, &join.left_conditions, &join.right_conditions),
+                    (1, &join.right_conditions, &join.left_conditions),
+                ];
+                for (kept, kept_conditions, ref_conditions) in sides {
+                    // The referenced side must be the whole table, so that every value
+                    // of the foreign key has exactly one match.
+                    let ref_scan = match s_expr.child(1 - kept)?.plan() {
+                        RelOperator::Scan(scan) => scan,
+                        _ => continue,
+                    };
+                    if !ref_scan.columns.is_disjoint(required)
+                        || contains_outer_join(s_expr.child(kept)?)
+                    {
+                        continue;
+                    }
+                    let ref_keys = match self.base_columns(ref_scan.table_index, ref_conditions) {
+                        Some(keys) => keys,
+                        None => continue,
+                    };
+                    if self.is_foreign_key(kept_conditions, ref_scan.table_index, &ref_keys) {
+                        return Ok(Some(s_expr.child(kept)?.clone()));
+                    }
+                }
+                Ok(None)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn is_redundant_distinct(&self, s_expr: &SExpr, agg: &Aggregate) -> Result<bool> {
+        if !agg.from_distinct
+            || !agg.aggregate_functions.is_empty()
+            || !agg.grouping_sets.is_empty()
+            || agg.limit.is_some()
+        {
+            return Ok(false);
+        }
+        // The group items must be the columns of the child, so the aggregate can be
+        // replaced by the child without changing the output columns.
+        for item in agg.group_items.iter() {
+            match &item.scalar {
+                ScalarExpr::BoundColumnRef(column) if column.column.index == item.index => {}
+                _ => return Ok(false),
+            }
+        }
+        let scan = match find_scan(s_expr.child(0)?, true) {
+            Some(scan) => scan,
+            None => return Ok(false),
+        };
+        // The other distinct columns are derived from the rows of the scan, they don't matter.
+        let keys = agg
+            .group_items
+            .iter()
+            .filter_map(|item| self.base_column(scan.table_index, &item.scalar))
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        Ok(self.has_unique_key(scan.table_index, &keys))
+    }
+
+    /// Returns true if the kept conditions are exactly a non-nullable foreign key
+    /// that references a unique key of the table in the other side.
+    fn is_foreign_key(
+        &self,
+        kept_conditions: &[ScalarExpr],
+        ref_table_index: IndexType,
+        ref_columns: &[String],
+    ) -> bool {
+        let table_index = match kept_conditions.first() {
+            Some(ScalarExpr::BoundColumnRef(column)) => match column.column.table_index {
+                Some(table_index) => table_index,
+                None => return false,
+            },
+            _ => return false,
+        };
+        let mut columns = Vec::with_capacity(kept_conditions.len());
+        for condition in kept_conditions {
+            let nullable = condition.data_type().map_or(true, |ty| ty.is_nullable());
+            match self.base_column(table_index, condition) {
+                Some((name, false)) if !nullable => columns.push(name),
+                _ => return false,
+            }
+        }
+
+        let constraints = match self.table_constraints(table_index) {
+            Some(constraints) => constraints,
+            None => return false,
+        };
+        let (ref_database, ref_table) = {
+            let metadata = self.metadata.read();
+            let entry = metadata.table(ref_table_index);
+            (entry.database().to_string(), entry.name().to_string())
+        };
+        let pairs = columns
+            .iter()
+            .zip(ref_columns.iter())
+            .collect::<HashSet<_>>();
+        constraints.foreign_keys.iter().any(|fk| {
+            fk.ref_database == ref_database
+                && fk.ref_table == ref_table
+                && fk
+                    .columns
+                    .iter()
+                    .zip(fk.ref_columns.iter())
+                    .collect::<HashSet<_>>()
+                    == pairs
+                && self.has_unique_key(ref_table_index, &fk.ref_columns)
+        })
+    }
+
+    /// Returns true if the columns contain the primary key or a non-nullable unique key.
+    fn has_unique_key(&self, table_index: IndexType, columns: &[String]) -> bool {
+        let constraints = match self.table_constraints(table_index) {
+            Some(constraints) => constraints,
+            None => return false,
+        };
+        let schema = self.metadata.read().table(table_index).table().schema();
+        let contains = |key: &Vec<String>| key.iter().all(|column| columns.contains(column));
+        constraints.primary_key.iter().any(|key| contains(key))
+            || constraints.unique_keys.iter().any(|key| {
+                contains(key)
+                    && key.iter().all(|column| {
+                        schema
+                            .field_with_name(column)
+                            .map_or(false, |field| !field.is_nullable())
+                    })
+            })
+    }
+
+    fn table_constraints(&self, table_index: IndexType) -> Option<TableConstraints> {
+        let table = self.metadata.read().table(table_index).table();
+        let value = table.options().get(OPT_KEY_CONSTRAINTS)?;
+        TableConstraints::parse(value).ok()
+    }
+
+    /// Resolve the names of the columns, all of them must be columns of the table.
+    fn base_columns(&self, table_index: IndexType, columns: &[ScalarExpr]) -> Option<Vec<String>> {
+        columns
+            .iter()
+            .map(|column| self.base_column(table_index, column).map(|(name, _)| name))
+            .collect()
+    }
+
+    /// Returns the name and nullability of the column if it is a column of the table.
+    fn base_column(&self, table_index: IndexType, scalar: &ScalarExpr) -> Option<(String, bool)> {
+        let index = match scalar {
+            ScalarExpr::BoundColumnRef(BoundColumnRef { column, .. }) => column.index,
+            _ => return None,
+        };
+        match self.metadata.read().column(index) {
+            ColumnEntry::BaseTableColumn(column)
+                if column.table_index == table_index && column.path_indices.is_none() =>
+            {
+                Some((column.column_name.clone(), column.data_type.is_nullable()))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Find the table scan under the operators that don't duplicate rows.
+fn find_scan(s_expr: &SExpr, through_projection: bool) -> Option<&Scan> {
+    match s_expr.plan() {
+        RelOperator::Scan(scan) => Some(scan),
+        RelOperator::Filter(_) => find_scan(s_expr.child(0).ok()?, through_projection),
+        RelOperator::EvalScalar(_) | RelOperator::Sort(_) | RelOperator::Limit(_)
+            if through_projection =>
+        {
+            find_scan(s_expr.child(0).ok()?, through_projection)
+        }
+        _ => None,
+    }
+}
+
+/// The columns under an outer join could be NULL even if they are not nullable in the table.
+fn contains_outer_join(s_expr: &SExpr) -> bool {
+    if let RelOperator::Join(join) = s_expr.plan() {
+        if !matches!(join.join_type, JoinType::Inner | JoinType::Cross) {
+            return true;
+        }
+    }
+    s_expr
+        .children()
+        .iter()
+        .any(|child| contains_outer_join(child))
+}
@@ -52,6 +52,7 @@ pub fn display_rel_op(rel_op: &RelOperator) -> String {
         RelOperator::CteScan(_) => "CteScan".to_string(),
         RelOperator::MaterializedCte(_) => "MaterializedCte".to_string(),
         RelOperator::Lambda(_) => "LambdaFunc".to_string(),
+        RelOperator::Udf(_) => "Udf".to_string(),
         RelOperator::ConstantTableScan(_) => "ConstantTableScan".to_string(),
     }
 }
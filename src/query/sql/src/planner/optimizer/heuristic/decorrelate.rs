@@ -285,6 +285,7 @@ impl SubqueryRewriter {
         flatten_info: &mut FlattenInfo,
         is_conjunctive_predicate: bool,
     ) -> Result<(SExpr, UnnestResult)> {
+        self.outer_plan = Some(left.clone());
         match subquery.typ {
             SubqueryType::Scalar => {
                 let correlated_columns = subquery.outer_columns.clone();
@@ -316,6 +317,9 @@ impl SubqueryRewriter {
                 Ok((s_expr, UnnestResult::SingleJoin { output_index: None }))
             }
             SubqueryType::Exists | SubqueryType::NotExists => {
+                let mut subquery = subquery.clone();
+                subquery.subquery = Box::new(remove_limit_and_sort_of_exists(&subquery.subquery)?);
+                let subquery = &subquery;
                 if is_conjunctive_predicate {
                     if let Some(result) = self.try_decorrelate_simple_subquery(left, subquery)? {
                         return Ok((result, UnnestResult::SimpleJoin));
@@ -436,66 +440,10 @@ impl SubqueryRewriter {
             if !need_cross_join {
                 return Ok(plan.clone());
             }
-            // Construct a LogicalGet plan by correlated columns.
+            // Construct a plan of the distinct values of the correlated columns,
+            // so the subquery is only evaluated once for each of them.
             // Finally generate a cross join, so we finish flattening the subquery.
-            let mut metadata = self.metadata.write();
-            // Currently, we don't support left plan's from clause contains subquery.
-            // Such as: select t2.a from (select a + 1 as a from t) as t2 where (select sum(a) from t as t1 where t1.a < t2.a) = 1;
-            let table_index = metadata
-                .table_index_by_column_indexes(correlated_columns)
-                .unwrap();
-            let mut data_types = Vec::with_capacity(correlated_columns.len());
-            for correlated_column in correlated_columns.iter() {
-                let column_entry = metadata.column(*correlated_column).clone();
-                let name = column_entry.name();
-                let data_type = column_entry.data_type();
-                data_types.push(data_type.clone());
-                self.derived_columns.insert(
-                    *correlated_column,
-                    metadata.add_derived_column(name.to_string(), data_type),
-                );
-            }
-            let logical_get = SExpr::create_leaf(Arc::new(
-                Scan {
-                    table_index,
-                    columns: self.derived_columns.values().cloned().collect(),
-                    ..Default::default()
-                }
-                .into(),
-            ));
-            // Wrap logical get with distinct to eliminate duplicates rows.
-            let mut group_items = Vec::with_capacity(self.derived_columns.len());
-            for (index, column_index) in self.derived_columns.values().cloned().enumerate() {
-                group_items.push(ScalarItem {
-                    scalar: ScalarExpr::BoundColumnRef(BoundColumnRef {
-                        span: None,
-                        column: ColumnBindingBuilder::new(
-                            "".to_string(),
-                            column_index,
-                            Box::new(data_types[index].clone()),
-                            Visibility::Visible,
-                        )
-                        .table_index(Some(table_index))
-                        .build(),
-                    }),
-                    index: column_index,
-                });
-            }
-            let duplicate_delete_get = SExpr::create_unary(
-                Arc::new(
-                    Aggregate {
-                        mode: AggregateMode::Initial,
-                        group_items,
-                        aggregate_functions: vec![],
-                        from_distinct: false,
-                        limit: None,
-                        grouping_id_index: 0,
-                        grouping_sets: vec![],
-                    }
-                    .into(),
-                ),
-                Arc::new(logical_get),
-            );
+            let distinct_correlated_values = self.distinct_correlated_values(correlated_columns)?;
             let cross_join = Join {
                 left_conditions: vec![],
                 right_conditions: vec![],
@@ -508,7 +456,7 @@ impl SubqueryRewriter {
             .into();
             return Ok(SExpr::create_binary(
                 Arc::new(cross_join),
-                Arc::new(duplicate_delete_get),
+                Arc::new(distinct_correlated_values),
                 Arc::new(plan.clone()),
             ));
         }
@@ -843,6 +791,106 @@ impl SubqueryRewriter {
         }
     }
 
+    /// Build the plan of the distinct values of `correlated_columns`, which are output as the derived columns.
+    ///
+    /// If the correlated columns are all from the same table, the values are read from the table.
+    /// Otherwise, e.g. the correlated columns are computed by expressions or from different tables,
+    /// the values are collected from the outer plan. Either way the results of the subquery are
+    /// memoized by the correlated values, instead of evaluating the subquery for each outer row.
+    fn distinct_correlated_values(&mut self, correlated_columns: &ColumnSet) -> Result<SExpr> {
+        let mut metadata = self.metadata.write();
+        let table_index = metadata
+            .table_index_by_column_indexes(correlated_columns)
+            .filter(|table_index| {
+                correlated_columns.iter().all(|column| {
+                    matches!(
+                        metadata.column(*column),
+                        ColumnEntry::BaseTableColumn(BaseTableColumn { table_index: index, .. })
+                            if index == table_index
+                    )
+                })
+            });
+
+        let mut group_items = Vec::with_capacity(correlated_columns.len());
+        let mut derived_items = Vec::with_capacity(correlated_columns.len());
+        for correlated_column in correlated_columns.iter() {
+            let column_entry = metadata.column(*correlated_column).clone();
+            let name = column_entry.name();
+            let data_type = column_entry.data_type();
+            let derived_column = metadata.add_derived_column(name.clone(), data_type.clone());
+            self.derived_columns.insert(*correlated_column, derived_column);
+            group_items.push(ScalarItem {
+                scalar: ScalarExpr::BoundColumnRef(BoundColumnRef {
+                    span: None,
+                    column: ColumnBindingBuilder::new(
+                        name.clone(),
+                        derived_column,
+                        Box::new(data_type.clone()),
+                        Visibility::Visible,
+                    )
+                    .table_index(table_index)
+                    .build(),
+                }),
+                index: derived_column,
+            });
+            derived_items.push(ScalarItem {
+                scalar: ScalarExpr::BoundColumnRef(BoundColumnRef {
+                    span: None,
+                    column: ColumnBindingBuilder::new(
+                        name,
+                        *correlated_column,
+                        Box::new(data_type),
+                        Visibility::Visible,
+                    )
+                    .build(),
+                }),
+                index: derived_column,
+            });
+        }
+
+        let input = match table_index {
+            Some(table_index) => SExpr::create_leaf(Arc::new(
+                Scan {
+                    table_index,
+                    columns: group_items.iter().map(|item| item.index).collect(),
+                    ..Default::default()
+                }
+                .into(),
+            )),
+            None => {
+                let outer_plan = self.outer_plan.clone().ok_or_else(|| {
+                    ErrorCode::Internal("Outer plan of the correlated subquery is missing")
+                })?;
+                SExpr::create_unary(
+                    Arc::new(
+                        EvalScalar {
+                            items: derived_items,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(outer_plan),
+                )
+            }
+        };
+
+        // Wrap with distinct to eliminate duplicates rows.
+        Ok(SExpr::create_unary(
+            Arc::new(
+                Aggregate {
+                    mode: AggregateMode::Initial,
+                    group_items,
+                    aggregate_functions: vec![],
+                    from_distinct: false,
+                    limit: None,
+                    grouping_id_index: 0,
+                    grouping_sets: vec![],
+                }
+                .into(),
+            ),
+            Arc::new(input),
+        ))
+    }
+
     fn add_equi_conditions(
         &self,
         span: Span,
@@ -924,3 +972,19 @@ impl SubqueryRewriter {
         }))
     }
 }
+
+/// `EXISTS` only checks whether the subquery returns any rows, so the `ORDER BY` and
+/// `LIMIT n` (`n > 0` without offset) at the top of the subquery can be removed,
+/// which is common in generated SQL and can't be flattened.
+fn remove_limit_and_sort_of_exists(s_expr: &SExpr) -> Result<SExpr> {
+    match s_expr.plan() {
+        RelOperator::Limit(limit) if limit.offset == 0 && limit.limit != Some(0) => {
+            remove_limit_and_sort_of_exists(s_expr.child(0)?)
+        }
+        RelOperator::Sort(_) => remove_limit_and_sort_of_exists(s_expr.child(0)?),
+        RelOperator::EvalScalar(_) => Ok(s_expr.replace_children(vec![Arc::new(
+            remove_limit_and_sort_of_exists(s_expr.child(0)?)?,
+        )])),
+        _ => Ok(s_expr.clone()),
+    }
+}
@@ -67,6 +67,8 @@ pub struct FlattenInfo {
 pub struct SubqueryRewriter {
     pub(crate) metadata: MetadataRef,
     pub(crate) derived_columns: HashMap<IndexType, IndexType>,
+    /// The outer plan of the correlated subquery being flattened.
+    pub(crate) outer_plan: Option<SExpr>,
 }
 
 impl SubqueryRewriter {
@@ -74,6 +76,7 @@ impl SubqueryRewriter {
         Self {
             metadata,
             derived_columns: Default::default(),
+            outer_plan: None,
         }
     }
 
@@ -163,10 +166,12 @@ impl SubqueryRewriter {
                 ))
             }
 
-            RelOperator::Limit(_) | RelOperator::Sort(_) => Ok(SExpr::create_unary(
-                Arc::new(s_expr.plan().clone()),
-                Arc::new(self.rewrite(s_expr.child(0)?)?),
-            )),
+            RelOperator::Limit(_) | RelOperator::Sort(_) | RelOperator::Udf(_) => {
+                Ok(SExpr::create_unary(
+                    Arc::new(s_expr.plan().clone()),
+                    Arc::new(self.rewrite(s_expr.child(0)?)?),
+                ))
+            }
 
             RelOperator::DummyTableScan(_) | RelOperator::Scan(_) | RelOperator::CteScan(_) => {
                 Ok(s_expr.clone())
@@ -190,6 +195,7 @@ impl SubqueryRewriter {
             ScalarExpr::WindowFunction(_) => Ok((scalar.clone(), s_expr.clone())),
             ScalarExpr::AggregateFunction(_) => Ok((scalar.clone(), s_expr.clone())),
             ScalarExpr::LambdaFunction(_) => Ok((scalar.clone(), s_expr.clone())),
+            ScalarExpr::UDFCall(_) => Ok((scalar.clone(), s_expr.clone())),
             ScalarExpr::FunctionCall(func) => {
                 let mut args = vec![];
                 let mut s_expr = s_expr.clone();
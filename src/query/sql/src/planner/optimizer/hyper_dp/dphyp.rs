@@ -159,6 +159,7 @@ impl DPhpy {
                     RelOperator::EvalScalar(_)
                         | RelOperator::Aggregate(_)
                         | RelOperator::Lambda(_)
+                        | RelOperator::Udf(_)
                         | RelOperator::Sort(_)
                         | RelOperator::Limit(_)
                         | RelOperator::ProjectSet(_)
@@ -171,6 +172,7 @@ impl DPhpy {
                     RelOperator::EvalScalar(_)
                         | RelOperator::Aggregate(_)
                         | RelOperator::Lambda(_)
+                        | RelOperator::Udf(_)
                         | RelOperator::Sort(_)
                         | RelOperator::Limit(_)
                         | RelOperator::ProjectSet(_)
@@ -216,6 +218,7 @@ impl DPhpy {
             RelOperator::ProjectSet(_)
             | RelOperator::Aggregate(_)
             | RelOperator::Lambda(_)
+            | RelOperator::Udf(_)
             | RelOperator::Sort(_)
             | RelOperator::Limit(_)
             | RelOperator::EvalScalar(_)
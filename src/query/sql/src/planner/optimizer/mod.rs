@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod cascades;
+mod constraint;
 mod cost;
 mod distributed;
 mod format;
@@ -31,6 +32,7 @@ pub mod s_expr;
 mod util;
 
 pub use cascades::CascadesOptimizer;
+pub use constraint::ConstraintOptimizer;
 pub use heuristic::HeuristicOptimizer;
 pub use heuristic::SubqueryRewriter;
 pub use heuristic::DEFAULT_REWRITE_RULES;
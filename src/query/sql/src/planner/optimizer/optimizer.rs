@@ -25,6 +25,7 @@ use super::cost::CostContext;
 use super::format::display_memo;
 use super::Memo;
 use crate::optimizer::cascades::CascadesOptimizer;
+use crate::optimizer::constraint::ConstraintOptimizer;
 use crate::optimizer::distributed::optimize_distributed_query;
 use crate::optimizer::hyper_dp::DPhpy;
 use crate::optimizer::runtime_filter::try_add_runtime_filter_nodes;
@@ -35,6 +36,7 @@ use crate::optimizer::DEFAULT_REWRITE_RULES;
 use crate::optimizer::RESIDUAL_RULES;
 use crate::plans::CopyPlan;
 use crate::plans::Plan;
+use crate::ColumnSet;
 use crate::IndexType;
 use crate::MetadataRef;
 
@@ -68,7 +70,13 @@ pub fn optimize(
             formatted_ast,
             ignore_result,
         } => Ok(Plan::Query {
-            s_expr: Box::new(optimize_query(ctx, opt_ctx, metadata.clone(), *s_expr)?),
+            s_expr: Box::new(optimize_query(
+                ctx,
+                opt_ctx,
+                metadata.clone(),
+                bind_context.column_set(),
+                *s_expr,
+            )?),
             bind_context,
             metadata,
             rewrite_kind,
@@ -112,12 +120,14 @@ pub fn optimize(
                     stage,
                     path,
                     validation_mode,
+                    manifest,
                     from,
                 } => {
                     CopyPlan::IntoStage {
                         stage,
                         path,
                         validation_mode,
+                        manifest,
                         // Make sure the subquery has been optimized.
                         from: Box::new(optimize(ctx, opt_ctx, *from)?),
                     }
@@ -125,8 +135,10 @@ pub fn optimize(
                 CopyPlan::NoFileToCopy => *v,
 
                 CopyPlan::IntoTable(mut into_table) => {
+                    // The loading status of files is only collected on the local node.
                     into_table.enable_distributed = opt_ctx.config.enable_distributed_optimization
-                        && ctx.get_settings().get_enable_distributed_copy()?;
+                        && ctx.get_settings().get_enable_distributed_copy()?
+                        && !into_table.return_file_status;
                     info!(
                         "after optimization enable_distributed_copy? : {}",
                         into_table.enable_distributed
@@ -144,12 +156,18 @@ pub fn optimize_query(
     ctx: Arc<dyn TableContext>,
     opt_ctx: Arc<OptimizerContext>,
     metadata: MetadataRef,
+    required_columns: ColumnSet,
     s_expr: SExpr,
 ) -> Result<SExpr> {
     let contains_local_table_scan = contains_local_table_scan(&s_expr, &metadata);
 
     let heuristic = HeuristicOptimizer::new(ctx.get_function_context()?, metadata.clone());
     let mut result = heuristic.pre_optimize(s_expr)?;
+    // Eliminate joins and distinct with table constraints before the rewrite rules,
+    // which may push down filters and split the aggregates.
+    if !result.contain_subquery() {
+        result = ConstraintOptimizer::new(metadata.clone()).optimize(&result, required_columns)?;
+    }
     result = heuristic.optimize_expression(&result, &DEFAULT_REWRITE_RULES)?;
     let mut dphyp_optimized = false;
     if ctx.get_settings().get_enable_dphyp()? {
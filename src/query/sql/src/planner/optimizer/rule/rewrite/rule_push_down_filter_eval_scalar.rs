@@ -35,6 +35,7 @@ use crate::plans::PatternPlan;
 use crate::plans::RelOp;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
+use crate::plans::UDFCall;
 use crate::plans::WindowFunc;
 use crate::plans::WindowFuncType;
 use crate::plans::WindowOrderBy;
@@ -216,6 +217,23 @@ impl RulePushDownFilterEvalScalar {
                     return_type: lambda_func.return_type.clone(),
                 }))
             }
+            ScalarExpr::UDFCall(udf) => {
+                let arguments = udf
+                    .arguments
+                    .iter()
+                    .map(|arg| Self::replace_predicate(arg, items))
+                    .collect::<Result<Vec<ScalarExpr>>>()?;
+
+                Ok(ScalarExpr::UDFCall(UDFCall {
+                    span: udf.span,
+                    func_name: udf.func_name.clone(),
+                    display_name: udf.display_name.clone(),
+                    arguments,
+                    return_type: udf.return_type.clone(),
+                    handler: udf.handler.clone(),
+                    udf_type: udf.udf_type.clone(),
+                }))
+            }
             ScalarExpr::CastExpr(cast) => {
                 let arg = Self::replace_predicate(&cast.argument, items)?;
                 Ok(ScalarExpr::CastExpr(CastExpr {
@@ -148,7 +148,11 @@ pub fn try_push_down_filter_join(
                 left_push_down.push(predicate);
             }
             JoinPredicate::Right(_) => {
-                if matches!(join.join_type, JoinType::Left) {
+                // The filters on the right side of asof join change the nearest matches.
+                if matches!(
+                    join.join_type,
+                    JoinType::Left | JoinType::Asof | JoinType::LeftAsof
+                ) {
                     original_predicates.push(predicate);
                     continue;
                 }
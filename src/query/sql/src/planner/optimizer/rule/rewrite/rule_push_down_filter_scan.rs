@@ -32,6 +32,7 @@ use crate::plans::NthValueFunction;
 use crate::plans::PatternPlan;
 use crate::plans::RelOp;
 use crate::plans::Scan;
+use crate::plans::UDFCall;
 use crate::plans::WindowFunc;
 use crate::plans::WindowFuncType;
 use crate::plans::WindowOrderBy;
@@ -228,6 +229,23 @@ impl RulePushDownFilterScan {
                     return_type: lambda_func.return_type.clone(),
                 }))
             }
+            ScalarExpr::UDFCall(udf) => {
+                let arguments = udf
+                    .arguments
+                    .iter()
+                    .map(|arg| Self::replace_view_column(arg, table_entries, column_entries))
+                    .collect::<Result<Vec<ScalarExpr>>>()?;
+
+                Ok(ScalarExpr::UDFCall(UDFCall {
+                    span: udf.span,
+                    func_name: udf.func_name.clone(),
+                    display_name: udf.display_name.clone(),
+                    arguments,
+                    return_type: udf.return_type.clone(),
+                    handler: udf.handler.clone(),
+                    udf_type: udf.udf_type.clone(),
+                }))
+            }
             ScalarExpr::FunctionCall(func) => {
                 let arguments = func
                     .arguments
@@ -280,7 +298,12 @@ impl RulePushDownFilterScan {
                     ColumnEntry::VirtualColumn(_) => {}
                 }
             }
-            if !contain_derived_column {
+            // The masked columns are read as raw data from the storage,
+            // the predicate must be evaluated on the masked values.
+            let contain_masked_column = used_columns
+                .iter()
+                .any(|index| metadata.is_masked_column(*index));
+            if !contain_derived_column && !contain_masked_column {
                 if is_source_of_view {
                     let new_predicate =
                         Self::replace_view_column(predicate, table_entries, column_entries)?;
@@ -34,6 +34,7 @@ use crate::plans::NthValueFunction;
 use crate::plans::PatternPlan;
 use crate::plans::RelOp;
 use crate::plans::ScalarExpr;
+use crate::plans::UDFCall;
 use crate::plans::UnionAll;
 use crate::plans::WindowFunc;
 use crate::plans::WindowFuncType;
@@ -262,6 +263,23 @@ fn replace_column_binding(
                 return_type: lambda_func.return_type,
             }))
         }
+        ScalarExpr::UDFCall(udf) => {
+            let arguments = udf
+                .arguments
+                .into_iter()
+                .map(|arg| replace_column_binding(index_pairs, arg))
+                .collect::<Result<Vec<ScalarExpr>>>()?;
+
+            Ok(ScalarExpr::UDFCall(UDFCall {
+                span: udf.span,
+                func_name: udf.func_name,
+                display_name: udf.display_name,
+                arguments,
+                return_type: udf.return_type,
+                handler: udf.handler,
+                udf_type: udf.udf_type,
+            }))
+        }
         ScalarExpr::CastExpr(expr) => Ok(ScalarExpr::CastExpr(CastExpr {
             span: expr.span,
             is_try: expr.is_try,
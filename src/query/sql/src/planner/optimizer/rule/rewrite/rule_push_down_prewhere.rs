@@ -130,6 +130,10 @@ impl RulePushDownPrewhere {
         // filter.predicates are already split by AND
         for pred in filter.predicates.iter() {
             match Self::collect_columns(get.table_index, &table.schema(), pred) {
+                // Prewhere is evaluated on the raw data, before the masking policy is applied.
+                Some(columns) if columns.iter().any(|c| metadata.is_masked_column(*c)) => {
+                    return Ok(s_expr.clone());
+                }
                 Some(columns) => {
                     prewhere_pred.push(pred.clone());
                     prewhere_columns.extend(&columns);
@@ -26,6 +26,7 @@ use crate::plans::RelOp;
 use crate::plans::RelOperator;
 use crate::plans::Scan;
 use crate::plans::Sort;
+use crate::MetadataRef;
 
 /// Input:  Sort
 ///           \
@@ -39,10 +40,11 @@ use crate::plans::Sort;
 pub struct RulePushDownSortScan {
     id: RuleID,
     patterns: Vec<SExpr>,
+    metadata: MetadataRef,
 }
 
 impl RulePushDownSortScan {
-    pub fn new() -> Self {
+    pub fn new(metadata: MetadataRef) -> Self {
         Self {
             id: RuleID::PushDownSortScan,
             patterns: vec![SExpr::create_unary(
@@ -59,6 +61,7 @@ impl RulePushDownSortScan {
                     .into(),
                 ))),
             )],
+            metadata,
         }
     }
 }
@@ -70,6 +73,17 @@ impl Rule for RulePushDownSortScan {
 
     fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
         let sort: Sort = s_expr.plan().clone().try_into()?;
+        {
+            // The storage sorts by the raw data of masked columns.
+            let metadata = self.metadata.read();
+            if sort
+                .items
+                .iter()
+                .any(|item| metadata.is_masked_column(item.index))
+            {
+                return Ok(());
+            }
+        }
         let child = s_expr.child(0)?;
         let mut get: Scan = child.plan().clone().try_into()?;
         if get.order_by.is_none() {
@@ -204,6 +204,10 @@ impl Rule for RuleTryApplyAggIndex {
     ) -> Result<()> {
         let (table_index, table_name) = self.get_table(s_expr);
         let metadata = self.metadata.read();
+        if metadata.has_masked_columns(table_index) {
+            // The aggregating index is built on the raw data of masked columns.
+            return Ok(());
+        }
         let index_plans = metadata.get_agg_indexes(&table_name);
         if index_plans.is_none() {
             // No enterprise license or no index.
@@ -71,6 +71,7 @@ pub enum RuleID {
     CommuteJoinBaseTable,
     LeftExchangeJoin,
     EagerAggregation,
+    PushDownAggregateUnion,
 }
 
 impl Display for RuleID {
@@ -104,6 +105,7 @@ impl Display for RuleID {
             RuleID::CommuteJoinBaseTable => write!(f, "CommuteJoinBaseTable"),
             RuleID::LeftExchangeJoin => write!(f, "LeftExchangeJoin"),
             RuleID::EagerAggregation => write!(f, "EagerAggregation"),
+            RuleID::PushDownAggregateUnion => write!(f, "PushDownAggregateUnion"),
             RuleID::TryApplyAggIndex => write!(f, "TryApplyAggIndex"),
         }
     }
@@ -15,8 +15,10 @@
 mod rule_commute_join_base_table;
 mod rule_eager_aggregation;
 mod rule_left_exchange_join;
+mod rule_push_down_aggregate_union;
 mod util;
 
 pub use rule_commute_join_base_table::RuleCommuteJoinBaseTable;
 pub use rule_eager_aggregation::RuleEagerAggregation;
 pub use rule_left_exchange_join::RuleLeftExchangeJoin;
+pub use rule_push_down_aggregate_union::RulePushDownAggregateUnion;
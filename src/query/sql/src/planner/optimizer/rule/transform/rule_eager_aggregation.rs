@@ -524,6 +524,23 @@ impl Rule for RuleEagerAggregation {
             }
         }
 
+        // Group the eager aggregation of a child by its join columns as well, so a child
+        // can be aggregated before the join even if the group items come from the other
+        // child, e.g. a fact table joined with a dimension table and grouped by the columns
+        // of the dimension table.
+        for (idx, conditions) in join_conditions.iter().enumerate() {
+            for cond in conditions.iter() {
+                if let ScalarExpr::BoundColumnRef(join_column) = cond {
+                    if group_columns_set[idx].insert(join_column.column.index) {
+                        final_agg.group_items.push(ScalarItem {
+                            scalar: cond.clone(),
+                            index: join_column.column.index,
+                        });
+                    }
+                }
+            }
+        }
+
         // If a child's `can_eager` is true, its group_columns_set should include all
         // join conditions related to the child.
         let mut can_eager = [true; 2];
@@ -1211,7 +1228,8 @@ impl Rule for RuleEagerAggregation {
             }
         }
 
-        // Remove redundant group items that propagated from another child.
+        // Remove redundant group items that propagated from another child, or added from
+        // the join conditions.
         for final_agg in final_agg_finals.iter_mut() {
             while final_agg.group_items.len() > original_group_items_len {
                 final_agg.group_items.pop();
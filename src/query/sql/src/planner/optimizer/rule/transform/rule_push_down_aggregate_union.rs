@@ -0,0 +1,374 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_functions::aggregates::AggregateFunctionFactory;
+
+use crate::binder::wrap_cast;
+use crate::binder::ColumnBindingBuilder;
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::Aggregate;
+use crate::plans::AggregateFunction;
+use crate::plans::AggregateMode;
+use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
+use crate::plans::PatternPlan;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::ScalarItem;
+use crate::plans::UnionAll;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+use crate::Visibility;
+
+/// Rule to push aggregation through UNION ALL, each branch of the union is aggregated
+/// first, and the final aggregation merges the results of the branches.
+///
+/// Input:
+///                 expression
+///                     |
+///              aggregate: SUM(x), COUNT(*)
+///                     |
+///                 union all
+///                    /  \
+///                   *    *
+///
+/// Output:
+///                 expression: CAST(cnt AS UINT64)
+///                     |
+///           aggregate: SUM(sum_x), SUM(cnt)
+///                     |
+///                 union all
+///                 /        \
+///   aggregate: SUM(x),     aggregate: SUM(x),
+///     COUNT(*)               COUNT(*)
+///        |                      |
+///        *                      *
+///
+/// Only SUM, COUNT, MIN and MAX without DISTINCT are supported, and the group items
+/// must be columns of the union.
+pub struct RulePushDownAggregateUnion {
+    id: RuleID,
+    patterns: Vec<SExpr>,
+    metadata: MetadataRef,
+}
+
+impl RulePushDownAggregateUnion {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::PushDownAggregateUnion,
+            //     Expression
+            //         |
+            //  Aggregate(final)
+            //         |
+            // Aggregate(partial)
+            //         |
+            //      UnionAll
+            //       /    \
+            //      *      *
+            patterns: vec![SExpr::create_unary(
+                Arc::new(
+                    PatternPlan {
+                        plan_type: RelOp::EvalScalar,
+                    }
+                    .into(),
+                ),
+                Arc::new(SExpr::create_unary(
+                    Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::Aggregate,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(SExpr::create_unary(
+                        Arc::new(
+                            PatternPlan {
+                                plan_type: RelOp::Aggregate,
+                            }
+                            .into(),
+                        ),
+                        Arc::new(SExpr::create_binary(
+                            Arc::new(
+                                PatternPlan {
+                                    plan_type: RelOp::UnionAll,
+                                }
+                                .into(),
+                            ),
+                            Arc::new(SExpr::create_pattern_leaf()),
+                            Arc::new(SExpr::create_pattern_leaf()),
+                        )),
+                    )),
+                )),
+            )],
+            metadata,
+        }
+    }
+}
+
+impl Rule for RulePushDownAggregateUnion {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let mut eval_scalar: EvalScalar = s_expr.plan().clone().try_into()?;
+        let final_agg_expr = s_expr.child(0)?;
+        let final_agg: Aggregate = final_agg_expr.plan().clone().try_into()?;
+        let partial_agg_expr = final_agg_expr.child(0)?;
+        let partial_agg: Aggregate = partial_agg_expr.plan().clone().try_into()?;
+        let union_expr = partial_agg_expr.child(0)?;
+        let union: UnionAll = union_expr.plan().clone().try_into()?;
+
+        if final_agg.mode != AggregateMode::Final
+            || partial_agg.mode != AggregateMode::Partial
+            || final_agg.group_items.is_empty()
+            || !final_agg.grouping_sets.is_empty()
+        {
+            return Ok(());
+        }
+        // The branches have been aggregated already.
+        if union_expr
+            .children()
+            .iter()
+            .any(|child| matches!(child.plan(), RelOperator::Aggregate(_)))
+        {
+            return Ok(());
+        }
+
+        // The output columns of union are the columns of the left child, map them to
+        // the columns of the right child.
+        let mut right_columns = HashMap::with_capacity(union.pairs.len());
+        for (left, right) in union.pairs.iter() {
+            if right_columns.insert(*left, *right).is_some() {
+                return Ok(());
+            }
+        }
+        let metadata = self.metadata.read().clone();
+        let right_column = |scalar: &ScalarExpr| -> Option<ScalarExpr> {
+            let column = match scalar {
+                ScalarExpr::BoundColumnRef(column) => column,
+                _ => return None,
+            };
+            let right = *right_columns.get(&column.column.index)?;
+            let data_type = metadata.column(right).data_type();
+            if data_type != metadata.column(column.column.index).data_type() {
+                return None;
+            }
+            Some(ScalarExpr::BoundColumnRef(BoundColumnRef {
+                span: column.span,
+                column: ColumnBindingBuilder::new(
+                    metadata.column(right).name(),
+                    right,
+                    Box::new(data_type),
+                    Visibility::Visible,
+                )
+                .build(),
+            }))
+        };
+
+        let mut pairs = Vec::new();
+        let mut right_group_items = Vec::with_capacity(final_agg.group_items.len());
+        for item in final_agg.group_items.iter() {
+            let scalar = match right_column(&item.scalar) {
+                Some(scalar) => scalar,
+                None => return Ok(()),
+            };
+            let right = right_columns[&item.index];
+            pairs.push((item.index, right));
+            right_group_items.push(ScalarItem {
+                scalar,
+                index: right,
+            });
+        }
+
+        let function_factory = AggregateFunctionFactory::instance();
+        let mut left_functions = Vec::with_capacity(final_agg.aggregate_functions.len());
+        let mut right_functions = Vec::with_capacity(final_agg.aggregate_functions.len());
+        let mut final_functions = Vec::with_capacity(final_agg.aggregate_functions.len());
+        // The columns of COUNT, which are replaced by the SUM of the counts of the branches.
+        let mut count_columns = HashMap::new();
+        for item in final_agg.aggregate_functions.iter() {
+            let agg = match &item.scalar {
+                ScalarExpr::AggregateFunction(agg) => agg,
+                _ => return Ok(()),
+            };
+            let func_name = agg.func_name.to_lowercase();
+            if agg.distinct
+                || !agg.params.is_empty()
+                || !matches!(func_name.as_str(), "sum" | "count" | "min" | "max")
+            {
+                return Ok(());
+            }
+
+            let mut right_agg = agg.clone();
+            right_agg.args = match agg.args.iter().map(right_column).collect::<Option<_>>() {
+                Some(args) => args,
+                None => return Ok(()),
+            };
+
+            // The results of the branches.
+            let left_index = self
+                .metadata
+                .write()
+                .add_derived_column(agg.display_name.clone(), *agg.return_type.clone());
+            let right_index = self
+                .metadata
+                .write()
+                .add_derived_column(agg.display_name.clone(), *agg.return_type.clone());
+            pairs.push((left_index, right_index));
+            left_functions.push(ScalarItem {
+                scalar: item.scalar.clone(),
+                index: left_index,
+            });
+            right_functions.push(ScalarItem {
+                scalar: ScalarExpr::AggregateFunction(right_agg),
+                index: right_index,
+            });
+
+            // Merge the results of the branches.
+            let merge_name = match func_name.as_str() {
+                "count" => "sum",
+                _ => func_name.as_str(),
+            };
+            let merge_func =
+                function_factory.get(merge_name, vec![], vec![*agg.return_type.clone()])?;
+            let merge_return_type = merge_func.return_type()?;
+            let merge_index = if func_name == "count" {
+                let index = self.metadata.write().add_derived_column(
+                    format!("sum({})", agg.display_name),
+                    merge_return_type.clone(),
+                );
+                count_columns.insert(item.index, (index, merge_return_type.clone()));
+                index
+            } else if merge_return_type != *agg.return_type {
+                return Ok(());
+            } else {
+                item.index
+            };
+            final_functions.push(ScalarItem {
+                scalar: ScalarExpr::AggregateFunction(AggregateFunction {
+                    func_name: merge_name.to_string(),
+                    distinct: false,
+                    params: vec![],
+                    args: vec![ScalarExpr::BoundColumnRef(BoundColumnRef {
+                        span: None,
+                        column: ColumnBindingBuilder::new(
+                            agg.display_name.clone(),
+                            left_index,
+                            agg.return_type.clone(),
+                            Visibility::Visible,
+                        )
+                        .build(),
+                    })],
+                    return_type: Box::new(merge_return_type),
+                    display_name: format!("{}({})", merge_name, agg.display_name),
+                }),
+                index: merge_index,
+            });
+        }
+
+        // The SUM of counts is nullable, cast it back to the type of COUNT, which requires
+        // the columns of COUNT are only referenced directly by the expression.
+        for (count_index, (sum_index, sum_type)) in count_columns.iter() {
+            let mut referenced = false;
+            for item in eval_scalar.items.iter_mut() {
+                if !item.scalar.used_columns().contains(count_index) {
+                    continue;
+                }
+                let column = match &item.scalar {
+                    ScalarExpr::BoundColumnRef(column) => column,
+                    _ => return Ok(()),
+                };
+                let count_type = *column.column.data_type.clone();
+                item.scalar = wrap_cast(
+                    &ScalarExpr::BoundColumnRef(BoundColumnRef {
+                        span: column.span,
+                        column: ColumnBindingBuilder::new(
+                            column.column.column_name.clone(),
+                            *sum_index,
+                            Box::new(sum_type.clone()),
+                            Visibility::Visible,
+                        )
+                        .build(),
+                    }),
+                    &count_type,
+                );
+                referenced = true;
+            }
+            if !referenced {
+                return Ok(());
+            }
+        }
+
+        let branch_agg =
+            |group_items: Vec<ScalarItem>, aggregate_functions: Vec<ScalarItem>, child: &SExpr| {
+                let final_agg = Aggregate {
+                    mode: AggregateMode::Final,
+                    group_items,
+                    aggregate_functions,
+                    from_distinct: false,
+                    limit: None,
+                    grouping_id_index: 0,
+                    grouping_sets: vec![],
+                };
+                let mut partial_agg = final_agg.clone();
+                partial_agg.mode = AggregateMode::Partial;
+                SExpr::create_unary(
+                    Arc::new(final_agg.into()),
+                    Arc::new(SExpr::create_unary(
+                        Arc::new(partial_agg.into()),
+                        Arc::new(child.clone()),
+                    )),
+                )
+            };
+        let left_expr = branch_agg(
+            final_agg.group_items.clone(),
+            left_functions,
+            union_expr.child(0)?,
+        );
+        let right_expr = branch_agg(right_group_items, right_functions, union_expr.child(1)?);
+        let union_expr = SExpr::create_binary(
+            Arc::new(UnionAll { pairs }.into()),
+            Arc::new(left_expr),
+            Arc::new(right_expr),
+        );
+
+        let mut final_agg = final_agg;
+        final_agg.aggregate_functions = final_functions;
+        let mut partial_agg = final_agg.clone();
+        partial_agg.mode = AggregateMode::Partial;
+        let result = SExpr::create_unary(
+            Arc::new(eval_scalar.into()),
+            Arc::new(SExpr::create_unary(
+                Arc::new(final_agg.into()),
+                Arc::new(SExpr::create_unary(
+                    Arc::new(partial_agg.into()),
+                    Arc::new(union_expr),
+                )),
+            )),
+        );
+        state.add_result(result);
+        Ok(())
+    }
+
+    fn patterns(&self) -> &Vec<SExpr> {
+        &self.patterns
+    }
+}
@@ -295,6 +295,10 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
             .items
             .iter()
             .any(|expr| find_subquery_in_expr(&expr.scalar)),
+        RelOperator::Udf(op) => op
+            .items
+            .iter()
+            .any(|expr| find_subquery_in_expr(&expr.scalar)),
     }
 }
 
@@ -314,5 +318,6 @@ fn find_subquery_in_expr(expr: &ScalarExpr) -> bool {
         ScalarExpr::FunctionCall(expr) => expr.arguments.iter().any(find_subquery_in_expr),
         ScalarExpr::CastExpr(expr) => find_subquery_in_expr(&expr.argument),
         ScalarExpr::SubqueryExpr(_) => true,
+        ScalarExpr::UDFCall(expr) => expr.arguments.iter().any(find_subquery_in_expr),
     }
 }
@@ -60,6 +60,7 @@ impl Planner {
         Planner { ctx }
     }
 
+    #[minitrace::trace]
     #[async_backtrace::framed]
     pub async fn plan_sql(&mut self, sql: &str) -> Result<(Plan, PlanExtras)> {
         let settings = self.ctx.get_settings();
@@ -15,12 +15,18 @@
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::str::FromStr;
+use std::sync::Arc;
 use std::time::Instant;
 
 use common_catalog::plan::StageTableInfo;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataField;
+use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
 use common_expression::Scalar;
 use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::CatalogInfo;
@@ -91,6 +97,8 @@ pub struct CopyIntoTablePlan {
     pub write_mode: CopyIntoTableMode,
     pub validation_mode: ValidationMode,
     pub force: bool,
+    /// Return the loading status of each file as the result set of COPY.
+    pub return_file_status: bool,
 
     pub stage_table_info: StageTableInfo,
     pub query: Option<Box<Plan>>,
@@ -221,10 +229,31 @@ pub enum CopyPlan {
         stage: Box<StageInfo>,
         path: String,
         validation_mode: ValidationMode,
+        /// Write a manifest listing the unloaded files.
+        manifest: bool,
         from: Box<Plan>,
     },
 }
 
+impl CopyPlan {
+    pub fn has_result_set(&self) -> bool {
+        matches!(self, CopyPlan::IntoTable(plan) if plan.return_file_status)
+    }
+
+    pub fn schema(&self) -> DataSchemaRef {
+        if self.has_result_set() {
+            DataSchemaRefExt::create(vec![
+                DataField::new("File", DataType::String),
+                DataField::new("Rows_loaded", DataType::Number(NumberDataType::UInt64)),
+                DataField::new("Errors_seen", DataType::Number(NumberDataType::UInt64)),
+                DataField::new("Error", DataType::Nullable(Box::new(DataType::String))),
+            ])
+        } else {
+            Arc::new(DataSchema::empty())
+        }
+    }
+}
+
 impl Debug for CopyPlan {
     // Ignore the schema.
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -236,11 +265,13 @@ impl Debug for CopyPlan {
                 stage,
                 path,
                 validation_mode,
+                manifest,
                 ..
             } => {
                 write!(f, "Copy into {stage:?}")?;
                 write!(f, ", path: {path:?}")?;
                 write!(f, ", validation_mode: {validation_mode:?}")?;
+                write!(f, ", manifest: {manifest}")?;
             }
             CopyPlan::NoFileToCopy => {
                 write!(f, "No file to copy")?;
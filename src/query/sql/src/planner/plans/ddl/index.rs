@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_ast::ast::TableIndexType;
+use common_functions::scalars::Tokenizer;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::TableInfo;
 use common_meta_types::MetaId;
@@ -48,3 +49,23 @@ pub struct RefreshIndexPlan {
     pub user_defined_block_name: bool,
     pub segment_locs: Option<Vec<Location>>,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateInvertedIndexPlan {
+    pub if_not_exists: bool,
+    pub index_name: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub columns: Vec<String>,
+    pub tokenizer: Tokenizer,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropInvertedIndexPlan {
+    pub if_exists: bool,
+    pub index_name: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
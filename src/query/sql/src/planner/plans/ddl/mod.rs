@@ -14,10 +14,15 @@
 
 mod account;
 mod catalog;
+mod comment;
 mod database;
 mod file_format;
 mod index;
+mod pipe;
+mod sequence;
 mod stage;
+mod storage_profile;
+mod stream;
 mod table;
 mod udf;
 mod view;
@@ -25,10 +30,15 @@ mod virtual_column;
 
 pub use account::*;
 pub use catalog::*;
+pub use comment::*;
 pub use database::*;
 pub use file_format::*;
 pub use index::*;
+pub use pipe::*;
+pub use sequence::*;
 pub use stage::*;
+pub use storage_profile::*;
+pub use stream::*;
 pub use table::*;
 pub use udf::*;
 pub use view::*;
@@ -0,0 +1,87 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataField;
+use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::PipeSource;
+use common_meta_app::principal::UserIdentity;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreatePipePlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub name: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub source: PipeSource,
+    pub file_format_params: FileFormatParams,
+    pub batch_size: u64,
+    pub owner: UserIdentity,
+    pub comment: String,
+}
+
+impl CreatePipePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropPipePlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+}
+
+impl DropPipePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DescPipePlan {
+    pub name: String,
+}
+
+impl DescPipePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        pipe_schema()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShowPipesPlan {}
+
+impl ShowPipesPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        pipe_schema()
+    }
+}
+
+fn pipe_schema() -> DataSchemaRef {
+    DataSchemaRefExt::create(vec![
+        DataField::new("Name", DataType::String),
+        DataField::new("Table", DataType::String),
+        DataField::new("Source", DataType::String),
+        DataField::new("File Format", DataType::String),
+        DataField::new("Batch Size", DataType::Number(NumberDataType::UInt64)),
+        DataField::new("Owner", DataType::String),
+        DataField::new("Comment", DataType::String),
+    ])
+}
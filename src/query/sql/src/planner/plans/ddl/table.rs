@@ -24,9 +24,11 @@ use common_expression::DataField;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
+use common_expression::Scalar;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
+use common_meta_app::principal::SequenceInfo;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::UndropTableReq;
 use common_meta_app::storage::StorageParams;
@@ -51,6 +53,9 @@ pub struct CreateTablePlan {
     pub field_comments: Vec<String>,
     pub cluster_key: Option<String>,
     pub as_select: Option<Box<Plan>>,
+    pub clone_from: Option<CloneTableSource>,
+    /// The sequences of the identity columns, created with the table.
+    pub identity_sequences: Vec<SequenceInfo>,
 }
 
 impl CreateTablePlan {
@@ -59,6 +64,16 @@ impl CreateTablePlan {
     }
 }
 
+/// The source of `CREATE TABLE ... CLONE`, the new table shares the snapshot of the
+/// source table at the navigation point without copying any data.
+#[derive(Clone, Debug)]
+pub struct CloneTableSource {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub navigation: Option<NavigationPoint>,
+}
+
 /// Desc.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DescribeTablePlan {
@@ -66,6 +81,8 @@ pub struct DescribeTablePlan {
     pub database: String,
     /// The table name.
     pub table: String,
+    /// Describe the table as it was at this point.
+    pub navigation: Option<NavigationPoint>,
     /// The schema description of the output.
     pub schema: DataSchemaRef,
 }
@@ -151,6 +168,8 @@ pub struct OptimizeTablePlan {
     pub table: String,
     pub action: OptimizeTableAction,
     pub limit: Option<usize>,
+    /// Only compact the segments of the given partition.
+    pub partition: Option<Scalar>,
 }
 
 impl OptimizeTablePlan {
@@ -213,6 +232,21 @@ impl SetOptionsPlan {
     }
 }
 
+/// Set or unset the tags of a table, a `None` value unsets the tag.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetTableTagsPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub tags: BTreeMap<String, Option<String>>,
+}
+
+impl SetTableTagsPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 // Table add column
 #[derive(Clone, Debug, PartialEq)]
 pub struct AddTableColumnPlan {
@@ -395,3 +429,18 @@ impl DropTableClusterKeyPlan {
         Arc::new(DataSchema::empty())
     }
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropTablePartitionPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub partition: Scalar,
+}
+
+impl DropTablePartitionPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
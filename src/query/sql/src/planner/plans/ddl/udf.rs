@@ -17,6 +17,7 @@ use common_meta_app::principal::UserDefinedFunction;
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateUDFPlan {
     pub if_not_exists: bool,
+    pub temporary: bool,
     pub udf: UserDefinedFunction,
 }
 
@@ -28,5 +29,6 @@ pub struct AlterUDFPlan {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DropUDFPlan {
     pub if_exists: bool,
+    pub temporary: bool,
     pub name: String,
 }
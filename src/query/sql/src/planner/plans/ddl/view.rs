@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_expression::TableSchemaRef;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateViewPlan {
     pub if_not_exists: bool,
+    pub temporary: bool,
     pub tenant: String,
     pub catalog: String,
     pub database: String,
@@ -35,6 +38,44 @@ pub struct AlterViewPlan {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DropViewPlan {
+    pub if_exists: bool,
+    pub temporary: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateMaterializedViewPlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+    pub schema: TableSchemaRef,
+    pub subquery: String,
+    /// The `(database, table)` of the tables read by the query, without duplicates.
+    pub source_tables: Vec<(String, String)>,
+    /// Whether the query only filters and projects the rows of one table, so that the view can
+    /// be refreshed by appending the result of the query over the rows inserted into the table.
+    pub incremental: bool,
+    /// Set if the query aggregates the rows of one table with `count`, `sum`, `min` and `max`
+    /// only, the function merging each column of the view, empty for the group keys. The view
+    /// can then be refreshed by merging it with the result of the query over the inserted rows.
+    pub aggregates: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefreshMaterializedViewPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropMaterializedViewPlan {
     pub if_exists: bool,
     pub tenant: String,
     pub catalog: String,
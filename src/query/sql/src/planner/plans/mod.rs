@@ -44,6 +44,7 @@ mod scan;
 mod setting;
 pub mod share;
 mod sort;
+mod udf;
 mod union_all;
 mod update;
 mod window;
@@ -83,6 +84,7 @@ pub use scan::*;
 pub use setting::*;
 pub use share::*;
 pub use sort::*;
+pub use udf::*;
 pub use union_all::UnionAll;
 pub use update::*;
 pub use window::*;
@@ -40,6 +40,7 @@ use crate::plans::CteScan;
 use crate::plans::Exchange;
 use crate::plans::Lambda;
 use crate::plans::ProjectSet;
+use crate::plans::Udf;
 use crate::plans::Window;
 
 pub trait Operator {
@@ -83,6 +84,7 @@ pub enum RelOp {
     ProjectSet,
     MaterializedCte,
     Lambda,
+    Udf,
     ConstantTableScan,
 
     // Pattern
@@ -108,6 +110,7 @@ pub enum RelOperator {
     ProjectSet(ProjectSet),
     MaterializedCte(MaterializedCte),
     Lambda(Lambda),
+    Udf(Udf),
     ConstantTableScan(ConstantTableScan),
 
     Pattern(PatternPlan),
@@ -133,6 +136,7 @@ impl Operator for RelOperator {
             RelOperator::CteScan(rel_op) => rel_op.rel_op(),
             RelOperator::MaterializedCte(rel_op) => rel_op.rel_op(),
             RelOperator::Lambda(rel_op) => rel_op.rel_op(),
+            RelOperator::Udf(rel_op) => rel_op.rel_op(),
             RelOperator::ConstantTableScan(rel_op) => rel_op.rel_op(),
         }
     }
@@ -156,6 +160,7 @@ impl Operator for RelOperator {
             RelOperator::CteScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::MaterializedCte(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Lambda(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::Udf(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
         }
     }
@@ -179,6 +184,7 @@ impl Operator for RelOperator {
             RelOperator::CteScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::MaterializedCte(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Lambda(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::Udf(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
         }
     }
@@ -202,6 +208,7 @@ impl Operator for RelOperator {
             RelOperator::CteScan(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::MaterializedCte(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::Lambda(rel_op) => rel_op.derive_cardinality(rel_expr),
+            RelOperator::Udf(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_cardinality(rel_expr),
         }
     }
@@ -265,6 +272,9 @@ impl Operator for RelOperator {
             RelOperator::Lambda(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::Udf(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
             RelOperator::ConstantTableScan(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
@@ -591,6 +601,24 @@ impl TryFrom<RelOperator> for Lambda {
     }
 }
 
+impl From<Udf> for RelOperator {
+    fn from(value: Udf) -> Self {
+        Self::Udf(value)
+    }
+}
+
+impl TryFrom<RelOperator> for Udf {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
+        if let RelOperator::Udf(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal("Cannot downcast RelOperator to Udf"))
+        }
+    }
+}
+
 impl From<ConstantTableScan> for RelOperator {
     fn from(value: ConstantTableScan) -> Self {
         Self::ConstantTableScan(value)
@@ -18,22 +18,27 @@ use std::sync::Arc;
 
 use common_ast::ast::ExplainKind;
 use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
 use common_expression::DataField;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
 
 use super::data_mask::CreateDatamaskPolicyPlan;
+use super::CommentPlan;
 use super::CopyIntoTableMode;
 use super::CreateIndexPlan;
+use super::CreateInvertedIndexPlan;
 use super::CreateShareEndpointPlan;
 use super::DescDatamaskPolicyPlan;
 use super::DropDatamaskPolicyPlan;
 use super::DropIndexPlan;
+use super::DropInvertedIndexPlan;
 use super::DropShareEndpointPlan;
 use super::ModifyTableColumnPlan;
 use super::RenameTableColumnPlan;
 use super::SetOptionsPlan;
+use super::SetTableTagsPlan;
 use super::VacuumDropTablePlan;
 use super::VacuumTablePlan;
 use crate::optimizer::SExpr;
@@ -61,9 +66,14 @@ use crate::plans::AnalyzeTablePlan;
 use crate::plans::CreateCatalogPlan;
 use crate::plans::CreateDatabasePlan;
 use crate::plans::CreateFileFormatPlan;
+use crate::plans::CreateMaterializedViewPlan;
 use crate::plans::CreateNetworkPolicyPlan;
+use crate::plans::CreatePipePlan;
 use crate::plans::CreateRolePlan;
+use crate::plans::CreateSequencePlan;
 use crate::plans::CreateStagePlan;
+use crate::plans::CreateStorageProfilePlan;
+use crate::plans::CreateStreamPlan;
 use crate::plans::CreateTablePlan;
 use crate::plans::CreateUDFPlan;
 use crate::plans::CreateUserPlan;
@@ -71,15 +81,23 @@ use crate::plans::CreateViewPlan;
 use crate::plans::CreateVirtualColumnPlan;
 use crate::plans::DeletePlan;
 use crate::plans::DescNetworkPolicyPlan;
+use crate::plans::DescPipePlan;
+use crate::plans::DescStorageProfilePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropCatalogPlan;
 use crate::plans::DropDatabasePlan;
 use crate::plans::DropFileFormatPlan;
+use crate::plans::DropMaterializedViewPlan;
 use crate::plans::DropNetworkPolicyPlan;
+use crate::plans::DropPipePlan;
 use crate::plans::DropRolePlan;
+use crate::plans::DropSequencePlan;
 use crate::plans::DropStagePlan;
+use crate::plans::DropStorageProfilePlan;
+use crate::plans::DropStreamPlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
+use crate::plans::DropTablePartitionPlan;
 use crate::plans::DropTablePlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
@@ -91,6 +109,7 @@ use crate::plans::GrantRolePlan;
 use crate::plans::KillPlan;
 use crate::plans::OptimizeTablePlan;
 use crate::plans::RefreshIndexPlan;
+use crate::plans::RefreshMaterializedViewPlan;
 use crate::plans::RefreshVirtualColumnPlan;
 use crate::plans::RemoveStagePlan;
 use crate::plans::RenameDatabasePlan;
@@ -107,8 +126,11 @@ use crate::plans::ShowCreateTablePlan;
 use crate::plans::ShowFileFormatsPlan;
 use crate::plans::ShowGrantsPlan;
 use crate::plans::ShowNetworkPoliciesPlan;
+use crate::plans::ShowPipesPlan;
 use crate::plans::ShowRolesPlan;
+use crate::plans::ShowSequencesPlan;
 use crate::plans::ShowShareEndpointPlan;
+use crate::plans::ShowStorageProfilesPlan;
 use crate::plans::TruncateTablePlan;
 use crate::plans::UnSettingPlan;
 use crate::plans::UndropDatabasePlan;
@@ -177,6 +199,7 @@ pub enum Plan {
     ModifyTableColumn(Box<ModifyTableColumnPlan>),
     AlterTableClusterKey(Box<AlterTableClusterKeyPlan>),
     DropTableClusterKey(Box<DropTableClusterKeyPlan>),
+    DropTablePartition(Box<DropTablePartitionPlan>),
     ReclusterTable(Box<ReclusterTablePlan>),
     RevertTable(Box<RevertTablePlan>),
     TruncateTable(Box<TruncateTablePlan>),
@@ -186,6 +209,10 @@ pub enum Plan {
     AnalyzeTable(Box<AnalyzeTablePlan>),
     ExistsTable(Box<ExistsTablePlan>),
     SetOptions(Box<SetOptionsPlan>),
+    SetTableTags(Box<SetTableTagsPlan>),
+
+    // Comments
+    Comment(Box<CommentPlan>),
 
     // Insert
     Insert(Box<Insert>),
@@ -197,11 +224,20 @@ pub enum Plan {
     CreateView(Box<CreateViewPlan>),
     AlterView(Box<AlterViewPlan>),
     DropView(Box<DropViewPlan>),
+    CreateMaterializedView(Box<CreateMaterializedViewPlan>),
+    RefreshMaterializedView(Box<RefreshMaterializedViewPlan>),
+    DropMaterializedView(Box<DropMaterializedViewPlan>),
+
+    // Streams
+    CreateStream(Box<CreateStreamPlan>),
+    DropStream(Box<DropStreamPlan>),
 
     // Indexes
     CreateIndex(Box<CreateIndexPlan>),
     DropIndex(Box<DropIndexPlan>),
     RefreshIndex(Box<RefreshIndexPlan>),
+    CreateInvertedIndex(Box<CreateInvertedIndexPlan>),
+    DropInvertedIndex(Box<DropInvertedIndexPlan>),
 
     // Virtual Columns
     CreateVirtualColumn(Box<CreateVirtualColumnPlan>),
@@ -273,6 +309,23 @@ pub enum Plan {
     DropNetworkPolicy(Box<DropNetworkPolicyPlan>),
     DescNetworkPolicy(Box<DescNetworkPolicyPlan>),
     ShowNetworkPolicies(Box<ShowNetworkPoliciesPlan>),
+
+    // Storage profile
+    CreateStorageProfile(Box<CreateStorageProfilePlan>),
+    DropStorageProfile(Box<DropStorageProfilePlan>),
+    DescStorageProfile(Box<DescStorageProfilePlan>),
+    ShowStorageProfiles(Box<ShowStorageProfilesPlan>),
+
+    // Pipe
+    CreatePipe(Box<CreatePipePlan>),
+    DropPipe(Box<DropPipePlan>),
+    DescPipe(Box<DescPipePlan>),
+    ShowPipes(Box<ShowPipesPlan>),
+
+    // Sequence
+    CreateSequence(Box<CreateSequencePlan>),
+    DropSequence(Box<DropSequencePlan>),
+    ShowSequences(Box<ShowSequencesPlan>),
 }
 
 #[derive(Clone, Debug)]
@@ -335,6 +388,7 @@ impl Display for Plan {
             Plan::DropTableColumn(_) => write!(f, "DropTableColumn"),
             Plan::AlterTableClusterKey(_) => write!(f, "AlterTableClusterKey"),
             Plan::DropTableClusterKey(_) => write!(f, "DropTableClusterKey"),
+            Plan::DropTablePartition(_) => write!(f, "DropTablePartition"),
             Plan::ReclusterTable(_) => write!(f, "ReclusterTable"),
             Plan::TruncateTable(_) => write!(f, "TruncateTable"),
             Plan::OptimizeTable(_) => write!(f, "OptimizeTable"),
@@ -345,9 +399,16 @@ impl Display for Plan {
             Plan::CreateView(_) => write!(f, "CreateView"),
             Plan::AlterView(_) => write!(f, "AlterView"),
             Plan::DropView(_) => write!(f, "DropView"),
+            Plan::CreateMaterializedView(_) => write!(f, "CreateMaterializedView"),
+            Plan::RefreshMaterializedView(_) => write!(f, "RefreshMaterializedView"),
+            Plan::DropMaterializedView(_) => write!(f, "DropMaterializedView"),
+            Plan::CreateStream(_) => write!(f, "CreateStream"),
+            Plan::DropStream(_) => write!(f, "DropStream"),
             Plan::CreateIndex(_) => write!(f, "CreateIndex"),
             Plan::DropIndex(_) => write!(f, "DropIndex"),
             Plan::RefreshIndex(_) => write!(f, "RefreshIndex"),
+            Plan::CreateInvertedIndex(_) => write!(f, "CreateInvertedIndex"),
+            Plan::DropInvertedIndex(_) => write!(f, "DropInvertedIndex"),
             Plan::CreateVirtualColumn(_) => write!(f, "CreateVirtualColumn"),
             Plan::AlterVirtualColumn(_) => write!(f, "AlterVirtualColumn"),
             Plan::DropVirtualColumn(_) => write!(f, "DropVirtualColumn"),
@@ -408,11 +469,24 @@ impl Display for Plan {
             Plan::SetOptions(..) => {
                 write!(f, "SetOptions")
             }
+            Plan::SetTableTags(..) => write!(f, "SetTableTags"),
+            Plan::Comment(..) => write!(f, "Comment"),
             Plan::CreateNetworkPolicy(_) => write!(f, "CreateNetworkPolicy"),
             Plan::AlterNetworkPolicy(_) => write!(f, "AlterNetworkPolicy"),
             Plan::DropNetworkPolicy(_) => write!(f, "DropNetworkPolicy"),
             Plan::DescNetworkPolicy(_) => write!(f, "DescNetworkPolicy"),
             Plan::ShowNetworkPolicies(_) => write!(f, "ShowNetworkPolicies"),
+            Plan::CreateStorageProfile(_) => write!(f, "CreateStorageProfile"),
+            Plan::DropStorageProfile(_) => write!(f, "DropStorageProfile"),
+            Plan::DescStorageProfile(_) => write!(f, "DescStorageProfile"),
+            Plan::ShowStorageProfiles(_) => write!(f, "ShowStorageProfiles"),
+            Plan::CreatePipe(_) => write!(f, "CreatePipe"),
+            Plan::DropPipe(_) => write!(f, "DropPipe"),
+            Plan::DescPipe(_) => write!(f, "DescPipe"),
+            Plan::ShowPipes(_) => write!(f, "ShowPipes"),
+            Plan::CreateSequence(_) => write!(f, "CreateSequence"),
+            Plan::DropSequence(_) => write!(f, "DropSequence"),
+            Plan::ShowSequences(_) => write!(f, "ShowSequences"),
         }
     }
 }
@@ -426,6 +500,20 @@ impl Plan {
                 bind_context,
                 ..
             } => bind_context.output_schema(),
+            Plan::Explain {
+                kind: ExplainKind::Estimate,
+                ..
+            } => DataSchemaRefExt::create(vec![
+                DataField::new("table", DataType::String),
+                DataField::new("estimated_rows", DataType::Number(NumberDataType::UInt64)),
+                DataField::new("read_rows", DataType::Number(NumberDataType::UInt64)),
+                DataField::new("read_bytes", DataType::Number(NumberDataType::UInt64)),
+                DataField::new("partitions_total", DataType::Number(NumberDataType::UInt64)),
+                DataField::new(
+                    "partitions_scanned",
+                    DataType::Number(NumberDataType::UInt64),
+                ),
+            ]),
             Plan::Explain { .. }
             | Plan::ExplainAst { .. }
             | Plan::ExplainSyntax { .. }
@@ -445,6 +533,7 @@ impl Plan {
 
             Plan::Insert(plan) => plan.schema(),
             Plan::Replace(plan) => plan.schema(),
+            Plan::Copy(plan) => plan.schema(),
 
             Plan::Presign(plan) => plan.schema(),
             Plan::ShowShareEndpoint(plan) => plan.schema(),
@@ -459,6 +548,17 @@ impl Plan {
             Plan::DropNetworkPolicy(plan) => plan.schema(),
             Plan::DescNetworkPolicy(plan) => plan.schema(),
             Plan::ShowNetworkPolicies(plan) => plan.schema(),
+            Plan::CreateStorageProfile(plan) => plan.schema(),
+            Plan::DropStorageProfile(plan) => plan.schema(),
+            Plan::DescStorageProfile(plan) => plan.schema(),
+            Plan::ShowStorageProfiles(plan) => plan.schema(),
+            Plan::CreatePipe(plan) => plan.schema(),
+            Plan::DropPipe(plan) => plan.schema(),
+            Plan::DescPipe(plan) => plan.schema(),
+            Plan::ShowPipes(plan) => plan.schema(),
+            Plan::CreateSequence(plan) => plan.schema(),
+            Plan::DropSequence(plan) => plan.schema(),
+            Plan::ShowSequences(plan) => plan.schema(),
             other => {
                 debug_assert!(!other.has_result_set());
                 Arc::new(DataSchema::empty())
@@ -492,6 +592,11 @@ impl Plan {
                 | Plan::DescDatamaskPolicy(_)
                 | Plan::DescNetworkPolicy(_)
                 | Plan::ShowNetworkPolicies(_)
-        )
+                | Plan::DescStorageProfile(_)
+                | Plan::ShowStorageProfiles(_)
+                | Plan::DescPipe(_)
+                | Plan::ShowPipes(_)
+                | Plan::ShowSequences(_)
+        ) || matches!(self, Plan::Copy(plan) if plan.has_result_set())
     }
 }
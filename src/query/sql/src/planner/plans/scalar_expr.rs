@@ -14,6 +14,7 @@
 
 use std::hash::Hash;
 use std::hash::Hasher;
+use std::sync::Arc;
 
 use common_ast::ast::BinaryOperator;
 use common_exception::ErrorCode;
@@ -22,6 +23,7 @@ use common_exception::Result;
 use common_exception::Span;
 use common_expression::types::DataType;
 use common_expression::Scalar;
+use common_meta_app::principal::SequenceInfo;
 use educe::Educe;
 use itertools::Itertools;
 
@@ -43,6 +45,7 @@ pub enum ScalarExpr {
     FunctionCall(FunctionCall),
     CastExpr(CastExpr),
     SubqueryExpr(SubqueryExpr),
+    UDFCall(UDFCall),
 }
 
 impl ScalarExpr {
@@ -87,6 +90,13 @@ impl ScalarExpr {
             }
             ScalarExpr::CastExpr(scalar) => scalar.argument.used_columns(),
             ScalarExpr::SubqueryExpr(scalar) => scalar.outer_columns.clone(),
+            ScalarExpr::UDFCall(scalar) => {
+                let mut result = ColumnSet::new();
+                for scalar in &scalar.arguments {
+                    result = result.union(&scalar.used_columns()).cloned().collect();
+                }
+                result
+            }
         }
     }
 
@@ -122,6 +132,13 @@ impl ScalarExpr {
                 }
                 Ok(result)
             }
+            ScalarExpr::UDFCall(scalar) => {
+                let mut result = vec![];
+                for scalar in &scalar.arguments {
+                    result.append(&mut scalar.used_tables(metadata.clone())?);
+                }
+                Ok(result)
+            }
             ScalarExpr::CastExpr(scalar) => scalar.argument.used_tables(metadata),
             ScalarExpr::WindowFunction(_) | ScalarExpr::SubqueryExpr(_) => {
                 Err(ErrorCode::Unimplemented(
@@ -147,6 +164,7 @@ impl ScalarExpr {
             }),
             ScalarExpr::CastExpr(expr) => expr.span.or(expr.argument.span()),
             ScalarExpr::SubqueryExpr(expr) => expr.span,
+            ScalarExpr::UDFCall(expr) => expr.span,
             _ => None,
         }
     }
@@ -156,7 +174,8 @@ impl ScalarExpr {
             ScalarExpr::BoundColumnRef(_) | ScalarExpr::ConstantExpr(_) => true,
             ScalarExpr::WindowFunction(_)
             | ScalarExpr::AggregateFunction(_)
-            | ScalarExpr::SubqueryExpr(_) => false,
+            | ScalarExpr::SubqueryExpr(_)
+            | ScalarExpr::UDFCall(_) => false,
             ScalarExpr::FunctionCall(func) => {
                 func.arguments.iter().all(|arg| arg.valid_for_clustering())
             }
@@ -261,6 +280,24 @@ impl TryFrom<ScalarExpr> for LambdaFunc {
     }
 }
 
+impl From<UDFCall> for ScalarExpr {
+    fn from(v: UDFCall) -> Self {
+        Self::UDFCall(v)
+    }
+}
+
+impl TryFrom<ScalarExpr> for UDFCall {
+    type Error = ErrorCode;
+
+    fn try_from(value: ScalarExpr) -> Result<Self> {
+        if let ScalarExpr::UDFCall(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal("Cannot downcast Scalar to UDFCall"))
+        }
+    }
+}
+
 impl From<FunctionCall> for ScalarExpr {
     fn from(v: FunctionCall) -> Self {
         Self::FunctionCall(v)
@@ -462,6 +499,42 @@ pub struct LambdaFunc {
     pub return_type: Box<DataType>,
 }
 
+/// A call of a WASM or UDF server function, evaluated by the `Udf` operator.
+#[derive(Clone, Debug, Educe)]
+#[educe(PartialEq, Eq, Hash)]
+pub struct UDFCall {
+    #[educe(Hash(ignore), PartialEq(ignore), Eq(ignore))]
+    pub span: Span,
+    pub func_name: String,
+    pub display_name: String,
+    pub arguments: Vec<ScalarExpr>,
+    pub return_type: Box<DataType>,
+    pub handler: String,
+    #[educe(Hash(ignore), PartialEq(ignore), Eq(ignore))]
+    pub udf_type: UDFType,
+}
+
+/// How a function called by a `UDFCall` is evaluated.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum UDFType {
+    /// By the WASM module implementing it, read from its stage when the call is bound.
+    Wasm {
+        /// The SHA-256 of the module, hex encoded.
+        code_hash: String,
+        /// The bytes of the module, hex encoded.
+        code: Arc<String>,
+    },
+    /// By an external UDF server.
+    Server {
+        address: String,
+        /// Whether NULL is returned without calling the server if an argument is NULL.
+        returns_null_on_null_input: bool,
+    },
+    /// By allocating the numbers of a sequence, i.e. `nextval`, it's the sequence when the
+    /// call is bound.
+    Sequence(SequenceInfo),
+}
+
 #[derive(Clone, Debug, Educe)]
 #[educe(PartialEq, Eq, Hash)]
 pub struct FunctionCall {
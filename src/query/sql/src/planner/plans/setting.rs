@@ -26,5 +26,7 @@ pub struct SettingPlan {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UnSettingPlan {
+    /// Only reset the session value, the global value is kept.
+    pub is_session: bool,
     pub vars: Vec<String>,
 }
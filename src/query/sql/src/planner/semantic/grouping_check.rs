@@ -23,6 +23,7 @@ use crate::plans::CastExpr;
 use crate::plans::FunctionCall;
 use crate::plans::LambdaFunc;
 use crate::plans::ScalarExpr;
+use crate::plans::UDFCall;
 use crate::BindContext;
 
 /// Check validity of scalar expression in a grouping context.
@@ -118,6 +119,18 @@ impl<'a> GroupingChecker<'a> {
                 }
                 .into())
             }
+            ScalarExpr::UDFCall(udf) => {
+                let arguments = udf
+                    .arguments
+                    .iter()
+                    .map(|arg| self.resolve(arg, span))
+                    .collect::<Result<Vec<ScalarExpr>>>()?;
+                Ok(UDFCall {
+                    arguments,
+                    ..udf.clone()
+                }
+                .into())
+            }
             ScalarExpr::CastExpr(cast) => Ok(CastExpr {
                 span: cast.span,
                 is_try: cast.is_try,
@@ -227,6 +227,18 @@ impl ScalarExpr {
                 data_type: (*func.return_type).clone(),
                 display_name: func.display_name.clone(),
             },
+            ScalarExpr::UDFCall(udf) => RawExpr::ColumnRef {
+                span: None,
+                id: ColumnBindingBuilder::new(
+                    udf.display_name.clone(),
+                    usize::MAX,
+                    Box::new((*udf.return_type).clone()),
+                    Visibility::Visible,
+                )
+                .build(),
+                data_type: (*udf.return_type).clone(),
+                display_name: udf.display_name.clone(),
+            },
             ScalarExpr::FunctionCall(func) => RawExpr::FunctionCall {
                 span: func.span,
                 name: func.func_name.clone(),
@@ -61,6 +61,8 @@ use common_expression::TableDataType;
 use common_functions::aggregates::AggregateCountFunction;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::is_builtin_function;
+use common_functions::scalars::format;
+use common_functions::ASYNC_FUNCTIONS;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_functions::GENERAL_LAMBDA_FUNCTIONS;
 use common_functions::GENERAL_WINDOW_FUNCTIONS;
@@ -72,6 +74,7 @@ use simsearch::SimSearch;
 
 use super::name_resolution::NameResolutionContext;
 use super::normalize_identifier;
+use crate::binder::read_wasm_module;
 use crate::binder::wrap_cast;
 use crate::binder::Binder;
 use crate::binder::ColumnBindingBuilder;
@@ -94,6 +97,8 @@ use crate::plans::NtileFunction;
 use crate::plans::ScalarExpr;
 use crate::plans::SubqueryExpr;
 use crate::plans::SubqueryType;
+use crate::plans::UDFCall;
+use crate::plans::UDFType;
 use crate::plans::WindowFunc;
 use crate::plans::WindowFuncFrame;
 use crate::plans::WindowFuncFrameBound;
@@ -664,6 +669,7 @@ impl<'a> TypeChecker<'a> {
                             .chain(AggregateFunctionFactory::instance().registered_names())
                             .chain(GENERAL_WINDOW_FUNCTIONS.iter().cloned().map(str::to_string))
                             .chain(GENERAL_LAMBDA_FUNCTIONS.iter().cloned().map(str::to_string))
+                            .chain(ASYNC_FUNCTIONS.iter().cloned().map(str::to_string))
                             .chain(
                                 Self::all_rewritable_scalar_function()
                                     .iter()
@@ -861,6 +867,8 @@ impl<'a> TypeChecker<'a> {
                             ));
                         }
                     }
+                } else if ASYNC_FUNCTIONS.contains(&name.as_str()) {
+                    self.resolve_async_function(*span, &name, &args).await?
                 } else {
                     // Scalar function
                     let params = params
@@ -1613,6 +1621,27 @@ impl<'a> TypeChecker<'a> {
             params
         };
 
+        // Convert the percentile of approx_percentile and the k of approx_top_k to params
+        let params = if (func_name.eq_ignore_ascii_case("approx_percentile")
+            || func_name.eq_ignore_ascii_case("approx_top_k"))
+            && arguments.len() == 2
+            && params.is_empty()
+        {
+            let param_value = ConstantExpr::try_from(arguments[1].clone());
+            if !(arg_types[1].is_numeric() || arg_types[1].is_decimal()) || param_value.is_err() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The second argument of `{}` must be a constant number",
+                    func_name.to_lowercase()
+                )));
+            }
+            arguments.pop();
+            arg_types.pop();
+            let param = param_value.unwrap();
+            vec![param.value]
+        } else {
+            params
+        };
+
         // Rewrite `xxx(distinct)` to `xxx_distinct(...)`
         let (func_name, distinct) = if func_name.eq_ignore_ascii_case("count") && distinct {
             ("count_distinct", false)
@@ -2397,10 +2426,67 @@ impl<'a> TypeChecker<'a> {
                     "Array aggregate function name be must a constant string",
                 )))
             }
+            (name @ ("format" | "printf"), args) if !args.is_empty() => {
+                Some(self.resolve_format_function(span, name, args).await)
+            }
             _ => None,
         }
     }
 
+    /// Resolves `format(template, arg, ...)` and `printf(template, arg, ...)`, the arguments
+    /// are cast to strings, and a constant template is checked to take as many arguments as
+    /// are given.
+    #[async_recursion::async_recursion]
+    #[async_backtrace::framed]
+    async fn resolve_format_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        args: &[&Expr],
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let mut new_args = Vec::with_capacity(args.len());
+        for (i, arg) in args.iter().enumerate() {
+            let box (mut scalar, data_type) = self.resolve(arg).await?;
+            if i > 0
+                && !matches!(
+                    data_type.remove_nullable(),
+                    DataType::String | DataType::Null
+                )
+            {
+                let target_type = match data_type.is_nullable() {
+                    true => DataType::String.wrap_nullable(),
+                    false => DataType::String,
+                };
+                scalar = CastExpr {
+                    span: arg.span(),
+                    is_try: false,
+                    argument: Box::new(scalar),
+                    target_type: Box::new(target_type),
+                }
+                .into();
+            }
+            new_args.push(scalar);
+        }
+
+        if let Ok(ConstantExpr {
+            value: Scalar::String(template),
+            ..
+        }) = ConstantExpr::try_from(new_args[0].clone())
+        {
+            let pieces = match func_name {
+                "format" => format::parse_format(&template),
+                _ => format::parse_printf(&template),
+            }
+            .map_err(|e| format!("{}: {}", func_name, e));
+            pieces
+                .and_then(|pieces| format::check_args(func_name, &pieces, args.len() - 1))
+                .map_err(|e| ErrorCode::SemanticError(e).set_span(span))?;
+        }
+
+        self.resolve_scalar_function_call(span, func_name, vec![], new_args)
+            .await
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     async fn resolve_trim_function(
@@ -2580,9 +2666,15 @@ impl<'a> TypeChecker<'a> {
             return Ok(None);
         }
 
-        let udf = UserApiProvider::instance()
-            .get_udf(self.ctx.get_tenant().as_str(), func_name)
-            .await;
+        // The temporary UDF of the session shadows the persistent one.
+        let udf = match self.ctx.get_temporary_udf(func_name) {
+            Some(udf) => Ok(udf),
+            None => {
+                UserApiProvider::instance()
+                    .get_udf(self.ctx.get_tenant().as_str(), func_name)
+                    .await
+            }
+        };
 
         let udf = if let Ok(udf) = udf {
             udf
@@ -2590,6 +2682,59 @@ impl<'a> TypeChecker<'a> {
             return Ok(None);
         };
 
+        if udf.returns_table {
+            return Err(ErrorCode::SemanticError(format!(
+                "`{}` is a table function, it can only be called in the FROM clause",
+                func_name
+            ))
+            .set_span(span));
+        }
+
+        if let Some(wasm) = udf.wasm {
+            let (code, code_hash) = read_wasm_module(&self.ctx, &udf.definition).await?;
+            if code_hash != wasm.code_hash {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The WASM module '{}' of `{}` was changed since the function was created",
+                    udf.definition, func_name
+                ))
+                .set_span(span));
+            }
+            let udf_type = UDFType::Wasm {
+                code_hash,
+                code: Arc::new(hex::encode(code)),
+            };
+            return Ok(Some(
+                self.resolve_external_udf(
+                    span,
+                    func_name,
+                    arguments,
+                    &wasm.arg_types,
+                    &wasm.return_type,
+                    wasm.handler,
+                    udf_type,
+                )
+                .await?,
+            ));
+        }
+        if let Some(server) = udf.server {
+            let udf_type = UDFType::Server {
+                address: server.address,
+                returns_null_on_null_input: server.returns_null_on_null_input,
+            };
+            return Ok(Some(
+                self.resolve_external_udf(
+                    span,
+                    func_name,
+                    arguments,
+                    &server.arg_types,
+                    &server.return_type,
+                    server.handler,
+                    udf_type,
+                )
+                .await?,
+            ));
+        }
+
         let parameters = udf.parameters;
         if parameters.len() != arguments.len() {
             return Err(ErrorCode::SyntaxException(format!(
@@ -2623,6 +2768,136 @@ impl<'a> TypeChecker<'a> {
         Ok(Some(self.resolve(&udf_expr).await?))
     }
 
+    /// Resolves a call of a WASM or UDF server function, it's evaluated by the `Udf` operator
+    /// the call is rewritten into, see `UdfRewriter`.
+    #[allow(clippy::too_many_arguments)]
+    #[async_recursion::async_recursion]
+    #[async_backtrace::framed]
+    async fn resolve_external_udf(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        arguments: &[Expr],
+        arg_types: &[DataType],
+        return_type: &DataType,
+        handler: String,
+        udf_type: UDFType,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if !matches!(self.bind_context.expr_context, ExprContext::SelectClause) {
+            return Err(ErrorCode::SemanticError(format!(
+                "`{}` is a WASM or UDF server function, it can only be used in SELECT",
+                func_name
+            ))
+            .set_span(span));
+        }
+        if arg_types.len() != arguments.len() {
+            return Err(ErrorCode::SyntaxException(format!(
+                "Require {} parameters, but got: {}",
+                arg_types.len(),
+                arguments.len()
+            ))
+            .set_span(span));
+        }
+
+        let mut args = Vec::with_capacity(arguments.len());
+        let mut has_nullable_arg = false;
+        for (argument, arg_type) in arguments.iter().zip(arg_types.iter()) {
+            let box (arg, data_type) = self.resolve(argument).await?;
+            // NULLs are passed to the function, which must handle them.
+            let arg_type = if data_type.is_nullable_or_null() {
+                has_nullable_arg = true;
+                arg_type.wrap_nullable()
+            } else {
+                arg_type.clone()
+            };
+            if data_type == arg_type {
+                args.push(arg);
+            } else {
+                args.push(wrap_cast(&arg, &arg_type));
+            }
+        }
+        let display_name = format!(
+            "{}({})",
+            func_name,
+            arguments
+                .iter()
+                .map(|arg| format!("{:#}", arg))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+
+        let return_type = match udf_type {
+            UDFType::Server {
+                returns_null_on_null_input: true,
+                ..
+            } if has_nullable_arg => return_type.wrap_nullable(),
+            _ => return_type.clone(),
+        };
+        Ok(Box::new((
+            UDFCall {
+                span,
+                func_name: func_name.to_string(),
+                display_name,
+                arguments: args,
+                return_type: Box::new(return_type.clone()),
+                handler,
+                udf_type,
+            }
+            .into(),
+            return_type,
+        )))
+    }
+
+    /// Resolves a call of `nextval`, which is evaluated by the `Udf` operator the call is
+    /// rewritten into, like the WASM and UDF server functions.
+    #[async_backtrace::framed]
+    async fn resolve_async_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        arguments: &[&Expr],
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if !matches!(self.bind_context.expr_context, ExprContext::SelectClause) {
+            return Err(ErrorCode::SemanticError(format!(
+                "`{}` can only be used in SELECT",
+                func_name
+            ))
+            .set_span(span));
+        }
+        let sequence_name = match arguments {
+            [Expr::Literal {
+                lit: Literal::String(name),
+                ..
+            }] => name,
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "`{}` expects a sequence name as a string literal",
+                    func_name
+                ))
+                .set_span(span));
+            }
+        };
+        let sequence = UserApiProvider::instance()
+            .get_sequence(self.ctx.get_tenant().as_str(), sequence_name)
+            .await
+            .map_err(|e| e.set_span(span))?;
+
+        let return_type = DataType::Number(NumberDataType::Int64);
+        Ok(Box::new((
+            UDFCall {
+                span,
+                func_name: func_name.to_string(),
+                display_name: format!("{}('{}')", func_name, sequence_name),
+                arguments: vec![],
+                return_type: Box::new(return_type.clone()),
+                handler: sequence_name.to_string(),
+                udf_type: UDFType::Sequence(sequence),
+            }
+            .into(),
+            return_type,
+        )))
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     async fn resolve_map_access(
@@ -0,0 +1,76 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_ast::ast::ColumnID;
+use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
+use common_ast::ast::TypeName;
+use common_ast::walk_expr_mut;
+use common_ast::VisitorMut;
+
+/// Replaces the references to the parameters of a SQL UDF in its definition, e.g. with the
+/// arguments of a call. Parameters shadow the columns of the same name.
+pub struct UDFParameterRewriter {
+    args: HashMap<String, Expr>,
+}
+
+impl UDFParameterRewriter {
+    pub fn new(args: HashMap<String, Expr>) -> Self {
+        Self { args }
+    }
+
+    /// Casts the references to the parameters to their declared types.
+    pub fn with_casts(parameters: &[Identifier], arg_types: &[TypeName]) -> Self {
+        let args = parameters
+            .iter()
+            .zip(arg_types)
+            .map(|(parameter, arg_type)| {
+                let column = Expr::ColumnRef {
+                    span: parameter.span,
+                    database: None,
+                    table: None,
+                    column: ColumnID::Name(parameter.clone()),
+                };
+                let cast = Expr::Cast {
+                    span: parameter.span,
+                    expr: Box::new(column),
+                    target_type: arg_type.clone(),
+                    pg_style: false,
+                };
+                (parameter.name.clone(), cast)
+            })
+            .collect();
+        Self { args }
+    }
+}
+
+impl VisitorMut for UDFParameterRewriter {
+    fn visit_expr(&mut self, expr: &mut Expr) {
+        if let Expr::ColumnRef {
+            database: None,
+            table: None,
+            column: ColumnID::Name(column),
+            ..
+        } = expr
+        {
+            if let Some(arg) = self.args.get(&column.name) {
+                *expr = arg.clone();
+                return;
+            }
+        }
+        walk_expr_mut(self, expr);
+    }
+}
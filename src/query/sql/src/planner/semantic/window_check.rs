@@ -60,6 +60,19 @@ impl<'a> WindowChecker<'a> {
                 }
                 Err(ErrorCode::Internal("Window Check: Invalid lambda function"))
             }
+            ScalarExpr::UDFCall(udf) => {
+                if let Some(column_ref) = self
+                    .bind_context
+                    .udf_info
+                    .udf_functions_map
+                    .get(&udf.display_name)
+                {
+                    return Ok(column_ref.clone().into());
+                }
+                Err(ErrorCode::Internal(
+                    "Window Check: Invalid WASM or UDF server function",
+                ))
+            }
             ScalarExpr::CastExpr(cast) => Ok(CastExpr {
                 span: cast.span,
                 is_try: cast.is_try,
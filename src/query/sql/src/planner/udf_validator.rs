@@ -19,8 +19,12 @@ use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
 use common_ast::ast::Lambda;
 use common_ast::ast::Literal;
+use common_ast::ast::Query;
+use common_ast::ast::TableReference;
 use common_ast::ast::Window;
 use common_ast::walk_expr;
+use common_ast::walk_query;
+use common_ast::walk_table_reference;
 use common_ast::Visitor;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -69,9 +73,30 @@ impl UDFValidator {
             },
         )))
     }
+
+    /// Checks the query of a table UDF. Unlike an expression, the query may refer to the
+    /// columns of the tables it reads, so the parameters are not checked to be used.
+    pub fn verify_definition_query(&mut self, definition_query: &Query) -> Result<()> {
+        walk_query(self, definition_query);
+
+        if self.has_recursive {
+            return Err(ErrorCode::SyntaxException("Recursive UDF is not supported"));
+        }
+        Ok(())
+    }
 }
 
 impl<'ast> Visitor<'ast> for UDFValidator {
+    fn visit_table_reference(&mut self, table_ref: &'ast TableReference) {
+        if let TableReference::TableFunction { name, .. } = table_ref {
+            if self.name.eq_ignore_ascii_case(&name.to_string()) {
+                self.has_recursive = true;
+                return;
+            }
+        }
+        walk_table_reference(self, table_ref);
+    }
+
     fn visit_column_ref(
         &mut self,
         _span: Span,
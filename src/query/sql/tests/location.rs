@@ -185,6 +185,10 @@ fn test_parse_uri_location() -> Result<()> {
                     role_arn: "".to_string(),
                     external_id: "".to_string(),
                     allow_anonymous: false,
+                    secrets_provider: "".to_string(),
+                    server_side_encryption: "".to_string(),
+                    server_side_encryption_aws_kms_key_id: "".to_string(),
+                    server_side_encryption_customer_key: "".to_string(),
                 }),
                 "/".to_string(),
             ),
@@ -220,6 +224,10 @@ fn test_parse_uri_location() -> Result<()> {
                     role_arn: "".to_string(),
                     external_id: "".to_string(),
                     allow_anonymous: false,
+                    secrets_provider: "".to_string(),
+                    server_side_encryption: "".to_string(),
+                    server_side_encryption_aws_kms_key_id: "".to_string(),
+                    server_side_encryption_customer_key: "".to_string(),
                 }),
                 "/".to_string(),
             ),
@@ -255,6 +263,10 @@ fn test_parse_uri_location() -> Result<()> {
                     role_arn: "".to_string(),
                     external_id: "".to_string(),
                     allow_anonymous: false,
+                    secrets_provider: "".to_string(),
+                    server_side_encryption: "".to_string(),
+                    server_side_encryption_aws_kms_key_id: "".to_string(),
+                    server_side_encryption_customer_key: "".to_string(),
                 }),
                 "/".to_string(),
             ),
@@ -286,6 +298,48 @@ fn test_parse_uri_location() -> Result<()> {
                     role_arn: "aws::iam::xxxx".to_string(),
                     external_id: "".to_string(),
                     allow_anonymous: false,
+                    secrets_provider: "".to_string(),
+                    server_side_encryption: "".to_string(),
+                    server_side_encryption_aws_kms_key_id: "".to_string(),
+                    server_side_encryption_customer_key: "".to_string(),
+                }),
+                "/".to_string(),
+            ),
+        ),
+        (
+            "s3_with_server_side_encryption",
+            UriLocation::new(
+                "s3".to_string(),
+                "test".to_string(),
+                "/tmp/".to_string(),
+                "".to_string(),
+                vec![
+                    ("server_side_encryption", "aws:kms"),
+                    ("server_side_encryption_aws_kms_key_id", "key_id"),
+                ]
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<BTreeMap<String, String>>(),
+            ),
+            (
+                StorageParams::S3(StorageS3Config {
+                    endpoint_url: STORAGE_S3_DEFAULT_ENDPOINT.to_string(),
+                    region: "".to_string(),
+                    bucket: "test".to_string(),
+                    access_key_id: "".to_string(),
+                    secret_access_key: "".to_string(),
+                    security_token: "".to_string(),
+                    master_key: "".to_string(),
+                    root: "/tmp/".to_string(),
+                    disable_credential_loader: true,
+                    enable_virtual_host_style: false,
+                    role_arn: "".to_string(),
+                    external_id: "".to_string(),
+                    allow_anonymous: false,
+                    secrets_provider: "".to_string(),
+                    server_side_encryption: "aws:kms".to_string(),
+                    server_side_encryption_aws_kms_key_id: "key_id".to_string(),
+                    server_side_encryption_customer_key: "".to_string(),
                 }),
                 "/".to_string(),
             ),
@@ -419,5 +473,25 @@ fn test_parse_uri_location() -> Result<()> {
         assert_eq!(expected, actual, "{}", name);
     }
 
+    // The secrets providers read the secrets of the node, they are not allowed unless
+    // `allow_insecure` is enabled.
+    for provider in [
+        "env",
+        "file:///tmp/credentials.json",
+        "vault://aws/creds/reader",
+    ] {
+        let mut input = UriLocation::new(
+            "s3".to_string(),
+            "test".to_string(),
+            "/tmp/".to_string(),
+            "".to_string(),
+            vec![("secrets_provider", provider)]
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<BTreeMap<String, String>>(),
+        );
+        assert!(parse_uri_location(&mut input).is_err(), "{}", provider);
+    }
+
     Ok(())
 }
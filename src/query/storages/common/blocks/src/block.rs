@@ -17,6 +17,8 @@ use common_arrow::arrow::datatypes::DataType as ArrowDataType;
 use common_arrow::arrow::io::parquet::write::transverse;
 use common_arrow::arrow::io::parquet::write::RowGroupIterator;
 use common_arrow::arrow::io::parquet::write::WriteOptions;
+use common_arrow::parquet::compression::CompressionOptions;
+use common_arrow::parquet::compression::ZstdLevel;
 use common_arrow::parquet::encoding::Encoding;
 use common_arrow::parquet::metadata::ThriftFileMetaData;
 use common_arrow::parquet::write::Version;
@@ -25,40 +27,123 @@ use common_config::DATABEND_COMMIT_VERSION;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
+use common_expression::FieldIndex;
 use common_expression::TableSchema;
 use storages_common_table_meta::table::TableCompression;
 
+use crate::bloom_filter::append_bloom_filters;
+use crate::bloom_filter::ColumnBloomFilter;
+
+/// Options of the parquet writer.
+#[derive(Clone, Debug, Default)]
+pub struct ParquetWriteOptions {
+    pub compression: TableCompression,
+    /// Compression level, only work with zstd.
+    pub compression_level: Option<i32>,
+    /// Use dictionary encoding for the columns, the writer falls back to plain encoding
+    /// if the dictionary is not efficient.
+    pub enable_dictionary: bool,
+    /// Max size in bytes of the data pages.
+    pub data_page_size: Option<usize>,
+    /// Fields to write bloom filters for, only the fields of
+    /// `parquet_bloom_filter_supported_type` are supported.
+    pub bloom_filter_fields: Vec<FieldIndex>,
+    /// Fields always written with dictionary encoding, whatever `enable_dictionary` is.
+    pub dictionary_fields: Vec<FieldIndex>,
+}
+
+impl ParquetWriteOptions {
+    pub fn with_compression(compression: TableCompression) -> Self {
+        Self {
+            compression,
+            ..Default::default()
+        }
+    }
+
+    fn compression_options(&self) -> Result<CompressionOptions> {
+        match (self.compression, self.compression_level) {
+            (TableCompression::Zstd, Some(level)) => {
+                let level = ZstdLevel::try_new(level)
+                    .map_err(|e| ErrorCode::TableOptionInvalid(e.to_string()))?;
+                Ok(CompressionOptions::Zstd(Some(level)))
+            }
+            (compression, _) => Ok(compression.into()),
+        }
+    }
+}
+
 /// Serialize data blocks to parquet format.
 pub fn blocks_to_parquet(
     schema: impl AsRef<TableSchema>,
     blocks: Vec<DataBlock>,
     write_buffer: &mut Vec<u8>,
     compression: TableCompression,
+) -> Result<(u64, ThriftFileMetaData)> {
+    blocks_to_parquet_with_options(
+        schema,
+        blocks,
+        write_buffer,
+        &ParquetWriteOptions::with_compression(compression),
+    )
+}
+
+/// Serialize data blocks to parquet format with the options.
+///
+/// Each block is written as a row group.
+pub fn blocks_to_parquet_with_options(
+    schema: impl AsRef<TableSchema>,
+    blocks: Vec<DataBlock>,
+    write_buffer: &mut Vec<u8>,
+    options: &ParquetWriteOptions,
 ) -> Result<(u64, ThriftFileMetaData)> {
     let arrow_schema = schema.as_ref().to_arrow();
 
     let row_group_write_options = WriteOptions {
         write_statistics: false,
         version: Version::V2,
-        compression: compression.into(),
-        data_pagesize_limit: None,
+        compression: options.compression_options()?,
+        data_pagesize_limit: options.data_page_size,
     };
     let batches = blocks
         .into_iter()
         .map(Chunk::try_from)
         .collect::<Result<Vec<_>>>()?;
 
+    let enable_dictionary = options.enable_dictionary;
     let encoding_map = |data_type: &ArrowDataType| match data_type {
         ArrowDataType::Dictionary(..) => Encoding::RleDictionary,
+        _ if enable_dictionary => Encoding::RleDictionary,
         _ => col_encoding(data_type),
     };
 
     let encodings: Vec<Vec<_>> = arrow_schema
         .fields
         .iter()
-        .map(|f| transverse(&f.data_type, encoding_map))
+        .enumerate()
+        .map(|(idx, f)| {
+            if options.dictionary_fields.contains(&idx) {
+                transverse(&f.data_type, |_| Encoding::RleDictionary)
+            } else {
+                transverse(&f.data_type, encoding_map)
+            }
+        })
         .collect::<Vec<_>>();
 
+    // Bloom filters are built before the batches are consumed by the writer.
+    let mut bloom_filters = vec![];
+    for field_index in options.bloom_filter_fields.iter() {
+        let leaf_index = encodings[..*field_index]
+            .iter()
+            .map(|leaves| leaves.len())
+            .sum::<usize>();
+        for (row_group, batch) in batches.iter().enumerate() {
+            let array = batch.arrays()[*field_index].as_ref();
+            if let Some(filter) = ColumnBloomFilter::try_create(row_group, leaf_index, array)? {
+                bloom_filters.push(filter);
+            }
+        }
+    }
+
     let row_groups = RowGroupIterator::try_new(
         batches.into_iter().map(Ok),
         &arrow_schema,
@@ -67,27 +152,36 @@ pub fn blocks_to_parquet(
     )?;
 
     use common_arrow::parquet::write::WriteOptions as FileWriteOption;
-    let options = FileWriteOption {
+    let file_write_options = FileWriteOption {
         write_statistics: false,
         version: Version::V2,
     };
 
-    match write_parquet_file(
+    let start = write_buffer.len();
+    let (size, mut meta) = match write_parquet_file(
         write_buffer,
         row_groups,
         arrow_schema,
-        options,
+        file_write_options,
         Some(format!(
             "DatabendQuery {} with Arrow2",
             *DATABEND_COMMIT_VERSION
         )),
     ) {
-        Ok(result) => Ok(result),
-        Err(cause) => Err(ErrorCode::Internal(format!(
-            "write_parquet_file fail: {:?}",
-            cause,
-        ))),
+        Ok(result) => result,
+        Err(cause) => {
+            return Err(ErrorCode::Internal(format!(
+                "write_parquet_file fail: {:?}",
+                cause,
+            )));
+        }
+    };
+
+    if bloom_filters.is_empty() {
+        return Ok((size, meta));
     }
+    let size = append_bloom_filters(write_buffer, start, &mut meta, bloom_filters)?;
+    Ok((size, meta))
 }
 
 fn col_encoding(_data_type: &ArrowDataType) -> Encoding {
@@ -0,0 +1,198 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::BinaryArray;
+use common_arrow::arrow::array::PrimitiveArray;
+use common_arrow::arrow::array::Utf8Array;
+use common_arrow::arrow::datatypes::PhysicalType;
+use common_arrow::arrow::datatypes::PrimitiveType;
+use common_arrow::parquet::bloom_filter::hash_byte;
+use common_arrow::parquet::bloom_filter::hash_native;
+use common_arrow::parquet::bloom_filter::insert;
+use common_arrow::parquet::metadata::ThriftFileMetaData;
+use common_arrow::parquet::thrift_format::thrift::protocol::TCompactOutputProtocol;
+use common_arrow::parquet::thrift_format::BloomFilterAlgorithm;
+use common_arrow::parquet::thrift_format::BloomFilterCompression;
+use common_arrow::parquet::thrift_format::BloomFilterHash;
+use common_arrow::parquet::thrift_format::BloomFilterHeader;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableDataType;
+
+const PARQUET_MAGIC: &[u8] = b"PAR1";
+// The false positive probability used to size the filters.
+const BLOOM_FILTER_FPP: f64 = 0.05;
+const BLOOM_FILTER_MIN_BYTES: usize = 32;
+const BLOOM_FILTER_MAX_BYTES: usize = 128 * 1024 * 1024;
+
+/// Column types whose parquet bloom filters can be written.
+///
+/// Only the types stored as a single primitive or byte array parquet column are supported.
+pub fn parquet_bloom_filter_supported_type(data_type: &TableDataType) -> bool {
+    matches!(
+        data_type.remove_nullable(),
+        TableDataType::Number(_)
+            | TableDataType::String
+            | TableDataType::Timestamp
+            | TableDataType::Date
+    )
+}
+
+/// The split block bloom filter of a column chunk, in the format of the parquet specification.
+pub(crate) struct ColumnBloomFilter {
+    pub(crate) row_group: usize,
+    pub(crate) leaf_index: usize,
+    pub(crate) bitset: Vec<u8>,
+}
+
+impl ColumnBloomFilter {
+    /// Build the filter from the non-null values of the array, values are hashed in the
+    /// physical type of the parquet column.
+    pub(crate) fn try_create(
+        row_group: usize,
+        leaf_index: usize,
+        array: &dyn Array,
+    ) -> Result<Option<Self>> {
+        let hashes = match hash_values(array) {
+            Some(hashes) => hashes,
+            None => return Ok(None),
+        };
+        if hashes.is_empty() {
+            return Ok(None);
+        }
+
+        let mut bitset = vec![0; optimal_num_of_bytes(hashes.len())];
+        for hash in hashes {
+            insert(&mut bitset, hash);
+        }
+        Ok(Some(Self {
+            row_group,
+            leaf_index,
+            bitset,
+        }))
+    }
+}
+
+fn optimal_num_of_bytes(ndv: usize) -> usize {
+    let num_bits = -8.0 * ndv as f64 / (1.0 - BLOOM_FILTER_FPP.powf(1.0 / 8.0)).ln();
+    let num_bytes = (num_bits / 8.0) as usize;
+    num_bytes
+        .clamp(BLOOM_FILTER_MIN_BYTES, BLOOM_FILTER_MAX_BYTES)
+        .next_power_of_two()
+}
+
+macro_rules! hash_primitive {
+    ($array:expr, $native:ty, $physical:ty) => {{
+        let array = $array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<$native>>()
+            .unwrap();
+        array
+            .iter()
+            .flatten()
+            .map(|v| hash_native(*v as $physical))
+            .collect()
+    }};
+}
+
+macro_rules! hash_bytes {
+    ($array:expr, $ty:ty) => {{
+        let array = $array.as_any().downcast_ref::<$ty>().unwrap();
+        array.iter().flatten().map(hash_byte).collect()
+    }};
+}
+
+fn hash_values(array: &dyn Array) -> Option<HashSet<u64>> {
+    let hashes = match array.data_type().to_physical_type() {
+        PhysicalType::Primitive(primitive) => match primitive {
+            PrimitiveType::Int8 => hash_primitive!(array, i8, i32),
+            PrimitiveType::Int16 => hash_primitive!(array, i16, i32),
+            PrimitiveType::Int32 => hash_primitive!(array, i32, i32),
+            PrimitiveType::Int64 => hash_primitive!(array, i64, i64),
+            PrimitiveType::UInt8 => hash_primitive!(array, u8, i32),
+            PrimitiveType::UInt16 => hash_primitive!(array, u16, i32),
+            PrimitiveType::UInt32 => hash_primitive!(array, u32, i32),
+            PrimitiveType::UInt64 => hash_primitive!(array, u64, i64),
+            PrimitiveType::Float32 => hash_primitive!(array, f32, f32),
+            PrimitiveType::Float64 => hash_primitive!(array, f64, f64),
+            _ => return None,
+        },
+        PhysicalType::Binary => hash_bytes!(array, BinaryArray<i32>),
+        PhysicalType::LargeBinary => hash_bytes!(array, BinaryArray<i64>),
+        PhysicalType::Utf8 => hash_bytes!(array, Utf8Array<i32>),
+        PhysicalType::LargeUtf8 => hash_bytes!(array, Utf8Array<i64>),
+        _ => return None,
+    };
+    Some(hashes)
+}
+
+/// Append the bloom filters to the parquet file written in `buf` from `start`, and rewrite
+/// the footer to reference them by the `bloom_filter_offset` of the column chunks.
+///
+/// Returns the new size of the file.
+pub(crate) fn append_bloom_filters(
+    buf: &mut Vec<u8>,
+    start: usize,
+    meta: &mut ThriftFileMetaData,
+    filters: Vec<ColumnBloomFilter>,
+) -> Result<u64> {
+    let file_len = buf.len() - start;
+    if file_len < 8 || &buf[buf.len() - 4..] != PARQUET_MAGIC {
+        return Err(ErrorCode::Internal("invalid parquet file footer"));
+    }
+    let metadata_len =
+        u32::from_le_bytes(buf[buf.len() - 8..buf.len() - 4].try_into().unwrap()) as usize;
+    buf.truncate(buf.len() - 8 - metadata_len);
+
+    for filter in filters {
+        let column = meta
+            .row_groups
+            .get_mut(filter.row_group)
+            .and_then(|row_group| row_group.columns.get_mut(filter.leaf_index))
+            .and_then(|column| column.meta_data.as_mut());
+        let column = match column {
+            Some(column) => column,
+            None => continue,
+        };
+
+        let header = BloomFilterHeader {
+            num_bytes: filter.bitset.len() as i32,
+            algorithm: BloomFilterAlgorithm::BLOCK(Default::default()),
+            hash: BloomFilterHash::XXHASH(Default::default()),
+            compression: BloomFilterCompression::UNCOMPRESSED(Default::default()),
+        };
+        column.bloom_filter_offset = Some((buf.len() - start) as i64);
+        let mut protocol = TCompactOutputProtocol::new(&mut *buf);
+        header
+            .write_to_out_protocol(&mut protocol)
+            .map_err(thrift_error)?;
+        buf.extend_from_slice(&filter.bitset);
+    }
+
+    let metadata_start = buf.len();
+    let mut protocol = TCompactOutputProtocol::new(&mut *buf);
+    meta.write_to_out_protocol(&mut protocol)
+        .map_err(thrift_error)?;
+    let metadata_len = (buf.len() - metadata_start) as u32;
+    buf.extend_from_slice(&metadata_len.to_le_bytes());
+    buf.extend_from_slice(PARQUET_MAGIC);
+    Ok((buf.len() - start) as u64)
+}
+
+fn thrift_error<E: std::fmt::Display>(e: E) -> ErrorCode {
+    ErrorCode::Internal(format!("write parquet bloom filter fail: {}", e))
+}
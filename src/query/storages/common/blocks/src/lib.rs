@@ -15,5 +15,9 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod block;
+mod bloom_filter;
 
 pub use block::blocks_to_parquet;
+pub use block::blocks_to_parquet_with_options;
+pub use block::ParquetWriteOptions;
+pub use bloom_filter::parquet_bloom_filter_supported_type;
@@ -32,12 +32,14 @@ use crate::caches::BloomIndexFilterCache;
 use crate::caches::BloomIndexMetaCache;
 use crate::caches::ColumnArrayCache;
 use crate::caches::CompactSegmentInfoCache;
+use crate::caches::DataBlocksCache;
 use crate::caches::FileMetaDataCache;
 use crate::caches::TableSnapshotCache;
 use crate::caches::TableSnapshotStatisticCache;
 use crate::BloomIndexFilterMeter;
 use crate::ColumnArrayMeter;
 use crate::CompactSegmentInfoMeter;
+use crate::DataBlocksMeter;
 use crate::PrunePartitionsCache;
 
 static DEFAULT_FILE_META_DATA_CACHE_ITEMS: u64 = 3000;
@@ -53,6 +55,7 @@ pub struct CacheManager {
     file_meta_data_cache: Option<FileMetaDataCache>,
     table_data_cache: Option<TableDataCache>,
     table_column_array_cache: Option<ColumnArrayCache>,
+    table_data_block_cache: Option<DataBlocksCache>,
 }
 
 impl CacheManager {
@@ -82,6 +85,13 @@ impl CacheManager {
             "table_data_column_array",
         );
 
+        // setup in-memory table data block cache
+        let table_data_block_cache = Self::new_in_memory_cache(
+            config.table_data_block_bytes,
+            DataBlocksMeter,
+            "table_data_block",
+        );
+
         // setup in-memory table meta cache
         if !config.enable_table_meta_cache {
             GlobalInstance::set(Arc::new(Self {
@@ -94,6 +104,7 @@ impl CacheManager {
                 table_statistic_cache: None,
                 table_data_cache,
                 table_column_array_cache,
+                table_data_block_cache,
             }));
         } else {
             let table_snapshot_cache =
@@ -129,6 +140,7 @@ impl CacheManager {
                 table_statistic_cache,
                 table_data_cache,
                 table_column_array_cache,
+                table_data_block_cache,
             }));
         }
 
@@ -175,6 +187,10 @@ impl CacheManager {
         self.table_column_array_cache.clone()
     }
 
+    pub fn get_table_data_block_cache(&self) -> Option<DataBlocksCache> {
+        self.table_data_block_cache.clone()
+    }
+
     // create cache that meters size by `Count`
     fn new_item_cache<V>(
         capacity: u64,
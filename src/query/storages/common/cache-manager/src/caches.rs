@@ -23,6 +23,7 @@ use common_cache::DefaultHashBuilder;
 use common_cache::Meter;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
+use common_expression::DataBlock;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache::InMemoryItemCacheHolder;
 use storages_common_cache::NamedCache;
@@ -64,6 +65,10 @@ pub type SizedColumnArray = (
     ArrayRawDataUncompressedSize,
 );
 
+/// In memory object cache of the decoded data blocks of a table snapshot
+pub type DataBlocksCache =
+    NamedCache<InMemoryItemCacheHolder<Vec<DataBlock>, DefaultHashBuilder, DataBlocksMeter>>;
+
 // Bind Type of cached objects to Caches
 //
 // The `Cache` should return
@@ -146,6 +151,16 @@ impl<K, V> Meter<K, Arc<(V, usize)>> for ColumnArrayMeter {
     }
 }
 
+pub struct DataBlocksMeter;
+
+impl Meter<String, Arc<Vec<DataBlock>>> for DataBlocksMeter {
+    type Measure = usize;
+
+    fn measure<Q: ?Sized>(&self, _: &Q, value: &Arc<Vec<DataBlock>>) -> Self::Measure {
+        value.iter().map(|block| block.memory_size()).sum()
+    }
+}
+
 pub struct CompactSegmentInfoMeter;
 
 impl Meter<String, Arc<CompactSegmentInfo>> for CompactSegmentInfoMeter {
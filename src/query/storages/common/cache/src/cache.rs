@@ -24,6 +24,16 @@ use crate::metrics_inc_cache_access_count;
 use crate::metrics_inc_cache_hit_count;
 use crate::metrics_inc_cache_miss_count;
 
+/// Priority of the cached items, used by caches that keep items of different value apart,
+/// e.g. the table data cache.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CachePriority {
+    /// Large items that are cheap to reload compared to their size, like the column data of blocks.
+    Low,
+    /// Small items of high value, like bloom filters, which are kept in preference to the low ones.
+    High,
+}
+
 // The cache accessor, crate users usually working on this interface while manipulating caches
 pub trait CacheAccessor<K, V, S = DefaultHashBuilder, M = Count>
 where
@@ -33,6 +43,13 @@ where
 {
     fn get<Q: AsRef<str>>(&self, k: Q) -> Option<Arc<V>>;
     fn put(&self, key: K, value: Arc<V>);
+    // Caches that are not aware of the priority treat all the items the same.
+    fn get_with_priority<Q: AsRef<str>>(&self, k: Q, _priority: CachePriority) -> Option<Arc<V>> {
+        self.get(k)
+    }
+    fn put_with_priority(&self, key: K, value: Arc<V>, _priority: CachePriority) {
+        self.put(key, value)
+    }
     fn evict(&self, k: &str) -> bool;
     fn contains_key(&self, k: &str) -> bool;
     fn size(&self) -> u64;
@@ -95,6 +112,10 @@ where
         self.cache.put(key, value)
     }
 
+    fn put_with_priority(&self, key: K, value: Arc<V>, priority: CachePriority) {
+        self.cache.put_with_priority(key, value, priority)
+    }
+
     fn evict(&self, k: &str) -> bool {
         self.cache.evict(k)
     }
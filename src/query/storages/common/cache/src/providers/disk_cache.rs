@@ -36,9 +36,21 @@ use siphasher::sip128;
 use siphasher::sip128::Hasher128;
 
 use crate::CacheAccessor;
+use crate::CachePriority;
 
+/// Share of the capacity that the high priority items may take at most, so that they
+/// can not squeeze the low priority items out of the cache entirely.
+const HIGH_PRIORITY_CAPACITY_RATIO: f64 = 0.5;
+
+/// A segmented disk cache, items of low and high priority are kept in separate segments.
+///
+/// While the cache is full, the low priority items are evicted first, the high priority
+/// items are evicted only if there are no low priority items left, or the high priority
+/// segment exceeds its own capacity.
 pub struct DiskCache<C> {
     cache: C,
+    high_priority_cache: C,
+    high_priority_capacity: u64,
     root: PathBuf,
 }
 
@@ -83,6 +95,12 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize>
     where PathBuf: From<T> {
         DiskCache {
             cache: C::with_meter_and_hasher(size, FileSize, DefaultHashBuilder::default()),
+            high_priority_cache: C::with_meter_and_hasher(
+                size,
+                FileSize,
+                DefaultHashBuilder::default(),
+            ),
+            high_priority_capacity: (size as f64 * HIGH_PRIORITY_CAPACITY_RATIO) as u64,
             root: PathBuf::from(path),
         }
         .init()
@@ -94,16 +112,26 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize>
 {
     /// Return the current size of all the files in the cache.
     pub fn size(&self) -> u64 {
-        self.cache.size()
+        self.cache.size() + self.high_priority_cache.size()
     }
 
     /// Return the count of entries in the cache.
     pub fn len(&self) -> usize {
-        self.cache.len()
+        self.cache.len() + self.high_priority_cache.len()
     }
 
     pub fn is_empty(&self) -> bool {
-        self.cache.len() == 0
+        self.len() == 0
+    }
+
+    /// Return the current size of the files of the given priority.
+    pub fn size_of(&self, priority: CachePriority) -> u64 {
+        self.segment(priority).size()
+    }
+
+    /// Return the count of entries of the given priority.
+    pub fn len_of(&self, priority: CachePriority) -> usize {
+        self.segment(priority).len()
     }
 
     /// Return the maximum size of the cache.
@@ -140,33 +168,81 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize>
         DiskCacheKey::from(key)
     }
 
+    fn segment(&self, priority: CachePriority) -> &C {
+        match priority {
+            CachePriority::Low => &self.cache,
+            CachePriority::High => &self.high_priority_cache,
+        }
+    }
+
+    fn segment_mut(&mut self, priority: CachePriority) -> &mut C {
+        match priority {
+            CachePriority::Low => &mut self.cache,
+            CachePriority::High => &mut self.high_priority_cache,
+        }
+    }
+
+    /// Evict the least recently used item of the given priority, returns false if there is none.
+    fn evict_one(&mut self, priority: CachePriority) -> bool {
+        match self.segment_mut(priority).pop_by_policy() {
+            Some((rel_path, _)) => {
+                let cached_item_path = self.abs_path_of_cache_key(&DiskCacheKey(rel_path));
+                fs::remove_file(&cached_item_path).unwrap_or_else(|e| {
+                    error!(
+                        "Error removing file from cache: `{:?}`: {}",
+                        cached_item_path, e
+                    )
+                });
+                true
+            }
+            None => false,
+        }
+    }
+
     fn abs_path_of_cache_key(&self, cache_key: &DiskCacheKey) -> PathBuf {
         let path = PathBuf::from(cache_key);
         self.rel_to_abs_path(path)
     }
 
     pub fn insert_bytes(&mut self, key: &str, bytes: &[&[u8]]) -> self::result::Result<()> {
+        self.insert_bytes_with_priority(key, bytes, CachePriority::Low)
+    }
+
+    pub fn insert_bytes_with_priority(
+        &mut self,
+        key: &str,
+        bytes: &[&[u8]],
+        priority: CachePriority,
+    ) -> self::result::Result<()> {
         let bytes_len = bytes.iter().map(|x| x.len() as u64).sum::<u64>();
         // check if this chunk of bytes itself is too large
         if !self.can_store(bytes_len) {
             return Err(Error::FileTooLarge);
         }
+        // items too large for the high priority segment are kept as low priority ones
+        let priority = match priority {
+            CachePriority::High if bytes_len > self.high_priority_capacity => CachePriority::Low,
+            priority => priority,
+        };
+
+        // the file of the key (if any) is overwritten, drop it from both segments
+        let cache_key = self.cache_key(key.as_ref());
+        self.cache.pop(&cache_key.0);
+        self.high_priority_cache.pop(&cache_key.0);
 
         // check eviction
-        while self.cache.size() + bytes_len > self.cache.capacity() {
-            if let Some((rel_path, _)) = self.cache.pop_by_policy() {
-                let cached_item_path = self.abs_path_of_cache_key(&DiskCacheKey(rel_path));
-                fs::remove_file(&cached_item_path).unwrap_or_else(|e| {
-                    error!(
-                        "Error removing file from cache: `{:?}`: {}",
-                        cached_item_path, e
-                    )
-                });
+        if priority == CachePriority::High {
+            while self.high_priority_cache.size() + bytes_len > self.high_priority_capacity {
+                self.evict_one(CachePriority::High);
+            }
+        }
+        while self.size() + bytes_len > self.capacity() {
+            if !self.evict_one(CachePriority::Low) {
+                self.evict_one(CachePriority::High);
             }
         }
-        debug_assert!(self.cache.size() <= self.cache.capacity());
+        debug_assert!(self.size() <= self.capacity());
 
-        let cache_key = self.cache_key(key.as_ref());
         let path = self.abs_path_of_cache_key(&cache_key);
         if let Some(parent_path) = path.parent() {
             fs::create_dir_all(parent_path)?;
@@ -177,20 +253,21 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize>
             bufs.push(IoSlice::new(slick));
         }
         f.write_all_vectored(&mut bufs)?;
-        self.cache.put(cache_key.0, bytes_len);
+        self.segment_mut(priority).put(cache_key.0, bytes_len);
         Ok(())
     }
 
     /// Return `true` if a file with path `key` is in the cache.
     pub fn contains_key(&self, key: &str) -> bool {
         let cache_key = self.cache_key(key);
-        self.cache.contains(&cache_key.0)
+        self.cache.contains(&cache_key.0) || self.high_priority_cache.contains(&cache_key.0)
     }
 
     pub fn get_cache_path(&mut self, key: &str) -> Option<PathBuf> {
         let cache_key = self.cache_key(key);
         self.cache
             .get(&cache_key.0)
+            .or_else(|| self.high_priority_cache.get(&cache_key.0))
             .map(|_| ()) // release the &mut self
             .map(|_| self.abs_path_of_cache_key(&cache_key))
     }
@@ -198,7 +275,11 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize>
     /// Remove the given key from the cache.
     pub fn remove(&mut self, key: &str) -> Result<()> {
         let cache_key = self.cache_key(key);
-        match self.cache.pop(&cache_key.0) {
+        let removed = self
+            .cache
+            .pop(&cache_key.0)
+            .or_else(|| self.high_priority_cache.pop(&cache_key.0));
+        match removed {
             Some(_) => {
                 let path = self.abs_path_of_cache_key(&cache_key);
                 fs::remove_file(&path).map_err(|e| {
@@ -309,10 +390,16 @@ impl CacheAccessor<String, Vec<u8>, common_cache::DefaultHashBuilder, Count>
     }
 
     fn put(&self, key: String, value: Arc<Vec<u8>>) {
+        self.put_with_priority(key, value, CachePriority::Low)
+    }
+
+    fn put_with_priority(&self, key: String, value: Arc<Vec<u8>>, priority: CachePriority) {
         let crc = crc32fast::hash(value.as_slice());
         let crc_bytes = crc.to_le_bytes();
         let mut cache = self.write();
-        if let Err(e) = cache.insert_bytes(&key, &[value.as_slice(), &crc_bytes]) {
+        if let Err(e) =
+            cache.insert_bytes_with_priority(&key, &[value.as_slice(), &crc_bytes], priority)
+        {
             error!("put disk cache item failed {}", e);
         }
     }
@@ -66,6 +66,7 @@ mod impls {
 
     use super::*;
     use crate::cache::CacheAccessor;
+    use crate::cache::CachePriority;
 
     // Wrap a Cache with RwLock, and impl CacheAccessor for it
     impl<V, C, S, M> CacheAccessor<String, V, S, M> for Arc<RwLock<C>>
@@ -122,6 +123,21 @@ mod impls {
             }
         }
 
+        fn get_with_priority<Q: AsRef<str>>(
+            &self,
+            k: Q,
+            priority: CachePriority,
+        ) -> Option<Arc<V>> {
+            self.as_ref()
+                .and_then(|cache| cache.get_with_priority(k, priority))
+        }
+
+        fn put_with_priority(&self, k: String, v: Arc<V>, priority: CachePriority) {
+            if let Some(cache) = self {
+                cache.put_with_priority(k, v, priority);
+            }
+        }
+
         fn evict(&self, k: &str) -> bool {
             if let Some(cache) = self {
                 cache.evict(k)
@@ -31,11 +31,13 @@ use crate::metrics_inc_cache_population_overflow_count;
 use crate::metrics_inc_cache_population_pending_count;
 use crate::providers::LruDiskCacheHolder;
 use crate::CacheAccessor;
+use crate::CachePriority;
 use crate::LruDiskCacheBuilder;
 
 struct CacheItem {
     key: String,
     value: Arc<Vec<u8>>,
+    priority: CachePriority,
 }
 
 #[derive(Clone)]
@@ -71,6 +73,15 @@ pub struct TableDataCache<T = LruDiskCacheHolder> {
 }
 
 const TABLE_DATA_CACHE_NAME: &str = "table_data";
+// name of the metrics of the high priority items, e.g. bloom filters
+const TABLE_INDEX_DATA_CACHE_NAME: &str = "table_index_data";
+
+fn metrics_name_of(priority: CachePriority) -> &'static str {
+    match priority {
+        CachePriority::Low => TABLE_DATA_CACHE_NAME,
+        CachePriority::High => TABLE_INDEX_DATA_CACHE_NAME,
+    }
+}
 
 pub struct TableDataCacheBuilder;
 impl TableDataCacheBuilder {
@@ -92,22 +103,40 @@ impl TableDataCacheBuilder {
 
 impl CacheAccessor<String, Vec<u8>, DefaultHashBuilder, Count> for TableDataCache {
     fn get<Q: AsRef<str>>(&self, k: Q) -> Option<Arc<Vec<u8>>> {
-        metrics_inc_cache_access_count(1, TABLE_DATA_CACHE_NAME);
+        self.get_with_priority(k, CachePriority::Low)
+    }
+
+    fn put(&self, k: String, v: Arc<Vec<u8>>) {
+        self.put_with_priority(k, v, CachePriority::Low)
+    }
+
+    // the hit rates of the items of different priorities are recorded separately
+    fn get_with_priority<Q: AsRef<str>>(
+        &self,
+        k: Q,
+        priority: CachePriority,
+    ) -> Option<Arc<Vec<u8>>> {
+        let metrics_name = metrics_name_of(priority);
+        metrics_inc_cache_access_count(1, metrics_name);
         let k = k.as_ref();
         if let Some(item) = self.external_cache.get(k) {
-            metrics_inc_cache_hit_count(1, TABLE_DATA_CACHE_NAME);
+            metrics_inc_cache_hit_count(1, metrics_name);
             Some(item)
         } else {
-            metrics_inc_cache_miss_count(1, TABLE_DATA_CACHE_NAME);
+            metrics_inc_cache_miss_count(1, metrics_name);
             None
         }
     }
 
-    fn put(&self, k: String, v: Arc<Vec<u8>>) {
+    fn put_with_priority(&self, k: String, v: Arc<Vec<u8>>, priority: CachePriority) {
         // check if external(disk/redis) already have it.
         if !self.external_cache.contains_key(&k) {
             // populate the cache to external cache(disk/redis) asyncly
-            let msg = CacheItem { key: k, value: v };
+            let msg = CacheItem {
+                key: k,
+                value: v,
+                priority,
+            };
             match self.population_queue.try_send(msg) {
                 Ok(_) => {
                     metrics_inc_cache_population_pending_count(1, TABLE_DATA_CACHE_NAME);
@@ -151,13 +180,17 @@ where T: CacheAccessor<String, Vec<u8>, DefaultHashBuilder, Count> + Send + Sync
     fn populate(&self) {
         loop {
             match self.population_queue.recv() {
-                Ok(CacheItem { key, value }) => {
+                Ok(CacheItem {
+                    key,
+                    value,
+                    priority,
+                }) => {
                     {
                         if self.cache.contains_key(&key) {
                             continue;
                         }
                     }
-                    self.cache.put(key, value);
+                    self.cache.put_with_priority(key, value, priority);
                     metrics_inc_cache_population_pending_count(-1, TABLE_DATA_CACHE_NAME);
                 }
                 Err(_) => {
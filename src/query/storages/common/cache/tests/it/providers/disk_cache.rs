@@ -18,6 +18,7 @@ use std::io::Read;
 use std::path::Path;
 use std::path::PathBuf;
 
+use storages_common_cache::CachePriority;
 use storages_common_cache::DiskCacheError;
 use storages_common_cache::DiskCacheKey;
 use storages_common_cache::DiskCacheResult;
@@ -152,3 +153,60 @@ fn test_evict_until_enough_space() {
     // file3 MUST be keeped
     assert!(c.contains_key("file3"));
 }
+
+#[test]
+fn test_evict_low_priority_first() {
+    let f = TestFixture::new();
+    let mut c = DiskCache::new(f.tmp(), 20).unwrap();
+    c.insert_bytes_with_priority("index1", &[&[1; 5]], CachePriority::High)
+        .unwrap();
+    c.insert_single_slice("file1", &[1; 5]).unwrap();
+    c.insert_single_slice("file2", &[2; 5]).unwrap();
+    c.insert_single_slice("file3", &[3; 5]).unwrap();
+    assert_eq!(c.size(), 20);
+
+    // the high priority item is the least recently used one, but the low priority ones go first
+    c.insert_single_slice("file4", &[4; 10]).unwrap();
+    assert!(c.contains_key("index1"));
+    assert!(!c.contains_key("file1"));
+    assert!(!c.contains_key("file2"));
+    assert!(c.contains_key("file3"));
+    assert_eq!(c.size_of(CachePriority::High), 5);
+    assert_eq!(c.size_of(CachePriority::Low), 15);
+    assert_eq!(c.len_of(CachePriority::Low), 2);
+
+    // high priority items are evicted only if there are no low priority items left
+    c.insert_bytes_with_priority("index2", &[&[2; 5]], CachePriority::High)
+        .unwrap();
+    c.insert_single_slice("file5", &[5; 10]).unwrap();
+    assert!(c.contains_key("index1"));
+    assert!(c.contains_key("index2"));
+    assert!(!c.contains_key("file3"));
+    assert!(!c.contains_key("file4"));
+}
+
+#[test]
+fn test_high_priority_capacity() {
+    let f = TestFixture::new();
+    let mut c = DiskCache::new(f.tmp(), 20).unwrap();
+    c.insert_single_slice("file1", &[1; 10]).unwrap();
+    c.insert_bytes_with_priority("index1", &[&[1; 5]], CachePriority::High)
+        .unwrap();
+    c.insert_bytes_with_priority("index2", &[&[2; 5]], CachePriority::High)
+        .unwrap();
+
+    // the high priority items may take half of the capacity at most
+    c.insert_bytes_with_priority("index3", &[&[3; 5]], CachePriority::High)
+        .unwrap();
+    assert!(!c.contains_key("index1"));
+    assert!(c.contains_key("file1"));
+    assert_eq!(c.size_of(CachePriority::High), 10);
+
+    // too large for the high priority segment, kept as a low priority item
+    c.insert_bytes_with_priority("index4", &[&[4; 15]], CachePriority::High)
+        .unwrap();
+    assert_eq!(c.size_of(CachePriority::Low), 15);
+    assert!(!c.contains_key("file1"));
+    assert!(!c.contains_key("index2"));
+    assert!(c.contains_key("index3"));
+}
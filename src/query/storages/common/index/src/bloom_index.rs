@@ -46,6 +46,7 @@ use common_expression::TableField;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_expression::Value;
+use common_functions::scalars::Tokenizer;
 use common_functions::BUILTIN_FUNCTIONS;
 use storages_common_table_meta::meta::SingleColumnMeta;
 use storages_common_table_meta::meta::Versioned;
@@ -56,7 +57,9 @@ use crate::filters::FilterBuilder;
 use crate::filters::V2BloomBlock;
 use crate::filters::Xor8Builder;
 use crate::filters::Xor8Filter;
+use crate::inverted_index::visit_expr_match_constant;
 use crate::Index;
+use crate::InvertedIndex;
 
 #[derive(Clone)]
 pub struct BloomIndexMeta {
@@ -178,6 +181,25 @@ impl BloomIndex {
         version: u64,
         data_blocks_tobe_indexed: &[&DataBlock],
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    ) -> Result<Option<Self>> {
+        Self::try_create_with_inverted_index(
+            func_ctx,
+            version,
+            data_blocks_tobe_indexed,
+            bloom_columns_map,
+            BTreeMap::new(),
+            Tokenizer::default(),
+        )
+    }
+
+    /// Create a filter block from source data, with the term filters of the inverted index columns.
+    pub fn try_create_with_inverted_index(
+        func_ctx: FunctionContext,
+        version: u64,
+        data_blocks_tobe_indexed: &[&DataBlock],
+        bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        inverted_columns_map: BTreeMap<FieldIndex, TableField>,
+        tokenizer: Tokenizer,
     ) -> Result<Option<Self>> {
         if data_blocks_tobe_indexed.is_empty() {
             return Err(ErrorCode::BadArguments("block is empty"));
@@ -273,6 +295,28 @@ impl BloomIndex {
             filters.push(Arc::new(filter));
         }
 
+        for (index, field) in inverted_columns_map.into_iter() {
+            let field_type = &data_blocks_tobe_indexed[0].get_by_offset(index).data_type;
+            if field_type.remove_nullable() != DataType::String {
+                continue;
+            }
+            let source_columns = data_blocks_tobe_indexed
+                .iter()
+                .map(|block| {
+                    let value = &block.get_by_offset(index).value;
+                    value.convert_to_full_column(field_type, block.num_rows())
+                })
+                .collect::<Vec<_>>();
+
+            if let Some(filter) =
+                InvertedIndex::build_term_filter(&func_ctx, &source_columns, tokenizer)?
+            {
+                let filter_name = InvertedIndex::build_filter_column_name(&field, tokenizer);
+                filter_fields.push(TableField::new(&filter_name, TableDataType::String));
+                filters.push(Arc::new(filter));
+            }
+        }
+
         if filter_fields.is_empty() {
             return Ok(None);
         }
@@ -333,6 +377,30 @@ impl BloomIndex {
             },
         )?;
 
+        visit_expr_match_constant(
+            &mut expr,
+            &mut |span, col_name, tokenizer, groups, return_type| {
+                let filter_column = &InvertedIndex::build_filter_column_name(
+                    data_schema.field_with_name(col_name)?,
+                    tokenizer,
+                );
+
+                // If every group of the query has a term that doesn't show up in the block,
+                // rewrite the expression to `false`.
+                if self.find_terms(filter_column, groups, scalar_map)?
+                    == FilterEvalResult::MustFalse
+                {
+                    Ok(Some(Expr::Constant {
+                        span,
+                        scalar: Scalar::Boolean(false),
+                        data_type: return_type.clone(),
+                    }))
+                } else {
+                    Ok(None)
+                }
+            },
+        )?;
+
         let (new_expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
 
         match new_expr {
@@ -478,6 +546,38 @@ impl BloomIndex {
         }
     }
 
+    fn find_terms(
+        &self,
+        filter_column: &str,
+        groups: &[Vec<String>],
+        scalar_map: &HashMap<Scalar, u64>,
+    ) -> Result<FilterEvalResult> {
+        if !self.filter_schema.has_field(filter_column) {
+            // The column doesn't have a term filter.
+            return Ok(FilterEvalResult::Uncertain);
+        }
+
+        let idx = self.filter_schema.index_of(filter_column)?;
+        let filter = &self.filters[idx];
+
+        let digests = groups
+            .iter()
+            .map(|terms| {
+                terms
+                    .iter()
+                    .filter_map(|term| scalar_map.get(&Scalar::String(term.as_bytes().to_vec())))
+                    .copied()
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        if InvertedIndex::contains_any_group(filter, &digests) {
+            Ok(FilterEvalResult::Uncertain)
+        } else {
+            Ok(FilterEvalResult::MustFalse)
+        }
+    }
+
     pub fn supported_type(data_type: &TableDataType) -> bool {
         let data_type = DataType::from(data_type);
         Self::supported_data_type(&data_type)
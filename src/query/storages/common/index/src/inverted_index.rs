@@ -0,0 +1,208 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Deref;
+use std::str::FromStr;
+
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::UInt64Type;
+use common_expression::types::ValueType;
+use common_expression::Column;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_functions::scalars::parse_query;
+use common_functions::scalars::Tokenizer;
+
+use crate::filters::Filter;
+use crate::filters::FilterBuilder;
+use crate::filters::Xor8Builder;
+use crate::filters::Xor8Filter;
+use crate::BloomIndex;
+
+/// InvertedIndex represents the term filters of the full-text indexed columns.
+///
+/// The terms of each indexed string column are produced by the table's tokenizer,
+/// and their digests are stored as a xor filter in the bloom index file of the block,
+/// alongside the ordinary bloom filters, with field name 'Inverted(column_id, tokenizer)'.
+///
+/// A predicate `match(col, 'query')` could be rewritten to `false` if any term of the
+/// query doesn't show up in the term filter of the block, and so could `query(col, 'query')`
+/// if every group of its terms has such a term. Since the tokenizer is part
+/// of the field name, a query tokenized differently never looks up a filter built by
+/// another tokenizer, e.g. after the table's tokenizer has been altered.
+pub struct InvertedIndex;
+
+impl InvertedIndex {
+    pub fn supported_type(data_type: &TableDataType) -> bool {
+        matches!(
+            DataType::from(data_type).remove_nullable(),
+            DataType::String
+        )
+    }
+
+    pub fn build_filter_column_name(field: &TableField, tokenizer: Tokenizer) -> String {
+        format!("Inverted({}, {})", field.column_id(), tokenizer)
+    }
+
+    /// Create a term filter from the source columns of a string field.
+    ///
+    /// Returns `None` if there are no terms at all, e.g. all the values are NULL or empty.
+    pub fn build_term_filter(
+        func_ctx: &FunctionContext,
+        source_columns: &[Column],
+        tokenizer: Tokenizer,
+    ) -> Result<Option<Xor8Filter>> {
+        let mut builder = StringColumnBuilder::with_capacity(0, 0);
+        for column in source_columns {
+            // Nulls are stored as empty strings, which produce no terms.
+            if let Column::String(column) = column.remove_nullable() {
+                for text in column.iter() {
+                    for term in tokenizer.tokenize(&String::from_utf8_lossy(text)) {
+                        builder.put_str(&term);
+                        builder.commit_row();
+                    }
+                }
+            }
+        }
+        if builder.len() == 0 {
+            return Ok(None);
+        }
+
+        let terms = Column::String(builder.build());
+        let digests = BloomIndex::calculate_column_digest(
+            func_ctx,
+            &terms,
+            &DataType::String,
+            &DataType::Number(NumberDataType::UInt64),
+        )?;
+        let digests = UInt64Type::try_downcast_column(&digests).unwrap();
+
+        let mut filter_builder = Xor8Builder::create();
+        filter_builder.add_digests(digests.deref());
+        Ok(Some(filter_builder.build()?))
+    }
+
+    /// Returns true if all the given term digests may be contained in the filter.
+    pub fn contains_all(filter: &Xor8Filter, digests: &[u64]) -> bool {
+        digests.iter().all(|digest| filter.contains_digest(*digest))
+    }
+
+    /// Returns true if all the term digests of any group may be contained in the filter.
+    pub fn contains_any_group(filter: &Xor8Filter, groups: &[Vec<u64>]) -> bool {
+        groups
+            .iter()
+            .any(|digests| Self::contains_all(filter, digests))
+    }
+
+    /// Find all columns that match the pattern of `match(col, <constant>)` or
+    /// `query(col, <constant>)` in the expression, together with the tokenizer and the terms
+    /// of the constant query.
+    pub fn find_match_columns(
+        expr: &Expr<String>,
+        fields: &[TableField],
+    ) -> Result<Vec<(TableField, Tokenizer, Vec<String>)>> {
+        let mut cols = Vec::new();
+        visit_expr_match_constant(
+            &mut expr.clone(),
+            &mut |_, col_name, tokenizer, groups, _| {
+                if let Some(v) = fields.iter().find(|f| f.name() == col_name) {
+                    // a group without terms matches any text, the block can't be pruned.
+                    if Self::supported_type(v.data_type()) && groups.iter().all(|g| !g.is_empty()) {
+                        cols.push((v.clone(), tokenizer, groups.concat()));
+                    }
+                }
+                Ok(None)
+            },
+        )?;
+        Ok(cols)
+    }
+}
+
+/// Find patterns like `match(Column, <constant>)` or `match(Column, <constant>, <tokenizer>)`,
+/// and the same of `query`, and pass the tokenizer and the groups of the terms of the
+/// constant query to the visitor. The query of `match` is a single group.
+pub(crate) fn visit_expr_match_constant(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(
+        Span,
+        &str,
+        Tokenizer,
+        &[Vec<String>],
+        &DataType,
+    ) -> Result<Option<Expr<String>>>,
+) -> Result<()> {
+    if let Expr::FunctionCall {
+        span,
+        function,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        let name = function.signature.name.as_str();
+        if name == "match" || name == "query" {
+            let tokenizer = match args.get(2) {
+                None => Some(Tokenizer::default()),
+                Some(Expr::Constant {
+                    scalar: Scalar::String(s),
+                    ..
+                }) => Tokenizer::from_str(&String::from_utf8_lossy(s)).ok(),
+                Some(_) => None,
+            };
+            if let (
+                Some(tokenizer),
+                Some(Expr::ColumnRef { id, .. }),
+                Some(Expr::Constant {
+                    scalar: Scalar::String(query),
+                    ..
+                }),
+            ) = (tokenizer, args.first(), args.get(1))
+            {
+                let query = String::from_utf8_lossy(query);
+                let groups = if name == "match" {
+                    vec![tokenizer.tokenize(&query)]
+                } else {
+                    parse_query(tokenizer, &query)
+                };
+                // If the visitor returns a new expression, then replace with the current expression.
+                if let Some(new_expr) = visitor(*span, id, tokenizer, &groups, return_type)? {
+                    *expr = new_expr;
+                }
+            }
+            return Ok(());
+        }
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_match_constant(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_match_constant(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
@@ -18,6 +18,7 @@
 mod bloom_index;
 pub mod filters;
 mod index;
+mod inverted_index;
 mod page_index;
 mod range_index;
 
@@ -25,5 +26,6 @@ pub use bloom_index::BloomIndex;
 pub use bloom_index::BloomIndexMeta;
 pub use bloom_index::FilterEvalResult;
 pub use index::Index;
+pub use inverted_index::InvertedIndex;
 pub use page_index::PageIndex;
 pub use range_index::RangeIndex;
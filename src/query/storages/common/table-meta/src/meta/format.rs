@@ -169,18 +169,25 @@ where
     decode(encoding, &decompressed_data)
 }
 
+/// Set in the encoding byte of the segment header if a crc32 checksum of the segment data
+/// trails the segment.
+pub const SEGMENT_CHECKSUM_FLAG: u8 = 0x80;
+
 pub struct SegmentHeader {
     pub version: u64,
     pub encoding: MetaEncoding,
     pub compression: MetaCompression,
     pub blocks_size: u64,
     pub summary_size: u64,
+    pub has_checksum: bool,
 }
 
 pub fn decode_segment_header<R>(reader: &mut R) -> Result<SegmentHeader>
 where R: Read + Unpin + Send {
     let version = reader.read_scalar::<u64>()?;
-    let encoding = MetaEncoding::try_from(reader.read_scalar::<u8>()?)?;
+    let encoding = reader.read_scalar::<u8>()?;
+    let has_checksum = encoding & SEGMENT_CHECKSUM_FLAG != 0;
+    let encoding = MetaEncoding::try_from(encoding & !SEGMENT_CHECKSUM_FLAG)?;
     let compression = MetaCompression::try_from(reader.read_scalar::<u8>()?)?;
     let blocks_size: u64 = reader.read_scalar::<u64>()?;
     let summary_size: u64 = reader.read_scalar::<u64>()?;
@@ -190,9 +197,34 @@ where R: Read + Unpin + Send {
         compression,
         blocks_size,
         summary_size,
+        has_checksum,
     })
 }
 
+/// Verifies the crc32 checksum trailing the segment data, which ends at `data_len`.
+///
+/// Only called for the segments whose header has the `SEGMENT_CHECKSUM_FLAG` set.
+pub fn verify_segment_checksum(bytes: &[u8], data_len: usize) -> Result<()> {
+    let trailer = &bytes[data_len..];
+    let expected = match <[u8; 4]>::try_from(trailer) {
+        Ok(trailer) => u32::from_le_bytes(trailer),
+        Err(_) => {
+            return Err(ErrorCode::StorageDataCorrupted(format!(
+                "invalid segment checksum, expect 4 trailing bytes, got {}",
+                trailer.len()
+            )));
+        }
+    };
+    let actual = crc32fast::hash(&bytes[..data_len]);
+    if actual != expected {
+        return Err(ErrorCode::StorageDataCorrupted(format!(
+            "segment checksum mismatch, expected {}, got {}",
+            expected, actual
+        )));
+    }
+    Ok(())
+}
+
 pub async fn load_json<T>(bytes: &[u8], _v: &PhantomData<T>) -> Result<T>
 where T: DeserializeOwned {
     Ok(serde_json::from_slice::<T>(bytes)?)
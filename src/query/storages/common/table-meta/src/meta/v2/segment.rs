@@ -80,6 +80,22 @@ pub struct BlockMeta {
 
     // block create_on
     pub create_on: Option<DateTime<Utc>>,
+
+    /// location of deletion vector, the rows marked in it are treated as deleted
+    #[serde(default)]
+    pub deletion_vector_location: Option<Location>,
+    #[serde(default)]
+    pub deletion_vector_size: u64,
+    /// number of rows marked as deleted by the deletion vector
+    #[serde(default)]
+    pub deleted_row_count: u64,
+    /// crc32 of the raw bytes of each column in the block file, recorded at write time.
+    /// Empty for blocks written before checksums were recorded.
+    #[serde(default)]
+    pub column_checksums: HashMap<ColumnId, u32>,
+    /// version of the table data key the block file is encrypted with, None if not encrypted.
+    #[serde(default)]
+    pub encryption_key_version: Option<u32>,
 }
 
 impl BlockMeta {
@@ -109,6 +125,11 @@ impl BlockMeta {
             bloom_filter_index_size,
             compression,
             create_on,
+            deletion_vector_location: None,
+            deletion_vector_size: 0,
+            deleted_row_count: 0,
+            column_checksums: HashMap::new(),
+            encryption_key_version: None,
         }
     }
 
@@ -127,6 +148,11 @@ impl BlockMeta {
             self.row_count
         }
     }
+
+    /// Get the number of rows which are not marked as deleted by the deletion vector.
+    pub fn live_row_count(&self) -> u64 {
+        self.row_count - self.deleted_row_count
+    }
 }
 
 #[typetag::serde(name = "blockmeta")]
@@ -254,6 +280,11 @@ impl BlockMeta {
             bloom_filter_index_size: 0,
             compression: Compression::Lz4,
             create_on: None,
+            deletion_vector_location: None,
+            deletion_vector_size: 0,
+            deleted_row_count: 0,
+            column_checksums: HashMap::new(),
+            encryption_key_version: None,
         }
     }
 
@@ -285,6 +316,11 @@ impl BlockMeta {
             bloom_filter_index_size: s.bloom_filter_index_size,
             compression: s.compression,
             create_on: None,
+            deletion_vector_location: None,
+            deletion_vector_size: 0,
+            deleted_row_count: 0,
+            column_checksums: HashMap::new(),
+            encryption_key_version: None,
         }
     }
 }
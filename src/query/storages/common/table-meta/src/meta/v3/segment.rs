@@ -80,6 +80,7 @@ impl SegmentInfo {
             compression,
             blocks_size,
             summary_size,
+            ..
         } = decode_segment_header(&mut cursor)?;
 
         let blocks: Vec<frozen::BlockMeta> =
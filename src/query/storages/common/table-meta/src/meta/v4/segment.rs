@@ -27,8 +27,10 @@ use crate::meta::format::compress;
 use crate::meta::format::decode_segment_header;
 use crate::meta::format::encode;
 use crate::meta::format::read_and_deserialize;
+use crate::meta::format::verify_segment_checksum;
 use crate::meta::format::MetaCompression;
 use crate::meta::format::SegmentHeader;
+use crate::meta::format::SEGMENT_CHECKSUM_FLAG;
 use crate::meta::v2::BlockMeta;
 use crate::meta::FormatVersion;
 use crate::meta::MetaEncoding;
@@ -153,11 +155,12 @@ impl SegmentInfo {
             + blocks_compress.len().to_le_bytes().len()
             + blocks_compress.len()
             + summary_compress.len().to_le_bytes().len()
-            + summary_compress.len();
+            + summary_compress.len()
+            + 4;
         let mut buf = Vec::with_capacity(data_size);
 
         buf.extend_from_slice(&self.format_version.to_le_bytes());
-        buf.push(encoding as u8);
+        buf.push(encoding as u8 | SEGMENT_CHECKSUM_FLAG);
         buf.push(compression as u8);
         buf.extend_from_slice(&blocks_compress.len().to_le_bytes());
         buf.extend_from_slice(&summary_compress.len().to_le_bytes());
@@ -165,10 +168,20 @@ impl SegmentInfo {
         buf.extend(blocks_compress);
         buf.extend(summary_compress);
 
+        // checksum of all the bytes above, verified on read
+        let checksum = crc32fast::hash(&buf);
+        buf.extend_from_slice(&checksum.to_le_bytes());
+
         Ok(buf)
     }
 
     pub fn from_slice(bytes: &[u8]) -> Result<Self> {
+        Self::from_slice_with_checksum(bytes, false)
+    }
+
+    /// Deserializes the segment, verifying its checksum if `verify_checksum` is set and the
+    /// segment has one.
+    pub fn from_slice_with_checksum(bytes: &[u8], verify_checksum: bool) -> Result<Self> {
         let mut cursor = Cursor::new(bytes);
         let SegmentHeader {
             version,
@@ -176,12 +189,16 @@ impl SegmentInfo {
             compression,
             blocks_size,
             summary_size,
+            has_checksum,
         } = decode_segment_header(&mut cursor)?;
 
         let blocks: Vec<Arc<BlockMeta>> =
             read_and_deserialize(&mut cursor, blocks_size, &encoding, &compression)?;
         let summary: Statistics =
             read_and_deserialize(&mut cursor, summary_size, &encoding, &compression)?;
+        if verify_checksum && has_checksum {
+            verify_segment_checksum(bytes, cursor.position() as usize)?;
+        }
 
         let mut segment = Self::new(blocks, summary);
 
@@ -209,6 +226,12 @@ pub struct CompactSegmentInfo {
 
 impl CompactSegmentInfo {
     pub fn from_slice(bytes: &[u8]) -> Result<Self> {
+        Self::from_slice_with_checksum(bytes, false)
+    }
+
+    /// Deserializes the segment, verifying its checksum if `verify_checksum` is set and the
+    /// segment has one.
+    pub fn from_slice_with_checksum(bytes: &[u8], verify_checksum: bool) -> Result<Self> {
         let mut cursor = Cursor::new(bytes);
         let SegmentHeader {
             version,
@@ -216,6 +239,7 @@ impl CompactSegmentInfo {
             compression,
             blocks_size,
             summary_size,
+            has_checksum,
         } = decode_segment_header(&mut cursor)?;
 
         let mut block_metas_raw_bytes = vec![0; blocks_size as usize];
@@ -223,6 +247,9 @@ impl CompactSegmentInfo {
 
         let summary: Statistics =
             read_and_deserialize(&mut cursor, summary_size, &encoding, &compression)?;
+        if verify_checksum && has_checksum {
+            verify_segment_checksum(bytes, cursor.position() as usize)?;
+        }
 
         let segment = CompactSegmentInfo {
             format_version: version,
@@ -83,6 +83,12 @@ pub struct TableSnapshot {
     // The metadata of the cluster keys.
     pub cluster_key_meta: Option<ClusterKey>,
     pub table_statistics_location: Option<String>,
+
+    /// Number of rows inserted or deleted since the table was last analyzed
+    ///
+    /// Used to decide when the table statistics are stale and should be re-collected.
+    #[serde(default)]
+    pub rows_changed_since_analyze: u64,
 }
 
 impl TableSnapshot {
@@ -114,6 +120,7 @@ impl TableSnapshot {
             segments,
             cluster_key_meta,
             table_statistics_location,
+            rows_changed_since_analyze: 0,
         }
     }
 
@@ -134,7 +141,7 @@ impl TableSnapshot {
         let id = Uuid::new_v4();
         let clone = previous.clone();
         // the timestamp of the new snapshot will be adjusted by the `new` method
-        Self::new(
+        let mut snapshot = Self::new(
             id,
             &clone.timestamp,
             Some((clone.snapshot_id, clone.format_version)),
@@ -143,7 +150,9 @@ impl TableSnapshot {
             clone.segments,
             clone.cluster_key_meta,
             clone.table_statistics_location,
-        )
+        );
+        snapshot.rows_changed_since_analyze = clone.rows_changed_since_analyze;
+        snapshot
     }
 
     /// Serializes the struct to a byte vector.
@@ -221,6 +230,7 @@ impl From<v2::TableSnapshot> for TableSnapshot {
             segments: s.segments,
             cluster_key_meta: s.cluster_key_meta,
             table_statistics_location: s.table_statistics_location,
+            rows_changed_since_analyze: 0,
         }
     }
 }
@@ -242,6 +252,7 @@ where T: Into<v3::TableSnapshot>
             segments: s.segments,
             cluster_key_meta: s.cluster_key_meta,
             table_statistics_location: s.table_statistics_location,
+            rows_changed_since_analyze: 0,
         }
     }
 }
@@ -260,6 +271,7 @@ pub struct TableSnapshotLite {
     pub uncompressed_byte_size: u64,
     pub compressed_byte_size: u64,
     pub segment_count: u64,
+    pub rows_changed_since_analyze: u64,
 }
 
 impl From<(&TableSnapshot, FormatVersion)> for TableSnapshotLite {
@@ -275,6 +287,7 @@ impl From<(&TableSnapshot, FormatVersion)> for TableSnapshotLite {
             uncompressed_byte_size: value.summary.uncompressed_byte_size,
             segment_count: value.segments.len() as u64,
             compressed_byte_size: value.summary.compressed_byte_size,
+            rows_changed_since_analyze: value.rows_changed_since_analyze,
         }
     }
 }
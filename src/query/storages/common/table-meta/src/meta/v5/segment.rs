@@ -323,6 +323,7 @@ impl SegmentInfo {
             // maybe we need to modify as blocks_or_segments_size
             blocks_size,
             summary_size,
+            ..
         } = decode_segment_header(&mut cursor)?;
 
         let typ = Self::read_typ(&mut cursor)?;
@@ -369,6 +370,7 @@ impl CompactSegmentInfo {
             compression,
             blocks_size,
             summary_size,
+            ..
         } = decode_segment_header(&mut cursor)?;
 
         let typ = SegmentInfo::read_typ(&mut cursor)?;
@@ -25,16 +25,19 @@ use crate::meta::SegmentInfoVersion;
 use crate::readers::VersionedReader;
 
 #[async_trait::async_trait]
-impl VersionedReader<CompactSegmentInfo> for (SegmentInfoVersion, TableSchemaRef) {
+impl VersionedReader<CompactSegmentInfo> for (SegmentInfoVersion, TableSchemaRef, bool) {
     type TargetType = CompactSegmentInfo;
     #[async_backtrace::framed]
     async fn read<R>(&self, mut reader: R) -> Result<CompactSegmentInfo>
     where R: AsyncRead + Unpin + Send {
         let schema = &self.1;
+        let verify_checksum = self.2;
         let mut buffer: Vec<u8> = vec![];
         reader.read_to_end(&mut buffer).await?;
         match &self.0 {
-            SegmentInfoVersion::V4(_) => CompactSegmentInfo::from_slice(&buffer),
+            SegmentInfoVersion::V4(_) => {
+                CompactSegmentInfo::from_slice_with_checksum(&buffer, verify_checksum)
+            }
             SegmentInfoVersion::V3(_) => {
                 let current: SegmentInfo = SegmentInfoV3::from_slice(&buffer)?.into();
                 current.try_into()
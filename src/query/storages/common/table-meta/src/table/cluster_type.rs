@@ -0,0 +1,54 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use common_exception::ErrorCode;
+
+/// The way the blocks of a clustered table are sorted by the cluster keys.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ClusterType {
+    /// Sort by the cluster keys lexicographically.
+    #[default]
+    Linear,
+    /// Sort by the z-order curve of the cluster keys, so that each key
+    /// contributes equally to the locality of the blocks.
+    ZOrder,
+}
+
+impl FromStr for ClusterType {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "linear" => Ok(ClusterType::Linear),
+            "zorder" => Ok(ClusterType::ZOrder),
+            other => Err(ErrorCode::TableOptionInvalid(format!(
+                "unsupported cluster type: {}, expecting 'linear' or 'zorder'",
+                other
+            ))),
+        }
+    }
+}
+
+impl Display for ClusterType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClusterType::Linear => write!(f, "linear"),
+            ClusterType::ZOrder => write!(f, "zorder"),
+        }
+    }
+}
@@ -12,10 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod cluster_type;
 mod table_compression;
+mod table_constraints;
 mod table_keys;
 mod table_prefix;
 
+pub use cluster_type::ClusterType;
 pub use table_compression::TableCompression;
+pub use table_constraints::ForeignKey;
+pub use table_constraints::TableConstraints;
 pub use table_keys::*;
 pub use table_prefix::*;
@@ -0,0 +1,69 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::table::OPT_KEY_CONSTRAINTS;
+
+/// Informational constraints of a table.
+///
+/// The constraints are declared by the user and stored in the table options as json,
+/// they are NOT enforced when data is written. The optimizer trusts them to eliminate
+/// redundant joins and distinct aggregations, so a violated constraint may lead to
+/// wrong query results.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TableConstraints {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_key: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unique_keys: Vec<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub foreign_keys: Vec<ForeignKey>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ForeignKey {
+    pub columns: Vec<String>,
+    pub ref_database: String,
+    pub ref_table: String,
+    pub ref_columns: Vec<String>,
+}
+
+impl TableConstraints {
+    pub fn is_empty(&self) -> bool {
+        self.primary_key.is_none() && self.unique_keys.is_empty() && self.foreign_keys.is_empty()
+    }
+
+    /// All the keys that identify a row: the primary key and the unique keys.
+    pub fn unique_keys(&self) -> impl Iterator<Item = &Vec<String>> {
+        self.primary_key.iter().chain(self.unique_keys.iter())
+    }
+
+    pub fn parse(value: &str) -> Result<Self> {
+        serde_json::from_str(value).map_err(|e| {
+            ErrorCode::TableOptionInvalid(format!(
+                "invalid {} option '{}': {}",
+                OPT_KEY_CONSTRAINTS, value, e
+            ))
+        })
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string(self)
+            .map_err(|e| ErrorCode::Internal(format!("fail to serialize table constraints: {}", e)))
+    }
+}
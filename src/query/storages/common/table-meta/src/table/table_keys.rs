@@ -21,9 +21,51 @@ pub const OPT_KEY_SNAPSHOT_LOCATION: &str = "snapshot_location";
 pub const OPT_KEY_STORAGE_FORMAT: &str = "storage_format";
 pub const OPT_KEY_TABLE_COMPRESSION: &str = "compression";
 pub const OPT_KEY_COMMENT: &str = "comment";
+pub const OPT_KEY_TAGS: &str = "tags";
 pub const OPT_KEY_EXTERNAL_LOCATION: &str = "external_location";
 pub const OPT_KEY_ENGINE: &str = "engine";
+pub const OPT_KEY_CLUSTER_TYPE: &str = "cluster_type";
+pub const OPT_KEY_PARTITION_BY: &str = "partition_by";
+pub const OPT_KEY_CONSTRAINTS: &str = "constraints";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
+pub const OPT_KEY_INVERTED_INDEX_NAME: &str = "inverted_index_name";
+pub const OPT_KEY_INVERTED_INDEX_COLUMNS: &str = "inverted_index_columns";
+pub const OPT_KEY_INVERTED_INDEX_TOKENIZER: &str = "inverted_index_tokenizer";
+/// The name of the storage profile the table's data is stored with.
+pub const OPT_KEY_STORAGE_PROFILE: &str = "storage_profile";
+/// The ids of the tables whose files are shared by a table created by `CREATE TABLE ... CLONE`,
+/// separated by commas.
+pub const OPT_KEY_CLONED_FROM: &str = "cloned_from";
+/// The ids of the tables created by `CREATE TABLE ... CLONE`, which share the files of a table,
+/// separated by commas.
+pub const OPT_KEY_CLONED_BY: &str = "cloned_by";
+/// Set on a table once a stream has been created on it. Deletes on such a table always rewrite
+/// blocks instead of writing deletion vectors, so that streams can see the deleted rows.
+pub const OPT_KEY_CHANGE_TRACKING: &str = "change_tracking";
+/// Enables the encryption of the table's blocks at rest, the only supported value is `aes256`.
+pub const OPT_KEY_ENCRYPTION: &str = "encryption";
+/// The data keys of an encrypted table, wrapped by the KMS of the cluster. Formatted as
+/// `<version>:<wrapped key>` separated by commas, the key of the largest version is current.
+pub const OPT_KEY_ENCRYPTION_KEYS: &str = "encryption_keys";
+/// Set on the FUSE table holding the result of a materialized view, the query of the view.
+pub const OPT_KEY_MATERIALIZED_VIEW_QUERY: &str = "materialized_view_query";
+/// The names of the streams tracking the changes of the source tables of a materialized view,
+/// separated by commas. The streams are in the database of the view.
+pub const OPT_KEY_MATERIALIZED_VIEW_STREAMS: &str = "materialized_view_streams";
+/// Set if the query of a materialized view only filters and projects the rows of one table,
+/// the view is then refreshed by appending the result of the query over the inserted rows.
+pub const OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL: &str = "materialized_view_incremental";
+/// Set if the query of a materialized view aggregates the rows of one table with mergeable
+/// functions only, the function merging each column of the view, empty for the group keys,
+/// separated by commas. The view is then refreshed by merging it with the result of the query
+/// over the inserted rows.
+pub const OPT_KEY_MATERIALIZED_VIEW_AGGREGATES: &str = "materialized_view_aggregates";
+/// The user creating a materialized view in JSON, the view is refreshed in the background with
+/// the privileges of the user.
+pub const OPT_KEY_MATERIALIZED_VIEW_OWNER: &str = "materialized_view_owner";
+/// The id of the oldest snapshot kept by `fuse_vacuum`, the snapshots before it are no longer
+/// part of the history of the table and their files are removed.
+pub const OPT_KEY_SNAPSHOT_HISTORY_START: &str = "snapshot_history_start";
 
 /// Legacy table snapshot location key
 ///
@@ -42,6 +84,17 @@ pub static RESERVED_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_PARTITION_BY);
+    r.insert(OPT_KEY_CLONED_FROM);
+    r.insert(OPT_KEY_CLONED_BY);
+    r.insert(OPT_KEY_CHANGE_TRACKING);
+    r.insert(OPT_KEY_ENCRYPTION_KEYS);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_STREAMS);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_AGGREGATES);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_OWNER);
+    r.insert(OPT_KEY_SNAPSHOT_HISTORY_START);
     r
 });
 
@@ -50,6 +103,17 @@ pub static INTERNAL_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_PARTITION_BY);
+    r.insert(OPT_KEY_CLONED_FROM);
+    r.insert(OPT_KEY_CLONED_BY);
+    r.insert(OPT_KEY_CHANGE_TRACKING);
+    r.insert(OPT_KEY_ENCRYPTION_KEYS);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_STREAMS);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_AGGREGATES);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_OWNER);
+    r.insert(OPT_KEY_SNAPSHOT_HISTORY_START);
     r
 });
 
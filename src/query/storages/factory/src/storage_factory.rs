@@ -19,9 +19,19 @@ use common_config::InnerConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::TableInfo;
+use common_storages_federated::Dialect;
+use common_storages_federated::FederatedTable;
+use common_storages_federated::MYSQL_ENGINE;
+use common_storages_federated::POSTGRES_ENGINE;
 use common_storages_memory::MemoryTable;
 use common_storages_null::NullTable;
+use common_storages_parquet::DeltaTable;
+use common_storages_parquet::ExternalTable;
+use common_storages_parquet::DELTA_ENGINE;
+use common_storages_parquet::EXTERNAL_ENGINE;
 use common_storages_random::RandomTable;
+use common_storages_stream::stream_table::StreamTable;
+use common_storages_stream::stream_table::STREAM_ENGINE;
 use common_storages_view::view_table::ViewTable;
 use dashmap::DashMap;
 
@@ -96,12 +106,44 @@ impl StorageFactory {
             descriptor: Arc::new(ViewTable::description),
         });
 
+        // Register STREAM table engine
+        creators.insert(STREAM_ENGINE.to_string(), Storage {
+            creator: Arc::new(StreamTable::try_create),
+            descriptor: Arc::new(StreamTable::description),
+        });
+
         // Register RANDOM table engine
         creators.insert("RANDOM".to_string(), Storage {
             creator: Arc::new(RandomTable::try_create),
             descriptor: Arc::new(RandomTable::description),
         });
 
+        // Register EXTERNAL table engine
+        creators.insert(EXTERNAL_ENGINE.to_string(), Storage {
+            creator: Arc::new(ExternalTable::try_create),
+            descriptor: Arc::new(ExternalTable::description),
+        });
+
+        // Register DELTA table engine
+        creators.insert(DELTA_ENGINE.to_string(), Storage {
+            creator: Arc::new(DeltaTable::try_create),
+            descriptor: Arc::new(DeltaTable::description),
+        });
+
+        // Register MYSQL and POSTGRES table engines
+        creators.insert(MYSQL_ENGINE.to_string(), Storage {
+            creator: Arc::new(|table_info: TableInfo| {
+                FederatedTable::try_create(Dialect::MySql, table_info)
+            }),
+            descriptor: Arc::new(|| FederatedTable::description(Dialect::MySql)),
+        });
+        creators.insert(POSTGRES_ENGINE.to_string(), Storage {
+            creator: Arc::new(|table_info: TableInfo| {
+                FederatedTable::try_create(Dialect::Postgres, table_info)
+            }),
+            descriptor: Arc::new(|| FederatedTable::description(Dialect::Postgres)),
+        });
+
         StorageFactory { storages: creators }
     }
 
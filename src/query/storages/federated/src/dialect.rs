@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The SQL dialect of the remote server.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dialect {
+    MySql,
+    Postgres,
+}
+
+impl Dialect {
+    pub fn engine_name(&self) -> &'static str {
+        match self {
+            Dialect::MySql => crate::MYSQL_ENGINE,
+            Dialect::Postgres => crate::POSTGRES_ENGINE,
+        }
+    }
+
+    pub fn quote_identifier(&self, ident: &str) -> String {
+        match self {
+            Dialect::MySql => format!("`{}`", ident.replace('`', "``")),
+            Dialect::Postgres => format!("\"{}\"", ident.replace('"', "\"\"")),
+        }
+    }
+
+    /// Quote a possibly qualified name, like `schema.table`.
+    pub fn quote_name(&self, name: &str) -> String {
+        name.split('.')
+            .map(|part| self.quote_identifier(part))
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    pub fn quote_string(&self, value: &str) -> String {
+        match self {
+            // Backslash is an escape character in the string literals of MySQL.
+            Dialect::MySql => format!("'{}'", value.replace('\\', "\\\\").replace('\'', "''")),
+            Dialect::Postgres => format!("'{}'", value.replace('\'', "''")),
+        }
+    }
+
+    /// Build the query sent to the remote server.
+    ///
+    /// A constant is selected if no column is needed, so that the number of rows is kept.
+    pub fn build_query(
+        &self,
+        table: &str,
+        columns: &[String],
+        filter: Option<String>,
+        limit: Option<usize>,
+    ) -> String {
+        let columns = if columns.is_empty() {
+            "1".to_string()
+        } else {
+            columns
+                .iter()
+                .map(|column| self.quote_identifier(column))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let mut query = format!("SELECT {} FROM {}", columns, self.quote_name(table));
+        if let Some(filter) = filter {
+            query.push_str(&format!(" WHERE {}", filter));
+        }
+        if let Some(limit) = limit {
+            query.push_str(&format!(" LIMIT {}", limit));
+        }
+        query
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::DataType;
+use common_expression::types::NumberScalar;
+use common_expression::Expr;
+use common_expression::Scalar;
+
+use crate::Dialect;
+
+/// Convert the filter into a condition of the remote query.
+///
+/// The filter is still evaluated on the rows returned by the remote server, so the
+/// condition may keep more rows than the filter, but never less. Conjunctions that
+/// can't be converted are dropped, and comparisons of strings are only converted to
+/// `=` and `LIKE`, because the collation of the remote server may be case insensitive,
+/// or order the strings differently.
+pub fn filter_to_sql(expr: &Expr<String>, dialect: Dialect) -> Option<String> {
+    match expr {
+        Expr::FunctionCall { function, args, .. }
+            if matches!(
+                function.signature.name.as_str(),
+                "and" | "and_filters" | "is_true"
+            ) =>
+        {
+            let conjunctions = args
+                .iter()
+                .filter_map(|arg| filter_to_sql(arg, dialect))
+                .collect::<Vec<_>>();
+            match conjunctions.len() {
+                0 => None,
+                1 => conjunctions.into_iter().next(),
+                _ => Some(conjunctions.join(" AND ")),
+            }
+        }
+        _ => predicate_to_sql(expr, dialect),
+    }
+}
+
+fn predicate_to_sql(expr: &Expr<String>, dialect: Dialect) -> Option<String> {
+    let (name, args) = match expr {
+        Expr::FunctionCall { function, args, .. } => (function.signature.name.as_str(), args),
+        _ => return None,
+    };
+    match (name, args.as_slice()) {
+        ("and" | "and_filters", _) => {
+            let conjunctions = args
+                .iter()
+                .map(|arg| predicate_to_sql(arg, dialect))
+                .collect::<Option<Vec<_>>>()?;
+            Some(format!("({})", conjunctions.join(" AND ")))
+        }
+        ("or", _) => {
+            let disjunctions = args
+                .iter()
+                .map(|arg| predicate_to_sql(arg, dialect))
+                .collect::<Option<Vec<_>>>()?;
+            Some(format!("({})", disjunctions.join(" OR ")))
+        }
+        ("is_true", [arg]) => predicate_to_sql(arg, dialect),
+        ("is_not_null", [arg]) => {
+            let (column, _) = column_to_sql(arg, dialect)?;
+            Some(format!("{} IS NOT NULL", column))
+        }
+        ("not", [Expr::FunctionCall { function, args, .. }])
+            if function.signature.name == "is_not_null" && args.len() == 1 =>
+        {
+            let (column, _) = column_to_sql(&args[0], dialect)?;
+            Some(format!("{} IS NULL", column))
+        }
+        ("like", [column, Expr::Constant { scalar, .. }]) => {
+            let (column, data_type) = column_to_sql(column, dialect)?;
+            if data_type != DataType::String {
+                return None;
+            }
+            let pattern = literal_to_sql(scalar, dialect)?;
+            Some(format!("{} LIKE {}", column, pattern))
+        }
+        (_, [left, right]) => {
+            let op = match name {
+                "eq" => "=",
+                "noteq" => "<>",
+                "lt" => "<",
+                "lte" => "<=",
+                "gt" => ">",
+                "gte" => ">=",
+                _ => return None,
+            };
+            let (column, data_type, literal, op) = match (left, right) {
+                (column, Expr::Constant { scalar, .. }) => {
+                    let (column, data_type) = column_to_sql(column, dialect)?;
+                    (column, data_type, scalar, op)
+                }
+                (Expr::Constant { scalar, .. }, column) => {
+                    let (column, data_type) = column_to_sql(column, dialect)?;
+                    let op = match op {
+                        "<" => ">",
+                        "<=" => ">=",
+                        ">" => "<",
+                        ">=" => "<=",
+                        op => op,
+                    };
+                    (column, data_type, scalar, op)
+                }
+                _ => return None,
+            };
+            match data_type {
+                DataType::String if op != "=" => return None,
+                DataType::String | DataType::Boolean | DataType::Decimal(_) => {}
+                DataType::Number(number) if !number.is_float() => {}
+                _ => return None,
+            }
+            let literal = literal_to_sql(literal, dialect)?;
+            Some(format!("{} {} {}", column, op, literal))
+        }
+        _ => None,
+    }
+}
+
+/// Convert a column, and return the type of the column without nullable.
+///
+/// The casts that don't change the values of the column, like casting to nullable or
+/// a wider integer type, are removed.
+fn column_to_sql(expr: &Expr<String>, dialect: Dialect) -> Option<(String, DataType)> {
+    match expr {
+        Expr::ColumnRef { id, data_type, .. } => {
+            Some((dialect.quote_identifier(id), data_type.remove_nullable()))
+        }
+        Expr::Cast {
+            is_try: false,
+            expr,
+            dest_type,
+            ..
+        } => {
+            let (column, data_type) = column_to_sql(expr, dialect)?;
+            match (&data_type, dest_type.remove_nullable()) {
+                (src, dest) if *src == dest => Some((column, data_type)),
+                (DataType::Number(src), DataType::Number(dest))
+                    if !src.is_float() && !dest.is_float() && src.can_lossless_cast_to(dest) =>
+                {
+                    Some((column, DataType::Number(dest)))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn literal_to_sql(scalar: &Scalar, dialect: Dialect) -> Option<String> {
+    match scalar {
+        Scalar::Number(NumberScalar::Float32(_) | NumberScalar::Float64(_)) => None,
+        Scalar::Number(number) => Some(number.to_string()),
+        Scalar::Decimal(decimal) => Some(decimal.to_string()),
+        Scalar::Boolean(true) => Some("TRUE".to_string()),
+        Scalar::Boolean(false) => Some("FALSE".to_string()),
+        Scalar::String(value) => {
+            let value = std::str::from_utf8(value).ok()?;
+            Some(dialect.quote_string(value))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tables over the tables of a remote MySQL or PostgreSQL server.
+//!
+//! The projection, and the part of the filter and limit that the remote server can
+//! evaluate, are pushed down into the query sent to the remote server, the results
+//! are streamed back as data blocks.
+
+mod dialect;
+mod filter;
+mod mysql;
+mod part;
+mod postgres;
+mod source;
+mod table;
+
+pub use dialect::Dialect;
+pub use part::FederatedPart;
+pub use table::FederatedTable;
+pub use table::FEDERATED_OPT_KEY_CONNECTION;
+pub use table::FEDERATED_OPT_KEY_REMOTE_TABLE;
+pub use table::MYSQL_ENGINE;
+pub use table::POSTGRES_ENGINE;
@@ -0,0 +1,71 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_channel::Sender;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use mysql_async::prelude::Queryable;
+use mysql_async::Conn;
+use mysql_async::Opts;
+use mysql_async::Value;
+
+use crate::source::Rows;
+
+fn mysql_error(e: mysql_async::Error) -> ErrorCode {
+    ErrorCode::StorageOther(format!("MySQL error: {}", e))
+}
+
+/// Run the query on the MySQL server, and send the rows in batches of `batch_size`.
+///
+/// The query is sent in the text protocol, so all values are returned as text.
+#[async_backtrace::framed]
+pub async fn fetch_rows(
+    connection: &str,
+    query: &str,
+    batch_size: usize,
+    sender: &Sender<Result<Rows>>,
+) -> Result<()> {
+    let opts = Opts::from_url(connection).map_err(|e| {
+        ErrorCode::TableOptionInvalid(format!("invalid MySQL connection url: {}", e))
+    })?;
+    let mut conn = Conn::new(opts).await.map_err(mysql_error)?;
+    let mut result = conn.query_iter(query).await.map_err(mysql_error)?;
+
+    let mut rows = Vec::with_capacity(batch_size);
+    while let Some(row) = result.next().await.map_err(mysql_error)? {
+        let values = row
+            .unwrap()
+            .into_iter()
+            .map(|value| match value {
+                Value::NULL => None,
+                Value::Bytes(bytes) => Some(bytes),
+                value => Some(value.as_sql(true).into_bytes()),
+            })
+            .collect();
+        rows.push(values);
+        if rows.len() == batch_size {
+            let batch = std::mem::replace(&mut rows, Vec::with_capacity(batch_size));
+            if sender.send(Ok(batch)).await.is_err() {
+                // The query is finished, no more rows are needed.
+                return Ok(());
+            }
+        }
+    }
+    drop(result);
+
+    if !rows.is_empty() && sender.send(Ok(rows)).await.is_err() {
+        return Ok(());
+    }
+    conn.disconnect().await.map_err(mysql_error)
+}
@@ -0,0 +1,86 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_channel::Sender;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use log::error;
+use tokio_postgres::NoTls;
+use tokio_postgres::SimpleQueryMessage;
+
+use crate::source::Rows;
+
+const CURSOR_NAME: &str = "databend_federated_cursor";
+
+fn postgres_error(e: tokio_postgres::Error) -> ErrorCode {
+    ErrorCode::StorageOther(format!("PostgreSQL error: {}", e))
+}
+
+/// Run the query on the PostgreSQL server, and send the rows in batches of `batch_size`.
+///
+/// The rows are fetched from a cursor of the query in the simple query protocol, so
+/// only a batch of rows is held in memory, and all values are returned as text.
+#[async_backtrace::framed]
+pub async fn fetch_rows(
+    connection: &str,
+    query: &str,
+    batch_size: usize,
+    sender: &Sender<Result<Rows>>,
+) -> Result<()> {
+    let (client, conn) = tokio_postgres::connect(connection, NoTls)
+        .await
+        .map_err(postgres_error)?;
+    // The connection performs the communication with the server, it exits when the
+    // client is dropped.
+    tokio::spawn(async_backtrace::location!().frame(async move {
+        if let Err(e) = conn.await {
+            error!("PostgreSQL connection error: {}", e);
+        }
+    }));
+
+    client
+        .batch_execute(&format!(
+            "BEGIN READ ONLY; DECLARE {} NO SCROLL CURSOR FOR {}",
+            CURSOR_NAME, query
+        ))
+        .await
+        .map_err(postgres_error)?;
+    let fetch = format!("FETCH FORWARD {} FROM {}", batch_size, CURSOR_NAME);
+    loop {
+        let rows = client
+            .simple_query(&fetch)
+            .await
+            .map_err(postgres_error)?
+            .into_iter()
+            .filter_map(|message| match message {
+                SimpleQueryMessage::Row(row) => Some(
+                    (0..row.len())
+                        .map(|i| row.get(i).map(|value| value.as_bytes().to_vec()))
+                        .collect(),
+                ),
+                _ => None,
+            })
+            .collect::<Rows>();
+
+        let is_last = rows.len() < batch_size;
+        if !rows.is_empty() && sender.send(Ok(rows)).await.is_err() {
+            // The query is finished, no more rows are needed.
+            break;
+        }
+        if is_last {
+            break;
+        }
+    }
+    client.batch_execute("COMMIT").await.map_err(postgres_error)
+}
@@ -0,0 +1,185 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use async_channel::Receiver;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::check_cast;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::ScalarRef;
+use common_expression::TableSchemaRef;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+
+use crate::mysql;
+use crate::postgres;
+use crate::Dialect;
+
+/// Rows returned by the remote server, the values are in the text format of the server.
+pub type Rows = Vec<Vec<Option<Vec<u8>>>>;
+
+/// Read the result of the query from the remote server.
+///
+/// The rows are fetched by a separate task, and sent to the source in batches through
+/// a bounded channel, so the remote server is not read ahead of the query too much.
+pub struct FederatedSource {
+    func_ctx: FunctionContext,
+    schema: TableSchemaRef,
+    dialect: Dialect,
+    connection: String,
+    query: String,
+    batch_size: usize,
+    receiver: Option<Receiver<Result<Rows>>>,
+}
+
+impl FederatedSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        schema: TableSchemaRef,
+        dialect: Dialect,
+        connection: String,
+        query: String,
+    ) -> Result<ProcessorPtr> {
+        let func_ctx = ctx.get_function_context()?;
+        let batch_size = ctx.get_settings().get_max_block_size()? as usize;
+        AsyncSourcer::create(ctx, output, FederatedSource {
+            func_ctx,
+            schema,
+            dialect,
+            connection,
+            query,
+            batch_size,
+            receiver: None,
+        })
+    }
+
+    fn start_fetch(&self) -> Receiver<Result<Rows>> {
+        let (sender, receiver) = async_channel::bounded(1);
+        let dialect = self.dialect;
+        let connection = self.connection.clone();
+        let query = self.query.clone();
+        let batch_size = self.batch_size;
+        tokio::spawn(async_backtrace::location!().frame(async move {
+            let res = match dialect {
+                Dialect::MySql => mysql::fetch_rows(&connection, &query, batch_size, &sender).await,
+                Dialect::Postgres => {
+                    postgres::fetch_rows(&connection, &query, batch_size, &sender).await
+                }
+            };
+            if let Err(e) = res {
+                let _ = sender.send(Err(e)).await;
+            }
+        }));
+        receiver
+    }
+
+    /// Cast the text values to the types of the columns.
+    fn rows_to_block(&self, rows: Rows) -> Result<DataBlock> {
+        let num_rows = rows.len();
+        let text_type = DataType::Nullable(Box::new(DataType::String));
+        let fields = self.schema.fields();
+        let is_boolean = fields
+            .iter()
+            .map(|field| DataType::from(field.data_type()).remove_nullable() == DataType::Boolean)
+            .collect::<Vec<_>>();
+
+        let mut builders = fields
+            .iter()
+            .map(|_| ColumnBuilder::with_capacity(&text_type, num_rows))
+            .collect::<Vec<_>>();
+        for row in rows.iter() {
+            if row.len() < fields.len() {
+                return Err(ErrorCode::StorageOther(format!(
+                    "expect {} columns from the remote server, but got {}",
+                    fields.len(),
+                    row.len()
+                )));
+            }
+            for (idx, builder) in builders.iter_mut().enumerate() {
+                match &row[idx] {
+                    None => builder.push(ScalarRef::Null),
+                    // MySQL returns booleans as 1 and 0, PostgreSQL returns them as t and f.
+                    Some(value) if is_boolean[idx] => match value.as_slice() {
+                        b"1" | b"t" => builder.push(ScalarRef::String(b"true")),
+                        b"0" | b"f" => builder.push(ScalarRef::String(b"false")),
+                        value => builder.push(ScalarRef::String(value)),
+                    },
+                    Some(value) => builder.push(ScalarRef::String(value)),
+                }
+            }
+        }
+        let entries = builders
+            .into_iter()
+            .map(|builder| BlockEntry::new(text_type.clone(), Value::Column(builder.build())))
+            .collect();
+        let block = DataBlock::new(entries, num_rows);
+
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let entries = fields
+            .iter()
+            .enumerate()
+            .map(|(idx, field)| {
+                let data_type = DataType::from(field.data_type());
+                let expr = Expr::ColumnRef {
+                    span: None,
+                    id: idx,
+                    data_type: text_type.clone(),
+                    display_name: field.name().clone(),
+                };
+                let expr = check_cast(None, false, expr, &data_type, &BUILTIN_FUNCTIONS)?;
+                let value = evaluator.run(&expr).map_err(|e| {
+                    ErrorCode::BadBytes(format!(
+                        "fail to read column '{}' from the remote server: {}",
+                        field.name(),
+                        e.message()
+                    ))
+                })?;
+                Ok(BlockEntry::new(data_type, value))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DataBlock::new(entries, num_rows))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for FederatedSource {
+    const NAME: &'static str = "FederatedSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.receiver.is_none() {
+            self.receiver = Some(self.start_fetch());
+        }
+        // The channel is closed after all rows are sent.
+        match self.receiver.as_ref().unwrap().recv().await {
+            Ok(rows) => Ok(Some(self.rows_to_block(rows?)?)),
+            Err(_) => Ok(None),
+        }
+    }
+}
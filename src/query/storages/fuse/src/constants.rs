@@ -17,6 +17,18 @@ pub const FUSE_OPT_KEY_BLOCK_PER_SEGMENT: &str = "block_per_segment";
 pub const FUSE_OPT_KEY_ROW_PER_BLOCK: &str = "row_per_block";
 pub const FUSE_OPT_KEY_ROW_PER_PAGE: &str = "row_per_page";
 pub const FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD: &str = "row_avg_depth_threshold";
+// Options of the parquet writer, not work in native format.
+/// The zstd compression level, from 1 to 22.
+pub const FUSE_OPT_KEY_COMPRESSION_LEVEL: &str = "compression_level";
+/// Whether to use dictionary encoding for the columns.
+pub const FUSE_OPT_KEY_ENABLE_DICTIONARY: &str = "enable_dictionary";
+/// Max size in bytes of the data pages.
+pub const FUSE_OPT_KEY_PAGE_SIZE: &str = "page_size";
+/// The columns to write parquet bloom filters for, separated by commas.
+pub const FUSE_OPT_KEY_PARQUET_BLOOM_FILTER_COLUMNS: &str = "parquet_bloom_filter_columns";
+/// When enabled, the Variant columns are re-encoded with sorted object keys when the blocks
+/// are compacted, and are written with dictionary encoding, so repeated documents are stored once per block.
+pub const FUSE_OPT_KEY_COMPACT_VARIANT: &str = "compact_variant";
 
 pub const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
 pub const FUSE_TBL_BLOCK_INDEX_PREFIX: &str = "_i";
@@ -27,6 +39,7 @@ pub const FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX: &str = "_ts";
 pub const FUSE_TBL_LAST_SNAPSHOT_HINT: &str = "last_snapshot_location_hint";
 pub const FUSE_TBL_VIRTUAL_BLOCK_PREFIX: &str = "_vb";
 pub const FUSE_TBL_AGG_INDEX_PREFIX: &str = "_i_a";
+pub const FUSE_TBL_DELETION_VECTOR_PREFIX: &str = "_dv";
 
 pub const DEFAULT_BLOCK_PER_SEGMENT: usize = 1000;
 pub const DEFAULT_ROW_PER_PAGE: usize = 131072;
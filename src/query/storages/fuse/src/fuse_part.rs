@@ -43,6 +43,12 @@ pub struct FusePartInfo {
 
     pub sort_min_max: Option<(Scalar, Scalar)>,
     pub block_meta_index: Option<BlockMetaIndex>,
+    /// location of the deletion vector of the block, if any.
+    pub deletion_vector_location: Option<String>,
+    /// checksums of the columns of the block, keyed by column id.
+    pub columns_checksum: HashMap<ColumnId, u32>,
+    /// version of the table data key the block is encrypted with, if any.
+    pub encryption_key_version: Option<u32>,
 }
 
 #[typetag::serde(name = "fuse")]
@@ -65,6 +71,7 @@ impl PartInfo for FusePartInfo {
 }
 
 impl FusePartInfo {
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         location: String,
         rows_count: u64,
@@ -73,6 +80,9 @@ impl FusePartInfo {
         sort_min_max: Option<(Scalar, Scalar)>,
         block_meta_index: Option<BlockMetaIndex>,
         create_on: Option<DateTime<Utc>>,
+        deletion_vector_location: Option<String>,
+        columns_checksum: HashMap<ColumnId, u32>,
+        encryption_key_version: Option<u32>,
     ) -> Arc<Box<dyn PartInfo>> {
         Arc::new(Box::new(FusePartInfo {
             location,
@@ -82,6 +92,9 @@ impl FusePartInfo {
             compression,
             sort_min_max,
             block_meta_index,
+            deletion_vector_location,
+            columns_checksum,
+            encryption_key_version,
         }))
     }
 
@@ -36,6 +36,7 @@ use common_expression::BlockThresholds;
 use common_expression::ColumnId;
 use common_expression::FieldIndex;
 use common_expression::RemoteExpr;
+use common_functions::scalars::Tokenizer;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use common_meta_app::schema::DatabaseType;
@@ -62,17 +63,26 @@ use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 use storages_common_table_meta::meta::Versioned;
 use storages_common_table_meta::table::table_storage_prefix;
+use storages_common_table_meta::table::ClusterType;
 use storages_common_table_meta::table::TableCompression;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
+use storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_ENCRYPTION_KEYS;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_TOKENIZER;
 use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
+use storages_common_table_meta::table::OPT_KEY_STORAGE_PROFILE;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use uuid::Uuid;
 
 use crate::io::MetaReaders;
+use crate::io::TableEncryption;
 use crate::io::TableMetaLocationGenerator;
 use crate::io::WriteSettings;
 use crate::pipelines::Pipeline;
@@ -85,6 +95,11 @@ use crate::DEFAULT_ROW_PER_PAGE;
 use crate::DEFAULT_ROW_PER_PAGE_FOR_BLOCKING;
 use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use crate::FUSE_OPT_KEY_COMPRESSION_LEVEL;
+use crate::FUSE_OPT_KEY_COMPACT_VARIANT;
+use crate::FUSE_OPT_KEY_ENABLE_DICTIONARY;
+use crate::FUSE_OPT_KEY_PAGE_SIZE;
+use crate::FUSE_OPT_KEY_PARQUET_BLOOM_FILTER_COLUMNS;
 use crate::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use crate::FUSE_OPT_KEY_ROW_PER_PAGE;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
@@ -95,9 +110,15 @@ pub struct FuseTable {
     pub(crate) meta_location_generator: TableMetaLocationGenerator,
 
     pub(crate) cluster_key_meta: Option<ClusterKey>,
+    pub(crate) cluster_type: ClusterType,
+    pub(crate) partition_key: Option<String>,
     pub(crate) storage_format: FuseStorageFormat,
     pub(crate) table_compression: TableCompression,
     pub(crate) bloom_index_cols: BloomIndexColumns,
+    pub(crate) inverted_index_cols: BloomIndexColumns,
+    pub(crate) inverted_index_tokenizer: Tokenizer,
+    pub(crate) parquet_bloom_filter_cols: BloomIndexColumns,
+    pub(crate) encryption: Option<Arc<TableEncryption>>,
 
     pub(crate) operator: Operator,
     pub(crate) data_metrics: Arc<StorageMetrics>,
@@ -111,6 +132,12 @@ impl FuseTable {
     pub fn do_create(table_info: TableInfo) -> Result<Box<FuseTable>> {
         let storage_prefix = Self::parse_storage_prefix(&table_info)?;
         let cluster_key_meta = table_info.meta.cluster_key();
+        let cluster_type = table_info
+            .options()
+            .get(OPT_KEY_CLUSTER_TYPE)
+            .and_then(|s| s.parse::<ClusterType>().ok())
+            .unwrap_or_default();
+        let partition_key = table_info.options().get(OPT_KEY_PARTITION_BY).cloned();
 
         let mut operator = match table_info.db_type.clone() {
             DatabaseType::ShareDB(share_ident) => create_share_table_operator(
@@ -124,6 +151,13 @@ impl FuseTable {
                 let storage_params = table_info.meta.storage_params.clone();
                 match storage_params {
                     Some(sp) => Ok(init_operator(&sp)?),
+                    // Never fall back to the global storage for a table bound to a storage profile.
+                    None if table_info.options().contains_key(OPT_KEY_STORAGE_PROFILE) => {
+                        Err(ErrorCode::IllegalStorageProfile(format!(
+                            "storage profile of table {} is not resolved",
+                            table_info.name
+                        )))
+                    }
                     None => Ok(DataOperator::instance().operator()),
                 }
             }
@@ -150,6 +184,32 @@ impl FuseTable {
             .and_then(|s| s.parse::<BloomIndexColumns>().ok())
             .unwrap_or(BloomIndexColumns::All);
 
+        // Unlike bloom index, the inverted index is only built for the specified columns.
+        let inverted_index_cols = table_info
+            .options()
+            .get(OPT_KEY_INVERTED_INDEX_COLUMNS)
+            .and_then(|s| s.parse::<BloomIndexColumns>().ok())
+            .unwrap_or(BloomIndexColumns::None);
+
+        let inverted_index_tokenizer = table_info
+            .options()
+            .get(OPT_KEY_INVERTED_INDEX_TOKENIZER)
+            .and_then(|s| s.parse::<Tokenizer>().ok())
+            .unwrap_or_default();
+
+        // Parquet bloom filters are only written for the specified columns.
+        let parquet_bloom_filter_cols = table_info
+            .options()
+            .get(FUSE_OPT_KEY_PARQUET_BLOOM_FILTER_COLUMNS)
+            .and_then(|s| s.parse::<BloomIndexColumns>().ok())
+            .unwrap_or(BloomIndexColumns::None);
+
+        let encryption = table_info
+            .options()
+            .get(OPT_KEY_ENCRYPTION_KEYS)
+            .map(|s| TableEncryption::parse(s).map(Arc::new))
+            .transpose()?;
+
         let part_prefix = table_info.meta.part_prefix.clone();
 
         let meta_location_generator =
@@ -159,7 +219,13 @@ impl FuseTable {
             table_info,
             meta_location_generator,
             cluster_key_meta,
+            cluster_type,
+            partition_key,
             bloom_index_cols,
+            inverted_index_cols,
+            inverted_index_tokenizer,
+            parquet_bloom_filter_cols,
+            encryption,
             operator,
             data_metrics,
             storage_format: FuseStorageFormat::from_str(storage_format.as_str())?,
@@ -179,6 +245,11 @@ impl FuseTable {
         matches!(self.storage_format, FuseStorageFormat::Native)
     }
 
+    /// The data keys of the table if its blocks are encrypted.
+    pub fn encryption(&self) -> Option<Arc<TableEncryption>> {
+        self.encryption.clone()
+    }
+
     pub fn meta_location_generator(&self) -> &TableMetaLocationGenerator {
         &self.meta_location_generator
     }
@@ -192,12 +263,30 @@ impl FuseTable {
         let max_page_size = self.get_option(FUSE_OPT_KEY_ROW_PER_PAGE, default_rows_per_page);
         let block_per_seg =
             self.get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT);
+        let compression_level = self
+            .table_info
+            .options()
+            .get(FUSE_OPT_KEY_COMPRESSION_LEVEL)
+            .and_then(|s| s.parse::<i32>().ok());
+        let enable_dictionary = self.get_option(FUSE_OPT_KEY_ENABLE_DICTIONARY, false);
+        let data_page_size = self
+            .table_info
+            .options()
+            .get(FUSE_OPT_KEY_PAGE_SIZE)
+            .and_then(|s| s.parse::<usize>().ok());
+        let compact_variant = self.get_option(FUSE_OPT_KEY_COMPACT_VARIANT, false);
 
         WriteSettings {
             storage_format: self.storage_format,
             table_compression: self.table_compression,
             max_page_size,
             block_per_seg,
+            compression_level,
+            enable_dictionary,
+            data_page_size,
+            parquet_bloom_filter_cols: self.parquet_bloom_filter_cols.clone(),
+            compact_variant,
+            encryption: self.encryption.clone(),
         }
     }
 
@@ -343,6 +432,11 @@ impl FuseTable {
         self.table_info.meta.options.contains_key("TRANSIENT")
     }
 
+    /// Whether a stream has been created on this table.
+    pub fn change_tracking_enabled(&self) -> bool {
+        self.table_info.meta.options.contains_key(OPT_KEY_CHANGE_TRACKING)
+    }
+
     pub fn cluster_key_str(&self) -> Option<&String> {
         self.cluster_key_meta.as_ref().map(|(_, key)| key)
     }
@@ -354,6 +448,24 @@ impl FuseTable {
     pub fn bloom_index_cols(&self) -> BloomIndexColumns {
         self.bloom_index_cols.clone()
     }
+
+    pub fn is_partitioned(&self) -> bool {
+        self.partition_key.is_some()
+    }
+
+    /// The partition key of the table, the column references are the names of the columns.
+    pub fn partition_key(&self, ctx: Arc<dyn TableContext>) -> Result<Option<RemoteExpr<String>>> {
+        let partition_key = match &self.partition_key {
+            Some(partition_key) => partition_key,
+            None => return Ok(None),
+        };
+        let table_meta = Arc::new(self.clone());
+        let exprs = parse_exprs(ctx, table_meta.clone(), partition_key)?;
+        let partition_key = exprs[0]
+            .project_column_ref(|index| table_meta.schema().field(*index).name().to_string())
+            .as_remote_expr();
+        Ok(Some(partition_key))
+    }
 }
 
 #[async_trait::async_trait]
@@ -428,13 +540,15 @@ impl Table for FuseTable {
         let prev_statistics_location = prev
             .as_ref()
             .and_then(|v| v.table_statistics_location.clone());
+        let prev_rows_changed_since_analyze =
+            prev.as_ref().map_or(0, |v| v.rows_changed_since_analyze);
         let (summary, segments) = if let Some(v) = prev {
             (v.summary.clone(), v.segments.clone())
         } else {
             (FuseStatistics::default(), vec![])
         };
 
-        let new_snapshot = TableSnapshot::new(
+        let mut new_snapshot = TableSnapshot::new(
             Uuid::new_v4(),
             &prev_timestamp,
             prev_snapshot_id,
@@ -444,6 +558,7 @@ impl Table for FuseTable {
             cluster_key_meta,
             prev_statistics_location,
         );
+        new_snapshot.rows_changed_since_analyze = prev_rows_changed_since_analyze;
 
         let mut table_info = self.table_info.clone();
         table_info.meta = new_table_meta;
@@ -478,6 +593,8 @@ impl Table for FuseTable {
         let prev_statistics_location = prev
             .as_ref()
             .and_then(|v| v.table_statistics_location.clone());
+        let prev_rows_changed_since_analyze =
+            prev.as_ref().map_or(0, |v| v.rows_changed_since_analyze);
         let prev_snapshot_id = prev.as_ref().map(|v| (v.snapshot_id, prev_version));
         let (summary, segments) = if let Some(v) = prev {
             (v.summary.clone(), v.segments.clone())
@@ -485,7 +602,7 @@ impl Table for FuseTable {
             (FuseStatistics::default(), vec![])
         };
 
-        let new_snapshot = TableSnapshot::new(
+        let mut new_snapshot = TableSnapshot::new(
             Uuid::new_v4(),
             &prev_timestamp,
             prev_snapshot_id,
@@ -495,6 +612,7 @@ impl Table for FuseTable {
             None,
             prev_statistics_location,
         );
+        new_snapshot.rows_changed_since_analyze = prev_rows_changed_since_analyze;
 
         let mut table_info = self.table_info.clone();
         table_info.meta = new_table_meta;
@@ -688,7 +806,7 @@ impl Table for FuseTable {
         limit: Option<usize>,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
-        self.do_compact(ctx, target, limit, pipeline).await
+        self.do_compact(ctx, target, limit, None, pipeline).await
     }
 
     #[async_backtrace::framed]
@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnId;
+use rand::Rng;
+use storages_common_table_meta::meta::ColumnMeta;
+
+/// One out of every `CHECKSUM_SAMPLE_RATIO` column reads is verified in `sample` mode.
+const CHECKSUM_SAMPLE_RATIO: u32 = 16;
+
+/// How the checksums of block data are verified on read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChecksumVerification {
+    Off,
+    Sample,
+    Always,
+}
+
+impl ChecksumVerification {
+    pub fn should_verify(&self) -> bool {
+        match self {
+            ChecksumVerification::Off => false,
+            ChecksumVerification::Sample => rand::thread_rng().gen_ratio(1, CHECKSUM_SAMPLE_RATIO),
+            ChecksumVerification::Always => true,
+        }
+    }
+}
+
+impl TryFrom<&str> for ChecksumVerification {
+    type Error = ErrorCode;
+
+    fn try_from(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_str() {
+            "off" => Ok(ChecksumVerification::Off),
+            "sample" => Ok(ChecksumVerification::Sample),
+            "always" => Ok(ChecksumVerification::Always),
+            other => Err(ErrorCode::BadArguments(format!(
+                "invalid data_checksum_verification: {}, expect one of off, sample, always",
+                other
+            ))),
+        }
+    }
+}
+
+/// Computes the checksum of each column of a serialized block.
+pub fn gen_columns_checksum(
+    block_data: &[u8],
+    col_metas: &HashMap<ColumnId, ColumnMeta>,
+) -> HashMap<ColumnId, u32> {
+    col_metas
+        .iter()
+        .map(|(column_id, column_meta)| {
+            let (offset, len) = column_meta.offset_length();
+            let data = &block_data[offset as usize..(offset + len) as usize];
+            (*column_id, crc32fast::hash(data))
+        })
+        .collect()
+}
+
+pub fn verify_column_checksum(
+    location: &str,
+    column_id: ColumnId,
+    data: &[u8],
+    expected: u32,
+) -> Result<()> {
+    let actual = crc32fast::hash(data);
+    if actual != expected {
+        return Err(ErrorCode::StorageDataCorrupted(format!(
+            "checksum mismatch of column {} in block {}, expected {}, got {}",
+            column_id, location, expected, actual
+        )));
+    }
+    Ok(())
+}
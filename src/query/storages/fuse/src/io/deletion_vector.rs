@@ -0,0 +1,71 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::Result;
+use common_expression::DataBlock;
+use opendal::Operator;
+use roaring::RoaringBitmap;
+
+/// Version of the deletion vector file, a serialized roaring bitmap.
+pub const DELETION_VECTOR_VERSION: u64 = 0;
+
+/// The deletion vector of a block, marks the offsets of the rows which are deleted
+/// from the block without rewriting it.
+pub struct DeletionVector;
+
+impl DeletionVector {
+    #[async_backtrace::framed]
+    pub async fn read(dal: &Operator, location: &str) -> Result<RoaringBitmap> {
+        let data = dal.read(location).await?;
+        Ok(RoaringBitmap::deserialize_from(data.as_slice())?)
+    }
+
+    pub fn blocking_read(dal: &Operator, location: &str) -> Result<RoaringBitmap> {
+        let data = dal.blocking().read(location)?;
+        Ok(RoaringBitmap::deserialize_from(data.as_slice())?)
+    }
+
+    pub fn serialize(deletion_vector: &RoaringBitmap) -> Result<Vec<u8>> {
+        let mut data = Vec::with_capacity(deletion_vector.serialized_size());
+        deletion_vector.serialize_into(&mut data)?;
+        Ok(data)
+    }
+
+    /// Remove the deleted rows from the block.
+    ///
+    /// Returns the block of the remaining rows, along with their offsets in the original block.
+    pub fn apply(
+        block: DataBlock,
+        deletion_vector: &RoaringBitmap,
+    ) -> Result<(DataBlock, Vec<usize>)> {
+        let num_rows = block.num_rows();
+        let mut filter = MutableBitmap::with_capacity(num_rows);
+        let mut offsets = Vec::with_capacity(num_rows);
+        for offset in 0..num_rows {
+            let deleted = deletion_vector.contains(offset as u32);
+            filter.push(!deleted);
+            if !deleted {
+                offsets.push(offset);
+            }
+        }
+        if offsets.len() == num_rows {
+            return Ok((block, offsets));
+        }
+
+        let filter: Bitmap = filter.into();
+        Ok((block.filter_with_bitmap(&filter)?, offsets))
+    }
+}
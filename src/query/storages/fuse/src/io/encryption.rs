@@ -0,0 +1,406 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Envelope encryption of the blocks of fuse tables.
+//!
+//! Each encrypted table has its own data keys, which are generated and wrapped by the KMS of
+//! the cluster (`data_encryption_kms_*` of the query config) and kept wrapped in the table
+//! options. Rotating the keys of a table adds a new data key which is used by the blocks
+//! written afterwards, the blocks written before keep being readable with the key they were
+//! written with, which is recorded in their block meta.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::ops::Range;
+use std::sync::Arc;
+
+use common_base::base::GlobalInstance;
+use common_base::runtime::GlobalIORuntime;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use parking_lot::RwLock;
+use ring::aead::Aad;
+use ring::aead::LessSafeKey;
+use ring::aead::Nonce;
+use ring::aead::UnboundKey;
+use ring::aead::AES_256_GCM;
+use ring::aead::NONCE_LEN;
+use ring::hkdf::Prk;
+use ring::hkdf::HKDF_SHA256;
+
+use crate::io::kms::KmsClient;
+use crate::io::kms::LocalKms;
+use crate::io::kms::VaultKms;
+
+/// The only supported value of the `encryption` table option.
+pub const ENCRYPTION_ALGORITHM: &str = "aes256";
+
+/// Block data is encrypted in chunks of this size, each chunk is followed by its tag, so that
+/// any range of a block can be authenticated and decrypted without reading the whole block.
+const CHUNK_SIZE: u64 = 64 * 1024;
+
+const TAG_LEN: u64 = 16;
+
+const ENCRYPTED_CHUNK_SIZE: u64 = CHUNK_SIZE + TAG_LEN;
+
+/// Generates and unwraps the data keys of the encrypted tables through the KMS.
+pub struct KeyManager {
+    kms: Option<Arc<dyn KmsClient>>,
+    /// The unwrapped data keys, by their wrapped keys, so that the KMS is only requested
+    /// the first time a data key is used.
+    data_keys: RwLock<HashMap<String, Arc<Prk>>>,
+}
+
+impl KeyManager {
+    /// Table encryption is disabled if neither `kms_endpoint` nor `kms_local_key` is set.
+    pub fn init(
+        kms_endpoint: &str,
+        kms_key_name: &str,
+        kms_token: &str,
+        kms_local_key: &str,
+    ) -> Result<()> {
+        let kms: Option<Arc<dyn KmsClient>> = match (kms_endpoint, kms_local_key) {
+            ("", "") => None,
+            (endpoint, "") => Some(Arc::new(VaultKms::create(
+                endpoint,
+                kms_key_name,
+                kms_token,
+            )?)),
+            ("", local_key) => Some(Arc::new(LocalKms::create(local_key)?)),
+            _ => {
+                return Err(ErrorCode::InvalidConfig(
+                    "Only one of data_encryption_kms_endpoint and data_encryption_kms_local_key \
+                     can be set",
+                ));
+            }
+        };
+
+        GlobalInstance::set(Arc::new(KeyManager {
+            kms,
+            data_keys: RwLock::new(HashMap::new()),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<KeyManager> {
+        GlobalInstance::get()
+    }
+
+    fn kms(&self) -> Result<&Arc<dyn KmsClient>> {
+        self.kms.as_ref().ok_or_else(|| {
+            ErrorCode::StorageEncryptionError(
+                "Table encryption requires data_encryption_kms_endpoint or \
+                 data_encryption_kms_local_key of the query to be configured",
+            )
+        })
+    }
+
+    /// Generates a data key, returns it wrapped by the KMS.
+    #[async_backtrace::framed]
+    pub async fn generate_data_key(&self) -> Result<String> {
+        let data_key = self.kms()?.generate_data_key().await?;
+        let prk = Arc::new(Prk::new_less_safe(HKDF_SHA256, &data_key.plaintext));
+        self.data_keys.write().insert(data_key.wrapped.clone(), prk);
+        Ok(data_key.wrapped)
+    }
+
+    #[async_backtrace::framed]
+    pub async fn unwrap_data_key(&self, wrapped: &str) -> Result<Arc<Prk>> {
+        let cached = self.data_keys.read().get(wrapped).cloned();
+        if let Some(prk) = cached {
+            return Ok(prk);
+        }
+
+        let key = self.kms()?.decrypt_data_key(wrapped).await?;
+        let prk = Arc::new(Prk::new_less_safe(HKDF_SHA256, &key));
+        self.data_keys
+            .write()
+            .insert(wrapped.to_string(), prk.clone());
+        Ok(prk)
+    }
+}
+
+/// The wrapped data keys of an encrypted table, by version.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TableEncryption {
+    keys: BTreeMap<u32, String>,
+}
+
+impl TableEncryption {
+    /// Creates the encryption of a new table, with a single data key of version 1.
+    #[async_backtrace::framed]
+    pub async fn create() -> Result<Self> {
+        let mut keys = BTreeMap::new();
+        keys.insert(1, KeyManager::instance().generate_data_key().await?);
+        Ok(TableEncryption { keys })
+    }
+
+    /// Parses the value of the `encryption_keys` table option.
+    pub fn parse(option: &str) -> Result<Self> {
+        let mut keys = BTreeMap::new();
+        for item in option.split(',') {
+            let (version, key) = item
+                .split_once(':')
+                .and_then(|(version, key)| Some((version.trim().parse::<u32>().ok()?, key)))
+                .ok_or_else(|| {
+                    ErrorCode::StorageEncryptionError(format!(
+                        "Invalid encryption keys of table: {}",
+                        item
+                    ))
+                })?;
+            keys.insert(version, key.trim().to_string());
+        }
+        if keys.is_empty() {
+            return Err(ErrorCode::StorageEncryptionError(
+                "Encrypted table has no data key",
+            ));
+        }
+        Ok(TableEncryption { keys })
+    }
+
+    /// Returns a copy with a new data key, which is used by the blocks written afterwards.
+    #[async_backtrace::framed]
+    pub async fn rotate(&self) -> Result<Self> {
+        let mut keys = self.keys.clone();
+        keys.insert(
+            self.current_version() + 1,
+            KeyManager::instance().generate_data_key().await?,
+        );
+        Ok(TableEncryption { keys })
+    }
+
+    pub fn current_version(&self) -> u32 {
+        self.keys.keys().next_back().copied().unwrap_or_default()
+    }
+
+    pub fn to_option(&self) -> String {
+        self.keys
+            .iter()
+            .map(|(version, key)| format!("{}:{}", version, key))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    #[async_backtrace::framed]
+    pub async fn cipher(&self, version: u32) -> Result<BlockCipher> {
+        let wrapped = self.keys.get(&version).ok_or_else(|| {
+            ErrorCode::StorageEncryptionError(format!(
+                "Data key of version {} not found in table",
+                version
+            ))
+        })?;
+        Ok(BlockCipher {
+            version,
+            key: KeyManager::instance().unwrap_data_key(wrapped).await?,
+        })
+    }
+
+    /// Returns the cipher of `version` in the sync processors, the data key is only
+    /// requested from the KMS the first time it's used.
+    pub fn blocking_cipher(self: &Arc<Self>, version: u32) -> Result<BlockCipher> {
+        let encryption = self.clone();
+        GlobalIORuntime::instance().block_on(async move { encryption.cipher(version).await })
+    }
+
+    pub fn blocking_current_cipher(self: &Arc<Self>) -> Result<BlockCipher> {
+        self.blocking_cipher(self.current_version())
+    }
+}
+
+impl Debug for TableEncryption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TableEncryption")
+            .field("versions", &self.keys.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// Encrypts and decrypts block data with a data key.
+///
+/// Each block is encrypted with AES-256-GCM under its own key, derived from the data key and
+/// the block location with HKDF, block locations are unique and never reused by another
+/// block. The plain block is split into chunks of `CHUNK_SIZE`, the last one padded with
+/// zeros, each chunk is sealed with its index as the nonce and followed by its tag. As all the
+/// encrypted chunks have the same size, the offsets of the column metas map to the encrypted
+/// block without knowing its size, and a column can be read and authenticated on its own.
+#[derive(Clone)]
+pub struct BlockCipher {
+    pub version: u32,
+    key: Arc<Prk>,
+}
+
+impl BlockCipher {
+    fn block_key(&self, location: &str) -> Result<LessSafeKey> {
+        let info = [location.as_bytes()];
+        let okm = self.key.expand(&info, &AES_256_GCM).map_err(|_| {
+            ErrorCode::StorageEncryptionError(format!(
+                "Failed to derive the key of block {}",
+                location
+            ))
+        })?;
+        Ok(LessSafeKey::new(UnboundKey::from(okm)))
+    }
+
+    fn nonce(chunk: u64) -> Nonce {
+        let mut nonce = [0u8; NONCE_LEN];
+        nonce[NONCE_LEN - 8..].copy_from_slice(&chunk.to_be_bytes());
+        Nonce::assume_unique_for_key(nonce)
+    }
+
+    /// Encrypts the plain `data` of the block at `location`.
+    pub fn encrypt(&self, location: &str, data: &[u8]) -> Result<Vec<u8>> {
+        let key = self.block_key(location)?;
+        let num_chunks = (data.len() as u64 + CHUNK_SIZE - 1) / CHUNK_SIZE;
+        let mut encrypted = Vec::with_capacity((num_chunks * ENCRYPTED_CHUNK_SIZE) as usize);
+        for (idx, chunk) in data.chunks(CHUNK_SIZE as usize).enumerate() {
+            let start = encrypted.len();
+            encrypted.extend_from_slice(chunk);
+            encrypted.resize(start + CHUNK_SIZE as usize, 0);
+            let tag = key
+                .seal_in_place_separate_tag(
+                    Self::nonce(idx as u64),
+                    Aad::empty(),
+                    &mut encrypted[start..],
+                )
+                .map_err(|_| {
+                    ErrorCode::StorageEncryptionError(format!(
+                        "Failed to encrypt block {}",
+                        location
+                    ))
+                })?;
+            encrypted.extend_from_slice(tag.as_ref());
+        }
+        Ok(encrypted)
+    }
+
+    /// Returns the range of the encrypted block holding the `range` of the plain block.
+    pub fn encrypted_range(&self, range: &Range<u64>) -> Range<u64> {
+        let first_chunk = range.start / CHUNK_SIZE;
+        let end_chunk = (range.end + CHUNK_SIZE - 1) / CHUNK_SIZE;
+        first_chunk * ENCRYPTED_CHUNK_SIZE..end_chunk * ENCRYPTED_CHUNK_SIZE
+    }
+
+    /// Authenticates and decrypts `data`, read from the `encrypted_range` of `range` of the
+    /// block at `location`, returns the plain data of `range`.
+    pub fn decrypt_range(
+        &self,
+        location: &str,
+        range: &Range<u64>,
+        data: &[u8],
+    ) -> Result<Vec<u8>> {
+        let encrypted_range = self.encrypted_range(range);
+        if data.len() as u64 != encrypted_range.end - encrypted_range.start {
+            return Err(ErrorCode::StorageEncryptionError(format!(
+                "Encrypted block {} is truncated",
+                location
+            )));
+        }
+
+        let key = self.block_key(location)?;
+        let first_chunk = range.start / CHUNK_SIZE;
+        let mut plain = Vec::with_capacity(data.len());
+        for (idx, chunk) in data.chunks(ENCRYPTED_CHUNK_SIZE as usize).enumerate() {
+            let start = plain.len();
+            plain.extend_from_slice(chunk);
+            key.open_in_place(
+                Self::nonce(first_chunk + idx as u64),
+                Aad::empty(),
+                &mut plain[start..],
+            )
+            .map_err(|_| {
+                ErrorCode::StorageEncryptionError(format!(
+                    "Failed to authenticate block {}, it's corrupted or has been tampered with",
+                    location
+                ))
+            })?;
+            // drops the tag.
+            plain.truncate(start + CHUNK_SIZE as usize);
+        }
+
+        let offset = (range.start - first_chunk * CHUNK_SIZE) as usize;
+        plain.truncate(offset + (range.end - range.start) as usize);
+        plain.drain(..offset);
+        Ok(plain)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_cipher() -> BlockCipher {
+        BlockCipher {
+            version: 1,
+            key: Arc::new(Prk::new_less_safe(HKDF_SHA256, &[7u8; 32])),
+        }
+    }
+
+    #[test]
+    fn test_block_cipher_ranged_decryption() -> Result<()> {
+        let cipher = test_cipher();
+        let location = "1/2/_b/0000_v2.parquet";
+        let plain = (0..3 * CHUNK_SIZE + 100)
+            .map(|i| (i % 251) as u8)
+            .collect::<Vec<_>>();
+
+        let encrypted = cipher.encrypt(location, &plain)?;
+        assert_eq!(encrypted.len() as u64, 4 * ENCRYPTED_CHUNK_SIZE);
+
+        // ranges inside a chunk and across chunks can be decrypted on their own.
+        for range in [
+            0..10,
+            100..200,
+            CHUNK_SIZE - 5..2 * CHUNK_SIZE + 5,
+            0..plain.len() as u64,
+        ] {
+            let encrypted_range = cipher.encrypted_range(&range);
+            let data = &encrypted[encrypted_range.start as usize..encrypted_range.end as usize];
+            let decrypted = cipher.decrypt_range(location, &range, data)?;
+            assert_eq!(decrypted, plain[range.start as usize..range.end as usize]);
+        }
+
+        // the same data is encrypted differently in another block.
+        let other = cipher.encrypt("1/2/_b/0001_v2.parquet", &plain)?;
+        assert_ne!(other, encrypted);
+        Ok(())
+    }
+
+    #[test]
+    fn test_block_cipher_authentication() -> Result<()> {
+        let cipher = test_cipher();
+        let location = "1/2/_b/0000_v2.parquet";
+        let plain = vec![1u8; 2 * CHUNK_SIZE as usize];
+        let encrypted = cipher.encrypt(location, &plain)?;
+        let range = 0..plain.len() as u64;
+
+        let mut tampered = encrypted.clone();
+        tampered[10] ^= 1;
+        assert!(cipher.decrypt_range(location, &range, &tampered).is_err());
+
+        // chunks can not be swapped.
+        let mut swapped = encrypted[ENCRYPTED_CHUNK_SIZE as usize..].to_vec();
+        swapped.extend_from_slice(&encrypted[..ENCRYPTED_CHUNK_SIZE as usize]);
+        assert!(cipher.decrypt_range(location, &range, &swapped).is_err());
+
+        // nor the block be moved to another location.
+        assert!(
+            cipher
+                .decrypt_range("1/2/_b/0001_v2.parquet", &range, &encrypted)
+                .is_err()
+        );
+        Ok(())
+    }
+}
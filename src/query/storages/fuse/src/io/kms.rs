@@ -0,0 +1,228 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Clients of the key management services generating and unwrapping the data keys of the
+//! encrypted tables.
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use ring::aead::Aad;
+use ring::aead::LessSafeKey;
+use ring::aead::Nonce;
+use ring::aead::UnboundKey;
+use ring::aead::AES_256_GCM;
+use ring::aead::NONCE_LEN;
+use ring::rand::SecureRandom;
+use ring::rand::SystemRandom;
+use serde::Deserialize;
+use serde_json::json;
+
+pub const DATA_KEY_LEN: usize = 32;
+
+/// A data key generated by a KMS.
+pub struct DataKey {
+    /// The plain key, which is only kept in memory.
+    pub plaintext: Vec<u8>,
+    /// The key wrapped by the KMS, which is persisted in the table options.
+    pub wrapped: String,
+}
+
+#[async_trait::async_trait]
+pub trait KmsClient: Send + Sync {
+    /// Generates a random data key of `DATA_KEY_LEN` bytes.
+    async fn generate_data_key(&self) -> Result<DataKey>;
+
+    /// Returns the plain key of a data key wrapped by `generate_data_key`.
+    async fn decrypt_data_key(&self, wrapped: &str) -> Result<Vec<u8>>;
+}
+
+/// Client of the transit secrets engine of HashiCorp Vault (or of a KMS serving its API),
+/// the key wrapping the data keys never leaves the KMS.
+pub struct VaultKms {
+    client: reqwest::Client,
+    endpoint: String,
+    key_name: String,
+    token: String,
+}
+
+#[derive(Deserialize)]
+struct VaultResponse {
+    data: VaultData,
+}
+
+#[derive(Deserialize)]
+struct VaultData {
+    plaintext: String,
+    #[serde(default)]
+    ciphertext: String,
+}
+
+impl VaultKms {
+    pub fn create(endpoint: &str, key_name: &str, token: &str) -> Result<Self> {
+        if key_name.is_empty() {
+            return Err(ErrorCode::InvalidConfig(
+                "data_encryption_kms_key_name is required by data_encryption_kms_endpoint",
+            ));
+        }
+        Ok(VaultKms {
+            client: reqwest::Client::new(),
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            key_name: key_name.to_string(),
+            token: token.to_string(),
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn post(&self, action: &str, body: serde_json::Value) -> Result<VaultData> {
+        let url = format!("{}/v1/transit/{}/{}", self.endpoint, action, self.key_name);
+        let request_failure = |e: reqwest::Error| {
+            ErrorCode::StorageEncryptionError(format!("Failed to request KMS {}: {}", url, e))
+        };
+        let response = self
+            .client
+            .post(&url)
+            .header("X-Vault-Token", &self.token)
+            .json(&body)
+            .send()
+            .await
+            .map_err(request_failure)?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(ErrorCode::StorageEncryptionError(format!(
+                "KMS {} responded {}: {}",
+                url, status, body
+            )));
+        }
+        let response = response
+            .json::<VaultResponse>()
+            .await
+            .map_err(request_failure)?;
+        Ok(response.data)
+    }
+}
+
+#[async_trait::async_trait]
+impl KmsClient for VaultKms {
+    #[async_backtrace::framed]
+    async fn generate_data_key(&self) -> Result<DataKey> {
+        let data = self
+            .post("datakey/plaintext", json!({ "bits": DATA_KEY_LEN * 8 }))
+            .await?;
+        Ok(DataKey {
+            plaintext: decode_plain_key(&data.plaintext)?,
+            wrapped: data.ciphertext,
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn decrypt_data_key(&self, wrapped: &str) -> Result<Vec<u8>> {
+        let data = self
+            .post("decrypt", json!({ "ciphertext": wrapped }))
+            .await?;
+        decode_plain_key(&data.plaintext)
+    }
+}
+
+fn decode_plain_key(plaintext: &str) -> Result<Vec<u8>> {
+    match BASE64_STANDARD.decode(plaintext) {
+        Ok(key) if key.len() == DATA_KEY_LEN => Ok(key),
+        _ => Err(ErrorCode::StorageEncryptionError(
+            "KMS returned an invalid data key",
+        )),
+    }
+}
+
+/// Wraps the data keys with a key of the query config, for deployments and tests without a
+/// KMS. The key must be `DATA_KEY_LEN` random bytes, encoded in base64.
+pub struct LocalKms {
+    key: LessSafeKey,
+    rng: SystemRandom,
+}
+
+impl LocalKms {
+    pub fn create(key: &str) -> Result<Self> {
+        let invalid = || {
+            ErrorCode::InvalidConfig(format!(
+                "data_encryption_kms_local_key must be {} random bytes encoded in base64",
+                DATA_KEY_LEN
+            ))
+        };
+        let key = BASE64_STANDARD.decode(key).map_err(|_| invalid())?;
+        if key.len() != DATA_KEY_LEN {
+            return Err(invalid());
+        }
+        let key = UnboundKey::new(&AES_256_GCM, &key).map_err(|_| invalid())?;
+        Ok(LocalKms {
+            key: LessSafeKey::new(key),
+            rng: SystemRandom::new(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl KmsClient for LocalKms {
+    /// The wrapped key is the base64 of `nonce | encrypted key | tag`.
+    #[async_backtrace::framed]
+    async fn generate_data_key(&self) -> Result<DataKey> {
+        let mut nonce = [0u8; NONCE_LEN];
+        let mut plaintext = vec![0u8; DATA_KEY_LEN];
+        self.rng
+            .fill(&mut nonce)
+            .and_then(|_| self.rng.fill(&mut plaintext))
+            .map_err(|_| ErrorCode::StorageEncryptionError("Failed to generate data key"))?;
+
+        let mut in_out = plaintext.clone();
+        self.key
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::empty(),
+                &mut in_out,
+            )
+            .map_err(|_| ErrorCode::StorageEncryptionError("Failed to wrap data key"))?;
+
+        let mut wrapped = Vec::with_capacity(NONCE_LEN + in_out.len());
+        wrapped.extend_from_slice(&nonce);
+        wrapped.extend_from_slice(&in_out);
+        Ok(DataKey {
+            plaintext,
+            wrapped: BASE64_STANDARD.encode(wrapped),
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn decrypt_data_key(&self, wrapped: &str) -> Result<Vec<u8>> {
+        let corrupted = || ErrorCode::StorageEncryptionError("Wrapped data key is corrupted");
+        let data = BASE64_STANDARD.decode(wrapped).map_err(|_| corrupted())?;
+        if data.len() < NONCE_LEN {
+            return Err(corrupted());
+        }
+
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce).map_err(|_| corrupted())?;
+        let mut in_out = ciphertext.to_vec();
+        let key = self
+            .key
+            .open_in_place(nonce, Aad::empty(), &mut in_out)
+            .map_err(|_| {
+                ErrorCode::StorageEncryptionError(
+                    "Failed to unwrap data key, data_encryption_kms_local_key may be changed",
+                )
+            })?;
+        Ok(key.to_vec())
+    }
+}
@@ -12,13 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod checksum;
+mod deletion_vector;
+mod encryption;
 mod files;
+mod kms;
 mod locations;
 pub mod read;
 mod segments;
 mod snapshots;
 mod write;
 
+pub use checksum::gen_columns_checksum;
+pub use checksum::verify_column_checksum;
+pub use checksum::ChecksumVerification;
+pub use deletion_vector::DeletionVector;
+pub use deletion_vector::DELETION_VECTOR_VERSION;
+pub use encryption::BlockCipher;
+pub use encryption::KeyManager;
+pub use encryption::TableEncryption;
+pub use encryption::ENCRYPTION_ALGORITHM;
 pub use files::Files;
 pub use locations::TableMetaLocationGenerator;
 pub use read::AggIndexReader;
@@ -64,6 +64,7 @@ impl AggIndexReader {
             agg.projection.clone(),
             ctx.clone(),
             false,
+            None,
         )?;
 
         let func_ctx = ctx.get_function_context()?;
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_arrow::arrow::chunk::Chunk;
@@ -54,6 +55,9 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
+                    HashMap::new(),
+                    None,
                 );
                 let res = self
                     .reader
@@ -105,6 +109,9 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
+                    HashMap::new(),
+                    None,
                 );
                 let res = self
                     .reader
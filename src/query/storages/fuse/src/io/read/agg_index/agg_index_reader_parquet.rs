@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_arrow::arrow::io::parquet::read as pread;
@@ -51,6 +52,9 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
+                    HashMap::new(),
+                    None,
                 );
                 let res = self
                     .reader
@@ -88,7 +92,14 @@ impl AggIndexReader {
                 let columns_meta = build_columns_meta(row_group);
                 let res = self
                     .reader
-                    .read_columns_data_by_merge_io(read_settings, loc, &columns_meta, &None)
+                    .read_columns_data_by_merge_io(
+                        read_settings,
+                        loc,
+                        &columns_meta,
+                        &HashMap::new(),
+                        None,
+                        &None,
+                    )
                     .await
                     .inspect_err(|e| debug!("Read aggregating index `{loc}` failed: {e}"))
                     .ok()?;
@@ -100,6 +111,9 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
+                    HashMap::new(),
+                    None,
                 );
                 Some((part, res))
             }
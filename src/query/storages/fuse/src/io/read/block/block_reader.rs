@@ -35,10 +35,14 @@ use common_storage::ColumnNode;
 use common_storage::ColumnNodes;
 use opendal::Operator;
 
+use crate::io::BlockCipher;
+use crate::io::TableEncryption;
+
 // TODO: make BlockReader as a trait.
 #[derive(Clone)]
 pub struct BlockReader {
     pub(crate) operator: Operator,
+    pub(crate) table_schema: TableSchemaRef,
     pub(crate) projection: Projection,
     pub(crate) projected_schema: TableSchemaRef,
     pub(crate) project_indices: BTreeMap<FieldIndex, (ColumnId, Field, DataType)>,
@@ -46,6 +50,7 @@ pub struct BlockReader {
     pub(crate) parquet_schema_descriptor: SchemaDescriptor,
     pub(crate) default_vals: Vec<Scalar>,
     pub query_internal_columns: bool,
+    pub(crate) encryption: Option<Arc<TableEncryption>>,
 }
 
 fn inner_project_field_default_values(default_vals: &[Scalar], paths: &[usize]) -> Result<Scalar> {
@@ -79,6 +84,7 @@ impl BlockReader {
         projection: Projection,
         ctx: Arc<dyn TableContext>,
         query_internal_columns: bool,
+        encryption: Option<Arc<TableEncryption>>,
     ) -> Result<Arc<BlockReader>> {
         // init projected_schema and default_vals of schema.fields
         let (projected_schema, default_vals) = match projection {
@@ -114,6 +120,27 @@ impl BlockReader {
             }
         };
 
+        Self::build(
+            operator,
+            schema,
+            projection,
+            projected_schema,
+            default_vals,
+            query_internal_columns,
+            encryption,
+        )
+        .map(Arc::new)
+    }
+
+    pub(crate) fn build(
+        operator: Operator,
+        schema: TableSchemaRef,
+        projection: Projection,
+        projected_schema: TableSchemaRef,
+        default_vals: Vec<Scalar>,
+        query_internal_columns: bool,
+        encryption: Option<Arc<TableEncryption>>,
+    ) -> Result<BlockReader> {
         let arrow_schema = schema.to_arrow();
         let parquet_schema_descriptor = to_parquet_schema(&arrow_schema)?;
 
@@ -126,8 +153,9 @@ impl BlockReader {
             .collect();
         let project_indices = Self::build_projection_indices(&project_column_nodes);
 
-        Ok(Arc::new(BlockReader {
+        Ok(BlockReader {
             operator,
+            table_schema: schema,
             projection,
             projected_schema,
             project_indices,
@@ -135,7 +163,8 @@ impl BlockReader {
             parquet_schema_descriptor,
             default_vals,
             query_internal_columns,
-        }))
+            encryption,
+        })
     }
 
     pub fn support_blocking_api(&self) -> bool {
@@ -160,6 +189,36 @@ impl BlockReader {
         indices
     }
 
+    /// Returns the cipher to decrypt a block encrypted with the data key of `version`.
+    #[async_backtrace::framed]
+    pub(crate) async fn block_cipher(&self, version: Option<u32>) -> Result<Option<BlockCipher>> {
+        match self.block_encryption(version)? {
+            Some((encryption, version)) => Ok(Some(encryption.cipher(version).await?)),
+            None => Ok(None),
+        }
+    }
+
+    pub(crate) fn sync_block_cipher(&self, version: Option<u32>) -> Result<Option<BlockCipher>> {
+        match self.block_encryption(version)? {
+            Some((encryption, version)) => Ok(Some(encryption.blocking_cipher(version)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn block_encryption(
+        &self,
+        version: Option<u32>,
+    ) -> Result<Option<(&Arc<TableEncryption>, u32)>> {
+        match (version, &self.encryption) {
+            (None, _) => Ok(None),
+            (Some(version), Some(encryption)) => Ok(Some((encryption, version))),
+            (Some(version), None) => Err(ErrorCode::StorageEncryptionError(format!(
+                "block is encrypted with data key of version {}, but the table has no data keys",
+                version
+            ))),
+        }
+    }
+
     pub fn query_internal_columns(&self) -> bool {
         self.query_internal_columns
     }
@@ -106,7 +106,14 @@ impl BlockReader {
 
         // Get the merged IO read result.
         let merge_io_read_result = self
-            .read_columns_data_by_merge_io(settings, &meta.location.0, columns_meta, &None)
+            .read_columns_data_by_merge_io(
+                settings,
+                &meta.location.0,
+                columns_meta,
+                &meta.column_checksums,
+                meta.encryption_key_version,
+                &None,
+            )
             .await?;
 
         // Get the columns chunk.
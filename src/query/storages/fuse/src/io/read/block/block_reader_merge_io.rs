@@ -100,7 +100,7 @@ impl MergeIOReadResult {
         Ok(res)
     }
 
-    fn get_chunk(&self, index: usize, path: &str) -> Result<&[u8]> {
+    pub(crate) fn get_chunk(&self, index: usize, path: &str) -> Result<&[u8]> {
         self.owner_memory.get_chunk(index, path)
     }
 
@@ -23,6 +23,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::ColumnId;
 use futures::future::try_join_all;
+use log::warn;
 use opendal::Operator;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache::TableDataCacheKey;
@@ -31,6 +32,8 @@ use storages_common_table_meta::meta::ColumnMeta;
 
 use crate::io::read::block::block_reader_merge_io::OwnerMemory;
 use crate::io::read::ReadSettings;
+use crate::io::verify_column_checksum;
+use crate::io::BlockCipher;
 use crate::io::BlockReader;
 use crate::metrics::*;
 use crate::MergeIOReadResult;
@@ -48,6 +51,8 @@ impl BlockReader {
         op: Operator,
         location: &str,
         raw_ranges: Vec<(ColumnId, Range<u64>)>,
+        columns_checksum: &HashMap<ColumnId, u32>,
+        cipher: Option<&BlockCipher>,
     ) -> Result<MergeIOReadResult> {
         if raw_ranges.is_empty() {
             // shortcut
@@ -75,6 +80,11 @@ impl BlockReader {
         // Read merged range data.
         let mut read_handlers = Vec::with_capacity(merged_ranges.len());
         for (idx, range) in merged_ranges.iter().enumerate() {
+            let range = match cipher {
+                Some(cipher) => cipher.encrypted_range(range),
+                None => range.clone(),
+            };
+
             // Perf.
             {
                 metrics_inc_remote_io_seeks_after_merged(1);
@@ -91,8 +101,18 @@ impl BlockReader {
         }
 
         let start = Instant::now();
-        let owner_memory = OwnerMemory::create(try_join_all(read_handlers).await?);
-        let table_data_cache = CacheManager::instance().get_table_data_cache();
+        let mut chunks = try_join_all(read_handlers).await?;
+        // The decrypted data is never populated into the table data cache, which may be on disk.
+        let table_data_cache = match cipher {
+            Some(cipher) => {
+                for (idx, chunk) in chunks.iter_mut() {
+                    *chunk = cipher.decrypt_range(location, &merged_ranges[*idx], chunk)?;
+                }
+                None
+            }
+            None => CacheManager::instance().get_table_data_cache(),
+        };
+        let owner_memory = OwnerMemory::create(chunks);
         let mut read_res = MergeIOReadResult::create(
             owner_memory,
             raw_ranges.len(),
@@ -118,6 +138,14 @@ impl BlockReader {
             let start = (column_range.start - merged_range.start) as usize;
             let end = (column_range.end - merged_range.start) as usize;
             let column_id = *raw_idx as ColumnId;
+
+            // Verify the data before it is populated into the table data cache.
+            if let Some(checksum) = columns_checksum.get(&column_id) {
+                if read_settings.checksum_verification.should_verify() {
+                    let chunk = read_res.get_chunk(merged_range_idx, location)?;
+                    verify_column_checksum(location, column_id, &chunk[start..end], *checksum)?;
+                }
+            }
             read_res.add_column_chunk(merged_range_idx, column_id, start..end);
         }
 
@@ -130,6 +158,8 @@ impl BlockReader {
         settings: &ReadSettings,
         location: &str,
         columns_meta: &HashMap<ColumnId, ColumnMeta>,
+        columns_checksum: &HashMap<ColumnId, u32>,
+        encryption_key_version: Option<u32>,
         ignore_column_ids: &Option<HashSet<ColumnId>>,
     ) -> Result<MergeIOReadResult> {
         // Perf
@@ -137,18 +167,28 @@ impl BlockReader {
             metrics_inc_remote_io_read_parts(1);
         }
 
+        let cipher = self.block_cipher(encryption_key_version).await?;
         let mut ranges = vec![];
-        // for async read, try using table data cache (if enabled in settings)
-        let column_data_cache = CacheManager::instance().get_table_data_cache();
+        // for async read, try using table data cache (if enabled in settings),
+        // the data of encrypted blocks is not cached.
+        let column_data_cache = match cipher {
+            Some(_) => None,
+            None => CacheManager::instance().get_table_data_cache(),
+        };
         let column_array_cache = CacheManager::instance().get_table_data_array_cache();
         let mut cached_column_data = vec![];
         let mut cached_column_array = vec![];
+        let prev_versions = self.stored_prev_versions(columns_meta);
         for (_index, (column_id, ..)) in self.project_indices.iter() {
             if let Some(ignore_column_ids) = ignore_column_ids {
                 if ignore_column_ids.contains(column_id) {
                     continue;
                 }
             }
+            // read the previous version if the block is written before the column is widened
+            let column_id = prev_versions
+                .get(column_id)
+                .map_or(column_id, |v| &v.column_id);
             let column_cache_key = TableDataCacheKey::new(location, *column_id);
 
             // first, check column array object cache
@@ -159,8 +199,28 @@ impl BlockReader {
 
             // and then, check column data cache
             if let Some(cached_column_raw_data) = column_data_cache.get(&column_cache_key) {
-                cached_column_data.push((*column_id, cached_column_raw_data));
-                continue;
+                let corrupted = match columns_checksum.get(column_id) {
+                    Some(checksum) if settings.checksum_verification.should_verify() => {
+                        verify_column_checksum(
+                            location,
+                            *column_id,
+                            &cached_column_raw_data,
+                            *checksum,
+                        )
+                        .is_err()
+                    }
+                    _ => false,
+                };
+                if !corrupted {
+                    cached_column_data.push((*column_id, cached_column_raw_data));
+                    continue;
+                }
+                // exclude the corrupted cache copy, and read the column from storage instead
+                warn!(
+                    "checksum mismatch of cached column {} in block {}, evict it from cache",
+                    column_id, location
+                );
+                column_data_cache.evict(column_cache_key.as_ref());
             }
 
             // if all cache missed, prepare the ranges to be read
@@ -176,8 +236,15 @@ impl BlockReader {
             }
         }
 
-        let mut merge_io_read_res =
-            Self::merge_io_read(settings, self.operator.clone(), location, ranges).await?;
+        let mut merge_io_read_res = Self::merge_io_read(
+            settings,
+            self.operator.clone(),
+            location,
+            ranges,
+            columns_checksum,
+            cipher.as_ref(),
+        )
+        .await?;
 
         merge_io_read_res.cached_column_data = cached_column_data;
         merge_io_read_res.cached_column_array = cached_column_array;
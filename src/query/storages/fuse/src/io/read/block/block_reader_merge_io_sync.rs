@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::ops::Range;
 
@@ -28,6 +29,8 @@ use storages_common_cache_manager::CacheManager;
 use crate::fuse_part::FusePartInfo;
 use crate::io::read::block::block_reader_merge_io::OwnerMemory;
 use crate::io::read::ReadSettings;
+use crate::io::verify_column_checksum;
+use crate::io::BlockCipher;
 use crate::io::BlockReader;
 use crate::MergeIOReadResult;
 
@@ -37,6 +40,8 @@ impl BlockReader {
         op: Operator,
         location: &str,
         raw_ranges: Vec<(ColumnId, Range<u64>)>,
+        columns_checksum: &HashMap<ColumnId, u32>,
+        cipher: Option<&BlockCipher>,
     ) -> Result<MergeIOReadResult> {
         let path = location.to_string();
 
@@ -55,13 +60,21 @@ impl BlockReader {
         // Read merged range data.
         let mut io_res = Vec::with_capacity(merged_ranges.len());
         for (idx, range) in merged_ranges.iter().enumerate() {
-            io_res.push(Self::sync_read_range(
-                op.clone(),
-                location,
-                idx,
-                range.start,
-                range.end,
-            )?);
+            let chunk = match cipher {
+                Some(cipher) => {
+                    let encrypted_range = cipher.encrypted_range(range);
+                    let (_, chunk) = Self::sync_read_range(
+                        op.clone(),
+                        location,
+                        idx,
+                        encrypted_range.start,
+                        encrypted_range.end,
+                    )?;
+                    cipher.decrypt_range(location, range, &chunk)?
+                }
+                None => Self::sync_read_range(op.clone(), location, idx, range.start, range.end)?.1,
+            };
+            io_res.push((idx, chunk));
         }
 
         let owner_memory = OwnerMemory::create(io_res);
@@ -88,6 +101,12 @@ impl BlockReader {
             // Fetch the raw data for the raw range.
             let start = (column_range.start - merged_range.start) as usize;
             let end = (column_range.end - merged_range.start) as usize;
+            if let Some(checksum) = columns_checksum.get(&column_id) {
+                if read_settings.checksum_verification.should_verify() {
+                    let chunk = read_res.get_chunk(merged_range_idx, location)?;
+                    verify_column_checksum(location, column_id, &chunk[start..end], *checksum)?;
+                }
+            }
             read_res.add_column_chunk(merged_range_idx, column_id, start..end);
         }
 
@@ -101,16 +120,22 @@ impl BlockReader {
         ignore_column_ids: &Option<HashSet<ColumnId>>,
     ) -> Result<MergeIOReadResult> {
         let part = FusePartInfo::from_part(&part)?;
+        let cipher = self.sync_block_cipher(part.encryption_key_version)?;
         let column_array_cache = CacheManager::instance().get_table_data_array_cache();
 
         let mut ranges = vec![];
         let mut cached_column_array = vec![];
+        let prev_versions = self.stored_prev_versions(&part.columns_meta);
         for (_index, (column_id, ..)) in self.project_indices.iter() {
             if let Some(ignore_column_ids) = ignore_column_ids {
                 if ignore_column_ids.contains(column_id) {
                     continue;
                 }
             }
+            // read the previous version if the block is written before the column is widened
+            let column_id = prev_versions
+                .get(column_id)
+                .map_or(column_id, |v| &v.column_id);
             // first, check column array object cache
             let block_path = &part.location;
             let column_cache_key = TableDataCacheKey::new(block_path, *column_id);
@@ -124,8 +149,14 @@ impl BlockReader {
             }
         }
 
-        let mut merge_io_result =
-            Self::sync_merge_io_read(settings, self.operator.clone(), &part.location, ranges)?;
+        let mut merge_io_result = Self::sync_merge_io_read(
+            settings,
+            self.operator.clone(),
+            &part.location,
+            ranges,
+            &part.columns_checksum,
+            cipher.as_ref(),
+        )?;
         merge_io_result.cached_column_array = cached_column_array;
         Ok(merge_io_result)
     }
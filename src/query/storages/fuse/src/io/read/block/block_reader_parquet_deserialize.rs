@@ -97,6 +97,19 @@ impl BlockReader {
             return self.build_default_values_block(num_rows);
         }
 
+        let prev_versions = self.stored_prev_versions(column_metas);
+        if !prev_versions.is_empty() {
+            return self.deserialize_prev_version_parquet_chunks(
+                &prev_versions,
+                block_path,
+                num_rows,
+                compression,
+                column_metas,
+                column_chunks,
+                uncompressed_buffer,
+            );
+        }
+
         let mut need_default_vals = Vec::with_capacity(self.project_column_nodes.len());
         let mut need_to_fill_default_val = false;
         let mut deserialized_column_arrays = Vec::with_capacity(self.projection.len());
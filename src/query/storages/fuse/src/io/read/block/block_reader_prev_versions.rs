@@ -0,0 +1,144 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::type_check::check_cast;
+use common_expression::BlockEntry;
+use common_expression::ColumnId;
+use common_expression::ColumnVersion;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_functions::BUILTIN_FUNCTIONS;
+use storages_common_table_meta::meta::ColumnMeta;
+use storages_common_table_meta::meta::Compression;
+
+use crate::io::read::block::block_reader_merge_io::DataItem;
+use crate::io::BlockReader;
+use crate::io::UncompressedBuffer;
+
+impl BlockReader {
+    /// Returns the previous versions stored in a block, of the projected columns that are
+    /// widened after the block is written, keyed by the current column id.
+    pub(crate) fn stored_prev_versions(
+        &self,
+        column_metas: &HashMap<ColumnId, ColumnMeta>,
+    ) -> HashMap<ColumnId, &ColumnVersion> {
+        let mut versions = HashMap::new();
+        for field in self.projected_schema.fields() {
+            if field.prev_versions().is_empty() || column_metas.contains_key(&field.column_id()) {
+                continue;
+            }
+            if let Some(version) = field
+                .prev_versions()
+                .iter()
+                .find(|v| column_metas.contains_key(&v.column_id))
+            {
+                versions.insert(field.column_id(), version);
+            }
+        }
+        versions
+    }
+
+    /// Deserialize a parquet block that is written before some of the projected columns are
+    /// widened. These columns are read with the previous data types, and cast to the current ones.
+    pub(super) fn deserialize_prev_version_parquet_chunks(
+        &self,
+        versions: &HashMap<ColumnId, &ColumnVersion>,
+        block_path: &str,
+        num_rows: usize,
+        compression: &Compression,
+        column_metas: &HashMap<ColumnId, ColumnMeta>,
+        column_chunks: HashMap<ColumnId, DataItem>,
+        uncompressed_buffer: Option<Arc<UncompressedBuffer>>,
+    ) -> Result<DataBlock> {
+        let reader = self.prev_version_reader(versions)?;
+        let block = reader.deserialize_parquet_chunks_with_buffer(
+            block_path,
+            num_rows,
+            compression,
+            column_metas,
+            column_chunks,
+            uncompressed_buffer,
+        )?;
+
+        let data_schema = self.data_schema();
+        let func_ctx = FunctionContext::default();
+        let evaluator = Evaluator::new(&block, &func_ctx, &BUILTIN_FUNCTIONS);
+        let entries = block
+            .columns()
+            .iter()
+            .zip(data_schema.fields())
+            .enumerate()
+            .map(|(i, (entry, field))| {
+                if &entry.data_type == field.data_type() {
+                    return Ok(entry.clone());
+                }
+                let expr = Expr::ColumnRef {
+                    span: None,
+                    id: i,
+                    data_type: entry.data_type.clone(),
+                    display_name: field.name().clone(),
+                };
+                let expr = check_cast(None, false, expr, field.data_type(), &BUILTIN_FUNCTIONS)?;
+                let value = evaluator.run(&expr)?;
+                Ok(BlockEntry::new(field.data_type().clone(), value))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DataBlock::new(entries, num_rows))
+    }
+
+    /// Build a reader that reads the widened columns with the given previous versions.
+    fn prev_version_reader(&self, versions: &HashMap<ColumnId, &ColumnVersion>) -> Result<Self> {
+        let prev_field = |field: &TableField| match versions.get(&field.column_id()) {
+            Some(v) => {
+                TableField::new_from_column_id(field.name(), v.data_type.clone(), v.column_id)
+                    .with_default_expr(field.default_expr().cloned())
+            }
+            None => field.clone(),
+        };
+
+        let schema = TableSchema {
+            fields: self.table_schema.fields().iter().map(prev_field).collect(),
+            metadata: self.table_schema.meta().clone(),
+            next_column_id: self.table_schema.next_column_id(),
+        };
+        let projected_schema = TableSchema {
+            fields: self
+                .projected_schema
+                .fields()
+                .iter()
+                .map(prev_field)
+                .collect(),
+            metadata: self.projected_schema.meta().clone(),
+            next_column_id: self.projected_schema.next_column_id(),
+        };
+
+        Self::build(
+            self.operator.clone(),
+            Arc::new(schema),
+            self.projection.clone(),
+            Arc::new(projected_schema),
+            self.default_vals.clone(),
+            self.query_internal_columns,
+            self.encryption.clone(),
+        )
+    }
+}
@@ -20,6 +20,7 @@ mod block_reader_merge_io_sync;
 mod block_reader_native;
 mod block_reader_native_deserialize;
 mod block_reader_parquet_deserialize;
+mod block_reader_prev_versions;
 mod decompressor;
 
 pub use block_reader::BlockReader;
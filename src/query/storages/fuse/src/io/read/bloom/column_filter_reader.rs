@@ -33,11 +33,14 @@ use common_exception::Result;
 use common_expression::Column;
 use common_expression::ColumnId;
 use opendal::Operator;
+use storages_common_cache::CacheAccessor;
 use storages_common_cache::CacheKey;
+use storages_common_cache::CachePriority;
 use storages_common_cache::InMemoryCacheReader;
 use storages_common_cache::LoadParams;
 use storages_common_cache::Loader;
 use storages_common_cache_manager::BloomIndexFilterMeter;
+use storages_common_cache_manager::CacheManager;
 use storages_common_cache_manager::CachedObject;
 use storages_common_index::filters::Filter;
 use storages_common_index::filters::Xor8Filter;
@@ -131,10 +134,25 @@ pub struct Xor8FilterLoader {
 impl Loader<Xor8Filter> for Xor8FilterLoader {
     #[async_backtrace::framed]
     async fn load(&self, params: &LoadParams) -> Result<Xor8Filter> {
-        let bytes = self
-            .operator
-            .range_read(&params.location, self.offset..self.offset + self.len)
-            .await?;
+        // the raw data of the filter is also kept in the table data cache (if enabled), with
+        // high priority, so that it survives the eviction of the filter object in memory.
+        let table_data_cache = CacheManager::instance().get_table_data_cache();
+        let bytes = match table_data_cache.get_with_priority(&self.cache_key, CachePriority::High) {
+            Some(bytes) => bytes,
+            None => {
+                let bytes = Arc::new(
+                    self.operator
+                        .range_read(&params.location, self.offset..self.offset + self.len)
+                        .await?,
+                );
+                table_data_cache.put_with_priority(
+                    self.cache_key.clone(),
+                    bytes.clone(),
+                    CachePriority::High,
+                );
+                bytes
+            }
+        };
 
         let page_meta_data = PageMetaData {
             column_start: 0,
@@ -144,7 +162,7 @@ impl Loader<Xor8Filter> for Xor8FilterLoader {
         };
 
         let page_reader = PageReader::new_with_page_meta(
-            std::io::Cursor::new(bytes),
+            std::io::Cursor::new(bytes.as_slice()),
             page_meta_data,
             Arc::new(|_, _| true),
             vec![],
@@ -46,7 +46,7 @@ pub type BloomIndexMetaReader = InMemoryItemCacheReader<BloomIndexMeta, LoaderWr
 pub type TableSnapshotReader = InMemoryItemCacheReader<TableSnapshot, LoaderWrapper<Operator>>;
 pub type CompactSegmentInfoReader = InMemoryItemCacheReader<
     CompactSegmentInfo,
-    LoaderWrapper<(Operator, TableSchemaRef)>,
+    LoaderWrapper<(Operator, TableSchemaRef, bool)>,
     DefaultHashBuilder,
     CompactSegmentInfoMeter,
 >;
@@ -55,9 +55,19 @@ pub struct MetaReaders;
 
 impl MetaReaders {
     pub fn segment_info_reader(dal: Operator, schema: TableSchemaRef) -> CompactSegmentInfoReader {
+        Self::segment_info_reader_with_checksum(dal, schema, false)
+    }
+
+    /// The segments loaded from the storage by the reader have their checksum verified,
+    /// if `verify_checksum` is set.
+    pub fn segment_info_reader_with_checksum(
+        dal: Operator,
+        schema: TableSchemaRef,
+        verify_checksum: bool,
+    ) -> CompactSegmentInfoReader {
         CompactSegmentInfoReader::new(
             CacheManager::instance().get_table_segment_cache(),
-            LoaderWrapper((dal, schema)),
+            LoaderWrapper((dal, schema, verify_checksum)),
         )
     }
 
@@ -108,13 +118,15 @@ impl Loader<TableSnapshotStatistics> for LoaderWrapper<Operator> {
 }
 
 #[async_trait::async_trait]
-impl Loader<CompactSegmentInfo> for LoaderWrapper<(Operator, TableSchemaRef)> {
+impl Loader<CompactSegmentInfo> for LoaderWrapper<(Operator, TableSchemaRef, bool)> {
     #[async_backtrace::framed]
     async fn load(&self, params: &LoadParams) -> Result<CompactSegmentInfo> {
         let version = SegmentInfoVersion::try_from(params.ver)?;
-        let LoaderWrapper((operator, schema)) = &self;
+        let LoaderWrapper((operator, schema, verify_checksum)) = &self;
         let reader = bytes_reader(operator, params.location.as_str(), params.len_hint).await?;
-        (version, schema.clone()).read(reader).await
+        (version, schema.clone(), *verify_checksum)
+            .read(reader)
+            .await
     }
 }
 
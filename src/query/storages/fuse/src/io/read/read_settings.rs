@@ -17,10 +17,13 @@ use std::sync::Arc;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 
+use crate::io::ChecksumVerification;
+
 #[derive(Clone)]
 pub struct ReadSettings {
     pub storage_io_min_bytes_for_seek: u64,
     pub storage_io_max_page_bytes_for_read: u64,
+    pub checksum_verification: ChecksumVerification,
 }
 
 impl ReadSettings {
@@ -32,6 +35,11 @@ impl ReadSettings {
             storage_io_max_page_bytes_for_read: ctx
                 .get_settings()
                 .get_storage_io_max_page_bytes_for_read()?,
+            checksum_verification: ChecksumVerification::try_from(
+                ctx.get_settings()
+                    .get_data_checksum_verification()?
+                    .as_str(),
+            )?,
         })
     }
 }
@@ -31,64 +31,74 @@ pub type TableSnapshotStream = Pin<
     >,
 >;
 
+/// Walks the history of a table lazily, from the given snapshot back to the oldest one,
+/// i.e. the snapshots are ordered by timestamp DESC.
+///
+/// A snapshot is read only when the stream is polled, so the history of a table with
+/// lots of snapshots is never loaded at once. At most `limit` snapshots are read.
+///
+/// Only the first snapshot is put into the cache, the precedent ones are rarely read
+/// again, caching them would evict the hot snapshots.
 pub trait SnapshotHistoryReader {
     fn snapshot_history(
         self,
         location: String,
         format_version: u64,
         location_gen: TableMetaLocationGenerator,
+        limit: Option<usize>,
     ) -> TableSnapshotStream;
 }
+
+struct HistoryState {
+    reader: TableSnapshotReader,
+    location_gen: TableMetaLocationGenerator,
+    next: Option<(String, FormatVersion)>,
+    remaining: Option<usize>,
+    put_cache: bool,
+}
+
 impl SnapshotHistoryReader for TableSnapshotReader {
     fn snapshot_history(
         self,
         location: String,
         format_version: u64,
         location_gen: TableMetaLocationGenerator,
+        limit: Option<usize>,
     ) -> TableSnapshotStream {
-        let stream = stream::try_unfold(
-            (self, location_gen, Some((location, format_version))),
-            |(reader, gen, next)| async move {
-                if let Some((loc, ver)) = next {
-                    let load_params = LoadParams {
-                        location: loc,
-                        len_hint: None,
-                        ver,
-                        put_cache: true,
-                    };
+        let state = HistoryState {
+            reader: self,
+            location_gen,
+            next: Some((location, format_version)),
+            remaining: limit,
+            put_cache: true,
+        };
+        let stream = stream::try_unfold(state, |mut state| async move {
+            let (loc, ver) = match state.next.take() {
+                Some(next) if state.remaining != Some(0) => next,
+                _ => return Ok(None),
+            };
+            let load_params = LoadParams {
+                location: loc,
+                len_hint: None,
+                ver,
+                put_cache: state.put_cache,
+            };
 
-                    let snapshot = match reader.read(&load_params).await {
-                        Ok(s) => Ok(Some(s)),
-                        Err(e) => {
-                            if e.code() == ErrorCode::STORAGE_NOT_FOUND {
-                                Ok(None)
-                            } else {
-                                Err(e)
-                            }
-                        }
-                    };
-                    match snapshot {
-                        Ok(Some(snapshot)) => {
-                            if let Some((prev_id, prev_version)) = snapshot.prev_snapshot_id {
-                                let new_ver = prev_version;
-                                let new_loc =
-                                    gen.snapshot_location_from_uuid(&prev_id, prev_version)?;
-                                Ok(Some((
-                                    (snapshot, ver),
-                                    (reader, gen, Some((new_loc, new_ver))),
-                                )))
-                            } else {
-                                Ok(Some(((snapshot, ver), (reader, gen, None))))
-                            }
-                        }
-                        Ok(None) => Ok(None),
-                        Err(e) => Err(e),
-                    }
-                } else {
-                    Ok(None)
-                }
-            },
-        );
+            let snapshot = match state.reader.read(&load_params).await {
+                Ok(snapshot) => snapshot,
+                Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => return Ok(None),
+                Err(e) => return Err(e),
+            };
+            if let Some((prev_id, prev_version)) = snapshot.prev_snapshot_id {
+                let prev_loc = state
+                    .location_gen
+                    .snapshot_location_from_uuid(&prev_id, prev_version)?;
+                state.next = Some((prev_loc, prev_version));
+            }
+            state.remaining = state.remaining.map(|remaining| remaining - 1);
+            state.put_cache = false;
+            Ok(Some(((snapshot, ver), state)))
+        });
         Box::pin(stream)
     }
 }
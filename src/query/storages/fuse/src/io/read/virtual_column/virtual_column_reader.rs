@@ -114,6 +114,7 @@ impl VirtualColumnReader {
             Projection::Columns(vec![]),
             ctx.clone(),
             false,
+            None,
         )?;
 
         Ok(Self {
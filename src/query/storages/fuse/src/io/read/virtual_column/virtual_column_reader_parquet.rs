@@ -98,11 +98,20 @@ impl VirtualColumnReader {
                 None,
                 None,
                 None,
+                None,
+                HashMap::new(),
+                None,
             );
 
-            let merge_io_result =
-                BlockReader::sync_merge_io_read(read_settings, self.dal.clone(), loc, ranges)
-                    .ok()?;
+            let merge_io_result = BlockReader::sync_merge_io_read(
+                read_settings,
+                self.dal.clone(),
+                loc,
+                ranges,
+                &HashMap::new(),
+                None,
+            )
+            .ok()?;
 
             Some(VirtualMergeIOReadResult::create(
                 part,
@@ -139,12 +148,21 @@ impl VirtualColumnReader {
                 None,
                 None,
                 None,
+                None,
+                HashMap::new(),
+                None,
             );
 
-            let merge_io_result =
-                BlockReader::merge_io_read(read_settings, self.dal.clone(), loc, ranges)
-                    .await
-                    .ok()?;
+            let merge_io_result = BlockReader::merge_io_read(
+                read_settings,
+                self.dal.clone(),
+                loc,
+                ranges,
+                &HashMap::new(),
+                None,
+            )
+            .await
+            .ok()?;
 
             Some(VirtualMergeIOReadResult::create(
                 part,
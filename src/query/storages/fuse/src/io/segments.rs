@@ -29,6 +29,7 @@ use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::Versioned;
 
+use crate::io::ChecksumVerification;
 use crate::io::MetaReaders;
 
 #[derive(Clone)]
@@ -60,9 +61,29 @@ impl SegmentsIO {
         segment_location: Location,
         table_schema: TableSchemaRef,
         put_cache: bool,
+    ) -> Result<Arc<CompactSegmentInfo>> {
+        Self::read_compact_segment_with_checksum(
+            dal,
+            segment_location,
+            table_schema,
+            put_cache,
+            false,
+        )
+        .await
+    }
+
+    // Read one segment file by location, verifying its checksum if `verify_checksum` is set.
+    #[async_backtrace::framed]
+    pub async fn read_compact_segment_with_checksum(
+        dal: Operator,
+        segment_location: Location,
+        table_schema: TableSchemaRef,
+        put_cache: bool,
+        verify_checksum: bool,
     ) -> Result<Arc<CompactSegmentInfo>> {
         let (path, ver) = segment_location;
-        let reader = MetaReaders::segment_info_reader(dal, table_schema);
+        let reader =
+            MetaReaders::segment_info_reader_with_checksum(dal, table_schema, verify_checksum);
 
         // Keep in mind that segment_info_read must need a schema
         let load_params = LoadParams {
@@ -86,6 +107,13 @@ impl SegmentsIO {
     where
         T: TryFrom<Arc<CompactSegmentInfo>> + Send + 'static,
     {
+        let checksum_verification = ChecksumVerification::try_from(
+            self.ctx
+                .get_settings()
+                .get_data_checksum_verification()?
+                .as_str(),
+        )?;
+
         // combine all the tasks.
         let mut iter = segment_locations.iter();
         let tasks = std::iter::from_fn(|| {
@@ -93,10 +121,16 @@ impl SegmentsIO {
                 let dal = self.operator.clone();
                 let table_schema = self.schema.clone();
                 let segment_location = location.clone();
+                let verify_checksum = checksum_verification.should_verify();
                 async move {
-                    let compact_segment =
-                        Self::read_compact_segment(dal, segment_location, table_schema, put_cache)
-                            .await?;
+                    let compact_segment = Self::read_compact_segment_with_checksum(
+                        dal,
+                        segment_location,
+                        table_schema,
+                        put_cache,
+                        verify_checksum,
+                    )
+                    .await?;
                     compact_segment
                         .try_into()
                         .map_err(|_| ErrorCode::Internal("Failed to convert compact segment info"))
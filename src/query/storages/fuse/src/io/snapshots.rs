@@ -24,7 +24,6 @@ use common_base::runtime::execute_futures_in_parallel;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
-use futures::stream::StreamExt;
 use futures_util::TryStreamExt;
 use log::info;
 use log::warn;
@@ -223,15 +222,11 @@ impl SnapshotsIO {
             MetaReaders::table_snapshot_reader(self.ctx.get_data_operator()?.operator());
         let format_version = TableMetaLocationGenerator::snapshot_version(root_snapshot.as_str());
         let lite_snapshot_stream = table_snapshot_reader
-            .snapshot_history(root_snapshot, format_version, location_generator)
+            .snapshot_history(root_snapshot, format_version, location_generator, limit)
             .map_ok(|(snapshot, format_version)| {
                 TableSnapshotLite::from((snapshot.as_ref(), format_version))
             });
-        if let Some(l) = limit {
-            lite_snapshot_stream.take(l).try_collect::<Vec<_>>().await
-        } else {
-            lite_snapshot_stream.try_collect::<Vec<_>>().await
-        }
+        lite_snapshot_stream.try_collect::<Vec<_>>().await
     }
 
     #[async_backtrace::framed]
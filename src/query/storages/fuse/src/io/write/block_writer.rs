@@ -23,13 +23,20 @@ use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::ColumnId;
 use common_expression::DataBlock;
+use common_expression::Expr;
 use common_expression::FieldIndex;
+use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchemaRef;
+use common_expression::PARTITION_KEY_COLUMN_ID;
+use common_functions::scalars::Tokenizer;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
 use opendal::Operator;
 use storages_common_blocks::blocks_to_parquet;
+use storages_common_blocks::blocks_to_parquet_with_options;
+use storages_common_blocks::parquet_bloom_filter_supported_type;
+use storages_common_blocks::ParquetWriteOptions;
 use storages_common_index::BloomIndex;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
@@ -38,10 +45,12 @@ use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::table::TableCompression;
 
 use crate::fuse_table::FuseStorageFormat;
+use crate::io::gen_columns_checksum;
 use crate::io::write::WriteSettings;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::util;
 use crate::statistics::gen_columns_statistics;
+use crate::statistics::gen_partition_statistics;
 use crate::statistics::ClusterStatsGenerator;
 
 // TODO rename this, it is serialization, or pass in a writer(if not rename)
@@ -54,8 +63,29 @@ pub fn serialize_block(
     let schema = Arc::new(schema.remove_virtual_computed_fields());
     match write_settings.storage_format {
         FuseStorageFormat::Parquet => {
-            let result =
-                blocks_to_parquet(&schema, vec![block], buf, write_settings.table_compression)?;
+            let bloom_filter_fields = write_settings
+                .parquet_bloom_filter_cols
+                .bloom_index_fields(schema.clone(), parquet_bloom_filter_supported_type)?;
+            let dictionary_fields = if write_settings.compact_variant {
+                schema
+                    .fields()
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, f)| f.data_type().remove_nullable() == TableDataType::Variant)
+                    .map(|(idx, _)| idx)
+                    .collect()
+            } else {
+                vec![]
+            };
+            let options = ParquetWriteOptions {
+                compression: write_settings.table_compression,
+                compression_level: write_settings.compression_level,
+                enable_dictionary: write_settings.enable_dictionary,
+                data_page_size: write_settings.data_page_size,
+                bloom_filter_fields: bloom_filter_fields.into_keys().collect(),
+                dictionary_fields,
+            };
+            let result = blocks_to_parquet_with_options(&schema, vec![block], buf, &options)?;
             let meta = util::column_parquet_metas(&result.1, &schema)?;
             Ok((result.0, meta))
         }
@@ -118,13 +148,17 @@ impl BloomIndexState {
         block: &DataBlock,
         location: Location,
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        inverted_columns_map: BTreeMap<FieldIndex, TableField>,
+        tokenizer: Tokenizer,
     ) -> Result<Option<Self>> {
         // write index
-        let maybe_bloom_index = BloomIndex::try_create(
+        let maybe_bloom_index = BloomIndex::try_create_with_inverted_index(
             ctx.get_function_context()?,
             location.1,
             &[block],
             bloom_columns_map,
+            inverted_columns_map,
+            tokenizer,
         )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
@@ -165,6 +199,9 @@ pub struct BlockBuilder {
     pub write_settings: WriteSettings,
     pub cluster_stats_gen: ClusterStatsGenerator,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub inverted_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub inverted_index_tokenizer: Tokenizer,
+    pub partition_key: Option<Expr>,
 }
 
 impl BlockBuilder {
@@ -180,6 +217,8 @@ impl BlockBuilder {
             &data_block,
             bloom_index_location,
             self.bloom_columns_map.clone(),
+            self.inverted_columns_map.clone(),
+            self.inverted_index_tokenizer,
         )?;
         let column_distinct_count = bloom_index_state
             .as_ref()
@@ -187,16 +226,36 @@ impl BlockBuilder {
 
         let row_count = data_block.num_rows() as u64;
         let block_size = data_block.memory_size() as u64;
-        let col_stats =
+        let mut col_stats =
             gen_columns_statistics(&data_block, column_distinct_count, &self.source_schema)?;
+        if let Some(partition_key) = &self.partition_key {
+            let partition_stats = gen_partition_statistics(
+                &data_block,
+                partition_key,
+                &self.ctx.get_function_context()?,
+            )?;
+            col_stats.insert(PARTITION_KEY_COLUMN_ID, partition_stats);
+        }
 
         let mut buffer = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
-        let (file_size, col_metas) = serialize_block(
+        let (mut file_size, col_metas) = serialize_block(
             &self.write_settings,
             &self.source_schema,
             data_block,
             &mut buffer,
         )?;
+        let column_checksums = gen_columns_checksum(&buffer, &col_metas);
+
+        // checksums are computed on the plain data, they are verified after decryption.
+        let encryption_key_version = match &self.write_settings.encryption {
+            Some(encryption) => {
+                let cipher = encryption.blocking_current_cipher()?;
+                buffer = cipher.encrypt(&block_location.0, &buffer)?;
+                file_size = buffer.len() as u64;
+                Some(cipher.version)
+            }
+            None => None,
+        };
 
         let block_meta = BlockMeta {
             row_count,
@@ -213,6 +272,11 @@ impl BlockBuilder {
                 .unwrap_or_default(),
             compression: self.write_settings.table_compression.try_into()?,
             create_on: Some(Utc::now()),
+            deletion_vector_location: None,
+            deletion_vector_size: 0,
+            deleted_row_count: 0,
+            column_checksums,
+            encryption_key_version,
         };
 
         let serialized = BlockSerialization {
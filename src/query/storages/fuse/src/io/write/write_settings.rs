@@ -12,8 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
+use common_sql::BloomIndexColumns;
 use storages_common_table_meta::table::TableCompression;
 
+use crate::io::TableEncryption;
 use crate::FuseStorageFormat;
 use crate::DEFAULT_BLOCK_PER_SEGMENT;
 use crate::DEFAULT_ROW_PER_PAGE;
@@ -26,6 +30,16 @@ pub struct WriteSettings {
     pub max_page_size: usize,
 
     pub block_per_seg: usize,
+
+    // parquet writer options, current only work in parquet format
+    pub compression_level: Option<i32>,
+    pub enable_dictionary: bool,
+    pub data_page_size: Option<usize>,
+    pub parquet_bloom_filter_cols: BloomIndexColumns,
+    // write the variant columns with dictionary encoding, and re-encode them while compacting
+    pub compact_variant: bool,
+    // the data keys to encrypt the blocks with, None if the table is not encrypted
+    pub encryption: Option<Arc<TableEncryption>>,
 }
 
 impl Default for WriteSettings {
@@ -35,6 +49,12 @@ impl Default for WriteSettings {
             table_compression: TableCompression::default(),
             max_page_size: DEFAULT_ROW_PER_PAGE,
             block_per_seg: DEFAULT_BLOCK_PER_SEGMENT,
+            compression_level: None,
+            enable_dictionary: false,
+            data_page_size: None,
+            parquet_bloom_filter_cols: BloomIndexColumns::None,
+            compact_variant: false,
+            encryption: None,
         }
     }
 }
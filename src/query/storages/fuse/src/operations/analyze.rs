@@ -141,6 +141,7 @@ impl FuseTable {
             new_snapshot.summary.col_stats = col_stats;
             new_snapshot.summary.cluster_stats = cluster_stats;
             new_snapshot.table_statistics_location = Some(table_statistics_location);
+            new_snapshot.rows_changed_since_analyze = 0;
             FuseTable::commit_to_meta_server(
                 ctx.as_ref(),
                 &self.table_info,
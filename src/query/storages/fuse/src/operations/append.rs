@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -19,10 +20,10 @@ use common_catalog::table::AppendMode;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::types::DataType;
 use common_expression::BlockThresholds;
 use common_expression::DataField;
 use common_expression::Expr;
-use common_expression::SortColumnDescription;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::Pipeline;
@@ -32,11 +33,16 @@ use common_pipeline_transforms::processors::transforms::TransformCompact;
 use common_pipeline_transforms::processors::transforms::TransformSortPartial;
 use common_sql::evaluator::BlockOperator;
 use common_sql::evaluator::CompoundBlockOperator;
+use storages_common_table_meta::table::ClusterType;
 
+use crate::operations::common::TransformAddZOrderKey;
+use crate::operations::common::TransformPartitionScatter;
 use crate::operations::common::TransformSerializeBlock;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
 
+const ZORDER_KEY_COLUMN_NAME: &str = "_zorder_key";
+
 impl FuseTable {
     pub fn do_append_data(
         &self,
@@ -91,6 +97,8 @@ impl FuseTable {
         pipeline: &mut Pipeline,
         block_thresholds: BlockThresholds,
     ) -> Result<ClusterStatsGenerator> {
+        self.scatter_by_partition(ctx.clone(), pipeline)?;
+
         let cluster_stats_gen = self.get_cluster_stats_gen(ctx.clone(), 0, block_thresholds)?;
 
         let operators = cluster_stats_gen.operators.clone();
@@ -108,17 +116,19 @@ impl FuseTable {
             })?;
         }
 
-        let cluster_keys = &cluster_stats_gen.cluster_key_index;
-        if !cluster_keys.is_empty() {
-            let sort_descs: Vec<SortColumnDescription> = cluster_keys
-                .iter()
-                .map(|index| SortColumnDescription {
-                    offset: *index,
-                    asc: true,
-                    nulls_first: false,
-                    is_nullable: false, // This information is not needed here.
-                })
-                .collect();
+        if cluster_stats_gen.zorder_key_index.is_some() {
+            let cluster_key_index = cluster_stats_gen.cluster_key_index.clone();
+            pipeline.add_transform(|transform_input_port, transform_output_port| {
+                Ok(ProcessorPtr::create(TransformAddZOrderKey::try_create(
+                    transform_input_port,
+                    transform_output_port,
+                    cluster_key_index.clone(),
+                )?))
+            })?;
+        }
+
+        if cluster_stats_gen.is_cluster() {
+            let sort_descs = cluster_stats_gen.sort_descs();
 
             pipeline.add_transform(|transform_input_port, transform_output_port| {
                 Ok(ProcessorPtr::create(TransformSortPartial::try_create(
@@ -132,6 +142,44 @@ impl FuseTable {
         Ok(cluster_stats_gen)
     }
 
+    /// Split the blocks by the partition key, if the table is partitioned.
+    pub fn scatter_by_partition(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        if let Some(partition_key) = self.partition_key_expr(ctx.clone())? {
+            let func_ctx = ctx.get_function_context()?;
+            pipeline.add_transform(|transform_input_port, transform_output_port| {
+                Ok(ProcessorPtr::create(TransformPartitionScatter::try_create(
+                    transform_input_port,
+                    transform_output_port,
+                    partition_key.clone(),
+                    func_ctx.clone(),
+                )?))
+            })?;
+        }
+        Ok(())
+    }
+
+    /// The partition key whose column references are the offsets of the stored columns.
+    pub(crate) fn partition_key_expr(&self, ctx: Arc<dyn TableContext>) -> Result<Option<Expr>> {
+        let partition_key = match self.partition_key(ctx)? {
+            Some(partition_key) => partition_key.as_expr(&BUILTIN_FUNCTIONS),
+            None => return Ok(None),
+        };
+        let source_schema = self.table_info.schema().remove_virtual_computed_fields();
+        let indices = partition_key
+            .column_refs()
+            .into_keys()
+            .map(|name| {
+                let index = source_schema.index_of(&name)?;
+                Ok((name, index))
+            })
+            .collect::<Result<HashMap<_, _>>>()?;
+        Ok(Some(partition_key.project_column_ref(|name| indices[name])))
+    }
+
     pub fn get_cluster_stats_gen(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -171,6 +219,16 @@ impl FuseTable {
             cluster_key_index.push(index);
         }
 
+        // The z-order key is appended after the extra cluster keys.
+        let zorder_key_index = match self.cluster_type {
+            ClusterType::Linear => None,
+            ClusterType::ZOrder => {
+                merged.push(DataField::new(ZORDER_KEY_COLUMN_NAME, DataType::String));
+                extra_key_num += 1;
+                Some(merged.len() - 1)
+            }
+        };
+
         let operators = if exprs.is_empty() {
             vec![]
         } else {
@@ -180,7 +238,7 @@ impl FuseTable {
             }]
         };
 
-        Ok(ClusterStatsGenerator::new(
+        let cluster_stats_gen = ClusterStatsGenerator::new(
             self.cluster_key_meta.as_ref().unwrap().0,
             cluster_key_index,
             extra_key_num,
@@ -190,7 +248,11 @@ impl FuseTable {
             operators,
             merged,
             ctx.get_function_context()?,
-        ))
+        );
+        Ok(match zorder_key_index {
+            Some(index) => cluster_stats_gen.with_zorder_key(index),
+            None => cluster_stats_gen,
+        })
     }
 
     pub fn get_option<T: FromStr>(&self, opt_key: &str, default: T) -> T {
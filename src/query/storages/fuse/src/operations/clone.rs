@@ -0,0 +1,253 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_catalog::catalog::Catalog;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use futures::TryStreamExt;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::OPT_KEY_CLONED_BY;
+use storages_common_table_meta::table::OPT_KEY_CLONED_FROM;
+use uuid::Uuid;
+
+use crate::io::MetaReaders;
+use crate::io::SnapshotHistoryReader;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+/// The ids of the tables whose files are shared by the table, see [`OPT_KEY_CLONED_FROM`].
+pub fn get_cloned_from(table_info: &TableInfo) -> Vec<u64> {
+    parse_table_ids(table_info, OPT_KEY_CLONED_FROM)
+}
+
+/// The ids of the tables sharing the files of the table, see [`OPT_KEY_CLONED_BY`].
+pub fn get_cloned_by(table_info: &TableInfo) -> Vec<u64> {
+    parse_table_ids(table_info, OPT_KEY_CLONED_BY)
+}
+
+fn parse_table_ids(table_info: &TableInfo, key: &str) -> Vec<u64> {
+    match table_info.options().get(key) {
+        Some(ids) => ids
+            .split(',')
+            .filter_map(|id| id.trim().parse::<u64>().ok())
+            .collect(),
+        None => vec![],
+    }
+}
+
+/// The value of [`OPT_KEY_CLONED_FROM`] of a table cloned from `source`.
+///
+/// A clone of a clone shares the files of all the tables in the chain.
+pub fn cloned_from_option(source: &TableInfo) -> String {
+    let mut ids = vec![source.ident.table_id];
+    ids.extend(get_cloned_from(source));
+    ids.iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Gets the table by id, the dropped one that is not vacuumed yet included.
+#[async_backtrace::framed]
+async fn get_table_by_id(catalog: &dyn Catalog, table_id: u64) -> Result<Option<Arc<dyn Table>>> {
+    let (ident, meta) = match catalog.get_table_meta_by_id(table_id).await {
+        Ok(table) => table,
+        Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    let table_info = TableInfo {
+        ident,
+        desc: "".to_owned(),
+        name: "".to_owned(),
+        meta: meta.as_ref().clone(),
+        tenant: "".to_owned(),
+        db_type: DatabaseType::NormalDB,
+    };
+    catalog.get_table_by_info(&table_info).map(Some)
+}
+
+/// Lists the clones of the table which are not vacuumed yet, the dropped ones included.
+#[async_backtrace::framed]
+pub async fn list_clones(
+    ctx: &dyn TableContext,
+    table_info: &TableInfo,
+) -> Result<Vec<Arc<dyn Table>>> {
+    let catalog = ctx.get_catalog(table_info.catalog()).await?;
+    // the clones are registered on the latest table meta.
+    let table_info = match get_table_by_id(catalog.as_ref(), table_info.ident.table_id).await? {
+        Some(table) => table.get_table_info().clone(),
+        None => table_info.clone(),
+    };
+
+    let mut clones = vec![];
+    for table_id in get_cloned_by(&table_info) {
+        if let Some(table) = get_table_by_id(catalog.as_ref(), table_id).await? {
+            clones.push(table);
+        }
+    }
+    Ok(clones)
+}
+
+/// Registers the clone `clone_id` in [`OPT_KEY_CLONED_BY`] of each of the tables it shares
+/// the files of, so that purging those tables only has to look at their own clones.
+#[async_backtrace::framed]
+pub async fn register_clone(
+    ctx: &dyn TableContext,
+    catalog: &str,
+    cloned_from: &[u64],
+    clone_id: u64,
+) -> Result<()> {
+    let catalog = ctx.get_catalog(catalog).await?;
+    for table_id in cloned_from {
+        loop {
+            let table = match get_table_by_id(catalog.as_ref(), *table_id).await? {
+                Some(table) => table,
+                None => break,
+            };
+            let table_info = table.get_table_info();
+            let mut cloned_by = get_cloned_by(table_info);
+            cloned_by.push(clone_id);
+
+            let mut new_table_meta = table_info.meta.clone();
+            new_table_meta.options.insert(
+                OPT_KEY_CLONED_BY.to_owned(),
+                cloned_by
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            );
+            let req = UpdateTableMetaReq {
+                table_id: *table_id,
+                seq: MatchSeq::Exact(table_info.ident.seq),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+                update_stream_meta: vec![],
+            };
+            match catalog.update_table_meta(table_info, req).await {
+                Ok(_) => break,
+                // the table is changed concurrently, retry with the latest meta.
+                Err(e) if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED => continue,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+    Ok(())
+}
+
+impl FuseTable {
+    /// Makes the table, which is just created, share the snapshot of `source` without
+    /// copying any data.
+    ///
+    /// The new snapshot is written under the prefix of this table, and refers to the
+    /// segments of `source`.
+    #[async_backtrace::framed]
+    pub async fn do_clone(&self, ctx: &dyn TableContext, source: &FuseTable) -> Result<()> {
+        let source_snapshot = match source.read_table_snapshot().await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(()),
+        };
+
+        let new_snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &None,
+            None,
+            source_snapshot.schema.clone(),
+            source_snapshot.summary.clone(),
+            source_snapshot.segments.clone(),
+            None,
+            // the table statistics are not shared, `ANALYZE TABLE` re-generates them.
+            None,
+        );
+
+        FuseTable::commit_to_meta_server(
+            ctx,
+            &self.table_info,
+            &self.meta_location_generator,
+            new_snapshot,
+            None,
+            &None,
+            &self.operator,
+        )
+        .await
+    }
+
+    /// Whether the file is written by this table, the files shared from the tables
+    /// this one is cloned from are not.
+    pub fn is_owned_location(&self, location: &str) -> bool {
+        let prefix = self.meta_location_generator.prefix();
+        location.len() > prefix.len()
+            && location.starts_with(prefix)
+            && location[prefix.len()..].starts_with('/')
+    }
+
+    /// Collects the segments, blocks, bloom indexes and deletion vectors of this table, which
+    /// are referenced by the history of its clones. They must be kept while any of the clones
+    /// exists.
+    ///
+    /// A segment written by a clone, e.g. by compacting or deleting, may still refer to the
+    /// blocks of this table, so all the segments of the clones are read.
+    #[async_backtrace::framed]
+    pub async fn locations_shared_with_clones(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+    ) -> Result<HashSet<String>> {
+        let mut shared = HashSet::new();
+        for table in list_clones(ctx.as_ref(), &self.table_info).await? {
+            let clone = FuseTable::try_from_table(table.as_ref())?;
+            let location = match clone.snapshot_loc().await? {
+                Some(location) => location,
+                None => continue,
+            };
+            let reader = MetaReaders::table_snapshot_reader(clone.get_operator());
+            let ver = TableMetaLocationGenerator::snapshot_version(location.as_str());
+            let mut history = reader.snapshot_history(
+                location,
+                ver,
+                clone.meta_location_generator().clone(),
+                None,
+            );
+            let mut segments = HashSet::<Location>::new();
+            while let Some((snapshot, _)) = history.try_next().await? {
+                segments.extend(snapshot.segments.iter().cloned());
+            }
+
+            let segments = Vec::from_iter(segments);
+            let locations = clone
+                .get_block_locations(ctx.clone(), &segments, false, false)
+                .await?;
+            shared.extend(
+                segments
+                    .into_iter()
+                    .map(|(location, _)| location)
+                    .chain(locations.block_location)
+                    .chain(locations.bloom_location)
+                    .chain(locations.deletion_vector_location)
+                    .filter(|location| self.is_owned_location(location)),
+            );
+        }
+        Ok(shared)
+    }
+}
@@ -62,6 +62,7 @@ use crate::operations::common::AbortOperation;
 use crate::operations::common::AppendGenerator;
 use crate::operations::common::CommitSink;
 use crate::operations::common::ConflictResolveContext;
+use crate::operations::common::StagedCommitSink;
 use crate::operations::common::TableMutationAggregator;
 use crate::operations::common::TransformSerializeSegment;
 use crate::statistics::merge_statistics;
@@ -99,6 +100,29 @@ impl FuseTable {
             )))
         })?;
 
+        let coalesce_window_ms = ctx.get_settings().get_insert_commit_coalesce_window_ms()?;
+        // The label of a deduplicated INSERT is committed with its own snapshot, such
+        // INSERTs are never coalesced.
+        if coalesce_window_ms > 0
+            && !overwrite
+            && !self.transient()
+            && copied_files.is_none()
+            && prev_snapshot_id.is_none()
+            && ctx.get_settings().get_deduplicate_label()?.is_none()
+        {
+            // The commit is deferred, and coalesced with the other appends to this table.
+            let max_rows = ctx.get_settings().get_insert_commit_coalesce_max_rows()?;
+            return pipeline.add_sink(|input| {
+                StagedCommitSink::try_create(
+                    self,
+                    ctx.clone(),
+                    input,
+                    Duration::from_millis(coalesce_window_ms),
+                    max_rows,
+                )
+            });
+        }
+
         let snapshot_gen = AppendGenerator::new(ctx.clone(), overwrite);
         pipeline.add_sink(|input| {
             CommitSink::try_create(
@@ -217,6 +241,7 @@ impl FuseTable {
             new_table_meta,
             copied_files: copied_files.clone(),
             deduplicated_label: ctx.get_settings().get_deduplicate_label()?,
+            update_stream_meta: ctx.get_stream_updates(),
         };
 
         // 3. let's roll
@@ -0,0 +1,308 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use backoff::backoff::Backoff;
+use common_base::base::tokio;
+use common_base::base::tokio::sync::oneshot;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableSchemaRef;
+use log::error;
+use log::info;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::meta::Versioned;
+
+use crate::metrics::metrics_inc_commit_aborts;
+use crate::metrics::metrics_inc_commit_mutation_success;
+use crate::operations::common::AbortOperation;
+use crate::operations::common::AppendGenerator;
+use crate::operations::common::ConflictResolveContext;
+use crate::operations::common::SnapshotGenerator;
+use crate::operations::common::SnapshotMerged;
+use crate::statistics::merge_statistics;
+use crate::FuseTable;
+
+static COMMIT_COALESCER: Lazy<CommitCoalescer> = Lazy::new(CommitCoalescer::default);
+
+// Appends of a table staged by INSERTs, waiting to be committed in one snapshot.
+//
+// The INSERTs wait for the commit, so the context of any of them is alive until it's done.
+struct StagedAppend {
+    id: u64,
+    ctx: Arc<dyn TableContext>,
+    table: Arc<dyn Table>,
+    merged: SnapshotMerged,
+    schema: TableSchemaRef,
+    cluster_key_id: Option<u32>,
+    abort_operation: AbortOperation,
+    waiters: Vec<oneshot::Sender<Result<()>>>,
+}
+
+impl StagedAppend {
+    fn row_count(&self) -> u64 {
+        self.merged.merged_statistics.row_count
+    }
+
+    // The later appends go first, as `AppendGenerator` does with the segments of a snapshot.
+    fn merge(
+        &mut self,
+        ctx: Arc<dyn TableContext>,
+        merged: SnapshotMerged,
+        abort: AbortOperation,
+        waiter: oneshot::Sender<Result<()>>,
+    ) {
+        self.merged.merged_segments = merged
+            .merged_segments
+            .into_iter()
+            .chain(std::mem::take(&mut self.merged.merged_segments))
+            .collect();
+        self.merged.merged_statistics = merge_statistics(
+            &merged.merged_statistics,
+            &self.merged.merged_statistics,
+            self.cluster_key_id,
+        );
+        self.abort_operation.merge(abort);
+        self.ctx = ctx;
+        self.waiters.push(waiter);
+    }
+
+    /// Commits the staged appends, and sends the result to all the INSERTs waiting for it.
+    #[async_backtrace::framed]
+    async fn commit_and_notify(mut self, table_id: u64) {
+        let waiters = std::mem::take(&mut self.waiters);
+        let rows = self.row_count();
+        let result = self.commit().await;
+        match &result {
+            Ok(_) => info!(
+                "coalesced commit of table {} done, {} rows committed",
+                table_id, rows
+            ),
+            Err(e) => error!(
+                "coalesced commit of table {} failed, {} rows of {} INSERTs not committed: {:?}",
+                table_id,
+                rows,
+                waiters.len(),
+                e
+            ),
+        }
+        for waiter in waiters {
+            // The INSERT may have been killed, nothing is waiting for the result then.
+            let _ = waiter.send(result.clone());
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn commit(self) -> Result<()> {
+        let StagedAppend {
+            ctx,
+            table,
+            merged,
+            schema,
+            abort_operation,
+            ..
+        } = self;
+
+        let mut snapshot_gen = AppendGenerator::new(ctx.clone(), false);
+        snapshot_gen
+            .set_conflict_resolve_context(ConflictResolveContext::AppendOnly((merged, schema)));
+        let mut backoff = FuseTable::set_backoff(None);
+        let mut latest = table.refresh(ctx.as_ref()).await?;
+        let mut fill_default = true;
+        loop {
+            let fuse_table = FuseTable::try_from_table(latest.as_ref())?;
+            let operator = fuse_table.get_operator();
+            let previous = fuse_table.read_table_snapshot().await?;
+            let schema = fuse_table.schema().as_ref().clone();
+            if fill_default {
+                snapshot_gen
+                    .fill_default_values(schema.clone(), &previous)
+                    .await?;
+                fill_default = false;
+            }
+
+            let snapshot = match snapshot_gen.generate_new_snapshot(
+                schema,
+                fuse_table.cluster_key_meta.clone(),
+                previous,
+            ) {
+                Ok(snapshot) => snapshot,
+                Err(e) => {
+                    metrics_inc_commit_aborts();
+                    abort_operation.abort(ctx, operator).await?;
+                    return Err(e);
+                }
+            };
+            let location = fuse_table
+                .meta_location_generator
+                .snapshot_location_from_uuid(&snapshot.snapshot_id, TableSnapshot::VERSION)?;
+            operator.write(&location, snapshot.to_bytes()?).await?;
+
+            match FuseTable::update_table_meta(
+                ctx.as_ref(),
+                &fuse_table.table_info,
+                &fuse_table.meta_location_generator,
+                snapshot,
+                location,
+                &None,
+                &operator,
+            )
+            .await
+            {
+                Ok(_) => {
+                    metrics_inc_commit_mutation_success();
+                    return Ok(());
+                }
+                Err(e) if FuseTable::is_error_recoverable(&e, false) => {
+                    match backoff.next_backoff() {
+                        Some(d) => {
+                            tokio::time::sleep(d).await;
+                            latest = latest.refresh(ctx.as_ref()).await?;
+                        }
+                        None => {
+                            if FuseTable::no_side_effects_in_meta_store(&e) {
+                                metrics_inc_commit_aborts();
+                                abort_operation.abort(ctx, operator).await?;
+                            }
+                            return Err(e);
+                        }
+                    }
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// Coalesces the commits of INSERTs into the same table.
+///
+/// The segments written by an INSERT are staged instead of being committed, the staged
+/// segments of a table are committed together once the coalesce window elapsed, or
+/// the staged rows reach the limit. Each INSERT waits for the commit of its segments and
+/// returns its result, so an INSERT never succeeds before its rows are committed.
+#[derive(Default)]
+pub struct CommitCoalescer {
+    next_id: AtomicU64,
+    staged: Mutex<HashMap<u64, StagedAppend>>,
+}
+
+impl CommitCoalescer {
+    pub fn instance() -> &'static CommitCoalescer {
+        &COMMIT_COALESCER
+    }
+
+    #[async_backtrace::framed]
+    pub async fn stage(
+        &'static self,
+        ctx: Arc<dyn TableContext>,
+        table: &FuseTable,
+        conflict_resolve_context: ConflictResolveContext,
+        abort_operation: AbortOperation,
+        window: Duration,
+        max_rows: u64,
+    ) -> Result<()> {
+        let (merged, schema) = match conflict_resolve_context {
+            ConflictResolveContext::AppendOnly(ctx) => ctx,
+            _ => unreachable!("the commit of INSERT is always append only"),
+        };
+
+        let table_id = table.get_id();
+        let (waiter, committed) = oneshot::channel();
+        let mut to_commit = Vec::new();
+        let mut new_staged = None;
+        {
+            let mut staged = self.staged.lock();
+            let new_append = |id, waiter| StagedAppend {
+                id,
+                ctx: ctx.clone(),
+                table: Arc::new(table.clone()),
+                merged: merged.clone(),
+                schema: schema.clone(),
+                cluster_key_id: table.cluster_key_meta.as_ref().map(|v| v.0),
+                abort_operation: abort_operation.clone(),
+                waiters: vec![waiter],
+            };
+            match staged.entry(table_id) {
+                Entry::Occupied(mut entry) if entry.get().schema == schema => {
+                    entry.get_mut().merge(
+                        ctx.clone(),
+                        merged.clone(),
+                        abort_operation.clone(),
+                        waiter,
+                    );
+                }
+                Entry::Occupied(mut entry) => {
+                    // The schema was changed, the staged appends can't be committed together.
+                    let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+                    to_commit.push(entry.insert(new_append(id, waiter)));
+                    new_staged = Some(id);
+                }
+                Entry::Vacant(entry) => {
+                    let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+                    entry.insert(new_append(id, waiter));
+                    new_staged = Some(id);
+                }
+            }
+
+            if staged[&table_id].row_count() >= max_rows {
+                to_commit.extend(staged.remove(&table_id));
+            }
+        }
+
+        if let Some(id) = new_staged {
+            tokio::spawn(async_backtrace::location!().frame(async move {
+                tokio::time::sleep(window).await;
+                self.commit_expired(table_id, id).await;
+            }));
+        }
+
+        // The staged appends reaching the limit are committed by the INSERT itself.
+        for append in to_commit {
+            append.commit_and_notify(table_id).await;
+        }
+
+        committed.await.map_err(|_| {
+            ErrorCode::Internal(format!(
+                "the coalesced commit of table {} was dropped",
+                table_id
+            ))
+        })?
+    }
+
+    #[async_backtrace::framed]
+    async fn commit_expired(&self, table_id: u64, id: u64) {
+        let append = {
+            let mut staged = self.staged.lock();
+            match staged.get(&table_id) {
+                // Already committed, and the staged appends are a new batch.
+                Some(append) if append.id != id => None,
+                _ => staged.remove(&table_id),
+            }
+        };
+
+        if let Some(append) = append {
+            append.commit_and_notify(table_id).await;
+        }
+    }
+}
@@ -13,11 +13,13 @@
 // limitations under the License.
 
 mod abort_operation;
+mod commit_coalescer;
 mod mutation_log;
 mod processors;
 mod snapshot_generator;
 
 pub use abort_operation::AbortOperation;
+pub use commit_coalescer::CommitCoalescer;
 pub use mutation_log::*;
 pub use processors::*;
 pub use snapshot_generator::*;
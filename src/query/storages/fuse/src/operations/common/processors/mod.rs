@@ -14,12 +14,18 @@
 
 mod fill_internal_columns;
 mod sink_commit;
+mod sink_staged_commit;
+mod transform_add_zorder_key;
 mod transform_mutation_aggregator;
+mod transform_partition_scatter;
 mod transform_serialize_block;
 mod transform_serialize_segment;
 
 pub use fill_internal_columns::FillInternalColumnProcessor;
 pub use sink_commit::CommitSink;
+pub use sink_staged_commit::StagedCommitSink;
+pub use transform_add_zorder_key::TransformAddZOrderKey;
 pub use transform_mutation_aggregator::TableMutationAggregator;
+pub use transform_partition_scatter::TransformPartitionScatter;
 pub use transform_serialize_block::TransformSerializeBlock;
 pub use transform_serialize_segment::TransformSerializeSegment;
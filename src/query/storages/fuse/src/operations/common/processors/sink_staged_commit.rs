@@ -0,0 +1,87 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockMetaInfoDowncast;
+use common_expression::DataBlock;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sinks::AsyncSink;
+use common_pipeline_sinks::AsyncSinker;
+
+use crate::operations::common::CommitCoalescer;
+use crate::operations::common::CommitMeta;
+use crate::FuseTable;
+
+// Stages the appended segments to be committed together with the other INSERTs.
+pub struct StagedCommitSink {
+    ctx: Arc<dyn TableContext>,
+    table: FuseTable,
+    window: Duration,
+    max_rows: u64,
+}
+
+impl StagedCommitSink {
+    pub fn try_create(
+        table: &FuseTable,
+        ctx: Arc<dyn TableContext>,
+        input: Arc<InputPort>,
+        window: Duration,
+        max_rows: u64,
+    ) -> Result<ProcessorPtr> {
+        let sinker = AsyncSinker::create(input, StagedCommitSink {
+            ctx,
+            table: table.clone(),
+            window,
+            max_rows,
+        });
+        Ok(ProcessorPtr::create(sinker))
+    }
+}
+
+#[async_trait]
+impl AsyncSink for StagedCommitSink {
+    const NAME: &'static str = "StagedCommitSink";
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        let input_meta = data_block
+            .get_meta()
+            .cloned()
+            .ok_or(ErrorCode::Internal("No block meta. It's a bug"))?;
+        let meta = CommitMeta::downcast_from(input_meta)
+            .ok_or(ErrorCode::Internal("No commit meta. It's a bug"))?;
+
+        self.ctx.set_status_info("stage commit");
+        CommitCoalescer::instance()
+            .stage(
+                self.ctx.clone(),
+                &self.table,
+                meta.conflict_resolve_context,
+                meta.abort_operation,
+                self.window,
+                self.max_rows,
+            )
+            .await?;
+        Ok(true)
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::DataType;
+use common_expression::types::DecimalScalar;
+use common_expression::types::NumberScalar;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::ScalarRef;
+use common_expression::Value;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::Transform;
+use common_pipeline_transforms::processors::transforms::Transformer;
+
+/// Appends the z-order key of the cluster keys to the block, so that the block
+/// can be sorted along the z-order curve by the ordinary sort transforms.
+pub struct TransformAddZOrderKey {
+    cluster_key_index: Vec<usize>,
+}
+
+impl TransformAddZOrderKey {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        cluster_key_index: Vec<usize>,
+    ) -> Result<Box<dyn Processor>> {
+        Ok(Transformer::create(input, output, TransformAddZOrderKey {
+            cluster_key_index,
+        }))
+    }
+}
+
+impl Transform for TransformAddZOrderKey {
+    const NAME: &'static str = "AddZOrderKeyTransform";
+
+    fn transform(&mut self, mut block: DataBlock) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        let columns = self
+            .cluster_key_index
+            .iter()
+            .map(|index| {
+                let entry = block.get_by_offset(*index);
+                entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows)
+            })
+            .collect::<Vec<_>>();
+        let zorder_key = zorder_key_column(&columns, num_rows);
+        block.add_column(BlockEntry {
+            data_type: DataType::String,
+            value: Value::Column(zorder_key),
+        });
+        Ok(block)
+    }
+}
+
+/// Build the z-order keys of the rows by interleaving the bits of the normalized keys,
+/// from the most significant bit to the least.
+///
+/// Comparing the z-order keys as bytes yields the order of the rows along the z-order curve.
+pub fn zorder_key_column(columns: &[Column], num_rows: usize) -> Column {
+    let key_len = columns.len() * 8;
+    let mut builder = StringColumnBuilder::with_capacity(num_rows, num_rows * key_len);
+    let mut keys = vec![0u64; columns.len()];
+    let mut buf = vec![0u8; key_len];
+    for row in 0..num_rows {
+        for (key, column) in keys.iter_mut().zip(columns) {
+            *key = normalize_key(unsafe { column.index_unchecked(row) });
+        }
+        interleave_bits(&keys, &mut buf);
+        builder.put_slice(&buf);
+        builder.commit_row();
+    }
+    Column::String(builder.build())
+}
+
+fn interleave_bits(keys: &[u64], buf: &mut [u8]) {
+    buf.fill(0);
+    let mut pos = 0;
+    for bit in (0..64).rev() {
+        for key in keys {
+            if (key >> bit) & 1 == 1 {
+                buf[pos / 8] |= 0x80 >> (pos % 8);
+            }
+            pos += 1;
+        }
+    }
+}
+
+/// Map the value to an u64 which preserves the order of the values, and whose
+/// most significant bits are the most significant bits of the value, so that
+/// keys of different widths contribute to the z-order at the same pace.
+///
+/// NULLs are mapped to the maximum, i.e. sorted last. Types that can't be
+/// normalized are mapped to 0, and don't take part in the z-order.
+fn normalize_key(value: ScalarRef) -> u64 {
+    const SIGN: u64 = 1 << 63;
+    match value {
+        ScalarRef::Null => u64::MAX,
+        ScalarRef::Boolean(v) => (v as u64) << 63,
+        ScalarRef::Number(v) => match v {
+            NumberScalar::UInt8(v) => (v as u64) << 56,
+            NumberScalar::UInt16(v) => (v as u64) << 48,
+            NumberScalar::UInt32(v) => (v as u64) << 32,
+            NumberScalar::UInt64(v) => v,
+            NumberScalar::Int8(v) => ((v as i64 as u64) << 56) ^ SIGN,
+            NumberScalar::Int16(v) => ((v as i64 as u64) << 48) ^ SIGN,
+            NumberScalar::Int32(v) => ((v as i64 as u64) << 32) ^ SIGN,
+            NumberScalar::Int64(v) => (v as u64) ^ SIGN,
+            NumberScalar::Float32(v) => normalize_f64(v.0 as f64),
+            NumberScalar::Float64(v) => normalize_f64(v.0),
+        },
+        // most decimals are small, they are saturated to i64 rather than keeping the high
+        // bits, which are the same for all of them.
+        ScalarRef::Decimal(v) => match v {
+            DecimalScalar::Decimal128(v, _) => (saturate_i128(v) as u64) ^ SIGN,
+            DecimalScalar::Decimal256(v, _) => {
+                let (high, low) = v.into_words();
+                // the value fits in i128 if the high word only extends the sign of the low one.
+                let v = if high == low >> 127 {
+                    low
+                } else if high < 0 {
+                    i128::MIN
+                } else {
+                    i128::MAX
+                };
+                (saturate_i128(v) as u64) ^ SIGN
+            }
+        },
+        ScalarRef::Timestamp(v) => (v as u64) ^ SIGN,
+        ScalarRef::Date(v) => ((v as i64 as u64) << 32) ^ SIGN,
+        ScalarRef::String(v) => {
+            let mut bytes = [0u8; 8];
+            let len = v.len().min(8);
+            bytes[..len].copy_from_slice(&v[..len]);
+            u64::from_be_bytes(bytes)
+        }
+        _ => 0,
+    }
+}
+
+fn saturate_i128(v: i128) -> i64 {
+    v.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+fn normalize_f64(v: f64) -> u64 {
+    let bits = v.to_bits();
+    if bits >> 63 == 1 {
+        !bits
+    } else {
+        bits ^ (1 << 63)
+    }
+}
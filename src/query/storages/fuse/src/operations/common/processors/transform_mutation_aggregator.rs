@@ -255,12 +255,20 @@ impl TableMutationAggregator {
                     self.default_cluster_key_id,
                 );
 
+                // The write progress counts the rows deleted, updated or inserted, while the
+                // statistics of the rewritten segments also count the untouched rows.
+                let changed_rows = match self.kind {
+                    MutationKind::Recluster => 0,
+                    _ => self.ctx.get_write_progress_value().rows as u64,
+                };
+
                 ConflictResolveContext::ModifiedSegmentExistsInLatest(SnapshotChanges {
                     appended_segments,
                     replaced_segments,
                     removed_segment_indexes,
                     merged_statistics,
                     removed_statistics,
+                    changed_rows,
                 })
             }
         };
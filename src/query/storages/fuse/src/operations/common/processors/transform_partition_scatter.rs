@@ -0,0 +1,87 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::AccumulatingTransform;
+use common_pipeline_transforms::processors::transforms::AccumulatingTransformer;
+
+/// Splits the blocks by the value of the partition key, so that the rows of
+/// a block always belong to the same partition.
+///
+/// Blocks that carry meta info are produced by mutations, which never move rows
+/// across partitions, they are passed through as is.
+pub struct TransformPartitionScatter {
+    partition_key: Expr,
+    func_ctx: FunctionContext,
+}
+
+impl TransformPartitionScatter {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        partition_key: Expr,
+        func_ctx: FunctionContext,
+    ) -> Result<Box<dyn Processor>> {
+        Ok(AccumulatingTransformer::create(
+            input,
+            output,
+            TransformPartitionScatter {
+                partition_key,
+                func_ctx,
+            },
+        ))
+    }
+}
+
+impl AccumulatingTransform for TransformPartitionScatter {
+    const NAME: &'static str = "PartitionScatterTransform";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        if data.is_empty() || data.get_meta().is_some() {
+            return Ok(vec![data]);
+        }
+
+        let evaluator = Evaluator::new(&data, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let partition_values = evaluator.run(&self.partition_key)?;
+        if partition_values.as_scalar().is_some() {
+            return Ok(vec![data]);
+        }
+
+        let mut partitions: BTreeMap<Scalar, Vec<u32>> = BTreeMap::new();
+        for row in 0..data.num_rows() {
+            let value = partition_values.index(row).unwrap().to_owned();
+            partitions.entry(value).or_default().push(row as u32);
+        }
+        if partitions.len() == 1 {
+            return Ok(vec![data]);
+        }
+
+        partitions
+            .values()
+            .map(|indices| data.take(indices))
+            .collect()
+    }
+}
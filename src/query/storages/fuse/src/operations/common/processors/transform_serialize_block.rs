@@ -27,6 +27,7 @@ use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use opendal::Operator;
 use storages_common_index::BloomIndex;
+use storages_common_index::InvertedIndex;
 
 use crate::io::write_data;
 use crate::io::BlockBuilder;
@@ -83,6 +84,10 @@ impl TransformSerializeBlock {
         let bloom_columns_map = table
             .bloom_index_cols
             .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?;
+        let inverted_columns_map = table
+            .inverted_index_cols
+            .bloom_index_fields(source_schema.clone(), InvertedIndex::supported_type)?;
+        let partition_key = table.partition_key_expr(ctx.clone())?;
         let block_builder = BlockBuilder {
             ctx,
             meta_locations: table.meta_location_generator().clone(),
@@ -90,6 +95,9 @@ impl TransformSerializeBlock {
             write_settings: table.get_write_settings(),
             cluster_stats_gen,
             bloom_columns_map,
+            inverted_columns_map,
+            inverted_index_tokenizer: table.inverted_index_tokenizer,
+            partition_key,
         };
         Ok(TransformSerializeBlock {
             state: State::Consume,
@@ -177,6 +185,14 @@ impl Processor for TransformSerializeBlock {
                     Self::mutation_logs(MutationLogEntry::DeletedSegment { deleted_segment });
                 self.output.push_data(Ok(data_block));
                 Ok(Event::NeedConsume)
+            } else if let Some(block_meta) = meta.replaced_block_meta {
+                // the deletion vector of the block is updated, block level
+                let data_block = Self::mutation_logs(MutationLogEntry::ReplacedBlock {
+                    index: meta.index,
+                    block_meta,
+                });
+                self.output.push_data(Ok(data_block));
+                Ok(Event::NeedConsume)
             } else if input_data.is_empty() {
                 // delete a whole block, block level
                 let data_block =
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -22,6 +23,7 @@ use common_exception::Result;
 use common_expression::BlockMetaInfoDowncast;
 use common_expression::BlockThresholds;
 use common_expression::DataBlock;
+use common_expression::Scalar;
 use common_pipeline_core::pipe::PipeItem;
 use common_pipeline_core::processors::port::OutputPort;
 use log::info;
@@ -40,6 +42,7 @@ use crate::pipelines::processors::port::InputPort;
 use crate::pipelines::processors::processor::Event;
 use crate::pipelines::processors::processor::ProcessorPtr;
 use crate::pipelines::processors::Processor;
+use crate::statistics::partition_of;
 use crate::statistics::StatisticsAccumulator;
 use crate::FuseTable;
 use crate::DEFAULT_BLOCK_PER_SEGMENT;
@@ -47,7 +50,9 @@ use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 
 enum State {
     None,
-    GenerateSegment,
+    GenerateSegment {
+        partition: Option<Scalar>,
+    },
     SerializedSegment {
         data: Vec<u8>,
         location: String,
@@ -64,7 +69,8 @@ pub struct TransformSerializeSegment {
     ctx: Arc<dyn TableContext>,
     data_accessor: Operator,
     meta_locations: TableMetaLocationGenerator,
-    accumulator: StatisticsAccumulator,
+    // The blocks of different partitions are never put into the same segment.
+    accumulators: BTreeMap<Option<Scalar>, StatisticsAccumulator>,
     state: State,
     input: Arc<InputPort>,
     output: Arc<OutputPort>,
@@ -92,7 +98,7 @@ impl TransformSerializeSegment {
             data_accessor: table.get_operator(),
             meta_locations: table.meta_location_generator().clone(),
             state: State::None,
-            accumulator: Default::default(),
+            accumulators: BTreeMap::new(),
             block_per_seg: table
                 .get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT)
                 as u64,
@@ -126,7 +132,7 @@ impl Processor for TransformSerializeSegment {
     fn event(&mut self) -> Result<Event> {
         if matches!(
             &self.state,
-            State::GenerateSegment | State::PreCommitSegment { .. }
+            State::GenerateSegment { .. } | State::PreCommitSegment { .. }
         ) {
             return Ok(Event::Sync);
         }
@@ -149,8 +155,13 @@ impl Processor for TransformSerializeSegment {
         }
 
         if self.input.is_finished() {
-            if self.accumulator.summary_row_count != 0 {
-                self.state = State::GenerateSegment;
+            let pending = self
+                .accumulators
+                .iter()
+                .find(|(_, acc)| acc.summary_row_count != 0)
+                .map(|(partition, _)| partition.clone());
+            if let Some(partition) = pending {
+                self.state = State::GenerateSegment { partition };
                 return Ok(Event::Sync);
             }
             self.output.finish();
@@ -170,9 +181,11 @@ impl Processor for TransformSerializeSegment {
                 .ok_or(ErrorCode::Internal("No commit meta. It's a bug"))?
                 .clone();
 
-            self.accumulator.add_with_block_meta(block_meta);
-            if self.accumulator.summary_block_count >= self.block_per_seg {
-                self.state = State::GenerateSegment;
+            let partition = partition_of(&block_meta.col_stats);
+            let accumulator = self.accumulators.entry(partition.clone()).or_default();
+            accumulator.add_with_block_meta(block_meta);
+            if accumulator.summary_block_count >= self.block_per_seg {
+                self.state = State::GenerateSegment { partition };
                 return Ok(Event::Sync);
             }
         }
@@ -183,8 +196,8 @@ impl Processor for TransformSerializeSegment {
 
     fn process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::None) {
-            State::GenerateSegment => {
-                let acc = std::mem::take(&mut self.accumulator);
+            State::GenerateSegment { partition } => {
+                let acc = self.accumulators.remove(&partition).unwrap_or_default();
                 let summary = acc.summary(self.thresholds, self.default_cluster_key_id);
 
                 let segment_info = SegmentInfo::new(acc.blocks_metas, summary);
@@ -68,6 +68,9 @@ pub struct SnapshotChanges {
 
     pub merged_statistics: Statistics,
     pub removed_statistics: Statistics,
+    // The number of rows deleted, updated or inserted by the mutation.
+    #[serde(default)]
+    pub changed_rows: u64,
 }
 
 #[derive(Clone, serde::Serialize, serde::Deserialize, Debug, PartialEq)]
@@ -229,7 +232,7 @@ impl SnapshotGenerator for MutationGenerator {
                         &append_statistics,
                         default_cluster_key_id,
                     );
-                    let new_snapshot = TableSnapshot::new(
+                    let mut new_snapshot = TableSnapshot::new(
                         Uuid::new_v4(),
                         &previous.timestamp,
                         Some((previous.snapshot_id, previous.format_version)),
@@ -239,6 +242,9 @@ impl SnapshotGenerator for MutationGenerator {
                         cluster_key_meta,
                         previous.table_statistics_location.clone(),
                     );
+                    // The compaction changes no rows, and the appends are already counted in
+                    // the latest snapshot.
+                    new_snapshot.rows_changed_since_analyze = previous.rows_changed_since_analyze;
                     return Ok(new_snapshot);
                 }
             }
@@ -265,7 +271,7 @@ impl SnapshotGenerator for MutationGenerator {
                         default_cluster_key_id,
                     );
                     deduct_statistics_mut(&mut new_summary, &ctx.removed_statistics);
-                    let new_snapshot = TableSnapshot::new(
+                    let mut new_snapshot = TableSnapshot::new(
                         Uuid::new_v4(),
                         &previous.timestamp,
                         Some((previous.snapshot_id, previous.format_version)),
@@ -275,6 +281,8 @@ impl SnapshotGenerator for MutationGenerator {
                         cluster_key_meta,
                         previous.table_statistics_location.clone(),
                     );
+                    new_snapshot.rows_changed_since_analyze =
+                        previous.rows_changed_since_analyze + ctx.changed_rows;
                     return Ok(new_snapshot);
                 }
             }
@@ -382,6 +390,7 @@ impl SnapshotGenerator for AppendGenerator {
         let mut prev_timestamp = None;
         let mut prev_snapshot_id = None;
         let mut table_statistics_location = None;
+        let mut rows_changed_since_analyze = snapshot_merged.merged_statistics.row_count;
         let mut new_segments = snapshot_merged.merged_segments.clone();
         let mut new_summary = snapshot_merged.merged_statistics.clone();
 
@@ -389,8 +398,11 @@ impl SnapshotGenerator for AppendGenerator {
             prev_timestamp = snapshot.timestamp;
             prev_snapshot_id = Some((snapshot.snapshot_id, snapshot.format_version));
             table_statistics_location = snapshot.table_statistics_location.clone();
-
-            if !self.overwrite {
+            rows_changed_since_analyze += snapshot.rows_changed_since_analyze;
+            if self.overwrite {
+                // all the existing rows are replaced.
+                rows_changed_since_analyze += snapshot.summary.row_count;
+            } else {
                 let mut summary = snapshot.summary.clone();
                 if self.check_fill_default(&summary)? {
                     self.leaf_default_values
@@ -429,7 +441,7 @@ impl SnapshotGenerator for AppendGenerator {
             }
         }
 
-        Ok(TableSnapshot::new(
+        let mut new_snapshot = TableSnapshot::new(
             Uuid::new_v4(),
             &prev_timestamp,
             prev_snapshot_id,
@@ -438,7 +450,9 @@ impl SnapshotGenerator for AppendGenerator {
             new_segments,
             cluster_key_meta,
             table_statistics_location,
-        ))
+        );
+        new_snapshot.rows_changed_since_analyze = rows_changed_since_analyze;
+        Ok(new_snapshot)
     }
 }
 
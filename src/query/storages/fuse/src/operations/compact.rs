@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_catalog::plan::Projection;
 use common_catalog::table::CompactTarget;
 use common_exception::Result;
+use common_expression::Scalar;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_transforms::processors::transforms::AsyncAccumulatingTransformer;
 use storages_common_table_meta::meta::TableSnapshot;
@@ -34,6 +35,7 @@ use crate::Table;
 use crate::TableContext;
 use crate::DEFAULT_BLOCK_PER_SEGMENT;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use crate::FUSE_OPT_KEY_COMPACT_VARIANT;
 
 #[derive(Clone)]
 pub struct CompactOptions {
@@ -41,6 +43,8 @@ pub struct CompactOptions {
     pub base_snapshot: Arc<TableSnapshot>,
     pub block_per_seg: usize,
     pub limit: Option<usize>,
+    // only compact the segments of this partition, if specified.
+    pub partition: Option<Scalar>,
 }
 
 impl FuseTable {
@@ -50,6 +54,7 @@ impl FuseTable {
         ctx: Arc<dyn TableContext>,
         target: CompactTarget,
         limit: Option<usize>,
+        partition: Option<Scalar>,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
         let snapshot_opt = self.read_table_snapshot().await?;
@@ -71,6 +76,7 @@ impl FuseTable {
             base_snapshot,
             block_per_seg,
             limit,
+            partition,
         };
 
         match target {
@@ -79,6 +85,20 @@ impl FuseTable {
         }
     }
 
+    /// Compact the segments or blocks of the given partition only.
+    #[async_backtrace::framed]
+    pub async fn compact_partition(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        target: CompactTarget,
+        partition: Scalar,
+        limit: Option<usize>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        self.do_compact(ctx, target, limit, Some(partition), pipeline)
+            .await
+    }
+
     #[async_backtrace::framed]
     async fn compact_segments(
         &self,
@@ -141,6 +161,7 @@ impl FuseTable {
             ctx.get_settings().get_max_threads()? as usize,
             mutator.compact_tasks.len(),
         );
+        let compact_variant = self.get_option(FUSE_OPT_KEY_COMPACT_VARIANT, false);
         // Add source pipe.
         pipeline.add_source(
             |output| {
@@ -148,6 +169,7 @@ impl FuseTable {
                     ctx.clone(),
                     self.storage_format,
                     block_reader.clone(),
+                    compact_variant,
                     output,
                 )
             },
@@ -258,6 +258,8 @@ impl FuseTable {
                     ops.clone(),
                     self.storage_format,
                     query_row_id_col,
+                    self.meta_location_generator().clone(),
+                    self.change_tracking_enabled(),
                 )
             },
             max_threads,
@@ -380,6 +382,7 @@ impl FuseTable {
                             cluster_stats,
                             c,
                             whole_block_deletion,
+                            block_meta,
                         )),
                     ));
                     part_info_ptr
@@ -81,6 +81,7 @@ impl FuseTable {
                 table_id: self.get_id(),
             })
             .await?;
+        let shared_locations = self.locations_shared_with_clones(ctx).await?;
 
         // 2. Read snapshot fields by chunk size.
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
@@ -164,6 +165,7 @@ impl FuseTable {
                         ts_to_be_purged,
                         snapshots_to_be_purged,
                         &table_agg_index_ids,
+                        &shared_locations,
                     )
                     .await?;
 
@@ -179,6 +181,7 @@ impl FuseTable {
                         ts_to_be_purged,
                         snapshots_to_be_purged,
                         &table_agg_index_ids,
+                        &shared_locations,
                     )
                     .await?;
 
@@ -219,6 +222,7 @@ impl FuseTable {
                     ts_to_be_purged,
                     snapshots_to_be_purged,
                     &table_agg_index_ids,
+                    &shared_locations,
                 )
                 .await?;
             } else {
@@ -230,6 +234,7 @@ impl FuseTable {
                     ts_to_be_purged,
                     snapshots_to_be_purged,
                     &table_agg_index_ids,
+                    &shared_locations,
                 )
                 .await?;
             }
@@ -248,6 +253,7 @@ impl FuseTable {
                 root_snapshot_info.referenced_locations,
                 root_snapshot_info.snapshot_location,
                 &table_agg_index_ids,
+                &shared_locations,
             )
             .await?;
         }
@@ -314,6 +320,7 @@ impl FuseTable {
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
         table_agg_index_ids: &[u64],
+        shared_locations: &HashSet<String>,
     ) -> Result<()> {
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
         // Purge segments&blocks by chunk size
@@ -325,7 +332,9 @@ impl FuseTable {
                 .await?;
 
             for loc in &locations.block_location {
-                if locations_referenced_by_root.block_location.contains(loc) {
+                if locations_referenced_by_root.block_location.contains(loc)
+                    || !self.is_purgeable_location(loc, shared_locations)
+                {
                     continue;
                 }
                 purge_files.push(loc.to_string());
@@ -339,13 +348,31 @@ impl FuseTable {
             }
 
             for loc in &locations.bloom_location {
-                if locations_referenced_by_root.bloom_location.contains(loc) {
+                if locations_referenced_by_root.bloom_location.contains(loc)
+                    || !self.is_purgeable_location(loc, shared_locations)
+                {
+                    continue;
+                }
+                purge_files.push(loc.to_string())
+            }
+
+            for loc in &locations.deletion_vector_location {
+                if locations_referenced_by_root
+                    .deletion_vector_location
+                    .contains(loc)
+                    || !self.is_purgeable_location(loc, shared_locations)
+                {
                     continue;
                 }
                 purge_files.push(loc.to_string())
             }
 
-            purge_files.extend(chunk.iter().map(|loc| loc.0.clone()));
+            purge_files.extend(
+                chunk
+                    .iter()
+                    .filter(|loc| self.is_purgeable_location(&loc.0, shared_locations))
+                    .map(|loc| loc.0.clone()),
+            );
         }
         purge_files.extend(ts_to_be_purged.iter().map(|loc| loc.to_string()));
         purge_files.extend(snapshots_to_be_purged.iter().map(|loc| loc.to_string()));
@@ -363,6 +390,7 @@ impl FuseTable {
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
         table_agg_index_ids: &[u64],
+        shared_locations: &HashSet<String>,
     ) -> Result<()> {
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
         // Purge segments&blocks by chunk size
@@ -398,6 +426,17 @@ impl FuseTable {
                 blooms_to_be_purged.insert(loc.to_string());
             }
 
+            let mut deletion_vectors_to_be_purged = HashSet::new();
+            for loc in &locations.deletion_vector_location {
+                if locations_referenced_by_root
+                    .deletion_vector_location
+                    .contains(loc)
+                {
+                    continue;
+                }
+                deletion_vectors_to_be_purged.insert(loc.to_string());
+            }
+
             let segment_locations_to_be_purged = HashSet::from_iter(
                 chunk
                     .iter()
@@ -423,7 +462,9 @@ impl FuseTable {
                 blocks_to_be_purged,
                 agg_indexes_to_be_purged,
                 blooms_to_be_purged,
+                deletion_vectors_to_be_purged,
                 segment_locations_to_be_purged,
+                shared_locations,
             )
             .await?;
         }
@@ -432,6 +473,7 @@ impl FuseTable {
             .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn purge_root_snapshot(
         &self,
         ctx: &Arc<dyn TableContext>,
@@ -440,6 +482,7 @@ impl FuseTable {
         root_location_tuple: LocationTuple,
         root_snapshot_location: String,
         table_agg_index_ids: &[u64],
+        shared_locations: &HashSet<String>,
     ) -> Result<()> {
         let segment_locations_to_be_purged = HashSet::from_iter(
             root_snapshot
@@ -464,7 +507,9 @@ impl FuseTable {
             root_location_tuple.block_location,
             agg_indexes_to_be_purged,
             root_location_tuple.bloom_location,
+            root_location_tuple.deletion_vector_location,
             segment_locations_to_be_purged,
+            shared_locations,
         )
         .await?;
 
@@ -481,15 +526,27 @@ impl FuseTable {
         .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn purge_block_segments(
         &self,
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
-        blocks_to_be_purged: HashSet<String>,
-        agg_indexes_to_be_purged: HashSet<String>,
-        blooms_to_be_purged: HashSet<String>,
-        segments_to_be_purged: HashSet<String>,
+        mut blocks_to_be_purged: HashSet<String>,
+        mut agg_indexes_to_be_purged: HashSet<String>,
+        mut blooms_to_be_purged: HashSet<String>,
+        mut deletion_vectors_to_be_purged: HashSet<String>,
+        mut segments_to_be_purged: HashSet<String>,
+        shared_locations: &HashSet<String>,
     ) -> Result<()> {
+        // The files of other tables, and the ones shared with the clones of this table,
+        // are never purged.
+        blocks_to_be_purged.retain(|loc| self.is_purgeable_location(loc, shared_locations));
+        agg_indexes_to_be_purged.retain(|loc| self.is_owned_location(loc));
+        blooms_to_be_purged.retain(|loc| self.is_purgeable_location(loc, shared_locations));
+        deletion_vectors_to_be_purged
+            .retain(|loc| self.is_purgeable_location(loc, shared_locations));
+        segments_to_be_purged.retain(|loc| self.is_purgeable_location(loc, shared_locations));
+
         // 1. Try to purge block file chunks.
         let blocks_count = blocks_to_be_purged.len();
         if blocks_count > 0 {
@@ -516,6 +573,13 @@ impl FuseTable {
             .await?;
         }
 
+        let deletion_vectors_count = deletion_vectors_to_be_purged.len();
+        if deletion_vectors_count > 0 {
+            counter.deletion_vectors += deletion_vectors_count;
+            self.try_purge_location_files(ctx.clone(), deletion_vectors_to_be_purged)
+                .await?;
+        }
+
         // 3. Try to purge segment file chunks.
         let segments_count = segments_to_be_purged.len();
         if segments_count > 0 {
@@ -561,9 +625,10 @@ impl FuseTable {
         // 5. Refresh status.
         {
             let status = format!(
-                "gc: block files purged:{}, bloom files purged:{}, segment files purged:{}, table statistic files purged:{}, snapshots purged:{}, take:{} sec",
+                "gc: block files purged:{}, bloom files purged:{}, deletion vector files purged:{}, segment files purged:{}, table statistic files purged:{}, snapshots purged:{}, take:{} sec",
                 counter.blocks,
                 counter.blooms,
+                counter.deletion_vectors,
                 counter.segments,
                 counter.table_statistics,
                 counter.snapshots,
@@ -617,6 +682,7 @@ impl FuseTable {
     ) -> Result<LocationTuple> {
         let mut blocks = HashSet::new();
         let mut blooms = HashSet::new();
+        let mut deletion_vectors = HashSet::new();
 
         let fuse_segments = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
@@ -640,15 +706,21 @@ impl FuseTable {
                 };
                 blocks.extend(location_tuple.block_location.into_iter());
                 blooms.extend(location_tuple.bloom_location.into_iter());
+                deletion_vectors.extend(location_tuple.deletion_vector_location.into_iter());
             }
         }
 
         Ok(LocationTuple {
             block_location: blocks,
             bloom_location: blooms,
+            deletion_vector_location: deletion_vectors,
         })
     }
 
+    fn is_purgeable_location(&self, location: &str, shared_locations: &HashSet<String>) -> bool {
+        self.is_owned_location(location) && !shared_locations.contains(location)
+    }
+
     pub async fn list_snapshot_files(&self) -> Result<Vec<String>> {
         let prefix = format!(
             "{}/{}/",
@@ -669,6 +741,7 @@ struct RootSnapshotInfo {
 pub struct LocationTuple {
     pub block_location: HashSet<String>,
     pub bloom_location: HashSet<String>,
+    pub deletion_vector_location: HashSet<String>,
 }
 
 impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
@@ -676,16 +749,21 @@ impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
     fn try_from(value: Arc<CompactSegmentInfo>) -> Result<Self> {
         let mut block_location = HashSet::new();
         let mut bloom_location = HashSet::new();
+        let mut deletion_vector_location = HashSet::new();
         let block_metas = value.block_metas()?;
         for block_meta in block_metas.into_iter() {
             block_location.insert(block_meta.location.0.clone());
             if let Some(bloom_loc) = &block_meta.bloom_filter_index_location {
                 bloom_location.insert(bloom_loc.0.clone());
             }
+            if let Some(dv_loc) = &block_meta.deletion_vector_location {
+                deletion_vector_location.insert(dv_loc.0.clone());
+            }
         }
         Ok(Self {
             block_location,
             bloom_location,
+            deletion_vector_location,
         })
     }
 }
@@ -695,6 +773,7 @@ struct PurgeCounter {
     blocks: usize,
     agg_indexes: usize,
     blooms: usize,
+    deletion_vectors: usize,
     segments: usize,
     table_statistics: usize,
     snapshots: usize,
@@ -707,6 +786,7 @@ impl PurgeCounter {
             blocks: 0,
             agg_indexes: 0,
             blooms: 0,
+            deletion_vectors: 0,
             segments: 0,
             table_statistics: 0,
             snapshots: 0,
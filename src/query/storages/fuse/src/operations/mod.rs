@@ -15,6 +15,7 @@
 mod agg_index_sink;
 mod analyze;
 mod append;
+mod clone;
 mod commit;
 pub mod common;
 mod compact;
@@ -22,6 +23,7 @@ mod delete;
 mod gc;
 mod mutation;
 mod navigate;
+mod partition;
 mod read;
 mod read_data;
 mod read_partitions;
@@ -32,8 +34,14 @@ mod revert;
 mod truncate;
 mod update;
 pub mod util;
+mod vacuum;
 
 pub use agg_index_sink::AggIndexSink;
+pub use clone::cloned_from_option;
+pub use clone::get_cloned_by;
+pub use clone::get_cloned_from;
+pub use clone::list_clones;
+pub use clone::register_clone;
 pub use common::BlockMetaIndex;
 pub use common::FillInternalColumnProcessor;
 pub use common::TransformSerializeBlock;
@@ -44,5 +52,7 @@ pub use mutation::ReclusterMutator;
 pub use mutation::SegmentCompactMutator;
 pub use mutation::SegmentCompactionState;
 pub use mutation::SegmentCompactor;
+pub use partition::PartitionStatistics;
 pub use read::build_row_fetcher_pipeline;
 pub use util::column_parquet_metas;
+pub use vacuum::VacuumStatistics;
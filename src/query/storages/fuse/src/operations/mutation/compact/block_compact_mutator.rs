@@ -25,6 +25,7 @@ use common_catalog::plan::PartitionsShuffleKind;
 use common_exception::Result;
 use common_expression::BlockThresholds;
 use common_expression::ColumnId;
+use common_expression::Scalar;
 use opendal::Operator;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::Location;
@@ -36,6 +37,7 @@ use crate::operations::common::BlockMetaIndex;
 use crate::operations::mutation::CompactPartInfo;
 use crate::operations::mutation::MAX_BLOCK_COUNT;
 use crate::operations::CompactOptions;
+use crate::statistics::partition_of;
 use crate::statistics::reducers::deduct_statistics_mut;
 use crate::statistics::sort_by_cluster_stats;
 use crate::TableContext;
@@ -107,7 +109,8 @@ impl BlockCompactMutator {
             self.operator.clone(),
             Arc::new(self.compact_params.base_snapshot.schema.clone()),
         );
-        let mut checker = SegmentCompactChecker::new(self.compact_params.block_per_seg as u64);
+        // The segments of different partitions are never compacted together.
+        let mut checkers: BTreeMap<Option<Scalar>, SegmentCompactChecker> = BTreeMap::new();
         let chunk_size = self.ctx.get_settings().get_max_threads()? as usize * 4;
         let mut is_end = false;
         for chunk in segment_locations.chunks(chunk_size) {
@@ -140,7 +143,20 @@ impl BlockCompactMutator {
                     continue;
                 }
 
-                let segments_vec = checker.add(loc.clone(), segment);
+                let partition = partition_of(&segment.summary.col_stats);
+                if self.compact_params.partition.is_some()
+                    && partition != self.compact_params.partition
+                {
+                    self.unchanged_segments_map.insert(segment_idx, loc.clone());
+                    segment_idx += 1;
+                    continue;
+                }
+
+                let block_per_seg = self.compact_params.block_per_seg as u64;
+                let segments_vec = checkers
+                    .entry(partition)
+                    .or_insert_with(|| SegmentCompactChecker::new(block_per_seg))
+                    .add(loc.clone(), segment);
                 for segments in segments_vec {
                     if SegmentCompactChecker::check_for_compact(&segments) {
                         self.compacted_segment_cnt += segments.len();
@@ -158,12 +174,18 @@ impl BlockCompactMutator {
                     segment_idx += 1;
                 }
 
-                if self.compacted_segment_cnt + checker.segments.len() >= limit
+                let pending_segment_cnt = checkers
+                    .values()
+                    .map(|checker| checker.segments.len())
+                    .sum::<usize>();
+                if self.compacted_segment_cnt + pending_segment_cnt >= limit
                     || compacted_block_cnt >= MAX_BLOCK_COUNT
                 {
                     // The remaining segments needs to be pushed into unchanged_map,
                     // so execute finalize here.
-                    self.finalize(std::mem::take(&mut checker.segments), &mut segment_idx);
+                    for checker in checkers.values_mut() {
+                        self.finalize(std::mem::take(&mut checker.segments), &mut segment_idx);
+                    }
                     is_end = true;
                 }
             }
@@ -187,7 +209,9 @@ impl BlockCompactMutator {
         }
 
         // finalize the compaction.
-        self.finalize(std::mem::take(&mut checker.segments), &mut segment_idx);
+        for checker in checkers.values_mut() {
+            self.finalize(std::mem::take(&mut checker.segments), &mut segment_idx);
+        }
 
         // combine with the unprocessed segments (which are outside of the limit).
         for segment_location in segment_locations[checked_end_at..].iter() {
@@ -315,6 +339,11 @@ impl SegmentCompactChecker {
         segments.len() != 1
             || (segments[0].1.summary.block_count > 1
                 && segments[0].1.summary.perfect_block_count != segments[0].1.summary.block_count)
+            || segments[0]
+                .1
+                .blocks
+                .iter()
+                .any(|b| b.deletion_vector_location.is_some())
     }
 
     fn add(
@@ -383,7 +412,7 @@ impl CompactTaskBuilder {
             }
         }
 
-        let total_rows = self.total_rows + block.row_count as usize;
+        let total_rows = self.total_rows + block.live_row_count() as usize;
         let total_size = self.total_size + block.block_size as usize;
         if !thresholds.check_large_enough(total_rows, total_size) {
             // blocks < N
@@ -419,6 +448,11 @@ impl CompactTaskBuilder {
     }
 
     fn check_compact(&self, block: &Arc<BlockMeta>) -> bool {
+        if block.deletion_vector_location.is_some() {
+            // Fold the deletion vector into the block.
+            return true;
+        }
+
         let column_ids: HashSet<ColumnId> = block.col_metas.keys().cloned().collect();
         if self.column_ids == column_ids {
             // Check if the block needs to be resort.
@@ -21,10 +21,16 @@ use common_catalog::plan::PartInfoPtr;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::nullable::NullableColumn;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::BlockEntry;
+use common_expression::Column;
 use common_expression::DataBlock;
+use common_expression::Value;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::io::ReadSettings;
 use crate::metrics::*;
 use crate::operations::mutation::mutation_meta::ClusterStatsGenType;
@@ -48,6 +54,8 @@ pub struct CompactSource {
     ctx: Arc<dyn TableContext>,
     block_reader: Arc<BlockReader>,
     storage_format: FuseStorageFormat,
+    // re-encode the variant columns with sorted object keys.
+    compact_variant: bool,
     output: Arc<OutputPort>,
 }
 
@@ -56,6 +64,7 @@ impl CompactSource {
         ctx: Arc<dyn TableContext>,
         storage_format: FuseStorageFormat,
         block_reader: Arc<BlockReader>,
+        compact_variant: bool,
         output: Arc<OutputPort>,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(CompactSource {
@@ -63,11 +72,53 @@ impl CompactSource {
             ctx,
             block_reader,
             storage_format,
+            compact_variant,
             output,
         })))
     }
 }
 
+/// Re-encode the variant values into canonical jsonb, the object keys are sorted,
+/// so that the same documents have the same bytes, and are deduplicated by the
+/// dictionary encoding of the block.
+fn canonicalize_variant_columns(block: DataBlock) -> DataBlock {
+    let num_rows = block.num_rows();
+    let columns = block
+        .columns()
+        .iter()
+        .map(|entry| match &entry.value {
+            Value::Column(column) => BlockEntry::new(
+                entry.data_type.clone(),
+                Value::Column(canonicalize_variant_column(column)),
+            ),
+            Value::Scalar(_) => entry.clone(),
+        })
+        .collect();
+    DataBlock::new(columns, num_rows)
+}
+
+fn canonicalize_variant_column(column: &Column) -> Column {
+    match column {
+        Column::Variant(col) => {
+            let mut builder = StringColumnBuilder::with_capacity(col.len(), col.data().len());
+            for value in col.iter() {
+                match jsonb::from_slice(value) {
+                    Ok(value) => value.write_to_vec(&mut builder.data),
+                    // keep the invalid values as is.
+                    Err(_) => builder.put_slice(value),
+                }
+                builder.commit_row();
+            }
+            Column::Variant(builder.build())
+        }
+        Column::Nullable(col) => Column::Nullable(Box::new(NullableColumn {
+            column: canonicalize_variant_column(&col.column),
+            validity: col.validity.clone(),
+        })),
+        _ => column.clone(),
+    }
+}
+
 #[async_trait::async_trait]
 impl Processor for CompactSource {
     fn name(&self) -> String {
@@ -125,6 +176,11 @@ impl Processor for CompactSource {
                 } else {
                     DataBlock::concat(&blocks)?
                 };
+                let block = if self.compact_variant {
+                    canonicalize_variant_columns(block)
+                } else {
+                    block
+                };
 
                 let meta = SerializeDataMeta::create(index, ClusterStatsGenType::Generally);
                 let new_block = block.add_meta(Some(meta))?;
@@ -165,9 +221,21 @@ impl Processor for CompactSource {
                             metrics_inc_compact_block_read_bytes(block.block_size);
                         }
 
-                        block_reader
+                        let data_block = block_reader
                             .read_by_meta(&settings, block.as_ref(), &storage_format)
-                            .await
+                            .await?;
+
+                        // fold the deletion vector into the compacted block.
+                        match &block.deletion_vector_location {
+                            Some((location, _)) => {
+                                let deletion_vector =
+                                    DeletionVector::read(&block_reader.operator, location).await?;
+                                let (data_block, _) =
+                                    DeletionVector::apply(data_block, &deletion_vector)?;
+                                Ok::<_, ErrorCode>(data_block)
+                            }
+                            None => Ok(data_block),
+                        }
                     });
                 }
 
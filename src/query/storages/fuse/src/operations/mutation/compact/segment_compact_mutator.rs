@@ -17,6 +17,7 @@ use std::time::Instant;
 
 use common_catalog::table::Table;
 use common_exception::Result;
+use common_expression::Scalar;
 use log::info;
 use metrics::gauge;
 use opendal::Operator;
@@ -30,6 +31,7 @@ use crate::io::SegmentsIO;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::common::AbortOperation;
 use crate::operations::CompactOptions;
+use crate::statistics::partition_of;
 use crate::statistics::reducers::merge_statistics_mut;
 use crate::statistics::sort_by_cluster_stats;
 use crate::FuseTable;
@@ -106,7 +108,8 @@ impl SegmentCompactMutator {
             chunk_size,
             &fuse_segment_io,
             segment_writer,
-        );
+        )
+        .with_partition(self.compact_params.partition.clone());
 
         self.compaction = compactor
             .compact(base_segment_locations, limit, |status| {
@@ -181,6 +184,8 @@ pub struct SegmentCompactor<'a> {
     segment_writer: SegmentWriter<'a>,
     // accumulated compaction state
     compacted_state: SegmentCompactionState,
+    // if specified, only the segments of this partition are compacted
+    partition: Option<Scalar>,
 }
 
 impl<'a> SegmentCompactor<'a> {
@@ -200,9 +205,15 @@ impl<'a> SegmentCompactor<'a> {
             segment_reader,
             segment_writer,
             compacted_state: Default::default(),
+            partition: None,
         }
     }
 
+    pub fn with_partition(mut self, partition: Option<Scalar>) -> Self {
+        self.partition = partition;
+        self
+    }
+
     #[async_backtrace::framed]
     pub async fn compact<T>(
         mut self,
@@ -303,6 +314,20 @@ impl<'a> SegmentCompactor<'a> {
             return Ok(());
         }
 
+        let partition = partition_of(&segment_info.summary.col_stats);
+        if self.partition.is_some() && partition != self.partition {
+            // segments of other partitions are kept as they are
+            self.compact_fragments().await?;
+            self.compacted_state.segments_locations.push(location);
+            return Ok(());
+        }
+        // segments of different partitions are never compacted together
+        if let Some((fragment, _)) = self.fragmented_segments.first() {
+            if partition_of(&fragment.summary.col_stats) != partition {
+                self.compact_fragments().await?;
+            }
+        }
+
         let s = self.accumulated_num_blocks + num_blocks_current_segment;
 
         if s < self.threshold {
@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_expression::BlockMetaInfo;
 use common_expression::BlockMetaInfoDowncast;
 use common_expression::BlockMetaInfoPtr;
+use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
 
 use crate::operations::common::BlockMetaIndex;
@@ -31,6 +34,8 @@ pub struct SerializeDataMeta {
     pub index: BlockMetaIndex,
     pub stats_type: ClusterStatsGenType,
     pub deleted_segment: Option<MutationDeletedSegment>,
+    /// The meta of the block with its deletion vector updated, the block data is not rewritten.
+    pub replaced_block_meta: Option<Arc<BlockMeta>>,
 }
 
 #[typetag::serde(name = "serialize_data_meta")]
@@ -50,6 +55,19 @@ impl SerializeDataMeta {
             index,
             stats_type,
             deleted_segment: None,
+            replaced_block_meta: None,
+        })
+    }
+
+    pub fn create_with_replaced_block_meta(
+        index: BlockMetaIndex,
+        replaced_block_meta: Arc<BlockMeta>,
+    ) -> BlockMetaInfoPtr {
+        Box::new(SerializeDataMeta {
+            index,
+            stats_type: ClusterStatsGenType::Generally, // default value
+            deleted_segment: None,
+            replaced_block_meta: Some(replaced_block_meta),
         })
     }
 
@@ -60,6 +78,7 @@ impl SerializeDataMeta {
             index: BlockMetaIndex::default(),           // default value
             stats_type: ClusterStatsGenType::Generally, // default value
             deleted_segment: Some(deleted_segment),
+            replaced_block_meta: None,
         })
     }
 }
@@ -13,12 +13,14 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::sync::Arc;
 
 use common_catalog::plan::PartInfo;
 use common_catalog::plan::PartInfoPtr;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use storages_common_pruner::BlockMetaIndex;
+use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
 
 use crate::pruning::DeletedSegmentInfo;
@@ -98,6 +100,8 @@ pub struct MutationPartInfo {
     pub cluster_stats: Option<ClusterStatistics>,
     pub inner_part: PartInfoPtr,
     pub whole_block_mutation: bool,
+    /// The meta of the block to be mutated, kept to record a deletion vector for it.
+    pub block_meta: Arc<BlockMeta>,
 }
 
 #[typetag::serde(name = "mutation")]
@@ -123,12 +127,14 @@ impl MutationPartInfo {
         cluster_stats: Option<ClusterStatistics>,
         inner_part: PartInfoPtr,
         whole_block_mutation: bool,
+        block_meta: Arc<BlockMeta>,
     ) -> Self {
         MutationPartInfo {
             index,
             cluster_stats,
             inner_part,
             whole_block_mutation,
+            block_meta,
         }
     }
 }
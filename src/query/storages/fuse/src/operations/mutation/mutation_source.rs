@@ -34,10 +34,15 @@ use common_expression::Value;
 use common_expression::ROW_ID_COL_NAME;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_sql::evaluator::BlockOperator;
+use roaring::RoaringBitmap;
+use storages_common_table_meta::meta::BlockMeta;
 
 use crate::fuse_part::FusePartInfo;
+use crate::io::write_data;
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::io::ReadSettings;
+use crate::io::TableMetaLocationGenerator;
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::mutation::mutation_meta::ClusterStatsGenType;
 use crate::operations::mutation::Mutation;
@@ -69,6 +74,7 @@ enum State {
         filter: Option<Value<BooleanType>>,
     },
     PerformOperator(DataBlock),
+    WriteDeletionVector(RoaringBitmap),
     Output(Option<PartInfoPtr>, DataBlock),
     Finish,
 }
@@ -88,6 +94,11 @@ pub struct MutationSource {
 
     index: BlockMetaIndex,
     stats_type: ClusterStatsGenType,
+
+    location_gen: TableMetaLocationGenerator,
+    deletion_vector_max_percent: u64,
+    block_meta: Option<Arc<BlockMeta>>,
+    deletion_vector: Option<RoaringBitmap>,
 }
 
 impl MutationSource {
@@ -102,7 +113,17 @@ impl MutationSource {
         operators: Vec<BlockOperator>,
         storage_format: FuseStorageFormat,
         query_row_id_col: bool,
+        location_gen: TableMetaLocationGenerator,
+        change_tracking: bool,
     ) -> Result<ProcessorPtr> {
+        // the deletion vectors are only applied while reading blocks of parquet format,
+        // and are never written for tables tracked by streams, which diff the block locations.
+        let deletion_vector_max_percent = match storage_format {
+            FuseStorageFormat::Parquet if !change_tracking => {
+                ctx.get_settings().get_deletion_vector_max_percent()?
+            }
+            _ => 0,
+        };
         Ok(ProcessorPtr::create(Box::new(MutationSource {
             state: State::ReadData(None),
             output,
@@ -116,10 +137,31 @@ impl MutationSource {
             query_row_id_col,
             index: BlockMetaIndex::default(),
             stats_type: ClusterStatsGenType::Generally,
+            location_gen,
+            deletion_vector_max_percent,
+            block_meta: None,
+            deletion_vector: None,
         })))
     }
 }
 
+impl MutationSource {
+    // Check whether the deletion is small enough to be recorded in the deletion vector,
+    // the rows deleted previously are taken into account.
+    fn should_write_deletion_vector(&self, affect_rows: usize) -> bool {
+        if self.deletion_vector_max_percent == 0 {
+            return false;
+        }
+        match &self.block_meta {
+            Some(block_meta) => {
+                let deleted_rows = block_meta.deleted_row_count + affect_rows as u64;
+                deleted_rows * 100 <= block_meta.row_count * self.deletion_vector_max_percent
+            }
+            None => false,
+        }
+    }
+}
+
 #[async_trait::async_trait]
 impl Processor for MutationSource {
     fn name(&self) -> String {
@@ -162,7 +204,10 @@ impl Processor for MutationSource {
             }
         }
 
-        if matches!(self.state, State::ReadData(_) | State::ReadRemain { .. }) {
+        if matches!(
+            self.state,
+            State::ReadData(_) | State::ReadRemain { .. } | State::WriteDeletionVector(_)
+        ) {
             Ok(Event::Async)
         } else {
             Ok(Event::Sync)
@@ -178,6 +223,15 @@ impl Processor for MutationSource {
                     chunks,
                     &self.storage_format,
                 )?;
+                // the rows deleted by the deletion vector are not visible to the mutation.
+                let offsets = match &self.deletion_vector {
+                    Some(deletion_vector) => {
+                        let (block, offsets) = DeletionVector::apply(data_block, deletion_vector)?;
+                        data_block = block;
+                        Some(offsets)
+                    }
+                    None => None,
+                };
                 let num_rows = data_block.num_rows();
 
                 if let Some(filter) = self.filter.as_ref() {
@@ -191,7 +245,7 @@ impl Processor for MutationSource {
                             block_location: block_meta.block_location.clone(),
                             segment_location: block_meta.segment_location.clone(),
                             snapshot_location: None,
-                            offsets: None,
+                            offsets: offsets.clone(),
                         };
                         let internal_col = InternalColumn {
                             column_name: ROW_ID_COL_NAME.to_string(),
@@ -247,6 +301,18 @@ impl Processor for MutationSource {
                                         self.ctx.get_partition(),
                                         DataBlock::empty_with_meta(meta),
                                     );
+                                } else if self.should_write_deletion_vector(affect_rows) {
+                                    // mark the rows as deleted, instead of rewriting the block.
+                                    let predicate_col = predicates.into_column().unwrap();
+                                    let mut deletion_vector =
+                                        self.deletion_vector.take().unwrap_or_default();
+                                    for (row, deleted) in predicate_col.iter().enumerate() {
+                                        if deleted {
+                                            let offset = offsets.as_ref().map_or(row, |v| v[row]);
+                                            deletion_vector.insert(offset as u32);
+                                        }
+                                    }
+                                    self.state = State::WriteDeletionVector(deletion_vector);
                                 } else {
                                     let predicate_col = predicates.into_column().unwrap();
                                     let filter = predicate_col.not();
@@ -306,6 +372,13 @@ impl Processor for MutationSource {
                         &self.storage_format,
                     )?;
 
+                    let remain_block = match &self.deletion_vector {
+                        Some(deletion_vector) => {
+                            DeletionVector::apply(remain_block, deletion_vector)?.0
+                        }
+                        None => remain_block,
+                    };
+
                     let remain_block = if let Some(filter) = filter {
                         // for deletion.
                         remain_block.filter_boolean_value(&filter)?
@@ -362,6 +435,7 @@ impl Processor for MutationSource {
                             segment_idx: part.index.segment_idx,
                             block_idx: part.index.block_idx,
                         };
+                        self.block_meta = Some(part.block_meta.clone());
                         if matches!(self.action, MutationAction::Deletion) {
                             self.stats_type =
                                 ClusterStatsGenType::WithOrigin(part.cluster_stats.clone());
@@ -375,7 +449,7 @@ impl Processor for MutationSource {
                         {
                             // whole block deletion.
                             let progress_values = ProgressValues {
-                                rows: fuse_part.nums_rows,
+                                rows: part.block_meta.live_row_count() as usize,
                                 bytes: 0,
                             };
                             self.ctx.get_write_progress().incr(&progress_values);
@@ -394,9 +468,18 @@ impl Processor for MutationSource {
                                     &settings,
                                     &fuse_part.location,
                                     &fuse_part.columns_meta,
+                                    &fuse_part.columns_checksum,
+                                    fuse_part.encryption_key_version,
                                     &None,
                                 )
                                 .await?;
+                            self.deletion_vector = match &fuse_part.deletion_vector_location {
+                                Some(location) => Some(
+                                    DeletionVector::read(&self.block_reader.operator, location)
+                                        .await?,
+                                ),
+                                None => None,
+                            };
                             self.state = State::FilterData(inner_part, read_res);
                         }
                     }
@@ -416,6 +499,8 @@ impl Processor for MutationSource {
                             &settings,
                             &fuse_part.location,
                             &fuse_part.columns_meta,
+                            &fuse_part.columns_checksum,
+                            fuse_part.encryption_key_version,
                             &None,
                         )
                         .await?;
@@ -429,6 +514,27 @@ impl Processor for MutationSource {
                     return Err(ErrorCode::Internal("It's a bug. No remain reader"));
                 }
             }
+            State::WriteDeletionVector(deletion_vector) => {
+                let block_meta = self
+                    .block_meta
+                    .as_ref()
+                    .ok_or(ErrorCode::Internal("It's a bug. No block meta"))?;
+                let location = self.location_gen.gen_deletion_vector_location();
+                let data = DeletionVector::serialize(&deletion_vector)?;
+                let size = data.len() as u64;
+                write_data(data, &self.block_reader.operator, &location.0).await?;
+
+                let mut new_block_meta = block_meta.as_ref().clone();
+                new_block_meta.deletion_vector_location = Some(location);
+                new_block_meta.deletion_vector_size = size;
+                new_block_meta.deleted_row_count = deletion_vector.len();
+                let meta = SerializeDataMeta::create_with_replaced_block_meta(
+                    self.index.clone(),
+                    Arc::new(new_block_meta),
+                );
+                self.state =
+                    State::Output(self.ctx.get_partition(), DataBlock::empty_with_meta(meta));
+            }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
         Ok(())
@@ -43,14 +43,29 @@ impl FuseTable {
         location: String,
         time_point: DateTime<Utc>,
     ) -> Result<Arc<FuseTable>> {
-        self.find(location, |snapshot| {
-            if let Some(ts) = snapshot.timestamp {
-                ts <= time_point
-            } else {
-                false
+        // The history is walked from the latest snapshot, and stops at the first
+        // snapshot committed before the time point.
+        let mut earliest = None;
+        let res = self
+            .find(location, |snapshot| match snapshot.timestamp {
+                Some(ts) if ts <= time_point => true,
+                Some(ts) => {
+                    earliest = Some(ts);
+                    false
+                }
+                None => false,
+            })
+            .await;
+
+        match (res, earliest) {
+            (Err(e), Some(earliest)) if e.code() == ErrorCode::TABLE_HISTORICAL_DATA_NOT_FOUND => {
+                Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                    "No historical data found at {}, the earliest data available is at {}",
+                    time_point, earliest
+                )))
             }
-        })
-        .await
+            (res, _) => res,
+        }
     }
 
     #[async_backtrace::framed]
@@ -81,6 +96,7 @@ impl FuseTable {
             location,
             snapshot_version,
             self.meta_location_generator().clone(),
+            None,
         );
 
         // Find the instant which matches the given `time_point`.
@@ -0,0 +1,138 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::Scalar;
+use storages_common_table_meta::meta::SegmentInfo;
+
+use crate::io::SegmentsIO;
+use crate::operations::common::AbortOperation;
+use crate::statistics::may_contain_partition;
+use crate::statistics::partition_of;
+use crate::statistics::reducers::deduct_statistics_mut;
+use crate::FuseTable;
+
+/// The aggregated statistics of the segments of a partition.
+#[derive(Default, Clone, Debug)]
+pub struct PartitionStatistics {
+    pub segment_count: u64,
+    pub block_count: u64,
+    pub row_count: u64,
+    pub uncompressed_byte_size: u64,
+    pub compressed_byte_size: u64,
+}
+
+impl FuseTable {
+    /// Remove the segments of the given partition from the table.
+    ///
+    /// Returns the number of the removed segments. Fails if a segment holds the rows of the
+    /// partition along with the rows of other partitions, e.g. one written by `REPLACE INTO`,
+    /// since the rows can only be removed by a `DELETE` then.
+    #[async_backtrace::framed]
+    pub async fn drop_partition(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        partition: &Scalar,
+    ) -> Result<usize> {
+        let base_snapshot = match self.read_table_snapshot().await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(0),
+        };
+
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+        let mut remaining_segments = Vec::with_capacity(base_snapshot.segments.len());
+        let mut summary = base_snapshot.summary.clone();
+        let mut dropped_segment_count = 0;
+        for chunk in base_snapshot.segments.chunks(chunk_size) {
+            let segments = segments_io
+                .read_segments::<SegmentInfo>(chunk, false)
+                .await?;
+            for (segment, location) in segments.into_iter().zip(chunk.iter()) {
+                let segment = segment?;
+                let col_stats = &segment.summary.col_stats;
+                match partition_of(col_stats) {
+                    Some(value) if &value == partition => {
+                        deduct_statistics_mut(&mut summary, &segment.summary);
+                        dropped_segment_count += 1;
+                    }
+                    None if may_contain_partition(col_stats, partition) => {
+                        return Err(ErrorCode::PartitionNotDroppable(format!(
+                            "Partition {} of table {} shares segment {} with other partitions",
+                            partition, self.table_info.desc, location.0
+                        )));
+                    }
+                    _ => remaining_segments.push(location.clone()),
+                }
+            }
+        }
+
+        if dropped_segment_count == 0 {
+            return Ok(0);
+        }
+
+        self.commit_mutation(
+            &ctx,
+            base_snapshot,
+            &remaining_segments,
+            summary,
+            AbortOperation::default(),
+            None,
+        )
+        .await?;
+        Ok(dropped_segment_count)
+    }
+
+    /// List the partitions of the table, with the aggregated statistics of their segments.
+    ///
+    /// The segments that don't belong to a single partition, e.g. the ones written before
+    /// the table is partitioned, are listed under `None`.
+    #[async_backtrace::framed]
+    pub async fn partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Result<BTreeMap<Option<Scalar>, PartitionStatistics>> {
+        let mut partitions: BTreeMap<Option<Scalar>, PartitionStatistics> = BTreeMap::new();
+        let snapshot = match self.read_table_snapshot().await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(partitions),
+        };
+
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+        for chunk in snapshot.segments.chunks(chunk_size) {
+            let segments = segments_io
+                .read_segments::<SegmentInfo>(chunk, true)
+                .await?;
+            for segment in segments {
+                let summary = segment?.summary;
+                let stats = partitions
+                    .entry(partition_of(&summary.col_stats))
+                    .or_default();
+                stats.segment_count += 1;
+                stats.block_count += summary.block_count;
+                stats.row_count += summary.row_count;
+                stats.uncompressed_byte_size += summary.uncompressed_byte_size;
+                stats.compressed_byte_size += summary.compressed_byte_size;
+            }
+        }
+        Ok(partitions)
+    }
+}
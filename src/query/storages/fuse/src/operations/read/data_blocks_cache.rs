@@ -0,0 +1,225 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::Pipeline;
+use common_pipeline_sources::BlocksSource;
+use common_pipeline_transforms::processors::transforms::Transform;
+use common_pipeline_transforms::processors::transforms::Transformer;
+use log::info;
+use parking_lot::Mutex;
+use storages_common_cache::CacheAccessor;
+use storages_common_cache_manager::CacheManager;
+
+use crate::io::BlockReader;
+
+/// Returns the key of the decoded blocks of the scan in the table data block cache,
+/// or `None` if the result of the scan can not be cached.
+///
+/// Only the scans which read all the rows of a small table are cached, the key is
+/// composed of the snapshot location and the projected columns, so that the cached
+/// blocks are invalidated as soon as the table is changed.
+pub fn data_blocks_cache_key(
+    ctx: &Arc<dyn TableContext>,
+    plan: &DataSourcePlan,
+    block_reader: &BlockReader,
+) -> Result<Option<String>> {
+    if CacheManager::instance()
+        .get_table_data_block_cache()
+        .is_none()
+    {
+        return Ok(None);
+    }
+
+    let max_table_bytes = ctx
+        .get_settings()
+        .get_table_data_block_cache_max_table_bytes()? as usize;
+    if max_table_bytes == 0 || plan.statistics.read_bytes > max_table_bytes {
+        return Ok(None);
+    }
+
+    let Some(snapshot_loc) = plan.statistics.snapshot.as_ref() else {
+        return Ok(None);
+    };
+
+    // The partitions are pruned, or distributed to the other nodes of the cluster.
+    if plan.query_internal_columns || plan.parts.len() != plan.statistics.partitions_total {
+        return Ok(None);
+    }
+
+    if let Some(push_downs) = &plan.push_downs {
+        if push_downs.filter.is_some()
+            || push_downs.prewhere.is_some()
+            || push_downs.limit.is_some()
+            || push_downs.virtual_columns.is_some()
+            || push_downs.agg_index.is_some()
+        {
+            return Ok(None);
+        }
+    }
+
+    Ok(Some(format!(
+        "{}-{:?}",
+        snapshot_loc,
+        block_reader.schema().to_leaf_column_ids()
+    )))
+}
+
+/// Builds the source pipeline from the cached blocks, returns false if they are not cached.
+pub fn build_data_blocks_cache_source_pipeline(
+    ctx: Arc<dyn TableContext>,
+    pipeline: &mut Pipeline,
+    cache_key: &str,
+) -> Result<bool> {
+    let Some(cache) = CacheManager::instance().get_table_data_block_cache() else {
+        return Ok(false);
+    };
+    let Some(blocks) = cache.get(cache_key) else {
+        return Ok(false);
+    };
+
+    info!("read {} blocks from table data block cache", blocks.len());
+
+    let max_threads = ctx.get_settings().get_max_threads()? as usize;
+    let source_num = max_threads.min(blocks.len()).max(1);
+    let blocks = Arc::new(Mutex::new(
+        blocks.iter().cloned().collect::<VecDeque<DataBlock>>(),
+    ));
+
+    pipeline.add_source(
+        |output| BlocksSource::create(ctx.clone(), output, blocks.clone()),
+        source_num,
+    )?;
+    Ok(true)
+}
+
+/// Collects the blocks produced by the source pipeline, and puts them into the
+/// table data block cache once all of them have been read.
+pub fn add_data_blocks_cache_transform(
+    ctx: Arc<dyn TableContext>,
+    plan: &DataSourcePlan,
+    pipeline: &mut Pipeline,
+    cache_key: String,
+) -> Result<()> {
+    let max_table_bytes = ctx
+        .get_settings()
+        .get_table_data_block_cache_max_table_bytes()? as usize;
+
+    let collector = Arc::new(DataBlocksCollector {
+        cache_key,
+        expected_rows: plan.statistics.read_rows,
+        max_bytes: max_table_bytes,
+        state: Mutex::new(CollectorState {
+            blocks: vec![],
+            rows: 0,
+            bytes: 0,
+            unfinished: pipeline.output_len(),
+            overflow: false,
+        }),
+    });
+
+    pipeline.add_transform(|input, output| {
+        TransformCacheDataBlocks::try_create(input, output, collector.clone())
+    })
+}
+
+struct CollectorState {
+    blocks: Vec<DataBlock>,
+    rows: usize,
+    bytes: usize,
+    unfinished: usize,
+    overflow: bool,
+}
+
+struct DataBlocksCollector {
+    cache_key: String,
+    expected_rows: usize,
+    max_bytes: usize,
+    state: Mutex<CollectorState>,
+}
+
+impl DataBlocksCollector {
+    fn collect(&self, block: &DataBlock) {
+        let mut state = self.state.lock();
+        if state.overflow {
+            return;
+        }
+
+        state.rows += block.num_rows();
+        state.bytes += block.memory_size();
+        if state.bytes > self.max_bytes {
+            // The decoded blocks are too large to be kept.
+            state.overflow = true;
+            state.blocks.clear();
+            return;
+        }
+        state.blocks.push(block.clone());
+    }
+
+    fn finish(&self) {
+        let mut state = self.state.lock();
+        state.unfinished -= 1;
+
+        // The scan may be stopped early by the downstream, e.g. `LIMIT`.
+        if state.unfinished != 0 || state.overflow || state.rows != self.expected_rows {
+            return;
+        }
+
+        if let Some(cache) = CacheManager::instance().get_table_data_block_cache() {
+            let blocks = std::mem::take(&mut state.blocks);
+            cache.put(self.cache_key.clone(), Arc::new(blocks));
+        }
+    }
+}
+
+struct TransformCacheDataBlocks {
+    collector: Arc<DataBlocksCollector>,
+}
+
+impl TransformCacheDataBlocks {
+    fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        collector: Arc<DataBlocksCollector>,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(Transformer::create(
+            input,
+            output,
+            TransformCacheDataBlocks { collector },
+        )))
+    }
+}
+
+impl Transform for TransformCacheDataBlocks {
+    const NAME: &'static str = "TransformCacheDataBlocks";
+
+    fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        self.collector.collect(&data);
+        Ok(data)
+    }
+
+    fn on_finish(&mut self) -> Result<()> {
+        self.collector.finish();
+        Ok(())
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod data_blocks_cache;
 mod fuse_rows_fetcher;
 pub mod fuse_source;
 mod native_data_source;
@@ -23,5 +24,8 @@ mod parquet_data_source_deserializer;
 mod parquet_data_source_reader;
 mod parquet_rows_fetcher;
 
+pub use data_blocks_cache::add_data_blocks_cache_transform;
+pub use data_blocks_cache::build_data_blocks_cache_source_pipeline;
+pub use data_blocks_cache::data_blocks_cache_key;
 pub use fuse_rows_fetcher::build_row_fetcher_pipeline;
 pub use fuse_source::build_fuse_parquet_source_pipeline;
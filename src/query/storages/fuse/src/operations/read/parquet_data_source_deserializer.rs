@@ -38,6 +38,7 @@ use super::parquet_data_source::DataSource;
 use crate::fuse_part::FusePartInfo;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::io::UncompressedBuffer;
 use crate::io::VirtualColumnReader;
 use crate::metrics::metrics_inc_remote_io_deserialize_milliseconds;
@@ -188,7 +189,7 @@ impl Processor for DeserializeDataTransform {
 
                     self.output_data = Some(block);
                 }
-                DataSource::Normal((data, virtual_data)) => {
+                DataSource::Normal((data, virtual_data, deletion_vector)) => {
                     let start = Instant::now();
                     let columns_chunks = data.columns_chunks()?;
                     let part = FusePartInfo::from_part(&part)?;
@@ -211,6 +212,18 @@ impl Processor for DeserializeDataTransform {
                         )?;
                     }
 
+                    // Remove the rows marked as deleted, the offsets of the remaining rows are
+                    // kept to generate the internal columns.
+                    let offsets = match &deletion_vector {
+                        Some(deletion_vector) => {
+                            let (block, offsets) =
+                                DeletionVector::apply(data_block, deletion_vector)?;
+                            data_block = block;
+                            Some(offsets)
+                        }
+                        None => None,
+                    };
+
                     // Perf.
                     {
                         metrics_inc_remote_io_deserialize_milliseconds(
@@ -229,7 +242,7 @@ impl Processor for DeserializeDataTransform {
                     // Fill `BlockMetaIndex` as `DataBlock.meta` if query internal columns,
                     // `FillInternalColumnProcessor` will generate internal columns using `BlockMetaIndex` in next pipeline.
                     if self.block_reader.query_internal_columns() {
-                        let data_block = fill_internal_column_meta(data_block, part, None)?;
+                        let data_block = fill_internal_column_meta(data_block, part, offsets)?;
                         self.output_data = Some(data_block);
                     } else {
                         self.output_data = Some(data_block);
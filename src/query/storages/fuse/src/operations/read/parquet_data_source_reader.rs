@@ -33,6 +33,7 @@ use super::parquet_data_source::DataSource;
 use crate::fuse_part::FusePartInfo;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::io::ReadSettings;
 use crate::io::TableMetaLocationGenerator;
 use crate::io::VirtualColumnReader;
@@ -101,8 +102,13 @@ impl SyncSource for ReadParquetDataSource<true> {
         match self.partitions.steal_one(self.id) {
             None => Ok(None),
             Some(part) => {
-                if let Some(index_reader) = self.index_reader.as_ref() {
-                    let fuse_part = FusePartInfo::from_part(&part)?;
+                let fuse_part = FusePartInfo::from_part(&part)?;
+                // the aggregating index is not aware of the rows deleted by the deletion vector.
+                let index_reader = match &fuse_part.deletion_vector_location {
+                    None => self.index_reader.as_ref().as_ref(),
+                    Some(_) => None,
+                };
+                if let Some(index_reader) = index_reader {
                     let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &fuse_part.location,
@@ -122,7 +128,6 @@ impl SyncSource for ReadParquetDataSource<true> {
 
                 // If virtual column file exists, read the data from the virtual columns directly.
                 let virtual_source = if let Some(virtual_reader) = self.virtual_reader.as_ref() {
-                    let fuse_part = FusePartInfo::from_part(&part)?;
                     let loc =
                         TableMetaLocationGenerator::gen_virtual_block_location(&fuse_part.location);
 
@@ -146,9 +151,21 @@ impl SyncSource for ReadParquetDataSource<true> {
                     ignore_column_ids,
                 )?;
 
+                let deletion_vector = match &fuse_part.deletion_vector_location {
+                    Some(location) => Some(DeletionVector::blocking_read(
+                        &self.block_reader.operator,
+                        location,
+                    )?),
+                    None => None,
+                };
+
                 Ok(Some(DataBlock::empty_with_meta(DataSourceMeta::create(
                     vec![part],
-                    vec![DataSource::Normal((source, virtual_source))],
+                    vec![DataSource::Normal((
+                        source,
+                        virtual_source,
+                        deletion_vector,
+                    ))],
                 ))))
             }
         }
@@ -206,7 +223,12 @@ impl Processor for ReadParquetDataSource<false> {
                     tokio::spawn(async_backtrace::location!().frame(async move {
                         let part = FusePartInfo::from_part(&part)?;
 
-                        if let Some(index_reader) = index_reader.as_ref() {
+                        // the aggregating index is not aware of the rows deleted by the deletion vector.
+                        let index_reader = match &part.deletion_vector_location {
+                            None => index_reader.as_ref().as_ref(),
+                            Some(_) => None,
+                        };
+                        if let Some(index_reader) = index_reader {
                             let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &part.location,
@@ -245,11 +267,24 @@ impl Processor for ReadParquetDataSource<false> {
                                 &settings,
                                 &part.location,
                                 &part.columns_meta,
+                                &part.columns_checksum,
+                                part.encryption_key_version,
                                 ignore_column_ids,
                             )
                             .await?;
 
-                        Ok(DataSource::Normal((source, virtual_source)))
+                        let deletion_vector = match &part.deletion_vector_location {
+                            Some(location) => {
+                                Some(DeletionVector::read(&block_reader.operator, location).await?)
+                            }
+                            None => None,
+                        };
+
+                        Ok(DataSource::Normal((
+                            source,
+                            virtual_source,
+                            deletion_vector,
+                        )))
                     }))
                     .await
                     .unwrap()
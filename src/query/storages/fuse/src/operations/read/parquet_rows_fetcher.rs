@@ -182,6 +182,8 @@ impl<const BLOCKING_IO: bool> ParquetRowsFetcher<BLOCKING_IO> {
                         &self.settings,
                         &part.location,
                         &part.columns_meta,
+                        &part.columns_checksum,
+                        part.encryption_key_version,
                         &None,
                     )
                     .await?;
@@ -34,7 +34,10 @@ use crate::fuse_lazy_part::FuseLazyPartInfo;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
 use crate::io::VirtualColumnReader;
+use crate::operations::read::add_data_blocks_cache_transform;
+use crate::operations::read::build_data_blocks_cache_source_pipeline;
 use crate::operations::read::build_fuse_parquet_source_pipeline;
+use crate::operations::read::data_blocks_cache_key;
 use crate::operations::read::fuse_source::build_fuse_native_source_pipeline;
 use crate::pruning::SegmentLocation;
 use crate::FuseStorageFormat;
@@ -54,6 +57,7 @@ impl FuseTable {
             projection,
             ctx,
             query_internal_columns,
+            self.encryption.clone(),
         )
     }
 
@@ -137,6 +141,16 @@ impl FuseTable {
         plan: &DataSourcePlan,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
+        let block_reader = self.build_block_reader(plan, ctx.clone())?;
+
+        // small tables which are read entirely may be served from the table data block cache
+        let blocks_cache_key = data_blocks_cache_key(&ctx, plan, &block_reader)?;
+        if let Some(cache_key) = &blocks_cache_key {
+            if build_data_blocks_cache_source_pipeline(ctx.clone(), pipeline, cache_key)? {
+                return self.apply_data_mask_policy_if_needed(ctx, plan, pipeline);
+            }
+        }
+
         let snapshot_loc = plan.statistics.snapshot.clone();
         let mut lazy_init_segments = Vec::with_capacity(plan.parts.len());
 
@@ -186,7 +200,6 @@ impl FuseTable {
             });
         }
 
-        let block_reader = self.build_block_reader(plan, ctx.clone())?;
         let max_io_requests = self.adjust_io_request(&ctx)?;
 
         let topk = plan.push_downs.as_ref().and_then(|x| {
@@ -240,6 +253,10 @@ impl FuseTable {
             virtual_reader,
         )?;
 
+        if let Some(cache_key) = blocks_cache_key {
+            add_data_blocks_cache_transform(ctx.clone(), plan, pipeline, cache_key)?;
+        }
+
         // replace the column which has data mask if needed
         self.apply_data_mask_policy_if_needed(ctx, plan, pipeline)?;
 
@@ -164,27 +164,27 @@ impl FuseTable {
             }
         }
 
-        let mut pruner = if !self.is_native() || self.cluster_key_meta.is_none() {
-            FusePruner::create(
-                &ctx,
-                dal.clone(),
-                table_info.schema(),
-                &push_downs,
-                self.bloom_index_cols(),
-            )?
-        } else {
-            let cluster_keys = self.cluster_keys(ctx.clone());
-
-            FusePruner::create_with_pages(
-                &ctx,
-                dal.clone(),
-                table_info.schema(),
-                &push_downs,
-                self.cluster_key_meta.clone(),
-                cluster_keys,
-                self.bloom_index_cols(),
-            )?
-        };
+        let (cluster_key_meta, cluster_keys) =
+            if !self.is_native() || self.cluster_key_meta.is_none() {
+                (None, vec![])
+            } else {
+                (
+                    self.cluster_key_meta.clone(),
+                    self.cluster_keys(ctx.clone()),
+                )
+            };
+        let partition_key = self.partition_key(ctx.clone())?;
+
+        let mut pruner = FusePruner::create_with_pages(
+            &ctx,
+            dal.clone(),
+            table_info.schema(),
+            &push_downs,
+            cluster_key_meta,
+            cluster_keys,
+            self.bloom_index_cols(),
+            partition_key,
+        )?;
 
         let block_metas = pruner.read_pruning(segments_location).await?;
         let pruning_stats = pruner.pruning_stats();
@@ -326,7 +326,7 @@ impl FuseTable {
 
         let mut remaining = limit;
         for (block_meta_index, block_meta) in block_metas.iter() {
-            let rows = block_meta.row_count as usize;
+            let rows = block_meta.live_row_count() as usize;
             partitions.partitions.push(Self::all_columns_part(
                 schema,
                 block_meta_index,
@@ -441,6 +441,9 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.deletion_vector_location.as_ref().map(|v| v.0.clone()),
+            meta.column_checksums.clone(),
+            meta.encryption_key_version,
         )
     }
 
@@ -461,6 +464,12 @@ impl FuseTable {
                     columns_meta.insert(*column_id, column_meta.clone());
                 }
             }
+            // the block may be written before the column is widened
+            for column_id in &column.prev_column_ids {
+                if let Some(column_meta) = meta.col_metas.get(column_id) {
+                    columns_meta.insert(*column_id, column_meta.clone());
+                }
+            }
         }
 
         let rows_count = meta.row_count;
@@ -483,6 +492,9 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.deletion_vector_location.as_ref().map(|v| v.0.clone()),
+            meta.column_checksums.clone(),
+            meta.encryption_key_version,
         )
     }
 }
@@ -24,7 +24,6 @@ use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::DataField;
 use common_expression::DataSchemaRefExt;
-use common_expression::SortColumnDescription;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_transforms::processors::transforms::build_merge_sort_pipeline;
 use common_pipeline_transforms::processors::transforms::AsyncAccumulatingTransformer;
@@ -37,6 +36,7 @@ use crate::operations::common::BlockMetaIndex;
 use crate::operations::common::CommitSink;
 use crate::operations::common::MutationGenerator;
 use crate::operations::common::TableMutationAggregator;
+use crate::operations::common::TransformAddZOrderKey;
 use crate::operations::common::TransformSerializeBlock;
 use crate::operations::common::TransformSerializeSegment;
 use crate::operations::mutation::MAX_BLOCK_COUNT;
@@ -204,6 +204,17 @@ impl FuseTable {
             })?;
         }
 
+        if cluster_stats_gen.zorder_key_index.is_some() {
+            let cluster_key_index = cluster_stats_gen.cluster_key_index.clone();
+            pipeline.add_transform(|transform_input_port, transform_output_port| {
+                Ok(ProcessorPtr::create(TransformAddZOrderKey::try_create(
+                    transform_input_port,
+                    transform_output_port,
+                    cluster_key_index.clone(),
+                )?))
+            })?;
+        }
+
         // merge sort
         let block_num = mutator
             .total_bytes
@@ -224,16 +235,7 @@ impl FuseTable {
         // construct output fields
         let output_fields: Vec<DataField> = cluster_stats_gen.out_fields.clone();
         let schema = DataSchemaRefExt::create(output_fields);
-        let sort_descs: Vec<SortColumnDescription> = cluster_stats_gen
-            .cluster_key_index
-            .iter()
-            .map(|offset| SortColumnDescription {
-                offset: *offset,
-                asc: true,
-                nulls_first: false,
-                is_nullable: false, // This information is not needed here.
-            })
-            .collect();
+        let sort_descs = cluster_stats_gen.sort_descs();
 
         build_merge_sort_pipeline(
             pipeline,
@@ -248,6 +250,7 @@ impl FuseTable {
         let output_block_num = mutator.total_rows.div_ceil(final_block_size);
         let max_threads = std::cmp::min(max_threads, output_block_num);
         pipeline.try_resize(max_threads)?;
+        self.scatter_by_partition(ctx.clone(), pipeline)?;
         pipeline.add_transform(|transform_input_port, transform_output_port| {
             let proc = TransformSerializeBlock::try_create(
                 ctx.clone(),
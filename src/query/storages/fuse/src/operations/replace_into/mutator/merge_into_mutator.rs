@@ -53,6 +53,7 @@ use crate::io::write_data;
 use crate::io::BlockBuilder;
 use crate::io::BlockReader;
 use crate::io::CompactSegmentInfoReader;
+use crate::io::DeletionVector;
 use crate::io::MetaReaders;
 use crate::io::ReadSettings;
 use crate::io::WriteSettings;
@@ -154,6 +155,7 @@ impl MergeIntoOperationAggregator {
                 projection,
                 ctx.clone(),
                 false,
+                write_settings.encryption.clone(),
             )
         }?;
 
@@ -168,6 +170,7 @@ impl MergeIntoOperationAggregator {
                     projection,
                     ctx.clone(),
                     false,
+                    write_settings.encryption.clone(),
                 )?;
                 Some(reader)
             }
@@ -452,11 +455,10 @@ impl AggregationContext {
             .incr(&progress_values);
 
         // shortcut: whole block deletion
-        if delete_nums == block_meta.row_count as usize {
+        if delete_nums == num_rows {
             info!("whole block deletion");
             metrics_inc_replace_whole_block_deletion(1);
             // whole block deletion
-            // NOTE that if deletion marker is enabled, check the real meaning of `row_count`
             let mutation = MutationLogEntry::DeletedBlock {
                 index: BlockMetaIndex {
                     segment_idx: segment_index,
@@ -604,6 +606,8 @@ impl AggregationContext {
                 &self.read_settings,
                 &block_meta.location.0,
                 &block_meta.col_metas,
+                &block_meta.column_checksums,
+                block_meta.encryption_key_version,
                 &None,
             )
             .await?;
@@ -613,7 +617,7 @@ impl AggregationContext {
         let storage_format = self.write_settings.storage_format;
         let block_meta_ptr = block_meta.clone();
         let reader = reader.clone();
-        GlobalIORuntime::instance()
+        let data_block = GlobalIORuntime::instance()
             .spawn_blocking(move || {
                 let column_chunks = merged_io_read_result.columns_chunks()?;
                 reader.deserialize_chunks(
@@ -625,7 +629,16 @@ impl AggregationContext {
                     &storage_format,
                 )
             })
-            .await
+            .await?;
+
+        // the rows deleted by the deletion vector are not visible to replace into.
+        match &block_meta.deletion_vector_location {
+            Some((location, _)) => {
+                let deletion_vector = DeletionVector::read(&self.data_accessor, location).await?;
+                Ok(DeletionVector::apply(data_block, &deletion_vector)?.0)
+            }
+            None => Ok(data_block),
+        }
     }
 
     // return true if the block is pruned, otherwise false
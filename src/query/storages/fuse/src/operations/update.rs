@@ -284,6 +284,8 @@ impl FuseTable {
                         ops.clone(),
                         self.storage_format,
                         true,
+                        self.meta_location_generator().clone(),
+                        self.change_tracking_enabled(),
                     )
                 },
                 max_threads,
@@ -0,0 +1,323 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_meta_app::schema::ListIndexesByIdReq;
+use futures::TryStreamExt;
+use storages_common_index::BloomIndexMeta;
+use storages_common_table_meta::meta::CompactSegmentInfo;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::SnapshotId;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::meta::TableSnapshotStatistics;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_HISTORY_START;
+use uuid::Uuid;
+
+use crate::io::MetaReaders;
+use crate::io::SnapshotHistoryReader;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+use crate::FUSE_TBL_BLOCK_PREFIX;
+use crate::FUSE_TBL_DELETION_VECTOR_PREFIX;
+use crate::FUSE_TBL_SEGMENT_PREFIX;
+use crate::FUSE_TBL_SNAPSHOT_PREFIX;
+use crate::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
+use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
+
+/// The number of files removed by a vacuum.
+#[derive(Default, Clone, Debug)]
+pub struct VacuumStatistics {
+    pub snapshots: u64,
+    pub segments: u64,
+    pub blocks: u64,
+    pub indexes: u64,
+    pub table_statistics: u64,
+}
+
+impl FuseTable {
+    /// Remove the history of the table which is older than `retention_time`.
+    ///
+    /// The vacuum is done in two steps:
+    ///
+    /// 1. The history is cut at the snapshot the table was at `retention_time`, by committing
+    /// a new snapshot of the current data which records the cut as the start of the history.
+    /// The commit is done on condition that the table version is unchanged, so nothing
+    /// happens to the table if a concurrent commit wins. The existing snapshots are left
+    /// as they are, time travel and the offsets of the streams within the kept history
+    /// stay valid.
+    /// 2. The files which are not referenced by the committed history, and not modified
+    /// within the retention period, are removed.
+    ///
+    /// Both steps only rely on the committed state of the table, a vacuum that is
+    /// interrupted half way is resumed by running it again.
+    #[async_backtrace::framed]
+    pub async fn vacuum_history(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        retention_time: DateTime<Utc>,
+    ) -> Result<VacuumStatistics> {
+        self.truncate_history(&ctx, retention_time).await?;
+
+        // the table may have been changed by the truncation and concurrent commits.
+        let table = self.refresh(ctx.as_ref()).await?;
+        let table = FuseTable::try_from_table(table.as_ref())?;
+
+        // files of the transactions which are not committed yet are protected by the
+        // retention period.
+        let retention = Duration::hours(ctx.get_settings().get_retention_period()? as i64);
+        let gc_point = std::cmp::min(Utc::now() - retention, retention_time);
+        table.remove_unreferenced_files(&ctx, gc_point).await
+    }
+
+    #[async_backtrace::framed]
+    async fn truncate_history(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        retention_time: DateTime<Utc>,
+    ) -> Result<()> {
+        let location = match self.snapshot_loc().await? {
+            Some(location) => location,
+            None => return Ok(()),
+        };
+
+        // 1. Find the snapshot the table was at `retention_time`, walking the history from
+        // the latest snapshot to the start recorded by the previous vacuum.
+        let history_start = self.snapshot_history_start();
+        let location_gen = self.meta_location_generator();
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let ver = TableMetaLocationGenerator::snapshot_version(location.as_str());
+        let mut history = reader.snapshot_history(location, ver, location_gen.clone(), None);
+        let mut head = None;
+        let mut cut: Option<Arc<TableSnapshot>> = None;
+        let mut has_older = false;
+        while let Some((snapshot, _)) = history.try_next().await? {
+            match &cut {
+                // a snapshot referencing the same segments as the cut is kept along with it,
+                // so that the snapshot committed by the previous vacuum is not cut at again.
+                Some(oldest) if oldest.segments != snapshot.segments => {
+                    has_older = true;
+                    break;
+                }
+                Some(_) => cut = Some(snapshot.clone()),
+                None if snapshot.timestamp.map_or(true, |t| t <= retention_time) => {
+                    cut = Some(snapshot.clone())
+                }
+                None => {}
+            }
+            let is_start = history_start == Some(snapshot.snapshot_id);
+            head.get_or_insert(snapshot);
+            if is_start {
+                break;
+            }
+        }
+
+        let (head, cut) = match (head, cut) {
+            (Some(head), Some(cut)) if has_older => (head, cut),
+            // nothing older than the retention time is referenced.
+            _ => return Ok(()),
+        };
+
+        // 2. Commit a new snapshot of the same data as the head, along with the new start of
+        // the history. Snapshots are never rewritten, the snapshots before the start are
+        // just no longer referenced. The table version is checked by the meta service, so
+        // nothing happens to the table if a concurrent commit wins.
+        let snapshot = TableSnapshot::from_previous(head.as_ref());
+        let mut table_info = self.table_info.clone();
+        table_info.meta.options.insert(
+            OPT_KEY_SNAPSHOT_HISTORY_START.to_owned(),
+            cut.snapshot_id.simple().to_string(),
+        );
+        FuseTable::commit_to_meta_server(
+            ctx.as_ref(),
+            &table_info,
+            location_gen,
+            snapshot,
+            None,
+            &None,
+            &self.operator,
+        )
+        .await
+    }
+
+    /// The id of the oldest snapshot in the history of the table, see
+    /// [`OPT_KEY_SNAPSHOT_HISTORY_START`].
+    fn snapshot_history_start(&self) -> Option<SnapshotId> {
+        self.table_info
+            .options()
+            .get(OPT_KEY_SNAPSHOT_HISTORY_START)
+            .and_then(|id| Uuid::parse_str(id).ok())
+    }
+
+    #[async_backtrace::framed]
+    async fn remove_unreferenced_files(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        gc_point: DateTime<Utc>,
+    ) -> Result<VacuumStatistics> {
+        let mut stats = VacuumStatistics::default();
+        let location = match self.snapshot_loc().await? {
+            Some(location) => location,
+            None => return Ok(stats),
+        };
+
+        // 1. Collect the files referenced by the history of the table.
+        let location_gen = self.meta_location_generator();
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let ver = TableMetaLocationGenerator::snapshot_version(location.as_str());
+        let mut history = reader.snapshot_history(location, ver, location_gen.clone(), None);
+        let mut referenced_snapshots = HashSet::new();
+        let mut referenced_segments = HashSet::<Location>::new();
+        let mut referenced_table_statistics = HashSet::new();
+        let history_start = self.snapshot_history_start();
+        while let Some((snapshot, ver)) = history.try_next().await? {
+            referenced_snapshots
+                .insert(location_gen.snapshot_location_from_uuid(&snapshot.snapshot_id, ver)?);
+            referenced_segments.extend(snapshot.segments.iter().cloned());
+            if let Some(location) = &snapshot.table_statistics_location {
+                referenced_table_statistics.insert(location.clone());
+            }
+            if history_start == Some(snapshot.snapshot_id) {
+                break;
+            }
+        }
+        let referenced_segments = Vec::from_iter(referenced_segments);
+        // referenced segments must be readable, otherwise nothing is removed.
+        let mut referenced_locations = self
+            .get_block_locations(ctx.clone(), &referenced_segments, false, false)
+            .await?;
+        let mut referenced_segments = HashSet::from_iter(
+            referenced_segments
+                .into_iter()
+                .map(|(location, _)| location),
+        );
+        // the files shared with the clones of the table are referenced by them.
+        let shared_locations = self.locations_shared_with_clones(ctx).await?;
+        referenced_segments.extend(shared_locations.iter().cloned());
+        referenced_locations
+            .block_location
+            .extend(shared_locations.iter().cloned());
+        referenced_locations
+            .bloom_location
+            .extend(shared_locations.iter().cloned());
+        referenced_locations
+            .deletion_vector_location
+            .extend(shared_locations);
+
+        // 2. Remove the unreferenced files, data files first.
+        let blocks = self
+            .list_unreferenced_files(
+                FUSE_TBL_BLOCK_PREFIX,
+                &referenced_locations.block_location,
+                gc_point,
+            )
+            .await?;
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        let table_agg_index_ids = catalog
+            .list_index_ids_by_table_id(ListIndexesByIdReq {
+                tenant: ctx.get_tenant(),
+                table_id: self.get_id(),
+            })
+            .await?;
+        let mut agg_indexes = HashSet::new();
+        for index_id in table_agg_index_ids {
+            agg_indexes.extend(blocks.iter().map(|location| {
+                TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
+                    location, index_id,
+                )
+            }));
+        }
+        // agg indexes are located by their blocks, remove them before the blocks.
+        self.try_purge_location_files(ctx.clone(), agg_indexes)
+            .await?;
+        stats.blocks = blocks.len() as u64;
+        self.try_purge_location_files(ctx.clone(), blocks).await?;
+
+        let blooms = self
+            .list_unreferenced_files(
+                FUSE_TBL_XOR_BLOOM_INDEX_PREFIX,
+                &referenced_locations.bloom_location,
+                gc_point,
+            )
+            .await?;
+        stats.indexes = blooms.len() as u64;
+        self.try_purge_location_files_and_cache::<BloomIndexMeta, _, _>(ctx.clone(), blooms)
+            .await?;
+
+        // deletion vectors are counted along with the blocks they belong to.
+        let deletion_vectors = self
+            .list_unreferenced_files(
+                FUSE_TBL_DELETION_VECTOR_PREFIX,
+                &referenced_locations.deletion_vector_location,
+                gc_point,
+            )
+            .await?;
+        stats.blocks += deletion_vectors.len() as u64;
+        self.try_purge_location_files(ctx.clone(), deletion_vectors)
+            .await?;
+
+        let segments = self
+            .list_unreferenced_files(FUSE_TBL_SEGMENT_PREFIX, &referenced_segments, gc_point)
+            .await?;
+        stats.segments = segments.len() as u64;
+        self.try_purge_location_files_and_cache::<CompactSegmentInfo, _, _>(ctx.clone(), segments)
+            .await?;
+
+        let table_statistics = self
+            .list_unreferenced_files(
+                FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX,
+                &referenced_table_statistics,
+                gc_point,
+            )
+            .await?;
+        stats.table_statistics = table_statistics.len() as u64;
+        self.try_purge_location_files_and_cache::<TableSnapshotStatistics, _, _>(
+            ctx.clone(),
+            table_statistics,
+        )
+        .await?;
+
+        let snapshots = self
+            .list_unreferenced_files(FUSE_TBL_SNAPSHOT_PREFIX, &referenced_snapshots, gc_point)
+            .await?;
+        stats.snapshots = snapshots.len() as u64;
+        self.try_purge_location_files_and_cache::<TableSnapshot, _, _>(ctx.clone(), snapshots)
+            .await?;
+
+        Ok(stats)
+    }
+
+    #[async_backtrace::framed]
+    async fn list_unreferenced_files(
+        &self,
+        prefix: &str,
+        referenced: &HashSet<String>,
+        gc_point: DateTime<Utc>,
+    ) -> Result<HashSet<String>> {
+        let prefix = format!("{}/{}/", self.meta_location_generator().prefix(), prefix);
+        let files = self
+            .list_files(prefix, |location, modified| {
+                modified <= gc_point && !referenced.contains(&location)
+            })
+            .await?;
+        Ok(HashSet::from_iter(files))
+    }
+}
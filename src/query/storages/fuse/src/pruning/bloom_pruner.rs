@@ -18,6 +18,7 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
 use common_expression::ColumnId;
 use common_expression::Expr;
 use common_expression::FunctionContext;
@@ -29,6 +30,7 @@ use log::warn;
 use opendal::Operator;
 use storages_common_index::BloomIndex;
 use storages_common_index::FilterEvalResult;
+use storages_common_index::InvertedIndex;
 use storages_common_table_meta::meta::Location;
 
 use crate::io::BloomBlockFilterReader;
@@ -50,6 +52,9 @@ pub struct BloomPrunerCreator {
     /// indices that should be loaded from filter block
     index_fields: Vec<TableField>,
 
+    /// term filters of inverted index that should be loaded from filter block
+    inverted_index_columns: Vec<(TableField, String)>,
+
     /// the expression that would be evaluate
     filter_expression: Expr<String>,
 
@@ -77,7 +82,14 @@ impl BloomPrunerCreator {
             let bloom_column_fields = bloom_columns_map.values().cloned().collect::<Vec<_>>();
             let point_query_cols = BloomIndex::find_eq_columns(expr, bloom_column_fields)?;
 
-            if !point_query_cols.is_empty() {
+            // the term filters are self-described by the filter block, a block
+            // without term filter of the column will not be pruned.
+            let match_query_cols = InvertedIndex::find_match_columns(
+                expr,
+                schema.remove_virtual_computed_fields().fields(),
+            )?;
+
+            if !point_query_cols.is_empty() || !match_query_cols.is_empty() {
                 // convert to filter column names
                 let mut filter_fields = Vec::with_capacity(point_query_cols.len());
                 let mut scalar_map = HashMap::<Scalar, u64>::new();
@@ -89,9 +101,27 @@ impl BloomPrunerCreator {
                     }
                 }
 
+                let mut inverted_index_columns = Vec::with_capacity(match_query_cols.len());
+                for (field, tokenizer, terms) in match_query_cols.into_iter() {
+                    let filter_name = InvertedIndex::build_filter_column_name(&field, tokenizer);
+                    inverted_index_columns.push((field, filter_name));
+                    for term in terms {
+                        let scalar = Scalar::String(term.into_bytes());
+                        if let Entry::Vacant(e) = scalar_map.entry(scalar.clone()) {
+                            let digest = BloomIndex::calculate_scalar_digest(
+                                &func_ctx,
+                                &scalar,
+                                &DataType::String,
+                            )?;
+                            e.insert(digest);
+                        }
+                    }
+                }
+
                 let creator = BloomPrunerCreator {
                     func_ctx,
                     index_fields: filter_fields,
+                    inverted_index_columns,
                     filter_expression: expr.clone(),
                     scalar_map,
                     dal,
@@ -114,7 +144,7 @@ impl BloomPrunerCreator {
         let version = index_location.1;
 
         // filter out columns that no longer exist in the indexed block
-        let index_columns = self.index_fields.iter().try_fold(
+        let mut index_columns = self.index_fields.iter().try_fold(
             Vec::with_capacity(self.index_fields.len()),
             |mut acc, field| {
                 if column_ids_of_indexed_block.contains(&field.column_id()) {
@@ -123,6 +153,11 @@ impl BloomPrunerCreator {
                 Ok::<_, ErrorCode>(acc)
             },
         )?;
+        for (field, filter_name) in &self.inverted_index_columns {
+            if column_ids_of_indexed_block.contains(&field.column_id()) {
+                index_columns.push(filter_name.clone());
+            }
+        }
         // load the relevant index columns
         let maybe_filter = index_location
             .read_block_filter(self.dal.clone(), &index_columns, index_length)
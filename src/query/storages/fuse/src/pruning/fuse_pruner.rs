@@ -24,8 +24,12 @@ use common_catalog::plan::PushDownInfo;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::infer_schema_type;
+use common_expression::Expr;
 use common_expression::RemoteExpr;
 use common_expression::TableSchemaRef;
+use common_expression::PARTITION_KEY_COLUMN_ID;
+use common_expression::PARTITION_KEY_COL_NAME;
 use common_expression::SEGMENT_NAME_COL_NAME;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_sql::field_default_value;
@@ -49,6 +53,7 @@ use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::Statistics;
 use storages_common_table_meta::meta::StatisticsOfColumns;
 
+use crate::io::ChecksumVerification;
 use crate::pruning::segment_pruner::SegmentPruner;
 use crate::pruning::BlockPruner;
 use crate::pruning::BloomPruner;
@@ -69,6 +74,8 @@ pub struct PruningContext {
     pub internal_column_pruner: Option<Arc<InternalColumnPruner>>,
 
     pub pruning_stats: Arc<FusePruningStatistics>,
+    /// How the checksums of the segments read are verified.
+    pub checksum_verification: ChecksumVerification,
 }
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Clone, Debug)]
 pub struct DeletedSegmentInfo {
@@ -113,10 +120,12 @@ impl FusePruner {
             None,
             vec![],
             bloom_index_cols,
+            None,
         )
     }
 
-    // Create fuse pruner with pages.
+    // Create fuse pruner with pages and partitions.
+    #[allow(clippy::too_many_arguments)]
     pub fn create_with_pages(
         ctx: &Arc<dyn TableContext>,
         dal: Operator,
@@ -125,6 +134,7 @@ impl FusePruner {
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        partition_key: Option<RemoteExpr<String>>,
     ) -> Result<Self> {
         let func_ctx = ctx.get_function_context()?;
 
@@ -148,6 +158,11 @@ impl FusePruner {
             .flatten()
             .filter_map(|(name, _)| {
                 let field = table_schema.field_with_name(&name).ok()?;
+                // the blocks written before the column is widened store the data with
+                // the previous column ids, the column is not missing in them.
+                if !field.prev_versions().is_empty() {
+                    return None;
+                }
                 let default_scalar = field_default_value(ctx.clone(), field).ok()?;
 
                 let stats =
@@ -156,12 +171,31 @@ impl FusePruner {
             })
             .collect();
 
+        // Partition filter.
+        // The partition key in the filter is replaced by the partition values recorded
+        // in the statistics, so that the segments and blocks of the other partitions can
+        // be pruned by the range filter, no matter whether the key is a plain column.
+        let (range_schema, range_filter_expr) = match (&partition_key, &filter_expr) {
+            (Some(partition_key), Some(filter)) => {
+                let partition_key = partition_key.as_expr(&BUILTIN_FUNCTIONS);
+                let mut schema = table_schema.as_ref().clone();
+                schema.add_internal_field(
+                    PARTITION_KEY_COL_NAME,
+                    infer_schema_type(partition_key.data_type())?,
+                    PARTITION_KEY_COLUMN_ID,
+                );
+                let filter = replace_partition_key(filter, &partition_key);
+                (Arc::new(schema), Some(filter))
+            }
+            _ => (table_schema.clone(), filter_expr.clone()),
+        };
+
         // Range filter.
         // if filter_expression is none, an dummy pruner will be returned, which prunes nothing
         let range_pruner = RangePrunerCreator::try_create_with_default_stats(
             func_ctx.clone(),
-            &table_schema,
-            filter_expr.as_ref(),
+            &range_schema,
+            range_filter_expr.as_ref(),
             default_stats,
         )?;
 
@@ -211,6 +245,11 @@ impl FusePruner {
         )?);
         let pruning_semaphore = Arc::new(Semaphore::new(max_concurrency));
         let pruning_stats = Arc::new(FusePruningStatistics::default());
+        let checksum_verification = ChecksumVerification::try_from(
+            ctx.get_settings()
+                .get_data_checksum_verification()?
+                .as_str(),
+        )?;
 
         let pruning_ctx = Arc::new(PruningContext {
             ctx: ctx.clone(),
@@ -223,6 +262,7 @@ impl FusePruner {
             page_pruner,
             internal_column_pruner,
             pruning_stats,
+            checksum_verification,
         });
 
         Ok(FusePruner {
@@ -418,3 +458,111 @@ impl FusePruner {
         self.inverse_range_index.clone()
     }
 }
+
+// Replace the sub-expressions that are the partition key with the column of the partition key.
+fn replace_partition_key(expr: &Expr<String>, partition_key: &Expr<String>) -> Expr<String> {
+    if is_same_expr(expr, partition_key) {
+        return Expr::ColumnRef {
+            span: None,
+            id: PARTITION_KEY_COL_NAME.to_string(),
+            data_type: partition_key.data_type().clone(),
+            display_name: PARTITION_KEY_COL_NAME.to_string(),
+        };
+    }
+    match expr {
+        Expr::Cast {
+            span,
+            is_try,
+            expr,
+            dest_type,
+        } => Expr::Cast {
+            span: *span,
+            is_try: *is_try,
+            expr: Box::new(replace_partition_key(expr, partition_key)),
+            dest_type: dest_type.clone(),
+        },
+        Expr::FunctionCall {
+            span,
+            id,
+            function,
+            generics,
+            args,
+            return_type,
+        } => Expr::FunctionCall {
+            span: *span,
+            id: id.clone(),
+            function: function.clone(),
+            generics: generics.clone(),
+            args: args
+                .iter()
+                .map(|arg| replace_partition_key(arg, partition_key))
+                .collect(),
+            return_type: return_type.clone(),
+        },
+        _ => expr.clone(),
+    }
+}
+
+// Compare the expressions regardless of the display names of the columns.
+fn is_same_expr(lhs: &Expr<String>, rhs: &Expr<String>) -> bool {
+    match (lhs, rhs) {
+        (
+            Expr::Constant {
+                scalar: l_scalar,
+                data_type: l_type,
+                ..
+            },
+            Expr::Constant {
+                scalar: r_scalar,
+                data_type: r_type,
+                ..
+            },
+        ) => l_scalar == r_scalar && l_type == r_type,
+        (
+            Expr::ColumnRef {
+                id: l_id,
+                data_type: l_type,
+                ..
+            },
+            Expr::ColumnRef {
+                id: r_id,
+                data_type: r_type,
+                ..
+            },
+        ) => l_id == r_id && l_type == r_type,
+        (
+            Expr::Cast {
+                is_try: l_try,
+                expr: l_expr,
+                dest_type: l_type,
+                ..
+            },
+            Expr::Cast {
+                is_try: r_try,
+                expr: r_expr,
+                dest_type: r_type,
+                ..
+            },
+        ) => l_try == r_try && l_type == r_type && is_same_expr(l_expr, r_expr),
+        (
+            Expr::FunctionCall {
+                id: l_id,
+                generics: l_generics,
+                args: l_args,
+                ..
+            },
+            Expr::FunctionCall {
+                id: r_id,
+                generics: r_generics,
+                args: r_args,
+                ..
+            },
+        ) => {
+            l_id == r_id
+                && l_generics == r_generics
+                && l_args.len() == r_args.len()
+                && l_args.iter().zip(r_args).all(|(l, r)| is_same_expr(l, r))
+        }
+        _ => false,
+    }
+}
@@ -57,11 +57,12 @@ impl SegmentPruner {
         let range_pruner = self.pruning_ctx.range_pruner.clone();
 
         for segment_location in segment_locs {
-            let info = SegmentsIO::read_compact_segment(
+            let info = SegmentsIO::read_compact_segment_with_checksum(
                 self.pruning_ctx.dal.clone(),
                 segment_location.location.clone(),
                 self.table_schema.clone(),
                 true,
+                self.pruning_ctx.checksum_verification.should_verify(),
             )
             .await?;
 
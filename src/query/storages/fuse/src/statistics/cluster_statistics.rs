@@ -20,6 +20,8 @@ use common_expression::DataBlock;
 use common_expression::DataField;
 use common_expression::FunctionContext;
 use common_expression::Scalar;
+use common_expression::SortColumnDescription;
+use common_functions::aggregates::eval_aggr;
 use common_sql::evaluator::BlockOperator;
 use storages_common_table_meta::meta::ClusterStatistics;
 
@@ -34,6 +36,9 @@ pub struct ClusterStatsGenerator {
 
     pub(crate) cluster_key_index: Vec<usize>,
     pub(crate) extra_key_num: usize,
+    // The offset of the z-order key if the table is z-ordered, the blocks are sorted by it
+    // instead of the cluster keys.
+    pub(crate) zorder_key_index: Option<usize>,
 
     max_page_size: Option<usize>,
 
@@ -62,6 +67,7 @@ impl ClusterStatsGenerator {
             cluster_key_id,
             cluster_key_index,
             extra_key_num,
+            zorder_key_index: None,
             max_page_size,
             level,
             block_thresholds,
@@ -71,10 +77,33 @@ impl ClusterStatsGenerator {
         }
     }
 
+    /// Sort the blocks along the z-order curve of the cluster keys, the z-order key
+    /// is expected to be appended to the block at `zorder_key_index`.
+    pub fn with_zorder_key(mut self, zorder_key_index: usize) -> Self {
+        self.zorder_key_index = Some(zorder_key_index);
+        self
+    }
+
     pub fn is_cluster(&self) -> bool {
         !self.cluster_key_index.is_empty()
     }
 
+    pub fn sort_descs(&self) -> Vec<SortColumnDescription> {
+        let sort_keys = match self.zorder_key_index {
+            Some(index) => vec![index],
+            None => self.cluster_key_index.clone(),
+        };
+        sort_keys
+            .into_iter()
+            .map(|offset| SortColumnDescription {
+                offset,
+                asc: true,
+                nulls_first: false,
+                is_nullable: false, // This information is not needed here.
+            })
+            .collect()
+    }
+
     pub fn block_thresholds(&self) -> BlockThresholds {
         self.block_thresholds
     }
@@ -107,7 +136,8 @@ impl ClusterStatsGenerator {
 
         let mut block = data_block.clone();
 
-        if !self.cluster_key_index.is_empty() {
+        // The min and max of a z-ordered block are not in the first and the last row.
+        if !self.cluster_key_index.is_empty() && self.zorder_key_index.is_none() {
             let indices = vec![0u32, block.num_rows() as u32 - 1];
             block = block.take(&indices)?;
         }
@@ -129,6 +159,10 @@ impl ClusterStatsGenerator {
         if self.cluster_key_index.is_empty() {
             return Ok(None);
         }
+        if self.zorder_key_index.is_some() {
+            return self.zorder_clusters_statistics(data_block, level);
+        }
+
         let mut min = Vec::with_capacity(self.cluster_key_index.len());
         let mut max = Vec::with_capacity(self.cluster_key_index.len());
 
@@ -188,6 +222,62 @@ impl ClusterStatsGenerator {
             pages,
         )))
     }
+
+    /// The rows of a z-ordered block are not sorted by any of the cluster keys,
+    /// so the min and max have to be calculated from all the rows, and there are
+    /// no page statistics.
+    fn zorder_clusters_statistics(
+        &self,
+        data_block: &DataBlock,
+        level: i32,
+    ) -> Result<Option<ClusterStatistics>> {
+        let num_rows = data_block.num_rows();
+        let mut min = Vec::with_capacity(self.cluster_key_index.len());
+        let mut max = Vec::with_capacity(self.cluster_key_index.len());
+
+        for key in self.cluster_key_index.iter() {
+            let entry = data_block.get_by_offset(*key);
+            let column = entry.value.convert_to_full_column(&entry.data_type, num_rows);
+            let (mins, _) = eval_aggr("min", vec![], &[column.clone()], num_rows)?;
+            let (maxs, _) = eval_aggr("max", vec![], &[column.clone()], num_rows)?;
+            let left = mins.index(0).map(|v| v.to_owned()).unwrap_or(Scalar::Null);
+            // NULLs are sorted last, same as the linear cluster statistics.
+            let right = match column.validity() {
+                (true, _) => Scalar::Null,
+                (false, Some(bitmap)) if bitmap.unset_bits() > 0 => Scalar::Null,
+                _ => maxs.index(0).map(|v| v.to_owned()).unwrap_or(Scalar::Null),
+            };
+            min.push(
+                left.clone()
+                    .trim_min(CLUSTER_STATS_STRING_PREFIX_LEN)
+                    .unwrap_or(left),
+            );
+            max.push(
+                right
+                    .clone()
+                    .trim_min(CLUSTER_STATS_STRING_PREFIX_LEN)
+                    .unwrap_or(right),
+            );
+        }
+
+        let level = if min == max
+            && self
+                .block_thresholds
+                .check_perfect_block(num_rows, data_block.memory_size())
+        {
+            -1
+        } else {
+            level
+        };
+
+        Ok(Some(ClusterStatistics::new(
+            self.cluster_key_id,
+            min,
+            max,
+            level,
+            None,
+        )))
+    }
 }
 
 pub fn sort_by_cluster_stats(
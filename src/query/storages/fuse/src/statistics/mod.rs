@@ -16,6 +16,7 @@ pub mod accumulator;
 mod block_statistics;
 mod cluster_statistics;
 mod column_statistic;
+mod partition_statistics;
 pub mod reducers;
 
 pub use accumulator::StatisticsAccumulator;
@@ -29,6 +30,9 @@ pub use column_statistic::traverse;
 pub use column_statistic::Trim;
 pub use column_statistic::STATS_REPLACEMENT_CHAR;
 pub use column_statistic::STATS_STRING_PREFIX_LEN;
+pub use partition_statistics::gen_partition_statistics;
+pub use partition_statistics::may_contain_partition;
+pub use partition_statistics::partition_of;
 pub use reducers::merge_statistics;
 pub use reducers::reduce_block_metas;
 pub use reducers::reduce_block_statistics;
@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::PARTITION_KEY_COLUMN_ID;
+use common_functions::aggregates::eval_aggr;
+use common_functions::BUILTIN_FUNCTIONS;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+
+/// Generate the statistics of the partition key of the block.
+///
+/// Unlike the statistics of the columns, the min/max values are not trimmed,
+/// so that they can be compared with the partition values exactly.
+pub fn gen_partition_statistics(
+    data_block: &DataBlock,
+    partition_key: &Expr,
+    func_ctx: &FunctionContext,
+) -> Result<ColumnStatistics> {
+    let rows = data_block.num_rows();
+    let evaluator = Evaluator::new(data_block, func_ctx, &BUILTIN_FUNCTIONS);
+    let column = evaluator
+        .run(partition_key)?
+        .convert_to_full_column(partition_key.data_type(), rows);
+
+    let (mins, _) = eval_aggr("min", vec![], &[column.clone()], rows)?;
+    let (maxs, _) = eval_aggr("max", vec![], &[column.clone()], rows)?;
+    let min = mins.index(0).map_or(Scalar::Null, |v| v.to_owned());
+    let max = maxs.index(0).map_or(Scalar::Null, |v| v.to_owned());
+
+    let (is_all_null, bitmap) = column.validity();
+    let null_count = match (is_all_null, bitmap) {
+        (true, _) => rows,
+        (false, Some(bitmap)) => bitmap.unset_bits(),
+        (false, None) => 0,
+    };
+
+    Ok(ColumnStatistics::new(
+        min,
+        max,
+        null_count as u64,
+        column.memory_size() as u64,
+        None,
+    ))
+}
+
+/// The partition value of the block or segment, `None` if the statistics are not
+/// generated by a partitioned table, or the rows span more than one partition.
+pub fn partition_of(col_stats: &StatisticsOfColumns) -> Option<Scalar> {
+    col_stats
+        .get(&PARTITION_KEY_COLUMN_ID)
+        .filter(|stats| stats.min() == stats.max())
+        .map(|stats| stats.min().clone())
+}
+
+/// Whether the block or segment may hold rows of `partition`, judged by the range of its
+/// partition values. Statistics without partition values are assumed to hold any partition.
+pub fn may_contain_partition(col_stats: &StatisticsOfColumns, partition: &Scalar) -> bool {
+    match col_stats.get(&PARTITION_KEY_COLUMN_ID) {
+        Some(stats) => stats.min() <= partition && partition <= stats.max(),
+        None => true,
+    }
+}
@@ -0,0 +1,220 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::Projection;
+use common_catalog::table::Table;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::UInt32Type;
+use common_expression::ColumnId;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRefExt;
+use log::warn;
+use rand::seq::SliceRandom;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::SegmentInfo;
+
+use crate::io::ReadSettings;
+use crate::io::SegmentsIO;
+use crate::sessions::TableContext;
+use crate::statistics::gen_columns_statistics;
+use crate::FuseTable;
+
+/// Re-compute the column statistics of the (sampled) blocks from their data, and
+/// report the ones that do not match the statistics stored in the block metas.
+///
+/// Wrong statistics make the range pruning skip blocks that should be read, so this
+/// is used to find out the blocks written with wrong statistics.
+pub struct FuseCheckStats<'a> {
+    pub ctx: Arc<dyn TableContext>,
+    pub table: &'a FuseTable,
+    pub sample_blocks: Option<usize>,
+}
+
+#[derive(Default)]
+struct Mismatches {
+    block_location: Vec<Vec<u8>>,
+    column_name: Vec<Vec<u8>>,
+    column_id: Vec<u32>,
+    stat: Vec<Vec<u8>>,
+    stored: Vec<Vec<u8>>,
+    actual: Vec<Vec<u8>>,
+}
+
+impl Mismatches {
+    fn push(
+        &mut self,
+        block_location: &str,
+        column_name: &str,
+        column_id: ColumnId,
+        stat: &str,
+        stored: String,
+        actual: String,
+    ) {
+        self.block_location.push(block_location.as_bytes().to_vec());
+        self.column_name.push(column_name.as_bytes().to_vec());
+        self.column_id.push(column_id);
+        self.stat.push(stat.as_bytes().to_vec());
+        self.stored.push(stored.into_bytes());
+        self.actual.push(actual.into_bytes());
+    }
+
+    fn into_block(self) -> DataBlock {
+        DataBlock::new_from_columns(vec![
+            StringType::from_data(self.block_location),
+            StringType::from_data(self.column_name),
+            UInt32Type::from_data(self.column_id),
+            StringType::from_data(self.stat),
+            StringType::from_data(self.stored),
+            StringType::from_data(self.actual),
+        ])
+    }
+}
+
+impl<'a> FuseCheckStats<'a> {
+    pub fn new(
+        ctx: Arc<dyn TableContext>,
+        table: &'a FuseTable,
+        sample_blocks: Option<usize>,
+    ) -> Self {
+        Self {
+            ctx,
+            table,
+            sample_blocks,
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn check(&self) -> Result<DataBlock> {
+        let mut mismatches = Mismatches::default();
+        let blocks = self.sample().await?;
+        if blocks.is_empty() {
+            return Ok(mismatches.into_block());
+        }
+
+        let schema = self.table.schema();
+        let leaf_fields = schema.leaf_fields();
+        let projection = Projection::Columns(self.table.all_column_indices());
+        let block_reader = self
+            .table
+            .create_block_reader(projection, false, self.ctx.clone())?;
+        let settings = ReadSettings::from_ctx(&self.ctx)?;
+        let storage_format = self.table.storage_format;
+
+        let chunk_size = (self.ctx.get_settings().get_max_threads()? as usize).max(1);
+        for chunk in blocks.chunks(chunk_size) {
+            // the deletion vectors are not applied, the stored statistics are of all the rows.
+            let data_blocks =
+                futures::future::try_join_all(chunk.iter().map(|meta| {
+                    block_reader.read_by_meta(&settings, meta.as_ref(), &storage_format)
+                }))
+                .await?;
+
+            for (meta, data_block) in chunk.iter().zip(data_blocks) {
+                let actual_stats = gen_columns_statistics(&data_block, None, &schema)?;
+                let location = meta.location.0.as_str();
+                for (column_id, stored) in meta.col_stats.iter() {
+                    // the statistics are not generated for some types of columns.
+                    let Some(actual) = actual_stats.get(column_id) else {
+                        continue;
+                    };
+                    let column_name = leaf_fields
+                        .iter()
+                        .find(|f| f.column_id == *column_id)
+                        .map(|f| f.name.as_str())
+                        .unwrap_or_default();
+                    let mut check = |stat: &str, stored: String, actual: String| {
+                        if stored != actual {
+                            mismatches.push(
+                                location,
+                                column_name,
+                                *column_id,
+                                stat,
+                                stored,
+                                actual,
+                            );
+                        }
+                    };
+                    check("min", stored.min.to_string(), actual.min.to_string());
+                    check("max", stored.max.to_string(), actual.max.to_string());
+                    check(
+                        "null_count",
+                        stored.null_count.to_string(),
+                        actual.null_count.to_string(),
+                    );
+                }
+            }
+        }
+
+        if !mismatches.column_id.is_empty() {
+            warn!(
+                "found {} mismatches of the block statistics in table {}",
+                mismatches.column_id.len(),
+                self.table.get_table_info().desc
+            );
+        }
+        Ok(mismatches.into_block())
+    }
+
+    /// Pick the blocks to check randomly, all the blocks are checked if the sample
+    /// size is not given.
+    #[async_backtrace::framed]
+    async fn sample(&self) -> Result<Vec<Arc<BlockMeta>>> {
+        let Some(snapshot) = self.table.read_table_snapshot().await? else {
+            return Ok(vec![]);
+        };
+
+        let segments_io = SegmentsIO::create(
+            self.ctx.clone(),
+            self.table.operator.clone(),
+            self.table.schema(),
+        );
+        let chunk_size = (self.ctx.get_settings().get_max_threads()? as usize * 4).max(1);
+        let mut blocks = Vec::with_capacity(snapshot.summary.block_count as usize);
+        for chunk in snapshot.segments.chunks(chunk_size) {
+            let segments = segments_io
+                .read_segments::<SegmentInfo>(chunk, true)
+                .await?;
+            for segment in segments {
+                blocks.extend(segment?.blocks.iter().cloned());
+            }
+        }
+
+        match self.sample_blocks {
+            Some(n) if n < blocks.len() => Ok(blocks
+                .choose_multiple(&mut rand::thread_rng(), n)
+                .cloned()
+                .collect()),
+            _ => Ok(blocks),
+        }
+    }
+
+    pub fn schema() -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new("block_location", TableDataType::String),
+            TableField::new("column_name", TableDataType::String),
+            TableField::new("column_id", TableDataType::Number(NumberDataType::UInt32)),
+            TableField::new("stat", TableDataType::String),
+            TableField::new("stored", TableDataType::String),
+            TableField::new("actual", TableDataType::String),
+        ])
+    }
+}
@@ -80,6 +80,7 @@ impl<'a> FuseColumn<'a> {
                     snapshot_location,
                     snapshot_version,
                     tbl.meta_location_generator().clone(),
+                    None,
                 );
 
                 // find the element by snapshot_id in stream
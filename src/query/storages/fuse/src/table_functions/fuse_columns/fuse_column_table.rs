@@ -32,6 +32,7 @@ use crate::pipelines::processors::AsyncSource;
 use crate::pipelines::processors::AsyncSourcer;
 use crate::pipelines::Pipeline;
 use crate::sessions::TableContext;
+use crate::table_functions::apply_push_downs;
 use crate::table_functions::parse_db_tb_ssid_args;
 use crate::table_functions::string_literal;
 use crate::table_functions::FuseColumn;
@@ -92,6 +93,10 @@ impl Table for FuseColumnTable {
         &self.table_info
     }
 
+    fn support_column_projection(&self) -> bool {
+        true
+    }
+
     #[async_backtrace::framed]
     async fn read_partitions(
         &self,
@@ -126,7 +131,7 @@ impl Table for FuseColumnTable {
                     self.arg_database_name.to_owned(),
                     self.arg_table_name.to_owned(),
                     self.arg_snapshot_id.to_owned(),
-                    plan.push_downs.as_ref().and_then(|x| x.limit),
+                    plan.push_downs.clone(),
                 )
             },
             1,
@@ -142,7 +147,7 @@ struct FuseColumnSource {
     arg_database_name: String,
     arg_table_name: String,
     arg_snapshot_id: Option<String>,
-    limit: Option<usize>,
+    push_downs: Option<PushDownInfo>,
 }
 
 impl FuseColumnSource {
@@ -152,7 +157,7 @@ impl FuseColumnSource {
         arg_database_name: String,
         arg_table_name: String,
         arg_snapshot_id: Option<String>,
-        limit: Option<usize>,
+        push_downs: Option<PushDownInfo>,
     ) -> Result<ProcessorPtr> {
         AsyncSourcer::create(ctx.clone(), output, FuseColumnSource {
             ctx,
@@ -160,7 +165,7 @@ impl FuseColumnSource {
             arg_table_name,
             arg_database_name,
             arg_snapshot_id,
-            limit,
+            push_downs,
         })
     }
 }
@@ -189,16 +194,20 @@ impl AsyncSource for FuseColumnSource {
             )
             .await?;
         let tbl = FuseTable::try_from_table(tbl.as_ref())?;
-        Ok(Some(
-            FuseColumn::new(
-                self.ctx.clone(),
-                tbl,
-                self.arg_snapshot_id.clone(),
-                self.limit,
-            )
+        // The limit is applied before the filter, so it's only pushed down without filter.
+        let limit = match &self.push_downs {
+            Some(push_downs) if push_downs.filter.is_none() => push_downs.limit,
+            _ => None,
+        };
+        let block = FuseColumn::new(self.ctx.clone(), tbl, self.arg_snapshot_id.clone(), limit)
             .get_blocks()
-            .await?,
-        ))
+            .await?;
+        Ok(Some(apply_push_downs(
+            &self.ctx,
+            &FuseColumn::schema(),
+            &self.push_downs,
+            block,
+        )?))
     }
 }
 
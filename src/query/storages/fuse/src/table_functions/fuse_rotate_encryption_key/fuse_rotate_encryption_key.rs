@@ -0,0 +1,82 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::NumberDataType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::UpsertTableOptionReq;
+use storages_common_table_meta::table::OPT_KEY_ENCRYPTION_KEYS;
+
+use crate::sessions::TableContext;
+use crate::FuseTable;
+use crate::Table;
+
+pub struct FuseRotateEncryptionKey<'a> {
+    pub ctx: Arc<dyn TableContext>,
+    pub table: &'a FuseTable,
+}
+
+impl<'a> FuseRotateEncryptionKey<'a> {
+    pub fn new(ctx: Arc<dyn TableContext>, table: &'a FuseTable) -> Self {
+        Self { ctx, table }
+    }
+
+    /// Adds a new data key to the table, the blocks written afterwards are encrypted with it.
+    ///
+    /// The existing blocks are not rewritten, they keep being decrypted with the keys they
+    /// were written with until they are rewritten by compaction or mutations.
+    #[async_backtrace::framed]
+    pub async fn rotate(self, database: &str) -> Result<DataBlock> {
+        let table_info = self.table.get_table_info();
+        let encryption = self.table.encryption().ok_or_else(|| {
+            ErrorCode::StorageEncryptionError(format!(
+                "table {}.{} is not encrypted",
+                database, table_info.name
+            ))
+        })?;
+        let rotated = encryption.rotate().await?;
+
+        // fails if the table is changed meanwhile, e.g. by a concurrent rotation.
+        let req = UpsertTableOptionReq::new(
+            &table_info.ident,
+            OPT_KEY_ENCRYPTION_KEYS,
+            rotated.to_option(),
+        );
+        let catalog = self.ctx.get_catalog(CATALOG_DEFAULT).await?;
+        catalog
+            .upsert_table_option(self.ctx.get_tenant().as_str(), database, req)
+            .await?;
+
+        Ok(DataBlock::new_from_columns(vec![UInt64Type::from_data(
+            vec![rotated.current_version() as u64],
+        )]))
+    }
+
+    pub fn schema() -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![TableField::new(
+            "key_version",
+            TableDataType::Number(NumberDataType::UInt64),
+        )])
+    }
+}
@@ -75,6 +75,7 @@ impl<'a> FuseSegment<'a> {
                     snapshot_location,
                     snapshot_version,
                     tbl.meta_location_generator().clone(),
+                    None,
                 );
                 while let Some((snapshot, _)) = snapshot_stream.try_next().await? {
                     if snapshot.snapshot_id.simple().to_string() == snapshot_id {
@@ -47,38 +47,19 @@ impl<'a> FuseSnapshot<'a> {
     pub async fn get_snapshots(self, limit: Option<usize>) -> Result<DataBlock> {
         let meta_location_generator = self.table.meta_location_generator.clone();
         let snapshot_location = self.table.snapshot_loc().await?;
-        let snapshot = self.table.read_table_snapshot().await?;
         if let Some(snapshot_location) = snapshot_location {
             let snapshot_version =
                 TableMetaLocationGenerator::snapshot_version(snapshot_location.as_str());
             let snapshots_io = SnapshotsIO::create(self.ctx.clone(), self.table.operator.clone());
-            let snapshot_lite = if limit.is_none() {
-                // Use SnapshotsIO::read_snapshot_lites only if limit is None
-                //
-                // SnapshotsIO::read_snapshot lists snapshots from object storage, taking limit into
-                // account, BEFORE the snapshots are chained, so there might be the case that although
-                // there are more than limited number of snapshots could be chained, the number of
-                // snapshots returned is lesser.
-                let (chained_snapshot_lites, _) = snapshots_io
-                    .read_snapshot_lites_ext(
-                        snapshot_location,
-                        snapshot.and_then(|s| s.timestamp),
-                        &|_| {},
-                    )
-                    .await?;
-                Ok(chained_snapshot_lites)
-            } else {
-                // SnapshotsIO::read_chained_snapshot_lists traverses the history of snapshot sequentially, by using the
-                // TableSnapshot::prev_snapshot_id, which guarantees that the number of snapshot
-                // returned is as expected
-                snapshots_io
-                    .read_chained_snapshot_lites(
-                        meta_location_generator.clone(),
-                        snapshot_location,
-                        limit,
-                    )
-                    .await
-            }?;
+            // Walk the history lazily by TableSnapshot::prev_snapshot_id, only the lite
+            // snapshots are kept, and only the first `limit` snapshots are read.
+            let snapshot_lite = snapshots_io
+                .read_chained_snapshot_lites(
+                    meta_location_generator.clone(),
+                    snapshot_location,
+                    limit,
+                )
+                .await?;
 
             return self.to_block(&meta_location_generator, &snapshot_lite, snapshot_version);
         }
@@ -105,6 +86,7 @@ impl<'a> FuseSnapshot<'a> {
         let mut uncompressed: Vec<u64> = Vec::with_capacity(len);
         let mut index_size: Vec<u64> = Vec::with_capacity(len);
         let mut timestamps: Vec<Option<i64>> = Vec::with_capacity(len);
+        let mut rows_changed: Vec<u64> = Vec::with_capacity(len);
         let mut current_snapshot_version = latest_snapshot_version;
         for s in snapshots {
             snapshot_ids.push(s.snapshot_id.simple().to_string().into_bytes());
@@ -125,6 +107,7 @@ impl<'a> FuseSnapshot<'a> {
             uncompressed.push(s.uncompressed_byte_size);
             index_size.push(s.index_size);
             timestamps.push(s.timestamp.map(|dt| (dt.timestamp_micros())));
+            rows_changed.push(s.rows_changed_since_analyze);
             current_snapshot_version = ver;
         }
 
@@ -140,6 +123,7 @@ impl<'a> FuseSnapshot<'a> {
             UInt64Type::from_data(compressed),
             UInt64Type::from_data(index_size),
             TimestampType::from_opt_data(timestamps),
+            UInt64Type::from_data(rows_changed),
         ]))
     }
 
@@ -171,6 +155,10 @@ impl<'a> FuseSnapshot<'a> {
             ),
             TableField::new("index_size", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("timestamp", TableDataType::Timestamp.wrap_nullable()),
+            TableField::new(
+                "rows_changed_since_analyze",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
         ])
     }
 }
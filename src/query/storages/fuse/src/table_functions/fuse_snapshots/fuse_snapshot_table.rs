@@ -33,6 +33,7 @@ use super::fuse_snapshot::FuseSnapshot;
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::Pipeline;
 use crate::sessions::TableContext;
+use crate::table_functions::apply_push_downs;
 use crate::table_functions::parse_db_tb_args;
 use crate::table_functions::string_literal;
 use crate::table_functions::TableArgs;
@@ -90,6 +91,10 @@ impl Table for FuseSnapshotTable {
         &self.table_info
     }
 
+    fn support_column_projection(&self) -> bool {
+        true
+    }
+
     #[async_backtrace::framed]
     async fn read_partitions(
         &self,
@@ -120,7 +125,7 @@ impl Table for FuseSnapshotTable {
                     output,
                     self.arg_database_name.to_owned(),
                     self.arg_table_name.to_owned(),
-                    plan.push_downs.as_ref().and_then(|extras| extras.limit),
+                    plan.push_downs.clone(),
                 )
             },
             1,
@@ -146,7 +151,7 @@ struct FuseSnapshotSource {
     ctx: Arc<dyn TableContext>,
     arg_database_name: String,
     arg_table_name: String,
-    limit: Option<usize>,
+    push_downs: Option<PushDownInfo>,
 }
 
 impl FuseSnapshotSource {
@@ -155,14 +160,14 @@ impl FuseSnapshotSource {
         output: Arc<OutputPort>,
         arg_database_name: String,
         arg_table_name: String,
-        limit: Option<usize>,
+        push_downs: Option<PushDownInfo>,
     ) -> Result<ProcessorPtr> {
         AsyncSourcer::create(ctx.clone(), output, FuseSnapshotSource {
             ctx,
             finish: false,
             arg_table_name,
             arg_database_name,
-            limit,
+            push_downs,
         })
     }
 }
@@ -192,10 +197,19 @@ impl AsyncSource for FuseSnapshotSource {
             .await?;
 
         let tbl = FuseTable::try_from_table(tbl.as_ref())?;
-        Ok(Some(
-            FuseSnapshot::new(self.ctx.clone(), tbl)
-                .get_snapshots(self.limit)
-                .await?,
-        ))
+        // The limit is applied before the filter, so it's only pushed down without filter.
+        let limit = match &self.push_downs {
+            Some(push_downs) if push_downs.filter.is_none() => push_downs.limit,
+            _ => None,
+        };
+        let block = FuseSnapshot::new(self.ctx.clone(), tbl)
+            .get_snapshots(limit)
+            .await?;
+        Ok(Some(apply_push_downs(
+            &self.ctx,
+            &FuseSnapshot::schema(),
+            &self.push_downs,
+            block,
+        )?))
     }
 }
@@ -0,0 +1,84 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_exception::Result;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::NumberDataType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRefExt;
+
+use crate::sessions::TableContext;
+use crate::FuseTable;
+
+pub struct FuseVacuum<'a> {
+    pub ctx: Arc<dyn TableContext>,
+    pub table: &'a FuseTable,
+    pub retention_time: DateTime<Utc>,
+}
+
+impl<'a> FuseVacuum<'a> {
+    pub fn new(
+        ctx: Arc<dyn TableContext>,
+        table: &'a FuseTable,
+        retention_time: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            ctx,
+            table,
+            retention_time,
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn vacuum(self) -> Result<DataBlock> {
+        let stats = self
+            .table
+            .vacuum_history(self.ctx.clone(), self.retention_time)
+            .await?;
+        Ok(DataBlock::new_from_columns(vec![
+            UInt64Type::from_data(vec![stats.snapshots]),
+            UInt64Type::from_data(vec![stats.segments]),
+            UInt64Type::from_data(vec![stats.blocks]),
+            UInt64Type::from_data(vec![stats.indexes]),
+            UInt64Type::from_data(vec![stats.table_statistics]),
+        ]))
+    }
+
+    pub fn schema() -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new(
+                "snapshot_files",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "segment_files",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("block_files", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("index_files", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "statistic_files",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+        ])
+    }
+}
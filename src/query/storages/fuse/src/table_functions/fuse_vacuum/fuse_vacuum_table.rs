@@ -0,0 +1,255 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PushDownInfo;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::check_number;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::DataBlock;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+
+use super::fuse_vacuum::FuseVacuum;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::Pipeline;
+use crate::sessions::TableContext;
+use crate::table_functions::string_literal;
+use crate::table_functions::string_value;
+use crate::table_functions::TableArgs;
+use crate::table_functions::TableFunction;
+use crate::FuseTable;
+use crate::Table;
+
+const FUSE_FUNC_VACUUM: &str = "fuse_vacuum";
+
+pub struct FuseVacuumTable {
+    table_info: TableInfo,
+    arg_database_name: String,
+    arg_table_name: String,
+    arg_retention_hours: Option<u64>,
+}
+
+impl FuseVacuumTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let (arg_database_name, arg_table_name, arg_retention_hours) =
+            parse_vacuum_args(&table_args)?;
+
+        let engine = FUSE_FUNC_VACUUM.to_owned();
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: FuseVacuum::schema(),
+                engine,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(FuseVacuumTable {
+            table_info,
+            arg_database_name,
+            arg_table_name,
+            arg_retention_hours,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for FuseVacuumTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((PartStatistics::default(), Partitions::default()))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        let mut args = vec![
+            string_literal(self.arg_database_name.as_str()),
+            string_literal(self.arg_table_name.as_str()),
+        ];
+        if let Some(hours) = self.arg_retention_hours {
+            args.push(Scalar::Number(NumberScalar::UInt64(hours)));
+        }
+        Some(TableArgs::new_positioned(args))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        pipeline.add_source(
+            |output| {
+                FuseVacuumSource::create(
+                    ctx.clone(),
+                    output,
+                    self.arg_database_name.to_owned(),
+                    self.arg_table_name.to_owned(),
+                    self.arg_retention_hours,
+                )
+            },
+            1,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl TableFunction for FuseVacuumTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+struct FuseVacuumSource {
+    finish: bool,
+    ctx: Arc<dyn TableContext>,
+    arg_database_name: String,
+    arg_table_name: String,
+    arg_retention_hours: Option<u64>,
+}
+
+impl FuseVacuumSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        arg_database_name: String,
+        arg_table_name: String,
+        arg_retention_hours: Option<u64>,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, FuseVacuumSource {
+            ctx,
+            finish: false,
+            arg_table_name,
+            arg_database_name,
+            arg_retention_hours,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for FuseVacuumSource {
+    const NAME: &'static str = "fuse_vacuum";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finish {
+            return Ok(None);
+        }
+
+        self.finish = true;
+        let tenant_id = self.ctx.get_tenant();
+        let tbl = self
+            .ctx
+            .get_catalog(CATALOG_DEFAULT)
+            .await?
+            .get_table(
+                tenant_id.as_str(),
+                self.arg_database_name.as_str(),
+                self.arg_table_name.as_str(),
+            )
+            .await?;
+
+        let hours = match self.arg_retention_hours {
+            Some(hours) => hours as i64,
+            None => self.ctx.get_settings().get_retention_period()? as i64,
+        };
+        let retention_time = chrono::Utc::now() - chrono::Duration::hours(hours);
+
+        let tbl = FuseTable::try_from_table(tbl.as_ref())?;
+        Ok(Some(
+            FuseVacuum::new(self.ctx.clone(), tbl, retention_time)
+                .vacuum()
+                .await?,
+        ))
+    }
+}
+
+fn parse_vacuum_args(table_args: &TableArgs) -> Result<(String, String, Option<u64>)> {
+    let args = table_args.expect_all_positioned(FUSE_FUNC_VACUUM, None)?;
+    match args.len() {
+        2 | 3 => {
+            let db = string_value(&args[0])?;
+            let tbl = string_value(&args[1])?;
+            let retention_hours = match args.get(2) {
+                Some(hours) => Some(check_number(
+                    None,
+                    &FunctionContext::default(),
+                    &Expr::<usize>::Cast {
+                        span: None,
+                        is_try: false,
+                        expr: Box::new(Expr::Constant {
+                            span: None,
+                            scalar: hours.clone(),
+                            data_type: hours.as_ref().infer_data_type(),
+                        }),
+                        dest_type: DataType::Number(NumberDataType::UInt64),
+                    },
+                    &BUILTIN_FUNCTIONS,
+                )?),
+                None => None,
+            };
+            Ok((db, tbl, retention_hours))
+        }
+        _ => Err(ErrorCode::BadArguments(format!(
+            "expecting <database>, <table_name> and <retention_hours> (as literals), but got {:?}",
+            args
+        ))),
+    }
+}
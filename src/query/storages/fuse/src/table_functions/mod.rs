@@ -14,10 +14,14 @@
 
 mod clustering_information;
 mod fuse_blocks;
+mod fuse_check_stats;
 mod fuse_columns;
+mod fuse_rotate_encryption_key;
 mod fuse_segments;
 mod fuse_snapshots;
 mod fuse_statistics;
+mod fuse_vacuum;
+mod push_downs;
 mod table_args;
 
 pub use clustering_information::ClusteringInformation;
@@ -26,11 +30,16 @@ use common_catalog::table_args::TableArgs;
 use common_catalog::table_function::TableFunction;
 pub use fuse_blocks::FuseBlock;
 pub use fuse_blocks::FuseBlockTable;
+pub use fuse_check_stats::FuseCheckStats;
+pub use fuse_check_stats::FuseCheckStatsTable;
 pub use fuse_columns::FuseColumn;
 pub use fuse_columns::FuseColumnTable;
+pub use fuse_rotate_encryption_key::FuseRotateEncryptionKeyTable;
 pub use fuse_segments::FuseSegment;
 pub use fuse_segments::FuseSegmentTable;
 pub use fuse_snapshots::FuseSnapshot;
 pub use fuse_snapshots::FuseSnapshotTable;
 pub use fuse_statistics::FuseStatisticTable;
+pub use fuse_vacuum::FuseVacuumTable;
+pub use push_downs::apply_push_downs;
 pub use table_args::*;
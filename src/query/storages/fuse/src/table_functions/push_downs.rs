@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::Projection;
+use common_catalog::plan::PushDownInfo;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::BooleanType;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::TableSchema;
+use common_functions::BUILTIN_FUNCTIONS;
+
+use crate::sessions::TableContext;
+
+/// Apply the push downs to the block of a table function, which has all the columns
+/// of the `schema`.
+///
+/// The rows not matching the filter are removed first, then the block is projected to
+/// the columns of the projection, which are the output columns of the table function
+/// if it supports column projection.
+pub fn apply_push_downs(
+    ctx: &Arc<dyn TableContext>,
+    schema: &TableSchema,
+    push_downs: &Option<PushDownInfo>,
+    block: DataBlock,
+) -> Result<DataBlock> {
+    let push_downs = match push_downs {
+        Some(push_downs) => push_downs,
+        None => return Ok(block),
+    };
+
+    let mut block = block;
+    // The filter is evaluated again after the scan, so a non-deterministic filter is skipped.
+    if let Some(filter) = push_downs.filter.as_ref().filter(|_| push_downs.is_deterministic) {
+        let filter = filter.as_expr(&BUILTIN_FUNCTIONS);
+        if filter
+            .column_refs()
+            .keys()
+            .all(|name| schema.index_of(name).is_ok())
+        {
+            let filter = filter.project_column_ref(|name| schema.index_of(name).unwrap());
+            let func_ctx = ctx.get_function_context()?;
+            let evaluator = Evaluator::new(&block, &func_ctx, &BUILTIN_FUNCTIONS);
+            let predicate = evaluator
+                .run(&filter)?
+                .try_downcast::<BooleanType>()
+                .unwrap();
+            block = block.filter_boolean_value(&predicate)?;
+        }
+    }
+
+    match &push_downs.projection {
+        Some(Projection::Columns(indices)) => {
+            let num_rows = block.num_rows();
+            let columns = indices
+                .iter()
+                .map(|index| block.get_by_offset(*index).clone())
+                .collect();
+            Ok(DataBlock::new(columns, num_rows))
+        }
+        Some(Projection::InnerColumns(_)) => Err(ErrorCode::Internal(
+            "table functions don't support the projection of inner columns",
+        )),
+        None => Ok(block),
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::NumberScalar;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use futures::TryStreamExt;
+use opendal::Operator;
+use parquet::arrow::ParquetRecordBatchStreamBuilder;
+use parquet::arrow::ProjectionMask;
+
+use crate::partition::IcebergDeleteFile;
+
+/// Names of the columns of position delete files.
+const POSITION_DELETE_FILE_PATH: &str = "file_path";
+const POSITION_DELETE_POS: &str = "pos";
+
+/// Read the position delete files, returns the sorted positions of the deleted rows
+/// in the data file at `data_file_path`.
+#[async_backtrace::framed]
+pub async fn read_position_deletes(
+    op: &Operator,
+    delete_files: &[IcebergDeleteFile],
+    data_file_path: &str,
+) -> Result<Vec<u64>> {
+    let mut positions = vec![];
+    for delete_file in delete_files {
+        let blocks = read_parquet_columns(op, &delete_file.location, &[
+            POSITION_DELETE_FILE_PATH,
+            POSITION_DELETE_POS,
+        ])
+        .await?;
+        for block in blocks {
+            let paths = &block.get_by_offset(0).value;
+            let poses = &block.get_by_offset(1).value;
+            for row in 0..block.num_rows() {
+                if let (
+                    Some(ScalarRef::String(path)),
+                    Some(ScalarRef::Number(NumberScalar::Int64(pos))),
+                ) = (paths.index(row), poses.index(row))
+                {
+                    if path == data_file_path.as_bytes() {
+                        positions.push(pos as u64);
+                    }
+                }
+            }
+        }
+    }
+    positions.sort_unstable();
+    positions.dedup();
+    Ok(positions)
+}
+
+/// Rows deleted by an equality delete file, a row of the data file is deleted
+/// if its values of `columns` are equal to any of the deleted rows.
+pub struct EqualityDeletes {
+    columns: Vec<String>,
+    rows: HashSet<Vec<Scalar>>,
+}
+
+impl EqualityDeletes {
+    #[async_backtrace::framed]
+    pub async fn try_create(op: &Operator, delete_file: &IcebergDeleteFile) -> Result<Self> {
+        let columns = delete_file
+            .equality_columns
+            .iter()
+            .map(|c| c.as_str())
+            .collect::<Vec<_>>();
+        let blocks = read_parquet_columns(op, &delete_file.location, &columns).await?;
+        let mut rows = HashSet::new();
+        for block in blocks {
+            for row in 0..block.num_rows() {
+                rows.insert(row_of_block(&block, row));
+            }
+        }
+        Ok(Self {
+            columns: delete_file.equality_columns.clone(),
+            rows,
+        })
+    }
+
+    /// Remove the deleted rows from `block`, whose columns are named by `column_names`.
+    pub fn apply(&self, block: DataBlock, column_names: &[String]) -> Result<DataBlock> {
+        if self.rows.is_empty() || block.is_empty() {
+            return Ok(block);
+        }
+        let offsets = self
+            .columns
+            .iter()
+            .map(|column| {
+                column_names
+                    .iter()
+                    .position(|name| name == column)
+                    .ok_or_else(|| {
+                        ErrorCode::Internal(format!(
+                            "Column {column} of the equality deletes is not read"
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let key_block = project_block(&block, &offsets);
+
+        let mut bitmap = MutableBitmap::with_capacity(block.num_rows());
+        for row in 0..block.num_rows() {
+            bitmap.push(!self.rows.contains(&row_of_block(&key_block, row)));
+        }
+        block.filter_with_bitmap(&bitmap.into())
+    }
+}
+
+fn row_of_block(block: &DataBlock, row: usize) -> Vec<Scalar> {
+    block
+        .columns()
+        .iter()
+        .map(|entry| {
+            entry
+                .value
+                .index(row)
+                .map(|v| v.to_owned())
+                .unwrap_or(Scalar::Null)
+        })
+        .collect()
+}
+
+pub(crate) fn project_block(block: &DataBlock, offsets: &[usize]) -> DataBlock {
+    let columns = offsets
+        .iter()
+        .map(|offset| block.get_by_offset(*offset).clone())
+        .collect();
+    DataBlock::new(columns, block.num_rows())
+}
+
+/// Read the columns named `columns` of the parquet file at `location`,
+/// the columns of the returned blocks are in the order of `columns`.
+async fn read_parquet_columns(
+    op: &Operator,
+    location: &str,
+    columns: &[&str],
+) -> Result<Vec<DataBlock>> {
+    let reader = op.reader(location).await?;
+    let builder = ParquetRecordBatchStreamBuilder::new(reader).await?;
+    let arrow_schema = builder.schema().clone();
+    let indices = columns
+        .iter()
+        .map(|column| {
+            arrow_schema.index_of(column).map_err(|_| {
+                ErrorCode::TableSchemaMismatch(format!(
+                    "Cannot find column {column} in delete file {location}"
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let projection = ProjectionMask::roots(builder.parquet_schema(), indices);
+    let batches = builder
+        .with_projection(projection)
+        .build()?
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    let mut blocks = Vec::with_capacity(batches.len());
+    for batch in batches {
+        let (block, schema) = DataBlock::from_record_batch(&batch)?;
+        let offsets = columns
+            .iter()
+            .map(|column| schema.index_of(column))
+            .collect::<Result<Vec<_>>>()?;
+        blocks.push(project_block(&block, &offsets));
+    }
+    Ok(blocks)
+}
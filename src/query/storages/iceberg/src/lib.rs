@@ -92,6 +92,8 @@
 
 mod catalog;
 mod database;
+mod delete;
+mod manifest;
 mod partition;
 mod stats;
 mod table;
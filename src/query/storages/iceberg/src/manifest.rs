@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use icelake::types::parse_manifest_file;
+use icelake::types::parse_manifest_list;
+use icelake::types::DataFile;
+use icelake::types::ManifestStatus;
+use opendal::Operator;
+
+/// A live file in the current snapshot of the table.
+pub struct ManifestFileEntry {
+    /// The data sequence number of the file, used to decide which delete files apply to it.
+    pub sequence_number: i64,
+    pub data_file: DataFile,
+}
+
+/// Read the manifest list of the current snapshot and the manifests in it,
+/// returns the live data files and delete files.
+#[async_backtrace::framed]
+pub async fn read_current_manifests(
+    table: &icelake::Table,
+    op: &Operator,
+) -> Result<Vec<ManifestFileEntry>> {
+    let meta = table.current_table_metadata();
+    let snapshot = meta.current_snapshot_id.and_then(|id| {
+        meta.snapshots
+            .iter()
+            .flatten()
+            .find(|snapshot| snapshot.snapshot_id == id)
+    });
+    let Some(snapshot) = snapshot else {
+        // The table has no snapshot yet.
+        return Ok(vec![]);
+    };
+
+    let content = read_file(table, op, &snapshot.manifest_list).await?;
+    let manifest_list = parse_manifest_list(&content)
+        .map_err(|e| ErrorCode::ReadTableDataError(format!("Cannot parse manifest list: {e:?}")))?;
+
+    let manifests = futures::future::try_join_all(manifest_list.entries.into_iter().map(
+        |manifest| async move {
+            let content = read_file(table, op, &manifest.manifest_path).await?;
+            let manifest_file = parse_manifest_file(&content).map_err(|e| {
+                ErrorCode::ReadTableDataError(format!("Cannot parse manifest: {e:?}"))
+            })?;
+            Ok::<_, ErrorCode>((manifest.sequence_number, manifest_file))
+        },
+    ))
+    .await?;
+
+    let mut entries = vec![];
+    for (manifest_sequence_number, manifest_file) in manifests {
+        for entry in manifest_file.entries {
+            if matches!(entry.status, ManifestStatus::Deleted) {
+                continue;
+            }
+            entries.push(ManifestFileEntry {
+                // Files added in the snapshot of the manifest inherit its sequence number.
+                sequence_number: entry.sequence_number.unwrap_or(manifest_sequence_number),
+                data_file: entry.data_file,
+            });
+        }
+    }
+    Ok(entries)
+}
+
+async fn read_file(table: &icelake::Table, op: &Operator, path: &str) -> Result<Vec<u8>> {
+    let location = table
+        .rel_path(path)
+        .map_err(|e| ErrorCode::ReadTableDataError(format!("Invalid path {path}: {e:?}")))?;
+    Ok(op.read(&location).await?)
+}
@@ -13,6 +13,9 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
 
 use common_catalog::plan::PartInfo;
 use common_catalog::plan::PartInfoPtr;
@@ -26,6 +29,28 @@ use common_storages_parquet::ParquetPart;
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
 pub enum IcebergPartInfo {
     Parquet(ParquetPart),
+    /// A parquet data file with the delete files applied to it.
+    ParquetWithDeletes(IcebergParquetWithDeletesPart),
+}
+
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct IcebergParquetWithDeletesPart {
+    /// Location of the data file, relative to the table.
+    pub location: String,
+    pub size: u64,
+    /// Path of the data file recorded in the manifest, which is referenced by the position deletes.
+    pub file_path: String,
+    pub position_deletes: Vec<IcebergDeleteFile>,
+    pub equality_deletes: Vec<IcebergDeleteFile>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct IcebergDeleteFile {
+    /// Location of the delete file, relative to the table.
+    pub location: String,
+    pub size: u64,
+    /// Names of the columns to compare, only for equality deletes.
+    pub equality_columns: Vec<String>,
 }
 
 impl IcebergPartInfo {
@@ -53,6 +78,11 @@ impl PartInfo for IcebergPartInfo {
     fn hash(&self) -> u64 {
         match self {
             IcebergPartInfo::Parquet(p) => p.hash(),
+            IcebergPartInfo::ParquetWithDeletes(p) => {
+                let mut s = DefaultHasher::new();
+                p.location.hash(&mut s);
+                s.finish()
+            }
         }
     }
 }
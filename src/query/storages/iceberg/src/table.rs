@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeSet;
 use std::sync::Arc;
 
 use arrow_schema::Schema as ArrowSchema;
@@ -26,6 +27,7 @@ use common_catalog::plan::PartInfo;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::PartitionsShuffleKind;
+use common_catalog::plan::Projection;
 use common_catalog::plan::PushDownInfo;
 use common_catalog::table::Table;
 use common_catalog::table_args::TableArgs;
@@ -33,6 +35,7 @@ use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataSchema;
+use common_expression::FieldIndex;
 use common_expression::TableSchema;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::schema::TableIdent;
@@ -43,9 +46,13 @@ use common_storage::DataOperator;
 use common_storages_parquet::ParquetFilesPart;
 use common_storages_parquet::ParquetPart;
 use common_storages_parquet::ParquetRSReader;
+use icelake::types::DataContentType;
 use storages_common_pruner::RangePrunerCreator;
 use tokio::sync::OnceCell;
 
+use crate::manifest::read_current_manifests;
+use crate::partition::IcebergDeleteFile;
+use crate::partition::IcebergParquetWithDeletesPart;
 use crate::partition::IcebergPartInfo;
 use crate::stats::get_stats_of_data_file;
 use crate::table_source::IcebergTableSource;
@@ -155,6 +162,49 @@ impl IcebergTable {
         let max_threads = std::cmp::min(parts_len, max_threads);
 
         let table_schema = self.schema();
+        let output_schema = Arc::new(DataSchema::from(plan.schema()));
+
+        // The columns of equality deletes are also needed to filter out the deleted rows.
+        let equality_columns = plan
+            .parts
+            .partitions
+            .iter()
+            .filter_map(|part| match IcebergPartInfo::from_part(part) {
+                Ok(IcebergPartInfo::ParquetWithDeletes(part)) => Some(&part.equality_deletes),
+                _ => None,
+            })
+            .flatten()
+            .flat_map(|delete_file| delete_file.equality_columns.iter())
+            .map(|column| table_schema.index_of(column))
+            .collect::<Result<BTreeSet<_>>>()?;
+        let read_plan = read_plan_with_columns(plan, &equality_columns);
+        let read_columns = read_projection(&read_plan)
+            .map(|projection| {
+                projection
+                    .project_schema(&table_schema)
+                    .fields()
+                    .iter()
+                    .map(|f| f.name().clone())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_else(|| {
+                table_schema
+                    .fields()
+                    .iter()
+                    .map(|f| f.name().clone())
+                    .collect()
+            });
+        let output_offsets = output_schema
+            .fields()
+            .iter()
+            .map(|f| {
+                read_columns
+                    .iter()
+                    .position(|name| name == f.name())
+                    .ok_or_else(|| ErrorCode::Internal(format!("Column {} is not read", f.name())))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
         let arrow_schema = table_schema.to_arrow();
         let arrow_fields = arrow_schema
             .fields
@@ -168,14 +218,13 @@ impl IcebergTable {
             self.op.clone(),
             table_schema,
             &arrow_schema,
-            plan,
+            &read_plan,
             ParquetReadOptions::default(),
             true,
         )?);
 
         // TODO: we need to support top_k.
         // TODO: we need to support prewhere.
-        let output_schema = Arc::new(DataSchema::from(plan.schema()));
         pipeline.add_source(
             |output| {
                 IcebergTableSource::create(
@@ -183,6 +232,9 @@ impl IcebergTable {
                     output,
                     output_schema.clone(),
                     praquet_reader.clone(),
+                    self.op.clone(),
+                    read_columns.clone(),
+                    output_offsets.clone(),
                 )
             },
             max_threads.max(1),
@@ -198,9 +250,16 @@ impl IcebergTable {
     ) -> Result<(PartStatistics, Partitions)> {
         let table = self.table().await?;
 
-        let data_files = table.current_data_files().await.map_err(|e| {
-            ErrorCode::ReadTableDataError(format!("Cannot get current data files: {e:?}"))
-        })?;
+        let mut data_files = vec![];
+        let mut position_deletes = vec![];
+        let mut equality_deletes = vec![];
+        for entry in read_current_manifests(table, &self.op).await? {
+            match entry.data_file.content {
+                DataContentType::Data => data_files.push(entry),
+                DataContentType::EqualityDeletes => equality_deletes.push(entry),
+                _ => position_deletes.push(entry),
+            }
+        }
 
         let filter = push_downs
             .as_ref()
@@ -211,20 +270,41 @@ impl IcebergTable {
         let pruner =
             RangePrunerCreator::try_create(ctx.get_function_context()?, &schema, filter.as_ref())?;
 
+        let position_deletes = position_deletes
+            .into_iter()
+            .map(|entry| {
+                Ok((
+                    entry.sequence_number,
+                    self.delete_file(table, entry.data_file)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let equality_deletes = equality_deletes
+            .into_iter()
+            .map(|entry| {
+                Ok((
+                    entry.sequence_number,
+                    self.delete_file(table, entry.data_file)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
         // TODO: support other file formats. We only support parquet files now.
         let mut read_rows = 0;
         let mut read_bytes = 0;
         let total_files = data_files.len();
         let parts = data_files
             .into_iter()
-            .filter(|df| {
-                if let Some(stats) = get_stats_of_data_file(&schema, df) {
+            .filter(|entry| {
+                if let Some(stats) = get_stats_of_data_file(&schema, &entry.data_file) {
                     pruner.should_keep(&stats, None)
                 } else {
                     true
                 }
             })
-            .map(|v: icelake::types::DataFile| {
+            .map(|entry| {
+                let sequence_number = entry.sequence_number;
+                let v = entry.data_file;
                 read_rows += v.record_count as usize;
                 read_bytes += v.file_size_in_bytes as usize;
                 match v.file_format {
@@ -232,14 +312,36 @@ impl IcebergTable {
                         let location = table
                             .rel_path(&v.file_path)
                             .expect("file path must be rel to table");
-                        Ok(Arc::new(
-                            Box::new(IcebergPartInfo::Parquet(ParquetPart::ParquetFiles(
-                                ParquetFilesPart {
-                                    files: vec![(location, v.file_size_in_bytes as u64)],
-                                    estimated_uncompressed_size: v.file_size_in_bytes as u64, // This field is not used here.
-                                },
-                            ))) as Box<dyn PartInfo>,
-                        ))
+
+                        // Position deletes apply to the data files with the same or older sequence number,
+                        // equality deletes apply to the data files with older sequence number.
+                        // TODO: check the partitions of the delete files.
+                        let position_deletes = position_deletes
+                            .iter()
+                            .filter(|(seq, _)| *seq >= sequence_number)
+                            .map(|(_, file)| file.clone())
+                            .collect::<Vec<_>>();
+                        let equality_deletes = equality_deletes
+                            .iter()
+                            .filter(|(seq, _)| *seq > sequence_number)
+                            .map(|(_, file)| file.clone())
+                            .collect::<Vec<_>>();
+
+                        let part = if position_deletes.is_empty() && equality_deletes.is_empty() {
+                            IcebergPartInfo::Parquet(ParquetPart::ParquetFiles(ParquetFilesPart {
+                                files: vec![(location, v.file_size_in_bytes as u64)],
+                                estimated_uncompressed_size: v.file_size_in_bytes as u64, // This field is not used here.
+                            }))
+                        } else {
+                            IcebergPartInfo::ParquetWithDeletes(IcebergParquetWithDeletesPart {
+                                location,
+                                size: v.file_size_in_bytes as u64,
+                                file_path: v.file_path,
+                                position_deletes,
+                                equality_deletes,
+                            })
+                        };
+                        Ok(Arc::new(Box::new(part) as Box<dyn PartInfo>))
                     }
                     _ => Err(ErrorCode::Unimplemented(
                         "Only parquet format is supported for iceberg table",
@@ -255,6 +357,50 @@ impl IcebergTable {
             Partitions::create_nolazy(PartitionsShuffleKind::Mod, parts),
         ))
     }
+
+    fn delete_file(
+        &self,
+        table: &icelake::Table,
+        data_file: icelake::types::DataFile,
+    ) -> Result<IcebergDeleteFile> {
+        if !matches!(
+            data_file.file_format,
+            icelake::types::DataFileFormat::Parquet
+        ) {
+            return Err(ErrorCode::Unimplemented(
+                "Only parquet format is supported for iceberg delete files",
+            ));
+        }
+        let location = table
+            .rel_path(&data_file.file_path)
+            .expect("file path must be rel to table");
+
+        let schema = self.schema();
+        let equality_columns = data_file
+            .equality_ids
+            .unwrap_or_default()
+            .into_iter()
+            .map(|id| {
+                // The column id in iceberg is 1-based while the column id in Databend is 0-based.
+                schema
+                    .fields()
+                    .iter()
+                    .find(|f| f.column_id as i32 + 1 == id)
+                    .map(|f| f.name().clone())
+                    .ok_or_else(|| {
+                        ErrorCode::Unimplemented(format!(
+                            "Equality deletes on field {id} is not supported, only top level columns are supported"
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(IcebergDeleteFile {
+            location,
+            size: data_file.file_size_in_bytes as u64,
+            equality_columns,
+        })
+    }
 }
 
 #[async_trait]
@@ -307,3 +453,28 @@ impl Table for IcebergTable {
         true
     }
 }
+
+/// Add `columns` to the columns to read of `plan`.
+fn read_plan_with_columns(plan: &DataSourcePlan, columns: &BTreeSet<FieldIndex>) -> DataSourcePlan {
+    let mut plan = plan.clone();
+    if let Some(push_downs) = plan.push_downs.as_mut() {
+        for column in columns {
+            if let Some(projection) = push_downs.projection.as_mut() {
+                projection.add_col(*column);
+            }
+            if let Some(prewhere) = push_downs.prewhere.as_mut() {
+                prewhere.output_columns.add_col(*column);
+            }
+        }
+    }
+    plan
+}
+
+/// The projection of the columns read by [`ParquetRSReader`], `None` means all columns.
+fn read_projection(plan: &DataSourcePlan) -> Option<&Projection> {
+    let push_downs = plan.push_downs.as_ref()?;
+    match &push_downs.prewhere {
+        Some(prewhere) => Some(&prewhere.output_columns),
+        None => push_downs.projection.as_ref(),
+    }
+}
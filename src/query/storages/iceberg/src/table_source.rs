@@ -29,9 +29,13 @@ use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
 use common_storages_parquet::ParquetPart;
 use common_storages_parquet::ParquetRSReader;
+use opendal::Operator;
 use opendal::Reader;
 use parquet::arrow::async_reader::ParquetRecordBatchStream;
 
+use crate::delete::project_block;
+use crate::delete::read_position_deletes;
+use crate::delete::EqualityDeletes;
 use crate::partition::IcebergPartInfo;
 
 pub struct IcebergTableSource {
@@ -47,6 +51,14 @@ pub struct IcebergTableSource {
     output_schema: DataSchemaRef,
     parquet_reader: Arc<ParquetRSReader>,
     stream: Option<ParquetRecordBatchStream<Reader>>,
+
+    // Used to apply the delete files.
+    op: Operator,
+    /// Names of the columns read by `parquet_reader`.
+    read_columns: Vec<String>,
+    /// Offsets of the output columns in the read columns.
+    output_offsets: Vec<usize>,
+    equality_deletes: Vec<EqualityDeletes>,
 }
 
 impl IcebergTableSource {
@@ -55,6 +67,9 @@ impl IcebergTableSource {
         output: Arc<OutputPort>,
         output_schema: DataSchemaRef,
         parquet_reader: Arc<ParquetRSReader>,
+        op: Operator,
+        read_columns: Vec<String>,
+        output_offsets: Vec<usize>,
     ) -> Result<ProcessorPtr> {
         let scan_progress = ctx.get_scan_progress();
         Ok(ProcessorPtr::create(Box::new(IcebergTableSource {
@@ -66,8 +81,20 @@ impl IcebergTableSource {
             stream: None,
             generated_data: None,
             is_finished: false,
+            op,
+            read_columns,
+            output_offsets,
+            equality_deletes: vec![],
         })))
     }
+
+    /// Remove the rows deleted by the equality deletes, and project the block to the output columns.
+    fn apply_equality_deletes(&self, mut block: DataBlock) -> Result<DataBlock> {
+        for deletes in self.equality_deletes.iter() {
+            block = deletes.apply(block, &self.read_columns)?;
+        }
+        Ok(project_block(&block, &self.output_offsets))
+    }
 }
 
 #[async_trait::async_trait]
@@ -115,10 +142,14 @@ impl Processor for IcebergTableSource {
                 .parquet_reader
                 .read_block_from_stream(&mut stream)
                 .await?
+                .map(|b| self.apply_equality_deletes(b))
+                .transpose()?
                 .map(|b| check_block_schema(&self.output_schema, b))
                 .transpose()?
             {
-                self.generated_data = Some(block);
+                if !block.is_empty() {
+                    self.generated_data = Some(block);
+                }
                 self.stream = Some(stream);
             }
             // else:
@@ -128,9 +159,26 @@ impl Processor for IcebergTableSource {
             match IcebergPartInfo::from_part(&part)? {
                 IcebergPartInfo::Parquet(ParquetPart::ParquetFiles(files)) => {
                     assert_eq!(files.files.len(), 1);
+                    self.equality_deletes.clear();
+                    let stream = self
+                        .parquet_reader
+                        .prepare_data_stream(&files.files[0].0, None)
+                        .await?;
+                    self.stream = Some(stream);
+                }
+                IcebergPartInfo::ParquetWithDeletes(part) => {
+                    let deleted_rows =
+                        read_position_deletes(&self.op, &part.position_deletes, &part.file_path)
+                            .await?;
+                    self.equality_deletes = futures::future::try_join_all(
+                        part.equality_deletes
+                            .iter()
+                            .map(|delete_file| EqualityDeletes::try_create(&self.op, delete_file)),
+                    )
+                    .await?;
                     let stream = self
                         .parquet_reader
-                        .prepare_data_stream(&files.files[0].0)
+                        .prepare_data_stream(&part.location, Some(&deleted_rows))
                         .await?;
                     self.stream = Some(stream);
                 }
@@ -34,7 +34,8 @@ impl SchemataTable {
             NULL AS default_character_set_schema,
             NULL AS default_character_set_name,
             NULL AS default_collation_name,
-            NULL AS sql_path
+            NULL AS sql_path,
+            comment AS schema_comment
         FROM system.databases;";
 
         let mut options = BTreeMap::new();
@@ -0,0 +1,28 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reading ORC files of a stage with `SELECT ... FROM @stage` and `COPY INTO`.
+//!
+//! Each file is a partition. The stripes of a file are pruned by the pushed down
+//! filter with the stripe statistics, a file is skipped if all its stripes are pruned.
+
+#![allow(clippy::uninlined_format_args)]
+
+mod partition;
+mod pruning;
+mod source;
+mod table;
+
+pub use partition::OrcPart;
+pub use table::OrcTable;
@@ -0,0 +1,210 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableSchemaRef;
+use common_functions::BUILTIN_FUNCTIONS;
+use orc_rust::proto::ColumnStatistics as OrcColumnStatistics;
+use orc_rust::reader::metadata::FileMetadata;
+use storages_common_pruner::RangePruner;
+use storages_common_pruner::RangePrunerCreator;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+
+/// Prunes the stripes of ORC files with the statistics of stripes.
+pub struct OrcPruner {
+    schema: TableSchemaRef,
+    range_pruner: Option<Arc<dyn RangePruner + Send + Sync>>,
+    /// Names of the top level columns referred by the filter.
+    predicate_columns: Vec<String>,
+}
+
+impl OrcPruner {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        schema: TableSchemaRef,
+        push_down: &Option<PushDownInfo>,
+    ) -> Result<Self> {
+        let filter = push_down
+            .as_ref()
+            .and_then(|p| p.filter.as_ref().map(|f| f.as_expr(&BUILTIN_FUNCTIONS)));
+
+        let mut predicate_columns = vec![];
+        let range_pruner = match &filter {
+            Some(expr) => {
+                predicate_columns = expr.column_refs().into_keys().collect();
+                Some(RangePrunerCreator::try_create(
+                    func_ctx,
+                    &schema,
+                    filter.as_ref(),
+                )?)
+            }
+            None => None,
+        };
+
+        Ok(OrcPruner {
+            schema,
+            range_pruner,
+            predicate_columns,
+        })
+    }
+
+    /// Prune the stripes of an ORC file.
+    ///
+    /// Return the row ranges of the selected stripes, the adjacent ones are merged.
+    pub fn prune_stripes(&self, meta: &FileMetadata) -> Vec<Range<usize>> {
+        let mut ranges: Vec<Range<usize>> = vec![];
+        let mut start = 0;
+        for (i, stripe) in meta.footer.stripes.iter().enumerate() {
+            let num_rows = stripe.number_of_rows.unwrap_or_default() as usize;
+            let end = start + num_rows;
+            if num_rows > 0 && self.should_keep_stripe(meta, i, num_rows) {
+                match ranges.last_mut() {
+                    Some(last) if last.end == start => last.end = end,
+                    _ => ranges.push(start..end),
+                }
+            }
+            start = end;
+        }
+        ranges
+    }
+
+    fn should_keep_stripe(&self, meta: &FileMetadata, stripe: usize, num_rows: usize) -> bool {
+        let Some(pruner) = &self.range_pruner else {
+            return true;
+        };
+        // Files written without stripe statistics can't be pruned.
+        let Some(stripe_stats) = meta.metadata.stripe_stats.get(stripe) else {
+            return true;
+        };
+        let Some(root) = meta.footer.types.first() else {
+            return true;
+        };
+
+        let mut stats = HashMap::with_capacity(self.predicate_columns.len());
+        for name in &self.predicate_columns {
+            let Ok(field) = self.schema.field_with_name(name) else {
+                continue;
+            };
+            let column_ids = self.schema.leaf_columns_of(name);
+            if column_ids.len() != 1 {
+                // Nested columns are not pruned.
+                continue;
+            }
+            // The columns of an ORC file are numbered in pre-order, the subtypes of
+            // the root struct are the ids of the top level columns.
+            let orc_column_id = root
+                .field_names
+                .iter()
+                .position(|n| n.eq_ignore_ascii_case(name))
+                .and_then(|i| root.subtypes.get(i));
+            let Some(orc_stats) =
+                orc_column_id.and_then(|id| stripe_stats.col_stats.get(*id as usize))
+            else {
+                continue;
+            };
+            if let Some(column_stats) =
+                convert_column_statistics(orc_stats, &field.data_type().remove_nullable(), num_rows)
+            {
+                stats.insert(column_ids[0], column_stats);
+            }
+        }
+        pruner.should_keep(&stats, None)
+    }
+}
+
+/// Convert the ORC statistics of a column to [`ColumnStatistics`].
+///
+/// Return `None` if the statistics of the type are not supported or missing.
+fn convert_column_statistics(
+    s: &OrcColumnStatistics,
+    typ: &TableDataType,
+    num_rows: usize,
+) -> Option<ColumnStatistics> {
+    let num_values = s.number_of_values? as usize;
+    let null_count = num_rows.saturating_sub(num_values) as u64;
+    if num_values == 0 {
+        return Some(ColumnStatistics::new(
+            Scalar::Null,
+            Scalar::Null,
+            null_count,
+            0,
+            None,
+        ));
+    }
+
+    let (min, max) = match typ {
+        TableDataType::Number(
+            NumberDataType::Int8
+            | NumberDataType::Int16
+            | NumberDataType::Int32
+            | NumberDataType::Int64,
+        ) => {
+            let int_stats = s.int_statistics.as_ref()?;
+            let (min, max) = (int_stats.minimum?, int_stats.maximum?);
+            match typ {
+                TableDataType::Number(NumberDataType::Int8) => {
+                    (Scalar::from(min as i8), Scalar::from(max as i8))
+                }
+                TableDataType::Number(NumberDataType::Int16) => {
+                    (Scalar::from(min as i16), Scalar::from(max as i16))
+                }
+                TableDataType::Number(NumberDataType::Int32) => {
+                    (Scalar::from(min as i32), Scalar::from(max as i32))
+                }
+                _ => (Scalar::from(min), Scalar::from(max)),
+            }
+        }
+        TableDataType::Number(NumberDataType::Float32) => {
+            let double_stats = s.double_statistics.as_ref()?;
+            let (min, max) = (double_stats.minimum?, double_stats.maximum?);
+            (Scalar::from(min as f32), Scalar::from(max as f32))
+        }
+        TableDataType::Number(NumberDataType::Float64) => {
+            let double_stats = s.double_statistics.as_ref()?;
+            (
+                Scalar::from(double_stats.minimum?),
+                Scalar::from(double_stats.maximum?),
+            )
+        }
+        TableDataType::String => {
+            // The minimum and maximum are absent if they are truncated by the writer.
+            let string_stats = s.string_statistics.as_ref()?;
+            (
+                Scalar::String(string_stats.minimum.clone()?.into_bytes()),
+                Scalar::String(string_stats.maximum.clone()?.into_bytes()),
+            )
+        }
+        TableDataType::Date => {
+            let date_stats = s.date_statistics.as_ref()?;
+            (
+                Scalar::Date(date_stats.minimum?),
+                Scalar::Date(date_stats.maximum?),
+            )
+        }
+        // TODO(orc): timestamps and decimals.
+        _ => return None,
+    };
+
+    Some(ColumnStatistics::new(min, max, null_count, 0, None))
+}
@@ -0,0 +1,267 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::io::Cursor;
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use common_base::base::Progress;
+use common_base::base::ProgressValues;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::processors::Processor;
+use opendal::Operator;
+use orc_rust::arrow_reader::ArrowReader;
+use orc_rust::arrow_reader::Cursor as OrcCursor;
+
+use crate::partition::OrcPart;
+use crate::pruning::OrcPruner;
+use crate::table::open_orc_file;
+use crate::table::orc_error;
+
+/// The ORC file being read.
+struct OrcFileReader {
+    path: String,
+    reader: ArrowReader<Cursor<Vec<u8>>>,
+    /// Rows of the stripes kept by the pruner.
+    row_ranges: Vec<Range<usize>>,
+    /// Offset of the next batch in the file.
+    offset: usize,
+}
+
+pub struct OrcSource {
+    // Source processor related fields.
+    output: Arc<OutputPort>,
+    scan_progress: Arc<Progress>,
+    // Used for event transforming.
+    ctx: Arc<dyn TableContext>,
+    generated_data: Option<DataBlock>,
+    is_finished: bool,
+
+    // Used to read ORC files.
+    operator: Operator,
+    output_schema: DataSchemaRef,
+    pruner: Arc<OrcPruner>,
+    batch_size: usize,
+    reader: Option<OrcFileReader>,
+}
+
+impl OrcSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        operator: Operator,
+        output_schema: DataSchemaRef,
+        pruner: Arc<OrcPruner>,
+    ) -> Result<ProcessorPtr> {
+        let scan_progress = ctx.get_scan_progress();
+        let batch_size = ctx.get_settings().get_max_block_size()? as usize;
+        Ok(ProcessorPtr::create(Box::new(OrcSource {
+            output,
+            scan_progress,
+            ctx,
+            generated_data: None,
+            is_finished: false,
+            operator,
+            output_schema,
+            pruner,
+            batch_size,
+            reader: None,
+        })))
+    }
+
+    async fn open_file(&self, part: &OrcPart) -> Result<Option<OrcFileReader>> {
+        let path = &part.location;
+        let data = self.operator.read(path).await?;
+        let reader = open_orc_file(path, data)?;
+
+        let row_ranges = self.pruner.prune_stripes(reader.metadata());
+        if row_ranges.is_empty() {
+            return Ok(None);
+        }
+
+        // Only decode the columns to output, at least one column is needed
+        // to know the number of rows.
+        let file_fields = reader
+            .metadata()
+            .footer
+            .types
+            .first()
+            .map(|root| root.field_names.clone())
+            .unwrap_or_default();
+        let mut columns = self
+            .output_schema
+            .fields()
+            .iter()
+            .map(|f| {
+                file_fields
+                    .iter()
+                    .find(|n| n.eq_ignore_ascii_case(f.name()))
+                    .cloned()
+                    .ok_or_else(|| {
+                        ErrorCode::TableSchemaMismatch(format!(
+                            "column '{}' not found in ORC file '{}'",
+                            f.name(),
+                            path
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if columns.is_empty() {
+            columns.extend(file_fields.into_iter().take(1));
+        }
+
+        let cursor = OrcCursor::new(reader, &columns).map_err(|e| orc_error(path, e))?;
+        Ok(Some(OrcFileReader {
+            path: path.clone(),
+            reader: ArrowReader::new(cursor, Some(self.batch_size)),
+            row_ranges,
+            offset: 0,
+        }))
+    }
+
+    fn to_block(&self, path: &str, batch: &RecordBatch) -> Result<DataBlock> {
+        let batch_schema = batch.schema();
+        let mut columns = Vec::with_capacity(self.output_schema.num_fields());
+        for field in self.output_schema.fields() {
+            let i = batch_schema
+                .fields()
+                .iter()
+                .position(|f| f.name().eq_ignore_ascii_case(field.name()))
+                .ok_or_else(|| {
+                    ErrorCode::TableSchemaMismatch(format!(
+                        "column '{}' not found in ORC file '{}'",
+                        field.name(),
+                        path
+                    ))
+                })?;
+            let column = Column::from_arrow_rs(batch.column(i).clone(), batch_schema.field(i))?;
+            if &column.data_type() != field.data_type() {
+                return Err(ErrorCode::TableSchemaMismatch(format!(
+                    "Data schema mismatched (col name: {}) in ORC file '{}'. Data column type is {:?}, but schema field type is {:?}",
+                    field.name(),
+                    path,
+                    column.data_type(),
+                    field.data_type()
+                )));
+            }
+            columns.push(column);
+        }
+
+        if columns.is_empty() {
+            Ok(DataBlock::new(vec![], batch.num_rows()))
+        } else {
+            Ok(DataBlock::new_from_columns(columns))
+        }
+    }
+
+    fn read_block(&self, file: &mut OrcFileReader) -> Result<Option<DataBlock>> {
+        while let Some(batch) = file.reader.next() {
+            let batch = batch.map_err(|e| orc_error(&file.path, e))?;
+            let batch_range = file.offset..file.offset + batch.num_rows();
+            file.offset = batch_range.end;
+
+            // Keep the rows of the batch which belong to the selected stripes.
+            let mut selected = vec![];
+            for range in &file.row_ranges {
+                let start = range.start.max(batch_range.start);
+                let end = range.end.min(batch_range.end);
+                if start < end {
+                    selected.push(start - batch_range.start..end - batch_range.start);
+                }
+            }
+            if selected.is_empty() {
+                continue;
+            }
+
+            let block = self.to_block(&file.path, &batch)?;
+            if selected.len() == 1 && selected[0].len() == block.num_rows() {
+                return Ok(Some(block));
+            }
+            let blocks = selected
+                .into_iter()
+                .map(|range| block.slice(range))
+                .collect::<Vec<_>>();
+            return Ok(Some(DataBlock::concat(&blocks)?));
+        }
+        Ok(None)
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for OrcSource {
+    fn name(&self) -> String {
+        "OrcSource".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.is_finished {
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        if self.output.is_finished() {
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            return Ok(Event::NeedConsume);
+        }
+
+        match self.generated_data.take() {
+            None => Ok(Event::Async),
+            Some(data_block) => {
+                let progress_values = ProgressValues {
+                    rows: data_block.num_rows(),
+                    bytes: data_block.memory_size(),
+                };
+                self.scan_progress.incr(&progress_values);
+                self.output.push_data(Ok(data_block));
+                Ok(Event::NeedConsume)
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        if let Some(mut file) = self.reader.take() {
+            if let Some(block) = self.read_block(&mut file)? {
+                self.generated_data = Some(block);
+                self.reader = Some(file);
+            }
+            // else:
+            // The file is finished, try to open another file (in next event loop).
+        } else if let Some(part) = self.ctx.get_partition() {
+            // Files whose stripes are all pruned are skipped.
+            self.reader = self.open_file(OrcPart::from_part(&part)?).await?;
+        } else {
+            self.is_finished = true;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,259 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow_array::RecordBatchReader;
+use arrow_schema::Field as ArrowField;
+use arrow_schema::Schema as ArrowSchema;
+use chrono::NaiveDateTime;
+use chrono::TimeZone;
+use chrono::Utc;
+use common_catalog::plan::DataSourceInfo;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::OrcTableInfo;
+use common_catalog::plan::PartInfo;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataSchema;
+use common_expression::TableSchema;
+use common_meta_app::principal::StageInfo;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_pipeline_core::Pipeline;
+use common_storage::init_stage_operator;
+use common_storage::StageFileInfo;
+use common_storage::StageFilesInfo;
+use opendal::Operator;
+use orc_rust::arrow_reader::ArrowReader;
+use orc_rust::arrow_reader::Cursor as OrcCursor;
+use orc_rust::reader::Reader;
+
+use crate::partition::OrcPart;
+use crate::pruning::OrcPruner;
+use crate::source::OrcSource;
+
+pub struct OrcTable {
+    pub(crate) stage_info: StageInfo,
+    pub(crate) files_info: StageFilesInfo,
+    pub(crate) files_to_read: Option<Vec<StageFileInfo>>,
+    pub(crate) schema_from: String,
+
+    pub(crate) operator: Operator,
+    pub(crate) table_info: TableInfo,
+}
+
+impl OrcTable {
+    pub fn from_info(info: &OrcTableInfo) -> Result<Arc<dyn Table>> {
+        let operator = init_stage_operator(&info.stage_info)?;
+
+        Ok(Arc::new(OrcTable {
+            stage_info: info.stage_info.clone(),
+            files_info: info.files_info.clone(),
+            files_to_read: info.files_to_read.clone(),
+            schema_from: info.schema_from.clone(),
+            operator,
+            table_info: info.table_info.clone(),
+        }))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn create(
+        stage_info: StageInfo,
+        files_info: StageFilesInfo,
+        files_to_read: Option<Vec<StageFileInfo>>,
+    ) -> Result<Arc<dyn Table>> {
+        let operator = init_stage_operator(&stage_info)?;
+        let first_file = match &files_to_read {
+            Some(files) => files[0].path.clone(),
+            None => files_info.first_file(&operator).await?.path.clone(),
+        };
+
+        // Infer schema from the first ORC file.
+        // Assume all ORC files have the same schema.
+        // If not, throw error during reading.
+        let data = operator.read(&first_file).await?;
+        let reader = open_orc_file(&first_file, data)?;
+        let cursor = OrcCursor::root(reader).map_err(|e| orc_error(&first_file, e))?;
+        let arrow_schema = ArrowReader::new(cursor, None).schema();
+        let table_info = naive_orc_table_info(arrow_to_table_schema(&arrow_schema)?.into());
+
+        Ok(Arc::new(OrcTable {
+            stage_info,
+            files_info,
+            files_to_read,
+            schema_from: first_file,
+            operator,
+            table_info,
+        }))
+    }
+
+    #[async_backtrace::framed]
+    async fn do_read_partitions(&self) -> Result<(PartStatistics, Partitions)> {
+        let files = match &self.files_to_read {
+            Some(files) => files
+                .iter()
+                .map(|f| (f.path.clone(), f.size))
+                .collect::<Vec<_>>(),
+            None => self
+                .files_info
+                .list(&self.operator, false, None)
+                .await?
+                .into_iter()
+                .map(|f| (f.path, f.size))
+                .collect::<Vec<_>>(),
+        };
+
+        // The stripes are pruned when the files are read, the number of rows is unknown here.
+        let read_bytes = files.iter().map(|(_, size)| *size as usize).sum();
+        let stats = PartStatistics::new_estimated(None, 0, read_bytes, files.len(), files.len());
+        let parts = files
+            .into_iter()
+            .map(|(location, size)| {
+                Arc::new(Box::new(OrcPart { location, size }) as Box<dyn PartInfo>)
+            })
+            .collect();
+        Ok((
+            stats,
+            Partitions::create_nolazy(PartitionsShuffleKind::Mod, parts),
+        ))
+    }
+
+    fn do_read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::min(plan.parts.len(), max_threads);
+
+        let pruner = Arc::new(OrcPruner::try_create(
+            ctx.get_function_context()?,
+            self.schema(),
+            &plan.push_downs,
+        )?);
+        let output_schema = Arc::new(DataSchema::from(plan.schema()));
+        pipeline.add_source(
+            |output| {
+                OrcSource::create(
+                    ctx.clone(),
+                    output,
+                    self.operator.clone(),
+                    output_schema.clone(),
+                    pruner.clone(),
+                )
+            },
+            max_threads.max(1),
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for OrcTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn is_local(&self) -> bool {
+        false
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn support_column_projection(&self) -> bool {
+        true
+    }
+
+    fn get_data_source_info(&self) -> DataSourceInfo {
+        DataSourceInfo::OrcSource(OrcTableInfo {
+            stage_info: self.stage_info.clone(),
+            files_info: self.files_info.clone(),
+            table_info: self.table_info.clone(),
+            files_to_read: self.files_to_read.clone(),
+            schema_from: self.schema_from.clone(),
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        self.do_read_partitions().await
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        self.do_read_data(ctx, plan, pipeline)
+    }
+
+    fn is_stage_table(&self) -> bool {
+        true
+    }
+}
+
+pub(crate) fn open_orc_file(path: &str, data: Vec<u8>) -> Result<Reader<Cursor<Vec<u8>>>> {
+    Reader::new(Cursor::new(data)).map_err(|e| orc_error(path, e))
+}
+
+pub(crate) fn orc_error(path: &str, e: impl std::fmt::Display) -> ErrorCode {
+    ErrorCode::BadBytes(format!("fail to read ORC file '{}': {}", path, e))
+}
+
+fn arrow_to_table_schema(schema: &ArrowSchema) -> Result<TableSchema> {
+    let fields = schema
+        .fields
+        .iter()
+        .map(|f| {
+            let name = f.name().to_lowercase();
+            Arc::new(ArrowField::clone(f).with_name(name))
+        })
+        .collect::<Vec<_>>();
+    let schema = ArrowSchema::new_with_metadata(fields, schema.metadata().clone());
+    TableSchema::try_from(&schema).map_err(ErrorCode::from_std_error)
+}
+
+fn naive_orc_table_info(schema: Arc<TableSchema>) -> TableInfo {
+    TableInfo {
+        ident: TableIdent::new(0, 0),
+        desc: "''.'read_orc'".to_string(),
+        name: "read_orc".to_string(),
+        meta: TableMeta {
+            schema,
+            engine: "SystemReadOrc".to_string(),
+            created_on: Utc.from_utc_datetime(&NaiveDateTime::from_timestamp_opt(0, 0).unwrap()),
+            updated_on: Utc.from_utc_datetime(&NaiveDateTime::from_timestamp_opt(0, 0).unwrap()),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
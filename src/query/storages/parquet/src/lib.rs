@@ -25,7 +25,16 @@ mod utils;
 pub use parquet2::Parquet2Table;
 pub use parquet_part::ParquetFilesPart;
 pub use parquet_part::ParquetPart;
+pub use parquet_rs::DeltaTable;
+pub use parquet_rs::DeltaTablePart;
+pub use parquet_rs::ExternalTable;
+pub use parquet_rs::ExternalTablePart;
 pub use parquet_rs::ParquetRSPruner;
 pub use parquet_rs::ParquetRSReader;
 pub use parquet_rs::ParquetRSRowGroupPart;
 pub use parquet_rs::ParquetRSTable;
+pub use parquet_rs::DELTA_ENGINE;
+pub use parquet_rs::EXTERNAL_ENGINE;
+pub use parquet_rs::EXTERNAL_OPT_KEY_FILE_LIST_CACHE_TTL;
+pub use parquet_rs::EXTERNAL_OPT_KEY_PARTITION_COLUMNS;
+pub use parquet_rs::EXTERNAL_OPT_KEY_PATTERN;
@@ -40,6 +40,7 @@ use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
+use dashmap::DashMap;
 use opendal::services::Memory;
 use opendal::Operator;
 
@@ -66,6 +67,7 @@ pub trait SmallFilePrunner: Send + Sync {
 pub struct Parquet2DeserializeTransform {
     // Used for pipeline operations
     scan_progress: Arc<Progress>,
+    rows_loaded_map: Arc<DashMap<String, u64>>,
     input: Arc<InputPort>,
     output: Arc<OutputPort>,
     output_data: Vec<DataBlock>,
@@ -102,10 +104,12 @@ impl Parquet2DeserializeTransform {
         partition_pruner: Arc<PartitionPruner>,
     ) -> Result<ProcessorPtr> {
         let scan_progress = ctx.get_scan_progress();
+        let rows_loaded_map = ctx.get_rows_loaded_per_file();
 
         Ok(ProcessorPtr::create(Box::new(
             Parquet2DeserializeTransform {
                 scan_progress,
+                rows_loaded_map,
                 input,
                 output,
                 output_data: vec![],
@@ -178,6 +182,11 @@ impl Parquet2DeserializeTransform {
         part: &Parquet2RowGroupPart,
         readers: &mut IndexedReaders,
     ) -> Result<Option<DataBlock>> {
+        *self
+            .rows_loaded_map
+            .entry(part.location.clone())
+            .or_insert(0) += part.num_rows as u64;
+
         let row_selection = part
             .row_selection
             .as_ref()
@@ -0,0 +1,145 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opendal::Operator;
+use roaring::RoaringTreemap;
+
+use super::log::table_relative_path;
+
+/// Magic number at the beginning of a serialized deletion vector.
+const DELETION_VECTOR_MAGIC: u32 = 1681511377;
+
+const Z85_CHARS: &[u8] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+/// The rows deleted from a data file, stored inline or in a deletion vector file.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeletionVectorDescriptor {
+    /// `u` for a file in the table with an uuid name, `p` for a file of absolute path,
+    /// and `i` for an inline deletion vector.
+    pub storage_type: String,
+    pub path_or_inline_dv: String,
+    /// Offset of the deletion vector in the file.
+    pub offset: Option<i32>,
+    pub size_in_bytes: i32,
+    pub cardinality: i64,
+}
+
+impl DeletionVectorDescriptor {
+    pub(super) fn unique_id(&self) -> String {
+        match self.offset {
+            Some(offset) => format!("{}{}@{}", self.storage_type, self.path_or_inline_dv, offset),
+            None => format!("{}{}", self.storage_type, self.path_or_inline_dv),
+        }
+    }
+
+    /// Read the sorted positions of the deleted rows, `op` is the operator of the table.
+    #[async_backtrace::framed]
+    pub(super) async fn read(&self, op: &Operator) -> Result<Vec<u64>> {
+        let size = self.size_in_bytes as usize;
+        let data = match self.storage_type.as_str() {
+            "i" => {
+                let mut data = z85_decode(&self.path_or_inline_dv)?;
+                data.truncate(size);
+                data
+            }
+            "u" | "p" => {
+                let path = self.file_path(op)?;
+                // The deletion vector is prefixed by its size, and followed by its checksum.
+                let offset = self.offset.unwrap_or(1) as u64 + 4;
+                op.range_read(&path, offset..offset + size as u64).await?
+            }
+            storage_type => {
+                return Err(ErrorCode::StorageOther(format!(
+                    "unknown storage type '{}' of deletion vector",
+                    storage_type
+                )));
+            }
+        };
+
+        if data.len() < 4
+            || u32::from_le_bytes(data[..4].try_into().unwrap()) != DELETION_VECTOR_MAGIC
+        {
+            return Err(ErrorCode::StorageOther(format!(
+                "invalid deletion vector '{}'",
+                self.unique_id()
+            )));
+        }
+        let bitmap = RoaringTreemap::deserialize_from(&data[4..]).map_err(|e| {
+            ErrorCode::StorageOther(format!(
+                "invalid deletion vector '{}': {}",
+                self.unique_id(),
+                e
+            ))
+        })?;
+        Ok(bitmap.iter().collect())
+    }
+
+    /// Path of the deletion vector file relative to the root of the table.
+    fn file_path(&self, op: &Operator) -> Result<String> {
+        if self.storage_type == "p" {
+            return table_relative_path(op, &self.path_or_inline_dv);
+        }
+
+        // The last 20 characters are the z85 encoded uuid of the file, the others are
+        // the random prefix of its directory.
+        let encoded = &self.path_or_inline_dv;
+        if encoded.len() < 20 {
+            return Err(ErrorCode::StorageOther(format!(
+                "invalid deletion vector path '{}'",
+                encoded
+            )));
+        }
+        let (prefix, uuid) = encoded.split_at(encoded.len() - 20);
+        let uuid = z85_decode(uuid)?
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<String>();
+        let file_name = format!(
+            "deletion_vector_{}-{}-{}-{}-{}.bin",
+            &uuid[0..8],
+            &uuid[8..12],
+            &uuid[12..16],
+            &uuid[16..20],
+            &uuid[20..32]
+        );
+        if prefix.is_empty() {
+            Ok(file_name)
+        } else {
+            Ok(format!("{}/{}", prefix, file_name))
+        }
+    }
+}
+
+/// Decode the Z85 encoding, every 5 characters are decoded into 4 bytes.
+fn z85_decode(encoded: &str) -> Result<Vec<u8>> {
+    let invalid = || ErrorCode::StorageOther(format!("invalid z85 encoded string '{}'", encoded));
+    if encoded.len() % 5 != 0 {
+        return Err(invalid());
+    }
+    let mut decoded = Vec::with_capacity(encoded.len() / 5 * 4);
+    for chunk in encoded.as_bytes().chunks(5) {
+        let mut value: u64 = 0;
+        for c in chunk {
+            let digit = Z85_CHARS.iter().position(|z| z == c).ok_or_else(invalid)?;
+            value = value * 85 + digit as u64;
+        }
+        let value = u32::try_from(value).map_err(|_| invalid())?;
+        decoded.extend_from_slice(&value.to_be_bytes());
+    }
+    Ok(decoded)
+}
@@ -0,0 +1,409 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Replay of the transaction log of Delta Lake tables.
+//!
+//! See <https://github.com/delta-io/delta/blob/master/PROTOCOL.md> for the protocol.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int32Type;
+use arrow_array::types::Int64Type;
+use arrow_array::Array;
+use arrow_schema::DataType as ArrowDataType;
+use bytes::Bytes;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::TryStreamExt;
+use opendal::Operator;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+
+use super::deletion_vector::DeletionVectorDescriptor;
+
+pub(super) const DELTA_LOG_DIR: &str = "_delta_log/";
+
+/// Reader features this implementation knows how to handle.
+const SUPPORTED_READER_FEATURES: &[&str] = &["deletionVectors", "timestampNtz"];
+
+/// A data file of the table, added by an `add` action.
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AddFile {
+    /// Path of the file relative to the root of the table, URL encoded.
+    pub path: String,
+    #[serde(default)]
+    pub partition_values: HashMap<String, Option<String>>,
+    pub size: u64,
+    /// JSON encoded statistics of the file.
+    pub stats: Option<String>,
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+impl AddFile {
+    /// Number of records in the file, if the statistics are collected.
+    pub fn num_records(&self) -> Option<u64> {
+        let stats: JsonValue = serde_json::from_str(self.stats.as_ref()?).ok()?;
+        stats.get("numRecords")?.as_u64()
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+struct RemoveFile {
+    path: String,
+    deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Metadata {
+    /// The schema of the table, in the JSON format of spark `StructType`.
+    pub schema_string: String,
+    #[serde(default)]
+    pub partition_columns: Vec<String>,
+    #[serde(default)]
+    pub configuration: HashMap<String, Option<String>>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Protocol {
+    min_reader_version: i32,
+    reader_features: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Default, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Action {
+    add: Option<AddFile>,
+    remove: Option<RemoveFile>,
+    meta_data: Option<Metadata>,
+    protocol: Option<Protocol>,
+}
+
+/// The state of a Delta table at its latest version.
+pub(super) struct DeltaSnapshot {
+    pub version: u64,
+    pub metadata: Metadata,
+    /// The data files of the table, in the order they are added.
+    pub files: Vec<AddFile>,
+}
+
+impl DeltaSnapshot {
+    /// Replay the transaction log under the root of `op`, starting from the latest
+    /// complete checkpoint, followed by the commits after it.
+    #[async_backtrace::framed]
+    pub async fn load(op: &Operator) -> Result<DeltaSnapshot> {
+        let mut commits = BTreeMap::new();
+        let mut checkpoints: BTreeMap<u64, (Option<u64>, Vec<String>)> = BTreeMap::new();
+        let mut lister = op.list(DELTA_LOG_DIR).await?;
+        while let Some(entry) = lister.try_next().await? {
+            match parse_log_file_name(entry.name()) {
+                Some(LogFile::Commit(version)) => {
+                    commits.insert(version, entry.path().to_string());
+                }
+                Some(LogFile::Checkpoint { version, parts }) => {
+                    let checkpoint = checkpoints.entry(version).or_default();
+                    checkpoint.0 = parts;
+                    checkpoint.1.push(entry.path().to_string());
+                }
+                None => {}
+            }
+        }
+
+        // Multi-part checkpoints are usable only if all the parts are written.
+        let checkpoint = checkpoints
+            .into_iter()
+            .rev()
+            .find(|(_, (parts, paths))| parts.map_or(true, |n| n as usize == paths.len()));
+
+        let mut replay = LogReplay::default();
+        let mut version = None;
+        if let Some((checkpoint_version, (_, mut paths))) = checkpoint {
+            paths.sort();
+            for path in paths.iter() {
+                for action in read_checkpoint(op, path).await? {
+                    replay.apply(action)?;
+                }
+            }
+            version = Some(checkpoint_version);
+        }
+        for (commit_version, path) in commits.into_iter() {
+            if version.is_some_and(|v| commit_version <= v) {
+                continue;
+            }
+            let expected = version.map_or(0, |v| v + 1);
+            if commit_version != expected {
+                return Err(ErrorCode::StorageOther(format!(
+                    "commit {} of delta table is missing in {}",
+                    expected, DELTA_LOG_DIR
+                )));
+            }
+            let data = op.read(&path).await?;
+            for line in data.split(|b| *b == b'\n') {
+                if line.iter().all(|b| b.is_ascii_whitespace()) {
+                    continue;
+                }
+                let action: Action = serde_json::from_slice(line).map_err(|e| {
+                    ErrorCode::StorageOther(format!("invalid action in '{}': {}", path, e))
+                })?;
+                replay.apply(action)?;
+            }
+            version = Some(commit_version);
+        }
+
+        let version = version.ok_or_else(|| {
+            ErrorCode::StorageOther(format!(
+                "no delta transaction log found in {}",
+                DELTA_LOG_DIR
+            ))
+        })?;
+        let metadata = replay.metadata.ok_or_else(|| {
+            ErrorCode::StorageOther("no metadata found in the delta transaction log")
+        })?;
+        check_column_mapping(&metadata)?;
+        Ok(DeltaSnapshot {
+            version,
+            metadata,
+            files: replay.files.into_values().collect(),
+        })
+    }
+}
+
+#[derive(Default)]
+struct LogReplay {
+    metadata: Option<Metadata>,
+    /// Data files by the path and the deletion vector, ordered by when they are added.
+    files: BTreeMap<usize, AddFile>,
+    file_ids: HashMap<(String, Option<String>), usize>,
+    next_id: usize,
+}
+
+impl LogReplay {
+    fn apply(&mut self, action: Action) -> Result<()> {
+        if let Some(protocol) = action.protocol {
+            check_protocol(&protocol)?;
+        }
+        if let Some(metadata) = action.meta_data {
+            self.metadata = Some(metadata);
+        }
+        if let Some(remove) = action.remove {
+            let key = (
+                remove.path,
+                remove.deletion_vector.as_ref().map(|dv| dv.unique_id()),
+            );
+            if let Some(id) = self.file_ids.remove(&key) {
+                self.files.remove(&id);
+            }
+        }
+        if let Some(add) = action.add {
+            let key = (
+                add.path.clone(),
+                add.deletion_vector.as_ref().map(|dv| dv.unique_id()),
+            );
+            if let Some(id) = self.file_ids.insert(key, self.next_id) {
+                self.files.remove(&id);
+            }
+            self.files.insert(self.next_id, add);
+            self.next_id += 1;
+        }
+        Ok(())
+    }
+}
+
+/// Convert the URI of a file in the delta log, which is relative to the root of the
+/// table or absolute, into the path relative to the root of `op`.
+pub(super) fn table_relative_path(op: &Operator, uri: &str) -> Result<String> {
+    let path = match uri.split_once("://") {
+        Some((_, rest)) => {
+            let info = op.info();
+            let root = info.root();
+            let path = rest.find('/').map_or("/", |i| &rest[i..]);
+            path.strip_prefix(root)
+                .or_else(|| path.strip_prefix(root.trim_end_matches('/')))
+                .ok_or_else(|| {
+                    ErrorCode::StorageOther(format!(
+                        "file '{}' is not in the location of the delta table",
+                        uri
+                    ))
+                })?
+                .trim_start_matches('/')
+        }
+        None => uri,
+    };
+    percent_decode(path)
+}
+
+fn percent_decode(path: &str) -> Result<String> {
+    let bytes = path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+            if let Some(b) = hex.and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+                decoded.push(b);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8(decoded)
+        .map_err(|_| ErrorCode::StorageOther(format!("invalid encoded path '{}'", path)))
+}
+
+enum LogFile {
+    Commit(u64),
+    Checkpoint { version: u64, parts: Option<u64> },
+}
+
+/// Parse the names of the commit files `<version>.json` and the checkpoint files
+/// `<version>.checkpoint.parquet` or `<version>.checkpoint.<part>.<parts>.parquet`.
+fn parse_log_file_name(name: &str) -> Option<LogFile> {
+    let (version, rest) = name.split_once('.')?;
+    if version.len() != 20 {
+        return None;
+    }
+    let version = version.parse::<u64>().ok()?;
+    if rest == "json" {
+        return Some(LogFile::Commit(version));
+    }
+    let rest = rest.strip_prefix("checkpoint.")?.strip_suffix("parquet")?;
+    if rest.is_empty() {
+        return Some(LogFile::Checkpoint {
+            version,
+            parts: None,
+        });
+    }
+    let (_, parts) = rest.strip_suffix('.')?.split_once('.')?;
+    Some(LogFile::Checkpoint {
+        version,
+        parts: Some(parts.parse::<u64>().ok()?),
+    })
+}
+
+fn check_protocol(protocol: &Protocol) -> Result<()> {
+    match protocol.min_reader_version {
+        1 | 2 => Ok(()),
+        3 => {
+            let features = protocol.reader_features.as_deref().unwrap_or_default();
+            match features
+                .iter()
+                .find(|f| !SUPPORTED_READER_FEATURES.contains(&f.as_str()))
+            {
+                Some(feature) => Err(ErrorCode::Unimplemented(format!(
+                    "reader feature '{}' of delta table is not supported",
+                    feature
+                ))),
+                None => Ok(()),
+            }
+        }
+        version => Err(ErrorCode::Unimplemented(format!(
+            "delta table of reader version {} is not supported",
+            version
+        ))),
+    }
+}
+
+fn check_column_mapping(metadata: &Metadata) -> Result<()> {
+    match metadata
+        .configuration
+        .get("delta.columnMapping.mode")
+        .cloned()
+        .flatten()
+    {
+        Some(mode) if mode != "none" => Err(ErrorCode::Unimplemented(format!(
+            "column mapping mode '{}' of delta table is not supported",
+            mode
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Read the actions in a checkpoint file, each row of which is an action.
+#[async_backtrace::framed]
+async fn read_checkpoint(op: &Operator, path: &str) -> Result<Vec<Action>> {
+    let data = Bytes::from(op.read(path).await?);
+    let reader = ParquetRecordBatchReaderBuilder::try_new(data)?.build()?;
+    let mut actions = vec![];
+    for batch in reader {
+        let batch = batch?;
+        let schema = batch.schema();
+        for row in 0..batch.num_rows() {
+            let action = schema
+                .fields()
+                .iter()
+                .zip(batch.columns())
+                .map(|(field, column)| (field.name().clone(), arrow_to_json(column.as_ref(), row)))
+                .collect::<serde_json::Map<_, _>>();
+            actions.push(
+                serde_json::from_value(JsonValue::Object(action)).map_err(|e| {
+                    ErrorCode::StorageOther(format!("invalid action in '{}': {}", path, e))
+                })?,
+            );
+        }
+    }
+    Ok(actions)
+}
+
+/// Convert the value at `row` of the arrow array to JSON, in the same form as the
+/// actions in the commit files. Values of unused types are converted to null.
+fn arrow_to_json(array: &dyn Array, row: usize) -> JsonValue {
+    if array.is_null(row) {
+        return JsonValue::Null;
+    }
+    match array.data_type() {
+        ArrowDataType::Boolean => JsonValue::from(array.as_boolean().value(row)),
+        ArrowDataType::Int32 => JsonValue::from(array.as_primitive::<Int32Type>().value(row)),
+        ArrowDataType::Int64 => JsonValue::from(array.as_primitive::<Int64Type>().value(row)),
+        ArrowDataType::Utf8 => JsonValue::from(array.as_string::<i32>().value(row)),
+        ArrowDataType::LargeUtf8 => JsonValue::from(array.as_string::<i64>().value(row)),
+        ArrowDataType::Struct(fields) => JsonValue::Object(
+            fields
+                .iter()
+                .zip(array.as_struct().columns())
+                .map(|(field, column)| (field.name().clone(), arrow_to_json(column.as_ref(), row)))
+                .collect(),
+        ),
+        ArrowDataType::List(_) => {
+            let values = array.as_list::<i32>().value(row);
+            JsonValue::Array(
+                (0..values.len())
+                    .map(|i| arrow_to_json(values.as_ref(), i))
+                    .collect(),
+            )
+        }
+        ArrowDataType::Map(_, _) => {
+            let entries = array.as_map().value(row);
+            JsonValue::Object(
+                (0..entries.len())
+                    .map(|i| {
+                        let key = match arrow_to_json(entries.column(0).as_ref(), i) {
+                            JsonValue::String(key) => key,
+                            key => key.to_string(),
+                        };
+                        (key, arrow_to_json(entries.column(1).as_ref(), i))
+                    })
+                    .collect(),
+            )
+        }
+        _ => JsonValue::Null,
+    }
+}
@@ -0,0 +1,178 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use common_catalog::plan::PartInfo;
+use common_catalog::plan::PartInfoPtr;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+use storages_common_pruner::RangePruner;
+use storages_common_pruner::RangePrunerCreator;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+
+use super::deletion_vector::DeletionVectorDescriptor;
+use super::log::table_relative_path;
+use super::log::DeltaSnapshot;
+use super::DeltaTable;
+use crate::parquet_rs::external_table::partition_value_to_scalar;
+
+/// A data file of DELTA table.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct DeltaTablePart {
+    /// Path of the file relative to the location of the table.
+    pub location: String,
+    pub size: u64,
+    /// Values of the partition columns, `None` for null.
+    pub partition_values: Vec<Option<String>>,
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+#[typetag::serde(name = "delta_table_part")]
+impl PartInfo for DeltaTablePart {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        info.as_any()
+            .downcast_ref::<DeltaTablePart>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn hash(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        self.location.hash(&mut s);
+        s.finish()
+    }
+}
+
+impl DeltaTablePart {
+    pub fn from_part(info: &PartInfoPtr) -> Result<&DeltaTablePart> {
+        info.as_any()
+            .downcast_ref::<DeltaTablePart>()
+            .ok_or(ErrorCode::Internal(
+                "Cannot downcast from PartInfo to DeltaTablePart.",
+            ))
+    }
+}
+
+impl DeltaTable {
+    #[async_backtrace::framed]
+    pub(super) async fn do_read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_down: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let snapshot = DeltaSnapshot::load(&self.operator).await?;
+        let partition_columns = &snapshot.metadata.partition_columns;
+        let schema = self.schema();
+        if partition_columns.len() >= schema.num_fields() {
+            return Err(ErrorCode::TableSchemaMismatch(format!(
+                "partition columns {:?} of delta table don't match the table {}",
+                partition_columns, self.table_info.name
+            )));
+        }
+
+        // Prune the files by the values of the partition columns in the log.
+        let filter = push_down
+            .as_ref()
+            .and_then(|p| p.filter.as_ref().map(|f| f.as_expr(&BUILTIN_FUNCTIONS)));
+        let partition_pruner = match &filter {
+            Some(filter) if !partition_columns.is_empty() => Some(RangePrunerCreator::try_create(
+                ctx.get_function_context()?,
+                &schema,
+                Some(filter),
+            )?),
+            _ => None,
+        };
+
+        let mut parts = vec![];
+        let mut read_rows = 0;
+        let mut read_bytes = 0;
+        let num_files = snapshot.files.len();
+        for file in snapshot.files.into_iter() {
+            // An empty string is a null value of any type.
+            let values = partition_columns
+                .iter()
+                .map(|column| {
+                    file.partition_values
+                        .get(column)
+                        .cloned()
+                        .flatten()
+                        .filter(|value| !value.is_empty())
+                })
+                .collect::<Vec<_>>();
+            if let Some(pruner) = &partition_pruner {
+                let stats = self.partition_stats(&values)?;
+                if !pruner.should_keep(&stats, None) {
+                    continue;
+                }
+            }
+
+            let deleted_rows = file.deletion_vector.as_ref().map_or(0, |dv| dv.cardinality);
+            read_rows += (file.num_records().unwrap_or(0) as i64 - deleted_rows).max(0) as usize;
+            read_bytes += file.size as usize;
+            parts.push(Arc::new(Box::new(DeltaTablePart {
+                location: table_relative_path(&self.operator, &file.path)?,
+                size: file.size,
+                partition_values: values,
+                deletion_vector: file.deletion_vector,
+            }) as Box<dyn PartInfo>));
+        }
+
+        Ok((
+            PartStatistics::new_estimated(
+                Some(snapshot.version.to_string()),
+                read_rows,
+                read_bytes,
+                parts.len(),
+                num_files,
+            ),
+            Partitions::create_nolazy(PartitionsShuffleKind::Mod, parts),
+        ))
+    }
+
+    fn partition_stats(&self, values: &[Option<String>]) -> Result<StatisticsOfColumns> {
+        let schema = self.schema();
+        let num_file_fields = schema.num_fields() - values.len();
+        let mut stats = HashMap::with_capacity(values.len());
+        for (field, value) in schema.fields()[num_file_fields..].iter().zip(values) {
+            let scalar = match value {
+                Some(value) => partition_value_to_scalar(value, &field.data_type().into())?,
+                None => Scalar::Null,
+            };
+            let null_count = u64::from(scalar.is_null());
+            stats.insert(
+                field.column_id(),
+                ColumnStatistics::new(scalar.clone(), scalar, null_count, 0, None),
+            );
+        }
+        Ok(stats)
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::ParquetReadOptions;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_pipeline_core::Pipeline;
+
+use super::partition::DeltaTablePart;
+use super::source::DeltaTableSource;
+use super::DeltaTable;
+use crate::parquet_rs::external_table::split_projection;
+use crate::ParquetRSReader;
+
+impl DeltaTable {
+    pub(super) fn do_read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let parts_len = plan.parts.len();
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::min(parts_len, max_threads);
+
+        // Every part has the values of all the partition columns.
+        let num_partition_columns = match plan.parts.partitions.first() {
+            Some(part) => DeltaTablePart::from_part(part)?.partition_values.len(),
+            None => 0,
+        };
+        let table_schema = self.schema();
+        let file_schema = self.file_schema(num_partition_columns);
+        let projection = PushDownInfo::projection_of_push_downs(&table_schema, &plan.push_downs);
+        let (file_projection, output_columns) =
+            split_projection(projection, file_schema.num_fields());
+        let partition_types = table_schema.fields()[file_schema.num_fields()..]
+            .iter()
+            .map(|field| field.data_type().into())
+            .collect::<Vec<DataType>>();
+
+        // The reader only reads the columns stored in the files, the filters are
+        // already used to prune the parts.
+        let mut file_plan = plan.clone();
+        file_plan.push_downs = Some(PushDownInfo {
+            projection: Some(file_projection),
+            ..Default::default()
+        });
+        let arrow_fields = file_schema
+            .to_arrow()
+            .fields
+            .into_iter()
+            .map(|f| f.into())
+            .collect::<Vec<arrow_schema::Field>>();
+        let reader = Arc::new(ParquetRSReader::create(
+            ctx.clone(),
+            self.operator.clone(),
+            file_schema,
+            &arrow_schema::Schema::new(arrow_fields),
+            &file_plan,
+            ParquetReadOptions::default(),
+            false,
+        )?);
+
+        pipeline.add_source(
+            |output| {
+                DeltaTableSource::create(
+                    ctx.clone(),
+                    output,
+                    reader.clone(),
+                    self.operator.clone(),
+                    output_columns.clone(),
+                    partition_types.clone(),
+                )
+            },
+            max_threads.max(1),
+        )
+    }
+}
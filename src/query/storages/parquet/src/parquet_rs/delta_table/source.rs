@@ -0,0 +1,165 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_base::base::Progress;
+use common_base::base::ProgressValues;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::processors::Processor;
+use opendal::Operator;
+use opendal::Reader;
+use parquet::arrow::async_reader::ParquetRecordBatchStream;
+
+use super::partition::DeltaTablePart;
+use crate::parquet_rs::external_table::fill_partition_columns;
+use crate::parquet_rs::external_table::partition_value_to_scalar;
+use crate::parquet_rs::external_table::OutputColumn;
+use crate::ParquetRSReader;
+
+pub struct DeltaTableSource {
+    // Source processor related fields.
+    output: Arc<OutputPort>,
+    scan_progress: Arc<Progress>,
+
+    // Used for event transforming.
+    ctx: Arc<dyn TableContext>,
+    generated_data: Option<DataBlock>,
+    is_finished: bool,
+
+    // Used to read parquet.
+    reader: Arc<ParquetRSReader>,
+    stream: Option<ParquetRecordBatchStream<Reader>>,
+    // Used to read the deletion vectors.
+    op: Operator,
+
+    // Used to fill the partition columns.
+    output_columns: Vec<OutputColumn>,
+    partition_types: Vec<DataType>,
+    partition_values: Vec<Scalar>,
+}
+
+impl DeltaTableSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        reader: Arc<ParquetRSReader>,
+        op: Operator,
+        output_columns: Vec<OutputColumn>,
+        partition_types: Vec<DataType>,
+    ) -> Result<ProcessorPtr> {
+        let scan_progress = ctx.get_scan_progress();
+        Ok(ProcessorPtr::create(Box::new(Self {
+            output,
+            scan_progress,
+            ctx,
+            reader,
+            stream: None,
+            op,
+            generated_data: None,
+            is_finished: false,
+            output_columns,
+            partition_types,
+            partition_values: vec![],
+        })))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for DeltaTableSource {
+    fn name(&self) -> String {
+        "DeltaTableSource".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.is_finished {
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        if self.output.is_finished() {
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            return Ok(Event::NeedConsume);
+        }
+
+        match self.generated_data.take() {
+            None => Ok(Event::Async),
+            Some(data_block) => {
+                let progress_values = ProgressValues {
+                    rows: data_block.num_rows(),
+                    bytes: data_block.memory_size(),
+                };
+                self.scan_progress.incr(&progress_values);
+                self.output.push_data(Ok(data_block));
+                Ok(Event::NeedConsume)
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        if let Some(mut stream) = self.stream.take() {
+            if let Some(block) = self.reader.read_block_from_stream(&mut stream).await? {
+                if !block.is_empty() {
+                    self.generated_data = Some(fill_partition_columns(
+                        block,
+                        &self.output_columns,
+                        &self.partition_types,
+                        &self.partition_values,
+                    ));
+                }
+                self.stream = Some(stream);
+            }
+        } else if let Some(part) = self.ctx.get_partition() {
+            let part = DeltaTablePart::from_part(&part)?;
+            self.partition_values = part
+                .partition_values
+                .iter()
+                .zip(self.partition_types.iter())
+                .map(|(value, data_type)| match value {
+                    Some(value) => partition_value_to_scalar(value, data_type),
+                    None => Ok(Scalar::Null),
+                })
+                .collect::<Result<_>>()?;
+            let deleted_rows = match &part.deletion_vector {
+                Some(deletion_vector) => Some(deletion_vector.read(&self.op).await?),
+                None => None,
+            };
+            let stream = self
+                .reader
+                .prepare_data_stream(&part.location, deleted_rows.as_deref())
+                .await?;
+            self.stream = Some(stream);
+        } else {
+            self.is_finished = true;
+        }
+
+        Ok(())
+    }
+}
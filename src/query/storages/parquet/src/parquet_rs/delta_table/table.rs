@@ -0,0 +1,217 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_catalog::catalog::StorageDescription;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::storage::StorageParams;
+use common_pipeline_core::Pipeline;
+use common_storage::init_operator;
+use common_storage::parquet_rs::infer_schema_with_extension;
+use common_storage::parquet_rs::read_metadata_async;
+use opendal::Operator;
+use serde_json::Value as JsonValue;
+
+use super::log::table_relative_path;
+use super::log::DeltaSnapshot;
+use super::log::Metadata;
+use crate::parquet_rs::parquet_table::arrow_to_table_schema;
+
+pub const DELTA_ENGINE: &str = "DELTA";
+
+/// A read-only table over a Delta Lake table, the data files to read are taken from the
+/// transaction log of the Delta table at the time of the query.
+///
+/// The schema of the table is the schema of the data files followed by the partition columns.
+pub struct DeltaTable {
+    pub(super) table_info: TableInfo,
+    pub(super) operator: Operator,
+}
+
+impl DeltaTable {
+    pub fn try_create(table_info: TableInfo) -> Result<Box<dyn Table>> {
+        let storage_params = table_info.meta.storage_params.as_ref().ok_or_else(|| {
+            ErrorCode::TableInfoError(format!("DELTA table {} has no location", table_info.name))
+        })?;
+        let operator = init_operator(storage_params)?;
+        Ok(Box::new(DeltaTable {
+            operator,
+            table_info,
+        }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: DELTA_ENGINE.to_string(),
+            comment: "DELTA Storage Engine".to_string(),
+            support_cluster_key: false,
+        }
+    }
+
+    /// Build the schema of a new DELTA table from the latest version of the delta table.
+    ///
+    /// The columns of the data files are inferred from the data file added last, the types
+    /// of the partition columns are taken from the schema in the transaction log.
+    #[async_backtrace::framed]
+    pub async fn infer_schema(
+        storage_params: &StorageParams,
+        schema: TableSchemaRef,
+    ) -> Result<TableSchemaRef> {
+        if !schema.fields().is_empty() {
+            return Err(ErrorCode::TableSchemaMismatch(
+                "the columns of DELTA table are inferred from the delta table, and can't be declared",
+            ));
+        }
+        let operator = init_operator(storage_params)?;
+        let snapshot = DeltaSnapshot::load(&operator).await?;
+        let file = snapshot.files.last().ok_or_else(|| {
+            ErrorCode::TableSchemaMismatch(format!(
+                "can't infer the schema of delta table of version {} without data files",
+                snapshot.version
+            ))
+        })?;
+        let path = table_relative_path(&operator, &file.path)?;
+        let meta = read_metadata_async(&path, &operator, Some(file.size))
+            .await
+            .map_err(|e| {
+                ErrorCode::Internal(format!("Read parquet file '{}''s meta error: {}", path, e))
+            })?;
+        let file_schema = arrow_to_table_schema(&infer_schema_with_extension(&meta)?)?;
+
+        let mut fields = file_schema.fields().clone();
+        for field in partition_fields(&snapshot.metadata)? {
+            if file_schema.index_of(field.name()).is_ok() {
+                return Err(ErrorCode::TableSchemaMismatch(format!(
+                    "partition column '{}' is also a column of the data files",
+                    field.name()
+                )));
+            }
+            fields.push(field);
+        }
+        Ok(TableSchemaRefExt::create(fields))
+    }
+
+    /// The part of the table schema stored in the data files.
+    pub(super) fn file_schema(&self, num_partition_columns: usize) -> TableSchemaRef {
+        let fields = self.table_info.schema().fields().clone();
+        let num_file_fields = fields.len() - num_partition_columns;
+        TableSchemaRefExt::create(fields[..num_file_fields].to_vec())
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for DeltaTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn is_local(&self) -> bool {
+        false
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn support_column_projection(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        self.do_read_partitions(ctx, push_downs).await
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        self.do_read_data(ctx, plan, pipeline)
+    }
+}
+
+/// Fields of the partition columns, whose types are taken from the schema of the delta table.
+fn partition_fields(metadata: &Metadata) -> Result<Vec<TableField>> {
+    let schema: JsonValue = serde_json::from_str(&metadata.schema_string)?;
+    let fields = schema
+        .get("fields")
+        .and_then(|fields| fields.as_array())
+        .ok_or_else(|| {
+            ErrorCode::TableSchemaMismatch(format!(
+                "invalid schema of delta table: {}",
+                metadata.schema_string
+            ))
+        })?;
+
+    metadata
+        .partition_columns
+        .iter()
+        .map(|column| {
+            let field_type = fields
+                .iter()
+                .find(|field| field.get("name").and_then(|name| name.as_str()) == Some(column))
+                .and_then(|field| field.get("type"))
+                .ok_or_else(|| {
+                    ErrorCode::TableSchemaMismatch(format!(
+                        "partition column '{}' is not in the schema of delta table",
+                        column
+                    ))
+                })?;
+            let data_type = match field_type.as_str() {
+                Some("string") => TableDataType::String,
+                Some("boolean") => TableDataType::Boolean,
+                Some("byte") => TableDataType::Number(NumberDataType::Int8),
+                Some("short") => TableDataType::Number(NumberDataType::Int16),
+                Some("integer") => TableDataType::Number(NumberDataType::Int32),
+                Some("long") => TableDataType::Number(NumberDataType::Int64),
+                Some("float") => TableDataType::Number(NumberDataType::Float32),
+                Some("double") => TableDataType::Number(NumberDataType::Float64),
+                Some("date") => TableDataType::Date,
+                _ => {
+                    return Err(ErrorCode::Unimplemented(format!(
+                        "partition column '{}' of type {} is not supported",
+                        column, field_type
+                    )));
+                }
+            };
+            // Values of partition columns are null if they are missing in the log.
+            Ok(TableField::new(
+                &column.to_lowercase(),
+                TableDataType::Nullable(Box::new(data_type)),
+            ))
+        })
+        .collect()
+}
@@ -0,0 +1,29 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod partition;
+mod read;
+mod source;
+mod table;
+
+pub(crate) use partition::partition_value_to_scalar;
+pub use partition::ExternalTablePart;
+pub(crate) use read::fill_partition_columns;
+pub(crate) use read::split_projection;
+pub(crate) use read::OutputColumn;
+pub use table::ExternalTable;
+pub use table::EXTERNAL_ENGINE;
+pub use table::EXTERNAL_OPT_KEY_FILE_LIST_CACHE_TTL;
+pub use table::EXTERNAL_OPT_KEY_PARTITION_COLUMNS;
+pub use table::EXTERNAL_OPT_KEY_PATTERN;
@@ -0,0 +1,287 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+use std::time::Instant;
+
+use chrono::NaiveDate;
+use common_catalog::plan::ParquetReadOptions;
+use common_catalog::plan::PartInfo;
+use common_catalog::plan::PartInfoPtr;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::serialize::uniform_date;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::with_number_mapped_type;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_storage::parquet_rs::read_metadata_async;
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use storages_common_pruner::RangePruner;
+use storages_common_pruner::RangePrunerCreator;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+
+use super::ExternalTable;
+use crate::parquet_rs::parquet_table::prune_row_groups;
+use crate::parquet_rs::parquet_table::read_parquet_metas_in_parallel;
+use crate::ParquetRSPruner;
+use crate::ParquetRSRowGroupPart;
+
+const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Listed files of the EXTERNAL tables, by the table id and the version of the table meta.
+static FILE_LIST_CACHE: Lazy<DashMap<(u64, u64), (Instant, Arc<Vec<(String, u64)>>)>> =
+    Lazy::new(DashMap::new);
+
+/// A row group of a file of EXTERNAL table.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct ExternalTablePart {
+    pub row_group: ParquetRSRowGroupPart,
+    /// Values of the partition columns, derived from the path of the file.
+    pub partition_values: Vec<String>,
+}
+
+#[typetag::serde(name = "external_table_part")]
+impl PartInfo for ExternalTablePart {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        info.as_any()
+            .downcast_ref::<ExternalTablePart>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn hash(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        self.row_group.location.hash(&mut s);
+        s.finish()
+    }
+}
+
+impl ExternalTablePart {
+    pub fn from_part(info: &PartInfoPtr) -> Result<&ExternalTablePart> {
+        info.as_any()
+            .downcast_ref::<ExternalTablePart>()
+            .ok_or(ErrorCode::Internal(
+                "Cannot downcast from PartInfo to ExternalTablePart.",
+            ))
+    }
+}
+
+impl ExternalTable {
+    #[async_backtrace::framed]
+    pub(super) async fn do_read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_down: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let func_ctx = ctx.get_function_context()?;
+        let filter = push_down
+            .as_ref()
+            .and_then(|p| p.filter.as_ref().map(|f| f.as_expr(&BUILTIN_FUNCTIONS)));
+
+        // Prune the files by the values of the partition columns in their paths.
+        let partition_pruner = match &filter {
+            Some(filter) if !self.partition_columns.is_empty() => Some(
+                RangePrunerCreator::try_create(func_ctx.clone(), &self.schema(), Some(filter))?,
+            ),
+            _ => None,
+        };
+        let mut file_locations = vec![];
+        let mut files_partition_values = vec![];
+        for (path, size) in self.list_files().await?.iter() {
+            let values = partition_values(path, &self.partition_columns)?;
+            if let Some(pruner) = &partition_pruner {
+                let stats = self.partition_stats(&values)?;
+                if !pruner.should_keep(&stats, None) {
+                    continue;
+                }
+            }
+            file_locations.push((path.clone(), *size));
+            files_partition_values.push(values);
+        }
+        if file_locations.is_empty() {
+            return Ok((PartStatistics::default_exact(), Partitions::default()));
+        }
+
+        // Assume all parquet files have the same schema as the first one.
+        let schema_from = file_locations[0].0.clone();
+        let first_meta =
+            read_metadata_async(&schema_from, &self.operator, Some(file_locations[0].1)).await?;
+        let settings = ctx.get_settings();
+        let parquet_metas = read_parquet_metas_in_parallel(
+            self.operator.clone(),
+            file_locations,
+            first_meta.file_metadata().schema_descr_ptr(),
+            schema_from,
+            settings.get_max_threads()? as usize,
+            64,
+            settings.get_max_memory_usage()?,
+        )
+        .await?;
+
+        // Prune the row groups and pages by the statistics in the footers of the files.
+        let pruner = ParquetRSPruner::try_create(
+            func_ctx,
+            self.file_schema(),
+            &push_down,
+            ParquetReadOptions::default(),
+        )?;
+        let mut parts = vec![];
+        let mut part_stats = PartStatistics::default_exact();
+        for ((location, meta), values) in parquet_metas.into_iter().zip(files_partition_values) {
+            for row_group in prune_row_groups(&pruner, &location, &meta, &mut part_stats)? {
+                parts.push(Arc::new(Box::new(ExternalTablePart {
+                    row_group,
+                    partition_values: values.clone(),
+                }) as Box<dyn PartInfo>));
+            }
+        }
+
+        Ok((
+            part_stats,
+            Partitions::create_nolazy(PartitionsShuffleKind::Mod, parts),
+        ))
+    }
+
+    /// List the files under the location, the result is cached for `file_list_cache_ttl`.
+    #[async_backtrace::framed]
+    async fn list_files(&self) -> Result<Arc<Vec<(String, u64)>>> {
+        let key = (self.table_info.ident.table_id, self.table_info.ident.seq);
+        let use_cache = !self.file_list_cache_ttl.is_zero();
+        if use_cache {
+            if let Some(entry) = FILE_LIST_CACHE.get(&key) {
+                let (listed_on, files) = entry.value();
+                if listed_on.elapsed() < self.file_list_cache_ttl {
+                    return Ok(files.clone());
+                }
+            }
+        }
+
+        let listed_on = Instant::now();
+        let files = if self.operator.info().can_blocking() {
+            self.files_info.blocking_list(&self.operator, false, None)
+        } else {
+            self.files_info.list(&self.operator, false, None).await
+        }?
+        .into_iter()
+        .map(|f| (f.path, f.size))
+        .collect::<Vec<_>>();
+        let files = Arc::new(files);
+        if use_cache {
+            FILE_LIST_CACHE.insert(key, (listed_on, files.clone()));
+        }
+        Ok(files)
+    }
+
+    fn partition_stats(&self, values: &[String]) -> Result<StatisticsOfColumns> {
+        let schema = self.schema();
+        let mut stats = HashMap::with_capacity(values.len());
+        for (column, value) in self.partition_columns.iter().zip(values) {
+            let field = schema.field_with_name(column)?;
+            let scalar = partition_value_to_scalar(value, &field.data_type().into())?;
+            let null_count = u64::from(scalar.is_null());
+            stats.insert(
+                schema.column_id_of(column)?,
+                ColumnStatistics::new(scalar.clone(), scalar, null_count, 0, None),
+            );
+        }
+        Ok(stats)
+    }
+}
+
+/// Values of the partition columns in the hive style `<column>=<value>` directories of `path`.
+pub(super) fn partition_values(path: &str, partition_columns: &[String]) -> Result<Vec<String>> {
+    let directories = path
+        .rsplit('/')
+        .skip(1)
+        .filter_map(|directory| directory.split_once('='))
+        .collect::<Vec<_>>();
+    partition_columns
+        .iter()
+        .map(|column| {
+            directories
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(column))
+                .map(|(_, value)| value.to_string())
+                .ok_or_else(|| {
+                    ErrorCode::TableSchemaMismatch(format!(
+                        "can't find the value of partition column '{}' in the path of file '{}'",
+                        column, path
+                    ))
+                })
+        })
+        .collect()
+}
+
+pub(crate) fn partition_value_to_scalar(value: &str, data_type: &DataType) -> Result<Scalar> {
+    match data_type {
+        DataType::Nullable(ty) => {
+            if value == HIVE_DEFAULT_PARTITION {
+                Ok(Scalar::Null)
+            } else {
+                partition_value_to_scalar(value, ty)
+            }
+        }
+        DataType::String => Ok(Scalar::String(value.as_bytes().to_vec())),
+        DataType::Boolean => match value.to_lowercase().as_str() {
+            "true" => Ok(Scalar::Boolean(true)),
+            "false" => Ok(Scalar::Boolean(false)),
+            _ => Err(ErrorCode::BadBytes(format!(
+                "can't parse partition value '{}' as {}",
+                value, data_type
+            ))),
+        },
+        DataType::Date => NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .map(|date| Scalar::Date(uniform_date(date)))
+            .map_err(|_| {
+                ErrorCode::BadBytes(format!(
+                    "can't parse partition value '{}' as {}",
+                    value, data_type
+                ))
+            }),
+        DataType::Number(num_ty) => with_number_mapped_type!(|NUM_TYPE| match num_ty {
+            NumberDataType::NUM_TYPE => value
+                .parse::<NUM_TYPE>()
+                .map(|v| Scalar::Number(NumberScalar::NUM_TYPE(v)))
+                .map_err(|_| {
+                    ErrorCode::BadBytes(format!(
+                        "can't parse partition value '{}' as {}",
+                        value, data_type
+                    ))
+                }),
+        }),
+        _ => Err(ErrorCode::Unimplemented(format!(
+            "partition column of type {} is not supported",
+            data_type
+        ))),
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::ParquetReadOptions;
+use common_catalog::plan::Projection;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_expression::Value;
+use common_pipeline_core::Pipeline;
+
+use super::source::ExternalTableSource;
+use super::ExternalTable;
+use crate::ParquetRSReader;
+
+/// Where to take a column of the output blocks from.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum OutputColumn {
+    /// The i-th column read from the parquet file.
+    File(usize),
+    /// The value of the i-th partition column.
+    Partition(usize),
+}
+
+impl ExternalTable {
+    pub(super) fn do_read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let parts_len = plan.parts.len();
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::min(parts_len, max_threads);
+
+        let table_schema = self.schema();
+        let projection = PushDownInfo::projection_of_push_downs(&table_schema, &plan.push_downs);
+        let (file_projection, output_columns) =
+            split_projection(projection, self.file_schema().num_fields());
+
+        // The reader only reads the columns stored in the files, the filters are
+        // already used to prune the parts.
+        let mut file_plan = plan.clone();
+        file_plan.push_downs = Some(PushDownInfo {
+            projection: Some(file_projection),
+            ..Default::default()
+        });
+        let file_schema = self.file_schema();
+        let arrow_fields = file_schema
+            .to_arrow()
+            .fields
+            .into_iter()
+            .map(|f| f.into())
+            .collect::<Vec<arrow_schema::Field>>();
+        let reader = Arc::new(ParquetRSReader::create(
+            ctx.clone(),
+            self.operator.clone(),
+            file_schema,
+            &arrow_schema::Schema::new(arrow_fields),
+            &file_plan,
+            ParquetReadOptions::default(),
+            false,
+        )?);
+
+        let partition_types = self
+            .partition_columns
+            .iter()
+            .map(|column| Ok(table_schema.field_with_name(column)?.data_type().into()))
+            .collect::<Result<Vec<DataType>>>()?;
+        pipeline.add_source(
+            |output| {
+                ExternalTableSource::create(
+                    ctx.clone(),
+                    output,
+                    reader.clone(),
+                    output_columns.clone(),
+                    partition_types.clone(),
+                )
+            },
+            max_threads.max(1),
+        )
+    }
+}
+
+/// Split the projection of a table whose columns stored in the files are followed by the
+/// partition columns into the projection of the columns stored in the files, and the layout
+/// of the output columns.
+pub(crate) fn split_projection(
+    projection: Projection,
+    num_file_fields: usize,
+) -> (Projection, Vec<OutputColumn>) {
+    let mut output_columns = vec![];
+    let file_projection = match projection {
+        Projection::Columns(indices) => {
+            let mut file_indices = vec![];
+            for index in indices {
+                if index < num_file_fields {
+                    output_columns.push(OutputColumn::File(file_indices.len()));
+                    file_indices.push(index);
+                } else {
+                    output_columns.push(OutputColumn::Partition(index - num_file_fields));
+                }
+            }
+            Projection::Columns(file_indices)
+        }
+        Projection::InnerColumns(path_indices) => {
+            let mut file_path_indices = BTreeMap::new();
+            for (index, path) in path_indices {
+                if path[0] < num_file_fields {
+                    output_columns.push(OutputColumn::File(file_path_indices.len()));
+                    file_path_indices.insert(index, path);
+                } else {
+                    output_columns.push(OutputColumn::Partition(path[0] - num_file_fields));
+                }
+            }
+            Projection::InnerColumns(file_path_indices)
+        }
+    };
+
+    if file_projection.is_empty() {
+        // Only partition columns are needed, read the first column to get the number of rows.
+        (Projection::Columns(vec![0]), output_columns)
+    } else {
+        (file_projection, output_columns)
+    }
+}
+
+/// Build the output block from the block read from the files and the values of the partition columns.
+pub(crate) fn fill_partition_columns(
+    block: DataBlock,
+    output_columns: &[OutputColumn],
+    partition_types: &[DataType],
+    partition_values: &[Scalar],
+) -> DataBlock {
+    let num_rows = block.num_rows();
+    let columns = output_columns
+        .iter()
+        .map(|column| match column {
+            OutputColumn::File(i) => block.get_by_offset(*i).clone(),
+            OutputColumn::Partition(i) => BlockEntry::new(
+                partition_types[*i].clone(),
+                Value::Scalar(partition_values[*i].clone()),
+            ),
+        })
+        .collect();
+    DataBlock::new(columns, num_rows)
+}
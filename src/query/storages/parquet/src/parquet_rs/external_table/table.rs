@@ -0,0 +1,274 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_catalog::catalog::StorageDescription;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::storage::StorageParams;
+use common_pipeline_core::Pipeline;
+use common_storage::init_operator;
+use common_storage::parquet_rs::infer_schema_with_extension;
+use common_storage::parquet_rs::read_metadata_async;
+use common_storage::StageFilesInfo;
+use opendal::Operator;
+
+use super::partition::partition_values;
+use crate::parquet_rs::parquet_table::arrow_to_table_schema;
+
+pub const EXTERNAL_ENGINE: &str = "EXTERNAL";
+
+/// Comma separated names of the partition columns, whose values are taken from the
+/// hive style `<column>=<value>` directories in the paths of the files.
+pub const EXTERNAL_OPT_KEY_PARTITION_COLUMNS: &str = "partition_columns";
+/// Regex of the paths of the files to read, relative to the location of the table.
+pub const EXTERNAL_OPT_KEY_PATTERN: &str = "pattern";
+/// Seconds to cache the listed files of the table, `0` to list the files for each query.
+pub const EXTERNAL_OPT_KEY_FILE_LIST_CACHE_TTL: &str = "file_list_cache_ttl";
+
+const DEFAULT_PATTERN: &str = r".*\.parquet$";
+const DEFAULT_FILE_LIST_CACHE_TTL_SECS: u64 = 60;
+
+/// A table over the parquet files under a location, the files are read in place.
+///
+/// The schema of the table is the schema of the parquet files followed by the partition columns.
+pub struct ExternalTable {
+    pub(super) table_info: TableInfo,
+    pub(super) operator: Operator,
+    pub(super) files_info: StageFilesInfo,
+    pub(super) partition_columns: Vec<String>,
+    pub(super) file_list_cache_ttl: Duration,
+}
+
+impl ExternalTable {
+    pub fn try_create(table_info: TableInfo) -> Result<Box<dyn Table>> {
+        let storage_params = table_info.meta.storage_params.as_ref().ok_or_else(|| {
+            ErrorCode::TableInfoError(format!(
+                "EXTERNAL table {} has no location",
+                table_info.name
+            ))
+        })?;
+        let operator = init_operator(storage_params)?;
+        let options = &table_info.meta.options;
+        Ok(Box::new(ExternalTable {
+            operator,
+            files_info: files_info(options),
+            partition_columns: partition_columns(options),
+            file_list_cache_ttl: file_list_cache_ttl(options)?,
+            table_info,
+        }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: EXTERNAL_ENGINE.to_string(),
+            comment: "EXTERNAL Storage Engine".to_string(),
+            support_cluster_key: false,
+        }
+    }
+
+    /// Build the schema of a new EXTERNAL table, the columns of the parquet files are inferred
+    /// from the first file found under the location.
+    ///
+    /// If columns are declared in `schema`, they must be the columns of the files followed by the
+    /// partition columns, otherwise the partition columns are strings.
+    #[async_backtrace::framed]
+    pub async fn infer_schema(
+        storage_params: &StorageParams,
+        options: &BTreeMap<String, String>,
+        schema: TableSchemaRef,
+    ) -> Result<TableSchemaRef> {
+        file_list_cache_ttl(options)?;
+        let operator = init_operator(storage_params)?;
+        let first_file = files_info(options).first_file(&operator).await?;
+        let meta = read_metadata_async(&first_file.path, &operator, Some(first_file.size))
+            .await
+            .map_err(|e| {
+                ErrorCode::Internal(format!(
+                    "Read parquet file '{}''s meta error: {}",
+                    first_file.path, e
+                ))
+            })?;
+        let file_schema = arrow_to_table_schema(&infer_schema_with_extension(&meta)?)?;
+
+        let partition_columns = partition_columns(options);
+        for column in partition_columns.iter() {
+            if file_schema.index_of(column).is_ok() {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "partition column '{column}' is also a column of the parquet files"
+                )));
+            }
+        }
+        // Make sure the partition columns can be derived from the paths.
+        partition_values(&first_file.path, &partition_columns)?;
+
+        if schema.fields().is_empty() {
+            let mut fields = file_schema.fields().clone();
+            fields.extend(
+                partition_columns
+                    .iter()
+                    .map(|column| TableField::new(column, TableDataType::String)),
+            );
+            return Ok(TableSchemaRefExt::create(fields));
+        }
+
+        check_declared_schema(&schema, &file_schema, &partition_columns)?;
+        Ok(schema)
+    }
+
+    /// The part of the table schema stored in the parquet files.
+    pub(super) fn file_schema(&self) -> TableSchemaRef {
+        let fields = self.table_info.schema().fields().clone();
+        let num_file_fields = fields.len() - self.partition_columns.len();
+        TableSchemaRefExt::create(fields[..num_file_fields].to_vec())
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for ExternalTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn is_local(&self) -> bool {
+        false
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn support_column_projection(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        self.do_read_partitions(ctx, push_downs).await
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        self.do_read_data(ctx, plan, pipeline)
+    }
+}
+
+fn files_info(options: &BTreeMap<String, String>) -> StageFilesInfo {
+    let pattern = options
+        .get(EXTERNAL_OPT_KEY_PATTERN)
+        .cloned()
+        .unwrap_or_else(|| DEFAULT_PATTERN.to_string());
+    StageFilesInfo {
+        path: "/".to_string(),
+        files: None,
+        pattern: Some(pattern),
+    }
+}
+
+fn file_list_cache_ttl(options: &BTreeMap<String, String>) -> Result<Duration> {
+    let secs = match options.get(EXTERNAL_OPT_KEY_FILE_LIST_CACHE_TTL) {
+        Some(ttl) => ttl.parse::<u64>().map_err(|_| {
+            ErrorCode::TableOptionInvalid(format!(
+                "table option {EXTERNAL_OPT_KEY_FILE_LIST_CACHE_TTL} must be a number of seconds, but got '{ttl}'"
+            ))
+        })?,
+        None => DEFAULT_FILE_LIST_CACHE_TTL_SECS,
+    };
+    Ok(Duration::from_secs(secs))
+}
+
+fn partition_columns(options: &BTreeMap<String, String>) -> Vec<String> {
+    options
+        .get(EXTERNAL_OPT_KEY_PARTITION_COLUMNS)
+        .map(|columns| {
+            columns
+                .split(',')
+                .map(|column| column.trim().to_lowercase())
+                .filter(|column| !column.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn check_declared_schema(
+    schema: &TableSchema,
+    file_schema: &TableSchema,
+    partition_columns: &[String],
+) -> Result<()> {
+    let fields = schema.fields();
+    let file_fields = file_schema.fields();
+    let mismatch = || {
+        ErrorCode::TableSchemaMismatch(format!(
+            "the columns of EXTERNAL table must be the columns of the parquet files {:?} followed by the partition columns {:?}",
+            file_fields
+                .iter()
+                .map(|f| format!("{} {}", f.name(), f.data_type()))
+                .collect::<Vec<_>>(),
+            partition_columns
+        ))
+    };
+
+    if fields.len() != file_fields.len() + partition_columns.len() {
+        return Err(mismatch());
+    }
+    for (field, file_field) in fields.iter().zip(file_fields.iter()) {
+        if field.name() != file_field.name() || field.data_type() != file_field.data_type() {
+            return Err(mismatch());
+        }
+    }
+    for (field, column) in fields[file_fields.len()..]
+        .iter()
+        .zip(partition_columns.iter())
+    {
+        if field.name() != column {
+            return Err(mismatch());
+        }
+        if !matches!(
+            field.data_type().remove_nullable(),
+            TableDataType::String | TableDataType::Number(_)
+        ) {
+            return Err(ErrorCode::TableSchemaMismatch(format!(
+                "partition column '{}' must be a string or number column, but got {}",
+                column,
+                field.data_type()
+            )));
+        }
+    }
+    Ok(())
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod delta_table;
+mod external_table;
 mod parquet_reader;
 mod parquet_table;
 mod partition;
@@ -21,6 +23,15 @@ mod row_group;
 mod source;
 mod statistics;
 
+pub use delta_table::DeltaTable;
+pub use delta_table::DeltaTablePart;
+pub use delta_table::DELTA_ENGINE;
+pub use external_table::ExternalTable;
+pub use external_table::ExternalTablePart;
+pub use external_table::EXTERNAL_ENGINE;
+pub use external_table::EXTERNAL_OPT_KEY_FILE_LIST_CACHE_TTL;
+pub use external_table::EXTERNAL_OPT_KEY_PARTITION_COLUMNS;
+pub use external_table::EXTERNAL_OPT_KEY_PATTERN;
 pub use parquet_reader::ParquetRSReader;
 pub use parquet_table::ParquetRSTable;
 pub use partition::ParquetRSRowGroupPart;
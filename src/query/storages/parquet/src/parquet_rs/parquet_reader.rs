@@ -47,6 +47,7 @@ use parquet::arrow::parquet_to_arrow_schema_by_columns;
 use parquet::arrow::FieldLevels;
 use parquet::arrow::ParquetRecordBatchStreamBuilder;
 use parquet::arrow::ProjectionMask;
+use parquet::file::metadata::ParquetMetaData;
 use parquet::format::PageLocation;
 use parquet::schema::types::SchemaDescriptor;
 
@@ -173,7 +174,14 @@ impl ParquetRSReader {
         })
     }
 
-    pub async fn prepare_data_stream(&self, loc: &str) -> Result<ParquetRecordBatchStream<Reader>> {
+    /// Prepare a stream to read the parquet file at `loc`.
+    ///
+    /// `deleted_rows` are the sorted positions of the rows in the file which should not be read.
+    pub async fn prepare_data_stream(
+        &self,
+        loc: &str,
+        deleted_rows: Option<&[u64]>,
+    ) -> Result<ParquetRecordBatchStream<Reader>> {
         let reader: Reader = self.op.reader(loc).await?;
         let mut builder = ParquetRecordBatchStreamBuilder::new_with_options(
             reader,
@@ -188,14 +196,26 @@ impl ParquetRSReader {
         // Prune row groups.
         let file_meta = builder.metadata();
 
-        if let Some(pruner) = &self.pruner {
-            let selected_row_groups = pruner.prune_row_groups(file_meta)?;
-            let row_selection = pruner.prune_pages(file_meta, &selected_row_groups)?;
-
-            builder = builder.with_row_groups(selected_row_groups);
-            if let Some(row_selection) = row_selection {
-                builder = builder.with_row_selection(row_selection);
+        let (selected_row_groups, mut row_selection) = match &self.pruner {
+            Some(pruner) => {
+                let selected_row_groups = pruner.prune_row_groups(file_meta)?;
+                let row_selection = pruner.prune_pages(file_meta, &selected_row_groups)?;
+                (selected_row_groups, row_selection)
             }
+            None => ((0..file_meta.num_row_groups()).collect(), None),
+        };
+        if let Some(deleted_rows) = deleted_rows.filter(|rows| !rows.is_empty()) {
+            let selection =
+                deleted_rows_to_selection(file_meta, &selected_row_groups, deleted_rows);
+            row_selection = Some(match row_selection {
+                Some(row_selection) => row_selection.intersection(&selection),
+                None => selection,
+            });
+        }
+
+        builder = builder.with_row_groups(selected_row_groups);
+        if let Some(row_selection) = row_selection {
+            builder = builder.with_row_selection(row_selection);
         }
 
         if let Some(predicate) = self.predicate.as_ref() {
@@ -333,6 +353,43 @@ impl ParquetRSReader {
     }
 }
 
+/// Build the [`RowSelection`] of `row_groups` which skips the rows at `deleted_rows`.
+fn deleted_rows_to_selection(
+    meta: &ParquetMetaData,
+    row_groups: &[usize],
+    deleted_rows: &[u64],
+) -> RowSelection {
+    let mut row_group_offsets = Vec::with_capacity(meta.num_row_groups());
+    let mut offset = 0;
+    for rg in meta.row_groups() {
+        row_group_offsets.push(offset);
+        offset += rg.num_rows() as u64;
+    }
+
+    let mut selectors = vec![];
+    for rg in row_groups {
+        let start = row_group_offsets[*rg];
+        let end = start + meta.row_group(*rg).num_rows() as u64;
+        let mut cursor = start;
+        let first = deleted_rows.partition_point(|pos| *pos < start);
+        for pos in deleted_rows[first..].iter().take_while(|pos| **pos < end) {
+            if *pos < cursor {
+                // Duplicated positions.
+                continue;
+            }
+            if *pos > cursor {
+                selectors.push(RowSelector::select((*pos - cursor) as usize));
+            }
+            selectors.push(RowSelector::skip(1));
+            cursor = *pos + 1;
+        }
+        if end > cursor {
+            selectors.push(RowSelector::select((end - cursor) as usize));
+        }
+    }
+    RowSelection::from(selectors)
+}
+
 fn to_arrow_schema(schema: &TableSchema) -> arrow_schema::Schema {
     let fields = schema
         .fields()
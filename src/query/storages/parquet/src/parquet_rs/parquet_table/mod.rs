@@ -16,4 +16,7 @@ mod partition;
 mod read;
 mod table;
 
+pub(crate) use partition::prune_row_groups;
+pub(crate) use partition::read_parquet_metas_in_parallel;
+pub(crate) use table::arrow_to_table_schema;
 pub use table::ParquetRSTable;
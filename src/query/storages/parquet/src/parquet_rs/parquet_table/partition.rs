@@ -97,45 +97,9 @@ fn prune_and_generate_partitions(
     let mut parts = vec![];
     let mut part_stats = PartStatistics::default_exact();
     for (location, meta) in parquet_metas {
-        part_stats.partitions_total += meta.num_row_groups();
-        let rgs = pruner.prune_row_groups(&meta)?;
-        let mut row_selections = pruner.prune_pages(&meta, &rgs)?;
-        for rg in rgs {
-            let rg_meta = meta.row_group(rg);
-            let num_rows = rg_meta.num_rows() as usize;
-            // Split rows belonging to current row group.
-            let selection = row_selections.as_mut().map(|s| s.split_off(num_rows));
-            if !selection.as_ref().map(|x| x.selects_any()).unwrap_or(true) {
-                // All rows in current row group are filtered out.
-                continue;
-            }
-
-            let serde_selection = selection.map(|s| {
-                let selectors: Vec<RowSelector> = s.into();
-                selectors
-                    .iter()
-                    .map(SerdeRowSelector::from)
-                    .collect::<Vec<_>>()
-            });
-
-            part_stats.read_rows += num_rows;
-            part_stats.read_bytes += rg_meta.total_byte_size() as usize;
-            part_stats.partitions_scanned += 1;
-
-            let page_locations = meta.offset_index().map(|x| {
-                x[rg]
-                    .iter()
-                    .map(|x| x.iter().map(SerdePageLocation::from).collect())
-                    .collect()
-            });
-
+        for part in prune_row_groups(&pruner, &location, &meta, &mut part_stats)? {
             parts.push(Arc::new(
-                Box::new(ParquetPart::ParquetRSRowGroup(ParquetRSRowGroupPart {
-                    location: location.clone(),
-                    selectors: serde_selection,
-                    meta: rg_meta.clone(),
-                    page_locations,
-                })) as Box<dyn PartInfo>,
+                Box::new(ParquetPart::ParquetRSRowGroup(part)) as Box<dyn PartInfo>
             ))
         }
     }
@@ -146,6 +110,57 @@ fn prune_and_generate_partitions(
     ))
 }
 
+/// Prune the row groups and pages of a parquet file,
+/// and generate a part for each row group to read.
+pub(crate) fn prune_row_groups(
+    pruner: &ParquetRSPruner,
+    location: &str,
+    meta: &ParquetMetaData,
+    part_stats: &mut PartStatistics,
+) -> Result<Vec<ParquetRSRowGroupPart>> {
+    let mut parts = vec![];
+    part_stats.partitions_total += meta.num_row_groups();
+    let rgs = pruner.prune_row_groups(meta)?;
+    let mut row_selections = pruner.prune_pages(meta, &rgs)?;
+    for rg in rgs {
+        let rg_meta = meta.row_group(rg);
+        let num_rows = rg_meta.num_rows() as usize;
+        // Split rows belonging to current row group.
+        let selection = row_selections.as_mut().map(|s| s.split_off(num_rows));
+        if !selection.as_ref().map(|x| x.selects_any()).unwrap_or(true) {
+            // All rows in current row group are filtered out.
+            continue;
+        }
+
+        let serde_selection = selection.map(|s| {
+            let selectors: Vec<RowSelector> = s.into();
+            selectors
+                .iter()
+                .map(SerdeRowSelector::from)
+                .collect::<Vec<_>>()
+        });
+
+        part_stats.read_rows += num_rows;
+        part_stats.read_bytes += rg_meta.total_byte_size() as usize;
+        part_stats.partitions_scanned += 1;
+
+        let page_locations = meta.offset_index().map(|x| {
+            x[rg]
+                .iter()
+                .map(|x| x.iter().map(SerdePageLocation::from).collect())
+                .collect()
+        });
+
+        parts.push(ParquetRSRowGroupPart {
+            location: location.to_string(),
+            selectors: serde_selection,
+            meta: rg_meta.clone(),
+            page_locations,
+        })
+    }
+    Ok(parts)
+}
+
 fn check_parquet_schema(
     expect: &SchemaDescriptor,
     actual: &SchemaDescriptor,
@@ -218,7 +218,7 @@ fn lower_field_name(field: &ArrowField) -> ArrowField {
     }
 }
 
-fn arrow_to_table_schema(schema: &ArrowSchema) -> Result<TableSchema> {
+pub(crate) fn arrow_to_table_schema(schema: &ArrowSchema) -> Result<TableSchema> {
     let fields = schema
         .fields
         .iter()
@@ -65,11 +65,11 @@ impl ParquetRSPruner {
                     .unwrap()
                     .column_refs()
                     .into_keys()
-                    .map(|name| {
+                    // Columns not stored in the files (e.g. partition columns) have no statistics.
+                    .filter_map(|name| {
                         fields
                             .iter()
                             .position(|f| f.name.eq_ignore_ascii_case(&name))
-                            .unwrap()
                     })
                     .collect::<Vec<_>>();
                 predicate_columns.sort();
@@ -114,6 +114,11 @@ impl Processor for ParquetSource {
         } else if let Some(part) = self.ctx.get_partition() {
             match ParquetPart::from_part(&part)? {
                 ParquetPart::ParquetRSRowGroup(part) => {
+                    *self
+                        .ctx
+                        .get_rows_loaded_per_file()
+                        .entry(part.location.clone())
+                        .or_insert(0) += part.meta.num_rows() as u64;
                     let reader = self.reader.prepare_row_group_reader(part).await?;
                     self.batch_reader = reader;
                 }
@@ -12,14 +12,33 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::hash::Hash;
+use std::hash::Hasher;
+
 use sha2::Digest;
 use sha2::Sha256;
 
 const RESULT_CACHE_PREFIX: &str = "_result_cache";
 
-#[inline(always)]
-pub fn gen_result_cache_key(raw: &str) -> String {
-    format!("{:x}", Sha256::digest(raw))
+/// Generate the key from the hash of a value, e.g. the plan of the query.
+pub fn gen_result_cache_key_of<T: Hash>(value: &T) -> String {
+    let mut hasher = Sha256Hasher(Sha256::new());
+    value.hash(&mut hasher);
+    format!("{:x}", hasher.0.finalize())
+}
+
+/// Feed the hashed bytes into sha256, to make the key stable and collision free.
+struct Sha256Hasher(Sha256);
+
+impl Hasher for Sha256Hasher {
+    fn finish(&self) -> u64 {
+        let digest = self.0.clone().finalize();
+        u64::from_le_bytes(digest[..8].try_into().unwrap())
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
 }
 
 #[inline(always)]
@@ -51,8 +70,9 @@ pub struct ResultCacheValue {
     pub result_size: usize,
     /// The number of rows in the result cache.
     pub num_rows: usize,
-    /// The sha256 of the partitions for each table in the query.
-    pub partitions_shas: Vec<String>,
+    /// The snapshots of the tables in the query, the cache is valid only if they are unchanged.
+    #[serde(default)]
+    pub table_snapshots: Vec<String>,
     /// The location of the result cache file.
     pub location: String,
 }
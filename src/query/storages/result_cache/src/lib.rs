@@ -22,7 +22,7 @@ mod read;
 mod table_function;
 mod write;
 
-pub use common::gen_result_cache_key;
+pub use common::gen_result_cache_key_of;
 pub use common::gen_result_cache_meta_key;
 pub use common::gen_result_cache_prefix;
 pub use meta_manager::ResultCacheMetaManager;
@@ -17,8 +17,8 @@ use std::sync::Arc;
 use common_exception::Result;
 use common_meta_kvapi::kvapi::KVApi;
 use common_meta_store::MetaStore;
-use common_meta_types::KVMeta;
 use common_meta_types::MatchSeq;
+use common_meta_types::MetaSpec;
 use common_meta_types::Operation;
 use common_meta_types::SeqV;
 use common_meta_types::UpsertKV;
@@ -50,9 +50,7 @@ impl ResultCacheMetaManager {
                 key,
                 seq,
                 value: Operation::Update(value),
-                value_meta: Some(KVMeta {
-                    expire_at: Some(expire_at),
-                }),
+                value_meta: Some(MetaSpec::new_expire(expire_at)),
             })
             .await?;
         Ok(())
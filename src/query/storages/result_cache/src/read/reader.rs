@@ -37,10 +37,10 @@ pub struct ResultCacheReader {
 
     operator: Operator,
     /// To ensure the cache is valid.
-    partitions_shas: Vec<String>,
+    table_snapshots: Vec<String>,
 
     /// If true, the cache will be used even if it is inconsistent.
-    /// In another word, `partitions_sha` will not be checked.
+    /// In another word, `table_snapshots` will not be checked.
     tolerate_inconsistent: bool,
 }
 
@@ -48,17 +48,17 @@ impl ResultCacheReader {
     pub fn create(
         ctx: Arc<dyn TableContext>,
         key: &str,
+        table_snapshots: Vec<String>,
         kv_store: Arc<MetaStore>,
         tolerate_inconsistent: bool,
     ) -> Self {
         let tenant = ctx.get_tenant();
         let meta_key = gen_result_cache_meta_key(&tenant, key);
-        let partitions_shas = ctx.get_partitions_shas();
 
         Self {
             meta_mgr: ResultCacheMetaManager::create(kv_store, 0),
             meta_key,
-            partitions_shas,
+            table_snapshots,
             operator: DataOperator::instance().operator(),
             tolerate_inconsistent,
         }
@@ -71,7 +71,7 @@ impl ResultCacheReader {
     #[async_backtrace::framed]
     pub async fn check_cache(&self) -> Result<Option<ResultCacheValue>> {
         if let Some(v) = self.meta_mgr.get(self.meta_key.clone()).await? {
-            if self.tolerate_inconsistent || v.partitions_shas == self.partitions_shas {
+            if self.tolerate_inconsistent || v.table_snapshots == self.table_snapshots {
                 return Ok(Some(v));
             }
         }
@@ -91,7 +91,7 @@ impl ResultCacheReader {
     ) -> Result<Option<Vec<DataBlock>>> {
         match self.meta_mgr.get(meta_key).await? {
             Some(value) => {
-                if self.tolerate_inconsistent || value.partitions_shas == self.partitions_shas {
+                if self.tolerate_inconsistent || value.table_snapshots == self.table_snapshots {
                     if value.num_rows == 0 {
                         Ok(Some(vec![DataBlock::empty()]))
                     } else {
@@ -36,7 +36,7 @@ use crate::meta_manager::ResultCacheMetaManager;
 pub struct WriteResultCacheSink {
     ctx: Arc<dyn TableContext>,
     sql: String,
-    partitions_shas: Vec<String>,
+    table_snapshots: Vec<String>,
 
     meta_mgr: ResultCacheMetaManager,
     meta_key: String,
@@ -78,7 +78,7 @@ impl AsyncMpscSink for WriteResultCacheSink {
             query_id: self.ctx.get_id(),
             query_time: now,
             ttl,
-            partitions_shas: self.partitions_shas.clone(),
+            table_snapshots: self.table_snapshots.clone(),
             result_size: self.cache_writer.current_bytes(),
             num_rows: self.cache_writer.num_rows(),
             location,
@@ -96,6 +96,7 @@ impl WriteResultCacheSink {
     pub fn try_create(
         ctx: Arc<dyn TableContext>,
         key: &str,
+        table_snapshots: Vec<String>,
         schema: TableSchemaRef,
         inputs: Vec<Arc<InputPort>>,
         kv_store: Arc<MetaStore>,
@@ -105,7 +106,6 @@ impl WriteResultCacheSink {
         let ttl = settings.get_query_result_cache_ttl_secs()?;
         let tenant = ctx.get_tenant();
         let sql = ctx.get_query_str();
-        let partitions_shas = ctx.get_partitions_shas();
 
         let meta_key = gen_result_cache_meta_key(&tenant, key);
         let location = gen_result_cache_dir(key);
@@ -118,7 +118,7 @@ impl WriteResultCacheSink {
             WriteResultCacheSink {
                 ctx,
                 sql,
-                partitions_shas,
+                table_snapshots,
                 meta_mgr: ResultCacheMetaManager::create(kv_store, ttl),
                 meta_key,
                 cache_writer,
@@ -17,5 +17,8 @@
 mod parquet_file;
 mod row_based_file;
 mod stage_table;
+mod unload_manifest;
 
 pub use stage_table::StageTable;
+pub use unload_manifest::UnloadManifest;
+pub use unload_manifest::UnloadedFile;
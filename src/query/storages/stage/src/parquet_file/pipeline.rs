@@ -23,6 +23,7 @@ use opendal::Operator;
 
 use crate::parquet_file::limit_file_size_processor::LimitFileSizeProcessor;
 use crate::parquet_file::sink_processor::ParquetFileSink;
+use crate::unload_manifest::UnloadManifest;
 
 // LimitFileSizeProcessor * 1:  slice/group block to batches (as a block meta) that are suitable as a file.
 // ParquetFileSink * N: simply serialize blocks in each meta to a whole file and write out.
@@ -36,6 +37,7 @@ pub(crate) fn append_data_to_parquet_files(
     max_threads: usize,
     uuid: String,
     group_id: &std::sync::atomic::AtomicUsize,
+    manifest: Option<UnloadManifest>,
 ) -> Result<()> {
     pipeline.try_resize(1)?;
     pipeline.add_transform(|input, output| {
@@ -57,6 +59,7 @@ pub(crate) fn append_data_to_parquet_files(
             table_info.clone(),
             output_format,
             op.clone(),
+            manifest.clone(),
             uuid.clone(),
             gid,
         )
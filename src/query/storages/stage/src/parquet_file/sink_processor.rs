@@ -30,6 +30,7 @@ use opendal::Operator;
 
 use crate::parquet_file::block_batch::BlockBatch;
 use crate::stage_table::unload_path;
+use crate::unload_manifest::UnloadManifest;
 
 pub struct ParquetFileSink {
     input: Arc<InputPort>,
@@ -38,8 +39,10 @@ pub struct ParquetFileSink {
 
     input_data: Option<DataBlock>,
     output_data: Vec<u8>,
+    output_rows: usize,
 
     data_accessor: Operator,
+    manifest: Option<UnloadManifest>,
 
     uuid: String,
     group_id: usize,
@@ -53,6 +56,7 @@ impl ParquetFileSink {
         table_info: StageTableInfo,
         output_format: Box<dyn OutputFormat>,
         data_accessor: Operator,
+        manifest: Option<UnloadManifest>,
         uuid: String,
         group_id: usize,
     ) -> Result<ProcessorPtr> {
@@ -62,7 +66,9 @@ impl ParquetFileSink {
             output_format,
             input_data: None,
             output_data: vec![],
+            output_rows: 0,
             data_accessor,
+            manifest,
             uuid,
             group_id,
             batch_id: 0,
@@ -104,19 +110,32 @@ impl Processor for ParquetFileSink {
         let block = self.input_data.take().unwrap();
         let block_meta = block.get_owned_meta().unwrap();
         let blocks = BlockBatch::downcast_from(block_meta).unwrap();
+        let mut rows = 0;
         for b in blocks.blocks {
+            rows += b.num_rows();
             self.output_format.serialize_block(&b)?;
         }
         self.output_data = self.output_format.finalize()?;
+        self.output_rows = rows;
         Ok(())
     }
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
         assert!(!self.output_data.is_empty());
-        let path = unload_path(&self.table_info, &self.uuid, self.group_id, self.batch_id);
+        let path = unload_path(
+            &self.table_info,
+            &self.uuid,
+            self.group_id,
+            self.batch_id,
+            None,
+        );
         let data = mem::take(&mut self.output_data);
+        let size = data.len();
         self.data_accessor.write(&path, data).await?;
+        if let Some(manifest) = &self.manifest {
+            manifest.add(path, size, self.output_rows);
+        }
         self.batch_id += 1;
         Ok(())
     }
@@ -25,6 +25,7 @@ use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
 
+use crate::row_based_file::buffers::FileOutputBuffer;
 use crate::row_based_file::buffers::FileOutputBuffers;
 
 pub(super) struct LimitFileSizeProcessor {
@@ -34,7 +35,7 @@ pub(super) struct LimitFileSizeProcessor {
 
     input_data: Option<DataBlock>,
     output_data: Option<DataBlock>,
-    buffers: Vec<Vec<u8>>,
+    buffers: Vec<FileOutputBuffer>,
 }
 
 impl LimitFileSizeProcessor {
@@ -119,7 +120,7 @@ impl Processor for LimitFileSizeProcessor {
             .iter()
             .enumerate()
             .find_map(|(idx, b)| {
-                size += b.len();
+                size += b.buffer.len();
                 if size >= self.threshold {
                     Some(idx)
                 } else {
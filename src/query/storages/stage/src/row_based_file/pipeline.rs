@@ -19,11 +19,13 @@ use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_formats::FileFormatOptionsExt;
 use common_pipeline_core::Pipeline;
+use common_pipeline_sources::input_formats::InputContext;
 use opendal::Operator;
 
 use crate::row_based_file::limit_file_size_processor::LimitFileSizeProcessor;
 use crate::row_based_file::serialize_processor::SerializeProcessor;
 use crate::row_based_file::sink_processor::RowBasedFileSink;
+use crate::unload_manifest::UnloadManifest;
 
 // SerializeProcessor * N: serialize each data block to many small byte buffers.
 // LimitFileSizeProcessor * 1:  group small byte buffers to batches (as a block meta) that are large enough as a file.
@@ -38,6 +40,7 @@ pub(crate) fn append_data_to_row_based_files(
     max_threads: usize,
     uuid: String,
     group_id: &std::sync::atomic::AtomicUsize,
+    manifest: Option<UnloadManifest>,
 ) -> Result<()> {
     let mut options_ext = FileFormatOptionsExt::create_from_settings(&ctx.get_settings(), false)?;
     let output_format = options_ext.get_output_format(
@@ -45,6 +48,10 @@ pub(crate) fn append_data_to_row_based_files(
         table_info.stage_info.file_format_params.clone(),
     )?;
     let prefix = output_format.serialize_prefix()?;
+    let compression = InputContext::get_compression_alg_copy(
+        table_info.stage_info.file_format_params.compression(),
+        "",
+    )?;
 
     pipeline.add_transform(|input, output| {
         let mut options_ext =
@@ -69,6 +76,8 @@ pub(crate) fn append_data_to_row_based_files(
             table_info.clone(),
             op.clone(),
             prefix.clone(),
+            compression,
+            manifest.clone(),
             uuid.clone(),
             gid,
         )
@@ -25,6 +25,7 @@ use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_transforms::processors::transforms::Transform;
 use common_pipeline_transforms::processors::transforms::Transformer;
 
+use crate::row_based_file::buffers::FileOutputBuffer;
 use crate::row_based_file::buffers::FileOutputBuffers;
 
 pub(super) struct SerializeProcessor {
@@ -60,7 +61,10 @@ impl Transform for SerializeProcessor {
             let small_block = block.slice(i..end);
             let bs = self.output_format.serialize_block(&small_block)?;
             bytes += bs.len();
-            buffers.push(bs);
+            buffers.push(FileOutputBuffer {
+                buffer: bs,
+                num_rows: end - i,
+            });
         }
         let progress_values = ProgressValues {
             rows: num_rows,
@@ -18,6 +18,8 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use common_catalog::plan::StageTableInfo;
+use common_compress::compress;
+use common_compress::CompressAlgorithm;
 use common_exception::Result;
 use common_expression::BlockMetaInfoDowncast;
 use common_expression::DataBlock;
@@ -29,6 +31,7 @@ use opendal::Operator;
 
 use crate::row_based_file::buffers::FileOutputBuffers;
 use crate::stage_table::unload_path;
+use crate::unload_manifest::UnloadManifest;
 
 pub struct RowBasedFileSink {
     input: Arc<InputPort>,
@@ -38,9 +41,12 @@ pub struct RowBasedFileSink {
     input_data: Option<DataBlock>,
     // always the data for a whole file if not empty
     output_data: Vec<u8>,
+    output_rows: usize,
 
     data_accessor: Operator,
     prefix: Vec<u8>,
+    compression: Option<CompressAlgorithm>,
+    manifest: Option<UnloadManifest>,
 
     uuid: String,
     group_id: usize,
@@ -54,6 +60,8 @@ impl RowBasedFileSink {
         table_info: StageTableInfo,
         data_accessor: Operator,
         prefix: Vec<u8>,
+        compression: Option<CompressAlgorithm>,
+        manifest: Option<UnloadManifest>,
         uuid: String,
         group_id: usize,
     ) -> Result<ProcessorPtr> {
@@ -63,10 +71,13 @@ impl RowBasedFileSink {
             input_data: None,
             data_accessor,
             prefix,
+            compression,
+            manifest,
             uuid,
             group_id,
             batch_id: 0,
             output_data: vec![],
+            output_rows: 0,
         })))
     }
 }
@@ -105,21 +116,41 @@ impl Processor for RowBasedFileSink {
         let block = self.input_data.take().unwrap();
         let block_meta = block.get_owned_meta().unwrap();
         let buffers = FileOutputBuffers::downcast_from(block_meta).unwrap();
-        let size = buffers.buffers.iter().map(|b| b.len()).sum::<usize>();
+        let size = buffers
+            .buffers
+            .iter()
+            .map(|b| b.buffer.len())
+            .sum::<usize>();
         let mut output = Vec::with_capacity(self.prefix.len() + size);
         output.extend_from_slice(self.prefix.as_slice());
+        let mut rows = 0;
         for b in buffers.buffers {
-            output.extend_from_slice(b.as_slice());
+            output.extend_from_slice(b.buffer.as_slice());
+            rows += b.num_rows;
         }
         self.output_data = output;
+        self.output_rows = rows;
         Ok(())
     }
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
-        let path = unload_path(&self.table_info, &self.uuid, self.group_id, self.batch_id);
-        let data = mem::take(&mut self.output_data);
+        let path = unload_path(
+            &self.table_info,
+            &self.uuid,
+            self.group_id,
+            self.batch_id,
+            self.compression,
+        );
+        let mut data = mem::take(&mut self.output_data);
+        if let Some(algo) = self.compression {
+            data = compress(algo, &data).await?;
+        }
+        let size = data.len();
         self.data_accessor.write(&path, data).await?;
+        if let Some(manifest) = &self.manifest {
+            manifest.add(path, size, self.output_rows);
+        }
         self.batch_id += 1;
         Ok(())
     }
@@ -18,6 +18,7 @@ use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
 
 use common_base::base::uuid;
+use common_base::runtime::GlobalIORuntime;
 use common_catalog::plan::DataSourceInfo;
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfo;
@@ -30,6 +31,7 @@ use common_catalog::plan::StageTableInfo;
 use common_catalog::table::AppendMode;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
+use common_compress::CompressAlgorithm;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::BlockThresholds;
@@ -49,6 +51,7 @@ use parking_lot::Mutex;
 
 use crate::parquet_file::append_data_to_parquet_files;
 use crate::row_based_file::append_data_to_row_based_files;
+use crate::unload_manifest::UnloadManifest;
 /// TODO: we need to track the data metrics in stage table.
 pub struct StageTable {
     table_info: StageTableInfo,
@@ -198,6 +201,7 @@ impl Table for StageTable {
             ctx.get_scan_progress(),
             compact_threshold,
             on_error_map,
+            ctx.get_rows_loaded_per_file(),
             self.table_info.is_select,
             projection,
         )?);
@@ -233,6 +237,20 @@ impl Table for StageTable {
         let fmt = self.table_info.stage_info.file_format_params.clone();
         let uuid = uuid::Uuid::new_v4().to_string();
         let group_id = AtomicUsize::new(0);
+        let manifest = if self.table_info.manifest {
+            let manifest = UnloadManifest::default();
+            let op = op.clone();
+            let path = unload_manifest_path(&self.table_info, &uuid);
+            let on_finished_manifest = manifest.clone();
+            pipeline.set_on_finished(move |may_error| match may_error {
+                None => GlobalIORuntime::instance()
+                    .block_on(async move { on_finished_manifest.write(&op, &path).await }),
+                Some(_) => Ok(()),
+            });
+            Some(manifest)
+        } else {
+            None
+        };
         match fmt {
             FileFormatParams::Parquet(_) => append_data_to_parquet_files(
                 pipeline,
@@ -243,6 +261,7 @@ impl Table for StageTable {
                 max_threads,
                 uuid,
                 &group_id,
+                manifest,
             )?,
             _ => append_data_to_row_based_files(
                 pipeline,
@@ -253,6 +272,7 @@ impl Table for StageTable {
                 max_threads,
                 uuid,
                 &group_id,
+                manifest,
             )?,
         };
         Ok(())
@@ -282,12 +302,16 @@ pub fn unload_path(
     uuid: &str,
     group_id: usize,
     batch_id: usize,
+    compression: Option<CompressAlgorithm>,
 ) -> String {
-    let format_name = format!(
+    let mut format_name = format!(
         "{:?}",
         stage_table_info.stage_info.file_format_params.get_type()
     )
     .to_ascii_lowercase();
+    if let Some(compression) = compression {
+        format_name = format!("{}.{}", format_name, compression.extension());
+    }
 
     let path = &stage_table_info.files_info.path;
 
@@ -303,3 +327,13 @@ pub fn unload_path(
         )
     }
 }
+
+pub fn unload_manifest_path(stage_table_info: &StageTableInfo, uuid: &str) -> String {
+    let path = &stage_table_info.files_info.path;
+
+    if path.ends_with("data_") {
+        format!("{}{}_manifest.json", path, uuid)
+    } else {
+        format!("{}/manifest_{}.json", path, uuid)
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use opendal::Operator;
+use parking_lot::Mutex;
+use serde::Serialize;
+
+/// A file written by `COPY INTO <location>`.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct UnloadedFile {
+    pub path: String,
+    pub size: usize,
+    pub rows: usize,
+}
+
+#[derive(Serialize)]
+struct ManifestContent<'a> {
+    entries: &'a [UnloadedFile],
+}
+
+/// Collects the files written by the unload sinks, and writes them out
+/// as a json manifest once the pipeline is finished.
+#[derive(Clone, Default)]
+pub struct UnloadManifest {
+    files: Arc<Mutex<Vec<UnloadedFile>>>,
+}
+
+impl UnloadManifest {
+    pub fn add(&self, path: String, size: usize, rows: usize) {
+        self.files.lock().push(UnloadedFile { path, size, rows });
+    }
+
+    pub fn files(&self) -> Vec<UnloadedFile> {
+        let mut files = self.files.lock().clone();
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        files
+    }
+
+    #[async_backtrace::framed]
+    pub async fn write(&self, op: &Operator, path: &str) -> Result<()> {
+        let files = self.files();
+        let content = serde_json::to_vec(&ManifestContent { entries: &files })?;
+        op.write(path, content).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+
+use common_catalog::catalog::StorageDescription;
+use common_catalog::table::Table;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+
+pub const STREAM_ENGINE: &str = "STREAM";
+/// The database of the table the stream is created on.
+pub const OPT_KEY_TABLE_DATABASE: &str = "table_database";
+/// The name of the table the stream is created on.
+pub const OPT_KEY_TABLE_NAME: &str = "table_name";
+/// The id of the table the stream is created on, to tell it from a table recreated with the
+/// same name.
+pub const OPT_KEY_TABLE_ID: &str = "table_id";
+/// The id of the snapshot of the source table the stream has been consumed up to,
+/// empty if the source table had no snapshot when the stream was created.
+pub const OPT_KEY_OFFSET: &str = "offset";
+
+/// The extra column of a stream telling whether a row was inserted or deleted.
+pub const CHANGE_ACTION_COL_NAME: &str = "change$action";
+
+/// A stream records the changes made to a FUSE table since its offset.
+///
+/// Reading a stream compares the blocks of the current snapshot of the source table with the
+/// blocks of the snapshot at the offset: rows of the blocks only in the current snapshot are
+/// `INSERT` changes, rows of the blocks only in the offset snapshot are `DELETE` changes.
+/// A row both deleted and inserted, e.g. by a compaction or a recluster which rewrites the
+/// blocks without changing the rows, is not a change.
+/// The offset is moved to the current snapshot when the stream is consumed by an `INSERT`.
+pub struct StreamTable {
+    table_info: TableInfo,
+    pub table_database: String,
+    pub table_name: String,
+    pub table_id: u64,
+    pub offset: String,
+}
+
+impl StreamTable {
+    pub fn try_create(table_info: TableInfo) -> Result<Box<dyn Table>> {
+        Ok(Box::new(Self::try_new(table_info)?))
+    }
+
+    pub fn try_new(table_info: TableInfo) -> Result<StreamTable> {
+        let options = table_info.options();
+        let get_option = |key: &str| {
+            options.get(key).cloned().ok_or_else(|| {
+                ErrorCode::Internal(format!("Need `{key}` when creating StreamTable"))
+            })
+        };
+        Ok(StreamTable {
+            table_database: get_option(OPT_KEY_TABLE_DATABASE)?,
+            table_name: get_option(OPT_KEY_TABLE_NAME)?,
+            table_id: get_option(OPT_KEY_TABLE_ID)?.parse().map_err(|e| {
+                ErrorCode::Internal(format!("Invalid `{OPT_KEY_TABLE_ID}` of StreamTable: {e}"))
+            })?,
+            offset: get_option(OPT_KEY_OFFSET)?,
+            table_info,
+        })
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: STREAM_ENGINE.to_string(),
+            comment: "STREAM STORAGE (TABLE CHANGES)".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// The query the changes of the source table are read with, up to the snapshot `end`
+    /// of the source table, which is the one the offset is moved to if the stream is consumed.
+    pub fn query(&self, end: &str) -> String {
+        let database = quote_ident(&self.table_database);
+        let table = quote_ident(&self.table_name);
+        let database_literal = quote_literal(&self.table_database);
+        let table_literal = quote_literal(&self.table_name);
+
+        // the source table had no snapshot, there are no changes.
+        if end.is_empty() {
+            return format!(
+                "SELECT *, 'INSERT' AS \"{CHANGE_ACTION_COL_NAME}\" FROM {database}.{table} LIMIT 0"
+            );
+        }
+
+        let end = quote_literal(end);
+        if self.offset.is_empty() {
+            return format!(
+                "SELECT *, 'INSERT' AS \"{CHANGE_ACTION_COL_NAME}\" FROM {database}.{table} \
+                 AT (SNAPSHOT => {end})"
+            );
+        }
+
+        let offset = quote_literal(&self.offset);
+        let inserted = format!(
+            "SELECT * FROM {database}.{table} AT (SNAPSHOT => {end}) \
+             WHERE _block_name NOT IN \
+             (SELECT block_location FROM fuse_block({database_literal}, {table_literal}, {offset}))"
+        );
+        let deleted = format!(
+            "SELECT * FROM {database}.{table} AT (SNAPSHOT => {offset}) \
+             WHERE _block_name NOT IN \
+             (SELECT block_location FROM fuse_block({database_literal}, {table_literal}, {end}))"
+        );
+        format!(
+            "SELECT *, 'INSERT' AS \"{CHANGE_ACTION_COL_NAME}\" FROM \
+             ({inserted} EXCEPT ALL {deleted}) AS inserted \
+             UNION ALL \
+             SELECT *, 'DELETE' AS \"{CHANGE_ACTION_COL_NAME}\" FROM \
+             ({deleted} EXCEPT ALL {inserted}) AS deleted"
+        )
+    }
+}
+
+/// Extracts the snapshot id from the location of a FUSE table snapshot,
+/// e.g. `1/2/_ss/0b3b5a1f0ab24c2f8b2cbe8b5d61f6bb_v4.mpk`.
+pub fn snapshot_id_from_location(location: &str) -> String {
+    let file_name = location.rsplit('/').next().unwrap_or_default();
+    file_name
+        .split_once("_v")
+        .map_or(file_name, |(id, _)| id)
+        .to_string()
+}
+
+fn quote_ident(ident: &str) -> String {
+    format!("\"{}\"", ident.replace('"', "\"\""))
+}
+
+fn quote_literal(literal: &str) -> String {
+    format!("'{}'", literal.replace('\'', "''"))
+}
+
+#[async_trait::async_trait]
+impl Table for StreamTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+}
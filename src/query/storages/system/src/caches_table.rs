@@ -66,6 +66,7 @@ impl SyncSystemTable for CachesTable {
         let file_meta_data_cache = cache_manager.get_file_meta_data_cache();
         let table_data_cache = cache_manager.get_table_data_cache();
         let table_column_array_cache = cache_manager.get_table_data_array_cache();
+        let table_data_block_cache = cache_manager.get_table_data_block_cache();
 
         if let Some(table_snapshot_cache) = table_snapshot_cache {
             nodes.push(local_node.clone().into_bytes());
@@ -123,12 +124,19 @@ impl SyncSystemTable for CachesTable {
         }
 
         if let Some(table_column_array_cache) = table_column_array_cache {
-            nodes.push(local_node.into_bytes());
+            nodes.push(local_node.clone().into_bytes());
             names.push("table_column_array_cache");
             num_items.push(table_column_array_cache.len() as u64);
             size.push(table_column_array_cache.size());
         }
 
+        if let Some(table_data_block_cache) = table_data_block_cache {
+            nodes.push(local_node.into_bytes());
+            names.push("table_data_block_cache");
+            num_items.push(table_data_block_cache.len() as u64);
+            size.push(table_data_block_cache.size());
+        }
+
         let names: Vec<_> = names.iter().map(|x| x.as_bytes().to_vec()).collect();
         Ok(DataBlock::new_from_columns(vec![
             StringType::from_data(nodes),
@@ -0,0 +1,125 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_profile::ColumnUsageManager;
+
+use crate::columns_table::GrantObjectVisibilityChecker;
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The columns read by the table scans of this node, aggregated by column.
+pub struct ColumnUsageTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for ColumnUsageTable {
+    const NAME: &'static str = "system.column_usage";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let user = ctx.get_current_user()?;
+        let roles = ctx.get_current_available_roles().await?;
+        let visibility_checker = GrantObjectVisibilityChecker::new(&user, &roles);
+
+        let usages = ColumnUsageManager::instance().list_all();
+
+        let mut catalogs = Vec::with_capacity(usages.len());
+        let mut databases = Vec::with_capacity(usages.len());
+        let mut tables = Vec::with_capacity(usages.len());
+        let mut columns = Vec::with_capacity(usages.len());
+        let mut read_counts = Vec::with_capacity(usages.len());
+        let mut last_read_ons = Vec::with_capacity(usages.len());
+
+        for usage in usages {
+            if !visibility_checker.check_table_visibility(
+                &usage.catalog,
+                &usage.database,
+                &usage.table,
+            ) {
+                continue;
+            }
+
+            catalogs.push(usage.catalog.into_bytes());
+            databases.push(usage.database.into_bytes());
+            tables.push(usage.table.into_bytes());
+            columns.push(usage.column.into_bytes());
+            read_counts.push(usage.read_count);
+            last_read_ons.push(usage.last_read_on.timestamp_micros());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(catalogs),
+            StringType::from_data(databases),
+            StringType::from_data(tables),
+            StringType::from_data(columns),
+            UInt64Type::from_data(read_counts),
+            TimestampType::from_data(last_read_ons),
+        ]))
+    }
+}
+
+impl ColumnUsageTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("catalog", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new("column", TableDataType::String),
+            TableField::new("read_count", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("last_read_on", TableDataType::Timestamp),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'column_usage'".to_string(),
+            name: "column_usage".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemColumnUsage".to_string(),
+
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
@@ -25,9 +25,11 @@ use common_expression::types::StringType;
 use common_expression::types::UInt64Type;
 use common_expression::utils::FromData;
 use common_expression::DataBlock;
+use common_expression::Scalar;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchemaRefExt;
+use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
@@ -35,6 +37,8 @@ use common_meta_app::schema::TableMeta;
 use crate::columns_table::GrantObjectVisibilityChecker;
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
+use crate::util::find_eq_filter;
+use crate::util::find_like_prefix_filter;
 
 pub struct DatabasesTable {
     table_info: TableInfo,
@@ -52,7 +56,7 @@ impl AsyncSystemTable for DatabasesTable {
     async fn get_full_data(
         &self,
         ctx: Arc<dyn TableContext>,
-        _push_downs: Option<PushDownInfo>,
+        push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
         let tenant = ctx.get_tenant();
         let catalogs = CatalogManager::instance();
@@ -66,15 +70,68 @@ impl AsyncSystemTable for DatabasesTable {
         let mut catalog_names = vec![];
         let mut db_names = vec![];
         let mut db_id = vec![];
+        let mut comments = vec![];
 
         let user = ctx.get_current_user()?;
         let roles = ctx.get_current_available_roles().await?;
         let visibility_checker = GrantObjectVisibilityChecker::new(&user, &roles);
 
+        // Filters pushed down from `SHOW DATABASES [LIKE | WHERE]`, used to avoid
+        // listing the databases which would be filtered out anyway.
+        let mut filter_catalogs = Vec::new();
+        let mut filter_dbs = Vec::new();
+        let mut filter_db_prefixes = Vec::new();
+        if let Some(filter) = push_downs.as_ref().and_then(|p| p.filter.as_ref()) {
+            let expr = filter.as_expr(&BUILTIN_FUNCTIONS);
+            find_eq_filter(&expr, &mut |col_name, scalar| {
+                if let Scalar::String(s) = scalar {
+                    if let Ok(value) = String::from_utf8(s.clone()) {
+                        match col_name {
+                            "catalog" => filter_catalogs.push(value),
+                            "name" => filter_dbs.push(value),
+                            _ => {}
+                        }
+                    }
+                }
+            });
+            find_like_prefix_filter(&expr, &mut |col_name, prefix| {
+                if col_name == "name" && !prefix.is_empty() {
+                    filter_db_prefixes.push(prefix.to_string());
+                }
+            });
+        }
+
         for (ctl_name, catalog) in catalogs.into_iter() {
-            let databases = catalog.list_databases(tenant.as_str()).await?;
+            if !filter_catalogs.is_empty() && !filter_catalogs.contains(&ctl_name) {
+                continue;
+            }
+
+            let databases = if filter_dbs.is_empty() {
+                // The databases are listed from meta by the longest prefix, the others are
+                // checked below.
+                let prefix = filter_db_prefixes
+                    .iter()
+                    .max_by_key(|prefix| prefix.len())
+                    .map_or("", |prefix| prefix.as_str());
+                catalog
+                    .list_databases_with_prefix(tenant.as_str(), prefix)
+                    .await?
+            } else {
+                let mut res = Vec::new();
+                for db in &filter_dbs {
+                    if let Ok(database) = catalog.get_database(tenant.as_str(), db).await {
+                        res.push(database);
+                    }
+                }
+                res
+            };
             let final_dbs = databases
                 .into_iter()
+                .filter(|db| {
+                    filter_db_prefixes
+                        .iter()
+                        .all(|prefix| db.name().starts_with(prefix.as_str()))
+                })
                 .filter(|db| visibility_checker.check_database_visibility(&ctl_name, db.name()))
                 .collect::<Vec<_>>();
 
@@ -84,6 +141,7 @@ impl AsyncSystemTable for DatabasesTable {
                 db_names.push(db_name);
                 let id = db.get_db_info().ident.db_id;
                 db_id.push(id);
+                comments.push(db.get_db_info().meta.comment.clone().into_bytes());
             }
         }
 
@@ -91,6 +149,7 @@ impl AsyncSystemTable for DatabasesTable {
             StringType::from_data(catalog_names),
             StringType::from_data(db_names),
             UInt64Type::from_data(db_id),
+            StringType::from_data(comments),
         ]))
     }
 }
@@ -101,6 +160,7 @@ impl DatabasesTable {
             TableField::new("catalog", TableDataType::String),
             TableField::new("name", TableDataType::String),
             TableField::new("database_id", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("comment", TableDataType::String),
         ]);
 
         let table_info = TableInfo {
@@ -26,6 +26,7 @@ mod caches_table;
 mod catalogs_table;
 mod clustering_history_table;
 mod clusters_table;
+mod column_usage_table;
 mod columns_table;
 mod configs_table;
 mod contributors_table;
@@ -37,8 +38,10 @@ mod indexes_table;
 mod log_queue;
 mod malloc_stats_table;
 mod malloc_stats_totals_table;
+mod metasrv_status_table;
 mod metrics_table;
 mod one_table;
+mod partitions_table;
 mod processes_table;
 mod query_cache_table;
 mod query_log_table;
@@ -48,6 +51,7 @@ mod settings_table;
 mod stages_table;
 mod table;
 mod table_functions_table;
+mod tables_history_table;
 mod tables_table;
 mod tracing_table;
 mod users_table;
@@ -63,6 +67,7 @@ pub use clustering_history_table::ClusteringHistoryLogElement;
 pub use clustering_history_table::ClusteringHistoryQueue;
 pub use clustering_history_table::ClusteringHistoryTable;
 pub use clusters_table::ClustersTable;
+pub use column_usage_table::ColumnUsageTable;
 pub use columns_table::ColumnsTable;
 pub use configs_table::ConfigsTable;
 pub use contributors_table::ContributorsTable;
@@ -76,8 +81,10 @@ pub use log_queue::SystemLogQueue;
 pub use log_queue::SystemLogTable;
 pub use malloc_stats_table::MallocStatsTable;
 pub use malloc_stats_totals_table::MallocStatsTotalsTable;
+pub use metasrv_status_table::MetasrvStatusTable;
 pub use metrics_table::MetricsTable;
 pub use one_table::OneTable;
+pub use partitions_table::PartitionsTable;
 pub use processes_table::ProcessesTable;
 pub use query_cache_table::QueryCacheTable;
 pub use query_log_table::LogType;
@@ -91,6 +98,7 @@ pub use stages_table::StagesTable;
 pub use table::SyncOneBlockSystemTable;
 pub use table::SyncSystemTable;
 pub use table_functions_table::TableFunctionsTable;
+pub use tables_history_table::TablesHistoryTable;
 pub use tables_table::TablesTable;
 pub use tables_table::TablesTableWithHistory;
 pub use tables_table::TablesTableWithoutHistory;
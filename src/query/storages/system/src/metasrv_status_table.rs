@@ -0,0 +1,100 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberType;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// Shows the number of keys and bytes stored in meta service, grouped by the top level key prefix,
+/// such as `__fd_table` or `__fd_users`.
+pub struct MetasrvStatusTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for MetasrvStatusTable {
+    const NAME: &'static str = "system.metasrv_status";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let meta_store = UserApiProvider::instance().get_meta_store_client();
+        let stats = meta_store.key_space_stats().await?;
+
+        let mut prefixes = Vec::with_capacity(stats.len());
+        let mut keys = Vec::with_capacity(stats.len());
+        let mut bytes = Vec::with_capacity(stats.len());
+        for stat in stats {
+            prefixes.push(stat.prefix.into_bytes());
+            keys.push(stat.keys);
+            bytes.push(stat.bytes);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(prefixes),
+            NumberType::from_data(keys),
+            NumberType::from_data(bytes),
+        ]))
+    }
+}
+
+impl MetasrvStatusTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("prefix", TableDataType::String),
+            TableField::new("keys", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("bytes", TableDataType::Number(NumberDataType::UInt64)),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'metasrv_status'".to_string(),
+            name: "metasrv_status".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemMetasrvStatus".to_string(),
+
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
@@ -0,0 +1,157 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::FromOptData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_storages_fuse::FuseTable;
+
+use crate::columns_table::GrantObjectVisibilityChecker;
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct PartitionsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for PartitionsTable {
+    const NAME: &'static str = "system.partitions";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
+        let user = ctx.get_current_user()?;
+        let roles = ctx.get_current_available_roles().await?;
+        let visibility_checker = GrantObjectVisibilityChecker::new(&user, &roles);
+
+        let mut databases = vec![];
+        let mut tables = vec![];
+        let mut partitions = vec![];
+        let mut segment_counts = vec![];
+        let mut block_counts = vec![];
+        let mut row_counts = vec![];
+        let mut uncompressed_sizes = vec![];
+        let mut compressed_sizes = vec![];
+
+        for database in catalog.list_databases(tenant.as_str()).await? {
+            let database = database.name();
+            if !visibility_checker.check_database_visibility(CATALOG_DEFAULT, database) {
+                continue;
+            }
+            for table in catalog.list_tables(tenant.as_str(), database).await? {
+                if !visibility_checker.check_table_visibility(
+                    CATALOG_DEFAULT,
+                    database,
+                    table.name(),
+                ) {
+                    continue;
+                }
+                let fuse_table = match FuseTable::try_from_table(table.as_ref()) {
+                    Ok(fuse_table) if fuse_table.is_partitioned() => fuse_table,
+                    _ => continue,
+                };
+
+                for (partition, stats) in fuse_table.partitions(ctx.clone()).await? {
+                    databases.push(database.as_bytes().to_vec());
+                    tables.push(table.name().as_bytes().to_vec());
+                    partitions.push(partition.map(|v| v.to_string().into_bytes()));
+                    segment_counts.push(stats.segment_count);
+                    block_counts.push(stats.block_count);
+                    row_counts.push(stats.row_count);
+                    uncompressed_sizes.push(stats.uncompressed_byte_size);
+                    compressed_sizes.push(stats.compressed_byte_size);
+                }
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(databases),
+            StringType::from_data(tables),
+            StringType::from_opt_data(partitions),
+            UInt64Type::from_data(segment_counts),
+            UInt64Type::from_data(block_counts),
+            UInt64Type::from_data(row_counts),
+            UInt64Type::from_data(uncompressed_sizes),
+            UInt64Type::from_data(compressed_sizes),
+        ]))
+    }
+}
+
+impl PartitionsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new(
+                "partition",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "segment_count",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("block_count", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("row_count", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "bytes_uncompressed",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "bytes_compressed",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'partitions'".to_string(),
+            name: "partitions".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemPartitions".to_string(),
+
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
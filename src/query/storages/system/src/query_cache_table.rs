@@ -67,7 +67,7 @@ impl AsyncSystemTable for QueryCacheTable {
         let mut query_id_vec: Vec<&str> = Vec::with_capacity(cached_values.len());
         let mut result_size_vec = Vec::with_capacity(cached_values.len());
         let mut num_rows_vec = Vec::with_capacity(cached_values.len());
-        let mut partitions_sha_vec = Vec::with_capacity(cached_values.len());
+        let mut table_snapshots_vec = Vec::with_capacity(cached_values.len());
         let mut location_vec = Vec::with_capacity(cached_values.len());
         let mut active_result_scan: Vec<bool> = Vec::with_capacity(cached_values.len());
 
@@ -76,7 +76,7 @@ impl AsyncSystemTable for QueryCacheTable {
             query_id_vec.push(x.query_id.as_str());
             result_size_vec.push(x.result_size as u64);
             num_rows_vec.push(x.num_rows as u64);
-            partitions_sha_vec.push(x.partitions_shas.clone());
+            table_snapshots_vec.push(x.table_snapshots.clone());
             location_vec.push(x.location.as_str());
         });
 
@@ -90,9 +90,9 @@ impl AsyncSystemTable for QueryCacheTable {
             }
         }
 
-        let partitions_sha_vec: Vec<String> = partitions_sha_vec
+        let table_snapshots_vec: Vec<String> = table_snapshots_vec
             .into_iter()
-            .map(|part| part.into_iter().join(", "))
+            .map(|snapshots| snapshots.into_iter().join(", "))
             .collect();
 
         Ok(DataBlock::new_from_columns(vec![
@@ -101,9 +101,9 @@ impl AsyncSystemTable for QueryCacheTable {
             UInt64Type::from_data(result_size_vec),
             UInt64Type::from_data(num_rows_vec),
             StringType::from_data(
-                partitions_sha_vec
+                table_snapshots_vec
                     .iter()
-                    .map(|part_sha| part_sha.as_str())
+                    .map(|snapshots| snapshots.as_str())
                     .collect::<Vec<_>>(),
             ),
             StringType::from_data(location_vec),
@@ -119,7 +119,7 @@ impl QueryCacheTable {
             TableField::new("query_id", TableDataType::String),
             TableField::new("result_size", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("num_rows", TableDataType::Number(NumberDataType::UInt64)),
-            TableField::new("partitions_sha", TableDataType::String),
+            TableField::new("table_snapshots", TableDataType::String),
             TableField::new("location", TableDataType::String),
             TableField::new("active_result_scan", TableDataType::Boolean),
         ]);
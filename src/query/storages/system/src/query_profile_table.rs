@@ -69,6 +69,9 @@ fn encode_operator_attribute(attr: &OperatorAttribute) -> jsonb::Value {
         OperatorAttribute::Lambda(lambda_attr) => {
             (&serde_json::json!({ "scalars": lambda_attr.scalars })).into()
         }
+        OperatorAttribute::Udf(udf_attr) => {
+            (&serde_json::json!({ "scalars": udf_attr.scalars })).into()
+        }
         OperatorAttribute::Limit(limit_attr) => (&serde_json::json!({
             "limit": limit_attr.limit,
             "offset": limit_attr.offset,
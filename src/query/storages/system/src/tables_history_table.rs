@@ -0,0 +1,179 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::catalog::CATALOG_DEFAULT;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+
+use crate::columns_table::GrantObjectVisibilityChecker;
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+use crate::util::find_eq_filter;
+
+/// Lists the versions of the table meta kept in meta, one row per version.
+///
+/// A version is recorded when a table is created and whenever its schema changes,
+/// so that the schema changes can be audited.
+pub struct TablesHistoryTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TablesHistoryTable {
+    const NAME: &'static str = "system.tables_history";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
+
+        let user = ctx.get_current_user()?;
+        let roles = ctx.get_current_available_roles().await?;
+        let visibility_checker = GrantObjectVisibilityChecker::new(&user, &roles);
+
+        let mut filter_dbs = Vec::new();
+        let mut filter_tables = Vec::new();
+        if let Some(filter) = push_downs.as_ref().and_then(|p| p.filter.as_ref()) {
+            let expr = filter.as_expr(&BUILTIN_FUNCTIONS);
+            find_eq_filter(&expr, &mut |col_name, scalar| {
+                if let Scalar::String(s) = scalar {
+                    if let Ok(value) = String::from_utf8(s.clone()) {
+                        match col_name {
+                            "database" => filter_dbs.push(value),
+                            "name" => filter_tables.push(value),
+                            _ => {}
+                        }
+                    }
+                }
+            });
+        }
+
+        let mut databases = vec![];
+        let mut names = vec![];
+        let mut table_ids = vec![];
+        let mut engines = vec![];
+        let mut schemas = vec![];
+        let mut updated_ons = vec![];
+
+        for db in catalog.list_databases(tenant.as_str()).await? {
+            let db_name = db.name();
+            // Tables of the system databases are not kept in meta.
+            if db_name == "system" || db_name == "information_schema" {
+                continue;
+            }
+            if !filter_dbs.is_empty() && !filter_dbs.iter().any(|v| v == db_name) {
+                continue;
+            }
+            if !visibility_checker.check_database_visibility(CATALOG_DEFAULT, db_name) {
+                continue;
+            }
+
+            let tables = catalog
+                .list_tables_history(tenant.as_str(), db_name)
+                .await?;
+            for table in tables {
+                let table_name = table.name();
+                if !filter_tables.is_empty() && !filter_tables.iter().any(|v| v == table_name) {
+                    continue;
+                }
+                if !visibility_checker.check_table_visibility(CATALOG_DEFAULT, db_name, table_name)
+                {
+                    continue;
+                }
+
+                let table_id = table.get_id();
+                let history = catalog.list_table_meta_history(table_id).await?;
+                for (updated_on, table_meta) in history {
+                    let schema = table_meta
+                        .schema
+                        .fields()
+                        .iter()
+                        .map(|f| format!("{} {}", f.name(), f.data_type().sql_name()))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+
+                    databases.push(db_name.as_bytes().to_vec());
+                    names.push(table_name.as_bytes().to_vec());
+                    table_ids.push(table_id);
+                    engines.push(table_meta.engine.as_bytes().to_vec());
+                    schemas.push(format!("({schema})").as_bytes().to_vec());
+                    updated_ons.push(updated_on.timestamp_micros());
+                }
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(databases),
+            StringType::from_data(names),
+            UInt64Type::from_data(table_ids),
+            StringType::from_data(engines),
+            StringType::from_data(schemas),
+            TimestampType::from_data(updated_ons),
+        ]))
+    }
+}
+
+impl TablesHistoryTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("database", TableDataType::String),
+            TableField::new("name", TableDataType::String),
+            TableField::new("table_id", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("engine", TableDataType::String),
+            TableField::new("schema", TableDataType::String),
+            TableField::new("updated_on", TableDataType::Timestamp),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'tables_history'".to_string(),
+            name: "tables_history".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTablesHistory".to_string(),
+
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
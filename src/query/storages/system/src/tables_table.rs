@@ -42,6 +42,7 @@ use crate::columns_table::GrantObjectVisibilityChecker;
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
 use crate::util::find_eq_filter;
+use crate::util::find_like_prefix_filter;
 
 pub struct TablesTable<const WITH_HISTORY: bool> {
     table_info: TableInfo,
@@ -57,6 +58,7 @@ pub trait HistoryAware {
         catalog: &Arc<dyn Catalog>,
         tenant: &str,
         db_name: &str,
+        prefix: &str,
     ) -> Result<Vec<Arc<dyn Table>>>;
 }
 
@@ -68,6 +70,7 @@ impl HistoryAware for TablesTable<true> {
         catalog: &Arc<dyn Catalog>,
         tenant: &str,
         database_name: &str,
+        _prefix: &str,
     ) -> Result<Vec<Arc<dyn Table>>> {
         catalog.list_tables_history(tenant, database_name).await
     }
@@ -81,8 +84,11 @@ impl HistoryAware for TablesTable<false> {
         catalog: &Arc<dyn Catalog>,
         tenant: &str,
         database_name: &str,
+        prefix: &str,
     ) -> Result<Vec<Arc<dyn Table>>> {
-        catalog.list_tables(tenant, database_name).await
+        catalog
+            .list_tables_with_prefix(tenant, database_name, prefix)
+            .await
     }
 }
 
@@ -120,27 +126,49 @@ where TablesTable<T>: HistoryAware
         let roles = ctx.get_current_available_roles().await?;
         let visibility_checker = GrantObjectVisibilityChecker::new(&user, &roles);
 
-        for (ctl_name, ctl) in ctls.into_iter() {
-            let mut dbs = Vec::new();
-            if let Some(push_downs) = &push_downs {
-                let mut db_name = Vec::new();
-                if let Some(filter) = &push_downs.filter {
-                    let expr = filter.as_expr(&BUILTIN_FUNCTIONS);
-                    find_eq_filter(&expr, &mut |col_name, scalar| {
-                        if col_name == "database" {
-                            if let Scalar::String(s) = scalar {
-                                if let Ok(database) = String::from_utf8(s.clone()) {
-                                    db_name.push(database);
-                                }
-                            }
-                        }
-                    });
-                    for db in db_name {
-                        if let Ok(database) = ctl.get_database(tenant.as_str(), db.as_str()).await {
-                            dbs.push(database);
+        // Filters pushed down from `SHOW TABLES [LIKE | WHERE]`, used to avoid
+        // listing the tables which would be filtered out anyway.
+        let mut filter_catalogs = Vec::new();
+        let mut filter_dbs = Vec::new();
+        let mut filter_tables = Vec::new();
+        let mut filter_table_prefixes = Vec::new();
+        if let Some(filter) = push_downs.as_ref().and_then(|p| p.filter.as_ref()) {
+            let expr = filter.as_expr(&BUILTIN_FUNCTIONS);
+            find_eq_filter(&expr, &mut |col_name, scalar| {
+                if let Scalar::String(s) = scalar {
+                    if let Ok(value) = String::from_utf8(s.clone()) {
+                        match col_name {
+                            "catalog" => filter_catalogs.push(value),
+                            "database" => filter_dbs.push(value),
+                            "name" => filter_tables.push(value),
+                            _ => {}
                         }
                     }
                 }
+            });
+            find_like_prefix_filter(&expr, &mut |col_name, prefix| {
+                if col_name == "name" && !prefix.is_empty() {
+                    filter_table_prefixes.push(prefix.to_string());
+                }
+            });
+        }
+
+        // The tables are listed from meta by the longest prefix, the others are checked below.
+        let list_prefix = filter_table_prefixes
+            .iter()
+            .max_by_key(|prefix| prefix.len())
+            .map_or("", |prefix| prefix.as_str());
+
+        for (ctl_name, ctl) in ctls.into_iter() {
+            if !filter_catalogs.is_empty() && !filter_catalogs.contains(&ctl_name) {
+                continue;
+            }
+
+            let mut dbs = Vec::new();
+            for db in &filter_dbs {
+                if let Ok(database) = ctl.get_database(tenant.as_str(), db.as_str()).await {
+                    dbs.push(database);
+                }
             }
 
             if dbs.is_empty() {
@@ -155,21 +183,37 @@ where TablesTable<T>: HistoryAware
             for db in final_dbs {
                 let name = db.name().to_string().into_boxed_str();
                 let name: &str = Box::leak(name);
-                let tables = match Self::list_tables(&ctl, tenant.as_str(), name).await {
-                    Ok(tables) => tables,
-                    Err(err) => {
-                        // Swallow the errors related with sharing. Listing tables in a shared database
-                        // is easy to get errors with invalid configs, but system.tables is better not
-                        // to be affected by it.
-                        if db.get_db_info().meta.from_share.is_some() {
-                            warn!("list tables failed on sharing db {}: {}", db.name(), err);
-                            continue;
+                let tables = if !WITH_HISTORY && !filter_tables.is_empty() {
+                    let mut res = Vec::new();
+                    for table in &filter_tables {
+                        if let Ok(table) = ctl.get_table(tenant.as_str(), name, table).await {
+                            res.push(table);
+                        }
+                    }
+                    res
+                } else {
+                    match Self::list_tables(&ctl, tenant.as_str(), name, list_prefix).await {
+                        Ok(tables) => tables,
+                        Err(err) => {
+                            // Swallow the errors related with sharing. Listing tables in a shared database
+                            // is easy to get errors with invalid configs, but system.tables is better not
+                            // to be affected by it.
+                            if db.get_db_info().meta.from_share.is_some() {
+                                warn!("list tables failed on sharing db {}: {}", db.name(), err);
+                                continue;
+                            }
+                            return Err(err);
                         }
-                        return Err(err);
                     }
                 };
 
                 for table in tables {
+                    if !filter_table_prefixes
+                        .iter()
+                        .all(|prefix| table.name().starts_with(prefix.as_str()))
+                    {
+                        continue;
+                    }
                     // If db1 is visible, do not means db1.table1 is visible. An user may have a grant about db1.table2, so db1 is visible
                     // for her, but db1.table1 may be not visible. So we need an extra check about table here after db visibility check.
                     if visibility_checker.check_table_visibility(ctl_name, db.name(), table.name())
@@ -263,6 +307,26 @@ where TablesTable<T>: HistoryAware
                 }
             })
             .collect();
+        let comments: Vec<Vec<u8>> = database_tables
+            .iter()
+            .map(|v| {
+                v.options()
+                    .get("comment")
+                    .cloned()
+                    .unwrap_or_default()
+                    .into_bytes()
+            })
+            .collect();
+        let tags: Vec<Vec<u8>> = database_tables
+            .iter()
+            .map(|v| {
+                v.options()
+                    .get("tags")
+                    .cloned()
+                    .unwrap_or_default()
+                    .into_bytes()
+            })
+            .collect();
         Ok(DataBlock::new_from_columns(vec![
             StringType::from_data(catalogs),
             StringType::from_data(databases),
@@ -281,6 +345,8 @@ where TablesTable<T>: HistoryAware
             UInt64Type::from_opt_data(index_size),
             UInt64Type::from_opt_data(number_of_segments),
             UInt64Type::from_opt_data(number_of_blocks),
+            StringType::from_data(comments),
+            StringType::from_data(tags),
         ]))
     }
 }
@@ -325,6 +391,8 @@ where TablesTable<T>: HistoryAware
                 "number_of_blocks",
                 TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
             ),
+            TableField::new("comment", TableDataType::String),
+            TableField::new("tags", TableDataType::String),
         ])
     }
 
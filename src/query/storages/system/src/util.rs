@@ -39,3 +39,40 @@ pub fn find_eq_filter(expr: &Expr<String>, visitor: &mut impl FnMut(&str, &Scala
         }
     }
 }
+
+/// Find patterns like `column LIKE '<constant>'` in the conjunctions of the filter,
+/// and pass the literal prefix of the pattern to the visitor.
+///
+/// The prefix ends at the first wildcard or escape character, so every value matching
+/// the pattern must start with the prefix, e.g. `name LIKE 'abc%d'` gives `abc`.
+pub fn find_like_prefix_filter(expr: &Expr<String>, visitor: &mut impl FnMut(&str, &str)) {
+    match expr {
+        Expr::Constant { .. } | Expr::ColumnRef { .. } => {}
+        Expr::Cast { expr, .. } => find_like_prefix_filter(expr, visitor),
+        Expr::FunctionCall { function, args, .. } => {
+            if function.signature.name == "like" {
+                if let [
+                    Expr::ColumnRef { id, .. },
+                    Expr::Constant {
+                        scalar: Scalar::String(pattern),
+                        ..
+                    },
+                ] = args.as_slice()
+                {
+                    let prefix = pattern
+                        .iter()
+                        .take_while(|c| !matches!(c, b'%' | b'_' | b'\\'))
+                        .copied()
+                        .collect::<Vec<_>>();
+                    if let Ok(prefix) = String::from_utf8(prefix) {
+                        visitor(id, &prefix);
+                    }
+                }
+            } else if function.signature.name == "and_filters" {
+                for arg in args {
+                    find_like_prefix_filter(arg, visitor)
+                }
+            }
+        }
+    }
+}
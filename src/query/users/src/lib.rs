@@ -20,7 +20,10 @@ extern crate core;
 
 mod jwt;
 mod network_policy;
+mod pipe;
 mod role_mgr;
+mod sequence;
+mod storage_profile;
 mod user;
 mod user_api;
 mod user_mgr;
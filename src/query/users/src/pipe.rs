@@ -0,0 +1,132 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::time::Duration;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::PipeApi;
+use common_meta_app::principal::PipeInfo;
+use common_meta_app::principal::PipeProgress;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+use crate::UserApiProvider;
+
+impl UserApiProvider {
+    // Add a new pipe.
+    #[async_backtrace::framed]
+    pub async fn add_pipe(&self, tenant: &str, pipe: PipeInfo, if_not_exists: bool) -> Result<u64> {
+        if if_not_exists && self.exists_pipe(tenant, pipe.name.as_str()).await? {
+            return Ok(0);
+        }
+
+        let client = self.get_pipe_api_client(tenant)?;
+        let add_pipe = client.add_pipe(pipe);
+        match add_pipe.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::PIPE_ALREADY_EXISTS {
+                    Ok(0)
+                } else {
+                    Err(e.add_message_back("(while add pipe)"))
+                }
+            }
+        }
+    }
+
+    // Drop a pipe by name.
+    #[async_backtrace::framed]
+    pub async fn drop_pipe(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        let client = self.get_pipe_api_client(tenant)?;
+        match client.drop_pipe(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_PIPE {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop pipe)"))
+                }
+            }
+        }
+    }
+
+    // Check whether a pipe is exist.
+    #[async_backtrace::framed]
+    pub async fn exists_pipe(&self, tenant: &str, name: &str) -> Result<bool> {
+        match self.get_pipe(tenant, name).await {
+            Ok(_) => Ok(true),
+            Err(e) => {
+                if e.code() == ErrorCode::UNKNOWN_PIPE {
+                    Ok(false)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a pipe by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_pipe(&self, tenant: &str, name: &str) -> Result<PipeInfo> {
+        let client = self.get_pipe_api_client(tenant)?;
+        let pipe = client.get_pipe(name, MatchSeq::GE(0)).await?.data;
+        Ok(pipe)
+    }
+
+    // Get all pipes by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_pipes(&self, tenant: &str) -> Result<Vec<PipeInfo>> {
+        let client = self.get_pipe_api_client(tenant)?;
+        let pipes = client
+            .get_pipes()
+            .await
+            .map_err(|e| e.add_message_back(" (while get pipes)."))?;
+        Ok(pipes)
+    }
+
+    // Get the progress of a pipe.
+    #[async_backtrace::framed]
+    pub async fn get_pipe_progress(&self, tenant: &str, name: &str) -> Result<SeqV<PipeProgress>> {
+        let client = self.get_pipe_api_client(tenant)?;
+        client.get_pipe_progress(name).await
+    }
+
+    // Update the progress of a pipe if it's not updated since `seq`.
+    #[async_backtrace::framed]
+    pub async fn update_pipe_progress(
+        &self,
+        tenant: &str,
+        name: &str,
+        progress: &PipeProgress,
+        seq: u64,
+    ) -> Result<u64> {
+        let client = self.get_pipe_api_client(tenant)?;
+        client
+            .update_pipe_progress(name, progress, MatchSeq::Exact(seq))
+            .await
+    }
+
+    // Acquire or renew the lease to run a pipe on this node.
+    #[async_backtrace::framed]
+    pub async fn acquire_pipe_lease(
+        &self,
+        tenant: &str,
+        name: &str,
+        node_id: &str,
+        ttl: Duration,
+    ) -> Result<bool> {
+        let client = self.get_pipe_api_client(tenant)?;
+        client.acquire_pipe_lease(name, node_id, ttl).await
+    }
+}
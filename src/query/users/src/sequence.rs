@@ -0,0 +1,90 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::SequenceApi;
+use common_meta_app::principal::SequenceInfo;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+impl UserApiProvider {
+    // Add a new sequence.
+    #[async_backtrace::framed]
+    pub async fn add_sequence(
+        &self,
+        tenant: &str,
+        sequence: SequenceInfo,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let client = self.get_sequence_api_client(tenant)?;
+        match client.add_sequence(sequence).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::SEQUENCE_ALREADY_EXISTS {
+                    Ok(0)
+                } else {
+                    Err(e.add_message_back("(while add sequence)"))
+                }
+            }
+        }
+    }
+
+    // Drop a sequence by name.
+    #[async_backtrace::framed]
+    pub async fn drop_sequence(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        let client = self.get_sequence_api_client(tenant)?;
+        match client.drop_sequence(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_SEQUENCE {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop sequence)"))
+                }
+            }
+        }
+    }
+
+    // Get a sequence by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_sequence(&self, tenant: &str, name: &str) -> Result<SequenceInfo> {
+        let client = self.get_sequence_api_client(tenant)?;
+        let sequence = client.get_sequence(name, MatchSeq::GE(0)).await?.data;
+        Ok(sequence)
+    }
+
+    // Get all sequences by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_sequences(&self, tenant: &str) -> Result<Vec<SequenceInfo>> {
+        let client = self.get_sequence_api_client(tenant)?;
+        let sequences = client
+            .get_sequences()
+            .await
+            .map_err(|e| e.add_message_back(" (while get sequences)."))?;
+        Ok(sequences)
+    }
+
+    // Allocate `count` numbers of a sequence, returns the sequence before the allocation.
+    #[async_backtrace::framed]
+    pub async fn allocate_sequence_values(
+        &self,
+        tenant: &str,
+        name: &str,
+        count: u64,
+    ) -> Result<SequenceInfo> {
+        let client = self.get_sequence_api_client(tenant)?;
+        client.allocate_sequence_values(name, count).await
+    }
+}
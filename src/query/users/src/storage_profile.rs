@@ -0,0 +1,110 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::StorageProfileApi;
+use common_meta_app::storage::StorageProfile;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+impl UserApiProvider {
+    // Add a new storage profile.
+    #[async_backtrace::framed]
+    pub async fn add_storage_profile(
+        &self,
+        tenant: &str,
+        storage_profile: StorageProfile,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        if if_not_exists
+            && self
+                .exists_storage_profile(tenant, storage_profile.name.as_str())
+                .await?
+        {
+            return Ok(0);
+        }
+
+        let client = self.get_storage_profile_api_client(tenant)?;
+        let add_storage_profile = client.add_storage_profile(storage_profile);
+        match add_storage_profile.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::STORAGE_PROFILE_ALREADY_EXISTS {
+                    Ok(0)
+                } else {
+                    Err(e.add_message_back("(while add storage profile)"))
+                }
+            }
+        }
+    }
+
+    // Drop a storage profile by name.
+    #[async_backtrace::framed]
+    pub async fn drop_storage_profile(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let client = self.get_storage_profile_api_client(tenant)?;
+        match client.drop_storage_profile(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_STORAGE_PROFILE {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop storage profile)"))
+                }
+            }
+        }
+    }
+
+    // Check whether a storage profile is exist.
+    #[async_backtrace::framed]
+    pub async fn exists_storage_profile(&self, tenant: &str, name: &str) -> Result<bool> {
+        match self.get_storage_profile(tenant, name).await {
+            Ok(_) => Ok(true),
+            Err(e) => {
+                if e.code() == ErrorCode::UNKNOWN_STORAGE_PROFILE {
+                    Ok(false)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a storage profile by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_storage_profile(&self, tenant: &str, name: &str) -> Result<StorageProfile> {
+        let client = self.get_storage_profile_api_client(tenant)?;
+        let storage_profile = client
+            .get_storage_profile(name, MatchSeq::GE(0))
+            .await?
+            .data;
+        Ok(storage_profile)
+    }
+
+    // Get all storage profiles by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_storage_profiles(&self, tenant: &str) -> Result<Vec<StorageProfile>> {
+        let client = self.get_storage_profile_api_client(tenant)?;
+        let storage_profiles = client
+            .get_storage_profiles()
+            .await
+            .map_err(|e| e.add_message_back(" (while get storage profiles)."))?;
+        Ok(storage_profiles)
+    }
+}
@@ -16,20 +16,27 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_base::base::GlobalInstance;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_grpc::RpcClientConf;
 use common_management::FileFormatApi;
 use common_management::FileFormatMgr;
 use common_management::NetworkPolicyApi;
 use common_management::NetworkPolicyMgr;
+use common_management::PipeApi;
+use common_management::PipeMgr;
 use common_management::QuotaApi;
 use common_management::QuotaMgr;
 use common_management::RoleApi;
 use common_management::RoleMgr;
+use common_management::SequenceApi;
+use common_management::SequenceMgr;
 use common_management::SettingApi;
 use common_management::SettingMgr;
 use common_management::StageApi;
 use common_management::StageMgr;
+use common_management::StorageProfileApi;
+use common_management::StorageProfileMgr;
 use common_management::UdfApi;
 use common_management::UdfMgr;
 use common_management::UserApi;
@@ -48,6 +55,7 @@ pub struct UserApiProvider {
     meta: MetaStore,
     client: Arc<dyn kvapi::KVApi<Error = MetaError> + Send + Sync>,
     idm_config: IDMConfig,
+    storage_profile_secret: String,
 }
 
 impl UserApiProvider {
@@ -55,10 +63,21 @@ impl UserApiProvider {
     pub async fn init(
         conf: RpcClientConf,
         idm_config: IDMConfig,
+        storage_profile_secret: &str,
         tenant: &str,
         quota: Option<TenantQuota>,
     ) -> Result<()> {
-        GlobalInstance::set(Self::try_create(conf, idm_config).await?);
+        let provider = Self::try_create(conf, idm_config, storage_profile_secret).await?;
+        // The tables bound to the storage profiles can't be loaded without the secret.
+        if storage_profile_secret.is_empty()
+            && StorageProfileMgr::exists_any(provider.client.clone(), tenant).await?
+        {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "Tenant {} has storage profiles, storage_profile_secret must be set",
+                tenant
+            )));
+        }
+        GlobalInstance::set(provider);
         if let Some(q) = quota {
             let i = UserApiProvider::instance().get_tenant_quota_api_client(tenant)?;
             let res = i.get_quota(MatchSeq::GE(0)).await?;
@@ -71,18 +90,20 @@ impl UserApiProvider {
     pub async fn try_create(
         conf: RpcClientConf,
         idm_config: IDMConfig,
+        storage_profile_secret: &str,
     ) -> Result<Arc<UserApiProvider>> {
         let client = MetaStoreProvider::new(conf).create_meta_store().await?;
         Ok(Arc::new(UserApiProvider {
             meta: client.clone(),
             client: client.arc(),
             idm_config,
+            storage_profile_secret: storage_profile_secret.to_string(),
         }))
     }
 
     #[async_backtrace::framed]
     pub async fn try_create_simple(conf: RpcClientConf) -> Result<Arc<UserApiProvider>> {
-        Self::try_create(conf, IDMConfig::default()).await
+        Self::try_create(conf, IDMConfig::default(), "").await
     }
 
     pub fn instance() -> Arc<UserApiProvider> {
@@ -130,6 +151,25 @@ impl UserApiProvider {
         )?))
     }
 
+    pub fn get_pipe_api_client(&self, tenant: &str) -> Result<Arc<impl PipeApi>> {
+        Ok(Arc::new(PipeMgr::create(self.client.clone(), tenant)?))
+    }
+
+    pub fn get_sequence_api_client(&self, tenant: &str) -> Result<Arc<impl SequenceApi>> {
+        Ok(Arc::new(SequenceMgr::create(self.client.clone(), tenant)?))
+    }
+
+    pub fn get_storage_profile_api_client(
+        &self,
+        tenant: &str,
+    ) -> Result<Arc<impl StorageProfileApi>> {
+        Ok(Arc::new(StorageProfileMgr::create(
+            self.client.clone(),
+            tenant,
+            &self.storage_profile_secret,
+        )?))
+    }
+
     pub fn get_meta_store_client(&self) -> Arc<MetaStore> {
         Arc::new(self.meta.clone())
     }
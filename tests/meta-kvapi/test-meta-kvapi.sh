@@ -29,4 +29,7 @@ echo " === test kvapi::list"
 ${DATABEND_META} --grpc-api-address "127.0.0.1:9191" --cmd kvapi::list --prefix 1:
 ${DATABEND_META} --grpc-api-address "127.0.0.1:9191" --cmd kvapi::list --prefix 2:
 
+echo " === test bench"
+${DATABEND_META} --grpc-api-address "127.0.0.1:9191" --cmd bench::upsert,get,txn --bench-clients 2 --bench-requests 30
+
 kill $METASRV_PID
\ No newline at end of file
@@ -0,0 +1,19 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop database if exists db_03_0016" | $MYSQL_CLIENT_CONNECT
+echo "create database db_03_0016" | $MYSQL_CLIENT_CONNECT
+echo "create table db_03_0016.t(a int) Engine = Fuse" | $MYSQL_CLIENT_CONNECT
+
+# the INSERTs commit concurrently, the losers of the commit race rebase their
+# appended segments onto the new head and retry, none of them should fail
+for _ in $(seq 1 10); do
+  echo "insert into db_03_0016.t select number from numbers(100)" | $MYSQL_CLIENT_CONNECT &
+done
+wait
+
+echo "select count(*), sum(a) from db_03_0016.t" | $MYSQL_CLIENT_CONNECT
+
+echo "drop database db_03_0016" | $MYSQL_CLIENT_CONNECT
@@ -0,0 +1,47 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+
+## Create table t12_0005
+echo "drop table if exists t12_0005" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists t12_0005_clone" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists t12_0005_clone_at" | $MYSQL_CLIENT_CONNECT
+echo "create table t12_0005(c int) storage_format = 'native'" | $MYSQL_CLIENT_CONNECT
+echo "insert into t12_0005 values(1),(2)" | $MYSQL_CLIENT_CONNECT
+echo "insert into t12_0005 values(3)" | $MYSQL_CLIENT_CONNECT
+
+echo "clone the latest snapshot, which should contain 3 rows"
+echo "create table t12_0005_clone clone t12_0005" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from t12_0005_clone" | $MYSQL_CLIENT_CONNECT
+
+echo "storage format of the source table is kept"
+echo "set hide_options_in_show_create_table=0; show create table t12_0005_clone" | $MYSQL_CLIENT_CONNECT | grep -o "STORAGE_FORMAT='native'"
+
+## Get the previous snapshot id of the latest snapshot
+SNAPSHOT_ID=$(echo "select previous_snapshot_id from fuse_snapshot('default','t12_0005') where row_count=3 " | $MYSQL_CLIENT_CONNECT)
+
+echo "clone the data set of first insertion, which should contain 2 rows"
+echo "create table t12_0005_clone_at clone t12_0005 at (snapshot => '$SNAPSHOT_ID')" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from t12_0005_clone_at" | $MYSQL_CLIENT_CONNECT
+
+echo "writes to the clone don't change the source table"
+echo "insert into t12_0005_clone_at values(4)" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from t12_0005" | $MYSQL_CLIENT_CONNECT
+
+echo "no data is copied, the blocks of the clone are the ones of the source table"
+echo "select count(*) from fuse_block('default','t12_0005_clone') where block_location not in (select block_location from fuse_block('default','t12_0005'))" | $MYSQL_CLIENT_CONNECT
+
+echo "the shared files are kept while the source table is purged"
+echo "drop table t12_0005 all" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from t12_0005_clone" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from t12_0005_clone_at" | $MYSQL_CLIENT_CONNECT
+
+echo "purging the clone doesn't remove the shared files"
+echo "truncate table t12_0005_clone_at purge" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from t12_0005_clone" | $MYSQL_CLIENT_CONNECT
+
+## Drop table.
+echo "drop table t12_0005_clone" | $MYSQL_CLIENT_CONNECT
+echo "drop table t12_0005_clone_at" | $MYSQL_CLIENT_CONNECT
@@ -0,0 +1,27 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+
+## Create table t12_0006
+echo "create table t12_0006(c int not null)" | $MYSQL_CLIENT_CONNECT
+
+# alter table add a column
+echo "alter table add a column"
+echo "alter table t12_0006 add column a varchar not null" | $MYSQL_CLIENT_CONNECT
+
+echo "system.tables_history should contain 2 versions"
+echo "select schema from system.tables_history where database = 'default' and name = 't12_0006' order by updated_on" | $MYSQL_CLIENT_CONNECT
+
+# Get the time point at which the table was created.
+TIMEPOINT=$(echo "select min(updated_on) from system.tables_history where database = 'default' and name = 't12_0006'" | $MYSQL_CLIENT_CONNECT)
+
+echo "describe the table at the time point of creation, which should contain 1 column"
+echo "describe t12_0006 at (TIMESTAMP => '$TIMEPOINT'::TIMESTAMP)" | $MYSQL_CLIENT_CONNECT | cut -f1,2
+
+echo "describe the latest table, which should contain 2 columns"
+echo "describe t12_0006" | $MYSQL_CLIENT_CONNECT | cut -f1,2
+
+## Drop table.
+echo "drop table t12_0006" | $MYSQL_CLIENT_CONNECT
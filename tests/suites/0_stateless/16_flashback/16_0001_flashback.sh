@@ -42,8 +42,8 @@ echo "select count(*)=1  from fuse_snapshot('default', 't16')" | $MYSQL_CLIENT_C
 echo "flash back to snapshot id that not exist should report error 1105"
 echo "alter table t16 flashback to (snapshot => 'NOTE_EXIST')" | $MYSQL_CLIENT_CONNECT
 
-echo "flash back to timestamp that not exist should report error 1105"
-echo "alter table t16 flashback to (TIMESTAMP => '2000-12-06 04:35:17.856848'::TIMESTAMP)" | $MYSQL_CLIENT_CONNECT;
+echo "flash back to timestamp out of the retention period should report error 2013"
+echo "alter table t16 flashback to (TIMESTAMP => '2000-12-06 04:35:17.856848'::TIMESTAMP)" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -o "Code: 2013, Text = Time travel point 2000-12-06 04:35:17.856848 UTC is out of the retention period of 12 hours"
 
 # flash back to point that does not visible to the current snapshot will also fail
 #  although $SNAPSHOT_ID has been in the history of table `t16`, but
@@ -56,13 +56,10 @@ echo "select 'test -- optimize table'" | $TEST_USER_CONNECT
 echo "optimize table t20_0012 all" | $TEST_USER_CONNECT
 ## grant user privilege
 echo "GRANT Super ON *.* TO 'test-user'" | $MYSQL_CLIENT_CONNECT
-echo "GRANT SELECT ON system.fuse_snapshot TO 'test-user'" | $MYSQL_CLIENT_CONNECT
 ## optimize table
 echo "set retention_period=0; optimize table t20_0012 all" | $TEST_USER_CONNECT
 ## verify
 echo "select count(*)>=1  from fuse_snapshot('default', 't20_0012')" | $TEST_USER_CONNECT
-## revoke privilege
-echo "REVOKE SELECT ON system.fuse_snapshot FROM 'test-user'" | $MYSQL_CLIENT_CONNECT
 
 ## select data
 echo "select 'test -- select'" | $TEST_USER_CONNECT
@@ -93,24 +90,24 @@ echo "REVOKE SELECT ON default.t20_0012_a FROM 'test-user'" | $MYSQL_CLIENT_CONN
 echo "REVOKE SELECT ON default.t20_0012_b FROM 'test-user'" | $MYSQL_CLIENT_CONNECT
 echo "select * from default2.v_t20_0012" | $TEST_USER_CONNECT
 
-## select procedure
+## select procedure, the privilege is checked on the target table
 ## clustering_information
 echo "select 'test -- clustering_information'" | $MYSQL_CLIENT_CONNECT
 echo "select count(*)>=1 from clustering_information('default', 't20_0012_a')" | $TEST_USER_CONNECT
-echo "GRANT SELECT ON system.clustering_information TO 'test-user'" | $MYSQL_CLIENT_CONNECT
+echo "GRANT SELECT ON default.t20_0012_a TO 'test-user'" | $MYSQL_CLIENT_CONNECT
 echo "select count(*)>=1 from clustering_information('default', 't20_0012_a')" | $TEST_USER_CONNECT
 ## fuse_snapshot
 echo "select count(*)>=1 from fuse_snapshot('default', 't20_0012_a')" | $TEST_USER_CONNECT
-echo "GRANT SELECT ON system.fuse_snapshot TO 'test-user'" | $MYSQL_CLIENT_CONNECT
-echo "select count(*)>=1 from fuse_snapshot('default', 't20_0012_a')" | $TEST_USER_CONNECT
+echo "select count(*)>=1 from fuse_snapshot('default', 't20_0012_b')" | $TEST_USER_CONNECT
 ## fuse_segment
 echo "select count(*)=0 from fuse_segment('default', 't20_0012_a', '')" | $TEST_USER_CONNECT
-echo "GRANT SELECT ON system.fuse_segment TO 'test-user'" | $MYSQL_CLIENT_CONNECT
-echo "select count(*)=0 from fuse_segment('default', 't20_0012_a', '')" | $TEST_USER_CONNECT
 ## fuse_block
 echo "select count(*)>=1 from fuse_block('default', 't20_0012_a')" | $TEST_USER_CONNECT
-echo "GRANT SELECT ON system.fuse_block TO 'test-user'" | $MYSQL_CLIENT_CONNECT
-echo "select count(*)>=1 from fuse_block('default', 't20_0012_a')" | $TEST_USER_CONNECT
+## fuse_vacuum
+echo "REVOKE DELETE ON * FROM 'test-user'" | $MYSQL_CLIENT_CONNECT
+echo "select count(*)>=0 from fuse_vacuum('default', 't20_0012_a')" | $TEST_USER_CONNECT
+echo "GRANT DELETE ON default.t20_0012_a TO 'test-user'" | $MYSQL_CLIENT_CONNECT
+echo "select count(*)>=0 from fuse_vacuum('default', 't20_0012_a')" | $TEST_USER_CONNECT
 
 ## Drop table.
 echo "drop table default.t20_0012 all" | $MYSQL_CLIENT_CONNECT
@@ -0,0 +1,24 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists wrong_csv;" | $MYSQL_CLIENT_CONNECT
+
+## Create table
+echo "CREATE TABLE wrong_csv(Id INT, City VARCHAR, Score INT);" | $MYSQL_CLIENT_CONNECT
+
+# Should be <root>/tests/data/
+DATADIR=$(realpath $CURDIR/../../../data/)
+
+COPY_CSV="COPY INTO wrong_csv FROM 'fs://${DATADIR}/' pattern='wrong_sample.*.csv' FILE_FORMAT = (type = CSV field_delimiter = ','  record_delimiter = '\n' skip_header = 0) ON_ERROR=continue RETURN_FILE_STATUS=true"
+
+# print the file name, rows loaded and errors seen of each file
+echo "$COPY_CSV" | $MYSQL_CLIENT_CONNECT | awk -F'\t' '{n=split($1, p, "/"); print p[n], $2, $3}' | sort
+echo "select count(1) from wrong_csv" | $MYSQL_CLIENT_CONNECT
+
+# files already loaded are skipped
+echo "$COPY_CSV" | $MYSQL_CLIENT_CONNECT | wc -l
+
+## Drop table
+echo "drop table if exists wrong_csv;" | $MYSQL_CLIENT_CONNECT
@@ -0,0 +1,32 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists table_storage_profile;" | $MYSQL_CLIENT_CONNECT
+echo "drop storage profile if exists test_profile;" | $MYSQL_CLIENT_CONNECT
+
+## Create storage profile
+echo "create storage profile test_profile 's3://testbucket/admin/profile/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='${STORAGE_S3_ENDPOINT_URL}') comment = 'minio';" | $MYSQL_CLIENT_CONNECT
+echo "show storage profiles;" | $MYSQL_CLIENT_CONNECT | cut -f 1,3
+
+## Create table with the storage profile
+echo "create table table_storage_profile(a int) storage_profile = 'test_profile';" | $MYSQL_CLIENT_CONNECT
+echo "insert into table_storage_profile(a) values(888)" | $MYSQL_CLIENT_CONNECT
+echo "insert into table_storage_profile(a) values(1024)" | $MYSQL_CLIENT_CONNECT
+
+## Select table
+echo "select * from table_storage_profile order by a;" | $MYSQL_CLIENT_CONNECT
+
+## The option can't be changed
+echo "alter table table_storage_profile set options(storage_profile = 'test_profile');" | $MYSQL_CLIENT_CONNECT
+
+## Unknown storage profile
+echo "create table table_storage_profile_unknown(a int) storage_profile = 'unknown_profile';" | $MYSQL_CLIENT_CONNECT
+
+## The storage profile is in use
+echo "drop storage profile test_profile;" | $MYSQL_CLIENT_CONNECT
+
+## Drop table
+echo "drop table if exists table_storage_profile;" | $MYSQL_CLIENT_CONNECT
+echo "drop storage profile test_profile;" | $MYSQL_CLIENT_CONNECT
@@ -0,0 +1,17 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists external_customer;" | $MYSQL_CLIENT_CONNECT
+
+## Create table
+echo "create table external_customer engine = external 's3://testbucket/admin/data/hive/customer_p2/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='${STORAGE_S3_ENDPOINT_URL}') partition_columns='c_region,c_nation';" | $MYSQL_CLIENT_CONNECT
+
+## Select table
+echo "select * from external_customer order by c_nation, foo;" | $MYSQL_CLIENT_CONNECT
+echo "select c_nation from external_customer where c_nation like 'CHIN%' order by foo;" | $MYSQL_CLIENT_CONNECT
+echo "select * from external_customer where c_region = 'ASIA' and foo = 'foo2' order by c_nation;" | $MYSQL_CLIENT_CONNECT
+
+## Drop table
+echo "drop table if exists external_customer;" | $MYSQL_CLIENT_CONNECT
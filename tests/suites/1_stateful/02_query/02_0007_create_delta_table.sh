@@ -0,0 +1,17 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists delta_customer;" | $MYSQL_CLIENT_CONNECT
+
+## Create table
+echo "create table delta_customer engine = delta 's3://testbucket/admin/data/delta/customer_p2/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='${STORAGE_S3_ENDPOINT_URL}');" | $MYSQL_CLIENT_CONNECT
+
+## Select table, the files of RUSSIA are removed, and the row 'foo' of GERMANY is deleted by a deletion vector
+echo "select * from delta_customer order by c_nation, foo;" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from delta_customer where c_region = 'EUROPE';" | $MYSQL_CLIENT_CONNECT
+echo "select c_nation, foo from delta_customer where c_region = 'ASIA' and foo = 'foo2' order by c_nation;" | $MYSQL_CLIENT_CONNECT
+
+## Drop table
+echo "drop table if exists delta_customer;" | $MYSQL_CLIENT_CONNECT
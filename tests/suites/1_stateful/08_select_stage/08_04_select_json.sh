@@ -0,0 +1,24 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+DATADIR_PATH="$CURDIR/../../../data/"
+
+echo "drop stage if exists data_json;" | $MYSQL_CLIENT_CONNECT
+echo "create stage data_json url = 'fs://$DATADIR_PATH' FILE_FORMAT = (type = JSON)" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table if exists t;" | $MYSQL_CLIENT_CONNECT
+echo "create table t (b int, c string, d variant)" | $MYSQL_CLIENT_CONNECT
+
+echo "---simple"
+echo "select \$1 from @data_json (files=>('json_sample.json')) order by \$1:b;" | $MYSQL_CLIENT_CONNECT
+
+echo "---alias"
+echo "select \$1:c as c from @data_json (files=>('json_sample.json')) order by c;" | $MYSQL_CLIENT_CONNECT
+
+echo "---copy"
+echo "copy into t from @data_json files=('json_sample.json');" | $MYSQL_CLIENT_CONNECT
+echo "select * from t order by b" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table if exists t;" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists data_json;" | $MYSQL_CLIENT_CONNECT
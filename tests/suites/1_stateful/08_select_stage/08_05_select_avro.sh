@@ -0,0 +1,21 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+DATADIR_PATH="$CURDIR/../../../data/"
+
+echo "drop stage if exists data_avro;" | $MYSQL_CLIENT_CONNECT
+echo "create stage data_avro url = 'fs://$DATADIR_PATH' FILE_FORMAT = (type = AVRO)" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table if exists t;" | $MYSQL_CLIENT_CONNECT
+echo "create table t (id int, name string, price decimal(10, 2), created_at timestamp)" | $MYSQL_CLIENT_CONNECT
+
+echo "---simple"
+echo "select \$1 from @data_avro (files=>('avro_sample.avro')) order by \$1:id;" | $MYSQL_CLIENT_CONNECT
+
+echo "---copy"
+echo "copy into t from @data_avro files=('avro_sample.avro');" | $MYSQL_CLIENT_CONNECT
+echo "select * from t order by id" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table if exists t;" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists data_avro;" | $MYSQL_CLIENT_CONNECT
@@ -84,6 +84,17 @@ if __name__ == "__main__":
         data = mycursor.fetchall()
         print(data)
 
+        # filter and sort on the masked column see the masked values
+        mycursor.execute("select * from data_mask_test where b = 'abc'")
+        data = mycursor.fetchall()
+        print(data)
+        mycursor.execute("select count(*) from data_mask_test where b = '*********'")
+        data = mycursor.fetchall()
+        print(data)
+        mycursor.execute("select b from data_mask_test order by b limit 1")
+        data = mycursor.fetchall()
+        print(data)
+
         # set column b masking policy
         sql = " alter table data_mask_test modify column a set masking policy maska"
         mycursor.execute(sql)